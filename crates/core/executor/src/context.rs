@@ -4,6 +4,7 @@ use std::sync::Arc;
 use hashbrown::HashMap;
 
 use crate::{
+    gas::GasMeter,
     hook::{hookify, BoxedHook, HookEnv, HookRegistry},
     subproof::SubproofVerifier,
 };
@@ -21,6 +22,9 @@ pub struct SP1Context<'a> {
 
     /// The maximum number of cpu cycles to use for execution.
     pub max_cycles: Option<u64>,
+
+    /// The meter of the gas of the execution, if it is bounded by a gas limit.
+    pub gas_meter: Option<GasMeter>,
 }
 
 /// A builder for [`SP1Context`].
@@ -30,6 +34,7 @@ pub struct SP1ContextBuilder<'a> {
     hook_registry_entries: Vec<(u32, BoxedHook<'a>)>,
     subproof_verifier: Option<Arc<dyn SubproofVerifier + 'a>>,
     max_cycles: Option<u64>,
+    gas_meter: Option<GasMeter>,
 }
 
 impl<'a> SP1Context<'a> {
@@ -68,7 +73,8 @@ impl<'a> SP1ContextBuilder<'a> {
             });
         let subproof_verifier = take(&mut self.subproof_verifier);
         let cycle_limit = take(&mut self.max_cycles);
-        SP1Context { hook_registry, subproof_verifier, max_cycles: cycle_limit }
+        let gas_meter = take(&mut self.gas_meter);
+        SP1Context { hook_registry, subproof_verifier, max_cycles: cycle_limit, gas_meter }
     }
 
     /// Add a runtime [Hook](super::Hook) into the context.
@@ -110,6 +116,12 @@ impl<'a> SP1ContextBuilder<'a> {
         self.max_cycles = Some(max_cycles);
         self
     }
+
+    /// Meter the gas of the execution, which fails once the limit of the meter is exceeded.
+    pub fn gas_meter(&mut self, gas_meter: GasMeter) -> &mut Self {
+        self.gas_meter = Some(gas_meter);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -120,11 +132,12 @@ mod tests {
 
     #[test]
     fn defaults() {
-        let SP1Context { hook_registry, subproof_verifier, max_cycles: cycle_limit } =
+        let SP1Context { hook_registry, subproof_verifier, max_cycles: cycle_limit, gas_meter } =
             SP1Context::builder().build();
         assert!(hook_registry.is_none());
         assert!(subproof_verifier.is_none());
         assert!(cycle_limit.is_none());
+        assert!(gas_meter.is_none());
     }
 
     #[test]
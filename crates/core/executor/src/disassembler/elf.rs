@@ -8,6 +8,9 @@ use elf::{
 };
 use sp1_primitives::consts::{MAXIMUM_MEMORY_SIZE, WORD_SIZE};
 
+#[cfg(feature = "profiling")]
+use {crate::FunctionSymbol, elf::abi::STT_FUNC};
+
 /// RISC-V 32IM ELF (Executable and Linkable Format) File.
 ///
 /// This file represents a binary in the ELF format, specifically the RISC-V 32IM architecture
@@ -144,4 +147,41 @@ impl Elf {
 
         Ok(Elf::new(instructions, entry, base_address, image))
     }
+
+    /// Read the functions of the symbol table of the ELF, sorted by address, with their names
+    /// demangled. Functions at the same address as an earlier one are dropped, and each function
+    /// ends at the start of the next one at the latest, so that the ranges are disjoint.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if the symbol table is not valid.
+    #[cfg(feature = "profiling")]
+    pub(crate) fn decode_function_symbols(input: &[u8]) -> eyre::Result<Vec<FunctionSymbol>> {
+        let elf = ElfBytes::<LittleEndian>::minimal_parse(input)?;
+        let Some((symbols, strings)) = elf.symbol_table()? else {
+            return Ok(Vec::new());
+        };
+
+        let mut functions = Vec::new();
+        for symbol in symbols.iter() {
+            if symbol.st_symtype() != STT_FUNC || symbol.st_size == 0 {
+                continue;
+            }
+            let start: u32 = symbol.st_value.try_into()?;
+            let size: u32 = symbol.st_size.try_into()?;
+            let name = strings.get(symbol.st_name as usize)?;
+            functions.push(FunctionSymbol {
+                name: format!("{:#}", rustc_demangle::demangle(name)),
+                start,
+                end: start.saturating_add(size),
+            });
+        }
+
+        functions.sort_by_key(|function| function.start);
+        functions.dedup_by_key(|function| function.start);
+        for i in 1..functions.len() {
+            functions[i - 1].end = functions[i - 1].end.min(functions[i].start);
+        }
+        Ok(functions)
+    }
 }
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use crate::{ByteOpcode, Opcode};
 
 /// The number of different byte operations.
-pub const NUM_BYTE_OPS: usize = 9;
+pub const NUM_BYTE_OPS: usize = 12;
 
 /// Byte Lookup Event.
 ///
@@ -113,6 +113,18 @@ pub trait ByteRecord {
         ls.iter().for_each(|x| self.add_u16_range_check(shard, channel, *x));
     }
 
+    /// Adds the `ByteLookupEvent`s checking that `value` is a canonical BabyBear element, matching
+    /// `WordAirBuilder::assert_in_field`.
+    fn add_in_field_range_checks(&mut self, shard: u32, channel: u8, value: u32) {
+        debug_assert!(value < 0x78000001, "{value:#x} is not a canonical BabyBear element");
+        let bytes = value.to_le_bytes();
+        self.add_u8_range_checks(shard, channel, &bytes);
+        for byte in &bytes[..3] {
+            let diff = u16::from(0x78 - bytes[3]) * u16::from(u8::MAX) - u16::from(*byte);
+            self.add_u16_range_check(shard, channel, diff);
+        }
+    }
+
     /// Adds a `ByteLookupEvent` to compute the bitwise OR of the two input values.
     fn lookup_or(&mut self, shard: u32, channel: u8, b: u8, c: u8) {
         self.add_byte_lookup_event(ByteLookupEvent {
@@ -243,6 +255,9 @@ impl ByteOpcode {
             ByteOpcode::LTU,
             ByteOpcode::MSB,
             ByteOpcode::U16Range,
+            ByteOpcode::SBOX,
+            ByteOpcode::POPCNT,
+            ByteOpcode::CLZ,
         ];
         assert_eq!(opcodes.len(), NUM_BYTE_OPS);
         opcodes
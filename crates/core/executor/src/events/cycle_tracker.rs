@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Cycle Tracker Event.
+///
+/// This object records a region of the guest program delimited by the `CYCLE_TRACKER_START` and
+/// `CYCLE_TRACKER_END` syscalls. It is not proven; it only feeds the
+/// [`crate::CycleReport`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycleTrackerEvent {
+    /// The hash of the region's label.
+    pub label_hash: u32,
+    /// The global clock when the region was entered.
+    pub start_clk: u64,
+    /// The global clock when the region was exited.
+    pub end_clk: u64,
+}
@@ -3,6 +3,7 @@
 mod alu;
 mod byte;
 mod cpu;
+mod cycle_tracker;
 mod memory;
 mod precompiles;
 mod utils;
@@ -10,6 +11,7 @@ mod utils;
 pub use alu::*;
 pub use byte::*;
 pub use cpu::*;
+pub use cycle_tracker::*;
 pub use memory::*;
 pub use precompiles::*;
 pub use utils::*;
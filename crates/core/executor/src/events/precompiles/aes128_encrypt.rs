@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// The number of words in an AES block and in an AES-128 key.
+pub const AES128_BLOCK_NUM_WORDS: usize = 4;
+
+/// AES-128 Encrypt Event.
+///
+/// This event is emitted when a block is encrypted with AES-128.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Aes128EncryptEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the block, which holds the plaintext and then the ciphertext.
+    pub block_ptr: u32,
+    /// The pointer to the key.
+    pub key_ptr: u32,
+    /// The plaintext as a list of words.
+    pub plaintext: [u32; AES128_BLOCK_NUM_WORDS],
+    /// The key as a list of words.
+    pub key: [u32; AES128_BLOCK_NUM_WORDS],
+    /// The memory records for the ciphertext written over the plaintext.
+    pub block_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the key.
+    pub key_memory_records: Vec<MemoryReadRecord>,
+}
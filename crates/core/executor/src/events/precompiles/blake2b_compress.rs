@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// The number of words in the Blake2b state, with each u64 split into two u32 words.
+pub(crate) const BLAKE2B_STATE_NUM_WORDS: usize = 16;
+
+/// The number of words in a Blake2b block: the 32-word message, the 4-word byte counter, and the
+/// final block flag.
+pub(crate) const BLAKE2B_BLOCK_NUM_WORDS: usize = 37;
+
+/// Blake2b Compress Event.
+///
+/// This event is emitted when a Blake2b compression function call is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Blake2bCompressEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The pointer to the block.
+    pub block_ptr: u32,
+    /// The state before the compression.
+    pub state: [u32; BLAKE2B_STATE_NUM_WORDS],
+    /// The message, byte counter, and final block flag.
+    pub block: [u32; BLAKE2B_BLOCK_NUM_WORDS],
+    /// The memory records for reading the state.
+    pub state_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for writing the state.
+    pub state_write_records: Vec<MemoryWriteRecord>,
+    /// The memory records for reading the block.
+    pub block_read_records: Vec<MemoryReadRecord>,
+}
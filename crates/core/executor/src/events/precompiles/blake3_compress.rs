@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// The number of words in the Blake3 state and message block.
+pub(crate) const BLAKE3_NUM_WORDS: usize = 16;
+
+/// Blake3 Compress Event.
+///
+/// This event is emitted when the rounds of a Blake3 compression are performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Blake3CompressEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The pointer to the message block.
+    pub message_ptr: u32,
+    /// The state before the rounds.
+    pub state: [u32; BLAKE3_NUM_WORDS],
+    /// The message block.
+    pub message: [u32; BLAKE3_NUM_WORDS],
+    /// The memory records for reading the state.
+    pub state_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for writing the state.
+    pub state_write_records: Vec<MemoryWriteRecord>,
+    /// The memory records for reading the message block.
+    pub message_read_records: Vec<MemoryReadRecord>,
+}
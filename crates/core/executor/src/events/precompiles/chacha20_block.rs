@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// The number of words in the ChaCha20 state.
+pub(crate) const CHACHA20_NUM_WORDS: usize = 16;
+
+/// ChaCha20 Block Event.
+///
+/// This event is emitted when a ChaCha20 keystream block is computed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChaCha20BlockEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The input state.
+    pub state: [u32; CHACHA20_NUM_WORDS],
+    /// The memory records for reading the state.
+    pub state_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for writing the keystream block.
+    pub state_write_records: Vec<MemoryWriteRecord>,
+}
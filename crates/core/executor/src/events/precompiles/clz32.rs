@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryWriteRecord, LookupId};
+
+/// Clz32 Event.
+///
+/// This event is emitted when the leading zeros of a word are counted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Clz32Event {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the word, which the count is written over.
+    pub ptr: u32,
+    /// The word whose leading zeros are counted.
+    pub value: u32,
+    /// The memory record for the count written over the word.
+    pub memory_record: MemoryWriteRecord,
+}
@@ -1,8 +1,12 @@
+use num::{BigUint, Zero};
 use serde::{Deserialize, Serialize};
 
 use sp1_curves::{
     params::{NumLimbs, NumWords},
-    weierstrass::{bls12_381::bls12381_decompress, secp256k1::secp256k1_decompress},
+    weierstrass::{
+        bls12_381::bls12381_decompress, p256::p256_decompress, p384::p384_decompress,
+        secp256k1::secp256k1_decompress, WeierstrassParameters,
+    },
     AffinePoint, CurveType, EllipticCurve,
 };
 use sp1_primitives::consts::{bytes_to_words_le_vec, words_to_bytes_le_vec};
@@ -63,6 +67,116 @@ pub struct EllipticCurveDoubleEvent {
     pub p_memory_records: Vec<MemoryWriteRecord>,
 }
 
+/// Elliptic Curve Scalar Multiplication Event.
+///
+/// This event is emitted when an elliptic curve scalar multiplication operation is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EllipticCurveMulEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the point.
+    pub p_ptr: u32,
+    /// The point as a list of words.
+    pub p: Vec<u32>,
+    /// The pointer to the scalar.
+    pub scalar_ptr: u32,
+    /// The scalar as a list of little endian words.
+    pub scalar: Vec<u32>,
+    /// The memory records for reading the point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the scalar.
+    pub scalar_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for writing the result to the point.
+    pub result_memory_records: Vec<MemoryWriteRecord>,
+}
+
+/// The number of bits of each window of an elliptic curve multi-scalar multiplication.
+pub const MSM_WINDOW_BITS: usize = 8;
+
+/// The number of windows of a 256-bit scalar.
+pub const MSM_NUM_WINDOWS: usize = 256 / MSM_WINDOW_BITS;
+
+/// The largest number of (scalar, point) pairs of a multi-scalar multiplication.
+pub const MSM_MAX_PAIRS: u32 = 255;
+
+/// The number of words of a (scalar, point) pair of a multi-scalar multiplication: an 8 word
+/// scalar followed by a 16 word point.
+pub const MSM_PAIR_WORDS: usize = 24;
+
+/// Elliptic Curve Multi-Scalar Multiplication Event.
+///
+/// This event is emitted when the sum of a list of points multiplied by scalars is computed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EllipticCurveMsmEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the (scalar, point) pairs.
+    pub ptr: u32,
+    /// The number of pairs.
+    pub num_pairs: u32,
+    /// The scalars as lists of little endian words.
+    pub scalars: Vec<Vec<u32>>,
+    /// The points as lists of words.
+    pub points: Vec<Vec<u32>>,
+    /// The memory records for reading the pairs, one list per window and pair, most significant
+    /// window first. Each list is the scalar word of the window followed by the point.
+    pub pair_memory_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for writing the result over the first point.
+    pub result_memory_records: Vec<MemoryWriteRecord>,
+}
+
+/// The largest number of (scalar, point) pairs of an elliptic curve linear combination.
+pub const LINEAR_COMBINATION_MAX_PAIRS: u32 = 8;
+
+/// The number of words of an entry of a linear combination: a pointer to a scalar followed by a
+/// pointer to a point.
+pub const LINEAR_COMBINATION_ENTRY_WORDS: usize = 2;
+
+/// Elliptic Curve Linear Combination Event.
+///
+/// This event is emitted when the sum of a list of points multiplied by scalars, given by pointers,
+/// is computed with Shamir's trick.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EllipticCurveLinearCombinationEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the (scalar pointer, point pointer) entries.
+    pub ptr: u32,
+    /// The number of entries.
+    pub num_pairs: u32,
+    /// The pointers to the scalars.
+    pub scalar_ptrs: Vec<u32>,
+    /// The pointers to the points.
+    pub point_ptrs: Vec<u32>,
+    /// The scalars as lists of little endian words.
+    pub scalars: Vec<Vec<u32>>,
+    /// The points as lists of words.
+    pub points: Vec<Vec<u32>>,
+    /// The memory records for reading the pairs, one list per pair. Each list is the entry of the
+    /// pair followed by the scalar and the point.
+    pub pair_memory_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for writing the result over the first point.
+    pub result_memory_records: Vec<MemoryWriteRecord>,
+}
+
 /// Elliptic Curve Point Decompress Event.
 ///
 /// This event is emitted when an elliptic curve point decompression operation is performed.
@@ -179,6 +293,275 @@ pub fn create_ec_double_event<E: EllipticCurve>(
     }
 }
 
+/// Create an elliptic curve scalar multiplication event.
+///
+/// It takes a pointer to a point and a pointer to a little endian scalar the size of a field
+/// element, multiplies the point by the scalar, and writes the result back to the point. The scalar
+/// must be nonzero and less than the order of the group, so that the result is never the point at
+/// infinity. The point and the scalar must not overlap.
+pub fn create_ec_mul_event<E: EllipticCurve + WeierstrassParameters>(
+    rt: &mut SyscallContext,
+    arg1: u32,
+    arg2: u32,
+) -> EllipticCurveMulEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr % 4 == 0, "p_ptr must be 4-byte aligned");
+    let scalar_ptr = arg2;
+    assert!(scalar_ptr % 4 == 0, "scalar_ptr must be 4-byte aligned");
+
+    let num_words = <E::BaseField as NumWords>::WordsCurvePoint::USIZE;
+    let num_scalar_words = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+
+    let (p_memory_records, p) = rt.mr_slice(p_ptr, num_words);
+    let (scalar_memory_records, scalar) = rt.mr_slice(scalar_ptr, num_scalar_words);
+
+    let scalar_int = BigUint::from_slice(&scalar);
+    assert!(!scalar_int.is_zero(), "scalar must be nonzero");
+    assert!(scalar_int < E::prime_group_order(), "scalar must be less than the group order");
+
+    // The result is written one cycle after the point is read.
+    rt.clk += 1;
+
+    let p_affine = AffinePoint::<E>::from_words_le(&p);
+    let mut result: Option<AffinePoint<E>> = None;
+    for i in (0..scalar_int.bits()).rev() {
+        result = result.map(|acc| E::ec_double(&acc));
+        if scalar_int.bit(i) {
+            result = Some(match result {
+                Some(acc) => E::ec_add(&acc, &p_affine),
+                None => p_affine.clone(),
+            });
+        }
+    }
+    let result_words = result.unwrap().to_words_le();
+
+    let result_memory_records = rt.mw_slice(p_ptr, &result_words);
+
+    EllipticCurveMulEvent {
+        lookup_id: rt.syscall_lookup_id,
+        shard: rt.current_shard(),
+        channel: rt.current_channel(),
+        clk: start_clk,
+        p_ptr,
+        p,
+        scalar_ptr,
+        scalar,
+        p_memory_records,
+        scalar_memory_records,
+        result_memory_records,
+    }
+}
+
+/// Adds `q` to `acc` for the bucket method of an elliptic curve multi-scalar multiplication, where
+/// `None` is the point at infinity.
+///
+/// A point added to itself is doubled. A point added to its negation is not supported, since the
+/// affine formulas can't represent the point at infinity.
+pub fn ec_msm_accumulate<E: EllipticCurve>(
+    acc: &Option<AffinePoint<E>>,
+    q: &AffinePoint<E>,
+) -> AffinePoint<E> {
+    match acc {
+        None => q.clone(),
+        Some(acc) if acc.x == q.x => {
+            assert!(acc.y == q.y, "a partial sum of the msm is the point at infinity");
+            E::ec_double(q)
+        }
+        Some(acc) => E::ec_add(acc, q),
+    }
+}
+
+/// Create an elliptic curve multi-scalar multiplication event.
+///
+/// It takes a pointer to a list of (scalar, point) pairs and the number of pairs, between 1 and
+/// [`MSM_MAX_PAIRS`]. The sum of the points multiplied by their scalars is computed with the bucket
+/// method of Pippenger over windows of [`MSM_WINDOW_BITS`] bits, and written over the first point.
+/// Within each window, the points are added to the running sum in decreasing order of their window
+/// value, so each bucket is summed on the way down and no bucket is stored.
+///
+/// The pairs are read once per window, the `k`-th window being read `k` cycles after the syscall,
+/// and the result is written after the last window. Neither the result nor any partial sum may be
+/// the point at infinity, except for the empty sums at the start of each window.
+pub fn create_ec_msm_event<E: EllipticCurve + WeierstrassParameters>(
+    rt: &mut SyscallContext,
+    arg1: u32,
+    arg2: u32,
+) -> EllipticCurveMsmEvent {
+    let start_clk = rt.clk;
+    let ptr = arg1;
+    assert!(ptr % 4 == 0, "ptr must be 4-byte aligned");
+    let num_pairs = arg2;
+    assert!(
+        (1..=MSM_MAX_PAIRS).contains(&num_pairs),
+        "the number of pairs must be between 1 and {MSM_MAX_PAIRS}"
+    );
+
+    let num_scalar_words = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+    let num_point_words = <E::BaseField as NumWords>::WordsCurvePoint::USIZE;
+    assert_eq!(num_scalar_words + num_point_words, MSM_PAIR_WORDS);
+    let pair_ptr = |i: u32| ptr + (i * MSM_PAIR_WORDS as u32) * 4;
+
+    let scalars =
+        (0..num_pairs).map(|i| rt.slice_unsafe(pair_ptr(i), num_scalar_words)).collect::<Vec<_>>();
+    let points = (0..num_pairs)
+        .map(|i| rt.slice_unsafe(pair_ptr(i) + num_scalar_words as u32 * 4, num_point_words))
+        .collect::<Vec<_>>();
+    let affine_points =
+        points.iter().map(|point| AffinePoint::<E>::from_words_le(point)).collect::<Vec<_>>();
+
+    let mut pair_memory_records = Vec::with_capacity(MSM_NUM_WINDOWS * num_pairs as usize);
+    let mut result: Option<AffinePoint<E>> = None;
+    for k in 0..MSM_NUM_WINDOWS {
+        let window = MSM_NUM_WINDOWS - 1 - k;
+        let scalar_word = window * MSM_WINDOW_BITS / 32;
+
+        // Each window is read one cycle after the previous one.
+        rt.clk = start_clk + k as u32;
+        let mut digits = Vec::with_capacity(num_pairs as usize);
+        for i in 0..num_pairs {
+            let (record, word) = rt.mr(pair_ptr(i) + scalar_word as u32 * 4);
+            let (point_records, _) =
+                rt.mr_slice(pair_ptr(i) + num_scalar_words as u32 * 4, num_point_words);
+            let mut records = vec![record];
+            records.extend(point_records);
+            pair_memory_records.push(records);
+            digits.push((word >> (window * MSM_WINDOW_BITS % 32)) & 0xff);
+        }
+
+        result = result.map(|mut result| {
+            for _ in 0..MSM_WINDOW_BITS {
+                result = E::ec_double(&result);
+            }
+            result
+        });
+
+        // The window sum is the sum of the running sums of the buckets from the top bucket down.
+        let mut running: Option<AffinePoint<E>> = None;
+        let mut window_sum: Option<AffinePoint<E>> = None;
+        for digit in (1..1 << MSM_WINDOW_BITS).rev() {
+            for (i, point) in affine_points.iter().enumerate() {
+                if digits[i] == digit {
+                    running = Some(ec_msm_accumulate(&running, point));
+                }
+            }
+            if let Some(running) = running.as_ref() {
+                window_sum = Some(ec_msm_accumulate(&window_sum, running));
+            }
+        }
+        if let Some(window_sum) = window_sum.as_ref() {
+            result = Some(ec_msm_accumulate(&result, window_sum));
+        }
+    }
+    let result_words =
+        result.expect("the result of the msm is the point at infinity").to_words_le();
+
+    rt.clk = start_clk + MSM_NUM_WINDOWS as u32;
+    let result_memory_records =
+        rt.mw_slice(pair_ptr(0) + num_scalar_words as u32 * 4, &result_words);
+
+    EllipticCurveMsmEvent {
+        lookup_id: rt.syscall_lookup_id,
+        shard: rt.current_shard(),
+        channel: rt.current_channel(),
+        clk: start_clk,
+        ptr,
+        num_pairs,
+        scalars,
+        points,
+        pair_memory_records,
+        result_memory_records,
+    }
+}
+
+/// Create an elliptic curve linear combination event.
+///
+/// It takes a pointer to a list of entries and the number of entries, between 1 and
+/// [`LINEAR_COMBINATION_MAX_PAIRS`]. Each entry is a pointer to a little endian scalar the size of
+/// a field element followed by a pointer to a point. The sum of the points multiplied by their
+/// scalars is computed with Shamir's trick, which doubles a single accumulator once per bit of the
+/// scalars, most significant first, and adds to it the points whose scalar has the bit set. The
+/// result is written over the first point.
+///
+/// The `k`-th pair is read `k` cycles after the syscall, so that pairs may share a scalar or a
+/// point, and the result is written after the reads of the largest number of pairs. Neither the
+/// result nor any partial sum may be the point at infinity.
+pub fn create_ec_linear_combination_event<E: EllipticCurve + WeierstrassParameters>(
+    rt: &mut SyscallContext,
+    arg1: u32,
+    arg2: u32,
+) -> EllipticCurveLinearCombinationEvent {
+    let start_clk = rt.clk;
+    let ptr = arg1;
+    assert!(ptr % 4 == 0, "ptr must be 4-byte aligned");
+    let num_pairs = arg2;
+    assert!(
+        (1..=LINEAR_COMBINATION_MAX_PAIRS).contains(&num_pairs),
+        "the number of pairs must be between 1 and {LINEAR_COMBINATION_MAX_PAIRS}"
+    );
+
+    let num_scalar_words = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+    let num_point_words = <E::BaseField as NumWords>::WordsCurvePoint::USIZE;
+
+    let mut scalars = Vec::with_capacity(num_pairs as usize);
+    let mut points = Vec::with_capacity(num_pairs as usize);
+    let mut scalar_ptrs = Vec::with_capacity(num_pairs as usize);
+    let mut point_ptrs = Vec::with_capacity(num_pairs as usize);
+    let mut pair_memory_records = Vec::with_capacity(num_pairs as usize);
+    for k in 0..num_pairs {
+        // Each pair is read one cycle after the previous one.
+        rt.clk = start_clk + k;
+        let entry_ptr = ptr + k * LINEAR_COMBINATION_ENTRY_WORDS as u32 * 4;
+        let (mut records, entry) = rt.mr_slice(entry_ptr, LINEAR_COMBINATION_ENTRY_WORDS);
+        let (scalar_ptr, point_ptr) = (entry[0], entry[1]);
+        assert!(scalar_ptr % 4 == 0, "scalar_ptr must be 4-byte aligned");
+        assert!(point_ptr % 4 == 0, "point_ptr must be 4-byte aligned");
+        let (scalar_records, scalar) = rt.mr_slice(scalar_ptr, num_scalar_words);
+        let (point_records, point) = rt.mr_slice(point_ptr, num_point_words);
+        records.extend(scalar_records);
+        records.extend(point_records);
+        pair_memory_records.push(records);
+        scalars.push(scalar);
+        points.push(point);
+        scalar_ptrs.push(scalar_ptr);
+        point_ptrs.push(point_ptr);
+    }
+
+    let affine_points =
+        points.iter().map(|point| AffinePoint::<E>::from_words_le(point)).collect::<Vec<_>>();
+    let scalar_ints = scalars.iter().map(|scalar| BigUint::from_slice(scalar)).collect::<Vec<_>>();
+    let mut result: Option<AffinePoint<E>> = None;
+    for bit in (0..num_scalar_words as u64 * 32).rev() {
+        result = result.map(|acc| E::ec_double(&acc));
+        for (scalar, point) in scalar_ints.iter().zip(&affine_points) {
+            if scalar.bit(bit) {
+                result = Some(ec_msm_accumulate(&result, point));
+            }
+        }
+    }
+    let result_words = result
+        .expect("the result of the linear combination is the point at infinity")
+        .to_words_le();
+
+    rt.clk = start_clk + LINEAR_COMBINATION_MAX_PAIRS;
+    let result_memory_records = rt.mw_slice(point_ptrs[0], &result_words);
+
+    EllipticCurveLinearCombinationEvent {
+        lookup_id: rt.syscall_lookup_id,
+        shard: rt.current_shard(),
+        channel: rt.current_channel(),
+        clk: start_clk,
+        ptr,
+        num_pairs,
+        scalar_ptrs,
+        point_ptrs,
+        scalars,
+        points,
+        pair_memory_records,
+        result_memory_records,
+    }
+}
+
 /// Create an elliptic curve decompress event.
 ///
 /// It takes a pointer to a memory location, reads the point from memory, decompresses it, and
@@ -202,16 +585,24 @@ pub fn create_ec_decompress_event<E: EllipticCurve>(
     let mut x_bytes_be = x_bytes.clone();
     x_bytes_be.reverse();
 
-    let decompress_fn = match E::CURVE_TYPE {
-        CurveType::Secp256k1 => secp256k1_decompress::<E>,
-        CurveType::Bls12381 => bls12381_decompress::<E>,
+    let computed_point: Option<AffinePoint<E>> = match E::CURVE_TYPE {
+        CurveType::Secp256k1 => Some(secp256k1_decompress::<E>(&x_bytes_be, sign_bit)),
+        CurveType::Bls12381 => Some(bls12381_decompress::<E>(&x_bytes_be, sign_bit)),
+        CurveType::P256 => p256_decompress::<E>(&x_bytes_be, sign_bit),
+        CurveType::P384 => p384_decompress::<E>(&x_bytes_be, sign_bit),
         _ => panic!("Unsupported curve"),
     };
 
-    let computed_point: AffinePoint<E> = decompress_fn(&x_bytes_be, sign_bit);
-
-    let mut decompressed_y_bytes = computed_point.y.to_bytes_le();
-    decompressed_y_bytes.resize(num_limbs, 0u8);
+    // An x coordinate that is not on the curve is reported to the guest by writing a y coordinate
+    // with every bit set, which is never a reduced field element.
+    let decompressed_y_bytes = match computed_point {
+        Some(point) => {
+            let mut bytes = point.y.to_bytes_le();
+            bytes.resize(num_limbs, 0u8);
+            bytes
+        }
+        None => vec![0xff; num_limbs],
+    };
     let y_words = bytes_to_words_le_vec(&decompressed_y_bytes);
 
     let y_memory_records = rt.mw_slice(slice_ptr, &y_words);
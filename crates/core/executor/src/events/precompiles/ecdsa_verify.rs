@@ -0,0 +1,106 @@
+use num::BigUint;
+use serde::{Deserialize, Serialize};
+use sp1_curves::{
+    params::FieldParameters, weierstrass::WeierstrassParameters, AffinePoint, EllipticCurve,
+};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// The number of words of the input of an ECDSA verification: `r`, `s`, the message hash and the
+/// two coordinates of the public key, each of them 8 little endian words.
+pub const ECDSA_VERIFY_INPUT_WORDS: usize = 40;
+
+/// The number of bits of the scalars of an ECDSA verification.
+pub const ECDSA_SCALAR_BITS: usize = 256;
+
+/// ECDSA Verify Event.
+///
+/// This event is emitted when an ECDSA signature is verified against a message hash and a public
+/// key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcdsaVerifyEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the input, which is followed by the result.
+    pub ptr: u32,
+    /// The input as a list of words: `r`, `s`, the message hash and the public key.
+    pub input: Vec<u32>,
+    /// Whether the signature is valid.
+    pub is_valid: bool,
+    /// The memory records for the input.
+    pub input_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result.
+    pub result_memory_record: MemoryWriteRecord,
+}
+
+/// Adds `q` to `acc`, where `None` is the point at infinity. Both points must be on the curve, so
+/// that two points with the same x coordinate are either equal or the negation of each other.
+pub fn ecdsa_accumulate<E: EllipticCurve>(
+    acc: &Option<AffinePoint<E>>,
+    q: &AffinePoint<E>,
+) -> Option<AffinePoint<E>> {
+    match acc {
+        None => Some(q.clone()),
+        Some(acc) if acc.x == q.x && acc.y == q.y => Some(E::ec_double(q)),
+        Some(acc) if acc.x == q.x => None,
+        Some(acc) => Some(E::ec_add(acc, q)),
+    }
+}
+
+/// Verifies the ECDSA signature `(r, s)` of the message hash `z` for the public key `(q_x, q_y)`.
+///
+/// The scalars must be between 1 and the order of the curve minus one, and the coordinates must
+/// be reduced. The signature is valid if the public key is on the curve, and if the x coordinate
+/// of `u1 * G + u2 * Q` is `r` modulo the order, where `u1 = z / s` and `u2 = r / s`. The sum is
+/// computed with the trick of Shamir, adding `G`, `Q` or `G + Q` after each doubling.
+pub fn ecdsa_verify<E: EllipticCurve + WeierstrassParameters>(
+    r: &BigUint,
+    s: &BigUint,
+    z: &BigUint,
+    q_x: &BigUint,
+    q_y: &BigUint,
+) -> bool {
+    let modulus = E::BaseField::modulus();
+    let order = E::prime_group_order();
+    let rhs = (q_x * q_x * q_x + E::a_int() * q_x + E::b_int()) % &modulus;
+    if (q_y * q_y) % &modulus != rhs {
+        return false;
+    }
+
+    let w = s.modpow(&(&order - 2u32), &order);
+    let u1 = (z * &w) % &order;
+    let u2 = (r * &w) % &order;
+
+    let (g_x, g_y) = E::generator();
+    let generator = AffinePoint::<E>::new(g_x, g_y);
+    let pubkey = AffinePoint::<E>::new(q_x.clone(), q_y.clone());
+    let sum = ecdsa_accumulate(&Some(generator.clone()), &pubkey);
+
+    let mut result: Option<AffinePoint<E>> = None;
+    for i in (0..ECDSA_SCALAR_BITS as u64).rev() {
+        result = result.and_then(|result| ecdsa_accumulate(&Some(result.clone()), &result));
+        let q = match (u1.bit(i), u2.bit(i)) {
+            (true, false) => Some(generator.clone()),
+            (false, true) => Some(pubkey.clone()),
+            (true, true) => sum.clone(),
+            (false, false) => None,
+        };
+        if let Some(q) = q {
+            result = ecdsa_accumulate(&result, &q);
+        }
+    }
+
+    match result {
+        Some(result) => result.x % &order == *r,
+        None => false,
+    }
+}
@@ -0,0 +1,84 @@
+use num::BigUint;
+use serde::{Deserialize, Serialize};
+use sp1_curves::{
+    edwards::ed25519::{Ed25519, Ed25519BaseField},
+    params::FieldParameters,
+    AffinePoint, EllipticCurve,
+};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// The number of words of the input of an Ed25519 verification: `S`, the challenge `k`, and the
+/// two coordinates of the public key `A` and of the point `R`, each of them 8 little endian words.
+pub const ED25519_VERIFY_INPUT_WORDS: usize = 48;
+
+/// The number of bits of the scalars of an Ed25519 verification.
+pub const ED25519_SCALAR_BITS: usize = 256;
+
+/// Ed25519 Verify Event.
+///
+/// This event is emitted when an Ed25519 signature is verified against its challenge and a public
+/// key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ed25519VerifyEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the input, which is followed by the result.
+    pub ptr: u32,
+    /// The input as a list of words: `S`, `k`, the public key and `R`.
+    pub input: Vec<u32>,
+    /// Whether the signature is valid.
+    pub is_valid: bool,
+    /// The memory records for the input.
+    pub input_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result.
+    pub result_memory_record: MemoryWriteRecord,
+}
+
+/// Negates a point of Ed25519, reducing the x coordinate so that the negation of `(0, y)` is
+/// `(0, y)`.
+pub fn ed25519_neg(p: &AffinePoint<Ed25519>) -> AffinePoint<Ed25519> {
+    let modulus = Ed25519BaseField::modulus();
+    AffinePoint::new((&modulus - &p.x % &modulus) % &modulus, p.y.clone())
+}
+
+/// Verifies the Ed25519 signature `(R, S)` with the challenge `k` for the public key `A`.
+///
+/// The public key must be a point of the curve with reduced coordinates. The signature is valid if
+/// `S * B - k * A` is `R`, with the coordinates of `R` taken modulo the prime. The difference is
+/// computed with the trick of Shamir from the neutral element, adding `B`, `-A` or `B - A` after
+/// each doubling, which the complete addition law of the curve handles without special cases.
+pub fn ed25519_verify(
+    s: &BigUint,
+    k: &BigUint,
+    pubkey: &AffinePoint<Ed25519>,
+    r: &AffinePoint<Ed25519>,
+) -> bool {
+    let generator = Ed25519::ec_generator();
+    let neg_pubkey = ed25519_neg(pubkey);
+    let sum = &generator + &neg_pubkey;
+
+    let mut result = Ed25519::neutral();
+    for i in (0..ED25519_SCALAR_BITS as u64).rev() {
+        result = &result + &result;
+        let q = match (s.bit(i), k.bit(i)) {
+            (true, false) => &generator,
+            (false, true) => &neg_pubkey,
+            (true, true) => &sum,
+            (false, false) => continue,
+        };
+        result = &result + q;
+    }
+
+    let modulus = Ed25519BaseField::modulus();
+    result.x == &r.x % &modulus && result.y == &r.y % &modulus
+}
@@ -1,5 +1,10 @@
+use num::BigUint;
 use serde::{Deserialize, Serialize};
-use sp1_curves::{edwards::WORDS_FIELD_ELEMENT, COMPRESSED_POINT_BYTES, NUM_BYTES_FIELD_ELEMENT};
+use sp1_curves::{
+    edwards::{ed25519::Ed25519BaseField, WORDS_FIELD_ELEMENT},
+    params::FieldParameters,
+    COMPRESSED_POINT_BYTES, NUM_BYTES_FIELD_ELEMENT,
+};
 
 use crate::events::{
     memory::{MemoryReadRecord, MemoryWriteRecord},
@@ -25,10 +30,73 @@ pub struct EdDecompressEvent {
     pub sign: bool,
     /// The comprssed y coordinate as a list of bytes.
     pub y_bytes: [u8; COMPRESSED_POINT_BYTES],
-    /// The decompressed x coordinate as a list of bytes.
+    /// The decompressed x coordinate as a list of bytes, with every bit set if the encoding is not
+    /// a valid point.
     pub decompressed_x_bytes: [u8; NUM_BYTES_FIELD_ELEMENT],
     /// The memory records for the x coordinate.
     pub x_memory_records: [MemoryWriteRecord; WORDS_FIELD_ELEMENT],
     /// The memory records for the y coordinate.
     pub y_memory_records: [MemoryReadRecord; WORDS_FIELD_ELEMENT],
 }
+
+/// The number of words in the X25519 ladder state `[x2, z2, x3, z3]`.
+pub const X25519_LADDER_STATE_WORDS: usize = 4 * WORDS_FIELD_ELEMENT;
+
+/// X25519 Ladder Step Event.
+///
+/// This event is emitted when a single step of the X25519 Montgomery ladder is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct X25519LadderStepEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the ladder state, which the next state is written over.
+    pub state_ptr: u32,
+    /// The ladder state `[x2, z2, x3, z3]` as a list of little endian words.
+    pub state: Vec<u32>,
+    /// The pointer to the u-coordinate of the base point.
+    pub u_ptr: u32,
+    /// The u-coordinate of the base point as a list of little endian words.
+    pub u: Vec<u32>,
+    /// The memory records for the ladder state.
+    pub state_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the u-coordinate.
+    pub u_memory_records: Vec<MemoryReadRecord>,
+}
+
+/// The constant `(A - 2) / 4` of Curve25519, where `A = 486662`.
+pub const X25519_A24: u32 = 121665;
+
+/// Computes one step of the X25519 Montgomery ladder as specified in RFC 7748.
+///
+/// Given the projective u-coordinates `(x2 : z2)` and `(x3 : z3)` of two points whose difference
+/// has u-coordinate `x1`, returns the doubling of the first point and the sum of the two.
+#[must_use]
+pub fn x25519_ladder_step(x1: &BigUint, state: [BigUint; 4]) -> [BigUint; 4] {
+    let p = &Ed25519BaseField::modulus();
+    let [x2, z2, x3, z3] = state;
+
+    let a = (&x2 + &z2) % p;
+    let aa = (&a * &a) % p;
+    let b = (&x2 + p - &z2 % p) % p;
+    let bb = (&b * &b) % p;
+    let e = (&aa + p - &bb) % p;
+    let c = (&x3 + &z3) % p;
+    let d = (&x3 + p - &z3 % p) % p;
+    let da = (&d * &a) % p;
+    let cb = (&c * &b) % p;
+
+    let da_plus_cb = (&da + &cb) % p;
+    let da_minus_cb = (&da + p - &cb) % p;
+    let x3 = (&da_plus_cb * &da_plus_cb) % p;
+    let z3 = (x1 * &((&da_minus_cb * &da_minus_cb) % p)) % p;
+    let x2 = (&aa * &bb) % p;
+    let z2 = (&e * &((&aa + &e * X25519_A24) % p)) % p;
+
+    [x2, z2, x3, z3]
+}
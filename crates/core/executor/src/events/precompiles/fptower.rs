@@ -13,6 +13,10 @@ pub enum FieldOperation {
     Sub,
     /// Division.
     Div,
+    /// Squaring, which only reads the first operand.
+    Square,
+    /// Cubing, which only reads the first operand.
+    Cube,
 }
 
 /// Emulated Field Operation Events.
@@ -97,3 +101,27 @@ pub struct Fp2MulEvent {
     /// The memory records for the y operand.
     pub y_memory_records: Vec<MemoryReadRecord>,
 }
+
+/// Emulated Batch Field Inversion Events.
+///
+/// This event is emitted when the elements of an array of emulated field elements are inverted in
+/// place, with the zero elements left as zero.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchFieldInverseEvent {
+    /// The lookup id.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the elements.
+    pub ptr: u32,
+    /// The number of elements.
+    pub len: u32,
+    /// The elements, one after the other.
+    pub values: Vec<u32>,
+    /// The memory records for the elements.
+    pub memory_records: Vec<MemoryWriteRecord>,
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// FRI Decommit Event.
+///
+/// This event is emitted when the leaf values of a FRI query are hashed and authenticated against
+/// a Poseidon2 Merkle commitment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FriDecommitEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the query index, which is followed by the number of values, the result, the
+    /// root, the values and the siblings.
+    pub ptr: u32,
+    /// The number of levels of the path.
+    pub depth: u32,
+    /// The query index. Bit `i` is set if the node at level `i` is the right child.
+    pub index: u32,
+    /// The number of leaf values.
+    pub num_values: u32,
+    /// The expected root as a list of words.
+    pub root: [u32; 8],
+    /// The leaf values as a list of words.
+    pub values: Vec<u32>,
+    /// The sibling digests of the path, the leaf level first.
+    pub siblings: Vec<[u32; 8]>,
+    /// Whether the computed root is equal to the expected root.
+    pub root_matches: bool,
+    /// The memory record for the query index.
+    pub index_memory_record: MemoryReadRecord,
+    /// The memory record for the number of values.
+    pub num_values_memory_record: MemoryReadRecord,
+    /// The memory records for the root.
+    pub root_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the values.
+    pub value_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the siblings.
+    pub sibling_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result.
+    pub result_memory_record: MemoryWriteRecord,
+}
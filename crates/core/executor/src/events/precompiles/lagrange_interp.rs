@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// Lagrange Interpolation Event.
+///
+/// This event is emitted when the polynomial through a set of points is evaluated at a target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LagrangeInterpEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the points, as `x_i, y_i` pairs.
+    pub points_ptr: u32,
+    /// The pointer to the number of points, the target and the modulus.
+    pub params_ptr: u32,
+    /// The number of points.
+    pub len: u32,
+    /// The target the polynomial is evaluated at.
+    pub target: u32,
+    /// The modulus.
+    pub modulus: u32,
+    /// The memory records for reading the parameters.
+    pub params_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for reading the points, once for each of the points in turn.
+    pub points_read_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory record for writing the result over the target.
+    pub result_write_record: MemoryWriteRecord,
+}
+
+/// Computes the inverse of `a` modulo `modulus` with the extended Euclidean algorithm, or `None`
+/// if they are not coprime.
+pub fn inverse_mod(a: u32, modulus: u32) -> Option<u32> {
+    let (mut r0, mut r1) = (i64::from(modulus), i64::from(a % modulus));
+    let (mut t0, mut t1) = (0i64, 1i64);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    (r0 == 1).then(|| t0.rem_euclid(i64::from(modulus)) as u32)
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// Merkle Verify Path Event.
+///
+/// This event is emitted when a Merkle path is hashed up to the root and compared with the
+/// expected root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MerkleVerifyEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the leaf, which is followed by the root, the directions, the result and the
+    /// siblings.
+    pub ptr: u32,
+    /// The number of levels of the path.
+    pub depth: u32,
+    /// The leaf as a list of words.
+    pub leaf: [u32; 8],
+    /// The expected root as a list of words.
+    pub root: [u32; 8],
+    /// The direction bits of the path, the leaf level first. A set bit means that the node is the
+    /// right child.
+    pub directions: u32,
+    /// The sibling hashes of the path, the leaf level first.
+    pub siblings: Vec<[u32; 8]>,
+    /// Whether the computed root is equal to the expected root.
+    pub root_matches: bool,
+    /// The memory records for the leaf.
+    pub leaf_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the root.
+    pub root_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the directions.
+    pub directions_memory_record: MemoryReadRecord,
+    /// The memory records for the siblings.
+    pub sibling_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result.
+    pub result_memory_record: MemoryWriteRecord,
+}
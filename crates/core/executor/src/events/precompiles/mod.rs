@@ -1,15 +1,53 @@
+mod aes128_encrypt;
+mod blake2b_compress;
+mod blake3_compress;
+mod chacha20_block;
+mod clz32;
 mod ec;
+mod ecdsa_verify;
+mod ed25519_verify;
 mod edwards;
 mod fptower;
+mod fri_decommit;
 mod keccak256_permute;
+mod lagrange_interp;
+mod merkle_verify;
+mod ntt;
+mod pedersen_hash;
+mod poly_eval;
+mod poly_multi_eval;
+mod popcount;
+mod poseidon2_permute;
+mod rescue_permute;
+mod ripemd160_compress;
 mod sha256_compress;
 mod sha256_extend;
+mod sha256_hash;
 mod uint256;
 
+pub use aes128_encrypt::*;
+pub use blake2b_compress::*;
+pub use blake3_compress::*;
+pub use chacha20_block::*;
+pub use clz32::*;
 pub use ec::*;
+pub use ecdsa_verify::*;
+pub use ed25519_verify::*;
 pub use edwards::*;
 pub use fptower::*;
+pub use fri_decommit::*;
 pub use keccak256_permute::*;
+pub use lagrange_interp::*;
+pub use merkle_verify::*;
+pub use ntt::*;
+pub use pedersen_hash::*;
+pub use poly_eval::*;
+pub use poly_multi_eval::*;
+pub use popcount::*;
+pub use poseidon2_permute::*;
+pub use rescue_permute::*;
+pub use ripemd160_compress::*;
 pub use sha256_compress::*;
 pub use sha256_extend::*;
+pub use sha256_hash::*;
 pub use uint256::*;
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// NTT Event.
+///
+/// This event is emitted when a number theoretic transform, or its inverse, is applied in place to
+/// an array of coefficients.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NttEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the coefficients.
+    pub coeffs_ptr: u32,
+    /// The pointer to the length, the root of unity and the modulus.
+    pub params_ptr: u32,
+    /// The number of coefficients, which is a power of two.
+    pub len: u32,
+    /// The root of unity of order `len`.
+    pub root: u32,
+    /// The modulus.
+    pub modulus: u32,
+    /// The memory records for reading the parameters.
+    pub params_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for writing the coefficients, with one record per coefficient for each
+    /// stage of the transform.
+    pub stage_write_records: Vec<Vec<MemoryWriteRecord>>,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryWriteRecord, LookupId};
+
+/// The number of words in a Jubjub point, which holds two field elements.
+pub const PEDERSEN_POINT_NUM_WORDS: usize = 16;
+
+/// Pedersen Hash Event.
+///
+/// This event is emitted when a window of a Pedersen hash is added to the accumulator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PedersenHashEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the accumulator point.
+    pub acc_ptr: u32,
+    /// The index of the window.
+    pub window: u32,
+    /// The three input bits of the window.
+    pub chunk: u32,
+    /// The accumulator point as a list of words.
+    pub acc: [u32; PEDERSEN_POINT_NUM_WORDS],
+    /// The memory records for the new accumulator written over the old one.
+    pub acc_memory_records: Vec<MemoryWriteRecord>,
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// Polynomial Evaluation Event.
+///
+/// This event is emitted when a polynomial is evaluated at a point with Horner's method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolyEvalEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the coefficients, from the constant one up.
+    pub coeffs_ptr: u32,
+    /// The pointer to the degree, the point and the modulus.
+    pub params_ptr: u32,
+    /// The degree of the polynomial, which has one more coefficient.
+    pub degree: u32,
+    /// The point the polynomial is evaluated at.
+    pub point: u32,
+    /// The modulus.
+    pub modulus: u32,
+    /// The memory records for reading the parameters.
+    pub params_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for reading the coefficients, from the constant one up.
+    pub coeff_read_records: Vec<MemoryReadRecord>,
+    /// The memory record for writing the result over the point.
+    pub result_write_record: MemoryWriteRecord,
+}
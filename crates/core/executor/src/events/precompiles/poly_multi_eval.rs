@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// Polynomial Multi-Evaluation Event.
+///
+/// This event is emitted when a polynomial is evaluated at several points at once with Horner's
+/// method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolyMultiEvalEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the parameters.
+    pub params_ptr: u32,
+    /// The number of points.
+    pub count: u32,
+    /// The pointer to the coefficients, from the constant one up.
+    pub coeffs_ptr: u32,
+    /// The pointer to the points, which the results are written over.
+    pub points_ptr: u32,
+    /// The degree of the polynomial, which has one more coefficient.
+    pub degree: u32,
+    /// The modulus.
+    pub modulus: u32,
+    /// The points the polynomial is evaluated at.
+    pub points: Vec<u32>,
+    /// The memory records for reading the parameters.
+    pub params_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for reading the coefficients, from the constant one up.
+    pub coeff_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for writing the results over the points.
+    pub result_write_records: Vec<MemoryWriteRecord>,
+}
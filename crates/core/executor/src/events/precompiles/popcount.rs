@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryWriteRecord, LookupId};
+
+/// The number of words of the value whose bits are counted.
+pub const POPCOUNT_NUM_WORDS: usize = 2;
+
+/// Popcount Event.
+///
+/// This event is emitted when the set bits of a 64-bit value are counted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PopcountEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the value, which the count is written over.
+    pub ptr: u32,
+    /// The value as a list of little endian words.
+    pub value: [u32; POPCOUNT_NUM_WORDS],
+    /// The memory records for the count written over the value.
+    pub memory_records: Vec<MemoryWriteRecord>,
+}
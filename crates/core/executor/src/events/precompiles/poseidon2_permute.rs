@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryWriteRecord, LookupId};
+
+/// The number of BabyBear elements in the Poseidon2 state.
+pub const POSEIDON2_WIDTH: usize = 16;
+
+/// Poseidon2 Permutation Event.
+///
+/// This event is emitted when a Poseidon2 permutation operation is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Poseidon2PermuteEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The input state as a list of words.
+    pub input: [u32; POSEIDON2_WIDTH],
+    /// The output state as a list of canonical BabyBear elements.
+    pub output: [u32; POSEIDON2_WIDTH],
+    /// The memory records for the output written over the input.
+    pub state_memory_records: Vec<MemoryWriteRecord>,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryWriteRecord, LookupId};
+
+/// The number of words in the Rescue-Prime state, which holds three BN254 scalar field elements.
+pub const RESCUE_STATE_NUM_WORDS: usize = 24;
+
+/// Rescue-Prime Permute Event.
+///
+/// This event is emitted when a round of the Rescue-Prime permutation is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RescuePermuteEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The index of the round.
+    pub round: u32,
+    /// The input state as a list of words.
+    pub input: [u32; RESCUE_STATE_NUM_WORDS],
+    /// The output state as a list of words, with each element reduced.
+    pub output: [u32; RESCUE_STATE_NUM_WORDS],
+    /// The memory records for the output written over the input.
+    pub state_memory_records: Vec<MemoryWriteRecord>,
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// The number of words in the RIPEMD-160 state.
+pub(crate) const RIPEMD160_STATE_NUM_WORDS: usize = 5;
+
+/// The number of words in a RIPEMD-160 message block.
+pub(crate) const RIPEMD160_BLOCK_NUM_WORDS: usize = 16;
+
+/// RIPEMD-160 Compress Event.
+///
+/// This event is emitted when a RIPEMD-160 compression function call is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ripemd160CompressEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The pointer to the message block.
+    pub block_ptr: u32,
+    /// The state before the compression.
+    pub state: [u32; RIPEMD160_STATE_NUM_WORDS],
+    /// The message block.
+    pub block: [u32; RIPEMD160_BLOCK_NUM_WORDS],
+    /// The memory records for reading the state.
+    pub state_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for writing the state.
+    pub state_write_records: Vec<MemoryWriteRecord>,
+    /// The memory records for reading the message block.
+    pub block_read_records: Vec<MemoryReadRecord>,
+}
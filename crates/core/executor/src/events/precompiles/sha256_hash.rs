@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// SHA-256 Hash Event.
+///
+/// This event is emitted for each block of the padded message when the SHA-256 digest of a region
+/// of memory is computed. The events of the blocks of a message are emitted in order, one after the
+/// other.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sha256HashEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the digest.
+    pub result_ptr: u32,
+    /// The pointer to the pointer to the message and its length.
+    pub params_ptr: u32,
+    /// The pointer to the message.
+    pub input_ptr: u32,
+    /// The length of the message in bytes.
+    pub len: u32,
+    /// The index of the block within the padded message.
+    pub block_index: u32,
+    /// The number of blocks of the padded message.
+    pub num_blocks: u32,
+    /// The state before the compression of the block.
+    pub h_in: [u32; 8],
+    /// The block as big endian words, including the padding.
+    pub block: [u32; 16],
+    /// The memory records for reading the parameters, in the first block.
+    pub params_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for reading the words of the block which hold bytes of the message.
+    pub input_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for writing the digest, in the last block.
+    pub digest_write_records: Vec<MemoryWriteRecord>,
+}
+
+impl Sha256HashEvent {
+    /// Whether this is the first block of the message.
+    pub const fn is_first_block(&self) -> bool {
+        self.block_index == 0
+    }
+
+    /// Whether this is the last block of the message.
+    pub const fn is_last_block(&self) -> bool {
+        self.block_index + 1 == self.num_blocks
+    }
+}
@@ -1,15 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-use crate::events::{
-    memory::{MemoryReadRecord, MemoryWriteRecord},
-    LookupId,
+use crate::{
+    events::{
+        memory::{MemoryReadRecord, MemoryWriteRecord},
+        LookupId,
+    },
+    ByteOpcode,
 };
 
-/// Uint256 Mul Event.
+/// Uint Mul Event.
 ///
-/// This event is emitted when a uint256 mul operation is performed.
+/// This event is emitted when a uint256 or uint512 mul operation is performed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Uint256MulEvent {
+pub struct UintMulEvent {
     /// The lookup identifer.
     pub lookup_id: LookupId,
     /// The shard number.
@@ -35,3 +38,262 @@ pub struct Uint256MulEvent {
     /// The memory records for the modulus.
     pub modulus_memory_records: Vec<MemoryReadRecord>,
 }
+
+/// Uint256 AddMod Event.
+///
+/// This event is emitted when a uint256 addmod operation is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256AddModEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the y value.
+    pub y_ptr: u32,
+    /// The y value as a list of words.
+    pub y: Vec<u32>,
+    /// The modulus as a list of words.
+    pub modulus: Vec<u32>,
+    /// The memory records for the x value.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the y value.
+    pub y_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the modulus.
+    pub modulus_memory_records: Vec<MemoryReadRecord>,
+}
+
+/// Uint256 SubMod Event.
+///
+/// This event is emitted when a uint256 submod operation is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256SubModEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the y value.
+    pub y_ptr: u32,
+    /// The y value as a list of words.
+    pub y: Vec<u32>,
+    /// The modulus as a list of words.
+    pub modulus: Vec<u32>,
+    /// The memory records for the x value.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the y value.
+    pub y_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the modulus.
+    pub modulus_memory_records: Vec<MemoryReadRecord>,
+}
+
+/// Uint256 InvMod Event.
+///
+/// This event is emitted when a uint256 invmod operation is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256InvModEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the modulus.
+    pub modulus_ptr: u32,
+    /// The modulus as a list of words.
+    pub modulus: Vec<u32>,
+    /// The memory records for the x value.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the modulus.
+    pub modulus_memory_records: Vec<MemoryReadRecord>,
+}
+
+/// Uint256 SquareMod Event.
+///
+/// This event is emitted when a uint256 squaremod operation is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256SquareModEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the modulus.
+    pub modulus_ptr: u32,
+    /// The modulus as a list of words.
+    pub modulus: Vec<u32>,
+    /// The memory records for the x value.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the modulus.
+    pub modulus_memory_records: Vec<MemoryReadRecord>,
+}
+
+/// Uint256 MulWide Event.
+///
+/// This event is emitted when a uint256 widening mul operation is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256MulWideEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the y value.
+    pub y_ptr: u32,
+    /// The y value as a list of words.
+    pub y: Vec<u32>,
+    /// The memory records for the 512-bit product written over x.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the y value.
+    pub y_memory_records: Vec<MemoryReadRecord>,
+}
+
+/// Uint256 RangeCheck Event.
+///
+/// This event is emitted when a uint256 is compared against a modulus.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256RangeCheckEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the value, which is followed by the result.
+    pub value_ptr: u32,
+    /// The value as a list of words.
+    pub value: Vec<u32>,
+    /// The pointer to the prime.
+    pub prime_ptr: u32,
+    /// The prime as a list of words.
+    pub prime: Vec<u32>,
+    /// The memory records for the value.
+    pub value_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the prime.
+    pub prime_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result.
+    pub result_memory_record: MemoryWriteRecord,
+}
+
+/// Uint256 Cmp Event.
+///
+/// This event is emitted when two uint256 are compared.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256CmpEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value, which is followed by the result.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the y value.
+    pub y_ptr: u32,
+    /// The y value as a list of words.
+    pub y: Vec<u32>,
+    /// The memory records for the x value.
+    pub x_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the y value.
+    pub y_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result.
+    pub result_memory_record: MemoryWriteRecord,
+}
+
+/// Uint256 Bitwise Event.
+///
+/// This event is emitted when a uint256 and, or or xor operation is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256BitwiseEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The bitwise operation, one of `AND`, `OR` or `XOR`.
+    pub op: ByteOpcode,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the y value.
+    pub y_ptr: u32,
+    /// The y value as a list of words.
+    pub y: Vec<u32>,
+    /// The memory records for the x value.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the y value.
+    pub y_memory_records: Vec<MemoryReadRecord>,
+}
+
+/// Uint256 DivRem Event.
+///
+/// This event is emitted when a uint256 division with remainder is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256DivRemEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the divisor, which is followed by the remainder.
+    pub divisor_ptr: u32,
+    /// The divisor as a list of words.
+    pub divisor: Vec<u32>,
+    /// The memory records for the quotient written over x.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the divisor.
+    pub divisor_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the remainder.
+    pub remainder_memory_records: Vec<MemoryWriteRecord>,
+}
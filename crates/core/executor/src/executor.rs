@@ -16,6 +16,7 @@ use crate::{
         MemoryAccessPosition, MemoryInitializeFinalizeEvent, MemoryReadRecord, MemoryRecord,
         MemoryWriteRecord,
     },
+    gas::GasMeter,
     hook::{HookEnv, HookRegistry},
     memory::{Entry, PagedMemory},
     record::{ExecutionRecord, MemoryAccessRecord},
@@ -26,6 +27,12 @@ use crate::{
     Instruction, Opcode, Program, Register,
 };
 
+#[cfg(feature = "memory-report")]
+use {crate::report::MemoryReport, hashbrown::HashSet};
+
+#[cfg(feature = "profiling")]
+use crate::report::{CycleBreakdown, FunctionCounts};
+
 /// An executor for the SP1 RISC-V zkVM.
 ///
 /// The exeuctor is responsible for executing a user program and tracing important events which
@@ -98,9 +105,33 @@ pub struct Executor<'a> {
     /// The maximum number of cpu cycles to use for execution.
     pub max_cycles: Option<u64>,
 
+    /// The meter of the gas of the execution, if it is bounded by a gas limit.
+    pub gas_meter: Option<GasMeter>,
+
     /// Memory addresses that were touched in this batch of shards. Used to minimize the size of
     /// checkpoints.
     pub memory_checkpoint: PagedMemory<Option<MemoryRecord>>,
+
+    /// Report of the memory usage of the execution, filled in as shards are completed.
+    #[cfg(feature = "memory-report")]
+    pub memory_report: MemoryReport,
+
+    /// The pages touched by the whole execution.
+    #[cfg(feature = "memory-report")]
+    memory_pages: HashSet<u32>,
+
+    /// The pages touched by the current shard.
+    #[cfg(feature = "memory-report")]
+    shard_memory_pages: HashSet<u32>,
+
+    /// The counts of each function of the program, followed by the counts of the instructions
+    /// outside of them. They are moved into the report when the program ends.
+    #[cfg(feature = "profiling")]
+    function_counts: Vec<FunctionCounts>,
+
+    /// The index in `function_counts` of the function of the last instruction.
+    #[cfg(feature = "profiling")]
+    current_function: usize,
 }
 
 /// The different modes the executor can run in.
@@ -133,9 +164,19 @@ pub enum ExecutionError {
     #[error("breakpoint encountered")]
     Breakpoint(),
 
-    /// The execution failed with an exceeded cycle limit.
-    #[error("exceeded cycle limit of {0}")]
-    ExceededCycleLimit(u64),
+    /// The execution failed with an exceeded cycle limit, after the given number of shards and
+    /// with the given pc as the next one to run.
+    #[error("exceeded cycle limit of {0} after {1} shards at pc {2:#x}")]
+    ExceededCycleLimit(u64, u32, u32),
+
+    /// The execution failed because it consumed more gas than the limit of its gas meter.
+    #[error("out of gas: consumed {consumed} gas with a limit of {limit}")]
+    OutOfGas {
+        /// The gas consumed up to and including the instruction that exceeded the limit.
+        consumed: u64,
+        /// The gas limit.
+        limit: u64,
+    },
 
     /// The execution failed because the syscall was called in unconstrained mode.
     #[error("syscall called in unconstrained mode")]
@@ -148,6 +189,20 @@ pub enum ExecutionError {
     /// The program ended in unconstrained mode.
     #[error("program ended in unconstrained mode")]
     EndInUnconstrained(),
+
+    /// The execution failed because a syscall rejected one of its pointers, for example because it
+    /// was not word aligned.
+    #[error("syscall {0} at pc {1:#x} got an invalid pointer {2:#x}")]
+    InvalidSyscallPointer(SyscallCode, u32, u32),
+
+    /// The execution failed because a syscall was asked to divide by zero.
+    #[error("syscall {0} at pc {1:#x} divided by zero")]
+    SyscallDivisionByZero(SyscallCode, u32),
+
+    /// The execution failed because a syscall rejected the value of one of its arguments, for
+    /// example an operand that is not reduced modulo the modulus.
+    #[error("syscall {0} at pc {1:#x} got an invalid argument")]
+    InvalidSyscallArgument(SyscallCode, u32),
 }
 
 macro_rules! assert_valid_memory_access {
@@ -194,6 +249,9 @@ impl<'a> Executor<'a> {
             context.subproof_verifier.unwrap_or_else(|| Arc::new(DefaultSubproofVerifier::new()));
         let hook_registry = context.hook_registry.unwrap_or_default();
 
+        #[cfg(feature = "profiling")]
+        let function_counts = vec![FunctionCounts::default(); program.function_symbols.len() + 1];
+
         Self {
             record,
             records: vec![],
@@ -216,7 +274,18 @@ impl<'a> Executor<'a> {
             hook_registry,
             opts,
             max_cycles: context.max_cycles,
+            gas_meter: context.gas_meter,
             memory_checkpoint: PagedMemory::new_preallocated(),
+            #[cfg(feature = "memory-report")]
+            memory_report: MemoryReport::default(),
+            #[cfg(feature = "memory-report")]
+            memory_pages: HashSet::new(),
+            #[cfg(feature = "memory-report")]
+            shard_memory_pages: HashSet::new(),
+            #[cfg(feature = "profiling")]
+            function_counts,
+            #[cfg(feature = "profiling")]
+            current_function: 0,
         }
     }
 
@@ -354,6 +423,10 @@ impl<'a> Executor<'a> {
 
     /// Read a word from memory and create an access record.
     pub fn mr(&mut self, addr: u32, shard: u32, timestamp: u32) -> MemoryReadRecord {
+        #[cfg(feature = "memory-report")]
+        self.track_memory_access(addr, false);
+        self.charge_memory_access(addr);
+
         // Get the memory record entry.
         let entry = self.state.memory.entry(addr);
         if self.executor_mode == ExecutorMode::Checkpoint || self.unconstrained {
@@ -399,6 +472,10 @@ impl<'a> Executor<'a> {
 
     /// Write a word to memory and create an access record.
     pub fn mw(&mut self, addr: u32, value: u32, shard: u32, timestamp: u32) -> MemoryWriteRecord {
+        #[cfg(feature = "memory-report")]
+        self.track_memory_access(addr, true);
+        self.charge_memory_access(addr);
+
         // Get the memory record entry.
         let entry = self.state.memory.entry(addr);
         if self.executor_mode == ExecutorMode::Checkpoint || self.unconstrained {
@@ -709,6 +786,11 @@ impl<'a> Executor<'a> {
 
         if self.print_report && !self.unconstrained {
             self.report.opcode_counts[instruction.opcode] += 1;
+            #[cfg(feature = "profiling")]
+            self.count_function_cycle(pc);
+        }
+        if let Some(gas_meter) = self.gas_meter.as_mut().filter(|_| !self.unconstrained) {
+            gas_meter.charge_instruction();
         }
 
         match instruction.opcode {
@@ -941,6 +1023,10 @@ impl<'a> Executor<'a> {
                     return Err(ExecutionError::InvalidSyscallUsage(syscall_id as u64));
                 }
 
+                if let Some(gas_meter) = self.gas_meter.as_mut().filter(|_| !self.unconstrained) {
+                    gas_meter.charge_syscall(syscall);
+                }
+
                 let syscall_impl = self.get_syscall(syscall).cloned();
                 let mut precompile_rt = SyscallContext::new(self);
                 precompile_rt.syscall_lookup_id = syscall_lookup_id;
@@ -950,12 +1036,31 @@ impl<'a> Executor<'a> {
                         // register. If it returns None, we just keep the
                         // syscall_id in t0.
                         let res = syscall_impl.execute(&mut precompile_rt, b, c);
+                        #[cfg(feature = "profiling")]
+                        {
+                            *precompile_rt
+                                .record_mut()
+                                .syscall_histogram
+                                .entry(syscall)
+                                .or_default() += 1;
+                        }
                         if let Some(val) = res {
                             a = val;
                         } else {
                             a = syscall_id;
                         }
 
+                        // If the syscall rejected one of its pointers, return an error.
+                        if let Some(ptr) = precompile_rt.invalid_pointer {
+                            return Err(ExecutionError::InvalidSyscallPointer(syscall, pc, ptr));
+                        }
+                        if precompile_rt.division_by_zero {
+                            return Err(ExecutionError::SyscallDivisionByZero(syscall, pc));
+                        }
+                        if precompile_rt.invalid_argument {
+                            return Err(ExecutionError::InvalidSyscallArgument(syscall, pc));
+                        }
+
                         // If the syscall is `HALT` and the exit code is non-zero, return an error.
                         if syscall == SyscallCode::HALT && precompile_rt.exit_code != 0 {
                             return Err(ExecutionError::HaltWithNonZeroExitCode(
@@ -988,6 +1093,30 @@ impl<'a> Executor<'a> {
                     SyscallCode::KECCAK_PERMUTE => (self.opts.split_opts.keccak, 24),
                     SyscallCode::SHA_EXTEND => (self.opts.split_opts.sha_extend, 48),
                     SyscallCode::SHA_COMPRESS => (self.opts.split_opts.sha_compress, 80),
+                    SyscallCode::UINT256_MOD_EXP => (self.opts.split_opts.mod_exp, 256),
+                    SyscallCode::BLAKE3_COMPRESS => (self.opts.split_opts.blake3, 7),
+                    SyscallCode::BLAKE2B_COMPRESS => (self.opts.split_opts.blake2b, 12),
+                    SyscallCode::RIPEMD160_COMPRESS => (self.opts.split_opts.ripemd160, 80),
+                    SyscallCode::SECP256K1_MUL => (self.opts.split_opts.ec_mul, 256),
+                    SyscallCode::P256_MUL => (self.opts.split_opts.ec_mul, 256),
+                    SyscallCode::CHACHA20_BLOCK => (self.opts.split_opts.chacha20, 10),
+                    SyscallCode::MERKLE_VERIFY_PATH => (self.opts.split_opts.merkle, 1),
+                    SyscallCode::SECP256K1_MSM => (self.opts.split_opts.ec_msm, 1),
+                    SyscallCode::FRI_DECOMMIT => (self.opts.split_opts.fri, 1),
+                    SyscallCode::SECP256K1_ECDSA_VERIFY => (self.opts.split_opts.ecdsa, 1),
+                    SyscallCode::ED25519_VERIFY => (self.opts.split_opts.eddsa, 1),
+                    SyscallCode::BLS12381_FP_BATCH_INV
+                    | SyscallCode::BN254_FP_BATCH_INV
+                    | SyscallCode::BN254_FR_BATCH_INV => (self.opts.split_opts.batch_inverse, 1),
+                    SyscallCode::NTT | SyscallCode::INTT => (self.opts.split_opts.ntt, 1),
+                    SyscallCode::SHA256_HASH => (self.opts.split_opts.sha256_hash, 1),
+                    SyscallCode::POLY_EVAL | SyscallCode::POLY_MULTI_EVAL => {
+                        (self.opts.split_opts.poly_eval, 1)
+                    }
+                    SyscallCode::LAGRANGE_INTERP => (self.opts.split_opts.lagrange_interp, 1),
+                    SyscallCode::EC_LINEAR_COMBINATION => {
+                        (self.opts.split_opts.ec_linear_combination, 1)
+                    }
                     _ => (self.opts.split_opts.deferred, 1),
                 };
                 let nonce = (((*syscall_count as usize) % threshold) * multiplier) as u32;
@@ -1122,10 +1251,14 @@ impl<'a> Executor<'a> {
             self.bump_record();
         }
 
-        // If the cycle limit is exceeded, return an error.
-        if let Some(max_cycles) = self.max_cycles {
-            if self.state.global_clk >= max_cycles {
-                return Err(ExecutionError::ExceededCycleLimit(max_cycles));
+        // If the gas limit is exceeded, return an error.
+        if let Some(gas_meter) = &self.gas_meter {
+            self.report.gas = Some(gas_meter.consumed());
+            if gas_meter.is_exhausted() {
+                return Err(ExecutionError::OutOfGas {
+                    consumed: gas_meter.consumed(),
+                    limit: gas_meter.limit,
+                });
             }
         }
 
@@ -1136,11 +1269,106 @@ impl<'a> Executor<'a> {
             log::error!("program ended in unconstrained mode at clk {}", self.state.global_clk);
             return Err(ExecutionError::EndInUnconstrained());
         }
+
+        // If the program needs more cycles than the limit, return an error before running them.
+        if let Some(max_cycles) = self.max_cycles {
+            if !done && self.state.global_clk >= max_cycles {
+                // A shard that was just started has no instructions yet.
+                let shards = self.state.current_shard - u32::from(self.state.clk == 0);
+                return Err(ExecutionError::ExceededCycleLimit(max_cycles, shards, self.state.pc));
+            }
+        }
         Ok(done)
     }
 
+    /// Charge a memory access to the gas meter, if there is one. Accesses made in unconstrained
+    /// mode are not charged.
+    #[inline]
+    fn charge_memory_access(&mut self, addr: u32) {
+        if let Some(gas_meter) = self.gas_meter.as_mut().filter(|_| !self.unconstrained) {
+            gas_meter.charge_memory_access(addr);
+        }
+    }
+
+    /// Count a memory access towards the [`MemoryReport`]. Registers and accesses made in
+    /// unconstrained mode are not counted.
+    #[cfg(feature = "memory-report")]
+    fn track_memory_access(&mut self, addr: u32, is_write: bool) {
+        if addr < 32 || self.unconstrained {
+            return;
+        }
+        let page = addr / MemoryReport::PAGE_SIZE;
+        self.memory_pages.insert(page);
+        self.shard_memory_pages.insert(page);
+        if is_write {
+            let stats = &mut self.record.memory_stats;
+            stats.peak_address = stats.peak_address.max(addr);
+        }
+    }
+
+    /// Count the instruction at `pc` towards the function that contains it. The function of the
+    /// previous instruction is checked first, so the symbols are only searched on a change of
+    /// function.
+    #[cfg(feature = "profiling")]
+    fn count_function_cycle(&mut self, pc: u32) {
+        let functions = &self.program.function_symbols;
+        let contains = |index: usize| {
+            functions.get(index).is_some_and(|function| function.start <= pc && pc < function.end)
+        };
+        if !contains(self.current_function) {
+            // The only function that may contain `pc` is the last one that starts at or before it.
+            let index = functions.partition_point(|function| function.start <= pc);
+            self.current_function = match index.checked_sub(1) {
+                Some(index) if contains(index) => index,
+                _ => functions.len(),
+            };
+        }
+
+        let counts = &mut self.function_counts[self.current_function];
+        counts.cycles += 1;
+        if functions.get(self.current_function).is_some_and(|function| function.start == pc) {
+            counts.calls += 1;
+        }
+    }
+
+    /// Move the counts of the functions into the [`ExecutionReport`].
+    #[cfg(feature = "profiling")]
+    fn finish_function_counts(&mut self) {
+        let names = self
+            .program
+            .function_symbols
+            .iter()
+            .map(|function| function.name.as_str())
+            .chain([CycleBreakdown::UNKNOWN_FUNCTION]);
+        for (name, counts) in names.zip(&mut self.function_counts) {
+            let counts = std::mem::take(counts);
+            if counts.cycles > 0 {
+                *self.report.function_counts.entry(name.to_string()).or_default() += counts;
+            }
+        }
+    }
+
+    /// Close the memory stats of the current shard and add them to the [`MemoryReport`].
+    #[cfg(feature = "memory-report")]
+    fn finish_shard_memory_stats(&mut self) {
+        if self.shard_memory_pages.is_empty() {
+            return;
+        }
+        let stats = &mut self.record.memory_stats;
+        stats.pages_touched = self.shard_memory_pages.len() as u64;
+        self.shard_memory_pages.clear();
+
+        let report = &mut self.memory_report;
+        report.peak_address = report.peak_address.max(stats.peak_address);
+        report.pages_touched = self.memory_pages.len() as u64;
+        report.shards.push(*stats);
+    }
+
     /// Bump the record.
     pub fn bump_record(&mut self) {
+        #[cfg(feature = "memory-report")]
+        self.finish_shard_memory_stats();
+
         let removed_record =
             std::mem::replace(&mut self.record, ExecutionRecord::new(self.program.clone()));
         let public_values = removed_record.public_values;
@@ -1311,6 +1539,9 @@ impl<'a> Executor<'a> {
     }
 
     fn postprocess(&mut self) {
+        #[cfg(feature = "profiling")]
+        self.finish_function_counts();
+
         // Flush remaining stdout/stderr
         for (fd, buf) in &self.io_buf {
             if !buf.is_empty() {
@@ -1435,7 +1666,10 @@ mod tests {
 
     use crate::Register;
 
-    use super::{Executor, Instruction, Opcode, Program};
+    use crate::syscalls::SyscallCode;
+
+    use super::{ExecutionError, Executor, Instruction, Opcode, Program};
+    use crate::{CostTable, CycleReport, GasMeter, SP1Context};
 
     fn _assert_send<T: Send>() {}
 
@@ -2036,4 +2270,311 @@ mod tests {
         assert_eq!(runtime.register(Register::X12), 0x12346525);
         assert_eq!(runtime.register(Register::X11), 0x65256525);
     }
+
+    #[test]
+    fn test_misaligned_syscall_pointer() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::UINT256_MUL as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 102, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 300, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::InvalidSyscallPointer(SyscallCode::UINT256_MUL, 12, 102)
+        ));
+    }
+
+    #[test]
+    fn test_aliased_sha_compress_pointers() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::SHA_COMPRESS as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 400, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 400, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::InvalidSyscallPointer(SyscallCode::SHA_COMPRESS, 12, 400)
+        ));
+    }
+
+    #[test]
+    fn test_cycle_limit() {
+        // The program ends after exactly three cycles.
+        let context = SP1Context::builder().max_cycles(3).build();
+        let mut runtime = Executor::with_context(simple_program(), SP1CoreOpts::default(), context);
+        runtime.run().unwrap();
+
+        let context = SP1Context::builder().max_cycles(2).build();
+        let mut runtime = Executor::with_context(simple_program(), SP1CoreOpts::default(), context);
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err, ExecutionError::ExceededCycleLimit(2, 1, 8)));
+    }
+
+    #[test]
+    fn test_cycle_limit_loop() {
+        // A guest that spins forever on a jump to itself.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::JAL, 0, 0, 0, true, true),
+        ];
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1 << 10;
+        let context = SP1Context::builder().max_cycles(10_000).build();
+        let mut runtime = Executor::with_context(Program::new(instructions, 0, 0), opts, context);
+        let err = runtime.run().unwrap_err();
+        assert_eq!(runtime.state.global_clk, 10_000);
+
+        // Each shard runs the instructions that fit before the space reserved for a syscall.
+        let shard_cycles = u64::from(runtime.shard_size - runtime.max_syscall_cycles).div_ceil(4);
+        let shards = 10_000u64.div_ceil(shard_cycles) as u32;
+        assert!(shards > 1);
+        assert!(matches!(err, ExecutionError::ExceededCycleLimit(10_000, s, 4) if s == shards));
+    }
+
+    #[test]
+    fn test_gas_meter() {
+        // One page of memory is touched, at 100 and 300, and the registers are free.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::UINT256_CMP as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 100, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 300, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let table = CostTable::default();
+        let gas = 4 * table.base_instruction
+            + table.syscall(SyscallCode::UINT256_CMP)
+            + table.per_memory_page;
+        let run = |limit| {
+            let program = Program::new(instructions.clone(), 0, 0);
+            let context =
+                SP1Context::builder().gas_meter(GasMeter::new(table.clone(), limit)).build();
+            let mut runtime = Executor::with_context(program, SP1CoreOpts::default(), context);
+            let result = runtime.run();
+            (result, runtime.report.gas)
+        };
+
+        let (result, consumed) = run(gas);
+        assert!(result.is_ok());
+        assert_eq!(consumed, Some(gas));
+
+        // The execution stops after the instruction that exceeds the limit.
+        let (result, consumed) = run(gas - 1);
+        assert!(matches!(result, Err(ExecutionError::OutOfGas { consumed, limit })
+            if consumed == gas && limit == gas - 1));
+        assert_eq!(consumed, Some(gas));
+        let (result, _) = run(2);
+        assert!(matches!(result, Err(ExecutionError::OutOfGas { consumed: 3, limit: 2 })));
+    }
+
+    #[test]
+    fn test_uint256_divrem_by_zero() {
+        // The divisor at 300 is never written, so it is zero.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::UINT256_DIVREM as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 100, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 300, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::SyscallDivisionByZero(SyscallCode::UINT256_DIVREM, 12)
+        ));
+    }
+
+    #[test]
+    fn test_uint256_submod_unreduced() {
+        // x = 5 is not reduced modulo 3.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 100, false, true),
+            Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            Instruction::new(Opcode::ADD, 29, 0, 3, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 332, false, true),
+            Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::UINT256_SUB_MOD as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 100, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 300, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::InvalidSyscallArgument(SyscallCode::UINT256_SUB_MOD, 36)
+        ));
+    }
+
+    #[test]
+    fn test_uint256_mont_mul_even_modulus() {
+        // The modulus at 332 is never written, so it is zero, which is even.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::UINT256_MONT_MUL as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 100, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 300, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::InvalidSyscallArgument(SyscallCode::UINT256_MONT_MUL, 12)
+        ));
+    }
+
+    #[test]
+    fn test_cycle_tracker() {
+        let start = SyscallCode::CYCLE_TRACKER_START as u32;
+        let end = SyscallCode::CYCLE_TRACKER_END as u32;
+        let label_hash = CycleReport::label_hash("loop");
+        // The region is entered twice: once around a single instruction, once around three.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, start, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, label_hash, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 5, 0, end, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 5, 0, start, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 37, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, end, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let events =
+            runtime.records.iter().flat_map(|r| r.cycle_tracker_events.iter()).collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        assert_eq!((events[0].start_clk, events[0].end_clk), (2, 4));
+        assert_eq!((events[1].start_clk, events[1].end_clk), (6, 10));
+
+        let report = CycleReport::from_events(events);
+        let entry = report.get("loop").unwrap();
+        assert_eq!((entry.count, entry.total_cycles), (2, 6));
+        assert!(report.get("other").is_none());
+        assert!(runtime.state.cycle_tracker_starts[&label_hash].is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "memory-report")]
+    fn test_memory_report() {
+        // Write to pages 1 and 2, then read from page 5.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 42, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 0x1000, false, true),
+            Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 0x2004, false, true),
+            Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 0x5000, false, true),
+            Instruction::new(Opcode::LW, 31, 30, 0, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let report = &runtime.memory_report;
+        assert_eq!(report.peak_address, 0x2004);
+        assert_eq!(report.pages_touched, 3);
+        assert_eq!(report.shards.len(), 1);
+        assert_eq!(report.shards[0].pages_touched, 3);
+        assert_eq!(runtime.records[0].memory_stats, report.shards[0]);
+    }
+
+    #[test]
+    #[cfg(feature = "profiling")]
+    fn test_cycle_breakdown() {
+        use crate::{CycleBreakdown, FunctionSymbol};
+
+        // `main` calls `f` three times, in a loop whose branch is outside of any function.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 11, 11, 1, false, true),
+            Instruction::new(Opcode::JALR, 0, 1, 0, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 3, false, true),
+            Instruction::new(Opcode::JAL, 1, -12i32 as u32, 0, true, true),
+            Instruction::new(Opcode::SUB, 10, 10, 1, false, true),
+            Instruction::new(Opcode::BNE, 10, 0, -8i32 as u32, false, true),
+        ];
+        let mut program = Program::new(instructions, 0x108, 0x100);
+        program.function_symbols = vec![
+            FunctionSymbol { name: "f".to_string(), start: 0x100, end: 0x108 },
+            FunctionSymbol { name: "main".to_string(), start: 0x108, end: 0x114 },
+        ];
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.register(Register::X11), 3);
+
+        let breakdown = runtime.report.cycle_breakdown();
+        assert_eq!(breakdown.total_cycles, runtime.report.total_instruction_count());
+        let counts = |name: &str| {
+            let counts = breakdown.get(name).unwrap().counts;
+            (counts.cycles, counts.calls)
+        };
+        assert_eq!(counts("main"), (7, 1));
+        assert_eq!(counts("f"), (6, 3));
+        assert_eq!(counts(CycleBreakdown::UNKNOWN_FUNCTION), (3, 0));
+        assert_eq!(
+            breakdown.to_string(),
+            "cycle breakdown (16 total cycles):\n  \
+             7  43.75% 1 calls  main\n  \
+             6  37.50% 3 calls  f\n  \
+             3  18.75% 0 calls  <unknown>\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "profiling")]
+    fn test_cycle_breakdown_elf() {
+        let program = fibonacci_program();
+        let functions = &program.function_symbols;
+        assert!(functions.windows(2).all(|pair| pair[0].end <= pair[1].start));
+        assert!(functions.iter().any(|function| function.name == "main"));
+
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let breakdown = runtime.report.cycle_breakdown();
+        assert_eq!(breakdown.total_cycles, runtime.report.total_instruction_count());
+        assert_eq!(breakdown.get("main").unwrap().counts.calls, 1);
+        assert_eq!(breakdown.get("__start").unwrap().counts.calls, 1);
+    }
+
+    #[test]
+    #[cfg(feature = "profiling")]
+    fn test_syscall_histogram() {
+        let start = SyscallCode::CYCLE_TRACKER_START as u32;
+        let end = SyscallCode::CYCLE_TRACKER_END as u32;
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, start, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 5, 0, end, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 5, 0, end, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let record = &runtime.records[0];
+        assert_eq!(record.syscall_histogram[&SyscallCode::CYCLE_TRACKER_START], 1);
+        assert_eq!(record.syscall_histogram[&SyscallCode::CYCLE_TRACKER_END], 2);
+        assert_eq!(
+            record.syscall_histogram_table(),
+            "syscall histogram (3 total syscalls):\n  2 cycle_tracker_end\n  1 cycle_tracker_start\n"
+        );
+    }
 }
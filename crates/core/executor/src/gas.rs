@@ -0,0 +1,161 @@
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::syscalls::SyscallCode;
+
+/// The weights used by a [`GasMeter`] to charge an execution.
+///
+/// The unit of gas is roughly the trace area of one instruction, that is a row of the CPU table
+/// and of the table of its opcode. The defaults estimate the trace area of each operation in that
+/// unit, so that a gas limit bounds the cost of proving a program rather than its cycles.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CostTable {
+    /// The gas charged for every instruction, including the `ecall` of a syscall.
+    pub base_instruction: u64,
+    /// The gas charged for each syscall on top of its `ecall`. Syscalls that are missing are only
+    /// charged as an instruction.
+    pub per_syscall: HashMap<SyscallCode, u64>,
+    /// The gas charged the first time a page of [`CostTable::PAGE_SIZE`] bytes is accessed.
+    pub per_memory_page: u64,
+}
+
+impl CostTable {
+    /// The size in bytes of the pages charged by `per_memory_page`.
+    pub const PAGE_SIZE: u32 = 1 << 12;
+
+    /// The estimated trace area of one row of a precompile table. Precompile rows are an order of
+    /// magnitude wider than a CPU row.
+    const PRECOMPILE_ROW: u64 = 16;
+
+    /// The number of rows per call of the precompiles that use more than one, as in the shard
+    /// splitting of the executor.
+    const PRECOMPILE_ROWS: [(SyscallCode, u64); 10] = [
+        (SyscallCode::KECCAK_PERMUTE, 24),
+        (SyscallCode::SHA_EXTEND, 48),
+        (SyscallCode::SHA_COMPRESS, 80),
+        (SyscallCode::UINT256_MOD_EXP, 256),
+        (SyscallCode::BLAKE3_COMPRESS, 7),
+        (SyscallCode::BLAKE2B_COMPRESS, 12),
+        (SyscallCode::RIPEMD160_COMPRESS, 80),
+        (SyscallCode::SECP256K1_MUL, 256),
+        (SyscallCode::P256_MUL, 256),
+        (SyscallCode::CHACHA20_BLOCK, 10),
+    ];
+
+    /// Returns the gas charged for a syscall on top of its `ecall`.
+    #[must_use]
+    pub fn syscall(&self, code: SyscallCode) -> u64 {
+        self.per_syscall.get(&code).copied().unwrap_or_default()
+    }
+}
+
+impl Default for CostTable {
+    /// Charges each precompile the rows it adds to its table, and each page the initialization
+    /// and finalization rows of its words. The syscalls without a table, such as `WRITE` or
+    /// `HINT_READ`, are only charged as an instruction.
+    fn default() -> Self {
+        let mut per_syscall = SyscallCode::iter()
+            .filter(|code| code.should_send() == 1)
+            .map(|code| (code, Self::PRECOMPILE_ROW))
+            .collect::<HashMap<_, _>>();
+        for (code, rows) in Self::PRECOMPILE_ROWS {
+            per_syscall.insert(code, rows * Self::PRECOMPILE_ROW);
+        }
+        Self { base_instruction: 1, per_syscall, per_memory_page: u64::from(Self::PAGE_SIZE) / 2 }
+    }
+}
+
+/// Meters the gas of an execution against a limit, with the weights of a [`CostTable`].
+///
+/// Only the constrained execution is charged, since the unconstrained blocks leave nothing in the
+/// trace. The executor stops with [`crate::ExecutionError::OutOfGas`] after the instruction that
+/// exceeds the limit.
+///
+/// ### Examples
+/// ```ignore
+/// use sp1_core_executor::{CostTable, GasMeter, SP1Context};
+///
+/// let gas_meter = GasMeter::new(CostTable::default(), 1 << 24);
+/// let context = SP1Context::builder().gas_meter(gas_meter).build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasMeter {
+    /// The weights of the operations.
+    pub table: CostTable,
+    /// The maximum amount of gas of the execution.
+    pub limit: u64,
+    /// The gas consumed so far.
+    consumed: u64,
+    /// The pages that were accessed so far.
+    pages: HashSet<u32>,
+}
+
+impl GasMeter {
+    /// Creates a meter with the given weights and limit.
+    #[must_use]
+    pub fn new(table: CostTable, limit: u64) -> Self {
+        Self { table, limit, consumed: 0, pages: HashSet::new() }
+    }
+
+    /// The gas consumed so far.
+    #[must_use]
+    pub fn consumed(&self) -> u64 {
+        self.consumed
+    }
+
+    /// Whether the consumed gas exceeds the limit.
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.consumed > self.limit
+    }
+
+    /// Charges an instruction.
+    pub fn charge_instruction(&mut self) {
+        self.consumed = self.consumed.saturating_add(self.table.base_instruction);
+    }
+
+    /// Charges a syscall, on top of its `ecall`.
+    pub fn charge_syscall(&mut self, code: SyscallCode) {
+        self.consumed = self.consumed.saturating_add(self.table.syscall(code));
+    }
+
+    /// Charges a memory access, if it is the first one to its page. Registers are not charged.
+    pub fn charge_memory_access(&mut self, addr: u32) {
+        if addr >= 32 && self.pages.insert(addr / CostTable::PAGE_SIZE) {
+            self.consumed = self.consumed.saturating_add(self.table.per_memory_page);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_cost_table() {
+        let table = CostTable::default();
+        assert_eq!(table.syscall(SyscallCode::WRITE), 0);
+        assert_eq!(table.syscall(SyscallCode::SECP256K1_ADD), CostTable::PRECOMPILE_ROW);
+        assert_eq!(table.syscall(SyscallCode::KECCAK_PERMUTE), 24 * CostTable::PRECOMPILE_ROW);
+    }
+
+    #[test]
+    fn test_gas_meter() {
+        let mut meter = GasMeter::new(CostTable::default(), 3000);
+        meter.charge_instruction();
+        meter.charge_syscall(SyscallCode::SECP256K1_ADD);
+        assert_eq!(meter.consumed(), 17);
+
+        // Each page is charged once, and registers are free.
+        meter.charge_memory_access(4);
+        meter.charge_memory_access(0x1000);
+        meter.charge_memory_access(0x1ffc);
+        assert_eq!(meter.consumed(), 17 + 2048);
+        assert!(!meter.is_exhausted());
+
+        meter.charge_memory_access(0x2000);
+        assert_eq!(meter.consumed(), 17 + 2 * 2048);
+        assert!(meter.is_exhausted());
+    }
+}
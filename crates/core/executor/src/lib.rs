@@ -23,6 +23,7 @@ mod context;
 mod disassembler;
 pub mod events;
 mod executor;
+mod gas;
 mod hook;
 mod instruction;
 mod io;
@@ -40,6 +41,7 @@ pub mod syscalls;
 
 pub use context::*;
 pub use executor::*;
+pub use gas::*;
 pub use hook::*;
 pub use instruction::*;
 pub use opcode::*;
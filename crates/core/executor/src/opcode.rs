@@ -129,6 +129,12 @@ pub enum ByteOpcode {
     MSB = 7,
     /// Unsigned 16-bit Range Check.
     U16Range = 8,
+    /// The AES S-box.
+    SBOX = 9,
+    /// Population Count.
+    POPCNT = 10,
+    /// Count Leading Zeros.
+    CLZ = 11,
 }
 
 impl Opcode {
@@ -25,13 +25,36 @@ pub struct Program {
     pub pc_base: u32,
     /// The initial memory image, useful for global constants.
     pub memory_image: BTreeMap<u32, u32>,
+    /// The functions of the program, sorted by address, for the per-function cycle profile.
+    #[cfg(feature = "profiling")]
+    #[serde(default)]
+    pub function_symbols: Vec<FunctionSymbol>,
+}
+
+/// A function of a program, from the symbol table of its ELF.
+#[cfg(feature = "profiling")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionSymbol {
+    /// The demangled name of the function.
+    pub name: String,
+    /// The address of the first instruction of the function.
+    pub start: u32,
+    /// The address after the last instruction of the function.
+    pub end: u32,
 }
 
 impl Program {
     /// Create a new [Program].
     #[must_use]
     pub const fn new(instructions: Vec<Instruction>, pc_start: u32, pc_base: u32) -> Self {
-        Self { instructions, pc_start, pc_base, memory_image: BTreeMap::new() }
+        Self {
+            instructions,
+            pc_start,
+            pc_base,
+            memory_image: BTreeMap::new(),
+            #[cfg(feature = "profiling")]
+            function_symbols: Vec::new(),
+        }
     }
 
     /// Disassemble a RV32IM ELF to a program that be executed by the VM.
@@ -52,6 +75,8 @@ impl Program {
             pc_start: elf.pc_start,
             pc_base: elf.pc_base,
             memory_image: elf.memory_image,
+            #[cfg(feature = "profiling")]
+            function_symbols: Elf::decode_function_symbols(input)?,
         })
     }
 
@@ -8,12 +8,26 @@ use serde::{Deserialize, Serialize};
 
 use super::{program::Program, Opcode};
 use crate::events::{
-    add_sharded_byte_lookup_events, AluEvent, ByteLookupEvent, ByteRecord, CpuEvent,
+    add_sharded_byte_lookup_events, Aes128EncryptEvent, AluEvent, BatchFieldInverseEvent,
+    Blake2bCompressEvent, Blake3CompressEvent, ByteLookupEvent, ByteRecord, ChaCha20BlockEvent,
+    Clz32Event, CpuEvent, CycleTrackerEvent, EcdsaVerifyEvent, Ed25519VerifyEvent,
     EdDecompressEvent, EllipticCurveAddEvent, EllipticCurveDecompressEvent,
-    EllipticCurveDoubleEvent, Fp2AddSubEvent, Fp2MulEvent, FpOpEvent, KeccakPermuteEvent, LookupId,
-    MemoryInitializeFinalizeEvent, MemoryRecordEnum, ShaCompressEvent, ShaExtendEvent,
-    Uint256MulEvent,
+    EllipticCurveDoubleEvent, EllipticCurveLinearCombinationEvent, EllipticCurveMsmEvent,
+    EllipticCurveMulEvent, Fp2AddSubEvent, Fp2MulEvent, FpOpEvent, FriDecommitEvent,
+    KeccakPermuteEvent, LagrangeInterpEvent, LookupId, MemoryInitializeFinalizeEvent,
+    MemoryRecordEnum, MerkleVerifyEvent, NttEvent, PedersenHashEvent, PolyEvalEvent,
+    PolyMultiEvalEvent, PopcountEvent, Poseidon2PermuteEvent, RescuePermuteEvent,
+    Ripemd160CompressEvent, Sha256HashEvent, ShaCompressEvent, ShaExtendEvent, Uint256AddModEvent,
+    Uint256BitwiseEvent, Uint256CmpEvent, Uint256DivRemEvent, Uint256InvModEvent,
+    Uint256MulWideEvent, Uint256RangeCheckEvent, Uint256SquareModEvent, Uint256SubModEvent,
+    UintMulEvent, X25519LadderStepEvent,
 };
+#[cfg(feature = "memory-report")]
+use crate::ShardMemoryStats;
+#[cfg(feature = "profiling")]
+use crate::{events::sorted_table_lines, syscalls::SyscallCode};
+#[cfg(feature = "profiling")]
+use std::collections::BTreeMap;
 
 /// A record of the execution of a program.
 ///
@@ -48,14 +62,68 @@ pub struct ExecutionRecord {
     pub sha_compress_events: Vec<ShaCompressEvent>,
     /// A trace of the keccak256 permute events.
     pub keccak_permute_events: Vec<KeccakPermuteEvent>,
+    /// A trace of the poseidon2 permute events.
+    pub poseidon2_permute_events: Vec<Poseidon2PermuteEvent>,
+    /// A trace of the rescue permute events.
+    pub rescue_permute_events: Vec<RescuePermuteEvent>,
+    /// A trace of the pedersen hash events.
+    pub pedersen_hash_events: Vec<PedersenHashEvent>,
+    /// A trace of the AES-128 encrypt events.
+    pub aes128_encrypt_events: Vec<Aes128EncryptEvent>,
+    /// A trace of the ChaCha20 block events.
+    pub chacha20_block_events: Vec<ChaCha20BlockEvent>,
+    /// A trace of the popcount events.
+    pub popcount_events: Vec<PopcountEvent>,
+    /// A trace of the clz32 events.
+    pub clz32_events: Vec<Clz32Event>,
+    /// A trace of the blake3 compress events.
+    pub blake3_compress_events: Vec<Blake3CompressEvent>,
+    /// A trace of the blake2b compress events.
+    pub blake2b_compress_events: Vec<Blake2bCompressEvent>,
+    /// A trace of the ripemd160 compress events.
+    pub ripemd160_compress_events: Vec<Ripemd160CompressEvent>,
     /// A trace of the edwards add events.
     pub ed_add_events: Vec<EllipticCurveAddEvent>,
     /// A trace of the edwards decompress events.
     pub ed_decompress_events: Vec<EdDecompressEvent>,
+    /// A trace of the x25519 ladder step events.
+    pub x25519_ladder_step_events: Vec<X25519LadderStepEvent>,
+    /// A trace of the ed25519 verification events.
+    pub ed25519_verify_events: Vec<Ed25519VerifyEvent>,
     /// A trace of the secp256k1 add events.
     pub secp256k1_add_events: Vec<EllipticCurveAddEvent>,
     /// A trace of the secp256k1 double events.
     pub secp256k1_double_events: Vec<EllipticCurveDoubleEvent>,
+    /// A trace of the secp256k1 scalar multiplication events.
+    pub secp256k1_mul_events: Vec<EllipticCurveMulEvent>,
+    /// A trace of the secp256k1 multi-scalar multiplication events.
+    pub secp256k1_msm_events: Vec<EllipticCurveMsmEvent>,
+    /// A trace of the secp256k1 linear combination events.
+    pub secp256k1_linear_combination_events: Vec<EllipticCurveLinearCombinationEvent>,
+    /// A trace of the secp256k1 ECDSA verification events.
+    pub secp256k1_ecdsa_verify_events: Vec<EcdsaVerifyEvent>,
+    /// A trace of the P-256 add events.
+    pub p256_add_events: Vec<EllipticCurveAddEvent>,
+    /// A trace of the P-256 double events.
+    pub p256_double_events: Vec<EllipticCurveDoubleEvent>,
+    /// A trace of the P-256 scalar multiplication events.
+    pub p256_mul_events: Vec<EllipticCurveMulEvent>,
+    /// A trace of the p256 decompress events.
+    pub p256_decompress_events: Vec<EllipticCurveDecompressEvent>,
+    /// A trace of the P-384 add events.
+    pub p384_add_events: Vec<EllipticCurveAddEvent>,
+    /// A trace of the P-384 double events.
+    pub p384_double_events: Vec<EllipticCurveDoubleEvent>,
+    /// A trace of the P-384 decompress events.
+    pub p384_decompress_events: Vec<EllipticCurveDecompressEvent>,
+    /// A trace of the Grumpkin add events.
+    pub grumpkin_add_events: Vec<EllipticCurveAddEvent>,
+    /// A trace of the Grumpkin double events.
+    pub grumpkin_double_events: Vec<EllipticCurveDoubleEvent>,
+    /// A trace of the BabyJubJub add events.
+    pub babyjubjub_add_events: Vec<EllipticCurveAddEvent>,
+    /// A trace of the BabyJubJub double events.
+    pub babyjubjub_double_events: Vec<EllipticCurveDoubleEvent>,
     /// A trace of the bn254 add events.
     pub bn254_add_events: Vec<EllipticCurveAddEvent>,
     /// A trace of the bn254 double events.
@@ -67,7 +135,37 @@ pub struct ExecutionRecord {
     /// A trace of the bls12381 double events.
     pub bls12381_double_events: Vec<EllipticCurveDoubleEvent>,
     /// A trace of the uint256 mul events.
-    pub uint256_mul_events: Vec<Uint256MulEvent>,
+    pub uint256_mul_events: Vec<UintMulEvent>,
+    /// A trace of the uint256 addmod events.
+    pub uint256_add_mod_events: Vec<Uint256AddModEvent>,
+    /// A trace of the uint256 submod events.
+    pub uint256_sub_mod_events: Vec<Uint256SubModEvent>,
+    /// A trace of the uint384 mul events.
+    pub uint384_mul_events: Vec<UintMulEvent>,
+    /// A trace of the uint512 mul events.
+    pub uint512_mul_events: Vec<UintMulEvent>,
+    /// A trace of the uint256 invmod events.
+    pub uint256_inv_mod_events: Vec<Uint256InvModEvent>,
+    /// A trace of the uint256 squaremod events.
+    pub uint256_square_mod_events: Vec<Uint256SquareModEvent>,
+    /// A trace of the uint256 widening mul events.
+    pub uint256_mul_wide_events: Vec<Uint256MulWideEvent>,
+    /// A trace of the uint256 divrem events.
+    pub uint256_divrem_events: Vec<Uint256DivRemEvent>,
+    /// A trace of the uint256 Montgomery mul events.
+    pub uint256_mont_mul_events: Vec<UintMulEvent>,
+    /// A trace of the uint256 modular exponentiation events.
+    pub uint256_mod_exp_events: Vec<UintMulEvent>,
+    /// A trace of the uint256 range check events.
+    pub uint256_range_check_events: Vec<Uint256RangeCheckEvent>,
+    /// A trace of the uint256 comparison events.
+    pub uint256_cmp_events: Vec<Uint256CmpEvent>,
+    /// A trace of the uint256 bitwise events.
+    pub uint256_bitwise_events: Vec<Uint256BitwiseEvent>,
+    /// A trace of the Merkle path verification events.
+    pub merkle_verify_events: Vec<MerkleVerifyEvent>,
+    /// A trace of the FRI decommitment events.
+    pub fri_decommit_events: Vec<FriDecommitEvent>,
     /// A trace of the memory initialize events.
     pub memory_initialize_events: Vec<MemoryInitializeFinalizeEvent>,
     /// A trace of the memory finalize events.
@@ -82,10 +180,46 @@ pub struct ExecutionRecord {
     pub bls12381_fp2_mul_events: Vec<Fp2MulEvent>,
     /// A trace of the bn254 fp events.
     pub bn254_fp_events: Vec<FpOpEvent>,
+    /// A trace of the bn254 fr events.
+    pub bn254_fr_events: Vec<FpOpEvent>,
+    /// A trace of the bls12381 fp batch inverse events.
+    pub bls12381_fp_batch_inverse_events: Vec<BatchFieldInverseEvent>,
+    /// A trace of the bn254 fp batch inverse events.
+    pub bn254_fp_batch_inverse_events: Vec<BatchFieldInverseEvent>,
+    /// A trace of the bn254 fr batch inverse events.
+    pub bn254_fr_batch_inverse_events: Vec<BatchFieldInverseEvent>,
+    /// A trace of the NTT events.
+    pub ntt_events: Vec<NttEvent>,
+    /// A trace of the inverse NTT events.
+    pub intt_events: Vec<NttEvent>,
+    /// A trace of the SHA-256 hash events, with one event per block.
+    pub sha256_hash_events: Vec<Sha256HashEvent>,
+    /// A trace of the polynomial evaluation events.
+    pub poly_eval_events: Vec<PolyEvalEvent>,
+    /// A trace of the polynomial multi-evaluation events.
+    pub poly_multi_eval_events: Vec<PolyMultiEvalEvent>,
+    /// A trace of the Lagrange interpolation events.
+    pub lagrange_interp_events: Vec<LagrangeInterpEvent>,
     /// A trace of the bn254 fp2 add/sub events.
     pub bn254_fp2_addsub_events: Vec<Fp2AddSubEvent>,
     /// A trace of the bn254 fp2 mul events.
     pub bn254_fp2_mul_events: Vec<Fp2MulEvent>,
+    /// A trace of the bn254 G2 add events.
+    pub bn254_g2_add_events: Vec<EllipticCurveAddEvent>,
+    /// A trace of the bn254 G2 double events.
+    pub bn254_g2_double_events: Vec<EllipticCurveDoubleEvent>,
+    /// A trace of the bls12381 G2 add events.
+    pub bls12381_g2_add_events: Vec<EllipticCurveAddEvent>,
+    /// A trace of the bls12381 G2 double events.
+    pub bls12381_g2_double_events: Vec<EllipticCurveDoubleEvent>,
+    /// A trace of the cycle tracker regions closed by the guest.
+    pub cycle_tracker_events: Vec<CycleTrackerEvent>,
+    /// The memory usage of the shard.
+    #[cfg(feature = "memory-report")]
+    pub memory_stats: ShardMemoryStats,
+    /// The number of times each syscall was executed in the shard.
+    #[cfg(feature = "profiling")]
+    pub syscall_histogram: BTreeMap<SyscallCode, u64>,
     /// The public values.
     pub public_values: PublicValues<u32, u32>,
     /// The nonce lookup.
@@ -99,6 +233,18 @@ impl ExecutionRecord {
         Self { program, ..Default::default() }
     }
 
+    /// Formats the syscall histogram as a table sorted by invocation count (descending).
+    #[cfg(feature = "profiling")]
+    #[must_use]
+    pub fn syscall_histogram_table(&self) -> String {
+        let total = self.syscall_histogram.values().sum::<u64>();
+        let mut table = format!("syscall histogram ({total} total syscalls):\n");
+        for line in sorted_table_lines(&self.syscall_histogram) {
+            table.push_str(&format!("  {line}\n"));
+        }
+        table
+    }
+
     /// Add a mul event to the execution record.
     pub fn add_mul_event(&mut self, mul_event: AluEvent) {
         self.mul_events.push(mul_event);
@@ -149,11 +295,54 @@ impl ExecutionRecord {
     pub fn defer(&mut self) -> ExecutionRecord {
         ExecutionRecord {
             keccak_permute_events: std::mem::take(&mut self.keccak_permute_events),
+            poseidon2_permute_events: std::mem::take(&mut self.poseidon2_permute_events),
+            rescue_permute_events: std::mem::take(&mut self.rescue_permute_events),
+            pedersen_hash_events: std::mem::take(&mut self.pedersen_hash_events),
+            aes128_encrypt_events: std::mem::take(&mut self.aes128_encrypt_events),
+            chacha20_block_events: std::mem::take(&mut self.chacha20_block_events),
+            popcount_events: std::mem::take(&mut self.popcount_events),
+            clz32_events: std::mem::take(&mut self.clz32_events),
+            blake3_compress_events: std::mem::take(&mut self.blake3_compress_events),
+            blake2b_compress_events: std::mem::take(&mut self.blake2b_compress_events),
+            ripemd160_compress_events: std::mem::take(&mut self.ripemd160_compress_events),
             secp256k1_add_events: std::mem::take(&mut self.secp256k1_add_events),
             secp256k1_double_events: std::mem::take(&mut self.secp256k1_double_events),
+            secp256k1_mul_events: std::mem::take(&mut self.secp256k1_mul_events),
+            secp256k1_msm_events: std::mem::take(&mut self.secp256k1_msm_events),
+            secp256k1_linear_combination_events: std::mem::take(
+                &mut self.secp256k1_linear_combination_events,
+            ),
+            secp256k1_ecdsa_verify_events: std::mem::take(&mut self.secp256k1_ecdsa_verify_events),
+            p256_add_events: std::mem::take(&mut self.p256_add_events),
+            p256_double_events: std::mem::take(&mut self.p256_double_events),
+            p256_mul_events: std::mem::take(&mut self.p256_mul_events),
+            p256_decompress_events: std::mem::take(&mut self.p256_decompress_events),
+            p384_add_events: std::mem::take(&mut self.p384_add_events),
+            p384_double_events: std::mem::take(&mut self.p384_double_events),
+            p384_decompress_events: std::mem::take(&mut self.p384_decompress_events),
+            grumpkin_add_events: std::mem::take(&mut self.grumpkin_add_events),
+            grumpkin_double_events: std::mem::take(&mut self.grumpkin_double_events),
+            babyjubjub_add_events: std::mem::take(&mut self.babyjubjub_add_events),
+            babyjubjub_double_events: std::mem::take(&mut self.babyjubjub_double_events),
             bn254_fp_events: std::mem::take(&mut self.bn254_fp_events),
+            bn254_fr_events: std::mem::take(&mut self.bn254_fr_events),
+            bls12381_fp_batch_inverse_events: std::mem::take(
+                &mut self.bls12381_fp_batch_inverse_events,
+            ),
+            bn254_fp_batch_inverse_events: std::mem::take(&mut self.bn254_fp_batch_inverse_events),
+            bn254_fr_batch_inverse_events: std::mem::take(&mut self.bn254_fr_batch_inverse_events),
+            ntt_events: std::mem::take(&mut self.ntt_events),
+            intt_events: std::mem::take(&mut self.intt_events),
+            sha256_hash_events: std::mem::take(&mut self.sha256_hash_events),
+            poly_eval_events: std::mem::take(&mut self.poly_eval_events),
+            poly_multi_eval_events: std::mem::take(&mut self.poly_multi_eval_events),
+            lagrange_interp_events: std::mem::take(&mut self.lagrange_interp_events),
             bn254_fp2_addsub_events: std::mem::take(&mut self.bn254_fp2_addsub_events),
             bn254_fp2_mul_events: std::mem::take(&mut self.bn254_fp2_mul_events),
+            bn254_g2_add_events: std::mem::take(&mut self.bn254_g2_add_events),
+            bn254_g2_double_events: std::mem::take(&mut self.bn254_g2_double_events),
+            bls12381_g2_add_events: std::mem::take(&mut self.bls12381_g2_add_events),
+            bls12381_g2_double_events: std::mem::take(&mut self.bls12381_g2_double_events),
             bn254_add_events: std::mem::take(&mut self.bn254_add_events),
             bn254_double_events: std::mem::take(&mut self.bn254_double_events),
             bls12381_add_events: std::mem::take(&mut self.bls12381_add_events),
@@ -162,8 +351,25 @@ impl ExecutionRecord {
             sha_compress_events: std::mem::take(&mut self.sha_compress_events),
             ed_add_events: std::mem::take(&mut self.ed_add_events),
             ed_decompress_events: std::mem::take(&mut self.ed_decompress_events),
+            x25519_ladder_step_events: std::mem::take(&mut self.x25519_ladder_step_events),
+            ed25519_verify_events: std::mem::take(&mut self.ed25519_verify_events),
             k256_decompress_events: std::mem::take(&mut self.k256_decompress_events),
             uint256_mul_events: std::mem::take(&mut self.uint256_mul_events),
+            uint256_add_mod_events: std::mem::take(&mut self.uint256_add_mod_events),
+            uint256_sub_mod_events: std::mem::take(&mut self.uint256_sub_mod_events),
+            uint384_mul_events: std::mem::take(&mut self.uint384_mul_events),
+            uint512_mul_events: std::mem::take(&mut self.uint512_mul_events),
+            uint256_inv_mod_events: std::mem::take(&mut self.uint256_inv_mod_events),
+            uint256_square_mod_events: std::mem::take(&mut self.uint256_square_mod_events),
+            uint256_mul_wide_events: std::mem::take(&mut self.uint256_mul_wide_events),
+            uint256_divrem_events: std::mem::take(&mut self.uint256_divrem_events),
+            uint256_mont_mul_events: std::mem::take(&mut self.uint256_mont_mul_events),
+            uint256_mod_exp_events: std::mem::take(&mut self.uint256_mod_exp_events),
+            uint256_range_check_events: std::mem::take(&mut self.uint256_range_check_events),
+            uint256_cmp_events: std::mem::take(&mut self.uint256_cmp_events),
+            uint256_bitwise_events: std::mem::take(&mut self.uint256_bitwise_events),
+            merkle_verify_events: std::mem::take(&mut self.merkle_verify_events),
+            fri_decommit_events: std::mem::take(&mut self.fri_decommit_events),
             bls12381_fp_events: std::mem::take(&mut self.bls12381_fp_events),
             bls12381_fp2_addsub_events: std::mem::take(&mut self.bls12381_fp2_addsub_events),
             bls12381_fp2_mul_events: std::mem::take(&mut self.bls12381_fp2_mul_events),
@@ -207,8 +413,39 @@ impl ExecutionRecord {
         }
 
         split_events!(self, keccak_permute_events, shards, opts.keccak, last);
+        split_events!(self, poseidon2_permute_events, shards, opts.deferred, last);
+        split_events!(self, rescue_permute_events, shards, opts.deferred, last);
+        split_events!(self, pedersen_hash_events, shards, opts.deferred, last);
+        split_events!(self, aes128_encrypt_events, shards, opts.deferred, last);
+        split_events!(self, chacha20_block_events, shards, opts.chacha20, last);
+        split_events!(self, popcount_events, shards, opts.deferred, last);
+        split_events!(self, clz32_events, shards, opts.deferred, last);
+        split_events!(self, blake3_compress_events, shards, opts.blake3, last);
+        split_events!(self, blake2b_compress_events, shards, opts.blake2b, last);
+        split_events!(self, ripemd160_compress_events, shards, opts.ripemd160, last);
         split_events!(self, secp256k1_add_events, shards, opts.deferred, last);
         split_events!(self, secp256k1_double_events, shards, opts.deferred, last);
+        split_events!(self, secp256k1_mul_events, shards, opts.ec_mul, last);
+        split_events!(self, secp256k1_msm_events, shards, opts.ec_msm, last);
+        split_events!(
+            self,
+            secp256k1_linear_combination_events,
+            shards,
+            opts.ec_linear_combination,
+            last
+        );
+        split_events!(self, secp256k1_ecdsa_verify_events, shards, opts.ecdsa, last);
+        split_events!(self, p256_add_events, shards, opts.deferred, last);
+        split_events!(self, p256_double_events, shards, opts.deferred, last);
+        split_events!(self, p256_mul_events, shards, opts.ec_mul, last);
+        split_events!(self, p256_decompress_events, shards, opts.deferred, last);
+        split_events!(self, p384_add_events, shards, opts.deferred, last);
+        split_events!(self, p384_double_events, shards, opts.deferred, last);
+        split_events!(self, p384_decompress_events, shards, opts.deferred, last);
+        split_events!(self, grumpkin_add_events, shards, opts.deferred, last);
+        split_events!(self, grumpkin_double_events, shards, opts.deferred, last);
+        split_events!(self, babyjubjub_add_events, shards, opts.deferred, last);
+        split_events!(self, babyjubjub_double_events, shards, opts.deferred, last);
         split_events!(self, bn254_add_events, shards, opts.deferred, last);
         split_events!(self, bn254_double_events, shards, opts.deferred, last);
         split_events!(self, bls12381_add_events, shards, opts.deferred, last);
@@ -217,15 +454,73 @@ impl ExecutionRecord {
         split_events!(self, sha_compress_events, shards, opts.sha_compress, last);
         split_events!(self, ed_add_events, shards, opts.deferred, last);
         split_events!(self, ed_decompress_events, shards, opts.deferred, last);
+        split_events!(self, x25519_ladder_step_events, shards, opts.deferred, last);
+        split_events!(self, ed25519_verify_events, shards, opts.eddsa, last);
         split_events!(self, k256_decompress_events, shards, opts.deferred, last);
         split_events!(self, uint256_mul_events, shards, opts.deferred, last);
+        split_events!(self, uint256_add_mod_events, shards, opts.deferred, last);
+        split_events!(self, uint256_sub_mod_events, shards, opts.deferred, last);
+        split_events!(self, uint384_mul_events, shards, opts.deferred, last);
+        split_events!(self, uint512_mul_events, shards, opts.deferred, last);
+        split_events!(self, uint256_inv_mod_events, shards, opts.deferred, last);
+        split_events!(self, uint256_square_mod_events, shards, opts.deferred, last);
+        split_events!(self, uint256_mul_wide_events, shards, opts.deferred, last);
+        split_events!(self, uint256_divrem_events, shards, opts.deferred, last);
+        split_events!(self, uint256_mont_mul_events, shards, opts.deferred, last);
+        split_events!(self, uint256_mod_exp_events, shards, opts.mod_exp, last);
+        split_events!(self, uint256_range_check_events, shards, opts.deferred, last);
+        split_events!(self, uint256_cmp_events, shards, opts.deferred, last);
+        split_events!(self, uint256_bitwise_events, shards, opts.deferred, last);
+        split_events!(self, merkle_verify_events, shards, opts.merkle, last);
+        split_events!(self, fri_decommit_events, shards, opts.fri, last);
         split_events!(self, bls12381_decompress_events, shards, opts.deferred, last);
         split_events!(self, bls12381_fp_events, shards, opts.deferred, last);
         split_events!(self, bls12381_fp2_addsub_events, shards, opts.deferred, last);
         split_events!(self, bls12381_fp2_mul_events, shards, opts.deferred, last);
         split_events!(self, bn254_fp_events, shards, opts.deferred, last);
+        split_events!(self, bn254_fr_events, shards, opts.deferred, last);
+        split_events!(self, bls12381_fp_batch_inverse_events, shards, opts.batch_inverse, last);
+        split_events!(self, bn254_fp_batch_inverse_events, shards, opts.batch_inverse, last);
+        split_events!(self, bn254_fr_batch_inverse_events, shards, opts.batch_inverse, last);
+        split_events!(self, ntt_events, shards, opts.ntt, last);
+        split_events!(self, intt_events, shards, opts.ntt, last);
+        split_events!(self, poly_eval_events, shards, opts.poly_eval, last);
+        split_events!(self, poly_multi_eval_events, shards, opts.poly_eval, last);
+        split_events!(self, lagrange_interp_events, shards, opts.lagrange_interp, last);
         split_events!(self, bn254_fp2_addsub_events, shards, opts.deferred, last);
         split_events!(self, bn254_fp2_mul_events, shards, opts.deferred, last);
+        split_events!(self, bn254_g2_add_events, shards, opts.deferred, last);
+        split_events!(self, bn254_g2_double_events, shards, opts.deferred, last);
+        split_events!(self, bls12381_g2_add_events, shards, opts.deferred, last);
+        split_events!(self, bls12381_g2_double_events, shards, opts.deferred, last);
+
+        // The SHA-256 hash events are one per block, and the blocks of a message must stay in the
+        // same shard, so they are split by messages rather than by events.
+        let sha256_hash_events = std::mem::take(&mut self.sha256_hash_events);
+        let message_starts = sha256_hash_events
+            .iter()
+            .positions(Sha256HashEvent::is_first_block)
+            .chain(std::iter::once(sha256_hash_events.len()))
+            .collect::<Vec<_>>();
+        let mut chunk_start = 0;
+        for chunk_end in message_starts.iter().skip(opts.sha256_hash).step_by(opts.sha256_hash) {
+            shards.push(ExecutionRecord {
+                sha256_hash_events: sha256_hash_events[chunk_start..*chunk_end].to_vec(),
+                program: self.program.clone(),
+                ..Default::default()
+            });
+            chunk_start = *chunk_end;
+        }
+        let remainder = sha256_hash_events[chunk_start..].to_vec();
+        if !last {
+            self.sha256_hash_events = remainder;
+        } else if !remainder.is_empty() {
+            shards.push(ExecutionRecord {
+                sha256_hash_events: remainder,
+                program: self.program.clone(),
+                ..Default::default()
+            });
+        }
         // _ = last_pct;
 
         if last {
@@ -305,16 +600,67 @@ impl MachineRecord for ExecutionRecord {
         stats.insert("sha_extend_events".to_string(), self.sha_extend_events.len());
         stats.insert("sha_compress_events".to_string(), self.sha_compress_events.len());
         stats.insert("keccak_permute_events".to_string(), self.keccak_permute_events.len());
+        stats.insert("poseidon2_permute_events".to_string(), self.poseidon2_permute_events.len());
+        stats.insert("rescue_permute_events".to_string(), self.rescue_permute_events.len());
+        stats.insert("pedersen_hash_events".to_string(), self.pedersen_hash_events.len());
+        stats.insert("aes128_encrypt_events".to_string(), self.aes128_encrypt_events.len());
+        stats.insert("chacha20_block_events".to_string(), self.chacha20_block_events.len());
+        stats.insert("popcount_events".to_string(), self.popcount_events.len());
+        stats.insert("clz32_events".to_string(), self.clz32_events.len());
+        stats.insert("blake3_compress_events".to_string(), self.blake3_compress_events.len());
+        stats.insert("blake2b_compress_events".to_string(), self.blake2b_compress_events.len());
+        stats.insert("ripemd160_compress_events".to_string(), self.ripemd160_compress_events.len());
         stats.insert("ed_add_events".to_string(), self.ed_add_events.len());
         stats.insert("ed_decompress_events".to_string(), self.ed_decompress_events.len());
+        stats.insert("x25519_ladder_step_events".to_string(), self.x25519_ladder_step_events.len());
+        stats.insert("ed25519_verify_events".to_string(), self.ed25519_verify_events.len());
         stats.insert("secp256k1_add_events".to_string(), self.secp256k1_add_events.len());
         stats.insert("secp256k1_double_events".to_string(), self.secp256k1_double_events.len());
+        stats.insert("secp256k1_mul_events".to_string(), self.secp256k1_mul_events.len());
+        stats.insert("secp256k1_msm_events".to_string(), self.secp256k1_msm_events.len());
+        stats.insert(
+            "secp256k1_linear_combination_events".to_string(),
+            self.secp256k1_linear_combination_events.len(),
+        );
+        stats.insert(
+            "secp256k1_ecdsa_verify_events".to_string(),
+            self.secp256k1_ecdsa_verify_events.len(),
+        );
+        stats.insert("p256_add_events".to_string(), self.p256_add_events.len());
+        stats.insert("p256_double_events".to_string(), self.p256_double_events.len());
+        stats.insert("p256_mul_events".to_string(), self.p256_mul_events.len());
+        stats.insert("p256_decompress_events".to_string(), self.p256_decompress_events.len());
+        stats.insert("p384_add_events".to_string(), self.p384_add_events.len());
+        stats.insert("p384_double_events".to_string(), self.p384_double_events.len());
+        stats.insert("p384_decompress_events".to_string(), self.p384_decompress_events.len());
+        stats.insert("grumpkin_add_events".to_string(), self.grumpkin_add_events.len());
+        stats.insert("grumpkin_double_events".to_string(), self.grumpkin_double_events.len());
+        stats.insert("babyjubjub_add_events".to_string(), self.babyjubjub_add_events.len());
+        stats.insert("babyjubjub_double_events".to_string(), self.babyjubjub_double_events.len());
         stats.insert("bn254_add_events".to_string(), self.bn254_add_events.len());
         stats.insert("bn254_double_events".to_string(), self.bn254_double_events.len());
         stats.insert("k256_decompress_events".to_string(), self.k256_decompress_events.len());
         stats.insert("bls12381_add_events".to_string(), self.bls12381_add_events.len());
         stats.insert("bls12381_double_events".to_string(), self.bls12381_double_events.len());
         stats.insert("uint256_mul_events".to_string(), self.uint256_mul_events.len());
+        stats.insert("uint256_add_mod_events".to_string(), self.uint256_add_mod_events.len());
+        stats.insert("uint256_sub_mod_events".to_string(), self.uint256_sub_mod_events.len());
+        stats.insert("uint384_mul_events".to_string(), self.uint384_mul_events.len());
+        stats.insert("uint512_mul_events".to_string(), self.uint512_mul_events.len());
+        stats.insert("uint256_inv_mod_events".to_string(), self.uint256_inv_mod_events.len());
+        stats.insert("uint256_square_mod_events".to_string(), self.uint256_square_mod_events.len());
+        stats.insert("uint256_mul_wide_events".to_string(), self.uint256_mul_wide_events.len());
+        stats.insert("uint256_divrem_events".to_string(), self.uint256_divrem_events.len());
+        stats.insert("uint256_mont_mul_events".to_string(), self.uint256_mont_mul_events.len());
+        stats.insert("uint256_mod_exp_events".to_string(), self.uint256_mod_exp_events.len());
+        stats.insert(
+            "uint256_range_check_events".to_string(),
+            self.uint256_range_check_events.len(),
+        );
+        stats.insert("uint256_cmp_events".to_string(), self.uint256_cmp_events.len());
+        stats.insert("uint256_bitwise_events".to_string(), self.uint256_bitwise_events.len());
+        stats.insert("merkle_verify_events".to_string(), self.merkle_verify_events.len());
+        stats.insert("fri_decommit_events".to_string(), self.fri_decommit_events.len());
         stats.insert("bls12381_fp_event".to_string(), self.bls12381_fp_events.len());
         stats.insert(
             "bls12381_fp2_addsub_events".to_string(),
@@ -322,8 +668,31 @@ impl MachineRecord for ExecutionRecord {
         );
         stats.insert("bls12381_fp2_mul_events".to_string(), self.bls12381_fp2_mul_events.len());
         stats.insert("bn254_fp_events".to_string(), self.bn254_fp_events.len());
+        stats.insert("bn254_fr_events".to_string(), self.bn254_fr_events.len());
+        stats.insert(
+            "bls12381_fp_batch_inverse_events".to_string(),
+            self.bls12381_fp_batch_inverse_events.len(),
+        );
+        stats.insert(
+            "bn254_fp_batch_inverse_events".to_string(),
+            self.bn254_fp_batch_inverse_events.len(),
+        );
+        stats.insert(
+            "bn254_fr_batch_inverse_events".to_string(),
+            self.bn254_fr_batch_inverse_events.len(),
+        );
+        stats.insert("ntt_events".to_string(), self.ntt_events.len());
+        stats.insert("intt_events".to_string(), self.intt_events.len());
+        stats.insert("sha256_hash_events".to_string(), self.sha256_hash_events.len());
+        stats.insert("poly_eval_events".to_string(), self.poly_eval_events.len());
+        stats.insert("poly_multi_eval_events".to_string(), self.poly_multi_eval_events.len());
+        stats.insert("lagrange_interp_events".to_string(), self.lagrange_interp_events.len());
         stats.insert("bn254_fp2_addsub_events".to_string(), self.bn254_fp2_addsub_events.len());
         stats.insert("bn254_fp2_mul_events".to_string(), self.bn254_fp2_mul_events.len());
+        stats.insert("bn254_g2_add_events".to_string(), self.bn254_g2_add_events.len());
+        stats.insert("bn254_g2_double_events".to_string(), self.bn254_g2_double_events.len());
+        stats.insert("bls12381_g2_add_events".to_string(), self.bls12381_g2_add_events.len());
+        stats.insert("bls12381_g2_double_events".to_string(), self.bls12381_g2_double_events.len());
         stats.insert(
             "bls12381_decompress_events".to_string(),
             self.bls12381_decompress_events.len(),
@@ -355,22 +724,79 @@ impl MachineRecord for ExecutionRecord {
         self.sha_extend_events.append(&mut other.sha_extend_events);
         self.sha_compress_events.append(&mut other.sha_compress_events);
         self.keccak_permute_events.append(&mut other.keccak_permute_events);
+        self.poseidon2_permute_events.append(&mut other.poseidon2_permute_events);
+        self.rescue_permute_events.append(&mut other.rescue_permute_events);
+        self.pedersen_hash_events.append(&mut other.pedersen_hash_events);
+        self.aes128_encrypt_events.append(&mut other.aes128_encrypt_events);
+        self.chacha20_block_events.append(&mut other.chacha20_block_events);
+        self.popcount_events.append(&mut other.popcount_events);
+        self.clz32_events.append(&mut other.clz32_events);
+        self.blake3_compress_events.append(&mut other.blake3_compress_events);
+        self.blake2b_compress_events.append(&mut other.blake2b_compress_events);
+        self.ripemd160_compress_events.append(&mut other.ripemd160_compress_events);
         self.ed_add_events.append(&mut other.ed_add_events);
         self.ed_decompress_events.append(&mut other.ed_decompress_events);
+        self.x25519_ladder_step_events.append(&mut other.x25519_ladder_step_events);
+        self.ed25519_verify_events.append(&mut other.ed25519_verify_events);
         self.secp256k1_add_events.append(&mut other.secp256k1_add_events);
         self.secp256k1_double_events.append(&mut other.secp256k1_double_events);
+        self.secp256k1_mul_events.append(&mut other.secp256k1_mul_events);
+        self.secp256k1_msm_events.append(&mut other.secp256k1_msm_events);
+        self.secp256k1_linear_combination_events
+            .append(&mut other.secp256k1_linear_combination_events);
+        self.secp256k1_ecdsa_verify_events.append(&mut other.secp256k1_ecdsa_verify_events);
+        self.p256_add_events.append(&mut other.p256_add_events);
+        self.p256_double_events.append(&mut other.p256_double_events);
+        self.p256_mul_events.append(&mut other.p256_mul_events);
+        self.p256_decompress_events.append(&mut other.p256_decompress_events);
+        self.p384_add_events.append(&mut other.p384_add_events);
+        self.p384_double_events.append(&mut other.p384_double_events);
+        self.p384_decompress_events.append(&mut other.p384_decompress_events);
+        self.grumpkin_add_events.append(&mut other.grumpkin_add_events);
+        self.grumpkin_double_events.append(&mut other.grumpkin_double_events);
+        self.babyjubjub_add_events.append(&mut other.babyjubjub_add_events);
+        self.babyjubjub_double_events.append(&mut other.babyjubjub_double_events);
         self.bn254_add_events.append(&mut other.bn254_add_events);
         self.bn254_double_events.append(&mut other.bn254_double_events);
         self.k256_decompress_events.append(&mut other.k256_decompress_events);
         self.bls12381_add_events.append(&mut other.bls12381_add_events);
         self.bls12381_double_events.append(&mut other.bls12381_double_events);
         self.uint256_mul_events.append(&mut other.uint256_mul_events);
+        self.uint256_add_mod_events.append(&mut other.uint256_add_mod_events);
+        self.uint256_sub_mod_events.append(&mut other.uint256_sub_mod_events);
+        self.uint384_mul_events.append(&mut other.uint384_mul_events);
+        self.uint512_mul_events.append(&mut other.uint512_mul_events);
+        self.uint256_inv_mod_events.append(&mut other.uint256_inv_mod_events);
+        self.uint256_square_mod_events.append(&mut other.uint256_square_mod_events);
+        self.uint256_mul_wide_events.append(&mut other.uint256_mul_wide_events);
+        self.uint256_divrem_events.append(&mut other.uint256_divrem_events);
+        self.uint256_mont_mul_events.append(&mut other.uint256_mont_mul_events);
+        self.uint256_mod_exp_events.append(&mut other.uint256_mod_exp_events);
+        self.uint256_range_check_events.append(&mut other.uint256_range_check_events);
+        self.uint256_cmp_events.append(&mut other.uint256_cmp_events);
+        self.uint256_bitwise_events.append(&mut other.uint256_bitwise_events);
+        self.merkle_verify_events.append(&mut other.merkle_verify_events);
+        self.fri_decommit_events.append(&mut other.fri_decommit_events);
         self.bls12381_fp_events.append(&mut other.bls12381_fp_events);
         self.bls12381_fp2_addsub_events.append(&mut other.bls12381_fp2_addsub_events);
         self.bls12381_fp2_mul_events.append(&mut other.bls12381_fp2_mul_events);
         self.bn254_fp_events.append(&mut other.bn254_fp_events);
+        self.bn254_fr_events.append(&mut other.bn254_fr_events);
+        self.bls12381_fp_batch_inverse_events.append(&mut other.bls12381_fp_batch_inverse_events);
+        self.bn254_fp_batch_inverse_events.append(&mut other.bn254_fp_batch_inverse_events);
+        self.bn254_fr_batch_inverse_events.append(&mut other.bn254_fr_batch_inverse_events);
+        self.ntt_events.append(&mut other.ntt_events);
+        self.intt_events.append(&mut other.intt_events);
+        self.sha256_hash_events.append(&mut other.sha256_hash_events);
+        self.poly_eval_events.append(&mut other.poly_eval_events);
+        self.poly_multi_eval_events.append(&mut other.poly_multi_eval_events);
+        self.lagrange_interp_events.append(&mut other.lagrange_interp_events);
         self.bn254_fp2_addsub_events.append(&mut other.bn254_fp2_addsub_events);
         self.bn254_fp2_mul_events.append(&mut other.bn254_fp2_mul_events);
+        self.bn254_g2_add_events.append(&mut other.bn254_g2_add_events);
+        self.bn254_g2_double_events.append(&mut other.bn254_g2_double_events);
+        self.bls12381_g2_add_events.append(&mut other.bls12381_g2_add_events);
+        self.bls12381_g2_double_events.append(&mut other.bls12381_g2_double_events);
         self.bls12381_decompress_events.append(&mut other.bls12381_decompress_events);
 
         self.bls12381_decompress_events.append(&mut other.bls12381_decompress_events);
@@ -383,6 +809,12 @@ impl MachineRecord for ExecutionRecord {
 
         self.memory_initialize_events.append(&mut other.memory_initialize_events);
         self.memory_finalize_events.append(&mut other.memory_finalize_events);
+        self.cycle_tracker_events.append(&mut other.cycle_tracker_events);
+
+        #[cfg(feature = "profiling")]
+        for (syscall, count) in std::mem::take(&mut other.syscall_histogram) {
+            *self.syscall_histogram.entry(syscall).or_default() += count;
+        }
     }
 
     fn register_nonces(&mut self, _opts: &Self::Config) {
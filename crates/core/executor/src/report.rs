@@ -5,8 +5,13 @@ use std::{
 
 use enum_map::{EnumArray, EnumMap};
 use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
 
-use crate::{events::sorted_table_lines, syscalls::SyscallCode, Opcode};
+use crate::{
+    events::{sorted_table_lines, CycleTrackerEvent},
+    syscalls::SyscallCode,
+    Opcode,
+};
 
 /// An execution report.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
@@ -19,6 +24,11 @@ pub struct ExecutionReport {
     pub cycle_tracker: HashMap<String, u64>,
     /// The unique memory address counts.
     pub touched_memory_addresses: u64,
+    /// The gas consumed, if the execution was metered.
+    pub gas: Option<u64>,
+    /// The cycles and calls of each function, by name.
+    #[cfg(feature = "profiling")]
+    pub function_counts: HashMap<String, FunctionCounts>,
 }
 
 impl ExecutionReport {
@@ -33,6 +43,22 @@ impl ExecutionReport {
     pub fn total_syscall_count(&self) -> u64 {
         self.syscall_counts.values().sum()
     }
+
+    /// The cycles spent in each function of the program, sorted by cycles (descending).
+    #[cfg(feature = "profiling")]
+    #[must_use]
+    pub fn cycle_breakdown(&self) -> CycleBreakdown {
+        let mut entries = self
+            .function_counts
+            .iter()
+            .map(|(name, counts)| CycleBreakdownEntry { name: name.clone(), counts: *counts })
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by(|a, b| {
+            b.counts.cycles.cmp(&a.counts.cycles).then_with(|| a.name.cmp(&b.name))
+        });
+        let total_cycles = entries.iter().map(|entry| entry.counts.cycles).sum();
+        CycleBreakdown { total_cycles, entries }
+    }
 }
 
 /// Combines two `HashMap`s together. If a key is in both maps, the values are added together.
@@ -51,6 +77,14 @@ impl AddAssign for ExecutionReport {
         counts_add_assign(&mut self.opcode_counts, *rhs.opcode_counts);
         counts_add_assign(&mut self.syscall_counts, *rhs.syscall_counts);
         self.touched_memory_addresses += rhs.touched_memory_addresses;
+        self.gas = match (self.gas, rhs.gas) {
+            (None, None) => None,
+            (lhs, rhs) => Some(lhs.unwrap_or_default() + rhs.unwrap_or_default()),
+        };
+        #[cfg(feature = "profiling")]
+        for (name, counts) in rhs.function_counts {
+            *self.function_counts.entry(name).or_default() += counts;
+        }
     }
 }
 
@@ -75,6 +109,230 @@ impl Display for ExecutionReport {
             writeln!(f, "  {line}")?;
         }
 
+        if let Some(gas) = self.gas {
+            writeln!(f, "gas consumed: {gas}")?;
+        }
+
+        #[cfg(feature = "profiling")]
+        if !self.function_counts.is_empty() {
+            write!(f, "{}", self.cycle_breakdown())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The cycles spent in one labelled region of the guest program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycleReportEntry {
+    /// The hash of the region's label.
+    pub label_hash: u32,
+    /// The number of times the region was entered.
+    pub count: u64,
+    /// The total number of cycles spent in the region.
+    pub total_cycles: u64,
+}
+
+/// A per-label cycle report built from the guest's `CYCLE_TRACKER_START` and `CYCLE_TRACKER_END`
+/// syscalls.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycleReport {
+    /// The entries, sorted by total cycles (descending).
+    pub entries: Vec<CycleReportEntry>,
+}
+
+impl CycleReport {
+    /// Hashes a region label the same way the guest does (32-bit FNV-1a).
+    #[must_use]
+    pub const fn label_hash(label: &str) -> u32 {
+        let bytes = label.as_bytes();
+        let mut hash = 0x811c_9dc5u32;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+            i += 1;
+        }
+        hash
+    }
+
+    /// Aggregates cycle tracker events by label.
+    #[must_use]
+    pub fn from_events<'a>(events: impl IntoIterator<Item = &'a CycleTrackerEvent>) -> Self {
+        let mut totals: HashMap<u32, (u64, u64)> = HashMap::new();
+        for event in events {
+            let (count, total_cycles) = totals.entry(event.label_hash).or_default();
+            *count += 1;
+            *total_cycles += event.end_clk - event.start_clk;
+        }
+        let mut entries = totals
+            .into_iter()
+            .map(|(label_hash, (count, total_cycles))| CycleReportEntry {
+                label_hash,
+                count,
+                total_cycles,
+            })
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by(|a, b| {
+            b.total_cycles.cmp(&a.total_cycles).then_with(|| a.label_hash.cmp(&b.label_hash))
+        });
+        Self { entries }
+    }
+
+    /// Returns the entry for the given label, if the region was ever closed.
+    #[must_use]
+    pub fn get(&self, label: &str) -> Option<&CycleReportEntry> {
+        let label_hash = Self::label_hash(label);
+        self.entries.iter().find(|entry| entry.label_hash == label_hash)
+    }
+}
+
+impl Display for CycleReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "cycle tracker ({} labels):", self.entries.len())?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "  {:#010x}: {} cycles over {} calls",
+                entry.label_hash, entry.total_cycles, entry.count
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The memory usage of a single shard.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardMemoryStats {
+    /// The highest address written in the shard, or zero if nothing was written.
+    pub peak_address: u32,
+    /// The number of distinct pages read or written in the shard.
+    pub pages_touched: u64,
+}
+
+/// A report of how much of the address space a program used. Registers are not counted.
+///
+/// It is only filled in when the executor is built with the `memory-report` feature, so that the
+/// tracking costs nothing otherwise.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryReport {
+    /// The highest address written during the execution, or zero if nothing was written.
+    pub peak_address: u32,
+    /// The number of distinct pages read or written during the execution.
+    pub pages_touched: u64,
+    /// The memory usage of each shard, in execution order.
+    pub shards: Vec<ShardMemoryStats>,
+}
+
+impl MemoryReport {
+    /// The size in bytes of the pages counted by the report.
+    pub const PAGE_SIZE: u32 = 1 << 12;
+}
+
+impl Display for MemoryReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(
+            f,
+            "memory usage: peak_address={:#010x}, pages_touched={} ({} bytes)",
+            self.peak_address,
+            self.pages_touched,
+            self.pages_touched * u64::from(Self::PAGE_SIZE)
+        )?;
+        for (i, shard) in self.shards.iter().enumerate() {
+            writeln!(
+                f,
+                "  shard {i}: peak_address={:#010x}, pages_touched={}",
+                shard.peak_address, shard.pages_touched
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The cycles spent in a function of the program.
+#[cfg(feature = "profiling")]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCounts {
+    /// The number of instructions executed in the function, including the code inlined into it.
+    pub cycles: u64,
+    /// The number of times the first instruction of the function was executed, which counts the
+    /// calls, and the loops that jump back to it.
+    pub calls: u64,
+}
+
+#[cfg(feature = "profiling")]
+impl AddAssign for FunctionCounts {
+    fn add_assign(&mut self, rhs: Self) {
+        self.cycles += rhs.cycles;
+        self.calls += rhs.calls;
+    }
+}
+
+/// The cycles spent in one function of the program.
+#[cfg(feature = "profiling")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycleBreakdownEntry {
+    /// The demangled name of the function, or [`CycleBreakdown::UNKNOWN_FUNCTION`].
+    pub name: String,
+    /// The cycles and calls of the function.
+    pub counts: FunctionCounts,
+}
+
+/// A per-function cycle profile, which attributes each instruction to the function of the ELF
+/// symbol table that contains it.
+///
+/// It is only filled in when the executor is built with the `profiling` feature, since the lookup
+/// of the function slows down the execution.
+#[cfg(feature = "profiling")]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycleBreakdown {
+    /// The total number of cycles of the profile.
+    pub total_cycles: u64,
+    /// The entries, sorted by cycles (descending).
+    pub entries: Vec<CycleBreakdownEntry>,
+}
+
+#[cfg(feature = "profiling")]
+impl CycleBreakdown {
+    /// The name of the entry for the instructions outside of any function of the symbol table.
+    pub const UNKNOWN_FUNCTION: &'static str = "<unknown>";
+
+    /// Returns the entry for the given function, if it was executed.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&CycleBreakdownEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    /// The share of the total cycles spent in the entry, in percent.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn percent(&self, entry: &CycleBreakdownEntry) -> f64 {
+        100.0 * entry.counts.cycles as f64 / self.total_cycles as f64
+    }
+}
+
+#[cfg(feature = "profiling")]
+impl Display for CycleBreakdown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "cycle breakdown ({} total cycles):", self.total_cycles)?;
+        let cycles_width =
+            self.entries.first().map(|entry| entry.counts.cycles.to_string().len()).unwrap_or(0);
+        let calls_width = self
+            .entries
+            .iter()
+            .map(|entry| entry.counts.calls.to_string().len())
+            .max()
+            .unwrap_or(0);
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "  {:>cycles_width$} {:>6.2}% {:>calls_width$} calls  {}",
+                entry.counts.cycles,
+                self.percent(entry),
+                entry.counts.calls,
+                entry.name
+            )?;
+        }
         Ok(())
     }
 }
@@ -64,6 +64,10 @@ pub struct ExecutionState {
 
     /// Keeps track of how many times a certain syscall has been called.
     pub syscall_counts: HashMap<SyscallCode, u64>,
+
+    /// The global clocks of the open cycle tracker regions, keyed by label hash. Regions with the
+    /// same label may nest, so each label keeps a stack.
+    pub cycle_tracker_starts: HashMap<u32, Vec<u64>>,
 }
 
 impl ExecutionState {
@@ -86,6 +90,7 @@ impl ExecutionState {
             proof_stream: Vec::new(),
             proof_stream_ptr: 0,
             syscall_counts: HashMap::new(),
+            cycle_tracker_starts: HashMap::new(),
         }
     }
 }
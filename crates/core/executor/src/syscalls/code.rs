@@ -84,6 +84,12 @@ pub enum SyscallCode {
     /// Executes the `HINT_READ` precompile.
     HINT_READ = 0x00_00_00_F1,
 
+    /// Executes the `CYCLE_TRACKER_START` precompile.
+    CYCLE_TRACKER_START = 0x00_00_00_F2,
+
+    /// Executes the `CYCLE_TRACKER_END` precompile.
+    CYCLE_TRACKER_END = 0x00_00_00_F3,
+
     /// Executes the `UINT256_MUL` precompile.
     UINT256_MUL = 0x00_01_01_1D,
 
@@ -128,6 +134,186 @@ pub enum SyscallCode {
 
     /// Executes the `BN254_FP2_MUL` precompile.
     BN254_FP2_MUL = 0x00_01_01_2B,
+
+    /// Executes the `UINT256_ADD_MOD` precompile.
+    UINT256_ADD_MOD = 0x00_01_01_2C,
+
+    /// Executes the `UINT256_SUB_MOD` precompile.
+    UINT256_SUB_MOD = 0x00_01_01_2D,
+
+    /// Executes the `UINT512_MUL` precompile.
+    UINT512_MUL = 0x00_01_01_2E,
+
+    /// Executes the `UINT256_INV_MOD` precompile.
+    UINT256_INV_MOD = 0x00_01_01_2F,
+
+    /// Executes the `BLAKE3_COMPRESS` precompile.
+    BLAKE3_COMPRESS = 0x00_01_01_30,
+
+    /// Executes the `UINT256_SQUARE_MOD` precompile.
+    UINT256_SQUARE_MOD = 0x00_01_01_31,
+
+    /// Executes the `SECP256K1_MUL` precompile.
+    SECP256K1_MUL = 0x0001_0132,
+
+    /// Executes the `UINT384_MUL` precompile.
+    UINT384_MUL = 0x00_01_01_33,
+
+    /// Executes the `P256_ADD` precompile.
+    P256_ADD = 0x00_01_01_34,
+
+    /// Executes the `P256_DOUBLE` precompile.
+    P256_DOUBLE = 0x00_00_01_35,
+
+    /// Executes the `P256_MUL` precompile.
+    P256_MUL = 0x00_01_01_36,
+
+    /// Executes the `UINT256_MUL_WIDE` precompile.
+    UINT256_MUL_WIDE = 0x00_01_01_37,
+
+    /// Executes the `BN254_G2_ADD` precompile.
+    BN254_G2_ADD = 0x00_01_01_38,
+
+    /// Executes the `UINT256_DIVREM` precompile.
+    UINT256_DIVREM = 0x00_01_01_39,
+
+    /// Executes the `UINT256_MONT_MUL` precompile.
+    UINT256_MONT_MUL = 0x00_01_01_3A,
+
+    /// Executes the `UINT256_MOD_EXP` precompile.
+    UINT256_MOD_EXP = 0x00_01_01_3B,
+
+    /// Executes the `POSEIDON2_PERMUTE` precompile.
+    POSEIDON2_PERMUTE = 0x00_01_01_3C,
+
+    /// Executes the `RESCUE_PERMUTE` precompile.
+    RESCUE_PERMUTE = 0x00_01_01_3D,
+
+    /// Executes the `SECP256R1_DECOMPRESS` precompile.
+    SECP256R1_DECOMPRESS = 0x00_00_01_3E,
+
+    /// Executes the `PEDERSEN_HASH` precompile.
+    PEDERSEN_HASH = 0x00_01_01_3F,
+
+    /// Executes the `AES128_ENCRYPT` precompile.
+    AES128_ENCRYPT = 0x00_01_01_40,
+
+    /// Executes the `CHACHA20_BLOCK` precompile.
+    CHACHA20_BLOCK = 0x00_01_01_41,
+
+    /// Executes the `BLS12381_G2_ADD` precompile.
+    BLS12381_G2_ADD = 0x00_01_01_42,
+
+    /// Executes the `BLS12381_G2_DOUBLE` precompile.
+    BLS12381_G2_DOUBLE = 0x00_00_01_43,
+
+    /// Executes the `BN254_G2_DOUBLE` precompile.
+    BN254_G2_DOUBLE = 0x00_00_01_44,
+
+    /// Executes the `POPCOUNT` precompile.
+    POPCOUNT = 0x00_00_01_45,
+
+    /// Executes the `CLZ32` precompile.
+    CLZ32 = 0x00_00_01_46,
+
+    /// Executes the `UINT256_RANGE_CHECK` precompile.
+    UINT256_RANGE_CHECK = 0x00_01_01_47,
+
+    /// Executes the `MERKLE_VERIFY_PATH` precompile.
+    MERKLE_VERIFY_PATH = 0x00_01_01_48,
+
+    /// Executes the `SECP256K1_MSM` precompile.
+    SECP256K1_MSM = 0x00_21_01_49,
+
+    /// Executes the `X25519_LADDER_STEP` precompile.
+    X25519_LADDER_STEP = 0x00_01_01_4A,
+
+    /// Executes the `FRI_DECOMMIT` precompile.
+    FRI_DECOMMIT = 0x00_01_01_4B,
+
+    /// Executes the `SECP256K1_ECDSA_VERIFY` precompile.
+    SECP256K1_ECDSA_VERIFY = 0x00_01_01_4C,
+
+    /// Executes the `ED25519_VERIFY` precompile.
+    ED25519_VERIFY = 0x00_01_01_4D,
+
+    /// Executes the `P384_ADD` precompile.
+    P384_ADD = 0x00_01_01_4E,
+
+    /// Executes the `P384_DOUBLE` precompile.
+    P384_DOUBLE = 0x00_00_01_4F,
+
+    /// Executes the `SECP384R1_DECOMPRESS` precompile.
+    SECP384R1_DECOMPRESS = 0x00_00_01_50,
+
+    /// Executes the `BN254_FR_ADD` precompile.
+    BN254_FR_ADD = 0x00_01_01_51,
+
+    /// Executes the `BN254_FR_SUB` precompile.
+    BN254_FR_SUB = 0x00_01_01_52,
+
+    /// Executes the `BN254_FR_MUL` precompile.
+    BN254_FR_MUL = 0x00_01_01_53,
+
+    /// Executes the `GRUMPKIN_ADD` precompile.
+    GRUMPKIN_ADD = 0x00_01_01_54,
+
+    /// Executes the `GRUMPKIN_DOUBLE` precompile.
+    GRUMPKIN_DOUBLE = 0x00_00_01_55,
+
+    /// Executes the `BLAKE2B_COMPRESS` precompile.
+    BLAKE2B_COMPRESS = 0x00_01_01_56,
+
+    /// Executes the `RIPEMD160_COMPRESS` precompile.
+    RIPEMD160_COMPRESS = 0x00_01_01_57,
+
+    /// Executes the `BLS12381_FP_BATCH_INV` precompile.
+    BLS12381_FP_BATCH_INV = 0x00_01_01_58,
+
+    /// Executes the `BN254_FP_BATCH_INV` precompile.
+    BN254_FP_BATCH_INV = 0x00_01_01_59,
+
+    /// Executes the `BN254_FR_BATCH_INV` precompile.
+    BN254_FR_BATCH_INV = 0x00_01_01_5A,
+
+    /// Executes the `NTT` precompile.
+    NTT = 0x00_14_01_5B,
+
+    /// Executes the `INTT` precompile.
+    INTT = 0x00_14_01_5C,
+
+    /// Executes the `SHA256_HASH` precompile.
+    SHA256_HASH = 0x00_02_01_5D,
+
+    /// Executes the `POLY_EVAL` precompile.
+    POLY_EVAL = 0x00_02_01_5E,
+
+    /// Executes the `POLY_MULTI_EVAL` precompile.
+    POLY_MULTI_EVAL = 0x00_02_01_5F,
+
+    /// Executes the `BABYJUBJUB_ADD` precompile.
+    BABYJUBJUB_ADD = 0x00_01_01_60,
+
+    /// Executes the `BABYJUBJUB_DOUBLE` precompile.
+    BABYJUBJUB_DOUBLE = 0x00_00_01_61,
+
+    /// Executes the `LAGRANGE_INTERP` precompile.
+    LAGRANGE_INTERP = 0x00_21_01_62,
+
+    /// Executes the `EC_LINEAR_COMBINATION` precompile.
+    EC_LINEAR_COMBINATION = 0x00_09_01_63,
+
+    /// Executes the `UINT256_CMP` precompile.
+    UINT256_CMP = 0x0001_0164,
+
+    /// Executes the `UINT256_AND` precompile.
+    UINT256_AND = 0x00_01_01_65,
+
+    /// Executes the `UINT256_OR` precompile.
+    UINT256_OR = 0x00_01_01_66,
+
+    /// Executes the `UINT256_XOR` precompile.
+    UINT256_XOR = 0x00_01_01_67,
 }
 
 impl SyscallCode {
@@ -156,6 +342,8 @@ impl SyscallCode {
             0x00_00_00_1B => SyscallCode::VERIFY_SP1_PROOF,
             0x00_00_00_F0 => SyscallCode::HINT_LEN,
             0x00_00_00_F1 => SyscallCode::HINT_READ,
+            0x00_00_00_F2 => SyscallCode::CYCLE_TRACKER_START,
+            0x00_00_00_F3 => SyscallCode::CYCLE_TRACKER_END,
             0x00_01_01_1D => SyscallCode::UINT256_MUL,
             0x00_01_01_20 => SyscallCode::BLS12381_FP_ADD,
             0x00_01_01_21 => SyscallCode::BLS12381_FP_SUB,
@@ -170,6 +358,66 @@ impl SyscallCode {
             0x00_01_01_2A => SyscallCode::BN254_FP2_SUB,
             0x00_01_01_2B => SyscallCode::BN254_FP2_MUL,
             0x00_00_01_1C => SyscallCode::BLS12381_DECOMPRESS,
+            0x00_01_01_2C => SyscallCode::UINT256_ADD_MOD,
+            0x00_01_01_2D => SyscallCode::UINT256_SUB_MOD,
+            0x00_01_01_2E => SyscallCode::UINT512_MUL,
+            0x00_01_01_2F => SyscallCode::UINT256_INV_MOD,
+            0x00_01_01_30 => SyscallCode::BLAKE3_COMPRESS,
+            0x00_01_01_31 => SyscallCode::UINT256_SQUARE_MOD,
+            0x0001_0132 => SyscallCode::SECP256K1_MUL,
+            0x00_01_01_33 => SyscallCode::UINT384_MUL,
+            0x00_01_01_34 => SyscallCode::P256_ADD,
+            0x00_00_01_35 => SyscallCode::P256_DOUBLE,
+            0x00_01_01_36 => SyscallCode::P256_MUL,
+            0x00_01_01_37 => SyscallCode::UINT256_MUL_WIDE,
+            0x00_01_01_38 => SyscallCode::BN254_G2_ADD,
+            0x00_01_01_39 => SyscallCode::UINT256_DIVREM,
+            0x00_01_01_3A => SyscallCode::UINT256_MONT_MUL,
+            0x00_01_01_3B => SyscallCode::UINT256_MOD_EXP,
+            0x00_01_01_3C => SyscallCode::POSEIDON2_PERMUTE,
+            0x00_01_01_3D => SyscallCode::RESCUE_PERMUTE,
+            0x00_00_01_3E => SyscallCode::SECP256R1_DECOMPRESS,
+            0x00_01_01_3F => SyscallCode::PEDERSEN_HASH,
+            0x00_01_01_40 => SyscallCode::AES128_ENCRYPT,
+            0x00_01_01_41 => SyscallCode::CHACHA20_BLOCK,
+            0x00_01_01_42 => SyscallCode::BLS12381_G2_ADD,
+            0x00_00_01_43 => SyscallCode::BLS12381_G2_DOUBLE,
+            0x00_00_01_44 => SyscallCode::BN254_G2_DOUBLE,
+            0x00_00_01_45 => SyscallCode::POPCOUNT,
+            0x00_00_01_46 => SyscallCode::CLZ32,
+            0x00_01_01_47 => SyscallCode::UINT256_RANGE_CHECK,
+            0x00_01_01_48 => SyscallCode::MERKLE_VERIFY_PATH,
+            0x00_21_01_49 => SyscallCode::SECP256K1_MSM,
+            0x00_01_01_4A => SyscallCode::X25519_LADDER_STEP,
+            0x00_01_01_4B => SyscallCode::FRI_DECOMMIT,
+            0x00_01_01_4C => SyscallCode::SECP256K1_ECDSA_VERIFY,
+            0x00_01_01_4D => SyscallCode::ED25519_VERIFY,
+            0x00_01_01_4E => SyscallCode::P384_ADD,
+            0x00_00_01_4F => SyscallCode::P384_DOUBLE,
+            0x00_00_01_50 => SyscallCode::SECP384R1_DECOMPRESS,
+            0x00_01_01_51 => SyscallCode::BN254_FR_ADD,
+            0x00_01_01_52 => SyscallCode::BN254_FR_SUB,
+            0x00_01_01_53 => SyscallCode::BN254_FR_MUL,
+            0x00_01_01_54 => SyscallCode::GRUMPKIN_ADD,
+            0x00_00_01_55 => SyscallCode::GRUMPKIN_DOUBLE,
+            0x00_01_01_56 => SyscallCode::BLAKE2B_COMPRESS,
+            0x00_01_01_57 => SyscallCode::RIPEMD160_COMPRESS,
+            0x00_01_01_58 => SyscallCode::BLS12381_FP_BATCH_INV,
+            0x00_01_01_59 => SyscallCode::BN254_FP_BATCH_INV,
+            0x00_01_01_5A => SyscallCode::BN254_FR_BATCH_INV,
+            0x00_14_01_5B => SyscallCode::NTT,
+            0x00_14_01_5C => SyscallCode::INTT,
+            0x00_02_01_5D => SyscallCode::SHA256_HASH,
+            0x00_02_01_5E => SyscallCode::POLY_EVAL,
+            0x00_02_01_5F => SyscallCode::POLY_MULTI_EVAL,
+            0x00_01_01_60 => SyscallCode::BABYJUBJUB_ADD,
+            0x00_00_01_61 => SyscallCode::BABYJUBJUB_DOUBLE,
+            0x00_21_01_62 => SyscallCode::LAGRANGE_INTERP,
+            0x00_09_01_63 => SyscallCode::EC_LINEAR_COMBINATION,
+            0x0001_0164 => SyscallCode::UINT256_CMP,
+            0x00_01_01_65 => SyscallCode::UINT256_AND,
+            0x00_01_01_66 => SyscallCode::UINT256_OR,
+            0x00_01_01_67 => SyscallCode::UINT256_XOR,
             _ => panic!("invalid syscall number: {value}"),
         }
     }
@@ -200,9 +448,13 @@ impl SyscallCode {
             SyscallCode::BN254_FP_SUB => SyscallCode::BN254_FP_ADD,
             SyscallCode::BN254_FP_MUL => SyscallCode::BN254_FP_ADD,
             SyscallCode::BN254_FP2_SUB => SyscallCode::BN254_FP2_ADD,
+            SyscallCode::BN254_FR_SUB => SyscallCode::BN254_FR_ADD,
+            SyscallCode::BN254_FR_MUL => SyscallCode::BN254_FR_ADD,
             SyscallCode::BLS12381_FP_SUB => SyscallCode::BLS12381_FP_ADD,
             SyscallCode::BLS12381_FP_MUL => SyscallCode::BLS12381_FP_ADD,
             SyscallCode::BLS12381_FP2_SUB => SyscallCode::BLS12381_FP2_ADD,
+            SyscallCode::UINT256_OR => SyscallCode::UINT256_AND,
+            SyscallCode::UINT256_XOR => SyscallCode::UINT256_AND,
             _ => *self,
         }
     }
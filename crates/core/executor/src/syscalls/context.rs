@@ -16,6 +16,13 @@ pub struct SyscallContext<'a, 'b: 'a> {
     pub next_pc: u32,
     /// The exit code.
     pub exit_code: u32,
+    /// A pointer argument rejected by the syscall, which the executor reports as an error.
+    pub invalid_pointer: Option<u32>,
+    /// Whether the syscall was asked to divide by zero, which the executor reports as an error.
+    pub division_by_zero: bool,
+    /// Whether the syscall rejected the value of one of its arguments, which the executor reports
+    /// as an error.
+    pub invalid_argument: bool,
     /// The runtime.
     pub rt: &'a mut Executor<'b>,
     /// The syscall lookup id.
@@ -32,6 +39,9 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
             clk,
             next_pc: runtime.state.pc.wrapping_add(4),
             exit_code: 0,
+            invalid_pointer: None,
+            division_by_zero: false,
+            invalid_argument: false,
             rt: runtime,
             syscall_lookup_id: LookupId::default(),
         }
@@ -72,6 +82,13 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
         (records, values)
     }
 
+    /// Read a fixed number of words from memory.
+    pub fn mr_array<const N: usize>(&mut self, addr: u32) -> ([MemoryReadRecord; N], [u32; N]) {
+        let records: [MemoryReadRecord; N] =
+            core::array::from_fn(|i| self.mr(addr + i as u32 * 4).0);
+        (records, records.map(|record| record.value))
+    }
+
     /// Write a word to memory.
     pub fn mw(&mut self, addr: u32, value: u32) -> MemoryWriteRecord {
         self.rt.mw(addr, value, self.current_shard, self.clk)
@@ -87,6 +104,15 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
         records
     }
 
+    /// Write a fixed number of words to memory.
+    pub fn mw_array<const N: usize>(
+        &mut self,
+        addr: u32,
+        values: &[u32; N],
+    ) -> [MemoryWriteRecord; N] {
+        core::array::from_fn(|i| self.mw(addr + i as u32 * 4, values[i]))
+    }
+
     /// Get the current value of a register, but doesn't use a memory record.
     /// This is generally unconstrained, so you must be careful using it.
     #[must_use]
@@ -125,4 +151,17 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
     pub fn set_exit_code(&mut self, exit_code: u32) {
         self.exit_code = exit_code;
     }
+
+    /// Checks that `ptr` is aligned to a word boundary.
+    ///
+    /// If it is not, the pointer is recorded so that the executor returns an
+    /// [`crate::ExecutionError::InvalidSyscallPointer`] once the syscall returns, and the syscall
+    /// should return without accessing memory.
+    pub fn check_word_aligned(&mut self, ptr: u32) -> bool {
+        if ptr % 4 != 0 {
+            self.invalid_pointer = Some(ptr);
+            return false;
+        }
+        true
+    }
 }
@@ -0,0 +1,33 @@
+use crate::events::CycleTrackerEvent;
+
+use super::{Syscall, SyscallContext};
+
+pub(crate) struct CycleTrackerStartSyscall;
+
+impl Syscall for CycleTrackerStartSyscall {
+    fn execute(&self, ctx: &mut SyscallContext, label_hash: u32, _: u32) -> Option<u32> {
+        let global_clk = ctx.rt.state.global_clk;
+        ctx.rt.state.cycle_tracker_starts.entry(label_hash).or_default().push(global_clk);
+        None
+    }
+}
+
+pub(crate) struct CycleTrackerEndSyscall;
+
+impl Syscall for CycleTrackerEndSyscall {
+    fn execute(&self, ctx: &mut SyscallContext, label_hash: u32, _: u32) -> Option<u32> {
+        let end_clk = ctx.rt.state.global_clk;
+        let Some(start_clk) =
+            ctx.rt.state.cycle_tracker_starts.get_mut(&label_hash).and_then(Vec::pop)
+        else {
+            tracing::warn!("cycle tracker end for label hash {label_hash:#010x} without a start");
+            return None;
+        };
+        ctx.record_mut().cycle_tracker_events.push(CycleTrackerEvent {
+            label_hash,
+            start_clk,
+            end_clk,
+        });
+        None
+    }
+}
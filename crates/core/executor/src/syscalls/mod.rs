@@ -3,6 +3,7 @@
 mod code;
 mod commit;
 mod context;
+mod cycle_tracker;
 mod deferred;
 mod halt;
 mod hint;
@@ -14,6 +15,7 @@ mod write;
 use std::sync::Arc;
 
 use commit::CommitSyscall;
+use cycle_tracker::{CycleTrackerEndSyscall, CycleTrackerStartSyscall};
 use deferred::CommitDeferredSyscall;
 use halt::HaltSyscall;
 use hashbrown::HashMap;
@@ -22,22 +24,62 @@ pub use code::*;
 pub use context::*;
 use hint::{HintLenSyscall, HintReadSyscall};
 use precompiles::{
-    edwards::{add::EdwardsAddAssignSyscall, decompress::EdwardsDecompressSyscall},
-    fptower::{Fp2AddSubSyscall, Fp2MulSyscall, FpOpSyscall},
+    aes128::encrypt::Aes128EncryptSyscall,
+    blake2b::compress::Blake2bCompressSyscall,
+    blake3::compress::Blake3CompressSyscall,
+    chacha20::block::ChaCha20BlockSyscall,
+    clz32::Clz32Syscall,
+    edwards::{
+        add::EdwardsAddAssignSyscall, decompress::EdwardsDecompressSyscall,
+        double::EdwardsDoubleAssignSyscall, verify::Ed25519VerifySyscall,
+        x25519::X25519LadderStepSyscall,
+    },
+    fptower::{
+        BatchFieldInverseSyscall, Fp2AddSubSyscall, Fp2MulSyscall, FpOpSyscall, G2AddSyscall,
+        G2DoubleSyscall,
+    },
+    fri::FriDecommitSyscall,
     keccak256::permute::Keccak256PermuteSyscall,
-    sha256::{compress::Sha256CompressSyscall, extend::Sha256ExtendSyscall},
-    uint256::Uint256MulSyscall,
+    lagrange_interp::LagrangeInterpSyscall,
+    merkle::MerkleVerifyPathSyscall,
+    ntt::NttSyscall,
+    pedersen::hash::PedersenHashSyscall,
+    poly_eval::PolyEvalSyscall,
+    poly_multi_eval::PolyMultiEvalSyscall,
+    popcount::PopcountSyscall,
+    poseidon2::permute::Poseidon2PermuteSyscall,
+    rescue::permute::RescuePermuteSyscall,
+    ripemd160::compress::Ripemd160CompressSyscall,
+    sha256::{
+        compress::Sha256CompressSyscall, extend::Sha256ExtendSyscall, hash::Sha256HashSyscall,
+    },
+    uint256::{
+        Uint256AddModSyscall, Uint256BitwiseSyscall, Uint256CmpSyscall, Uint256DivRemSyscall,
+        Uint256InvModSyscall, Uint256ModExpSyscall, Uint256MontMulSyscall, Uint256MulWideSyscall,
+        Uint256RangeCheckSyscall, Uint256SquareModSyscall, Uint256SubModSyscall, UintMulSyscall,
+    },
     weierstrass::{
         add::WeierstrassAddAssignSyscall, decompress::WeierstrassDecompressSyscall,
-        double::WeierstrassDoubleAssignSyscall,
+        double::WeierstrassDoubleAssignSyscall, ecdsa_verify::WeierstrassEcdsaVerifySyscall,
+        linear_combination::WeierstrassLinearCombinationSyscall, msm::WeierstrassMsmSyscall,
+        mul::WeierstrassMulAssignSyscall,
     },
 };
 
 use sp1_curves::{
-    edwards::ed25519::{Ed25519, Ed25519Parameters},
+    edwards::{
+        babyjubjub::BabyJubJub,
+        ed25519::{Ed25519, Ed25519Parameters},
+    },
+    uint256::U256Field,
+    uint384::U384Field,
+    uint512::U512Field,
     weierstrass::{
         bls12_381::{Bls12381, Bls12381BaseField},
-        bn254::{Bn254, Bn254BaseField},
+        bn254::{Bn254, Bn254BaseField, Bn254ScalarField},
+        grumpkin::Grumpkin,
+        p256::P256,
+        p384::P384,
         secp256k1::Secp256k1,
     },
 };
@@ -45,7 +87,7 @@ use unconstrained::{EnterUnconstrainedSyscall, ExitUnconstrainedSyscall};
 use verify::VerifySyscall;
 use write::WriteSyscall;
 
-use crate::events::FieldOperation;
+use crate::{events::FieldOperation, ByteOpcode};
 
 /// A system call in the SP1 RISC-V zkVM.
 ///
@@ -85,8 +127,32 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(EdwardsDecompressSyscall::<Ed25519Parameters>::new()),
     );
 
+    syscall_map.insert(SyscallCode::X25519_LADDER_STEP, Arc::new(X25519LadderStepSyscall));
+
+    syscall_map.insert(SyscallCode::ED25519_VERIFY, Arc::new(Ed25519VerifySyscall));
+
     syscall_map.insert(SyscallCode::KECCAK_PERMUTE, Arc::new(Keccak256PermuteSyscall));
 
+    syscall_map.insert(SyscallCode::POSEIDON2_PERMUTE, Arc::new(Poseidon2PermuteSyscall));
+
+    syscall_map.insert(SyscallCode::RESCUE_PERMUTE, Arc::new(RescuePermuteSyscall));
+
+    syscall_map.insert(SyscallCode::PEDERSEN_HASH, Arc::new(PedersenHashSyscall));
+
+    syscall_map.insert(SyscallCode::AES128_ENCRYPT, Arc::new(Aes128EncryptSyscall));
+
+    syscall_map.insert(SyscallCode::CHACHA20_BLOCK, Arc::new(ChaCha20BlockSyscall));
+
+    syscall_map.insert(SyscallCode::POPCOUNT, Arc::new(PopcountSyscall));
+
+    syscall_map.insert(SyscallCode::CLZ32, Arc::new(Clz32Syscall));
+
+    syscall_map.insert(SyscallCode::BLAKE3_COMPRESS, Arc::new(Blake3CompressSyscall));
+
+    syscall_map.insert(SyscallCode::BLAKE2B_COMPRESS, Arc::new(Blake2bCompressSyscall));
+
+    syscall_map.insert(SyscallCode::RIPEMD160_COMPRESS, Arc::new(Ripemd160CompressSyscall));
+
     syscall_map.insert(
         SyscallCode::SECP256K1_ADD,
         Arc::new(WeierstrassAddAssignSyscall::<Secp256k1>::new()),
@@ -97,6 +163,19 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(WeierstrassDoubleAssignSyscall::<Secp256k1>::new()),
     );
 
+    syscall_map.insert(
+        SyscallCode::SECP256K1_MUL,
+        Arc::new(WeierstrassMulAssignSyscall::<Secp256k1>::new()),
+    );
+
+    syscall_map
+        .insert(SyscallCode::SECP256K1_MSM, Arc::new(WeierstrassMsmSyscall::<Secp256k1>::new()));
+
+    syscall_map.insert(
+        SyscallCode::SECP256K1_ECDSA_VERIFY,
+        Arc::new(WeierstrassEcdsaVerifySyscall::<Secp256k1>::new()),
+    );
+
     syscall_map.insert(
         SyscallCode::SECP256K1_DECOMPRESS,
         Arc::new(WeierstrassDecompressSyscall::<Secp256k1>::new()),
@@ -120,7 +199,86 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(WeierstrassDoubleAssignSyscall::<Bls12381>::new()),
     );
 
-    syscall_map.insert(SyscallCode::UINT256_MUL, Arc::new(Uint256MulSyscall));
+    syscall_map.insert(SyscallCode::P256_ADD, Arc::new(WeierstrassAddAssignSyscall::<P256>::new()));
+
+    syscall_map
+        .insert(SyscallCode::P256_DOUBLE, Arc::new(WeierstrassDoubleAssignSyscall::<P256>::new()));
+
+    syscall_map.insert(SyscallCode::P256_MUL, Arc::new(WeierstrassMulAssignSyscall::<P256>::new()));
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_DECOMPRESS,
+        Arc::new(WeierstrassDecompressSyscall::<P256>::new()),
+    );
+
+    syscall_map.insert(SyscallCode::P384_ADD, Arc::new(WeierstrassAddAssignSyscall::<P384>::new()));
+
+    syscall_map
+        .insert(SyscallCode::P384_DOUBLE, Arc::new(WeierstrassDoubleAssignSyscall::<P384>::new()));
+
+    syscall_map.insert(
+        SyscallCode::SECP384R1_DECOMPRESS,
+        Arc::new(WeierstrassDecompressSyscall::<P384>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::GRUMPKIN_ADD,
+        Arc::new(WeierstrassAddAssignSyscall::<Grumpkin>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::GRUMPKIN_DOUBLE,
+        Arc::new(WeierstrassDoubleAssignSyscall::<Grumpkin>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BABYJUBJUB_ADD,
+        Arc::new(EdwardsAddAssignSyscall::<BabyJubJub>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BABYJUBJUB_DOUBLE,
+        Arc::new(EdwardsDoubleAssignSyscall::<BabyJubJub>::new()),
+    );
+
+    syscall_map.insert(SyscallCode::UINT256_MUL, Arc::new(UintMulSyscall::<U256Field>::new()));
+
+    syscall_map.insert(SyscallCode::UINT384_MUL, Arc::new(UintMulSyscall::<U384Field>::new()));
+
+    syscall_map.insert(SyscallCode::UINT512_MUL, Arc::new(UintMulSyscall::<U512Field>::new()));
+
+    syscall_map.insert(SyscallCode::UINT256_INV_MOD, Arc::new(Uint256InvModSyscall));
+
+    syscall_map.insert(SyscallCode::UINT256_SQUARE_MOD, Arc::new(Uint256SquareModSyscall));
+
+    syscall_map.insert(SyscallCode::UINT256_MUL_WIDE, Arc::new(Uint256MulWideSyscall));
+
+    syscall_map.insert(SyscallCode::UINT256_DIVREM, Arc::new(Uint256DivRemSyscall));
+
+    syscall_map.insert(SyscallCode::UINT256_MONT_MUL, Arc::new(Uint256MontMulSyscall));
+
+    syscall_map.insert(SyscallCode::UINT256_MOD_EXP, Arc::new(Uint256ModExpSyscall));
+
+    syscall_map.insert(SyscallCode::UINT256_RANGE_CHECK, Arc::new(Uint256RangeCheckSyscall));
+
+    syscall_map.insert(SyscallCode::UINT256_CMP, Arc::new(Uint256CmpSyscall));
+
+    syscall_map
+        .insert(SyscallCode::UINT256_AND, Arc::new(Uint256BitwiseSyscall::new(ByteOpcode::AND)));
+
+    syscall_map
+        .insert(SyscallCode::UINT256_OR, Arc::new(Uint256BitwiseSyscall::new(ByteOpcode::OR)));
+
+    syscall_map
+        .insert(SyscallCode::UINT256_XOR, Arc::new(Uint256BitwiseSyscall::new(ByteOpcode::XOR)));
+
+    syscall_map.insert(SyscallCode::MERKLE_VERIFY_PATH, Arc::new(MerkleVerifyPathSyscall));
+
+    syscall_map.insert(SyscallCode::FRI_DECOMMIT, Arc::new(FriDecommitSyscall));
+
+    syscall_map.insert(SyscallCode::UINT256_ADD_MOD, Arc::new(Uint256AddModSyscall));
+
+    syscall_map.insert(SyscallCode::UINT256_SUB_MOD, Arc::new(Uint256SubModSyscall));
 
     syscall_map.insert(
         SyscallCode::BLS12381_FP_ADD,
@@ -150,6 +308,14 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
     syscall_map
         .insert(SyscallCode::BLS12381_FP2_MUL, Arc::new(Fp2MulSyscall::<Bls12381BaseField>::new()));
 
+    syscall_map
+        .insert(SyscallCode::BLS12381_G2_ADD, Arc::new(G2AddSyscall::<Bls12381BaseField>::new()));
+
+    syscall_map.insert(
+        SyscallCode::BLS12381_G2_DOUBLE,
+        Arc::new(G2DoubleSyscall::<Bls12381BaseField>::new()),
+    );
+
     syscall_map.insert(
         SyscallCode::BN254_FP_ADD,
         Arc::new(FpOpSyscall::<Bn254BaseField>::new(FieldOperation::Add)),
@@ -165,6 +331,53 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(FpOpSyscall::<Bn254BaseField>::new(FieldOperation::Mul)),
     );
 
+    syscall_map.insert(
+        SyscallCode::BN254_FR_ADD,
+        Arc::new(FpOpSyscall::<Bn254ScalarField>::new(FieldOperation::Add)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BN254_FR_SUB,
+        Arc::new(FpOpSyscall::<Bn254ScalarField>::new(FieldOperation::Sub)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BN254_FR_MUL,
+        Arc::new(FpOpSyscall::<Bn254ScalarField>::new(FieldOperation::Mul)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP_BATCH_INV,
+        Arc::new(BatchFieldInverseSyscall::<Bls12381BaseField>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BN254_FP_BATCH_INV,
+        Arc::new(BatchFieldInverseSyscall::<Bn254BaseField>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BN254_FR_BATCH_INV,
+        Arc::new(BatchFieldInverseSyscall::<Bn254ScalarField>::new()),
+    );
+
+    syscall_map.insert(SyscallCode::NTT, Arc::new(NttSyscall::ntt()));
+
+    syscall_map.insert(SyscallCode::INTT, Arc::new(NttSyscall::intt()));
+
+    syscall_map.insert(SyscallCode::SHA256_HASH, Arc::new(Sha256HashSyscall));
+
+    syscall_map.insert(SyscallCode::POLY_EVAL, Arc::new(PolyEvalSyscall));
+
+    syscall_map.insert(SyscallCode::POLY_MULTI_EVAL, Arc::new(PolyMultiEvalSyscall));
+
+    syscall_map.insert(SyscallCode::LAGRANGE_INTERP, Arc::new(LagrangeInterpSyscall));
+
+    syscall_map.insert(
+        SyscallCode::EC_LINEAR_COMBINATION,
+        Arc::new(WeierstrassLinearCombinationSyscall::<Secp256k1>::new()),
+    );
+
     syscall_map.insert(
         SyscallCode::BN254_FP2_ADD,
         Arc::new(Fp2AddSubSyscall::<Bn254BaseField>::new(FieldOperation::Add)),
@@ -178,6 +391,11 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
     syscall_map
         .insert(SyscallCode::BN254_FP2_MUL, Arc::new(Fp2MulSyscall::<Bn254BaseField>::new()));
 
+    syscall_map.insert(SyscallCode::BN254_G2_ADD, Arc::new(G2AddSyscall::<Bn254BaseField>::new()));
+
+    syscall_map
+        .insert(SyscallCode::BN254_G2_DOUBLE, Arc::new(G2DoubleSyscall::<Bn254BaseField>::new()));
+
     syscall_map.insert(SyscallCode::ENTER_UNCONSTRAINED, Arc::new(EnterUnconstrainedSyscall));
 
     syscall_map.insert(SyscallCode::EXIT_UNCONSTRAINED, Arc::new(ExitUnconstrainedSyscall));
@@ -194,6 +412,10 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
 
     syscall_map.insert(SyscallCode::HINT_READ, Arc::new(HintReadSyscall));
 
+    syscall_map.insert(SyscallCode::CYCLE_TRACKER_START, Arc::new(CycleTrackerStartSyscall));
+
+    syscall_map.insert(SyscallCode::CYCLE_TRACKER_END, Arc::new(CycleTrackerEndSyscall));
+
     syscall_map.insert(
         SyscallCode::BLS12381_DECOMPRESS,
         Arc::new(WeierstrassDecompressSyscall::<Bls12381>::new()),
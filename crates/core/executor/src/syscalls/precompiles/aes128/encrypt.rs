@@ -0,0 +1,59 @@
+use sp1_primitives::{
+    aes::aes128_encrypt,
+    consts::{bytes_to_words_le, words_to_bytes_le},
+};
+
+use crate::{
+    events::{Aes128EncryptEvent, AES128_BLOCK_NUM_WORDS},
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct Aes128EncryptSyscall;
+
+impl Syscall for Aes128EncryptSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let block_ptr = arg1;
+        let key_ptr = arg2;
+        if !rt.check_word_aligned(block_ptr) || !rt.check_word_aligned(key_ptr) {
+            return None;
+        }
+
+        let (key_memory_records, key) = rt.mr_slice(key_ptr, AES128_BLOCK_NUM_WORDS);
+        let key: [u32; AES128_BLOCK_NUM_WORDS] = key.try_into().unwrap();
+
+        // Read the plaintext. We can read a slice_unsafe here because we write the ciphertext
+        // over it later.
+        let plaintext: [u32; AES128_BLOCK_NUM_WORDS] =
+            rt.slice_unsafe(block_ptr, AES128_BLOCK_NUM_WORDS).try_into().unwrap();
+
+        let ciphertext = aes128_encrypt(&words_to_bytes_le(&key), &words_to_bytes_le(&plaintext));
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let block_memory_records =
+            rt.mw_slice(block_ptr, &bytes_to_words_le::<AES128_BLOCK_NUM_WORDS>(&ciphertext));
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().aes128_encrypt_events.push(Aes128EncryptEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            block_ptr,
+            key_ptr,
+            plaintext,
+            key,
+            block_memory_records,
+            key_memory_records,
+        });
+
+        None
+    }
+}
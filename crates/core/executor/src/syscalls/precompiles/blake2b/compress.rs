@@ -0,0 +1,155 @@
+use crate::{
+    events::Blake2bCompressEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The number of words in the Blake2b state, with each u64 split into two u32 words.
+pub const BLAKE2B_STATE_NUM_WORDS: usize = 16;
+
+/// The number of words in a Blake2b block: the 32-word message, the 4-word byte counter, and the
+/// final block flag.
+pub const BLAKE2B_BLOCK_NUM_WORDS: usize = 37;
+
+/// The number of rounds in the Blake2b compression function.
+pub const BLAKE2B_NUM_ROUNDS: usize = 12;
+
+/// The Blake2b initialization vector.
+pub const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// The message schedule. Round `r` uses the row `r % 10`.
+pub const BLAKE2B_SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// The state indices mixed by each of the eight `G` calls in a round: four columns followed by
+/// four diagonals.
+pub const BLAKE2B_G_INDICES: [[usize; 4]; 8] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+/// The Blake2b mixing function.
+pub fn blake2b_g(input: [u64; 4], mx: u64, my: u64) -> [u64; 4] {
+    let [mut a, mut b, mut c, mut d] = input;
+    a = a.wrapping_add(b).wrapping_add(mx);
+    d = (d ^ a).rotate_right(32);
+    c = c.wrapping_add(d);
+    b = (b ^ c).rotate_right(24);
+    a = a.wrapping_add(b).wrapping_add(my);
+    d = (d ^ a).rotate_right(16);
+    c = c.wrapping_add(d);
+    b = (b ^ c).rotate_right(63);
+    [a, b, c, d]
+}
+
+/// Applies one round of Blake2b to the working vector with the given message words, already
+/// permuted by the round's row of [`BLAKE2B_SIGMA`].
+pub fn blake2b_round(v: &mut [u64; 16], message: &[u64; 16]) {
+    for (i, indices) in BLAKE2B_G_INDICES.iter().enumerate() {
+        let input = indices.map(|j| v[j]);
+        let output = blake2b_g(input, message[2 * i], message[2 * i + 1]);
+        for (&j, value) in indices.iter().zip(output) {
+            v[j] = value;
+        }
+    }
+}
+
+/// The Blake2b compression function `F` from RFC 7693.
+pub fn blake2b_compress(h: &[u64; 8], m: &[u64; 16], t: [u64; 2], last: bool) -> [u64; 8] {
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&BLAKE2B_IV);
+    v[12] ^= t[0];
+    v[13] ^= t[1];
+    if last {
+        v[14] = !v[14];
+    }
+    for r in 0..BLAKE2B_NUM_ROUNDS {
+        let message = BLAKE2B_SIGMA[r % 10].map(|i| m[i]);
+        blake2b_round(&mut v, &message);
+    }
+    core::array::from_fn(|i| h[i] ^ v[i] ^ v[i + 8])
+}
+
+/// Joins little endian pairs of u32 words into u64 words.
+pub fn words_to_u64s<const N: usize>(words: &[u32]) -> [u64; N] {
+    core::array::from_fn(|i| words[2 * i] as u64 | (words[2 * i + 1] as u64) << 32)
+}
+
+pub(crate) struct Blake2bCompressSyscall;
+
+impl Syscall for Blake2bCompressSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let state_ptr = arg1;
+        let block_ptr = arg2;
+        if !rt.check_word_aligned(state_ptr) || !rt.check_word_aligned(block_ptr) {
+            return None;
+        }
+
+        let (state_read_records, state) = rt.mr_slice(state_ptr, BLAKE2B_STATE_NUM_WORDS);
+        let (block_read_records, block) = rt.mr_slice(block_ptr, BLAKE2B_BLOCK_NUM_WORDS);
+        let state: [u32; BLAKE2B_STATE_NUM_WORDS] = state.try_into().unwrap();
+        let block: [u32; BLAKE2B_BLOCK_NUM_WORDS] = block.try_into().unwrap();
+
+        let flag = block[BLAKE2B_BLOCK_NUM_WORDS - 1];
+        assert!(flag <= 1, "blake2b final block flag must be 0 or 1, got {flag}");
+
+        let h = words_to_u64s::<8>(&state);
+        let m = words_to_u64s::<16>(&block[..32]);
+        let t = words_to_u64s::<2>(&block[32..36]);
+        let result = blake2b_compress(&h, &m, t, flag == 1);
+        let result = result.iter().flat_map(|&x| [x as u32, (x >> 32) as u32]).collect::<Vec<_>>();
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        rt.clk += 1;
+        let state_write_records = rt.mw_slice(state_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().blake2b_compress_events.push(Blake2bCompressEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk: start_clk,
+            state_ptr,
+            block_ptr,
+            state,
+            block,
+            state_read_records,
+            state_write_records,
+            block_read_records,
+        });
+
+        None
+    }
+}
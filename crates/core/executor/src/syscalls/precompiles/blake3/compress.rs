@@ -0,0 +1,105 @@
+use crate::{
+    events::Blake3CompressEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The number of words in the Blake3 state and message block.
+pub const BLAKE3_NUM_WORDS: usize = 16;
+
+/// The number of rounds in the Blake3 compression function.
+pub const BLAKE3_NUM_ROUNDS: usize = 7;
+
+/// The permutation applied to the message words between rounds.
+pub const BLAKE3_MSG_PERMUTATION: [usize; BLAKE3_NUM_WORDS] =
+    [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// The state indices mixed by each of the eight `G` calls in a round: four columns followed by
+/// four diagonals.
+pub const BLAKE3_G_INDICES: [[usize; 4]; 8] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+/// The Blake3 mixing function.
+pub fn blake3_g(input: [u32; 4], mx: u32, my: u32) -> [u32; 4] {
+    let [mut a, mut b, mut c, mut d] = input;
+    a = a.wrapping_add(b).wrapping_add(mx);
+    d = (d ^ a).rotate_right(16);
+    c = c.wrapping_add(d);
+    b = (b ^ c).rotate_right(12);
+    a = a.wrapping_add(b).wrapping_add(my);
+    d = (d ^ a).rotate_right(8);
+    c = c.wrapping_add(d);
+    b = (b ^ c).rotate_right(7);
+    [a, b, c, d]
+}
+
+/// Applies one round of Blake3 to the state with the given message words.
+pub fn blake3_round(state: &mut [u32; BLAKE3_NUM_WORDS], message: &[u32; BLAKE3_NUM_WORDS]) {
+    for (i, indices) in BLAKE3_G_INDICES.iter().enumerate() {
+        let input = indices.map(|j| state[j]);
+        let output = blake3_g(input, message[2 * i], message[2 * i + 1]);
+        for (&j, value) in indices.iter().zip(output) {
+            state[j] = value;
+        }
+    }
+}
+
+pub(crate) struct Blake3CompressSyscall;
+
+impl Syscall for Blake3CompressSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let state_ptr = arg1;
+        let message_ptr = arg2;
+        if !rt.check_word_aligned(state_ptr) || !rt.check_word_aligned(message_ptr) {
+            return None;
+        }
+
+        let (state_read_records, state) = rt.mr_slice(state_ptr, BLAKE3_NUM_WORDS);
+        let (message_read_records, message) = rt.mr_slice(message_ptr, BLAKE3_NUM_WORDS);
+        let state: [u32; BLAKE3_NUM_WORDS] = state.try_into().unwrap();
+        let message: [u32; BLAKE3_NUM_WORDS] = message.try_into().unwrap();
+
+        // Run the rounds, permuting the message words between them.
+        let mut result = state;
+        let mut round_message = message;
+        for _ in 0..BLAKE3_NUM_ROUNDS {
+            blake3_round(&mut result, &round_message);
+            round_message = BLAKE3_MSG_PERMUTATION.map(|i| round_message[i]);
+        }
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        rt.clk += 1;
+        let state_write_records = rt.mw_slice(state_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().blake3_compress_events.push(Blake3CompressEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk: start_clk,
+            state_ptr,
+            message_ptr,
+            state,
+            message,
+            state_read_records,
+            state_write_records,
+            message_read_records,
+        });
+
+        None
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    events::ChaCha20BlockEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The number of words in the ChaCha20 state.
+pub const CHACHA20_NUM_WORDS: usize = 16;
+
+/// The number of double rounds in the ChaCha20 block function.
+pub const CHACHA20_NUM_DOUBLE_ROUNDS: usize = 10;
+
+/// The state indices mixed by each of the eight quarter rounds in a double round: four columns
+/// followed by four diagonals.
+pub const CHACHA20_QUARTER_ROUND_INDICES: [[usize; 4]; 8] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+/// The ChaCha20 quarter round.
+pub fn chacha20_quarter_round(input: [u32; 4]) -> [u32; 4] {
+    let [mut a, mut b, mut c, mut d] = input;
+    a = a.wrapping_add(b);
+    d = (d ^ a).rotate_left(16);
+    c = c.wrapping_add(d);
+    b = (b ^ c).rotate_left(12);
+    a = a.wrapping_add(b);
+    d = (d ^ a).rotate_left(8);
+    c = c.wrapping_add(d);
+    b = (b ^ c).rotate_left(7);
+    [a, b, c, d]
+}
+
+/// Applies one double round of ChaCha20 to the state.
+pub fn chacha20_double_round(state: &mut [u32; CHACHA20_NUM_WORDS]) {
+    for indices in CHACHA20_QUARTER_ROUND_INDICES.iter() {
+        let output = chacha20_quarter_round(indices.map(|j| state[j]));
+        for (&j, value) in indices.iter().zip(output) {
+            state[j] = value;
+        }
+    }
+}
+
+/// Computes the keystream block for the given state: the 20 rounds followed by the addition of the
+/// input state.
+pub fn chacha20_block(state: &[u32; CHACHA20_NUM_WORDS]) -> [u32; CHACHA20_NUM_WORDS] {
+    let mut result = *state;
+    for _ in 0..CHACHA20_NUM_DOUBLE_ROUNDS {
+        chacha20_double_round(&mut result);
+    }
+    core::array::from_fn(|i| result[i].wrapping_add(state[i]))
+}
+
+pub(crate) struct ChaCha20BlockSyscall;
+
+impl Syscall for ChaCha20BlockSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, _: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let state_ptr = arg1;
+        if !rt.check_word_aligned(state_ptr) {
+            return None;
+        }
+
+        let (state_read_records, state) = rt.mr_slice(state_ptr, CHACHA20_NUM_WORDS);
+        let state: [u32; CHACHA20_NUM_WORDS] = state.try_into().unwrap();
+        let keystream = chacha20_block(&state);
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        rt.clk += 1;
+        let state_write_records = rt.mw_slice(state_ptr, &keystream);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().chacha20_block_events.push(ChaCha20BlockEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk: start_clk,
+            state_ptr,
+            state,
+            state_read_records,
+            state_write_records,
+        });
+
+        None
+    }
+}
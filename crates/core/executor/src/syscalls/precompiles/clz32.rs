@@ -0,0 +1,36 @@
+use crate::{
+    events::Clz32Event,
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct Clz32Syscall;
+
+impl Syscall for Clz32Syscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let ptr = arg1;
+        assert!(arg2 == 0, "arg2 must be 0");
+        if !rt.check_word_aligned(ptr) {
+            return None;
+        }
+
+        // Read the word. We can read it unsafely here because we write the count over it.
+        let value = rt.word_unsafe(ptr);
+        let memory_record = rt.mw(ptr, value.leading_zeros());
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().clz32_events.push(Clz32Event {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            ptr,
+            value,
+            memory_record,
+        });
+
+        None
+    }
+}
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use sp1_curves::{edwards::EdwardsParameters, EllipticCurve};
+use sp1_curves::{edwards::EdwardsParameters, CurveType, EllipticCurve};
 
 use crate::{
     events::create_ec_add_event,
@@ -25,7 +25,11 @@ impl<E: EllipticCurve + EdwardsParameters> Syscall for EdwardsAddAssignSyscall<E
 
     fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
         let event = create_ec_add_event::<E>(rt, arg1, arg2);
-        rt.record_mut().ed_add_events.push(event);
+        match E::CURVE_TYPE {
+            CurveType::Ed25519 => rt.record_mut().ed_add_events.push(event),
+            CurveType::BabyJubJub => rt.record_mut().babyjubjub_add_events.push(event),
+            _ => panic!("Unsupported curve"),
+        }
         None
     }
 }
@@ -2,13 +2,13 @@ use std::marker::PhantomData;
 
 use sp1_curves::{
     curve25519_dalek::CompressedEdwardsY,
-    edwards::{ed25519::decompress, EdwardsParameters, WORDS_FIELD_ELEMENT},
-    COMPRESSED_POINT_BYTES,
+    edwards::{ed25519::decompress_strict, EdwardsParameters, WORDS_FIELD_ELEMENT},
+    COMPRESSED_POINT_BYTES, NUM_BYTES_FIELD_ELEMENT,
 };
 use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le};
 
 use crate::{
-    events::{EdDecompressEvent, MemoryReadRecord, MemoryWriteRecord},
+    events::EdDecompressEvent,
     syscalls::{Syscall, SyscallContext},
 };
 
@@ -27,36 +27,45 @@ impl<E: EdwardsParameters> Syscall for EdwardsDecompressSyscall<E> {
     fn execute(&self, rt: &mut SyscallContext, arg1: u32, sign: u32) -> Option<u32> {
         let start_clk = rt.clk;
         let slice_ptr = arg1;
-        assert!(slice_ptr % 4 == 0, "Pointer must be 4-byte aligned.");
+        if !rt.check_word_aligned(slice_ptr) {
+            return None;
+        }
         assert!(sign <= 1, "Sign bit must be 0 or 1.");
 
-        let (y_memory_records_vec, y_vec) =
-            rt.mr_slice(slice_ptr + (COMPRESSED_POINT_BYTES as u32), WORDS_FIELD_ELEMENT);
-        let y_memory_records: [MemoryReadRecord; 8] = y_memory_records_vec.try_into().unwrap();
+        let (y_memory_records, y_words) =
+            rt.mr_array::<WORDS_FIELD_ELEMENT>(slice_ptr + (COMPRESSED_POINT_BYTES as u32));
 
         let sign_bool = sign != 0;
 
-        let y_bytes: [u8; COMPRESSED_POINT_BYTES] = words_to_bytes_le(&y_vec);
+        let y_bytes: [u8; COMPRESSED_POINT_BYTES] = words_to_bytes_le(&y_words);
 
-        // Copy bytes into another array so we can modify the last byte and make CompressedEdwardsY,
-        // which we'll use to compute the expected X.
-        // Re-insert sign bit into last bit of Y for CompressedEdwardsY format
+        // Re-insert the sign bit into the last bit of Y for the CompressedEdwardsY format. The
+        // top bit of Y must be clear, as a set bit would make it at least 2^255.
         let mut compressed_edwards_y: [u8; COMPRESSED_POINT_BYTES] = y_bytes;
-        compressed_edwards_y[compressed_edwards_y.len() - 1] &= 0b0111_1111;
-        compressed_edwards_y[compressed_edwards_y.len() - 1] |= (sign as u8) << 7;
+        let y_top_bit_set = compressed_edwards_y[COMPRESSED_POINT_BYTES - 1] >> 7 == 1;
+        compressed_edwards_y[COMPRESSED_POINT_BYTES - 1] &= 0b0111_1111;
+        compressed_edwards_y[COMPRESSED_POINT_BYTES - 1] |= (sign as u8) << 7;
 
-        // Compute actual decompressed X
-        let compressed_y = CompressedEdwardsY(compressed_edwards_y);
-        let decompressed = decompress(&compressed_y);
-
-        let mut decompressed_x_bytes = decompressed.x.to_bytes_le();
-        decompressed_x_bytes.resize(32, 0u8);
+        // An encoding that is not a valid point is reported to the guest by writing an X with
+        // every bit set, which is never a reduced field element.
+        let decompressed = if y_top_bit_set {
+            None
+        } else {
+            decompress_strict(&CompressedEdwardsY(compressed_edwards_y))
+        };
+        let decompressed_x_bytes = match decompressed {
+            Some(point) => {
+                let mut bytes = point.x.to_bytes_le();
+                bytes.resize(NUM_BYTES_FIELD_ELEMENT, 0u8);
+                bytes
+            }
+            None => vec![0xff; NUM_BYTES_FIELD_ELEMENT],
+        };
         let decompressed_x_words: [u32; WORDS_FIELD_ELEMENT] =
             bytes_to_words_le(&decompressed_x_bytes);
 
         // Write decompressed X into slice
-        let x_memory_records_vec = rt.mw_slice(slice_ptr, &decompressed_x_words);
-        let x_memory_records: [MemoryWriteRecord; 8] = x_memory_records_vec.try_into().unwrap();
+        let x_memory_records = rt.mw_array(slice_ptr, &decompressed_x_words);
 
         let lookup_id = rt.syscall_lookup_id;
         let shard = rt.current_shard();
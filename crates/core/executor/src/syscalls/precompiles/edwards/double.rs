@@ -0,0 +1,30 @@
+use std::marker::PhantomData;
+
+use sp1_curves::{edwards::EdwardsParameters, CurveType, EllipticCurve};
+
+use crate::{
+    events::create_ec_double_event,
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct EdwardsDoubleAssignSyscall<E: EllipticCurve + EdwardsParameters> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EllipticCurve + EdwardsParameters> EdwardsDoubleAssignSyscall<E> {
+    /// Create a new instance of the [`EdwardsDoubleAssignSyscall`].
+    pub const fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<E: EllipticCurve + EdwardsParameters> Syscall for EdwardsDoubleAssignSyscall<E> {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let event = create_ec_double_event::<E>(rt, arg1, arg2);
+        match E::CURVE_TYPE {
+            CurveType::BabyJubJub => rt.record_mut().babyjubjub_double_events.push(event),
+            _ => panic!("Unsupported curve"),
+        }
+        None
+    }
+}
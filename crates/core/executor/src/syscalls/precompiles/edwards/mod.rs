@@ -1,2 +1,5 @@
 pub mod add;
 pub mod decompress;
+pub mod double;
+pub mod verify;
+pub mod x25519;
@@ -0,0 +1,70 @@
+use num::BigUint;
+use sp1_curves::{
+    edwards::{
+        ed25519::{Ed25519, Ed25519BaseField},
+        EdwardsParameters,
+    },
+    params::FieldParameters,
+    AffinePoint,
+};
+
+use crate::{
+    events::{ed25519_verify, Ed25519VerifyEvent, ED25519_VERIFY_INPUT_WORDS},
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct Ed25519VerifySyscall;
+
+impl Syscall for Ed25519VerifySyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, _: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let ptr = arg1;
+        if !rt.check_word_aligned(ptr) {
+            return None;
+        }
+
+        // The input is `S`, `k`, the public key and `R`, followed by the result.
+        let (input_memory_records, input) = rt.mr_slice(ptr, ED25519_VERIFY_INPUT_WORDS);
+        let [s, k, a_x, a_y, r_x, r_y] =
+            core::array::from_fn(|i| BigUint::from_slice(&input[8 * i..8 * (i + 1)]));
+
+        // The public key must be reduced and satisfy `-x^2 + y^2 = 1 + d * x^2 * y^2`, so that the
+        // complete addition law never divides by zero. Otherwise the signature is invalid.
+        let modulus = Ed25519BaseField::modulus();
+        let is_reduced = a_x < modulus && a_y < modulus;
+        let (xx, yy) = (&a_x * &a_x % &modulus, &a_y * &a_y % &modulus);
+        let lhs = (&yy + &modulus - &xx) % &modulus;
+        let rhs = (Ed25519::d_biguint() * xx * yy + 1u32) % &modulus;
+        let is_on_curve = is_reduced && lhs == rhs;
+
+        let pubkey = AffinePoint::<Ed25519>::new(a_x, a_y);
+        let r = AffinePoint::<Ed25519>::new(r_x, r_y);
+        let is_valid = is_on_curve && ed25519_verify(&s, &k, &pubkey, &r);
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        rt.clk += 1;
+        let result_memory_record =
+            rt.mw(ptr + ED25519_VERIFY_INPUT_WORDS as u32 * 4, is_valid as u32);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().ed25519_verify_events.push(Ed25519VerifyEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk: start_clk,
+            ptr,
+            input,
+            is_valid,
+            input_memory_records,
+            result_memory_record,
+        });
+
+        None
+    }
+}
@@ -0,0 +1,65 @@
+use num::BigUint;
+use sp1_curves::edwards::WORDS_FIELD_ELEMENT;
+
+use crate::{
+    events::{x25519_ladder_step, X25519LadderStepEvent, X25519_LADDER_STATE_WORDS},
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct X25519LadderStepSyscall;
+
+impl Syscall for X25519LadderStepSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let state_ptr = arg1;
+        let u_ptr = arg2;
+        if !rt.check_word_aligned(state_ptr) || !rt.check_word_aligned(u_ptr) {
+            return None;
+        }
+
+        let state = rt.slice_unsafe(state_ptr, X25519_LADDER_STATE_WORDS);
+        let (u_memory_records, u) = rt.mr_slice(u_ptr, WORDS_FIELD_ELEMENT);
+        rt.clk += 1;
+
+        let x1 = BigUint::from_slice(&u);
+        let coordinates = state
+            .chunks_exact(WORDS_FIELD_ELEMENT)
+            .map(BigUint::from_slice)
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        // Each coordinate is padded on its own so that they always start at a fixed offset.
+        let result = x25519_ladder_step(&x1, coordinates)
+            .iter()
+            .flat_map(|c| {
+                let mut words = c.to_u32_digits();
+                words.resize(WORDS_FIELD_ELEMENT, 0);
+                words
+            })
+            .collect::<Vec<u32>>();
+
+        let state_memory_records = rt.mw_slice(state_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().x25519_ladder_step_events.push(X25519LadderStepEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            state_ptr,
+            state,
+            u_ptr,
+            u,
+            state_memory_records,
+            u_memory_records,
+        });
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -0,0 +1,113 @@
+use num::{BigUint, One, Zero};
+use sp1_curves::{
+    params::NumWords,
+    weierstrass::{FieldType, FpOpField},
+};
+use std::marker::PhantomData;
+use typenum::Unsigned;
+
+use crate::{
+    events::BatchFieldInverseEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The maximum number of elements of a batch inversion.
+pub const BATCH_INVERSE_MAX_LEN: u32 = 64;
+
+/// Inverts each of the elements with Montgomery's trick, which takes a single inversion of the
+/// product of the elements. Zero elements are skipped in the product, and their inverse is zero.
+///
+/// The elements must be reduced modulo the prime `modulus`.
+pub fn batch_inverse(elements: &[BigUint], modulus: &BigUint) -> Vec<BigUint> {
+    // The prefix products, with the zero elements counted as one.
+    let mut prefix = Vec::with_capacity(elements.len());
+    let mut product = BigUint::one();
+    for x in elements {
+        prefix.push(product.clone());
+        if !x.is_zero() {
+            product = (product * x) % modulus;
+        }
+    }
+
+    // The inverse of each suffix product, going down from the inverse of the full product.
+    let mut suffix_inv = product.modpow(&(modulus - 2u32), modulus);
+    let mut inverses = vec![BigUint::zero(); elements.len()];
+    for (i, x) in elements.iter().enumerate().rev() {
+        if !x.is_zero() {
+            inverses[i] = (&suffix_inv * &prefix[i]) % modulus;
+            suffix_inv = (suffix_inv * x) % modulus;
+        }
+    }
+    inverses
+}
+
+pub struct BatchFieldInverseSyscall<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P> BatchFieldInverseSyscall<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+impl<P: FpOpField> Syscall for BatchFieldInverseSyscall<P> {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let ptr = arg1;
+        let len = arg2;
+        if !rt.check_word_aligned(ptr) {
+            return None;
+        }
+        assert!(
+            (1..=BATCH_INVERSE_MAX_LEN).contains(&len),
+            "the number of elements must be between 1 and 64"
+        );
+
+        let num_words = <P as NumWords>::WordsFieldElement::USIZE;
+
+        let values = rt.slice_unsafe(ptr, len as usize * num_words);
+
+        let modulus = &BigUint::from_bytes_le(P::MODULUS);
+        let elements = values
+            .chunks_exact(num_words)
+            .map(|words| {
+                let x = BigUint::from_slice(words);
+                assert!(&x < modulus, "the elements must be reduced");
+                x
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = Vec::with_capacity(values.len());
+        for inverse in batch_inverse(&elements, modulus) {
+            let mut words = inverse.to_u32_digits();
+            words.resize(num_words, 0);
+            result.extend(words);
+        }
+
+        rt.clk += 1;
+        let memory_records = rt.mw_slice(ptr, &result);
+
+        let event = BatchFieldInverseEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk,
+            ptr,
+            len,
+            values,
+            memory_records,
+        };
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => rt.record_mut().bn254_fp_batch_inverse_events.push(event),
+            FieldType::Bls12381 => rt.record_mut().bls12381_fp_batch_inverse_events.push(event),
+            FieldType::Bn254Scalar => rt.record_mut().bn254_fr_batch_inverse_events.push(event),
+        }
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -26,12 +26,9 @@ impl<P: FpOpField> Syscall for FpOpSyscall<P> {
     fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
         let clk = rt.clk;
         let x_ptr = arg1;
-        if x_ptr % 4 != 0 {
-            panic!();
-        }
         let y_ptr = arg2;
-        if y_ptr % 4 != 0 {
-            panic!();
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(y_ptr) {
+            return None;
         }
 
         let num_words = <P as NumWords>::WordsFieldElement::USIZE;
@@ -89,6 +86,21 @@ impl<P: FpOpField> Syscall for FpOpSyscall<P> {
                     y_memory_records,
                 });
             }
+            FieldType::Bn254Scalar => {
+                rt.record_mut().bn254_fr_events.push(FpOpEvent {
+                    lookup_id,
+                    shard,
+                    channel,
+                    clk,
+                    x_ptr,
+                    x,
+                    y_ptr,
+                    y,
+                    op: self.op,
+                    x_memory_records,
+                    y_memory_records,
+                });
+            }
         }
 
         None
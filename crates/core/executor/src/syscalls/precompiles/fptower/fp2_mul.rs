@@ -26,12 +26,9 @@ impl<P: FpOpField> Syscall for Fp2MulSyscall<P> {
     fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
         let clk = rt.clk;
         let x_ptr = arg1;
-        if x_ptr % 4 != 0 {
-            panic!();
-        }
         let y_ptr = arg2;
-        if y_ptr % 4 != 0 {
-            panic!();
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(y_ptr) {
+            return None;
         }
 
         let num_words = <P as NumWords>::WordsCurvePoint::USIZE;
@@ -56,10 +53,16 @@ impl<P: FpOpField> Syscall for Fp2MulSyscall<P> {
         };
         let c1 = ((ac0 * bc1) % modulus + (ac1 * bc0) % modulus) % modulus;
 
-        let mut result =
-            c0.to_u32_digits().into_iter().chain(c1.to_u32_digits()).collect::<Vec<u32>>();
+        // Each coefficient is padded on its own so that `c1` always starts halfway through.
+        let result = [c0, c1]
+            .iter()
+            .flat_map(|c| {
+                let mut words = c.to_u32_digits();
+                words.resize(num_words / 2, 0);
+                words
+            })
+            .collect::<Vec<u32>>();
 
-        result.resize(num_words, 0);
         let x_memory_records = rt.mw_slice(x_ptr, &result);
 
         let lookup_id = rt.syscall_lookup_id;
@@ -90,6 +93,7 @@ impl<P: FpOpField> Syscall for Fp2MulSyscall<P> {
                 x_memory_records,
                 y_memory_records,
             }),
+            _ => panic!("Unsupported field"),
         };
         None
     }
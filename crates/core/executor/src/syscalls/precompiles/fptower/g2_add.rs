@@ -0,0 +1,143 @@
+use std::marker::PhantomData;
+
+use num::{BigUint, Zero};
+use sp1_curves::{
+    params::NumWords,
+    weierstrass::{FieldType, FpOpField},
+};
+use typenum::Unsigned;
+
+use crate::{
+    events::EllipticCurveAddEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// An element `c0 + c1 * u` of `Fp2 = Fp[u]/(u^2 + 1)`.
+pub(super) type Fp2 = [BigUint; 2];
+
+pub(crate) struct G2AddSyscall<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P> G2AddSyscall<P> {
+    /// Create a new instance of the [`G2AddSyscall`].
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+impl<P: FpOpField> Syscall for G2AddSyscall<P> {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let p_ptr = arg1;
+        let q_ptr = arg2;
+        if !rt.check_word_aligned(p_ptr) || !rt.check_word_aligned(q_ptr) {
+            return None;
+        }
+
+        // A G2 point is two Fp2 coordinates, each of which is two base field elements.
+        let num_words = 2 * <P as NumWords>::WordsCurvePoint::USIZE;
+
+        let p = rt.slice_unsafe(p_ptr, num_words);
+        let (q_memory_records, q) = rt.mr_slice(q_ptr, num_words);
+
+        // When we write to p, we want the clk to be incremented because p and q could be the same.
+        rt.clk += 1;
+
+        let result_words = g2_add(&P::modulus(), &p, &q);
+        let p_memory_records = rt.mw_slice(p_ptr, &result_words);
+
+        let event = EllipticCurveAddEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk: start_clk,
+            p_ptr,
+            p,
+            q_ptr,
+            q,
+            p_memory_records,
+            q_memory_records,
+        };
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => rt.record_mut().bn254_g2_add_events.push(event),
+            FieldType::Bls12381 => rt.record_mut().bls12381_g2_add_events.push(event),
+            _ => panic!("Unsupported field"),
+        }
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+/// Adds two affine G2 points given as little endian words `[x.c0, x.c1, y.c0, y.c1]`.
+///
+/// As with the G1 precompiles, the points must be distinct, not inverses of each other, and not
+/// the point at infinity.
+fn g2_add(modulus: &BigUint, p: &[u32], q: &[u32]) -> Vec<u32> {
+    let (p_x, p_y) = decode_g2(p);
+    let (q_x, q_y) = decode_g2(q);
+
+    let denominator = fp2_sub(modulus, &q_x, &p_x);
+    assert!(
+        !(denominator[0].is_zero() && denominator[1].is_zero()),
+        "the points must have distinct x coordinates"
+    );
+
+    // slope = (q.y - p.y) / (q.x - p.x).
+    let slope = fp2_mul(modulus, &fp2_sub(modulus, &q_y, &p_y), &fp2_inv(modulus, &denominator));
+    // x = slope * slope - (p.x + q.x).
+    let x = fp2_sub(modulus, &fp2_mul(modulus, &slope, &slope), &fp2_add(modulus, &p_x, &q_x));
+    // y = slope * (p.x - x) - p.y.
+    let y = fp2_sub(modulus, &fp2_mul(modulus, &slope, &fp2_sub(modulus, &p_x, &x)), &p_y);
+
+    encode_g2(&x, &y, p.len() / 4)
+}
+
+/// Decodes the coordinates `(x, y)` of a G2 point from its little endian words.
+pub(super) fn decode_g2(words: &[u32]) -> (Fp2, Fp2) {
+    let num_words_field_element = words.len() / 4;
+    let element = |i: usize| {
+        BigUint::from_slice(&words[i * num_words_field_element..(i + 1) * num_words_field_element])
+    };
+    ([element(0), element(1)], [element(2), element(3)])
+}
+
+/// Encodes the coordinates of a G2 point as little endian words, padding each element to the
+/// given number of words.
+pub(super) fn encode_g2(x: &Fp2, y: &Fp2, num_words_field_element: usize) -> Vec<u32> {
+    let mut result = Vec::with_capacity(4 * num_words_field_element);
+    for element in [&x[0], &x[1], &y[0], &y[1]] {
+        let mut words = element.to_u32_digits();
+        words.resize(num_words_field_element, 0);
+        result.extend(words);
+    }
+    result
+}
+
+pub(super) fn fp2_add(modulus: &BigUint, a: &Fp2, b: &Fp2) -> Fp2 {
+    [(&a[0] + &b[0]) % modulus, (&a[1] + &b[1]) % modulus]
+}
+
+pub(super) fn fp2_sub(modulus: &BigUint, a: &Fp2, b: &Fp2) -> Fp2 {
+    [
+        (modulus + &a[0] % modulus - &b[0] % modulus) % modulus,
+        (modulus + &a[1] % modulus - &b[1] % modulus) % modulus,
+    ]
+}
+
+pub(super) fn fp2_mul(modulus: &BigUint, a: &Fp2, b: &Fp2) -> Fp2 {
+    let a0_mul_b0 = (&a[0] * &b[0]) % modulus;
+    let a1_mul_b1 = (&a[1] * &b[1]) % modulus;
+    [(modulus + a0_mul_b0 - a1_mul_b1) % modulus, (&a[0] * &b[1] + &a[1] * &b[0]) % modulus]
+}
+
+/// Inverts a nonzero element: `1 / a = conj(a) / (a0^2 + a1^2)`.
+pub(super) fn fp2_inv(modulus: &BigUint, a: &Fp2) -> Fp2 {
+    let norm = (&a[0] * &a[0] + &a[1] * &a[1]) % modulus;
+    let norm_inv = norm.modpow(&(modulus - 2u32), modulus);
+    [(&a[0] * &norm_inv) % modulus, ((modulus - &a[1] % modulus) * &norm_inv) % modulus]
+}
@@ -0,0 +1,85 @@
+use std::marker::PhantomData;
+
+use num::{BigUint, Zero};
+use sp1_curves::{
+    params::NumWords,
+    weierstrass::{FieldType, FpOpField},
+};
+use typenum::Unsigned;
+
+use super::g2_add::{decode_g2, encode_g2, fp2_add, fp2_inv, fp2_mul, fp2_sub};
+use crate::{
+    events::EllipticCurveDoubleEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct G2DoubleSyscall<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P> G2DoubleSyscall<P> {
+    /// Create a new instance of the [`G2DoubleSyscall`].
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+impl<P: FpOpField> Syscall for G2DoubleSyscall<P> {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, _: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let p_ptr = arg1;
+        if !rt.check_word_aligned(p_ptr) {
+            return None;
+        }
+
+        let num_words = 2 * <P as NumWords>::WordsCurvePoint::USIZE;
+
+        let p = rt.slice_unsafe(p_ptr, num_words);
+        let result_words = g2_double(&P::modulus(), &p);
+        let p_memory_records = rt.mw_slice(p_ptr, &result_words);
+
+        let event = EllipticCurveDoubleEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk: start_clk,
+            p_ptr,
+            p,
+            p_memory_records,
+        };
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => rt.record_mut().bn254_g2_double_events.push(event),
+            FieldType::Bls12381 => rt.record_mut().bls12381_g2_double_events.push(event),
+            _ => panic!("Unsupported field"),
+        }
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        0
+    }
+}
+
+/// Doubles an affine G2 point on a twist `y^2 = x^3 + b` given as little endian words
+/// `[x.c0, x.c1, y.c0, y.c1]`.
+///
+/// As with the G1 precompiles, the point must not have order two and must not be the point at
+/// infinity.
+fn g2_double(modulus: &BigUint, p: &[u32]) -> Vec<u32> {
+    let (p_x, p_y) = decode_g2(p);
+
+    let two_y = fp2_add(modulus, &p_y, &p_y);
+    assert!(!(two_y[0].is_zero() && two_y[1].is_zero()), "the point must not have order two");
+
+    // slope = 3 * p.x^2 / (2 * p.y).
+    let p_x_squared = fp2_mul(modulus, &p_x, &p_x);
+    let numerator = fp2_add(modulus, &fp2_add(modulus, &p_x_squared, &p_x_squared), &p_x_squared);
+    let slope = fp2_mul(modulus, &numerator, &fp2_inv(modulus, &two_y));
+    // x = slope * slope - 2 * p.x.
+    let x = fp2_sub(modulus, &fp2_mul(modulus, &slope, &slope), &fp2_add(modulus, &p_x, &p_x));
+    // y = slope * (p.x - x) - p.y.
+    let y = fp2_sub(modulus, &fp2_mul(modulus, &slope, &fp2_sub(modulus, &p_x, &x)), &p_y);
+
+    encode_g2(&x, &y, p.len() / 4)
+}
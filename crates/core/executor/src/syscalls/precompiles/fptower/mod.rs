@@ -1,7 +1,13 @@
+mod batch_inverse;
 mod fp;
 mod fp2_addsub;
 mod fp2_mul;
+mod g2_add;
+mod g2_double;
 
+pub use batch_inverse::*;
 pub use fp::*;
 pub use fp2_addsub::*;
 pub use fp2_mul::*;
+pub(crate) use g2_add::*;
+pub(crate) use g2_double::*;
@@ -0,0 +1,123 @@
+use p3_baby_bear::BabyBear;
+use p3_field::AbstractField;
+use p3_symmetric::Permutation;
+use sp1_primitives::poseidon2_init;
+
+use crate::{
+    events::FriDecommitEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The maximum number of levels of a path, which is the number of bits of the query index.
+pub const FRI_MAX_DEPTH: u32 = 32;
+
+/// The maximum number of leaf values.
+pub const FRI_MAX_VALUES: u32 = 256;
+
+/// The number of words in a digest, which is also the rate of the leaf hash.
+pub const FRI_DIGEST_WORDS: usize = 8;
+
+/// The offset of the values from the pointer, after the query index, the number of values, the
+/// result and the root.
+pub const FRI_VALUES_OFFSET: u32 = 44;
+
+/// Hashes the leaf values with the padding-free Poseidon2 sponge of the recursion Merkle trees,
+/// and authenticates the digest along the path given by `index` and `siblings`. Returns the root
+/// of the path.
+pub fn fri_decommit_root(values: &[u32], index: u32, siblings: &[[u32; 8]]) -> [BabyBear; 8] {
+    let perm = poseidon2_init();
+
+    // Each chunk of values overwrites the start of the state, which is then permuted.
+    let mut state = [BabyBear::zero(); 16];
+    for chunk in values.chunks(FRI_DIGEST_WORDS) {
+        for (x, &value) in state.iter_mut().zip(chunk) {
+            *x = BabyBear::from_wrapped_u32(value);
+        }
+        state = perm.permute(state);
+    }
+
+    // Each parent is the truncated permutation of the left child followed by the right child.
+    let mut node: [BabyBear; 8] = state[..FRI_DIGEST_WORDS].try_into().unwrap();
+    for (level, sibling) in siblings.iter().enumerate() {
+        let sibling = sibling.map(BabyBear::from_wrapped_u32);
+        let (left, right) =
+            if (index >> level) & 1 == 1 { (sibling, node) } else { (node, sibling) };
+        let mut state = [BabyBear::zero(); 16];
+        state[..FRI_DIGEST_WORDS].copy_from_slice(&left);
+        state[FRI_DIGEST_WORDS..].copy_from_slice(&right);
+        node = perm.permute(state)[..FRI_DIGEST_WORDS].try_into().unwrap();
+    }
+    node
+}
+
+pub(crate) struct FriDecommitSyscall;
+
+impl Syscall for FriDecommitSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let ptr = arg1;
+        let depth = arg2;
+        if !rt.check_word_aligned(ptr) {
+            return None;
+        }
+        assert!((1..=FRI_MAX_DEPTH).contains(&depth), "depth must be between 1 and 32");
+
+        // The query index is followed by the number of values, the result, the root, the values
+        // and the siblings.
+        let (index_memory_record, index) = rt.mr(ptr);
+        let (num_values_memory_record, num_values) = rt.mr(ptr + 4);
+        assert!(
+            (1..=FRI_MAX_VALUES).contains(&num_values),
+            "the number of values must be between 1 and 256"
+        );
+        let result_ptr = ptr + 8;
+        let (root_memory_records, root) = rt.mr_slice(ptr + 12, FRI_DIGEST_WORDS);
+        let values_ptr = ptr + FRI_VALUES_OFFSET;
+        let (value_memory_records, values) = rt.mr_slice(values_ptr, num_values as usize);
+        let (sibling_memory_records, siblings) =
+            rt.mr_slice(values_ptr + 4 * num_values, depth as usize * FRI_DIGEST_WORDS);
+
+        // The words are reduced modulo the BabyBear prime before they are hashed or compared.
+        let root: [u32; 8] = root.try_into().unwrap();
+        let siblings = siblings
+            .chunks_exact(FRI_DIGEST_WORDS)
+            .map(|sibling| sibling.try_into().unwrap())
+            .collect::<Vec<[u32; 8]>>();
+        let computed_root = fri_decommit_root(&values, index, &siblings);
+        let root_matches = computed_root == root.map(BabyBear::from_wrapped_u32);
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        rt.clk += 1;
+        let result_memory_record = rt.mw(result_ptr, root_matches as u32);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().fri_decommit_events.push(FriDecommitEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk: start_clk,
+            ptr,
+            depth,
+            index,
+            num_values,
+            root,
+            values,
+            siblings,
+            root_matches,
+            index_memory_record,
+            num_values_memory_record,
+            root_memory_records,
+            value_memory_records,
+            sibling_memory_records,
+            result_memory_record,
+        });
+
+        None
+    }
+}
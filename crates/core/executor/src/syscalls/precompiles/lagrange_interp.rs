@@ -0,0 +1,105 @@
+use crate::{
+    events::{inverse_mod, LagrangeInterpEvent},
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The maximum number of points of an interpolation.
+pub const LAGRANGE_INTERP_MAX_POINTS: usize = 32;
+
+/// The number of words of the parameters, which are the number of points, the target and the
+/// modulus.
+pub const LAGRANGE_INTERP_PARAMS_NUM_WORDS: usize = 3;
+
+/// Evaluates at the target the polynomial of least degree through the points `(x_i, y_i)`,
+/// `sum_i y_i * prod_{j != i} (target - x_j) / (x_i - x_j)`.
+///
+/// The terms are added up as a single fraction, so that there is one division at the end. Returns
+/// `None` if the denominator is not invertible, which is the case when two of the `x_i` are equal.
+pub fn lagrange_interp(points: &[(u32, u32)], target: u32, modulus: u32) -> Option<u32> {
+    let m = u64::from(modulus);
+    let (mut num, mut den) = (0u64, 1u64 % m);
+    for (i, &(x_i, y_i)) in points.iter().enumerate() {
+        let (mut term_num, mut term_den) = (1u64, 1u64);
+        for (j, &(x_j, _)) in points.iter().enumerate() {
+            if i != j {
+                term_num = term_num * ((u64::from(target) + m - u64::from(x_j)) % m) % m;
+                term_den = term_den * ((u64::from(x_i) + m - u64::from(x_j)) % m) % m;
+            }
+        }
+        let term = u64::from(y_i) * term_num % m;
+        num = (num * term_den + term * den) % m;
+        den = den * term_den % m;
+    }
+    let den_inv = inverse_mod(den as u32, modulus)?;
+    Some((num * u64::from(den_inv) % m) as u32)
+}
+
+/// Evaluates the polynomial through a set of points at a target modulo a word-sized modulus.
+///
+/// The first input is a pointer to the points, as `x_i, y_i` pairs, and the second one is a pointer
+/// to the parameters: the number of points, the target and the modulus. The parameters are read at
+/// the clk of the syscall, and the points are read once for each of the points, one cycle apart.
+/// The result is written over the target after the maximum number of reads, so that the cycle of
+/// the write does not depend on the number of points.
+pub(crate) struct LagrangeInterpSyscall;
+
+impl Syscall for LagrangeInterpSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let points_ptr = arg1;
+        let params_ptr = arg2;
+        if !rt.check_word_aligned(points_ptr) || !rt.check_word_aligned(params_ptr) {
+            return None;
+        }
+
+        let (params_read_records, params) =
+            rt.mr_slice(params_ptr, LAGRANGE_INTERP_PARAMS_NUM_WORDS);
+        let (len, target, modulus) = (params[0], params[1], params[2]);
+        assert!(
+            (1..=LAGRANGE_INTERP_MAX_POINTS as u32).contains(&len),
+            "the number of points must be between 1 and 32"
+        );
+        assert!(modulus > 1, "the modulus must be greater than one");
+        assert!(target < modulus, "the target must be reduced");
+
+        let words = rt.slice_unsafe(points_ptr, 2 * len as usize);
+        assert!(words.iter().all(|&x| x < modulus), "the points must be reduced");
+        let points = words.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect::<Vec<_>>();
+        let Some(result) = lagrange_interp(&points, target, modulus) else {
+            rt.division_by_zero = true;
+            return None;
+        };
+
+        let mut points_read_records = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            rt.clk += 1;
+            points_read_records.push(rt.mr_slice(points_ptr, 2 * len as usize).0);
+        }
+
+        rt.clk = clk + LAGRANGE_INTERP_MAX_POINTS as u32 + 1;
+        let result_write_record = rt.mw(params_ptr + 4, result);
+
+        let event = LagrangeInterpEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk,
+            points_ptr,
+            params_ptr,
+            len,
+            target,
+            modulus,
+            params_read_records,
+            points_read_records,
+            result_write_record,
+        };
+        rt.record_mut().lagrange_interp_events.push(event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        // One cycle per read of the points, and one to write the result.
+        LAGRANGE_INTERP_MAX_POINTS as u32 + 1
+    }
+}
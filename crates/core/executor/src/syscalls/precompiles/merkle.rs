@@ -0,0 +1,139 @@
+use crate::{
+    events::MerkleVerifyEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+use super::sha256::compress::SHA_COMPRESS_K;
+
+/// The maximum number of levels of a path, which is the number of bits of the directions word.
+pub const MERKLE_MAX_DEPTH: u32 = 32;
+
+/// The number of words in a node of the tree.
+pub const MERKLE_NODE_WORDS: usize = 8;
+
+/// The SHA-256 initial hash value.
+pub const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The second block of the SHA-256 padding of a 64-byte message, which is the concatenation of two
+/// nodes.
+pub const SHA256_PAIR_PADDING_BLOCK: [u32; 16] =
+    [0x80000000, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 512];
+
+/// Applies the SHA-256 compression function to `state` with the given block of big endian words.
+pub fn sha256_compress(state: &[u32; 8], block: &[u32; 16]) -> [u32; 8] {
+    let mut w = [0u32; 64];
+    w[..16].copy_from_slice(block);
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = s1.wrapping_add(w[i - 7]).wrapping_add(s0).wrapping_add(w[i - 16]);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let temp1 =
+            h.wrapping_add(s1).wrapping_add(ch).wrapping_add(SHA_COMPRESS_K[i]).wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    let v = [a, b, c, d, e, f, g, h];
+    core::array::from_fn(|i| state[i].wrapping_add(v[i]))
+}
+
+/// Computes the SHA-256 digest of `left || right`, with the nodes and the digest as big endian
+/// words.
+pub fn merkle_hash_pair(left: &[u32; 8], right: &[u32; 8]) -> [u32; 8] {
+    let mut block = [0u32; 16];
+    block[..8].copy_from_slice(left);
+    block[8..].copy_from_slice(right);
+    let state = sha256_compress(&SHA256_IV, &block);
+    sha256_compress(&state, &SHA256_PAIR_PADDING_BLOCK)
+}
+
+pub(crate) struct MerkleVerifyPathSyscall;
+
+impl Syscall for MerkleVerifyPathSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let ptr = arg1;
+        let depth = arg2;
+        if !rt.check_word_aligned(ptr) {
+            return None;
+        }
+        assert!((1..=MERKLE_MAX_DEPTH).contains(&depth), "depth must be between 1 and 32");
+
+        // The leaf is followed by the root, the directions, the result and the siblings.
+        let node_bytes = (MERKLE_NODE_WORDS * 4) as u32;
+        let (leaf_memory_records, leaf) = rt.mr_slice(ptr, MERKLE_NODE_WORDS);
+        let (root_memory_records, root) = rt.mr_slice(ptr + node_bytes, MERKLE_NODE_WORDS);
+        let (directions_memory_record, directions) = rt.mr(ptr + 2 * node_bytes);
+        let result_ptr = ptr + 2 * node_bytes + 4;
+        let (sibling_memory_records, siblings) =
+            rt.mr_slice(result_ptr + 4, depth as usize * MERKLE_NODE_WORDS);
+
+        // The nodes are hashed as bytes, so each word is byte swapped into a big endian word.
+        let leaf: [u32; 8] = leaf.try_into().unwrap();
+        let root: [u32; 8] = root.try_into().unwrap();
+        let siblings = siblings
+            .chunks_exact(MERKLE_NODE_WORDS)
+            .map(|sibling| sibling.try_into().unwrap())
+            .collect::<Vec<[u32; 8]>>();
+        let mut node = leaf.map(u32::swap_bytes);
+        for (level, sibling) in siblings.iter().enumerate() {
+            let sibling = sibling.map(u32::swap_bytes);
+            node = if (directions >> level) & 1 == 1 {
+                merkle_hash_pair(&sibling, &node)
+            } else {
+                merkle_hash_pair(&node, &sibling)
+            };
+        }
+        let root_matches = node == root.map(u32::swap_bytes);
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        rt.clk += 1;
+        let result_memory_record = rt.mw(result_ptr, root_matches as u32);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().merkle_verify_events.push(MerkleVerifyEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk: start_clk,
+            ptr,
+            depth,
+            leaf,
+            root,
+            directions,
+            siblings,
+            root_matches,
+            leaf_memory_records,
+            root_memory_records,
+            directions_memory_record,
+            sibling_memory_records,
+            result_memory_record,
+        });
+
+        None
+    }
+}
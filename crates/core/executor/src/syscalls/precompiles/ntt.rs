@@ -0,0 +1,145 @@
+use crate::{
+    events::NttEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The base two logarithm of the maximum number of coefficients of a transform.
+pub const NTT_MAX_LOG_LEN: u32 = 20;
+
+/// The number of words of the parameters, which are the number of coefficients, the root of unity
+/// and the modulus.
+pub const NTT_PARAMS_NUM_WORDS: usize = 3;
+
+fn add_mod(a: u32, b: u32, modulus: u32) -> u32 {
+    ((u64::from(a) + u64::from(b)) % u64::from(modulus)) as u32
+}
+
+fn sub_mod(a: u32, b: u32, modulus: u32) -> u32 {
+    ((u64::from(a) + u64::from(modulus) - u64::from(b)) % u64::from(modulus)) as u32
+}
+
+fn mul_mod(a: u32, b: u32, modulus: u32) -> u32 {
+    ((u64::from(a) * u64::from(b)) % u64::from(modulus)) as u32
+}
+
+/// Returns the stages of a transform of `len` coefficients in the order they run, as the distance
+/// between the two coefficients of a butterfly and the root of unity of order twice that distance.
+///
+/// The forward transform halves the distance at each stage, starting from `len / 2` with `root`,
+/// and the root of each stage after that is the square of the previous one. The inverse transform
+/// runs the same stages in the opposite order.
+pub fn ntt_stages(len: u32, root: u32, modulus: u32, inverse: bool) -> Vec<(usize, u32)> {
+    let mut stages = Vec::new();
+    let mut half = len as usize / 2;
+    let mut stage_root = root;
+    while half > 0 {
+        stages.push((half, stage_root));
+        half /= 2;
+        stage_root = mul_mod(stage_root, stage_root, modulus);
+    }
+    if inverse {
+        stages.reverse();
+    }
+    stages
+}
+
+/// Applies the butterflies of one stage of a transform to `coeffs` in place. The coefficients are
+/// split into blocks of `2 * half`, and the `j`-th butterfly of a block combines the coefficients
+/// `j` and `j + half` with the twiddle factor `stage_root^j`.
+///
+/// The forward transform uses the Gentleman-Sande butterfly `(a + b, (a - b) * w)`, and the inverse
+/// transform uses the Cooley-Tukey butterfly `(a + w * b, a - w * b)`.
+pub fn ntt_stage(coeffs: &mut [u32], half: usize, stage_root: u32, modulus: u32, inverse: bool) {
+    for block in coeffs.chunks_exact_mut(2 * half) {
+        let (lo, hi) = block.split_at_mut(half);
+        let mut twiddle = 1;
+        for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+            if inverse {
+                let t = mul_mod(twiddle, *b, modulus);
+                (*a, *b) = (add_mod(*a, t, modulus), sub_mod(*a, t, modulus));
+            } else {
+                let diff = sub_mod(*a, *b, modulus);
+                (*a, *b) = (add_mod(*a, *b, modulus), mul_mod(diff, twiddle, modulus));
+            }
+            twiddle = mul_mod(twiddle, stage_root, modulus);
+        }
+    }
+}
+
+/// Applies a number theoretic transform, or its inverse, to an array of coefficients in place.
+///
+/// The forward transform takes the coefficients in the natural order and leaves the evaluations in
+/// bit-reversed order, and the inverse transform takes the evaluations in bit-reversed order and
+/// leaves the coefficients in the natural order, so that neither needs a permutation. The inverse
+/// transform is given the inverse of the root, and its result is not divided by the length.
+pub struct NttSyscall {
+    inverse: bool,
+}
+
+impl NttSyscall {
+    /// The forward transform.
+    pub const fn ntt() -> Self {
+        Self { inverse: false }
+    }
+
+    /// The inverse transform.
+    pub const fn intt() -> Self {
+        Self { inverse: true }
+    }
+}
+
+impl Syscall for NttSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let coeffs_ptr = arg1;
+        let params_ptr = arg2;
+        if !rt.check_word_aligned(coeffs_ptr) || !rt.check_word_aligned(params_ptr) {
+            return None;
+        }
+
+        let (params_read_records, params) = rt.mr_slice(params_ptr, NTT_PARAMS_NUM_WORDS);
+        let (len, root, modulus) = (params[0], params[1], params[2]);
+        assert!(
+            len.is_power_of_two() && (2..=1 << NTT_MAX_LOG_LEN).contains(&len),
+            "the number of coefficients must be a power of two between 2 and 2^20"
+        );
+        assert!(root < modulus, "the root must be reduced");
+
+        let mut coeffs = rt.slice_unsafe(coeffs_ptr, len as usize);
+        assert!(coeffs.iter().all(|&x| x < modulus), "the coefficients must be reduced");
+
+        // Each stage writes all of the coefficients, one clk after the previous stage.
+        let mut stage_write_records = Vec::new();
+        for (half, stage_root) in ntt_stages(len, root, modulus, self.inverse) {
+            ntt_stage(&mut coeffs, half, stage_root, modulus, self.inverse);
+            rt.clk += 1;
+            stage_write_records.push(rt.mw_slice(coeffs_ptr, &coeffs));
+        }
+
+        let event = NttEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk,
+            coeffs_ptr,
+            params_ptr,
+            len,
+            root,
+            modulus,
+            params_read_records,
+            stage_write_records,
+        };
+        if self.inverse {
+            rt.record_mut().intt_events.push(event);
+        } else {
+            rt.record_mut().ntt_events.push(event);
+        }
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        // One cycle per stage.
+        NTT_MAX_LOG_LEN
+    }
+}
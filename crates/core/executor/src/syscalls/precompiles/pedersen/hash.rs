@@ -0,0 +1,59 @@
+use sp1_curves::{
+    edwards::jubjub::Jubjub,
+    pedersen::{pedersen_add_window, PEDERSEN_NUM_WINDOWS, PEDERSEN_WINDOW_BITS},
+    AffinePoint,
+};
+
+use crate::{
+    events::{PedersenHashEvent, PEDERSEN_POINT_NUM_WORDS},
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct PedersenHashSyscall;
+
+impl Syscall for PedersenHashSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let acc_ptr = arg1;
+        let window = arg2 >> PEDERSEN_WINDOW_BITS;
+        let chunk = arg2 & ((1 << PEDERSEN_WINDOW_BITS) - 1);
+        if window as usize >= PEDERSEN_NUM_WINDOWS {
+            panic!("Expected a window below {PEDERSEN_NUM_WINDOWS} in arg2, got {window}");
+        }
+        if !rt.check_word_aligned(acc_ptr) {
+            return None;
+        }
+
+        // Read the accumulator. We can read a slice_unsafe here because we write the new
+        // accumulator over it later.
+        let acc: [u32; PEDERSEN_POINT_NUM_WORDS] =
+            rt.slice_unsafe(acc_ptr, PEDERSEN_POINT_NUM_WORDS).try_into().unwrap();
+        let point = AffinePoint::<Jubjub>::from_words_le(&acc);
+        let result = pedersen_add_window(&point, window as usize, chunk);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let acc_memory_records = rt.mw_slice(acc_ptr, &result.to_words_le());
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().pedersen_hash_events.push(PedersenHashEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            acc_ptr,
+            window,
+            chunk,
+            acc,
+            acc_memory_records,
+        });
+
+        None
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    events::PolyEvalEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The maximum number of coefficients of a polynomial, one more than its degree.
+pub const POLY_EVAL_MAX_LEN: u32 = 1 << 20;
+
+/// The number of words of the parameters, which are the degree, the point and the modulus.
+pub const POLY_EVAL_PARAMS_NUM_WORDS: usize = 3;
+
+/// Evaluates a polynomial at a point with Horner's method, starting from the leading coefficient
+/// and multiplying by the point before adding each of the others.
+pub fn poly_eval(coeffs: &[u32], point: u32, modulus: u32) -> u32 {
+    coeffs.iter().rev().fold(0, |acc, &coeff| {
+        ((u64::from(acc) * u64::from(point) + u64::from(coeff)) % u64::from(modulus)) as u32
+    })
+}
+
+/// Evaluates a polynomial modulo a word-sized modulus.
+///
+/// The first input is a pointer to the coefficients, from the constant one up, and the second one
+/// is a pointer to the parameters: the degree, the point and the modulus. The parameters are read
+/// at the clk of the syscall and the coefficients one cycle later, and the result is written over
+/// the point one cycle after that.
+pub(crate) struct PolyEvalSyscall;
+
+impl Syscall for PolyEvalSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let coeffs_ptr = arg1;
+        let params_ptr = arg2;
+        if !rt.check_word_aligned(coeffs_ptr) || !rt.check_word_aligned(params_ptr) {
+            return None;
+        }
+
+        let (params_read_records, params) = rt.mr_slice(params_ptr, POLY_EVAL_PARAMS_NUM_WORDS);
+        let (degree, point, modulus) = (params[0], params[1], params[2]);
+        assert!(degree < POLY_EVAL_MAX_LEN, "the degree must be below 2^20");
+        assert!(point < modulus, "the point must be reduced");
+
+        rt.clk += 1;
+        let (coeff_read_records, coeffs) = rt.mr_slice(coeffs_ptr, degree as usize + 1);
+        assert!(coeffs.iter().all(|&x| x < modulus), "the coefficients must be reduced");
+
+        let result = poly_eval(&coeffs, point, modulus);
+        rt.clk += 1;
+        let result_write_record = rt.mw(params_ptr + 4, result);
+
+        let event = PolyEvalEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk,
+            coeffs_ptr,
+            params_ptr,
+            degree,
+            point,
+            modulus,
+            params_read_records,
+            coeff_read_records,
+            result_write_record,
+        };
+        rt.record_mut().poly_eval_events.push(event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        // One cycle to read the coefficients and one to write the result.
+        2
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    events::PolyMultiEvalEvent,
+    syscalls::{
+        precompiles::poly_eval::{poly_eval, POLY_EVAL_MAX_LEN},
+        Syscall, SyscallContext,
+    },
+};
+
+/// The maximum number of points of a syscall.
+pub const POLY_MULTI_EVAL_MAX_POINTS: u32 = 8;
+
+/// The number of words of the parameters, which are the pointer to the coefficients, the pointer
+/// to the points, the degree and the modulus.
+pub const POLY_MULTI_EVAL_PARAMS_NUM_WORDS: usize = 4;
+
+/// Evaluates a polynomial at up to 8 points modulo a word-sized modulus.
+///
+/// The first input is a pointer to the parameters: a pointer to the coefficients, from the constant
+/// one up, a pointer to the points, the degree and the modulus. The second input is the number of
+/// points. The parameters are read at the clk of the syscall and the coefficients one cycle later,
+/// once for all of the points, and the results are written over the points one cycle after that.
+pub(crate) struct PolyMultiEvalSyscall;
+
+impl Syscall for PolyMultiEvalSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let params_ptr = arg1;
+        let count = arg2;
+        if !rt.check_word_aligned(params_ptr) {
+            return None;
+        }
+        assert!(
+            (1..=POLY_MULTI_EVAL_MAX_POINTS).contains(&count),
+            "the number of points must be between 1 and 8"
+        );
+
+        let (params_read_records, params) =
+            rt.mr_slice(params_ptr, POLY_MULTI_EVAL_PARAMS_NUM_WORDS);
+        let (coeffs_ptr, points_ptr, degree, modulus) =
+            (params[0], params[1], params[2], params[3]);
+        if !rt.check_word_aligned(coeffs_ptr) || !rt.check_word_aligned(points_ptr) {
+            return None;
+        }
+        assert!(degree < POLY_EVAL_MAX_LEN, "the degree must be below 2^20");
+
+        // We can read a slice_unsafe here because we write the results over the points.
+        let points = rt.slice_unsafe(points_ptr, count as usize);
+        assert!(points.iter().all(|&x| x < modulus), "the points must be reduced");
+
+        rt.clk += 1;
+        let (coeff_read_records, coeffs) = rt.mr_slice(coeffs_ptr, degree as usize + 1);
+        assert!(coeffs.iter().all(|&x| x < modulus), "the coefficients must be reduced");
+
+        let results =
+            points.iter().map(|&point| poly_eval(&coeffs, point, modulus)).collect::<Vec<_>>();
+        rt.clk += 1;
+        let result_write_records = rt.mw_slice(points_ptr, &results);
+
+        let event = PolyMultiEvalEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk,
+            params_ptr,
+            count,
+            coeffs_ptr,
+            points_ptr,
+            degree,
+            modulus,
+            points,
+            params_read_records,
+            coeff_read_records,
+            result_write_records,
+        };
+        rt.record_mut().poly_multi_eval_events.push(event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        // One cycle to read the coefficients and one to write the results.
+        2
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    events::{PopcountEvent, POPCOUNT_NUM_WORDS},
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct PopcountSyscall;
+
+impl Syscall for PopcountSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let ptr = arg1;
+        assert!(arg2 == 0, "arg2 must be 0");
+        if !rt.check_word_aligned(ptr) {
+            return None;
+        }
+
+        // Read the value. We can read a slice_unsafe here because we write the count over it.
+        let value: [u32; POPCOUNT_NUM_WORDS] =
+            rt.slice_unsafe(ptr, POPCOUNT_NUM_WORDS).try_into().unwrap();
+
+        // The count of a 64-bit value fits in the low word, and the high word is cleared.
+        let count = value.iter().map(|word| word.count_ones()).sum::<u32>();
+        let memory_records = rt.mw_slice(ptr, &[count, 0]);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().popcount_events.push(PopcountEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            ptr,
+            value,
+            memory_records,
+        });
+
+        None
+    }
+}
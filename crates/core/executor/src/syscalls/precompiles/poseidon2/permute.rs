@@ -0,0 +1,59 @@
+use p3_baby_bear::BabyBear;
+use p3_field::{AbstractField, PrimeField32};
+use p3_symmetric::Permutation;
+use sp1_primitives::poseidon2_init;
+
+use crate::{
+    events::{Poseidon2PermuteEvent, POSEIDON2_WIDTH},
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The field size parameter of the BabyBear Poseidon2 permutation, which is the number of bits of
+/// the BabyBear modulus.
+pub const POSEIDON2_BABYBEAR_FIELD_BITS: u32 = 31;
+
+pub(crate) struct Poseidon2PermuteSyscall;
+
+impl Syscall for Poseidon2PermuteSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let state_ptr = arg1;
+        if arg2 != POSEIDON2_BABYBEAR_FIELD_BITS {
+            panic!("Expected arg2 to be {POSEIDON2_BABYBEAR_FIELD_BITS}, got {arg2}");
+        }
+        if !rt.check_word_aligned(state_ptr) {
+            return None;
+        }
+
+        // Read the input state. We can read a slice_unsafe here because we write the output over
+        // it later. The words are reduced modulo the BabyBear prime.
+        let input: [u32; POSEIDON2_WIDTH] =
+            rt.slice_unsafe(state_ptr, POSEIDON2_WIDTH).try_into().unwrap();
+        let state = input.map(BabyBear::from_wrapped_u32);
+        let output = poseidon2_init().permute(state).map(|x| x.as_canonical_u32());
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let state_memory_records = rt.mw_slice(state_ptr, &output);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().poseidon2_permute_events.push(Poseidon2PermuteEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            state_ptr,
+            input,
+            output,
+            state_memory_records,
+        });
+
+        None
+    }
+}
@@ -0,0 +1,69 @@
+use num::BigUint;
+use sp1_curves::rescue::{rescue_round, RESCUE_NUM_ROUNDS, RESCUE_WIDTH};
+use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le_vec};
+
+use crate::{
+    events::{RescuePermuteEvent, RESCUE_STATE_NUM_WORDS},
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The number of words in a state element.
+const NUM_WORDS_PER_ELEMENT: usize = RESCUE_STATE_NUM_WORDS / RESCUE_WIDTH;
+
+pub(crate) struct RescuePermuteSyscall;
+
+impl Syscall for RescuePermuteSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let state_ptr = arg1;
+        let round = arg2;
+        if round as usize >= RESCUE_NUM_ROUNDS {
+            panic!("Expected arg2 to be a round below {RESCUE_NUM_ROUNDS}, got {round}");
+        }
+        if !rt.check_word_aligned(state_ptr) {
+            return None;
+        }
+
+        // Read the input state. We can read a slice_unsafe here because we write the output over
+        // it later.
+        let input: [u32; RESCUE_STATE_NUM_WORDS] =
+            rt.slice_unsafe(state_ptr, RESCUE_STATE_NUM_WORDS).try_into().unwrap();
+        let state: [BigUint; RESCUE_WIDTH] = core::array::from_fn(|i| {
+            let words = &input[i * NUM_WORDS_PER_ELEMENT..(i + 1) * NUM_WORDS_PER_ELEMENT];
+            BigUint::from_bytes_le(&words_to_bytes_le_vec(words))
+        });
+
+        let mut output = [0u32; RESCUE_STATE_NUM_WORDS];
+        for (i, element) in rescue_round(&state, round as usize).iter().enumerate() {
+            let mut bytes = element.to_bytes_le();
+            bytes.resize(NUM_WORDS_PER_ELEMENT * 4, 0);
+            output[i * NUM_WORDS_PER_ELEMENT..(i + 1) * NUM_WORDS_PER_ELEMENT]
+                .copy_from_slice(&bytes_to_words_le::<NUM_WORDS_PER_ELEMENT>(&bytes));
+        }
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let state_memory_records = rt.mw_slice(state_ptr, &output);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().rescue_permute_events.push(RescuePermuteEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            state_ptr,
+            round,
+            input,
+            output,
+            state_memory_records,
+        });
+
+        None
+    }
+}
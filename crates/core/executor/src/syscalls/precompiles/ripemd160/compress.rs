@@ -0,0 +1,166 @@
+use crate::{
+    events::Ripemd160CompressEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The number of words in the RIPEMD-160 state.
+pub const RIPEMD160_STATE_NUM_WORDS: usize = 5;
+
+/// The number of words in a RIPEMD-160 message block.
+pub const RIPEMD160_BLOCK_NUM_WORDS: usize = 16;
+
+/// The number of steps of each lane of the RIPEMD-160 compression function, in five rounds of 16.
+pub const RIPEMD160_NUM_STEPS: usize = 80;
+
+/// The message word selected at each step of the left lane.
+pub const RIPEMD160_R_LEFT: [usize; RIPEMD160_NUM_STEPS] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, //
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8, //
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, //
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2, //
+    4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+
+/// The message word selected at each step of the right lane.
+pub const RIPEMD160_R_RIGHT: [usize; RIPEMD160_NUM_STEPS] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, //
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2, //
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, //
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14, //
+    12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+
+/// The left rotation at each step of the left lane.
+pub const RIPEMD160_S_LEFT: [u32; RIPEMD160_NUM_STEPS] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, //
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12, //
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, //
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, //
+    9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+
+/// The left rotation at each step of the right lane.
+pub const RIPEMD160_S_RIGHT: [u32; RIPEMD160_NUM_STEPS] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, //
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11, //
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, //
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8, //
+    8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+
+/// The constant added in each round of the left lane.
+pub const RIPEMD160_K_LEFT: [u32; 5] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+
+/// The constant added in each round of the right lane.
+pub const RIPEMD160_K_RIGHT: [u32; 5] =
+    [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+/// The boolean function `f_j` of RIPEMD-160, for `j` in `0..5`. The left lane uses them in order,
+/// and the right lane in reverse order.
+pub const fn ripemd160_f(j: usize, x: u32, y: u32, z: u32) -> u32 {
+    match j {
+        0 => x ^ y ^ z,
+        1 => (x & y) | (!x & z),
+        2 => (x | !y) ^ z,
+        3 => (x & z) | (y & !z),
+        _ => x ^ (y | !z),
+    }
+}
+
+/// Applies one step to a lane `[a, b, c, d, e]`, given the boolean function, the message word, the
+/// round constant and the rotation.
+pub const fn ripemd160_step(lane: [u32; 5], f: usize, x: u32, k: u32, s: u32) -> [u32; 5] {
+    let [a, b, c, d, e] = lane;
+    let t = a
+        .wrapping_add(ripemd160_f(f, b, c, d))
+        .wrapping_add(x)
+        .wrapping_add(k)
+        .rotate_left(s)
+        .wrapping_add(e);
+    [e, t, b, c.rotate_left(10), d]
+}
+
+/// The RIPEMD-160 compression function.
+pub fn ripemd160_compress(
+    h: &[u32; RIPEMD160_STATE_NUM_WORDS],
+    block: &[u32; RIPEMD160_BLOCK_NUM_WORDS],
+) -> [u32; RIPEMD160_STATE_NUM_WORDS] {
+    let mut left = *h;
+    let mut right = *h;
+    for j in 0..RIPEMD160_NUM_STEPS {
+        let round = j / 16;
+        left = ripemd160_step(
+            left,
+            round,
+            block[RIPEMD160_R_LEFT[j]],
+            RIPEMD160_K_LEFT[round],
+            RIPEMD160_S_LEFT[j],
+        );
+        right = ripemd160_step(
+            right,
+            4 - round,
+            block[RIPEMD160_R_RIGHT[j]],
+            RIPEMD160_K_RIGHT[round],
+            RIPEMD160_S_RIGHT[j],
+        );
+    }
+    ripemd160_combine(h, &left, &right)
+}
+
+/// Combines the chaining value with the two lanes after the last step.
+pub fn ripemd160_combine(
+    h: &[u32; RIPEMD160_STATE_NUM_WORDS],
+    left: &[u32; RIPEMD160_STATE_NUM_WORDS],
+    right: &[u32; RIPEMD160_STATE_NUM_WORDS],
+) -> [u32; RIPEMD160_STATE_NUM_WORDS] {
+    core::array::from_fn(|i| {
+        h[(i + 1) % 5].wrapping_add(left[(i + 2) % 5]).wrapping_add(right[(i + 3) % 5])
+    })
+}
+
+pub(crate) struct Ripemd160CompressSyscall;
+
+impl Syscall for Ripemd160CompressSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let state_ptr = arg1;
+        let block_ptr = arg2;
+        if !rt.check_word_aligned(state_ptr) || !rt.check_word_aligned(block_ptr) {
+            return None;
+        }
+
+        let (state_read_records, state) = rt.mr_slice(state_ptr, RIPEMD160_STATE_NUM_WORDS);
+        let (block_read_records, block) = rt.mr_slice(block_ptr, RIPEMD160_BLOCK_NUM_WORDS);
+        let state: [u32; RIPEMD160_STATE_NUM_WORDS] = state.try_into().unwrap();
+        let block: [u32; RIPEMD160_BLOCK_NUM_WORDS] = block.try_into().unwrap();
+
+        let result = ripemd160_compress(&state, &block);
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        rt.clk += 1;
+        let state_write_records = rt.mw_slice(state_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().ripemd160_compress_events.push(Ripemd160CompressEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk: start_clk,
+            state_ptr,
+            block_ptr,
+            state,
+            block,
+            state_read_records,
+            state_write_records,
+            block_read_records,
+        });
+
+        None
+    }
+}
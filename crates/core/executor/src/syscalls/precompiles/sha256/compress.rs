@@ -25,20 +25,19 @@ impl Syscall for Sha256CompressSyscall {
     fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
         let w_ptr = arg1;
         let h_ptr = arg2;
-        assert_ne!(w_ptr, h_ptr);
+        if !rt.check_word_aligned(w_ptr) || !rt.check_word_aligned(h_ptr) {
+            return None;
+        }
+        if w_ptr == h_ptr {
+            rt.invalid_pointer = Some(h_ptr);
+            return None;
+        }
 
         let start_clk = rt.clk;
-        let mut h_read_records = Vec::new();
         let mut w_i_read_records = Vec::new();
-        let mut h_write_records = Vec::new();
 
         // Execute the "initialize" phase where we read in the h values.
-        let mut hx = [0u32; 8];
-        for i in 0..8 {
-            let (record, value) = rt.mr(h_ptr + i as u32 * 4);
-            h_read_records.push(record);
-            hx[i] = value;
-        }
+        let (h_read_records, hx) = rt.mr_array::<8>(h_ptr);
 
         let mut original_w = Vec::new();
         // Execute the "compress" phase.
@@ -80,10 +79,8 @@ impl Syscall for Sha256CompressSyscall {
 
         // Execute the "finalize" phase.
         let v = [a, b, c, d, e, f, g, h];
-        for i in 0..8 {
-            let record = rt.mw(h_ptr + i as u32 * 4, hx[i].wrapping_add(v[i]));
-            h_write_records.push(record);
-        }
+        let h_write_records =
+            rt.mw_array(h_ptr, &core::array::from_fn(|i| hx[i].wrapping_add(v[i])));
 
         // Push the SHA extend event.
         let lookup_id = rt.syscall_lookup_id;
@@ -98,9 +95,9 @@ impl Syscall for Sha256CompressSyscall {
             h_ptr,
             w: original_w,
             h: hx,
-            h_read_records: h_read_records.try_into().unwrap(),
+            h_read_records,
             w_i_read_records,
-            h_write_records: h_write_records.try_into().unwrap(),
+            h_write_records,
         });
 
         None
@@ -0,0 +1,114 @@
+use crate::{
+    events::Sha256HashEvent,
+    syscalls::{
+        precompiles::merkle::{sha256_compress, SHA256_IV},
+        Syscall, SyscallContext,
+    },
+};
+
+/// The number of words of the parameters, which are the pointer to the message and its length.
+pub const SHA256_HASH_PARAMS_NUM_WORDS: usize = 2;
+
+/// The length of a message must be below `2^24` bytes, so that its length in bits fits in the last
+/// word of the padding.
+pub const SHA256_HASH_MAX_LEN: u32 = 1 << 24;
+
+/// Returns the blocks of the SHA-256 padding of a message of `len` bytes as big endian words, given
+/// the words of memory which hold the message.
+///
+/// The message is followed by a `0x80` byte, by zeros up to 8 bytes before the end of a block, and
+/// by its length in bits as a big endian 64-bit integer.
+pub fn sha256_padded_blocks(words: &[u32], len: u32) -> Vec<[u32; 16]> {
+    let mut bytes =
+        words.iter().flat_map(|word| word.to_le_bytes()).take(len as usize).collect::<Vec<_>>();
+    bytes.push(0x80);
+    while bytes.len() % 64 != 56 {
+        bytes.push(0);
+    }
+    bytes.extend_from_slice(&(u64::from(len) * 8).to_be_bytes());
+    bytes
+        .chunks_exact(64)
+        .map(|block| {
+            core::array::from_fn(|i| {
+                u32::from_be_bytes(block[4 * i..4 * i + 4].try_into().unwrap())
+            })
+        })
+        .collect()
+}
+
+/// Computes the SHA-256 digest of a region of memory.
+///
+/// The first input is a pointer to the 8 words of the digest, which are written with the bytes of
+/// the digest in order. The second input is a pointer to the parameters: a word aligned pointer to
+/// the message and its length in bytes. The parameters are read at the clk of the syscall, the
+/// message one cycle later and the digest is written one cycle after that, so that any of them may
+/// overlap.
+pub(crate) struct Sha256HashSyscall;
+
+impl Syscall for Sha256HashSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let result_ptr = arg1;
+        let params_ptr = arg2;
+        if !rt.check_word_aligned(result_ptr) || !rt.check_word_aligned(params_ptr) {
+            return None;
+        }
+
+        let (mut params_read_records, params) =
+            rt.mr_slice(params_ptr, SHA256_HASH_PARAMS_NUM_WORDS);
+        let (input_ptr, len) = (params[0], params[1]);
+        if !rt.check_word_aligned(input_ptr) {
+            return None;
+        }
+        assert!(len < SHA256_HASH_MAX_LEN, "the message must be shorter than 2^24 bytes");
+
+        rt.clk += 1;
+        let (mut input_read_records, words) = rt.mr_slice(input_ptr, (len as usize).div_ceil(4));
+        let blocks = sha256_padded_blocks(&words, len);
+
+        let mut states = vec![SHA256_IV];
+        for block in blocks.iter() {
+            states.push(sha256_compress(states.last().unwrap(), block));
+        }
+        let digest = states.last().unwrap().map(u32::swap_bytes);
+
+        rt.clk += 1;
+        let mut digest_write_records = rt.mw_slice(result_ptr, &digest);
+
+        // Emit one event per block, with the reads of the words of the message in that block.
+        let num_blocks = blocks.len() as u32;
+        for (i, block) in blocks.into_iter().enumerate() {
+            let num_read_words = input_read_records.len().min(16);
+            let block_read_records = input_read_records.drain(..num_read_words).collect();
+            let event = Sha256HashEvent {
+                lookup_id: rt.syscall_lookup_id,
+                shard: rt.current_shard(),
+                channel: rt.current_channel(),
+                clk,
+                result_ptr,
+                params_ptr,
+                input_ptr,
+                len,
+                block_index: i as u32,
+                num_blocks,
+                h_in: states[i],
+                block,
+                params_read_records: std::mem::take(&mut params_read_records),
+                input_read_records: block_read_records,
+                digest_write_records: if i as u32 + 1 == num_blocks {
+                    std::mem::take(&mut digest_write_records)
+                } else {
+                    Vec::new()
+                },
+            };
+            rt.record_mut().sha256_hash_events.push(event);
+        }
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        // One cycle to read the message and one to write the digest.
+        2
+    }
+}
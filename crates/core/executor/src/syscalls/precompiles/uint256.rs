@@ -1,26 +1,123 @@
+use std::marker::PhantomData;
+
 use num::{BigUint, One, Zero};
 
-use sp1_curves::edwards::WORDS_FIELD_ELEMENT;
+use sp1_curves::{
+    edwards::WORDS_FIELD_ELEMENT,
+    params::NumWords,
+    uint::{UintField, UintType},
+};
 use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le_vec, WORD_SIZE};
+use typenum::Unsigned;
 
 use crate::{
-    events::Uint256MulEvent,
+    events::{
+        Uint256AddModEvent, Uint256BitwiseEvent, Uint256CmpEvent, Uint256DivRemEvent,
+        Uint256InvModEvent, Uint256MulWideEvent, Uint256RangeCheckEvent, Uint256SquareModEvent,
+        Uint256SubModEvent, UintMulEvent,
+    },
     syscalls::{Syscall, SyscallContext},
+    ByteOpcode,
 };
 
-pub(crate) struct Uint256MulSyscall;
+pub(crate) struct UintMulSyscall<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P> UintMulSyscall<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
 
-impl Syscall for Uint256MulSyscall {
+impl<P: UintField> Syscall for UintMulSyscall<P> {
     fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
         let clk = rt.clk;
 
         let x_ptr = arg1;
-        if x_ptr % 4 != 0 {
-            panic!();
+        let y_ptr = arg2;
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(y_ptr) {
+            return None;
+        }
+
+        let num_words = <P as NumWords>::WordsFieldElement::USIZE;
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, num_words);
+
+        // Read the y value. The pointers may be equal to square x in place: y is read at clk,
+        // before x is written at clk + 1, so it observes the original value of x.
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, num_words);
+
+        // The modulus is stored after the y value. We increment the pointer by the number of words.
+        let modulus_ptr = y_ptr + num_words as u32 * WORD_SIZE as u32;
+        let (modulus_memory_records, modulus) = rt.mr_slice(modulus_ptr, num_words);
+
+        // Get the BigUint values for x, y, and the modulus.
+        let uint_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&y));
+        let uint_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
+
+        // Perform the multiplication and take the result modulo the modulus. A zero modulus means
+        // the result is taken modulo 2^bits.
+        let result: BigUint = if uint_modulus.is_zero() {
+            let modulus = BigUint::one() << P::nb_bits();
+            (uint_x * uint_y) % modulus
+        } else {
+            (uint_x * uint_y) % uint_modulus
+        };
+
+        // Convert the result to little endian u32 words, padded to the full width.
+        let mut result = result.to_u32_digits();
+        result.resize(num_words, 0);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        let event = UintMulEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            modulus,
+            x_memory_records,
+            y_memory_records,
+            modulus_memory_records,
+        };
+        match P::UINT_TYPE {
+            UintType::Uint256 => rt.record_mut().uint256_mul_events.push(event),
+            UintType::Uint384 => rt.record_mut().uint384_mul_events.push(event),
+            UintType::Uint512 => rt.record_mut().uint512_mul_events.push(event),
         }
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256AddModSyscall;
+
+impl Syscall for Uint256AddModSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
         let y_ptr = arg2;
-        if y_ptr % 4 != 0 {
-            panic!();
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(y_ptr) {
+            return None;
         }
 
         // First read the words for the x value. We can read a slice_unsafe here because we write
@@ -39,12 +136,20 @@ impl Syscall for Uint256MulSyscall {
         let uint256_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&y));
         let uint256_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
 
-        // Perform the multiplication and take the result modulo the modulus.
+        // The chip proves the sum as x + y = result + carry * modulus, where the carry must fit in
+        // 256 bits. That is not the case for a modulus of one, so it is rejected.
+        if uint256_modulus.is_one() {
+            rt.invalid_argument = true;
+            return None;
+        }
+
+        // Perform the addition and take the result modulo the modulus. The sum is computed over
+        // the integers, so it is allowed to exceed 2^256 before the reduction.
         let result: BigUint = if uint256_modulus.is_zero() {
             let modulus = BigUint::one() << 256;
-            (uint256_x * uint256_y) % modulus
+            (uint256_x + uint256_y) % modulus
         } else {
-            (uint256_x * uint256_y) % uint256_modulus
+            (uint256_x + uint256_y) % uint256_modulus
         };
 
         let mut result_bytes = result.to_bytes_le();
@@ -61,7 +166,84 @@ impl Syscall for Uint256MulSyscall {
         let lookup_id = rt.syscall_lookup_id;
         let shard = rt.current_shard();
         let channel = rt.current_channel();
-        rt.record_mut().uint256_mul_events.push(Uint256MulEvent {
+        rt.record_mut().uint256_add_mod_events.push(Uint256AddModEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            modulus,
+            x_memory_records,
+            y_memory_records,
+            modulus_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256SubModSyscall;
+
+impl Syscall for Uint256SubModSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        let y_ptr = arg2;
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(y_ptr) {
+            return None;
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the y value.
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, WORDS_FIELD_ELEMENT);
+
+        // The modulus is stored after the y value. We increment the pointer by the number of words.
+        let modulus_ptr = y_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        let (modulus_memory_records, modulus) = rt.mr_slice(modulus_ptr, WORDS_FIELD_ELEMENT);
+
+        // Get the BigUint values for x, y, and the modulus.
+        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint256_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&y));
+        let uint256_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
+
+        // Perform the subtraction modulo the modulus. When x < y the difference wraps around to
+        // modulus - (y - x). The chip proves this as x = result + y - carry * modulus, which
+        // requires x to already be reduced, so an unreduced x is rejected.
+        let effective_modulus =
+            if uint256_modulus.is_zero() { BigUint::one() << 256 } else { uint256_modulus };
+        if uint256_x >= effective_modulus {
+            rt.invalid_argument = true;
+            return None;
+        }
+        let result: BigUint =
+            (&effective_modulus + uint256_x - uint256_y % &effective_modulus) % &effective_modulus;
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(32, 0u8); // Pad the result to 32 bytes.
+
+        // Convert the result to little endian u32 words.
+        let result = bytes_to_words_le::<8>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_sub_mod_events.push(Uint256SubModEvent {
             lookup_id,
             shard,
             channel,
@@ -83,3 +265,593 @@ impl Syscall for Uint256MulSyscall {
         1
     }
 }
+
+pub(crate) struct Uint256InvModSyscall;
+
+impl Syscall for Uint256InvModSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        let modulus_ptr = arg2;
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(modulus_ptr) {
+            return None;
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the modulus.
+        let (modulus_memory_records, modulus) = rt.mr_slice(modulus_ptr, WORDS_FIELD_ELEMENT);
+
+        // Get the BigUint values for x and the modulus.
+        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint256_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
+
+        // Compute the inverse of x. If x is not invertible, the result is zero, which is never a
+        // valid inverse for a modulus greater than one. Modulo one, zero is the inverse of every
+        // value.
+        let effective_modulus =
+            if uint256_modulus.is_zero() { BigUint::one() << 256 } else { uint256_modulus };
+        let result = uint256_x.modinv(&effective_modulus).unwrap_or_else(BigUint::zero);
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(32, 0u8); // Pad the result to 32 bytes.
+
+        // Convert the result to little endian u32 words.
+        let result = bytes_to_words_le::<8>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_inv_mod_events.push(Uint256InvModEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr,
+            x,
+            modulus_ptr,
+            modulus,
+            x_memory_records,
+            modulus_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256SquareModSyscall;
+
+impl Syscall for Uint256SquareModSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        let modulus_ptr = arg2;
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(modulus_ptr) {
+            return None;
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the modulus.
+        let (modulus_memory_records, modulus) = rt.mr_slice(modulus_ptr, WORDS_FIELD_ELEMENT);
+
+        // Get the BigUint values for x and the modulus.
+        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint256_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
+
+        // Square x and take the result modulo the modulus. A zero modulus means the result is
+        // taken modulo 2^256. The chip needs the quotient of the square to fit in 256 bits, so an
+        // unreduced x is rejected.
+        let effective_modulus =
+            if uint256_modulus.is_zero() { BigUint::one() << 256 } else { uint256_modulus };
+        if uint256_x >= effective_modulus {
+            rt.invalid_argument = true;
+            return None;
+        }
+        let result = (&uint256_x * &uint256_x) % effective_modulus;
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(32, 0u8); // Pad the result to 32 bytes.
+
+        // Convert the result to little endian u32 words.
+        let result = bytes_to_words_le::<8>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_square_mod_events.push(Uint256SquareModEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr,
+            x,
+            modulus_ptr,
+            modulus,
+            x_memory_records,
+            modulus_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256MulWideSyscall;
+
+impl Syscall for Uint256MulWideSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        let y_ptr = arg2;
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(y_ptr) {
+            return None;
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the y value.
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, WORDS_FIELD_ELEMENT);
+
+        // Get the BigUint values for x and y. The product always fits in 512 bits.
+        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint256_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&y));
+        let result = uint256_x * uint256_y;
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(64, 0u8); // Pad the result to 64 bytes.
+
+        // Convert the result to little endian u32 words.
+        let result = bytes_to_words_le::<16>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the product over x and the 8 words after it, and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_mul_wide_events.push(Uint256MulWideEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            x_memory_records,
+            y_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256RangeCheckSyscall;
+
+impl Syscall for Uint256RangeCheckSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let value_ptr = arg1;
+        let prime_ptr = arg2;
+        if !rt.check_word_aligned(value_ptr) || !rt.check_word_aligned(prime_ptr) {
+            return None;
+        }
+
+        // Read the value and the prime.
+        let (value_memory_records, value) = rt.mr_slice(value_ptr, WORDS_FIELD_ELEMENT);
+        let (prime_memory_records, prime) = rt.mr_slice(prime_ptr, WORDS_FIELD_ELEMENT);
+
+        // The result is zero if the value is less than the prime, and one otherwise.
+        let is_less = value.iter().rev().lt(prime.iter().rev());
+        let result = !is_less as u32;
+
+        // Increment clk so that the write is not at the same cycle as the reads.
+        rt.clk += 1;
+        // Write the result to the word after the value, and keep track of the memory record.
+        let result_ptr = value_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        let result_memory_record = rt.mw(result_ptr, result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_range_check_events.push(Uint256RangeCheckEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            value_ptr,
+            value,
+            prime_ptr,
+            prime,
+            value_memory_records,
+            prime_memory_records,
+            result_memory_record,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256CmpSyscall;
+
+impl Syscall for Uint256CmpSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        let y_ptr = arg2;
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(y_ptr) {
+            return None;
+        }
+
+        // Read x and y.
+        let (x_memory_records, x) = rt.mr_slice(x_ptr, WORDS_FIELD_ELEMENT);
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, WORDS_FIELD_ELEMENT);
+
+        // The result is -1, 0 or 1 as x is less than, equal to or greater than y.
+        let result = x.iter().rev().cmp(y.iter().rev()) as i32 as u32;
+
+        // Increment clk so that the write is not at the same cycle as the reads.
+        rt.clk += 1;
+        // Write the result to the word after x, and keep track of the memory record.
+        let result_ptr = x_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        let result_memory_record = rt.mw(result_ptr, result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_cmp_events.push(Uint256CmpEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            x_memory_records,
+            y_memory_records,
+            result_memory_record,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256BitwiseSyscall {
+    op: ByteOpcode,
+}
+
+impl Uint256BitwiseSyscall {
+    pub const fn new(op: ByteOpcode) -> Self {
+        Self { op }
+    }
+}
+
+impl Syscall for Uint256BitwiseSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        let y_ptr = arg2;
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(y_ptr) {
+            return None;
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the y value.
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, WORDS_FIELD_ELEMENT);
+
+        // Apply the operation word by word.
+        let result = x
+            .iter()
+            .zip(y.iter())
+            .map(|(x, y)| match self.op {
+                ByteOpcode::AND => x & y,
+                ByteOpcode::OR => x | y,
+                ByteOpcode::XOR => x ^ y,
+                _ => panic!("Invalid operation"),
+            })
+            .collect::<Vec<_>>();
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_bitwise_events.push(Uint256BitwiseEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            op: self.op,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            x_memory_records,
+            y_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256DivRemSyscall;
+
+impl Syscall for Uint256DivRemSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        let divisor_ptr = arg2;
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(divisor_ptr) {
+            return None;
+        }
+
+        // The remainder is written right after the divisor. It is written at the same cycle as
+        // the quotient, so the two outputs must not overlap.
+        let remainder_ptr = divisor_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        if x_ptr.abs_diff(remainder_ptr) < WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32 {
+            rt.invalid_pointer = Some(remainder_ptr);
+            return None;
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the quotient to x later.
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the divisor.
+        let (divisor_memory_records, divisor) = rt.mr_slice(divisor_ptr, WORDS_FIELD_ELEMENT);
+
+        // Get the BigUint values for x and the divisor.
+        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint256_divisor = BigUint::from_bytes_le(&words_to_bytes_le_vec(&divisor));
+        if uint256_divisor.is_zero() {
+            rt.division_by_zero = true;
+            return None;
+        }
+
+        let quotient = &uint256_x / &uint256_divisor;
+        let remainder = uint256_x % uint256_divisor;
+
+        // Convert the results to little endian u32 words, padded to the full width.
+        let mut quotient = quotient.to_u32_digits();
+        quotient.resize(WORDS_FIELD_ELEMENT, 0);
+        let mut remainder = remainder.to_u32_digits();
+        remainder.resize(WORDS_FIELD_ELEMENT, 0);
+
+        // Increment clk so that the writes are not at the same cycle as the reads.
+        rt.clk += 1;
+        // Write the quotient to x and the remainder after the divisor, and keep track of the
+        // memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &quotient);
+        let remainder_memory_records = rt.mw_slice(remainder_ptr, &remainder);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_divrem_events.push(Uint256DivRemEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr,
+            x,
+            divisor_ptr,
+            divisor,
+            x_memory_records,
+            divisor_memory_records,
+            remainder_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256MontMulSyscall;
+
+impl Syscall for Uint256MontMulSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        let y_ptr = arg2;
+        if !rt.check_word_aligned(x_ptr) || !rt.check_word_aligned(y_ptr) {
+            return None;
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the y value. As with `UINT256_MUL`, the pointers may be equal.
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, WORDS_FIELD_ELEMENT);
+
+        // The modulus is stored after the y value. We increment the pointer by the number of words.
+        let modulus_ptr = y_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        let (modulus_memory_records, modulus) = rt.mr_slice(modulus_ptr, WORDS_FIELD_ELEMENT);
+
+        // Get the BigUint values for x, y, and the modulus.
+        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint256_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&y));
+        let uint256_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
+
+        // Compute the Montgomery product x * y * R^-1 % modulus with R = 2^256, which requires R
+        // to be invertible modulo the modulus, so an even modulus is rejected.
+        let Some(r_inverse) = uint256_modulus
+            .bit(0)
+            .then(|| (BigUint::one() << 256).modinv(&uint256_modulus))
+            .flatten()
+        else {
+            rt.invalid_argument = true;
+            return None;
+        };
+
+        // The chip bounds the quotient of x * y by the modulus to 256 bits, which needs x to be
+        // reduced.
+        if uint256_x >= uint256_modulus {
+            rt.invalid_argument = true;
+            return None;
+        }
+        let result = (uint256_x * uint256_y % &uint256_modulus) * r_inverse % &uint256_modulus;
+
+        // Convert the result to little endian u32 words, padded to the full width.
+        let mut result = result.to_u32_digits();
+        result.resize(WORDS_FIELD_ELEMENT, 0);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_mont_mul_events.push(UintMulEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            modulus,
+            x_memory_records,
+            y_memory_records,
+            modulus_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Uint256ModExpSyscall;
+
+impl Syscall for Uint256ModExpSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let base_ptr = arg1;
+        let exponent_ptr = arg2;
+        if !rt.check_word_aligned(base_ptr) || !rt.check_word_aligned(exponent_ptr) {
+            return None;
+        }
+
+        // First read the words for the base. We can read a slice_unsafe here because we write the
+        // computed result to the base later.
+        let base = rt.slice_unsafe(base_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the exponent.
+        let (exponent_memory_records, exponent) = rt.mr_slice(exponent_ptr, WORDS_FIELD_ELEMENT);
+
+        // The modulus is stored after the exponent. We increment the pointer by the number of
+        // words.
+        let modulus_ptr = exponent_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        let (modulus_memory_records, modulus) = rt.mr_slice(modulus_ptr, WORDS_FIELD_ELEMENT);
+
+        // Get the BigUint values for the base, the exponent, and the modulus.
+        let uint256_base = BigUint::from_bytes_le(&words_to_bytes_le_vec(&base));
+        let uint256_exponent = BigUint::from_bytes_le(&words_to_bytes_le_vec(&exponent));
+        let uint256_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
+
+        // As with `UINT256_MUL`, a zero modulus means the result is taken modulo 2^256.
+        let effective_modulus =
+            if uint256_modulus.is_zero() { BigUint::one() << 256 } else { uint256_modulus };
+        let result = uint256_base.modpow(&uint256_exponent, &effective_modulus);
+
+        // Convert the result to little endian u32 words, padded to the full width.
+        let mut result = result.to_u32_digits();
+        result.resize(WORDS_FIELD_ELEMENT, 0);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to the base and keep track of the memory records.
+        let base_memory_records = rt.mw_slice(base_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().uint256_mod_exp_events.push(UintMulEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr: base_ptr,
+            x: base,
+            y_ptr: exponent_ptr,
+            y: exponent,
+            modulus,
+            x_memory_records: base_memory_records,
+            y_memory_records: exponent_memory_records,
+            modulus_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -25,6 +25,9 @@ impl<E: EllipticCurve> Syscall for WeierstrassAddAssignSyscall<E> {
             CurveType::Secp256k1 => rt.record_mut().secp256k1_add_events.push(event),
             CurveType::Bn254 => rt.record_mut().bn254_add_events.push(event),
             CurveType::Bls12381 => rt.record_mut().bls12381_add_events.push(event),
+            CurveType::P256 => rt.record_mut().p256_add_events.push(event),
+            CurveType::P384 => rt.record_mut().p384_add_events.push(event),
+            CurveType::Grumpkin => rt.record_mut().grumpkin_add_events.push(event),
             _ => panic!("Unsupported curve"),
         }
         None
@@ -24,6 +24,8 @@ impl<E: EllipticCurve> Syscall for WeierstrassDecompressSyscall<E> {
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => rt.record_mut().k256_decompress_events.push(event),
             CurveType::Bls12381 => rt.record_mut().bls12381_decompress_events.push(event),
+            CurveType::P256 => rt.record_mut().p256_decompress_events.push(event),
+            CurveType::P384 => rt.record_mut().p384_decompress_events.push(event),
             _ => panic!("Unsupported curve"),
         }
         None
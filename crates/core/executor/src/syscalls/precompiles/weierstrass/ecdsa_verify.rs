@@ -0,0 +1,74 @@
+use std::marker::PhantomData;
+
+use num::{BigUint, Zero};
+use sp1_curves::{
+    params::FieldParameters, weierstrass::WeierstrassParameters, CurveType, EllipticCurve,
+};
+
+use crate::{
+    events::{ecdsa_verify, EcdsaVerifyEvent, ECDSA_VERIFY_INPUT_WORDS},
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct WeierstrassEcdsaVerifySyscall<E: EllipticCurve> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EllipticCurve> WeierstrassEcdsaVerifySyscall<E> {
+    /// Create a new instance of the [`WeierstrassEcdsaVerifySyscall`].
+    pub const fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<E: EllipticCurve + WeierstrassParameters> Syscall for WeierstrassEcdsaVerifySyscall<E> {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, _: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let ptr = arg1;
+        if !rt.check_word_aligned(ptr) {
+            return None;
+        }
+
+        // The input is `r`, `s`, the message hash and the public key, followed by the result.
+        let (input_memory_records, input) = rt.mr_slice(ptr, ECDSA_VERIFY_INPUT_WORDS);
+        let [r, s, z, q_x, q_y] =
+            core::array::from_fn(|i| BigUint::from_slice(&input[8 * i..8 * (i + 1)]));
+        let order = E::prime_group_order();
+        let modulus = E::BaseField::modulus();
+        // A signature with `r` or `s` outside of `[1, order)`, or an unreduced public key, is
+        // reported as invalid.
+        let is_in_range = !r.is_zero()
+            && r < order
+            && !s.is_zero()
+            && s < order
+            && q_x < modulus
+            && q_y < modulus;
+        let is_valid = is_in_range && ecdsa_verify::<E>(&r, &s, &z, &q_x, &q_y);
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        rt.clk += 1;
+        let result_memory_record =
+            rt.mw(ptr + ECDSA_VERIFY_INPUT_WORDS as u32 * 4, is_valid as u32);
+
+        let event = EcdsaVerifyEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk: start_clk,
+            ptr,
+            input,
+            is_valid,
+            input_memory_records,
+            result_memory_record,
+        };
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => rt.record_mut().secp256k1_ecdsa_verify_events.push(event),
+            _ => panic!("Unsupported curve"),
+        }
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
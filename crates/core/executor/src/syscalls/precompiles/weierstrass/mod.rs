@@ -1,3 +1,7 @@
 pub mod add;
 pub mod decompress;
 pub mod double;
+pub mod ecdsa_verify;
+pub mod linear_combination;
+pub mod msm;
+pub mod mul;
@@ -0,0 +1,35 @@
+use std::marker::PhantomData;
+
+use sp1_curves::{weierstrass::WeierstrassParameters, CurveType, EllipticCurve};
+
+use crate::{
+    events::create_ec_mul_event,
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct WeierstrassMulAssignSyscall<E: EllipticCurve> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EllipticCurve> WeierstrassMulAssignSyscall<E> {
+    /// Create a new instance of the [`WeierstrassMulAssignSyscall`].
+    pub const fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<E: EllipticCurve + WeierstrassParameters> Syscall for WeierstrassMulAssignSyscall<E> {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let event = create_ec_mul_event::<E>(rt, arg1, arg2);
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => rt.record_mut().secp256k1_mul_events.push(event),
+            CurveType::P256 => rt.record_mut().p256_mul_events.push(event),
+            _ => panic!("Unsupported curve"),
+        }
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
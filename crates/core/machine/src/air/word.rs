@@ -85,6 +85,39 @@ pub trait WordAirBuilder: ByteAirBuilder {
         }
     }
 
+    /// Asserts that the little-endian bytes of `value` are a canonical BabyBear element, that is
+    /// each limb is a u8 and the word is less than the modulus `0x78000001`.
+    ///
+    /// Besides the byte range checks, no columns are needed: for each lower byte `b`, the value
+    /// `(0x78 - most_sig_byte) * 255 - b` is checked to be a u16. It is at most `0x78 * 255` when
+    /// the most significant byte is less than `0x78`, is `-b` when it is equal, and wraps around
+    /// the field when it is greater.
+    fn assert_in_field(
+        &mut self,
+        value: Word<impl Into<Self::Expr> + Clone>,
+        shard: impl Into<Self::Expr> + Clone,
+        channel: impl Into<Self::Expr> + Clone,
+        is_real: impl Into<Self::Expr> + Clone,
+    ) {
+        self.slice_range_check_u8(&value.0, shard.clone(), channel.clone(), is_real.clone());
+
+        let most_sig_byte: Self::Expr = value[WORD_SIZE - 1].clone().into();
+        for byte in value.0[..WORD_SIZE - 1].iter() {
+            let byte: Self::Expr = byte.clone().into();
+            self.send_byte(
+                Self::Expr::from_canonical_u8(ByteOpcode::U16Range as u8),
+                (Self::Expr::from_canonical_u8(0x78) - most_sig_byte.clone())
+                    * Self::Expr::from_canonical_u8(u8::MAX)
+                    - byte,
+                Self::Expr::zero(),
+                Self::Expr::zero(),
+                shard.clone(),
+                channel.clone(),
+                is_real.clone(),
+            );
+        }
+    }
+
     /// Check that each limb of the given slice is a u16.
     fn slice_range_check_u16(
         &mut self,
@@ -87,6 +87,33 @@ impl<AB: SP1AirBuilder + PairBuilder> Air<AB> for ByteChip<AB::F> {
                         channel_f,
                         mult,
                     ),
+                    ByteOpcode::SBOX => builder.receive_byte(
+                        field_op,
+                        local.sbox,
+                        local.b,
+                        AB::F::zero(),
+                        shard,
+                        channel_f,
+                        mult,
+                    ),
+                    ByteOpcode::POPCNT => builder.receive_byte(
+                        field_op,
+                        local.popcount,
+                        local.b,
+                        AB::F::zero(),
+                        shard,
+                        channel_f,
+                        mult,
+                    ),
+                    ByteOpcode::CLZ => builder.receive_byte(
+                        field_op,
+                        local.clz,
+                        local.b,
+                        AB::F::zero(),
+                        shard,
+                        channel_f,
+                        mult,
+                    ),
                 }
             }
         }
@@ -42,6 +42,15 @@ pub struct BytePreprocessedCols<T> {
 
     /// A u16 value used for `U16Range`.
     pub value_u16: T,
+
+    /// The AES S-box applied to `b`.
+    pub sbox: T,
+
+    /// The number of set bits in `b`.
+    pub popcount: T,
+
+    /// The number of leading zeros of `b`.
+    pub clz: T,
 }
 
 /// For each byte operation in the preprocessed table, a corresponding ByteMultCols row tracks the
@@ -13,6 +13,7 @@ use std::marker::PhantomData;
 use itertools::Itertools;
 use p3_field::Field;
 use p3_matrix::dense::RowMajorMatrix;
+use sp1_primitives::aes::AES_SBOX;
 
 use self::{
     columns::{BytePreprocessedCols, NUM_BYTE_PREPROCESSED_COLS},
@@ -21,7 +22,7 @@ use self::{
 use crate::bytes::trace::NUM_ROWS;
 
 /// The number of different byte operations.
-pub const NUM_BYTE_OPS: usize = 9;
+pub const NUM_BYTE_OPS: usize = 12;
 
 /// The number of different byte lookup channels.
 pub const NUM_BYTE_LOOKUP_CHANNELS: u8 = 16;
@@ -106,6 +107,21 @@ impl<F: Field> ByteChip<F> {
                             col.value_u16 = F::from_canonical_u32(v);
                             ByteLookupEvent::new(shard, channel, *opcode, v as u16, 0, 0, 0)
                         }
+                        ByteOpcode::SBOX => {
+                            let sbox = AES_SBOX[b as usize];
+                            col.sbox = F::from_canonical_u8(sbox);
+                            ByteLookupEvent::new(shard, channel, *opcode, sbox as u16, 0, b, 0)
+                        }
+                        ByteOpcode::POPCNT => {
+                            let popcount = b.count_ones();
+                            col.popcount = F::from_canonical_u32(popcount);
+                            ByteLookupEvent::new(shard, channel, *opcode, popcount as u16, 0, b, 0)
+                        }
+                        ByteOpcode::CLZ => {
+                            let clz = b.leading_zeros();
+                            col.clz = F::from_canonical_u32(clz);
+                            ByteLookupEvent::new(shard, channel, *opcode, clz as u16, 0, b, 0)
+                        }
                     };
                 }
             }
@@ -0,0 +1,253 @@
+use num::BigUint;
+
+use p3_field::PrimeField32;
+
+use sp1_core_executor::events::{ByteRecord, FieldOperation};
+use sp1_curves::params::{FieldParameters, Limbs};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{Polynomial, SP1AirBuilder};
+
+use super::field_op::FieldOpCols;
+
+/// A set of columns to compute the cube `a * a * a mod M` of a field element.
+///
+/// The product of three operands does not fit in the carry and witness of a single
+/// [`FieldOpCols`], so this is the square `a * a`, followed by the multiplication of `a` with the
+/// square. The square only reads the columns of `a`, so both steps are degree two in the limbs.
+/// It inherits the safety assumptions of [`FieldOpCols`] on the operand.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct FieldCubeCols<T, P: FieldParameters> {
+    pub(crate) square: FieldOpCols<T, P>,
+    /// The cube, in `cube.result`.
+    pub cube: FieldOpCols<T, P>,
+}
+
+impl<F: PrimeField32, P: FieldParameters> FieldCubeCols<F, P> {
+    /// Populate these columns with a specified modulus.
+    pub fn populate_with_modulus(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        a: &BigUint,
+        modulus: &BigUint,
+    ) -> BigUint {
+        let square = self.square.populate_with_modulus(
+            record,
+            shard,
+            channel,
+            a,
+            a,
+            modulus,
+            FieldOperation::Square,
+        );
+        self.cube.populate_with_modulus(
+            record,
+            shard,
+            channel,
+            a,
+            &square,
+            modulus,
+            FieldOperation::Mul,
+        )
+    }
+
+    /// Populate these columns with the modulus of the field parameters.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        a: &BigUint,
+    ) -> BigUint {
+        self.populate_with_modulus(record, shard, channel, a, &P::modulus())
+    }
+}
+
+impl<V: Copy, P: FieldParameters> FieldCubeCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    pub fn result(&self) -> Limbs<V, P::Limbs> {
+        self.cube.result
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval_with_modulus<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        modulus: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        self.square.eval_with_modulus(
+            builder,
+            a,
+            a,
+            modulus,
+            FieldOperation::Square,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.cube.eval_with_modulus(
+            builder,
+            a,
+            &self.square.result,
+            modulus,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        let p_limbs = Polynomial::from_iter(P::modulus_field_iter::<AB::F>().map(AB::Expr::from));
+        self.eval_with_modulus::<AB>(builder, a, &p_limbs, shard, channel, is_real);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigUint, One, Zero};
+    use p3_air::BaseAir;
+    use p3_field::{Field, PrimeField32};
+    use sp1_core_executor::{ExecutionRecord, Program};
+    use sp1_curves::params::{FieldParameters, Limbs};
+    use sp1_stark::air::{MachineAir, SP1AirBuilder};
+
+    use crate::utils::{pad_to_power_of_two, uni_stark_prove as prove, uni_stark_verify as verify};
+    use core::{
+        borrow::{Borrow, BorrowMut},
+        mem::size_of,
+    };
+    use num::bigint::RandBigInt;
+    use p3_air::Air;
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+    use p3_matrix::{dense::RowMajorMatrix, Matrix};
+    use rand::thread_rng;
+    use sp1_core_executor::events::ByteRecord;
+    use sp1_curves::edwards::ed25519::Ed25519BaseField;
+    use sp1_derive::AlignedBorrow;
+    use sp1_stark::{baby_bear_poseidon2::BabyBearPoseidon2, StarkGenericConfig};
+
+    use super::FieldCubeCols;
+
+    #[derive(AlignedBorrow, Debug)]
+    pub struct TestCols<T, P: FieldParameters> {
+        pub a: Limbs<T, P::Limbs>,
+        pub a_cubed: FieldCubeCols<T, P>,
+    }
+
+    pub const NUM_TEST_COLS: usize = size_of::<TestCols<u8, Ed25519BaseField>>();
+
+    struct FieldCubeChip<P: FieldParameters> {
+        pub _phantom: std::marker::PhantomData<P>,
+    }
+
+    impl<P: FieldParameters> FieldCubeChip<P> {
+        pub const fn new() -> Self {
+            Self { _phantom: std::marker::PhantomData }
+        }
+    }
+
+    impl<F: PrimeField32, P: FieldParameters> MachineAir<F> for FieldCubeChip<P> {
+        type Record = ExecutionRecord;
+
+        type Program = Program;
+
+        fn name(&self) -> String {
+            "FieldCube".to_string()
+        }
+
+        fn generate_trace(
+            &self,
+            _: &ExecutionRecord,
+            output: &mut ExecutionRecord,
+        ) -> RowMajorMatrix<F> {
+            let mut rng = thread_rng();
+            let num_rows = 1 << 8;
+            let mut operands: Vec<BigUint> =
+                (0..num_rows - 3).map(|_| rng.gen_biguint(256) % &P::modulus()).collect();
+
+            // Hardcoded edge cases.
+            operands.extend(vec![BigUint::zero(), BigUint::one(), P::modulus() - 1u32]);
+
+            let rows = operands
+                .iter()
+                .map(|a| {
+                    let mut blu_events = Vec::new();
+                    let mut row = [F::zero(); NUM_TEST_COLS];
+                    let cols: &mut TestCols<F, P> = row.as_mut_slice().borrow_mut();
+                    cols.a = P::to_limbs_field::<F, _>(a);
+                    let result = cols.a_cubed.populate(&mut blu_events, 1, 0, a);
+                    assert_eq!(result, a.modpow(&BigUint::from(3u32), &P::modulus()));
+                    output.add_byte_lookup_events(blu_events);
+                    row
+                })
+                .collect::<Vec<_>>();
+            // Convert the trace to a row major matrix.
+            let mut trace =
+                RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_TEST_COLS);
+
+            // Pad the trace to a power of two.
+            pad_to_power_of_two::<NUM_TEST_COLS, F>(&mut trace.values);
+
+            trace
+        }
+
+        fn included(&self, _: &Self::Record) -> bool {
+            true
+        }
+    }
+
+    impl<F: Field, P: FieldParameters> BaseAir<F> for FieldCubeChip<P> {
+        fn width(&self) -> usize {
+            NUM_TEST_COLS
+        }
+    }
+
+    impl<AB, P: FieldParameters> Air<AB> for FieldCubeChip<P>
+    where
+        AB: SP1AirBuilder,
+        Limbs<AB::Var, P::Limbs>: Copy,
+    {
+        fn eval(&self, builder: &mut AB) {
+            let main = builder.main();
+            let local = main.row_slice(0);
+            let local: &TestCols<AB::Var, P> = (*local).borrow();
+            local.a_cubed.eval(builder, &local.a, AB::F::one(), AB::F::zero(), AB::F::one());
+        }
+    }
+
+    #[test]
+    fn prove_babybear() {
+        let config = BabyBearPoseidon2::new();
+        let mut challenger = config.challenger();
+
+        let chip: FieldCubeChip<Ed25519BaseField> = FieldCubeChip::new();
+        let shard = ExecutionRecord::default();
+        let trace: RowMajorMatrix<BabyBear> =
+            chip.generate_trace(&shard, &mut ExecutionRecord::default());
+        let proof = prove::<BabyBearPoseidon2, _>(&config, &chip, &mut challenger, trace);
+
+        let mut challenger = config.challenger();
+        verify(&config, &chip, &mut challenger, &proof).unwrap();
+    }
+}
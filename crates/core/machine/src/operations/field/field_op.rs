@@ -28,6 +28,10 @@ use typenum::Unsigned;
 /// * When `op` is `FieldOperation::Mul`, then `result = a * b mod M`.
 /// * When `op` is `FieldOperation::Sub`, then `result = a - b mod M`.
 /// * When `op` is `FieldOperation::Div`, then `result * b = a mod M`.
+/// * When `op` is `FieldOperation::Square`, then `result = a * a mod M`, and `b` is ignored.
+///
+/// `FieldOperation::Cube` does not fit in a single set of columns, since the carry and witness are
+/// sized for a product of two operands. Use [`super::field_cube::FieldCubeCols`] instead.
 ///
 /// **Warning**: The constraints do not check for division by zero. The caller is responsible for
 /// ensuring that the division operation is valid.
@@ -54,14 +58,22 @@ impl<F: PrimeField32, P: FieldParameters> FieldOpCols<F, P> {
         let (result, carry) = match op {
             FieldOperation::Add => ((a + b) % modulus, (a + b - (a + b) % modulus) / modulus),
             FieldOperation::Mul => ((a * b) % modulus, (a * b - (a * b) % modulus) / modulus),
-            FieldOperation::Sub | FieldOperation::Div => unreachable!(),
+            FieldOperation::Sub
+            | FieldOperation::Div
+            | FieldOperation::Square
+            | FieldOperation::Cube => unreachable!(),
         };
         debug_assert!(&result < modulus);
-        debug_assert!(&carry < modulus);
+        // The operands of a precompile such as `addmod` need not be reduced, so the carry is only
+        // required to fit in the carry limbs rather than being smaller than the modulus.
+        debug_assert!(carry.bits() as usize <= P::nb_bits());
         match op {
             FieldOperation::Add => debug_assert_eq!(&carry * modulus, a + b - &result),
             FieldOperation::Mul => debug_assert_eq!(&carry * modulus, a * b - &result),
-            FieldOperation::Sub | FieldOperation::Div => unreachable!(),
+            FieldOperation::Sub
+            | FieldOperation::Div
+            | FieldOperation::Square
+            | FieldOperation::Cube => unreachable!(),
         }
 
         // Here we have special logic for p_modulus because to_limbs_field only works for numbers in
@@ -77,7 +89,10 @@ impl<F: PrimeField32, P: FieldParameters> FieldOpCols<F, P> {
         let p_op = match op {
             FieldOperation::Add => &p_a + &p_b,
             FieldOperation::Mul => &p_a * &p_b,
-            FieldOperation::Sub | FieldOperation::Div => unreachable!(),
+            FieldOperation::Sub
+            | FieldOperation::Div
+            | FieldOperation::Square
+            | FieldOperation::Cube => unreachable!(),
         };
         let p_vanishing: Polynomial<F> = &p_op - &p_result - &p_carry * &p_modulus;
 
@@ -121,7 +136,9 @@ impl<F: PrimeField32, P: FieldParameters> FieldOpCols<F, P> {
         let result = match op {
             // If doing the subtraction operation, a - b = result, equivalent to a = result + b.
             FieldOperation::Sub => {
-                let result = (modulus.clone() + a - b) % modulus;
+                // Reduce b first so that the subtraction cannot underflow when b exceeds the
+                // modulus.
+                let result = (modulus.clone() + a - b % modulus) % modulus;
                 // We populate the carry, witness_low, witness_high as if we were doing an addition
                 // with result + b. But we populate `result` with the actual result
                 // of the subtraction because those columns are expected to contain
@@ -148,7 +165,16 @@ impl<F: PrimeField32, P: FieldParameters> FieldOpCols<F, P> {
                 self.result = P::to_limbs_field::<F, _>(&result);
                 result
             }
-            _ => self.populate_carry_and_witness(a, b, op, modulus),
+            // The square is a multiplication of `a` with itself, so `b` is not read.
+            FieldOperation::Square => {
+                self.populate_carry_and_witness(a, a, FieldOperation::Mul, modulus)
+            }
+            FieldOperation::Cube => {
+                panic!("cubing needs an intermediate square, use `FieldCubeCols` instead")
+            }
+            FieldOperation::Add | FieldOperation::Mul => {
+                self.populate_carry_and_witness(a, b, op, modulus)
+            }
         };
 
         // Range checks
@@ -160,6 +186,33 @@ impl<F: PrimeField32, P: FieldParameters> FieldOpCols<F, P> {
         result
     }
 
+    /// Populate these columns for the division `a / b = result` with a caller-supplied `result`,
+    /// returning `a = result * b mod modulus`. This is useful when the modulus is not prime, as in
+    /// the `invmod` precompile, so that the quotient cannot be found with Fermat's little theorem.
+    #[allow(clippy::too_many_arguments)]
+    pub fn populate_div_with_quotient(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        result: &BigUint,
+        b: &BigUint,
+        modulus: &BigUint,
+    ) -> BigUint {
+        // As in the `Div` case of `populate_with_modulus`, the carry and witness are those of the
+        // multiplication result * b, while `result` holds the quotient.
+        let a = self.populate_carry_and_witness(result, b, FieldOperation::Mul, modulus);
+        self.result = P::to_limbs_field::<F, _>(result);
+
+        // Range checks
+        record.add_u8_range_checks_field(shard, channel, &self.result.0);
+        record.add_u8_range_checks_field(shard, channel, &self.carry.0);
+        record.add_u8_range_checks_field(shard, channel, &self.witness_low.0);
+        record.add_u8_range_checks_field(shard, channel, &self.witness_high.0);
+
+        a
+    }
+
     /// Populate these columns without a specified modulus (will use the modulus of the field
     /// parameters).
     pub fn populate(
@@ -243,12 +296,20 @@ impl<V: Copy, P: FieldParameters> FieldOpCols<V, P> {
         let p_b: Polynomial<AB::Expr> = (b).clone().into();
 
         let (p_a, p_result): (Polynomial<_>, Polynomial<_>) = match op {
-            FieldOperation::Add | FieldOperation::Mul => (p_a_param, self.result.into()),
+            FieldOperation::Add | FieldOperation::Mul | FieldOperation::Square => {
+                (p_a_param, self.result.into())
+            }
             FieldOperation::Sub | FieldOperation::Div => (self.result.into(), p_a_param),
+            FieldOperation::Cube => {
+                panic!("cubing needs an intermediate square, use `FieldCubeCols` instead")
+            }
         };
         let p_op: Polynomial<<AB as AirBuilder>::Expr> = match op {
             FieldOperation::Add | FieldOperation::Sub => p_a + p_b,
             FieldOperation::Mul | FieldOperation::Div => p_a * p_b,
+            // Only the columns of `a` appear in the product.
+            FieldOperation::Square => &p_a * &p_a,
+            FieldOperation::Cube => unreachable!(),
         };
         self.eval_with_polynomials(
             builder,
@@ -355,7 +416,8 @@ mod tests {
     use rand::thread_rng;
     use sp1_core_executor::events::ByteRecord;
     use sp1_curves::{
-        edwards::ed25519::Ed25519BaseField, weierstrass::secp256k1::Secp256k1BaseField,
+        edwards::ed25519::Ed25519BaseField,
+        weierstrass::{bn254::Bn254ScalarField, secp256k1::Secp256k1BaseField},
     };
     use sp1_derive::AlignedBorrow;
     use sp1_stark::baby_bear_poseidon2::BabyBearPoseidon2;
@@ -472,7 +534,10 @@ mod tests {
 
     #[test]
     fn generate_trace() {
-        for op in [FieldOperation::Add, FieldOperation::Mul, FieldOperation::Sub].iter() {
+        for op in
+            [FieldOperation::Add, FieldOperation::Mul, FieldOperation::Sub, FieldOperation::Square]
+                .iter()
+        {
             println!("op: {:?}", op);
             let chip: FieldOpChip<Ed25519BaseField> = FieldOpChip::new(*op);
             let shard = ExecutionRecord::default();
@@ -486,9 +551,14 @@ mod tests {
     fn prove_babybear() {
         let config = BabyBearPoseidon2::new();
 
-        for op in
-            [FieldOperation::Add, FieldOperation::Sub, FieldOperation::Mul, FieldOperation::Div]
-                .iter()
+        for op in [
+            FieldOperation::Add,
+            FieldOperation::Sub,
+            FieldOperation::Mul,
+            FieldOperation::Div,
+            FieldOperation::Square,
+        ]
+        .iter()
         {
             println!("op: {:?}", op);
 
@@ -504,4 +574,32 @@ mod tests {
             verify(&config, &chip, &mut challenger, &proof).unwrap();
         }
     }
+
+    #[test]
+    fn prove_babybear_bn254_scalar_field() {
+        // The scalar field of BN254 is the field of the group order `r`, so this checks operations
+        // like `a * b mod r`.
+        let config = BabyBearPoseidon2::new();
+
+        for op in [
+            FieldOperation::Add,
+            FieldOperation::Sub,
+            FieldOperation::Mul,
+            FieldOperation::Div,
+            FieldOperation::Square,
+        ]
+        .iter()
+        {
+            let mut challenger = config.challenger();
+
+            let chip: FieldOpChip<Bn254ScalarField> = FieldOpChip::new(*op);
+            let shard = ExecutionRecord::default();
+            let trace: RowMajorMatrix<BabyBear> =
+                chip.generate_trace(&shard, &mut ExecutionRecord::default());
+            let proof = prove::<BabyBearPoseidon2, _>(&config, &chip, &mut challenger, trace);
+
+            let mut challenger = config.challenger();
+            verify(&config, &chip, &mut challenger, &proof).unwrap();
+        }
+    }
 }
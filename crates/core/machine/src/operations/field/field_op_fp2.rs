@@ -0,0 +1,277 @@
+use crate::air::WordAirBuilder;
+use num::{BigUint, Zero};
+
+use p3_air::AirBuilder;
+use p3_field::PrimeField32;
+
+use sp1_core_executor::events::{ByteRecord, FieldOperation};
+use sp1_curves::params::{FieldParameters, Limbs, NumLimbs};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
+
+use super::field_op::FieldOpCols;
+
+/// The limbs of an element `c0 + c1 * u` of `Fp2 = Fp[u]/(u^2 + 1)`, given as `[c0, c1]`.
+pub type Fp2Limbs<T, P> = [Limbs<T, <P as NumLimbs>::Limbs>; 2];
+
+/// A set of columns to compute an addition or subtraction in `Fp2 = Fp[u]/(u^2 + 1)`.
+///
+/// Both operations act on the two coefficients independently, so this is a pair of
+/// [`FieldOpCols`] and inherits its safety assumptions on the operands.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2OpCols<T, P: FieldParameters> {
+    pub c0: FieldOpCols<T, P>,
+    pub c1: FieldOpCols<T, P>,
+}
+
+/// A set of columns to compute a multiplication in `Fp2 = Fp[u]/(u^2 + 1)`.
+///
+/// The product is `(a0 * b0 - a1 * b1) + (a0 * b1 + a1 * b0) * u`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2MulCols<T, P: FieldParameters> {
+    pub(crate) a0_mul_b0: FieldOpCols<T, P>,
+    pub(crate) a1_mul_b1: FieldOpCols<T, P>,
+    pub(crate) a0_mul_b1: FieldOpCols<T, P>,
+    pub(crate) a1_mul_b0: FieldOpCols<T, P>,
+    pub c0: FieldOpCols<T, P>,
+    pub c1: FieldOpCols<T, P>,
+}
+
+/// A set of columns to compute a division `a / b` in `Fp2 = Fp[u]/(u^2 + 1)`.
+///
+/// The quotient is a witness, and the constraints assert that `result * b = a`. As with
+/// [`FieldOpCols`], division by zero is not checked, and is only accepted when dividing zero so
+/// that padded rows can be all zero.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2DivCols<T, P: FieldParameters> {
+    /// The quotient `a / b`.
+    pub result: Fp2Limbs<T, P>,
+    pub(crate) product: Fp2MulCols<T, P>,
+}
+
+impl<F: PrimeField32, P: FieldParameters> Fp2OpCols<F, P> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        a: &[BigUint; 2],
+        b: &[BigUint; 2],
+        op: FieldOperation,
+    ) -> [BigUint; 2] {
+        assert!(
+            op == FieldOperation::Add || op == FieldOperation::Sub,
+            "only addition and subtraction act coefficient-wise"
+        );
+        let c0 = self.c0.populate(record, shard, channel, &a[0], &b[0], op);
+        let c1 = self.c1.populate(record, shard, channel, &a[1], &b[1], op);
+        [c0, c1]
+    }
+}
+
+impl<V: Copy, P: FieldParameters> Fp2OpCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    pub fn result(&self) -> Fp2Limbs<V, P> {
+        [self.c0.result, self.c1.result]
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &Fp2Limbs<V, P>,
+        b: &Fp2Limbs<V, P>,
+        op: FieldOperation,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        self.c0.eval(builder, &a[0], &b[0], op, shard.clone(), channel.clone(), is_real.clone());
+        self.c1.eval(builder, &a[1], &b[1], op, shard, channel, is_real);
+    }
+}
+
+impl<F: PrimeField32, P: FieldParameters> Fp2MulCols<F, P> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        a: &[BigUint; 2],
+        b: &[BigUint; 2],
+    ) -> [BigUint; 2] {
+        let a0_mul_b0 =
+            self.a0_mul_b0.populate(record, shard, channel, &a[0], &b[0], FieldOperation::Mul);
+        let a1_mul_b1 =
+            self.a1_mul_b1.populate(record, shard, channel, &a[1], &b[1], FieldOperation::Mul);
+        let a0_mul_b1 =
+            self.a0_mul_b1.populate(record, shard, channel, &a[0], &b[1], FieldOperation::Mul);
+        let a1_mul_b0 =
+            self.a1_mul_b0.populate(record, shard, channel, &a[1], &b[0], FieldOperation::Mul);
+        let c0 =
+            self.c0.populate(record, shard, channel, &a0_mul_b0, &a1_mul_b1, FieldOperation::Sub);
+        let c1 =
+            self.c1.populate(record, shard, channel, &a0_mul_b1, &a1_mul_b0, FieldOperation::Add);
+        [c0, c1]
+    }
+}
+
+impl<V: Copy, P: FieldParameters> Fp2MulCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    pub fn result(&self) -> Fp2Limbs<V, P> {
+        [self.c0.result, self.c1.result]
+    }
+
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &Fp2Limbs<V, P>,
+        b: &Fp2Limbs<V, P>,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        let mul = FieldOperation::Mul;
+        self.a0_mul_b0.eval(
+            builder,
+            &a[0],
+            &b[0],
+            mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.a1_mul_b1.eval(
+            builder,
+            &a[1],
+            &b[1],
+            mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.a0_mul_b1.eval(
+            builder,
+            &a[0],
+            &b[1],
+            mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.a1_mul_b0.eval(
+            builder,
+            &a[1],
+            &b[0],
+            mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.c0.eval(
+            builder,
+            &self.a0_mul_b0.result,
+            &self.a1_mul_b1.result,
+            FieldOperation::Sub,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.c1.eval(
+            builder,
+            &self.a0_mul_b1.result,
+            &self.a1_mul_b0.result,
+            FieldOperation::Add,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
+
+impl<F: PrimeField32, P: FieldParameters> Fp2DivCols<F, P> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        a: &[BigUint; 2],
+        b: &[BigUint; 2],
+    ) -> [BigUint; 2] {
+        let modulus = P::modulus();
+        let result = if b[0].is_zero() && b[1].is_zero() {
+            // Division by 0 is allowed only when dividing 0 so that padded rows can be all 0.
+            assert!(
+                a[0].is_zero() && a[1].is_zero(),
+                "division by zero is allowed only when dividing zero"
+            );
+            [BigUint::zero(), BigUint::zero()]
+        } else {
+            // a / b = a * conj(b) / (b0^2 + b1^2), and the norm is inverted with Fermat's little
+            // theorem.
+            let norm = (&b[0] * &b[0] + &b[1] * &b[1]) % &modulus;
+            let norm_inv = norm.modpow(&(&modulus - 2u32), &modulus);
+            let c0 = (&a[0] * &b[0] + &a[1] * &b[1]) % &modulus;
+            let c1 = (&a[1] * &b[0] % &modulus + &modulus - &a[0] * &b[1] % &modulus) % &modulus;
+            [(c0 * &norm_inv) % &modulus, (c1 * &norm_inv) % &modulus]
+        };
+
+        self.result =
+            [P::to_limbs_field::<F, _>(&result[0]), P::to_limbs_field::<F, _>(&result[1])];
+        self.product.populate(record, shard, channel, &result, b);
+
+        // Range checks
+        record.add_u8_range_checks_field(shard, channel, &self.result[0].0);
+        record.add_u8_range_checks_field(shard, channel, &self.result[1].0);
+
+        result
+    }
+}
+
+impl<V: Copy, P: FieldParameters> Fp2DivCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &Fp2Limbs<V, P>,
+        b: &Fp2Limbs<V, P>,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        // result * b = a.
+        self.product.eval(
+            builder,
+            &self.result,
+            b,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        let product = self.product.result();
+        for i in 0..2 {
+            builder.when(is_real.clone()).assert_all_eq(product[i], a[i]);
+        }
+
+        // Range checks for the quotient, which is not the result of a `FieldOpCols`.
+        for limbs in self.result.iter() {
+            builder.slice_range_check_u8(&limbs.0, shard.clone(), channel.clone(), is_real.clone());
+        }
+    }
+}
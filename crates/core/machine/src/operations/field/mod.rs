@@ -1,6 +1,8 @@
+pub mod field_cube;
 pub mod field_den;
 pub mod field_inner_product;
 pub mod field_op;
+pub mod field_op_fp2;
 pub mod field_sqrt;
 // pub mod params;
 pub mod range;
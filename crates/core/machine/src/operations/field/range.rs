@@ -151,3 +151,74 @@ impl<V: Copy, P: FieldParameters> FieldLtCols<V, P> {
         )
     }
 }
+
+/// Operation columns for computing whether `value < bound`.
+///
+/// Unlike [`FieldLtCols`], the comparison may go either way: if `is_less` is set, `lhs < rhs` is
+/// `value < bound`, and otherwise it is `bound - 1 < value`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct FieldRangeCols<T, P: FieldParameters> {
+    /// Whether the value is less than the bound.
+    pub is_less: T,
+
+    pub(crate) lhs: Limbs<T, P::Limbs>,
+
+    pub(crate) rhs: Limbs<T, P::Limbs>,
+
+    pub(crate) lt: FieldLtCols<T, P>,
+}
+
+impl<F: PrimeField32, P: FieldParameters> FieldRangeCols<F, P> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        value: &BigUint,
+        bound: &BigUint,
+    ) -> bool {
+        let is_less = value < bound;
+        let (lhs, rhs) =
+            if is_less { (value.clone(), bound.clone()) } else { (bound - 1u32, value.clone()) };
+        self.is_less = F::from_bool(is_less);
+        self.lhs = P::to_limbs_field::<F, _>(&lhs);
+        self.rhs = P::to_limbs_field::<F, _>(&rhs);
+        self.lt.populate(record, shard, channel, &lhs, &rhs);
+        is_less
+    }
+}
+
+impl<V: Copy, P: FieldParameters> FieldRangeCols<V, P> {
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        value: &Limbs<V, P::Limbs>,
+        bound: &BigUint,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+        Limbs<V, P::Limbs>: Copy,
+    {
+        builder.when(is_real.clone()).assert_bool(self.is_less);
+
+        // The operands are the value and the bound, or the bound minus one and the value.
+        let bound_minus_one = P::to_limbs_field::<AB::Expr, _>(&(bound - 1u32));
+        let bound = P::to_limbs_field::<AB::Expr, _>(bound);
+        let is_not_less = AB::Expr::one() - self.is_less;
+        for i in 0..P::NB_LIMBS {
+            builder.when(is_real.clone()).assert_eq(
+                self.lhs[i],
+                self.is_less * value[i] + is_not_less.clone() * bound_minus_one[i].clone(),
+            );
+            builder.when(is_real.clone()).assert_eq(
+                self.rhs[i],
+                self.is_less * bound[i].clone() + is_not_less.clone() * value[i],
+            );
+        }
+
+        self.lt.eval(builder, &self.lhs, &self.rhs, shard, channel, is_real);
+    }
+}
@@ -1,7 +1,7 @@
 use p3_baby_bear::BabyBear;
 use sp1_core_executor::{syscalls::SyscallCode, ExecutionReport, Opcode};
 
-use crate::riscv::RiscvAirDiscriminants;
+use crate::{riscv::RiscvAirDiscriminants, syscall::precompiles::sha256::SHA256X4_LANES};
 
 use super::RiscvAir;
 
@@ -37,8 +37,17 @@ impl CostEstimator for ExecutionReport {
         total_area += (sha_extend_events as u64) * costs[&RiscvAirDiscriminants::Sha256Extend];
         total_chips += 1;
 
+        // The compressions are proven four at a time, except for the remainder.
         let sha_compress_events = self.syscall_counts[SyscallCode::SHA_COMPRESS];
-        total_area += (sha_compress_events as u64) * costs[&RiscvAirDiscriminants::Sha256Compress];
+        total_area += (sha_compress_events as u64 % SHA256X4_LANES as u64)
+            * costs[&RiscvAirDiscriminants::Sha256Compress];
+        total_chips += 1;
+        total_area += (sha_compress_events as u64 / SHA256X4_LANES as u64)
+            * costs[&RiscvAirDiscriminants::Sha256x4];
+        total_chips += 1;
+
+        let sha256_hash_events = self.syscall_counts[SyscallCode::SHA256_HASH];
+        total_area += (sha256_hash_events as u64) * costs[&RiscvAirDiscriminants::Sha256Hash];
         total_chips += 1;
 
         let ed_add_events = self.syscall_counts[SyscallCode::ED_ADD];
@@ -50,6 +59,15 @@ impl CostEstimator for ExecutionReport {
             (ed_decompress_events as u64) * costs[&RiscvAirDiscriminants::Ed25519Decompress];
         total_chips += 1;
 
+        let x25519_ladder_step_events = self.syscall_counts[SyscallCode::X25519_LADDER_STEP];
+        total_area +=
+            (x25519_ladder_step_events as u64) * costs[&RiscvAirDiscriminants::X25519LadderStep];
+        total_chips += 1;
+
+        let ed25519_verify_events = self.syscall_counts[SyscallCode::ED25519_VERIFY];
+        total_area += (ed25519_verify_events as u64) * costs[&RiscvAirDiscriminants::Ed25519Verify];
+        total_chips += 1;
+
         let k256_decompress_events = self.syscall_counts[SyscallCode::SECP256K1_DECOMPRESS];
         total_area +=
             (k256_decompress_events as u64) * costs[&RiscvAirDiscriminants::K256Decompress];
@@ -64,10 +82,39 @@ impl CostEstimator for ExecutionReport {
             (secp256k1_double_events as u64) * costs[&RiscvAirDiscriminants::Secp256k1Double];
         total_chips += 1;
 
+        let secp256k1_mul_events = self.syscall_counts[SyscallCode::SECP256K1_MUL];
+        total_area += (secp256k1_mul_events as u64) * costs[&RiscvAirDiscriminants::Secp256k1Mul];
+        total_chips += 1;
+
+        let secp256k1_msm_events = self.syscall_counts[SyscallCode::SECP256K1_MSM];
+        total_area += (secp256k1_msm_events as u64) * costs[&RiscvAirDiscriminants::Secp256k1Msm];
+        total_chips += 1;
+
+        let secp256k1_ecdsa_verify_events =
+            self.syscall_counts[SyscallCode::SECP256K1_ECDSA_VERIFY];
+        total_area += (secp256k1_ecdsa_verify_events as u64)
+            * costs[&RiscvAirDiscriminants::Secp256k1EcdsaVerify];
+        total_chips += 1;
+
         let keccak256_permute_events = self.syscall_counts[SyscallCode::KECCAK_PERMUTE];
         total_area += (keccak256_permute_events as u64) * costs[&RiscvAirDiscriminants::KeccakP];
         total_chips += 1;
 
+        let blake3_compress_events = self.syscall_counts[SyscallCode::BLAKE3_COMPRESS];
+        total_area +=
+            (blake3_compress_events as u64) * costs[&RiscvAirDiscriminants::Blake3Compress];
+        total_chips += 1;
+
+        let blake2b_compress_events = self.syscall_counts[SyscallCode::BLAKE2B_COMPRESS];
+        total_area +=
+            (blake2b_compress_events as u64) * costs[&RiscvAirDiscriminants::Blake2bCompress];
+        total_chips += 1;
+
+        let ripemd160_compress_events = self.syscall_counts[SyscallCode::RIPEMD160_COMPRESS];
+        total_area +=
+            (ripemd160_compress_events as u64) * costs[&RiscvAirDiscriminants::Ripemd160Compress];
+        total_chips += 1;
+
         let bn254_add_events = self.syscall_counts[SyscallCode::BN254_ADD];
         total_area += (bn254_add_events as u64) * costs[&RiscvAirDiscriminants::Bn254Add];
         total_chips += 1;
@@ -85,10 +132,158 @@ impl CostEstimator for ExecutionReport {
             (bls12381_double_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Double];
         total_chips += 1;
 
+        let p256_add_events = self.syscall_counts[SyscallCode::P256_ADD];
+        total_area += (p256_add_events as u64) * costs[&RiscvAirDiscriminants::P256Add];
+        total_chips += 1;
+
+        let p256_double_events = self.syscall_counts[SyscallCode::P256_DOUBLE];
+        total_area += (p256_double_events as u64) * costs[&RiscvAirDiscriminants::P256Double];
+        total_chips += 1;
+
+        let p256_mul_events = self.syscall_counts[SyscallCode::P256_MUL];
+        total_area += (p256_mul_events as u64) * costs[&RiscvAirDiscriminants::P256Mul];
+        total_chips += 1;
+
+        let p256_decompress_events = self.syscall_counts[SyscallCode::SECP256R1_DECOMPRESS];
+        total_area +=
+            (p256_decompress_events as u64) * costs[&RiscvAirDiscriminants::P256Decompress];
+        total_chips += 1;
+
+        let p384_add_events = self.syscall_counts[SyscallCode::P384_ADD];
+        total_area += (p384_add_events as u64) * costs[&RiscvAirDiscriminants::P384Add];
+        total_chips += 1;
+
+        let p384_double_events = self.syscall_counts[SyscallCode::P384_DOUBLE];
+        total_area += (p384_double_events as u64) * costs[&RiscvAirDiscriminants::P384Double];
+        total_chips += 1;
+
+        let p384_decompress_events = self.syscall_counts[SyscallCode::SECP384R1_DECOMPRESS];
+        total_area +=
+            (p384_decompress_events as u64) * costs[&RiscvAirDiscriminants::P384Decompress];
+        total_chips += 1;
+
+        let grumpkin_add_events = self.syscall_counts[SyscallCode::GRUMPKIN_ADD];
+        total_area += (grumpkin_add_events as u64) * costs[&RiscvAirDiscriminants::GrumpkinAdd];
+        total_chips += 1;
+
+        let grumpkin_double_events = self.syscall_counts[SyscallCode::GRUMPKIN_DOUBLE];
+        total_area +=
+            (grumpkin_double_events as u64) * costs[&RiscvAirDiscriminants::GrumpkinDouble];
+        total_chips += 1;
+
+        let babyjubjub_add_events = self.syscall_counts[SyscallCode::BABYJUBJUB_ADD];
+        total_area += (babyjubjub_add_events as u64) * costs[&RiscvAirDiscriminants::BabyJubJubAdd];
+        total_chips += 1;
+
+        let babyjubjub_double_events = self.syscall_counts[SyscallCode::BABYJUBJUB_DOUBLE];
+        total_area +=
+            (babyjubjub_double_events as u64) * costs[&RiscvAirDiscriminants::BabyJubJubDouble];
+        total_chips += 1;
+
         let uint256_mul_events = self.syscall_counts[SyscallCode::UINT256_MUL];
         total_area += (uint256_mul_events as u64) * costs[&RiscvAirDiscriminants::Uint256Mul];
         total_chips += 1;
 
+        let uint256_add_mod_events = self.syscall_counts[SyscallCode::UINT256_ADD_MOD];
+        total_area +=
+            (uint256_add_mod_events as u64) * costs[&RiscvAirDiscriminants::Uint256AddMod];
+        total_chips += 1;
+
+        let uint256_sub_mod_events = self.syscall_counts[SyscallCode::UINT256_SUB_MOD];
+        total_area +=
+            (uint256_sub_mod_events as u64) * costs[&RiscvAirDiscriminants::Uint256SubMod];
+        total_chips += 1;
+
+        let uint384_mul_events = self.syscall_counts[SyscallCode::UINT384_MUL];
+        total_area += (uint384_mul_events as u64) * costs[&RiscvAirDiscriminants::Uint384Mul];
+        total_chips += 1;
+
+        let uint512_mul_events = self.syscall_counts[SyscallCode::UINT512_MUL];
+        total_area += (uint512_mul_events as u64) * costs[&RiscvAirDiscriminants::Uint512Mul];
+        total_chips += 1;
+
+        let uint256_inv_mod_events = self.syscall_counts[SyscallCode::UINT256_INV_MOD];
+        total_area +=
+            (uint256_inv_mod_events as u64) * costs[&RiscvAirDiscriminants::Uint256InvMod];
+        total_chips += 1;
+
+        let uint256_square_mod_events = self.syscall_counts[SyscallCode::UINT256_SQUARE_MOD];
+        total_area +=
+            (uint256_square_mod_events as u64) * costs[&RiscvAirDiscriminants::Uint256SquareMod];
+        total_chips += 1;
+
+        let uint256_mul_wide_events = self.syscall_counts[SyscallCode::UINT256_MUL_WIDE];
+        total_area +=
+            (uint256_mul_wide_events as u64) * costs[&RiscvAirDiscriminants::Uint256MulWide];
+        total_chips += 1;
+
+        let uint256_divrem_events = self.syscall_counts[SyscallCode::UINT256_DIVREM];
+        total_area += (uint256_divrem_events as u64) * costs[&RiscvAirDiscriminants::Uint256DivRem];
+        total_chips += 1;
+
+        let uint256_mont_mul_events = self.syscall_counts[SyscallCode::UINT256_MONT_MUL];
+        total_area +=
+            (uint256_mont_mul_events as u64) * costs[&RiscvAirDiscriminants::Uint256MontMul];
+        total_chips += 1;
+
+        let uint256_mod_exp_events = self.syscall_counts[SyscallCode::UINT256_MOD_EXP];
+        total_area +=
+            (uint256_mod_exp_events as u64) * costs[&RiscvAirDiscriminants::Uint256ModExp];
+        total_chips += 1;
+
+        let uint256_range_check_events = self.syscall_counts[SyscallCode::UINT256_RANGE_CHECK];
+        total_area +=
+            (uint256_range_check_events as u64) * costs[&RiscvAirDiscriminants::Uint256RangeCheck];
+        total_chips += 1;
+
+        let uint256_cmp_events = self.syscall_counts[SyscallCode::UINT256_CMP];
+        total_area += (uint256_cmp_events as u64) * costs[&RiscvAirDiscriminants::Uint256Cmp];
+        total_chips += 1;
+
+        let uint256_bitwise_events = self.syscall_counts[SyscallCode::UINT256_AND]
+            + self.syscall_counts[SyscallCode::UINT256_OR]
+            + self.syscall_counts[SyscallCode::UINT256_XOR];
+        total_area +=
+            (uint256_bitwise_events as u64) * costs[&RiscvAirDiscriminants::Uint256Bitwise];
+        total_chips += 1;
+
+        let poseidon2_permute_events = self.syscall_counts[SyscallCode::POSEIDON2_PERMUTE];
+        total_area +=
+            (poseidon2_permute_events as u64) * costs[&RiscvAirDiscriminants::Poseidon2Permute];
+        total_chips += 1;
+
+        let rescue_permute_events = self.syscall_counts[SyscallCode::RESCUE_PERMUTE];
+        total_area += (rescue_permute_events as u64) * costs[&RiscvAirDiscriminants::RescuePrime];
+        total_chips += 1;
+
+        let pedersen_hash_events = self.syscall_counts[SyscallCode::PEDERSEN_HASH];
+        total_area += (pedersen_hash_events as u64) * costs[&RiscvAirDiscriminants::PedersenHash];
+        total_chips += 1;
+
+        let aes128_encrypt_events = self.syscall_counts[SyscallCode::AES128_ENCRYPT];
+        total_area += (aes128_encrypt_events as u64) * costs[&RiscvAirDiscriminants::Aes128Encrypt];
+        total_chips += 1;
+
+        let chacha20_block_events = self.syscall_counts[SyscallCode::CHACHA20_BLOCK];
+        total_area += (chacha20_block_events as u64) * costs[&RiscvAirDiscriminants::ChaCha20];
+        total_chips += 1;
+
+        let merkle_verify_events = self.syscall_counts[SyscallCode::MERKLE_VERIFY_PATH];
+        total_area += (merkle_verify_events as u64) * costs[&RiscvAirDiscriminants::MerkleVerify];
+        total_chips += 1;
+
+        let fri_decommit_events = self.syscall_counts[SyscallCode::FRI_DECOMMIT];
+        total_area += (fri_decommit_events as u64) * costs[&RiscvAirDiscriminants::FriDecommit];
+        total_chips += 1;
+
+        let popcount_events = self.syscall_counts[SyscallCode::POPCOUNT];
+        total_area += (popcount_events as u64) * costs[&RiscvAirDiscriminants::Popcount];
+        total_chips += 1;
+
+        let clz32_events = self.syscall_counts[SyscallCode::CLZ32];
+        total_area += (clz32_events as u64) * costs[&RiscvAirDiscriminants::Clz];
+        total_chips += 1;
+
         let bls12381_fp_events = self.syscall_counts[SyscallCode::BLS12381_FP_ADD]
             + self.syscall_counts[SyscallCode::BLS12381_FP_SUB]
             + self.syscall_counts[SyscallCode::BLS12381_FP_MUL];
@@ -112,6 +307,56 @@ impl CostEstimator for ExecutionReport {
         total_area += (bn254_fp_events as u64) * costs[&RiscvAirDiscriminants::Bn254Fp];
         total_chips += 1;
 
+        let bn254_fr_events = self.syscall_counts[SyscallCode::BN254_FR_ADD]
+            + self.syscall_counts[SyscallCode::BN254_FR_SUB]
+            + self.syscall_counts[SyscallCode::BN254_FR_MUL];
+        total_area += (bn254_fr_events as u64) * costs[&RiscvAirDiscriminants::Bn254Fr];
+        total_chips += 1;
+
+        let bls12381_fp_batch_inverse_events =
+            self.syscall_counts[SyscallCode::BLS12381_FP_BATCH_INV];
+        total_area += (bls12381_fp_batch_inverse_events as u64)
+            * costs[&RiscvAirDiscriminants::Bls12381FpBatchInverse];
+        total_chips += 1;
+
+        let bn254_fp_batch_inverse_events = self.syscall_counts[SyscallCode::BN254_FP_BATCH_INV];
+        total_area += (bn254_fp_batch_inverse_events as u64)
+            * costs[&RiscvAirDiscriminants::Bn254FpBatchInverse];
+        total_chips += 1;
+
+        let bn254_fr_batch_inverse_events = self.syscall_counts[SyscallCode::BN254_FR_BATCH_INV];
+        total_area += (bn254_fr_batch_inverse_events as u64)
+            * costs[&RiscvAirDiscriminants::Bn254FrBatchInverse];
+        total_chips += 1;
+
+        let ntt_events = self.syscall_counts[SyscallCode::NTT];
+        total_area += (ntt_events as u64) * costs[&RiscvAirDiscriminants::Ntt];
+        total_chips += 1;
+
+        let intt_events = self.syscall_counts[SyscallCode::INTT];
+        total_area += (intt_events as u64) * costs[&RiscvAirDiscriminants::Intt];
+        total_chips += 1;
+
+        let poly_eval_events = self.syscall_counts[SyscallCode::POLY_EVAL];
+        total_area += (poly_eval_events as u64) * costs[&RiscvAirDiscriminants::PolyEval];
+        total_chips += 1;
+
+        let poly_multi_eval_events = self.syscall_counts[SyscallCode::POLY_MULTI_EVAL];
+        total_area +=
+            (poly_multi_eval_events as u64) * costs[&RiscvAirDiscriminants::PolyMultiEval];
+        total_chips += 1;
+
+        let lagrange_interp_events = self.syscall_counts[SyscallCode::LAGRANGE_INTERP];
+        total_area +=
+            (lagrange_interp_events as u64) * costs[&RiscvAirDiscriminants::LagrangeInterp];
+        total_chips += 1;
+
+        let secp256k1_linear_combination_events =
+            self.syscall_counts[SyscallCode::EC_LINEAR_COMBINATION];
+        total_area += (secp256k1_linear_combination_events as u64)
+            * costs[&RiscvAirDiscriminants::Secp256k1LinearCombination];
+        total_chips += 1;
+
         let bn254_fp2_addsub_events = self.syscall_counts[SyscallCode::BN254_FP2_ADD]
             + self.syscall_counts[SyscallCode::BN254_FP2_SUB];
         total_area +=
@@ -122,6 +367,25 @@ impl CostEstimator for ExecutionReport {
         total_area += (bn254_fp2_mul_events as u64) * costs[&RiscvAirDiscriminants::Bn254Fp2Mul];
         total_chips += 1;
 
+        let bn254_g2_add_events = self.syscall_counts[SyscallCode::BN254_G2_ADD];
+        total_area += (bn254_g2_add_events as u64) * costs[&RiscvAirDiscriminants::Bn254G2Add];
+        total_chips += 1;
+
+        let bn254_g2_double_events = self.syscall_counts[SyscallCode::BN254_G2_DOUBLE];
+        total_area +=
+            (bn254_g2_double_events as u64) * costs[&RiscvAirDiscriminants::Bn254G2Double];
+        total_chips += 1;
+
+        let bls12381_g2_add_events = self.syscall_counts[SyscallCode::BLS12381_G2_ADD];
+        total_area +=
+            (bls12381_g2_add_events as u64) * costs[&RiscvAirDiscriminants::Bls12381G2Add];
+        total_chips += 1;
+
+        let bls12381_g2_double_events = self.syscall_counts[SyscallCode::BLS12381_G2_DOUBLE];
+        total_area +=
+            (bls12381_g2_double_events as u64) * costs[&RiscvAirDiscriminants::Bls12381G2Double];
+        total_chips += 1;
+
         let bls12381_decompress_events = self.syscall_counts[SyscallCode::BLS12381_DECOMPRESS];
         total_area +=
             (bls12381_decompress_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Decompress];
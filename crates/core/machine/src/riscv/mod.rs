@@ -2,12 +2,28 @@ pub mod cost;
 
 use crate::{
     memory::{MemoryChipType, MemoryProgramChip},
-    syscall::precompiles::fptower::{Fp2AddSubAssignChip, Fp2MulAssignChip, FpOpChip},
+    syscall::precompiles::{
+        edwards::ED25519_VERIFY_ROWS,
+        fptower::{
+            BatchFieldInverseChip, Fp2AddSubAssignChip, Fp2MulAssignChip, FpOpChip, G2AddChip,
+            G2DoubleChip, BATCH_INVERSE_MAX_LEN,
+        },
+        fri::FRI_MAX_ROWS,
+        lagrange_interp::LAGRANGE_INTERP_COST_ROWS,
+        merkle::{MERKLE_MAX_DEPTH, MERKLE_ROWS_PER_LEVEL},
+        ntt::NTT_MAX_ROWS,
+        poly_eval::POLY_EVAL_COST_ROWS,
+        sha256::SHA256_HASH_COST_ROWS,
+        weierstrass::{ECDSA_VERIFY_ROWS, LINEAR_COMBINATION_MAX_ROWS, MSM_MAX_ROWS},
+    },
 };
 use hashbrown::HashMap;
 use p3_field::PrimeField32;
 pub use riscv_chips::*;
-use sp1_curves::weierstrass::{bls12_381::Bls12381BaseField, bn254::Bn254BaseField};
+use sp1_curves::weierstrass::{
+    bls12_381::Bls12381BaseField,
+    bn254::{Bn254BaseField, Bn254ScalarField},
+};
 use sp1_stark::{
     air::{MachineAir, SP1_PROOF_NUM_PV_ELTS},
     Chip, StarkGenericConfig, StarkMachine,
@@ -24,20 +40,48 @@ pub(crate) mod riscv_chips {
         memory::MemoryChip,
         program::ProgramChip,
         syscall::precompiles::{
-            edwards::{EdAddAssignChip, EdDecompressChip},
+            aes128::Aes128EncryptChip,
+            blake2b::Blake2bCompressChip,
+            blake3::Blake3CompressChip,
+            chacha20::ChaCha20Chip,
+            clz::ClzChip,
+            edwards::{
+                Ed25519VerifyChip, EdAddAssignChip, EdDecompressChip, EdDoubleAssignChip,
+                X25519LadderStepChip,
+            },
+            fri::FriDecommitChip,
             keccak256::KeccakPermuteChip,
-            sha256::{ShaCompressChip, ShaExtendChip},
-            uint256::Uint256MulChip,
+            lagrange_interp::LagrangeInterpChip,
+            merkle::MerkleVerifyChip,
+            ntt::NttChip,
+            pedersen::PedersenHashChip,
+            poly_eval::PolyEvalChip,
+            poly_multi_eval::PolyMultiEvalChip,
+            popcount::PopcountChip,
+            poseidon2::Poseidon2PermuteChip,
+            rescue::RescuePrimeChip,
+            ripemd160::Ripemd160CompressChip,
+            sha256::{Sha256HashChip, Sha256x4Chip, ShaCompressChip, ShaExtendChip},
+            uint::UintMulChip,
+            uint256::{
+                Uint256AddModChip, Uint256BitwiseChip, Uint256CmpChip, Uint256DivRemChip,
+                Uint256InvModChip, Uint256ModExpChip, Uint256MontMulChip, Uint256MulChip,
+                Uint256MulWideChip, Uint256RangeCheckChip, Uint256SquareModChip, Uint256SubModChip,
+            },
             weierstrass::{
-                WeierstrassAddAssignChip, WeierstrassDecompressChip, WeierstrassDoubleAssignChip,
+                EcdsaVerifyChip, LinearCombinationChip, WeierstrassAddAssignChip,
+                WeierstrassDecompressChip, WeierstrassDoubleAssignChip, WeierstrassMsmChip,
+                WeierstrassMulAssignChip,
             },
         },
     };
     pub use sp1_curves::{
-        edwards::{ed25519::Ed25519Parameters, EdwardsCurve},
+        edwards::{babyjubjub::BabyJubJub, ed25519::Ed25519Parameters, EdwardsCurve},
+        uint384::U384Field,
+        uint512::U512Field,
         weierstrass::{
-            bls12_381::Bls12381Parameters, bn254::Bn254Parameters, secp256k1::Secp256k1Parameters,
-            SwCurve,
+            bls12_381::Bls12381Parameters, bn254::Bn254Parameters, grumpkin::GrumpkinParameters,
+            p256::P256Parameters, p384::P384Parameters, secp256k1::Secp256k1Parameters, SwCurve,
         },
     };
 }
@@ -80,18 +124,38 @@ pub enum RiscvAir<F: PrimeField32> {
     Sha256Extend(ShaExtendChip),
     /// A precompile for sha256 compress.
     Sha256Compress(ShaCompressChip),
+    /// A precompile for sha256 compress, laying out four compressions per row.
+    Sha256x4(Sha256x4Chip),
+    /// A precompile for the sha256 digest of a message, one block per compression.
+    Sha256Hash(Sha256HashChip),
     /// A precompile for addition on the Elliptic curve ed25519.
     Ed25519Add(EdAddAssignChip<EdwardsCurve<Ed25519Parameters>>),
     /// A precompile for decompressing a point on the Edwards curve ed25519.
     Ed25519Decompress(EdDecompressChip<Ed25519Parameters>),
+    /// A precompile for a step of the X25519 Montgomery ladder.
+    X25519LadderStep(X25519LadderStepChip),
+    /// A precompile for verifying Ed25519 signatures.
+    Ed25519Verify(Ed25519VerifyChip),
     /// A precompile for decompressing a point on the K256 curve.
     K256Decompress(WeierstrassDecompressChip<SwCurve<Secp256k1Parameters>>),
     /// A precompile for addition on the Elliptic curve secp256k1.
     Secp256k1Add(WeierstrassAddAssignChip<SwCurve<Secp256k1Parameters>>),
     /// A precompile for doubling a point on the Elliptic curve secp256k1.
     Secp256k1Double(WeierstrassDoubleAssignChip<SwCurve<Secp256k1Parameters>>),
+    /// A precompile for scalar multiplication on the Elliptic curve secp256k1.
+    Secp256k1Mul(WeierstrassMulAssignChip<SwCurve<Secp256k1Parameters>>),
+    /// A precompile for multi-scalar multiplication on the Elliptic curve secp256k1.
+    Secp256k1Msm(WeierstrassMsmChip<SwCurve<Secp256k1Parameters>>),
+    /// A precompile for verifying ECDSA signatures on the Elliptic curve secp256k1.
+    Secp256k1EcdsaVerify(EcdsaVerifyChip),
     /// A precompile for the Keccak permutation.
     KeccakP(KeccakPermuteChip),
+    /// A precompile for the Blake3 compression rounds.
+    Blake3Compress(Blake3CompressChip),
+    /// A precompile for the Blake2b compression function.
+    Blake2bCompress(Blake2bCompressChip),
+    /// A precompile for the RIPEMD-160 compression function.
+    Ripemd160Compress(Ripemd160CompressChip),
     /// A precompile for addition on the Elliptic curve bn254.
     Bn254Add(WeierstrassAddAssignChip<SwCurve<Bn254Parameters>>),
     /// A precompile for doubling a point on the Elliptic curve bn254.
@@ -100,8 +164,74 @@ pub enum RiscvAir<F: PrimeField32> {
     Bls12381Add(WeierstrassAddAssignChip<SwCurve<Bls12381Parameters>>),
     /// A precompile for doubling a point on the Elliptic curve bls12_381.
     Bls12381Double(WeierstrassDoubleAssignChip<SwCurve<Bls12381Parameters>>),
+    /// A precompile for addition on the Elliptic curve P-256.
+    P256Add(WeierstrassAddAssignChip<SwCurve<P256Parameters>>),
+    /// A precompile for doubling a point on the Elliptic curve P-256.
+    P256Double(WeierstrassDoubleAssignChip<SwCurve<P256Parameters>>),
+    /// A precompile for scalar multiplication on the Elliptic curve P-256.
+    P256Mul(WeierstrassMulAssignChip<SwCurve<P256Parameters>>),
+    /// A precompile for decompressing a point on the Elliptic curve P-256.
+    P256Decompress(WeierstrassDecompressChip<SwCurve<P256Parameters>>),
+    /// A precompile for addition on the Elliptic curve P-384.
+    P384Add(WeierstrassAddAssignChip<SwCurve<P384Parameters>>),
+    /// A precompile for doubling a point on the Elliptic curve P-384.
+    P384Double(WeierstrassDoubleAssignChip<SwCurve<P384Parameters>>),
+    /// A precompile for decompressing a point on the Elliptic curve P-384.
+    P384Decompress(WeierstrassDecompressChip<SwCurve<P384Parameters>>),
+    /// A precompile for addition on the Grumpkin curve.
+    GrumpkinAdd(WeierstrassAddAssignChip<SwCurve<GrumpkinParameters>>),
+    /// A precompile for doubling a point on the Grumpkin curve.
+    GrumpkinDouble(WeierstrassDoubleAssignChip<SwCurve<GrumpkinParameters>>),
+    /// A precompile for addition on the BabyJubJub curve.
+    BabyJubJubAdd(EdAddAssignChip<BabyJubJub>),
+    /// A precompile for doubling a point on the BabyJubJub curve.
+    BabyJubJubDouble(EdDoubleAssignChip<BabyJubJub>),
     /// A precompile for uint256 mul.
     Uint256Mul(Uint256MulChip),
+    /// A precompile for uint256 addmod.
+    Uint256AddMod(Uint256AddModChip),
+    /// A precompile for uint256 submod.
+    Uint256SubMod(Uint256SubModChip),
+    /// A precompile for uint384 mul.
+    Uint384Mul(UintMulChip<U384Field>),
+    /// A precompile for uint512 mul.
+    Uint512Mul(UintMulChip<U512Field>),
+    /// A precompile for uint256 invmod.
+    Uint256InvMod(Uint256InvModChip),
+    /// A precompile for uint256 squaremod.
+    Uint256SquareMod(Uint256SquareModChip),
+    /// A precompile for uint256 widening mul.
+    Uint256MulWide(Uint256MulWideChip),
+    /// A precompile for uint256 division with remainder.
+    Uint256DivRem(Uint256DivRemChip),
+    /// A precompile for uint256 Montgomery mul.
+    Uint256MontMul(Uint256MontMulChip),
+    /// A precompile for uint256 modular exponentiation.
+    Uint256ModExp(Uint256ModExpChip),
+    /// A precompile for checking that a uint256 is less than a prime.
+    Uint256RangeCheck(Uint256RangeCheckChip),
+    /// A precompile for comparing two uint256.
+    Uint256Cmp(Uint256CmpChip),
+    /// A precompile for the bitwise and, or and xor of two uint256.
+    Uint256Bitwise(Uint256BitwiseChip),
+    /// A precompile for the Poseidon2 permutation.
+    Poseidon2Permute(Poseidon2PermuteChip),
+    /// A precompile for a round of the Rescue-Prime permutation.
+    RescuePrime(RescuePrimeChip),
+    /// A precompile for a window of a Pedersen hash over the Jubjub curve.
+    PedersenHash(PedersenHashChip),
+    /// A precompile for AES-128 encryption of a block.
+    Aes128Encrypt(Aes128EncryptChip),
+    /// A precompile for the ChaCha20 block function.
+    ChaCha20(ChaCha20Chip),
+    /// A precompile for verifying a Merkle path.
+    MerkleVerify(MerkleVerifyChip),
+    /// A precompile for decommitting a FRI query.
+    FriDecommit(FriDecommitChip),
+    /// A precompile for counting the set bits of a 64-bit value.
+    Popcount(PopcountChip),
+    /// A precompile for counting the leading zeros of a word.
+    Clz(ClzChip),
     /// A precompile for decompressing a point on the BLS12-381 curve.
     Bls12381Decompress(WeierstrassDecompressChip<SwCurve<Bls12381Parameters>>),
     /// A precompile for BLS12-381 fp operation.
@@ -112,10 +242,38 @@ pub enum RiscvAir<F: PrimeField32> {
     Bls12381Fp2AddSub(Fp2AddSubAssignChip<Bls12381BaseField>),
     /// A precompile for BN-254 fp operation.
     Bn254Fp(FpOpChip<Bn254BaseField>),
+    /// A precompile for BN-254 scalar field (fr) operation.
+    Bn254Fr(FpOpChip<Bn254ScalarField>),
+    /// A precompile for BLS12-381 fp batch inversion.
+    Bls12381FpBatchInverse(BatchFieldInverseChip<Bls12381BaseField>),
+    /// A precompile for BN-254 fp batch inversion.
+    Bn254FpBatchInverse(BatchFieldInverseChip<Bn254BaseField>),
+    /// A precompile for BN-254 scalar field (fr) batch inversion.
+    Bn254FrBatchInverse(BatchFieldInverseChip<Bn254ScalarField>),
+    /// A precompile for the number theoretic transform.
+    Ntt(NttChip),
+    /// A precompile for the inverse number theoretic transform.
+    Intt(NttChip),
+    /// A precompile for the evaluation of a polynomial with Horner's method.
+    PolyEval(PolyEvalChip),
+    /// A precompile for the evaluation of a polynomial at several points at once.
+    PolyMultiEval(PolyMultiEvalChip),
+    /// A precompile for the evaluation of the polynomial through a set of points at a target.
+    LagrangeInterp(LagrangeInterpChip),
+    /// A precompile for linear combinations of points on the Elliptic curve secp256k1.
+    Secp256k1LinearCombination(LinearCombinationChip<SwCurve<Secp256k1Parameters>>),
     /// A precompile for BN-254 fp2 multiplication.
     Bn254Fp2Mul(Fp2MulAssignChip<Bn254BaseField>),
     /// A precompile for BN-254 fp2 addition/subtraction.
     Bn254Fp2AddSub(Fp2AddSubAssignChip<Bn254BaseField>),
+    /// A precompile for addition on the BN-254 G2 twist.
+    Bn254G2Add(G2AddChip<Bn254BaseField>),
+    /// A precompile for doubling on the BN-254 G2 twist.
+    Bn254G2Double(G2DoubleChip<Bn254BaseField>),
+    /// A precompile for addition on the BLS12-381 G2 twist.
+    Bls12381G2Add(G2AddChip<Bls12381BaseField>),
+    /// A precompile for doubling on the BLS12-381 G2 twist.
+    Bls12381G2Double(G2DoubleChip<Bls12381BaseField>),
 }
 
 impl<F: PrimeField32> RiscvAir<F> {
@@ -158,6 +316,17 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Sha256Compress, 80 * sha_compress.cost());
         chips.push(sha_compress);
 
+        let sha256x4 = Chip::new(RiscvAir::Sha256x4(Sha256x4Chip::default()));
+        costs.insert(RiscvAirDiscriminants::Sha256x4, 80 * sha256x4.cost());
+        chips.push(sha256x4);
+
+        let sha256_hash = Chip::new(RiscvAir::Sha256Hash(Sha256HashChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::Sha256Hash,
+            SHA256_HASH_COST_ROWS as u64 * sha256_hash.cost(),
+        );
+        chips.push(sha256_hash);
+
         let ed_add_assign = Chip::new(RiscvAir::Ed25519Add(EdAddAssignChip::<
             EdwardsCurve<Ed25519Parameters>,
         >::new()));
@@ -170,6 +339,17 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Ed25519Decompress, ed_decompress.cost());
         chips.push(ed_decompress);
 
+        let x25519_ladder_step = Chip::new(RiscvAir::X25519LadderStep(X25519LadderStepChip::new()));
+        costs.insert(RiscvAirDiscriminants::X25519LadderStep, x25519_ladder_step.cost());
+        chips.push(x25519_ladder_step);
+
+        let ed25519_verify = Chip::new(RiscvAir::Ed25519Verify(Ed25519VerifyChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::Ed25519Verify,
+            ED25519_VERIFY_ROWS as u64 * ed25519_verify.cost(),
+        );
+        chips.push(ed25519_verify);
+
         let k256_decompress = Chip::new(RiscvAir::K256Decompress(WeierstrassDecompressChip::<
             SwCurve<Secp256k1Parameters>,
         >::with_lsb_rule()));
@@ -189,10 +369,48 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Secp256k1Double, secp256k1_double_assign.cost());
         chips.push(secp256k1_double_assign);
 
+        let secp256k1_mul_assign = Chip::new(RiscvAir::Secp256k1Mul(WeierstrassMulAssignChip::<
+            SwCurve<Secp256k1Parameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::Secp256k1Mul, 256 * secp256k1_mul_assign.cost());
+        chips.push(secp256k1_mul_assign);
+
+        // The number of pairs is not known from the number of syscalls, so the cost is of the
+        // largest number of pairs.
+        let secp256k1_msm = Chip::new(RiscvAir::Secp256k1Msm(WeierstrassMsmChip::<
+            SwCurve<Secp256k1Parameters>,
+        >::new()));
+        costs.insert(
+            RiscvAirDiscriminants::Secp256k1Msm,
+            MSM_MAX_ROWS as u64 * secp256k1_msm.cost(),
+        );
+        chips.push(secp256k1_msm);
+
+        let secp256k1_ecdsa_verify =
+            Chip::new(RiscvAir::Secp256k1EcdsaVerify(EcdsaVerifyChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::Secp256k1EcdsaVerify,
+            ECDSA_VERIFY_ROWS as u64 * secp256k1_ecdsa_verify.cost(),
+        );
+        chips.push(secp256k1_ecdsa_verify);
+
         let keccak_permute = Chip::new(RiscvAir::KeccakP(KeccakPermuteChip::new()));
         costs.insert(RiscvAirDiscriminants::KeccakP, 24 * keccak_permute.cost());
         chips.push(keccak_permute);
 
+        let blake3_compress = Chip::new(RiscvAir::Blake3Compress(Blake3CompressChip::new()));
+        costs.insert(RiscvAirDiscriminants::Blake3Compress, 7 * blake3_compress.cost());
+        chips.push(blake3_compress);
+
+        let blake2b_compress = Chip::new(RiscvAir::Blake2bCompress(Blake2bCompressChip::new()));
+        costs.insert(RiscvAirDiscriminants::Blake2bCompress, 12 * blake2b_compress.cost());
+        chips.push(blake2b_compress);
+
+        let ripemd160_compress =
+            Chip::new(RiscvAir::Ripemd160Compress(Ripemd160CompressChip::new()));
+        costs.insert(RiscvAirDiscriminants::Ripemd160Compress, 80 * ripemd160_compress.cost());
+        chips.push(ripemd160_compress);
+
         let bn254_add_assign = Chip::new(RiscvAir::Bn254Add(WeierstrassAddAssignChip::<
             SwCurve<Bn254Parameters>,
         >::new()));
@@ -217,10 +435,171 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Bls12381Double, bls12381_double.cost());
         chips.push(bls12381_double);
 
-        let uint256_mul = Chip::new(RiscvAir::Uint256Mul(Uint256MulChip::default()));
+        let p256_add_assign = Chip::new(RiscvAir::P256Add(WeierstrassAddAssignChip::<
+            SwCurve<P256Parameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::P256Add, p256_add_assign.cost());
+        chips.push(p256_add_assign);
+
+        let p256_double_assign = Chip::new(RiscvAir::P256Double(WeierstrassDoubleAssignChip::<
+            SwCurve<P256Parameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::P256Double, p256_double_assign.cost());
+        chips.push(p256_double_assign);
+
+        let p256_mul_assign = Chip::new(RiscvAir::P256Mul(WeierstrassMulAssignChip::<
+            SwCurve<P256Parameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::P256Mul, 256 * p256_mul_assign.cost());
+        chips.push(p256_mul_assign);
+
+        let p256_decompress = Chip::new(RiscvAir::P256Decompress(WeierstrassDecompressChip::<
+            SwCurve<P256Parameters>,
+        >::with_lsb_rule()));
+        costs.insert(RiscvAirDiscriminants::P256Decompress, p256_decompress.cost());
+        chips.push(p256_decompress);
+
+        let p384_add_assign = Chip::new(RiscvAir::P384Add(WeierstrassAddAssignChip::<
+            SwCurve<P384Parameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::P384Add, p384_add_assign.cost());
+        chips.push(p384_add_assign);
+
+        let p384_double_assign = Chip::new(RiscvAir::P384Double(WeierstrassDoubleAssignChip::<
+            SwCurve<P384Parameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::P384Double, p384_double_assign.cost());
+        chips.push(p384_double_assign);
+
+        let p384_decompress = Chip::new(RiscvAir::P384Decompress(WeierstrassDecompressChip::<
+            SwCurve<P384Parameters>,
+        >::with_lsb_rule()));
+        costs.insert(RiscvAirDiscriminants::P384Decompress, p384_decompress.cost());
+        chips.push(p384_decompress);
+
+        let grumpkin_add_assign = Chip::new(RiscvAir::GrumpkinAdd(WeierstrassAddAssignChip::<
+            SwCurve<GrumpkinParameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::GrumpkinAdd, grumpkin_add_assign.cost());
+        chips.push(grumpkin_add_assign);
+
+        let grumpkin_double_assign =
+            Chip::new(RiscvAir::GrumpkinDouble(WeierstrassDoubleAssignChip::<
+                SwCurve<GrumpkinParameters>,
+            >::new()));
+        costs.insert(RiscvAirDiscriminants::GrumpkinDouble, grumpkin_double_assign.cost());
+        chips.push(grumpkin_double_assign);
+
+        let babyjubjub_add_assign =
+            Chip::new(RiscvAir::BabyJubJubAdd(EdAddAssignChip::<BabyJubJub>::new()));
+        costs.insert(RiscvAirDiscriminants::BabyJubJubAdd, babyjubjub_add_assign.cost());
+        chips.push(babyjubjub_add_assign);
+
+        let babyjubjub_double_assign =
+            Chip::new(RiscvAir::BabyJubJubDouble(EdDoubleAssignChip::<BabyJubJub>::new()));
+        costs.insert(RiscvAirDiscriminants::BabyJubJubDouble, babyjubjub_double_assign.cost());
+        chips.push(babyjubjub_double_assign);
+
+        let uint256_mul = Chip::new(RiscvAir::Uint256Mul(Uint256MulChip::new()));
         costs.insert(RiscvAirDiscriminants::Uint256Mul, uint256_mul.cost());
         chips.push(uint256_mul);
 
+        let uint256_add_mod = Chip::new(RiscvAir::Uint256AddMod(Uint256AddModChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256AddMod, uint256_add_mod.cost());
+        chips.push(uint256_add_mod);
+
+        let uint256_sub_mod = Chip::new(RiscvAir::Uint256SubMod(Uint256SubModChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256SubMod, uint256_sub_mod.cost());
+        chips.push(uint256_sub_mod);
+
+        let uint384_mul = Chip::new(RiscvAir::Uint384Mul(UintMulChip::<U384Field>::new()));
+        costs.insert(RiscvAirDiscriminants::Uint384Mul, uint384_mul.cost());
+        chips.push(uint384_mul);
+
+        let uint512_mul = Chip::new(RiscvAir::Uint512Mul(UintMulChip::<U512Field>::new()));
+        costs.insert(RiscvAirDiscriminants::Uint512Mul, uint512_mul.cost());
+        chips.push(uint512_mul);
+
+        let uint256_inv_mod = Chip::new(RiscvAir::Uint256InvMod(Uint256InvModChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256InvMod, uint256_inv_mod.cost());
+        chips.push(uint256_inv_mod);
+
+        let uint256_square_mod =
+            Chip::new(RiscvAir::Uint256SquareMod(Uint256SquareModChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256SquareMod, uint256_square_mod.cost());
+        chips.push(uint256_square_mod);
+
+        let uint256_mul_wide = Chip::new(RiscvAir::Uint256MulWide(Uint256MulWideChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256MulWide, uint256_mul_wide.cost());
+        chips.push(uint256_mul_wide);
+
+        let uint256_divrem = Chip::new(RiscvAir::Uint256DivRem(Uint256DivRemChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256DivRem, uint256_divrem.cost());
+        chips.push(uint256_divrem);
+
+        let uint256_mont_mul = Chip::new(RiscvAir::Uint256MontMul(Uint256MontMulChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256MontMul, uint256_mont_mul.cost());
+        chips.push(uint256_mont_mul);
+
+        let uint256_mod_exp = Chip::new(RiscvAir::Uint256ModExp(Uint256ModExpChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256ModExp, 256 * uint256_mod_exp.cost());
+        chips.push(uint256_mod_exp);
+
+        let uint256_range_check =
+            Chip::new(RiscvAir::Uint256RangeCheck(Uint256RangeCheckChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256RangeCheck, uint256_range_check.cost());
+        chips.push(uint256_range_check);
+
+        let uint256_cmp = Chip::new(RiscvAir::Uint256Cmp(Uint256CmpChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256Cmp, uint256_cmp.cost());
+        chips.push(uint256_cmp);
+
+        let uint256_bitwise = Chip::new(RiscvAir::Uint256Bitwise(Uint256BitwiseChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint256Bitwise, uint256_bitwise.cost());
+        chips.push(uint256_bitwise);
+
+        let poseidon2_permute = Chip::new(RiscvAir::Poseidon2Permute(Poseidon2PermuteChip::new()));
+        costs.insert(RiscvAirDiscriminants::Poseidon2Permute, poseidon2_permute.cost());
+        chips.push(poseidon2_permute);
+
+        let rescue_prime = Chip::new(RiscvAir::RescuePrime(RescuePrimeChip::new()));
+        costs.insert(RiscvAirDiscriminants::RescuePrime, rescue_prime.cost());
+        chips.push(rescue_prime);
+
+        let pedersen_hash = Chip::new(RiscvAir::PedersenHash(PedersenHashChip::new()));
+        costs.insert(RiscvAirDiscriminants::PedersenHash, pedersen_hash.cost());
+        chips.push(pedersen_hash);
+
+        let aes128_encrypt = Chip::new(RiscvAir::Aes128Encrypt(Aes128EncryptChip::new()));
+        costs.insert(RiscvAirDiscriminants::Aes128Encrypt, aes128_encrypt.cost());
+        chips.push(aes128_encrypt);
+
+        let chacha20 = Chip::new(RiscvAir::ChaCha20(ChaCha20Chip::new()));
+        costs.insert(RiscvAirDiscriminants::ChaCha20, 10 * chacha20.cost());
+        chips.push(chacha20);
+
+        // The depth is not known from the number of syscalls, so the cost is of the deepest path.
+        let merkle_verify = Chip::new(RiscvAir::MerkleVerify(MerkleVerifyChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::MerkleVerify,
+            (MERKLE_MAX_DEPTH * MERKLE_ROWS_PER_LEVEL) as u64 * merkle_verify.cost(),
+        );
+        chips.push(merkle_verify);
+
+        // The number of values and the depth are not known from the number of syscalls, so the
+        // cost is of the largest query.
+        let fri_decommit = Chip::new(RiscvAir::FriDecommit(FriDecommitChip::new()));
+        costs.insert(RiscvAirDiscriminants::FriDecommit, FRI_MAX_ROWS as u64 * fri_decommit.cost());
+        chips.push(fri_decommit);
+
+        let popcount = Chip::new(RiscvAir::Popcount(PopcountChip::new()));
+        costs.insert(RiscvAirDiscriminants::Popcount, popcount.cost());
+        chips.push(popcount);
+
+        let clz = Chip::new(RiscvAir::Clz(ClzChip::new()));
+        costs.insert(RiscvAirDiscriminants::Clz, clz.cost());
+        chips.push(clz);
+
         let bls12381_fp = Chip::new(RiscvAir::Bls12381Fp(FpOpChip::<Bls12381BaseField>::new()));
         costs.insert(RiscvAirDiscriminants::Bls12381Fp, bls12381_fp.cost());
         chips.push(bls12381_fp);
@@ -239,6 +618,76 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Bn254Fp, bn254_fp.cost());
         chips.push(bn254_fp);
 
+        let bn254_fr = Chip::new(RiscvAir::Bn254Fr(FpOpChip::<Bn254ScalarField>::new()));
+        costs.insert(RiscvAirDiscriminants::Bn254Fr, bn254_fr.cost());
+        chips.push(bn254_fr);
+
+        let bls12381_fp_batch_inverse = Chip::new(RiscvAir::Bls12381FpBatchInverse(
+            BatchFieldInverseChip::<Bls12381BaseField>::new(),
+        ));
+        costs.insert(
+            RiscvAirDiscriminants::Bls12381FpBatchInverse,
+            BATCH_INVERSE_MAX_LEN as u64 * bls12381_fp_batch_inverse.cost(),
+        );
+        chips.push(bls12381_fp_batch_inverse);
+
+        let bn254_fp_batch_inverse = Chip::new(RiscvAir::Bn254FpBatchInverse(
+            BatchFieldInverseChip::<Bn254BaseField>::new(),
+        ));
+        costs.insert(
+            RiscvAirDiscriminants::Bn254FpBatchInverse,
+            BATCH_INVERSE_MAX_LEN as u64 * bn254_fp_batch_inverse.cost(),
+        );
+        chips.push(bn254_fp_batch_inverse);
+
+        let bn254_fr_batch_inverse = Chip::new(RiscvAir::Bn254FrBatchInverse(
+            BatchFieldInverseChip::<Bn254ScalarField>::new(),
+        ));
+        costs.insert(
+            RiscvAirDiscriminants::Bn254FrBatchInverse,
+            BATCH_INVERSE_MAX_LEN as u64 * bn254_fr_batch_inverse.cost(),
+        );
+        chips.push(bn254_fr_batch_inverse);
+
+        let ntt = Chip::new(RiscvAir::Ntt(NttChip::ntt()));
+        costs.insert(RiscvAirDiscriminants::Ntt, NTT_MAX_ROWS as u64 * ntt.cost());
+        chips.push(ntt);
+
+        let intt = Chip::new(RiscvAir::Intt(NttChip::intt()));
+        costs.insert(RiscvAirDiscriminants::Intt, NTT_MAX_ROWS as u64 * intt.cost());
+        chips.push(intt);
+
+        let poly_eval = Chip::new(RiscvAir::PolyEval(PolyEvalChip::new()));
+        costs
+            .insert(RiscvAirDiscriminants::PolyEval, POLY_EVAL_COST_ROWS as u64 * poly_eval.cost());
+        chips.push(poly_eval);
+
+        let poly_multi_eval = Chip::new(RiscvAir::PolyMultiEval(PolyMultiEvalChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::PolyMultiEval,
+            POLY_EVAL_COST_ROWS as u64 * poly_multi_eval.cost(),
+        );
+        chips.push(poly_multi_eval);
+
+        let lagrange_interp = Chip::new(RiscvAir::LagrangeInterp(LagrangeInterpChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::LagrangeInterp,
+            LAGRANGE_INTERP_COST_ROWS as u64 * lagrange_interp.cost(),
+        );
+        chips.push(lagrange_interp);
+
+        // The number of pairs is not known from the number of syscalls, so the cost is of the
+        // largest number of pairs.
+        let secp256k1_linear_combination =
+            Chip::new(RiscvAir::Secp256k1LinearCombination(LinearCombinationChip::<
+                SwCurve<Secp256k1Parameters>,
+            >::new()));
+        costs.insert(
+            RiscvAirDiscriminants::Secp256k1LinearCombination,
+            LINEAR_COMBINATION_MAX_ROWS as u64 * secp256k1_linear_combination.cost(),
+        );
+        chips.push(secp256k1_linear_combination);
+
         let bn254_fp2_addsub =
             Chip::new(RiscvAir::Bn254Fp2AddSub(Fp2AddSubAssignChip::<Bn254BaseField>::new()));
         costs.insert(RiscvAirDiscriminants::Bn254Fp2AddSub, bn254_fp2_addsub.cost());
@@ -249,6 +698,25 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Bn254Fp2Mul, bn254_fp2_mul.cost());
         chips.push(bn254_fp2_mul);
 
+        let bn254_g2_add = Chip::new(RiscvAir::Bn254G2Add(G2AddChip::<Bn254BaseField>::new()));
+        costs.insert(RiscvAirDiscriminants::Bn254G2Add, bn254_g2_add.cost());
+        chips.push(bn254_g2_add);
+
+        let bn254_g2_double =
+            Chip::new(RiscvAir::Bn254G2Double(G2DoubleChip::<Bn254BaseField>::new()));
+        costs.insert(RiscvAirDiscriminants::Bn254G2Double, bn254_g2_double.cost());
+        chips.push(bn254_g2_double);
+
+        let bls12381_g2_add =
+            Chip::new(RiscvAir::Bls12381G2Add(G2AddChip::<Bls12381BaseField>::new()));
+        costs.insert(RiscvAirDiscriminants::Bls12381G2Add, bls12381_g2_add.cost());
+        chips.push(bls12381_g2_add);
+
+        let bls12381_g2_double =
+            Chip::new(RiscvAir::Bls12381G2Double(G2DoubleChip::<Bls12381BaseField>::new()));
+        costs.insert(RiscvAirDiscriminants::Bls12381G2Double, bls12381_g2_double.cost());
+        chips.push(bls12381_g2_double);
+
         let bls12381_decompress =
             Chip::new(RiscvAir::Bls12381Decompress(WeierstrassDecompressChip::<
                 SwCurve<Bls12381Parameters>,
@@ -328,18 +796,18 @@ pub mod tests {
         io::SP1Stdin,
         riscv::RiscvAir,
         utils,
-        utils::{prove, run_test, setup_logger},
+        utils::{prove, prove_with_context, run_test, setup_logger, SP1CoreProverError},
     };
 
     use sp1_core_executor::{
         programs::tests::{
             fibonacci_program, simple_memory_program, simple_program, ssz_withdrawals_program,
         },
-        Instruction, Opcode, Program,
+        ExecutionError, Instruction, Opcode, Program, SP1Context,
     };
     use sp1_stark::{
-        baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, SP1CoreOpts, StarkProvingKey,
-        StarkVerifyingKey,
+        baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, MachineProver, SP1CoreOpts,
+        StarkProvingKey, StarkVerifyingKey,
     };
 
     #[test]
@@ -368,6 +836,22 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_shift_imm_prove() {
+        utils::setup_logger();
+        // `SRLI` and `SRAI` are `SRL` and `SRA` with an immediate `c`, proven by the same chip.
+        let mut instructions = vec![];
+        for value in [0x8765_4321, 0x1234_5678, u32::MAX] {
+            instructions.push(Instruction::new(Opcode::ADD, 29, 0, value, false, true));
+            for shift in 0..32 {
+                instructions.push(Instruction::new(Opcode::SRL, 30, 29, shift, false, true));
+                instructions.push(Instruction::new(Opcode::SRA, 31, 29, shift, false, true));
+            }
+        }
+        let program = Program::new(instructions, 0, 0);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
     #[test]
     fn test_sub_prove() {
         utils::setup_logger();
@@ -485,6 +969,33 @@ pub mod tests {
         prove::<_, CpuProver<_, _>>(program, &stdin, BabyBearPoseidon2::new(), opts).unwrap();
     }
 
+    #[test]
+    fn test_cycle_limit_prove() {
+        setup_logger();
+
+        // A guest that spins forever on a jump to itself.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::JAL, 0, 0, 0, true, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let prover = CpuProver::new(RiscvAir::machine(BabyBearPoseidon2::new()));
+        let (pk, _) = prover.setup(&program);
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1024;
+        opts.shard_batch_size = 2;
+        let context = SP1Context::builder().max_cycles(10_000).build();
+        let result = prove_with_context(&prover, &pk, program, &SP1Stdin::new(), opts, context);
+        assert!(matches!(
+            result,
+            Err(SP1CoreProverError::ExecutionError(ExecutionError::ExceededCycleLimit(
+                10_000,
+                _,
+                4
+            )))
+        ));
+    }
+
     #[test]
     fn test_fibonacci_prove_batch() {
         setup_logger();
@@ -0,0 +1,247 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{syscalls::SyscallCode, ByteOpcode};
+use sp1_primitives::aes::{aes_shift_rows_index, AES128_NUM_ROUNDS, AES_BLOCK_SIZE, AES_RCON};
+use sp1_stark::air::SP1AirBuilder;
+
+use crate::{air::MemoryAirBuilder, memory::MemoryCols};
+
+use super::{
+    columns::{Aes128EncryptCols, KeyExpansionCols, MixColumnsCols, NUM_AES128_ENCRYPT_COLS},
+    Aes128EncryptChip,
+};
+
+impl<F> BaseAir<F> for Aes128EncryptChip {
+    fn width(&self) -> usize {
+        NUM_AES128_ENCRYPT_COLS
+    }
+}
+
+impl<AB> Air<AB> for Aes128EncryptChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Aes128EncryptCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Aes128EncryptCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let plaintext: [AB::Expr; AES_BLOCK_SIZE] =
+            core::array::from_fn(|i| local.block_mem[i / 4].prev_value()[i % 4].into());
+        let ciphertext: [AB::Expr; AES_BLOCK_SIZE] =
+            core::array::from_fn(|i| local.block_mem[i / 4].value()[i % 4].into());
+        let key: [AB::Expr; AES_BLOCK_SIZE] =
+            core::array::from_fn(|i| local.key_mem[i / 4].value()[i % 4].into());
+
+        // The first `AddRoundKey` uses the key itself.
+        for i in 0..AES_BLOCK_SIZE {
+            send_xor(builder, local, local.initial_state[i], plaintext[i].clone(), key[i].clone());
+        }
+
+        let mut state: [AB::Expr; AES_BLOCK_SIZE] = local.initial_state.map(Into::into);
+        let mut round_key = key;
+        for round in 0..AES128_NUM_ROUNDS {
+            let cols = &local.rounds[round];
+
+            for i in 0..AES_BLOCK_SIZE {
+                send_sbox(builder, local, cols.sub_bytes[i], state[i].clone());
+            }
+            let shifted: [AB::Expr; AES_BLOCK_SIZE] =
+                core::array::from_fn(|i| cols.sub_bytes[aes_shift_rows_index(i)].into());
+            let mixed = if round < AES128_NUM_ROUNDS - 1 {
+                self.eval_mix_columns(builder, local, &local.mix_columns[round], &shifted)
+            } else {
+                shifted
+            };
+
+            self.eval_key_expansion(builder, local, &cols.key_expansion, &round_key, round);
+            for i in 0..AES_BLOCK_SIZE {
+                send_xor(
+                    builder,
+                    local,
+                    cols.state[i],
+                    mixed[i].clone(),
+                    cols.key_expansion.round_key[i],
+                );
+            }
+
+            state = cols.state.map(Into::into);
+            round_key = cols.key_expansion.round_key.map(Into::into);
+        }
+
+        // The output of the last round is the ciphertext.
+        for i in 0..AES_BLOCK_SIZE {
+            builder.assert_eq(state[i].clone(), ciphertext[i].clone());
+        }
+
+        // Read the key and write the ciphertext over the plaintext one cycle later.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.key_ptr,
+            &local.key_mem,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.block_ptr,
+            &local.block_mem,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::AES128_ENCRYPT.syscall_id()),
+            local.block_ptr,
+            local.key_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+impl Aes128EncryptChip {
+    /// Constrains the round key of the given round, derived from the previous round key.
+    fn eval_key_expansion<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Aes128EncryptCols<AB::Var>,
+        cols: &KeyExpansionCols<AB::Var>,
+        key: &[AB::Expr; AES_BLOCK_SIZE],
+        round: usize,
+    ) {
+        for i in 0..4 {
+            send_sbox(builder, local, cols.sub_word[i], key[12 + (i + 1) % 4].clone());
+        }
+        send_xor(
+            builder,
+            local,
+            cols.sub_word_rcon,
+            cols.sub_word[0],
+            AB::F::from_canonical_u8(AES_RCON[round]),
+        );
+
+        for i in 0..AES_BLOCK_SIZE {
+            let addend = match i {
+                0 => cols.sub_word_rcon,
+                1..=3 => cols.sub_word[i],
+                _ => cols.round_key[i - 4],
+            };
+            send_xor(builder, local, cols.round_key[i], key[i].clone(), addend);
+        }
+    }
+
+    /// Constrains `MixColumns` of the state and returns the result.
+    fn eval_mix_columns<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Aes128EncryptCols<AB::Var>,
+        cols: &MixColumnsCols<AB::Var>,
+        state: &[AB::Expr; AES_BLOCK_SIZE],
+    ) -> [AB::Expr; AES_BLOCK_SIZE] {
+        for c in 0..4 {
+            send_xor(builder, local, cols.column_sum[c], cols.pair[4 * c], cols.pair[4 * c + 2]);
+
+            for i in 0..4 {
+                let j = 4 * c + i;
+                send_xor(
+                    builder,
+                    local,
+                    cols.pair[j],
+                    state[j].clone(),
+                    state[4 * c + (i + 1) % 4].clone(),
+                );
+
+                // `xtime` drops the top bit of the pair shifted left, and adds `0x1b` if it was
+                // set. The lookups also check that the shifted byte is in range, and so that the
+                // most significant bit is correct.
+                send_msb(builder, local, cols.pair_msb[j], cols.pair[j]);
+                send_xor(
+                    builder,
+                    local,
+                    cols.pair_xtime[j],
+                    cols.pair[j] * AB::F::two()
+                        - cols.pair_msb[j] * AB::F::from_canonical_u32(1 << 8),
+                    cols.pair_msb[j] * AB::F::from_canonical_u8(0x1b),
+                );
+
+                send_xor(builder, local, cols.partial[j], state[j].clone(), cols.column_sum[c]);
+                send_xor(builder, local, cols.output[j], cols.partial[j], cols.pair_xtime[j]);
+            }
+        }
+        cols.output.map(Into::into)
+    }
+}
+
+/// Sends the lookup `a = b ^ c`.
+fn send_xor<AB: SP1AirBuilder>(
+    builder: &mut AB,
+    local: &Aes128EncryptCols<AB::Var>,
+    a: impl Into<AB::Expr>,
+    b: impl Into<AB::Expr>,
+    c: impl Into<AB::Expr>,
+) {
+    builder.send_byte(
+        ByteOpcode::XOR.as_field::<AB::F>(),
+        a,
+        b,
+        c,
+        local.shard,
+        local.channel,
+        local.is_real,
+    );
+}
+
+/// Sends the lookup of `a` as the S-box of `b`.
+fn send_sbox<AB: SP1AirBuilder>(
+    builder: &mut AB,
+    local: &Aes128EncryptCols<AB::Var>,
+    a: impl Into<AB::Expr>,
+    b: impl Into<AB::Expr>,
+) {
+    builder.send_byte(
+        ByteOpcode::SBOX.as_field::<AB::F>(),
+        a,
+        b,
+        AB::Expr::zero(),
+        local.shard,
+        local.channel,
+        local.is_real,
+    );
+}
+
+/// Sends the lookup of `a` as the most significant bit of `b`.
+fn send_msb<AB: SP1AirBuilder>(
+    builder: &mut AB,
+    local: &Aes128EncryptCols<AB::Var>,
+    a: impl Into<AB::Expr>,
+    b: impl Into<AB::Expr>,
+) {
+    builder.send_byte(
+        ByteOpcode::MSB.as_field::<AB::F>(),
+        a,
+        b,
+        AB::Expr::zero(),
+        local.shard,
+        local.channel,
+        local.is_real,
+    );
+}
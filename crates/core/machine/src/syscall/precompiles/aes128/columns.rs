@@ -0,0 +1,83 @@
+use core::mem::size_of;
+
+use sp1_core_executor::events::AES128_BLOCK_NUM_WORDS;
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::aes::{AES128_NUM_ROUNDS, AES_BLOCK_SIZE};
+
+use crate::memory::{MemoryReadCols, MemoryWriteCols};
+
+pub const NUM_AES128_ENCRYPT_COLS: usize = size_of::<Aes128EncryptCols<u8>>();
+
+/// A set of columns for deriving a round key from the previous one.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct KeyExpansionCols<T> {
+    /// `SubWord(RotWord(w3))`, where `w3` is the last word of the previous round key.
+    pub sub_word: [T; 4],
+    /// The first byte of `sub_word` plus the round constant.
+    pub sub_word_rcon: T,
+    /// The round key.
+    pub round_key: [T; AES_BLOCK_SIZE],
+}
+
+/// A set of columns for `MixColumns`.
+///
+/// Each output byte is computed as `a_i ^ t ^ xtime(a_i ^ a_(i+1))`, where `a` is a column of the
+/// input and `t` is the xor of the column.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MixColumnsCols<T> {
+    /// `a_i ^ a_(i+1)` within each column.
+    pub pair: [T; AES_BLOCK_SIZE],
+    /// The xor of each column, as `(a_0 ^ a_1) ^ (a_2 ^ a_3)`.
+    pub column_sum: [T; 4],
+    /// The most significant bit of each pair.
+    pub pair_msb: [T; AES_BLOCK_SIZE],
+    /// `xtime` of each pair, as the pair shifted left by one plus `0x1b` if its top bit was set.
+    pub pair_xtime: [T; AES_BLOCK_SIZE],
+    /// `a_i ^ t`.
+    pub partial: [T; AES_BLOCK_SIZE],
+    /// The result.
+    pub output: [T; AES_BLOCK_SIZE],
+}
+
+/// A set of columns for a round of AES-128.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Aes128RoundCols<T> {
+    /// The state after `SubBytes`. `ShiftRows` only permutes bytes, so it has no columns.
+    pub sub_bytes: [T; AES_BLOCK_SIZE],
+    /// The round key.
+    pub key_expansion: KeyExpansionCols<T>,
+    /// The state after `AddRoundKey`, which is the output of the round.
+    pub state: [T; AES_BLOCK_SIZE],
+}
+
+/// A set of columns needed to encrypt a block with AES-128.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Aes128EncryptCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub block_ptr: T,
+    pub key_ptr: T,
+
+    /// Memory columns for the block. The plaintext is the previous value of each word, and the
+    /// ciphertext is written over it.
+    pub block_mem: [MemoryWriteCols<T>; AES128_BLOCK_NUM_WORDS],
+
+    /// Memory columns for the key.
+    pub key_mem: [MemoryReadCols<T>; AES128_BLOCK_NUM_WORDS],
+
+    /// The plaintext plus the key, which is the input of the first round.
+    pub initial_state: [T; AES_BLOCK_SIZE],
+
+    pub rounds: [Aes128RoundCols<T>; AES128_NUM_ROUNDS],
+
+    /// `MixColumns` of every round but the last.
+    pub mix_columns: [MixColumnsCols<T>; AES128_NUM_ROUNDS - 1],
+
+    pub is_real: T,
+}
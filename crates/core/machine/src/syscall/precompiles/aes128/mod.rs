@@ -0,0 +1,79 @@
+mod air;
+pub mod columns;
+mod trace;
+
+/// Implements AES-128 encryption of a single block, as specified in FIPS 197. The inputs to the
+/// syscall are a pointer to the block, whose four words of plaintext are overwritten with the
+/// ciphertext, and a pointer to the four words of the key. Each block is one row, which holds all
+/// ten rounds and the key expansion.
+///
+/// Every byte operation is a lookup into the byte table. `SubBytes` and `SubWord` use the `SBOX`
+/// lookup, `ShiftRows` is a permutation of the columns, `AddRoundKey` is a `XOR` lookup per byte,
+/// and `MixColumns` computes `xtime` from the `MSB` lookup and a `XOR` with `0x1b`.
+#[derive(Default)]
+pub struct Aes128EncryptChip;
+
+impl Aes128EncryptChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(test)]
+pub mod aes128_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_primitives::{
+        aes::aes128_encrypt,
+        consts::{bytes_to_words_le, words_to_bytes_le},
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{self, run_test, tests::syscall_program};
+
+    const BLOCK_PTR: u32 = 100;
+    const KEY_PTR: u32 = 200;
+
+    /// Builds a program that encrypts the plaintext in place at `BLOCK_PTR` with the key at
+    /// `KEY_PTR`.
+    pub fn aes128_encrypt_program(key: &[u8; 16], plaintext: &[u8; 16]) -> Program {
+        let words = bytes_to_words_le::<4>(plaintext)
+            .into_iter()
+            .enumerate()
+            .map(|(i, word)| (BLOCK_PTR + i as u32 * 4, word))
+            .chain(
+                bytes_to_words_le::<4>(key)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, word)| (KEY_PTR + i as u32 * 4, word)),
+            );
+        syscall_program(SyscallCode::AES128_ENCRYPT, words, BLOCK_PTR, KEY_PTR)
+    }
+
+    fn aes128_cases() -> Vec<([u8; 16], [u8; 16])> {
+        vec![
+            ([0; 16], [0; 16]),
+            ([0xff; 16], [0xff; 16]),
+            (core::array::from_fn(|i| i as u8), core::array::from_fn(|i| (i * 0x11) as u8)),
+        ]
+    }
+
+    #[test]
+    fn test_aes128_encrypt_matches_primitives() {
+        utils::setup_logger();
+        for (key, plaintext) in aes128_cases() {
+            let mut runtime =
+                Executor::new(aes128_encrypt_program(&key, &plaintext), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> = (0..4).map(|i| runtime.word(BLOCK_PTR + i * 4)).collect();
+            assert_eq!(words_to_bytes_le::<16>(&result), aes128_encrypt(&key, &plaintext));
+        }
+    }
+
+    #[test]
+    fn test_aes128_encrypt_prove() {
+        utils::setup_logger();
+        for (key, plaintext) in aes128_cases() {
+            run_test::<CpuProver<_, _>>(aes128_encrypt_program(&key, &plaintext)).unwrap();
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord},
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_primitives::aes::{
+    aes128_next_round_key, aes_shift_rows, AES128_NUM_ROUNDS, AES_BLOCK_SIZE, AES_RCON, AES_SBOX,
+};
+use sp1_stark::air::MachineAir;
+
+use crate::utils::{pad_rows, par_generate_rows, words_to_bytes_le};
+
+use super::{
+    columns::{Aes128EncryptCols, KeyExpansionCols, MixColumnsCols, NUM_AES128_ENCRYPT_COLS},
+    Aes128EncryptChip,
+};
+
+impl<F: PrimeField32> MachineAir<F> for Aes128EncryptChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Aes128Encrypt".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.aes128_encrypt_events, |event, new_byte_lookup_events| {
+                let mut row = [F::zero(); NUM_AES128_ENCRYPT_COLS];
+                let cols: &mut Aes128EncryptCols<F> = row.as_mut_slice().borrow_mut();
+
+                let (shard, channel) = (event.shard, event.channel);
+                cols.shard = F::from_canonical_u32(shard);
+                cols.channel = F::from_canonical_u8(channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+                cols.key_ptr = F::from_canonical_u32(event.key_ptr);
+                cols.is_real = F::one();
+
+                for (mem, record) in
+                    cols.block_mem.iter_mut().zip(event.block_memory_records.iter())
+                {
+                    mem.populate(channel, *record, new_byte_lookup_events);
+                }
+                for (mem, record) in cols.key_mem.iter_mut().zip(event.key_memory_records.iter()) {
+                    mem.populate(channel, *record, new_byte_lookup_events);
+                }
+
+                let plaintext = words_to_bytes_le::<AES_BLOCK_SIZE>(&event.plaintext);
+                let mut round_key = words_to_bytes_le::<AES_BLOCK_SIZE>(&event.key);
+                let mut state: [u8; AES_BLOCK_SIZE] = core::array::from_fn(|i| {
+                    xor(new_byte_lookup_events, shard, channel, plaintext[i], round_key[i])
+                });
+                cols.initial_state = state.map(F::from_canonical_u8);
+
+                for round in 0..AES128_NUM_ROUNDS {
+                    let round_cols = &mut cols.rounds[round];
+
+                    let sub_bytes = state.map(|b| sbox(new_byte_lookup_events, shard, channel, b));
+                    round_cols.sub_bytes = sub_bytes.map(F::from_canonical_u8);
+
+                    let mut mixed = aes_shift_rows(&sub_bytes);
+                    if round < AES128_NUM_ROUNDS - 1 {
+                        mixed = cols.mix_columns[round].populate(
+                            new_byte_lookup_events,
+                            shard,
+                            channel,
+                            &mixed,
+                        );
+                    }
+
+                    round_key = round_cols.key_expansion.populate(
+                        new_byte_lookup_events,
+                        shard,
+                        channel,
+                        &round_key,
+                        round,
+                    );
+                    state = core::array::from_fn(|i| {
+                        xor(new_byte_lookup_events, shard, channel, mixed[i], round_key[i])
+                    });
+                    round_cols.state = state.map(F::from_canonical_u8);
+                }
+
+                debug_assert_eq!(
+                    state.to_vec(),
+                    event
+                        .block_memory_records
+                        .iter()
+                        .flat_map(|record| record.value.to_le_bytes())
+                        .collect::<Vec<_>>()
+                );
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // Every constraint on the round columns is a lookup gated by `is_real`, so the padding
+        // rows can be all zeros.
+        pad_rows(&mut rows, || [F::zero(); NUM_AES128_ENCRYPT_COLS]);
+
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_AES128_ENCRYPT_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Aes128EncryptCols<F> = trace.values
+                [i * NUM_AES128_ENCRYPT_COLS..(i + 1) * NUM_AES128_ENCRYPT_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.aes128_encrypt_events.is_empty()
+    }
+}
+
+impl<F: PrimeField32> KeyExpansionCols<F> {
+    /// Populates the columns for the key of the given round and returns it.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        key: &[u8; AES_BLOCK_SIZE],
+        round: usize,
+    ) -> [u8; AES_BLOCK_SIZE] {
+        let sub_word: [u8; 4] =
+            core::array::from_fn(|i| sbox(record, shard, channel, key[12 + (i + 1) % 4]));
+        let sub_word_rcon = xor(record, shard, channel, sub_word[0], AES_RCON[round]);
+
+        let mut round_key = [0u8; AES_BLOCK_SIZE];
+        for i in 0..AES_BLOCK_SIZE {
+            let addend = match i {
+                0 => sub_word_rcon,
+                1..=3 => sub_word[i],
+                _ => round_key[i - 4],
+            };
+            round_key[i] = xor(record, shard, channel, key[i], addend);
+        }
+        debug_assert_eq!(round_key, aes128_next_round_key(key, round));
+
+        self.sub_word = sub_word.map(F::from_canonical_u8);
+        self.sub_word_rcon = F::from_canonical_u8(sub_word_rcon);
+        self.round_key = round_key.map(F::from_canonical_u8);
+        round_key
+    }
+}
+
+impl<F: PrimeField32> MixColumnsCols<F> {
+    /// Populates the columns for `MixColumns` of the state and returns the result.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        state: &[u8; AES_BLOCK_SIZE],
+    ) -> [u8; AES_BLOCK_SIZE] {
+        let mut output = [0u8; AES_BLOCK_SIZE];
+        for (c, column) in state.chunks_exact(4).enumerate() {
+            let pair: [u8; 4] = core::array::from_fn(|i| {
+                xor(record, shard, channel, column[i], column[(i + 1) % 4])
+            });
+            let column_sum = xor(record, shard, channel, pair[0], pair[2]);
+            self.column_sum[c] = F::from_canonical_u8(column_sum);
+
+            for i in 0..4 {
+                let j = 4 * c + i;
+                let pair_msb = msb(record, shard, channel, pair[i]);
+                let pair_xtime = xor(record, shard, channel, pair[i] << 1, pair_msb * 0x1b);
+                let partial = xor(record, shard, channel, column[i], column_sum);
+                output[j] = xor(record, shard, channel, partial, pair_xtime);
+
+                self.pair[j] = F::from_canonical_u8(pair[i]);
+                self.pair_msb[j] = F::from_canonical_u8(pair_msb);
+                self.pair_xtime[j] = F::from_canonical_u8(pair_xtime);
+                self.partial[j] = F::from_canonical_u8(partial);
+                self.output[j] = F::from_canonical_u8(output[j]);
+            }
+        }
+        output
+    }
+}
+
+/// Records the lookup of `b ^ c` and returns it.
+fn xor(record: &mut impl ByteRecord, shard: u32, channel: u8, b: u8, c: u8) -> u8 {
+    let xor = b ^ c;
+    record.add_byte_lookup_event(ByteLookupEvent::new(
+        shard,
+        channel,
+        ByteOpcode::XOR,
+        xor as u16,
+        0,
+        b,
+        c,
+    ));
+    xor
+}
+
+/// Records the lookup of the S-box of `b` and returns it.
+fn sbox(record: &mut impl ByteRecord, shard: u32, channel: u8, b: u8) -> u8 {
+    let sbox = AES_SBOX[b as usize];
+    record.add_byte_lookup_event(ByteLookupEvent::new(
+        shard,
+        channel,
+        ByteOpcode::SBOX,
+        sbox as u16,
+        0,
+        b,
+        0,
+    ));
+    sbox
+}
+
+/// Records the lookup of the most significant bit of `b` and returns it.
+fn msb(record: &mut impl ByteRecord, shard: u32, channel: u8, b: u8) -> u8 {
+    let msb = b >> 7;
+    record.add_byte_lookup_event(ByteLookupEvent::new(
+        shard,
+        channel,
+        ByteOpcode::MSB,
+        msb as u16,
+        0,
+        b,
+        0,
+    ));
+    msb
+}
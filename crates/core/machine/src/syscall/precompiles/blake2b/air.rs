@@ -0,0 +1,270 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{syscalls::SyscallCode, ByteOpcode};
+use sp1_stark::{
+    air::{BaseAirBuilder, SP1AirBuilder},
+    Word,
+};
+
+use super::{
+    blake2b_msg_permutation,
+    columns::{Blake2bCompressCols, NUM_BLAKE2B_COMPRESS_COLS},
+    g::{eval_xor64, GOperation},
+    Blake2bCompressChip, BLAKE2B_BLOCK_NUM_WORDS, BLAKE2B_G_INDICES, BLAKE2B_IV,
+    BLAKE2B_NUM_ROUNDS, BLAKE2B_STATE_NUM_WORDS,
+};
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+};
+
+impl<F> BaseAir<F> for Blake2bCompressChip {
+    fn width(&self) -> usize {
+        NUM_BLAKE2B_COMPRESS_COLS
+    }
+}
+
+impl<AB> Air<AB> for Blake2bCompressChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Blake2bCompressCols<AB::Var> = (*local).borrow();
+        let next: &Blake2bCompressCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The round flags are one-hot, start at the first round and cycle through the rounds.
+        for i in 0..BLAKE2B_NUM_ROUNDS {
+            builder.assert_bool(local.round[i]);
+        }
+        builder.assert_one(local.round.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag));
+        builder.when_first_row().assert_one(local.round[0]);
+        for i in 0..BLAKE2B_NUM_ROUNDS {
+            builder
+                .when_transition()
+                .assert_eq(local.round[i], next.round[(i + 1) % BLAKE2B_NUM_ROUNDS]);
+        }
+
+        let first_round = local.round[0];
+        let final_round = local.round[BLAKE2B_NUM_ROUNDS - 1];
+        let not_final_round = AB::Expr::one() - final_round;
+
+        builder.assert_bool(local.is_real);
+        builder.assert_eq((first_round + final_round) * local.is_real, local.do_memory_check);
+        builder.assert_eq(first_round * local.is_real, local.receive_ecall);
+        builder.assert_eq(final_round * local.is_real, local.is_final);
+
+        // Constrain that the inputs stay the same throughout the rows of each syscall.
+        let mut transition_builder = builder.when_transition();
+        let mut transition_not_final_builder = transition_builder.when(not_final_round.clone());
+        transition_not_final_builder.assert_eq(local.shard, next.shard);
+        transition_not_final_builder.assert_eq(local.channel, next.channel);
+        transition_not_final_builder.assert_eq(local.clk, next.clk);
+        transition_not_final_builder.assert_eq(local.state_ptr, next.state_ptr);
+        transition_not_final_builder.assert_eq(local.block_ptr, next.block_ptr);
+        transition_not_final_builder.assert_eq(local.is_real, next.is_real);
+
+        // The last row must be nonreal because the number of rounds is not a power of 2. This
+        // constraint ensures that the table does not end abruptly.
+        builder.when_last_row().assert_zero(local.is_real);
+
+        self.eval_init(builder, local);
+
+        // Mix the columns and then the diagonals.
+        let mut v = local.v;
+        for (i, indices) in BLAKE2B_G_INDICES.iter().enumerate() {
+            GOperation::<AB::F>::eval(
+                builder,
+                indices.map(|j| v[j]),
+                local.message[2 * i],
+                local.message[2 * i + 1],
+                local.g[i],
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            for (&j, value) in indices.iter().zip(local.g[i].result()) {
+                v[j] = value;
+            }
+        }
+
+        // Carry the working vector to the next round.
+        let mut transition_builder = builder.when_transition();
+        let mut next_round_builder = transition_builder.when(not_final_round * local.is_real);
+        for i in 0..16 {
+            for half in 0..2 {
+                next_round_builder.assert_word_eq(next.v[i][half], v[i][half]);
+            }
+        }
+
+        // Carry the message to the next round, permuted according to the message schedule.
+        for r in 0..BLAKE2B_NUM_ROUNDS - 1 {
+            let permutation = blake2b_msg_permutation(r);
+            let mut transition_builder = builder.when_transition();
+            let mut round_builder = transition_builder.when(local.round[r] * local.is_real);
+            for (k, &j) in permutation.iter().enumerate() {
+                for half in 0..2 {
+                    round_builder.assert_word_eq(next.message[k][half], local.message[j][half]);
+                }
+            }
+        }
+
+        // In the last round, feed the working vector forward into the state and write it back.
+        for i in 0..8 {
+            eval_xor64(
+                builder,
+                v[i],
+                v[i + 8],
+                local.v_xor[i],
+                local.shard,
+                local.channel,
+                local.is_final,
+            );
+            let h =
+                [*local.state_mem[2 * i].prev_value(), *local.state_mem[2 * i + 1].prev_value()];
+            eval_xor64(
+                builder,
+                h,
+                local.v_xor[i].map(|op| op.value),
+                local.h_xor[i],
+                local.shard,
+                local.channel,
+                local.is_final,
+            );
+            for half in 0..2 {
+                builder.when(local.is_final).assert_word_eq(
+                    *local.state_mem[2 * i + half].value(),
+                    local.h_xor[i][half].value,
+                );
+            }
+        }
+
+        // Constrain memory.
+        for i in 0..BLAKE2B_STATE_NUM_WORDS as u32 {
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk + final_round, // The clk increments by 1 after the final round.
+                local.state_ptr + AB::Expr::from_canonical_u32(i * 4),
+                &local.state_mem[i as usize],
+                local.do_memory_check,
+            );
+        }
+        for i in 0..BLAKE2B_BLOCK_NUM_WORDS as u32 {
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk,
+                local.block_ptr + AB::Expr::from_canonical_u32(i * 4),
+                &local.block_mem[i as usize],
+                local.receive_ecall,
+            );
+        }
+
+        // Range check all the values read from and written to memory to be bytes.
+        for i in 0..BLAKE2B_STATE_NUM_WORDS {
+            builder.slice_range_check_u8(
+                &local.state_mem[i].value().0,
+                local.shard,
+                local.channel,
+                local.do_memory_check,
+            );
+        }
+        for i in 0..BLAKE2B_BLOCK_NUM_WORDS {
+            builder.slice_range_check_u8(
+                &local.block_mem[i].value().0,
+                local.shard,
+                local.channel,
+                local.receive_ecall,
+            );
+        }
+
+        // Receive the syscall in the first row of each syscall.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::BLAKE2B_COMPRESS.syscall_id()),
+            local.state_ptr,
+            local.block_ptr,
+            local.receive_ecall,
+        );
+    }
+}
+
+impl Blake2bCompressChip {
+    /// Constrains the working vector and the message of the first round to be initialized from
+    /// the state and the block read from memory.
+    fn eval_init<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &Blake2bCompressCols<AB::Var>) {
+        let iv_bytes = BLAKE2B_IV.map(|x| x.to_le_bytes().map(AB::F::from_canonical_u8));
+        let byte = |value: &[Word<AB::Var>; 2], i: usize| value[i / 4][i % 4];
+
+        // The state is unchanged since it's a memory read, and it makes up the first half of the
+        // working vector.
+        for i in 0..BLAKE2B_STATE_NUM_WORDS {
+            builder
+                .when(local.receive_ecall)
+                .assert_word_eq(*local.state_mem[i].value(), *local.state_mem[i].prev_value());
+            builder
+                .when(local.receive_ecall)
+                .assert_word_eq(*local.state_mem[i].value(), local.v[i / 2][i % 2]);
+        }
+
+        // The message is read unpermuted, since the first row of the schedule is the identity.
+        for i in 0..16 {
+            for half in 0..2 {
+                builder
+                    .when(local.receive_ecall)
+                    .assert_word_eq(*local.block_mem[2 * i + half].value(), local.message[i][half]);
+            }
+        }
+
+        // The final block flag is a boolean.
+        let flag = local.block_mem[BLAKE2B_BLOCK_NUM_WORDS - 1].value();
+        builder.when(local.receive_ecall).assert_bool(flag[0]);
+        for &flag_byte in &flag.0[1..] {
+            builder.when(local.receive_ecall).assert_zero(flag_byte);
+        }
+
+        // The second half of the working vector is the IV, with the byte counter xored into
+        // `v[12]` and `v[13]` and `v[14]` inverted in the final block.
+        for (i, iv) in iv_bytes.iter().enumerate() {
+            let v = &local.v[i + 8];
+            for (j, &iv_byte) in iv.iter().enumerate() {
+                match i {
+                    4 | 5 => {
+                        let counter_word = local.block_mem[32 + 2 * (i - 4) + j / 4].value();
+                        builder.send_byte(
+                            AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                            byte(v, j),
+                            iv_byte,
+                            counter_word[j % 4],
+                            local.shard,
+                            local.channel,
+                            local.receive_ecall,
+                        );
+                    }
+                    6 => {
+                        let inverted = AB::F::from_canonical_u8(255) - iv_byte.double();
+                        builder
+                            .when(local.receive_ecall)
+                            .assert_eq(byte(v, j), flag[0] * inverted + iv_byte);
+                    }
+                    _ => {
+                        builder.when(local.receive_ecall).assert_eq(byte(v, j), iv_byte);
+                    }
+                }
+            }
+        }
+    }
+}
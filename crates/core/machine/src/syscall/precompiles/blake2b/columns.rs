@@ -0,0 +1,67 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+use sp1_stark::Word;
+
+use crate::memory::{MemoryReadCols, MemoryReadWriteCols};
+
+use super::{
+    g::{GOperation, Xor64Operation},
+    BLAKE2B_BLOCK_NUM_WORDS, BLAKE2B_NUM_G, BLAKE2B_NUM_ROUNDS, BLAKE2B_STATE_NUM_WORDS,
+};
+
+pub const NUM_BLAKE2B_COMPRESS_COLS: usize = size_of::<Blake2bCompressCols<u8>>();
+
+/// A set of columns needed to compute the Blake2b compression function.
+///
+/// Each syscall is processed over 12 rows, one per round. The state and the block are read from
+/// memory in the first row, the working vector is carried to the next row after each round, and
+/// the state is written back to memory in the last row after the feed-forward.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct Blake2bCompressCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub state_ptr: T,
+    pub block_ptr: T,
+
+    /// Which round this row is processing.
+    pub round: [T; BLAKE2B_NUM_ROUNDS],
+
+    /// Memory columns for the state. The state is read in the first round and written in the
+    /// last round.
+    pub state_mem: [MemoryReadWriteCols<T>; BLAKE2B_STATE_NUM_WORDS],
+
+    /// Memory columns for the message, byte counter and final block flag, which are read in the
+    /// first round.
+    pub block_mem: [MemoryReadCols<T>; BLAKE2B_BLOCK_NUM_WORDS],
+
+    /// The working vector at the start of the round, as low and high words.
+    pub v: [[Word<T>; 2]; 16],
+
+    /// The message words, permuted for the current round.
+    pub message: [[Word<T>; 2]; 16],
+
+    /// The mixing functions applied to the columns and then to the diagonals of the working
+    /// vector.
+    pub g: [GOperation<T>; BLAKE2B_NUM_G],
+
+    /// `v[i] ^ v[i + 8]` after the last round.
+    pub v_xor: [Xor64Operation<T>; 8],
+
+    /// `h[i] ^ v[i] ^ v[i + 8]`, the new state, after the last round.
+    pub h_xor: [Xor64Operation<T>; 8],
+
+    /// If the row is real and in the first or last round.
+    pub do_memory_check: T,
+
+    /// If the row is real and in the first round.
+    pub receive_ecall: T,
+
+    /// If the row is real and in the last round.
+    pub is_final: T,
+
+    pub is_real: T,
+}
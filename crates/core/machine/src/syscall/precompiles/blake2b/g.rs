@@ -0,0 +1,397 @@
+use p3_field::{AbstractField, Field};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord},
+    ByteOpcode,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{air::SP1AirBuilder, Word};
+
+use crate::{bytes::utils::shr_carry, operations::XorOperation};
+
+/// The number of bytes in a u64.
+const U64_NUM_BYTES: usize = 8;
+
+/// Splits a u64 into its low and high words.
+pub fn u64_to_words<F: Field>(value: u64) -> [Word<F>; 2] {
+    [Word::from(value as u32), Word::from((value >> 32) as u32)]
+}
+
+/// The byte `i` of a u64 given as its low and high words.
+fn byte<T: Copy>(value: [Word<T>; 2], i: usize) -> T {
+    value[i / 4][i % 4]
+}
+
+/// Rotates a u64 given as its low and high words right by a whole number of bytes, which needs no
+/// columns.
+pub fn rotate_right_bytes<T: Copy>(value: [Word<T>; 2], nb_bytes: usize) -> [Word<T>; 2] {
+    let rotated = |i: usize| byte(value, (i + nb_bytes) % U64_NUM_BYTES);
+    [
+        Word([rotated(0), rotated(1), rotated(2), rotated(3)]),
+        Word([rotated(4), rotated(5), rotated(6), rotated(7)]),
+    ]
+}
+
+/// A set of columns needed to compute the wrapping sum of two u64s split into low and high words.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Add64Operation<T> {
+    /// The result of `a + b`.
+    pub value: [Word<T>; 2],
+
+    /// Whether the sum of each byte but the last carries into the next byte.
+    pub carry: [T; U64_NUM_BYTES - 1],
+}
+
+impl<F: Field> Add64Operation<F> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        a_u64: u64,
+        b_u64: u64,
+    ) -> u64 {
+        let expected = a_u64.wrapping_add(b_u64);
+        self.value = u64_to_words(expected);
+        let a = a_u64.to_le_bytes();
+        let b = b_u64.to_le_bytes();
+
+        let mut carry = 0u32;
+        for i in 0..U64_NUM_BYTES - 1 {
+            carry = (a[i] as u32 + b[i] as u32 + carry) >> 8;
+            self.carry[i] = F::from_canonical_u32(carry);
+        }
+
+        // The inputs are range checked where they are produced, so only the output is checked.
+        record.add_u8_range_checks(shard, channel, &expected.to_le_bytes());
+        expected
+    }
+
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        a: [Word<AB::Var>; 2],
+        b: [Word<AB::Var>; 2],
+        cols: Add64Operation<AB::Var>,
+        shard: AB::Var,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: AB::Var,
+    ) {
+        let base = AB::F::from_canonical_u32(256);
+
+        let mut builder_is_real = builder.when(is_real);
+
+        // For each byte, the sum with the incoming carry is the output byte plus the outgoing
+        // carry times the base. The carry out of the last byte is discarded, so there we only
+        // assert that the difference is either zero or the base.
+        let mut carry_in = AB::Expr::zero();
+        for i in 0..U64_NUM_BYTES {
+            let overflow = byte(a, i) + byte(b, i) + carry_in - byte(cols.value, i);
+            if i < U64_NUM_BYTES - 1 {
+                builder_is_real.assert_bool(cols.carry[i]);
+                builder_is_real.assert_eq(overflow, cols.carry[i] * base);
+                carry_in = cols.carry[i].into();
+            } else {
+                builder_is_real.assert_zero(overflow.clone() * (overflow - base));
+            }
+        }
+
+        builder.slice_range_check_u8(&cols.value[0].0, shard, channel.clone(), is_real);
+        builder.slice_range_check_u8(&cols.value[1].0, shard, channel, is_real);
+    }
+}
+
+/// A set of columns needed to compute `rotateright` of a u64 by a fixed offset that is not a
+/// whole number of bytes.
+///
+/// As in [`crate::operations::FixedRotateRightOperation`], the rotation is decomposed into a byte
+/// rotation and a bit shift of each byte.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct FixedRotateRight64Operation<T> {
+    /// The output value.
+    pub value: [Word<T>; 2],
+
+    /// The shift output of `shrcarry` on each byte.
+    pub shift: [T; U64_NUM_BYTES],
+
+    /// The carry output of `shrcarry` on each byte.
+    pub carry: [T; U64_NUM_BYTES],
+}
+
+impl<F: Field> FixedRotateRight64Operation<F> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        input: u64,
+        rotation: usize,
+    ) -> u64 {
+        let expected = input.rotate_right(rotation as u32);
+        let input_bytes = input.to_le_bytes();
+        let nb_bytes_to_shift = rotation / 8;
+        let nb_bits_to_shift = (rotation % 8) as u8;
+
+        for i in 0..U64_NUM_BYTES {
+            let b = input_bytes[(i + nb_bytes_to_shift) % U64_NUM_BYTES];
+            let (shift, carry) = shr_carry(b, nb_bits_to_shift);
+            record.add_byte_lookup_event(ByteLookupEvent {
+                shard,
+                channel,
+                opcode: ByteOpcode::ShrCarry,
+                a1: shift as u16,
+                a2: carry,
+                b,
+                c: nb_bits_to_shift,
+            });
+            self.shift[i] = F::from_canonical_u8(shift);
+            self.carry[i] = F::from_canonical_u8(carry);
+        }
+        self.value = u64_to_words(expected);
+
+        expected
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        input: [Word<AB::Var>; 2],
+        rotation: usize,
+        cols: FixedRotateRight64Operation<AB::Var>,
+        shard: AB::Var,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: AB::Var,
+    ) {
+        let nb_bytes_to_shift = rotation / 8;
+        let nb_bits_to_shift = rotation % 8;
+        let carry_multiplier = AB::F::from_canonical_u32(1 << (8 - nb_bits_to_shift));
+
+        let input_bytes_rotated = rotate_right_bytes(input, nb_bytes_to_shift);
+        for i in 0..U64_NUM_BYTES {
+            builder.send_byte_pair(
+                AB::F::from_canonical_u32(ByteOpcode::ShrCarry as u32),
+                cols.shift[i],
+                cols.carry[i],
+                byte(input_bytes_rotated, i),
+                AB::F::from_canonical_usize(nb_bits_to_shift),
+                shard,
+                channel.clone(),
+                is_real,
+            );
+
+            // Each output byte is the shifted byte with the low bits of the next byte on top.
+            builder.assert_eq(
+                byte(cols.value, i),
+                cols.shift[i] + cols.carry[(i + 1) % U64_NUM_BYTES] * carry_multiplier,
+            );
+        }
+    }
+}
+
+/// A set of columns needed to compute the xor of two u64s split into low and high words.
+pub type Xor64Operation<T> = [XorOperation<T>; 2];
+
+/// A set of columns needed to compute the Blake2b mixing function `G` on four state words.
+///
+/// The rotations are right rotations by 32, 24, 16 and 63 bits. The first three are whole numbers
+/// of bytes, so they are a relabeling of the xor output and need no columns.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct GOperation<T> {
+    pub a_plus_b: Add64Operation<T>,
+    /// `a := a + b + mx`.
+    pub a_plus_b_plus_mx: Add64Operation<T>,
+    /// `d := (d ^ a) >>> 32`.
+    pub d_xor_a: Xor64Operation<T>,
+    /// `c := c + d`.
+    pub c_plus_d: Add64Operation<T>,
+    /// `b := (b ^ c) >>> 24`.
+    pub b_xor_c: Xor64Operation<T>,
+
+    pub a_plus_b_2: Add64Operation<T>,
+    /// `a := a + b + my`.
+    pub a_plus_b_plus_my: Add64Operation<T>,
+    /// `d := (d ^ a) >>> 16`.
+    pub d_xor_a_2: Xor64Operation<T>,
+    /// `c := c + d`.
+    pub c_plus_d_2: Add64Operation<T>,
+    pub b_xor_c_2: Xor64Operation<T>,
+    /// `b := (b ^ c) >>> 63`.
+    pub b_rotated_2: FixedRotateRight64Operation<T>,
+}
+
+impl<T: Copy> GOperation<T> {
+    /// The value of `(d ^ a) >>> 32`.
+    fn d_rotated(&self) -> [Word<T>; 2] {
+        rotate_right_bytes(self.d_xor_a.map(|op| op.value), 4)
+    }
+
+    /// The value of `(b ^ c) >>> 24`.
+    fn b_rotated(&self) -> [Word<T>; 2] {
+        rotate_right_bytes(self.b_xor_c.map(|op| op.value), 3)
+    }
+
+    /// The value of `(d ^ a) >>> 16` in the second half.
+    fn d_rotated_2(&self) -> [Word<T>; 2] {
+        rotate_right_bytes(self.d_xor_a_2.map(|op| op.value), 2)
+    }
+
+    /// The output words `[a, b, c, d]`.
+    pub fn result(&self) -> [[Word<T>; 2]; 4] {
+        [
+            self.a_plus_b_plus_my.value,
+            self.b_rotated_2.value,
+            self.c_plus_d_2.value,
+            self.d_rotated_2(),
+        ]
+    }
+}
+
+impl<F: Field> GOperation<F> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        input: [u64; 4],
+        mx: u64,
+        my: u64,
+    ) -> [u64; 4] {
+        let [a, b, c, d] = input;
+
+        let a_plus_b = self.a_plus_b.populate(record, shard, channel, a, b);
+        let a = self.a_plus_b_plus_mx.populate(record, shard, channel, a_plus_b, mx);
+        let d = populate_xor64(&mut self.d_xor_a, record, shard, channel, d, a).rotate_right(32);
+        let c = self.c_plus_d.populate(record, shard, channel, c, d);
+        let b = populate_xor64(&mut self.b_xor_c, record, shard, channel, b, c).rotate_right(24);
+
+        let a_plus_b = self.a_plus_b_2.populate(record, shard, channel, a, b);
+        let a = self.a_plus_b_plus_my.populate(record, shard, channel, a_plus_b, my);
+        let d = populate_xor64(&mut self.d_xor_a_2, record, shard, channel, d, a).rotate_right(16);
+        let c = self.c_plus_d_2.populate(record, shard, channel, c, d);
+        let b_xor_c = populate_xor64(&mut self.b_xor_c_2, record, shard, channel, b, c);
+        let b = self.b_rotated_2.populate(record, shard, channel, b_xor_c, 63);
+
+        [a, b, c, d]
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        input: [[Word<AB::Var>; 2]; 4],
+        mx: [Word<AB::Var>; 2],
+        my: [Word<AB::Var>; 2],
+        cols: GOperation<AB::Var>,
+        shard: AB::Var,
+        channel: AB::Var,
+        is_real: AB::Var,
+    ) {
+        let [a, b, c, d] = input;
+
+        Add64Operation::<AB::F>::eval(builder, a, b, cols.a_plus_b, shard, channel, is_real);
+        Add64Operation::<AB::F>::eval(
+            builder,
+            cols.a_plus_b.value,
+            mx,
+            cols.a_plus_b_plus_mx,
+            shard,
+            channel,
+            is_real,
+        );
+        eval_xor64(builder, d, cols.a_plus_b_plus_mx.value, cols.d_xor_a, shard, channel, is_real);
+        Add64Operation::<AB::F>::eval(
+            builder,
+            c,
+            cols.d_rotated(),
+            cols.c_plus_d,
+            shard,
+            channel,
+            is_real,
+        );
+        eval_xor64(builder, b, cols.c_plus_d.value, cols.b_xor_c, shard, channel, is_real);
+
+        Add64Operation::<AB::F>::eval(
+            builder,
+            cols.a_plus_b_plus_mx.value,
+            cols.b_rotated(),
+            cols.a_plus_b_2,
+            shard,
+            channel,
+            is_real,
+        );
+        Add64Operation::<AB::F>::eval(
+            builder,
+            cols.a_plus_b_2.value,
+            my,
+            cols.a_plus_b_plus_my,
+            shard,
+            channel,
+            is_real,
+        );
+        eval_xor64(
+            builder,
+            cols.d_rotated(),
+            cols.a_plus_b_plus_my.value,
+            cols.d_xor_a_2,
+            shard,
+            channel,
+            is_real,
+        );
+        Add64Operation::<AB::F>::eval(
+            builder,
+            cols.c_plus_d.value,
+            cols.d_rotated_2(),
+            cols.c_plus_d_2,
+            shard,
+            channel,
+            is_real,
+        );
+        eval_xor64(
+            builder,
+            cols.b_rotated(),
+            cols.c_plus_d_2.value,
+            cols.b_xor_c_2,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRight64Operation::<AB::F>::eval(
+            builder,
+            cols.b_xor_c_2.map(|op| op.value),
+            63,
+            cols.b_rotated_2,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
+
+/// Populates the xor of the low and high words of two u64s.
+pub fn populate_xor64<F: Field>(
+    cols: &mut Xor64Operation<F>,
+    record: &mut impl ByteRecord,
+    shard: u32,
+    channel: u8,
+    x: u64,
+    y: u64,
+) -> u64 {
+    let lo = cols[0].populate(record, shard, channel, x as u32, y as u32);
+    let hi = cols[1].populate(record, shard, channel, (x >> 32) as u32, (y >> 32) as u32);
+    lo as u64 | (hi as u64) << 32
+}
+
+/// Evaluates the xor of the low and high words of two u64s.
+pub fn eval_xor64<AB: SP1AirBuilder>(
+    builder: &mut AB,
+    x: [Word<AB::Var>; 2],
+    y: [Word<AB::Var>; 2],
+    cols: Xor64Operation<AB::Var>,
+    shard: AB::Var,
+    channel: AB::Var,
+    is_real: AB::Var,
+) {
+    for i in 0..2 {
+        XorOperation::<AB::F>::eval(builder, x[i], y[i], cols[i], shard, channel, is_real);
+    }
+}
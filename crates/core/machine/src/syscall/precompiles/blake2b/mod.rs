@@ -0,0 +1,151 @@
+mod air;
+mod columns;
+mod g;
+mod trace;
+
+/// The number of words in the Blake2b state, with each u64 split into two u32 words.
+pub const BLAKE2B_STATE_NUM_WORDS: usize = 16;
+
+/// The number of words in a Blake2b block: the 32-word message, the 4-word byte counter, and the
+/// final block flag.
+pub const BLAKE2B_BLOCK_NUM_WORDS: usize = 37;
+
+/// The number of rounds in the Blake2b compression function.
+pub const BLAKE2B_NUM_ROUNDS: usize = 12;
+
+/// The number of `G` calls in a round.
+pub const BLAKE2B_NUM_G: usize = 8;
+
+/// The Blake2b initialization vector.
+pub const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// The message schedule. Round `r` uses the row `r % 10`.
+pub const BLAKE2B_SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// The working vector indices mixed by each `G` call in a round: four columns followed by four
+/// diagonals.
+pub const BLAKE2B_G_INDICES: [[usize; 4]; BLAKE2B_NUM_G] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+/// The permutation taking the message words of `round` to those of the next round, so that
+/// `next[k] = current[permutation[k]]`.
+pub fn blake2b_msg_permutation(round: usize) -> [usize; 16] {
+    let current = BLAKE2B_SIGMA[round % 10];
+    let next = BLAKE2B_SIGMA[(round + 1) % 10];
+    next.map(|i| current.iter().position(|&j| j == i).unwrap())
+}
+
+/// Implements the Blake2b compression function `F` from RFC 7693. The inputs to the syscall are a
+/// pointer to the 8 u64 state, stored as 16 little endian words and overwritten with the result,
+/// and a pointer to the block: the 16 u64 message words, the 128-bit byte counter and a final
+/// block flag word that is either 0 or 1.
+///
+/// In the AIR, each syscall takes up 12 rows, one per round. The working vector is initialized in
+/// the first row and the feed-forward into the state is done in the last row.
+#[derive(Default)]
+pub struct Blake2bCompressChip;
+
+impl Blake2bCompressChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod compress_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use super::BLAKE2B_IV;
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{slice_at, syscall_program},
+    };
+
+    const STATE_PTR: u32 = 100;
+    const BLOCK_PTR: u32 = 1000;
+
+    /// The state and the single block for the unkeyed BLAKE2b-512 hash of "abc".
+    fn abc_input() -> ([u32; 16], [u32; 37]) {
+        let mut h = BLAKE2B_IV;
+        h[0] ^= 0x01010040;
+        let state = core::array::from_fn(|i| (h[i / 2] >> (32 * (i % 2))) as u32);
+
+        let mut block = [0u32; 37];
+        block[0] = u32::from_le_bytes([b'a', b'b', b'c', 0]);
+        block[32] = 3;
+        block[36] = 1;
+        (state, block)
+    }
+
+    pub fn blake2b_compress_program(state: &[u32; 16], block: &[u32; 37]) -> Program {
+        let words = [slice_at(STATE_PTR, state), slice_at(BLOCK_PTR, block)].concat();
+        syscall_program(SyscallCode::BLAKE2B_COMPRESS, words, STATE_PTR, BLOCK_PTR)
+    }
+
+    #[test]
+    fn test_blake2b_compress_execute() {
+        setup_logger();
+        let (state, block) = abc_input();
+        let program = blake2b_compress_program(&state, &block);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let digest =
+            (0..16).flat_map(|i| runtime.word(STATE_PTR + i * 4).to_le_bytes()).collect::<Vec<_>>();
+        assert_eq!(
+            hex::encode(digest),
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+    }
+
+    #[test]
+    fn test_blake2b_compress_prove() {
+        setup_logger();
+        let (state, block) = abc_input();
+        run_test::<CpuProver<_, _>>(blake2b_compress_program(&state, &block)).unwrap();
+    }
+
+    #[test]
+    fn test_blake2b_compress_prove_not_final() {
+        setup_logger();
+        let (state, mut block) = abc_input();
+        for (i, word) in block[..32].iter_mut().enumerate() {
+            *word = 0x01010101 * i as u32;
+        }
+        block[32] = 0xffffff80;
+        block[33] = u32::MAX;
+        block[34] = 1;
+        block[36] = 0;
+        run_test::<CpuProver<_, _>>(blake2b_compress_program(&state, &block)).unwrap();
+    }
+}
@@ -0,0 +1,187 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{Blake2bCompressEvent, ByteLookupEvent, ByteRecord},
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_stark::air::MachineAir;
+
+use super::{
+    columns::{Blake2bCompressCols, NUM_BLAKE2B_COMPRESS_COLS},
+    g::{populate_xor64, u64_to_words},
+    Blake2bCompressChip, BLAKE2B_G_INDICES, BLAKE2B_IV, BLAKE2B_NUM_ROUNDS, BLAKE2B_SIGMA,
+};
+use crate::utils::{pad_rows, par_generate_rows};
+
+impl<F: PrimeField32> MachineAir<F> for Blake2bCompressChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Blake2bCompress".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(&input.blake2b_compress_events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        let num_real_rows = rows.len();
+
+        pad_rows(&mut rows, || [F::zero(); NUM_BLAKE2B_COMPRESS_COLS]);
+
+        // Set the round flags for the padded rows. Each event takes up a full cycle of rounds, so
+        // the padding continues the cycle from the first round.
+        for (i, row) in rows[num_real_rows..].iter_mut().enumerate() {
+            let cols: &mut Blake2bCompressCols<F> = row.as_mut_slice().borrow_mut();
+            cols.round[i % BLAKE2B_NUM_ROUNDS] = F::one();
+        }
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_BLAKE2B_COMPRESS_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Blake2bCompressCols<F> = trace.values
+                [i * NUM_BLAKE2B_COMPRESS_COLS..(i + 1) * NUM_BLAKE2B_COMPRESS_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.blake2b_compress_events.is_empty()
+    }
+}
+
+/// Joins little endian pairs of u32 words into u64 words.
+fn words_to_u64s<const N: usize>(words: &[u32]) -> [u64; N] {
+    core::array::from_fn(|i| words[2 * i] as u64 | (words[2 * i + 1] as u64) << 32)
+}
+
+impl Blake2bCompressChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &Blake2bCompressEvent,
+        rows: &mut Vec<[F; NUM_BLAKE2B_COMPRESS_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+
+        let h = words_to_u64s::<8>(&event.state);
+        let m = words_to_u64s::<16>(&event.block[..32]);
+        let t = words_to_u64s::<2>(&event.block[32..36]);
+        let last = event.block[36] == 1;
+
+        // Initialize the working vector.
+        let mut v = [0u64; 16];
+        v[..8].copy_from_slice(&h);
+        v[8..].copy_from_slice(&BLAKE2B_IV);
+        for (i, t) in t.iter().enumerate() {
+            v[12 + i] ^= t;
+            for (b, c) in BLAKE2B_IV[4 + i].to_le_bytes().into_iter().zip(t.to_le_bytes()) {
+                blu.add_byte_lookup_event(ByteLookupEvent {
+                    shard,
+                    channel,
+                    opcode: ByteOpcode::XOR,
+                    a1: (b ^ c) as u16,
+                    a2: 0,
+                    b,
+                    c,
+                });
+            }
+        }
+        if last {
+            v[14] = !v[14];
+        }
+
+        for round in 0..BLAKE2B_NUM_ROUNDS {
+            let mut row = [F::zero(); NUM_BLAKE2B_COMPRESS_COLS];
+            let cols: &mut Blake2bCompressCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.shard = F::from_canonical_u32(shard);
+            cols.channel = F::from_canonical_u8(channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+            cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+            cols.round[round] = F::one();
+            cols.is_real = F::one();
+
+            let message = BLAKE2B_SIGMA[round % 10].map(|i| m[i]);
+            cols.v = v.map(u64_to_words);
+            cols.message = message.map(u64_to_words);
+
+            // In the first round, read the state and the block.
+            if round == 0 {
+                for (j, read_record) in event.state_read_records.iter().enumerate() {
+                    cols.state_mem[j].populate_read(channel, *read_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &read_record.value.to_le_bytes());
+                }
+                for (j, read_record) in event.block_read_records.iter().enumerate() {
+                    cols.block_mem[j].populate(channel, *read_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &read_record.value.to_le_bytes());
+                }
+                cols.do_memory_check = F::one();
+                cols.receive_ecall = F::one();
+            }
+
+            // Mix the columns and then the diagonals.
+            for (i, indices) in BLAKE2B_G_INDICES.iter().enumerate() {
+                let g_input = indices.map(|j| v[j]);
+                let g_output = cols.g[i].populate(
+                    blu,
+                    shard,
+                    channel,
+                    g_input,
+                    message[2 * i],
+                    message[2 * i + 1],
+                );
+                for (&j, value) in indices.iter().zip(g_output) {
+                    v[j] = value;
+                }
+            }
+
+            // In the last round, feed the working vector forward and write the state back.
+            if round == BLAKE2B_NUM_ROUNDS - 1 {
+                for i in 0..8 {
+                    let v_xor =
+                        populate_xor64(&mut cols.v_xor[i], blu, shard, channel, v[i], v[i + 8]);
+                    let result =
+                        populate_xor64(&mut cols.h_xor[i], blu, shard, channel, h[i], v_xor);
+                    debug_assert_eq!(event.state_write_records[2 * i].value, result as u32);
+                    debug_assert_eq!(
+                        event.state_write_records[2 * i + 1].value,
+                        (result >> 32) as u32
+                    );
+                }
+                for (j, write_record) in event.state_write_records.iter().enumerate() {
+                    cols.state_mem[j].populate_write(channel, *write_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &write_record.value.to_le_bytes());
+                }
+                cols.do_memory_check = F::one();
+                cols.is_final = F::one();
+            }
+
+            rows.push(row);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+use sp1_stark::Word;
+
+use crate::memory::{MemoryReadCols, MemoryReadWriteCols};
+
+use super::{g::GOperation, BLAKE3_NUM_G, BLAKE3_NUM_ROUNDS, BLAKE3_NUM_WORDS};
+
+pub const NUM_BLAKE3_COMPRESS_COLS: usize = size_of::<Blake3CompressCols<u8>>();
+
+/// A set of columns needed to compute the rounds of the Blake3 compression function.
+///
+/// Each syscall is processed over 7 rows, one per round. The state and the message block are read
+/// from memory in the first row, carried to the next row after each round, and the state is
+/// written back to memory in the last row.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct Blake3CompressCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub state_ptr: T,
+    pub message_ptr: T,
+
+    /// Which round this row is processing.
+    pub round: [T; BLAKE3_NUM_ROUNDS],
+
+    /// Memory columns for the state. The state is read in the first round and written in the
+    /// last round.
+    pub state_mem: [MemoryReadWriteCols<T>; BLAKE3_NUM_WORDS],
+
+    /// Memory columns for the message block, which is read in the first round.
+    pub message_mem: [MemoryReadCols<T>; BLAKE3_NUM_WORDS],
+
+    /// The state at the start of the round.
+    pub state: [Word<T>; BLAKE3_NUM_WORDS],
+
+    /// The message words, permuted for the current round.
+    pub message: [Word<T>; BLAKE3_NUM_WORDS],
+
+    /// The mixing functions applied to the columns and then to the diagonals of the state.
+    pub g: [GOperation<T>; BLAKE3_NUM_G],
+
+    /// If the row is real and in the first or last round.
+    pub do_memory_check: T,
+
+    /// If the row is real and in the first round.
+    pub receive_ecall: T,
+
+    pub is_real: T,
+}
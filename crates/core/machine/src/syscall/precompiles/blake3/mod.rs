@@ -0,0 +1,104 @@
+mod air;
+mod columns;
+mod g;
+mod trace;
+
+/// The number of words in the Blake3 state and message block.
+pub const BLAKE3_NUM_WORDS: usize = 16;
+
+/// The number of rounds in the Blake3 compression function.
+pub const BLAKE3_NUM_ROUNDS: usize = 7;
+
+/// The number of `G` calls in a round.
+pub const BLAKE3_NUM_G: usize = 8;
+
+/// The permutation applied to the message words between rounds.
+pub const BLAKE3_MSG_PERMUTATION: [usize; BLAKE3_NUM_WORDS] =
+    [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// The state indices mixed by each `G` call in a round: four columns followed by four diagonals.
+pub const BLAKE3_G_INDICES: [[usize; 4]; BLAKE3_NUM_G] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+/// Implements the rounds of the Blake3 compression function. The inputs to the syscall are a
+/// pointer to the 16 word state, which is overwritten with the result, and a pointer to the 16 word
+/// message block.
+///
+/// The caller is responsible for initializing the state from the chaining value, counter, block
+/// length and flags, and for the final feed-forward, both of which are cheap in comparison.
+///
+/// In the AIR, each syscall takes up 7 rows, one per round.
+#[derive(Default)]
+pub struct Blake3CompressChip;
+
+impl Blake3CompressChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod compress_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{slice_at, syscall_program},
+    };
+
+    const STATE_PTR: u32 = 100;
+    const MESSAGE_PTR: u32 = 1000;
+
+    const IV: [u32; 8] = [
+        0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB,
+        0x5BE0CD19,
+    ];
+
+    /// The state for hashing the empty input: a single block that starts and ends the root chunk.
+    fn empty_input_state() -> [u32; 16] {
+        let flags = 1 | 2 | 8;
+        let mut state = [0u32; 16];
+        state[..8].copy_from_slice(&IV);
+        state[8..12].copy_from_slice(&IV[..4]);
+        state[15] = flags;
+        state
+    }
+
+    pub fn blake3_compress_program(state: &[u32; 16], message: &[u32; 16]) -> Program {
+        let words = [slice_at(STATE_PTR, state), slice_at(MESSAGE_PTR, message)].concat();
+        syscall_program(SyscallCode::BLAKE3_COMPRESS, words, STATE_PTR, MESSAGE_PTR)
+    }
+
+    #[test]
+    fn test_blake3_compress_execute() {
+        setup_logger();
+        let program = blake3_compress_program(&empty_input_state(), &[0; 16]);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let state = (0..16).map(|i| runtime.word(STATE_PTR + i * 4)).collect::<Vec<_>>();
+        let digest =
+            (0..8).flat_map(|i| (state[i] ^ state[i + 8]).to_le_bytes()).collect::<Vec<_>>();
+        assert_eq!(
+            hex::encode(digest),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+    }
+
+    #[test]
+    fn test_blake3_compress_prove() {
+        setup_logger();
+        let message = core::array::from_fn(|i| 0x01010101 * i as u32);
+        let program = blake3_compress_program(&empty_input_state(), &message);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
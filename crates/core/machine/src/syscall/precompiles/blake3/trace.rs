@@ -0,0 +1,145 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{Blake3CompressEvent, ByteLookupEvent, ByteRecord},
+    ExecutionRecord, Program,
+};
+use sp1_stark::{air::MachineAir, Word};
+
+use super::{
+    columns::{Blake3CompressCols, NUM_BLAKE3_COMPRESS_COLS},
+    Blake3CompressChip, BLAKE3_G_INDICES, BLAKE3_MSG_PERMUTATION, BLAKE3_NUM_ROUNDS,
+};
+use crate::utils::{pad_rows, par_generate_rows};
+
+impl<F: PrimeField32> MachineAir<F> for Blake3CompressChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Blake3Compress".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(&input.blake3_compress_events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        let num_real_rows = rows.len();
+
+        pad_rows(&mut rows, || [F::zero(); NUM_BLAKE3_COMPRESS_COLS]);
+
+        // Set the round flags for the padded rows. Each event takes up a full cycle of rounds, so
+        // the padding continues the cycle from the first round.
+        for (i, row) in rows[num_real_rows..].iter_mut().enumerate() {
+            let cols: &mut Blake3CompressCols<F> = row.as_mut_slice().borrow_mut();
+            cols.round[i % BLAKE3_NUM_ROUNDS] = F::one();
+        }
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_BLAKE3_COMPRESS_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Blake3CompressCols<F> = trace.values
+                [i * NUM_BLAKE3_COMPRESS_COLS..(i + 1) * NUM_BLAKE3_COMPRESS_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.blake3_compress_events.is_empty()
+    }
+}
+
+impl Blake3CompressChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &Blake3CompressEvent,
+        rows: &mut Vec<[F; NUM_BLAKE3_COMPRESS_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+
+        let mut state = event.state;
+        let mut message = event.message;
+        for round in 0..BLAKE3_NUM_ROUNDS {
+            let mut row = [F::zero(); NUM_BLAKE3_COMPRESS_COLS];
+            let cols: &mut Blake3CompressCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.shard = F::from_canonical_u32(shard);
+            cols.channel = F::from_canonical_u8(channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+            cols.message_ptr = F::from_canonical_u32(event.message_ptr);
+            cols.round[round] = F::one();
+            cols.is_real = F::one();
+
+            cols.state = state.map(Word::from);
+            cols.message = message.map(Word::from);
+
+            // In the first round, read the state and the message block.
+            if round == 0 {
+                for (j, read_record) in event.state_read_records.iter().enumerate() {
+                    cols.state_mem[j].populate_read(channel, *read_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &read_record.value.to_le_bytes());
+                }
+                for (j, read_record) in event.message_read_records.iter().enumerate() {
+                    cols.message_mem[j].populate(channel, *read_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &read_record.value.to_le_bytes());
+                }
+                cols.do_memory_check = F::one();
+                cols.receive_ecall = F::one();
+            }
+
+            // Mix the columns and then the diagonals.
+            for (i, indices) in BLAKE3_G_INDICES.iter().enumerate() {
+                let g_input = indices.map(|j| state[j]);
+                let g_output = cols.g[i].populate(
+                    blu,
+                    shard,
+                    channel,
+                    g_input,
+                    message[2 * i],
+                    message[2 * i + 1],
+                );
+                for (&j, value) in indices.iter().zip(g_output) {
+                    state[j] = value;
+                }
+            }
+            message = BLAKE3_MSG_PERMUTATION.map(|i| message[i]);
+
+            // In the last round, write the state back.
+            if round == BLAKE3_NUM_ROUNDS - 1 {
+                for (j, write_record) in event.state_write_records.iter().enumerate() {
+                    debug_assert_eq!(write_record.value, state[j]);
+                    cols.state_mem[j].populate_write(channel, *write_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &write_record.value.to_le_bytes());
+                }
+                cols.do_memory_check = F::one();
+            }
+
+            rows.push(row);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::syscalls::SyscallCode;
+use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
+
+use super::{
+    columns::{ChaCha20Cols, NUM_CHACHA20_COLS},
+    quarter_round::QuarterRoundOperation,
+    ChaCha20Chip, CHACHA20_NUM_DOUBLE_ROUNDS, CHACHA20_NUM_WORDS, CHACHA20_QUARTER_ROUND_INDICES,
+};
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+    operations::AddOperation,
+};
+
+impl<F> BaseAir<F> for ChaCha20Chip {
+    fn width(&self) -> usize {
+        NUM_CHACHA20_COLS
+    }
+}
+
+impl<AB> Air<AB> for ChaCha20Chip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &ChaCha20Cols<AB::Var> = (*local).borrow();
+        let next: &ChaCha20Cols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The round flags are one-hot, start at the first round and cycle through the rounds.
+        for i in 0..CHACHA20_NUM_DOUBLE_ROUNDS {
+            builder.assert_bool(local.round[i]);
+        }
+        builder.assert_one(local.round.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag));
+        builder.when_first_row().assert_one(local.round[0]);
+        for i in 0..CHACHA20_NUM_DOUBLE_ROUNDS {
+            builder
+                .when_transition()
+                .assert_eq(local.round[i], next.round[(i + 1) % CHACHA20_NUM_DOUBLE_ROUNDS]);
+        }
+
+        let first_round = local.round[0];
+        let final_round = local.round[CHACHA20_NUM_DOUBLE_ROUNDS - 1];
+        let not_final_round = AB::Expr::one() - final_round;
+
+        builder.assert_bool(local.is_real);
+        builder.assert_eq((first_round + final_round) * local.is_real, local.do_memory_check);
+        builder.assert_eq(first_round * local.is_real, local.receive_ecall);
+        builder.assert_eq(final_round * local.is_real, local.write_keystream);
+
+        // Constrain that the inputs stay the same throughout the rows of each syscall.
+        let mut transition_builder = builder.when_transition();
+        let mut transition_not_final_builder = transition_builder.when(not_final_round.clone());
+        transition_not_final_builder.assert_eq(local.shard, next.shard);
+        transition_not_final_builder.assert_eq(local.channel, next.channel);
+        transition_not_final_builder.assert_eq(local.clk, next.clk);
+        transition_not_final_builder.assert_eq(local.state_ptr, next.state_ptr);
+        transition_not_final_builder.assert_eq(local.is_real, next.is_real);
+
+        // The last row must be nonreal because the number of rounds is not a power of 2. This
+        // constraint ensures that the table does not end abruptly.
+        builder.when_last_row().assert_zero(local.is_real);
+
+        // In the first round, the state comes from memory and is unchanged since it's a memory
+        // read. It is also the input state that is added back in the last round.
+        for i in 0..CHACHA20_NUM_WORDS {
+            let mut first_round_builder = builder.when(first_round * local.is_real);
+            first_round_builder
+                .assert_word_eq(*local.state_mem[i].value(), *local.state_mem[i].prev_value());
+            first_round_builder.assert_word_eq(*local.state_mem[i].value(), local.state[i]);
+            first_round_builder.assert_word_eq(local.input[i], local.state[i]);
+        }
+
+        // Apply the quarter rounds to the columns and then to the diagonals.
+        let mut state = local.state;
+        for (i, indices) in CHACHA20_QUARTER_ROUND_INDICES.iter().enumerate() {
+            QuarterRoundOperation::<AB::F>::eval(
+                builder,
+                indices.map(|j| state[j]),
+                local.quarter_rounds[i],
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            for (&j, value) in indices.iter().zip(local.quarter_rounds[i].result()) {
+                state[j] = value;
+            }
+        }
+
+        // Carry the state and the input state to the next round.
+        let mut transition_builder = builder.when_transition();
+        let mut next_round_builder = transition_builder.when(not_final_round * local.is_real);
+        for i in 0..CHACHA20_NUM_WORDS {
+            next_round_builder.assert_word_eq(next.state[i], state[i]);
+            next_round_builder.assert_word_eq(next.input[i], local.input[i]);
+        }
+
+        // In the last round, the input state is added to the result and written back to memory.
+        for i in 0..CHACHA20_NUM_WORDS {
+            AddOperation::<AB::F>::eval(
+                builder,
+                state[i],
+                local.input[i],
+                local.keystream[i],
+                local.shard,
+                local.channel,
+                local.write_keystream.into(),
+            );
+            builder
+                .when(local.write_keystream)
+                .assert_word_eq(*local.state_mem[i].value(), local.keystream[i].value);
+        }
+
+        // Constrain memory.
+        for i in 0..CHACHA20_NUM_WORDS as u32 {
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk + final_round, // The clk increments by 1 after the final round.
+                local.state_ptr + AB::Expr::from_canonical_u32(i * 4),
+                &local.state_mem[i as usize],
+                local.do_memory_check,
+            );
+        }
+
+        // Range check all the values read from and written to memory to be bytes.
+        for i in 0..CHACHA20_NUM_WORDS {
+            builder.slice_range_check_u8(
+                &local.state_mem[i].value().0,
+                local.shard,
+                local.channel,
+                local.do_memory_check,
+            );
+        }
+
+        // Receive the syscall in the first row of each syscall.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::CHACHA20_BLOCK.syscall_id()),
+            local.state_ptr,
+            AB::Expr::zero(),
+            local.receive_ecall,
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+use sp1_stark::Word;
+
+use crate::{memory::MemoryReadWriteCols, operations::AddOperation};
+
+use super::{
+    quarter_round::QuarterRoundOperation, CHACHA20_NUM_DOUBLE_ROUNDS, CHACHA20_NUM_QUARTER_ROUNDS,
+    CHACHA20_NUM_WORDS,
+};
+
+pub const NUM_CHACHA20_COLS: usize = size_of::<ChaCha20Cols<u8>>();
+
+/// A set of columns needed to compute a ChaCha20 keystream block.
+///
+/// Each syscall is processed over 10 rows, one per double round. The state is read from memory in
+/// the first row and carried to the next row after each double round. In the last row, the input
+/// state is added to the result and the keystream block is written back to memory.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct ChaCha20Cols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub state_ptr: T,
+
+    /// Which double round this row is processing.
+    pub round: [T; CHACHA20_NUM_DOUBLE_ROUNDS],
+
+    /// Memory columns for the state. The state is read in the first round and the keystream block
+    /// is written in the last round.
+    pub state_mem: [MemoryReadWriteCols<T>; CHACHA20_NUM_WORDS],
+
+    /// The input state, which is carried through all the rows for the final addition.
+    pub input: [Word<T>; CHACHA20_NUM_WORDS],
+
+    /// The state at the start of the double round.
+    pub state: [Word<T>; CHACHA20_NUM_WORDS],
+
+    /// The quarter rounds applied to the columns and then to the diagonals of the state.
+    pub quarter_rounds: [QuarterRoundOperation<T>; CHACHA20_NUM_QUARTER_ROUNDS],
+
+    /// The addition of the input state to the result of the last double round.
+    pub keystream: [AddOperation<T>; CHACHA20_NUM_WORDS],
+
+    /// If the row is real and in the first or last round.
+    pub do_memory_check: T,
+
+    /// If the row is real and in the first round.
+    pub receive_ecall: T,
+
+    /// If the row is real and in the last round.
+    pub write_keystream: T,
+
+    pub is_real: T,
+}
@@ -0,0 +1,95 @@
+mod air;
+mod columns;
+mod quarter_round;
+mod trace;
+
+/// The number of words in the ChaCha20 state.
+pub const CHACHA20_NUM_WORDS: usize = 16;
+
+/// The number of double rounds in the ChaCha20 block function.
+pub const CHACHA20_NUM_DOUBLE_ROUNDS: usize = 10;
+
+/// The number of quarter rounds in a double round.
+pub const CHACHA20_NUM_QUARTER_ROUNDS: usize = 8;
+
+/// The state indices mixed by each quarter round in a double round: four columns followed by four
+/// diagonals.
+pub const CHACHA20_QUARTER_ROUND_INDICES: [[usize; 4]; CHACHA20_NUM_QUARTER_ROUNDS] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+/// Implements the ChaCha20 block function. The input to the syscall is a pointer to the 16 word
+/// state, which is overwritten with the keystream block: the state after the 20 rounds plus the
+/// input state.
+///
+/// In the AIR, each syscall takes up 10 rows, one per double round.
+#[derive(Default)]
+pub struct ChaCha20Chip;
+
+impl ChaCha20Chip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod chacha20_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    const STATE_PTR: u32 = 100;
+
+    /// The block function test vector from section 2.3.2 of RFC 8439.
+    const STATE: [u32; 16] = [
+        0x61707865, 0x3320646e, 0x79622d32, 0x6b206574, 0x03020100, 0x07060504, 0x0b0a0908,
+        0x0f0e0d0c, 0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c, 0x00000001, 0x09000000,
+        0x4a000000, 0x00000000,
+    ];
+
+    const KEYSTREAM: [u32; 16] = [
+        0xe4e7f110, 0x15593bd1, 0x1fdd0f50, 0xc47120a3, 0xc7f4d1c7, 0x0368c033, 0x9aaa2204,
+        0x4e6cd4c3, 0x466482d2, 0x09aa9f07, 0x05d7c214, 0xa2028bd9, 0xd19c12b5, 0xb94e16de,
+        0xe883d0cb, 0x4e3c50a2,
+    ];
+
+    pub fn chacha20_block_program(states: &[[u32; 16]]) -> Program {
+        let mut instructions = vec![];
+        for state in states {
+            store_words(&mut instructions, slice_at(STATE_PTR, state));
+            call_syscall(&mut instructions, SyscallCode::CHACHA20_BLOCK, STATE_PTR, 0);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_chacha20_block_execute() {
+        setup_logger();
+        let program = chacha20_block_program(&[STATE]);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let keystream = (0..16).map(|i| runtime.word(STATE_PTR + i * 4)).collect::<Vec<_>>();
+        assert_eq!(keystream, KEYSTREAM);
+    }
+
+    #[test]
+    fn test_chacha20_block_prove() {
+        setup_logger();
+        let mut counter_overflow = STATE;
+        counter_overflow[12] = u32::MAX;
+        let program = chacha20_block_program(&[STATE, counter_overflow]);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
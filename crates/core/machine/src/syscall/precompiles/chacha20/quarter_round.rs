@@ -0,0 +1,189 @@
+use p3_field::Field;
+use sp1_core_executor::events::ByteRecord;
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{air::SP1AirBuilder, Word};
+
+use crate::operations::{AddOperation, FixedRotateRightOperation, XorOperation};
+
+/// A set of columns needed to compute the ChaCha20 quarter round on four state words.
+///
+/// The quarter round rotates left by 16, 12, 8 and 7 bits, which are computed as right rotations by
+/// 16, 20, 24 and 25 bits.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct QuarterRoundOperation<T> {
+    /// `a := a + b`.
+    pub a_plus_b: AddOperation<T>,
+    pub d_xor_a: XorOperation<T>,
+    /// `d := (d ^ a) <<< 16`.
+    pub d_rotated: FixedRotateRightOperation<T>,
+    /// `c := c + d`.
+    pub c_plus_d: AddOperation<T>,
+    pub b_xor_c: XorOperation<T>,
+    /// `b := (b ^ c) <<< 12`.
+    pub b_rotated: FixedRotateRightOperation<T>,
+
+    /// `a := a + b`.
+    pub a_plus_b_2: AddOperation<T>,
+    pub d_xor_a_2: XorOperation<T>,
+    /// `d := (d ^ a) <<< 8`.
+    pub d_rotated_2: FixedRotateRightOperation<T>,
+    /// `c := c + d`.
+    pub c_plus_d_2: AddOperation<T>,
+    pub b_xor_c_2: XorOperation<T>,
+    /// `b := (b ^ c) <<< 7`.
+    pub b_rotated_2: FixedRotateRightOperation<T>,
+}
+
+impl<T: Copy> QuarterRoundOperation<T> {
+    /// The output words `[a, b, c, d]`.
+    pub fn result(&self) -> [Word<T>; 4] {
+        [
+            self.a_plus_b_2.value,
+            self.b_rotated_2.value,
+            self.c_plus_d_2.value,
+            self.d_rotated_2.value,
+        ]
+    }
+}
+
+impl<F: Field> QuarterRoundOperation<F> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        input: [u32; 4],
+    ) -> [u32; 4] {
+        let [a, b, c, d] = input;
+
+        let a = self.a_plus_b.populate(record, shard, channel, a, b);
+        let d_xor_a = self.d_xor_a.populate(record, shard, channel, d, a);
+        let d = self.d_rotated.populate(record, shard, channel, d_xor_a, 16);
+        let c = self.c_plus_d.populate(record, shard, channel, c, d);
+        let b_xor_c = self.b_xor_c.populate(record, shard, channel, b, c);
+        let b = self.b_rotated.populate(record, shard, channel, b_xor_c, 20);
+
+        let a = self.a_plus_b_2.populate(record, shard, channel, a, b);
+        let d_xor_a = self.d_xor_a_2.populate(record, shard, channel, d, a);
+        let d = self.d_rotated_2.populate(record, shard, channel, d_xor_a, 24);
+        let c = self.c_plus_d_2.populate(record, shard, channel, c, d);
+        let b_xor_c = self.b_xor_c_2.populate(record, shard, channel, b, c);
+        let b = self.b_rotated_2.populate(record, shard, channel, b_xor_c, 25);
+
+        [a, b, c, d]
+    }
+
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        input: [Word<AB::Var>; 4],
+        cols: QuarterRoundOperation<AB::Var>,
+        shard: AB::Var,
+        channel: AB::Var,
+        is_real: AB::Var,
+    ) {
+        let [a, b, c, d] = input;
+
+        AddOperation::<AB::F>::eval(builder, a, b, cols.a_plus_b, shard, channel, is_real.into());
+        XorOperation::<AB::F>::eval(
+            builder,
+            d,
+            cols.a_plus_b.value,
+            cols.d_xor_a,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            cols.d_xor_a.value,
+            16,
+            cols.d_rotated,
+            shard,
+            channel,
+            is_real,
+        );
+        AddOperation::<AB::F>::eval(
+            builder,
+            c,
+            cols.d_rotated.value,
+            cols.c_plus_d,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            b,
+            cols.c_plus_d.value,
+            cols.b_xor_c,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            cols.b_xor_c.value,
+            20,
+            cols.b_rotated,
+            shard,
+            channel,
+            is_real,
+        );
+
+        AddOperation::<AB::F>::eval(
+            builder,
+            cols.a_plus_b.value,
+            cols.b_rotated.value,
+            cols.a_plus_b_2,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            cols.d_rotated.value,
+            cols.a_plus_b_2.value,
+            cols.d_xor_a_2,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            cols.d_xor_a_2.value,
+            24,
+            cols.d_rotated_2,
+            shard,
+            channel,
+            is_real,
+        );
+        AddOperation::<AB::F>::eval(
+            builder,
+            cols.c_plus_d.value,
+            cols.d_rotated_2.value,
+            cols.c_plus_d_2,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            cols.b_rotated.value,
+            cols.c_plus_d_2.value,
+            cols.b_xor_c_2,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            cols.b_xor_c_2.value,
+            25,
+            cols.b_rotated_2,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
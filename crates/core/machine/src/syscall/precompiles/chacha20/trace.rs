@@ -0,0 +1,131 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, ChaCha20BlockEvent},
+    ExecutionRecord, Program,
+};
+use sp1_stark::{air::MachineAir, Word};
+
+use super::{
+    columns::{ChaCha20Cols, NUM_CHACHA20_COLS},
+    ChaCha20Chip, CHACHA20_NUM_DOUBLE_ROUNDS, CHACHA20_QUARTER_ROUND_INDICES,
+};
+use crate::utils::{pad_rows, par_generate_rows};
+
+impl<F: PrimeField32> MachineAir<F> for ChaCha20Chip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "ChaCha20".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(&input.chacha20_block_events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        let num_real_rows = rows.len();
+
+        pad_rows(&mut rows, || [F::zero(); NUM_CHACHA20_COLS]);
+
+        // Set the round flags for the padded rows. Each event takes up a full cycle of rounds, so
+        // the padding continues the cycle from the first round.
+        for (i, row) in rows[num_real_rows..].iter_mut().enumerate() {
+            let cols: &mut ChaCha20Cols<F> = row.as_mut_slice().borrow_mut();
+            cols.round[i % CHACHA20_NUM_DOUBLE_ROUNDS] = F::one();
+        }
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_CHACHA20_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut ChaCha20Cols<F> =
+                trace.values[i * NUM_CHACHA20_COLS..(i + 1) * NUM_CHACHA20_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.chacha20_block_events.is_empty()
+    }
+}
+
+impl ChaCha20Chip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &ChaCha20BlockEvent,
+        rows: &mut Vec<[F; NUM_CHACHA20_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+
+        let mut state = event.state;
+        for round in 0..CHACHA20_NUM_DOUBLE_ROUNDS {
+            let mut row = [F::zero(); NUM_CHACHA20_COLS];
+            let cols: &mut ChaCha20Cols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.shard = F::from_canonical_u32(shard);
+            cols.channel = F::from_canonical_u8(channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+            cols.round[round] = F::one();
+            cols.is_real = F::one();
+
+            cols.input = event.state.map(Word::from);
+            cols.state = state.map(Word::from);
+
+            // In the first round, read the state.
+            if round == 0 {
+                for (j, read_record) in event.state_read_records.iter().enumerate() {
+                    cols.state_mem[j].populate_read(channel, *read_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &read_record.value.to_le_bytes());
+                }
+                cols.do_memory_check = F::one();
+                cols.receive_ecall = F::one();
+            }
+
+            // Apply the quarter rounds to the columns and then to the diagonals.
+            for (i, indices) in CHACHA20_QUARTER_ROUND_INDICES.iter().enumerate() {
+                let output =
+                    cols.quarter_rounds[i].populate(blu, shard, channel, indices.map(|j| state[j]));
+                for (&j, value) in indices.iter().zip(output) {
+                    state[j] = value;
+                }
+            }
+
+            // In the last round, add the input state and write the keystream block back.
+            if round == CHACHA20_NUM_DOUBLE_ROUNDS - 1 {
+                for (j, write_record) in event.state_write_records.iter().enumerate() {
+                    let value =
+                        cols.keystream[j].populate(blu, shard, channel, state[j], event.state[j]);
+                    debug_assert_eq!(write_record.value, value);
+                    cols.state_mem[j].populate_write(channel, *write_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &write_record.value.to_le_bytes());
+                }
+                cols.do_memory_check = F::one();
+                cols.write_keystream = F::one();
+            }
+
+            rows.push(row);
+        }
+    }
+}
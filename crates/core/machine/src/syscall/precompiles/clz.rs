@@ -0,0 +1,304 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord},
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::WORD_SIZE;
+use sp1_stark::air::{MachineAir, SP1AirBuilder};
+
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryWriteCols},
+    utils::{pad_rows, par_generate_rows},
+};
+
+/// The number of columns in the ClzCols.
+const NUM_COLS: usize = size_of::<ClzCols<u8>>();
+
+/// The number of bits in a byte.
+const BYTE_BITS: u32 = 8;
+
+/// A chip that counts the leading zeros of a 32-bit word.
+///
+/// `ptr` points to the word, and the count is written over it.
+///
+/// The leading zeros of each byte of the word are looked up with a `CLZ` lookup into the byte
+/// table. The most significant nonzero byte is selected by a boolean flag, and the count is the
+/// number of bits above that byte plus the leading zeros of that byte. A zero word has its own flag
+/// and a count of 32.
+#[derive(Default)]
+pub struct ClzChip;
+
+impl ClzChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Clz operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct ClzCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the word.
+    pub ptr: T,
+
+    /// The word is the previous value of the memory, and the count is written over it.
+    pub memory: MemoryWriteCols<T>,
+
+    /// The number of leading zeros of each byte of the word.
+    pub byte_clz: [T; WORD_SIZE],
+
+    /// A flag for each byte which is set if it is the most significant nonzero byte of the word.
+    pub is_top: [T; WORD_SIZE],
+
+    /// A flag which is set if the word is zero.
+    pub is_zero: T,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for ClzChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Clz".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.clz32_events, |event, new_byte_lookup_events| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut ClzCols<F> = row.as_mut_slice().borrow_mut();
+
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.ptr = F::from_canonical_u32(event.ptr);
+                cols.memory.populate(event.channel, event.memory_record, new_byte_lookup_events);
+
+                let bytes = event.value.to_le_bytes();
+                for (clz, byte) in cols.byte_clz.iter_mut().zip(bytes) {
+                    *clz = F::from_canonical_u32(byte.leading_zeros());
+                    new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                        event.shard,
+                        event.channel,
+                        ByteOpcode::CLZ,
+                        byte.leading_zeros() as u16,
+                        0,
+                        byte,
+                        0,
+                    ));
+                }
+
+                match bytes.iter().rposition(|byte| *byte != 0) {
+                    Some(top) => {
+                        cols.is_top[top] = F::one();
+                        new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                            event.shard,
+                            event.channel,
+                            ByteOpcode::LTU,
+                            1,
+                            0,
+                            bytes[top].leading_zeros() as u8,
+                            BYTE_BITS as u8,
+                        ));
+                    }
+                    None => cols.is_zero = F::one(),
+                }
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut ClzCols<F> = trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.clz32_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for ClzChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for ClzChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &ClzCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &ClzCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Count the leading zeros of each byte of the word. The lookup also checks that the bytes
+        // of the word are bytes.
+        let value = local.memory.prev_value();
+        for (clz, byte) in local.byte_clz.iter().zip(value.0) {
+            builder.send_byte(
+                ByteOpcode::CLZ.as_field::<AB::F>(),
+                *clz,
+                byte,
+                AB::Expr::zero(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Exactly one of the flags is set on a real row.
+        let mut num_flags: AB::Expr = local.is_zero.into();
+        for is_top in local.is_top {
+            builder.assert_bool(is_top);
+            num_flags += is_top.into();
+        }
+        builder.assert_bool(local.is_zero);
+        builder.assert_eq(num_flags, local.is_real);
+
+        // The bytes above the flagged byte are zero, and all bytes are zero for a zero word.
+        for (i, byte) in value.0.iter().enumerate() {
+            for is_top in &local.is_top[..i] {
+                builder.when(*is_top).assert_zero(*byte);
+            }
+            builder.when(local.is_zero).assert_zero(*byte);
+        }
+
+        // The flagged byte is nonzero, which is when it has fewer than eight leading zeros.
+        let is_nonzero = local.is_top.iter().fold(AB::Expr::zero(), |acc, is_top| acc + *is_top);
+        let top_clz = local
+            .is_top
+            .iter()
+            .zip(local.byte_clz)
+            .fold(AB::Expr::zero(), |acc, (is_top, clz)| acc + *is_top * clz);
+        builder.send_byte(
+            ByteOpcode::LTU.as_field::<AB::F>(),
+            AB::Expr::one(),
+            top_clz.clone(),
+            AB::F::from_canonical_u32(BYTE_BITS),
+            local.shard,
+            local.channel,
+            is_nonzero,
+        );
+
+        // The count is the number of bits above the flagged byte plus its leading zeros, and is
+        // at most 32, so it fits in the first byte of the result.
+        let count = local.is_top.iter().enumerate().fold(
+            top_clz + local.is_zero * AB::F::from_canonical_u32(BYTE_BITS * WORD_SIZE as u32),
+            |acc, (i, is_top)| {
+                acc + *is_top * AB::F::from_canonical_u32(BYTE_BITS * (WORD_SIZE - 1 - i) as u32)
+            },
+        );
+        let result = local.memory.value();
+        builder.when(local.is_real).assert_eq(result[0], count);
+        for byte in &result.0[1..] {
+            builder.when(local.is_real).assert_zero(*byte);
+        }
+
+        // Read the word and write the count.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr,
+            &local.memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::CLZ32.syscall_id()),
+            local.ptr,
+            AB::Expr::zero(),
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{self, run_test, tests::syscall_program};
+
+    const PTR: u32 = 100;
+
+    fn clz_program(value: u32) -> Program {
+        syscall_program(SyscallCode::CLZ32, [(PTR, value)], PTR, 0)
+    }
+
+    const VALUES: [u32; 6] = [0, 1, 0x80, 0x100, 0x00ff_0000, u32::MAX];
+
+    #[test]
+    fn test_clz32() {
+        utils::setup_logger();
+        for value in VALUES {
+            let mut runtime = Executor::new(clz_program(value), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            assert_eq!(runtime.word(PTR), value.leading_zeros());
+        }
+    }
+
+    #[test]
+    fn test_clz32_prove() {
+        utils::setup_logger();
+        for value in VALUES {
+            run_test::<CpuProver<_, _>>(clz_program(value)).unwrap();
+        }
+    }
+}
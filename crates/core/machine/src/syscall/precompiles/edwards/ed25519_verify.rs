@@ -0,0 +1,1079 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use crate::air::MemoryAirBuilder;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{
+        ed25519_neg, ByteLookupEvent, ByteRecord, Ed25519VerifyEvent, FieldOperation,
+        ED25519_SCALAR_BITS, ED25519_VERIFY_INPUT_WORDS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    edwards::{
+        ed25519::{Ed25519, Ed25519BaseField, Ed25519Parameters},
+        EdwardsParameters,
+    },
+    params::{FieldParameters, Limbs},
+    AffinePoint, EllipticCurve,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder},
+    MachineRecord,
+};
+use typenum::U32;
+
+use super::EdAddCols;
+use crate::{
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{
+            field_op::FieldOpCols,
+            range::{FieldLtCols, FieldRangeCols},
+        },
+        IsZeroOperation,
+    },
+    utils::{limbs_from_access, pad_rows_with_strategy},
+};
+
+/// The number of bytes of the scalars, each of which takes up eight rows.
+const NUM_SCALAR_BYTES: usize = ED25519_SCALAR_BITS / 8;
+
+/// The number of rows of a syscall: a setup row, a row per bit of the scalars, and a final row.
+pub const ED25519_VERIFY_ROWS: usize = ED25519_SCALAR_BITS + 2;
+
+pub const NUM_ED25519_VERIFY_COLS: usize = size_of::<Ed25519VerifyCols<u8>>();
+
+type BaseField = Ed25519BaseField;
+type Point = AffinePoint<Ed25519>;
+
+/// A set of columns to verify an Ed25519 signature `(R, S)` with the challenge `k` for a public key
+/// `A`, which holds if `S * B - k * A = R`.
+///
+/// The setup row reads the input, checks that the public key is reduced and on the curve, negates
+/// it, and adds `-A` to the base point `B`. Each bit of the scalars, most significant first, then
+/// takes up a row, which doubles the result and adds `B`, `-A`, `B - A` or the neutral element to
+/// it. The final row compares the result with `R`, and writes the validity bit.
+///
+/// Every row performs two additions with the complete addition law, which has no exceptional
+/// cases. The first one doubles the result, except in the setup row where it computes `B - A`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Ed25519VerifyCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub ptr: T,
+
+    /// The kind of row.
+    pub is_setup: T,
+    pub is_step: T,
+    pub is_final: T,
+
+    /// Which byte of the scalars this row processes, most significant first.
+    pub byte_flags: [T; NUM_SCALAR_BYTES],
+    /// Which bit of the byte this row processes, most significant first.
+    pub bit_flags: [T; 8],
+    /// The bits of `S` and `k` of a step row, and their product.
+    pub s_bit: T,
+    pub k_bit: T,
+    pub both_bits: T,
+    /// The value of the bits of the byte that come before this row.
+    pub s_partial: T,
+    pub k_partial: T,
+    /// The bytes of `S` and `k` selected by the byte flags.
+    pub s_byte: T,
+    pub k_byte: T,
+
+    /// The values that stay the same throughout the rows of a syscall.
+    pub s: Limbs<T, U32>,
+    pub k: Limbs<T, U32>,
+    pub neg_pubkey_x: Limbs<T, U32>,
+    pub pubkey_y: Limbs<T, U32>,
+    pub r_x: Limbs<T, U32>,
+    pub r_y: Limbs<T, U32>,
+    pub sum_x: Limbs<T, U32>,
+    pub sum_y: Limbs<T, U32>,
+    /// If the public key is reduced and on the curve. Otherwise it is replaced with the neutral
+    /// element, and the validity bit is not set.
+    pub is_pubkey_valid: T,
+
+    /// The input of the setup row, the range checks and the curve equation of the public key, and
+    /// the negation of its x coordinate.
+    pub input_access: [MemoryReadCols<T>; ED25519_VERIFY_INPUT_WORDS],
+    pub(crate) pubkey_x_range: FieldRangeCols<T, BaseField>,
+    pub(crate) pubkey_y_range: FieldRangeCols<T, BaseField>,
+    /// The public key if it is reduced, and `(0, 0)` otherwise, which is not on the curve.
+    pub reduced_pubkey_x: Limbs<T, U32>,
+    pub reduced_pubkey_y: Limbs<T, U32>,
+    pub(crate) xx: FieldOpCols<T, BaseField>,
+    pub(crate) yy: FieldOpCols<T, BaseField>,
+    pub(crate) xxyy: FieldOpCols<T, BaseField>,
+    pub(crate) dxxyy: FieldOpCols<T, BaseField>,
+    pub(crate) curve_lhs: FieldOpCols<T, BaseField>,
+    pub(crate) curve_rhs: FieldOpCols<T, BaseField>,
+    pub(crate) curve_diff: FieldOpCols<T, BaseField>,
+    pub(crate) curve_diff_range: FieldLtCols<T, BaseField>,
+    pub curve_diff_is_zero: IsZeroOperation<T>,
+    /// The x coordinate of the public key if it is valid, and zero otherwise.
+    pub valid_pubkey_x: Limbs<T, U32>,
+    pub(crate) neg_pubkey: FieldOpCols<T, BaseField>,
+
+    /// The result at the start of the row.
+    pub result_x: Limbs<T, U32>,
+    pub result_y: Limbs<T, U32>,
+    /// The point added to the result by the first addition, which is `-A` in the setup row and the
+    /// result itself in the other rows.
+    pub double_q_x: Limbs<T, U32>,
+    pub double_q_y: Limbs<T, U32>,
+    pub(crate) double: EdAddCols<T>,
+    /// The point added to the output of the first addition by the second one.
+    pub q_x: Limbs<T, U32>,
+    pub q_y: Limbs<T, U32>,
+    pub(crate) add: EdAddCols<T>,
+
+    /// The comparison of the final row, where the differences must be reduced so that they are
+    /// zero exactly when the coordinates are equal modulo the prime.
+    pub(crate) x_diff: FieldOpCols<T, BaseField>,
+    pub(crate) x_diff_range: FieldLtCols<T, BaseField>,
+    pub x_diff_is_zero: IsZeroOperation<T>,
+    pub(crate) y_diff: FieldOpCols<T, BaseField>,
+    pub(crate) y_diff_range: FieldLtCols<T, BaseField>,
+    pub y_diff_is_zero: IsZeroOperation<T>,
+    /// If both coordinates of the result are those of `R`.
+    pub is_match: T,
+    pub result_access: MemoryWriteCols<T>,
+}
+
+/// Verifies Ed25519 signatures against their challenge.
+///
+/// The validity bit is set if the public key is a point of the curve with reduced coordinates, and
+/// if `S * B - k * A` is `R`, whose coordinates are compared modulo the prime.
+#[derive(Default)]
+pub struct Ed25519VerifyChip;
+
+impl Ed25519VerifyChip {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns a row whose field operations hold with zero operands.
+    ///
+    /// The constraints of a field operation do not hold with zero columns, as its witness is
+    /// shifted, so the operations of the setup and final rows are populated in the other rows as
+    /// well, where their operands are zero.
+    fn blank_row<F: PrimeField32>() -> Vec<F> {
+        let mut row = vec![F::zero(); NUM_ED25519_VERIFY_COLS];
+        let cols: &mut Ed25519VerifyCols<F> = row.as_mut_slice().borrow_mut();
+        let zero = BigUint::zero();
+        for (op, kind) in [
+            (&mut cols.xx, FieldOperation::Mul),
+            (&mut cols.yy, FieldOperation::Mul),
+            (&mut cols.xxyy, FieldOperation::Mul),
+            (&mut cols.dxxyy, FieldOperation::Mul),
+            (&mut cols.curve_lhs, FieldOperation::Sub),
+            (&mut cols.curve_rhs, FieldOperation::Add),
+            (&mut cols.curve_diff, FieldOperation::Sub),
+            (&mut cols.neg_pubkey, FieldOperation::Sub),
+            (&mut cols.x_diff, FieldOperation::Sub),
+            (&mut cols.y_diff, FieldOperation::Sub),
+        ] {
+            op.populate(&mut vec![], 0, 0, &zero, &zero, kind);
+        }
+        for op in [&mut cols.double, &mut cols.add] {
+            op.populate::<Ed25519Parameters>(&mut vec![], 0, 0, &zero, &zero, &zero, &zero);
+        }
+        row
+    }
+
+    /// Populates the two additions of a row, `result + double_q` and then `q`, and returns the
+    /// output of the row.
+    fn populate_additions<F: PrimeField32>(
+        cols: &mut Ed25519VerifyCols<F>,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        event: &Ed25519VerifyEvent,
+        result: &Point,
+        double_q: &Point,
+        q: &Point,
+    ) -> Point {
+        let (shard, channel) = (event.shard, event.channel);
+        cols.result_x = BaseField::to_limbs_field::<F, _>(&result.x);
+        cols.result_y = BaseField::to_limbs_field::<F, _>(&result.y);
+        cols.double_q_x = BaseField::to_limbs_field::<F, _>(&double_q.x);
+        cols.double_q_y = BaseField::to_limbs_field::<F, _>(&double_q.y);
+        cols.q_x = BaseField::to_limbs_field::<F, _>(&q.x);
+        cols.q_y = BaseField::to_limbs_field::<F, _>(&q.y);
+        let (x, y) = cols.double.populate::<Ed25519Parameters>(
+            blu_events,
+            shard,
+            channel,
+            &result.x,
+            &result.y,
+            &double_q.x,
+            &double_q.y,
+        );
+        let (x, y) =
+            cols.add.populate::<Ed25519Parameters>(blu_events, shard, channel, &x, &y, &q.x, &q.y);
+        Point::new(x, y)
+    }
+
+    /// Populates the range checks and the curve equation of the public key in the setup row, and
+    /// returns whether it is reduced and on the curve.
+    fn populate_pubkey<F: PrimeField32>(
+        cols: &mut Ed25519VerifyCols<F>,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        event: &Ed25519VerifyEvent,
+        pubkey_x: &BigUint,
+        pubkey_y: &BigUint,
+    ) -> bool {
+        let (shard, channel) = (event.shard, event.channel);
+        let modulus = BaseField::modulus();
+        let x_is_less =
+            cols.pubkey_x_range.populate(blu_events, shard, channel, pubkey_x, &modulus);
+        let y_is_less =
+            cols.pubkey_y_range.populate(blu_events, shard, channel, pubkey_y, &modulus);
+        let (x, y) = if x_is_less && y_is_less {
+            (pubkey_x.clone(), pubkey_y.clone())
+        } else {
+            (BigUint::zero(), BigUint::zero())
+        };
+        cols.reduced_pubkey_x = BaseField::to_limbs_field::<F, _>(&x);
+        cols.reduced_pubkey_y = BaseField::to_limbs_field::<F, _>(&y);
+
+        // The public key is on the curve if `(y^2 - x^2) - (d * x^2 * y^2 + 1)` is zero.
+        let xx = cols.xx.populate(blu_events, shard, channel, &x, &x, FieldOperation::Mul);
+        let yy = cols.yy.populate(blu_events, shard, channel, &y, &y, FieldOperation::Mul);
+        let xxyy = cols.xxyy.populate(blu_events, shard, channel, &xx, &yy, FieldOperation::Mul);
+        let dxxyy = cols.dxxyy.populate(
+            blu_events,
+            shard,
+            channel,
+            &xxyy,
+            &Ed25519Parameters::d_biguint(),
+            FieldOperation::Mul,
+        );
+        let lhs =
+            cols.curve_lhs.populate(blu_events, shard, channel, &yy, &xx, FieldOperation::Sub);
+        let rhs = cols.curve_rhs.populate(
+            blu_events,
+            shard,
+            channel,
+            &dxxyy,
+            &BigUint::one(),
+            FieldOperation::Add,
+        );
+        let diff =
+            cols.curve_diff.populate(blu_events, shard, channel, &lhs, &rhs, FieldOperation::Sub);
+        cols.curve_diff_range.populate(blu_events, shard, channel, &diff, &modulus);
+        let diff_sum = diff.to_bytes_le().iter().map(|&byte| byte as u32).sum::<u32>();
+        cols.curve_diff_is_zero.populate(diff_sum) == 1
+    }
+
+    fn event_rows<F: PrimeField32>(
+        event: &Ed25519VerifyEvent,
+        blu_events: &mut Vec<ByteLookupEvent>,
+    ) -> Vec<Vec<F>> {
+        let (shard, channel) = (event.shard, event.channel);
+        let [s, k, pubkey_x, pubkey_y, r_x, r_y] =
+            core::array::from_fn(|i| BigUint::from_slice(&event.input[8 * i..8 * (i + 1)]));
+        let generator = Ed25519::ec_generator();
+        let neutral = Ed25519::neutral();
+        let mut setup_row = Self::blank_row::<F>();
+        let cols: &mut Ed25519VerifyCols<F> = setup_row.as_mut_slice().borrow_mut();
+        let is_pubkey_valid = Self::populate_pubkey(cols, blu_events, event, &pubkey_x, &pubkey_y);
+        let pubkey = if is_pubkey_valid {
+            Point::new(pubkey_x.clone(), pubkey_y.clone())
+        } else {
+            neutral.clone()
+        };
+        let neg_pubkey = ed25519_neg(&pubkey);
+        let zero = Point::new(BigUint::zero(), BigUint::zero());
+        let sum = &generator + &neg_pubkey;
+        let mut s_bytes = s.to_bytes_le();
+        s_bytes.resize(NUM_SCALAR_BYTES, 0);
+        let mut k_bytes = k.to_bytes_le();
+        k_bytes.resize(NUM_SCALAR_BYTES, 0);
+
+        // Fills in the values that stay the same throughout the rows of the syscall.
+        let fill_row = |row: &mut [F]| {
+            let cols: &mut Ed25519VerifyCols<F> = row.borrow_mut();
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u8(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.ptr = F::from_canonical_u32(event.ptr);
+            cols.s = BaseField::to_limbs_field::<F, _>(&s);
+            cols.k = BaseField::to_limbs_field::<F, _>(&k);
+            cols.neg_pubkey_x = BaseField::to_limbs_field::<F, _>(&neg_pubkey.x);
+            cols.pubkey_y = BaseField::to_limbs_field::<F, _>(&pubkey.y);
+            cols.r_x = BaseField::to_limbs_field::<F, _>(&r_x);
+            cols.r_y = BaseField::to_limbs_field::<F, _>(&r_y);
+            cols.sum_x = BaseField::to_limbs_field::<F, _>(&sum.x);
+            cols.sum_y = BaseField::to_limbs_field::<F, _>(&sum.y);
+            cols.is_pubkey_valid = F::from_bool(is_pubkey_valid);
+        };
+        let new_row = || {
+            let mut row = Self::blank_row::<F>();
+            fill_row(&mut row);
+            row
+        };
+
+        // The setup row reads the input, negates the public key and adds it to the base point.
+        let mut rows = Vec::with_capacity(ED25519_VERIFY_ROWS);
+        fill_row(&mut setup_row);
+        let cols: &mut Ed25519VerifyCols<F> = setup_row.as_mut_slice().borrow_mut();
+        cols.is_setup = F::one();
+        for (access, record) in cols.input_access.iter_mut().zip(&event.input_memory_records) {
+            access.populate(channel, *record, blu_events);
+        }
+        cols.valid_pubkey_x = BaseField::to_limbs_field::<F, _>(&pubkey.x);
+        cols.neg_pubkey.populate(
+            blu_events,
+            shard,
+            channel,
+            &BigUint::zero(),
+            &pubkey.x,
+            FieldOperation::Sub,
+        );
+        Self::populate_additions(cols, blu_events, event, &generator, &neg_pubkey, &zero);
+        rows.push(setup_row);
+
+        // Double the result and add the point selected by the bits, most significant first.
+        let mut result = neutral.clone();
+        for j in 0..NUM_SCALAR_BYTES {
+            let (s_byte, k_byte) =
+                (s_bytes[NUM_SCALAR_BYTES - 1 - j], k_bytes[NUM_SCALAR_BYTES - 1 - j]);
+            for i in 0..8 {
+                let s_bit = (s_byte >> (7 - i)) & 1 == 1;
+                let k_bit = (k_byte >> (7 - i)) & 1 == 1;
+                let mut row = new_row();
+                let cols: &mut Ed25519VerifyCols<F> = row.as_mut_slice().borrow_mut();
+                cols.is_step = F::one();
+                cols.byte_flags[j] = F::one();
+                cols.bit_flags[i] = F::one();
+                cols.s_bit = F::from_bool(s_bit);
+                cols.k_bit = F::from_bool(k_bit);
+                cols.both_bits = F::from_bool(s_bit && k_bit);
+                cols.s_partial = F::from_canonical_u8(((s_byte as u16) >> (8 - i)) as u8);
+                cols.k_partial = F::from_canonical_u8(((k_byte as u16) >> (8 - i)) as u8);
+                cols.s_byte = F::from_canonical_u8(s_byte);
+                cols.k_byte = F::from_canonical_u8(k_byte);
+                let q = match (s_bit, k_bit) {
+                    (true, false) => &generator,
+                    (false, true) => &neg_pubkey,
+                    (true, true) => &sum,
+                    (false, false) => &neutral,
+                };
+                result = Self::populate_additions(cols, blu_events, event, &result, &result, q);
+                rows.push(row);
+            }
+        }
+
+        // Compare the result with `R`, and write the validity bit.
+        let mut row = new_row();
+        let cols: &mut Ed25519VerifyCols<F> = row.as_mut_slice().borrow_mut();
+        cols.is_final = F::one();
+        Self::populate_additions(cols, blu_events, event, &result, &result, &zero);
+        let modulus = BaseField::modulus();
+        let mut is_valid = true;
+        for (diff, range, is_zero, lhs, rhs) in [
+            (&mut cols.x_diff, &mut cols.x_diff_range, &mut cols.x_diff_is_zero, &result.x, &r_x),
+            (&mut cols.y_diff, &mut cols.y_diff_range, &mut cols.y_diff_is_zero, &result.y, &r_y),
+        ] {
+            let diff = diff.populate(blu_events, shard, channel, lhs, rhs, FieldOperation::Sub);
+            range.populate(blu_events, shard, channel, &diff, &modulus);
+            let diff_sum = diff.to_bytes_le().iter().map(|&byte| byte as u32).sum::<u32>();
+            is_valid &= is_zero.populate(diff_sum) == 1;
+        }
+        cols.is_match = F::from_bool(is_valid);
+        debug_assert_eq!(is_pubkey_valid && is_valid, event.is_valid);
+        cols.result_access.populate(channel, event.result_memory_record, blu_events);
+        rows.push(row);
+
+        rows
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for Ed25519VerifyChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Ed25519Verify".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = &input.ed25519_verify_events;
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        // Generate the trace rows & corresponding records for each chunk of events in parallel.
+        let rows_and_records = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut record = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+                let rows = events
+                    .iter()
+                    .flat_map(|event| Self::event_rows::<F>(event, &mut new_byte_lookup_events))
+                    .collect::<Vec<_>>();
+                record.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, record)
+            })
+            .collect::<Vec<_>>();
+
+        let mut rows = Vec::new();
+        for mut row_and_record in rows_and_records {
+            rows.extend(row_and_record.0);
+            output.append(&mut row_and_record.1);
+        }
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            Self::blank_row::<F>()
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_ED25519_VERIFY_COLS,
+        );
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut Ed25519VerifyCols<F> = trace.values
+                [i * NUM_ED25519_VERIFY_COLS..(i + 1) * NUM_ED25519_VERIFY_COLS]
+                .borrow_mut();
+            if i > 0 && cols.is_setup == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.ed25519_verify_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F> BaseAir<F> for Ed25519VerifyChip {
+    fn width(&self) -> usize {
+        NUM_ED25519_VERIFY_COLS
+    }
+}
+
+impl<AB> Air<AB> for Ed25519VerifyChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Ed25519VerifyCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Ed25519VerifyCols<AB::Var> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans several rows, so the nonce counts the syscalls
+        // rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_setup, next.nonce);
+
+        self.eval_flags(builder, local, next);
+
+        // Constrain that the inputs stay the same throughout the rows of each syscall.
+        let not_final = local.is_real - local.is_final;
+        let mut transition_builder = builder.when_transition();
+        let mut carry_builder = transition_builder.when(not_final);
+        carry_builder.assert_eq(local.shard, next.shard);
+        carry_builder.assert_eq(local.channel, next.channel);
+        carry_builder.assert_eq(local.clk, next.clk);
+        carry_builder.assert_eq(local.ptr, next.ptr);
+        carry_builder.assert_eq(local.is_pubkey_valid, next.is_pubkey_valid);
+        for (value, next_value) in [
+            (local.s, next.s),
+            (local.k, next.k),
+            (local.neg_pubkey_x, next.neg_pubkey_x),
+            (local.pubkey_y, next.pubkey_y),
+            (local.r_x, next.r_x),
+            (local.r_y, next.r_y),
+            (local.sum_x, next.sum_x),
+            (local.sum_y, next.sum_y),
+        ] {
+            carry_builder.assert_all_eq(value, next_value);
+        }
+
+        self.eval_bits(builder, local, next);
+
+        self.eval_setup(builder, local);
+
+        self.eval_additions(builder, local, next);
+
+        self.eval_final(builder, local);
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::ED25519_VERIFY.syscall_id()),
+            local.ptr,
+            AB::Expr::zero(),
+            local.is_setup,
+        );
+    }
+}
+
+impl Ed25519VerifyChip {
+    /// Constrains the order of the rows: a setup row, a step row per bit, most significant first,
+    /// and a final row.
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Ed25519VerifyCols<AB::Var>,
+        next: &Ed25519VerifyCols<AB::Var>,
+    ) {
+        let sum_flags =
+            |flags: &[AB::Var]| flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_setup);
+        builder.assert_bool(local.is_step);
+        builder.assert_bool(local.is_final);
+        for &flag in local.byte_flags.iter().chain(local.bit_flags.iter()) {
+            builder.assert_bool(flag);
+        }
+        builder.assert_eq(local.is_setup + local.is_step + local.is_final, local.is_real);
+        builder.assert_eq(sum_flags(&local.byte_flags), local.is_step);
+        builder.assert_eq(sum_flags(&local.bit_flags), local.is_step);
+
+        // A syscall starts in the first row, or after the final row of the previous syscall, and
+        // the padding rows come last.
+        builder.when_first_row().assert_eq(local.is_real, local.is_setup);
+        builder.when_transition().when(local.is_final).assert_eq(next.is_real, next.is_setup);
+        builder.when_transition().when(AB::Expr::one() - local.is_real).assert_zero(next.is_real);
+        builder.when_last_row().assert_eq(local.is_real, local.is_final);
+
+        // The setup row is followed by the step row of the first bit.
+        let mut setup_builder = builder.when_transition();
+        let mut setup_builder = setup_builder.when(local.is_setup);
+        setup_builder.assert_one(next.is_step);
+        setup_builder.assert_one(next.byte_flags[0]);
+        setup_builder.assert_one(next.bit_flags[0]);
+
+        // The step row of the last bit is followed by the final row, and the others by the step
+        // row of the next bit.
+        let is_last_bit = local.byte_flags[NUM_SCALAR_BYTES - 1] * local.bit_flags[7];
+        let mut step_builder = builder.when_transition();
+        let mut step_builder = step_builder.when(local.is_step);
+        step_builder.assert_eq(next.is_final, is_last_bit.clone());
+        step_builder.assert_eq(next.is_step, AB::Expr::one() - is_last_bit.clone());
+        for i in 0..8 {
+            builder
+                .when_transition()
+                .when(local.is_step - is_last_bit.clone())
+                .assert_eq(local.bit_flags[i], next.bit_flags[(i + 1) % 8]);
+        }
+        for j in 0..NUM_SCALAR_BYTES {
+            builder
+                .when_transition()
+                .when(local.is_step)
+                .when_not(local.bit_flags[7])
+                .assert_eq(local.byte_flags[j], next.byte_flags[j]);
+        }
+        for j in 0..NUM_SCALAR_BYTES - 1 {
+            builder
+                .when_transition()
+                .when(local.is_step)
+                .when(local.bit_flags[7])
+                .assert_eq(local.byte_flags[j], next.byte_flags[j + 1]);
+        }
+    }
+
+    /// Constrains the bits of the step rows to be the bits of `S` and `k`, most significant first.
+    /// The bits of each byte are accumulated, and compared with the byte after its last bit.
+    fn eval_bits<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Ed25519VerifyCols<AB::Var>,
+        next: &Ed25519VerifyCols<AB::Var>,
+    ) {
+        builder.assert_bool(local.s_bit);
+        builder.assert_bool(local.k_bit);
+        builder.assert_eq(local.both_bits, local.s_bit * local.k_bit);
+        builder.when_not(local.is_step).assert_zero(local.s_bit);
+        builder.when_not(local.is_step).assert_zero(local.k_bit);
+
+        for (partial, next_partial, bit, byte, scalar) in [
+            (local.s_partial, next.s_partial, local.s_bit, local.s_byte, &local.s),
+            (local.k_partial, next.k_partial, local.k_bit, local.k_byte, &local.k),
+        ] {
+            let selected_byte =
+                local.byte_flags.iter().enumerate().fold(AB::Expr::zero(), |acc, (j, &flag)| {
+                    acc + flag * scalar[NUM_SCALAR_BYTES - 1 - j]
+                });
+            builder.assert_eq(byte, selected_byte);
+
+            let partial_after = partial * AB::F::two() + bit;
+            builder.when_transition().when(local.is_setup).assert_zero(next_partial);
+            builder
+                .when_transition()
+                .when(local.is_step)
+                .when_not(local.bit_flags[7])
+                .assert_eq(partial_after.clone(), next_partial);
+            builder.when(local.is_step).when(local.bit_flags[7]).assert_eq(partial_after, byte);
+            builder
+                .when_transition()
+                .when(local.is_step)
+                .when(local.bit_flags[7])
+                .assert_zero(next_partial);
+        }
+    }
+
+    /// Constrains the setup row: the reads of the input, the range checks and the curve equation
+    /// of the public key, and its negation.
+    ///
+    /// The operands of the field operations are zero outside the setup rows, as the memory values
+    /// are, so that the operations hold with zero operands in the other rows.
+    fn eval_setup<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &Ed25519VerifyCols<AB::Var>) {
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr,
+            &local.input_access,
+            local.is_setup,
+        );
+        let s: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[0..8]);
+        let k: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[8..16]);
+        let pubkey_x: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[16..24]);
+        let pubkey_y: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[24..32]);
+        let r_x: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[32..40]);
+        let r_y: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[40..48]);
+        let mut setup_builder = builder.when(local.is_setup);
+        setup_builder.assert_all_eq(local.s, s);
+        setup_builder.assert_all_eq(local.k, k);
+        setup_builder.assert_all_eq(local.r_x, r_x);
+        setup_builder.assert_all_eq(local.r_y, r_y);
+        setup_builder.assert_all_eq(local.neg_pubkey_x, local.neg_pubkey.result);
+
+        // The public key is replaced with `(0, 0)` if it is not reduced.
+        let modulus = BaseField::modulus();
+        let (shard, channel, is_setup) = (local.shard, local.channel, local.is_setup);
+        local.pubkey_x_range.eval(builder, &pubkey_x, &modulus, shard, channel, is_setup);
+        local.pubkey_y_range.eval(builder, &pubkey_y, &modulus, shard, channel, is_setup);
+        let is_reduced = local.pubkey_x_range.is_less * local.pubkey_y_range.is_less;
+        for i in 0..BaseField::NB_LIMBS {
+            builder.assert_eq(local.reduced_pubkey_x[i], is_reduced.clone() * pubkey_x[i]);
+            builder.assert_eq(local.reduced_pubkey_y[i], is_reduced.clone() * pubkey_y[i]);
+        }
+        let (x, y) = (local.reduced_pubkey_x, local.reduced_pubkey_y);
+
+        // The public key is on the curve if `(y^2 - x^2) - (d * x^2 * y^2 + 1)` is zero. The
+        // constants are only nonzero in the setup row.
+        let constant = |value: &BigUint| {
+            Polynomial::<AB::Expr>::from(BaseField::to_limbs_field::<AB::Expr, _>(value))
+                * AB::Expr::from(is_setup)
+        };
+        local.xx.eval(builder, &x, &x, FieldOperation::Mul, shard, channel, is_setup);
+        local.yy.eval(builder, &y, &y, FieldOperation::Mul, shard, channel, is_setup);
+        local.xxyy.eval(
+            builder,
+            &local.xx.result,
+            &local.yy.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_setup,
+        );
+        local.dxxyy.eval(
+            builder,
+            &local.xxyy.result,
+            &constant(&Ed25519Parameters::d_biguint()),
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_setup,
+        );
+        local.curve_lhs.eval(
+            builder,
+            &local.yy.result,
+            &local.xx.result,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_setup,
+        );
+        local.curve_rhs.eval(
+            builder,
+            &local.dxxyy.result,
+            &constant(&BigUint::one()),
+            FieldOperation::Add,
+            shard,
+            channel,
+            is_setup,
+        );
+        local.curve_diff.eval(
+            builder,
+            &local.curve_lhs.result,
+            &local.curve_rhs.result,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_setup,
+        );
+        local.curve_diff_range.eval(
+            builder,
+            &local.curve_diff.result,
+            &BaseField::to_limbs_field::<AB::Expr, _>(&modulus),
+            shard,
+            channel,
+            is_setup,
+        );
+        let diff_sum =
+            local.curve_diff.result.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            diff_sum,
+            local.curve_diff_is_zero,
+            is_setup.into(),
+        );
+        builder.when(is_setup).assert_eq(local.is_pubkey_valid, local.curve_diff_is_zero.result);
+
+        // An invalid public key is replaced with the neutral element `(0, 1)`, so that the
+        // additions only ever see points of the curve.
+        let is_not_valid = AB::Expr::one() - local.is_pubkey_valid;
+        for i in 0..BaseField::NB_LIMBS {
+            builder.assert_eq(local.valid_pubkey_x[i], local.is_pubkey_valid * x[i]);
+            builder.when(is_setup).assert_eq(
+                local.pubkey_y[i],
+                local.is_pubkey_valid * y[i] + is_not_valid.clone() * AB::F::from_bool(i == 0),
+            );
+        }
+
+        local.neg_pubkey.eval(
+            builder,
+            &BaseField::to_limbs_field::<AB::Expr, _>(&BigUint::zero()),
+            &local.valid_pubkey_x,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_setup,
+        );
+    }
+
+    /// Constrains the two additions of each row, and the result from one row to the next.
+    ///
+    /// The setup row adds `-A` to the base point, which is `B - A`, and the result starts at the
+    /// neutral element in the first step row. A step row doubles the result and adds `B`, `-A` or
+    /// `B - A` for the bits `(1, 0)`, `(0, 1)` and `(1, 1)`, or the neutral element for `(0, 0)`.
+    /// The other rows add `(0, 0)` as the second point, which keeps the zero rows consistent.
+    fn eval_additions<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Ed25519VerifyCols<AB::Var>,
+        next: &Ed25519VerifyCols<AB::Var>,
+    ) {
+        let generator = Ed25519::ec_generator();
+        let b_x = BaseField::to_limbs_field::<AB::Expr, _>(&generator.x);
+        let b_y = BaseField::to_limbs_field::<AB::Expr, _>(&generator.y);
+
+        builder.when(local.is_setup).assert_all_eq(local.result_x, b_x.clone());
+        builder.when(local.is_setup).assert_all_eq(local.result_y, b_y.clone());
+        let (double_x, double_y) = local.double.result();
+        builder.when(local.is_setup).assert_all_eq(local.sum_x, *double_x);
+        builder.when(local.is_setup).assert_all_eq(local.sum_y, *double_y);
+
+        // The result is the neutral element `(0, 1)` after the setup row, and the output of the
+        // second addition after a step row.
+        let mut setup_builder = builder.when_transition();
+        let mut setup_builder = setup_builder.when(local.is_setup);
+        for i in 0..BaseField::NB_LIMBS {
+            setup_builder.assert_zero(next.result_x[i]);
+            setup_builder.assert_eq(next.result_y[i], AB::F::from_bool(i == 0));
+        }
+        let (added_x, added_y) = local.add.result();
+        let mut step_builder = builder.when_transition();
+        let mut step_builder = step_builder.when(local.is_step);
+        step_builder.assert_all_eq(*added_x, next.result_x);
+        step_builder.assert_all_eq(*added_y, next.result_y);
+
+        let not_setup = local.is_real - local.is_setup;
+        let none = AB::Expr::one() - local.s_bit - local.k_bit + local.both_bits;
+        let only_s = local.s_bit - local.both_bits;
+        let only_k = local.k_bit - local.both_bits;
+        for i in 0..BaseField::NB_LIMBS {
+            builder.assert_eq(
+                local.double_q_x[i],
+                local.is_setup * local.neg_pubkey_x[i] + not_setup.clone() * local.result_x[i],
+            );
+            builder.assert_eq(
+                local.double_q_y[i],
+                local.is_setup * local.pubkey_y[i] + not_setup.clone() * local.result_y[i],
+            );
+            builder.assert_eq(
+                local.q_x[i],
+                local.is_step
+                    * (only_s.clone() * b_x[i].clone()
+                        + only_k.clone() * local.neg_pubkey_x[i]
+                        + local.both_bits * local.sum_x[i]),
+            );
+            builder.assert_eq(
+                local.q_y[i],
+                local.is_step
+                    * (only_s.clone() * b_y[i].clone()
+                        + only_k.clone() * local.pubkey_y[i]
+                        + local.both_bits * local.sum_y[i]
+                        + none.clone() * AB::F::from_bool(i == 0)),
+            );
+        }
+
+        local.double.eval::<AB, Ed25519Parameters>(
+            builder,
+            &local.result_x,
+            &local.result_y,
+            &local.double_q_x,
+            &local.double_q_y,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.add.eval::<AB, Ed25519Parameters>(
+            builder,
+            double_x,
+            double_y,
+            &local.q_x,
+            &local.q_y,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+    }
+
+    /// Constrains the final row: the result is compared with `R`, and the validity bit is written
+    /// after the input.
+    fn eval_final<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &Ed25519VerifyCols<AB::Var>) {
+        let (shard, channel, is_final) = (local.shard, local.channel, local.is_final);
+        let modulus = BaseField::to_limbs_field::<AB::Expr, _>(&BaseField::modulus());
+
+        // The operands of the differences are only nonzero in the final row.
+        let gated = |limbs: Limbs<AB::Var, U32>| {
+            Polynomial::<AB::Expr>::from(limbs) * AB::Expr::from(local.is_final)
+        };
+        let mut is_match = Vec::with_capacity(2);
+        for (diff, range, is_zero, lhs, rhs) in [
+            (&local.x_diff, &local.x_diff_range, local.x_diff_is_zero, local.result_x, local.r_x),
+            (&local.y_diff, &local.y_diff_range, local.y_diff_is_zero, local.result_y, local.r_y),
+        ] {
+            diff.eval(
+                builder,
+                &gated(lhs),
+                &gated(rhs),
+                FieldOperation::Sub,
+                shard,
+                channel,
+                is_final,
+            );
+            range.eval(builder, &diff.result, &modulus, shard, channel, is_final);
+            let diff_sum = diff.result.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
+            IsZeroOperation::<AB::F>::eval(builder, diff_sum, is_zero, is_final.into());
+            is_match.push(is_zero.result);
+        }
+
+        builder.eval_memory_access(
+            shard,
+            channel,
+            local.clk + AB::F::one(),
+            local.ptr + AB::F::from_canonical_usize(ED25519_VERIFY_INPUT_WORDS * 4),
+            &local.result_access,
+            is_final,
+        );
+        let value = local.result_access.value();
+        builder.when(is_final).assert_eq(local.is_match, is_match[0] * is_match[1]);
+        builder.when(is_final).assert_eq(value[0], local.is_pubkey_valid * local.is_match);
+        for i in 1..4 {
+            builder.when(is_final).assert_zero(value[i]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigUint, One};
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        edwards::ed25519::{Ed25519, Ed25519BaseField},
+        params::FieldParameters,
+        AffinePoint, EllipticCurve,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, store_words, words_at},
+    };
+
+    const PTR: u32 = 100;
+
+    /// The size in bytes of the input and the result of a syscall.
+    const SYSCALL_SIZE: u32 = 196;
+
+    /// A signature `(R, S)` with the challenge `k` for the public key `A`.
+    #[derive(Clone)]
+    struct Signature {
+        s: BigUint,
+        k: BigUint,
+        pubkey: AffinePoint<Ed25519>,
+        r: AffinePoint<Ed25519>,
+    }
+
+    /// Multiplies the base point by `scalar` with the double-and-add method.
+    fn mul_generator(scalar: &BigUint) -> AffinePoint<Ed25519> {
+        let generator = Ed25519::ec_generator();
+        let mut result = Ed25519::neutral();
+        for i in (0..scalar.bits()).rev() {
+            result = &result + &result;
+            if scalar.bit(i) {
+                result = &result + &generator;
+            }
+        }
+        result
+    }
+
+    /// Signs with the secret scalar `a`, the nonce `r` and the challenge `k`, so that
+    /// `S = r + k * a` modulo the order.
+    fn sign(a: u64, r: u64, k: &BigUint) -> Signature {
+        let order = Ed25519::prime_group_order();
+        let s = (BigUint::from(r) + k * BigUint::from(a)) % &order;
+        Signature {
+            s,
+            k: k.clone(),
+            pubkey: mul_generator(&BigUint::from(a)),
+            r: mul_generator(&BigUint::from(r)),
+        }
+    }
+
+    /// Builds a program that verifies each signature, the inputs being laid out one after the
+    /// other from [`PTR`]. Returns the program and the pointer of each input.
+    fn ed25519_verify_program(signatures: &[Signature]) -> (Program, Vec<u32>) {
+        let mut instructions = vec![];
+        let mut ptrs = vec![];
+        for (i, signature) in signatures.iter().enumerate() {
+            let ptr = PTR + i as u32 * SYSCALL_SIZE;
+            ptrs.push(ptr);
+            let values = [
+                &signature.s,
+                &signature.k,
+                &signature.pubkey.x,
+                &signature.pubkey.y,
+                &signature.r.x,
+                &signature.r.y,
+            ];
+            store_words(&mut instructions, words_at(ptr, 8, &values));
+        }
+        for &ptr in ptrs.iter() {
+            call_syscall(&mut instructions, SyscallCode::ED25519_VERIFY, ptr, 0);
+        }
+        (Program::new(instructions, 0, 0), ptrs)
+    }
+
+    /// The signatures of the tests, and whether they are valid.
+    fn signatures() -> Vec<(Signature, bool)> {
+        let modulus = Ed25519BaseField::modulus();
+        let order = Ed25519::prime_group_order();
+        let k = BigUint::parse_bytes(
+            b"0b688df40bcedbe641ddb16ff0a1842d9c67ea1c3bf63f3e0471baa664531d1a",
+            16,
+        )
+        .unwrap();
+        let valid = sign(0x1234_5678, 0x9abc_def0, &k);
+        let r_neg = AffinePoint::new(&modulus - &valid.r.x, valid.r.y.clone());
+
+        vec![
+            (valid.clone(), true),
+            // Adding the order to `S` does not change the point.
+            (Signature { s: &valid.s + &order, ..valid.clone() }, true),
+            // The coordinates of `R` are compared modulo the prime.
+            (
+                Signature {
+                    r: AffinePoint::new(valid.r.x.clone(), &valid.r.y + &modulus),
+                    ..valid.clone()
+                },
+                true,
+            ),
+            (Signature { s: &valid.s + 1u32, ..valid.clone() }, false),
+            (Signature { k: &valid.k + 1u32, ..valid.clone() }, false),
+            (Signature { r: r_neg, ..valid.clone() }, false),
+            // The public key is the neutral element, so the signature only depends on `S`.
+            (sign(0, 0x9abc_def0, &k), true),
+            // The public key is the base point, so `B - A` is the neutral element.
+            (sign(1, 0x9abc_def0, &BigUint::one()), true),
+            // Public keys that are not reduced or not on the curve are reported as invalid.
+            (
+                Signature {
+                    pubkey: AffinePoint::new(valid.pubkey.x.clone(), &valid.pubkey.y + &modulus),
+                    ..valid.clone()
+                },
+                false,
+            ),
+            (
+                Signature {
+                    pubkey: AffinePoint::new(
+                        (BigUint::one() << 256) - 1u32,
+                        valid.pubkey.y.clone(),
+                    ),
+                    ..valid.clone()
+                },
+                false,
+            ),
+            (
+                Signature {
+                    pubkey: AffinePoint::new(valid.pubkey.x.clone(), &valid.pubkey.y + 1u32),
+                    ..valid.clone()
+                },
+                false,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_ed25519_verify_execute() {
+        setup_logger();
+        let (signatures, expected): (Vec<_>, Vec<_>) = signatures().into_iter().unzip();
+        let (program, ptrs) = ed25519_verify_program(&signatures);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        for (ptr, is_valid) in ptrs.into_iter().zip(expected) {
+            assert_eq!(runtime.word(ptr + 192), is_valid as u32);
+        }
+    }
+
+    #[test]
+    fn test_ed25519_verify_prove() {
+        setup_logger();
+        let signatures =
+            signatures().into_iter().map(|(signature, _)| signature).collect::<Vec<_>>();
+        let signatures = [&signatures[2..6], &signatures[8..]].concat();
+        run_test::<CpuProver<_, _>>(ed25519_verify_program(&signatures).0).unwrap();
+    }
+}
@@ -20,28 +20,31 @@ use sp1_core_executor::{
 };
 use sp1_curves::{
     edwards::{ed25519::Ed25519BaseField, EdwardsParameters, NUM_LIMBS, WORDS_CURVE_POINT},
-    params::FieldParameters,
-    AffinePoint, EllipticCurve,
+    params::{FieldParameters, Limbs},
+    AffinePoint, CurveType, EllipticCurve,
 };
 use sp1_derive::AlignedBorrow;
-use sp1_stark::air::{BaseAirBuilder, MachineAir, SP1AirBuilder};
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, SP1AirBuilder};
+use typenum::U32;
 
 use crate::{
     memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
     operations::field::{
         field_den::FieldDenCols, field_inner_product::FieldInnerProductCols, field_op::FieldOpCols,
     },
-    utils::{limbs_from_prev_access, pad_rows},
+    utils::{limbs_from_prev_access, pad_rows_with_strategy},
 };
 
-pub const NUM_ED_ADD_COLS: usize = size_of::<EdAddAssignCols<u8>>();
+pub const fn num_ed_add_cols<P: FieldParameters>() -> usize {
+    size_of::<EdAddAssignCols<u8, P>>()
+}
 
 /// A set of columns to compute `EdAdd` where a, b are field elements.
 /// Right now the number of limbs is assumed to be a constant, although this could be macro-ed
 /// or made generic in the future.
 #[derive(Debug, Clone, AlignedBorrow)]
 #[repr(C)]
-pub struct EdAddAssignCols<T> {
+pub struct EdAddAssignCols<T, P: FieldParameters> {
     pub is_real: T,
     pub shard: T,
     pub channel: T,
@@ -51,45 +54,46 @@ pub struct EdAddAssignCols<T> {
     pub q_ptr: T,
     pub p_access: [MemoryWriteCols<T>; WORDS_CURVE_POINT],
     pub q_access: [MemoryReadCols<T>; WORDS_CURVE_POINT],
-    pub(crate) x3_numerator: FieldInnerProductCols<T, Ed25519BaseField>,
-    pub(crate) y3_numerator: FieldInnerProductCols<T, Ed25519BaseField>,
-    pub(crate) x1_mul_y1: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) x2_mul_y2: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) f: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) d_mul_f: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) x3_ins: FieldDenCols<T, Ed25519BaseField>,
-    pub(crate) y3_ins: FieldDenCols<T, Ed25519BaseField>,
+    pub(crate) add: EdAddCols<T, P>,
 }
 
-#[derive(Default)]
-pub struct EdAddAssignChip<E> {
-    _marker: PhantomData<E>,
+/// The columns to add two points of a twisted Edwards curve with the complete addition law,
+/// `x3 = (x1 * y2 + x2 * y1) / (1 + d * f)` and `y3 = (y1 * y2 + x1 * x2) / (1 - d * f)` where
+/// `f = x1 * x2 * y1 * y2`. The operations are over the base field `P` of the curve.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub(crate) struct EdAddCols<T, P: FieldParameters = Ed25519BaseField> {
+    x3_numerator: FieldInnerProductCols<T, P>,
+    y3_numerator: FieldInnerProductCols<T, P>,
+    x1_mul_y1: FieldOpCols<T, P>,
+    x2_mul_y2: FieldOpCols<T, P>,
+    f: FieldOpCols<T, P>,
+    d_mul_f: FieldOpCols<T, P>,
+    x3_ins: FieldDenCols<T, P>,
+    y3_ins: FieldDenCols<T, P>,
 }
 
-impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
-    pub const fn new() -> Self {
-        Self { _marker: PhantomData }
-    }
-
+impl<F: PrimeField32, P: FieldParameters> EdAddCols<F, P> {
+    /// Populates the addition of `q` to `p`, and returns the sum.
     #[allow(clippy::too_many_arguments)]
-    fn populate_field_ops<F: PrimeField32>(
+    pub(crate) fn populate<E: EdwardsParameters>(
+        &mut self,
         record: &mut impl ByteRecord,
         shard: u32,
         channel: u8,
-        cols: &mut EdAddAssignCols<F>,
-        p_x: BigUint,
-        p_y: BigUint,
-        q_x: BigUint,
-        q_y: BigUint,
-    ) {
-        let x3_numerator = cols.x3_numerator.populate(
+        p_x: &BigUint,
+        p_y: &BigUint,
+        q_x: &BigUint,
+        q_y: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let x3_numerator = self.x3_numerator.populate(
             record,
             shard,
             channel,
             &[p_x.clone(), q_x.clone()],
             &[q_y.clone(), p_y.clone()],
         );
-        let y3_numerator = cols.y3_numerator.populate(
+        let y3_numerator = self.y3_numerator.populate(
             record,
             shard,
             channel,
@@ -97,17 +101,109 @@ impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
             &[q_y.clone(), q_x.clone()],
         );
         let x1_mul_y1 =
-            cols.x1_mul_y1.populate(record, shard, channel, &p_x, &p_y, FieldOperation::Mul);
+            self.x1_mul_y1.populate(record, shard, channel, p_x, p_y, FieldOperation::Mul);
         let x2_mul_y2 =
-            cols.x2_mul_y2.populate(record, shard, channel, &q_x, &q_y, FieldOperation::Mul);
+            self.x2_mul_y2.populate(record, shard, channel, q_x, q_y, FieldOperation::Mul);
         let f =
-            cols.f.populate(record, shard, channel, &x1_mul_y1, &x2_mul_y2, FieldOperation::Mul);
+            self.f.populate(record, shard, channel, &x1_mul_y1, &x2_mul_y2, FieldOperation::Mul);
 
         let d = E::d_biguint();
-        let d_mul_f = cols.d_mul_f.populate(record, shard, channel, &f, &d, FieldOperation::Mul);
+        let d_mul_f = self.d_mul_f.populate(record, shard, channel, &f, &d, FieldOperation::Mul);
 
-        cols.x3_ins.populate(record, shard, channel, &x3_numerator, &d_mul_f, true);
-        cols.y3_ins.populate(record, shard, channel, &y3_numerator, &d_mul_f, false);
+        let x3 = self.x3_ins.populate(record, shard, channel, &x3_numerator, &d_mul_f, true);
+        let y3 = self.y3_ins.populate(record, shard, channel, &y3_numerator, &d_mul_f, false);
+        (x3, y3)
+    }
+}
+
+impl<V: Copy, P: FieldParameters<Limbs = U32>> EdAddCols<V, P> {
+    /// The coordinates of the sum.
+    pub(crate) fn result(&self) -> (&Limbs<V, U32>, &Limbs<V, U32>) {
+        (&self.x3_ins.result, &self.y3_ins.result)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn eval<AB: SP1AirBuilder<Var = V>, E: EdwardsParameters>(
+        &self,
+        builder: &mut AB,
+        x1: &Limbs<V, U32>,
+        y1: &Limbs<V, U32>,
+        x2: &Limbs<V, U32>,
+        y2: &Limbs<V, U32>,
+        shard: V,
+        channel: V,
+        is_real: V,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        // x3_numerator = x1 * y2 + x2 * y1.
+        self.x3_numerator.eval(builder, &[*x1, *x2], &[*y2, *y1], shard, channel, is_real);
+
+        // y3_numerator = y1 * y2 + x1 * x2.
+        self.y3_numerator.eval(builder, &[*y1, *x1], &[*y2, *x2], shard, channel, is_real);
+
+        // f = x1 * x2 * y1 * y2.
+        self.x1_mul_y1.eval(builder, x1, y1, FieldOperation::Mul, shard, channel, is_real);
+        self.x2_mul_y2.eval(builder, x2, y2, FieldOperation::Mul, shard, channel, is_real);
+
+        let x1_mul_y1 = self.x1_mul_y1.result;
+        let x2_mul_y2 = self.x2_mul_y2.result;
+        self.f.eval(builder, &x1_mul_y1, &x2_mul_y2, FieldOperation::Mul, shard, channel, is_real);
+
+        // d * f.
+        let f = self.f.result;
+        let d_biguint = E::d_biguint();
+        let d_const = P::to_limbs_field::<AB::Expr, _>(&d_biguint);
+        self.d_mul_f.eval(builder, &f, &d_const, FieldOperation::Mul, shard, channel, is_real);
+
+        let d_mul_f = self.d_mul_f.result;
+
+        // x3 = x3_numerator / (1 + d * f).
+        self.x3_ins.eval(
+            builder,
+            &self.x3_numerator.result,
+            &d_mul_f,
+            true,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // y3 = y3_numerator / (1 - d * f).
+        self.y3_ins.eval(
+            builder,
+            &self.y3_numerator.result,
+            &d_mul_f,
+            false,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
+
+#[derive(Default)]
+pub struct EdAddAssignChip<E> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn populate_field_ops<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        cols: &mut EdAddAssignCols<F, E::BaseField>,
+        p_x: BigUint,
+        p_y: BigUint,
+        q_x: BigUint,
+        q_y: BigUint,
+    ) {
+        cols.add.populate::<E>(record, shard, channel, &p_x, &p_y, &q_x, &q_y);
     }
 }
 
@@ -117,7 +213,11 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
     type Program = Program;
 
     fn name(&self) -> String {
-        "EdAddAssign".to_string()
+        match E::CURVE_TYPE {
+            CurveType::Ed25519 => "EdAddAssign".to_string(),
+            CurveType::BabyJubJub => "BabyJubJubAddAssign".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
     }
 
     fn generate_trace(
@@ -125,21 +225,21 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
         input: &ExecutionRecord,
         _: &mut ExecutionRecord,
     ) -> RowMajorMatrix<F> {
-        let mut rows = input
-            .ed_add_events
+        let num_cols = num_ed_add_cols::<E::BaseField>();
+        let mut rows = Self::events(input)
             .par_iter()
             .map(|event| {
-                let mut row = [F::zero(); NUM_ED_ADD_COLS];
-                let cols: &mut EdAddAssignCols<F> = row.as_mut_slice().borrow_mut();
+                let mut row = vec![F::zero(); num_cols];
+                let cols: &mut EdAddAssignCols<F, E::BaseField> = row.as_mut_slice().borrow_mut();
                 let mut blu = Vec::new();
                 self.event_to_row(event, cols, &mut blu);
                 row
             })
             .collect::<Vec<_>>();
 
-        pad_rows(&mut rows, || {
-            let mut row = [F::zero(); NUM_ED_ADD_COLS];
-            let cols: &mut EdAddAssignCols<F> = row.as_mut_slice().borrow_mut();
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = vec![F::zero(); num_cols];
+            let cols: &mut EdAddAssignCols<F, E::BaseField> = row.as_mut_slice().borrow_mut();
             let zero = BigUint::zero();
             Self::populate_field_ops(
                 &mut vec![],
@@ -156,12 +256,12 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
 
         // Convert the trace to a row major matrix.
         let mut trace =
-            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_ED_ADD_COLS);
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), num_cols);
 
         // Write the nonces to the trace.
         for i in 0..trace.height() {
-            let cols: &mut EdAddAssignCols<F> =
-                trace.values[i * NUM_ED_ADD_COLS..(i + 1) * NUM_ED_ADD_COLS].borrow_mut();
+            let cols: &mut EdAddAssignCols<F, E::BaseField> =
+                trace.values[i * num_cols..(i + 1) * num_cols].borrow_mut();
             cols.nonce = F::from_canonical_usize(i);
         }
 
@@ -169,16 +269,17 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
     }
 
     fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
-        let chunk_size = std::cmp::max(input.ed_add_events.len() / num_cpus::get(), 1);
+        let events = Self::events(input);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
 
-        let blu_batches = input
-            .ed_add_events
+        let blu_batches = events
             .par_chunks(chunk_size)
             .map(|events| {
                 let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
                 events.iter().for_each(|event| {
-                    let mut row = [F::zero(); NUM_ED_ADD_COLS];
-                    let cols: &mut EdAddAssignCols<F> = row.as_mut_slice().borrow_mut();
+                    let mut row = vec![F::zero(); num_ed_add_cols::<E::BaseField>()];
+                    let cols: &mut EdAddAssignCols<F, E::BaseField> =
+                        row.as_mut_slice().borrow_mut();
                     self.event_to_row(event, cols, &mut blu);
                 });
                 blu
@@ -189,16 +290,29 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
     }
 
     fn included(&self, shard: &Self::Record) -> bool {
-        !shard.ed_add_events.is_empty()
+        !Self::events(shard).is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
     }
 }
 
 impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
+    /// The events of the curve.
+    fn events(record: &ExecutionRecord) -> &Vec<EllipticCurveAddEvent> {
+        match E::CURVE_TYPE {
+            CurveType::Ed25519 => &record.ed_add_events,
+            CurveType::BabyJubJub => &record.babyjubjub_add_events,
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
     /// Create a row from an event.
     fn event_to_row<F: PrimeField32>(
         &self,
         event: &EllipticCurveAddEvent,
-        cols: &mut EdAddAssignCols<F>,
+        cols: &mut EdAddAssignCols<F, E::BaseField>,
         blu: &mut impl ByteRecord,
     ) {
         // Decode affine points.
@@ -231,20 +345,21 @@ impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
 
 impl<F, E: EllipticCurve + EdwardsParameters> BaseAir<F> for EdAddAssignChip<E> {
     fn width(&self) -> usize {
-        NUM_ED_ADD_COLS
+        num_ed_add_cols::<E::BaseField>()
     }
 }
 
 impl<AB, E: EllipticCurve + EdwardsParameters> Air<AB> for EdAddAssignChip<E>
 where
     AB: SP1AirBuilder,
+    E::BaseField: FieldParameters<Limbs = U32>,
 {
     fn eval(&self, builder: &mut AB) {
         let main = builder.main();
         let local = main.row_slice(0);
-        let local: &EdAddAssignCols<AB::Var> = (*local).borrow();
+        let local: &EdAddAssignCols<AB::Var, E::BaseField> = (*local).borrow();
         let next = main.row_slice(1);
-        let next: &EdAddAssignCols<AB::Var> = (*next).borrow();
+        let next: &EdAddAssignCols<AB::Var, E::BaseField> = (*next).borrow();
 
         // Constrain the incrementing nonce.
         builder.when_first_row().assert_zero(local.nonce);
@@ -255,91 +370,12 @@ where
         let y1 = limbs_from_prev_access(&local.p_access[8..16]);
         let y2 = limbs_from_prev_access(&local.q_access[8..16]);
 
-        // x3_numerator = x1 * y2 + x2 * y1.
-        local.x3_numerator.eval(
-            builder,
-            &[x1, x2],
-            &[y2, y1],
-            local.shard,
-            local.channel,
-            local.is_real,
-        );
-
-        // y3_numerator = y1 * y2 + x1 * x2.
-        local.y3_numerator.eval(
-            builder,
-            &[y1, x1],
-            &[y2, x2],
-            local.shard,
-            local.channel,
-            local.is_real,
-        );
-
-        // f = x1 * x2 * y1 * y2.
-        local.x1_mul_y1.eval(
+        local.add.eval::<AB, E>(
             builder,
             &x1,
             &y1,
-            FieldOperation::Mul,
-            local.shard,
-            local.channel,
-            local.is_real,
-        );
-        local.x2_mul_y2.eval(
-            builder,
             &x2,
             &y2,
-            FieldOperation::Mul,
-            local.shard,
-            local.channel,
-            local.is_real,
-        );
-
-        let x1_mul_y1 = local.x1_mul_y1.result;
-        let x2_mul_y2 = local.x2_mul_y2.result;
-        local.f.eval(
-            builder,
-            &x1_mul_y1,
-            &x2_mul_y2,
-            FieldOperation::Mul,
-            local.shard,
-            local.channel,
-            local.is_real,
-        );
-
-        // d * f.
-        let f = local.f.result;
-        let d_biguint = E::d_biguint();
-        let d_const = E::BaseField::to_limbs_field::<AB::Expr, _>(&d_biguint);
-        local.d_mul_f.eval(
-            builder,
-            &f,
-            &d_const,
-            FieldOperation::Mul,
-            local.shard,
-            local.channel,
-            local.is_real,
-        );
-
-        let d_mul_f = local.d_mul_f.result;
-
-        // x3 = x3_numerator / (1 + d * f).
-        local.x3_ins.eval(
-            builder,
-            &local.x3_numerator.result,
-            &d_mul_f,
-            true,
-            local.shard,
-            local.channel,
-            local.is_real,
-        );
-
-        // y3 = y3_numerator / (1 - d * f).
-        local.y3_ins.eval(
-            builder,
-            &local.y3_numerator.result,
-            &d_mul_f,
-            false,
             local.shard,
             local.channel,
             local.is_real,
@@ -348,12 +384,11 @@ where
         // Constraint self.p_access.value = [self.x3_ins.result, self.y3_ins.result]
         // This is to ensure that p_access is updated with the new value.
         let p_access_vec = value_as_limbs(&local.p_access);
+        let (x3, y3) = local.add.result();
+        builder.when(local.is_real).assert_all_eq(*x3, p_access_vec[0..NUM_LIMBS].to_vec());
         builder
             .when(local.is_real)
-            .assert_all_eq(local.x3_ins.result, p_access_vec[0..NUM_LIMBS].to_vec());
-        builder
-            .when(local.is_real)
-            .assert_all_eq(local.y3_ins.result, p_access_vec[NUM_LIMBS..NUM_LIMBS * 2].to_vec());
+            .assert_all_eq(*y3, p_access_vec[NUM_LIMBS..NUM_LIMBS * 2].to_vec());
 
         builder.eval_memory_access_slice(
             local.shard,
@@ -373,29 +408,129 @@ where
             local.is_real,
         );
 
+        let syscall_code = match E::CURVE_TYPE {
+            CurveType::Ed25519 => SyscallCode::ED_ADD,
+            CurveType::BabyJubJub => SyscallCode::BABYJUBJUB_ADD,
+            _ => panic!("Unsupported curve"),
+        };
         builder.receive_syscall(
             local.shard,
             local.channel,
             local.clk,
             local.nonce,
-            AB::F::from_canonical_u32(SyscallCode::ED_ADD.syscall_id()),
+            AB::F::from_canonical_u32(syscall_code.syscall_id()),
             local.p_ptr,
             local.q_ptr,
             local.is_real,
         );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use sp1_core_executor::Program;
-    use sp1_stark::CpuProver;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        edwards::{babyjubjub::BabyJubJub, ed25519::Ed25519, EdwardsParameters, WORDS_CURVE_POINT},
+        AffinePoint, EllipticCurve,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
 
     use crate::{
         utils,
-        utils::tests::{ED25519_ELF, ED_ADD_ELF},
+        utils::tests::{slice_at, syscall_program, ED25519_ELF, ED_ADD_ELF},
     };
 
+    const P_PTR: u32 = 100;
+    const Q_PTR: u32 = 300;
+
+    /// Builds a program that stores p at `P_PTR` and q at `Q_PTR`, and adds q to p with the given
+    /// syscall.
+    fn ed_add_program<E: EllipticCurve>(
+        syscall: SyscallCode,
+        p: &AffinePoint<E>,
+        q: &AffinePoint<E>,
+    ) -> Program {
+        let words = [slice_at(P_PTR, &p.to_words_le()), slice_at(Q_PTR, &q.to_words_le())].concat();
+        syscall_program(syscall, words, P_PTR, Q_PTR)
+    }
+
+    /// Pairs of points whose sum involves the neutral element `(0, 1)`.
+    fn neutral_cases() -> Vec<(AffinePoint<Ed25519>, AffinePoint<Ed25519>)> {
+        let (x, y) = Ed25519::generator();
+        let generator = AffinePoint::<Ed25519>::new(x, y);
+        let neutral = Ed25519::neutral();
+        vec![
+            (generator.clone(), neutral.clone()),
+            (neutral.clone(), generator.clone()),
+            (neutral.clone(), neutral.clone()),
+            (generator.clone(), -generator),
+        ]
+    }
+
+    #[test]
+    fn test_ed_add_neutral_execute() {
+        utils::setup_logger();
+        for (p, q) in neutral_cases() {
+            let mut runtime =
+                Executor::new(ed_add_program(SyscallCode::ED_ADD, &p, &q), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let words = (0..WORDS_CURVE_POINT as u32)
+                .map(|i| runtime.word(P_PTR + i * 4))
+                .collect::<Vec<_>>();
+            assert_eq!(AffinePoint::<Ed25519>::from_words_le(&words), &p + &q);
+        }
+    }
+
+    #[test]
+    fn test_ed_add_neutral_prove() {
+        utils::setup_logger();
+        for (p, q) in neutral_cases() {
+            utils::run_test::<CpuProver<_, _>>(ed_add_program(SyscallCode::ED_ADD, &p, &q))
+                .unwrap();
+        }
+    }
+
+    /// Pairs of points of BabyJubJub, including the neutral element and a point and its negation.
+    fn babyjubjub_cases() -> Vec<(AffinePoint<BabyJubJub>, AffinePoint<BabyJubJub>)> {
+        let generator = BabyJubJub::ec_generator();
+        let double = &generator + &generator;
+        let neutral = BabyJubJub::neutral();
+        vec![
+            (generator.clone(), double.clone()),
+            (double.clone(), double.clone()),
+            (generator.clone(), neutral.clone()),
+            (double.clone(), -double),
+        ]
+    }
+
+    #[test]
+    fn test_babyjubjub_add_execute() {
+        utils::setup_logger();
+        for (p, q) in babyjubjub_cases() {
+            let mut runtime = Executor::new(
+                ed_add_program(SyscallCode::BABYJUBJUB_ADD, &p, &q),
+                SP1CoreOpts::default(),
+            );
+            runtime.run().unwrap();
+            let words = (0..WORDS_CURVE_POINT as u32)
+                .map(|i| runtime.word(P_PTR + i * 4))
+                .collect::<Vec<_>>();
+            assert_eq!(AffinePoint::<BabyJubJub>::from_words_le(&words), &p + &q);
+        }
+    }
+
+    #[test]
+    fn test_babyjubjub_add_prove() {
+        utils::setup_logger();
+        for (p, q) in babyjubjub_cases() {
+            let program = ed_add_program(SyscallCode::BABYJUBJUB_ADD, &p, &q);
+            utils::run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
     #[test]
     fn test_ed_add_simple() {
         utils::setup_logger();
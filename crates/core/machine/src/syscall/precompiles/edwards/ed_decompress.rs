@@ -13,23 +13,27 @@ use p3_matrix::{dense::RowMajorMatrix, Matrix};
 use sp1_core_executor::{
     events::{ByteLookupEvent, ByteRecord, EdDecompressEvent, FieldOperation},
     syscalls::SyscallCode,
-    ExecutionRecord, Program,
+    ByteOpcode, ExecutionRecord, Program,
 };
 use sp1_curves::{
     edwards::{
-        ed25519::{ed25519_sqrt, Ed25519BaseField},
+        ed25519::{ed25519_sqrt, ed25519_try_sqrt, Ed25519BaseField},
         EdwardsParameters, WordsFieldElement,
     },
     params::{limbs_from_vec, FieldParameters, Limbs},
+    COMPRESSED_POINT_BYTES, NUM_BYTES_FIELD_ELEMENT,
 };
 use sp1_derive::AlignedBorrow;
-use sp1_stark::air::{BaseAirBuilder, MachineAir, SP1AirBuilder};
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder};
 use typenum::U32;
 
 use crate::{
     memory::{MemoryReadCols, MemoryWriteCols},
-    operations::field::{field_op::FieldOpCols, field_sqrt::FieldSqrtCols, range::FieldLtCols},
-    utils::{limbs_from_access, limbs_from_prev_access, pad_rows},
+    operations::{
+        field::{field_op::FieldOpCols, field_sqrt::FieldSqrtCols, range::FieldLtCols},
+        IsZeroOperation,
+    },
+    utils::{limbs_from_access, limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows},
 };
 
 pub const NUM_ED_DECOMPRESS_COLS: usize = size_of::<EdDecompressCols<u8>>();
@@ -39,6 +43,10 @@ pub const NUM_ED_DECOMPRESS_COLS: usize = size_of::<EdDecompressCols<u8>>();
 /// compressed Y (without sign bit).
 ///
 /// After `EdDecompress`, the first 32 bytes of the slice are overwritten with the decompressed X.
+/// If the encoding is not a valid point in the sense of the strict decoding in RFC 8032, every bit
+/// of X is set instead. That is the case if Y is not reduced or has its top bit set, if there is no
+/// X for Y, or if X is zero and the sign bit is set. As the modulus is 5 mod 8, 2 is not a square,
+/// so `u / v` is not a square exactly when `2 * u / v` is a nonzero square.
 #[derive(Debug, Clone, AlignedBorrow)]
 #[repr(C)]
 pub struct EdDecompressCols<T> {
@@ -49,51 +57,100 @@ pub struct EdDecompressCols<T> {
     pub nonce: T,
     pub ptr: T,
     pub sign: T,
+    pub y_top_bit: T,
+    pub y_is_reduced: T,
+    pub y_is_unreduced: T,
+    pub is_square: T,
+    pub is_canonical: T,
+    pub is_decodable: T,
+    pub is_valid: T,
+    pub is_negated: T,
     pub x_access: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
     pub y_access: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
     pub(crate) y_range: FieldLtCols<T, Ed25519BaseField>,
+    pub(crate) y_unreduced_range: FieldLtCols<T, Ed25519BaseField>,
     pub(crate) yy: FieldOpCols<T, Ed25519BaseField>,
     pub(crate) u: FieldOpCols<T, Ed25519BaseField>,
     pub(crate) dyy: FieldOpCols<T, Ed25519BaseField>,
     pub(crate) v: FieldOpCols<T, Ed25519BaseField>,
     pub(crate) u_div_v: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) sqrt_input: FieldOpCols<T, Ed25519BaseField>,
     pub(crate) x: FieldSqrtCols<T, Ed25519BaseField>,
+    pub(crate) x_is_zero: IsZeroOperation<T>,
     pub(crate) neg_x: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) neg_x_range: FieldLtCols<T, Ed25519BaseField>,
 }
 
 impl<F: PrimeField32> EdDecompressCols<F> {
     pub fn populate<P: FieldParameters, E: EdwardsParameters>(
         &mut self,
         event: EdDecompressEvent,
-        record: &mut ExecutionRecord,
+        new_byte_lookup_events: &mut Vec<ByteLookupEvent>,
     ) {
-        let mut new_byte_lookup_events = Vec::new();
         self.is_real = F::from_bool(true);
         self.shard = F::from_canonical_u32(event.shard);
         self.channel = F::from_canonical_u8(event.channel);
         self.clk = F::from_canonical_u32(event.clk);
         self.ptr = F::from_canonical_u32(event.ptr);
-        self.nonce = F::from_canonical_u32(
-            record.nonce_lookup.get(&event.lookup_id).copied().unwrap_or_default(),
-        );
         self.sign = F::from_bool(event.sign);
         for i in 0..8 {
             self.x_access[i].populate(
                 event.channel,
                 event.x_memory_records[i],
-                &mut new_byte_lookup_events,
+                new_byte_lookup_events,
             );
             self.y_access[i].populate(
                 event.channel,
                 event.y_memory_records[i],
-                &mut new_byte_lookup_events,
+                new_byte_lookup_events,
             );
         }
 
-        let y = &BigUint::from_bytes_le(&event.y_bytes);
-        self.populate_field_ops::<E>(&mut new_byte_lookup_events, event.shard, event.channel, y);
+        // Split off the top bit of Y, and compare the rest with the modulus.
+        let mut y_bytes = event.y_bytes;
+        let y_top_bit = y_bytes[COMPRESSED_POINT_BYTES - 1] >> 7;
+        y_bytes[COMPRESSED_POINT_BYTES - 1] &= 0b0111_1111;
+        self.y_top_bit = F::from_canonical_u8(y_top_bit);
+        new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent {
+            shard: event.shard,
+            channel: event.channel,
+            opcode: ByteOpcode::LTU,
+            a1: 1,
+            a2: 0,
+            b: y_bytes[COMPRESSED_POINT_BYTES - 1],
+            c: 128,
+        });
+        let y = &BigUint::from_bytes_le(&y_bytes);
+        let modulus = Ed25519BaseField::modulus();
+        let y_is_reduced = y < &modulus;
+        if y_is_reduced {
+            self.y_range.populate(new_byte_lookup_events, event.shard, event.channel, y, &modulus);
+        } else {
+            self.y_unreduced_range.populate(
+                new_byte_lookup_events,
+                event.shard,
+                event.channel,
+                &(&modulus - BigUint::one()),
+                y,
+            );
+        }
+        self.y_is_reduced = F::from_bool(y_is_reduced);
+        self.y_is_unreduced = F::from_bool(!y_is_reduced);
+
+        self.populate_field_ops::<E>(
+            new_byte_lookup_events,
+            event.shard,
+            event.channel,
+            y,
+            event.sign,
+        );
 
-        record.add_byte_lookup_events(new_byte_lookup_events);
+        let is_canonical = y_is_reduced && y_top_bit == 0;
+        let is_valid = is_canonical && self.is_decodable == F::one();
+        self.is_canonical = F::from_bool(is_canonical);
+        self.is_valid = F::from_bool(is_valid);
+        self.is_negated = F::from_bool(is_valid && event.sign);
+        debug_assert_eq!(is_valid, event.decompressed_x_bytes != [0xff; NUM_BYTES_FIELD_ELEMENT]);
     }
 
     fn populate_field_ops<E: EdwardsParameters>(
@@ -102,9 +159,9 @@ impl<F: PrimeField32> EdDecompressCols<F> {
         shard: u32,
         channel: u8,
         y: &BigUint,
+        sign: bool,
     ) {
         let one = BigUint::one();
-        self.y_range.populate(blu_events, shard, channel, y, &Ed25519BaseField::modulus());
         let yy = self.yy.populate(blu_events, shard, channel, y, y, FieldOperation::Mul);
         let u = self.u.populate(blu_events, shard, channel, &yy, &one, FieldOperation::Sub);
         let dyy = self.dyy.populate(
@@ -118,8 +175,33 @@ impl<F: PrimeField32> EdDecompressCols<F> {
         let v = self.v.populate(blu_events, shard, channel, &one, &dyy, FieldOperation::Add);
         let u_div_v =
             self.u_div_v.populate(blu_events, shard, channel, &u, &v, FieldOperation::Div);
-        let x = self.x.populate(blu_events, shard, channel, &u_div_v, ed25519_sqrt);
-        self.neg_x.populate(blu_events, shard, channel, &BigUint::zero(), &x, FieldOperation::Sub);
+
+        // Take the square root of `u / v` if it is a square, and of `2 * u / v` otherwise.
+        let is_square = ed25519_try_sqrt(&u_div_v).is_some();
+        self.is_square = F::from_bool(is_square);
+        let multiplier = if is_square { one } else { BigUint::from(2u32) };
+        let sqrt_input = self.sqrt_input.populate(
+            blu_events,
+            shard,
+            channel,
+            &u_div_v,
+            &multiplier,
+            FieldOperation::Mul,
+        );
+        let x = self.x.populate(blu_events, shard, channel, &sqrt_input, ed25519_sqrt);
+        let x_limb_sum: u32 = Ed25519BaseField::to_limbs(&x).iter().map(|&limb| limb as u32).sum();
+        let x_is_zero = self.x_is_zero.populate(x_limb_sum) == 1;
+        self.is_decodable = F::from_bool(is_square && !(sign && x_is_zero));
+
+        let neg_x = self.neg_x.populate(
+            blu_events,
+            shard,
+            channel,
+            &BigUint::zero(),
+            &x,
+            FieldOperation::Sub,
+        );
+        self.neg_x_range.populate(blu_events, shard, channel, &neg_x, &Ed25519BaseField::modulus());
     }
 }
 
@@ -131,17 +213,57 @@ impl<V: Copy> EdDecompressCols<V> {
         V: Into<AB::Expr>,
     {
         builder.assert_bool(self.sign);
+        builder.assert_bool(self.y_top_bit);
+        builder.assert_bool(self.y_is_reduced);
+        builder.assert_bool(self.y_is_unreduced);
+        builder.assert_bool(self.is_square);
+        builder.assert_bool(self.is_real);
+        let is_real: AB::Expr = self.is_real.into();
+        let sign: AB::Expr = self.sign.into();
+        let y_top_bit: AB::Expr = self.y_top_bit.into();
+        let y_is_reduced: AB::Expr = self.y_is_reduced.into();
+        let is_square: AB::Expr = self.is_square.into();
+        let is_valid: AB::Expr = self.is_valid.into();
+        let is_negated: AB::Expr = self.is_negated.into();
+
+        // Y without its top bit, whose top byte must then be less than 128.
+        let y_access: Limbs<V, U32> = limbs_from_prev_access(&self.y_access);
+        let mut y_coeffs = y_access.0.iter().map(|&limb| limb.into()).collect::<Vec<AB::Expr>>();
+        y_coeffs[COMPRESSED_POINT_BYTES - 1] = y_coeffs[COMPRESSED_POINT_BYTES - 1].clone()
+            - y_top_bit.clone() * AB::F::from_canonical_u32(128);
+        builder.send_byte(
+            ByteOpcode::LTU.as_field::<AB::F>(),
+            AB::F::one(),
+            y_coeffs[COMPRESSED_POINT_BYTES - 1].clone(),
+            AB::F::from_canonical_u32(128),
+            self.shard,
+            self.channel,
+            self.is_real,
+        );
+        let y = Polynomial::from_coefficients(&y_coeffs);
 
-        let y: Limbs<V, U32> = limbs_from_prev_access(&self.y_access);
-        let max_num_limbs = P::to_limbs_field_vec(&Ed25519BaseField::modulus());
+        // Y is compared with the modulus in every real row, one way or the other.
+        builder.assert_eq(y_is_reduced.clone() + self.y_is_unreduced.into(), is_real.clone());
+        let modulus = Ed25519BaseField::modulus();
+        let modulus_limbs = P::to_limbs_field_vec(&modulus);
         self.y_range.eval(
             builder,
             &y,
-            &limbs_from_vec::<AB::Expr, P::Limbs, AB::F>(max_num_limbs),
+            &limbs_from_vec::<AB::Expr, P::Limbs, AB::F>(modulus_limbs.clone()),
             self.shard,
             self.channel,
-            self.is_real,
+            self.y_is_reduced,
+        );
+        let max_limbs = P::to_limbs_field_vec(&(&modulus - BigUint::one()));
+        self.y_unreduced_range.eval(
+            builder,
+            &limbs_from_vec::<AB::Expr, P::Limbs, AB::F>(max_limbs),
+            &y,
+            self.shard,
+            self.channel,
+            self.y_is_unreduced,
         );
+
         self.yy.eval(builder, &y, &y, FieldOperation::Mul, self.shard, self.channel, self.is_real);
         self.u.eval(
             builder,
@@ -181,14 +303,39 @@ impl<V: Copy> EdDecompressCols<V> {
             self.channel,
             self.is_real,
         );
-        self.x.eval(
+
+        // The square root is of `u / v` if it is a square, and of `2 * u / v` otherwise. In the
+        // latter case, the root must be nonzero, so that `u / v` is not zero either.
+        self.sqrt_input.eval(
             builder,
             &self.u_div_v.result,
+            &[AB::Expr::two() - is_square.clone()].iter(),
+            FieldOperation::Mul,
+            self.shard,
+            self.channel,
+            self.is_real,
+        );
+        self.x.eval(
+            builder,
+            &self.sqrt_input.result,
             AB::F::zero(),
             self.shard,
             self.channel,
             self.is_real,
         );
+        let x_limb_sum = self
+            .x
+            .multiplication
+            .result
+            .0
+            .iter()
+            .fold(AB::Expr::zero(), |acc, &limb| acc + limb.into());
+        IsZeroOperation::<AB::F>::eval(builder, x_limb_sum, self.x_is_zero, is_real.clone());
+        builder
+            .when(is_real.clone())
+            .when_not(is_square.clone())
+            .assert_zero(self.x_is_zero.result);
+
         self.neg_x.eval(
             builder,
             &[AB::Expr::zero()].iter(),
@@ -198,6 +345,25 @@ impl<V: Copy> EdDecompressCols<V> {
             self.channel,
             self.is_real,
         );
+        self.neg_x_range.eval(
+            builder,
+            &self.neg_x.result,
+            &limbs_from_vec::<AB::Expr, P::Limbs, AB::F>(modulus_limbs),
+            self.shard,
+            self.channel,
+            self.is_real,
+        );
+
+        // The encoding is valid if Y is canonical, and if there is an X with the sign bit.
+        let x_is_zero: AB::Expr = self.x_is_zero.result.into();
+        builder.assert_eq(self.is_canonical, y_is_reduced * (AB::Expr::one() - y_top_bit));
+        builder
+            .assert_eq(self.is_decodable, is_square * (AB::Expr::one() - sign.clone() * x_is_zero));
+        builder.assert_eq(
+            is_valid.clone(),
+            Into::<AB::Expr>::into(self.is_canonical) * self.is_decodable.into(),
+        );
+        builder.assert_eq(is_negated.clone(), is_valid.clone() * sign);
 
         builder.eval_memory_access_slice(
             self.shard,
@@ -216,13 +382,17 @@ impl<V: Copy> EdDecompressCols<V> {
             self.is_real,
         );
 
-        // Constrain that the correct result is written into x.
+        // Constrain that the correct result is written into x, which has every bit set if the
+        // encoding is not valid.
         let x_limbs: Limbs<V, U32> = limbs_from_access(&self.x_access);
-        builder.when(self.is_real).when(self.sign).assert_all_eq(self.neg_x.result, x_limbs);
+        builder.when(is_negated.clone()).assert_all_eq(self.neg_x.result, x_limbs);
         builder
-            .when(self.is_real)
-            .when_not(self.sign)
+            .when(is_valid.clone() - is_negated)
             .assert_all_eq(self.x.multiplication.result, x_limbs);
+        builder.when(is_real - is_valid).assert_all_eq(
+            x_limbs,
+            (0..NUM_BYTES_FIELD_ELEMENT).map(|_| AB::Expr::from_canonical_u8(u8::MAX)),
+        );
 
         builder.receive_syscall(
             self.shard,
@@ -262,22 +432,20 @@ impl<F: PrimeField32, E: EdwardsParameters> MachineAir<F> for EdDecompressChip<E
         input: &ExecutionRecord,
         output: &mut ExecutionRecord,
     ) -> RowMajorMatrix<F> {
-        let mut rows = Vec::new();
-
-        for i in 0..input.ed_decompress_events.len() {
-            let event = &input.ed_decompress_events[i];
-            let mut row = [F::zero(); NUM_ED_DECOMPRESS_COLS];
-            let cols: &mut EdDecompressCols<F> = row.as_mut_slice().borrow_mut();
-            cols.populate::<E::BaseField, E>(event.clone(), output);
-
-            rows.push(row);
-        }
-
-        pad_rows(&mut rows, || {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.ed_decompress_events, |event, new_byte_lookup_events| {
+                let mut row = [F::zero(); NUM_ED_DECOMPRESS_COLS];
+                let cols: &mut EdDecompressCols<F> = row.as_mut_slice().borrow_mut();
+                cols.populate::<E::BaseField, E>(event.clone(), new_byte_lookup_events);
+                row
+            });
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
             let mut row = [F::zero(); NUM_ED_DECOMPRESS_COLS];
             let cols: &mut EdDecompressCols<F> = row.as_mut_slice().borrow_mut();
             let zero = BigUint::zero();
-            cols.populate_field_ops::<E>(&mut vec![], 0, 0, &zero);
+            cols.populate_field_ops::<E>(&mut vec![], 0, 0, &zero, false);
             row
         });
 
@@ -300,6 +468,10 @@ impl<F: PrimeField32, E: EdwardsParameters> MachineAir<F> for EdDecompressChip<E
     fn included(&self, shard: &Self::Record) -> bool {
         !shard.ed_decompress_events.is_empty()
     }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
 }
 
 impl<F, E: EdwardsParameters> BaseAir<F> for EdDecompressChip<E> {
@@ -329,10 +501,15 @@ where
 
 #[cfg(test)]
 pub mod tests {
-    use sp1_core_executor::Program;
-    use sp1_stark::CpuProver;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
 
-    use crate::utils::{self, tests::ED_DECOMPRESS_ELF};
+    use crate::utils::{
+        self,
+        tests::{syscall_program, ED_DECOMPRESS_ELF},
+    };
+
+    const POINT_PTR: u32 = 100;
 
     #[test]
     fn test_ed_decompress() {
@@ -340,4 +517,89 @@ pub mod tests {
         let program = Program::from(ED_DECOMPRESS_ELF).unwrap();
         utils::run_test::<CpuProver<_, _>>(program).unwrap();
     }
+
+    /// Runs the precompile on an encoding the way the guest does, by moving the sign bit to the
+    /// second argument, and returns the x coordinate written back to memory.
+    fn decompress(encoding: &str, prove: bool) -> Vec<u8> {
+        let mut y: [u8; 32] = hex::decode(encoding).unwrap().try_into().unwrap();
+        let sign = (y[31] >> 7) as u32;
+        y[31] &= 0x7f;
+        let words = y.chunks_exact(4).enumerate().map(|(i, word)| {
+            (POINT_PTR + 32 + i as u32 * 4, u32::from_le_bytes(word.try_into().unwrap()))
+        });
+        let program = syscall_program(SyscallCode::ED_DECOMPRESS, words, POINT_PTR, sign);
+
+        let mut runtime = Executor::new(program.clone(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let x = (0..8).flat_map(|i| runtime.word(POINT_PTR + i * 4).to_le_bytes()).collect();
+
+        if prove {
+            utils::run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+        x
+    }
+
+    #[test]
+    fn test_ed_decompress_valid_encodings() {
+        utils::setup_logger();
+
+        // The public keys of the RFC 8032 test vectors, the identity, the point with y = -1, both
+        // points with y = 0 and a point of order 8.
+        let cases = [
+            (
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "ce457677bd8627b1247c185372d413c520f6d0608de0972229349d2b9ae0d055",
+            ),
+            (
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "ae43de571ee04a246f09a5b61ff98580524e8685653e81c04b384f5b2028ad74",
+            ),
+            (
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "02bdcd8654ffa945b9e9e334176f23189885cf8db4d1653f83689ddca23a2161",
+            ),
+            (
+                "0100000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ),
+            (
+                "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ),
+            (
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "b0a00e4a271beec478e42fad0618432fa7d7fb3d99004d2b0bdfc14f8024832b",
+            ),
+            (
+                "0000000000000000000000000000000000000000000000000000000000000080",
+                "3d5ff1b5d8e4113b871bd052f9e7bcd0582804c266ffb2d4f4203eb07fdb7c54",
+            ),
+            (
+                "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a",
+                "4ad145c54646a1de38e2e513703c195cbb4ade38329933e9284a3906a0b9d51f",
+            ),
+        ];
+        for (i, (encoding, x)) in cases.into_iter().enumerate() {
+            assert_eq!(decompress(encoding, i == 2 || i == 4), hex::decode(x).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_ed_decompress_invalid_encodings() {
+        utils::setup_logger();
+
+        // The identity and the point with y = -1 with the sign bit set, y = p, y = p + 1,
+        // y = 2^255 - 1 and a y with no corresponding x.
+        let cases = [
+            "0100000000000000000000000000000000000000000000000000000000000080",
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "0200000000000000000000000000000000000000000000000000000000000000",
+        ];
+        for (i, encoding) in cases.into_iter().enumerate() {
+            assert_eq!(decompress(encoding, i % 2 == 0), vec![0xff; 32]);
+        }
+    }
 }
@@ -0,0 +1,304 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::{fmt::Debug, marker::PhantomData};
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use num::{BigUint, Zero};
+
+use crate::air::MemoryAirBuilder;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{IntoParallelRefIterator, ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, EllipticCurveDoubleEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    edwards::{EdwardsParameters, NUM_LIMBS, WORDS_CURVE_POINT},
+    params::FieldParameters,
+    AffinePoint, CurveType, EllipticCurve,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, SP1AirBuilder};
+use typenum::U32;
+
+use super::EdAddCols;
+use crate::{
+    memory::{value_as_limbs, MemoryWriteCols},
+    utils::{limbs_from_prev_access, pad_rows_with_strategy},
+};
+
+pub const fn num_ed_double_cols<P: FieldParameters>() -> usize {
+    size_of::<EdDoubleAssignCols<u8, P>>()
+}
+
+/// A set of columns to double a point of a twisted Edwards curve, which is the addition of the
+/// point to itself with the complete addition law.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EdDoubleAssignCols<T, P: FieldParameters> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub p_ptr: T,
+    pub p_access: [MemoryWriteCols<T>; WORDS_CURVE_POINT],
+    pub(crate) double: EdAddCols<T, P>,
+}
+
+#[derive(Default)]
+pub struct EdDoubleAssignChip<E> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: EllipticCurve + EdwardsParameters> EdDoubleAssignChip<E> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    /// The events of the curve.
+    fn events(record: &ExecutionRecord) -> &Vec<EllipticCurveDoubleEvent> {
+        match E::CURVE_TYPE {
+            CurveType::BabyJubJub => &record.babyjubjub_double_events,
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    /// Create a row from an event.
+    fn event_to_row<F: PrimeField32>(
+        &self,
+        event: &EllipticCurveDoubleEvent,
+        cols: &mut EdDoubleAssignCols<F, E::BaseField>,
+        blu: &mut impl ByteRecord,
+    ) {
+        // Decode the affine point.
+        let p = AffinePoint::<E>::from_words_le(&event.p);
+        let (p_x, p_y) = (p.x, p.y);
+
+        // Populate basic columns.
+        cols.is_real = F::one();
+        cols.shard = F::from_canonical_u32(event.shard);
+        cols.channel = F::from_canonical_u8(event.channel);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+
+        cols.double.populate::<E>(blu, event.shard, event.channel, &p_x, &p_y, &p_x, &p_y);
+
+        // Populate the memory access columns.
+        for i in 0..WORDS_CURVE_POINT {
+            cols.p_access[i].populate(event.channel, event.p_memory_records[i], blu);
+        }
+    }
+}
+
+impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F>
+    for EdDoubleAssignChip<E>
+{
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match E::CURVE_TYPE {
+            CurveType::BabyJubJub => "BabyJubJubDoubleAssign".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let num_cols = num_ed_double_cols::<E::BaseField>();
+        let mut rows = Self::events(input)
+            .par_iter()
+            .map(|event| {
+                let mut row = vec![F::zero(); num_cols];
+                let cols: &mut EdDoubleAssignCols<F, E::BaseField> =
+                    row.as_mut_slice().borrow_mut();
+                let mut blu = Vec::new();
+                self.event_to_row(event, cols, &mut blu);
+                row
+            })
+            .collect::<Vec<_>>();
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = vec![F::zero(); num_cols];
+            let cols: &mut EdDoubleAssignCols<F, E::BaseField> = row.as_mut_slice().borrow_mut();
+            let zero = BigUint::zero();
+            cols.double.populate::<E>(&mut vec![], 0, 0, &zero, &zero, &zero, &zero);
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), num_cols);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut EdDoubleAssignCols<F, E::BaseField> =
+                trace.values[i * num_cols..(i + 1) * num_cols].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events = Self::events(input);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
+                events.iter().for_each(|event| {
+                    let mut row = vec![F::zero(); num_ed_double_cols::<E::BaseField>()];
+                    let cols: &mut EdDoubleAssignCols<F, E::BaseField> =
+                        row.as_mut_slice().borrow_mut();
+                    self.event_to_row(event, cols, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_sharded_byte_lookup_events(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !Self::events(shard).is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F, E: EllipticCurve + EdwardsParameters> BaseAir<F> for EdDoubleAssignChip<E> {
+    fn width(&self) -> usize {
+        num_ed_double_cols::<E::BaseField>()
+    }
+}
+
+impl<AB, E: EllipticCurve + EdwardsParameters> Air<AB> for EdDoubleAssignChip<E>
+where
+    AB: SP1AirBuilder,
+    E::BaseField: FieldParameters<Limbs = U32>,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &EdDoubleAssignCols<AB::Var, E::BaseField> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &EdDoubleAssignCols<AB::Var, E::BaseField> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The complete addition law also doubles, so the point is added to itself.
+        let x = limbs_from_prev_access(&local.p_access[0..8]);
+        let y = limbs_from_prev_access(&local.p_access[8..16]);
+        local.double.eval::<AB, E>(
+            builder,
+            &x,
+            &y,
+            &x,
+            &y,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // The point is overwritten with the result.
+        let p_access_vec = value_as_limbs(&local.p_access);
+        let (x3, y3) = local.double.result();
+        builder.when(local.is_real).assert_all_eq(*x3, p_access_vec[0..NUM_LIMBS].to_vec());
+        builder
+            .when(local.is_real)
+            .assert_all_eq(*y3, p_access_vec[NUM_LIMBS..NUM_LIMBS * 2].to_vec());
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.p_ptr,
+            &local.p_access,
+            local.is_real,
+        );
+
+        let syscall_code = match E::CURVE_TYPE {
+            CurveType::BabyJubJub => SyscallCode::BABYJUBJUB_DOUBLE,
+            _ => panic!("Unsupported curve"),
+        };
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(syscall_code.syscall_id()),
+            local.p_ptr,
+            AB::Expr::zero(),
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        edwards::{babyjubjub::BabyJubJub, WORDS_CURVE_POINT},
+        AffinePoint, EllipticCurve,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::{
+        utils,
+        utils::tests::{call_syscall, slice_at, store_words},
+    };
+
+    const P_PTR: u32 = 100;
+
+    /// Builds a program that stores p at `P_PTR` and doubles it twice.
+    fn babyjubjub_double_program(p: &AffinePoint<BabyJubJub>) -> Program {
+        let mut instructions = vec![];
+        store_words(&mut instructions, slice_at(P_PTR, &p.to_words_le()));
+        for _ in 0..2 {
+            call_syscall(&mut instructions, SyscallCode::BABYJUBJUB_DOUBLE, P_PTR, 0);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_babyjubjub_double_execute() {
+        utils::setup_logger();
+        for p in [BabyJubJub::ec_generator(), BabyJubJub::neutral()] {
+            let mut runtime = Executor::new(babyjubjub_double_program(&p), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let words = (0..WORDS_CURVE_POINT as u32)
+                .map(|i| runtime.word(P_PTR + i * 4))
+                .collect::<Vec<_>>();
+            let double = &p + &p;
+            assert_eq!(AffinePoint::<BabyJubJub>::from_words_le(&words), &double + &double);
+        }
+    }
+
+    #[test]
+    fn test_babyjubjub_double_prove() {
+        utils::setup_logger();
+        for p in [BabyJubJub::ec_generator(), BabyJubJub::neutral()] {
+            utils::run_test::<CpuProver<_, _>>(babyjubjub_double_program(&p)).unwrap();
+        }
+    }
+}
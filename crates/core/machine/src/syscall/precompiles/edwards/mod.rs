@@ -1,5 +1,11 @@
+mod ed25519_verify;
 mod ed_add;
 mod ed_decompress;
+mod ed_double;
+mod x25519_ladder;
 
+pub use ed25519_verify::*;
 pub use ed_add::*;
 pub use ed_decompress::*;
+pub use ed_double::*;
+pub use x25519_ladder::*;
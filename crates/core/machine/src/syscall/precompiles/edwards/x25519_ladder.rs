@@ -0,0 +1,608 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use num::{BigUint, Zero};
+
+use crate::air::MemoryAirBuilder;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{IntoParallelRefIterator, ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{
+        ByteLookupEvent, ByteRecord, FieldOperation, X25519LadderStepEvent, X25519_A24,
+        X25519_LADDER_STATE_WORDS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    edwards::{ed25519::Ed25519BaseField, NUM_LIMBS, WORDS_FIELD_ELEMENT},
+    params::FieldParameters,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, SP1AirBuilder};
+
+use crate::{
+    memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+    utils::{limbs_from_prev_access, pad_rows_with_strategy},
+};
+
+pub const NUM_X25519_LADDER_STEP_COLS: usize = size_of::<X25519LadderStepCols<u8>>();
+
+/// A set of columns to compute one step of the X25519 Montgomery ladder, following the formulas
+/// of RFC 7748 over the base field of Curve25519.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct X25519LadderStepCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub state_ptr: T,
+    pub u_ptr: T,
+    pub state_access: [MemoryWriteCols<T>; X25519_LADDER_STATE_WORDS],
+    pub u_access: [MemoryReadCols<T>; WORDS_FIELD_ELEMENT],
+    pub(crate) a: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) aa: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) b: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) bb: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) e: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) c: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) d: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) da: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) cb: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) da_plus_cb: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) da_minus_cb: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) x3: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) da_minus_cb_squared: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) z3: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) x2: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) a24_mul_e: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) aa_plus_a24_mul_e: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) z2: FieldOpCols<T, Ed25519BaseField>,
+}
+
+#[derive(Default)]
+pub struct X25519LadderStepChip;
+
+impl X25519LadderStepChip {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn populate_field_ops<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        cols: &mut X25519LadderStepCols<F>,
+        x1: BigUint,
+        x2: BigUint,
+        z2: BigUint,
+        x3: BigUint,
+        z3: BigUint,
+    ) {
+        let a = cols.a.populate(record, shard, channel, &x2, &z2, FieldOperation::Add);
+        let aa = cols.aa.populate(record, shard, channel, &a, &a, FieldOperation::Mul);
+        let b = cols.b.populate(record, shard, channel, &x2, &z2, FieldOperation::Sub);
+        let bb = cols.bb.populate(record, shard, channel, &b, &b, FieldOperation::Mul);
+        let e = cols.e.populate(record, shard, channel, &aa, &bb, FieldOperation::Sub);
+        let c = cols.c.populate(record, shard, channel, &x3, &z3, FieldOperation::Add);
+        let d = cols.d.populate(record, shard, channel, &x3, &z3, FieldOperation::Sub);
+        let da = cols.da.populate(record, shard, channel, &d, &a, FieldOperation::Mul);
+        let cb = cols.cb.populate(record, shard, channel, &c, &b, FieldOperation::Mul);
+
+        let da_plus_cb =
+            cols.da_plus_cb.populate(record, shard, channel, &da, &cb, FieldOperation::Add);
+        let da_minus_cb =
+            cols.da_minus_cb.populate(record, shard, channel, &da, &cb, FieldOperation::Sub);
+        cols.x3.populate(record, shard, channel, &da_plus_cb, &da_plus_cb, FieldOperation::Mul);
+        let da_minus_cb_squared = cols.da_minus_cb_squared.populate(
+            record,
+            shard,
+            channel,
+            &da_minus_cb,
+            &da_minus_cb,
+            FieldOperation::Mul,
+        );
+        cols.z3.populate(record, shard, channel, &x1, &da_minus_cb_squared, FieldOperation::Mul);
+
+        cols.x2.populate(record, shard, channel, &aa, &bb, FieldOperation::Mul);
+        let a24 = BigUint::from(X25519_A24);
+        let a24_mul_e =
+            cols.a24_mul_e.populate(record, shard, channel, &e, &a24, FieldOperation::Mul);
+        let aa_plus_a24_mul_e = cols.aa_plus_a24_mul_e.populate(
+            record,
+            shard,
+            channel,
+            &aa,
+            &a24_mul_e,
+            FieldOperation::Add,
+        );
+        cols.z2.populate(record, shard, channel, &e, &aa_plus_a24_mul_e, FieldOperation::Mul);
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for X25519LadderStepChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "X25519LadderStep".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = input
+            .x25519_ladder_step_events
+            .par_iter()
+            .map(|event| {
+                let mut row = [F::zero(); NUM_X25519_LADDER_STEP_COLS];
+                let cols: &mut X25519LadderStepCols<F> = row.as_mut_slice().borrow_mut();
+                let mut blu = Vec::new();
+                self.event_to_row(event, cols, &mut blu);
+                row
+            })
+            .collect::<Vec<_>>();
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = [F::zero(); NUM_X25519_LADDER_STEP_COLS];
+            let cols: &mut X25519LadderStepCols<F> = row.as_mut_slice().borrow_mut();
+            let zero = BigUint::zero();
+            Self::populate_field_ops(
+                &mut vec![],
+                0,
+                0,
+                cols,
+                zero.clone(),
+                zero.clone(),
+                zero.clone(),
+                zero.clone(),
+                zero,
+            );
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_X25519_LADDER_STEP_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut X25519LadderStepCols<F> = trace.values
+                [i * NUM_X25519_LADDER_STEP_COLS..(i + 1) * NUM_X25519_LADDER_STEP_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let chunk_size = std::cmp::max(input.x25519_ladder_step_events.len() / num_cpus::get(), 1);
+
+        let blu_batches = input
+            .x25519_ladder_step_events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
+                events.iter().for_each(|event| {
+                    let mut row = [F::zero(); NUM_X25519_LADDER_STEP_COLS];
+                    let cols: &mut X25519LadderStepCols<F> = row.as_mut_slice().borrow_mut();
+                    self.event_to_row(event, cols, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_sharded_byte_lookup_events(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.x25519_ladder_step_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl X25519LadderStepChip {
+    /// Create a row from an event.
+    fn event_to_row<F: PrimeField32>(
+        &self,
+        event: &X25519LadderStepEvent,
+        cols: &mut X25519LadderStepCols<F>,
+        blu: &mut impl ByteRecord,
+    ) {
+        // Decode the coordinates.
+        let x1 = BigUint::from_slice(&event.u);
+        let [x2, z2, x3, z3] = [0, 1, 2, 3].map(|i| {
+            BigUint::from_slice(
+                &event.state[i * WORDS_FIELD_ELEMENT..(i + 1) * WORDS_FIELD_ELEMENT],
+            )
+        });
+
+        // Populate basic columns.
+        cols.is_real = F::one();
+        cols.shard = F::from_canonical_u32(event.shard);
+        cols.channel = F::from_canonical_u8(event.channel);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+        cols.u_ptr = F::from_canonical_u32(event.u_ptr);
+
+        Self::populate_field_ops(blu, event.shard, event.channel, cols, x1, x2, z2, x3, z3);
+
+        // Populate the memory access columns.
+        for i in 0..WORDS_FIELD_ELEMENT {
+            cols.u_access[i].populate(event.channel, event.u_memory_records[i], blu);
+        }
+        for i in 0..X25519_LADDER_STATE_WORDS {
+            cols.state_access[i].populate(event.channel, event.state_memory_records[i], blu);
+        }
+    }
+}
+
+impl<F> BaseAir<F> for X25519LadderStepChip {
+    fn width(&self) -> usize {
+        NUM_X25519_LADDER_STEP_COLS
+    }
+}
+
+impl<AB> Air<AB> for X25519LadderStepChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &X25519LadderStepCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &X25519LadderStepCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let x1 = limbs_from_prev_access(&local.u_access);
+        let x2 = limbs_from_prev_access(&local.state_access[0..8]);
+        let z2 = limbs_from_prev_access(&local.state_access[8..16]);
+        let x3 = limbs_from_prev_access(&local.state_access[16..24]);
+        let z3 = limbs_from_prev_access(&local.state_access[24..32]);
+
+        // A = x2 + z2, AA = A^2, B = x2 - z2, BB = B^2 and E = AA - BB.
+        local.a.eval(
+            builder,
+            &x2,
+            &z2,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.aa.eval(
+            builder,
+            &local.a.result,
+            &local.a.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.b.eval(
+            builder,
+            &x2,
+            &z2,
+            FieldOperation::Sub,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.bb.eval(
+            builder,
+            &local.b.result,
+            &local.b.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.e.eval(
+            builder,
+            &local.aa.result,
+            &local.bb.result,
+            FieldOperation::Sub,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // C = x3 + z3, D = x3 - z3, DA = D * A and CB = C * B.
+        local.c.eval(
+            builder,
+            &x3,
+            &z3,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.d.eval(
+            builder,
+            &x3,
+            &z3,
+            FieldOperation::Sub,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.da.eval(
+            builder,
+            &local.d.result,
+            &local.a.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.cb.eval(
+            builder,
+            &local.c.result,
+            &local.b.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // x3' = (DA + CB)^2 and z3' = x1 * (DA - CB)^2.
+        local.da_plus_cb.eval(
+            builder,
+            &local.da.result,
+            &local.cb.result,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.da_minus_cb.eval(
+            builder,
+            &local.da.result,
+            &local.cb.result,
+            FieldOperation::Sub,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x3.eval(
+            builder,
+            &local.da_plus_cb.result,
+            &local.da_plus_cb.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.da_minus_cb_squared.eval(
+            builder,
+            &local.da_minus_cb.result,
+            &local.da_minus_cb.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.z3.eval(
+            builder,
+            &x1,
+            &local.da_minus_cb_squared.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // x2' = AA * BB and z2' = E * (AA + a24 * E).
+        local.x2.eval(
+            builder,
+            &local.aa.result,
+            &local.bb.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        let a24 = Ed25519BaseField::to_limbs_field::<AB::Expr, _>(&BigUint::from(X25519_A24));
+        local.a24_mul_e.eval(
+            builder,
+            &local.e.result,
+            &a24,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.aa_plus_a24_mul_e.eval(
+            builder,
+            &local.aa.result,
+            &local.a24_mul_e.result,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.z2.eval(
+            builder,
+            &local.e.result,
+            &local.aa_plus_a24_mul_e.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Constrain the state to be overwritten with `[x2', z2', x3', z3']`.
+        let state_access_vec = value_as_limbs(&local.state_access);
+        for (i, result) in [local.x2.result, local.z2.result, local.x3.result, local.z3.result]
+            .into_iter()
+            .enumerate()
+        {
+            builder.when(local.is_real).assert_all_eq(
+                result,
+                state_access_vec[i * NUM_LIMBS..(i + 1) * NUM_LIMBS].to_vec(),
+            );
+        }
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.u_ptr,
+            &local.u_access,
+            local.is_real,
+        );
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_u32(1),
+            local.state_ptr,
+            &local.state_access,
+            local.is_real,
+        );
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::X25519_LADDER_STEP.syscall_id()),
+            local.state_ptr,
+            local.u_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Instruction, Opcode, Program};
+    use sp1_curves::{edwards::ed25519::Ed25519BaseField, params::FieldParameters};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, store_words, words_at},
+    };
+
+    const STATE_PTR: u32 = 100;
+    const U_PTR: u32 = 300;
+
+    /// Swaps `(x2, z2)` with `(x3, z3)` in the ladder state.
+    fn swap_state(instructions: &mut Vec<Instruction>) {
+        for i in 0..16 {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 30, 0, STATE_PTR + i * 4, false, true),
+                Instruction::new(Opcode::ADD, 31, 0, STATE_PTR + 64 + i * 4, false, true),
+                Instruction::new(Opcode::LW, 28, 30, 0, false, true),
+                Instruction::new(Opcode::LW, 29, 31, 0, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                Instruction::new(Opcode::SW, 28, 31, 0, false, true),
+            ]);
+        }
+    }
+
+    /// Builds a program that runs the RFC 7748 ladder for the clamped `scalar` and `u`, leaving
+    /// the final projective u-coordinate `(x2 : z2)` at `STATE_PTR`. Only the steps for the top
+    /// `num_bits` bits of the scalar are run.
+    fn x25519_program(scalar: &[u8; 32], u: &BigUint, num_bits: usize) -> Program {
+        let mut instructions = vec![];
+        let one = BigUint::from(1u32);
+        let zero = BigUint::from(0u32);
+        store_words(&mut instructions, words_at(U_PTR, 8, &[u]));
+        store_words(&mut instructions, words_at(STATE_PTR, 8, &[&one, &zero, u, &one]));
+
+        // The swaps are known when the program is built, so they are unrolled into loads and
+        // stores.
+        let mut swap = false;
+        for t in (255 - num_bits..255).rev() {
+            let bit = (scalar[t / 8] >> (t % 8)) & 1 == 1;
+            if swap ^ bit {
+                swap_state(&mut instructions);
+            }
+            swap = bit;
+            call_syscall(&mut instructions, SyscallCode::X25519_LADDER_STEP, STATE_PTR, U_PTR);
+        }
+        if swap {
+            swap_state(&mut instructions);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    fn clamp(mut scalar: [u8; 32]) -> [u8; 32] {
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        scalar
+    }
+
+    fn decode_hex(hex: &str) -> [u8; 32] {
+        let bytes = (0..64)
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>();
+        bytes.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_x25519_ladder_step_execute() {
+        setup_logger();
+        // The first test vector of RFC 7748, section 5.2.
+        let scalar =
+            clamp(decode_hex("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"));
+        let mut u_bytes =
+            decode_hex("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c");
+        u_bytes[31] &= 127;
+        let u = BigUint::from_bytes_le(&u_bytes);
+
+        let mut runtime = Executor::new(x25519_program(&scalar, &u, 255), SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let words = (0..16).map(|i| runtime.word(STATE_PTR + i * 4)).collect::<Vec<_>>();
+        let x2 = BigUint::from_slice(&words[..8]);
+        let z2 = BigUint::from_slice(&words[8..]);
+        let p = Ed25519BaseField::modulus();
+        let result = (x2 * z2.modpow(&(&p - 2u32), &p)) % &p;
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(32, 0);
+        assert_eq!(
+            result_bytes,
+            decode_hex("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552")
+        );
+    }
+
+    #[test]
+    fn test_x25519_ladder_step_prove() {
+        setup_logger();
+        let scalar =
+            clamp(decode_hex("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"));
+        run_test::<CpuProver<_, _>>(x25519_program(&scalar, &BigUint::from(9u32), 4)).unwrap();
+    }
+}
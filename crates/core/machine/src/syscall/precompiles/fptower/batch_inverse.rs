@@ -0,0 +1,426 @@
+use std::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+    mem::size_of,
+};
+
+use crate::air::MemoryAirBuilder;
+use generic_array::GenericArray;
+use itertools::Itertools;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{BatchFieldInverseEvent, ByteLookupEvent, ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::{FieldType, FpOpField},
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder};
+use typenum::Unsigned;
+
+use crate::{
+    memory::MemoryWriteCols,
+    operations::field::field_op::FieldOpCols,
+    utils::{
+        limbs_from_access, limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows,
+        words_to_bytes_le_vec,
+    },
+};
+
+/// The maximum number of elements of a batch inversion.
+pub const BATCH_INVERSE_MAX_LEN: usize = 64;
+
+pub const fn num_batch_inverse_cols<P: FpOpField>() -> usize {
+    size_of::<BatchFieldInverseCols<u8, P>>()
+}
+
+/// Inverts an array of field elements in place with Montgomery's trick. The inputs to the syscall
+/// are a pointer to the elements and the number of elements, between 1 and 64. The elements must
+/// be reduced, and the zero elements are left as zero.
+///
+/// In the AIR, each syscall takes up one row per element. Going up the rows, the prefix products
+/// multiply in the nonzero elements. The inverse of the full product is a witness in the last row,
+/// and going down the rows, the suffix multiplications multiply it by the elements to get the
+/// inverse of each prefix product, which must reach one in the first row. The inverse of each
+/// element is the inverse of its prefix product times the prefix product before it.
+pub struct BatchFieldInverseChip<P> {
+    _marker: PhantomData<P>,
+}
+
+/// A set of columns for the batch inversion of one element.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct BatchFieldInverseCols<T, P: FpOpField> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub ptr: T,
+    pub len: T,
+
+    /// The index of the element of this row.
+    pub index: T,
+
+    /// If the row is real and the first row of the syscall.
+    pub is_first: T,
+
+    /// If the row is real and the last row of the syscall.
+    pub is_last: T,
+
+    /// Whether the element is zero. A zero element counts as one in the products, and its inverse
+    /// is written as zero.
+    pub is_zero: T,
+
+    /// Memory columns for the element, which is overwritten with its inverse.
+    pub memory: GenericArray<MemoryWriteCols<T>, P::WordsFieldElement>,
+
+    /// The product of the elements before this one.
+    pub prefix: Limbs<T, P::Limbs>,
+
+    /// `prefix * element`, the product of the elements up to this one.
+    pub prefix_mul: FieldOpCols<T, P>,
+
+    /// The inverse of the product of the elements up to this one.
+    pub suffix_inv: Limbs<T, P::Limbs>,
+
+    /// `suffix_inv * element`, the inverse of `prefix`.
+    pub suffix_mul: FieldOpCols<T, P>,
+
+    /// `suffix_inv * prefix`, the inverse of the element.
+    pub inverse: FieldOpCols<T, P>,
+}
+
+impl<P: FpOpField> BatchFieldInverseChip<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn syscall_code() -> SyscallCode {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => SyscallCode::BN254_FP_BATCH_INV,
+            FieldType::Bls12381 => SyscallCode::BLS12381_FP_BATCH_INV,
+            FieldType::Bn254Scalar => SyscallCode::BN254_FR_BATCH_INV,
+        }
+    }
+
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &BatchFieldInverseEvent,
+        rows: &mut Vec<Vec<F>>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let num_words = <P as NumWords>::WordsFieldElement::USIZE;
+        let modulus = &BigUint::from_bytes_le(P::MODULUS);
+
+        // The zero elements count as one in the products.
+        let elements = event
+            .values
+            .chunks_exact(num_words)
+            .map(|words| BigUint::from_bytes_le(&words_to_bytes_le_vec(words)))
+            .collect::<Vec<_>>();
+        let factors = elements
+            .iter()
+            .map(|x| if x.is_zero() { BigUint::one() } else { x.clone() })
+            .collect::<Vec<_>>();
+
+        let mut prefix = BigUint::one();
+        for (i, (element, factor)) in elements.iter().zip(factors.iter()).enumerate() {
+            let mut row = vec![F::zero(); num_batch_inverse_cols::<P>()];
+            let cols: &mut BatchFieldInverseCols<F, P> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u8(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.ptr = F::from_canonical_u32(event.ptr);
+            cols.len = F::from_canonical_u32(event.len);
+            cols.index = F::from_canonical_usize(i);
+            cols.is_first = F::from_bool(i == 0);
+            cols.is_last = F::from_bool(i == elements.len() - 1);
+            cols.is_zero = F::from_bool(element.is_zero());
+
+            for (j, access) in cols.memory.iter_mut().enumerate() {
+                access.populate(event.channel, event.memory_records[i * num_words + j], blu);
+            }
+
+            cols.prefix = P::to_limbs_field::<F, _>(&prefix);
+            let product = cols.prefix_mul.populate_with_modulus(
+                blu,
+                event.shard,
+                event.channel,
+                &prefix,
+                factor,
+                modulus,
+                FieldOperation::Mul,
+            );
+
+            let suffix_inv = product.modpow(&(modulus - 2u32), modulus);
+            cols.suffix_inv = P::to_limbs_field::<F, _>(&suffix_inv);
+            cols.suffix_mul.populate_with_modulus(
+                blu,
+                event.shard,
+                event.channel,
+                &suffix_inv,
+                factor,
+                modulus,
+                FieldOperation::Mul,
+            );
+            let inverse = cols.inverse.populate_with_modulus(
+                blu,
+                event.shard,
+                event.channel,
+                &suffix_inv,
+                &prefix,
+                modulus,
+                FieldOperation::Mul,
+            );
+            debug_assert_eq!((&inverse * factor) % modulus, BigUint::one());
+
+            prefix = product;
+            rows.push(row);
+        }
+    }
+}
+
+impl<F: PrimeField32, P: FpOpField> MachineAir<F> for BatchFieldInverseChip<P> {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254FpBatchInverse".to_string(),
+            FieldType::Bls12381 => "Bls12381FpBatchInverse".to_string(),
+            FieldType::Bn254Scalar => "Bn254FrBatchInverse".to_string(),
+        }
+    }
+
+    fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
+        let events = match P::FIELD_TYPE {
+            FieldType::Bn254 => &input.bn254_fp_batch_inverse_events,
+            FieldType::Bls12381 => &input.bls12381_fp_batch_inverse_events,
+            FieldType::Bn254Scalar => &input.bn254_fr_batch_inverse_events,
+        };
+
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // The field operations are not gated, so the padded rows multiply zeros.
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = vec![F::zero(); num_batch_inverse_cols::<P>()];
+            let cols: &mut BatchFieldInverseCols<F, P> = row.as_mut_slice().borrow_mut();
+            let zero = BigUint::zero();
+            let modulus = BigUint::from_bytes_le(P::MODULUS);
+            for op in [&mut cols.prefix_mul, &mut cols.suffix_mul, &mut cols.inverse] {
+                op.populate_with_modulus(
+                    &mut Vec::<ByteLookupEvent>::new(),
+                    0,
+                    0,
+                    &zero,
+                    &zero,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+            }
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_batch_inverse_cols::<P>(),
+        );
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut BatchFieldInverseCols<F, P> = trace.values
+                [i * num_batch_inverse_cols::<P>()..(i + 1) * num_batch_inverse_cols::<P>()]
+                .borrow_mut();
+            if i > 0 && cols.is_first == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => !shard.bn254_fp_batch_inverse_events.is_empty(),
+            FieldType::Bls12381 => !shard.bls12381_fp_batch_inverse_events.is_empty(),
+            FieldType::Bn254Scalar => !shard.bn254_fr_batch_inverse_events.is_empty(),
+        }
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F, P: FpOpField> BaseAir<F> for BatchFieldInverseChip<P> {
+    fn width(&self) -> usize {
+        num_batch_inverse_cols::<P>()
+    }
+}
+
+impl<AB, P: FpOpField> Air<AB> for BatchFieldInverseChip<P>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &BatchFieldInverseCols<AB::Var, P> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &BatchFieldInverseCols<AB::Var, P> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans a variable number of rows, so the nonce counts the
+        // syscalls rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_first, next.nonce);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.assert_bool(local.is_zero);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+
+        // A syscall starts in the first real row and in the real row after the end of a syscall,
+        // and continues with the next element until the last one.
+        builder.when_first_row().assert_eq(local.is_first, local.is_real);
+        builder.when_transition().when(local.is_last).assert_eq(next.is_first, next.is_real);
+        let mut transition_builder = builder.when_transition();
+        let mut in_syscall_builder = transition_builder.when(local.is_real - local.is_last);
+        in_syscall_builder.assert_one(next.is_real);
+        in_syscall_builder.assert_zero(next.is_first);
+        in_syscall_builder.assert_eq(local.shard, next.shard);
+        in_syscall_builder.assert_eq(local.channel, next.channel);
+        in_syscall_builder.assert_eq(local.clk, next.clk);
+        in_syscall_builder.assert_eq(local.ptr, next.ptr);
+        in_syscall_builder.assert_eq(local.len, next.len);
+        in_syscall_builder.assert_eq(local.index + AB::Expr::one(), next.index);
+
+        // Once the is_real flag is changed to false, it should not be changed back.
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+
+        // The table cannot end in the middle of a syscall.
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+
+        // The elements are indexed from zero, and the last one is at `len - 1`.
+        builder.when(local.is_first).assert_zero(local.index);
+        builder.when(local.is_last).assert_eq(local.index, local.len - AB::Expr::one());
+
+        let modulus_coeffs =
+            P::MODULUS.iter().map(|&limbs| AB::Expr::from_canonical_u8(limbs)).collect_vec();
+        let p_modulus = Polynomial::from_coefficients(&modulus_coeffs);
+
+        // A zero element is replaced by one in the products. A nonzero element is invertible,
+        // otherwise the suffix multiplications cannot reach one in the first row.
+        let element: Limbs<AB::Var, P::Limbs> = limbs_from_prev_access(&local.memory);
+        for &limb in element.0.iter() {
+            builder.when(local.is_zero).assert_zero(limb);
+        }
+        let factor_coeffs = element
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, &limb)| if i == 0 { limb + local.is_zero } else { limb.into() })
+            .collect_vec();
+        let p_factor = Polynomial::from_coefficients(&factor_coeffs);
+
+        // The prefix products start from one, and each one is passed to the next row.
+        let one = P::to_limbs_field::<AB::Expr, _>(&BigUint::one());
+        builder.when(local.is_first).assert_all_eq(local.prefix, one.clone());
+        local.prefix_mul.eval_with_modulus(
+            builder,
+            &local.prefix,
+            &p_factor,
+            &p_modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        builder
+            .when_transition()
+            .when(local.is_real - local.is_last)
+            .assert_all_eq(next.prefix, local.prefix_mul.result);
+
+        // The suffix multiplications go down from the inverse of the full product in the last row,
+        // and multiplying the inverse of the first element by it must give one.
+        local.suffix_mul.eval_with_modulus(
+            builder,
+            &local.suffix_inv,
+            &p_factor,
+            &p_modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        builder
+            .when_transition()
+            .when(local.is_real - local.is_last)
+            .assert_all_eq(next.suffix_mul.result, local.suffix_inv);
+        builder.when(local.is_first).assert_all_eq(local.suffix_mul.result, one);
+
+        // The inverse of the element is written back, or zero if the element is zero.
+        local.inverse.eval_with_modulus(
+            builder,
+            &local.suffix_inv,
+            &local.prefix,
+            &p_modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        let written: Limbs<AB::Var, P::Limbs> = limbs_from_access(&local.memory);
+        for (&value, &inverse) in written.0.iter().zip(local.inverse.result.0.iter()) {
+            builder
+                .when(local.is_real)
+                .assert_eq(value, inverse * (AB::Expr::one() - local.is_zero));
+        }
+
+        // The element is read and written back after the clk increments.
+        let num_bytes = <P as NumWords>::WordsFieldElement::U32 * 4;
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::one(),
+            local.ptr + local.index * AB::F::from_canonical_u32(num_bytes),
+            &local.memory,
+            local.is_real,
+        );
+
+        // Receive the arguments in the first row of each syscall.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(Self::syscall_code().syscall_id()),
+            local.ptr,
+            local.len,
+            local.is_first,
+        );
+    }
+}
@@ -21,12 +21,14 @@ use sp1_curves::{
     weierstrass::{FieldType, FpOpField},
 };
 use sp1_derive::AlignedBorrow;
-use sp1_stark::air::{BaseAirBuilder, MachineAir, Polynomial, SP1AirBuilder};
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder};
 
 use crate::{
     memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
     operations::field::field_op::FieldOpCols,
-    utils::{limbs_from_prev_access, pad_rows, words_to_bytes_le_vec},
+    utils::{
+        limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows, words_to_bytes_le_vec,
+    },
 };
 
 pub const fn num_fp_cols<P: FpOpField>() -> usize {
@@ -86,6 +88,7 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for FpOpChip<P> {
         match P::FIELD_TYPE {
             FieldType::Bn254 => "Bn254FpOpAssign".to_string(),
             FieldType::Bls12381 => "Bls12381FpOpAssign".to_string(),
+            FieldType::Bn254Scalar => "Bn254FrOpAssign".to_string(),
         }
     }
 
@@ -93,62 +96,59 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for FpOpChip<P> {
         let events = match P::FIELD_TYPE {
             FieldType::Bn254 => &input.bn254_fp_events,
             FieldType::Bls12381 => &input.bls12381_fp_events,
+            FieldType::Bn254Scalar => &input.bn254_fr_events,
         };
 
-        let mut rows = Vec::new();
-        let mut new_byte_lookup_events = Vec::new();
-
-        for i in 0..events.len() {
-            let event = &events[i];
-
-            let mut row = vec![F::zero(); num_fp_cols::<P>()];
-            let cols: &mut FpOpCols<F, P> = row.as_mut_slice().borrow_mut();
-
-            let modulus = &BigUint::from_bytes_le(P::MODULUS);
-            let p = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.x)) % modulus;
-            let q = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.y)) % modulus;
-
-            cols.is_add = F::from_canonical_u8((event.op == FieldOperation::Add) as u8);
-            cols.is_sub = F::from_canonical_u8((event.op == FieldOperation::Sub) as u8);
-            cols.is_mul = F::from_canonical_u8((event.op == FieldOperation::Mul) as u8);
-            cols.is_real = F::one();
-            cols.shard = F::from_canonical_u32(event.shard);
-            cols.channel = F::from_canonical_u8(event.channel);
-            cols.clk = F::from_canonical_u32(event.clk);
-            cols.x_ptr = F::from_canonical_u32(event.x_ptr);
-            cols.y_ptr = F::from_canonical_u32(event.y_ptr);
-
-            Self::populate_field_ops(
-                &mut new_byte_lookup_events,
-                event.shard,
-                event.channel,
-                cols,
-                p,
-                q,
-                event.op,
-            );
-
-            // Populate the memory access columns.
-            for i in 0..cols.y_access.len() {
-                cols.y_access[i].populate(
-                    event.channel,
-                    event.y_memory_records[i],
-                    &mut new_byte_lookup_events,
-                );
-            }
-            for i in 0..cols.x_access.len() {
-                cols.x_access[i].populate(
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(events, |event, new_byte_lookup_events| {
+                let mut row = vec![F::zero(); num_fp_cols::<P>()];
+                let cols: &mut FpOpCols<F, P> = row.as_mut_slice().borrow_mut();
+
+                let modulus = &BigUint::from_bytes_le(P::MODULUS);
+                let p = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.x)) % modulus;
+                let q = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.y)) % modulus;
+
+                cols.is_add = F::from_canonical_u8((event.op == FieldOperation::Add) as u8);
+                cols.is_sub = F::from_canonical_u8((event.op == FieldOperation::Sub) as u8);
+                cols.is_mul = F::from_canonical_u8((event.op == FieldOperation::Mul) as u8);
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+                Self::populate_field_ops(
+                    new_byte_lookup_events,
+                    event.shard,
                     event.channel,
-                    event.x_memory_records[i],
-                    &mut new_byte_lookup_events,
+                    cols,
+                    p,
+                    q,
+                    event.op,
                 );
-            }
-            rows.push(row)
-        }
+
+                // Populate the memory access columns.
+                for i in 0..cols.y_access.len() {
+                    cols.y_access[i].populate(
+                        event.channel,
+                        event.y_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+                for i in 0..cols.x_access.len() {
+                    cols.x_access[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+                row
+            });
 
         output.add_byte_lookup_events(new_byte_lookup_events);
 
-        pad_rows(&mut rows, || {
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
             let mut row = vec![F::zero(); num_fp_cols::<P>()];
             let cols: &mut FpOpCols<F, P> = row.as_mut_slice().borrow_mut();
             let zero = BigUint::zero();
@@ -183,8 +183,13 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for FpOpChip<P> {
         match P::FIELD_TYPE {
             FieldType::Bn254 => !shard.bn254_fp_events.is_empty(),
             FieldType::Bls12381 => !shard.bls12381_fp_events.is_empty(),
+            FieldType::Bn254Scalar => !shard.bn254_fr_events.is_empty(),
         }
     }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
 }
 
 impl<F, P: FpOpField> BaseAir<F> for FpOpChip<P> {
@@ -274,6 +279,11 @@ where
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP_SUB.syscall_id()),
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP_MUL.syscall_id()),
             ),
+            FieldType::Bn254Scalar => (
+                AB::F::from_canonical_u32(SyscallCode::BN254_FR_ADD.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::BN254_FR_SUB.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::BN254_FR_MUL.syscall_id()),
+            ),
         };
         let syscall_id_felt = local.is_add * add_syscall_id
             + local.is_sub * sub_syscall_id
@@ -289,5 +299,8 @@ where
             local.y_ptr,
             local.is_real,
         );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
     }
 }
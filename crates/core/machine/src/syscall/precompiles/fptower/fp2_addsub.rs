@@ -21,13 +21,15 @@ use sp1_curves::{
     weierstrass::{FieldType, FpOpField},
 };
 use sp1_derive::AlignedBorrow;
-use sp1_stark::air::{BaseAirBuilder, MachineAir, Polynomial, SP1AirBuilder};
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder};
 use typenum::Unsigned;
 
 use crate::{
     memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
     operations::field::field_op::FieldOpCols,
-    utils::{limbs_from_prev_access, pad_rows, words_to_bytes_le_vec},
+    utils::{
+        limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows, words_to_bytes_le_vec,
+    },
 };
 
 pub const fn num_fp2_addsub_cols<P: FpOpField>() -> usize {
@@ -89,6 +91,7 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2AddSubAssignChip<P> {
         match P::FIELD_TYPE {
             FieldType::Bn254 => "Bn254Fp2AddSubAssign".to_string(),
             FieldType::Bls12381 => "Bls12831Fp2AddSubAssign".to_string(),
+            _ => panic!("Unsupported field"),
         }
     }
 
@@ -96,65 +99,62 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2AddSubAssignChip<P> {
         let events = match P::FIELD_TYPE {
             FieldType::Bn254 => &input.bn254_fp2_addsub_events,
             FieldType::Bls12381 => &input.bls12381_fp2_addsub_events,
+            _ => panic!("Unsupported field"),
         };
 
-        let mut rows = Vec::new();
-        let mut new_byte_lookup_events = Vec::new();
-
-        for i in 0..events.len() {
-            let event = &events[i];
-
-            let mut row = vec![F::zero(); num_fp2_addsub_cols::<P>()];
-            let cols: &mut Fp2AddSubAssignCols<F, P> = row.as_mut_slice().borrow_mut();
-
-            let p = &event.x;
-            let q = &event.y;
-            let p_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&p[..p.len() / 2]));
-            let p_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&p[p.len() / 2..]));
-            let q_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&q[..q.len() / 2]));
-            let q_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&q[q.len() / 2..]));
-
-            cols.is_real = F::one();
-            cols.is_add = F::from_bool(event.op == FieldOperation::Add);
-            cols.shard = F::from_canonical_u32(event.shard);
-            cols.channel = F::from_canonical_u8(event.channel);
-            cols.clk = F::from_canonical_u32(event.clk);
-            cols.x_ptr = F::from_canonical_u32(event.x_ptr);
-            cols.y_ptr = F::from_canonical_u32(event.y_ptr);
-
-            Self::populate_field_ops(
-                &mut new_byte_lookup_events,
-                event.shard,
-                event.channel,
-                cols,
-                p_x,
-                p_y,
-                q_x,
-                q_y,
-                event.op,
-            );
-
-            // Populate the memory access columns.
-            for i in 0..cols.y_access.len() {
-                cols.y_access[i].populate(
-                    event.channel,
-                    event.y_memory_records[i],
-                    &mut new_byte_lookup_events,
-                );
-            }
-            for i in 0..cols.x_access.len() {
-                cols.x_access[i].populate(
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(events, |event, new_byte_lookup_events| {
+                let mut row = vec![F::zero(); num_fp2_addsub_cols::<P>()];
+                let cols: &mut Fp2AddSubAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+
+                let p = &event.x;
+                let q = &event.y;
+                let p_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&p[..p.len() / 2]));
+                let p_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&p[p.len() / 2..]));
+                let q_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&q[..q.len() / 2]));
+                let q_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&q[q.len() / 2..]));
+
+                cols.is_real = F::one();
+                cols.is_add = F::from_bool(event.op == FieldOperation::Add);
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+                Self::populate_field_ops(
+                    new_byte_lookup_events,
+                    event.shard,
                     event.channel,
-                    event.x_memory_records[i],
-                    &mut new_byte_lookup_events,
+                    cols,
+                    p_x,
+                    p_y,
+                    q_x,
+                    q_y,
+                    event.op,
                 );
-            }
-            rows.push(row)
-        }
+
+                // Populate the memory access columns.
+                for i in 0..cols.y_access.len() {
+                    cols.y_access[i].populate(
+                        event.channel,
+                        event.y_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+                for i in 0..cols.x_access.len() {
+                    cols.x_access[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+                row
+            });
 
         output.add_byte_lookup_events(new_byte_lookup_events);
 
-        pad_rows(&mut rows, || {
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
             let mut row = vec![F::zero(); num_fp2_addsub_cols::<P>()];
             let cols: &mut Fp2AddSubAssignCols<F, P> = row.as_mut_slice().borrow_mut();
             cols.is_add = F::one();
@@ -194,8 +194,13 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2AddSubAssignChip<P> {
         match P::FIELD_TYPE {
             FieldType::Bn254 => !shard.bn254_fp2_addsub_events.is_empty(),
             FieldType::Bls12381 => !shard.bls12381_fp2_addsub_events.is_empty(),
+            _ => panic!("Unsupported field"),
         }
     }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
 }
 
 impl<F, P: FpOpField> BaseAir<F> for Fp2AddSubAssignChip<P> {
@@ -298,6 +303,7 @@ where
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP2_ADD.syscall_id()),
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP2_SUB.syscall_id()),
             ),
+            _ => panic!("Unsupported field"),
         };
 
         let syscall_id_felt =
@@ -313,5 +319,8 @@ where
             local.y_ptr,
             local.is_real,
         );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
     }
 }
@@ -20,14 +20,16 @@ use sp1_curves::{
     weierstrass::{FieldType, FpOpField},
 };
 use sp1_derive::AlignedBorrow;
-use sp1_stark::air::{BaseAirBuilder, MachineAir, Polynomial, SP1AirBuilder};
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder};
 use std::mem::size_of;
 use typenum::Unsigned;
 
 use crate::{
     memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
     operations::field::field_op::FieldOpCols,
-    utils::{limbs_from_prev_access, pad_rows, words_to_bytes_le_vec},
+    utils::{
+        limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows, words_to_bytes_le_vec,
+    },
 };
 
 pub const fn num_fp2_mul_cols<P: FieldParameters + NumWords>() -> usize {
@@ -144,6 +146,7 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2MulAssignChip<P> {
         match P::FIELD_TYPE {
             FieldType::Bn254 => "Bn254Fp2MulAssign".to_string(),
             FieldType::Bls12381 => "Bls12831Fp2MulAssign".to_string(),
+            _ => panic!("Unsupported field"),
         }
     }
 
@@ -151,62 +154,60 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2MulAssignChip<P> {
         let events = match P::FIELD_TYPE {
             FieldType::Bn254 => &input.bn254_fp2_mul_events,
             FieldType::Bls12381 => &input.bls12381_fp2_mul_events,
+            _ => panic!("Unsupported field"),
         };
 
-        let mut rows = Vec::new();
-        let mut new_byte_lookup_events = Vec::new();
-
-        for i in 0..events.len() {
-            let event = &events[i];
-            let mut row = vec![F::zero(); num_fp2_mul_cols::<P>()];
-            let cols: &mut Fp2MulAssignCols<F, P> = row.as_mut_slice().borrow_mut();
-
-            let p = &event.x;
-            let q = &event.y;
-            let p_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&p[..p.len() / 2]));
-            let p_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&p[p.len() / 2..]));
-            let q_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&q[..q.len() / 2]));
-            let q_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&q[q.len() / 2..]));
-
-            cols.is_real = F::one();
-            cols.shard = F::from_canonical_u32(event.shard);
-            cols.channel = F::from_canonical_u8(event.channel);
-            cols.clk = F::from_canonical_u32(event.clk);
-            cols.x_ptr = F::from_canonical_u32(event.x_ptr);
-            cols.y_ptr = F::from_canonical_u32(event.y_ptr);
-
-            Self::populate_field_ops(
-                &mut new_byte_lookup_events,
-                event.shard,
-                event.channel,
-                cols,
-                p_x,
-                p_y,
-                q_x,
-                q_y,
-            );
-
-            // Populate the memory access columns.
-            for i in 0..cols.y_access.len() {
-                cols.y_access[i].populate(
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(events, |event, new_byte_lookup_events| {
+                let mut row = vec![F::zero(); num_fp2_mul_cols::<P>()];
+                let cols: &mut Fp2MulAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+
+                let p = &event.x;
+                let q = &event.y;
+                let p_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&p[..p.len() / 2]));
+                let p_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&p[p.len() / 2..]));
+                let q_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&q[..q.len() / 2]));
+                let q_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&q[q.len() / 2..]));
+
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+                Self::populate_field_ops(
+                    new_byte_lookup_events,
+                    event.shard,
                     event.channel,
-                    event.y_memory_records[i],
-                    &mut new_byte_lookup_events,
+                    cols,
+                    p_x,
+                    p_y,
+                    q_x,
+                    q_y,
                 );
-            }
-            for i in 0..cols.x_access.len() {
-                cols.x_access[i].populate(
-                    event.channel,
-                    event.x_memory_records[i],
-                    &mut new_byte_lookup_events,
-                );
-            }
-            rows.push(row)
-        }
+
+                // Populate the memory access columns.
+                for i in 0..cols.y_access.len() {
+                    cols.y_access[i].populate(
+                        event.channel,
+                        event.y_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+                for i in 0..cols.x_access.len() {
+                    cols.x_access[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+                row
+            });
 
         output.add_byte_lookup_events(new_byte_lookup_events);
 
-        pad_rows(&mut rows, || {
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
             let mut row = vec![F::zero(); num_fp2_mul_cols::<P>()];
             let cols: &mut Fp2MulAssignCols<F, P> = row.as_mut_slice().borrow_mut();
             let zero = BigUint::zero();
@@ -244,8 +245,13 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2MulAssignChip<P> {
         match P::FIELD_TYPE {
             FieldType::Bn254 => !shard.bn254_fp2_mul_events.is_empty(),
             FieldType::Bls12381 => !shard.bls12381_fp2_mul_events.is_empty(),
+            _ => panic!("Unsupported field"),
         }
     }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
 }
 
 impl<F, P: FpOpField> BaseAir<F> for Fp2MulAssignChip<P> {
@@ -382,6 +388,7 @@ where
             FieldType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP2_MUL.syscall_id())
             }
+            _ => panic!("Unsupported field"),
         };
 
         builder.receive_syscall(
@@ -394,5 +401,8 @@ where
             local.y_ptr,
             local.is_real,
         );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
     }
 }
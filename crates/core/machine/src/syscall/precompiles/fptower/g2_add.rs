@@ -0,0 +1,637 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::marker::PhantomData;
+
+use crate::air::MemoryAirBuilder;
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::{FieldType, FpOpField},
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{MachineAir, PaddingStrategy, SP1AirBuilder};
+use typenum::Unsigned;
+
+use crate::{
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op_fp2::{Fp2DivCols, Fp2Limbs, Fp2MulCols, Fp2OpCols},
+    utils::{
+        limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows, words_to_bytes_le_vec,
+    },
+};
+
+pub const fn num_g2_add_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<G2AddCols<u8, P>>()
+}
+
+/// A set of columns to compute the addition of two points on a G2 twist, which is a short
+/// Weierstrass curve over `Fp2 = Fp[u]/(u^2 + 1)`.
+///
+/// A point is laid out as `[x.c0, x.c1, y.c0, y.c1]`, and the memory accesses are split into the
+/// two Fp2 coordinates.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct G2AddCols<T, P: FieldParameters + NumWords> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub q_ptr: T,
+    pub p_x_access: GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>,
+    pub p_y_access: GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>,
+    pub q_x_access: GenericArray<MemoryReadCols<T>, P::WordsCurvePoint>,
+    pub q_y_access: GenericArray<MemoryReadCols<T>, P::WordsCurvePoint>,
+    pub(crate) slope_denominator: Fp2OpCols<T, P>,
+    pub(crate) slope_numerator: Fp2OpCols<T, P>,
+    pub(crate) slope: Fp2DivCols<T, P>,
+    pub(crate) slope_squared: Fp2MulCols<T, P>,
+    pub(crate) p_x_plus_q_x: Fp2OpCols<T, P>,
+    pub(crate) x3_ins: Fp2OpCols<T, P>,
+    pub(crate) p_x_minus_x: Fp2OpCols<T, P>,
+    pub(crate) y3_ins: Fp2OpCols<T, P>,
+    pub(crate) slope_times_p_x_minus_x: Fp2MulCols<T, P>,
+}
+
+/// A chip that adds two distinct points on a G2 twist with the affine addition law.
+///
+/// As with the G1 addition precompiles, the points must have distinct x coordinates, and neither
+/// may be the point at infinity.
+#[derive(Default)]
+pub struct G2AddChip<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P: FpOpField> G2AddChip<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        cols: &mut G2AddCols<F, P>,
+        p: ([BigUint; 2], [BigUint; 2]),
+        q: ([BigUint; 2], [BigUint; 2]),
+    ) {
+        let (p_x, p_y) = p;
+        let (q_x, q_y) = q;
+
+        // slope = (q.y - p.y) / (q.x - p.x).
+        let slope = {
+            let slope_numerator = cols.slope_numerator.populate(
+                blu_events,
+                shard,
+                channel,
+                &q_y,
+                &p_y,
+                FieldOperation::Sub,
+            );
+
+            let slope_denominator = cols.slope_denominator.populate(
+                blu_events,
+                shard,
+                channel,
+                &q_x,
+                &p_x,
+                FieldOperation::Sub,
+            );
+
+            cols.slope.populate(blu_events, shard, channel, &slope_numerator, &slope_denominator)
+        };
+
+        // x = slope * slope - (p.x + q.x).
+        let x = {
+            let slope_squared =
+                cols.slope_squared.populate(blu_events, shard, channel, &slope, &slope);
+            let p_x_plus_q_x = cols.p_x_plus_q_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &q_x,
+                FieldOperation::Add,
+            );
+            cols.x3_ins.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope_squared,
+                &p_x_plus_q_x,
+                FieldOperation::Sub,
+            )
+        };
+
+        // y = slope * (p.x - x_3n) - p.y.
+        {
+            let p_x_minus_x = cols.p_x_minus_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &x,
+                FieldOperation::Sub,
+            );
+            let slope_times_p_x_minus_x = cols.slope_times_p_x_minus_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope,
+                &p_x_minus_x,
+            );
+            cols.y3_ins.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope_times_p_x_minus_x,
+                &p_y,
+                FieldOperation::Sub,
+            );
+        }
+    }
+}
+
+/// Decodes the coordinates `(x, y)` of a G2 point from its little endian words.
+pub(super) fn decode_g2_point(words: &[u32]) -> ([BigUint; 2], [BigUint; 2]) {
+    let num_words_field_element = words.len() / 4;
+    let element = |i: usize| {
+        BigUint::from_bytes_le(&words_to_bytes_le_vec(
+            &words[i * num_words_field_element..(i + 1) * num_words_field_element],
+        ))
+    };
+    ([element(0), element(1)], [element(2), element(3)])
+}
+
+/// Returns the limbs `[c0, c1]` of an Fp2 coordinate from the previous values of its memory
+/// accesses.
+pub(super) fn fp2_limbs_from_prev_access<T: Copy, P: FieldParameters, M: MemoryCols<T>>(
+    cols: &[M],
+) -> Fp2Limbs<T, P> {
+    let (c0, c1) = cols.split_at(cols.len() / 2);
+    [limbs_from_prev_access(c0), limbs_from_prev_access(c1)]
+}
+
+impl<F: PrimeField32, P: FpOpField> MachineAir<F> for G2AddChip<P> {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254G2AddAssign".to_string(),
+            FieldType::Bls12381 => "Bls12381G2AddAssign".to_string(),
+            _ => panic!("Unsupported field"),
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = match P::FIELD_TYPE {
+            FieldType::Bn254 => &input.bn254_g2_add_events,
+            FieldType::Bls12381 => &input.bls12381_g2_add_events,
+            _ => panic!("Unsupported field"),
+        };
+
+        let num_cols = num_g2_add_cols::<P>();
+        let num_words_coordinate = <P as NumWords>::WordsCurvePoint::USIZE;
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(events, |event, new_byte_lookup_events| {
+                let mut row = vec![F::zero(); num_cols];
+                let cols: &mut G2AddCols<F, P> = row.as_mut_slice().borrow_mut();
+
+                // Populate basic columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+                cols.q_ptr = F::from_canonical_u32(event.q_ptr);
+
+                Self::populate_field_ops(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    cols,
+                    decode_g2_point(&event.p),
+                    decode_g2_point(&event.q),
+                );
+
+                // Populate the memory access columns.
+                for i in 0..num_words_coordinate {
+                    let j = i + num_words_coordinate;
+                    cols.q_x_access[i].populate(
+                        event.channel,
+                        event.q_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.q_y_access[i].populate(
+                        event.channel,
+                        event.q_memory_records[j],
+                        new_byte_lookup_events,
+                    );
+                    cols.p_x_access[i].populate(
+                        event.channel,
+                        event.p_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.p_y_access[i].populate(
+                        event.channel,
+                        event.p_memory_records[j],
+                        new_byte_lookup_events,
+                    );
+                }
+
+                row
+            });
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = vec![F::zero(); num_cols];
+            let cols: &mut G2AddCols<F, P> = row.as_mut_slice().borrow_mut();
+            let zero = || [BigUint::zero(), BigUint::zero()];
+            Self::populate_field_ops(&mut vec![], 0, 0, cols, (zero(), zero()), (zero(), zero()));
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), num_cols);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut G2AddCols<F, P> =
+                trace.values[i * num_cols..(i + 1) * num_cols].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => !shard.bn254_g2_add_events.is_empty(),
+            FieldType::Bls12381 => !shard.bls12381_g2_add_events.is_empty(),
+            _ => panic!("Unsupported field"),
+        }
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F, P: FpOpField> BaseAir<F> for G2AddChip<P> {
+    fn width(&self) -> usize {
+        num_g2_add_cols::<P>()
+    }
+}
+
+impl<AB, P: FpOpField> Air<AB> for G2AddChip<P>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &G2AddCols<AB::Var, P> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &G2AddCols<AB::Var, P> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let p_x: Fp2Limbs<AB::Var, P> = fp2_limbs_from_prev_access::<_, P, _>(&local.p_x_access);
+        let p_y: Fp2Limbs<AB::Var, P> = fp2_limbs_from_prev_access::<_, P, _>(&local.p_y_access);
+        let q_x: Fp2Limbs<AB::Var, P> = fp2_limbs_from_prev_access::<_, P, _>(&local.q_x_access);
+        let q_y: Fp2Limbs<AB::Var, P> = fp2_limbs_from_prev_access::<_, P, _>(&local.q_y_access);
+
+        // slope = (q.y - p.y) / (q.x - p.x).
+        let slope = {
+            local.slope_numerator.eval(
+                builder,
+                &q_y,
+                &p_y,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope_denominator.eval(
+                builder,
+                &q_x,
+                &p_x,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope.eval(
+                builder,
+                &local.slope_numerator.result(),
+                &local.slope_denominator.result(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            &local.slope.result
+        };
+
+        // x = slope * slope - (p.x + q.x).
+        let x = {
+            local.slope_squared.eval(
+                builder,
+                slope,
+                slope,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.p_x_plus_q_x.eval(
+                builder,
+                &p_x,
+                &q_x,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.x3_ins.eval(
+                builder,
+                &local.slope_squared.result(),
+                &local.p_x_plus_q_x.result(),
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.x3_ins.result()
+        };
+
+        // y = slope * (p.x - x_3n) - p.y.
+        let y = {
+            local.p_x_minus_x.eval(
+                builder,
+                &p_x,
+                &x,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope_times_p_x_minus_x.eval(
+                builder,
+                slope,
+                &local.p_x_minus_x.result(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.y3_ins.eval(
+                builder,
+                &local.slope_times_p_x_minus_x.result(),
+                &p_y,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.y3_ins.result()
+        };
+
+        // Constrain p_access.value = [x.c0, x.c1, y.c0, y.c1], so that p is updated with the sum.
+        eval_g2_point_write(builder, &x, &y, &local.p_x_access, &local.p_y_access, local.is_real);
+
+        let num_words_coordinate = <P as NumWords>::WordsCurvePoint::U32;
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.q_ptr,
+            &local.q_x_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.q_ptr + AB::F::from_canonical_u32(num_words_coordinate * 4),
+            &local.q_y_access,
+            local.is_real,
+        );
+        // We write p at +1 since p, q could be the same.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_u32(1),
+            local.p_ptr,
+            &local.p_x_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_u32(1),
+            local.p_ptr + AB::F::from_canonical_u32(num_words_coordinate * 4),
+            &local.p_y_access,
+            local.is_real,
+        );
+
+        let syscall_id_felt = match P::FIELD_TYPE {
+            FieldType::Bn254 => AB::F::from_canonical_u32(SyscallCode::BN254_G2_ADD.syscall_id()),
+            FieldType::Bls12381 => {
+                AB::F::from_canonical_u32(SyscallCode::BLS12381_G2_ADD.syscall_id())
+            }
+            _ => panic!("Unsupported field"),
+        };
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.p_ptr,
+            local.q_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+/// Constrains the values written to a G2 point to be the limbs of `x` and `y`.
+pub(super) fn eval_g2_point_write<AB: SP1AirBuilder, P: FieldParameters>(
+    builder: &mut AB,
+    x: &Fp2Limbs<AB::Var, P>,
+    y: &Fp2Limbs<AB::Var, P>,
+    x_access: &[MemoryWriteCols<AB::Var>],
+    y_access: &[MemoryWriteCols<AB::Var>],
+    is_real: AB::Var,
+) where
+    Limbs<AB::Var, P::Limbs>: Copy,
+{
+    let num_words_field_element = P::Limbs::USIZE / 4;
+    for (coordinate, access) in [(x, x_access), (y, y_access)] {
+        for (i, limbs) in coordinate.iter().enumerate() {
+            for j in 0..P::Limbs::USIZE {
+                builder.when(is_real).assert_eq(
+                    limbs[j],
+                    access[i * num_words_field_element + j / 4].value()[j % 4],
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod g2_tests {
+    use num::BigUint;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{slice_at, syscall_program},
+    };
+
+    pub const P_PTR: u32 = 100;
+    pub const Q_PTR: u32 = 400;
+
+    /// The BN254 G2 generator, as `[x.c0, x.c1, y.c0, y.c1]` in hex.
+    pub const BN254_G: [&[u8]; 4] = [
+        b"1800DEEF121F1E76426A00665E5C4479674322D4F75EDADD46DEBD5CD992F6ED",
+        b"198E9393920D483A7260BFB731FB5D25F1AA493335A9E71297E485B7AEF312C2",
+        b"12C85EA5DB8C6DEB4AAB71808DCB408FE3D1E7690C43D37B4CE6CC0166FA7DAA",
+        b"090689D0585FF075EC9E99AD690C3395BC4B313370B38EF355ACDADCD122975B",
+    ];
+
+    /// `2 * G`.
+    pub const BN254_G2: [&[u8]; 4] = [
+        b"27DC7234FD11D3E8C36C59277C3E6F149D5CD3CFA9A62AEE49F8130962B4B3B9",
+        b"203E205DB4F19B37B60121B83A7333706DB86431C6D835849957ED8C3928AD79",
+        b"04BB53B8977E5F92A0BC372742C4830944A59B4FE6B1C0466E2A6DAD122B5D2E",
+        b"195E8AA5B7827463722B8C153931579D3505566B4EDF48D498E185F0509DE152",
+    ];
+
+    /// `3 * G`.
+    pub const BN254_G3: [&[u8]; 4] = [
+        b"06064E784DB10E9051E52826E192715E8D7E478CB09A5E0012DEFA0694FBC7F5",
+        b"1014772F57BB9742735191CD5DCFE4EBBC04156B6878A0A7C9824F32FFB66E85",
+        b"058E1D5681B5B9E0074B0F9C8D2C68A069B920D74521E79765036D57666C5597",
+        b"021E2335F3354BB7922FFCC2F38D3323DD9453AC49B55441452AEACA147711B2",
+    ];
+
+    /// The BLS12-381 G2 generator.
+    pub const BLS12381_G: [&[u8]; 4] = [
+        b"024AA2B2F08F0A91260805272DC51051C6E47AD4FA403B02B4510B647AE3D1770BAC0326A805BBEFD48056C8C121BDB8",
+        b"13E02B6052719F607DACD3A088274F65596BD0D09920B61AB5DA61BBDC7F5049334CF11213945D57E5AC7D055D042B7E",
+        b"0CE5D527727D6E118CC9CDC6DA2E351AADFD9BAA8CBDD3A76D429A695160D12C923AC9CC3BACA289E193548608B82801",
+        b"0606C4A02EA734CC32ACD2B02BC28B99CB3E287E85A763AF267492AB572E99AB3F370D275CEC1DA1AAA9075FF05F79BE",
+    ];
+
+    /// `2 * G`.
+    pub const BLS12381_G2: [&[u8]; 4] = [
+        b"1638533957D540A9D2370F17CC7ED5863BC0B995B8825E0EE1EA1E1E4D00DBAE81F14B0BF3611B78C952AACAB827A053",
+        b"0A4EDEF9C1ED7F729F520E47730A124FD70662A904BA1074728114D1031E1572C6C886F6B57EC72A6178288C47C33577",
+        b"0468FB440D82B0630AEB8DCA2B5256789A66DA69BF91009CBFE6BD221E47AA8AE88DECE9764BF3BD999D95D71E4C9899",
+        b"0F6D4552FA65DD2638B361543F887136A43253D9C66C411697003F7A13C308F5422E1AA0A59C8967ACDEFD8B6E36CCF3",
+    ];
+
+    /// `3 * G`.
+    pub const BLS12381_G3: [&[u8]; 4] = [
+        b"122915C824A0857E2EE414A3DCCB23AE691AE54329781315A0C75DF1C04D6D7A50A030FC866F09D516020EF82324AFAE",
+        b"09380275BBC8E5DCEA7DC4DD7E0550FF2AC480905396EDA55062650F8D251C96EB480673937CC6D9D6A44AAA56CA66DC",
+        b"0B21DA7955969E61010C7A1ABC1A6F0136961D1E3B20B1A7326AC738FEF5C721479DFD948B52FDF2455E44813ECFD892",
+        b"08F239BA329B3967FE48D718A36CFE5F62A7E42E0BF1C1ED714150A166BFBD6BCF6B3B58B975B9EDEA56D53F23A0E849",
+    ];
+
+    /// Converts a point to little endian words, with each element padded to the given number of
+    /// words.
+    pub fn to_words(point: [&[u8]; 4], num_words_field_element: usize) -> Vec<u32> {
+        point
+            .iter()
+            .flat_map(|hex| {
+                let mut words = BigUint::parse_bytes(hex, 16).unwrap().to_u32_digits();
+                words.resize(num_words_field_element, 0);
+                words
+            })
+            .collect()
+    }
+
+    /// Builds a program that computes `G + 2G` on the G2 twist.
+    fn g2_add_program(
+        g: [&[u8]; 4],
+        g2: [&[u8]; 4],
+        num_words_field_element: usize,
+        syscall: SyscallCode,
+    ) -> Program {
+        let words = [
+            slice_at(P_PTR, &to_words(g, num_words_field_element)),
+            slice_at(Q_PTR, &to_words(g2, num_words_field_element)),
+        ]
+        .concat();
+        syscall_program(syscall, words, P_PTR, Q_PTR)
+    }
+
+    /// Runs the program and returns the point at `P_PTR`.
+    pub fn execute_g2_program(program: Program, num_words_field_element: usize) -> Vec<u32> {
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        (0..4 * num_words_field_element as u32).map(|i| runtime.word(P_PTR + i * 4)).collect()
+    }
+
+    #[test]
+    fn test_bn254_g2_add_execute() {
+        setup_logger();
+        let program = g2_add_program(BN254_G, BN254_G2, 8, SyscallCode::BN254_G2_ADD);
+        assert_eq!(execute_g2_program(program, 8), to_words(BN254_G3, 8));
+    }
+
+    #[test]
+    fn test_bn254_g2_add_prove() {
+        setup_logger();
+        let program = g2_add_program(BN254_G, BN254_G2, 8, SyscallCode::BN254_G2_ADD);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
+    #[test]
+    fn test_bls12381_g2_add_execute() {
+        setup_logger();
+        let program = g2_add_program(BLS12381_G, BLS12381_G2, 12, SyscallCode::BLS12381_G2_ADD);
+        assert_eq!(execute_g2_program(program, 12), to_words(BLS12381_G3, 12));
+    }
+
+    #[test]
+    fn test_bls12381_g2_add_prove() {
+        setup_logger();
+        let program = g2_add_program(BLS12381_G, BLS12381_G2, 12, SyscallCode::BLS12381_G2_ADD);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
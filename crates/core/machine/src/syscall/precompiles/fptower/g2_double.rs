@@ -0,0 +1,502 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::marker::PhantomData;
+
+use crate::air::MemoryAirBuilder;
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::{FieldType, FpOpField},
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{MachineAir, PaddingStrategy, SP1AirBuilder};
+use typenum::Unsigned;
+
+use super::g2_add::{decode_g2_point, eval_g2_point_write, fp2_limbs_from_prev_access};
+use crate::{
+    memory::MemoryWriteCols,
+    operations::field::field_op_fp2::{Fp2DivCols, Fp2Limbs, Fp2MulCols, Fp2OpCols},
+    utils::{pad_rows_with_strategy, par_generate_rows},
+};
+
+pub const fn num_g2_double_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<G2DoubleCols<u8, P>>()
+}
+
+/// A set of columns to compute the doubling of a point on a G2 twist `y^2 = x^3 + b` over
+/// `Fp2 = Fp[u]/(u^2 + 1)`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct G2DoubleCols<T, P: FieldParameters + NumWords> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub p_x_access: GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>,
+    pub p_y_access: GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>,
+    pub(crate) p_x_squared: Fp2MulCols<T, P>,
+    pub(crate) p_x_squared_times_2: Fp2OpCols<T, P>,
+    pub(crate) p_x_squared_times_3: Fp2OpCols<T, P>,
+    pub(crate) p_y_times_2: Fp2OpCols<T, P>,
+    pub(crate) slope: Fp2DivCols<T, P>,
+    pub(crate) slope_squared: Fp2MulCols<T, P>,
+    pub(crate) p_x_times_2: Fp2OpCols<T, P>,
+    pub(crate) x3_ins: Fp2OpCols<T, P>,
+    pub(crate) p_x_minus_x: Fp2OpCols<T, P>,
+    pub(crate) slope_times_p_x_minus_x: Fp2MulCols<T, P>,
+    pub(crate) y3_ins: Fp2OpCols<T, P>,
+}
+
+/// A chip that doubles a point on a G2 twist with the affine doubling law.
+///
+/// The slope is `3 * x^2 / (2 * y)`, which only holds for twists with `a = 0`. As with the G1
+/// doubling precompiles, the point must not be the point at infinity or have order two.
+#[derive(Default)]
+pub struct G2DoubleChip<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P: FpOpField> G2DoubleChip<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        cols: &mut G2DoubleCols<F, P>,
+        p: ([BigUint; 2], [BigUint; 2]),
+    ) {
+        let (p_x, p_y) = p;
+
+        // slope = 3 * p.x^2 / (2 * p.y).
+        let slope = {
+            let p_x_squared = cols.p_x_squared.populate(blu_events, shard, channel, &p_x, &p_x);
+            let p_x_squared_times_2 = cols.p_x_squared_times_2.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x_squared,
+                &p_x_squared,
+                FieldOperation::Add,
+            );
+            let p_x_squared_times_3 = cols.p_x_squared_times_3.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x_squared_times_2,
+                &p_x_squared,
+                FieldOperation::Add,
+            );
+            let p_y_times_2 = cols.p_y_times_2.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_y,
+                &p_y,
+                FieldOperation::Add,
+            );
+            cols.slope.populate(blu_events, shard, channel, &p_x_squared_times_3, &p_y_times_2)
+        };
+
+        // x = slope * slope - 2 * p.x.
+        let x = {
+            let slope_squared =
+                cols.slope_squared.populate(blu_events, shard, channel, &slope, &slope);
+            let p_x_times_2 = cols.p_x_times_2.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &p_x,
+                FieldOperation::Add,
+            );
+            cols.x3_ins.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope_squared,
+                &p_x_times_2,
+                FieldOperation::Sub,
+            )
+        };
+
+        // y = slope * (p.x - x) - p.y.
+        {
+            let p_x_minus_x = cols.p_x_minus_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &x,
+                FieldOperation::Sub,
+            );
+            let slope_times_p_x_minus_x = cols.slope_times_p_x_minus_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope,
+                &p_x_minus_x,
+            );
+            cols.y3_ins.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope_times_p_x_minus_x,
+                &p_y,
+                FieldOperation::Sub,
+            );
+        }
+    }
+}
+
+impl<F: PrimeField32, P: FpOpField> MachineAir<F> for G2DoubleChip<P> {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254G2DoubleAssign".to_string(),
+            FieldType::Bls12381 => "Bls12381G2DoubleAssign".to_string(),
+            _ => panic!("Unsupported field"),
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = match P::FIELD_TYPE {
+            FieldType::Bn254 => &input.bn254_g2_double_events,
+            FieldType::Bls12381 => &input.bls12381_g2_double_events,
+            _ => panic!("Unsupported field"),
+        };
+
+        let num_cols = num_g2_double_cols::<P>();
+        let num_words_coordinate = <P as NumWords>::WordsCurvePoint::USIZE;
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(events, |event, new_byte_lookup_events| {
+                let mut row = vec![F::zero(); num_cols];
+                let cols: &mut G2DoubleCols<F, P> = row.as_mut_slice().borrow_mut();
+
+                // Populate basic columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+
+                Self::populate_field_ops(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    cols,
+                    decode_g2_point(&event.p),
+                );
+
+                // Populate the memory access columns.
+                for i in 0..num_words_coordinate {
+                    cols.p_x_access[i].populate(
+                        event.channel,
+                        event.p_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.p_y_access[i].populate(
+                        event.channel,
+                        event.p_memory_records[i + num_words_coordinate],
+                        new_byte_lookup_events,
+                    );
+                }
+
+                row
+            });
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = vec![F::zero(); num_cols];
+            let cols: &mut G2DoubleCols<F, P> = row.as_mut_slice().borrow_mut();
+            let zero = || [BigUint::zero(), BigUint::zero()];
+            Self::populate_field_ops(&mut vec![], 0, 0, cols, (zero(), zero()));
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), num_cols);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut G2DoubleCols<F, P> =
+                trace.values[i * num_cols..(i + 1) * num_cols].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => !shard.bn254_g2_double_events.is_empty(),
+            FieldType::Bls12381 => !shard.bls12381_g2_double_events.is_empty(),
+            _ => panic!("Unsupported field"),
+        }
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F, P: FpOpField> BaseAir<F> for G2DoubleChip<P> {
+    fn width(&self) -> usize {
+        num_g2_double_cols::<P>()
+    }
+}
+
+impl<AB, P: FpOpField> Air<AB> for G2DoubleChip<P>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &G2DoubleCols<AB::Var, P> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &G2DoubleCols<AB::Var, P> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let p_x: Fp2Limbs<AB::Var, P> = fp2_limbs_from_prev_access::<_, P, _>(&local.p_x_access);
+        let p_y: Fp2Limbs<AB::Var, P> = fp2_limbs_from_prev_access::<_, P, _>(&local.p_y_access);
+
+        // slope = 3 * p.x^2 / (2 * p.y).
+        let slope = {
+            local.p_x_squared.eval(builder, &p_x, &p_x, local.shard, local.channel, local.is_real);
+
+            local.p_x_squared_times_2.eval(
+                builder,
+                &local.p_x_squared.result(),
+                &local.p_x_squared.result(),
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.p_x_squared_times_3.eval(
+                builder,
+                &local.p_x_squared_times_2.result(),
+                &local.p_x_squared.result(),
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.p_y_times_2.eval(
+                builder,
+                &p_y,
+                &p_y,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope.eval(
+                builder,
+                &local.p_x_squared_times_3.result(),
+                &local.p_y_times_2.result(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            &local.slope.result
+        };
+
+        // x = slope * slope - 2 * p.x.
+        let x = {
+            local.slope_squared.eval(
+                builder,
+                slope,
+                slope,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.p_x_times_2.eval(
+                builder,
+                &p_x,
+                &p_x,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.x3_ins.eval(
+                builder,
+                &local.slope_squared.result(),
+                &local.p_x_times_2.result(),
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.x3_ins.result()
+        };
+
+        // y = slope * (p.x - x) - p.y.
+        let y = {
+            local.p_x_minus_x.eval(
+                builder,
+                &p_x,
+                &x,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope_times_p_x_minus_x.eval(
+                builder,
+                slope,
+                &local.p_x_minus_x.result(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.y3_ins.eval(
+                builder,
+                &local.slope_times_p_x_minus_x.result(),
+                &p_y,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.y3_ins.result()
+        };
+
+        // Constrain p_access.value = [x.c0, x.c1, y.c0, y.c1], so that p is updated with the
+        // double.
+        eval_g2_point_write(builder, &x, &y, &local.p_x_access, &local.p_y_access, local.is_real);
+
+        let num_words_coordinate = <P as NumWords>::WordsCurvePoint::U32;
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.p_ptr,
+            &local.p_x_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.p_ptr + AB::F::from_canonical_u32(num_words_coordinate * 4),
+            &local.p_y_access,
+            local.is_real,
+        );
+
+        let syscall_id_felt = match P::FIELD_TYPE {
+            FieldType::Bn254 => {
+                AB::F::from_canonical_u32(SyscallCode::BN254_G2_DOUBLE.syscall_id())
+            }
+            FieldType::Bls12381 => {
+                AB::F::from_canonical_u32(SyscallCode::BLS12381_G2_DOUBLE.syscall_id())
+            }
+            _ => panic!("Unsupported field"),
+        };
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.p_ptr,
+            AB::Expr::zero(),
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Program};
+    use sp1_stark::CpuProver;
+
+    use super::super::g2_add::g2_tests::{
+        execute_g2_program, to_words, BLS12381_G, BLS12381_G2, BN254_G, BN254_G2, P_PTR,
+    };
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{slice_at, syscall_program},
+    };
+
+    /// Builds a program that computes `2G` on the G2 twist.
+    fn g2_double_program(
+        g: [&[u8]; 4],
+        num_words_field_element: usize,
+        syscall: SyscallCode,
+    ) -> Program {
+        let words = slice_at(P_PTR, &to_words(g, num_words_field_element));
+        syscall_program(syscall, words, P_PTR, 0)
+    }
+
+    #[test]
+    fn test_bn254_g2_double_execute() {
+        setup_logger();
+        let program = g2_double_program(BN254_G, 8, SyscallCode::BN254_G2_DOUBLE);
+        assert_eq!(execute_g2_program(program, 8), to_words(BN254_G2, 8));
+    }
+
+    #[test]
+    fn test_bn254_g2_double_prove() {
+        setup_logger();
+        let program = g2_double_program(BN254_G, 8, SyscallCode::BN254_G2_DOUBLE);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
+    #[test]
+    fn test_bls12381_g2_double_execute() {
+        setup_logger();
+        let program = g2_double_program(BLS12381_G, 12, SyscallCode::BLS12381_G2_DOUBLE);
+        assert_eq!(execute_g2_program(program, 12), to_words(BLS12381_G2, 12));
+    }
+
+    #[test]
+    fn test_bls12381_g2_double_prove() {
+        setup_logger();
+        let program = g2_double_program(BLS12381_G, 12, SyscallCode::BLS12381_G2_DOUBLE);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
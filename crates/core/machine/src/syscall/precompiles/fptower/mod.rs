@@ -1,24 +1,42 @@
+mod batch_inverse;
 mod fp;
 mod fp2_addsub;
 mod fp2_mul;
+mod g2_add;
+mod g2_double;
 
+pub use batch_inverse::*;
 pub use fp::*;
 pub use fp2_addsub::*;
 pub use fp2_mul::*;
+pub use g2_add::*;
+pub use g2_double::*;
 
 #[cfg(test)]
 mod tests {
-    use sp1_stark::CpuProver;
+    use num::BigUint;
+    use sp1_curves::{
+        params::FieldParameters,
+        weierstrass::{bls12_381::Bls12381BaseField, bn254::Bn254ScalarField},
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
 
     use sp1_core_executor::{
         programs::tests::{
             BLS12381_FP2_ADDSUB_ELF, BLS12381_FP2_MUL_ELF, BLS12381_FP_ELF, BN254_FP2_ADDSUB_ELF,
             BN254_FP2_MUL_ELF, BN254_FP_ELF,
         },
-        Program,
+        syscalls::SyscallCode,
+        Executor, Program,
     };
 
-    use crate::utils;
+    use crate::{
+        syscall::precompiles::uint::tests::{X_PTR, Y_PTR},
+        utils::{
+            self,
+            tests::{call_syscall, store_words, syscall_program, to_words, words_at},
+        },
+    };
 
     #[test]
     fn test_bls12381_fp() {
@@ -61,4 +79,111 @@ mod tests {
         let program = Program::from(BN254_FP2_MUL_ELF).unwrap();
         utils::run_test::<CpuProver<_, _>>(program).unwrap();
     }
+
+    /// Builds a program that applies the given BN254 scalar field syscall to x and y.
+    fn bn254_fr_program(syscall: SyscallCode, x: &BigUint, y: &BigUint) -> Program {
+        let words = words_at(X_PTR, 8, &[x]).into_iter().chain(words_at(Y_PTR, 8, &[y]));
+        syscall_program(syscall, words, X_PTR, Y_PTR)
+    }
+
+    /// Returns the syscalls of the BN254 scalar field chip with pairs of operands and the
+    /// expected results.
+    fn bn254_fr_cases() -> Vec<(SyscallCode, BigUint, BigUint, BigUint)> {
+        let r = Bn254ScalarField::modulus();
+        let x = &r - 5u32;
+        let y = &r - 7u32;
+        vec![
+            (SyscallCode::BN254_FR_ADD, x.clone(), y.clone(), (&x + &y) % &r),
+            (SyscallCode::BN254_FR_SUB, y.clone(), x.clone(), &r - 2u32),
+            (SyscallCode::BN254_FR_MUL, x.clone(), y.clone(), BigUint::from(35u32)),
+            (SyscallCode::BN254_FR_MUL, BigUint::from(3u32), BigUint::from(11u32), 33u32.into()),
+        ]
+    }
+
+    #[test]
+    fn test_bn254_fr_execute() {
+        utils::setup_logger();
+        for (syscall, x, y, expected) in bn254_fr_cases() {
+            let mut runtime =
+                Executor::new(bn254_fr_program(syscall, &x, &y), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> = (0..8).map(|i| runtime.word(X_PTR + i * 4)).collect();
+            assert_eq!(result, to_words(8, &expected));
+        }
+    }
+
+    #[test]
+    fn test_bn254_fr_prove() {
+        utils::setup_logger();
+        for (syscall, x, y, _) in bn254_fr_cases() {
+            utils::run_test::<CpuProver<_, _>>(bn254_fr_program(syscall, &x, &y)).unwrap();
+        }
+    }
+
+    /// Builds a program that stores each batch of elements of `num_words` words at `X_PTR` in
+    /// turn, and inverts it with the given batch inversion syscall.
+    fn batch_inverse_program(
+        syscall: SyscallCode,
+        num_words: usize,
+        batches: &[Vec<BigUint>],
+    ) -> Program {
+        let mut instructions = vec![];
+        for batch in batches {
+            let words = words_at(X_PTR, num_words, &batch.iter().collect::<Vec<_>>());
+            store_words(&mut instructions, words);
+            call_syscall(&mut instructions, syscall, X_PTR, batch.len() as u32);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    /// Returns a batch of BN254 scalar field elements with a zero element in the middle.
+    fn bn254_fr_batch() -> Vec<BigUint> {
+        let r = Bn254ScalarField::modulus();
+        vec![
+            BigUint::from(2u32),
+            &r - 1u32,
+            BigUint::from(0u32),
+            BigUint::from(0xdeadbeefu32) << 200,
+            BigUint::from(1u32),
+        ]
+    }
+
+    #[test]
+    fn test_bn254_fr_batch_inverse_execute() {
+        utils::setup_logger();
+        let r = Bn254ScalarField::modulus();
+        let batch = bn254_fr_batch();
+        let program = batch_inverse_program(SyscallCode::BN254_FR_BATCH_INV, 8, &[batch.clone()]);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        for (i, x) in batch.iter().enumerate() {
+            let ptr = X_PTR + i as u32 * 32;
+            let result: Vec<u32> = (0..8).map(|j| runtime.word(ptr + j * 4)).collect();
+            let expected = if *x == BigUint::from(0u32) {
+                BigUint::from(0u32)
+            } else {
+                x.modpow(&(&r - 2u32), &r)
+            };
+            assert_eq!(result, to_words(8, &expected));
+        }
+    }
+
+    #[test]
+    fn test_bn254_fr_batch_inverse_prove() {
+        utils::setup_logger();
+        let r = Bn254ScalarField::modulus();
+        let batches = [bn254_fr_batch(), vec![BigUint::from(0u32)], vec![&r - 2u32, &r - 3u32]];
+        let program = batch_inverse_program(SyscallCode::BN254_FR_BATCH_INV, 8, &batches);
+        utils::run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
+    #[test]
+    fn test_bls12381_fp_batch_inverse_prove() {
+        utils::setup_logger();
+        let p = Bls12381BaseField::modulus();
+        let batch = vec![BigUint::from(3u32), &p - 7u32, BigUint::from(0u32), &p >> 1];
+        let program = batch_inverse_program(SyscallCode::BLS12381_FP_BATCH_INV, 12, &[batch]);
+        utils::run_test::<CpuProver<_, _>>(program).unwrap();
+    }
 }
@@ -0,0 +1,364 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{syscalls::SyscallCode, ByteOpcode};
+use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
+
+use super::{
+    columns::{FriDecommitCols, NUM_FRI_DECOMMIT_COLS},
+    FriDecommitChip, FRI_DIGEST_WORDS, FRI_MAX_DEPTH, FRI_VALUES_OFFSET,
+};
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+    operations::IsZeroOperation,
+    syscall::precompiles::poseidon2::WIDTH,
+};
+
+impl<F> BaseAir<F> for FriDecommitChip {
+    fn width(&self) -> usize {
+        NUM_FRI_DECOMMIT_COLS
+    }
+}
+
+impl<AB> Air<AB> for FriDecommitChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &FriDecommitCols<AB::Var> = (*local).borrow();
+        let next: &FriDecommitCols<AB::Var> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans a variable number of rows, so the nonce counts the
+        // syscalls rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_syscall_start, next.nonce);
+
+        self.eval_flags(builder, local, next);
+
+        self.eval_inputs(builder, local);
+
+        self.eval_absorb(builder, local, next);
+
+        self.eval_levels(builder, local, next);
+
+        // The permutation is not gated, so the padding rows hold the permutation of zero.
+        local.permutation.eval(builder);
+
+        self.eval_result(builder, local);
+    }
+}
+
+impl FriDecommitChip {
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &FriDecommitCols<AB::Var>,
+        next: &FriDecommitCols<AB::Var>,
+    ) {
+        builder.assert_bool(local.is_absorb);
+        builder.assert_bool(local.is_compress);
+        builder.assert_bool(local.is_syscall_start);
+        builder.assert_eq(local.is_real, local.is_absorb + local.is_compress);
+        builder.assert_bool(local.is_real);
+        builder.assert_eq(local.is_syscall_end, local.is_compress * local.is_last_level.result);
+
+        // The level is the last one when there are no levels left after it.
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.levels_left - AB::Expr::one(),
+            local.is_last_level,
+            local.is_compress.into(),
+        );
+
+        // The chunk is the last one when it absorbs all the values left.
+        let num_absorbed = local.value_flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag);
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.values_left - num_absorbed,
+            local.is_last_chunk,
+            local.is_absorb.into(),
+        );
+
+        // A syscall starts by absorbing values in the first real row and in the real row after the
+        // end of a syscall, and continues until its end.
+        builder.when(local.is_syscall_start).assert_one(local.is_absorb);
+        builder.when_first_row().assert_eq(local.is_syscall_start, local.is_real);
+        builder
+            .when_transition()
+            .when(local.is_syscall_end)
+            .assert_eq(next.is_syscall_start, next.is_real);
+        let mut transition_builder = builder.when_transition();
+        let mut in_syscall_builder = transition_builder.when(local.is_real - local.is_syscall_end);
+        in_syscall_builder.assert_one(next.is_real);
+        in_syscall_builder.assert_zero(next.is_syscall_start);
+        in_syscall_builder.assert_eq(local.shard, next.shard);
+        in_syscall_builder.assert_eq(local.channel, next.channel);
+        in_syscall_builder.assert_eq(local.clk, next.clk);
+        in_syscall_builder.assert_eq(local.ptr, next.ptr);
+        in_syscall_builder.assert_eq(local.depth, next.depth);
+        for i in 0..FRI_DIGEST_WORDS {
+            in_syscall_builder
+                .assert_word_eq(*local.root_memory[i].value(), *next.root_memory[i].value());
+        }
+
+        // Once the is_real flag is changed to false, it should not be changed back.
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+
+        // The table cannot end in the middle of a syscall.
+        builder.when_last_row().when(local.is_real).assert_one(local.is_syscall_end);
+
+        // The values are absorbed until the last chunk, and then the levels are compressed until
+        // the end of the syscall.
+        builder
+            .when_transition()
+            .when(local.is_absorb)
+            .assert_eq(next.is_compress, local.is_last_chunk.result);
+        builder
+            .when_transition()
+            .when(local.is_compress - local.is_syscall_end)
+            .assert_one(next.is_compress);
+    }
+
+    /// Constrains the reads of the inputs and the write of the result.
+    fn eval_inputs<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &FriDecommitCols<AB::Var>) {
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr,
+            &local.index_memory,
+            local.is_syscall_start,
+        );
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr + AB::Expr::from_canonical_u32(4),
+            &local.num_values_memory,
+            local.is_syscall_start,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr + AB::Expr::from_canonical_u32(12),
+            &local.root_memory,
+            local.is_syscall_start,
+        );
+        for j in 0..FRI_DIGEST_WORDS {
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk,
+                local.values_ptr + AB::Expr::from_canonical_usize(4 * j),
+                &local.value_memory[j],
+                local.value_flags[j],
+            );
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.sibling_ptr,
+            &local.sibling_memory,
+            local.is_compress,
+        );
+
+        // The clk increments by 1 before the result is written.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk + AB::Expr::one(),
+            local.ptr + AB::Expr::from_canonical_u32(8),
+            &local.result_memory,
+            local.is_syscall_end,
+        );
+
+        // The depth is between 1 and the number of index bits.
+        builder.send_byte(
+            ByteOpcode::LTU.as_field::<AB::F>(),
+            AB::F::one(),
+            local.depth - AB::Expr::one(),
+            AB::F::from_canonical_usize(FRI_MAX_DEPTH),
+            local.shard,
+            local.channel,
+            local.is_syscall_start,
+        );
+
+        // Receive the arguments in the first row of each syscall.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::FRI_DECOMMIT.syscall_id()),
+            local.ptr,
+            local.depth,
+            local.is_syscall_start,
+        );
+    }
+
+    /// Constrains the values absorbed by each row, and the input of the permutation of the rows
+    /// that absorb values.
+    fn eval_absorb<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &FriDecommitCols<AB::Var>,
+        next: &FriDecommitCols<AB::Var>,
+    ) {
+        // The flags are a nonempty prefix in the rows that absorb values, and they are all set
+        // unless it is the last chunk.
+        for j in 0..FRI_DIGEST_WORDS {
+            builder.assert_bool(local.value_flags[j]);
+            builder.when_not(local.is_absorb).assert_zero(local.value_flags[j]);
+        }
+        for j in 0..FRI_DIGEST_WORDS - 1 {
+            builder.when(local.value_flags[j + 1]).assert_one(local.value_flags[j]);
+        }
+        builder.when(local.is_absorb).assert_one(local.value_flags[0]);
+        builder
+            .when(local.is_absorb)
+            .when_not(local.is_last_chunk.result)
+            .assert_one(local.value_flags[FRI_DIGEST_WORDS - 1]);
+
+        // The sponge starts from the zero state, with all the values ahead of it.
+        let mut start_builder = builder.when(local.is_syscall_start);
+        start_builder.assert_eq(local.values_left, local.num_values_memory.value().reduce::<AB>());
+        start_builder.assert_eq(
+            local.values_ptr,
+            local.ptr + AB::Expr::from_canonical_u32(FRI_VALUES_OFFSET),
+        );
+        let input = local.permutation.external_rounds_state[0];
+        for j in 0..FRI_DIGEST_WORDS {
+            let value = local.value_memory[j].value().reduce::<AB>();
+            start_builder.assert_eq(input[j], local.value_flags[j] * value);
+        }
+        for &x in &input[FRI_DIGEST_WORDS..] {
+            start_builder.assert_zero(x);
+        }
+
+        // The next chunk overwrites the start of the output of the permutation with its values.
+        let mut transition_builder = builder.when_transition();
+        let mut next_chunk_builder =
+            transition_builder.when(next.is_absorb - next.is_syscall_start);
+        let num_absorbed = local.value_flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag);
+        next_chunk_builder.assert_eq(next.values_left, local.values_left - num_absorbed);
+        next_chunk_builder.assert_eq(
+            next.values_ptr,
+            local.values_ptr + AB::Expr::from_canonical_usize(4 * FRI_DIGEST_WORDS),
+        );
+        let next_input = next.permutation.external_rounds_state[0];
+        let output = local.permutation.output_state;
+        for j in 0..FRI_DIGEST_WORDS {
+            let flag = next.value_flags[j];
+            let value = next.value_memory[j].value().reduce::<AB>();
+            next_chunk_builder.assert_eq(next_input[j], output[j] + flag * (value - output[j]));
+        }
+        for j in FRI_DIGEST_WORDS..WIDTH {
+            next_chunk_builder.assert_eq(next_input[j], output[j]);
+        }
+    }
+
+    /// Constrains the sibling and the index bits of each level, and the input of the permutation
+    /// that compresses it.
+    fn eval_levels<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &FriDecommitCols<AB::Var>,
+        next: &FriDecommitCols<AB::Var>,
+    ) {
+        for &bit in local.index_bits.iter() {
+            builder.assert_bool(bit);
+        }
+
+        // The path starts with all the levels and index bits ahead of it, and the siblings after
+        // the values.
+        let mut start_builder = builder.when(local.is_syscall_start);
+        start_builder.assert_eq(local.levels_left, local.depth);
+        start_builder.assert_eq(
+            local.sibling_ptr,
+            local.ptr
+                + AB::Expr::from_canonical_u32(FRI_VALUES_OFFSET)
+                + local.num_values_memory.value().reduce::<AB>() * AB::F::from_canonical_u32(4),
+        );
+        let index = local.index_memory.value();
+        for (i, bits) in local.index_bits.chunks_exact(8).enumerate() {
+            let byte = bits.iter().enumerate().fold(AB::Expr::zero(), |acc, (j, &bit)| {
+                acc + bit * AB::F::from_canonical_u32(1 << j)
+            });
+            start_builder.assert_eq(index[i], byte);
+        }
+
+        // The path does not move while the values are absorbed.
+        let mut transition_builder = builder.when_transition();
+        let mut absorb_builder = transition_builder.when(local.is_absorb);
+        absorb_builder.assert_eq(local.levels_left, next.levels_left);
+        absorb_builder.assert_eq(local.sibling_ptr, next.sibling_ptr);
+        for i in 0..FRI_MAX_DEPTH {
+            absorb_builder.assert_eq(local.index_bits[i], next.index_bits[i]);
+        }
+
+        // The next level has the next sibling and the index bits shifted by one bit.
+        let mut transition_builder = builder.when_transition();
+        let mut next_level_builder =
+            transition_builder.when(local.is_compress - local.is_syscall_end);
+        for i in 0..FRI_MAX_DEPTH - 1 {
+            next_level_builder.assert_eq(next.index_bits[i], local.index_bits[i + 1]);
+        }
+        next_level_builder.assert_zero(next.index_bits[FRI_MAX_DEPTH - 1]);
+        next_level_builder.assert_eq(next.levels_left, local.levels_left - AB::Expr::one());
+        next_level_builder.assert_eq(
+            next.sibling_ptr,
+            local.sibling_ptr + AB::Expr::from_canonical_usize(4 * FRI_DIGEST_WORDS),
+        );
+
+        // Each level compresses the digest of the previous row with the sibling, in the order
+        // given by the index bit.
+        let mut transition_builder = builder.when_transition();
+        let mut compress_builder = transition_builder.when(next.is_compress);
+        let next_input = next.permutation.external_rounds_state[0];
+        let node = local.permutation.output_state;
+        let is_right = next.index_bits[0];
+        for i in 0..FRI_DIGEST_WORDS {
+            let sibling = next.sibling_memory[i].value().reduce::<AB>();
+            compress_builder
+                .assert_eq(next_input[i], node[i] + is_right * (sibling.clone() - node[i]));
+            compress_builder.assert_eq(
+                next_input[FRI_DIGEST_WORDS + i],
+                sibling.clone() + is_right * (node[i] - sibling),
+            );
+        }
+    }
+
+    /// Constrains the comparison of the computed root with the expected root, and the result
+    /// written to memory.
+    fn eval_result<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &FriDecommitCols<AB::Var>) {
+        builder.assert_bool(local.root_matches);
+
+        // The roots differ if and only if some difference has an inverse.
+        let mut diff_dot_inv = AB::Expr::zero();
+        for i in 0..FRI_DIGEST_WORDS {
+            let computed = local.permutation.output_state[i];
+            let expected = local.root_memory[i].value().reduce::<AB>();
+            let diff = computed - expected;
+            diff_dot_inv += diff.clone() * local.root_diff_inv[i];
+            builder.when(local.is_syscall_end).when(local.root_matches).assert_zero(diff);
+        }
+        builder.assert_eq(local.root_diff_dot_inv, diff_dot_inv);
+        builder
+            .when(local.is_syscall_end)
+            .when_not(local.root_matches)
+            .assert_one(local.root_diff_dot_inv);
+
+        let result = local.result_memory.value();
+        builder.when(local.is_syscall_end).assert_eq(result[0], local.root_matches);
+        for &byte in &result.0[1..] {
+            builder.when(local.is_syscall_end).assert_zero(byte);
+        }
+    }
+}
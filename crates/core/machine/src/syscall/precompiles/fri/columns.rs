@@ -0,0 +1,94 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::IsZeroOperation,
+    syscall::precompiles::poseidon2::columns::Poseidon2PermutationCols,
+};
+
+use super::{FRI_DIGEST_WORDS, FRI_MAX_DEPTH};
+
+pub const NUM_FRI_DECOMMIT_COLS: usize = size_of::<FriDecommitCols<u8>>();
+
+/// A set of columns needed to hash the leaf values of a FRI query and authenticate them.
+///
+/// Each row is one Poseidon2 permutation. The first rows of a syscall absorb the values, eight at a
+/// time, and the following rows compress the digest with the sibling of each level of the path.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct FriDecommitCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub ptr: T,
+    pub depth: T,
+
+    /// Memory columns for the inputs, which are read in the first row of the syscall.
+    pub index_memory: MemoryReadCols<T>,
+    pub num_values_memory: MemoryReadCols<T>,
+    pub root_memory: [MemoryReadCols<T>; FRI_DIGEST_WORDS],
+
+    /// Memory column for the result, which is written in the last row of the syscall.
+    pub result_memory: MemoryWriteCols<T>,
+
+    /// The number of values left to absorb, including the ones of this row.
+    pub values_left: T,
+
+    /// Which of the values of this row are absorbed. The set flags are a prefix.
+    pub value_flags: [T; FRI_DIGEST_WORDS],
+
+    /// Whether this row absorbs the last values.
+    pub is_last_chunk: IsZeroOperation<T>,
+
+    /// The pointer to the values of this row.
+    pub values_ptr: T,
+
+    /// Memory columns for the values of this row.
+    pub value_memory: [MemoryReadCols<T>; FRI_DIGEST_WORDS],
+
+    /// The number of levels left in the path, including this one.
+    pub levels_left: T,
+
+    /// Whether this is the last level of the path.
+    pub is_last_level: IsZeroOperation<T>,
+
+    /// The pointer to the sibling of this level.
+    pub sibling_ptr: T,
+
+    /// Memory columns for the sibling of this level.
+    pub sibling_memory: [MemoryReadCols<T>; FRI_DIGEST_WORDS],
+
+    /// The bits of the query index for this level and the ones above it. The first bit is set if
+    /// the node is the right child.
+    pub index_bits: [T; FRI_MAX_DEPTH],
+
+    /// The permutation of this row. The digest is the start of its output.
+    pub permutation: Poseidon2PermutationCols<T>,
+
+    /// Whether the computed root is equal to the expected root.
+    pub root_matches: T,
+
+    /// Inverses of the differences between the computed root and the expected root, with one
+    /// nonzero difference inverted when the roots differ.
+    pub root_diff_inv: [T; FRI_DIGEST_WORDS],
+
+    /// The sum of the products of the differences and their inverses.
+    pub root_diff_dot_inv: T,
+
+    /// If the row is real and absorbs values.
+    pub is_absorb: T,
+
+    /// If the row is real and compresses a level of the path.
+    pub is_compress: T,
+
+    /// If the row is real and the first row of the syscall.
+    pub is_syscall_start: T,
+
+    /// If the row is real and the last row of the syscall.
+    pub is_syscall_end: T,
+
+    pub is_real: T,
+}
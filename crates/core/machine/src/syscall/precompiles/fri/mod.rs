@@ -0,0 +1,136 @@
+mod air;
+mod columns;
+mod trace;
+
+/// The maximum number of levels of a path, which is the number of bits of the query index.
+pub const FRI_MAX_DEPTH: usize = 32;
+
+/// The number of words in a digest, which is also the number of values absorbed per permutation.
+pub const FRI_DIGEST_WORDS: usize = 8;
+
+/// The maximum number of leaf values.
+pub const FRI_MAX_VALUES: usize = 256;
+
+/// The maximum number of rows of a syscall: one per chunk of values and one per level.
+pub const FRI_MAX_ROWS: usize = FRI_MAX_VALUES / FRI_DIGEST_WORDS + FRI_MAX_DEPTH;
+
+/// The offset of the values from the pointer, after the query index, the number of values, the
+/// result and the root.
+pub const FRI_VALUES_OFFSET: u32 = 44;
+
+/// Implements the decommitment of a FRI query against a commitment of the recursion prover.
+///
+/// The first input to the syscall is a pointer to the query index, followed by the number of leaf
+/// values, a word for the result, the 8 word root, the leaf values and the 8 word siblings from the
+/// leaf level up. The second input is the depth of the path, between 1 and 32. The words are
+/// BabyBear elements, which are reduced before they are hashed or compared.
+///
+/// The leaf is hashed with the padding-free Poseidon2 sponge of the recursion Merkle trees, which
+/// overwrites the first 8 elements of the state with each chunk of values and permutes it. Bit `i`
+/// of the index is set if the node at level `i` is the right child, and the parent is the first 8
+/// elements of the permutation of the left child followed by the right child. The result is one if
+/// the computed root is equal to the root, and zero otherwise.
+///
+/// In the AIR, each row is one permutation, so a syscall takes up one row per chunk of values and
+/// one row per level.
+#[derive(Default)]
+pub struct FriDecommitChip;
+
+impl FriDecommitChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod fri_tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::{AbstractField, PrimeField32};
+    use p3_symmetric::Permutation;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_primitives::{poseidon2_hash, poseidon2_init};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    const PTR: u32 = 100;
+
+    /// Computes the root of the path.
+    fn compute_root(values: &[u32], index: u32, siblings: &[[u32; 8]]) -> [u32; 8] {
+        let perm = poseidon2_init();
+        let mut node =
+            poseidon2_hash(values.iter().map(|&v| BabyBear::from_wrapped_u32(v)).collect());
+        for (level, sibling) in siblings.iter().enumerate() {
+            let sibling = sibling.map(BabyBear::from_wrapped_u32);
+            let (left, right) =
+                if (index >> level) & 1 == 1 { (sibling, node) } else { (node, sibling) };
+            let mut state = [BabyBear::zero(); 16];
+            state[..8].copy_from_slice(&left);
+            state[8..].copy_from_slice(&right);
+            node = perm.permute(state)[..8].try_into().unwrap();
+        }
+        node.map(|x| x.as_canonical_u32())
+    }
+
+    /// Returns a program that decommits the given queries, where each query is an index, the
+    /// values, the root and the siblings.
+    pub fn fri_decommit_program(queries: &[(u32, Vec<u32>, [u32; 8], Vec<[u32; 8]>)]) -> Program {
+        let mut instructions = vec![];
+        for (index, values, root, siblings) in queries {
+            let mut words = vec![*index, values.len() as u32, 0];
+            words.extend(root);
+            words.extend(values);
+            words.extend(siblings.iter().flatten());
+            store_words(&mut instructions, slice_at(PTR, &words));
+            call_syscall(&mut instructions, SyscallCode::FRI_DECOMMIT, PTR, siblings.len() as u32);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    /// Returns a valid query with the given number of values and depth, and the same query with a
+    /// wrong root.
+    fn queries(
+        num_values: usize,
+        depth: usize,
+        index: u32,
+    ) -> Vec<(u32, Vec<u32>, [u32; 8], Vec<[u32; 8]>)> {
+        let values =
+            (0..num_values).map(|i| 0x7000_0000 + i as u32 * 0x0101_0101).collect::<Vec<_>>();
+        let siblings = (0..depth)
+            .map(|level| {
+                core::array::from_fn(|i| ((level * 8 + i) as u32).wrapping_mul(0x1234_5678))
+            })
+            .collect::<Vec<[u32; 8]>>();
+        let root = compute_root(&values, index, &siblings);
+        let mut wrong_root = root;
+        wrong_root[7] ^= 1;
+        vec![(index, values.clone(), root, siblings.clone()), (index, values, wrong_root, siblings)]
+    }
+
+    #[test]
+    fn test_fri_decommit_execute() {
+        setup_logger();
+        for (num_values, depth, index) in
+            [(1, 1, 0), (8, 1, 1), (9, 3, 0b101), (20, 32, 0xa5a5_5a5a)]
+        {
+            for (i, query) in queries(num_values, depth, index).into_iter().enumerate() {
+                let program = fri_decommit_program(&[query]);
+                let mut runtime = Executor::new(program, SP1CoreOpts::default());
+                runtime.run().unwrap();
+                assert_eq!(runtime.word(PTR + 8), (i == 0) as u32);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fri_decommit_prove() {
+        setup_logger();
+        let mut queries_list = queries(4, 2, 0b10);
+        queries_list.extend(queries(17, 3, 0b011));
+        let program = fri_decommit_program(&queries_list);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
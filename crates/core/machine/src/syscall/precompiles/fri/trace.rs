@@ -0,0 +1,206 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FriDecommitEvent},
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_stark::air::{MachineAir, PaddingStrategy};
+
+use super::{
+    columns::{FriDecommitCols, NUM_FRI_DECOMMIT_COLS},
+    FriDecommitChip, FRI_DIGEST_WORDS, FRI_MAX_DEPTH, FRI_VALUES_OFFSET,
+};
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    syscall::precompiles::poseidon2::WIDTH,
+    utils::{pad_rows_with_strategy, par_generate_rows},
+};
+
+impl<F: PrimeField32> MachineAir<F> for FriDecommitChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "FriDecommit".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(&input.fri_decommit_events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // The permutation is constrained in every row, so the padded rows permute the zero state.
+        let mut padding_row = [F::zero(); NUM_FRI_DECOMMIT_COLS];
+        let cols: &mut FriDecommitCols<F> = padding_row.as_mut_slice().borrow_mut();
+        cols.permutation.populate([F::zero(); WIDTH]);
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| padding_row);
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_FRI_DECOMMIT_COLS,
+        );
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut FriDecommitCols<F> = trace.values
+                [i * NUM_FRI_DECOMMIT_COLS..(i + 1) * NUM_FRI_DECOMMIT_COLS]
+                .borrow_mut();
+            if i > 0 && cols.is_syscall_start == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.fri_decommit_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl FriDecommitChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &FriDecommitEvent,
+        rows: &mut Vec<[F; NUM_FRI_DECOMMIT_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+        let depth = event.depth as usize;
+        let num_values = event.num_values as usize;
+
+        // The inputs are only accessed in the first row, but the root is compared in the last row,
+        // so the memory columns are populated once and copied into every row of the syscall.
+        let mut index_memory = MemoryReadCols::<F>::default();
+        index_memory.populate(channel, event.index_memory_record, blu);
+        let mut num_values_memory = MemoryReadCols::<F>::default();
+        num_values_memory.populate(channel, event.num_values_memory_record, blu);
+        let mut root_memory = [MemoryReadCols::<F>::default(); FRI_DIGEST_WORDS];
+        for j in 0..FRI_DIGEST_WORDS {
+            root_memory[j].populate(channel, event.root_memory_records[j], blu);
+        }
+        let mut result_memory = MemoryWriteCols::<F>::default();
+        result_memory.populate(channel, event.result_memory_record, blu);
+        blu.add_byte_lookup_event(ByteLookupEvent {
+            shard,
+            channel,
+            opcode: ByteOpcode::LTU,
+            a1: 1,
+            a2: 0,
+            b: (event.depth - 1) as u8,
+            c: FRI_MAX_DEPTH as u8,
+        });
+
+        let values_ptr = event.ptr + FRI_VALUES_OFFSET;
+        let siblings_ptr = values_ptr + 4 * event.num_values;
+        let num_chunks = num_values.div_ceil(FRI_DIGEST_WORDS);
+        let expected_root = event.root.map(F::from_wrapped_u32);
+
+        let mut state = [F::zero(); WIDTH];
+        for row_index in 0..num_chunks + depth {
+            let mut row = [F::zero(); NUM_FRI_DECOMMIT_COLS];
+            let cols: &mut FriDecommitCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.shard = F::from_canonical_u32(shard);
+            cols.channel = F::from_canonical_u8(channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.ptr = F::from_canonical_u32(event.ptr);
+            cols.depth = F::from_canonical_u32(event.depth);
+
+            cols.index_memory = index_memory;
+            cols.num_values_memory = num_values_memory;
+            cols.root_memory = root_memory;
+            cols.result_memory = result_memory;
+
+            let is_absorb = row_index < num_chunks;
+            if is_absorb {
+                // Overwrite the start of the state with the values of the chunk.
+                let chunk = row_index;
+                let start = chunk * FRI_DIGEST_WORDS;
+                let end = num_values.min(start + FRI_DIGEST_WORDS);
+                cols.values_left = F::from_canonical_usize(num_values - start);
+                cols.is_last_chunk.populate((num_values - end) as u32);
+                cols.values_ptr = F::from_canonical_u32(values_ptr + 4 * start as u32);
+                for (j, k) in (start..end).enumerate() {
+                    cols.value_flags[j] = F::one();
+                    cols.value_memory[j].populate(channel, event.value_memory_records[k], blu);
+                    state[j] = F::from_wrapped_u32(event.values[k]);
+                }
+
+                // The path is the same as in the first level.
+                cols.levels_left = F::from_canonical_usize(depth);
+                cols.sibling_ptr = F::from_canonical_u32(siblings_ptr);
+                for (k, bit) in cols.index_bits.iter_mut().enumerate() {
+                    *bit = F::from_canonical_u32((event.index >> k) & 1);
+                }
+            } else {
+                // Compress the digest with the sibling of the level.
+                let level = row_index - num_chunks;
+                let index = event.index >> level;
+                cols.levels_left = F::from_canonical_usize(depth - level);
+                cols.is_last_level.populate((depth - level - 1) as u32);
+                cols.sibling_ptr =
+                    F::from_canonical_u32(siblings_ptr + (level * 4 * FRI_DIGEST_WORDS) as u32);
+                for (k, bit) in cols.index_bits.iter_mut().enumerate() {
+                    *bit = F::from_canonical_u32((index >> k) & 1);
+                }
+                for j in 0..FRI_DIGEST_WORDS {
+                    let record = event.sibling_memory_records[level * FRI_DIGEST_WORDS + j];
+                    cols.sibling_memory[j].populate(channel, record, blu);
+                }
+
+                let node: [F; FRI_DIGEST_WORDS] = state[..FRI_DIGEST_WORDS].try_into().unwrap();
+                let sibling = event.siblings[level].map(F::from_wrapped_u32);
+                let (left, right) = if index & 1 == 1 { (sibling, node) } else { (node, sibling) };
+                state[..FRI_DIGEST_WORDS].copy_from_slice(&left);
+                state[FRI_DIGEST_WORDS..].copy_from_slice(&right);
+            }
+
+            state = cols.permutation.populate(state);
+
+            let is_syscall_start = row_index == 0;
+            let is_syscall_end = row_index == num_chunks + depth - 1;
+            if is_syscall_end {
+                cols.root_matches = F::from_bool(event.root_matches);
+                let computed_root: [F; FRI_DIGEST_WORDS] =
+                    state[..FRI_DIGEST_WORDS].try_into().unwrap();
+                debug_assert_eq!(computed_root == expected_root, event.root_matches);
+                if let Some(j) =
+                    (0..FRI_DIGEST_WORDS).find(|&j| computed_root[j] != expected_root[j])
+                {
+                    cols.root_diff_inv[j] = (computed_root[j] - expected_root[j]).inverse();
+                    cols.root_diff_dot_inv = F::one();
+                }
+            }
+
+            cols.is_absorb = F::from_bool(is_absorb);
+            cols.is_compress = F::from_bool(!is_absorb);
+            cols.is_syscall_start = F::from_bool(is_syscall_start);
+            cols.is_syscall_end = F::from_bool(is_syscall_end);
+            cols.is_real = F::one();
+
+            rows.push(row);
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use std::borrow::Borrow;
+
+use num::BigUint;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{events::FieldOperation, syscalls::SyscallCode, ByteOpcode};
+use sp1_curves::{
+    params::{FieldParameters, Limbs},
+    uint32::U32Field,
+};
+use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
+
+use super::{
+    columns::{LagrangeInterpCols, WordLimbs, NUM_LAGRANGE_INTERP_COLS},
+    LagrangeInterpChip, LAGRANGE_INTERP_MAX_POINTS,
+};
+use crate::{air::MemoryAirBuilder, operations::IsZeroOperation, utils::limbs_from_access};
+
+impl<F> BaseAir<F> for LagrangeInterpChip {
+    fn width(&self) -> usize {
+        NUM_LAGRANGE_INTERP_COLS
+    }
+}
+
+impl<AB> Air<AB> for LagrangeInterpChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, WordLimbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &LagrangeInterpCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &LagrangeInterpCols<AB::Var> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans a variable number of rows, so the nonce counts the
+        // syscalls rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_first, next.nonce);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+
+        // A block ends in the row of its last point, and the syscall ends at the end of the block
+        // of its last point. Since `i` and `j` start from zero and only go up, they stay below the
+        // number of points.
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.i - local.j,
+            local.is_diagonal,
+            local.is_real.into(),
+        );
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.j + AB::Expr::one() - local.len,
+            local.is_block_end,
+            local.is_real.into(),
+        );
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.i + AB::Expr::one() - local.len,
+            local.is_last_block,
+            local.is_real.into(),
+        );
+        builder
+            .when(local.is_real)
+            .assert_eq(local.is_last, local.is_block_end.result * local.is_last_block.result);
+
+        let one = U32Field::to_limbs_field::<AB::Expr, _>(&BigUint::from(1u32));
+        let zero = U32Field::to_limbs_field::<AB::Expr, _>(&BigUint::from(0u32));
+
+        // A syscall starts in the first real row and in the real row after the end of a syscall.
+        // From one row to the next, the block goes on with the next point, or the next block
+        // starts from the first point with the term of the block added to the fraction.
+        builder.when_first_row().assert_eq(local.is_first, local.is_real);
+        builder.when_transition().when(local.is_last).assert_eq(next.is_first, next.is_real);
+        let mut transition_builder = builder.when_transition();
+        let mut in_syscall_builder = transition_builder.when(local.is_real - local.is_last);
+        in_syscall_builder.assert_one(next.is_real);
+        in_syscall_builder.assert_zero(next.is_first);
+        in_syscall_builder.assert_eq(local.shard, next.shard);
+        in_syscall_builder.assert_eq(local.channel, next.channel);
+        in_syscall_builder.assert_eq(local.clk, next.clk);
+        in_syscall_builder.assert_eq(local.points_ptr, next.points_ptr);
+        in_syscall_builder.assert_eq(local.params_ptr, next.params_ptr);
+        in_syscall_builder.assert_eq(local.len, next.len);
+        in_syscall_builder.assert_all_eq(local.target, next.target);
+        in_syscall_builder.assert_all_eq(local.modulus, next.modulus);
+
+        let mut in_block_builder =
+            in_syscall_builder.when(AB::Expr::one() - local.is_block_end.result);
+        in_block_builder.assert_eq(next.i, local.i);
+        in_block_builder.assert_eq(next.j, local.j + AB::Expr::one());
+        in_block_builder.assert_all_eq(next.x_i, local.x_i);
+        in_block_builder.assert_all_eq(next.y_i, local.y_i);
+        in_block_builder.assert_all_eq(next.num, local.num_product.result);
+        in_block_builder.assert_all_eq(next.den, local.den_product.result);
+        in_block_builder.assert_all_eq(next.acc_num, local.acc_num);
+        in_block_builder.assert_all_eq(next.acc_den, local.acc_den);
+
+        let mut block_end_builder = in_syscall_builder.when(local.is_block_end.result);
+        block_end_builder.assert_eq(next.i, local.i + AB::Expr::one());
+        block_end_builder.assert_zero(next.j);
+        block_end_builder.assert_all_eq(next.num, one.clone());
+        block_end_builder.assert_all_eq(next.den, one.clone());
+        block_end_builder.assert_all_eq(next.acc_num, local.next_num.result);
+        block_end_builder.assert_all_eq(next.acc_den, local.next_den.result);
+
+        // Once the is_real flag is changed to false, it should not be changed back.
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+
+        // The table cannot end in the middle of a syscall.
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+
+        // Read the parameters in the first row. The number of points is between 1 and 32, so it is
+        // its least significant byte, and one less than it is a byte below 32. The first block
+        // starts from empty products and a fraction of zero.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.params_ptr,
+            &local.params_memory,
+            local.is_first,
+        );
+        let len = local.params_memory[0].access.value;
+        for &byte in &len.0[1..] {
+            builder.when(local.is_first).assert_zero(byte);
+        }
+        builder.when(local.is_first).assert_eq(len[0], local.len);
+        builder.send_byte(
+            ByteOpcode::LTU.as_field::<AB::F>(),
+            AB::F::one(),
+            len[0] - AB::Expr::one(),
+            AB::F::from_canonical_usize(LAGRANGE_INTERP_MAX_POINTS),
+            local.shard,
+            local.channel,
+            local.is_first,
+        );
+        let target: Limbs<AB::Var, WordLimbs> = limbs_from_access(&local.params_memory[1..2]);
+        let modulus: Limbs<AB::Var, WordLimbs> = limbs_from_access(&local.params_memory[2..3]);
+        builder.when(local.is_first).assert_all_eq(local.target, target);
+        builder.when(local.is_first).assert_all_eq(local.modulus, modulus);
+        builder.when(local.is_first).assert_zero(local.i);
+        builder.when(local.is_first).assert_zero(local.j);
+        builder.when(local.is_first).assert_all_eq(local.num, one.clone());
+        builder.when(local.is_first).assert_all_eq(local.den, one.clone());
+        builder.when(local.is_first).assert_all_eq(local.acc_num, zero);
+        builder.when(local.is_first).assert_all_eq(local.acc_den, one.clone());
+
+        // Read the point of the row, one clk later in each block. The diagonal row reads the point
+        // of the block.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + local.i + AB::Expr::one(),
+            local.points_ptr + local.j * AB::F::from_canonical_u32(8),
+            &local.point_memory,
+            local.is_real,
+        );
+        let x_j: Limbs<AB::Var, WordLimbs> = limbs_from_access(&local.point_memory[0..1]);
+        let y_j: Limbs<AB::Var, WordLimbs> = limbs_from_access(&local.point_memory[1..2]);
+        builder.when(local.is_diagonal.result).assert_all_eq(local.x_i, x_j);
+        builder.when(local.is_diagonal.result).assert_all_eq(local.y_i, y_j);
+
+        // Multiply the factor of the row into the products of the block, or one in the diagonal
+        // row.
+        local.num_factor.eval_with_modulus(
+            builder,
+            &local.target,
+            &x_j,
+            &local.modulus,
+            FieldOperation::Sub,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.den_factor.eval_with_modulus(
+            builder,
+            &local.x_i,
+            &x_j,
+            &local.modulus,
+            FieldOperation::Sub,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        let mut diagonal_builder = builder.when(local.is_diagonal.result);
+        diagonal_builder.assert_all_eq(local.num_multiplier, one.clone());
+        diagonal_builder.assert_all_eq(local.den_multiplier, one.clone());
+        let mut off_diagonal_builder = builder.when(local.is_real - local.is_diagonal.result);
+        off_diagonal_builder.assert_all_eq(local.num_multiplier, local.num_factor.result);
+        off_diagonal_builder.assert_all_eq(local.den_multiplier, local.den_factor.result);
+        local.num_product.eval_with_modulus(
+            builder,
+            &local.num,
+            &local.num_multiplier,
+            &local.modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.den_product.eval_with_modulus(
+            builder,
+            &local.den,
+            &local.den_multiplier,
+            &local.modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Add the term `y_i * num / den` of the block to the fraction `acc_num / acc_den`, as
+        // `(acc_num * den + y_i * num * acc_den) / (acc_den * den)`. Only the result of the last
+        // row of a block is used.
+        for (cols, a, b, op) in [
+            (&local.term, &local.y_i, &local.num_product.result, FieldOperation::Mul),
+            (&local.cross_num, &local.acc_num, &local.den_product.result, FieldOperation::Mul),
+            (&local.cross_term, &local.term.result, &local.acc_den, FieldOperation::Mul),
+            (
+                &local.next_num,
+                &local.cross_num.result,
+                &local.cross_term.result,
+                FieldOperation::Add,
+            ),
+            (&local.next_den, &local.acc_den, &local.den_product.result, FieldOperation::Mul),
+        ] {
+            cols.eval_with_modulus(
+                builder,
+                a,
+                b,
+                &local.modulus,
+                op,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // In the last row, divide the numerator of the fraction by its denominator, as the product
+        // with an inverse of the denominator. The inverse exists only if the `x_i` are distinct.
+        local.inv_check.eval_with_modulus(
+            builder,
+            &local.next_den.result,
+            &local.den_inv,
+            &local.modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        builder.when(local.is_last).assert_all_eq(local.inv_check.result, one);
+        local.quotient.eval_with_modulus(
+            builder,
+            &local.next_num.result,
+            &local.den_inv,
+            &local.modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Write the reduced result over the target, after the reads of the points of the largest
+        // number of points.
+        local.result_range_check.eval(
+            builder,
+            &local.quotient.result,
+            &local.modulus,
+            local.shard,
+            local.channel,
+            local.is_last,
+        );
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_usize(LAGRANGE_INTERP_MAX_POINTS + 1),
+            local.params_ptr + AB::F::from_canonical_u32(4),
+            &local.result_memory,
+            local.is_last,
+        );
+        let result: Limbs<AB::Var, WordLimbs> = limbs_from_access(&[local.result_memory]);
+        builder.when(local.is_last).assert_all_eq(result, local.quotient.result);
+
+        // Receive the arguments in the first row of each syscall.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::LAGRANGE_INTERP.syscall_id()),
+            local.points_ptr,
+            local.params_ptr,
+            local.is_first,
+        );
+    }
+}
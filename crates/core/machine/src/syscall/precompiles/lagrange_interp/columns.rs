@@ -0,0 +1,146 @@
+use std::mem::size_of;
+
+use sp1_curves::{
+    params::{Limbs, NumLimbs},
+    uint32::U32Field,
+};
+use sp1_derive::AlignedBorrow;
+
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{field_op::FieldOpCols, range::FieldLtCols},
+        IsZeroOperation,
+    },
+};
+
+use super::LAGRANGE_INTERP_PARAMS_NUM_WORDS;
+
+pub const NUM_LAGRANGE_INTERP_COLS: usize = size_of::<LagrangeInterpCols<u8>>();
+
+/// The limbs of a word, which are its bytes.
+pub(crate) type WordLimbs = <U32Field as NumLimbs>::Limbs;
+
+/// A set of columns for one factor of a Lagrange basis polynomial.
+///
+/// Each syscall is processed over one row per pair of points `(i, j)`, grouped in one block per
+/// point `i`. The rows of a block multiply the numerator `target - x_j` and the denominator
+/// `x_i - x_j` of each factor with `j != i` into the products of the block. The last row of a
+/// block adds `y_i` times the quotient of the products to the fraction of the previous blocks.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct LagrangeInterpCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub points_ptr: T,
+    pub params_ptr: T,
+
+    /// If the row is real and the first row of the syscall.
+    pub is_first: T,
+
+    /// If the row is real and the last row of the syscall.
+    pub is_last: T,
+
+    /// The index of the point of the block.
+    pub i: T,
+
+    /// The index of the point of the row within the block.
+    pub j: T,
+
+    /// The number of points, carried through the rows of the syscall.
+    pub len: T,
+
+    /// Whether `i == j`, the row of the block which reads its own point.
+    pub is_diagonal: IsZeroOperation<T>,
+
+    /// Whether `j + 1 == len`, the last row of the block.
+    pub is_block_end: IsZeroOperation<T>,
+
+    /// Whether `i + 1 == len`, the last block of the syscall.
+    pub is_last_block: IsZeroOperation<T>,
+
+    /// Memory columns for the parameters, which are read in the first row.
+    pub params_memory: [MemoryReadCols<T>; LAGRANGE_INTERP_PARAMS_NUM_WORDS],
+
+    /// The target, carried through the rows of the syscall.
+    pub target: Limbs<T, WordLimbs>,
+
+    /// The modulus, carried through the rows of the syscall.
+    pub modulus: Limbs<T, WordLimbs>,
+
+    /// The abscissa of the point of the block, carried through its rows.
+    pub x_i: Limbs<T, WordLimbs>,
+
+    /// The value at the point of the block.
+    pub y_i: Limbs<T, WordLimbs>,
+
+    /// Memory columns for the point `(x_j, y_j)` of the row.
+    pub point_memory: [MemoryReadCols<T>; 2],
+
+    /// The product of the numerators of the previous rows of the block, which is one in its first
+    /// row.
+    pub num: Limbs<T, WordLimbs>,
+
+    /// The product of the denominators of the previous rows of the block, which is one in its
+    /// first row.
+    pub den: Limbs<T, WordLimbs>,
+
+    /// `target - x_j`.
+    pub num_factor: FieldOpCols<T, U32Field>,
+
+    /// `x_i - x_j`.
+    pub den_factor: FieldOpCols<T, U32Field>,
+
+    /// The factor of the numerator of this row, which is one in the diagonal row.
+    pub num_multiplier: Limbs<T, WordLimbs>,
+
+    /// The factor of the denominator of this row, which is one in the diagonal row.
+    pub den_multiplier: Limbs<T, WordLimbs>,
+
+    /// `num * num_multiplier`.
+    pub num_product: FieldOpCols<T, U32Field>,
+
+    /// `den * den_multiplier`.
+    pub den_product: FieldOpCols<T, U32Field>,
+
+    /// The numerator of the sum of the terms of the previous blocks, which is zero in the first
+    /// block.
+    pub acc_num: Limbs<T, WordLimbs>,
+
+    /// The denominator of the sum of the terms of the previous blocks, which is one in the first
+    /// block.
+    pub acc_den: Limbs<T, WordLimbs>,
+
+    /// `y_i * num_product`, the numerator of the term of the block.
+    pub term: FieldOpCols<T, U32Field>,
+
+    /// `acc_num * den_product`.
+    pub cross_num: FieldOpCols<T, U32Field>,
+
+    /// `term * acc_den`.
+    pub cross_term: FieldOpCols<T, U32Field>,
+
+    /// `cross_num + cross_term`, the numerator of the sum including the term of the block.
+    pub next_num: FieldOpCols<T, U32Field>,
+
+    /// `acc_den * den_product`, the denominator of the sum including the term of the block.
+    pub next_den: FieldOpCols<T, U32Field>,
+
+    /// The inverse of the final denominator, which is only set in the last row.
+    pub den_inv: Limbs<T, WordLimbs>,
+
+    /// `next_den * den_inv`, which is one in the last row.
+    pub inv_check: FieldOpCols<T, U32Field>,
+
+    /// `next_num * den_inv`, the result in the last row.
+    pub quotient: FieldOpCols<T, U32Field>,
+
+    /// Checks that the result is reduced in the last row.
+    pub result_range_check: FieldLtCols<T, U32Field>,
+
+    /// Memory columns for the result, which is written over the target in the last row.
+    pub result_memory: MemoryWriteCols<T>,
+}
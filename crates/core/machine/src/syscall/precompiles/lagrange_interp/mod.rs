@@ -0,0 +1,136 @@
+mod air;
+mod columns;
+mod trace;
+
+/// The maximum number of points of an interpolation.
+pub const LAGRANGE_INTERP_MAX_POINTS: usize = 32;
+
+/// The number of words of the parameters, which are the number of points, the target and the
+/// modulus.
+pub const LAGRANGE_INTERP_PARAMS_NUM_WORDS: usize = 3;
+
+/// The number of rows that the cost of a syscall is estimated with, one per pair of points of an
+/// interpolation of the maximum number of points.
+pub const LAGRANGE_INTERP_COST_ROWS: usize =
+    LAGRANGE_INTERP_MAX_POINTS * LAGRANGE_INTERP_MAX_POINTS;
+
+/// Implements the evaluation at a target of the polynomial of least degree through a set of points,
+/// modulo a word-sized modulus. The inputs to the syscall are a pointer to the points, as
+/// `x_i, y_i` pairs, and a pointer to the parameters: the number of points, between 1 and 32, the
+/// target and a modulus below 2^32. The `x_i` must be distinct, and the result is written over the
+/// target.
+///
+/// The result is `sum_i y_i * n_i / d_i` with `n_i = prod_{j != i} (target - x_j)` and
+/// `d_i = prod_{j != i} (x_i - x_j)`. In the AIR, each syscall takes up one row per pair of points
+/// `(i, j)`, in one block of rows per point `i`, and each row multiplies one factor into `n_i` and
+/// `d_i`, or one in the row `j = i`. The points are read again in each block, one clk later than in
+/// the previous block. The terms are added up as a single fraction, which is updated in the last
+/// row of each block, so that the last row of the syscall divides once, by multiplying the
+/// numerator by an inverse of the denominator.
+#[derive(Default)]
+pub struct LagrangeInterpChip;
+
+impl LagrangeInterpChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod lagrange_interp_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    const POINTS_PTR: u32 = 100;
+    const PARAMS_PTR: u32 = 1000;
+
+    /// The prime `119 * 2^23 + 1`.
+    const MODULUS: u32 = 998244353;
+
+    /// Writes the points to memory, and interpolates them at each of the targets one after the
+    /// other.
+    pub fn lagrange_interp_program(
+        points: &[(u32, u32)],
+        targets: &[u32],
+        modulus: u32,
+    ) -> Program {
+        let mut instructions = vec![];
+        let words = points.iter().flat_map(|&(x, y)| [x, y]).collect::<Vec<_>>();
+        store_words(&mut instructions, slice_at(POINTS_PTR, &words));
+        for &target in targets {
+            let params = [points.len() as u32, target, modulus];
+            store_words(&mut instructions, slice_at(PARAMS_PTR, &params));
+            call_syscall(&mut instructions, SyscallCode::LAGRANGE_INTERP, POINTS_PTR, PARAMS_PTR);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    /// Evaluates the polynomial with the given coefficients, from the constant one up.
+    fn eval(coeffs: &[u32], x: u32, modulus: u32) -> u32 {
+        coeffs.iter().rev().fold(0, |acc, &c| {
+            ((u64::from(acc) * u64::from(x) + u64::from(c)) % u64::from(modulus)) as u32
+        })
+    }
+
+    /// The points of a polynomial of degree `len - 1` at `1, 2, ..., len`.
+    fn points(len: u32, modulus: u32) -> (Vec<u32>, Vec<(u32, u32)>) {
+        let coeffs = (0..len).map(|i| i.wrapping_mul(0x9e3779b9) % modulus).collect::<Vec<_>>();
+        let points = (1..=len).map(|x| (x, eval(&coeffs, x, modulus))).collect();
+        (coeffs, points)
+    }
+
+    #[test]
+    fn test_lagrange_interp_execute() {
+        setup_logger();
+        let (coeffs, points) = points(8, MODULUS);
+        let target = 123456789;
+
+        let program = lagrange_interp_program(&points, &[target], MODULUS);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        // The interpolation recovers the polynomial, and the result is written over the target.
+        assert_eq!(runtime.word(PARAMS_PTR + 4), eval(&coeffs, target, MODULUS));
+    }
+
+    #[test]
+    fn test_lagrange_interp_secret_sharing() {
+        setup_logger();
+        // A secret shared with a polynomial of degree 2 is the value at zero of any 3 shares.
+        let modulus = 65521;
+        let coeffs = [1234, 166, 94];
+        let shares = [2, 4, 5].map(|x| (x, eval(&coeffs, x, modulus)));
+
+        let program = lagrange_interp_program(&shares, &[0], modulus);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.word(PARAMS_PTR + 4), 1234);
+    }
+
+    #[test]
+    fn test_lagrange_interp_prove() {
+        setup_logger();
+        let (_, points) = points(5, MODULUS);
+        // The targets include one of the points and the largest value.
+        run_test::<CpuProver<_, _>>(lagrange_interp_program(
+            &points,
+            &[7, 3, MODULUS - 1],
+            MODULUS,
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn test_lagrange_interp_prove_small() {
+        setup_logger();
+        // A single point takes a single row, and interpolates to a constant.
+        run_test::<CpuProver<_, _>>(lagrange_interp_program(&[(3, 5)], &[11], 17)).unwrap();
+        run_test::<CpuProver<_, _>>(lagrange_interp_program(&[(16, 16), (0, 1)], &[16, 5], 17))
+            .unwrap();
+    }
+}
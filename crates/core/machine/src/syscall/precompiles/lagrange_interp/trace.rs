@@ -0,0 +1,319 @@
+use std::borrow::BorrowMut;
+
+use num::{BigUint, One, Zero};
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{inverse_mod, ByteLookupEvent, ByteRecord, FieldOperation, LagrangeInterpEvent},
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_curves::{params::FieldParameters, uint32::U32Field};
+use sp1_stark::air::{MachineAir, PaddingStrategy};
+
+use super::{
+    columns::{LagrangeInterpCols, NUM_LAGRANGE_INTERP_COLS},
+    LagrangeInterpChip, LAGRANGE_INTERP_MAX_POINTS,
+};
+use crate::utils::{pad_rows_with_strategy, par_generate_rows};
+
+impl<F: PrimeField32> MachineAir<F> for LagrangeInterpChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "LagrangeInterp".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(&input.lagrange_interp_events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // The field operations are not gated, so the padded rows run them on zeros. The modulus
+        // of a padded row is one, so that the operations are defined.
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = [F::zero(); NUM_LAGRANGE_INTERP_COLS];
+            let cols: &mut LagrangeInterpCols<F> = row.as_mut_slice().borrow_mut();
+            let zero = BigUint::zero();
+            let modulus = BigUint::one();
+            cols.modulus = U32Field::to_limbs_field::<F, _>(&modulus);
+            for (op, operation) in [
+                (&mut cols.num_factor, FieldOperation::Sub),
+                (&mut cols.den_factor, FieldOperation::Sub),
+                (&mut cols.num_product, FieldOperation::Mul),
+                (&mut cols.den_product, FieldOperation::Mul),
+                (&mut cols.term, FieldOperation::Mul),
+                (&mut cols.cross_num, FieldOperation::Mul),
+                (&mut cols.cross_term, FieldOperation::Mul),
+                (&mut cols.next_num, FieldOperation::Add),
+                (&mut cols.next_den, FieldOperation::Mul),
+                (&mut cols.inv_check, FieldOperation::Mul),
+                (&mut cols.quotient, FieldOperation::Mul),
+            ] {
+                op.populate_with_modulus(
+                    &mut Vec::<ByteLookupEvent>::new(),
+                    0,
+                    0,
+                    &zero,
+                    &zero,
+                    &modulus,
+                    operation,
+                );
+            }
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_LAGRANGE_INTERP_COLS,
+        );
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut LagrangeInterpCols<F> = trace.values
+                [i * NUM_LAGRANGE_INTERP_COLS..(i + 1) * NUM_LAGRANGE_INTERP_COLS]
+                .borrow_mut();
+            if i > 0 && cols.is_first == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.lagrange_interp_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl LagrangeInterpChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &LagrangeInterpEvent,
+        rows: &mut Vec<[F; NUM_LAGRANGE_INTERP_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+        let len = event.len as usize;
+        let target = BigUint::from(event.target);
+        let modulus = BigUint::from(event.modulus);
+        let one = BigUint::one();
+
+        blu.add_byte_lookup_event(ByteLookupEvent {
+            shard,
+            channel,
+            opcode: ByteOpcode::LTU,
+            a1: 1,
+            a2: 0,
+            b: event.len as u8 - 1,
+            c: LAGRANGE_INTERP_MAX_POINTS as u8,
+        });
+
+        // The fraction of the terms of the blocks so far.
+        let mut acc_num = BigUint::zero();
+        let mut acc_den = one.clone();
+        for i in 0..len {
+            let x_i = BigUint::from(event.points_read_records[i][2 * i].value);
+            let y_i = BigUint::from(event.points_read_records[i][2 * i + 1].value);
+
+            // The products of the factors of the block so far.
+            let mut num = one.clone();
+            let mut den = one.clone();
+            for j in 0..len {
+                let mut row = [F::zero(); NUM_LAGRANGE_INTERP_COLS];
+                let cols: &mut LagrangeInterpCols<F> = row.as_mut_slice().borrow_mut();
+
+                let is_first = i == 0 && j == 0;
+                let is_block_end = j + 1 == len;
+                let is_last = is_block_end && i + 1 == len;
+
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(shard);
+                cols.channel = F::from_canonical_u8(channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.points_ptr = F::from_canonical_u32(event.points_ptr);
+                cols.params_ptr = F::from_canonical_u32(event.params_ptr);
+                cols.is_first = F::from_bool(is_first);
+                cols.is_last = F::from_bool(is_last);
+                cols.i = F::from_canonical_usize(i);
+                cols.j = F::from_canonical_usize(j);
+                cols.len = F::from_canonical_usize(len);
+                cols.is_diagonal.populate_from_field_element(cols.i - cols.j);
+                cols.is_block_end.populate_from_field_element(cols.j + F::one() - cols.len);
+                cols.is_last_block.populate_from_field_element(cols.i + F::one() - cols.len);
+
+                // In the first row, read the parameters.
+                if is_first {
+                    for (k, read_record) in event.params_read_records.iter().enumerate() {
+                        cols.params_memory[k].populate(channel, *read_record, blu);
+                    }
+                }
+
+                cols.target = U32Field::to_limbs_field::<F, _>(&target);
+                cols.modulus = U32Field::to_limbs_field::<F, _>(&modulus);
+                cols.x_i = U32Field::to_limbs_field::<F, _>(&x_i);
+                cols.y_i = U32Field::to_limbs_field::<F, _>(&y_i);
+
+                let x_record = event.points_read_records[i][2 * j];
+                let y_record = event.points_read_records[i][2 * j + 1];
+                cols.point_memory[0].populate(channel, x_record, blu);
+                cols.point_memory[1].populate(channel, y_record, blu);
+                let x_j = BigUint::from(x_record.value);
+
+                cols.num = U32Field::to_limbs_field::<F, _>(&num);
+                cols.den = U32Field::to_limbs_field::<F, _>(&den);
+                let num_factor = cols.num_factor.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &target,
+                    &x_j,
+                    &modulus,
+                    FieldOperation::Sub,
+                );
+                let den_factor = cols.den_factor.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &x_i,
+                    &x_j,
+                    &modulus,
+                    FieldOperation::Sub,
+                );
+                let (num_multiplier, den_multiplier) =
+                    if i == j { (one.clone(), one.clone()) } else { (num_factor, den_factor) };
+                cols.num_multiplier = U32Field::to_limbs_field::<F, _>(&num_multiplier);
+                cols.den_multiplier = U32Field::to_limbs_field::<F, _>(&den_multiplier);
+                num = cols.num_product.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &num,
+                    &num_multiplier,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+                den = cols.den_product.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &den,
+                    &den_multiplier,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+
+                cols.acc_num = U32Field::to_limbs_field::<F, _>(&acc_num);
+                cols.acc_den = U32Field::to_limbs_field::<F, _>(&acc_den);
+                let term = cols.term.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &y_i,
+                    &num,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+                let cross_num = cols.cross_num.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &acc_num,
+                    &den,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+                let cross_term = cols.cross_term.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &term,
+                    &acc_den,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+                let next_num = cols.next_num.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &cross_num,
+                    &cross_term,
+                    &modulus,
+                    FieldOperation::Add,
+                );
+                let next_den = cols.next_den.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &acc_den,
+                    &den,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+
+                // In the last row, divide by the denominator and write the result over the
+                // target.
+                let den_inv = if is_last {
+                    let next_den = u32::try_from(&next_den).unwrap();
+                    BigUint::from(
+                        inverse_mod(next_den, event.modulus)
+                            .expect("the denominator should be invertible"),
+                    )
+                } else {
+                    BigUint::zero()
+                };
+                cols.den_inv = U32Field::to_limbs_field::<F, _>(&den_inv);
+                cols.inv_check.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &next_den,
+                    &den_inv,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+                let quotient = cols.quotient.populate_with_modulus(
+                    blu,
+                    shard,
+                    channel,
+                    &next_num,
+                    &den_inv,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+                if is_last {
+                    debug_assert_eq!(BigUint::from(event.result_write_record.value), quotient);
+                    cols.result_range_check.populate(blu, shard, channel, &quotient, &modulus);
+                    cols.result_memory.populate(channel, event.result_write_record, blu);
+                }
+
+                // The next block starts from the fraction with the term of this block.
+                if is_block_end {
+                    acc_num = next_num;
+                    acc_den = next_den;
+                }
+
+                rows.push(row);
+            }
+        }
+    }
+}
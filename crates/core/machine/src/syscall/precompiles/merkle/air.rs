@@ -0,0 +1,368 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{syscalls::SyscallCode, ByteOpcode};
+use sp1_stark::{
+    air::{BaseAirBuilder, SP1AirBuilder},
+    Word,
+};
+
+use super::{
+    columns::{MerkleVerifyCols, NUM_MERKLE_VERIFY_COLS},
+    MerkleVerifyChip, MERKLE_MAX_DEPTH, MERKLE_NODE_WORDS, MERKLE_SIBLINGS_OFFSET,
+    SHA256_PAIR_PADDING_BLOCK,
+};
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+    operations::IsZeroOperation,
+    syscall::precompiles::sha256::{Sha256RoundCols, SHA256_IV},
+};
+
+impl<F> BaseAir<F> for MerkleVerifyChip {
+    fn width(&self) -> usize {
+        NUM_MERKLE_VERIFY_COLS
+    }
+}
+
+impl<AB> Air<AB> for MerkleVerifyChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &MerkleVerifyCols<AB::Var> = (*local).borrow();
+        let next: &MerkleVerifyCols<AB::Var> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans a variable number of rows, so the nonce counts the
+        // syscalls rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_syscall_start, next.nonce);
+
+        self.eval_flags(builder, local, next);
+
+        Sha256RoundCols::<AB::F>::eval_flags(builder, &local.sha, &next.sha);
+
+        self.eval_inputs(builder, local);
+
+        self.eval_levels(builder, local, next);
+
+        Sha256RoundCols::<AB::F>::eval(
+            builder,
+            &local.sha,
+            &next.sha,
+            local.shard,
+            local.channel,
+            local.is_real,
+            local.is_compression_end,
+        );
+
+        self.eval_compression_end(builder, local, next);
+
+        self.eval_result(builder, local);
+    }
+}
+
+/// Reverses the bytes of a word, which converts between a word of memory and the big endian word
+/// of the same four bytes.
+fn swap_bytes<T: Copy>(word: &Word<T>) -> Word<T> {
+    Word([word[3], word[2], word[1], word[0]])
+}
+
+impl MerkleVerifyChip {
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &MerkleVerifyCols<AB::Var>,
+        next: &MerkleVerifyCols<AB::Var>,
+    ) {
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_padding_block);
+        builder.assert_bool(local.is_syscall_start);
+        builder.assert_eq(
+            local.is_level_start,
+            local.sha.round[0] * (AB::Expr::one() - local.is_padding_block) * local.is_real,
+        );
+        builder.assert_eq(local.is_level_end, local.is_compression_end * local.is_padding_block);
+        builder.assert_eq(local.is_syscall_end, local.is_level_end * local.is_last_level.result);
+
+        // The level is the last one when there are no levels left after it.
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.levels_left - AB::Expr::one(),
+            local.is_last_level,
+            local.is_real.into(),
+        );
+
+        // A syscall starts in the first real row and in the real row after the end of a syscall,
+        // and continues until its end.
+        builder.when(local.is_syscall_start).assert_one(local.is_level_start);
+        builder.when_first_row().assert_eq(local.is_syscall_start, local.is_real);
+        builder
+            .when_transition()
+            .when(local.is_syscall_end)
+            .assert_eq(next.is_syscall_start, next.is_real);
+        let mut transition_builder = builder.when_transition();
+        let mut in_syscall_builder = transition_builder.when(local.is_real - local.is_syscall_end);
+        in_syscall_builder.assert_one(next.is_real);
+        in_syscall_builder.assert_zero(next.is_syscall_start);
+        in_syscall_builder.assert_eq(local.shard, next.shard);
+        in_syscall_builder.assert_eq(local.channel, next.channel);
+        in_syscall_builder.assert_eq(local.clk, next.clk);
+        in_syscall_builder.assert_eq(local.ptr, next.ptr);
+        in_syscall_builder.assert_eq(local.depth, next.depth);
+        for i in 0..MERKLE_NODE_WORDS {
+            in_syscall_builder
+                .assert_word_eq(*local.root_memory[i].value(), *next.root_memory[i].value());
+        }
+
+        // Once the is_real flag is changed to false, it should not be changed back.
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+
+        // The table cannot end in the middle of a syscall.
+        builder.when_last_row().when(local.is_real).assert_one(local.is_syscall_end);
+
+        // The compressions alternate between the block of the two nodes and the padding block.
+        builder
+            .when_transition()
+            .when(local.is_real - local.is_compression_end)
+            .assert_eq(local.is_padding_block, next.is_padding_block);
+        builder
+            .when_transition()
+            .when(local.is_compression_end - local.is_syscall_end)
+            .assert_eq(local.is_padding_block + next.is_padding_block, AB::Expr::one());
+    }
+
+    /// Constrains the reads of the inputs and the write of the result.
+    fn eval_inputs<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &MerkleVerifyCols<AB::Var>) {
+        let node_bytes = AB::Expr::from_canonical_usize(4 * MERKLE_NODE_WORDS);
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr,
+            &local.leaf_memory,
+            local.is_syscall_start,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr + node_bytes.clone(),
+            &local.root_memory,
+            local.is_syscall_start,
+        );
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr + node_bytes.clone() * AB::Expr::two(),
+            &local.directions_memory,
+            local.is_syscall_start,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.sibling_ptr,
+            &local.sibling_memory,
+            local.is_level_start,
+        );
+
+        // The clk increments by 1 before the result is written.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk + AB::Expr::one(),
+            local.ptr + node_bytes * AB::Expr::two() + AB::Expr::from_canonical_u32(4),
+            &local.result_memory,
+            local.is_syscall_end,
+        );
+
+        // Range check the nodes read from memory to be bytes.
+        for i in 0..MERKLE_NODE_WORDS {
+            builder.slice_range_check_u8(
+                &local.leaf_memory[i].value().0,
+                local.shard,
+                local.channel,
+                local.is_syscall_start,
+            );
+            builder.slice_range_check_u8(
+                &local.root_memory[i].value().0,
+                local.shard,
+                local.channel,
+                local.is_syscall_start,
+            );
+            builder.slice_range_check_u8(
+                &local.sibling_memory[i].value().0,
+                local.shard,
+                local.channel,
+                local.is_level_start,
+            );
+        }
+
+        // The depth is between 1 and the number of direction bits.
+        builder.send_byte(
+            ByteOpcode::LTU.as_field::<AB::F>(),
+            AB::F::one(),
+            local.depth - AB::Expr::one(),
+            AB::F::from_canonical_usize(MERKLE_MAX_DEPTH),
+            local.shard,
+            local.channel,
+            local.is_syscall_start,
+        );
+
+        // Receive the arguments in the first row of each syscall.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::MERKLE_VERIFY_PATH.syscall_id()),
+            local.ptr,
+            local.depth,
+            local.is_syscall_start,
+        );
+    }
+
+    /// Constrains the node, the sibling and the directions of each level, and the first block of
+    /// its hash.
+    fn eval_levels<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &MerkleVerifyCols<AB::Var>,
+        next: &MerkleVerifyCols<AB::Var>,
+    ) {
+        for &bit in local.directions.iter() {
+            builder.assert_bool(bit);
+        }
+
+        // The first level starts at the leaf, with all the levels and directions ahead of it.
+        let mut start_builder = builder.when(local.is_syscall_start);
+        for i in 0..MERKLE_NODE_WORDS {
+            start_builder.assert_word_eq(local.node[i], swap_bytes(local.leaf_memory[i].value()));
+        }
+        start_builder.assert_eq(local.levels_left, local.depth);
+        start_builder.assert_eq(
+            local.sibling_ptr,
+            local.ptr + AB::Expr::from_canonical_u32(MERKLE_SIBLINGS_OFFSET),
+        );
+        let directions = local.directions_memory.value();
+        for (i, bits) in local.directions.chunks_exact(8).enumerate() {
+            let byte = bits.iter().enumerate().fold(AB::Expr::zero(), |acc, (j, &bit)| {
+                acc + bit * AB::F::from_canonical_u32(1 << j)
+            });
+            start_builder.assert_eq(directions[i], byte);
+        }
+
+        // The level stays the same until its last row.
+        let mut transition_builder = builder.when_transition();
+        let mut in_level_builder = transition_builder.when(local.is_real - local.is_level_end);
+        for i in 0..MERKLE_NODE_WORDS {
+            in_level_builder.assert_word_eq(local.node[i], next.node[i]);
+            in_level_builder
+                .assert_word_eq(*local.sibling_memory[i].value(), *next.sibling_memory[i].value());
+        }
+        for i in 0..MERKLE_MAX_DEPTH {
+            in_level_builder.assert_eq(local.directions[i], next.directions[i]);
+        }
+        in_level_builder.assert_eq(local.levels_left, next.levels_left);
+        in_level_builder.assert_eq(local.sibling_ptr, next.sibling_ptr);
+
+        // The next level starts at the parent, with the next sibling and the directions shifted
+        // by one bit.
+        let mut transition_builder = builder.when_transition();
+        let mut next_level_builder =
+            transition_builder.when(local.is_level_end - local.is_syscall_end);
+        for i in 0..MERKLE_NODE_WORDS {
+            next_level_builder.assert_word_eq(next.node[i], local.sha.finalize_add[i].value);
+        }
+        for i in 0..MERKLE_MAX_DEPTH - 1 {
+            next_level_builder.assert_eq(next.directions[i], local.directions[i + 1]);
+        }
+        next_level_builder.assert_zero(next.directions[MERKLE_MAX_DEPTH - 1]);
+        next_level_builder.assert_eq(next.levels_left, local.levels_left - AB::Expr::one());
+        next_level_builder.assert_eq(
+            next.sibling_ptr,
+            local.sibling_ptr + AB::Expr::from_canonical_usize(4 * MERKLE_NODE_WORDS),
+        );
+
+        // The first compression of a level starts from the initial hash value, over the node and
+        // the sibling in the order given by the direction bit.
+        let mut level_start_builder = builder.when(local.is_level_start);
+        let working_variables = local.sha.working_variables();
+        for i in 0..8 {
+            let iv = Word::<AB::F>::from(SHA256_IV[i]);
+            level_start_builder.assert_word_eq(local.sha.h_in[i], iv);
+            level_start_builder.assert_word_eq(working_variables[i], iv);
+        }
+        let is_right = local.directions[0];
+        for i in 0..MERKLE_NODE_WORDS {
+            let node = local.node[i];
+            let sibling = swap_bytes(local.sibling_memory[i].value());
+            for j in 0..4 {
+                level_start_builder
+                    .assert_eq(local.sha.w[i][j], node[j] + is_right * (sibling[j] - node[j]));
+                level_start_builder.assert_eq(
+                    local.sha.w[MERKLE_NODE_WORDS + i][j],
+                    sibling[j] + is_right * (node[j] - sibling[j]),
+                );
+            }
+        }
+    }
+
+    /// Constrains the start of the compression of the padding block, whose input state is the
+    /// output of the compression of the two nodes.
+    fn eval_compression_end<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &MerkleVerifyCols<AB::Var>,
+        next: &MerkleVerifyCols<AB::Var>,
+    ) {
+        let mut transition_builder = builder.when_transition();
+        let mut padding_start_builder =
+            transition_builder.when(local.is_compression_end).when_not(local.is_padding_block);
+        let output = local.sha.output();
+        let next_working_variables = next.sha.working_variables();
+        for i in 0..8 {
+            padding_start_builder.assert_word_eq(next.sha.h_in[i], output[i]);
+            padding_start_builder.assert_word_eq(next_working_variables[i], output[i]);
+        }
+        for (i, &word) in SHA256_PAIR_PADDING_BLOCK.iter().enumerate() {
+            padding_start_builder.assert_word_eq(next.sha.w[i], Word::<AB::F>::from(word));
+        }
+    }
+
+    /// Constrains the comparison of the computed root with the expected root, and the result
+    /// written to memory.
+    fn eval_result<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &MerkleVerifyCols<AB::Var>) {
+        builder.assert_bool(local.root_matches);
+
+        // The roots differ if and only if some byte difference has an inverse.
+        let mut diff_dot_inv = AB::Expr::zero();
+        for i in 0..MERKLE_NODE_WORDS {
+            let computed = local.sha.finalize_add[i].value;
+            let expected = swap_bytes(local.root_memory[i].value());
+            for j in 0..4 {
+                let diff = computed[j] - expected[j];
+                diff_dot_inv += diff.clone() * local.root_diff_inv[4 * i + j];
+                builder.when(local.is_syscall_end).when(local.root_matches).assert_zero(diff);
+            }
+        }
+        builder.assert_eq(local.root_diff_dot_inv, diff_dot_inv);
+        builder
+            .when(local.is_syscall_end)
+            .when_not(local.root_matches)
+            .assert_one(local.root_diff_dot_inv);
+
+        let result = local.result_memory.value();
+        builder.when(local.is_syscall_end).assert_eq(result[0], local.root_matches);
+        for &byte in &result.0[1..] {
+            builder.when(local.is_syscall_end).assert_zero(byte);
+        }
+    }
+}
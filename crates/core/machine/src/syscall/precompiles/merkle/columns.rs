@@ -0,0 +1,89 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+use sp1_stark::Word;
+
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::IsZeroOperation,
+    syscall::precompiles::sha256::Sha256RoundCols,
+};
+
+use super::{MERKLE_MAX_DEPTH, MERKLE_NODE_WORDS};
+
+pub const NUM_MERKLE_VERIFY_COLS: usize = size_of::<MerkleVerifyCols<u8>>();
+
+/// A set of columns needed to verify a Merkle path.
+///
+/// Each level of the path is processed over 128 rows, one per round of the two SHA-256
+/// compressions of `left || right`. The second compression is over the constant padding block.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct MerkleVerifyCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub ptr: T,
+    pub depth: T,
+
+    /// Whether this row is compressing the padding block.
+    pub is_padding_block: T,
+
+    /// The number of levels left in the path, including this one.
+    pub levels_left: T,
+
+    /// Whether this is the last level of the path.
+    pub is_last_level: IsZeroOperation<T>,
+
+    /// The pointer to the sibling of this level.
+    pub sibling_ptr: T,
+
+    /// The direction bits of this level and the ones above it. The first bit is set if the node
+    /// is the right child.
+    pub directions: [T; MERKLE_MAX_DEPTH],
+
+    /// Memory columns for the inputs, which are read in the first row of the syscall.
+    pub leaf_memory: [MemoryReadCols<T>; MERKLE_NODE_WORDS],
+    pub root_memory: [MemoryReadCols<T>; MERKLE_NODE_WORDS],
+    pub directions_memory: MemoryReadCols<T>,
+
+    /// Memory columns for the sibling, which is read in the first row of the level.
+    pub sibling_memory: [MemoryReadCols<T>; MERKLE_NODE_WORDS],
+
+    /// Memory column for the result, which is written in the last row of the syscall.
+    pub result_memory: MemoryWriteCols<T>,
+
+    /// The node of this level as big endian words.
+    pub node: [Word<T>; MERKLE_NODE_WORDS],
+
+    /// The columns of the SHA-256 round of this row.
+    pub sha: Sha256RoundCols<T>,
+
+    /// Whether the computed root is equal to the expected root.
+    pub root_matches: T,
+
+    /// Inverses of the differences between the bytes of the computed root and the expected root,
+    /// with one nonzero difference inverted when the roots differ.
+    pub root_diff_inv: [T; 4 * MERKLE_NODE_WORDS],
+
+    /// The sum of the products of the differences and their inverses.
+    pub root_diff_dot_inv: T,
+
+    /// If the row is real and the first row of the syscall.
+    pub is_syscall_start: T,
+
+    /// If the row is real and the first row of a level.
+    pub is_level_start: T,
+
+    /// If the row is real and in the last round of a compression.
+    pub is_compression_end: T,
+
+    /// If the row is real and the last row of a level.
+    pub is_level_end: T,
+
+    /// If the row is real and the last row of the syscall.
+    pub is_syscall_end: T,
+
+    pub is_real: T,
+}
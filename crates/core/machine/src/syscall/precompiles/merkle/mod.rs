@@ -0,0 +1,127 @@
+mod air;
+mod columns;
+mod trace;
+
+use super::sha256::SHA256_NUM_ROUNDS;
+
+/// The maximum number of levels of a path, which is the number of bits of the directions word.
+pub const MERKLE_MAX_DEPTH: usize = 32;
+
+/// The number of words in a node of the tree.
+pub const MERKLE_NODE_WORDS: usize = 8;
+
+/// The number of rows of each level of the path: one per round of the two compressions.
+pub const MERKLE_ROWS_PER_LEVEL: usize = 2 * SHA256_NUM_ROUNDS;
+
+/// The offset of the siblings from the pointer, after the leaf, the root, the directions and the
+/// result.
+pub const MERKLE_SIBLINGS_OFFSET: u32 = 72;
+
+/// The second block of the SHA-256 padding of a 64-byte message, which is the concatenation of two
+/// nodes.
+pub const SHA256_PAIR_PADDING_BLOCK: [u32; 16] =
+    [0x80000000, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 512];
+
+/// Implements the verification of a Merkle path whose nodes are hashed with SHA-256.
+///
+/// The first input to the syscall is a pointer to the 8 word leaf, followed by the 8 word root,
+/// the word of direction bits, a word for the result and the 8 word siblings from the leaf level
+/// up. The second input is the depth of the path, between 1 and 32. Bit `i` of the directions is
+/// set if the node at level `i` is the right child, and the parent is the SHA-256 digest of the
+/// left child followed by the right child. The result is one if the computed root is equal to the
+/// root, and zero otherwise.
+///
+/// In the AIR, each level takes up 128 rows, one per round of the two SHA-256 compressions.
+#[derive(Default)]
+pub struct MerkleVerifyChip;
+
+impl MerkleVerifyChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod merkle_tests {
+    use sha2::{Digest, Sha256};
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    const PTR: u32 = 100;
+
+    /// Computes the root of the path as bytes.
+    fn compute_root(leaf: &[u8; 32], siblings: &[[u8; 32]], directions: u32) -> [u8; 32] {
+        let mut node = *leaf;
+        for (level, sibling) in siblings.iter().enumerate() {
+            let mut hasher = Sha256::new();
+            if (directions >> level) & 1 == 1 {
+                hasher.update(sibling);
+                hasher.update(node);
+            } else {
+                hasher.update(node);
+                hasher.update(sibling);
+            }
+            node = hasher.finalize().into();
+        }
+        node
+    }
+
+    /// Returns a program that verifies the given paths, where each path is a leaf, a root, the
+    /// directions and the siblings.
+    pub fn merkle_verify_program(paths: &[([u8; 32], [u8; 32], u32, Vec<[u8; 32]>)]) -> Program {
+        let mut instructions = vec![];
+        for (leaf, root, directions, siblings) in paths {
+            let mut words = vec![];
+            for node in [leaf, root] {
+                words.extend(
+                    node.chunks_exact(4).map(|c| u32::from_le_bytes(c.try_into().unwrap())),
+                );
+            }
+            words.extend([*directions, 0]);
+            for sibling in siblings {
+                words.extend(
+                    sibling.chunks_exact(4).map(|c| u32::from_le_bytes(c.try_into().unwrap())),
+                );
+            }
+            store_words(&mut instructions, slice_at(PTR, &words));
+            let depth = siblings.len() as u32;
+            call_syscall(&mut instructions, SyscallCode::MERKLE_VERIFY_PATH, PTR, depth);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    /// Returns a valid path of the given depth, and the same path with a wrong root.
+    fn paths(depth: usize, directions: u32) -> Vec<([u8; 32], [u8; 32], u32, Vec<[u8; 32]>)> {
+        let leaf: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let siblings = (0..depth).map(|level| [level as u8 + 1; 32]).collect::<Vec<[u8; 32]>>();
+        let root = compute_root(&leaf, &siblings, directions);
+        let mut wrong_root = root;
+        wrong_root[31] ^= 1;
+        vec![(leaf, root, directions, siblings.clone()), (leaf, wrong_root, directions, siblings)]
+    }
+
+    #[test]
+    fn test_merkle_verify_execute() {
+        setup_logger();
+        for (depth, directions) in [(1, 0), (1, 1), (3, 0b101), (32, 0xa5a5_5a5a)] {
+            for (i, path) in paths(depth, directions).into_iter().enumerate() {
+                let program = merkle_verify_program(&[path]);
+                let mut runtime = Executor::new(program, SP1CoreOpts::default());
+                runtime.run().unwrap();
+                assert_eq!(runtime.word(PTR + 68), (i == 0) as u32);
+            }
+        }
+    }
+
+    #[test]
+    fn test_merkle_verify_prove() {
+        setup_logger();
+        let program = merkle_verify_program(&paths(2, 0b10));
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
@@ -0,0 +1,207 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, MerkleVerifyEvent},
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_stark::{air::MachineAir, Word};
+
+use super::{
+    columns::{MerkleVerifyCols, NUM_MERKLE_VERIFY_COLS},
+    MerkleVerifyChip, MERKLE_MAX_DEPTH, MERKLE_NODE_WORDS, MERKLE_SIBLINGS_OFFSET,
+    SHA256_PAIR_PADDING_BLOCK,
+};
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    syscall::precompiles::sha256::{SHA256_IV, SHA256_NUM_ROUNDS},
+    utils::{pad_rows, par_generate_rows},
+};
+
+impl<F: PrimeField32> MachineAir<F> for MerkleVerifyChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "MerkleVerify".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(&input.merkle_verify_events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        let num_real_rows = rows.len();
+
+        pad_rows(&mut rows, || [F::zero(); NUM_MERKLE_VERIFY_COLS]);
+
+        // Set the round flags for the padded rows. Each level takes up two full cycles of rounds,
+        // so the padding continues the cycle from the first round.
+        for (i, row) in rows[num_real_rows..].iter_mut().enumerate() {
+            let cols: &mut MerkleVerifyCols<F> = row.as_mut_slice().borrow_mut();
+            cols.sha.round[i % SHA256_NUM_ROUNDS] = F::one();
+        }
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_MERKLE_VERIFY_COLS,
+        );
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut MerkleVerifyCols<F> = trace.values
+                [i * NUM_MERKLE_VERIFY_COLS..(i + 1) * NUM_MERKLE_VERIFY_COLS]
+                .borrow_mut();
+            if i > 0 && cols.is_syscall_start == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.merkle_verify_events.is_empty()
+    }
+}
+
+impl MerkleVerifyChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &MerkleVerifyEvent,
+        rows: &mut Vec<[F; NUM_MERKLE_VERIFY_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+        let depth = event.depth as usize;
+
+        // The inputs are only accessed in the first row, but the root is compared in the last row,
+        // so the memory columns are populated once and copied into every row of the syscall.
+        let mut leaf_memory = [MemoryReadCols::<F>::default(); MERKLE_NODE_WORDS];
+        let mut root_memory = [MemoryReadCols::<F>::default(); MERKLE_NODE_WORDS];
+        for j in 0..MERKLE_NODE_WORDS {
+            leaf_memory[j].populate(channel, event.leaf_memory_records[j], blu);
+            root_memory[j].populate(channel, event.root_memory_records[j], blu);
+            blu.add_u8_range_checks(shard, channel, &event.leaf[j].to_le_bytes());
+            blu.add_u8_range_checks(shard, channel, &event.root[j].to_le_bytes());
+        }
+        let mut directions_memory = MemoryReadCols::<F>::default();
+        directions_memory.populate(channel, event.directions_memory_record, blu);
+        let mut result_memory = MemoryWriteCols::<F>::default();
+        result_memory.populate(channel, event.result_memory_record, blu);
+        blu.add_byte_lookup_event(ByteLookupEvent {
+            shard,
+            channel,
+            opcode: ByteOpcode::LTU,
+            a1: 1,
+            a2: 0,
+            b: (event.depth - 1) as u8,
+            c: MERKLE_MAX_DEPTH as u8,
+        });
+
+        let expected_root = event.root.map(u32::swap_bytes);
+        let mut node = event.leaf.map(u32::swap_bytes);
+        for level in 0..depth {
+            let directions = event.directions >> level;
+            let is_right = directions & 1 == 1;
+            let sibling = event.siblings[level].map(u32::swap_bytes);
+            let sibling_ptr =
+                event.ptr + MERKLE_SIBLINGS_OFFSET + (level * 4 * MERKLE_NODE_WORDS) as u32;
+            let mut sibling_memory = [MemoryReadCols::<F>::default(); MERKLE_NODE_WORDS];
+            for j in 0..MERKLE_NODE_WORDS {
+                let record = event.sibling_memory_records[level * MERKLE_NODE_WORDS + j];
+                sibling_memory[j].populate(channel, record, blu);
+                blu.add_u8_range_checks(shard, channel, &record.value.to_le_bytes());
+            }
+            let is_last_level = level == depth - 1;
+
+            let (left, right) = if is_right { (sibling, node) } else { (node, sibling) };
+            let mut block = [0u32; 16];
+            block[..MERKLE_NODE_WORDS].copy_from_slice(&left);
+            block[MERKLE_NODE_WORDS..].copy_from_slice(&right);
+
+            let mut h_in = SHA256_IV;
+            for is_padding_block in [false, true] {
+                let mut w = block;
+                let mut state = h_in;
+                for round in 0..SHA256_NUM_ROUNDS {
+                    let mut row = [F::zero(); NUM_MERKLE_VERIFY_COLS];
+                    let cols: &mut MerkleVerifyCols<F> = row.as_mut_slice().borrow_mut();
+
+                    cols.shard = F::from_canonical_u32(shard);
+                    cols.channel = F::from_canonical_u8(channel);
+                    cols.clk = F::from_canonical_u32(event.clk);
+                    cols.ptr = F::from_canonical_u32(event.ptr);
+                    cols.depth = F::from_canonical_u32(event.depth);
+                    cols.is_padding_block = F::from_bool(is_padding_block);
+                    cols.levels_left = F::from_canonical_usize(depth - level);
+                    cols.is_last_level.populate((depth - level - 1) as u32);
+                    cols.sibling_ptr = F::from_canonical_u32(sibling_ptr);
+                    for (k, bit) in cols.directions.iter_mut().enumerate() {
+                        *bit = F::from_canonical_u32((directions >> k) & 1);
+                    }
+
+                    cols.leaf_memory = leaf_memory;
+                    cols.root_memory = root_memory;
+                    cols.directions_memory = directions_memory;
+                    cols.sibling_memory = sibling_memory;
+                    cols.result_memory = result_memory;
+
+                    cols.node = node.map(Word::from);
+                    cols.sha.populate(blu, shard, channel, round, &h_in, &mut w, &mut state);
+
+                    let is_syscall_start = level == 0 && !is_padding_block && round == 0;
+                    let is_level_start = !is_padding_block && round == 0;
+                    let is_compression_end = round == SHA256_NUM_ROUNDS - 1;
+                    let is_level_end = is_padding_block && is_compression_end;
+                    let is_syscall_end = is_last_level && is_level_end;
+
+                    if is_syscall_end {
+                        cols.root_matches = F::from_bool(event.root_matches);
+                        debug_assert_eq!(state == expected_root, event.root_matches);
+                        if let Some(j) = (0..4 * MERKLE_NODE_WORDS).find(|&j| {
+                            state[j / 4].to_le_bytes()[j % 4]
+                                != expected_root[j / 4].to_le_bytes()[j % 4]
+                        }) {
+                            let diff = F::from_canonical_u8(state[j / 4].to_le_bytes()[j % 4])
+                                - F::from_canonical_u8(expected_root[j / 4].to_le_bytes()[j % 4]);
+                            cols.root_diff_inv[j] = diff.inverse();
+                            cols.root_diff_dot_inv = F::one();
+                        }
+                    }
+
+                    cols.is_syscall_start = F::from_bool(is_syscall_start);
+                    cols.is_level_start = F::from_bool(is_level_start);
+                    cols.is_compression_end = F::from_bool(is_compression_end);
+                    cols.is_level_end = F::from_bool(is_level_end);
+                    cols.is_syscall_end = F::from_bool(is_syscall_end);
+                    cols.is_real = F::one();
+
+                    rows.push(row);
+                }
+
+                // The output of the compression of the nodes is the input state of the compression
+                // of the padding block.
+                h_in = state;
+                block = SHA256_PAIR_PADDING_BLOCK;
+            }
+            node = h_in;
+        }
+    }
+}
@@ -1,6 +1,23 @@
+pub mod aes128;
+pub mod blake2b;
+pub mod blake3;
+pub mod chacha20;
+pub mod clz;
 pub mod edwards;
 pub mod fptower;
+pub mod fri;
 pub mod keccak256;
+pub mod lagrange_interp;
+pub mod merkle;
+pub mod ntt;
+pub mod pedersen;
+pub mod poly_eval;
+pub mod poly_multi_eval;
+pub mod popcount;
+pub mod poseidon2;
+pub mod rescue;
+pub mod ripemd160;
 pub mod sha256;
+pub mod uint;
 pub mod uint256;
 pub mod weierstrass;
@@ -0,0 +1,350 @@
+use std::borrow::Borrow;
+
+use num::BigUint;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{events::FieldOperation, syscalls::SyscallCode};
+use sp1_curves::{
+    params::{FieldParameters, Limbs},
+    uint32::U32Field,
+};
+use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
+
+use super::{
+    columns::{NttCols, WordLimbs, NUM_NTT_COLS},
+    NttChip, NTT_MAX_LOG_LEN,
+};
+use crate::{
+    air::MemoryAirBuilder,
+    operations::IsZeroOperation,
+    utils::{limbs_from_access, limbs_from_prev_access},
+};
+
+impl<F> BaseAir<F> for NttChip {
+    fn width(&self) -> usize {
+        NUM_NTT_COLS
+    }
+}
+
+impl<AB> Air<AB> for NttChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, WordLimbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &NttCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &NttCols<AB::Var> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans a variable number of rows, so the nonce counts the
+        // syscalls rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_first, next.nonce);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+
+        // The number of coefficients and the distance of the butterflies are powers of two, given
+        // by one-hot encodings which are zero in the padded rows.
+        let mut len = AB::Expr::zero();
+        let mut half = AB::Expr::zero();
+        let mut log_len_sum = AB::Expr::zero();
+        let mut log_half_sum = AB::Expr::zero();
+        for i in 0..NTT_MAX_LOG_LEN {
+            builder.assert_bool(local.log_len[i]);
+            builder.assert_bool(local.log_half[i]);
+            len += local.log_len[i] * AB::F::from_canonical_u32(1 << (i + 1));
+            half += local.log_half[i] * AB::F::from_canonical_u32(1 << i);
+            log_len_sum += local.log_len[i].into();
+            log_half_sum += local.log_half[i].into();
+        }
+        builder.assert_eq(log_len_sum, local.is_real);
+        builder.assert_eq(log_half_sum, local.is_real);
+
+        // A syscall starts in the first real row and in the real row after the end of a syscall,
+        // and ends after the last butterfly of its last stage.
+        builder.when_first_row().assert_eq(local.is_first, local.is_real);
+        builder.when_transition().when(local.is_last).assert_eq(next.is_first, next.is_real);
+        let mut transition_builder = builder.when_transition();
+        let mut in_syscall_builder = transition_builder.when(local.is_real - local.is_last);
+        in_syscall_builder.assert_one(next.is_real);
+        in_syscall_builder.assert_zero(next.is_first);
+        in_syscall_builder.assert_eq(local.shard, next.shard);
+        in_syscall_builder.assert_eq(local.channel, next.channel);
+        in_syscall_builder.assert_eq(local.clk, next.clk);
+        in_syscall_builder.assert_eq(local.coeffs_ptr, next.coeffs_ptr);
+        in_syscall_builder.assert_eq(local.params_ptr, next.params_ptr);
+        in_syscall_builder.assert_all_eq(local.log_len, next.log_len);
+        in_syscall_builder.assert_all_eq(local.root, next.root);
+        in_syscall_builder.assert_all_eq(local.modulus, next.modulus);
+
+        // Once the is_real flag is changed to false, it should not be changed back.
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+
+        // The table cannot end in the middle of a syscall.
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+
+        // Find the last butterfly of the block and the last block of the stage.
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.offset + AB::Expr::one() - half.clone(),
+            local.is_block_end,
+            local.is_real.into(),
+        );
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.block + half.clone() * AB::F::two() - len.clone(),
+            local.is_last_block,
+            local.is_real.into(),
+        );
+        let is_block_end = local.is_block_end.result;
+        builder
+            .when(local.is_real)
+            .assert_eq(local.is_stage_end, is_block_end * local.is_last_block.result);
+
+        // The forward transform starts from the largest distance, half the number of
+        // coefficients, and ends with a distance of one. The inverse transform goes the other way.
+        let is_largest_half = (0..NTT_MAX_LOG_LEN)
+            .map(|i| local.log_half[i] * local.log_len[i])
+            .fold(AB::Expr::zero(), |acc, x| acc + x);
+        if self.inverse {
+            builder.when(local.is_first).assert_one(local.log_half[0]);
+            builder.when(local.is_real).assert_eq(local.is_last_stage, is_largest_half);
+        } else {
+            builder.when(local.is_first).assert_all_eq(local.log_half, local.log_len);
+            builder.when(local.is_real).assert_eq(local.is_last_stage, local.log_half[0]);
+        }
+        builder
+            .when(local.is_real)
+            .assert_eq(local.is_last, local.is_stage_end * local.is_last_stage);
+
+        // The first row starts the first block of the first stage, one clk after the syscall.
+        let one = U32Field::to_limbs_field::<AB::Expr, _>(&BigUint::from(1u32));
+        builder.when(local.is_first).assert_zero(local.block);
+        builder.when(local.is_first).assert_zero(local.offset);
+        builder.when(local.is_first).assert_all_eq(local.twiddle, one.clone());
+        builder.when(local.is_first).assert_eq(local.stage_clk, local.clk + AB::Expr::one());
+
+        // Within a block, go to the next butterfly and multiply the twiddle factor by the root of
+        // the stage. After the last one, start the next block from a twiddle factor of one.
+        let mut transition_builder = builder.when_transition();
+        let mut in_syscall_builder = transition_builder.when(local.is_real - local.is_last);
+        {
+            let mut in_block_builder = in_syscall_builder.when(AB::Expr::one() - is_block_end);
+            in_block_builder.assert_eq(next.offset, local.offset + AB::Expr::one());
+            in_block_builder.assert_eq(next.block, local.block);
+            in_block_builder.assert_all_eq(next.twiddle, local.twiddle_step.result);
+        }
+        {
+            let mut block_end_builder = in_syscall_builder.when(is_block_end);
+            block_end_builder.assert_zero(next.offset);
+            block_end_builder.assert_all_eq(next.twiddle, one);
+        }
+        in_syscall_builder
+            .when(is_block_end - local.is_stage_end)
+            .assert_eq(next.block, local.block + half.clone() * AB::F::two());
+
+        // Within a stage, keep the distance, the root and the clk of the stage. After the last
+        // block, start the next stage from the first block one clk later.
+        {
+            let mut in_stage_builder =
+                in_syscall_builder.when(AB::Expr::one() - local.is_stage_end);
+            in_stage_builder.assert_all_eq(next.log_half, local.log_half);
+            in_stage_builder.assert_all_eq(next.stage_root, local.stage_root);
+            in_stage_builder.assert_eq(next.stage_clk, local.stage_clk);
+        }
+        let mut stage_end_builder = in_syscall_builder.when(local.is_stage_end);
+        stage_end_builder.assert_zero(next.block);
+        stage_end_builder.assert_eq(next.stage_clk, local.stage_clk + AB::Expr::one());
+        if self.inverse {
+            // The distance doubles, and the root of this stage is the square of the next one.
+            stage_end_builder.assert_zero(next.log_half[0]);
+            for i in 1..NTT_MAX_LOG_LEN {
+                stage_end_builder.assert_eq(next.log_half[i], local.log_half[i - 1]);
+            }
+            stage_end_builder.assert_all_eq(local.stage_root, next.root_square.result);
+        } else {
+            // The distance halves, and the root of the next stage is the square of this one.
+            for i in 0..NTT_MAX_LOG_LEN - 1 {
+                stage_end_builder.assert_eq(next.log_half[i], local.log_half[i + 1]);
+            }
+            stage_end_builder.assert_zero(next.log_half[NTT_MAX_LOG_LEN - 1]);
+            stage_end_builder.assert_all_eq(next.stage_root, local.root_square.result);
+        }
+
+        // The root of the syscall is the root of the stage with the largest distance, which is the
+        // first stage of the forward transform and the last stage of the inverse transform.
+        if self.inverse {
+            builder.when(local.is_last).assert_all_eq(local.stage_root, local.root);
+        } else {
+            builder.when(local.is_first).assert_all_eq(local.stage_root, local.root);
+        }
+
+        // Read the parameters in the first row. The number of coefficients is at most 2^20, so its
+        // most significant byte is zero and it is reduced as a field element.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.params_ptr,
+            &local.params_memory,
+            local.is_first,
+        );
+        let len_word = local.params_memory[0].access.value;
+        builder.when(local.is_first).assert_zero(len_word[3]);
+        builder.when(local.is_first).assert_eq(len_word.reduce::<AB>(), len);
+        let root: Limbs<AB::Var, WordLimbs> = limbs_from_access(&local.params_memory[1..2]);
+        let modulus: Limbs<AB::Var, WordLimbs> = limbs_from_access(&local.params_memory[2..3]);
+        builder.when(local.is_first).assert_all_eq(local.root, root);
+        builder.when(local.is_first).assert_all_eq(local.modulus, modulus);
+
+        local.root_square.eval_with_modulus(
+            builder,
+            &local.stage_root,
+            &local.stage_root,
+            &local.modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.twiddle_step.eval_with_modulus(
+            builder,
+            &local.twiddle,
+            &local.stage_root,
+            &local.modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // The butterfly. The inputs are the previous values of the coefficients, and the outputs
+        // are written back reduced.
+        let a: Limbs<AB::Var, WordLimbs> = limbs_from_prev_access(&local.memory[0..1]);
+        let b: Limbs<AB::Var, WordLimbs> = limbs_from_prev_access(&local.memory[1..2]);
+        let output = if self.inverse {
+            local.product.eval_with_modulus(
+                builder,
+                &local.twiddle,
+                &b,
+                &local.modulus,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.sum.eval_with_modulus(
+                builder,
+                &a,
+                &local.product.result,
+                &local.modulus,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.diff.eval_with_modulus(
+                builder,
+                &a,
+                &local.product.result,
+                &local.modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.diff.result
+        } else {
+            local.sum.eval_with_modulus(
+                builder,
+                &a,
+                &b,
+                &local.modulus,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.diff.eval_with_modulus(
+                builder,
+                &a,
+                &b,
+                &local.modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.product.eval_with_modulus(
+                builder,
+                &local.diff.result,
+                &local.twiddle,
+                &local.modulus,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.product.result
+        };
+        local.output_range_checks[0].eval(
+            builder,
+            &local.sum.result,
+            &local.modulus,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.output_range_checks[1].eval(
+            builder,
+            &output,
+            &local.modulus,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        let written_a: Limbs<AB::Var, WordLimbs> = limbs_from_access(&local.memory[0..1]);
+        let written_b: Limbs<AB::Var, WordLimbs> = limbs_from_access(&local.memory[1..2]);
+        builder.when(local.is_real).assert_all_eq(written_a, local.sum.result);
+        builder.when(local.is_real).assert_all_eq(written_b, output);
+
+        // The coefficients of the butterfly are `half` apart, from the start of the block.
+        let addr_a = local.coeffs_ptr + (local.block + local.offset) * AB::F::from_canonical_u32(4);
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.stage_clk,
+            addr_a.clone(),
+            &local.memory[0],
+            local.is_real,
+        );
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.stage_clk,
+            addr_a + half * AB::F::from_canonical_u32(4),
+            &local.memory[1],
+            local.is_real,
+        );
+
+        // Receive the arguments in the first row of each syscall.
+        let syscall_code = if self.inverse { SyscallCode::INTT } else { SyscallCode::NTT };
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(syscall_code.syscall_id()),
+            local.coeffs_ptr,
+            local.params_ptr,
+            local.is_first,
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::mem::size_of;
+
+use sp1_curves::{
+    params::{Limbs, NumLimbs},
+    uint32::U32Field,
+};
+use sp1_derive::AlignedBorrow;
+
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{field_op::FieldOpCols, range::FieldLtCols},
+        IsZeroOperation,
+    },
+};
+
+use super::{NTT_MAX_LOG_LEN, NTT_PARAMS_NUM_WORDS};
+
+pub const NUM_NTT_COLS: usize = size_of::<NttCols<u8>>();
+
+/// The limbs of a word, which are its bytes.
+pub(crate) type WordLimbs = <U32Field as NumLimbs>::Limbs;
+
+/// A set of columns for one butterfly of a number theoretic transform.
+///
+/// Each syscall is processed over one row per butterfly, going through the stages in the order they
+/// run, and through the blocks and the butterflies of each block in order. Every row overwrites the
+/// two coefficients of its butterfly, at the clk of its stage.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct NttCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub coeffs_ptr: T,
+    pub params_ptr: T,
+
+    /// If the row is real and the first row of the syscall.
+    pub is_first: T,
+
+    /// If the row is real and the last row of the syscall.
+    pub is_last: T,
+
+    /// A one-hot encoding of the number of coefficients, with `log_len[i]` set for `2^(i + 1)`.
+    pub log_len: [T; NTT_MAX_LOG_LEN],
+
+    /// A one-hot encoding of the distance between the coefficients of a butterfly in this stage,
+    /// with `log_half[i]` set for `2^i`.
+    pub log_half: [T; NTT_MAX_LOG_LEN],
+
+    /// The clk at which the coefficients are written in this stage.
+    pub stage_clk: T,
+
+    /// The index of the first coefficient of the block.
+    pub block: T,
+
+    /// The index of the butterfly within the block.
+    pub offset: T,
+
+    /// Whether this is the last butterfly of the block, `offset + 1 == half`.
+    pub is_block_end: IsZeroOperation<T>,
+
+    /// Whether this is the last block of the stage, `block + 2 * half == len`.
+    pub is_last_block: IsZeroOperation<T>,
+
+    /// If the row is real and the last butterfly of the last block of the stage.
+    pub is_stage_end: T,
+
+    /// If the row is real and in the last stage of the syscall.
+    pub is_last_stage: T,
+
+    /// Memory columns for the parameters, which are read in the first row.
+    pub params_memory: [MemoryReadCols<T>; NTT_PARAMS_NUM_WORDS],
+
+    /// The root of unity, carried through the rows of the syscall.
+    pub root: Limbs<T, WordLimbs>,
+
+    /// The modulus, carried through the rows of the syscall.
+    pub modulus: Limbs<T, WordLimbs>,
+
+    /// The root of unity of this stage, whose order is twice the distance of the butterflies.
+    pub stage_root: Limbs<T, WordLimbs>,
+
+    /// `stage_root * stage_root`, the root of unity of the stage with half the distance.
+    pub root_square: FieldOpCols<T, U32Field>,
+
+    /// The twiddle factor of the butterfly, `stage_root^offset`.
+    pub twiddle: Limbs<T, WordLimbs>,
+
+    /// `twiddle * stage_root`, the twiddle factor of the next butterfly of the block.
+    pub twiddle_step: FieldOpCols<T, U32Field>,
+
+    /// Memory columns for the two coefficients of the butterfly.
+    pub memory: [MemoryWriteCols<T>; 2],
+
+    /// The sum of the butterfly, which is written to the first coefficient.
+    pub sum: FieldOpCols<T, U32Field>,
+
+    /// The difference of the butterfly, which is the second output of the inverse transform.
+    pub diff: FieldOpCols<T, U32Field>,
+
+    /// The twiddle multiplication of the butterfly, which is the second output of the forward
+    /// transform.
+    pub product: FieldOpCols<T, U32Field>,
+
+    /// Checks that the two outputs of the butterfly are reduced.
+    pub output_range_checks: [FieldLtCols<T, U32Field>; 2],
+}
@@ -0,0 +1,170 @@
+mod air;
+mod columns;
+mod trace;
+
+/// The base two logarithm of the maximum number of coefficients of a transform.
+pub const NTT_MAX_LOG_LEN: usize = 20;
+
+/// The number of words of the parameters, which are the number of coefficients, the root of unity
+/// and the modulus.
+pub const NTT_PARAMS_NUM_WORDS: usize = 3;
+
+/// The number of rows of a transform of the maximum length, one per butterfly.
+pub const NTT_MAX_ROWS: usize = NTT_MAX_LOG_LEN << (NTT_MAX_LOG_LEN - 1);
+
+/// Implements a number theoretic transform, or its inverse, in place. The inputs to the syscall
+/// are a pointer to the coefficients and a pointer to the parameters: the number of coefficients,
+/// a power of two between 2 and 2^20, a root of unity of that order and a modulus below 2^32.
+///
+/// The forward transform runs Gentleman-Sande butterflies `(a + b, (a - b) * w)`, halving the
+/// distance between the coefficients of a butterfly at each stage, and leaves the evaluations in
+/// bit-reversed order. The inverse transform runs Cooley-Tukey butterflies `(a + w * b, a - w * b)`
+/// with the inverse root, doubling the distance at each stage, and takes the evaluations in that
+/// order, so that neither needs a permutation. The result of the inverse is not divided by the
+/// number of coefficients.
+///
+/// In the AIR, each syscall takes up one row per butterfly. The twiddle factor starts from one in
+/// each block and is multiplied by the root of the stage from one row to the next, and the root of
+/// each stage is the square of the root of the stage with twice the distance, which is the root of
+/// the syscall for the largest distance.
+pub struct NttChip {
+    inverse: bool,
+}
+
+impl NttChip {
+    /// The chip of the forward transform.
+    pub const fn ntt() -> Self {
+        Self { inverse: false }
+    }
+
+    /// The chip of the inverse transform.
+    pub const fn intt() -> Self {
+        Self { inverse: true }
+    }
+}
+
+#[cfg(test)]
+pub mod ntt_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    const COEFFS_PTR: u32 = 100;
+    const PARAMS_PTR: u32 = 1000;
+
+    /// The prime `119 * 2^23 + 1`, whose multiplicative group is generated by 3.
+    const MODULUS: u32 = 998244353;
+
+    fn pow_mod(base: u32, mut exp: u64, modulus: u32) -> u32 {
+        let (mut base, mut result) = (u64::from(base), 1u64);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base % u64::from(modulus);
+            }
+            base = base * base % u64::from(modulus);
+            exp >>= 1;
+        }
+        result as u32
+    }
+
+    /// The root of unity of order `len` modulo `MODULUS`.
+    fn root_of_unity(len: usize) -> u32 {
+        pow_mod(3, u64::from(MODULUS - 1) / len as u64, MODULUS)
+    }
+
+    /// Writes the coefficients to memory, and runs each of the transforms on them with the given
+    /// root of unity one after the other.
+    pub fn ntt_program(coeffs: &[u32], modulus: u32, calls: &[(SyscallCode, u32)]) -> Program {
+        let mut instructions = vec![];
+        store_words(&mut instructions, slice_at(COEFFS_PTR, coeffs));
+        for &(syscall, root) in calls {
+            let params = [coeffs.len() as u32, root, modulus];
+            store_words(&mut instructions, slice_at(PARAMS_PTR, &params));
+            call_syscall(&mut instructions, syscall, COEFFS_PTR, PARAMS_PTR);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    fn coeffs(runtime: &Executor, len: usize) -> Vec<u32> {
+        (0..len as u32).map(|i| runtime.word(COEFFS_PTR + i * 4)).collect()
+    }
+
+    #[test]
+    fn test_ntt_execute() {
+        setup_logger();
+        let len = 16;
+        let input =
+            (0..len as u32).map(|i| i.wrapping_mul(0x9e3779b9) % MODULUS).collect::<Vec<_>>();
+        let root = root_of_unity(len);
+
+        let program = ntt_program(&input, MODULUS, &[(SyscallCode::NTT, root)]);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        // The evaluation at `root^k` is left at the bit-reversed index of `k`.
+        let log_len = len.trailing_zeros();
+        let output = coeffs(&runtime, len);
+        for k in 0..len {
+            let expected = input.iter().enumerate().fold(0u64, |acc, (j, &x)| {
+                let w = pow_mod(root, (j * k) as u64, MODULUS);
+                (acc + u64::from(x) * u64::from(w)) % u64::from(MODULUS)
+            });
+            let index = k.reverse_bits() >> (usize::BITS - log_len);
+            assert_eq!(u64::from(output[index]), expected);
+        }
+    }
+
+    #[test]
+    fn test_intt_execute() {
+        setup_logger();
+        let len = 16;
+        let input =
+            (0..len as u32).map(|i| i.wrapping_mul(0x9e3779b9) % MODULUS).collect::<Vec<_>>();
+        let root = root_of_unity(len);
+        let root_inv = pow_mod(root, u64::from(MODULUS - 2), MODULUS);
+
+        let calls = [(SyscallCode::NTT, root), (SyscallCode::INTT, root_inv)];
+        let mut runtime =
+            Executor::new(ntt_program(&input, MODULUS, &calls), SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        // The inverse transform gives back the coefficients multiplied by the length.
+        let expected = input
+            .iter()
+            .map(|&x| (u64::from(x) * len as u64 % u64::from(MODULUS)) as u32)
+            .collect::<Vec<_>>();
+        assert_eq!(coeffs(&runtime, len), expected);
+    }
+
+    #[test]
+    fn test_ntt_prove() {
+        setup_logger();
+        let len = 16;
+        let input =
+            (0..len as u32).map(|i| i.wrapping_mul(0x9e3779b9) % MODULUS).collect::<Vec<_>>();
+        let root = root_of_unity(len);
+        let root_inv = pow_mod(root, u64::from(MODULUS - 2), MODULUS);
+
+        let calls = [(SyscallCode::NTT, root), (SyscallCode::INTT, root_inv)];
+        run_test::<CpuProver<_, _>>(ntt_program(&input, MODULUS, &calls)).unwrap();
+    }
+
+    #[test]
+    fn test_ntt_prove_small_modulus() {
+        setup_logger();
+        // Modulo 17, 9 is a root of unity of order 8, and its inverse is 2. The transforms of two
+        // coefficients take a single butterfly.
+        let calls = [
+            (SyscallCode::NTT, 9),
+            (SyscallCode::INTT, 2),
+            (SyscallCode::NTT, 9),
+            (SyscallCode::NTT, 9),
+        ];
+        run_test::<CpuProver<_, _>>(ntt_program(&[1, 16, 0, 5, 7, 3, 16, 2], 17, &calls)).unwrap();
+        run_test::<CpuProver<_, _>>(ntt_program(&[3, 11], 17, &[(SyscallCode::INTT, 16)])).unwrap();
+    }
+}
@@ -0,0 +1,273 @@
+use std::borrow::BorrowMut;
+
+use num::{BigUint, One, Zero};
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation, NttEvent},
+    ExecutionRecord, Program,
+};
+use sp1_curves::{params::FieldParameters, uint32::U32Field};
+use sp1_stark::air::{MachineAir, PaddingStrategy};
+
+use super::{
+    columns::{NttCols, NUM_NTT_COLS},
+    NttChip,
+};
+use crate::utils::{pad_rows_with_strategy, par_generate_rows};
+
+impl<F: PrimeField32> MachineAir<F> for NttChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        if self.inverse {
+            "Intt".to_string()
+        } else {
+            "Ntt".to_string()
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = if self.inverse { &input.intt_events } else { &input.ntt_events };
+
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // The field operations are not gated, so the padded rows run them on zeros. The modulus
+        // of a padded row is one, so that the operations are defined.
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = [F::zero(); NUM_NTT_COLS];
+            let cols: &mut NttCols<F> = row.as_mut_slice().borrow_mut();
+            let zero = BigUint::zero();
+            let modulus = BigUint::one();
+            cols.modulus = U32Field::to_limbs_field::<F, _>(&modulus);
+            for (op, operation) in [
+                (&mut cols.root_square, FieldOperation::Mul),
+                (&mut cols.twiddle_step, FieldOperation::Mul),
+                (&mut cols.sum, FieldOperation::Add),
+                (&mut cols.diff, FieldOperation::Sub),
+                (&mut cols.product, FieldOperation::Mul),
+            ] {
+                op.populate_with_modulus(
+                    &mut Vec::<ByteLookupEvent>::new(),
+                    0,
+                    0,
+                    &zero,
+                    &zero,
+                    &modulus,
+                    operation,
+                );
+            }
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_NTT_COLS);
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut NttCols<F> =
+                trace.values[i * NUM_NTT_COLS..(i + 1) * NUM_NTT_COLS].borrow_mut();
+            if i > 0 && cols.is_first == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if self.inverse {
+            !shard.intt_events.is_empty()
+        } else {
+            !shard.ntt_events.is_empty()
+        }
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl NttChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &NttEvent,
+        rows: &mut Vec<[F; NUM_NTT_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+        let len = event.len as usize;
+        let log_len = len.trailing_zeros() as usize;
+        let root = BigUint::from(event.root);
+        let modulus = BigUint::from(event.modulus);
+
+        // The distances of the butterflies and the roots of the stages, in the order they run.
+        let mut stages = (0..log_len)
+            .map(|i| (len >> (i + 1), root.modpow(&(BigUint::one() << i), &modulus)))
+            .collect::<Vec<_>>();
+        if self.inverse {
+            stages.reverse();
+        }
+        debug_assert_eq!(stages.len(), event.stage_write_records.len());
+
+        for (stage, ((half, stage_root), records)) in
+            stages.iter().zip(event.stage_write_records.iter()).enumerate()
+        {
+            let half = *half;
+            let is_last_stage = stage == stages.len() - 1;
+            for block in (0..len).step_by(2 * half) {
+                let mut twiddle = BigUint::one();
+                for offset in 0..half {
+                    let mut row = [F::zero(); NUM_NTT_COLS];
+                    let cols: &mut NttCols<F> = row.as_mut_slice().borrow_mut();
+
+                    let is_stage_end = offset == half - 1 && block + 2 * half == len;
+
+                    cols.is_real = F::one();
+                    cols.shard = F::from_canonical_u32(shard);
+                    cols.channel = F::from_canonical_u8(channel);
+                    cols.clk = F::from_canonical_u32(event.clk);
+                    cols.coeffs_ptr = F::from_canonical_u32(event.coeffs_ptr);
+                    cols.params_ptr = F::from_canonical_u32(event.params_ptr);
+                    cols.is_first = F::from_bool(stage == 0 && block == 0 && offset == 0);
+                    cols.is_last = F::from_bool(is_last_stage && is_stage_end);
+                    cols.log_len[log_len - 1] = F::one();
+                    cols.log_half[half.trailing_zeros() as usize] = F::one();
+                    cols.stage_clk = F::from_canonical_u32(event.clk + 1 + stage as u32);
+                    cols.block = F::from_canonical_usize(block);
+                    cols.offset = F::from_canonical_usize(offset);
+                    cols.is_block_end.populate_from_field_element(
+                        F::from_canonical_usize(offset + 1) - F::from_canonical_usize(half),
+                    );
+                    cols.is_last_block.populate_from_field_element(
+                        F::from_canonical_usize(block + 2 * half) - F::from_canonical_usize(len),
+                    );
+                    cols.is_stage_end = F::from_bool(is_stage_end);
+                    cols.is_last_stage = F::from_bool(is_last_stage);
+
+                    // In the first row, read the parameters.
+                    if cols.is_first == F::one() {
+                        for (j, read_record) in event.params_read_records.iter().enumerate() {
+                            cols.params_memory[j].populate(channel, *read_record, blu);
+                        }
+                    }
+
+                    cols.root = U32Field::to_limbs_field::<F, _>(&root);
+                    cols.modulus = U32Field::to_limbs_field::<F, _>(&modulus);
+                    cols.stage_root = U32Field::to_limbs_field::<F, _>(stage_root);
+                    cols.root_square.populate_with_modulus(
+                        blu,
+                        shard,
+                        channel,
+                        stage_root,
+                        stage_root,
+                        &modulus,
+                        FieldOperation::Mul,
+                    );
+                    cols.twiddle = U32Field::to_limbs_field::<F, _>(&twiddle);
+                    let next_twiddle = cols.twiddle_step.populate_with_modulus(
+                        blu,
+                        shard,
+                        channel,
+                        &twiddle,
+                        stage_root,
+                        &modulus,
+                        FieldOperation::Mul,
+                    );
+
+                    let (a_record, b_record) =
+                        (records[block + offset], records[block + offset + half]);
+                    cols.memory[0].populate(channel, a_record, blu);
+                    cols.memory[1].populate(channel, b_record, blu);
+                    let a = BigUint::from(a_record.prev_value);
+                    let b = BigUint::from(b_record.prev_value);
+
+                    let (sum, output) = if self.inverse {
+                        let t = cols.product.populate_with_modulus(
+                            blu,
+                            shard,
+                            channel,
+                            &twiddle,
+                            &b,
+                            &modulus,
+                            FieldOperation::Mul,
+                        );
+                        let sum = cols.sum.populate_with_modulus(
+                            blu,
+                            shard,
+                            channel,
+                            &a,
+                            &t,
+                            &modulus,
+                            FieldOperation::Add,
+                        );
+                        let diff = cols.diff.populate_with_modulus(
+                            blu,
+                            shard,
+                            channel,
+                            &a,
+                            &t,
+                            &modulus,
+                            FieldOperation::Sub,
+                        );
+                        (sum, diff)
+                    } else {
+                        let sum = cols.sum.populate_with_modulus(
+                            blu,
+                            shard,
+                            channel,
+                            &a,
+                            &b,
+                            &modulus,
+                            FieldOperation::Add,
+                        );
+                        let diff = cols.diff.populate_with_modulus(
+                            blu,
+                            shard,
+                            channel,
+                            &a,
+                            &b,
+                            &modulus,
+                            FieldOperation::Sub,
+                        );
+                        let product = cols.product.populate_with_modulus(
+                            blu,
+                            shard,
+                            channel,
+                            &diff,
+                            &twiddle,
+                            &modulus,
+                            FieldOperation::Mul,
+                        );
+                        (sum, product)
+                    };
+                    debug_assert_eq!(BigUint::from(a_record.value), sum);
+                    debug_assert_eq!(BigUint::from(b_record.value), output);
+                    cols.output_range_checks[0].populate(blu, shard, channel, &sum, &modulus);
+                    cols.output_range_checks[1].populate(blu, shard, channel, &output, &modulus);
+
+                    twiddle = next_twiddle;
+                    rows.push(row);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use core::borrow::Borrow;
+use std::sync::OnceLock;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{events::FieldOperation, syscalls::SyscallCode};
+use sp1_curves::{
+    edwards::{
+        jubjub::{Jubjub, JubjubBaseField},
+        EdwardsParameters,
+    },
+    params::FieldParameters,
+    pedersen::{pedersen_table, PEDERSEN_WINDOW_BITS, PEDERSEN_WINDOW_SIZE},
+};
+use sp1_stark::air::{BaseAirBuilder, Polynomial, SP1AirBuilder};
+
+use crate::{
+    air::MemoryAirBuilder,
+    utils::{limbs_from_access, limbs_from_prev_access},
+};
+
+use super::{
+    columns::{JubjubAddCols, JubjubLimbs, PedersenHashCols, NUM_PEDERSEN_HASH_COLS},
+    PedersenHashChip,
+};
+
+/// The limbs of the `x` and `y` coordinates of a point in the table.
+type PointLimbs = [Vec<u8>; 2];
+
+impl<F> BaseAir<F> for PedersenHashChip {
+    fn width(&self) -> usize {
+        NUM_PEDERSEN_HASH_COLS
+    }
+}
+
+impl<AB> Air<AB> for PedersenHashChip
+where
+    AB: SP1AirBuilder,
+    JubjubLimbs<AB::Var>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &PedersenHashCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &PedersenHashCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Constrain the window and chunk flags to be one-hot on real rows and to encode the input.
+        let mut window_sum = AB::Expr::zero();
+        let mut chunk_sum = AB::Expr::zero();
+        let mut input = AB::Expr::zero();
+        for (i, &flag) in local.window_flags.iter().enumerate() {
+            builder.assert_bool(flag);
+            window_sum += flag.into();
+            input += AB::Expr::from_canonical_usize(i << PEDERSEN_WINDOW_BITS) * flag;
+        }
+        for (c, &flag) in local.chunk_flags.iter().enumerate() {
+            builder.assert_bool(flag);
+            chunk_sum += flag.into();
+            input += AB::Expr::from_canonical_usize(c) * flag;
+        }
+        builder.assert_eq(window_sum, local.is_real);
+        builder.assert_eq(chunk_sum, local.is_real);
+        builder.assert_eq(input, local.input);
+
+        // Look up the point selected by the flags, which is zero when no flag is set.
+        let selectors = local
+            .window_flags
+            .iter()
+            .flat_map(|&window_flag| {
+                local.chunk_flags.iter().map(move |&chunk_flag| window_flag * chunk_flag)
+            })
+            .collect::<Vec<AB::Expr>>();
+        let points = table_limbs().iter().flatten().collect::<Vec<_>>();
+        for (coordinate, limbs) in [local.point_x, local.point_y].iter().enumerate() {
+            for (j, &limb) in limbs.0.iter().enumerate() {
+                let value = selectors
+                    .iter()
+                    .zip(points.iter())
+                    .filter(|(_, point)| point[coordinate][j] != 0)
+                    .map(|(selector, point)| {
+                        selector.clone() * AB::F::from_canonical_u8(point[coordinate][j])
+                    })
+                    .sum::<AB::Expr>();
+                builder.assert_eq(limb, value);
+            }
+        }
+
+        // Add the point to the accumulator, which is the previous value in memory.
+        let x1: JubjubLimbs<AB::Var> = limbs_from_prev_access(&local.acc_access[0..8]);
+        let y1: JubjubLimbs<AB::Var> = limbs_from_prev_access(&local.acc_access[8..16]);
+        local.add.eval(
+            builder,
+            &x1,
+            &y1,
+            &local.point_x,
+            &local.point_y,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // The written accumulator is the sum, in reduced form.
+        let modulus = Polynomial::from_iter(
+            JubjubBaseField::modulus_field_iter::<AB::F>().map(AB::Expr::from),
+        );
+        let sum = [local.add.x3_ins.result, local.add.y3_ins.result];
+        for (i, result) in sum.into_iter().enumerate() {
+            let output: JubjubLimbs<AB::Var> =
+                limbs_from_access(&local.acc_access[i * 8..(i + 1) * 8]);
+            builder.when(local.is_real).assert_all_eq(output, result);
+            local.output_range_checks[i].eval(
+                builder,
+                &output,
+                &modulus,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Read and write the accumulator.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.acc_ptr,
+            &local.acc_access,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::PEDERSEN_HASH.syscall_id()),
+            local.acc_ptr,
+            local.input,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+/// Returns the limbs of the points in the table, which are computed once since the constraints
+/// are evaluated for every row.
+fn table_limbs() -> &'static [[PointLimbs; PEDERSEN_WINDOW_SIZE]] {
+    static TABLE_LIMBS: OnceLock<Vec<[PointLimbs; PEDERSEN_WINDOW_SIZE]>> = OnceLock::new();
+    TABLE_LIMBS.get_or_init(|| {
+        pedersen_table()
+            .iter()
+            .map(|row| {
+                row.each_ref().map(|point| {
+                    [JubjubBaseField::to_limbs(&point.x), JubjubBaseField::to_limbs(&point.y)]
+                })
+            })
+            .collect()
+    })
+}
+
+impl<V: Copy> JubjubAddCols<V> {
+    /// Evaluates the constraints for the sum of `(x1, y1)` and `(x2, y2)`, whose coordinates are
+    /// `self.x3_ins.result` and `self.y3_ins.result`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        x1: &JubjubLimbs<V>,
+        y1: &JubjubLimbs<V>,
+        x2: &JubjubLimbs<V>,
+        y2: &JubjubLimbs<V>,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+        JubjubLimbs<V>: Copy,
+    {
+        // x3_numerator = x1 * y2 + x2 * y1.
+        self.x3_numerator.eval(
+            builder,
+            &[*x1, *x2],
+            &[*y2, *y1],
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+
+        // y3_numerator = y1 * y2 + x1 * x2.
+        self.y3_numerator.eval(
+            builder,
+            &[*y1, *x1],
+            &[*y2, *x2],
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+
+        // f = x1 * x2 * y1 * y2.
+        self.x1_mul_y1.eval(
+            builder,
+            x1,
+            y1,
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.x2_mul_y2.eval(
+            builder,
+            x2,
+            y2,
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.f.eval(
+            builder,
+            &self.x1_mul_y1.result,
+            &self.x2_mul_y2.result,
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+
+        // d * f.
+        let d_const = JubjubBaseField::to_limbs_field::<AB::Expr, _>(&Jubjub::d_biguint());
+        self.d_mul_f.eval(
+            builder,
+            &self.f.result,
+            &d_const,
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+
+        // x3 = x3_numerator / (1 + d * f).
+        self.x3_ins.eval(
+            builder,
+            &self.x3_numerator.result,
+            &self.d_mul_f.result,
+            true,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+
+        // y3 = y3_numerator / (1 - d * f).
+        self.y3_ins.eval(
+            builder,
+            &self.y3_numerator.result,
+            &self.d_mul_f.result,
+            false,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use core::mem::size_of;
+
+use sp1_core_executor::events::PEDERSEN_POINT_NUM_WORDS;
+use sp1_curves::{
+    edwards::jubjub::JubjubBaseField,
+    params::{Limbs, NumLimbs},
+    pedersen::{PEDERSEN_NUM_WINDOWS, PEDERSEN_WINDOW_SIZE},
+};
+use sp1_derive::AlignedBorrow;
+
+use crate::{
+    memory::MemoryWriteCols,
+    operations::field::{
+        field_den::FieldDenCols, field_inner_product::FieldInnerProductCols, field_op::FieldOpCols,
+        range::FieldLtCols,
+    },
+};
+
+pub const NUM_PEDERSEN_HASH_COLS: usize = size_of::<PedersenHashCols<u8>>();
+
+pub(crate) type JubjubLimbs<T> = Limbs<T, <JubjubBaseField as NumLimbs>::Limbs>;
+
+/// A set of columns for the addition of two Jubjub points `(x1, y1)` and `(x2, y2)`, as in
+/// `EdAddAssignCols`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct JubjubAddCols<T> {
+    pub x3_numerator: FieldInnerProductCols<T, JubjubBaseField>,
+    pub y3_numerator: FieldInnerProductCols<T, JubjubBaseField>,
+    pub x1_mul_y1: FieldOpCols<T, JubjubBaseField>,
+    pub x2_mul_y2: FieldOpCols<T, JubjubBaseField>,
+    pub f: FieldOpCols<T, JubjubBaseField>,
+    pub d_mul_f: FieldOpCols<T, JubjubBaseField>,
+    pub x3_ins: FieldDenCols<T, JubjubBaseField>,
+    pub y3_ins: FieldDenCols<T, JubjubBaseField>,
+}
+
+/// A set of columns needed to add a window of a Pedersen hash to the accumulator.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct PedersenHashCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub acc_ptr: T,
+
+    /// The second argument of the syscall, which packs the window index and its three bits.
+    pub input: T,
+
+    /// The one-hot decomposition of the window index.
+    pub window_flags: [T; PEDERSEN_NUM_WINDOWS],
+
+    /// The one-hot decomposition of the three bits of the window.
+    pub chunk_flags: [T; PEDERSEN_WINDOW_SIZE],
+
+    /// The accumulator, whose previous value is the input and whose value is the output.
+    pub acc_access: [MemoryWriteCols<T>; PEDERSEN_POINT_NUM_WORDS],
+
+    /// The point looked up in the table for the window and its bits.
+    pub point_x: JubjubLimbs<T>,
+    pub point_y: JubjubLimbs<T>,
+
+    /// The sum of the accumulator and the point.
+    pub add: JubjubAddCols<T>,
+
+    /// The checks that the coordinates of the sum are reduced.
+    pub output_range_checks: [FieldLtCols<T, JubjubBaseField>; 2],
+
+    pub is_real: T,
+}
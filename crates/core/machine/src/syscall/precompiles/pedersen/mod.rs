@@ -0,0 +1,91 @@
+mod air;
+pub mod columns;
+mod trace;
+
+/// Implements a window of a Pedersen hash over the Jubjub curve, with the parameters of
+/// `sp1_curves::pedersen`. The inputs to the syscall are a pointer to the accumulator point, whose
+/// 16 words are overwritten with the result, and the index of the window shifted left by three,
+/// plus the three bits of the window. A full hash is one syscall per window, starting from the
+/// identity.
+///
+/// The points added for each window and value are constants of the AIR. The window index and the
+/// bits are decomposed into one-hot flags, which select the point from the table, and the point
+/// is added to the accumulator with the twisted Edwards addition law, as in `EdAddAssignChip`.
+#[derive(Default)]
+pub struct PedersenHashChip;
+
+impl PedersenHashChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(test)]
+pub mod pedersen_tests {
+    use num::BigUint;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        edwards::jubjub::Jubjub,
+        pedersen::{pedersen_add_window, pedersen_hash, pedersen_windows, PEDERSEN_WINDOW_BITS},
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        self, run_test,
+        tests::{call_syscall, store_words, to_words, words_at},
+    };
+
+    const ACC_PTR: u32 = 100;
+
+    /// Builds a program that hashes the input into the accumulator at `ACC_PTR`.
+    pub fn pedersen_hash_program(input: &[u8]) -> Program {
+        let mut instructions = vec![];
+        let identity = Jubjub::neutral();
+        store_words(&mut instructions, words_at(ACC_PTR, 8, &[&identity.x, &identity.y]));
+        for (window, chunk) in pedersen_windows(input).into_iter().enumerate() {
+            let arg2 = ((window as u32) << PEDERSEN_WINDOW_BITS) | chunk;
+            call_syscall(&mut instructions, SyscallCode::PEDERSEN_HASH, ACC_PTR, arg2);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_pedersen_hash_matches_curves() {
+        utils::setup_logger();
+        for input in [vec![], vec![0xa5], (0..64).collect::<Vec<u8>>()] {
+            let mut runtime = Executor::new(pedersen_hash_program(&input), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> = (0..16).map(|i| runtime.word(ACC_PTR + i * 4)).collect();
+
+            let expected = pedersen_windows(&input)
+                .into_iter()
+                .enumerate()
+                .fold(Jubjub::neutral(), |acc, (i, chunk)| pedersen_add_window(&acc, i, chunk));
+            assert_eq!(expected.x, pedersen_hash(&input));
+            let expected: Vec<u32> =
+                to_words(8, &expected.x).into_iter().chain(to_words(8, &expected.y)).collect();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_pedersen_hash_prove() {
+        utils::setup_logger();
+        run_test::<CpuProver<_, _>>(pedersen_hash_program(&[0x00, 0xff, 0x5a])).unwrap();
+    }
+
+    #[test]
+    fn test_pedersen_hash_last_window_prove() {
+        utils::setup_logger();
+        let mut input = vec![0u8; 64];
+        input[63] = 0xc3;
+        let program = pedersen_hash_program(&input);
+        let result = {
+            let mut runtime = Executor::new(program.clone(), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            BigUint::from_slice(&(0..8).map(|i| runtime.word(ACC_PTR + i * 4)).collect::<Vec<_>>())
+        };
+        assert_eq!(result, pedersen_hash(&input));
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+use std::borrow::BorrowMut;
+
+use num::{BigUint, Zero};
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation},
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    edwards::{
+        jubjub::{Jubjub, JubjubBaseField},
+        EdwardsParameters,
+    },
+    params::FieldParameters,
+    pedersen::pedersen_table,
+    AffinePoint,
+};
+use sp1_stark::air::{MachineAir, PaddingStrategy};
+
+use crate::utils::{pad_rows_with_strategy, par_generate_rows};
+
+use super::{
+    columns::{JubjubAddCols, PedersenHashCols, NUM_PEDERSEN_HASH_COLS},
+    PedersenHashChip,
+};
+
+impl<F: PrimeField32> MachineAir<F> for PedersenHashChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "PedersenHash".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let modulus = JubjubBaseField::modulus();
+        let table = pedersen_table();
+
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.pedersen_hash_events, |event, new_byte_lookup_events| {
+                let mut row = [F::zero(); NUM_PEDERSEN_HASH_COLS];
+                let cols: &mut PedersenHashCols<F> = row.as_mut_slice().borrow_mut();
+
+                let (shard, channel) = (event.shard, event.channel);
+
+                cols.shard = F::from_canonical_u32(shard);
+                cols.channel = F::from_canonical_u8(channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.acc_ptr = F::from_canonical_u32(event.acc_ptr);
+                cols.input = F::from_canonical_u32((event.window << 3) | event.chunk);
+                cols.window_flags[event.window as usize] = F::one();
+                cols.chunk_flags[event.chunk as usize] = F::one();
+                cols.is_real = F::one();
+
+                for (mem, record) in cols.acc_access.iter_mut().zip(event.acc_memory_records.iter())
+                {
+                    mem.populate(channel, *record, new_byte_lookup_events);
+                }
+
+                let acc = AffinePoint::<Jubjub>::from_words_le(&event.acc);
+                let point = &table[event.window as usize][event.chunk as usize];
+                cols.point_x = JubjubBaseField::to_limbs_field::<F, _>(&point.x);
+                cols.point_y = JubjubBaseField::to_limbs_field::<F, _>(&point.y);
+
+                let (x3, y3) =
+                    cols.add.populate(new_byte_lookup_events, shard, channel, &acc, point);
+                for (range_check, coordinate) in cols.output_range_checks.iter_mut().zip([x3, y3]) {
+                    range_check.populate(
+                        new_byte_lookup_events,
+                        shard,
+                        channel,
+                        &coordinate,
+                        &modulus,
+                    );
+                }
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // The field operations are not gated by `is_real`, so the padding rows hold the sum of
+        // two zero points, as in `EdAddAssignChip`.
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = [F::zero(); NUM_PEDERSEN_HASH_COLS];
+            let cols: &mut PedersenHashCols<F> = row.as_mut_slice().borrow_mut();
+            let zero = AffinePoint::new(BigUint::zero(), BigUint::zero());
+            cols.add.populate(&mut vec![], 0, 0, &zero, &zero);
+            row
+        });
+
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_PEDERSEN_HASH_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut PedersenHashCols<F> = trace.values
+                [i * NUM_PEDERSEN_HASH_COLS..(i + 1) * NUM_PEDERSEN_HASH_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.pedersen_hash_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F: PrimeField32> JubjubAddCols<F> {
+    /// Populates the columns for the sum of `p` and `q` and returns its coordinates.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        p: &AffinePoint<Jubjub>,
+        q: &AffinePoint<Jubjub>,
+    ) -> (BigUint, BigUint) {
+        let x3_numerator = self.x3_numerator.populate(
+            record,
+            shard,
+            channel,
+            &[p.x.clone(), q.x.clone()],
+            &[q.y.clone(), p.y.clone()],
+        );
+        let y3_numerator = self.y3_numerator.populate(
+            record,
+            shard,
+            channel,
+            &[p.y.clone(), p.x.clone()],
+            &[q.y.clone(), q.x.clone()],
+        );
+        let x1_mul_y1 =
+            self.x1_mul_y1.populate(record, shard, channel, &p.x, &p.y, FieldOperation::Mul);
+        let x2_mul_y2 =
+            self.x2_mul_y2.populate(record, shard, channel, &q.x, &q.y, FieldOperation::Mul);
+        let f =
+            self.f.populate(record, shard, channel, &x1_mul_y1, &x2_mul_y2, FieldOperation::Mul);
+
+        let d = Jubjub::d_biguint();
+        let d_mul_f = self.d_mul_f.populate(record, shard, channel, &f, &d, FieldOperation::Mul);
+
+        let x3 = self.x3_ins.populate(record, shard, channel, &x3_numerator, &d_mul_f, true);
+        let y3 = self.y3_ins.populate(record, shard, channel, &y3_numerator, &d_mul_f, false);
+        (x3, y3)
+    }
+}
@@ -0,0 +1,79 @@
+use std::mem::size_of;
+
+use sp1_curves::{
+    params::{Limbs, NumLimbs},
+    uint32::U32Field,
+};
+use sp1_derive::AlignedBorrow;
+
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{field_op::FieldOpCols, range::FieldLtCols},
+        IsZeroOperation,
+    },
+};
+
+use super::POLY_EVAL_PARAMS_NUM_WORDS;
+
+pub const NUM_POLY_EVAL_COLS: usize = size_of::<PolyEvalCols<u8>>();
+
+/// The limbs of a word, which are its bytes.
+pub(crate) type WordLimbs = <U32Field as NumLimbs>::Limbs;
+
+/// A set of columns for one step of Horner's method.
+///
+/// Each syscall is processed over one row per coefficient, from the leading one down to the
+/// constant one. Every row multiplies the value of the coefficients above it by the point and adds
+/// its coefficient.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct PolyEvalCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub coeffs_ptr: T,
+    pub params_ptr: T,
+
+    /// If the row is real and the first row of the syscall.
+    pub is_first: T,
+
+    /// If the row is real and the last row of the syscall.
+    pub is_last: T,
+
+    /// The index of the coefficient of this row, which goes from the degree down to zero.
+    pub index: T,
+
+    /// Whether the index is zero, which is the last row of the syscall.
+    pub is_index_zero: IsZeroOperation<T>,
+
+    /// Memory columns for the parameters, which are read in the first row.
+    pub params_memory: [MemoryReadCols<T>; POLY_EVAL_PARAMS_NUM_WORDS],
+
+    /// The point, carried through the rows of the syscall.
+    pub point: Limbs<T, WordLimbs>,
+
+    /// The modulus, carried through the rows of the syscall.
+    pub modulus: Limbs<T, WordLimbs>,
+
+    /// The value at the point of the polynomial of the coefficients above this one, which is zero
+    /// in the first row.
+    pub acc: Limbs<T, WordLimbs>,
+
+    /// Memory columns for the coefficient of this row.
+    pub coeff_memory: MemoryReadCols<T>,
+
+    /// `acc * point`.
+    pub product: FieldOpCols<T, U32Field>,
+
+    /// `acc * point + coeff`, the accumulator of the next row.
+    pub sum: FieldOpCols<T, U32Field>,
+
+    /// Checks that the result is reduced in the last row.
+    pub result_range_check: FieldLtCols<T, U32Field>,
+
+    /// Memory columns for the result, which is written over the point in the last row.
+    pub result_memory: MemoryWriteCols<T>,
+}
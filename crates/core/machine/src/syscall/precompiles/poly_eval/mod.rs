@@ -0,0 +1,96 @@
+mod air;
+mod columns;
+mod trace;
+
+/// The number of words of the parameters, which are the degree, the point and the modulus.
+pub const POLY_EVAL_PARAMS_NUM_WORDS: usize = 3;
+
+/// The number of rows that the cost of a syscall is estimated with, one per coefficient of a
+/// polynomial of degree 4095.
+pub const POLY_EVAL_COST_ROWS: usize = 4096;
+
+/// Implements the evaluation of a polynomial at a point modulo a word-sized modulus. The inputs to
+/// the syscall are a pointer to the coefficients, from the constant one up, and a pointer to the
+/// parameters: the degree, below 2^20, the point and a modulus below 2^32. The result is written
+/// over the point.
+///
+/// In the AIR, each syscall takes up one row per coefficient, which encodes one step of Horner's
+/// rule: the accumulator starts from zero in the first row, which reads the leading coefficient,
+/// and each row multiplies it by the point and adds its coefficient to give the accumulator of the
+/// next row. The index of the coefficient goes down by one from one row to the next, and the
+/// syscall ends in the row of the constant coefficient.
+#[derive(Default)]
+pub struct PolyEvalChip;
+
+impl PolyEvalChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod poly_eval_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    const COEFFS_PTR: u32 = 100;
+    const PARAMS_PTR: u32 = 1000;
+
+    /// The prime `119 * 2^23 + 1`.
+    const MODULUS: u32 = 998244353;
+
+    /// Writes the coefficients to memory, and evaluates them at each of the points one after the
+    /// other.
+    pub fn poly_eval_program(coeffs: &[u32], points: &[u32], modulus: u32) -> Program {
+        let mut instructions = vec![];
+        store_words(&mut instructions, slice_at(COEFFS_PTR, coeffs));
+        for &point in points {
+            let params = [coeffs.len() as u32 - 1, point, modulus];
+            store_words(&mut instructions, slice_at(PARAMS_PTR, &params));
+            call_syscall(&mut instructions, SyscallCode::POLY_EVAL, COEFFS_PTR, PARAMS_PTR);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    fn coeffs(len: u32) -> Vec<u32> {
+        (0..len).map(|i| i.wrapping_mul(0x9e3779b9) % MODULUS).collect()
+    }
+
+    #[test]
+    fn test_poly_eval_execute() {
+        setup_logger();
+        let coeffs = coeffs(10);
+        let point = 123456789;
+
+        let program = poly_eval_program(&coeffs, &[point], MODULUS);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        // The result is written over the point.
+        let expected = coeffs.iter().enumerate().fold(0u64, |acc, (i, &c)| {
+            let power = (0..i).fold(1u64, |p, _| p * u64::from(point) % u64::from(MODULUS));
+            (acc + u64::from(c) * power) % u64::from(MODULUS)
+        });
+        assert_eq!(u64::from(runtime.word(PARAMS_PTR + 4)), expected);
+    }
+
+    #[test]
+    fn test_poly_eval_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(poly_eval_program(&coeffs(17), &[5, 0, MODULUS - 1], MODULUS))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_poly_eval_prove_constant() {
+        setup_logger();
+        // A polynomial of degree zero takes a single row, and evaluates to its coefficient.
+        run_test::<CpuProver<_, _>>(poly_eval_program(&[7], &[3], 17)).unwrap();
+        run_test::<CpuProver<_, _>>(poly_eval_program(&[16, 16, 16], &[16, 1], 17)).unwrap();
+    }
+}
@@ -0,0 +1,170 @@
+use std::borrow::BorrowMut;
+
+use num::{BigUint, One, Zero};
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation, PolyEvalEvent},
+    ExecutionRecord, Program,
+};
+use sp1_curves::{params::FieldParameters, uint32::U32Field};
+use sp1_stark::air::{MachineAir, PaddingStrategy};
+
+use super::{
+    columns::{PolyEvalCols, NUM_POLY_EVAL_COLS},
+    PolyEvalChip,
+};
+use crate::utils::{pad_rows_with_strategy, par_generate_rows};
+
+impl<F: PrimeField32> MachineAir<F> for PolyEvalChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "PolyEval".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(&input.poly_eval_events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // The field operations are not gated, so the padded rows run them on zeros. The modulus
+        // of a padded row is one, so that the operations are defined.
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = [F::zero(); NUM_POLY_EVAL_COLS];
+            let cols: &mut PolyEvalCols<F> = row.as_mut_slice().borrow_mut();
+            let zero = BigUint::zero();
+            let modulus = BigUint::one();
+            cols.modulus = U32Field::to_limbs_field::<F, _>(&modulus);
+            for (op, operation) in
+                [(&mut cols.product, FieldOperation::Mul), (&mut cols.sum, FieldOperation::Add)]
+            {
+                op.populate_with_modulus(
+                    &mut Vec::<ByteLookupEvent>::new(),
+                    0,
+                    0,
+                    &zero,
+                    &zero,
+                    &modulus,
+                    operation,
+                );
+            }
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_POLY_EVAL_COLS);
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut PolyEvalCols<F> =
+                trace.values[i * NUM_POLY_EVAL_COLS..(i + 1) * NUM_POLY_EVAL_COLS].borrow_mut();
+            if i > 0 && cols.is_first == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.poly_eval_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl PolyEvalChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &PolyEvalEvent,
+        rows: &mut Vec<[F; NUM_POLY_EVAL_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+        let point = BigUint::from(event.point);
+        let modulus = BigUint::from(event.modulus);
+
+        // Go through the coefficients from the leading one down to the constant one.
+        let mut acc = BigUint::zero();
+        for index in (0..=event.degree as usize).rev() {
+            let mut row = [F::zero(); NUM_POLY_EVAL_COLS];
+            let cols: &mut PolyEvalCols<F> = row.as_mut_slice().borrow_mut();
+
+            let is_first = index == event.degree as usize;
+            let is_last = index == 0;
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(shard);
+            cols.channel = F::from_canonical_u8(channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.coeffs_ptr = F::from_canonical_u32(event.coeffs_ptr);
+            cols.params_ptr = F::from_canonical_u32(event.params_ptr);
+            cols.is_first = F::from_bool(is_first);
+            cols.is_last = F::from_bool(is_last);
+            cols.index = F::from_canonical_usize(index);
+            cols.is_index_zero.populate(index as u32);
+
+            // In the first row, read the parameters.
+            if is_first {
+                for (j, read_record) in event.params_read_records.iter().enumerate() {
+                    cols.params_memory[j].populate(channel, *read_record, blu);
+                }
+            }
+
+            cols.point = U32Field::to_limbs_field::<F, _>(&point);
+            cols.modulus = U32Field::to_limbs_field::<F, _>(&modulus);
+            cols.acc = U32Field::to_limbs_field::<F, _>(&acc);
+
+            let coeff_record = event.coeff_read_records[index];
+            cols.coeff_memory.populate(channel, coeff_record, blu);
+            let coeff = BigUint::from(coeff_record.value);
+
+            let product = cols.product.populate_with_modulus(
+                blu,
+                shard,
+                channel,
+                &acc,
+                &point,
+                &modulus,
+                FieldOperation::Mul,
+            );
+            acc = cols.sum.populate_with_modulus(
+                blu,
+                shard,
+                channel,
+                &product,
+                &coeff,
+                &modulus,
+                FieldOperation::Add,
+            );
+
+            // In the last row, write the result over the point.
+            if is_last {
+                debug_assert_eq!(BigUint::from(event.result_write_record.value), acc);
+                cols.result_range_check.populate(blu, shard, channel, &acc, &modulus);
+                cols.result_memory.populate(channel, event.result_write_record, blu);
+            }
+
+            rows.push(row);
+        }
+    }
+}
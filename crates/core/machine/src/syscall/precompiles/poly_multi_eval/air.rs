@@ -0,0 +1,199 @@
+use std::borrow::Borrow;
+
+use num::BigUint;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{events::FieldOperation, syscalls::SyscallCode};
+use sp1_curves::{
+    params::{FieldParameters, Limbs},
+    uint32::U32Field,
+};
+use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
+
+use super::{
+    columns::{PolyMultiEvalCols, WordLimbs, NUM_POLY_MULTI_EVAL_COLS},
+    PolyMultiEvalChip, POLY_MULTI_EVAL_MAX_POINTS,
+};
+use crate::{
+    air::MemoryAirBuilder,
+    operations::IsZeroOperation,
+    utils::{limbs_from_access, limbs_from_prev_access},
+};
+
+impl<F> BaseAir<F> for PolyMultiEvalChip {
+    fn width(&self) -> usize {
+        NUM_POLY_MULTI_EVAL_COLS
+    }
+}
+
+impl<AB> Air<AB> for PolyMultiEvalChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, WordLimbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &PolyMultiEvalCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &PolyMultiEvalCols<AB::Var> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans a variable number of rows, so the nonce counts the
+        // syscalls rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_first, next.nonce);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+
+        // The syscall ends in the row of the constant coefficient.
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.index.into(),
+            local.is_index_zero,
+            local.is_real.into(),
+        );
+        builder.when(local.is_real).assert_eq(local.is_last, local.is_index_zero.result);
+
+        // The active lanes are the first `count` ones, and there is at least one of them.
+        builder.assert_eq(local.is_active[0], local.is_real);
+        let mut num_active = AB::Expr::zero();
+        for i in 0..POLY_MULTI_EVAL_MAX_POINTS {
+            builder.assert_bool(local.is_active[i]);
+            if i > 0 {
+                builder.when(local.is_active[i]).assert_one(local.is_active[i - 1]);
+            }
+            num_active += local.is_active[i].into();
+        }
+        builder.when(local.is_first).assert_eq(num_active, local.count);
+
+        // A syscall starts in the first real row and in the real row after the end of a syscall,
+        // and goes down to the next coefficient from one row to the next until its end. The values
+        // of the coefficients so far are the accumulators of the next row.
+        builder.when_first_row().assert_eq(local.is_first, local.is_real);
+        builder.when_transition().when(local.is_last).assert_eq(next.is_first, next.is_real);
+        let mut transition_builder = builder.when_transition();
+        let mut in_syscall_builder = transition_builder.when(local.is_real - local.is_last);
+        in_syscall_builder.assert_one(next.is_real);
+        in_syscall_builder.assert_zero(next.is_first);
+        in_syscall_builder.assert_eq(local.shard, next.shard);
+        in_syscall_builder.assert_eq(local.channel, next.channel);
+        in_syscall_builder.assert_eq(local.clk, next.clk);
+        in_syscall_builder.assert_eq(local.params_ptr, next.params_ptr);
+        in_syscall_builder.assert_eq(local.coeffs_ptr, next.coeffs_ptr);
+        in_syscall_builder.assert_eq(local.points_ptr, next.points_ptr);
+        in_syscall_builder.assert_all_eq(local.modulus, next.modulus);
+        in_syscall_builder.assert_all_eq(local.is_active, next.is_active);
+        in_syscall_builder.assert_eq(next.index, local.index - AB::Expr::one());
+        for (lane, next_lane) in local.lanes.iter().zip(next.lanes.iter()) {
+            in_syscall_builder.assert_all_eq(lane.point, next_lane.point);
+            in_syscall_builder.assert_all_eq(next_lane.acc, lane.sum.result);
+        }
+
+        // Once the is_real flag is changed to false, it should not be changed back.
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+
+        // The table cannot end in the middle of a syscall.
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+
+        // Read the parameters in the first row. The degree is below 2^20, so its most significant
+        // byte is zero and it is reduced as a field element. It is the index of the leading
+        // coefficient, which starts from accumulators of zero.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.params_ptr,
+            &local.params_memory,
+            local.is_first,
+        );
+        let coeffs_ptr = local.params_memory[0].access.value;
+        let points_ptr = local.params_memory[1].access.value;
+        let degree = local.params_memory[2].access.value;
+        builder.when(local.is_first).assert_eq(coeffs_ptr.reduce::<AB>(), local.coeffs_ptr);
+        builder.when(local.is_first).assert_eq(points_ptr.reduce::<AB>(), local.points_ptr);
+        builder.when(local.is_first).assert_zero(degree[3]);
+        builder.when(local.is_first).assert_eq(degree.reduce::<AB>(), local.index);
+        let modulus: Limbs<AB::Var, WordLimbs> = limbs_from_access(&local.params_memory[3..4]);
+        builder.when(local.is_first).assert_all_eq(local.modulus, modulus);
+        let zero = U32Field::to_limbs_field::<AB::Expr, _>(&BigUint::from(0u32));
+        for lane in local.lanes.iter() {
+            builder.when(local.is_first).assert_all_eq(lane.acc, zero.clone());
+        }
+
+        // Read the coefficient of the row once for all of the lanes, one clk after the parameters.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk + AB::Expr::one(),
+            local.coeffs_ptr + local.index * AB::F::from_canonical_u32(4),
+            &local.coeff_memory,
+            local.is_real,
+        );
+        let coeff: Limbs<AB::Var, WordLimbs> = limbs_from_access(&[local.coeff_memory]);
+
+        for (i, lane) in local.lanes.iter().enumerate() {
+            // One step of Horner's rule.
+            lane.product.eval_with_modulus(
+                builder,
+                &lane.acc,
+                &lane.point,
+                &local.modulus,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            lane.sum.eval_with_modulus(
+                builder,
+                &lane.product.result,
+                &coeff,
+                &local.modulus,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            // In the last row, write the reduced result of an active lane over its point, one clk
+            // after the coefficients.
+            let is_write = local.is_last * local.is_active[i];
+            lane.result_range_check.eval(
+                builder,
+                &lane.sum.result,
+                &local.modulus,
+                local.shard,
+                local.channel,
+                is_write.clone(),
+            );
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk + AB::Expr::two(),
+                local.points_ptr + AB::F::from_canonical_usize(4 * i),
+                &lane.result_memory,
+                is_write.clone(),
+            );
+            let point: Limbs<AB::Var, WordLimbs> = limbs_from_prev_access(&[lane.result_memory]);
+            let result: Limbs<AB::Var, WordLimbs> = limbs_from_access(&[lane.result_memory]);
+            builder.when(is_write.clone()).assert_all_eq(lane.point, point);
+            builder.when(is_write).assert_all_eq(result, lane.sum.result);
+        }
+
+        // Receive the arguments in the first row of each syscall.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::POLY_MULTI_EVAL.syscall_id()),
+            local.params_ptr,
+            local.count,
+            local.is_first,
+        );
+    }
+}
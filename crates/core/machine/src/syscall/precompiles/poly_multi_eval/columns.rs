@@ -0,0 +1,98 @@
+use std::mem::size_of;
+
+use sp1_curves::{
+    params::{Limbs, NumLimbs},
+    uint32::U32Field,
+};
+use sp1_derive::AlignedBorrow;
+
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{field_op::FieldOpCols, range::FieldLtCols},
+        IsZeroOperation,
+    },
+};
+
+use super::{POLY_MULTI_EVAL_MAX_POINTS, POLY_MULTI_EVAL_PARAMS_NUM_WORDS};
+
+pub const NUM_POLY_MULTI_EVAL_COLS: usize = size_of::<PolyMultiEvalCols<u8>>();
+
+/// The limbs of a word, which are its bytes.
+pub(crate) type WordLimbs = <U32Field as NumLimbs>::Limbs;
+
+/// A set of columns for one step of Horner's method at each of the points of a syscall.
+///
+/// Each syscall is processed over one row per coefficient, from the leading one down to the
+/// constant one. Every row reads its coefficient once, and each lane multiplies the value of the
+/// coefficients above it at its point by the point and adds the coefficient.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct PolyMultiEvalCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub params_ptr: T,
+
+    /// The number of points, which is the second argument of the syscall.
+    pub count: T,
+
+    /// The pointer to the coefficients, carried through the rows of the syscall.
+    pub coeffs_ptr: T,
+
+    /// The pointer to the points, carried through the rows of the syscall.
+    pub points_ptr: T,
+
+    /// If the row is real and the first row of the syscall.
+    pub is_first: T,
+
+    /// If the row is real and the last row of the syscall.
+    pub is_last: T,
+
+    /// The index of the coefficient of this row, which goes from the degree down to zero.
+    pub index: T,
+
+    /// Whether the index is zero, which is the last row of the syscall.
+    pub is_index_zero: IsZeroOperation<T>,
+
+    /// Memory columns for the parameters, which are read in the first row.
+    pub params_memory: [MemoryReadCols<T>; POLY_MULTI_EVAL_PARAMS_NUM_WORDS],
+
+    /// The modulus, carried through the rows of the syscall.
+    pub modulus: Limbs<T, WordLimbs>,
+
+    /// Whether each lane evaluates one of the points, which holds for the first `count` lanes.
+    pub is_active: [T; POLY_MULTI_EVAL_MAX_POINTS],
+
+    /// Memory columns for the coefficient of this row.
+    pub coeff_memory: MemoryReadCols<T>,
+
+    /// The columns of the evaluation at each point.
+    pub lanes: [PolyMultiEvalLaneCols<T>; POLY_MULTI_EVAL_MAX_POINTS],
+}
+
+/// A set of columns for one step of Horner's method at one point.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct PolyMultiEvalLaneCols<T> {
+    /// The point, carried through the rows of the syscall.
+    pub point: Limbs<T, WordLimbs>,
+
+    /// The value at the point of the polynomial of the coefficients above this one, which is zero
+    /// in the first row.
+    pub acc: Limbs<T, WordLimbs>,
+
+    /// `acc * point`.
+    pub product: FieldOpCols<T, U32Field>,
+
+    /// `acc * point + coeff`, the accumulator of the next row.
+    pub sum: FieldOpCols<T, U32Field>,
+
+    /// Checks that the result is reduced in the last row.
+    pub result_range_check: FieldLtCols<T, U32Field>,
+
+    /// Memory columns for the result, which is written over the point in the last row.
+    pub result_memory: MemoryWriteCols<T>,
+}
@@ -0,0 +1,103 @@
+mod air;
+mod columns;
+mod trace;
+
+/// The maximum number of points of a syscall, which is the number of lanes of a row.
+pub const POLY_MULTI_EVAL_MAX_POINTS: usize = 8;
+
+/// The number of words of the parameters, which are the pointer to the coefficients, the pointer
+/// to the points, the degree and the modulus.
+pub const POLY_MULTI_EVAL_PARAMS_NUM_WORDS: usize = 4;
+
+/// Implements the evaluation of a polynomial at up to 8 points modulo a word-sized modulus. The
+/// first input to the syscall is a pointer to the parameters: a pointer to the coefficients, from
+/// the constant one up, a pointer to the points, the degree, below 2^20, and a modulus below 2^32.
+/// The second input is the number of points. The results are written over the points.
+///
+/// In the AIR, each syscall takes up one row per coefficient, as for the
+/// [`super::super::poly_eval::PolyEvalChip`], with one lane per point. The coefficient of a row is
+/// read once and used by every lane, which runs one step of Horner's rule at its point. The lanes
+/// past the number of points run on a point of zero, and write nothing.
+#[derive(Default)]
+pub struct PolyMultiEvalChip;
+
+impl PolyMultiEvalChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod poly_multi_eval_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    const PARAMS_PTR: u32 = 100;
+    const COEFFS_PTR: u32 = 200;
+    const POINTS_PTR: u32 = 1000;
+
+    /// The prime `119 * 2^23 + 1`.
+    const MODULUS: u32 = 998244353;
+
+    /// Writes the coefficients to memory, and evaluates them at each set of points one after the
+    /// other.
+    pub fn poly_multi_eval_program(coeffs: &[u32], calls: &[Vec<u32>], modulus: u32) -> Program {
+        let mut instructions = vec![];
+        store_words(&mut instructions, slice_at(COEFFS_PTR, coeffs));
+        let params = [COEFFS_PTR, POINTS_PTR, coeffs.len() as u32 - 1, modulus];
+        store_words(&mut instructions, slice_at(PARAMS_PTR, &params));
+        for points in calls {
+            store_words(&mut instructions, slice_at(POINTS_PTR, points));
+            let num_points = points.len() as u32;
+            call_syscall(&mut instructions, SyscallCode::POLY_MULTI_EVAL, PARAMS_PTR, num_points);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    fn coeffs(len: u32) -> Vec<u32> {
+        (0..len).map(|i| i.wrapping_mul(0x9e3779b9) % MODULUS).collect()
+    }
+
+    fn expected(coeffs: &[u32], point: u32, modulus: u32) -> u32 {
+        let modulus = u64::from(modulus);
+        coeffs.iter().rev().fold(0, |acc, &c| (acc * u64::from(point) + u64::from(c)) % modulus)
+            as u32
+    }
+
+    #[test]
+    fn test_poly_multi_eval_execute() {
+        setup_logger();
+        let coeffs = coeffs(10);
+        let points = vec![0, 1, 2, 123456789, MODULUS - 1, 42, 7, 99];
+
+        let program = poly_multi_eval_program(&coeffs, &[points.clone()], MODULUS);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        // The results are written over the points.
+        for (i, &point) in points.iter().enumerate() {
+            let result = runtime.word(POINTS_PTR + i as u32 * 4);
+            assert_eq!(result, expected(&coeffs, point, MODULUS));
+        }
+    }
+
+    #[test]
+    fn test_poly_multi_eval_prove() {
+        setup_logger();
+        let calls = [vec![5, 0, MODULUS - 1, 3, 11, 13, 17, 19], vec![123456789], vec![2, 4, 8]];
+        run_test::<CpuProver<_, _>>(poly_multi_eval_program(&coeffs(17), &calls, MODULUS)).unwrap();
+    }
+
+    #[test]
+    fn test_poly_multi_eval_prove_constant() {
+        setup_logger();
+        // A polynomial of degree zero takes a single row, and evaluates to its coefficient at
+        // every point.
+        run_test::<CpuProver<_, _>>(poly_multi_eval_program(&[7], &[vec![3, 16]], 17)).unwrap();
+    }
+}
@@ -0,0 +1,247 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, POPCOUNT_NUM_WORDS},
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::WORD_SIZE;
+use sp1_stark::air::{MachineAir, SP1AirBuilder};
+
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryWriteCols},
+    utils::{pad_rows, par_generate_rows},
+};
+
+/// The number of columns in the PopcountCols.
+const NUM_COLS: usize = size_of::<PopcountCols<u8>>();
+
+/// The number of bytes of the value whose bits are counted.
+const NUM_BYTES: usize = POPCOUNT_NUM_WORDS * WORD_SIZE;
+
+/// A chip that counts the set bits of a 64-bit value.
+///
+/// `ptr` points to the two little endian words of the value. The count is written over the low
+/// word and the high word is cleared, so a 32-bit value is counted with a zero high word.
+///
+/// Each byte of the value is counted with a `POPCNT` lookup into the byte table, and the count is
+/// the sum of the byte counts. It is at most 64, so it fits in the first byte of the result.
+#[derive(Default)]
+pub struct PopcountChip;
+
+impl PopcountChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Popcount operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct PopcountCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the value.
+    pub ptr: T,
+
+    /// The value is the previous value of the memory, and the count is written over it.
+    pub memory: [MemoryWriteCols<T>; POPCOUNT_NUM_WORDS],
+
+    /// The number of set bits in each byte of the value.
+    pub byte_counts: [T; NUM_BYTES],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for PopcountChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Popcount".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.popcount_events, |event, new_byte_lookup_events| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut PopcountCols<F> = row.as_mut_slice().borrow_mut();
+
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.ptr = F::from_canonical_u32(event.ptr);
+
+                for (mem, record) in cols.memory.iter_mut().zip(event.memory_records.iter()) {
+                    mem.populate(event.channel, *record, new_byte_lookup_events);
+                }
+
+                let bytes = event.value.iter().flat_map(|word| word.to_le_bytes());
+                for (count, byte) in cols.byte_counts.iter_mut().zip(bytes) {
+                    *count = F::from_canonical_u32(byte.count_ones());
+                    new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                        event.shard,
+                        event.channel,
+                        ByteOpcode::POPCNT,
+                        byte.count_ones() as u16,
+                        0,
+                        byte,
+                        0,
+                    ));
+                }
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut PopcountCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.popcount_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for PopcountChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for PopcountChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &PopcountCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &PopcountCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Count the set bits of each byte of the value. The lookup also checks that the bytes of
+        // the value are bytes.
+        let value_bytes = local.memory.iter().flat_map(|mem| mem.prev_value().0);
+        for (count, byte) in local.byte_counts.iter().zip(value_bytes) {
+            builder.send_byte(
+                ByteOpcode::POPCNT.as_field::<AB::F>(),
+                *count,
+                byte,
+                AB::Expr::zero(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // The result is the sum of the byte counts, followed by zero bytes.
+        let count = local.byte_counts.iter().fold(AB::Expr::zero(), |acc, count| acc + *count);
+        let result = local.memory.iter().flat_map(|mem| mem.value().0).collect::<Vec<_>>();
+        builder.when(local.is_real).assert_eq(result[0], count);
+        for byte in &result[1..] {
+            builder.when(local.is_real).assert_zero(*byte);
+        }
+
+        // Read the value and write the count.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr,
+            &local.memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::POPCOUNT.syscall_id()),
+            local.ptr,
+            AB::Expr::zero(),
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{self, run_test, tests::syscall_program};
+
+    const PTR: u32 = 100;
+
+    fn popcount_program(value: u64) -> Program {
+        let words = [(PTR, value as u32), (PTR + 4, (value >> 32) as u32)];
+        syscall_program(SyscallCode::POPCOUNT, words, PTR, 0)
+    }
+
+    const VALUES: [u64; 5] = [0, 1, u32::MAX as u64, u64::MAX, 0x8000_0001_1234_5678];
+
+    #[test]
+    fn test_popcount() {
+        utils::setup_logger();
+        for value in VALUES {
+            let mut runtime = Executor::new(popcount_program(value), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            assert_eq!(runtime.word(PTR), value.count_ones());
+            assert_eq!(runtime.word(PTR + 4), 0);
+        }
+    }
+
+    #[test]
+    fn test_popcount_prove() {
+        utils::setup_logger();
+        for value in VALUES {
+            run_test::<CpuProver<_, _>>(popcount_program(value)).unwrap();
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::syscalls::SyscallCode;
+use sp1_primitives::RC_16_30_U32;
+use sp1_stark::air::SP1AirBuilder;
+
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+};
+
+use super::{
+    columns::{Poseidon2PermutationCols, Poseidon2PermuteCols, NUM_POSEIDON2_PERMUTE_COLS},
+    external_linear_layer, internal_linear_layer, Poseidon2PermuteChip, BABYBEAR_FIELD_BITS,
+    NUM_EXTERNAL_ROUNDS, NUM_INTERNAL_ROUNDS, WIDTH,
+};
+
+impl<F> BaseAir<F> for Poseidon2PermuteChip {
+    fn width(&self) -> usize {
+        NUM_POSEIDON2_PERMUTE_COLS
+    }
+}
+
+impl<AB> Air<AB> for Poseidon2PermuteChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Poseidon2PermuteCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Poseidon2PermuteCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The input state is the previous value of the state words. Composing the bytes in the
+        // field reduces each word modulo the BabyBear prime.
+        for i in 0..WIDTH {
+            builder.when(local.is_real).assert_eq(
+                local.permutation.external_rounds_state[0][i],
+                local.state_mem[i].prev_value().reduce::<AB>(),
+            );
+        }
+
+        local.permutation.eval(builder);
+
+        // The written words are the output state, in canonical form.
+        for i in 0..WIDTH {
+            let value = *local.state_mem[i].value();
+            builder
+                .when(local.is_real)
+                .assert_eq(local.permutation.output_state[i], value.reduce::<AB>());
+            builder.assert_in_field(value, local.shard, local.channel, local.is_real);
+        }
+
+        // Read and write the state.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.state_ptr,
+            &local.state_mem,
+            local.is_real,
+        );
+
+        // Receive the arguments. The second argument is the field size parameter, which must be
+        // the one of BabyBear.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::POSEIDON2_PERMUTE.syscall_id()),
+            local.state_ptr,
+            AB::Expr::from_canonical_u32(BABYBEAR_FIELD_BITS),
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+impl<T: Copy> Poseidon2PermutationCols<T> {
+    /// Evaluates the constraints of the permutation. They are not gated, so rows that do not use
+    /// the permutation must still hold a valid one.
+    pub(crate) fn eval<AB: SP1AirBuilder<Var = T>>(&self, builder: &mut AB) {
+        // Apply the full rounds.
+        for r in 0..NUM_EXTERNAL_ROUNDS {
+            self.eval_external_round(builder, r);
+        }
+
+        // Apply the partial rounds.
+        self.eval_internal_rounds(builder);
+    }
+
+    /// Evaluates the constraints for a full round.
+    fn eval_external_round<AB: SP1AirBuilder<Var = T>>(&self, builder: &mut AB, r: usize) {
+        let mut local_state: [AB::Expr; WIDTH] =
+            core::array::from_fn(|i| self.external_rounds_state[r][i].into());
+
+        // For the first round, apply the linear layer.
+        if r == 0 {
+            external_linear_layer(&mut local_state);
+        }
+
+        // Add the round constants and apply the S-boxes. The constant is folded into the S-box
+        // constraint, and the x^3 -> x^7 part of the S-box into the linear layer constraint.
+        let round = if r < NUM_EXTERNAL_ROUNDS / 2 { r } else { r + NUM_INTERNAL_ROUNDS };
+        let mut state: [AB::Expr; WIDTH] = core::array::from_fn(|_| AB::Expr::zero());
+        for i in 0..WIDTH {
+            let add_rc = local_state[i].clone() + AB::F::from_wrapped_u32(RC_16_30_U32[round][i]);
+            let sbox_deg_3: AB::Expr = self.external_rounds_sbox[r][i].into();
+            builder.assert_eq(sbox_deg_3.clone(), add_rc.clone() * add_rc.clone() * add_rc.clone());
+            state[i] = sbox_deg_3.clone() * sbox_deg_3 * add_rc;
+        }
+
+        // Apply the linear layer.
+        external_linear_layer(&mut state);
+
+        let next_state = if r == NUM_EXTERNAL_ROUNDS / 2 - 1 {
+            &self.internal_rounds_state
+        } else if r == NUM_EXTERNAL_ROUNDS - 1 {
+            &self.output_state
+        } else {
+            &self.external_rounds_state[r + 1]
+        };
+
+        for i in 0..WIDTH {
+            builder.assert_eq(next_state[i], state[i].clone());
+        }
+    }
+
+    /// Evaluates the constraints for the partial rounds.
+    fn eval_internal_rounds<AB: SP1AirBuilder<Var = T>>(&self, builder: &mut AB) {
+        let mut state: [AB::Expr; WIDTH] =
+            core::array::from_fn(|i| self.internal_rounds_state[i].into());
+        for r in 0..NUM_INTERNAL_ROUNDS {
+            // Add the round constant to the first element and apply the S-box to it.
+            let round = r + NUM_EXTERNAL_ROUNDS / 2;
+            let add_rc =
+                if r == 0 { state[0].clone() } else { self.internal_rounds_s0[r - 1].into() }
+                    + AB::F::from_wrapped_u32(RC_16_30_U32[round][0]);
+            let sbox_deg_3: AB::Expr = self.internal_rounds_sbox[r].into();
+            builder.assert_eq(sbox_deg_3.clone(), add_rc.clone() * add_rc.clone() * add_rc.clone());
+            state[0] = sbox_deg_3.clone() * sbox_deg_3 * add_rc;
+
+            // Apply the linear layer.
+            internal_linear_layer(&mut state);
+
+            if r < NUM_INTERNAL_ROUNDS - 1 {
+                builder.assert_eq(self.internal_rounds_s0[r], state[0].clone());
+            }
+        }
+
+        let external_state = self.external_rounds_state[NUM_EXTERNAL_ROUNDS / 2];
+        for i in 0..WIDTH {
+            builder.assert_eq(external_state[i], state[i].clone());
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use core::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+
+use crate::memory::MemoryWriteCols;
+
+use super::{NUM_EXTERNAL_ROUNDS, NUM_INTERNAL_ROUNDS, WIDTH};
+
+pub const NUM_POSEIDON2_PERMUTE_COLS: usize = size_of::<Poseidon2PermuteCols<u8>>();
+
+/// A set of columns needed to compute the Poseidon2 permutation.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct Poseidon2PermuteCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub state_ptr: T,
+
+    /// Memory columns for the state. The input is the previous value of each word, and the output
+    /// is written over it.
+    pub state_mem: [MemoryWriteCols<T>; WIDTH],
+
+    /// The columns of the permutation.
+    pub permutation: Poseidon2PermutationCols<T>,
+
+    pub is_real: T,
+}
+
+/// The columns of a single Poseidon2 permutation, whose input is the state at the start of the
+/// first full round.
+///
+/// Only the inputs of the S-boxes and the cubes of those inputs get columns. The rest of the S-box,
+/// the linear layers and the round constants are folded into the constraints for the next state.
+#[derive(AlignedBorrow, Clone, Copy, Default)]
+#[repr(C)]
+pub struct Poseidon2PermutationCols<T> {
+    /// The state at the start of each full round.
+    pub external_rounds_state: [[T; WIDTH]; NUM_EXTERNAL_ROUNDS],
+
+    /// The cubes of the S-box inputs of each full round.
+    pub external_rounds_sbox: [[T; WIDTH]; NUM_EXTERNAL_ROUNDS],
+
+    /// The state at the start of the partial rounds.
+    pub internal_rounds_state: [T; WIDTH],
+
+    /// The first element of the state after each partial round but the last.
+    pub internal_rounds_s0: [T; NUM_INTERNAL_ROUNDS - 1],
+
+    /// The cubes of the S-box inputs of each partial round.
+    pub internal_rounds_sbox: [T; NUM_INTERNAL_ROUNDS],
+
+    /// The state after the last full round. It is a separate column so that the constraints on
+    /// the output can be gated without exceeding the degree.
+    pub output_state: [T; WIDTH],
+}
@@ -0,0 +1,142 @@
+#![allow(clippy::needless_range_loop)]
+
+mod air;
+pub mod columns;
+mod trace;
+
+use p3_baby_bear::{MONTY_INVERSE, POSEIDON2_INTERNAL_MATRIX_DIAG_16_BABYBEAR_MONTY};
+use p3_field::{AbstractField, PrimeField32};
+use p3_poseidon2::matmul_internal;
+
+/// The number of BabyBear elements in the state.
+pub const WIDTH: usize = 16;
+
+/// The number of full rounds, half of which come before the partial rounds.
+pub const NUM_EXTERNAL_ROUNDS: usize = 8;
+
+/// The number of partial rounds, which only apply the S-box to the first element.
+pub const NUM_INTERNAL_ROUNDS: usize = 13;
+
+/// The field size parameter of the syscall, which is the number of bits of the BabyBear modulus.
+pub const BABYBEAR_FIELD_BITS: u32 = 31;
+
+/// Implements the Poseidon2 permutation over BabyBear, with a width of 16 and the round constants
+/// of `sp1_primitives::RC_16_30`, so that it matches the permutation used by the prover. The
+/// inputs to the syscall are a pointer to the 16 word state, which is overwritten with the result,
+/// and the field size parameter, which must be `BABYBEAR_FIELD_BITS`.
+///
+/// The input words are reduced modulo the BabyBear prime, and the output words are canonical.
+///
+/// In the AIR, each syscall takes up a single row. The S-box is `x^7`, which is split into a column
+/// for `x^3` and a degree 3 constraint for the rest of the power and the linear layer.
+#[derive(Default)]
+pub struct Poseidon2PermuteChip;
+
+impl Poseidon2PermuteChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Multiplies four state elements by the 4x4 MDS matrix of the external linear layer.
+pub(crate) fn apply_m_4<AF: AbstractField>(x: &mut [AF]) {
+    let t01 = x[0].clone() + x[1].clone();
+    let t23 = x[2].clone() + x[3].clone();
+    let t0123 = t01.clone() + t23.clone();
+    let t01123 = t0123.clone() + x[1].clone();
+    let t01233 = t0123.clone() + x[3].clone();
+    // The order here is important. Need to overwrite x[0] and x[2] after x[1] and x[3].
+    x[3] = t01233.clone() + x[0].double(); // 3*x[0] + x[1] + x[2] + 2*x[3]
+    x[1] = t01123.clone() + x[2].double(); // x[0] + 2*x[1] + 3*x[2] + x[3]
+    x[0] = t01123 + t01; // 2*x[0] + 3*x[1] + x[2] + x[3]
+    x[2] = t01233 + t23; // x[0] + x[1] + 2*x[2] + 3*x[3]
+}
+
+/// Applies the linear layer of the full rounds.
+pub(crate) fn external_linear_layer<AF: AbstractField>(state: &mut [AF; WIDTH]) {
+    for j in (0..WIDTH).step_by(4) {
+        apply_m_4(&mut state[j..j + 4]);
+    }
+    let sums: [AF; 4] =
+        core::array::from_fn(|k| (0..WIDTH).step_by(4).map(|j| state[j + k].clone()).sum::<AF>());
+
+    for j in 0..WIDTH {
+        state[j] += sums[j % 4].clone();
+    }
+}
+
+pub(crate) fn external_linear_layer_immut<AF: AbstractField + Copy>(
+    state: &[AF; WIDTH],
+) -> [AF; WIDTH] {
+    let mut state = *state;
+    external_linear_layer(&mut state);
+    state
+}
+
+/// Applies the linear layer of the partial rounds.
+pub(crate) fn internal_linear_layer<AF: AbstractField>(state: &mut [AF; WIDTH]) {
+    let matmul_constants: [AF::F; WIDTH] = POSEIDON2_INTERNAL_MATRIX_DIAG_16_BABYBEAR_MONTY
+        .map(|x| AF::F::from_wrapped_u32(x.as_canonical_u32()));
+    matmul_internal(state, matmul_constants);
+    let monty_inverse = AF::from_wrapped_u32(MONTY_INVERSE.as_canonical_u32());
+    state.iter_mut().for_each(|i| *i *= monty_inverse.clone());
+}
+
+#[cfg(test)]
+pub mod permute_tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::{AbstractField, PrimeField32};
+    use p3_symmetric::Permutation;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_primitives::poseidon2_init;
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use super::{BABYBEAR_FIELD_BITS, WIDTH};
+    use crate::utils::{
+        self, run_test,
+        tests::{slice_at, syscall_program},
+    };
+
+    const STATE_PTR: u32 = 100;
+
+    pub fn poseidon2_permute_program(state: &[u32; WIDTH]) -> Program {
+        let words = slice_at(STATE_PTR, state);
+        syscall_program(SyscallCode::POSEIDON2_PERMUTE, words, STATE_PTR, BABYBEAR_FIELD_BITS)
+    }
+
+    fn poseidon2_cases() -> Vec<[u32; WIDTH]> {
+        let mut rng = StdRng::seed_from_u64(0);
+        vec![
+            [0; WIDTH],
+            core::array::from_fn(|i| i as u32),
+            // Words that are not canonical BabyBear elements are reduced.
+            [u32::MAX; WIDTH],
+            core::array::from_fn(|_| rng.gen()),
+        ]
+    }
+
+    #[test]
+    fn test_poseidon2_permute_matches_primitives() {
+        utils::setup_logger();
+        let perm = poseidon2_init();
+        for state in poseidon2_cases() {
+            let mut runtime =
+                Executor::new(poseidon2_permute_program(&state), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> =
+                (0..WIDTH as u32).map(|i| runtime.word(STATE_PTR + i * 4)).collect();
+
+            let expected = perm.permute(state.map(BabyBear::from_wrapped_u32));
+            assert_eq!(result, expected.map(|x| x.as_canonical_u32()));
+        }
+    }
+
+    #[test]
+    fn test_poseidon2_permute_prove() {
+        utils::setup_logger();
+        for state in poseidon2_cases() {
+            run_test::<CpuProver<_, _>>(poseidon2_permute_program(&state)).unwrap();
+        }
+    }
+}
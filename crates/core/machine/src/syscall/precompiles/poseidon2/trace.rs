@@ -0,0 +1,172 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{events::ByteRecord, ExecutionRecord, Program};
+use sp1_primitives::RC_16_30_U32;
+use sp1_stark::air::{MachineAir, PaddingStrategy};
+
+use crate::utils::{pad_rows_with_strategy, par_generate_rows};
+
+use super::{
+    columns::{Poseidon2PermutationCols, Poseidon2PermuteCols, NUM_POSEIDON2_PERMUTE_COLS},
+    external_linear_layer, external_linear_layer_immut, internal_linear_layer,
+    Poseidon2PermuteChip, NUM_EXTERNAL_ROUNDS, NUM_INTERNAL_ROUNDS, WIDTH,
+};
+
+impl<F: PrimeField32> MachineAir<F> for Poseidon2PermuteChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Poseidon2Permute".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.poseidon2_permute_events, |event, new_byte_lookup_events| {
+                let mut row = [F::zero(); NUM_POSEIDON2_PERMUTE_COLS];
+                let cols: &mut Poseidon2PermuteCols<F> = row.as_mut_slice().borrow_mut();
+
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+                cols.is_real = F::one();
+
+                for i in 0..WIDTH {
+                    cols.state_mem[i].populate(
+                        event.channel,
+                        event.state_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    new_byte_lookup_events.add_in_field_range_checks(
+                        event.shard,
+                        event.channel,
+                        event.output[i],
+                    );
+                }
+
+                let output_state = cols.permutation.populate(event.input.map(F::from_wrapped_u32));
+                debug_assert_eq!(output_state, event.output.map(F::from_canonical_u32));
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // The permutation constraints are not gated by `is_real`, so the padding rows hold the
+        // permutation of the zero state.
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = [F::zero(); NUM_POSEIDON2_PERMUTE_COLS];
+            let cols: &mut Poseidon2PermuteCols<F> = row.as_mut_slice().borrow_mut();
+            cols.permutation.populate([F::zero(); WIDTH]);
+            row
+        });
+
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_POSEIDON2_PERMUTE_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Poseidon2PermuteCols<F> = trace.values
+                [i * NUM_POSEIDON2_PERMUTE_COLS..(i + 1) * NUM_POSEIDON2_PERMUTE_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.poseidon2_permute_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F: PrimeField32> Poseidon2PermutationCols<F> {
+    /// Populates the permutation columns for the given input and returns the output state.
+    pub(crate) fn populate(&mut self, input: [F; WIDTH]) -> [F; WIDTH] {
+        self.external_rounds_state[0] = input;
+
+        // Apply the first half of the full rounds.
+        for r in 0..NUM_EXTERNAL_ROUNDS / 2 {
+            let next_state = self.populate_external_round(r);
+            if r == NUM_EXTERNAL_ROUNDS / 2 - 1 {
+                self.internal_rounds_state = next_state;
+            } else {
+                self.external_rounds_state[r + 1] = next_state;
+            }
+        }
+
+        // Apply the partial rounds.
+        self.external_rounds_state[NUM_EXTERNAL_ROUNDS / 2] = self.populate_internal_rounds();
+
+        // Apply the second half of the full rounds.
+        for r in NUM_EXTERNAL_ROUNDS / 2..NUM_EXTERNAL_ROUNDS {
+            let next_state = self.populate_external_round(r);
+            if r == NUM_EXTERNAL_ROUNDS - 1 {
+                self.output_state = next_state;
+            } else {
+                self.external_rounds_state[r + 1] = next_state;
+            }
+        }
+
+        self.output_state
+    }
+
+    fn populate_external_round(&mut self, r: usize) -> [F; WIDTH] {
+        // For the first round, apply the linear layer.
+        let round_state = if r == 0 {
+            external_linear_layer_immut(&self.external_rounds_state[r])
+        } else {
+            self.external_rounds_state[r]
+        };
+
+        // Add the round constants and apply the S-boxes.
+        let round = if r < NUM_EXTERNAL_ROUNDS / 2 { r } else { r + NUM_INTERNAL_ROUNDS };
+        let mut state = [F::zero(); WIDTH];
+        for i in 0..WIDTH {
+            let add_rc = round_state[i] + F::from_wrapped_u32(RC_16_30_U32[round][i]);
+            let sbox_deg_3 = add_rc * add_rc * add_rc;
+            self.external_rounds_sbox[r][i] = sbox_deg_3;
+            state[i] = sbox_deg_3 * sbox_deg_3 * add_rc;
+        }
+
+        // Apply the linear layer.
+        external_linear_layer(&mut state);
+        state
+    }
+
+    fn populate_internal_rounds(&mut self) -> [F; WIDTH] {
+        let mut state = self.internal_rounds_state;
+        for r in 0..NUM_INTERNAL_ROUNDS {
+            // Add the round constant to the first element and apply the S-box to it.
+            let round = r + NUM_EXTERNAL_ROUNDS / 2;
+            let add_rc = state[0] + F::from_wrapped_u32(RC_16_30_U32[round][0]);
+            let sbox_deg_3 = add_rc * add_rc * add_rc;
+            self.internal_rounds_sbox[r] = sbox_deg_3;
+            state[0] = sbox_deg_3 * sbox_deg_3 * add_rc;
+
+            // Apply the linear layer.
+            internal_linear_layer(&mut state);
+
+            // The rest of the state is a linear function of the state at the start of the partial
+            // rounds and of the first element after each round, so only the latter gets columns.
+            if r < NUM_INTERNAL_ROUNDS - 1 {
+                self.internal_rounds_s0[r] = state[0];
+            }
+        }
+        state
+    }
+}
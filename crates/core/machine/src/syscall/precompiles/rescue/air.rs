@@ -0,0 +1,291 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{events::FieldOperation, syscalls::SyscallCode};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs},
+    rescue::{rescue_round_constants, RESCUE_NUM_ROUNDS, RESCUE_WIDTH},
+    weierstrass::bn254::Bn254ScalarField,
+};
+use sp1_stark::air::{BaseAirBuilder, Polynomial, SP1AirBuilder};
+
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    utils::{limbs_from_access, limbs_from_prev_access},
+};
+
+use super::{
+    columns::{LinearLayerCols, QuinticPowerCols, RescuePrimeCols, NUM_RESCUE_PRIME_COLS},
+    RescuePrimeChip,
+};
+
+type ScalarLimbs<T> = Limbs<T, <Bn254ScalarField as NumLimbs>::Limbs>;
+
+impl<F> BaseAir<F> for RescuePrimeChip {
+    fn width(&self) -> usize {
+        NUM_RESCUE_PRIME_COLS
+    }
+}
+
+impl<AB> Air<AB> for RescuePrimeChip
+where
+    AB: SP1AirBuilder,
+    ScalarLimbs<AB::Var>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &RescuePrimeCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &RescuePrimeCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Constrain the round flags to be one-hot on real rows and to encode the round.
+        let mut flag_sum = AB::Expr::zero();
+        let mut round = AB::Expr::zero();
+        for (r, &flag) in local.round_flags.iter().enumerate() {
+            builder.assert_bool(flag);
+            flag_sum += flag.into();
+            round += AB::Expr::from_canonical_usize(r) * flag;
+        }
+        builder.assert_eq(flag_sum, local.is_real);
+        builder.assert_eq(round, local.round);
+
+        // Reduce the input elements, which are the previous values of the state.
+        let zero: Polynomial<AB::Expr> = Polynomial::from_coefficients(&[AB::Expr::zero()]);
+        for i in 0..RESCUE_WIDTH {
+            let input: ScalarLimbs<AB::Var> =
+                limbs_from_prev_access(&local.state_mem[i * 8..(i + 1) * 8]);
+            local.reduced_input[i].eval(
+                builder,
+                &input,
+                &zero,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Apply the forward S-boxes and the first linear layer.
+        for i in 0..RESCUE_WIDTH {
+            local.forward_sbox[i].eval(
+                builder,
+                &local.reduced_input[i].result,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+        local.forward_layer.eval(
+            builder,
+            &core::array::from_fn(|i| local.forward_sbox[i].fifth.result),
+            &Self::round_constants::<AB>(&local.round_flags, 0),
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Check that the fifth powers of the inverse S-box outputs are the state.
+        for i in 0..RESCUE_WIDTH {
+            builder.slice_range_check_u8(
+                &local.inverse_sbox_root[i].0,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.inverse_sbox[i].eval(
+                builder,
+                &local.inverse_sbox_root[i],
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            builder.when(local.is_real).assert_all_eq(
+                local.inverse_sbox[i].fifth.result,
+                local.forward_layer.constants[i].result,
+            );
+        }
+
+        // Apply the second linear layer.
+        local.inverse_layer.eval(
+            builder,
+            &local.inverse_sbox_root,
+            &Self::round_constants::<AB>(&local.round_flags, 1),
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // The written elements are the result of the round, in reduced form.
+        let modulus = Polynomial::from_iter(
+            Bn254ScalarField::modulus_field_iter::<AB::F>().map(AB::Expr::from),
+        );
+        for i in 0..RESCUE_WIDTH {
+            let output: ScalarLimbs<AB::Var> =
+                limbs_from_access(&local.state_mem[i * 8..(i + 1) * 8]);
+            builder
+                .when(local.is_real)
+                .assert_all_eq(output, local.inverse_layer.constants[i].result);
+            local.output_range_checks[i].eval(
+                builder,
+                &output,
+                &modulus,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Read and write the state.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.state_ptr,
+            &local.state_mem,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::RESCUE_PERMUTE.syscall_id()),
+            local.state_ptr,
+            local.round,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+impl RescuePrimeChip {
+    /// Returns the round constants of the given half of the round selected by the flags, as
+    /// polynomials whose coefficients are the limbs. The result is zero when no flag is set.
+    fn round_constants<AB: SP1AirBuilder>(
+        round_flags: &[AB::Var; RESCUE_NUM_ROUNDS],
+        half: usize,
+    ) -> [Polynomial<AB::Expr>; RESCUE_WIDTH] {
+        let constants = (0..RESCUE_NUM_ROUNDS)
+            .map(|r| rescue_round_constants(2 * r + half).map(|c| Bn254ScalarField::to_limbs(&c)))
+            .collect::<Vec<_>>();
+        core::array::from_fn(|i| {
+            Polynomial::from_iter((0..Bn254ScalarField::NB_LIMBS).map(|j| {
+                round_flags
+                    .iter()
+                    .zip(constants.iter())
+                    .map(|(&flag, c)| AB::Expr::from_canonical_u8(c[i][j]) * flag)
+                    .sum::<AB::Expr>()
+            }))
+        })
+    }
+}
+
+impl<V: Copy> QuinticPowerCols<V> {
+    /// Evaluates the constraints for `x^5`, whose result is `self.fifth.result`.
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        x: &ScalarLimbs<V>,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+        ScalarLimbs<V>: Copy,
+    {
+        self.square.eval(
+            builder,
+            x,
+            x,
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.fourth.eval(
+            builder,
+            &self.square.result,
+            &self.square.result,
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.fifth.eval(
+            builder,
+            &self.fourth.result,
+            x,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
+
+impl<V: Copy> LinearLayerCols<V> {
+    /// Evaluates the constraints for the linear layer and the round constants, whose result is
+    /// `self.constants[i].result`.
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        state: &[ScalarLimbs<V>; RESCUE_WIDTH],
+        constants: &[Polynomial<AB::Expr>; RESCUE_WIDTH],
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+        ScalarLimbs<V>: Copy,
+    {
+        self.partial_sum.eval(
+            builder,
+            &state[0],
+            &state[1],
+            FieldOperation::Add,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.sum.eval(
+            builder,
+            &self.partial_sum.result,
+            &state[2],
+            FieldOperation::Add,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        for i in 0..RESCUE_WIDTH {
+            self.mds[i].eval(
+                builder,
+                &self.sum.result,
+                &state[i],
+                FieldOperation::Add,
+                shard.clone(),
+                channel.clone(),
+                is_real.clone(),
+            );
+            self.constants[i].eval(
+                builder,
+                &self.mds[i].result,
+                &constants[i],
+                FieldOperation::Add,
+                shard.clone(),
+                channel.clone(),
+                is_real.clone(),
+            );
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use core::mem::size_of;
+
+use sp1_core_executor::events::RESCUE_STATE_NUM_WORDS;
+use sp1_curves::{
+    params::{Limbs, NumLimbs},
+    rescue::{RESCUE_NUM_ROUNDS, RESCUE_WIDTH},
+    weierstrass::bn254::Bn254ScalarField,
+};
+use sp1_derive::AlignedBorrow;
+
+use crate::{
+    memory::MemoryWriteCols,
+    operations::field::{field_op::FieldOpCols, range::FieldLtCols},
+};
+
+pub const NUM_RESCUE_PRIME_COLS: usize = size_of::<RescuePrimeCols<u8>>();
+
+type ScalarLimbs<T> = Limbs<T, <Bn254ScalarField as NumLimbs>::Limbs>;
+
+/// A set of columns for the fifth power of a field element.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct QuinticPowerCols<T> {
+    pub square: FieldOpCols<T, Bn254ScalarField>,
+    pub fourth: FieldOpCols<T, Bn254ScalarField>,
+    pub fifth: FieldOpCols<T, Bn254ScalarField>,
+}
+
+/// A set of columns for the linear layer followed by the addition of the round constants.
+///
+/// The MDS matrix is `J + I`, so each element of the result is the sum of the state, plus the
+/// element of the state at the same index, plus the round constant.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct LinearLayerCols<T> {
+    /// The sum of the first two elements.
+    pub partial_sum: FieldOpCols<T, Bn254ScalarField>,
+    /// The sum of the state.
+    pub sum: FieldOpCols<T, Bn254ScalarField>,
+    /// The product of the MDS matrix and the state.
+    pub mds: [FieldOpCols<T, Bn254ScalarField>; RESCUE_WIDTH],
+    /// The product plus the round constants, which is the result of the layer.
+    pub constants: [FieldOpCols<T, Bn254ScalarField>; RESCUE_WIDTH],
+}
+
+/// A set of columns needed to compute a round of the Rescue-Prime permutation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct RescuePrimeCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub state_ptr: T,
+
+    /// The index of the round.
+    pub round: T,
+
+    /// One-hot flags of the round, which select the round constants.
+    pub round_flags: [T; RESCUE_NUM_ROUNDS],
+
+    /// Memory columns for the state. The input is the previous value of each word, and the output
+    /// is written over it.
+    pub state_mem: [MemoryWriteCols<T>; RESCUE_STATE_NUM_WORDS],
+
+    /// The input elements reduced modulo the field order, as the sum of the input and zero.
+    pub reduced_input: [FieldOpCols<T, Bn254ScalarField>; RESCUE_WIDTH],
+
+    /// The forward S-boxes.
+    pub forward_sbox: [QuinticPowerCols<T>; RESCUE_WIDTH],
+
+    /// The linear layer after the forward S-boxes.
+    pub forward_layer: LinearLayerCols<T>,
+
+    /// The outputs of the inverse S-boxes, whose fifth powers are the inputs.
+    pub inverse_sbox_root: [ScalarLimbs<T>; RESCUE_WIDTH],
+
+    /// The fifth powers of the inverse S-box outputs.
+    pub inverse_sbox: [QuinticPowerCols<T>; RESCUE_WIDTH],
+
+    /// The linear layer after the inverse S-boxes, whose result is the output.
+    pub inverse_layer: LinearLayerCols<T>,
+
+    /// Range checks that the output elements are reduced.
+    pub output_range_checks: [FieldLtCols<T, Bn254ScalarField>; RESCUE_WIDTH],
+
+    pub is_real: T,
+}
@@ -0,0 +1,83 @@
+#![allow(clippy::needless_range_loop)]
+
+mod air;
+pub mod columns;
+mod trace;
+
+/// Implements a round of the Rescue-Prime permutation over the BN254 scalar field, with a state of
+/// three elements and the parameters of `sp1_curves::rescue`. The inputs to the syscall are a
+/// pointer to the state, whose 24 words are overwritten with the result, and the index of the
+/// round, which selects the round constants. A full permutation is one syscall per round.
+///
+/// The input elements need not be reduced, and the output elements are. The arithmetic is
+/// emulated with `FieldOpCols`. The forward S-box `x^5` is a chain of multiplications, and the
+/// inverse S-box `x^(1/5)` is witnessed as a root whose fifth power is constrained with the same
+/// chain, which is sound because `x -> x^5` is a bijection of the field.
+#[derive(Default)]
+pub struct RescuePrimeChip;
+
+impl RescuePrimeChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(test)]
+pub mod rescue_tests {
+    use num::BigUint;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::rescue::{rescue_permute, RESCUE_NUM_ROUNDS, RESCUE_WIDTH};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        self, run_test,
+        tests::{call_syscall, store_words, to_words, words_at},
+    };
+
+    const STATE_PTR: u32 = 100;
+
+    pub fn rescue_permute_program(state: &[BigUint; RESCUE_WIDTH], rounds: &[u32]) -> Program {
+        let mut instructions = vec![];
+        store_words(&mut instructions, words_at(STATE_PTR, 8, &state.iter().collect::<Vec<_>>()));
+        for &round in rounds {
+            call_syscall(&mut instructions, SyscallCode::RESCUE_PERMUTE, STATE_PTR, round);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    fn rescue_cases() -> Vec<[BigUint; RESCUE_WIDTH]> {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut random = || BigUint::from_bytes_le(&rng.gen::<[u8; 32]>());
+        vec![
+            core::array::from_fn(|_| BigUint::from(0u32)),
+            core::array::from_fn(|i| BigUint::from(i as u32 + 1)),
+            // The elements are not reduced.
+            core::array::from_fn(|_| random()),
+        ]
+    }
+
+    #[test]
+    fn test_rescue_permute_matches_curves() {
+        utils::setup_logger();
+        let rounds = (0..RESCUE_NUM_ROUNDS as u32).collect::<Vec<_>>();
+        for state in rescue_cases() {
+            let mut runtime =
+                Executor::new(rescue_permute_program(&state, &rounds), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> = (0..24).map(|i| runtime.word(STATE_PTR + i * 4)).collect();
+
+            let expected: Vec<u32> =
+                rescue_permute(&state).iter().flat_map(|x| to_words(8, x)).collect();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_rescue_permute_prove() {
+        utils::setup_logger();
+        for state in rescue_cases() {
+            run_test::<CpuProver<_, _>>(rescue_permute_program(&state, &[0, 5])).unwrap();
+        }
+    }
+}
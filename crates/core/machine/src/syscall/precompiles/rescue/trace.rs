@@ -0,0 +1,235 @@
+use std::borrow::BorrowMut;
+
+use num::{BigUint, Zero};
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation},
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::FieldParameters,
+    rescue::{rescue_alpha_inverse, rescue_round_constants, RESCUE_WIDTH},
+    weierstrass::bn254::Bn254ScalarField,
+};
+use sp1_stark::air::{MachineAir, PaddingStrategy};
+
+use crate::utils::{
+    pad_rows_with_strategy, par_generate_rows, words_to_bytes_le, words_to_bytes_le_vec,
+};
+
+use super::{
+    columns::{LinearLayerCols, QuinticPowerCols, RescuePrimeCols, NUM_RESCUE_PRIME_COLS},
+    RescuePrimeChip,
+};
+
+impl<F: PrimeField32> MachineAir<F> for RescuePrimeChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "RescuePrime".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let modulus = Bn254ScalarField::modulus();
+        let alpha_inverse = rescue_alpha_inverse();
+
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.rescue_permute_events, |event, new_byte_lookup_events| {
+                let mut row = [F::zero(); NUM_RESCUE_PRIME_COLS];
+                let cols: &mut RescuePrimeCols<F> = row.as_mut_slice().borrow_mut();
+
+                let (shard, channel) = (event.shard, event.channel);
+                let round = event.round as usize;
+
+                cols.shard = F::from_canonical_u32(shard);
+                cols.channel = F::from_canonical_u8(channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+                cols.round = F::from_canonical_u32(event.round);
+                cols.round_flags[round] = F::one();
+                cols.is_real = F::one();
+
+                for (mem, record) in
+                    cols.state_mem.iter_mut().zip(event.state_memory_records.iter())
+                {
+                    mem.populate(channel, *record, new_byte_lookup_events);
+                }
+
+                // Reduce the input and apply the forward S-boxes.
+                let forward: [BigUint; RESCUE_WIDTH] = core::array::from_fn(|i| {
+                    let input = BigUint::from_bytes_le(&words_to_bytes_le::<32>(
+                        &event.input[i * 8..(i + 1) * 8],
+                    ));
+                    let reduced = cols.reduced_input[i].populate(
+                        new_byte_lookup_events,
+                        shard,
+                        channel,
+                        &input,
+                        &BigUint::zero(),
+                        FieldOperation::Add,
+                    );
+                    cols.forward_sbox[i].populate(new_byte_lookup_events, shard, channel, &reduced)
+                });
+                let state = cols.forward_layer.populate(
+                    new_byte_lookup_events,
+                    shard,
+                    channel,
+                    &forward,
+                    &rescue_round_constants(2 * round),
+                );
+
+                // Witness the roots of the inverse S-boxes and check their fifth powers.
+                let inverse: [BigUint; RESCUE_WIDTH] = core::array::from_fn(|i| {
+                    let root = state[i].modpow(&alpha_inverse, &modulus);
+                    cols.inverse_sbox_root[i] = Bn254ScalarField::to_limbs_field::<F, _>(&root);
+                    new_byte_lookup_events.add_u8_range_checks_field(
+                        shard,
+                        channel,
+                        &cols.inverse_sbox_root[i].0,
+                    );
+                    let power = cols.inverse_sbox[i].populate(
+                        new_byte_lookup_events,
+                        shard,
+                        channel,
+                        &root,
+                    );
+                    debug_assert_eq!(power, state[i]);
+                    root
+                });
+                let state = cols.inverse_layer.populate(
+                    new_byte_lookup_events,
+                    shard,
+                    channel,
+                    &inverse,
+                    &rescue_round_constants(2 * round + 1),
+                );
+
+                for (i, element) in state.iter().enumerate() {
+                    debug_assert_eq!(
+                        *element,
+                        BigUint::from_bytes_le(&words_to_bytes_le_vec(
+                            &event.output[i * 8..(i + 1) * 8]
+                        ))
+                    );
+                    cols.output_range_checks[i].populate(
+                        new_byte_lookup_events,
+                        shard,
+                        channel,
+                        element,
+                        &modulus,
+                    );
+                }
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // The field operations are not gated by `is_real`, so the padding rows hold the round of
+        // the zero state with zero round constants.
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = [F::zero(); NUM_RESCUE_PRIME_COLS];
+            let cols: &mut RescuePrimeCols<F> = row.as_mut_slice().borrow_mut();
+
+            let zero = BigUint::zero();
+            let zeros: [BigUint; RESCUE_WIDTH] = core::array::from_fn(|_| BigUint::zero());
+            for i in 0..RESCUE_WIDTH {
+                cols.reduced_input[i].populate(
+                    &mut vec![],
+                    0,
+                    0,
+                    &zero,
+                    &zero,
+                    FieldOperation::Add,
+                );
+                cols.forward_sbox[i].populate(&mut vec![], 0, 0, &zero);
+                cols.inverse_sbox[i].populate(&mut vec![], 0, 0, &zero);
+            }
+            cols.forward_layer.populate(&mut vec![], 0, 0, &zeros, &zeros);
+            cols.inverse_layer.populate(&mut vec![], 0, 0, &zeros, &zeros);
+
+            row
+        });
+
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_RESCUE_PRIME_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut RescuePrimeCols<F> = trace.values
+                [i * NUM_RESCUE_PRIME_COLS..(i + 1) * NUM_RESCUE_PRIME_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.rescue_permute_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F: PrimeField32> QuinticPowerCols<F> {
+    /// Populates the columns for `x^5` and returns the result.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        x: &BigUint,
+    ) -> BigUint {
+        let square = self.square.populate(record, shard, channel, x, x, FieldOperation::Mul);
+        let fourth =
+            self.fourth.populate(record, shard, channel, &square, &square, FieldOperation::Mul);
+        self.fifth.populate(record, shard, channel, &fourth, x, FieldOperation::Mul)
+    }
+}
+
+impl<F: PrimeField32> LinearLayerCols<F> {
+    /// Populates the columns for the linear layer and the round constants and returns the result.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        state: &[BigUint; RESCUE_WIDTH],
+        constants: &[BigUint; RESCUE_WIDTH],
+    ) -> [BigUint; RESCUE_WIDTH] {
+        let partial_sum = self.partial_sum.populate(
+            record,
+            shard,
+            channel,
+            &state[0],
+            &state[1],
+            FieldOperation::Add,
+        );
+        let sum =
+            self.sum.populate(record, shard, channel, &partial_sum, &state[2], FieldOperation::Add);
+        core::array::from_fn(|i| {
+            let mds =
+                self.mds[i].populate(record, shard, channel, &sum, &state[i], FieldOperation::Add);
+            self.constants[i].populate(
+                record,
+                shard,
+                channel,
+                &mds,
+                &constants[i],
+                FieldOperation::Add,
+            )
+        })
+    }
+}
@@ -0,0 +1,206 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::syscalls::SyscallCode;
+use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
+
+use super::{
+    columns::{Ripemd160CompressCols, NUM_RIPEMD160_COMPRESS_COLS},
+    step::{Lane, StepOperation},
+    Ripemd160CompressChip, RIPEMD160_BLOCK_NUM_WORDS, RIPEMD160_NUM_STEPS,
+    RIPEMD160_STATE_NUM_WORDS,
+};
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+    operations::AddOperation,
+};
+
+impl<F> BaseAir<F> for Ripemd160CompressChip {
+    fn width(&self) -> usize {
+        NUM_RIPEMD160_COMPRESS_COLS
+    }
+}
+
+impl<AB> Air<AB> for Ripemd160CompressChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Ripemd160CompressCols<AB::Var> = (*local).borrow();
+        let next: &Ripemd160CompressCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The step flags are one-hot, start at the first step and cycle through the steps.
+        for i in 0..RIPEMD160_NUM_STEPS {
+            builder.assert_bool(local.step[i]);
+        }
+        builder.assert_one(local.step.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag));
+        builder.when_first_row().assert_one(local.step[0]);
+        for i in 0..RIPEMD160_NUM_STEPS {
+            builder
+                .when_transition()
+                .assert_eq(local.step[i], next.step[(i + 1) % RIPEMD160_NUM_STEPS]);
+        }
+
+        let first_step = local.step[0];
+        let final_step = local.step[RIPEMD160_NUM_STEPS - 1];
+        let not_final_step = AB::Expr::one() - final_step;
+
+        builder.assert_bool(local.is_real);
+        builder.assert_eq((first_step + final_step) * local.is_real, local.do_memory_check);
+        builder.assert_eq(first_step * local.is_real, local.receive_ecall);
+        builder.assert_eq(final_step * local.is_real, local.is_final);
+
+        // Constrain that the inputs stay the same throughout the rows of each syscall.
+        let mut transition_builder = builder.when_transition();
+        let mut transition_not_final_builder = transition_builder.when(not_final_step.clone());
+        transition_not_final_builder.assert_eq(local.shard, next.shard);
+        transition_not_final_builder.assert_eq(local.channel, next.channel);
+        transition_not_final_builder.assert_eq(local.clk, next.clk);
+        transition_not_final_builder.assert_eq(local.state_ptr, next.state_ptr);
+        transition_not_final_builder.assert_eq(local.block_ptr, next.block_ptr);
+        transition_not_final_builder.assert_eq(local.is_real, next.is_real);
+
+        // The last row must be nonreal because the number of steps is not a power of 2. This
+        // constraint ensures that the table does not end abruptly.
+        builder.when_last_row().assert_zero(local.is_real);
+
+        // In the first step, the state is unchanged since it's a memory read, and both lanes
+        // start from it. The block is taken from memory.
+        for i in 0..RIPEMD160_STATE_NUM_WORDS {
+            let mut first_step_builder = builder.when(local.receive_ecall);
+            first_step_builder
+                .assert_word_eq(*local.state_mem[i].value(), *local.state_mem[i].prev_value());
+            first_step_builder.assert_word_eq(*local.state_mem[i].value(), local.left[i]);
+            first_step_builder.assert_word_eq(*local.state_mem[i].value(), local.right[i]);
+        }
+        for i in 0..RIPEMD160_BLOCK_NUM_WORDS {
+            builder
+                .when(local.receive_ecall)
+                .assert_word_eq(*local.block_mem[i].value(), local.block[i]);
+        }
+
+        // Run the step on both lanes.
+        StepOperation::<AB::F>::eval(
+            builder,
+            Lane::Left,
+            &local.step,
+            local.left,
+            &local.block,
+            local.left_step,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        StepOperation::<AB::F>::eval(
+            builder,
+            Lane::Right,
+            &local.step,
+            local.right,
+            &local.block,
+            local.right_step,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        let left = local.left_step.result(local.left);
+        let right = local.right_step.result(local.right);
+
+        // Carry the lanes and the block to the next step.
+        let mut transition_builder = builder.when_transition();
+        let mut next_step_builder = transition_builder.when(not_final_step * local.is_real);
+        for i in 0..RIPEMD160_STATE_NUM_WORDS {
+            next_step_builder.assert_word_eq(next.left[i], left[i]);
+            next_step_builder.assert_word_eq(next.right[i], right[i]);
+        }
+        for i in 0..RIPEMD160_BLOCK_NUM_WORDS {
+            next_step_builder.assert_word_eq(next.block[i], local.block[i]);
+        }
+
+        // In the last step, combine the state with the lanes and write it back.
+        for i in 0..RIPEMD160_STATE_NUM_WORDS {
+            AddOperation::<AB::F>::eval(
+                builder,
+                *local.state_mem[(i + 1) % RIPEMD160_STATE_NUM_WORDS].prev_value(),
+                left[(i + 2) % RIPEMD160_STATE_NUM_WORDS],
+                local.finalize[i][0],
+                local.shard,
+                local.channel,
+                local.is_final.into(),
+            );
+            AddOperation::<AB::F>::eval(
+                builder,
+                local.finalize[i][0].value,
+                right[(i + 3) % RIPEMD160_STATE_NUM_WORDS],
+                local.finalize[i][1],
+                local.shard,
+                local.channel,
+                local.is_final.into(),
+            );
+            builder
+                .when(local.is_final)
+                .assert_word_eq(*local.state_mem[i].value(), local.finalize[i][1].value);
+        }
+
+        // Constrain memory.
+        for i in 0..RIPEMD160_STATE_NUM_WORDS as u32 {
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk + final_step, // The clk increments by 1 after the final step.
+                local.state_ptr + AB::Expr::from_canonical_u32(i * 4),
+                &local.state_mem[i as usize],
+                local.do_memory_check,
+            );
+        }
+        for i in 0..RIPEMD160_BLOCK_NUM_WORDS as u32 {
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk,
+                local.block_ptr + AB::Expr::from_canonical_u32(i * 4),
+                &local.block_mem[i as usize],
+                local.receive_ecall,
+            );
+        }
+
+        // Range check all the values read from and written to memory to be bytes.
+        for i in 0..RIPEMD160_STATE_NUM_WORDS {
+            builder.slice_range_check_u8(
+                &local.state_mem[i].value().0,
+                local.shard,
+                local.channel,
+                local.do_memory_check,
+            );
+        }
+        for i in 0..RIPEMD160_BLOCK_NUM_WORDS {
+            builder.slice_range_check_u8(
+                &local.block_mem[i].value().0,
+                local.shard,
+                local.channel,
+                local.receive_ecall,
+            );
+        }
+
+        // Receive the syscall in the first row of each syscall.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::RIPEMD160_COMPRESS.syscall_id()),
+            local.state_ptr,
+            local.block_ptr,
+            local.receive_ecall,
+        );
+    }
+}
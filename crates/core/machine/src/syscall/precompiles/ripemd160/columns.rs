@@ -0,0 +1,67 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+use sp1_stark::Word;
+
+use crate::{
+    memory::{MemoryReadCols, MemoryReadWriteCols},
+    operations::AddOperation,
+};
+
+use super::{
+    step::StepOperation, RIPEMD160_BLOCK_NUM_WORDS, RIPEMD160_NUM_STEPS, RIPEMD160_STATE_NUM_WORDS,
+};
+
+pub const NUM_RIPEMD160_COMPRESS_COLS: usize = size_of::<Ripemd160CompressCols<u8>>();
+
+/// A set of columns needed to compute the RIPEMD-160 compression function.
+///
+/// Each syscall is processed over 80 rows, one per step. The state and the block are read from
+/// memory in the first row, both lanes are carried to the next row after each step, and the state
+/// is written back to memory in the last row after combining it with the lanes.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct Ripemd160CompressCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub state_ptr: T,
+    pub block_ptr: T,
+
+    /// Which step this row is processing.
+    pub step: [T; RIPEMD160_NUM_STEPS],
+
+    /// Memory columns for the state. The state is read in the first step and written in the last
+    /// step.
+    pub state_mem: [MemoryReadWriteCols<T>; RIPEMD160_STATE_NUM_WORDS],
+
+    /// Memory columns for the message block, which is read in the first step.
+    pub block_mem: [MemoryReadCols<T>; RIPEMD160_BLOCK_NUM_WORDS],
+
+    /// The message block, carried through the rows of the syscall.
+    pub block: [Word<T>; RIPEMD160_BLOCK_NUM_WORDS],
+
+    /// The words `[a, b, c, d, e]` of the left lane at the start of the step.
+    pub left: [Word<T>; RIPEMD160_STATE_NUM_WORDS],
+
+    /// The words `[a, b, c, d, e]` of the right lane at the start of the step.
+    pub right: [Word<T>; RIPEMD160_STATE_NUM_WORDS],
+
+    pub left_step: StepOperation<T>,
+    pub right_step: StepOperation<T>,
+
+    /// `h[i + 1] + left[i + 2]`, and then `+ right[i + 3]`, the new state, after the last step.
+    pub finalize: [[AddOperation<T>; 2]; RIPEMD160_STATE_NUM_WORDS],
+
+    /// If the row is real and in the first or last step.
+    pub do_memory_check: T,
+
+    /// If the row is real and in the first step.
+    pub receive_ecall: T,
+
+    /// If the row is real and in the last step.
+    pub is_final: T,
+
+    pub is_real: T,
+}
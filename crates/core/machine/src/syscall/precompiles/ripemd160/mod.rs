@@ -0,0 +1,170 @@
+mod air;
+mod columns;
+mod step;
+mod trace;
+
+/// The number of words in the RIPEMD-160 state.
+pub const RIPEMD160_STATE_NUM_WORDS: usize = 5;
+
+/// The number of words in a RIPEMD-160 message block.
+pub const RIPEMD160_BLOCK_NUM_WORDS: usize = 16;
+
+/// The number of steps of each lane of the RIPEMD-160 compression function, in five rounds of 16.
+pub const RIPEMD160_NUM_STEPS: usize = 80;
+
+/// The RIPEMD-160 initial chaining value.
+pub const RIPEMD160_IV: [u32; RIPEMD160_STATE_NUM_WORDS] =
+    [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+/// The message word selected at each step of the left lane.
+pub const RIPEMD160_R_LEFT: [usize; RIPEMD160_NUM_STEPS] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, //
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8, //
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, //
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2, //
+    4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+
+/// The message word selected at each step of the right lane.
+pub const RIPEMD160_R_RIGHT: [usize; RIPEMD160_NUM_STEPS] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, //
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2, //
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, //
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14, //
+    12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+
+/// The left rotation at each step of the left lane.
+pub const RIPEMD160_S_LEFT: [u32; RIPEMD160_NUM_STEPS] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, //
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12, //
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, //
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, //
+    9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+
+/// The left rotation at each step of the right lane.
+pub const RIPEMD160_S_RIGHT: [u32; RIPEMD160_NUM_STEPS] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, //
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11, //
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, //
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8, //
+    8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+
+/// The constant added in each round of the left lane.
+pub const RIPEMD160_K_LEFT: [u32; 5] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+
+/// The constant added in each round of the right lane.
+pub const RIPEMD160_K_RIGHT: [u32; 5] =
+    [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+/// Implements the RIPEMD-160 compression function. The inputs to the syscall are a pointer to the
+/// 5 word state, which is overwritten with the result, and a pointer to the 16 word message block.
+///
+/// In the AIR, each syscall takes up 80 rows, one per step. A row runs the same step of both the
+/// left and the right lane, the lanes are initialized from the state in the first row, and they
+/// are combined with the state in the last row.
+#[derive(Default)]
+pub struct Ripemd160CompressChip;
+
+impl Ripemd160CompressChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod compress_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use super::RIPEMD160_IV;
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    const STATE_PTR: u32 = 100;
+    const BLOCK_PTR: u32 = 1000;
+
+    /// The padded single block of the message "abc".
+    fn abc_block() -> [u32; 16] {
+        let mut block = [0u32; 16];
+        block[0] = u32::from_le_bytes([b'a', b'b', b'c', 0x80]);
+        block[14] = 24;
+        block
+    }
+
+    /// Writes the state and each of the blocks to memory, and compresses the blocks into the state
+    /// one after the other.
+    pub fn ripemd160_compress_program(state: &[u32; 5], blocks: &[[u32; 16]]) -> Program {
+        let mut instructions = vec![];
+        store_words(&mut instructions, slice_at(STATE_PTR, state));
+        for block in blocks {
+            store_words(&mut instructions, slice_at(BLOCK_PTR, block));
+            call_syscall(&mut instructions, SyscallCode::RIPEMD160_COMPRESS, STATE_PTR, BLOCK_PTR);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    fn digest(runtime: &Executor) -> String {
+        hex::encode(
+            (0..5).flat_map(|i| runtime.word(STATE_PTR + i * 4).to_le_bytes()).collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_ripemd160_compress_execute() {
+        setup_logger();
+        let program = ripemd160_compress_program(&RIPEMD160_IV, &[abc_block()]);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        assert_eq!(digest(&runtime), "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc");
+    }
+
+    #[test]
+    fn test_ripemd160_compress_execute_two_blocks() {
+        setup_logger();
+        // The 56 byte message "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq" fills
+        // the first block, and the padding and length spill into a second one.
+        let message = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+        let mut bytes = message.to_vec();
+        bytes.push(0x80);
+        bytes.resize(120, 0);
+        bytes.extend_from_slice(&(message.len() as u64 * 8).to_le_bytes());
+        let blocks = bytes
+            .chunks_exact(64)
+            .map(|chunk| {
+                core::array::from_fn(|i| {
+                    u32::from_le_bytes(chunk[4 * i..4 * i + 4].try_into().unwrap())
+                })
+            })
+            .collect::<Vec<[u32; 16]>>();
+
+        let program = ripemd160_compress_program(&RIPEMD160_IV, &blocks);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        assert_eq!(digest(&runtime), "12a053384a9c0c88e405a06c27dcf49ada62eb2b");
+    }
+
+    #[test]
+    fn test_ripemd160_compress_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(ripemd160_compress_program(&RIPEMD160_IV, &[abc_block()]))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_ripemd160_compress_prove_two_blocks() {
+        setup_logger();
+        let state = [0x01234567, 0x89abcdef, 0xfedcba98, 0x76543210, 0xf0e1d2c3];
+        let blocks = [
+            core::array::from_fn(|i| 0x01010101 * i as u32),
+            core::array::from_fn(|i| u32::MAX - 0x00ff00ff * i as u32),
+        ];
+        run_test::<CpuProver<_, _>>(ripemd160_compress_program(&state, &blocks)).unwrap();
+    }
+}
@@ -0,0 +1,511 @@
+use core::ops::Sub;
+
+use p3_field::{AbstractField, Field};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord},
+    ByteOpcode,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::WORD_SIZE;
+use sp1_stark::{air::SP1AirBuilder, Word};
+
+use super::{
+    RIPEMD160_BLOCK_NUM_WORDS, RIPEMD160_K_LEFT, RIPEMD160_K_RIGHT, RIPEMD160_NUM_STEPS,
+    RIPEMD160_R_LEFT, RIPEMD160_R_RIGHT, RIPEMD160_STATE_NUM_WORDS, RIPEMD160_S_LEFT,
+    RIPEMD160_S_RIGHT,
+};
+use crate::{
+    bytes::utils::shr_carry,
+    operations::{Add4Operation, AddOperation, FixedRotateRightOperation},
+};
+
+/// The number of boolean functions.
+const NUM_FUNCTIONS: usize = 5;
+
+/// The byte operation computing the first part of each boolean function.
+const FIRST_OPCODE: [ByteOpcode; NUM_FUNCTIONS] =
+    [ByteOpcode::XOR, ByteOpcode::AND, ByteOpcode::OR, ByteOpcode::AND, ByteOpcode::OR];
+
+/// The byte operation computing the second part of each boolean function.
+const SECOND_OPCODE: [ByteOpcode; NUM_FUNCTIONS] =
+    [ByteOpcode::XOR, ByteOpcode::AND, ByteOpcode::XOR, ByteOpcode::AND, ByteOpcode::XOR];
+
+/// Whether the boolean function is the sum of its two parts rather than the second part. The two
+/// parts of these functions have disjoint bits, so their or is their sum.
+const ADD_FIRST: [bool; NUM_FUNCTIONS] = [false, true, false, true, false];
+
+/// The operands of the first part of the boolean function `f` applied to the bytes `x`, `y` and
+/// `z`, where `max - y` is the complement of `y`.
+fn first_operands<T: Clone + Sub<Output = T>>(f: usize, x: T, y: T, z: T, max: T) -> (T, T) {
+    match f {
+        // x ^ y, x & y
+        0 | 1 => (x, y),
+        // x | !y
+        2 => (x, max - y),
+        // x & z
+        3 => (x, z),
+        // y | !z
+        _ => (y, max - z),
+    }
+}
+
+/// The operands of the second part of the boolean function `f`, given the first part `t`.
+fn second_operands<T: Clone + Sub<Output = T>>(f: usize, x: T, y: T, z: T, t: T, max: T) -> (T, T) {
+    match f {
+        // (x ^ y) ^ z, (x | !y) ^ z
+        0 | 2 => (t, z),
+        // !x & z
+        1 => (max - x, z),
+        // y & !z
+        3 => (y, max - z),
+        // x ^ (y | !z)
+        _ => (x, t),
+    }
+}
+
+/// One of the two parallel lanes of the compression function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lane {
+    Left,
+    Right,
+}
+
+impl Lane {
+    /// The boolean function used at `step`. The left lane uses them in order, and the right lane
+    /// in reverse order.
+    pub const fn function(self, step: usize) -> usize {
+        match self {
+            Lane::Left => step / 16,
+            Lane::Right => NUM_FUNCTIONS - 1 - step / 16,
+        }
+    }
+
+    /// The index of the message word added at `step`.
+    pub const fn message_index(self, step: usize) -> usize {
+        match self {
+            Lane::Left => RIPEMD160_R_LEFT[step],
+            Lane::Right => RIPEMD160_R_RIGHT[step],
+        }
+    }
+
+    /// The round constant added at `step`.
+    pub const fn constant(self, step: usize) -> u32 {
+        match self {
+            Lane::Left => RIPEMD160_K_LEFT[step / 16],
+            Lane::Right => RIPEMD160_K_RIGHT[step / 16],
+        }
+    }
+
+    /// The left rotation at `step`.
+    pub const fn rotation(self, step: usize) -> u32 {
+        match self {
+            Lane::Left => RIPEMD160_S_LEFT[step],
+            Lane::Right => RIPEMD160_S_RIGHT[step],
+        }
+    }
+}
+
+/// The sum of the step flags, each weighted by `weight(step)`. Since the flags are one-hot, this
+/// is a linear expression for the value of `weight` at the current step.
+fn select<AB: SP1AirBuilder>(
+    step_flags: &[AB::Var; RIPEMD160_NUM_STEPS],
+    weight: impl Fn(usize) -> u32,
+) -> AB::Expr {
+    step_flags.iter().enumerate().fold(AB::Expr::zero(), |acc, (step, &flag)| {
+        acc + flag * AB::F::from_canonical_u32(weight(step))
+    })
+}
+
+/// A set of columns needed to compute the boolean function of a step on the words `x`, `y`, `z`.
+///
+/// Each of the five functions is made of two byte operations. The operands of the byte lookups
+/// depend on the step, so they are selected in their own columns.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct BooleanFunctionOperation<T> {
+    pub first_b: Word<T>,
+    pub first_c: Word<T>,
+    pub first: Word<T>,
+
+    pub second_b: Word<T>,
+    pub second_c: Word<T>,
+    pub second: Word<T>,
+
+    /// The output of the boolean function.
+    pub value: Word<T>,
+}
+
+impl<F: Field> BooleanFunctionOperation<F> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        f: usize,
+        x: u32,
+        y: u32,
+        z: u32,
+    ) -> u32 {
+        let (x, y, z) = (x.to_le_bytes(), y.to_le_bytes(), z.to_le_bytes());
+        let mut value = [0u8; WORD_SIZE];
+        for i in 0..WORD_SIZE {
+            let (b, c) = first_operands(f, x[i], y[i], z[i], u8::MAX);
+            let t = match FIRST_OPCODE[f] {
+                ByteOpcode::XOR => b ^ c,
+                ByteOpcode::AND => b & c,
+                _ => b | c,
+            };
+            record.add_byte_lookup_event(ByteLookupEvent {
+                shard,
+                channel,
+                opcode: FIRST_OPCODE[f],
+                a1: t as u16,
+                a2: 0,
+                b,
+                c,
+            });
+            self.first_b[i] = F::from_canonical_u8(b);
+            self.first_c[i] = F::from_canonical_u8(c);
+            self.first[i] = F::from_canonical_u8(t);
+
+            let (b, c) = second_operands(f, x[i], y[i], z[i], t, u8::MAX);
+            let u = match SECOND_OPCODE[f] {
+                ByteOpcode::XOR => b ^ c,
+                _ => b & c,
+            };
+            record.add_byte_lookup_event(ByteLookupEvent {
+                shard,
+                channel,
+                opcode: SECOND_OPCODE[f],
+                a1: u as u16,
+                a2: 0,
+                b,
+                c,
+            });
+            self.second_b[i] = F::from_canonical_u8(b);
+            self.second_c[i] = F::from_canonical_u8(c);
+            self.second[i] = F::from_canonical_u8(u);
+
+            value[i] = if ADD_FIRST[f] { t + u } else { u };
+        }
+        self.value = Word(value.map(F::from_canonical_u8));
+        u32::from_le_bytes(value)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        lane: Lane,
+        step_flags: &[AB::Var; RIPEMD160_NUM_STEPS],
+        x: Word<AB::Var>,
+        y: Word<AB::Var>,
+        z: Word<AB::Var>,
+        cols: BooleanFunctionOperation<AB::Var>,
+        shard: AB::Var,
+        channel: AB::Var,
+        is_real: AB::Var,
+    ) {
+        let max = AB::Expr::from_canonical_u8(u8::MAX);
+        let function_flags: [AB::Expr; NUM_FUNCTIONS] = core::array::from_fn(|f| {
+            select::<AB>(step_flags, |step| (lane.function(step) == f) as u32)
+        });
+        let first_opcode =
+            select::<AB>(step_flags, |step| FIRST_OPCODE[lane.function(step)] as u32);
+        let second_opcode =
+            select::<AB>(step_flags, |step| SECOND_OPCODE[lane.function(step)] as u32);
+        let add_first = select::<AB>(step_flags, |step| ADD_FIRST[lane.function(step)] as u32);
+
+        for i in 0..WORD_SIZE {
+            let (x_i, y_i, z_i): (AB::Expr, AB::Expr, AB::Expr) =
+                (x[i].into(), y[i].into(), z[i].into());
+            let t: AB::Expr = cols.first[i].into();
+
+            // Select the operands of the boolean function of the step.
+            let mut first_b = AB::Expr::zero();
+            let mut first_c = AB::Expr::zero();
+            let mut second_b = AB::Expr::zero();
+            let mut second_c = AB::Expr::zero();
+            for (f, flag) in function_flags.iter().enumerate() {
+                let (b, c) = first_operands(f, x_i.clone(), y_i.clone(), z_i.clone(), max.clone());
+                first_b += flag.clone() * b;
+                first_c += flag.clone() * c;
+                let (b, c) = second_operands(
+                    f,
+                    x_i.clone(),
+                    y_i.clone(),
+                    z_i.clone(),
+                    t.clone(),
+                    max.clone(),
+                );
+                second_b += flag.clone() * b;
+                second_c += flag.clone() * c;
+            }
+            let mut builder_is_real = builder.when(is_real);
+            builder_is_real.assert_eq(cols.first_b[i], first_b);
+            builder_is_real.assert_eq(cols.first_c[i], first_c);
+            builder_is_real.assert_eq(cols.second_b[i], second_b);
+            builder_is_real.assert_eq(cols.second_c[i], second_c);
+            builder_is_real.assert_eq(cols.value[i], cols.second[i] + add_first.clone() * t);
+
+            builder.send_byte(
+                first_opcode.clone(),
+                cols.first[i],
+                cols.first_b[i],
+                cols.first_c[i],
+                shard,
+                channel,
+                is_real,
+            );
+            builder.send_byte(
+                second_opcode.clone(),
+                cols.second[i],
+                cols.second_b[i],
+                cols.second_c[i],
+                shard,
+                channel,
+                is_real,
+            );
+        }
+    }
+}
+
+/// A set of columns needed to compute `rotateleft` by the rotation of a step.
+///
+/// The rotation is computed as a right rotation by `32 - s`, decomposed as in
+/// [`FixedRotateRightOperation`]. Since `5 <= s <= 15`, the byte rotation is by either two or
+/// three bytes, and the rotated input is selected in its own columns.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct StepRotateOperation<T> {
+    /// The input, rotated by a whole number of bytes.
+    pub input_rotated: Word<T>,
+
+    /// The shift output of `shrcarry` on each byte of the rotated input.
+    pub shift: Word<T>,
+
+    /// The carry output of `shrcarry` on each byte of the rotated input.
+    pub carry: Word<T>,
+
+    /// The output value.
+    pub value: Word<T>,
+}
+
+impl<F: Field> StepRotateOperation<F> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        input: u32,
+        rotation: u32,
+    ) -> u32 {
+        let expected = input.rotate_left(rotation);
+        let input_bytes = input.to_le_bytes();
+        let nb_bytes_to_shift = (32 - rotation as usize) / 8;
+        let nb_bits_to_shift = ((32 - rotation) % 8) as u8;
+
+        for i in 0..WORD_SIZE {
+            let b = input_bytes[(i + nb_bytes_to_shift) % WORD_SIZE];
+            let (shift, carry) = shr_carry(b, nb_bits_to_shift);
+            record.add_byte_lookup_event(ByteLookupEvent {
+                shard,
+                channel,
+                opcode: ByteOpcode::ShrCarry,
+                a1: shift as u16,
+                a2: carry,
+                b,
+                c: nb_bits_to_shift,
+            });
+            self.input_rotated[i] = F::from_canonical_u8(b);
+            self.shift[i] = F::from_canonical_u8(shift);
+            self.carry[i] = F::from_canonical_u8(carry);
+        }
+        self.value = Word::from(expected);
+
+        expected
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        lane: Lane,
+        step_flags: &[AB::Var; RIPEMD160_NUM_STEPS],
+        input: Word<AB::Var>,
+        cols: StepRotateOperation<AB::Var>,
+        shard: AB::Var,
+        channel: AB::Var,
+        is_real: AB::Var,
+    ) {
+        let by_three_bytes =
+            select::<AB>(step_flags, |step| ((32 - lane.rotation(step)) / 8 == 3) as u32);
+        let nb_bits_to_shift = select::<AB>(step_flags, |step| (32 - lane.rotation(step)) % 8);
+        let carry_multiplier =
+            select::<AB>(step_flags, |step| 1 << (8 - (32 - lane.rotation(step)) % 8));
+
+        for i in 0..WORD_SIZE {
+            let rotated = by_three_bytes.clone() * input[(i + 3) % WORD_SIZE]
+                + (AB::Expr::one() - by_three_bytes.clone()) * input[(i + 2) % WORD_SIZE];
+            builder.when(is_real).assert_eq(cols.input_rotated[i], rotated);
+
+            builder.send_byte_pair(
+                AB::F::from_canonical_u32(ByteOpcode::ShrCarry as u32),
+                cols.shift[i],
+                cols.carry[i],
+                cols.input_rotated[i],
+                nb_bits_to_shift.clone(),
+                shard,
+                channel,
+                is_real,
+            );
+
+            // Each output byte is the shifted byte with the low bits of the next byte on top.
+            builder.when(is_real).assert_eq(
+                cols.value[i],
+                cols.shift[i] + cols.carry[(i + 1) % WORD_SIZE] * carry_multiplier.clone(),
+            );
+        }
+    }
+}
+
+/// A set of columns needed to compute a step of one lane on the words `[a, b, c, d, e]`:
+///
+/// `t = rol(a + f(b, c, d) + x + k, s) + e`, and then `[a, b, c, d, e] = [e, t, b, rol(c, 10), d]`.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct StepOperation<T> {
+    /// The message word `x` of the step.
+    pub message: Word<T>,
+
+    /// The round constant `k` of the step.
+    pub constant: Word<T>,
+
+    /// `f(b, c, d)`.
+    pub function: BooleanFunctionOperation<T>,
+
+    /// `a + f(b, c, d) + x + k`.
+    pub sum: Add4Operation<T>,
+
+    /// `rol(a + f(b, c, d) + x + k, s)`.
+    pub rotated: StepRotateOperation<T>,
+
+    /// `t`.
+    pub t: AddOperation<T>,
+
+    /// `rol(c, 10)`, as a right rotation by 22.
+    pub c_rotated: FixedRotateRightOperation<T>,
+}
+
+impl<T: Copy> StepOperation<T> {
+    /// The lane after the step, given the lane before it.
+    pub fn result(&self, lane: [Word<T>; RIPEMD160_STATE_NUM_WORDS]) -> [Word<T>; 5] {
+        [lane[4], self.t.value, lane[1], self.c_rotated.value, lane[3]]
+    }
+}
+
+impl<F: Field> StepOperation<F> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        lane: Lane,
+        step: usize,
+        input: [u32; RIPEMD160_STATE_NUM_WORDS],
+        block: &[u32; RIPEMD160_BLOCK_NUM_WORDS],
+    ) -> [u32; RIPEMD160_STATE_NUM_WORDS] {
+        let [a, b, c, d, e] = input;
+        let x = block[lane.message_index(step)];
+        let k = lane.constant(step);
+        self.message = Word::from(x);
+        self.constant = Word::from(k);
+
+        let f = self.function.populate(record, shard, channel, lane.function(step), b, c, d);
+        let sum = self.sum.populate(record, shard, channel, a, f, x, k);
+        let rotated = self.rotated.populate(record, shard, channel, sum, lane.rotation(step));
+        let t = self.t.populate(record, shard, channel, rotated, e);
+        let c_rotated = self.c_rotated.populate(record, shard, channel, c, 22);
+
+        [e, t, b, c_rotated, d]
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        lane: Lane,
+        step_flags: &[AB::Var; RIPEMD160_NUM_STEPS],
+        input: [Word<AB::Var>; RIPEMD160_STATE_NUM_WORDS],
+        block: &[Word<AB::Var>; RIPEMD160_BLOCK_NUM_WORDS],
+        cols: StepOperation<AB::Var>,
+        shard: AB::Var,
+        channel: AB::Var,
+        is_real: AB::Var,
+    ) {
+        let [a, b, c, d, e] = input;
+
+        // Select the message word and the round constant of the step.
+        for i in 0..WORD_SIZE {
+            let message =
+                step_flags.iter().enumerate().fold(AB::Expr::zero(), |acc, (step, &flag)| {
+                    acc + flag * block[lane.message_index(step)][i]
+                });
+            let constant =
+                select::<AB>(step_flags, |step| lane.constant(step).to_le_bytes()[i] as u32);
+            builder.when(is_real).assert_eq(cols.message[i], message);
+            builder.when(is_real).assert_eq(cols.constant[i], constant);
+        }
+
+        BooleanFunctionOperation::<AB::F>::eval(
+            builder,
+            lane,
+            step_flags,
+            b,
+            c,
+            d,
+            cols.function,
+            shard,
+            channel,
+            is_real,
+        );
+        Add4Operation::<AB::F>::eval(
+            builder,
+            a,
+            cols.function.value,
+            cols.message,
+            cols.constant,
+            shard,
+            channel,
+            is_real,
+            cols.sum,
+        );
+        StepRotateOperation::<AB::F>::eval(
+            builder,
+            lane,
+            step_flags,
+            cols.sum.value,
+            cols.rotated,
+            shard,
+            channel,
+            is_real,
+        );
+        AddOperation::<AB::F>::eval(
+            builder,
+            cols.rotated.value,
+            e,
+            cols.t,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            c,
+            22,
+            cols.c_rotated,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, Ripemd160CompressEvent},
+    ExecutionRecord, Program,
+};
+use sp1_stark::{air::MachineAir, Word};
+
+use super::{
+    columns::{Ripemd160CompressCols, NUM_RIPEMD160_COMPRESS_COLS},
+    step::Lane,
+    Ripemd160CompressChip, RIPEMD160_NUM_STEPS, RIPEMD160_STATE_NUM_WORDS,
+};
+use crate::utils::{pad_rows, par_generate_rows};
+
+impl<F: PrimeField32> MachineAir<F> for Ripemd160CompressChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Ripemd160Compress".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(&input.ripemd160_compress_events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        let num_real_rows = rows.len();
+
+        pad_rows(&mut rows, || [F::zero(); NUM_RIPEMD160_COMPRESS_COLS]);
+
+        // Set the step flags for the padded rows. Each event takes up a full cycle of steps, so
+        // the padding continues the cycle from the first step.
+        for (i, row) in rows[num_real_rows..].iter_mut().enumerate() {
+            let cols: &mut Ripemd160CompressCols<F> = row.as_mut_slice().borrow_mut();
+            cols.step[i % RIPEMD160_NUM_STEPS] = F::one();
+        }
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_RIPEMD160_COMPRESS_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Ripemd160CompressCols<F> = trace.values
+                [i * NUM_RIPEMD160_COMPRESS_COLS..(i + 1) * NUM_RIPEMD160_COMPRESS_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.ripemd160_compress_events.is_empty()
+    }
+}
+
+impl Ripemd160CompressChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &Ripemd160CompressEvent,
+        rows: &mut Vec<[F; NUM_RIPEMD160_COMPRESS_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+
+        let h = event.state;
+        let mut left = h;
+        let mut right = h;
+
+        for step in 0..RIPEMD160_NUM_STEPS {
+            let mut row = [F::zero(); NUM_RIPEMD160_COMPRESS_COLS];
+            let cols: &mut Ripemd160CompressCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.shard = F::from_canonical_u32(shard);
+            cols.channel = F::from_canonical_u8(channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+            cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+            cols.step[step] = F::one();
+            cols.is_real = F::one();
+
+            cols.block = event.block.map(Word::from);
+            cols.left = left.map(Word::from);
+            cols.right = right.map(Word::from);
+
+            // In the first step, read the state and the block.
+            if step == 0 {
+                for (j, read_record) in event.state_read_records.iter().enumerate() {
+                    cols.state_mem[j].populate_read(channel, *read_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &read_record.value.to_le_bytes());
+                }
+                for (j, read_record) in event.block_read_records.iter().enumerate() {
+                    cols.block_mem[j].populate(channel, *read_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &read_record.value.to_le_bytes());
+                }
+                cols.do_memory_check = F::one();
+                cols.receive_ecall = F::one();
+            }
+
+            left =
+                cols.left_step.populate(blu, shard, channel, Lane::Left, step, left, &event.block);
+            right = cols.right_step.populate(
+                blu,
+                shard,
+                channel,
+                Lane::Right,
+                step,
+                right,
+                &event.block,
+            );
+
+            // In the last step, combine the state with the lanes and write it back.
+            if step == RIPEMD160_NUM_STEPS - 1 {
+                for i in 0..RIPEMD160_STATE_NUM_WORDS {
+                    let sum = cols.finalize[i][0].populate(
+                        blu,
+                        shard,
+                        channel,
+                        h[(i + 1) % RIPEMD160_STATE_NUM_WORDS],
+                        left[(i + 2) % RIPEMD160_STATE_NUM_WORDS],
+                    );
+                    let result = cols.finalize[i][1].populate(
+                        blu,
+                        shard,
+                        channel,
+                        sum,
+                        right[(i + 3) % RIPEMD160_STATE_NUM_WORDS],
+                    );
+                    debug_assert_eq!(event.state_write_records[i].value, result);
+                }
+                for (j, write_record) in event.state_write_records.iter().enumerate() {
+                    cols.state_mem[j].populate_write(channel, *write_record, blu);
+                    blu.add_u8_range_checks(shard, channel, &write_record.value.to_le_bytes());
+                }
+                cols.do_memory_check = F::one();
+                cols.is_final = F::one();
+            }
+
+            rows.push(row);
+        }
+    }
+}
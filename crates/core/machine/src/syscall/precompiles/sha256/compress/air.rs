@@ -7,7 +7,9 @@ use sp1_core_executor::syscalls::SyscallCode;
 use sp1_stark::{air::SP1AirBuilder, Word};
 
 use super::{
-    columns::{ShaCompressCols, NUM_SHA_COMPRESS_COLS},
+    columns::{
+        ShaCompressCols, ShaCompressControlCols, ShaCompressStateCols, NUM_SHA_COMPRESS_COLS,
+    },
     ShaCompressChip, SHA_COMPRESS_K,
 };
 use crate::{
@@ -37,37 +39,22 @@ where
         let next: &ShaCompressCols<AB::Var> = (*next).borrow();
 
         // Constrain the incrementing nonce.
-        builder.when_first_row().assert_zero(local.nonce);
-        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
-
-        self.eval_control_flow_flags(builder, local, next);
-
-        self.eval_memory(builder, local);
-
-        self.eval_compression_ops(builder, local, next);
+        builder.when_first_row().assert_zero(local.state.nonce);
+        builder.when_transition().assert_eq(local.state.nonce + AB::Expr::one(), next.state.nonce);
 
-        self.eval_finalize_ops(builder, local);
+        Self::eval_control(builder, &local.control, &next.control);
 
-        builder.assert_eq(local.start, local.is_real * local.octet[0] * local.octet_num[0]);
-        builder.receive_syscall(
-            local.shard,
-            local.channel,
-            local.clk,
-            local.nonce,
-            AB::F::from_canonical_u32(SyscallCode::SHA_COMPRESS.syscall_id()),
-            local.w_ptr,
-            local.h_ptr,
-            local.start,
-        );
+        Self::eval_state(builder, &local.control, &local.state, &next.state);
     }
 }
 
 impl ShaCompressChip {
-    fn eval_control_flow_flags<AB: SP1AirBuilder>(
-        &self,
+    /// Constrains the octet flags and the round constants, which follow the 80-row cycle of a
+    /// compression from the first row of the table.
+    pub(crate) fn eval_control<AB: SP1AirBuilder>(
         builder: &mut AB,
-        local: &ShaCompressCols<AB::Var>,
-        next: &ShaCompressCols<AB::Var>,
+        local: &ShaCompressControlCols<AB::Var>,
+        next: &ShaCompressControlCols<AB::Var>,
     ) {
         // Verify that all of the octet columns are bool.
         for i in 0..8 {
@@ -121,6 +108,53 @@ impl ShaCompressChip {
                 .assert_eq(local.octet_num[i], next.octet_num[(i + 1) % 10]);
         }
 
+        builder.assert_eq(local.is_last_row.into(), local.octet[7] * local.octet_num[9]);
+
+        // Constrain k column which loops over 64 constant values.
+        for i in 0..64 {
+            let octet_num = i / 8;
+            let inner_index = i % 8;
+            builder
+                .when(local.octet_num[octet_num + 1] * local.octet[inner_index])
+                .assert_all_eq(local.k, Word::<AB::F>::from(SHA_COMPRESS_K[i]));
+        }
+    }
+
+    /// Constrains a compression laid out next to the given control columns, and receives its
+    /// syscall.
+    pub(crate) fn eval_state<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        control: &ShaCompressControlCols<AB::Var>,
+        local: &ShaCompressStateCols<AB::Var>,
+        next: &ShaCompressStateCols<AB::Var>,
+    ) {
+        Self::eval_control_flow_flags(builder, control, local, next);
+
+        Self::eval_memory(builder, control, local);
+
+        Self::eval_compression_ops(builder, control, local, next);
+
+        Self::eval_finalize_ops(builder, control, local);
+
+        builder.assert_eq(local.start, local.is_real * control.octet[0] * control.octet_num[0]);
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::SHA_COMPRESS.syscall_id()),
+            local.w_ptr,
+            local.h_ptr,
+            local.start,
+        );
+    }
+
+    fn eval_control_flow_flags<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        control: &ShaCompressControlCols<AB::Var>,
+        local: &ShaCompressStateCols<AB::Var>,
+        next: &ShaCompressStateCols<AB::Var>,
+    ) {
         // Constrain A-H columns
         let vars = [local.a, local.b, local.c, local.d, local.e, local.f, local.g, local.h];
         let next_vars = [next.a, next.b, next.c, next.d, next.e, next.f, next.g, next.h];
@@ -129,62 +163,63 @@ impl ShaCompressChip {
             // last cycle is an exception since the next row must be a new 80-cycle loop or nonreal.
             builder
                 .when_transition()
-                .when(local.octet_num[0] + local.octet_num[9] * (AB::Expr::one() - local.octet[7]))
+                .when(
+                    control.octet_num[0]
+                        + control.octet_num[9] * (AB::Expr::one() - control.octet[7]),
+                )
                 .assert_word_eq(*var, next_vars[i]);
 
             // When column is read from memory during init, is should be equal to the memory value.
             builder
                 .when_transition()
-                .when(local.octet_num[0] * local.octet[i])
+                .when(control.octet_num[0] * control.octet[i])
                 .assert_word_eq(*var, *local.mem.value());
         }
 
         // Assert that the is_initialize flag is correct.
-        builder.assert_eq(local.is_initialize, local.octet_num[0] * local.is_real);
+        builder.assert_eq(local.is_initialize, control.octet_num[0] * local.is_real);
 
         // Assert that the is_compression flag is correct.
         builder.assert_eq(
             local.is_compression,
-            (local.octet_num[1]
-                + local.octet_num[2]
-                + local.octet_num[3]
-                + local.octet_num[4]
-                + local.octet_num[5]
-                + local.octet_num[6]
-                + local.octet_num[7]
-                + local.octet_num[8])
+            (control.octet_num[1]
+                + control.octet_num[2]
+                + control.octet_num[3]
+                + control.octet_num[4]
+                + control.octet_num[5]
+                + control.octet_num[6]
+                + control.octet_num[7]
+                + control.octet_num[8])
                 * local.is_real,
         );
 
         // Assert that the is_finalize flag is correct.
-        builder.assert_eq(local.is_finalize, local.octet_num[9] * local.is_real);
-
-        builder.assert_eq(local.is_last_row.into(), local.octet[7] * local.octet_num[9]);
+        builder.assert_eq(local.is_finalize, control.octet_num[9] * local.is_real);
 
         // If this row is real and not the last cycle, then next row should have same inputs
         builder
             .when_transition()
             .when(local.is_real)
-            .when_not(local.is_last_row)
+            .when_not(control.is_last_row)
             .assert_eq(local.shard, next.shard);
         builder
             .when_transition()
             .when(local.is_real)
-            .when_not(local.is_last_row)
+            .when_not(control.is_last_row)
             .assert_eq(local.clk, next.clk);
         builder
             .when_transition()
-            .when_not(local.is_last_row)
+            .when_not(control.is_last_row)
             .assert_eq(local.channel, next.channel);
         builder
             .when_transition()
             .when(local.is_real)
-            .when_not(local.is_last_row)
+            .when_not(control.is_last_row)
             .assert_eq(local.w_ptr, next.w_ptr);
         builder
             .when_transition()
             .when(local.is_real)
-            .when_not(local.is_last_row)
+            .when_not(control.is_last_row)
             .assert_eq(local.h_ptr, next.h_ptr);
 
         // Assert that is_real is a bool.
@@ -194,7 +229,7 @@ impl ShaCompressChip {
         builder
             .when_transition()
             .when(local.is_real)
-            .when_not(local.is_last_row)
+            .when_not(control.is_last_row)
             .assert_one(next.is_real);
 
         // Once the is_real flag is changed to false, it should not be changed back.
@@ -206,7 +241,11 @@ impl ShaCompressChip {
     }
 
     /// Constrains that memory address is correct and that memory is correctly written/read.
-    fn eval_memory<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &ShaCompressCols<AB::Var>) {
+    fn eval_memory<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        control: &ShaCompressControlCols<AB::Var>,
+        local: &ShaCompressStateCols<AB::Var>,
+    ) {
         builder.eval_memory_access(
             local.shard,
             local.channel,
@@ -219,13 +258,13 @@ impl ShaCompressChip {
         // Calculate the current cycle_num.
         let mut cycle_num = AB::Expr::zero();
         for i in 0..10 {
-            cycle_num += local.octet_num[i] * AB::Expr::from_canonical_usize(i);
+            cycle_num += control.octet_num[i] * AB::Expr::from_canonical_usize(i);
         }
 
         // Calculate the current step of the cycle 8.
         let mut cycle_step = AB::Expr::zero();
         for i in 0..8 {
-            cycle_step += local.octet[i] * AB::Expr::from_canonical_usize(i);
+            cycle_step += control.octet[i] * AB::Expr::from_canonical_usize(i);
         }
 
         // Verify correct mem address for initialize phase
@@ -255,11 +294,11 @@ impl ShaCompressChip {
         for (i, var) in vars.iter().enumerate() {
             builder
                 .when(local.is_initialize)
-                .when(local.octet[i])
+                .when(control.octet[i])
                 .assert_word_eq(*var, *local.mem.prev_value());
             builder
                 .when(local.is_initialize)
-                .when(local.octet[i])
+                .when(control.octet[i])
                 .assert_word_eq(*var, *local.mem.value());
         }
 
@@ -275,20 +314,11 @@ impl ShaCompressChip {
     }
 
     fn eval_compression_ops<AB: SP1AirBuilder>(
-        &self,
         builder: &mut AB,
-        local: &ShaCompressCols<AB::Var>,
-        next: &ShaCompressCols<AB::Var>,
+        control: &ShaCompressControlCols<AB::Var>,
+        local: &ShaCompressStateCols<AB::Var>,
+        next: &ShaCompressStateCols<AB::Var>,
     ) {
-        // Constrain k column which loops over 64 constant values.
-        for i in 0..64 {
-            let octet_num = i / 8;
-            let inner_index = i % 8;
-            builder
-                .when(local.octet_num[octet_num + 1] * local.octet[inner_index])
-                .assert_all_eq(local.k, Word::<AB::F>::from(SHA_COMPRESS_K[i]));
-        }
-
         // S1 := (e rightrotate 6) xor (e rightrotate 11) xor (e rightrotate 25).
         // Calculate e rightrotate 6.
         FixedRotateRightOperation::<AB::F>::eval(
@@ -385,7 +415,7 @@ impl ShaCompressChip {
         // Calculate temp1 := h + S1 + ch + k[i] + w[i].
         Add5Operation::<AB::F>::eval(
             builder,
-            &[local.h, local.s1.value, local.ch.value, local.k, local.mem.access.value],
+            &[local.h, local.s1.value, local.ch.value, control.k, local.mem.access.value],
             local.shard,
             local.channel,
             local.is_compression,
@@ -554,9 +584,9 @@ impl ShaCompressChip {
     }
 
     fn eval_finalize_ops<AB: SP1AirBuilder>(
-        &self,
         builder: &mut AB,
-        local: &ShaCompressCols<AB::Var>,
+        control: &ShaCompressControlCols<AB::Var>,
+        local: &ShaCompressStateCols<AB::Var>,
     ) {
         // In the finalize phase, need to execute h[0] + a, h[1] + b, ..., h[7] + h, for each of the
         // phase's 8 rows.
@@ -565,7 +595,7 @@ impl ShaCompressChip {
         let add_operands = [local.a, local.b, local.c, local.d, local.e, local.f, local.g, local.h];
         let zero = AB::Expr::zero();
         let mut filtered_operand = Word([zero.clone(), zero.clone(), zero.clone(), zero]);
-        for (i, operand) in local.octet.iter().zip(add_operands.iter()) {
+        for (i, operand) in control.octet.iter().zip(add_operands.iter()) {
             for j in 0..4 {
                 filtered_operand.0[j] += *i * operand.0[j];
             }
@@ -23,16 +23,15 @@ pub const NUM_SHA_COMPRESS_COLS: usize = size_of::<ShaCompressCols<u8>>();
 #[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
 #[repr(C)]
 pub struct ShaCompressCols<T> {
-    /// Inputs.
-    pub shard: T,
-    pub channel: T,
-    pub nonce: T,
-    pub clk: T,
-    pub w_ptr: T,
-    pub h_ptr: T,
-
-    pub start: T,
+    pub control: ShaCompressControlCols<T>,
+    pub state: ShaCompressStateCols<T>,
+}
 
+/// The columns that only depend on the position of a row within its 80-row cycle, so that they can
+/// be shared by several compressions laid out side by side.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ShaCompressControlCols<T> {
     /// Which cycle within the octet we are currently processing.
     pub octet: [T; 8],
 
@@ -42,6 +41,26 @@ pub struct ShaCompressCols<T> {
     ///  - The last octet is for finalize.
     pub octet_num: [T; 10],
 
+    /// Current value of K[i]. This is a constant array that loops around every 64 iterations.
+    pub k: Word<T>,
+
+    pub is_last_row: T,
+}
+
+/// The columns of a single compression: its inputs, its memory accesses and the state A-H.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ShaCompressStateCols<T> {
+    /// Inputs.
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub w_ptr: T,
+    pub h_ptr: T,
+
+    pub start: T,
+
     /// Memory access. During init and compression, this is read only. During finalize, this is
     /// used to write the result into memory.
     pub mem: MemoryReadWriteCols<T>,
@@ -58,9 +77,6 @@ pub struct ShaCompressCols<T> {
     pub g: Word<T>,
     pub h: Word<T>,
 
-    /// Current value of K[i]. This is a constant array that loops around every 64 iterations.
-    pub k: Word<T>,
-
     pub e_rr_6: FixedRotateRightOperation<T>,
     pub e_rr_11: FixedRotateRightOperation<T>,
     pub e_rr_25: FixedRotateRightOperation<T>,
@@ -106,7 +122,6 @@ pub struct ShaCompressCols<T> {
     pub is_initialize: T,
     pub is_compression: T,
     pub is_finalize: T,
-    pub is_last_row: T,
 
     pub is_real: T,
 }
@@ -2,6 +2,8 @@ mod air;
 mod columns;
 mod trace;
 
+pub use columns::{ShaCompressControlCols, ShaCompressStateCols};
+
 pub const SHA_COMPRESS_K: [u32; 64] = [
     0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
     0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
@@ -20,6 +22,9 @@ pub const SHA_COMPRESS_K: [u32; 64] = [
 /// In the AIR, each SHA compress syscall takes up 80 rows. The first and last 8 rows are for
 /// initialization and finalize respectively. The middle 64 rows are for compression. Each row
 /// operates over a single memory word.
+///
+/// The syscalls of a shard are proven four at a time by the [`super::Sha256x4Chip`], and this chip
+/// only proves the ones left over.
 #[derive(Default)]
 pub struct ShaCompressChip;
 
@@ -12,10 +12,12 @@ use sp1_core_executor::{
 use sp1_stark::{air::MachineAir, Word};
 
 use super::{
-    columns::{ShaCompressCols, NUM_SHA_COMPRESS_COLS},
+    columns::{
+        ShaCompressCols, ShaCompressControlCols, ShaCompressStateCols, NUM_SHA_COMPRESS_COLS,
+    },
     ShaCompressChip, SHA_COMPRESS_K,
 };
-use crate::utils::pad_rows;
+use crate::{syscall::precompiles::sha256::split_compress_events, utils::pad_rows};
 
 impl<F: PrimeField32> MachineAir<F> for ShaCompressChip {
     type Record = ExecutionRecord;
@@ -31,40 +33,27 @@ impl<F: PrimeField32> MachineAir<F> for ShaCompressChip {
         input: &ExecutionRecord,
         _: &mut ExecutionRecord,
     ) -> RowMajorMatrix<F> {
-        let rows = Vec::new();
-
-        let mut wrapped_rows = Some(rows);
-        for i in 0..input.sha_compress_events.len() {
-            let event = input.sha_compress_events[i].clone();
-            self.event_to_rows(&event, &mut wrapped_rows, &mut Vec::new());
+        let (events, _) = split_compress_events(&input.sha_compress_events);
+
+        let mut rows = Vec::new();
+        for event in events {
+            let mut states = Some(Vec::new());
+            Self::event_to_states::<F>(event, &mut states, &mut Vec::new());
+            for state in states.unwrap() {
+                let mut row = [F::zero(); NUM_SHA_COMPRESS_COLS];
+                let cols: &mut ShaCompressCols<F> = row.as_mut_slice().borrow_mut();
+                cols.state = state;
+                rows.push(row);
+            }
         }
-        let mut rows = wrapped_rows.unwrap();
-
-        let num_real_rows = rows.len();
 
         pad_rows(&mut rows, || [F::zero(); NUM_SHA_COMPRESS_COLS]);
 
-        // Set the octet_num and octect columns for the padded rows.
-        let mut octet_num = 0;
-        let mut octet = 0;
-        for row in rows[num_real_rows..].iter_mut() {
+        // Set the octet_num, octet and k columns, which follow the 80-row cycle in the padded
+        // rows as well.
+        for (i, row) in rows.iter_mut().enumerate() {
             let cols: &mut ShaCompressCols<F> = row.as_mut_slice().borrow_mut();
-            cols.octet_num[octet_num] = F::one();
-            cols.octet[octet] = F::one();
-
-            // If in the compression phase, set the k value.
-            if octet_num != 0 && octet_num != 9 {
-                let compression_idx = octet_num - 1;
-                let k_idx = compression_idx * 8 + octet;
-                cols.k = Word::from(SHA_COMPRESS_K[k_idx]);
-            }
-
-            octet = (octet + 1) % 8;
-            if octet == 0 {
-                octet_num = (octet_num + 1) % 10;
-            }
-
-            cols.is_last_row = cols.octet[7] * cols.octet_num[9];
+            cols.control.populate(i % 80);
         }
 
         // Convert the trace to a row major matrix.
@@ -78,22 +67,22 @@ impl<F: PrimeField32> MachineAir<F> for ShaCompressChip {
             let cols: &mut ShaCompressCols<F> = trace.values
                 [i * NUM_SHA_COMPRESS_COLS..(i + 1) * NUM_SHA_COMPRESS_COLS]
                 .borrow_mut();
-            cols.nonce = F::from_canonical_usize(i);
+            cols.state.nonce = F::from_canonical_usize(i);
         }
 
         trace
     }
 
     fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
-        let chunk_size = std::cmp::max(input.sha_compress_events.len() / num_cpus::get(), 1);
+        let (events, _) = split_compress_events(&input.sha_compress_events);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
 
-        let blu_batches = input
-            .sha_compress_events
+        let blu_batches = events
             .par_chunks(chunk_size)
             .map(|events| {
                 let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
                 events.iter().for_each(|event| {
-                    self.event_to_rows::<F>(event, &mut None, &mut blu);
+                    Self::event_to_states::<F>(event, &mut None, &mut blu);
                 });
                 blu
             })
@@ -103,15 +92,35 @@ impl<F: PrimeField32> MachineAir<F> for ShaCompressChip {
     }
 
     fn included(&self, shard: &Self::Record) -> bool {
-        !shard.sha_compress_events.is_empty()
+        let (events, _) = split_compress_events(&shard.sha_compress_events);
+        !events.is_empty()
+    }
+}
+
+impl<F: PrimeField32> ShaCompressControlCols<F> {
+    /// Populates the flags and the round constant of the given row of an 80-row cycle.
+    pub(crate) fn populate(&mut self, row: usize) {
+        let octet_num = row / 8;
+        let octet = row % 8;
+        self.octet[octet] = F::one();
+        self.octet_num[octet_num] = F::one();
+
+        // If in the compression phase, set the k value.
+        if octet_num != 0 && octet_num != 9 {
+            let compression_idx = octet_num - 1;
+            let k_idx = compression_idx * 8 + octet;
+            self.k = Word::from(SHA_COMPRESS_K[k_idx]);
+        }
+
+        self.is_last_row = self.octet[7] * self.octet_num[9];
     }
 }
 
 impl ShaCompressChip {
-    fn event_to_rows<F: PrimeField32>(
-        &self,
+    /// Computes the 80 states of the compression of `event`, one per row of its cycle.
+    pub(crate) fn event_to_states<F: PrimeField32>(
         event: &ShaCompressEvent,
-        rows: &mut Option<Vec<[F; NUM_SHA_COMPRESS_COLS]>>,
+        rows: &mut Option<Vec<ShaCompressStateCols<F>>>,
         blu: &mut impl ByteRecord,
     ) {
         let shard = event.shard;
@@ -119,12 +128,9 @@ impl ShaCompressChip {
 
         let og_h = event.h;
 
-        let mut octet_num_idx = 0;
-
         // Load a, b, c, d, e, f, g, h.
         for j in 0..8usize {
-            let mut row = [F::zero(); NUM_SHA_COMPRESS_COLS];
-            let cols: &mut ShaCompressCols<F> = row.as_mut_slice().borrow_mut();
+            let mut cols = ShaCompressStateCols::<F>::default();
 
             cols.shard = F::from_canonical_u32(event.shard);
             cols.channel = F::from_canonical_u8(event.channel);
@@ -132,8 +138,6 @@ impl ShaCompressChip {
             cols.w_ptr = F::from_canonical_u32(event.w_ptr);
             cols.h_ptr = F::from_canonical_u32(event.h_ptr);
 
-            cols.octet[j] = F::one();
-            cols.octet_num[octet_num_idx] = F::one();
             cols.is_initialize = F::one();
 
             cols.mem.populate_read(channel, event.h_read_records[j], blu);
@@ -149,25 +153,18 @@ impl ShaCompressChip {
             cols.h = Word::from(event.h_read_records[7].value);
 
             cols.is_real = F::one();
-            cols.start = cols.is_real * cols.octet_num[0] * cols.octet[0];
+            cols.start = F::from_bool(j == 0);
             if rows.as_ref().is_some() {
-                rows.as_mut().unwrap().push(row);
+                rows.as_mut().unwrap().push(cols);
             }
         }
 
         // Performs the compress operation.
         let mut h_array = event.h;
         for j in 0..64 {
-            if j % 8 == 0 {
-                octet_num_idx += 1;
-            }
-            let mut row = [F::zero(); NUM_SHA_COMPRESS_COLS];
-            let cols: &mut ShaCompressCols<F> = row.as_mut_slice().borrow_mut();
+            let mut cols = ShaCompressStateCols::<F>::default();
 
-            cols.k = Word::from(SHA_COMPRESS_K[j]);
             cols.is_compression = F::one();
-            cols.octet[j % 8] = F::one();
-            cols.octet_num[octet_num_idx] = F::one();
 
             cols.shard = F::from_canonical_u32(event.shard);
             cols.channel = F::from_canonical_u8(event.channel);
@@ -238,20 +235,17 @@ impl ShaCompressChip {
             h_array[0] = temp1_add_temp2;
 
             cols.is_real = F::one();
-            cols.start = cols.is_real * cols.octet_num[0] * cols.octet[0];
 
             if rows.as_ref().is_some() {
-                rows.as_mut().unwrap().push(row);
+                rows.as_mut().unwrap().push(cols);
             }
         }
 
         let mut v: [u32; 8] = (0..8).map(|i| h_array[i]).collect::<Vec<_>>().try_into().unwrap();
 
-        octet_num_idx += 1;
         // Store a, b, c, d, e, f, g, h.
         for j in 0..8usize {
-            let mut row = [F::zero(); NUM_SHA_COMPRESS_COLS];
-            let cols: &mut ShaCompressCols<F> = row.as_mut_slice().borrow_mut();
+            let mut cols = ShaCompressStateCols::<F>::default();
 
             cols.shard = F::from_canonical_u32(event.shard);
             cols.channel = F::from_canonical_u8(event.channel);
@@ -259,8 +253,6 @@ impl ShaCompressChip {
             cols.w_ptr = F::from_canonical_u32(event.w_ptr);
             cols.h_ptr = F::from_canonical_u32(event.h_ptr);
 
-            cols.octet[j] = F::one();
-            cols.octet_num[octet_num_idx] = F::one();
             cols.is_finalize = F::one();
 
             cols.finalize_add.populate(blu, shard, channel, og_h[j], h_array[j]);
@@ -290,11 +282,9 @@ impl ShaCompressChip {
             };
 
             cols.is_real = F::one();
-            cols.is_last_row = cols.octet[7] * cols.octet_num[9];
-            cols.start = cols.is_real * cols.octet_num[0] * cols.octet[0];
 
             if rows.as_ref().is_some() {
-                rows.as_mut().unwrap().push(row);
+                rows.as_mut().unwrap().push(cols);
             }
         }
     }
@@ -0,0 +1,38 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, BaseAir};
+use p3_matrix::Matrix;
+use sp1_stark::air::SP1AirBuilder;
+
+use super::{
+    columns::{Sha256x4Cols, NUM_SHA256X4_COLS},
+    Sha256x4Chip,
+};
+use crate::syscall::precompiles::sha256::ShaCompressChip;
+
+impl<F> BaseAir<F> for Sha256x4Chip {
+    fn width(&self) -> usize {
+        NUM_SHA256X4_COLS
+    }
+}
+
+impl<AB> Air<AB> for Sha256x4Chip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Sha256x4Cols<AB::Var> = (*local).borrow();
+        let next: &Sha256x4Cols<AB::Var> = (*next).borrow();
+
+        ShaCompressChip::eval_control(builder, &local.control, &next.control);
+
+        // Each compression is constrained as in the `ShaCompressChip`. Their nonces are not tied
+        // to the row index, since four syscalls share a row: they are the nonces that the executor
+        // assigned to the syscalls.
+        for (local_state, next_state) in local.states.iter().zip(next.states.iter()) {
+            ShaCompressChip::eval_state(builder, &local.control, local_state, next_state);
+        }
+    }
+}
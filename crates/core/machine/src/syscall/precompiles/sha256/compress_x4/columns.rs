@@ -0,0 +1,20 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+
+use super::SHA256X4_LANES;
+use crate::syscall::precompiles::sha256::{ShaCompressControlCols, ShaCompressStateCols};
+
+pub const NUM_SHA256X4_COLS: usize = size_of::<Sha256x4Cols<u8>>();
+
+/// A set of columns needed to compute four SHA-256 compression functions side by side.
+///
+/// The four compressions go through the same 80-row cycle as in the `ShaCompressChip`, so the
+/// octet flags and the round constant are stored once for all of them, and each compression only
+/// adds its own inputs, memory access and state.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Sha256x4Cols<T> {
+    pub control: ShaCompressControlCols<T>,
+    pub states: [ShaCompressStateCols<T>; SHA256X4_LANES],
+}
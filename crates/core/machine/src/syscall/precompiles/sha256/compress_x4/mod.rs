@@ -0,0 +1,75 @@
+mod air;
+mod columns;
+mod trace;
+
+use sp1_core_executor::events::ShaCompressEvent;
+
+/// The number of compressions that the [`Sha256x4Chip`] lays out in each row.
+pub const SHA256X4_LANES: usize = 4;
+
+/// Implements the SHA compress operation for four syscalls at once, laying out their states side
+/// by side in each row.
+///
+/// Like the [`super::ShaCompressChip`], each group of four syscalls takes up 80 rows, but the
+/// columns that only depend on the row of the cycle are shared by the four compressions, which
+/// lowers the fixed overhead per compression.
+#[derive(Default)]
+pub struct Sha256x4Chip;
+
+impl Sha256x4Chip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Splits the compress events of a shard between the [`super::ShaCompressChip`] and the
+/// [`Sha256x4Chip`].
+///
+/// The `Sha256x4Chip` takes the largest multiple of four events, and the `ShaCompressChip` the
+/// others. The latter are the first events of the shard, so that their rows in the
+/// `ShaCompressChip` still match the nonces that the executor assigns to the syscalls.
+pub(crate) fn split_compress_events(
+    events: &[ShaCompressEvent],
+) -> (&[ShaCompressEvent], &[ShaCompressEvent]) {
+    events.split_at(events.len() % SHA256X4_LANES)
+}
+
+#[cfg(test)]
+pub mod compress_x4_tests {
+
+    use sp1_core_executor::{syscalls::SyscallCode, Program};
+    use sp1_stark::CpuProver;
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    /// Compresses `num_calls` different blocks with `SHA_COMPRESS`.
+    pub fn sha_compress_x4_program(num_calls: u32) -> Program {
+        let mut instructions = vec![];
+        for call in 0..num_calls {
+            let w_ptr = 100 + call * 1024;
+            let h_ptr = w_ptr + 512;
+            store_words(&mut instructions, slice_at(w_ptr, &[5 + call; 64]));
+            store_words(&mut instructions, slice_at(h_ptr, &[5 + call; 8]));
+            call_syscall(&mut instructions, SyscallCode::SHA_COMPRESS, w_ptr, h_ptr);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn prove_babybear() {
+        setup_logger();
+        let program = sha_compress_x4_program(8);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
+    #[test]
+    fn prove_with_remainder() {
+        setup_logger();
+        // The first syscall is proven by the `ShaCompressChip`, and the other four side by side.
+        let program = sha_compress_x4_program(5);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+use std::borrow::BorrowMut;
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ShaCompressEvent},
+    ExecutionRecord, Program,
+};
+use sp1_stark::air::MachineAir;
+
+use super::{
+    columns::{Sha256x4Cols, NUM_SHA256X4_COLS},
+    split_compress_events, Sha256x4Chip, SHA256X4_LANES,
+};
+use crate::{syscall::precompiles::sha256::ShaCompressChip, utils::pad_rows};
+
+impl<F: PrimeField32> MachineAir<F> for Sha256x4Chip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Sha256x4".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (_, events) = split_compress_events(&input.sha_compress_events);
+        let chunk_size =
+            std::cmp::max(events.len() / SHA256X4_LANES / num_cpus::get(), 1) * SHA256X4_LANES;
+
+        let mut rows = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                events
+                    .chunks_exact(SHA256X4_LANES)
+                    .flat_map(|group| Self::group_to_rows::<F>(group, input))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        pad_rows(&mut rows, || [F::zero(); NUM_SHA256X4_COLS]);
+
+        // Set the octet_num, octet and k columns, which follow the 80-row cycle in the padded
+        // rows as well.
+        for (i, row) in rows.iter_mut().enumerate() {
+            let cols: &mut Sha256x4Cols<F> = row.as_mut_slice().borrow_mut();
+            cols.control.populate(i % 80);
+        }
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_SHA256X4_COLS)
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let (_, events) = split_compress_events(&input.sha_compress_events);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
+                events.iter().for_each(|event| {
+                    ShaCompressChip::event_to_states::<F>(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_sharded_byte_lookup_events(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        let (_, events) = split_compress_events(&shard.sha_compress_events);
+        !events.is_empty()
+    }
+}
+
+impl Sha256x4Chip {
+    /// Lays out the compressions of a group of four events side by side over 80 rows.
+    fn group_to_rows<F: PrimeField32>(
+        group: &[ShaCompressEvent],
+        input: &ExecutionRecord,
+    ) -> Vec<[F; NUM_SHA256X4_COLS]> {
+        let mut rows = vec![[F::zero(); NUM_SHA256X4_COLS]; 80];
+        for (lane, event) in group.iter().enumerate() {
+            let mut states = Some(Vec::new());
+            ShaCompressChip::event_to_states::<F>(event, &mut states, &mut Vec::new());
+
+            let nonce = F::from_canonical_u32(
+                input.nonce_lookup.get(&event.lookup_id).copied().unwrap_or_default(),
+            );
+            for (row, mut state) in rows.iter_mut().zip(states.unwrap()) {
+                let cols: &mut Sha256x4Cols<F> = row.as_mut_slice().borrow_mut();
+                state.nonce = nonce;
+                cols.states[lane] = state;
+            }
+        }
+        rows
+    }
+}
@@ -0,0 +1,318 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{syscalls::SyscallCode, ByteOpcode};
+use sp1_stark::{
+    air::{BaseAirBuilder, SP1AirBuilder},
+    Word,
+};
+
+use super::{
+    columns::{Sha256HashCols, NUM_SHA256_HASH_COLS},
+    Sha256HashChip, SHA256_BLOCK_BYTES, SHA256_BLOCK_WORDS,
+};
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+    syscall::precompiles::sha256::{Sha256RoundCols, SHA256_IV},
+};
+
+impl<F> BaseAir<F> for Sha256HashChip {
+    fn width(&self) -> usize {
+        NUM_SHA256_HASH_COLS
+    }
+}
+
+impl<AB> Air<AB> for Sha256HashChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Sha256HashCols<AB::Var> = (*local).borrow();
+        let next: &Sha256HashCols<AB::Var> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans a variable number of rows, so the nonce counts the
+        // syscalls rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_syscall_start, next.nonce);
+
+        self.eval_flags(builder, local, next);
+
+        Sha256RoundCols::<AB::F>::eval_flags(builder, &local.sha, &next.sha);
+
+        self.eval_inputs(builder, local);
+
+        self.eval_blocks(builder, local, next);
+
+        self.eval_padding(builder, local);
+
+        Sha256RoundCols::<AB::F>::eval(
+            builder,
+            &local.sha,
+            &next.sha,
+            local.shard,
+            local.channel,
+            local.is_real,
+            local.is_compression_end,
+        );
+
+        self.eval_digest(builder, local);
+    }
+}
+
+impl Sha256HashChip {
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256HashCols<AB::Var>,
+        next: &Sha256HashCols<AB::Var>,
+    ) {
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_syscall_start);
+        builder.assert_eq(local.is_block_start, local.sha.round[0] * local.is_real);
+        builder.assert_eq(local.is_syscall_end, local.is_compression_end * local.is_last_block);
+
+        // A syscall starts in the first real row and in the real row after the end of a syscall,
+        // and continues until its end.
+        builder.when(local.is_syscall_start).assert_one(local.is_block_start);
+        builder.when_first_row().assert_eq(local.is_syscall_start, local.is_real);
+        builder
+            .when_transition()
+            .when(local.is_syscall_end)
+            .assert_eq(next.is_syscall_start, next.is_real);
+        let mut transition_builder = builder.when_transition();
+        let mut in_syscall_builder = transition_builder.when(local.is_real - local.is_syscall_end);
+        in_syscall_builder.assert_one(next.is_real);
+        in_syscall_builder.assert_zero(next.is_syscall_start);
+        in_syscall_builder.assert_eq(local.shard, next.shard);
+        in_syscall_builder.assert_eq(local.channel, next.channel);
+        in_syscall_builder.assert_eq(local.clk, next.clk);
+        in_syscall_builder.assert_eq(local.result_ptr, next.result_ptr);
+        in_syscall_builder.assert_eq(local.params_ptr, next.params_ptr);
+        in_syscall_builder.assert_eq(local.input_ptr, next.input_ptr);
+        in_syscall_builder.assert_eq(local.len, next.len);
+        in_syscall_builder.assert_word_eq(local.bit_len, next.bit_len);
+
+        // Once the is_real flag is changed to false, it should not be changed back.
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+
+        // The table cannot end in the middle of a syscall.
+        builder.when_last_row().when(local.is_real).assert_one(local.is_syscall_end);
+    }
+
+    /// Constrains the reads of the parameters and the receipt of the syscall.
+    fn eval_inputs<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &Sha256HashCols<AB::Var>) {
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.params_ptr,
+            &local.params_memory,
+            local.is_syscall_start,
+        );
+        for params in local.params_memory.iter() {
+            builder.slice_range_check_u8(
+                &params.value().0,
+                local.shard,
+                local.channel,
+                local.is_syscall_start,
+            );
+        }
+
+        // The pointer to the message is the first word of the parameters, and its length is the
+        // second one, whose most significant byte must be zero.
+        let base = AB::F::from_canonical_u32(256);
+        let compose = |word: &Word<AB::Var>| {
+            word.0.iter().rev().fold(AB::Expr::zero(), |acc, &byte| acc * base + byte)
+        };
+        let mut start_builder = builder.when(local.is_syscall_start);
+        start_builder.assert_eq(local.input_ptr, compose(local.params_memory[0].value()));
+        start_builder.assert_eq(local.len, compose(local.params_memory[1].value()));
+        start_builder.assert_zero(local.params_memory[1].value()[3]);
+
+        // The length in bits is eight times the length in bytes. Its bytes are range checked and
+        // its most significant byte is below 8, so that the word is below the modulus.
+        start_builder.assert_eq(compose(&local.bit_len), local.len * AB::F::from_canonical_u32(8));
+        builder.slice_range_check_u8(
+            &local.bit_len.0,
+            local.shard,
+            local.channel,
+            local.is_syscall_start,
+        );
+        builder.send_byte(
+            ByteOpcode::LTU.as_field::<AB::F>(),
+            AB::F::one(),
+            local.bit_len[3],
+            AB::F::from_canonical_u32(8),
+            local.shard,
+            local.channel,
+            local.is_syscall_start,
+        );
+
+        // Receive the arguments in the first row of each syscall.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::SHA256_HASH.syscall_id()),
+            local.result_ptr,
+            local.params_ptr,
+            local.is_syscall_start,
+        );
+    }
+
+    /// Constrains the order of the blocks of a message, and the running state from one block to
+    /// the next.
+    fn eval_blocks<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256HashCols<AB::Var>,
+        next: &Sha256HashCols<AB::Var>,
+    ) {
+        // Each real block is either a full block, the end block or the length block.
+        builder.assert_bool(local.is_full_block);
+        builder.assert_bool(local.is_end_block);
+        builder.assert_bool(local.is_length_block);
+        builder.assert_eq(
+            local.is_full_block + local.is_end_block + local.is_length_block,
+            local.is_real,
+        );
+
+        // The position of the `0x80` byte is one-hot in the end block. The end block is the last
+        // one when the position leaves room for the length, and is followed by the length block
+        // otherwise.
+        let mut pad_position_sum = AB::Expr::zero();
+        let mut last_block_sum: AB::Expr = local.is_length_block.into();
+        for (i, &flag) in local.pad_position.iter().enumerate() {
+            builder.assert_bool(flag);
+            pad_position_sum += flag.into();
+            if i < SHA256_BLOCK_BYTES - 8 {
+                last_block_sum += flag.into();
+            }
+        }
+        builder.assert_eq(pad_position_sum, local.is_end_block);
+        builder.assert_eq(local.is_last_block, last_block_sum);
+
+        // The first block of a message starts from the initial hash value, and is not the length
+        // block.
+        let mut start_builder = builder.when(local.is_syscall_start);
+        start_builder.assert_zero(local.block_index);
+        start_builder.assert_zero(local.is_length_block);
+        let working_variables = local.sha.working_variables();
+        for i in 0..8 {
+            let iv = Word::<AB::F>::from(SHA256_IV[i]);
+            start_builder.assert_word_eq(local.sha.h_in[i], iv);
+            start_builder.assert_word_eq(working_variables[i], iv);
+        }
+
+        // The block stays the same until the end of its compression.
+        let mut transition_builder = builder.when_transition();
+        let mut in_block_builder =
+            transition_builder.when(local.is_real - local.is_compression_end);
+        in_block_builder.assert_eq(local.block_index, next.block_index);
+        in_block_builder.assert_eq(local.is_full_block, next.is_full_block);
+        in_block_builder.assert_eq(local.is_end_block, next.is_end_block);
+        in_block_builder.assert_eq(local.is_length_block, next.is_length_block);
+        for i in 0..SHA256_BLOCK_BYTES {
+            in_block_builder.assert_eq(local.pad_position[i], next.pad_position[i]);
+        }
+
+        // The next block of the message follows in memory, and its compression starts from the
+        // output of this one. Full blocks are followed by a full block or the end block, and an end
+        // block which is not the last one is followed by the length block.
+        let mut transition_builder = builder.when_transition();
+        let mut next_block_builder =
+            transition_builder.when(local.is_compression_end - local.is_syscall_end);
+        next_block_builder.assert_eq(next.block_index, local.block_index + AB::Expr::one());
+        next_block_builder.when(local.is_full_block).assert_zero(next.is_length_block);
+        next_block_builder.when(local.is_end_block).assert_one(next.is_length_block);
+        let output = local.sha.output();
+        let next_working_variables = next.sha.working_variables();
+        for i in 0..8 {
+            next_block_builder.assert_word_eq(next.sha.h_in[i], output[i]);
+            next_block_builder.assert_word_eq(next_working_variables[i], output[i]);
+        }
+    }
+
+    /// Constrains the words of each block in its first row: the bytes of the message read from
+    /// memory, followed by the `0x80` byte, zeros and the length of the message in bits.
+    fn eval_padding<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &Sha256HashCols<AB::Var>) {
+        // The position of the `0x80` byte in the end block is the number of bytes of the message
+        // left after the previous blocks.
+        let block_offset = local.block_index * AB::F::from_canonical_usize(SHA256_BLOCK_BYTES);
+        let pad_position =
+            local.pad_position.iter().enumerate().fold(AB::Expr::zero(), |acc, (i, &flag)| {
+                acc + flag * AB::F::from_canonical_usize(i)
+            });
+        builder.when(local.is_end_block).assert_eq(pad_position, local.len - block_offset.clone());
+
+        // A byte is part of the message if the block is full or if the `0x80` byte comes after it.
+        let mut is_message = vec![AB::Expr::zero(); SHA256_BLOCK_BYTES];
+        let mut pad_after = AB::Expr::zero();
+        for i in (0..SHA256_BLOCK_BYTES).rev() {
+            is_message[i] = local.is_full_block + pad_after.clone();
+            pad_after += local.pad_position[i].into();
+        }
+
+        // Read the words which hold bytes of the message, one cycle after the parameters.
+        for j in 0..SHA256_BLOCK_WORDS {
+            builder
+                .assert_eq(local.is_word_read[j], local.is_block_start * is_message[4 * j].clone());
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk + AB::Expr::one(),
+                local.input_ptr + block_offset.clone() + AB::F::from_canonical_usize(4 * j),
+                &local.input_memory[j],
+                local.is_word_read[j],
+            );
+            builder.slice_range_check_u8(
+                &local.input_memory[j].value().0,
+                local.shard,
+                local.channel,
+                local.is_word_read[j],
+            );
+        }
+
+        // The words of the schedule are big endian, so the byte at offset `k` of the `j`-th word
+        // of the block is its limb `3 - k`. The length in bits fills the last word of the last
+        // block, and the word before it is zero.
+        let mut block_start_builder = builder.when(local.is_block_start);
+        for i in 0..SHA256_BLOCK_BYTES {
+            let (j, k) = (i / 4, i % 4);
+            let mut byte = is_message[i].clone() * local.input_memory[j].value()[k]
+                + local.pad_position[i] * AB::F::from_canonical_u32(0x80);
+            if j == SHA256_BLOCK_WORDS - 1 {
+                byte += local.is_last_block * local.bit_len[3 - k];
+            }
+            block_start_builder.assert_eq(local.sha.w[j][3 - k], byte);
+        }
+    }
+
+    /// Constrains the write of the digest, whose bytes are the big endian bytes of the output of
+    /// the last compression.
+    fn eval_digest<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &Sha256HashCols<AB::Var>) {
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::Expr::two(),
+            local.result_ptr,
+            &local.digest_memory,
+            local.is_syscall_end,
+        );
+
+        let output = local.sha.output();
+        let mut end_builder = builder.when(local.is_syscall_end);
+        for i in 0..8 {
+            let value = local.digest_memory[i].value();
+            for k in 0..4 {
+                end_builder.assert_eq(value[k], output[i][3 - k]);
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+use sp1_stark::Word;
+
+use super::{SHA256_BLOCK_BYTES, SHA256_BLOCK_WORDS, SHA256_HASH_PARAMS_NUM_WORDS};
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    syscall::precompiles::sha256::Sha256RoundCols,
+};
+
+pub const NUM_SHA256_HASH_COLS: usize = size_of::<Sha256HashCols<u8>>();
+
+/// A set of columns needed to compute the SHA-256 digest of a region of memory.
+///
+/// Each block of the padded message is processed over 64 rows, one per round of its compression,
+/// and the blocks of a message are laid out one after the other. The input state of the compression
+/// is the running state of the hash, which is the output of the compression of the previous block.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct Sha256HashCols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub result_ptr: T,
+    pub params_ptr: T,
+
+    /// The pointer to the message, carried through the rows of the syscall.
+    pub input_ptr: T,
+
+    /// The length of the message in bytes, carried through the rows of the syscall.
+    pub len: T,
+
+    /// The length of the message in bits, which is the last word of the padding.
+    pub bit_len: Word<T>,
+
+    /// The index of the block within the padded message.
+    pub block_index: T,
+
+    /// The columns of the SHA-256 round of this row.
+    pub sha: Sha256RoundCols<T>,
+
+    /// If the row is real and the block only holds bytes of the message.
+    pub is_full_block: T,
+
+    /// If the row is real and the block holds the `0x80` byte which follows the message.
+    pub is_end_block: T,
+
+    /// If the row is real and the block comes after the end block, when the length does not fit
+    /// in the end block.
+    pub is_length_block: T,
+
+    /// If the row is real and the block is the last block of the message.
+    pub is_last_block: T,
+
+    /// A one-hot encoding of the position of the `0x80` byte in the end block, which is zero in
+    /// the other blocks.
+    pub pad_position: [T; SHA256_BLOCK_BYTES],
+
+    /// Whether each word of the block is read from memory, which is set in the first row of the
+    /// block for the words which hold bytes of the message.
+    pub is_word_read: [T; SHA256_BLOCK_WORDS],
+
+    /// Memory columns for the parameters, which are read in the first row of the syscall.
+    pub params_memory: [MemoryReadCols<T>; SHA256_HASH_PARAMS_NUM_WORDS],
+
+    /// Memory columns for the words of the message, which are read in the first row of the block.
+    pub input_memory: [MemoryReadCols<T>; SHA256_BLOCK_WORDS],
+
+    /// Memory columns for the digest, which is written in the last row of the syscall.
+    pub digest_memory: [MemoryWriteCols<T>; 8],
+
+    /// If the row is real and the first row of the syscall.
+    pub is_syscall_start: T,
+
+    /// If the row is real and the first row of a block.
+    pub is_block_start: T,
+
+    /// If the row is real and in the last round of a compression.
+    pub is_compression_end: T,
+
+    /// If the row is real and the last row of the syscall.
+    pub is_syscall_end: T,
+
+    pub is_real: T,
+}
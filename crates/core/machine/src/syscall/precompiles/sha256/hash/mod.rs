@@ -0,0 +1,119 @@
+mod air;
+mod columns;
+mod trace;
+
+use super::SHA256_NUM_ROUNDS;
+
+/// The number of bytes of a block of the padded message.
+pub const SHA256_BLOCK_BYTES: usize = 64;
+
+/// The number of words of a block of the padded message.
+pub const SHA256_BLOCK_WORDS: usize = 16;
+
+/// The number of words of the parameters, which are the pointer to the message and its length.
+pub const SHA256_HASH_PARAMS_NUM_WORDS: usize = 2;
+
+/// The number of blocks that the cost of a syscall is estimated with, which is the padding of a
+/// message of up to a kilobyte.
+pub const SHA256_HASH_COST_BLOCKS: usize = 16;
+
+/// The number of rows that the cost of a syscall is estimated with, one per round of each block.
+pub const SHA256_HASH_COST_ROWS: usize = SHA256_HASH_COST_BLOCKS * SHA256_NUM_ROUNDS;
+
+/// Implements the SHA-256 digest of a region of memory.
+///
+/// The first input to the syscall is a pointer to the 8 words of the digest, which are written with
+/// the bytes of the digest in order. The second input is a pointer to the parameters: a word
+/// aligned pointer to the message, and its length in bytes, which is below 2^24.
+///
+/// The executor emits one event per block of the padded message. In the AIR, each block takes up
+/// 64 rows, one per round of its compression, with the same round columns as the
+/// [`super::super::merkle::MerkleVerifyChip`]. The running state is carried from the output of one
+/// compression to the input of the next, and the index of the block gives the position of its words
+/// in the message. The padding is constrained byte by byte in the first row of each block, from the
+/// position of the `0x80` byte and the length of the message in bits.
+#[derive(Default)]
+pub struct Sha256HashChip;
+
+impl Sha256HashChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+pub mod sha256_hash_tests {
+    use sha2::{Digest, Sha256};
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words},
+    };
+
+    const RESULT_PTR: u32 = 100;
+    const PARAMS_PTR: u32 = 200;
+    const INPUT_PTR: u32 = 1000;
+
+    fn message(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i as u8).wrapping_mul(31).wrapping_add(7)).collect()
+    }
+
+    /// Returns a program that hashes each of the messages one after the other. The digest is
+    /// written over the start of the message when `in_place` is set.
+    pub fn sha256_hash_program(messages: &[Vec<u8>], in_place: bool) -> Program {
+        let mut instructions = vec![];
+        for message in messages {
+            // The bytes after the end of the message are not part of the hash.
+            let mut bytes = message.clone();
+            bytes.resize(message.len().next_multiple_of(4), 0xff);
+            let words = bytes
+                .chunks_exact(4)
+                .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+                .collect::<Vec<_>>();
+            store_words(&mut instructions, slice_at(INPUT_PTR, &words));
+            store_words(
+                &mut instructions,
+                slice_at(PARAMS_PTR, &[INPUT_PTR, message.len() as u32]),
+            );
+            let result_ptr = if in_place { INPUT_PTR } else { RESULT_PTR };
+            call_syscall(&mut instructions, SyscallCode::SHA256_HASH, result_ptr, PARAMS_PTR);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    fn digest(runtime: &Executor, ptr: u32) -> Vec<u8> {
+        (0..8).flat_map(|i| runtime.word(ptr + i * 4).to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_sha256_hash_execute() {
+        setup_logger();
+        for len in [0, 1, 3, 4, 55, 56, 63, 64, 65, 119, 120, 128, 200] {
+            let message = message(len);
+            let program = sha256_hash_program(&[message.clone()], false);
+            let mut runtime = Executor::new(program, SP1CoreOpts::default());
+            runtime.run().unwrap();
+            assert_eq!(digest(&runtime, RESULT_PTR), Sha256::digest(&message).to_vec());
+        }
+    }
+
+    #[test]
+    fn test_sha256_hash_in_place_execute() {
+        setup_logger();
+        let message = message(100);
+        let program = sha256_hash_program(&[message.clone()], true);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(digest(&runtime, INPUT_PTR), Sha256::digest(&message).to_vec());
+    }
+
+    #[test]
+    fn test_sha256_hash_prove() {
+        setup_logger();
+        let messages = [0, 7, 56, 130].map(message).to_vec();
+        run_test::<CpuProver<_, _>>(sha256_hash_program(&messages, false)).unwrap();
+        run_test::<CpuProver<_, _>>(sha256_hash_program(&[message(61)], true)).unwrap();
+    }
+}
@@ -0,0 +1,180 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, Sha256HashEvent},
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_stark::{air::MachineAir, Word};
+
+use super::{
+    columns::{Sha256HashCols, NUM_SHA256_HASH_COLS},
+    Sha256HashChip, SHA256_BLOCK_BYTES,
+};
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    syscall::precompiles::sha256::SHA256_NUM_ROUNDS,
+    utils::{pad_rows, par_generate_rows},
+};
+
+impl<F: PrimeField32> MachineAir<F> for Sha256HashChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Sha256Hash".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (event_rows, new_byte_lookup_events) =
+            par_generate_rows(&input.sha256_hash_events, |event, new_byte_lookup_events| {
+                let mut rows = Vec::new();
+                self.event_to_rows(event, &mut rows, new_byte_lookup_events);
+                rows
+            });
+        let mut rows = event_rows.into_iter().flatten().collect::<Vec<_>>();
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        let num_real_rows = rows.len();
+
+        pad_rows(&mut rows, || [F::zero(); NUM_SHA256_HASH_COLS]);
+
+        // Set the round flags for the padded rows. Each block takes up a full cycle of rounds, so
+        // the padding continues the cycle from the first round.
+        for (i, row) in rows[num_real_rows..].iter_mut().enumerate() {
+            let cols: &mut Sha256HashCols<F> = row.as_mut_slice().borrow_mut();
+            cols.sha.round[i % SHA256_NUM_ROUNDS] = F::one();
+        }
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_SHA256_HASH_COLS,
+        );
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut Sha256HashCols<F> =
+                trace.values[i * NUM_SHA256_HASH_COLS..(i + 1) * NUM_SHA256_HASH_COLS].borrow_mut();
+            if i > 0 && cols.is_syscall_start == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.sha256_hash_events.is_empty()
+    }
+}
+
+impl Sha256HashChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &Sha256HashEvent,
+        rows: &mut Vec<[F; NUM_SHA256_HASH_COLS]>,
+        blu: &mut Vec<ByteLookupEvent>,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+        let bit_len = event.len * 8;
+
+        // The memory columns are only used in the first row of the block, or in the last row of
+        // the syscall for the digest.
+        let mut params_memory = [MemoryReadCols::<F>::default(); 2];
+        for (j, record) in event.params_read_records.iter().enumerate() {
+            params_memory[j].populate(channel, *record, blu);
+            blu.add_u8_range_checks(shard, channel, &record.value.to_le_bytes());
+        }
+        if event.is_first_block() {
+            blu.add_u8_range_checks(shard, channel, &bit_len.to_le_bytes());
+            blu.add_byte_lookup_event(ByteLookupEvent {
+                shard,
+                channel,
+                opcode: ByteOpcode::LTU,
+                a1: 1,
+                a2: 0,
+                b: bit_len.to_le_bytes()[3],
+                c: 8,
+            });
+        }
+        let mut input_memory = [MemoryReadCols::<F>::default(); 16];
+        for (j, record) in event.input_read_records.iter().enumerate() {
+            input_memory[j].populate(channel, *record, blu);
+            blu.add_u8_range_checks(shard, channel, &record.value.to_le_bytes());
+        }
+        let mut digest_memory = [MemoryWriteCols::<F>::default(); 8];
+        for (j, record) in event.digest_write_records.iter().enumerate() {
+            digest_memory[j].populate(channel, *record, blu);
+        }
+
+        // The block holds the `0x80` byte if the message ends within it.
+        let block_offset = event.block_index as usize * SHA256_BLOCK_BYTES;
+        let len = event.len as usize;
+        let is_full_block = len >= block_offset + SHA256_BLOCK_BYTES;
+        let is_end_block = !is_full_block && len >= block_offset;
+
+        let mut w = event.block;
+        let mut state = event.h_in;
+        for round in 0..SHA256_NUM_ROUNDS {
+            let mut row = [F::zero(); NUM_SHA256_HASH_COLS];
+            let cols: &mut Sha256HashCols<F> = row.as_mut_slice().borrow_mut();
+
+            let is_block_start = round == 0;
+            let is_compression_end = round == SHA256_NUM_ROUNDS - 1;
+
+            cols.shard = F::from_canonical_u32(shard);
+            cols.channel = F::from_canonical_u8(channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.result_ptr = F::from_canonical_u32(event.result_ptr);
+            cols.params_ptr = F::from_canonical_u32(event.params_ptr);
+            cols.input_ptr = F::from_canonical_u32(event.input_ptr);
+            cols.len = F::from_canonical_u32(event.len);
+            cols.bit_len = Word::from(bit_len);
+            cols.block_index = F::from_canonical_u32(event.block_index);
+
+            cols.is_full_block = F::from_bool(is_full_block);
+            cols.is_end_block = F::from_bool(is_end_block);
+            cols.is_length_block = F::from_bool(!is_full_block && !is_end_block);
+            cols.is_last_block = F::from_bool(event.is_last_block());
+            if is_end_block {
+                cols.pad_position[len - block_offset] = F::one();
+            }
+
+            if is_block_start {
+                if event.is_first_block() {
+                    cols.params_memory = params_memory;
+                }
+                for j in 0..event.input_read_records.len() {
+                    cols.is_word_read[j] = F::one();
+                }
+                cols.input_memory = input_memory;
+            }
+
+            cols.sha.populate(blu, shard, channel, round, &event.h_in, &mut w, &mut state);
+
+            let is_syscall_end = event.is_last_block() && is_compression_end;
+            if is_syscall_end {
+                cols.digest_memory = digest_memory;
+            }
+
+            cols.is_syscall_start = F::from_bool(event.is_first_block() && is_block_start);
+            cols.is_block_start = F::from_bool(is_block_start);
+            cols.is_compression_end = F::from_bool(is_compression_end);
+            cols.is_syscall_end = F::from_bool(is_syscall_end);
+            cols.is_real = F::one();
+
+            rows.push(row);
+        }
+    }
+}
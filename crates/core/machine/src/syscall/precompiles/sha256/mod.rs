@@ -1,5 +1,11 @@
 mod compress;
+mod compress_x4;
 mod extend;
+mod hash;
+mod round;
 
 pub use compress::*;
+pub use compress_x4::*;
 pub use extend::*;
+pub use hash::*;
+pub use round::*;
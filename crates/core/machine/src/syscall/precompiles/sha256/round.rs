@@ -0,0 +1,659 @@
+use p3_air::AirBuilder;
+use p3_field::{AbstractField, Field};
+use sp1_core_executor::events::ByteRecord;
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{air::SP1AirBuilder, Word};
+
+use super::SHA_COMPRESS_K;
+use crate::{
+    air::WordAirBuilder,
+    operations::{
+        Add4Operation, Add5Operation, AddOperation, AndOperation, FixedRotateRightOperation,
+        FixedShiftRightOperation, NotOperation, XorOperation,
+    },
+};
+
+/// The number of rounds of the SHA-256 compression function.
+pub const SHA256_NUM_ROUNDS: usize = 64;
+
+/// The SHA-256 initial hash value.
+pub const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// A set of columns needed to compute one round of the SHA-256 compression function, together with
+/// the next word of its message schedule.
+///
+/// A compression takes up 64 consecutive rows, one per round. The message schedule is kept as a
+/// window of 16 words which moves by one word per round, so that `w[0]` is the word of the current
+/// round and `w[15]` is computed from the window. The chips using these columns constrain the input
+/// state and the block in the first round of each compression.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Sha256RoundCols<T> {
+    /// Which round of the compression this row is processing.
+    pub round: [T; SHA256_NUM_ROUNDS],
+
+    /// The input state of the compression.
+    pub h_in: [Word<T>; 8],
+
+    /// The window of the message schedule.
+    pub w: [Word<T>; 16],
+
+    /// `s0 := (w[1] rightrotate 7) xor (w[1] rightrotate 18) xor (w[1] rightshift 3)`.
+    pub w1_rr_7: FixedRotateRightOperation<T>,
+    pub w1_rr_18: FixedRotateRightOperation<T>,
+    pub w1_rs_3: FixedShiftRightOperation<T>,
+    pub schedule_s0_intermediate: XorOperation<T>,
+    pub schedule_s0: XorOperation<T>,
+
+    /// `s1 := (w[14] rightrotate 17) xor (w[14] rightrotate 19) xor (w[14] rightshift 10)`.
+    pub w14_rr_17: FixedRotateRightOperation<T>,
+    pub w14_rr_19: FixedRotateRightOperation<T>,
+    pub w14_rs_10: FixedShiftRightOperation<T>,
+    pub schedule_s1_intermediate: XorOperation<T>,
+    pub schedule_s1: XorOperation<T>,
+
+    /// The next word of the schedule, `w[16] := w[0] + s0 + w[9] + s1`.
+    pub w16: Add4Operation<T>,
+
+    /// The working variables at the start of the round.
+    pub a: Word<T>,
+    pub b: Word<T>,
+    pub c: Word<T>,
+    pub d: Word<T>,
+    pub e: Word<T>,
+    pub f: Word<T>,
+    pub g: Word<T>,
+    pub h: Word<T>,
+
+    /// The round constant.
+    pub k: Word<T>,
+
+    pub e_rr_6: FixedRotateRightOperation<T>,
+    pub e_rr_11: FixedRotateRightOperation<T>,
+    pub e_rr_25: FixedRotateRightOperation<T>,
+    pub s1_intermediate: XorOperation<T>,
+    /// `S1 := (e rightrotate 6) xor (e rightrotate 11) xor (e rightrotate 25)`.
+    pub s1: XorOperation<T>,
+
+    pub e_and_f: AndOperation<T>,
+    pub e_not: NotOperation<T>,
+    pub e_not_and_g: AndOperation<T>,
+    /// `ch := (e and f) xor ((not e) and g)`.
+    pub ch: XorOperation<T>,
+
+    /// `temp1 := h + S1 + ch + k[i] + w[i]`.
+    pub temp1: Add5Operation<T>,
+
+    pub a_rr_2: FixedRotateRightOperation<T>,
+    pub a_rr_13: FixedRotateRightOperation<T>,
+    pub a_rr_22: FixedRotateRightOperation<T>,
+    pub s0_intermediate: XorOperation<T>,
+    /// `S0 := (a rightrotate 2) xor (a rightrotate 13) xor (a rightrotate 22)`.
+    pub s0: XorOperation<T>,
+
+    pub a_and_b: AndOperation<T>,
+    pub a_and_c: AndOperation<T>,
+    pub b_and_c: AndOperation<T>,
+    pub maj_intermediate: XorOperation<T>,
+    /// `maj := (a and b) xor (a and c) xor (b and c)`.
+    pub maj: XorOperation<T>,
+
+    /// `temp2 := S0 + maj`.
+    pub temp2: AddOperation<T>,
+
+    /// The next value of `e` is `d + temp1`.
+    pub d_add_temp1: AddOperation<T>,
+    /// The next value of `a` is `temp1 + temp2`.
+    pub temp1_add_temp2: AddOperation<T>,
+
+    /// The input state plus the working variables after the round, which is the output of the
+    /// compression in the last round.
+    pub finalize_add: [AddOperation<T>; 8],
+}
+
+impl<T: Copy> Sha256RoundCols<T> {
+    /// Returns the working variables at the start of the round.
+    pub fn working_variables(&self) -> [Word<T>; 8] {
+        [self.a, self.b, self.c, self.d, self.e, self.f, self.g, self.h]
+    }
+
+    /// Returns the working variables after the round:
+    /// `(temp1 + temp2, a, b, c, d + temp1, e, f, g)`.
+    pub fn round_result(&self) -> [Word<T>; 8] {
+        [
+            self.temp1_add_temp2.value,
+            self.a,
+            self.b,
+            self.c,
+            self.d_add_temp1.value,
+            self.e,
+            self.f,
+            self.g,
+        ]
+    }
+
+    /// Returns the output of the compression, which is only meaningful in its last round.
+    pub fn output(&self) -> [Word<T>; 8] {
+        self.finalize_add.map(|add| add.value)
+    }
+}
+
+impl<F: Field> Sha256RoundCols<F> {
+    /// Populates the columns of the given round of a compression with input state `h_in`.
+    ///
+    /// The window of the message schedule is moved by one word, and the round is applied to the
+    /// working variables in `state`. In the last round, the input state is added to them, so that
+    /// `state` holds the output of the compression.
+    #[allow(clippy::too_many_arguments)]
+    pub fn populate(
+        &mut self,
+        blu: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        round: usize,
+        h_in: &[u32; 8],
+        w: &mut [u32; 16],
+        state: &mut [u32; 8],
+    ) {
+        self.round[round] = F::one();
+        self.h_in = h_in.map(Word::from);
+        self.w = w.map(Word::from);
+
+        // Compute the next word of the message schedule.
+        let w1_rr_7 = self.w1_rr_7.populate(blu, shard, channel, w[1], 7);
+        let w1_rr_18 = self.w1_rr_18.populate(blu, shard, channel, w[1], 18);
+        let w1_rs_3 = self.w1_rs_3.populate(blu, shard, channel, w[1], 3);
+        let schedule_s0_intermediate =
+            self.schedule_s0_intermediate.populate(blu, shard, channel, w1_rr_7, w1_rr_18);
+        let schedule_s0 =
+            self.schedule_s0.populate(blu, shard, channel, schedule_s0_intermediate, w1_rs_3);
+
+        let w14_rr_17 = self.w14_rr_17.populate(blu, shard, channel, w[14], 17);
+        let w14_rr_19 = self.w14_rr_19.populate(blu, shard, channel, w[14], 19);
+        let w14_rs_10 = self.w14_rs_10.populate(blu, shard, channel, w[14], 10);
+        let schedule_s1_intermediate =
+            self.schedule_s1_intermediate.populate(blu, shard, channel, w14_rr_17, w14_rr_19);
+        let schedule_s1 =
+            self.schedule_s1.populate(blu, shard, channel, schedule_s1_intermediate, w14_rs_10);
+
+        let w16 = self.w16.populate(blu, shard, channel, w[0], schedule_s0, w[9], schedule_s1);
+
+        // Apply the round to the working variables.
+        let [a, b, c, d, e, f, g, h] = *state;
+        self.a = Word::from(a);
+        self.b = Word::from(b);
+        self.c = Word::from(c);
+        self.d = Word::from(d);
+        self.e = Word::from(e);
+        self.f = Word::from(f);
+        self.g = Word::from(g);
+        self.h = Word::from(h);
+        self.k = Word::from(SHA_COMPRESS_K[round]);
+
+        let e_rr_6 = self.e_rr_6.populate(blu, shard, channel, e, 6);
+        let e_rr_11 = self.e_rr_11.populate(blu, shard, channel, e, 11);
+        let e_rr_25 = self.e_rr_25.populate(blu, shard, channel, e, 25);
+        let s1_intermediate = self.s1_intermediate.populate(blu, shard, channel, e_rr_6, e_rr_11);
+        let s1 = self.s1.populate(blu, shard, channel, s1_intermediate, e_rr_25);
+
+        let e_and_f = self.e_and_f.populate(blu, shard, channel, e, f);
+        let e_not = self.e_not.populate(blu, shard, channel, e);
+        let e_not_and_g = self.e_not_and_g.populate(blu, shard, channel, e_not, g);
+        let ch = self.ch.populate(blu, shard, channel, e_and_f, e_not_and_g);
+
+        let temp1 =
+            self.temp1.populate(blu, shard, channel, h, s1, ch, SHA_COMPRESS_K[round], w[0]);
+
+        let a_rr_2 = self.a_rr_2.populate(blu, shard, channel, a, 2);
+        let a_rr_13 = self.a_rr_13.populate(blu, shard, channel, a, 13);
+        let a_rr_22 = self.a_rr_22.populate(blu, shard, channel, a, 22);
+        let s0_intermediate = self.s0_intermediate.populate(blu, shard, channel, a_rr_2, a_rr_13);
+        let s0 = self.s0.populate(blu, shard, channel, s0_intermediate, a_rr_22);
+
+        let a_and_b = self.a_and_b.populate(blu, shard, channel, a, b);
+        let a_and_c = self.a_and_c.populate(blu, shard, channel, a, c);
+        let b_and_c = self.b_and_c.populate(blu, shard, channel, b, c);
+        let maj_intermediate =
+            self.maj_intermediate.populate(blu, shard, channel, a_and_b, a_and_c);
+        let maj = self.maj.populate(blu, shard, channel, maj_intermediate, b_and_c);
+
+        let temp2 = self.temp2.populate(blu, shard, channel, s0, maj);
+
+        let d_add_temp1 = self.d_add_temp1.populate(blu, shard, channel, d, temp1);
+        let temp1_add_temp2 = self.temp1_add_temp2.populate(blu, shard, channel, temp1, temp2);
+
+        *state = [temp1_add_temp2, a, b, c, d_add_temp1, e, f, g];
+
+        // Move the window of the message schedule.
+        w.copy_within(1.., 0);
+        w[15] = w16;
+
+        // In the last round, add the input state to get the output of the compression.
+        if round == SHA256_NUM_ROUNDS - 1 {
+            for j in 0..8 {
+                state[j] = self.finalize_add[j].populate(blu, shard, channel, h_in[j], state[j]);
+            }
+        }
+    }
+
+    /// Constrains the round flags, which start at the first round in the first row of the table and
+    /// cycle through the rounds, so that the padded rows must continue the cycle.
+    pub fn eval_flags<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        local: &Sha256RoundCols<AB::Var>,
+        next: &Sha256RoundCols<AB::Var>,
+    ) {
+        for i in 0..SHA256_NUM_ROUNDS {
+            builder.assert_bool(local.round[i]);
+        }
+        builder.assert_one(local.round.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag));
+        builder.when_first_row().assert_one(local.round[0]);
+        for i in 0..SHA256_NUM_ROUNDS {
+            builder
+                .when_transition()
+                .assert_eq(local.round[i], next.round[(i + 1) % SHA256_NUM_ROUNDS]);
+        }
+    }
+
+    /// Constrains a round of a compression and the next word of its message schedule, the movement
+    /// of the window and the working variables from one round to the next, and the output of the
+    /// compression in its last round.
+    ///
+    /// `is_compression_end` must be set in the real rows of the last round.
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        local: &Sha256RoundCols<AB::Var>,
+        next: &Sha256RoundCols<AB::Var>,
+        shard: AB::Var,
+        channel: AB::Var,
+        is_real: AB::Var,
+        is_compression_end: AB::Var,
+    ) {
+        builder.assert_eq(is_compression_end, local.round[SHA256_NUM_ROUNDS - 1] * is_real);
+
+        Self::eval_schedule(builder, local, shard, channel, is_real);
+
+        Self::eval_round(builder, local, shard, channel, is_real);
+
+        // Within a compression, the window moves by one word, the working variables of the next
+        // round are the result of this round, and the input state stays the same.
+        let mut transition_builder = builder.when_transition();
+        let mut in_compression_builder = transition_builder.when(is_real - is_compression_end);
+        for i in 0..15 {
+            in_compression_builder.assert_word_eq(next.w[i], local.w[i + 1]);
+        }
+        in_compression_builder.assert_word_eq(next.w[15], local.w16.value);
+        let result = local.round_result();
+        let next_working_variables = next.working_variables();
+        for i in 0..8 {
+            in_compression_builder.assert_word_eq(next_working_variables[i], result[i]);
+            in_compression_builder.assert_word_eq(next.h_in[i], local.h_in[i]);
+        }
+
+        // In the last round, add the input state to the result.
+        for i in 0..8 {
+            AddOperation::<AB::F>::eval(
+                builder,
+                local.h_in[i],
+                result[i],
+                local.finalize_add[i],
+                shard,
+                channel,
+                is_compression_end.into(),
+            );
+        }
+    }
+
+    /// Constrains the next word of the message schedule.
+    fn eval_schedule<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        local: &Sha256RoundCols<AB::Var>,
+        shard: AB::Var,
+        channel: AB::Var,
+        is_real: AB::Var,
+    ) {
+        // Compute `s0`.
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.w[1],
+            7,
+            local.w1_rr_7,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.w[1],
+            18,
+            local.w1_rr_18,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedShiftRightOperation::<AB::F>::eval(
+            builder,
+            local.w[1],
+            3,
+            local.w1_rs_3,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.w1_rr_7.value,
+            local.w1_rr_18.value,
+            local.schedule_s0_intermediate,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.schedule_s0_intermediate.value,
+            local.w1_rs_3.value,
+            local.schedule_s0,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // Compute `s1`.
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.w[14],
+            17,
+            local.w14_rr_17,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.w[14],
+            19,
+            local.w14_rr_19,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedShiftRightOperation::<AB::F>::eval(
+            builder,
+            local.w[14],
+            10,
+            local.w14_rs_10,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.w14_rr_17.value,
+            local.w14_rr_19.value,
+            local.schedule_s1_intermediate,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.schedule_s1_intermediate.value,
+            local.w14_rs_10.value,
+            local.schedule_s1,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // Compute `w[16] := w[0] + s0 + w[9] + s1`.
+        Add4Operation::<AB::F>::eval(
+            builder,
+            local.w[0],
+            local.schedule_s0.value,
+            local.w[9],
+            local.schedule_s1.value,
+            shard,
+            channel,
+            is_real,
+            local.w16,
+        );
+    }
+
+    /// Constrains the operations of a round of the compression function.
+    fn eval_round<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        local: &Sha256RoundCols<AB::Var>,
+        shard: AB::Var,
+        channel: AB::Var,
+        is_real: AB::Var,
+    ) {
+        // Constrain the round constant.
+        let zero = AB::Expr::zero();
+        let mut k = Word([zero.clone(), zero.clone(), zero.clone(), zero]);
+        for (&flag, &constant) in local.round.iter().zip(SHA_COMPRESS_K.iter()) {
+            let constant = Word::<AB::F>::from(constant);
+            for j in 0..4 {
+                k[j] = k[j].clone() + flag * constant[j];
+            }
+        }
+        builder.when(is_real).assert_word_eq(local.k, k);
+
+        // Calculate S1 := (e rightrotate 6) xor (e rightrotate 11) xor (e rightrotate 25).
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.e,
+            6,
+            local.e_rr_6,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.e,
+            11,
+            local.e_rr_11,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.e,
+            25,
+            local.e_rr_25,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.e_rr_6.value,
+            local.e_rr_11.value,
+            local.s1_intermediate,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.s1_intermediate.value,
+            local.e_rr_25.value,
+            local.s1,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // Calculate ch := (e and f) xor ((not e) and g).
+        AndOperation::<AB::F>::eval(
+            builder,
+            local.e,
+            local.f,
+            local.e_and_f,
+            shard,
+            channel,
+            is_real,
+        );
+        NotOperation::<AB::F>::eval(builder, local.e, local.e_not, shard, channel, is_real);
+        AndOperation::<AB::F>::eval(
+            builder,
+            local.e_not.value,
+            local.g,
+            local.e_not_and_g,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.e_and_f.value,
+            local.e_not_and_g.value,
+            local.ch,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // Calculate temp1 := h + S1 + ch + k[i] + w[i].
+        Add5Operation::<AB::F>::eval(
+            builder,
+            &[local.h, local.s1.value, local.ch.value, local.k, local.w[0]],
+            shard,
+            channel,
+            is_real,
+            local.temp1,
+        );
+
+        // Calculate S0 := (a rightrotate 2) xor (a rightrotate 13) xor (a rightrotate 22).
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.a,
+            2,
+            local.a_rr_2,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.a,
+            13,
+            local.a_rr_13,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightOperation::<AB::F>::eval(
+            builder,
+            local.a,
+            22,
+            local.a_rr_22,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.a_rr_2.value,
+            local.a_rr_13.value,
+            local.s0_intermediate,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.s0_intermediate.value,
+            local.a_rr_22.value,
+            local.s0,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // Calculate maj := (a and b) xor (a and c) xor (b and c).
+        AndOperation::<AB::F>::eval(
+            builder,
+            local.a,
+            local.b,
+            local.a_and_b,
+            shard,
+            channel,
+            is_real,
+        );
+        AndOperation::<AB::F>::eval(
+            builder,
+            local.a,
+            local.c,
+            local.a_and_c,
+            shard,
+            channel,
+            is_real,
+        );
+        AndOperation::<AB::F>::eval(
+            builder,
+            local.b,
+            local.c,
+            local.b_and_c,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.a_and_b.value,
+            local.a_and_c.value,
+            local.maj_intermediate,
+            shard,
+            channel,
+            is_real,
+        );
+        XorOperation::<AB::F>::eval(
+            builder,
+            local.maj_intermediate.value,
+            local.b_and_c.value,
+            local.maj,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // Calculate temp2 := S0 + maj.
+        AddOperation::<AB::F>::eval(
+            builder,
+            local.s0.value,
+            local.maj.value,
+            local.temp2,
+            shard,
+            channel,
+            is_real.into(),
+        );
+
+        // Calculate d + temp1 for the new value of e.
+        AddOperation::<AB::F>::eval(
+            builder,
+            local.d,
+            local.temp1.value,
+            local.d_add_temp1,
+            shard,
+            channel,
+            is_real.into(),
+        );
+
+        // Calculate temp1 + temp2 for the new value of a.
+        AddOperation::<AB::F>::eval(
+            builder,
+            local.temp1.value,
+            local.temp2.value,
+            local.temp1_add_temp2,
+            shard,
+            channel,
+            is_real.into(),
+        );
+    }
+}
@@ -7,8 +7,7 @@ use crate::{
     air::MemoryAirBuilder,
     operations::{field::range::FieldLtCols, IsZeroOperation},
     utils::{
-        limbs_from_access, limbs_from_prev_access, pad_rows, words_to_bytes_le,
-        words_to_bytes_le_vec,
+        limbs_from_access, limbs_from_prev_access, pad_rows_with_strategy, words_to_bytes_le_vec,
     },
 };
 
@@ -17,45 +16,47 @@ use num::{BigUint, One, Zero};
 use p3_air::{Air, AirBuilder, BaseAir};
 use p3_field::{AbstractField, PrimeField32};
 use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
 use sp1_core_executor::{
     events::{ByteRecord, FieldOperation},
     syscalls::SyscallCode,
     ExecutionRecord, Program,
 };
 use sp1_curves::{
-    params::{Limbs, NumLimbs, NumWords},
-    uint256::U256Field,
+    params::{Limbs, NumLimbs},
+    uint::{UintField, UintType},
 };
 use sp1_derive::AlignedBorrow;
 use sp1_stark::{
-    air::{BaseAirBuilder, MachineAir, Polynomial, SP1AirBuilder},
+    air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder},
     MachineRecord,
 };
 use std::{
     borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
     mem::size_of,
 };
-use typenum::Unsigned;
 
-/// The number of columns in the Uint256MulCols.
-const NUM_COLS: usize = size_of::<Uint256MulCols<u8>>();
+/// The number of columns in the UintMulCols.
+pub const fn num_uint_mul_cols<P: UintField>() -> usize {
+    size_of::<UintMulCols<u8, P>>()
+}
 
-#[derive(Default)]
-pub struct Uint256MulChip;
+/// A chip that computes `(x * y) % modulus` for unsigned integers of the width given by `P`.
+pub struct UintMulChip<P> {
+    _marker: PhantomData<P>,
+}
 
-impl Uint256MulChip {
+impl<P: UintField> UintMulChip<P> {
     pub const fn new() -> Self {
-        Self
+        Self { _marker: PhantomData }
     }
 }
 
-type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
-const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
-
-/// A set of columns for the Uint256Mul operation.
+/// A set of columns for the UintMul operation.
 #[derive(Debug, Clone, AlignedBorrow)]
 #[repr(C)]
-pub struct Uint256MulCols<T> {
+pub struct UintMulCols<T, P: UintField> {
     /// The shard number of the syscall.
     pub shard: T,
 
@@ -76,11 +77,11 @@ pub struct Uint256MulCols<T> {
 
     // Memory columns.
     // x_memory is written to with the result, which is why it is of type MemoryWriteCols.
-    pub x_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
-    pub y_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
-    pub modulus_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub x_memory: GenericArray<MemoryWriteCols<T>, P::WordsFieldElement>,
+    pub y_memory: GenericArray<MemoryReadCols<T>, P::WordsFieldElement>,
+    pub modulus_memory: GenericArray<MemoryReadCols<T>, P::WordsFieldElement>,
 
-    /// Columns for checking if modulus is zero. If it's zero, then use 2^256 as the effective
+    /// Columns for checking if modulus is zero. If it's zero, then use 2^bits as the effective
     /// modulus.
     pub modulus_is_zero: IsZeroOperation<T>,
 
@@ -88,19 +89,23 @@ pub struct Uint256MulCols<T> {
     pub modulus_is_not_zero: T,
 
     // Output values. We compute (x * y) % modulus.
-    pub output: FieldOpCols<T, U256Field>,
+    pub output: FieldOpCols<T, P>,
 
-    pub output_range_check: FieldLtCols<T, U256Field>,
+    pub output_range_check: FieldLtCols<T, P>,
 
     pub is_real: T,
 }
 
-impl<F: PrimeField32> MachineAir<F> for Uint256MulChip {
+impl<F: PrimeField32, P: UintField> MachineAir<F> for UintMulChip<P> {
     type Record = ExecutionRecord;
     type Program = Program;
 
     fn name(&self) -> String {
-        "Uint256MulMod".to_string()
+        match P::UINT_TYPE {
+            UintType::Uint256 => "Uint256MulMod".to_string(),
+            UintType::Uint384 => "Uint384MulMod".to_string(),
+            UintType::Uint512 => "Uint512MulMod".to_string(),
+        }
     }
 
     fn generate_trace(
@@ -108,10 +113,17 @@ impl<F: PrimeField32> MachineAir<F> for Uint256MulChip {
         input: &ExecutionRecord,
         output: &mut ExecutionRecord,
     ) -> RowMajorMatrix<F> {
-        // Generate the trace rows & corresponding records for each chunk of events concurrently.
-        let rows_and_records = input
-            .uint256_mul_events
-            .chunks(1)
+        let events = match P::UINT_TYPE {
+            UintType::Uint256 => &input.uint256_mul_events,
+            UintType::Uint384 => &input.uint384_mul_events,
+            UintType::Uint512 => &input.uint512_mul_events,
+        };
+
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        // Generate the trace rows & corresponding records for each chunk of events in parallel.
+        let rows_and_records = events
+            .par_chunks(chunk_size)
             .map(|events| {
                 let mut records = ExecutionRecord::default();
                 let mut new_byte_lookup_events = Vec::new();
@@ -119,14 +131,14 @@ impl<F: PrimeField32> MachineAir<F> for Uint256MulChip {
                 let rows = events
                     .iter()
                     .map(|event| {
-                        let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
-                        let cols: &mut Uint256MulCols<F> = row.as_mut_slice().borrow_mut();
+                        let mut row = vec![F::zero(); num_uint_mul_cols::<P>()];
+                        let cols: &mut UintMulCols<F, P> = row.as_mut_slice().borrow_mut();
 
-                        // Decode uint256 points
-                        let x = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.x));
-                        let y = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.y));
+                        // Decode the uint values.
+                        let x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.x));
+                        let y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.y));
                         let modulus =
-                            BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.modulus));
+                            BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.modulus));
 
                         // Assign basic values to the columns.
                         cols.is_real = F::one();
@@ -137,7 +149,7 @@ impl<F: PrimeField32> MachineAir<F> for Uint256MulChip {
                         cols.y_ptr = F::from_canonical_u32(event.y_ptr);
 
                         // Populate memory columns.
-                        for i in 0..WORDS_FIELD_ELEMENT {
+                        for i in 0..cols.x_memory.len() {
                             cols.x_memory[i].populate(
                                 event.channel,
                                 event.x_memory_records[i],
@@ -160,8 +172,11 @@ impl<F: PrimeField32> MachineAir<F> for Uint256MulChip {
                         IsZeroOperation::populate(&mut cols.modulus_is_zero, modulus_byte_sum);
 
                         // Populate the output column.
-                        let effective_modulus =
-                            if modulus.is_zero() { BigUint::one() << 256 } else { modulus.clone() };
+                        let effective_modulus = if modulus.is_zero() {
+                            BigUint::one() << P::nb_bits()
+                        } else {
+                            modulus.clone()
+                        };
                         let result = cols.output.populate_with_modulus(
                             &mut new_byte_lookup_events,
                             event.shard,
@@ -169,7 +184,6 @@ impl<F: PrimeField32> MachineAir<F> for Uint256MulChip {
                             &x,
                             &y,
                             &effective_modulus,
-                            // &modulus,
                             FieldOperation::Mul,
                         );
 
@@ -192,32 +206,48 @@ impl<F: PrimeField32> MachineAir<F> for Uint256MulChip {
             })
             .collect::<Vec<_>>();
 
-        //  Generate the trace rows for each event.
+        // Collect the trace rows in event order. The byte lookups are counted, so merging the
+        // records doesn't depend on how the events were chunked.
         let mut rows = Vec::new();
         for (row, mut record) in rows_and_records {
             rows.extend(row);
             output.append(&mut record);
         }
 
-        pad_rows(&mut rows, || {
-            let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
-            let cols: &mut Uint256MulCols<F> = row.as_mut_slice().borrow_mut();
-
-            let x = BigUint::zero();
-            let y = BigUint::zero();
-            cols.output.populate(&mut vec![], 0, 0, &x, &y, FieldOperation::Mul);
+        // A row of zeros would leave `modulus_is_zero` unset, so that the multiplication is checked
+        // against a modulus of zero and holds for any result. The padding rows are instead the
+        // multiplication of zeros with a zero modulus, which uses the effective modulus `2^bits`.
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = vec![F::zero(); num_uint_mul_cols::<P>()];
+            let cols: &mut UintMulCols<F, P> = row.as_mut_slice().borrow_mut();
+
+            IsZeroOperation::populate(&mut cols.modulus_is_zero, 0);
+            let zero = BigUint::zero();
+            let effective_modulus = BigUint::one() << P::nb_bits();
+            cols.output.populate_with_modulus(
+                &mut vec![],
+                0,
+                0,
+                &zero,
+                &zero,
+                &effective_modulus,
+                FieldOperation::Mul,
+            );
 
             row
         });
 
         // Convert the trace to a row major matrix.
-        let mut trace =
-            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_uint_mul_cols::<P>(),
+        );
 
         // Write the nonces to the trace.
         for i in 0..trace.height() {
-            let cols: &mut Uint256MulCols<F> =
-                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            let cols: &mut UintMulCols<F, P> = trace.values
+                [i * num_uint_mul_cols::<P>()..(i + 1) * num_uint_mul_cols::<P>()]
+                .borrow_mut();
             cols.nonce = F::from_canonical_usize(i);
         }
 
@@ -225,27 +255,35 @@ impl<F: PrimeField32> MachineAir<F> for Uint256MulChip {
     }
 
     fn included(&self, shard: &Self::Record) -> bool {
-        !shard.uint256_mul_events.is_empty()
+        match P::UINT_TYPE {
+            UintType::Uint256 => !shard.uint256_mul_events.is_empty(),
+            UintType::Uint384 => !shard.uint384_mul_events.is_empty(),
+            UintType::Uint512 => !shard.uint512_mul_events.is_empty(),
+        }
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
     }
 }
 
-impl<F> BaseAir<F> for Uint256MulChip {
+impl<F, P: UintField> BaseAir<F> for UintMulChip<P> {
     fn width(&self) -> usize {
-        NUM_COLS
+        num_uint_mul_cols::<P>()
     }
 }
 
-impl<AB> Air<AB> for Uint256MulChip
+impl<AB, P: UintField> Air<AB> for UintMulChip<P>
 where
     AB: SP1AirBuilder,
-    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
 {
     fn eval(&self, builder: &mut AB) {
         let main = builder.main();
         let local = main.row_slice(0);
-        let local: &Uint256MulCols<AB::Var> = (*local).borrow();
+        let local: &UintMulCols<AB::Var, P> = (*local).borrow();
         let next = main.row_slice(1);
-        let next: &Uint256MulCols<AB::Var> = (*next).borrow();
+        let next: &UintMulCols<AB::Var, P> = (*next).borrow();
 
         // Constrain the incrementing nonce.
         builder.when_first_row().assert_zero(local.nonce);
@@ -253,34 +291,37 @@ where
 
         // We are computing (x * y) % modulus. The value of x is stored in the "prev_value" of
         // the x_memory, since we write to it later.
-        let x_limbs = limbs_from_prev_access(&local.x_memory);
-        let y_limbs = limbs_from_access(&local.y_memory);
-        let modulus_limbs = limbs_from_access(&local.modulus_memory);
+        let x_limbs: Limbs<AB::Var, <P as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.x_memory);
+        let y_limbs: Limbs<AB::Var, <P as NumLimbs>::Limbs> = limbs_from_access(&local.y_memory);
+        let modulus_limbs: Limbs<AB::Var, <P as NumLimbs>::Limbs> =
+            limbs_from_access(&local.modulus_memory);
 
         // If the modulus is zero, then we don't perform the modulus operation.
         // Evaluate the modulus_is_zero operation by summing each byte of the modulus. The sum will
-        // not overflow because we are summing 32 bytes.
+        // not overflow because we are summing at most 64 bytes. This is checked in the padding
+        // rows too, so that their multiplication is never against a modulus of zero.
         let modulus_byte_sum =
             modulus_limbs.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
         IsZeroOperation::<AB::F>::eval(
             builder,
             modulus_byte_sum,
             local.modulus_is_zero,
-            local.is_real.into(),
+            AB::Expr::one(),
         );
 
-        // If the modulus is zero, we'll actually use 2^256 as the modulus, so nothing happens.
+        // If the modulus is zero, we'll actually use 2^bits as the modulus, so nothing happens.
         // Otherwise, we use the modulus passed in.
         let modulus_is_zero = local.modulus_is_zero.result;
-        let mut coeff_2_256 = Vec::new();
-        coeff_2_256.resize(32, AB::Expr::zero());
-        coeff_2_256.push(AB::Expr::one());
+        let mut coeff_2_bits = Vec::new();
+        coeff_2_bits.resize(P::NB_LIMBS, AB::Expr::zero());
+        coeff_2_bits.push(AB::Expr::one());
         let modulus_polynomial: Polynomial<AB::Expr> = modulus_limbs.into();
         let p_modulus: Polynomial<AB::Expr> = modulus_polynomial
             * (AB::Expr::one() - modulus_is_zero.into())
-            + Polynomial::from_coefficients(&coeff_2_256) * modulus_is_zero.into();
+            + Polynomial::from_coefficients(&coeff_2_bits) * modulus_is_zero.into();
 
-        // Evaluate the uint256 multiplication
+        // Evaluate the uint multiplication
         local.output.eval_with_modulus(
             builder,
             &x_limbs,
@@ -323,23 +364,30 @@ where
         );
 
         // Evaluate the y_ptr memory access. We concatenate y and modulus into a single array since
-        // we read it contiguously from the y_ptr memory location.
+        // we read it contiguously from the y_ptr memory location. If y_ptr equals x_ptr, the read
+        // of y at clk precedes the write of x at clk + 1, so the write's previous value is the
+        // value of y.
         builder.eval_memory_access_slice(
             local.shard,
             local.channel,
             local.clk.into(),
             local.y_ptr,
-            &[local.y_memory, local.modulus_memory].concat(),
+            &[local.y_memory.clone(), local.modulus_memory.clone()].concat(),
             local.is_real,
         );
 
         // Receive the arguments.
+        let syscall_id = match P::UINT_TYPE {
+            UintType::Uint256 => SyscallCode::UINT256_MUL.syscall_id(),
+            UintType::Uint384 => SyscallCode::UINT384_MUL.syscall_id(),
+            UintType::Uint512 => SyscallCode::UINT512_MUL.syscall_id(),
+        };
         builder.receive_syscall(
             local.shard,
             local.channel,
             local.clk,
             local.nonce,
-            AB::F::from_canonical_u32(SyscallCode::UINT256_MUL.syscall_id()),
+            AB::F::from_canonical_u32(syscall_id),
             local.x_ptr,
             local.y_ptr,
             local.is_real,
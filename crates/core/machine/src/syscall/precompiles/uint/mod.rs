@@ -0,0 +1,303 @@
+mod air;
+
+pub use air::*;
+
+#[cfg(test)]
+pub mod tests {
+    use std::{borrow::BorrowMut, time::Instant};
+
+    use num::{bigint::RandBigInt, BigUint, One, Zero};
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+    use p3_matrix::dense::RowMajorMatrix;
+    use rand::{rngs::StdRng, SeedableRng};
+    use sp1_core_executor::{syscalls::SyscallCode, ExecutionRecord, Executor, Program};
+    use sp1_curves::{
+        params::FieldParameters, uint256::U256Field, uint384::U384Field, uint512::U512Field,
+        utils::biguint_from_limbs, weierstrass::bls12_381::Bls12381BaseField,
+    };
+    use sp1_stark::{
+        air::{check_air_constraints, MachineAir, Polynomial},
+        baby_bear_poseidon2::BabyBearPoseidon2,
+        CpuProver, SP1CoreOpts, StarkGenericConfig,
+    };
+
+    use super::{num_uint_mul_cols, UintMulChip, UintMulCols};
+    use crate::{
+        operations::field::util::{compute_root_quotient_and_shift, split_u16_limbs_to_u8_limbs},
+        utils::{
+            self, run_test,
+            tests::{syscall_program, to_words, words_at},
+            uni_stark_prove, uni_stark_verify,
+        },
+    };
+
+    pub const X_PTR: u32 = 100;
+    pub const Y_PTR: u32 = 300;
+
+    /// Builds a program that stores x at `X_PTR`, y and the modulus at `Y_PTR`, and invokes the
+    /// given uint syscall on operands of `num_words` words.
+    pub fn uint_program(
+        syscall: SyscallCode,
+        num_words: usize,
+        x: &BigUint,
+        y: &BigUint,
+        modulus: &BigUint,
+    ) -> Program {
+        let words = words_at(X_PTR, num_words, &[x]).into_iter().chain(words_at(
+            Y_PTR,
+            num_words,
+            &[y, modulus],
+        ));
+        syscall_program(syscall, words, X_PTR, Y_PTR)
+    }
+
+    /// Executes the given uint syscall and returns the words written back to `X_PTR`.
+    pub fn execute_uint(
+        syscall: SyscallCode,
+        num_words: usize,
+        x: &BigUint,
+        y: &BigUint,
+        modulus: &BigUint,
+    ) -> Vec<u32> {
+        let program = uint_program(syscall, num_words, x, y, modulus);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        (0..num_words as u32).map(|i| runtime.word(X_PTR + i * 4)).collect()
+    }
+
+    fn uint512_mul_cases() -> Vec<(BigUint, BigUint, BigUint)> {
+        let max = (BigUint::one() << 512) - 1u32;
+        let rsa_modulus = (BigUint::one() << 511) + 187u32;
+        vec![
+            // Small values with a small modulus.
+            (BigUint::from(7u32), BigUint::from(12u32), BigUint::from(13u32)),
+            // The product overflows 2^512 and a zero modulus means it wraps around.
+            (max.clone(), max - 1u32, BigUint::zero()),
+            // A full-width modulus as used in RSA arithmetic.
+            (rsa_modulus.clone() - 5u32, rsa_modulus.clone() - 7u32, rsa_modulus.clone()),
+            // An input larger than the modulus.
+            (rsa_modulus.clone() + 3u32, BigUint::from(2u32), rsa_modulus),
+        ]
+    }
+
+    #[test]
+    fn test_uint512_mul_execute() {
+        utils::setup_logger();
+        for (x, y, modulus) in uint512_mul_cases() {
+            let result = execute_uint(SyscallCode::UINT512_MUL, 16, &x, &y, &modulus);
+            let effective_modulus =
+                if modulus.is_zero() { BigUint::one() << 512 } else { modulus.clone() };
+            assert_eq!(result, to_words(16, &((&x * &y) % effective_modulus)));
+        }
+    }
+
+    #[test]
+    fn test_uint512_mul_prove() {
+        utils::setup_logger();
+        for (x, y, modulus) in uint512_mul_cases() {
+            let program = uint_program(SyscallCode::UINT512_MUL, 16, &x, &y, &modulus);
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_uint512_modulus() {
+        assert_eq!(biguint_from_limbs(U512Field::MODULUS), U512Field::modulus());
+    }
+
+    /// Random pairs of BLS12-381 base field elements with the field modulus.
+    fn uint384_mul_cases() -> Vec<(BigUint, BigUint, BigUint)> {
+        let mut rng = StdRng::seed_from_u64(0);
+        let modulus = Bls12381BaseField::modulus();
+        (0..4)
+            .map(|_| {
+                let x = rng.gen_biguint_below(&modulus);
+                let y = rng.gen_biguint_below(&modulus);
+                (x, y, modulus.clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_uint384_mul_execute() {
+        utils::setup_logger();
+        for (x, y, modulus) in uint384_mul_cases() {
+            let result = execute_uint(SyscallCode::UINT384_MUL, 12, &x, &y, &modulus);
+            assert_eq!(result, to_words(12, &((&x * &y) % &modulus)));
+        }
+
+        // A zero modulus means the product wraps around 2^384.
+        let max = (BigUint::one() << 384) - 1u32;
+        let result = execute_uint(SyscallCode::UINT384_MUL, 12, &max, &max, &BigUint::zero());
+        assert_eq!(result, to_words(12, &((&max * &max) % (BigUint::one() << 384))));
+    }
+
+    #[test]
+    fn test_uint384_mul_prove() {
+        utils::setup_logger();
+        for (x, y, modulus) in uint384_mul_cases() {
+            let program = uint_program(SyscallCode::UINT384_MUL, 12, &x, &y, &modulus);
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_uint384_modulus() {
+        assert_eq!(biguint_from_limbs(U384Field::MODULUS), U384Field::modulus());
+    }
+
+    /// Replaces the result of a uint256 mul with `result + modulus`, which still fits in 256 bits,
+    /// and recomputes the carry and witness so that the multiplication itself remains satisfied.
+    /// Only the range check on the output can reject such a row.
+    #[test]
+    fn test_uint256_mul_non_canonical_result() {
+        let x = (BigUint::one() << 255) + 12345u32;
+        let y = (BigUint::one() << 200) + 6789u32;
+        let modulus = (BigUint::one() << 254) + 1u32;
+        let mut runtime = Executor::new(
+            uint_program(SyscallCode::UINT256_MUL, 8, &x, &y, &modulus),
+            SP1CoreOpts::default(),
+        );
+        runtime.run().unwrap();
+
+        let chip = UintMulChip::<U256Field>::new();
+        let width = num_uint_mul_cols::<U256Field>();
+        let mut trace: RowMajorMatrix<BabyBear> =
+            chip.generate_trace(&runtime.record, &mut ExecutionRecord::default());
+
+        let result = (&x * &y) % &modulus;
+        let carry = (&x * &y) / &modulus;
+        let tampered_result = &result + &modulus;
+        let tampered_carry = carry - 1u32;
+        assert!(tampered_result.bits() <= 256);
+
+        let p_x: Polynomial<BabyBear> = U256Field::to_limbs_field::<BabyBear, _>(&x).into();
+        let p_y: Polynomial<BabyBear> = U256Field::to_limbs_field::<BabyBear, _>(&y).into();
+        let p_modulus: Polynomial<BabyBear> =
+            U256Field::to_limbs_field::<BabyBear, _>(&modulus).into();
+        let p_result: Polynomial<BabyBear> =
+            U256Field::to_limbs_field::<BabyBear, _>(&tampered_result).into();
+        let p_carry: Polynomial<BabyBear> =
+            U256Field::to_limbs_field::<BabyBear, _>(&tampered_carry).into();
+        let p_op = &p_x * &p_y;
+        let p_vanishing = &p_op - &p_result - &p_carry * &p_modulus;
+        let p_witness = compute_root_quotient_and_shift(
+            &p_vanishing,
+            U256Field::WITNESS_OFFSET,
+            U256Field::NB_BITS_PER_LIMB as u32,
+            U256Field::NB_WITNESS_LIMBS,
+        );
+        let (witness_low, witness_high) = split_u16_limbs_to_u8_limbs(&p_witness);
+
+        let cols: &mut UintMulCols<BabyBear, U256Field> = trace.values[..width].borrow_mut();
+        for (limb, value) in cols.output.result.0.iter_mut().zip(p_result.coefficients()) {
+            *limb = *value;
+        }
+        for (limb, value) in cols.output.carry.0.iter_mut().zip(p_carry.coefficients()) {
+            *limb = *value;
+        }
+        for (limb, value) in cols.output.witness_low.0.iter_mut().zip(witness_low) {
+            *limb = value;
+        }
+        for (limb, value) in cols.output.witness_high.0.iter_mut().zip(witness_high) {
+            *limb = value;
+        }
+        for (i, memory) in cols.x_memory.iter_mut().enumerate() {
+            memory.access.value.0.copy_from_slice(&cols.output.result.0[i * 4..(i + 1) * 4]);
+        }
+
+        // The tampered row is rejected, and nothing else.
+        let failures = check_air_constraints(&chip, None, &trace, &[]);
+        assert!(!failures.is_empty());
+        assert!(failures.iter().all(|failure| failure.row == 0));
+    }
+
+    /// Clears `modulus_is_zero` in a padding row, whose modulus is zero, which would check the
+    /// row's multiplication against a modulus of zero.
+    #[test]
+    #[should_panic]
+    fn test_uint256_mul_padding_zero_modulus() {
+        let x = BigUint::from(3u32);
+        let y = BigUint::from(5u32);
+        let modulus = BigUint::from(7u32);
+        let mut runtime = Executor::new(
+            uint_program(SyscallCode::UINT256_MUL, 8, &x, &y, &modulus),
+            SP1CoreOpts::default(),
+        );
+        runtime.run().unwrap();
+
+        let chip = UintMulChip::<U256Field>::new();
+        let width = num_uint_mul_cols::<U256Field>();
+        let mut trace: RowMajorMatrix<BabyBear> =
+            chip.generate_trace(&runtime.record, &mut ExecutionRecord::default());
+
+        let cols: &mut UintMulCols<BabyBear, U256Field> =
+            trace.values[width..2 * width].borrow_mut();
+        assert_eq!(cols.is_real, BabyBear::zero());
+        cols.modulus_is_zero.result = BabyBear::zero();
+
+        let config = BabyBearPoseidon2::new();
+        let mut challenger = config.challenger();
+        let proof = uni_stark_prove::<BabyBearPoseidon2, _>(&config, &chip, &mut challenger, trace);
+
+        let mut challenger = config.challenger();
+        uni_stark_verify(&config, &chip, &mut challenger, &proof).unwrap();
+    }
+
+    /// Generates the trace for `num_events` copies of a single uint256 mul event, and checks that
+    /// every row and the byte lookups match those of the single event. Returns the time taken.
+    fn check_uint256_mul_trace(num_events: usize) -> std::time::Duration {
+        let x = (BigUint::one() << 255) + 12345u32;
+        let y = (BigUint::one() << 200) + 6789u32;
+        let modulus = (BigUint::one() << 254) + 1u32;
+        let mut runtime = Executor::new(
+            uint_program(SyscallCode::UINT256_MUL, 8, &x, &y, &modulus),
+            SP1CoreOpts::default(),
+        );
+        runtime.run().unwrap();
+        let event = runtime.record.uint256_mul_events[0].clone();
+
+        let chip = UintMulChip::<U256Field>::new();
+        let width = num_uint_mul_cols::<U256Field>();
+        let single =
+            ExecutionRecord { uint256_mul_events: vec![event.clone()], ..Default::default() };
+        let mut single_output = ExecutionRecord::default();
+        let single_trace: RowMajorMatrix<BabyBear> =
+            chip.generate_trace(&single, &mut single_output);
+
+        let many =
+            ExecutionRecord { uint256_mul_events: vec![event; num_events], ..Default::default() };
+        let mut many_output = ExecutionRecord::default();
+        let start = Instant::now();
+        let many_trace: RowMajorMatrix<BabyBear> = chip.generate_trace(&many, &mut many_output);
+        let elapsed = start.elapsed();
+
+        // The rows only differ in their nonces.
+        for i in 0..num_events {
+            let mut row = many_trace.values[i * width..(i + 1) * width].to_vec();
+            let cols: &mut UintMulCols<BabyBear, U256Field> = row.as_mut_slice().borrow_mut();
+            cols.nonce = BabyBear::zero();
+            assert_eq!(row, &single_trace.values[..width]);
+        }
+
+        for (shard, lookups) in single_output.byte_lookups.iter() {
+            for (lookup, count) in lookups.iter() {
+                assert_eq!(many_output.byte_lookups[shard][lookup], count * num_events);
+            }
+        }
+        elapsed
+    }
+
+    #[test]
+    fn test_uint256_mul_generate_trace() {
+        check_uint256_mul_trace(1000);
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_uint256_mul_generate_trace() {
+        let elapsed = check_uint256_mul_trace(100_000);
+        println!("generate_trace for 100k uint256 mul events: {:?}", elapsed);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::{pad_rows, par_generate_rows},
+};
+
+use generic_array::GenericArray;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord},
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_curves::{params::NumWords, uint256::U256Field};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Uint256BitwiseCols.
+const NUM_COLS: usize = size_of::<Uint256BitwiseCols<u8>>();
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// A chip that computes the bitwise and, or or xor of two 256-bit integers.
+///
+/// `x_ptr` points to the 8 words of `x`, which are overwritten with the result, and `y_ptr` points
+/// to the 8 words of `y`. Each byte of the result is checked against the bytes of `x` and `y` with
+/// a lookup into the bitwise rows of the byte table, as in the bitwise ALU chip, so the words are
+/// already decomposed into bytes by the memory columns.
+#[derive(Default)]
+pub struct Uint256BitwiseChip;
+
+impl Uint256BitwiseChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Uint256Bitwise operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Uint256BitwiseCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to x.
+    pub x_ptr: T,
+
+    /// The pointer to y.
+    pub y_ptr: T,
+
+    // Memory columns.
+    pub x_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+    pub y_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+
+    /// If the operation is AND.
+    pub is_and: T,
+
+    /// If the operation is OR.
+    pub is_or: T,
+
+    /// If the operation is XOR.
+    pub is_xor: T,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Uint256BitwiseChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Uint256Bitwise".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.uint256_bitwise_events, |event, new_byte_lookup_events| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Uint256BitwiseCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.is_and = F::from_bool(event.op == ByteOpcode::AND);
+                cols.is_or = F::from_bool(event.op == ByteOpcode::OR);
+                cols.is_xor = F::from_bool(event.op == ByteOpcode::XOR);
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+                // Populate memory columns.
+                for i in 0..WORDS_FIELD_ELEMENT {
+                    cols.x_memory[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.y_memory[i].populate(
+                        event.channel,
+                        event.y_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+
+                // Look up each byte of the result.
+                for (record, y) in event.x_memory_records.iter().zip(&event.y) {
+                    let x_bytes = record.prev_value.to_le_bytes();
+                    let result_bytes = record.value.to_le_bytes();
+                    for ((result, x), y) in
+                        result_bytes.into_iter().zip(x_bytes).zip(y.to_le_bytes())
+                    {
+                        new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                            event.shard,
+                            event.channel,
+                            event.op,
+                            result as u16,
+                            0,
+                            x,
+                            y,
+                        ));
+                    }
+                }
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Uint256BitwiseCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.uint256_bitwise_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for Uint256BitwiseChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Uint256BitwiseChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Uint256BitwiseCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Uint256BitwiseCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Exactly one operation is selected on a real row, and none on a padding row.
+        builder.assert_bool(local.is_and);
+        builder.assert_bool(local.is_or);
+        builder.assert_bool(local.is_xor);
+        builder.assert_eq(local.is_real, local.is_and + local.is_or + local.is_xor);
+
+        // Get the opcode for the operation.
+        let opcode = local.is_and * ByteOpcode::AND.as_field::<AB::F>()
+            + local.is_or * ByteOpcode::OR.as_field::<AB::F>()
+            + local.is_xor * ByteOpcode::XOR.as_field::<AB::F>();
+
+        // Each byte of the result is the operation applied to the bytes of x and y.
+        let result_bytes = local.x_memory.iter().flat_map(|mem| mem.value().0);
+        let x_bytes = local.x_memory.iter().flat_map(|mem| mem.prev_value().0);
+        let y_bytes = local.y_memory.iter().flat_map(|mem| mem.value().0);
+        for ((result, x), y) in result_bytes.zip(x_bytes).zip(y_bytes) {
+            builder.send_byte(
+                opcode.clone(),
+                result,
+                x,
+                y,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Read y and write the result over x.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.y_ptr,
+            &local.y_memory,
+            local.is_real,
+        );
+
+        // Get the syscall id of the operation.
+        let syscall_id = local.is_and
+            * AB::F::from_canonical_u32(SyscallCode::UINT256_AND.syscall_id())
+            + local.is_or * AB::F::from_canonical_u32(SyscallCode::UINT256_OR.syscall_id())
+            + local.is_xor * AB::F::from_canonical_u32(SyscallCode::UINT256_XOR.syscall_id());
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id,
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
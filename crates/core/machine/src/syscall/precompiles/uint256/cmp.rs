@@ -0,0 +1,318 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::{pad_rows, par_generate_rows},
+};
+
+use generic_array::GenericArray;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord},
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_curves::{params::NumWords, uint256::U256Field};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::WORD_SIZE;
+use sp1_stark::air::{MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Uint256CmpCols.
+const NUM_COLS: usize = size_of::<Uint256CmpCols<u8>>();
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// The number of bytes of a 256-bit integer.
+const NUM_BYTES: usize = WORDS_FIELD_ELEMENT * WORD_SIZE;
+
+/// A chip that compares two 256-bit integers.
+///
+/// `x_ptr` points to the 8 words of `x`, followed by a word to which the result is written: -1, 0
+/// or 1 as a two's complement word, when `x` is less than, equal to or greater than `y`. `y_ptr`
+/// points to the 8 words of `y`.
+///
+/// The most significant differing byte decides the comparison, and it is selected with a priority
+/// encoder from the most significant byte down: `eq_above[i]` is set when all the bytes above `i`
+/// are equal, so it is set for the differing byte and cleared for every byte below it. Each byte is
+/// compared with an `LTU` lookup in both directions, so equality is when neither is less.
+#[derive(Default)]
+pub struct Uint256CmpChip;
+
+impl Uint256CmpChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Uint256Cmp operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Uint256CmpCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to x.
+    pub x_ptr: T,
+
+    /// The pointer to y.
+    pub y_ptr: T,
+
+    // Memory columns.
+    pub x_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub y_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub result_memory: MemoryWriteCols<T>,
+
+    /// Whether each byte of x is less than the byte of y.
+    pub byte_is_less: [T; NUM_BYTES],
+
+    /// Whether each byte of x is greater than the byte of y.
+    pub byte_is_greater: [T; NUM_BYTES],
+
+    /// Whether all the bytes above each byte are equal in x and y.
+    pub eq_above: [T; NUM_BYTES],
+
+    /// Whether x is less than y.
+    pub is_less: T,
+
+    /// Whether x is greater than y.
+    pub is_greater: T,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Uint256CmpChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Uint256Cmp".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.uint256_cmp_events, |event, new_byte_lookup_events| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Uint256CmpCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+                // Populate memory columns.
+                for i in 0..WORDS_FIELD_ELEMENT {
+                    cols.x_memory[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.y_memory[i].populate(
+                        event.channel,
+                        event.y_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+                cols.result_memory.populate(
+                    event.channel,
+                    event.result_memory_record,
+                    new_byte_lookup_events,
+                );
+
+                // Compare the bytes.
+                let x_bytes = event.x.iter().flat_map(|word| word.to_le_bytes());
+                let y_bytes = event.y.iter().flat_map(|word| word.to_le_bytes());
+                let bytes = x_bytes.zip(y_bytes).collect::<Vec<_>>();
+                for (i, &(x, y)) in bytes.iter().enumerate() {
+                    cols.byte_is_less[i] = F::from_bool(x < y);
+                    cols.byte_is_greater[i] = F::from_bool(x > y);
+                    for (b, c) in [(x, y), (y, x)] {
+                        new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                            event.shard,
+                            event.channel,
+                            ByteOpcode::LTU,
+                            (b < c) as u16,
+                            0,
+                            b,
+                            c,
+                        ));
+                    }
+                }
+
+                // Select the most significant differing byte.
+                let mut eq_above = true;
+                for (i, &(x, y)) in bytes.iter().enumerate().rev() {
+                    cols.eq_above[i] = F::from_bool(eq_above);
+                    if eq_above && x != y {
+                        cols.is_less = F::from_bool(x < y);
+                        cols.is_greater = F::from_bool(x > y);
+                    }
+                    eq_above &= x == y;
+                }
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Uint256CmpCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.uint256_cmp_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for Uint256CmpChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Uint256CmpChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Uint256CmpCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Uint256CmpCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Compare each byte of x with the byte of y, in both directions. The lookups also check
+        // that the flags are booleans that are not both set on real rows.
+        let x_bytes = local.x_memory.iter().flat_map(|mem| mem.value().0);
+        let y_bytes = local.y_memory.iter().flat_map(|mem| mem.value().0);
+        for (i, (x, y)) in x_bytes.zip(y_bytes).enumerate() {
+            builder.send_byte(
+                ByteOpcode::LTU.as_field::<AB::F>(),
+                local.byte_is_less[i],
+                x,
+                y,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            builder.send_byte(
+                ByteOpcode::LTU.as_field::<AB::F>(),
+                local.byte_is_greater[i],
+                y,
+                x,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // There are no bytes above the most significant one, and `eq_above` stays set down to the
+        // most significant differing byte, below which it is cleared. On padding rows it is zero.
+        builder.assert_eq(local.eq_above[NUM_BYTES - 1], local.is_real);
+        for i in 1..NUM_BYTES {
+            let is_equal = AB::Expr::one() - local.byte_is_less[i] - local.byte_is_greater[i];
+            builder.assert_eq(local.eq_above[i - 1], local.eq_above[i] * is_equal);
+        }
+
+        // Only the most significant differing byte has `eq_above` set and differs, so it decides
+        // the comparison, and at most one of the flags is set.
+        let mut is_less = AB::Expr::zero();
+        let mut is_greater = AB::Expr::zero();
+        for i in 0..NUM_BYTES {
+            is_less = is_less + local.eq_above[i] * local.byte_is_less[i];
+            is_greater = is_greater + local.eq_above[i] * local.byte_is_greater[i];
+        }
+        builder.assert_eq(local.is_less, is_less);
+        builder.assert_eq(local.is_greater, is_greater);
+
+        // The result is -1, 0 or 1 as x is less than, equal to or greater than y, so its least
+        // significant byte is 255, 0 or 1 and the others are 255 if x is less, and 0 otherwise.
+        let result = local.result_memory.value();
+        let all_ones = AB::Expr::from_canonical_u8(u8::MAX) * local.is_less;
+        builder.when(local.is_real).assert_eq(result[0], all_ones.clone() + local.is_greater);
+        for byte in &result.0[1..] {
+            builder.when(local.is_real).assert_eq(*byte, all_ones.clone());
+        }
+
+        // Read x and y.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.y_ptr,
+            &local.y_memory,
+            local.is_real,
+        );
+
+        // Write the result after x.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr.into() + AB::Expr::from_canonical_usize(NUM_BYTES),
+            &local.result_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::UINT256_CMP.syscall_id()),
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
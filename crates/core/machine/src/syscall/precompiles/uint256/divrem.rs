@@ -0,0 +1,357 @@
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+};
+
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    operations::field::range::FieldLtCols,
+    utils::{
+        limbs_from_access, limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows,
+        words_to_bytes_le,
+    },
+};
+
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::U256Field,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Uint256DivRemCols.
+const NUM_COLS: usize = size_of::<Uint256DivRemCols<u8>>();
+
+/// A chip that divides a 256-bit integer x by a nonzero 256-bit divisor.
+///
+/// `x_ptr` points to the 8 words of x, which are overwritten with the quotient, and `divisor_ptr`
+/// points to the 8 words of the divisor, which are followed by 8 words that receive the remainder.
+///
+/// The quotient q and remainder r are witnessed and constrained by `x = q * divisor + r` with
+/// `r < divisor`. Both the product and the sum are computed modulo 2^256 with a carry asserted to
+/// be zero, so the equation holds over the integers.
+#[derive(Default)]
+pub struct Uint256DivRemChip;
+
+impl Uint256DivRemChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// A set of columns for the Uint256DivRem operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Uint256DivRemCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the dividend.
+    pub x_ptr: T,
+
+    /// The pointer to the divisor.
+    pub divisor_ptr: T,
+
+    // Memory columns.
+    // x_memory is written to with the quotient, which is why it is of type MemoryWriteCols.
+    pub x_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+    pub divisor_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub remainder_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+
+    /// The product of the quotient and the divisor.
+    pub quotient_times_divisor: FieldOpCols<T, U256Field>,
+
+    /// The sum of the product and the remainder, which must equal x.
+    pub dividend: FieldOpCols<T, U256Field>,
+
+    /// Columns for checking that the remainder is less than the divisor.
+    pub remainder_range_check: FieldLtCols<T, U256Field>,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Uint256DivRemChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Uint256DivRem".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.uint256_divrem_events, |event, new_byte_lookup_events| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Uint256DivRemCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Decode uint256 values.
+                let x = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.x));
+                let divisor = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.divisor));
+                let quotient = &x / &divisor;
+                let remainder = &x % &divisor;
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.divisor_ptr = F::from_canonical_u32(event.divisor_ptr);
+
+                // Populate memory columns.
+                for i in 0..WORDS_FIELD_ELEMENT {
+                    cols.x_memory[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.divisor_memory[i].populate(
+                        event.channel,
+                        event.divisor_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.remainder_memory[i].populate(
+                        event.channel,
+                        event.remainder_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+
+                // The quotient and remainder are not results of a field operation, so their limbs
+                // are range checked separately.
+                for value in [&quotient, &remainder] {
+                    new_byte_lookup_events.add_u8_range_checks_field(
+                        event.shard,
+                        event.channel,
+                        &U256Field::to_limbs_field::<F, _>(value).0,
+                    );
+                }
+
+                // Populate x = quotient * divisor + remainder, computed modulo 2^256.
+                let product = cols.quotient_times_divisor.populate(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    &quotient,
+                    &divisor,
+                    FieldOperation::Mul,
+                );
+                cols.dividend.populate(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    &product,
+                    &remainder,
+                    FieldOperation::Add,
+                );
+
+                cols.remainder_range_check.populate(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    &remainder,
+                    &divisor,
+                );
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+            let cols: &mut Uint256DivRemCols<F> = row.as_mut_slice().borrow_mut();
+
+            let zero = BigUint::zero();
+            cols.quotient_times_divisor.populate(
+                &mut vec![],
+                0,
+                0,
+                &zero,
+                &zero,
+                FieldOperation::Mul,
+            );
+            cols.dividend.populate(&mut vec![], 0, 0, &zero, &zero, FieldOperation::Add);
+
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Uint256DivRemCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.uint256_divrem_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F> BaseAir<F> for Uint256DivRemChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Uint256DivRemChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Uint256DivRemCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Uint256DivRemCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The value of x is stored in the "prev_value" of the x_memory, and the quotient is the
+        // value written over it.
+        let x_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.x_memory);
+        let quotient_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&local.x_memory);
+        let divisor_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&local.divisor_memory);
+        let remainder_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&local.remainder_memory);
+
+        // Range check the quotient and remainder, which are inputs to the field operations.
+        builder.slice_range_check_u8(&quotient_limbs.0, local.shard, local.channel, local.is_real);
+        builder.slice_range_check_u8(&remainder_limbs.0, local.shard, local.channel, local.is_real);
+
+        // Evaluate quotient * divisor + remainder modulo 2^256.
+        local.quotient_times_divisor.eval(
+            builder,
+            &quotient_limbs,
+            &divisor_limbs,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.dividend.eval(
+            builder,
+            &local.quotient_times_divisor.result,
+            &remainder_limbs,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Neither operation may wrap around 2^256, so that the equation holds over the integers.
+        for carry in
+            local.quotient_times_divisor.carry.0.iter().chain(local.dividend.carry.0.iter())
+        {
+            builder.when(local.is_real).assert_zero(*carry);
+        }
+
+        // Assert that the sum is x.
+        builder.when(local.is_real).assert_all_eq(local.dividend.result, x_limbs);
+
+        // Verify that the remainder is less than the divisor. This also rules out a zero divisor.
+        local.remainder_range_check.eval(
+            builder,
+            &remainder_limbs,
+            &divisor_limbs,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Read x and write the quotient.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+
+        // Read the divisor.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.divisor_ptr,
+            &local.divisor_memory,
+            local.is_real,
+        );
+
+        // Write the remainder after the divisor.
+        let remainder_ptr =
+            local.divisor_ptr + AB::F::from_canonical_u32((WORDS_FIELD_ELEMENT * 4) as u32);
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            remainder_ptr,
+            &local.remainder_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::UINT256_DIVREM.syscall_id()),
+            local.x_ptr,
+            local.divisor_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
@@ -0,0 +1,404 @@
+use crate::{
+    memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+};
+
+use crate::{
+    air::MemoryAirBuilder,
+    operations::{field::range::FieldLtCols, IsZeroOperation},
+    utils::{
+        limbs_from_access, limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows,
+        words_to_bytes_le, words_to_bytes_le_vec,
+    },
+};
+
+use generic_array::GenericArray;
+use num::{BigUint, Integer, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::U256Field,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Uint256InvModCols.
+const NUM_COLS: usize = size_of::<Uint256InvModCols<u8>>();
+
+/// A chip that computes `x^{-1} % modulus` for 256-bit integers.
+///
+/// `x_ptr` points to the 8 words of x, which are overwritten with the result, and `modulus_ptr`
+/// points to the 8 words of the modulus. A modulus of zero is interpreted as 2^256. If x is not
+/// invertible, the result is zero.
+///
+/// Both outcomes are proven with a single multiplication by a witness `w < modulus`:
+/// * If x is invertible, `w` is the inverse and `w * x = 1 (mod modulus)`.
+/// * Otherwise, `w` is a non-zero zero divisor, namely `modulus / gcd(x, modulus)`, and `w * x = 0
+///   (mod modulus)`. Such a `w` exists exactly when x is not invertible.
+///
+/// Modulo one, every value is invertible with inverse zero, but `0 * x = 1 (mod 1)` would need a
+/// negative carry, so the first case is checked as `w * x = 0 (mod 1)` instead.
+#[derive(Default)]
+pub struct Uint256InvModChip;
+
+impl Uint256InvModChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// A set of columns for the Uint256InvMod operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Uint256InvModCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the input.
+    pub x_ptr: T,
+
+    /// The pointer to the modulus.
+    pub modulus_ptr: T,
+
+    // Memory columns.
+    // x_memory is written to with the result, which is why it is of type MemoryWriteCols.
+    pub x_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+    pub modulus_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+
+    /// Columns for checking if modulus is zero. If it's zero, then use 2^256 as the effective
+    /// modulus.
+    pub modulus_is_zero: IsZeroOperation<T>,
+
+    /// Column that is equal to is_real * (1 - modulus_is_zero.result).
+    pub modulus_is_not_zero: T,
+
+    /// Columns for checking if the modulus is one, in which case the inverse is zero.
+    pub modulus_is_one: IsZeroOperation<T>,
+
+    /// Whether x is invertible.
+    pub is_invertible: T,
+
+    /// The witness `w`, constrained by `w * x = is_invertible (mod modulus)`.
+    pub witness: FieldOpCols<T, U256Field>,
+
+    pub witness_range_check: FieldLtCols<T, U256Field>,
+
+    /// Columns for checking that the witness is non-zero when x is not invertible.
+    pub witness_is_zero: IsZeroOperation<T>,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Uint256InvModChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Uint256InvMod".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.uint256_inv_mod_events, |event, new_byte_lookup_events| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Uint256InvModCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Decode uint256 points
+                let x = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.x));
+                let modulus = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.modulus));
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.modulus_ptr = F::from_canonical_u32(event.modulus_ptr);
+
+                // Populate memory columns.
+                for i in 0..WORDS_FIELD_ELEMENT {
+                    cols.x_memory[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.modulus_memory[i].populate(
+                        event.channel,
+                        event.modulus_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+
+                let modulus_bytes = words_to_bytes_le_vec(&event.modulus);
+                let modulus_byte_sum = modulus_bytes.iter().map(|b| *b as u32).sum::<u32>();
+                IsZeroOperation::populate(&mut cols.modulus_is_zero, modulus_byte_sum);
+                IsZeroOperation::populate_from_field_element(
+                    &mut cols.modulus_is_one,
+                    modulus_minus_one::<F>(&modulus_bytes),
+                );
+
+                // Compute the witness: the inverse if it exists, and a zero divisor otherwise.
+                let effective_modulus =
+                    if modulus.is_zero() { BigUint::one() << 256 } else { modulus.clone() };
+                let (is_invertible, witness) = match x.modinv(&effective_modulus) {
+                    Some(inverse) => (true, inverse),
+                    None => (false, &effective_modulus / x.gcd(&effective_modulus)),
+                };
+                cols.is_invertible = F::from_bool(is_invertible);
+
+                let product = cols.witness.populate_div_with_quotient(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    &witness,
+                    &x,
+                    &effective_modulus,
+                );
+                debug_assert_eq!(product, BigUint::from(is_invertible as u8) % &effective_modulus);
+
+                let witness_byte_sum =
+                    U256Field::to_limbs(&witness).iter().map(|b| *b as u32).sum::<u32>();
+                IsZeroOperation::populate(&mut cols.witness_is_zero, witness_byte_sum);
+
+                cols.modulus_is_not_zero = F::one() - cols.modulus_is_zero.result;
+                if cols.modulus_is_not_zero == F::one() {
+                    cols.witness_range_check.populate(
+                        new_byte_lookup_events,
+                        event.shard,
+                        event.channel,
+                        &witness,
+                        &effective_modulus,
+                    );
+                }
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+            let cols: &mut Uint256InvModCols<F> = row.as_mut_slice().borrow_mut();
+
+            let zero = BigUint::zero();
+            cols.witness.populate_div_with_quotient(
+                &mut vec![],
+                0,
+                0,
+                &zero,
+                &zero,
+                &U256Field::modulus(),
+            );
+
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Uint256InvModCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.uint256_inv_mod_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+/// Returns `modulus[0] - 1 + 256 * (modulus[1] + ... + modulus[31])` for the bytes of the modulus,
+/// which is zero exactly when the modulus is one, since the sum is far below the field order.
+fn modulus_minus_one<F: PrimeField32>(modulus_bytes: &[u8]) -> F {
+    let rest = modulus_bytes[1..].iter().map(|b| *b as u32).sum::<u32>();
+    F::from_canonical_u32(modulus_bytes[0] as u32 + 256 * rest) - F::one()
+}
+
+impl<F> BaseAir<F> for Uint256InvModChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Uint256InvModChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Uint256InvModCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Uint256InvModCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The value of x is stored in the "prev_value" of the x_memory, since we write to it
+        // later.
+        let x_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.x_memory);
+        let modulus_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&local.modulus_memory);
+
+        // If the modulus is zero, then we use 2^256 as the modulus.
+        // Evaluate the modulus_is_zero operation by summing each byte of the modulus. The sum will
+        // not overflow because we are summing 32 bytes.
+        let modulus_byte_sum =
+            modulus_limbs.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            modulus_byte_sum,
+            local.modulus_is_zero,
+            local.is_real.into(),
+        );
+
+        // Check whether the modulus is one.
+        let modulus_minus_one = modulus_limbs.0[0] - AB::Expr::one()
+            + modulus_limbs.0[1..].iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb)
+                * AB::F::from_canonical_u32(256);
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            modulus_minus_one,
+            local.modulus_is_one,
+            local.is_real.into(),
+        );
+
+        let modulus_is_zero = local.modulus_is_zero.result;
+        let mut coeff_2_256 = Vec::new();
+        coeff_2_256.resize(32, AB::Expr::zero());
+        coeff_2_256.push(AB::Expr::one());
+        let modulus_polynomial: Polynomial<AB::Expr> = modulus_limbs.into();
+        let p_modulus: Polynomial<AB::Expr> = modulus_polynomial
+            * (AB::Expr::one() - modulus_is_zero.into())
+            + Polynomial::from_coefficients(&coeff_2_256) * modulus_is_zero.into();
+
+        // Evaluate `w * x = is_invertible (mod modulus)` as the division
+        // `is_invertible / x = w`. Modulo one the right hand side is zero instead.
+        builder.assert_bool(local.is_invertible);
+        let p_product = Polynomial::from_coefficients(&[
+            local.is_invertible * (AB::Expr::one() - local.modulus_is_one.result)
+        ]);
+        local.witness.eval_with_modulus(
+            builder,
+            &p_product,
+            &x_limbs,
+            &p_modulus,
+            FieldOperation::Div,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Verify that the witness is reduced if the modulus is not zero. Also, check the value of
+        // modulus_is_not_zero.
+        local.witness_range_check.eval(
+            builder,
+            &local.witness.result,
+            &modulus_limbs,
+            local.shard,
+            local.channel,
+            local.modulus_is_not_zero,
+        );
+        builder.assert_eq(
+            local.modulus_is_not_zero,
+            local.is_real * (AB::Expr::one() - modulus_is_zero.into()),
+        );
+
+        // If x is not invertible, the witness must be a non-zero zero divisor.
+        let witness_byte_sum =
+            local.witness.result.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            witness_byte_sum,
+            local.witness_is_zero,
+            local.is_real.into(),
+        );
+        builder
+            .when(local.is_real)
+            .when_not(local.is_invertible)
+            .assert_zero(local.witness_is_zero.result);
+
+        // Assert that the correct result is being written to x_memory: the witness if x is
+        // invertible, and zero otherwise.
+        for (result_limb, written_limb) in
+            local.witness.result.0.iter().zip(value_as_limbs(&local.x_memory))
+        {
+            builder.when(local.is_real).assert_eq(written_limb, local.is_invertible * *result_limb);
+        }
+
+        // Read and write x.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+
+        // Read the modulus.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.modulus_ptr,
+            &local.modulus_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::UINT256_INV_MOD.syscall_id()),
+            local.x_ptr,
+            local.modulus_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
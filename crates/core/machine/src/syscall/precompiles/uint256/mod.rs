@@ -1,19 +1,193 @@
-mod air;
+mod add_mod;
+mod bitwise;
+mod cmp;
+mod divrem;
+mod inv_mod;
+mod mod_exp;
+mod mont_mul;
+mod mul_wide;
+mod range_check;
+mod square_mod;
+mod sub_mod;
 
-pub use air::*;
+pub use add_mod::*;
+pub use bitwise::*;
+pub use cmp::*;
+pub use divrem::*;
+pub use inv_mod::*;
+pub use mod_exp::*;
+pub use mont_mul::*;
+pub use mul_wide::*;
+pub use range_check::*;
+pub use square_mod::*;
+pub use sub_mod::*;
+
+use sp1_curves::uint256::U256Field;
+
+use super::uint::{UintMulChip, UintMulCols};
+
+/// A chip that computes `(x * y) % modulus` for 256-bit integers.
+pub type Uint256MulChip = UintMulChip<U256Field>;
+
+/// A set of columns for the Uint256Mul operation.
+pub type Uint256MulCols<T> = UintMulCols<T, U256Field>;
 
 #[cfg(test)]
 mod tests {
 
-    use sp1_core_executor::Program;
+    use num::{bigint::RandBigInt, BigUint, One, Zero};
+    use p3_air::BaseAir;
+    use p3_baby_bear::BabyBear;
+    use rand::{rngs::StdRng, SeedableRng};
+    use sp1_core_executor::{syscalls::SyscallCode, ExecutionError, Executor, Program};
     use sp1_curves::{params::FieldParameters, uint256::U256Field, utils::biguint_from_limbs};
-    use sp1_stark::CpuProver;
+    use sp1_stark::{CpuProver, SP1CoreOpts};
 
+    use super::{Uint256MulChip, Uint256SquareModChip};
     use crate::{
         io::SP1Stdin,
-        utils::{self, run_test_io, tests::UINT256_MUL_ELF},
+        syscall::precompiles::uint::tests::{execute_uint, uint_program, X_PTR, Y_PTR},
+        utils::{
+            self, run_test, run_test_io,
+            tests::{call_syscall, syscall_program, to_words, words_at, UINT256_MUL_ELF},
+        },
     };
 
+    const NUM_WORDS: usize = 8;
+
+    fn effective_modulus(modulus: &BigUint) -> BigUint {
+        if modulus.is_zero() {
+            BigUint::one() << 256
+        } else {
+            modulus.clone()
+        }
+    }
+
+    /// Executes the given syscall on x at `X_PTR`, and y and the modulus at `Y_PTR`, and checks
+    /// that the executor rejects the arguments.
+    fn assert_invalid_argument(syscall: SyscallCode, x: &BigUint, y: &BigUint, modulus: &BigUint) {
+        let program = uint_program(syscall, NUM_WORDS, x, y, modulus);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err, ExecutionError::InvalidSyscallArgument(code, _) if code == syscall));
+    }
+
+    fn mul_mod_cases() -> Vec<(BigUint, BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        vec![
+            // Small values with a small modulus.
+            (BigUint::from(7u32), BigUint::from(12u32), BigUint::from(13u32)),
+            // The product overflows 2^256 and a zero modulus means it wraps around.
+            (max.clone(), max.clone() - 1u32, BigUint::zero()),
+            // A product of two powers of two that is exactly 2^256 wraps around to zero.
+            (BigUint::one() << 128, BigUint::one() << 128, BigUint::zero()),
+            // A product that does not overflow is unaffected by a zero modulus.
+            (BigUint::from(u64::MAX), BigUint::from(u64::MAX), BigUint::zero()),
+            // An unreduced x is accepted as long as the quotient of the product fits in 256 bits.
+            (max.clone(), BigUint::from(12u32), BigUint::from(1_000_003u32)),
+            // The product overflows 2^256 with the largest possible modulus.
+            (max.clone() - 1u32, max.clone() - 2u32, max),
+        ]
+    }
+
+    fn add_mod_cases() -> Vec<(BigUint, BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        vec![
+            // Small values with a small modulus.
+            (BigUint::from(7u32), BigUint::from(12u32), BigUint::from(13u32)),
+            // The sum overflows 2^256 and a zero modulus means the sum wraps around.
+            (max.clone(), BigUint::from(5u32), BigUint::zero()),
+            // The inputs are larger than the modulus.
+            (max.clone() - 1u32, max.clone() - 2u32, BigUint::from(1_000_003u32)),
+            // The sum overflows 2^256 with the largest possible modulus.
+            (max.clone() - 1u32, max.clone() - 3u32, max),
+        ]
+    }
+
+    /// The add mod cases that the executor rejects.
+    fn invalid_add_mod_cases() -> Vec<(BigUint, BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        vec![
+            // A modulus of one, for which the carry of a sum that overflows 2^256 does not fit in
+            // 256 bits.
+            (max.clone(), max, BigUint::one()),
+            // A modulus of one is rejected even if the sum does not overflow.
+            (BigUint::from(7u32), BigUint::from(12u32), BigUint::one()),
+        ]
+    }
+
+    fn sub_mod_cases() -> Vec<(BigUint, BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let p = BigUint::from(1_000_003u32);
+        vec![
+            // x == y gives zero.
+            (BigUint::from(42u32), BigUint::from(42u32), p.clone()),
+            // x < y wraps around to modulus - (y - x).
+            (BigUint::from(3u32), BigUint::from(10u32), p.clone()),
+            // y == 0 leaves x unchanged.
+            (BigUint::from(999u32), BigUint::zero(), p.clone()),
+            // y is larger than the modulus.
+            (BigUint::from(5u32), max.clone(), p),
+            // x < y with a zero modulus wraps around 2^256.
+            (BigUint::one(), max.clone(), BigUint::zero()),
+            // x == y with the largest possible modulus.
+            (max.clone() - 1u32, max.clone() - 1u32, max),
+        ]
+    }
+
+    fn inv_mod_cases() -> Vec<(BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let p = BigUint::from(1_000_003u32);
+        vec![
+            // An invertible value modulo a prime.
+            (BigUint::from(12345u32), p.clone()),
+            // A value larger than the modulus.
+            (max.clone(), p),
+            // A value sharing a factor with the modulus is not invertible.
+            (BigUint::from(6u32), BigUint::from(1_000_002u32)),
+            // Zero is never invertible.
+            (BigUint::zero(), BigUint::from(17u32)),
+            // Odd values are invertible modulo 2^256.
+            (max.clone(), BigUint::zero()),
+            // Even values are not invertible modulo 2^256.
+            (max.clone() - 1u32, BigUint::zero()),
+            // Every value is invertible modulo one, with inverse zero.
+            (max, BigUint::one()),
+            (BigUint::zero(), BigUint::one()),
+            // A modulus whose bytes also sum to one.
+            (BigUint::from(3u32), BigUint::from(256u32)),
+        ]
+    }
+
+    fn square_mod_cases() -> Vec<(BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let p = BigUint::from(1_000_003u32);
+        vec![
+            // A small value with a small modulus.
+            (BigUint::from(12345u32), p.clone()),
+            // The largest reduced value.
+            (p.clone() - 1u32, p),
+            // The square overflows 2^256 and a zero modulus means it wraps around.
+            (max.clone(), BigUint::zero()),
+            // The square overflows 2^256 with the largest possible modulus.
+            (max.clone() - 1u32, max),
+        ]
+    }
+
+    /// The square mod cases that the executor rejects.
+    fn invalid_square_mod_cases() -> Vec<(BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let p = BigUint::from(1_000_003u32);
+        vec![
+            // An unreduced x whose square has a quotient that does not fit in 256 bits.
+            (max.clone(), p.clone()),
+            // An x equal to the modulus.
+            (p.clone(), p),
+            // An unreduced x with the largest possible modulus.
+            (max.clone(), max),
+        ]
+    }
+
     #[test]
     fn test_uint256_mul() {
         utils::setup_logger();
@@ -21,6 +195,603 @@ mod tests {
         run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
     }
 
+    #[test]
+    fn test_uint256_mul_mod_execute() {
+        utils::setup_logger();
+        for (x, y, modulus) in mul_mod_cases() {
+            let result = execute_uint(SyscallCode::UINT256_MUL, NUM_WORDS, &x, &y, &modulus);
+            let expected = to_words(NUM_WORDS, &((&x * &y) % effective_modulus(&modulus)));
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_uint256_mul_mod_prove() {
+        utils::setup_logger();
+        for (x, y, modulus) in mul_mod_cases() {
+            let program = uint_program(SyscallCode::UINT256_MUL, NUM_WORDS, &x, &y, &modulus);
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    /// Builds a program that squares x in place by passing `X_PTR` as both pointers, with the
+    /// modulus stored right after x.
+    fn square_in_place_program(x: &BigUint, modulus: &BigUint) -> Program {
+        let words = words_at(X_PTR, NUM_WORDS, &[x, modulus]);
+        syscall_program(SyscallCode::UINT256_MUL, words, X_PTR, X_PTR)
+    }
+
+    #[test]
+    fn test_uint256_square_in_place_execute() {
+        utils::setup_logger();
+        for (x, _, modulus) in mul_mod_cases() {
+            let mut runtime =
+                Executor::new(square_in_place_program(&x, &modulus), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> =
+                (0..NUM_WORDS as u32).map(|i| runtime.word(X_PTR + i * 4)).collect();
+            let expected = to_words(NUM_WORDS, &((&x * &x) % effective_modulus(&modulus)));
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_uint256_square_in_place_prove() {
+        utils::setup_logger();
+        for (x, _, modulus) in mul_mod_cases() {
+            run_test::<CpuProver<_, _>>(square_in_place_program(&x, &modulus)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_uint256_add_mod_execute() {
+        utils::setup_logger();
+        for (x, y, modulus) in add_mod_cases() {
+            let result = execute_uint(SyscallCode::UINT256_ADD_MOD, NUM_WORDS, &x, &y, &modulus);
+            let expected = to_words(NUM_WORDS, &((&x + &y) % effective_modulus(&modulus)));
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_uint256_add_mod_prove() {
+        utils::setup_logger();
+        for (x, y, modulus) in add_mod_cases() {
+            let program = uint_program(SyscallCode::UINT256_ADD_MOD, NUM_WORDS, &x, &y, &modulus);
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_uint256_add_mod_invalid() {
+        utils::setup_logger();
+        for (x, y, modulus) in invalid_add_mod_cases() {
+            assert_invalid_argument(SyscallCode::UINT256_ADD_MOD, &x, &y, &modulus);
+        }
+    }
+
+    #[test]
+    fn test_uint256_sub_mod_execute() {
+        utils::setup_logger();
+        for (x, y, modulus) in sub_mod_cases() {
+            let result = execute_uint(SyscallCode::UINT256_SUB_MOD, NUM_WORDS, &x, &y, &modulus);
+            let modulus = effective_modulus(&modulus);
+            let expected = to_words(NUM_WORDS, &((&modulus + &x - &y % &modulus) % &modulus));
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_uint256_sub_mod_prove() {
+        utils::setup_logger();
+        for (x, y, modulus) in sub_mod_cases() {
+            let program = uint_program(SyscallCode::UINT256_SUB_MOD, NUM_WORDS, &x, &y, &modulus);
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_uint256_inv_mod_execute() {
+        utils::setup_logger();
+        for (x, modulus) in inv_mod_cases() {
+            // The modulus is read from the second pointer, which the program fills with "y".
+            let result = execute_uint(
+                SyscallCode::UINT256_INV_MOD,
+                NUM_WORDS,
+                &x,
+                &modulus,
+                &BigUint::zero(),
+            );
+            let expected = x.modinv(&effective_modulus(&modulus)).unwrap_or_else(BigUint::zero);
+            assert_eq!(result, to_words(NUM_WORDS, &expected));
+        }
+    }
+
+    #[test]
+    fn test_uint256_inv_mod_prove() {
+        utils::setup_logger();
+        for (x, modulus) in inv_mod_cases() {
+            let program = uint_program(
+                SyscallCode::UINT256_INV_MOD,
+                NUM_WORDS,
+                &x,
+                &modulus,
+                &BigUint::zero(),
+            );
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_uint256_inv_mod_matches_fermat() {
+        utils::setup_logger();
+        // The secp256k1 and BN254 base field primes.
+        let primes = [
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            b"30644E72E131A029B85045B68181585D97816A916871CA8D3C208C16D87CFD47",
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+        for prime in primes {
+            let p = BigUint::parse_bytes(prime, 16).unwrap();
+            for _ in 0..4 {
+                let x = rng.gen_biguint_range(&BigUint::one(), &p);
+                let result =
+                    execute_uint(SyscallCode::UINT256_INV_MOD, NUM_WORDS, &x, &p, &BigUint::zero());
+                let expected = x.modpow(&(&p - 2u32), &p);
+                assert_eq!(result, to_words(NUM_WORDS, &expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_uint256_square_mod_execute() {
+        utils::setup_logger();
+        for (x, modulus) in square_mod_cases() {
+            // The modulus is read from the second pointer, which the program fills with "y".
+            let result = execute_uint(
+                SyscallCode::UINT256_SQUARE_MOD,
+                NUM_WORDS,
+                &x,
+                &modulus,
+                &BigUint::zero(),
+            );
+            let expected = to_words(NUM_WORDS, &((&x * &x) % effective_modulus(&modulus)));
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_uint256_square_mod_prove() {
+        utils::setup_logger();
+        for (x, modulus) in square_mod_cases() {
+            let program = uint_program(
+                SyscallCode::UINT256_SQUARE_MOD,
+                NUM_WORDS,
+                &x,
+                &modulus,
+                &BigUint::zero(),
+            );
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_uint256_square_mod_invalid() {
+        utils::setup_logger();
+        for (x, modulus) in invalid_square_mod_cases() {
+            // The modulus is read from the second pointer, which the program fills with "y".
+            assert_invalid_argument(
+                SyscallCode::UINT256_SQUARE_MOD,
+                &x,
+                &modulus,
+                &BigUint::zero(),
+            );
+        }
+    }
+
+    fn mul_wide_cases() -> Vec<(BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        vec![
+            // Small values leave the high half zero.
+            (BigUint::from(7u32), BigUint::from(12u32)),
+            // A zero operand gives a zero product.
+            (max.clone(), BigUint::zero()),
+            // A product of two powers of two that is exactly 2^256.
+            (BigUint::one() << 128, BigUint::one() << 128),
+            // The largest product, 2^512 - 2^257 + 1.
+            (max.clone(), max),
+        ]
+    }
+
+    /// Builds a program that stores x at `X_PTR` and y at `Y_PTR`, and writes their product to the
+    /// 16 words at `X_PTR`.
+    fn mul_wide_program(x: &BigUint, y: &BigUint) -> Program {
+        let words =
+            words_at(X_PTR, NUM_WORDS, &[x]).into_iter().chain(words_at(Y_PTR, NUM_WORDS, &[y]));
+        syscall_program(SyscallCode::UINT256_MUL_WIDE, words, X_PTR, Y_PTR)
+    }
+
+    #[test]
+    fn test_uint256_mul_wide_execute() {
+        utils::setup_logger();
+        for (x, y) in mul_wide_cases() {
+            let mut runtime = Executor::new(mul_wide_program(&x, &y), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> =
+                (0..2 * NUM_WORDS as u32).map(|i| runtime.word(X_PTR + i * 4)).collect();
+            assert_eq!(result, to_words(2 * NUM_WORDS, &(&x * &y)));
+        }
+    }
+
+    #[test]
+    fn test_uint256_mul_wide_prove() {
+        utils::setup_logger();
+        for (x, y) in mul_wide_cases() {
+            run_test::<CpuProver<_, _>>(mul_wide_program(&x, &y)).unwrap();
+        }
+    }
+
+    fn divrem_cases() -> Vec<(BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        vec![
+            // A small division with a nonzero remainder.
+            (BigUint::from(100u32), BigUint::from(7u32)),
+            // A dividend smaller than the divisor gives a zero quotient.
+            (BigUint::from(5u32), max.clone()),
+            // Dividing by one gives a zero remainder.
+            (max.clone(), BigUint::one()),
+            // The largest dividend with a divisor just above 2^128.
+            (max.clone(), (BigUint::one() << 128) + 1u32),
+            // Equal operands.
+            (max.clone(), max),
+        ]
+    }
+
+    /// Builds a program that stores x at `X_PTR` and the divisor at `Y_PTR`, and writes the
+    /// quotient to `X_PTR` and the remainder to the 8 words after the divisor.
+    fn divrem_program(x: &BigUint, divisor: &BigUint) -> Program {
+        let words = words_at(X_PTR, NUM_WORDS, &[x]).into_iter().chain(words_at(
+            Y_PTR,
+            NUM_WORDS,
+            &[divisor],
+        ));
+        syscall_program(SyscallCode::UINT256_DIVREM, words, X_PTR, Y_PTR)
+    }
+
+    #[test]
+    fn test_uint256_divrem_execute() {
+        utils::setup_logger();
+        for (x, divisor) in divrem_cases() {
+            let mut runtime = Executor::new(divrem_program(&x, &divisor), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let read = |ptr: u32| -> Vec<u32> {
+                (0..NUM_WORDS as u32).map(|i| runtime.word(ptr + i * 4)).collect()
+            };
+            assert_eq!(read(X_PTR), to_words(NUM_WORDS, &(&x / &divisor)));
+            assert_eq!(read(Y_PTR + 32), to_words(NUM_WORDS, &(&x % &divisor)));
+        }
+    }
+
+    #[test]
+    fn test_uint256_divrem_prove() {
+        utils::setup_logger();
+        for (x, divisor) in divrem_cases() {
+            run_test::<CpuProver<_, _>>(divrem_program(&x, &divisor)).unwrap();
+        }
+    }
+
+    fn range_check_cases() -> Vec<(BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16,
+        )
+        .unwrap();
+        vec![
+            // Small values on both sides of a small prime.
+            (BigUint::from(12u32), BigUint::from(13u32)),
+            (BigUint::from(14u32), BigUint::from(13u32)),
+            // The values around the prime, which differ from it in the lowest byte only.
+            (&p - 1u32, p.clone()),
+            (p.clone(), p.clone()),
+            (&p + 1u32, p.clone()),
+            // The values differ from the prime in the highest byte only.
+            (&p - (BigUint::one() << 248), p.clone()),
+            (max.clone(), max.clone() - (BigUint::one() << 248)),
+            // A lower byte that is less does not outweigh a higher byte that is greater.
+            ((BigUint::one() << 128) + 1u32, (BigUint::one() << 128) + 2u32),
+            ((BigUint::from(2u32) << 128) + 1u32, (BigUint::one() << 128) + 2u32),
+            // Zero is in range of every nonzero prime, and nothing is in range of zero.
+            (BigUint::zero(), BigUint::one()),
+            (BigUint::zero(), BigUint::zero()),
+            (max.clone(), max),
+        ]
+    }
+
+    /// Builds a program that stores the value at `X_PTR` and the prime at `Y_PTR`, and writes the
+    /// result to the word after the value.
+    fn range_check_program(value: &BigUint, prime: &BigUint) -> Program {
+        let words = words_at(X_PTR, NUM_WORDS, &[value]).into_iter().chain(words_at(
+            Y_PTR,
+            NUM_WORDS,
+            &[prime],
+        ));
+        syscall_program(SyscallCode::UINT256_RANGE_CHECK, words, X_PTR, Y_PTR)
+    }
+
+    #[test]
+    fn test_uint256_range_check_execute() {
+        utils::setup_logger();
+        for (value, prime) in range_check_cases() {
+            let mut runtime =
+                Executor::new(range_check_program(&value, &prime), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            assert_eq!(runtime.word(X_PTR + 32), (value >= prime) as u32);
+        }
+    }
+
+    #[test]
+    fn test_uint256_range_check_prove() {
+        utils::setup_logger();
+        for (value, prime) in range_check_cases() {
+            run_test::<CpuProver<_, _>>(range_check_program(&value, &prime)).unwrap();
+        }
+    }
+
+    fn cmp_cases() -> Vec<(BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let mut cases = vec![
+            // Small values, which differ in the lowest byte only.
+            (BigUint::from(12u32), BigUint::from(13u32)),
+            (BigUint::from(13u32), BigUint::from(13u32)),
+            // The values differ in the highest byte only.
+            (BigUint::one() << 248, BigUint::from(2u32) << 248),
+            (max.clone(), max.clone() - (BigUint::one() << 248)),
+            // A lower byte that is less does not outweigh a higher byte that is greater.
+            ((BigUint::from(2u32) << 128) + 1u32, (BigUint::one() << 128) + 2u32),
+            // The values differ in every byte.
+            (BigUint::zero(), max.clone()),
+            (BigUint::zero(), BigUint::zero()),
+            (max.clone(), max),
+        ];
+        // Both orders of each pair.
+        let swapped = cases.iter().map(|(x, y)| (y.clone(), x.clone())).collect::<Vec<_>>();
+        cases.extend(swapped);
+        cases
+    }
+
+    /// Builds a program that stores x at `X_PTR` and y at `Y_PTR`, and writes the result to the
+    /// word after x.
+    fn cmp_program(x: &BigUint, y: &BigUint) -> Program {
+        let words =
+            words_at(X_PTR, NUM_WORDS, &[x]).into_iter().chain(words_at(Y_PTR, NUM_WORDS, &[y]));
+        syscall_program(SyscallCode::UINT256_CMP, words, X_PTR, Y_PTR)
+    }
+
+    #[test]
+    fn test_uint256_cmp_execute() {
+        utils::setup_logger();
+        for (x, y) in cmp_cases() {
+            let mut runtime = Executor::new(cmp_program(&x, &y), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            assert_eq!(runtime.word(X_PTR + 32), x.cmp(&y) as i32 as u32);
+        }
+    }
+
+    #[test]
+    fn test_uint256_cmp_prove() {
+        utils::setup_logger();
+        for (x, y) in cmp_cases() {
+            run_test::<CpuProver<_, _>>(cmp_program(&x, &y)).unwrap();
+        }
+    }
+
+    fn bitwise_cases() -> Vec<(BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let mut cases = vec![
+            (BigUint::zero(), BigUint::zero()),
+            (BigUint::zero(), max.clone()),
+            (max.clone(), max.clone()),
+            // Alternating bits, so that every byte has both set and cleared bits.
+            (max.clone() / 3u32, max / 5u32),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..4 {
+            cases.push((rng.gen_biguint(256), rng.gen_biguint(256)));
+        }
+        cases
+    }
+
+    /// Builds a program that stores x at `X_PTR` and y at `Y_PTR`, and writes the result over x.
+    fn bitwise_program(code: SyscallCode, x: &BigUint, y: &BigUint) -> Program {
+        let words =
+            words_at(X_PTR, NUM_WORDS, &[x]).into_iter().chain(words_at(Y_PTR, NUM_WORDS, &[y]));
+        syscall_program(code, words, X_PTR, Y_PTR)
+    }
+
+    #[test]
+    fn test_uint256_bitwise_execute() {
+        utils::setup_logger();
+        for (x, y) in bitwise_cases() {
+            for (code, expected) in [
+                (SyscallCode::UINT256_AND, &x & &y),
+                (SyscallCode::UINT256_OR, &x | &y),
+                (SyscallCode::UINT256_XOR, &x ^ &y),
+            ] {
+                let mut runtime =
+                    Executor::new(bitwise_program(code, &x, &y), SP1CoreOpts::default());
+                runtime.run().unwrap();
+                let result: Vec<u32> =
+                    (0..NUM_WORDS as u32).map(|i| runtime.word(X_PTR + i * 4)).collect();
+                assert_eq!(result, to_words(NUM_WORDS, &expected), "{code:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_uint256_bitwise_prove() {
+        utils::setup_logger();
+        for (x, y) in bitwise_cases() {
+            for code in
+                [SyscallCode::UINT256_AND, SyscallCode::UINT256_OR, SyscallCode::UINT256_XOR]
+            {
+                run_test::<CpuProver<_, _>>(bitwise_program(code, &x, &y)).unwrap();
+            }
+        }
+    }
+
+    /// Builds a program that applies AND, OR and XOR in turn to x at `X_PTR` and y at `Y_PTR`,
+    /// so that the three operations share the rows and nonces of the bitwise chip.
+    fn mixed_bitwise_program(x: &BigUint, y: &BigUint) -> Program {
+        let mut program = bitwise_program(SyscallCode::UINT256_AND, x, y);
+        for code in [SyscallCode::UINT256_OR, SyscallCode::UINT256_XOR] {
+            call_syscall(&mut program.instructions, code, X_PTR, Y_PTR);
+        }
+        program
+    }
+
+    #[test]
+    fn test_uint256_bitwise_mixed_prove() {
+        utils::setup_logger();
+        for (x, y) in bitwise_cases() {
+            let program = mixed_bitwise_program(&x, &y);
+            let mut runtime = Executor::new(program.clone(), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> =
+                (0..NUM_WORDS as u32).map(|i| runtime.word(X_PTR + i * 4)).collect();
+            assert_eq!(result, to_words(NUM_WORDS, &(((&x & &y) | &y) ^ &y)));
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    fn mont_mul_cases() -> Vec<(BigUint, BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let mut cases = vec![
+            // Small values with a small modulus.
+            (BigUint::from(7u32), BigUint::from(12u32), BigUint::from(13u32)),
+            // A modulus of one gives zero.
+            (BigUint::zero(), BigUint::zero(), BigUint::one()),
+            // The largest odd modulus.
+            (&max - 1u32, &max - 2u32, max),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..8 {
+            let modulus = rng.gen_biguint(256) | BigUint::one();
+            let x = rng.gen_biguint_below(&modulus);
+            let y = rng.gen_biguint_below(&modulus);
+            cases.push((x, y, modulus));
+        }
+        cases
+    }
+
+    /// The Montgomery multiplication cases that the executor rejects.
+    fn invalid_mont_mul_cases() -> Vec<(BigUint, BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let p = BigUint::from(1_000_003u32);
+        vec![
+            // An even modulus, for which R is not invertible.
+            (BigUint::from(7u32), BigUint::from(12u32), BigUint::from(14u32)),
+            // An unreduced x whose product with y has a quotient that does not fit in 256 bits.
+            (max.clone(), max.clone() - 1u32, p.clone()),
+            // An x equal to the modulus.
+            (p.clone(), BigUint::from(12u32), p),
+        ]
+    }
+
+    #[test]
+    fn test_uint256_mont_mul_matches_mul() {
+        utils::setup_logger();
+        let r = BigUint::one() << 256;
+        for (x, y, modulus) in mont_mul_cases() {
+            // The Montgomery product of x * R and y is the plain product x * y.
+            let x_mont = (&x * &r) % &modulus;
+            let mont_result =
+                execute_uint(SyscallCode::UINT256_MONT_MUL, NUM_WORDS, &x_mont, &y, &modulus);
+            let mul_result = execute_uint(SyscallCode::UINT256_MUL, NUM_WORDS, &x, &y, &modulus);
+            assert_eq!(mont_result, mul_result);
+        }
+    }
+
+    #[test]
+    fn test_uint256_mont_mul_prove() {
+        utils::setup_logger();
+        for (x, y, modulus) in mont_mul_cases() {
+            let program = uint_program(SyscallCode::UINT256_MONT_MUL, NUM_WORDS, &x, &y, &modulus);
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_uint256_mont_mul_invalid() {
+        utils::setup_logger();
+        for (x, y, modulus) in invalid_mont_mul_cases() {
+            assert_invalid_argument(SyscallCode::UINT256_MONT_MUL, &x, &y, &modulus);
+        }
+    }
+
+    fn mod_exp_cases() -> Vec<(BigUint, BigUint, BigUint)> {
+        let max = (BigUint::one() << 256) - 1u32;
+        let mut cases = vec![
+            // Small values with a small modulus.
+            (BigUint::from(3u32), BigUint::from(200u32), BigUint::from(1_000_003u32)),
+            // A zero exponent gives one, and a modulus of one gives zero.
+            (max.clone(), BigUint::zero(), BigUint::from(13u32)),
+            (BigUint::from(5u32), BigUint::from(7u32), BigUint::one()),
+            // An unreduced base with the largest exponent.
+            (max.clone(), max.clone(), BigUint::from(1_000_003u32)),
+            // A zero modulus means the result is taken modulo 2^256.
+            (max.clone() - 2u32, BigUint::from(65537u32), BigUint::zero()),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..4 {
+            let modulus = rng.gen_biguint(256);
+            let base = rng.gen_biguint(256);
+            let exponent = rng.gen_biguint(256);
+            cases.push((base, exponent, modulus));
+        }
+        cases
+    }
+
+    #[test]
+    fn test_uint256_mod_exp_execute() {
+        utils::setup_logger();
+        for (base, exponent, modulus) in mod_exp_cases() {
+            let result =
+                execute_uint(SyscallCode::UINT256_MOD_EXP, NUM_WORDS, &base, &exponent, &modulus);
+            let expected = base.modpow(&exponent, &effective_modulus(&modulus));
+            assert_eq!(result, to_words(NUM_WORDS, &expected));
+        }
+    }
+
+    #[test]
+    fn test_uint256_mod_exp_prove() {
+        utils::setup_logger();
+        for (base, exponent, modulus) in mod_exp_cases() {
+            let program =
+                uint_program(SyscallCode::UINT256_MOD_EXP, NUM_WORDS, &base, &exponent, &modulus);
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    /// Two exponentiations in a row occupy consecutive row blocks of the same table.
+    #[test]
+    fn test_uint256_mod_exp_prove_consecutive() {
+        utils::setup_logger();
+        let (base, exponent, modulus) = mod_exp_cases().pop().unwrap();
+        let mut program =
+            uint_program(SyscallCode::UINT256_MOD_EXP, NUM_WORDS, &base, &exponent, &modulus);
+        let ecall = program.instructions[program.instructions.len() - 4..].to_vec();
+        program.instructions.extend(ecall);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
+    #[test]
+    fn test_uint256_square_mod_is_narrower_than_mul() {
+        let square_width = BaseAir::<BabyBear>::width(&Uint256SquareModChip::new());
+        let mul_width = BaseAir::<BabyBear>::width(&Uint256MulChip::new());
+        assert!(square_width < mul_width);
+    }
+
     #[test]
     fn test_uint256_modulus() {
         assert_eq!(biguint_from_limbs(U256Field::MODULUS), U256Field::modulus());
@@ -0,0 +1,573 @@
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+};
+
+use crate::{
+    air::MemoryAirBuilder,
+    operations::{field::range::FieldLtCols, IsZeroOperation},
+    utils::{
+        limbs_from_access, limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows,
+        words_to_bytes_le,
+    },
+};
+
+use generic_array::GenericArray;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::U256Field,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Uint256ModExpCols.
+const NUM_COLS: usize = size_of::<Uint256ModExpCols<u8>>();
+
+/// The number of rows used by a single exponentiation, one for each bit of the exponent.
+pub const NUM_MOD_EXP_ROWS: usize = 256;
+
+/// A chip that computes `base^exponent % modulus` for 256-bit integers.
+///
+/// `base_ptr` points to the 8 words of the base, which are overwritten with the result, and
+/// `exponent_ptr` points to the 8 words of the exponent followed by the 8 words of the modulus. As
+/// with `UINT256_MUL`, a zero modulus is interpreted as 2^256, and the base does not need to be
+/// reduced.
+///
+/// Each event spans 256 rows which run the right-to-left square-and-multiply algorithm, one row per
+/// exponent bit starting from the least significant one. Row i holds the accumulator
+/// `base^(exponent % 2^i)` and the square `base^(2^i)`, both modulo the modulus, and computes the
+/// next accumulator by multiplying with either the square or one depending on bit i. The memory
+/// accesses and the syscall are evaluated on the first row, and the accumulator of the last row is
+/// constrained to be the canonical result written over the base.
+#[derive(Default)]
+pub struct Uint256ModExpChip;
+
+impl Uint256ModExpChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+const NUM_LIMBS: usize = <U256Field as NumLimbs>::Limbs::USIZE;
+
+/// A set of columns for one exponent bit of the Uint256ModExp operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Uint256ModExpCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the base.
+    pub base_ptr: T,
+
+    /// The pointer to the exponent, which is followed by the modulus.
+    pub exponent_ptr: T,
+
+    // Memory columns. Their values are repeated on every row of an event, but the accesses are
+    // only evaluated on the first row.
+    // base_memory is written to with the result, which is why it is of type MemoryWriteCols.
+    pub base_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+    pub exponent_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub modulus_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+
+    /// A one-hot encoding of the index of the current exponent bit within its byte.
+    pub bit_flags: [T; 8],
+
+    /// A one-hot encoding of the index of the exponent byte containing the current bit.
+    pub byte_flags: [T; NUM_LIMBS],
+
+    /// The bits of the exponent byte containing the current bit.
+    pub exponent_byte_bits: [T; 8],
+
+    /// The current exponent bit.
+    pub bit: T,
+
+    /// Whether this is the row of the least significant exponent bit.
+    pub is_first_bit: T,
+
+    /// Whether this is the row of the most significant exponent bit.
+    pub is_last_bit: T,
+
+    /// Columns for checking if the modulus is zero. If it's zero, then use 2^256 as the effective
+    /// modulus.
+    pub modulus_is_zero: IsZeroOperation<T>,
+
+    /// Column that is equal to is_last_bit * (1 - modulus_is_zero.result).
+    pub check_output_range: T,
+
+    /// The value `base % modulus`, which is the square of the first row.
+    pub reduced_base: FieldOpCols<T, U256Field>,
+
+    /// The accumulated power of the base up to the current bit.
+    pub accumulator: Limbs<T, <U256Field as NumLimbs>::Limbs>,
+
+    /// The base raised to 2^i, where i is the index of the current bit.
+    pub square: Limbs<T, <U256Field as NumLimbs>::Limbs>,
+
+    /// The square if the current bit is set, and one otherwise.
+    pub multiplier: Limbs<T, <U256Field as NumLimbs>::Limbs>,
+
+    /// The value `(accumulator * multiplier) % modulus`, which is the next accumulator.
+    pub product: FieldOpCols<T, U256Field>,
+
+    /// The value `(square * square) % modulus`, which is the next square.
+    pub next_square: FieldOpCols<T, U256Field>,
+
+    pub output_range_check: FieldLtCols<T, U256Field>,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Uint256ModExpChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Uint256ModExp".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (rows, new_byte_lookup_events) =
+            par_generate_rows(&input.uint256_mod_exp_events, |event, new_byte_lookup_events| {
+                // Decode uint256 values.
+                let base = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.x));
+                let exponent_bytes = words_to_bytes_le::<32>(&event.y);
+                let exponent = BigUint::from_bytes_le(&exponent_bytes);
+                let modulus_bytes = words_to_bytes_le::<32>(&event.modulus);
+                let modulus = BigUint::from_bytes_le(&modulus_bytes);
+                let effective_modulus =
+                    if modulus.is_zero() { BigUint::one() << 256 } else { modulus.clone() };
+                let modulus_byte_sum = modulus_bytes.iter().map(|b| *b as u32).sum::<u32>();
+
+                // Populate the memory columns once, since the accesses are only made on the first
+                // row.
+                let mut base_memory =
+                    GenericArray::<MemoryWriteCols<F>, WordsFieldElement>::default();
+                let mut exponent_memory =
+                    GenericArray::<MemoryReadCols<F>, WordsFieldElement>::default();
+                let mut modulus_memory =
+                    GenericArray::<MemoryReadCols<F>, WordsFieldElement>::default();
+                for i in 0..WORDS_FIELD_ELEMENT {
+                    base_memory[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    exponent_memory[i].populate(
+                        event.channel,
+                        event.y_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    modulus_memory[i].populate(
+                        event.channel,
+                        event.modulus_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+
+                let mut rows = Vec::with_capacity(NUM_MOD_EXP_ROWS);
+                let mut accumulator = BigUint::one();
+                let mut square = base.clone();
+                for i in 0..NUM_MOD_EXP_ROWS {
+                    let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                    let cols: &mut Uint256ModExpCols<F> = row.as_mut_slice().borrow_mut();
+
+                    // Assign basic values to the columns.
+                    cols.is_real = F::one();
+                    cols.shard = F::from_canonical_u32(event.shard);
+                    cols.channel = F::from_canonical_u8(event.channel);
+                    cols.clk = F::from_canonical_u32(event.clk);
+                    cols.base_ptr = F::from_canonical_u32(event.x_ptr);
+                    cols.exponent_ptr = F::from_canonical_u32(event.y_ptr);
+                    cols.base_memory = base_memory.clone();
+                    cols.exponent_memory = exponent_memory.clone();
+                    cols.modulus_memory = modulus_memory.clone();
+
+                    // Select the current exponent bit.
+                    cols.bit_flags[i % 8] = F::one();
+                    cols.byte_flags[i / 8] = F::one();
+                    for (k, bit) in cols.exponent_byte_bits.iter_mut().enumerate() {
+                        *bit = F::from_canonical_u8((exponent_bytes[i / 8] >> k) & 1);
+                    }
+                    let bit = exponent.bit(i as u64);
+                    cols.bit = F::from_bool(bit);
+                    cols.is_first_bit = F::from_bool(i == 0);
+                    cols.is_last_bit = F::from_bool(i == NUM_MOD_EXP_ROWS - 1);
+
+                    IsZeroOperation::populate(&mut cols.modulus_is_zero, modulus_byte_sum);
+
+                    // The base is reduced on every row so that the rows of an event are uniform,
+                    // and the first row squares the reduced base.
+                    let reduced_base = cols.reduced_base.populate_with_modulus(
+                        new_byte_lookup_events,
+                        event.shard,
+                        event.channel,
+                        &base,
+                        &BigUint::one(),
+                        &effective_modulus,
+                        FieldOperation::Mul,
+                    );
+                    if i == 0 {
+                        square = reduced_base;
+                    }
+
+                    let multiplier = if bit { square.clone() } else { BigUint::one() };
+                    cols.accumulator = U256Field::to_limbs_field::<F, _>(&accumulator);
+                    cols.square = U256Field::to_limbs_field::<F, _>(&square);
+                    cols.multiplier = U256Field::to_limbs_field::<F, _>(&multiplier);
+
+                    let product = cols.product.populate_with_modulus(
+                        new_byte_lookup_events,
+                        event.shard,
+                        event.channel,
+                        &accumulator,
+                        &multiplier,
+                        &effective_modulus,
+                        FieldOperation::Mul,
+                    );
+                    let next_square = cols.next_square.populate_with_modulus(
+                        new_byte_lookup_events,
+                        event.shard,
+                        event.channel,
+                        &square,
+                        &square,
+                        &effective_modulus,
+                        FieldOperation::Mul,
+                    );
+
+                    // The last product is the result, which must be canonical.
+                    if i == NUM_MOD_EXP_ROWS - 1 && !modulus.is_zero() {
+                        cols.check_output_range = F::one();
+                        cols.output_range_check.populate(
+                            new_byte_lookup_events,
+                            event.shard,
+                            event.channel,
+                            &product,
+                            &effective_modulus,
+                        );
+                    }
+
+                    accumulator = product;
+                    square = next_square;
+                    rows.push(row);
+                }
+                rows
+            });
+
+        let mut rows = rows.into_iter().flatten().collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+            let cols: &mut Uint256ModExpCols<F> = row.as_mut_slice().borrow_mut();
+
+            let zero = BigUint::zero();
+            cols.reduced_base.populate(&mut vec![], 0, 0, &zero, &zero, FieldOperation::Mul);
+            cols.product.populate(&mut vec![], 0, 0, &zero, &zero, FieldOperation::Mul);
+            cols.next_square.populate(&mut vec![], 0, 0, &zero, &zero, FieldOperation::Mul);
+
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Uint256ModExpCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.uint256_mod_exp_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F> BaseAir<F> for Uint256ModExpChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Uint256ModExpChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Uint256ModExpCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Uint256ModExpCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let base_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.base_memory);
+        let result_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&local.base_memory);
+        let exponent_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&local.exponent_memory);
+        let modulus_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&local.modulus_memory);
+
+        // The flags are boolean, and exactly one of each kind is set on a real row.
+        let mut bit_flag_sum = AB::Expr::zero();
+        for &flag in local.bit_flags.iter() {
+            builder.assert_bool(flag);
+            bit_flag_sum += flag.into();
+        }
+        builder.assert_eq(bit_flag_sum, local.is_real);
+        let mut byte_flag_sum = AB::Expr::zero();
+        for &flag in local.byte_flags.iter() {
+            builder.assert_bool(flag);
+            byte_flag_sum += flag.into();
+        }
+        builder.assert_eq(byte_flag_sum, local.is_real);
+
+        builder.assert_eq(local.is_first_bit, local.bit_flags[0] * local.byte_flags[0]);
+        builder.assert_eq(local.is_last_bit, local.bit_flags[7] * local.byte_flags[NUM_LIMBS - 1]);
+
+        // An event starts at its least significant bit, runs through all the bits, and the table
+        // does not end in the middle of an event.
+        builder.when_first_row().assert_eq(local.is_first_bit, local.is_real);
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+        builder
+            .when_transition()
+            .when(local.is_last_bit)
+            .assert_eq(next.is_first_bit, next.is_real);
+        builder.when_last_row().assert_eq(local.is_real, local.is_last_bit);
+
+        // Within an event, the bit flags rotate on every row and the byte flags move on after the
+        // last bit of a byte.
+        let is_running = local.is_real - local.is_last_bit;
+        let mut running_builder = builder.when_transition();
+        let mut running_builder = running_builder.when(is_running);
+        running_builder.assert_one(next.is_real);
+        for i in 0..8 {
+            running_builder.assert_eq(next.bit_flags[(i + 1) % 8], local.bit_flags[i]);
+        }
+        let end_of_byte = local.bit_flags[7];
+        running_builder
+            .assert_eq(next.byte_flags[0], (AB::Expr::one() - end_of_byte) * local.byte_flags[0]);
+        for i in 1..NUM_LIMBS {
+            running_builder.assert_eq(
+                next.byte_flags[i],
+                end_of_byte * local.byte_flags[i - 1]
+                    + (AB::Expr::one() - end_of_byte) * local.byte_flags[i],
+            );
+        }
+
+        // The inputs stay the same throughout the rows of an event.
+        running_builder.assert_eq(local.shard, next.shard);
+        running_builder.assert_eq(local.channel, next.channel);
+        running_builder.assert_eq(local.clk, next.clk);
+        running_builder.assert_eq(local.base_ptr, next.base_ptr);
+        running_builder.assert_eq(local.exponent_ptr, next.exponent_ptr);
+        let next_base_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&next.base_memory);
+        let next_result_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&next.base_memory);
+        let next_exponent_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&next.exponent_memory);
+        let next_modulus_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&next.modulus_memory);
+        running_builder.assert_all_eq(base_limbs, next_base_limbs);
+        running_builder.assert_all_eq(result_limbs, next_result_limbs);
+        running_builder.assert_all_eq(exponent_limbs, next_exponent_limbs);
+        running_builder.assert_all_eq(modulus_limbs, next_modulus_limbs);
+
+        // The accumulator and the square of the next row are the outputs of this row.
+        running_builder.assert_all_eq(next.accumulator, local.product.result);
+        running_builder.assert_all_eq(next.square, local.next_square.result);
+
+        // Decompose the exponent byte containing the current bit, and select the bit.
+        let mut exponent_byte = AB::Expr::zero();
+        for (&flag, &byte) in local.byte_flags.iter().zip(exponent_limbs.0.iter()) {
+            exponent_byte += flag * byte;
+        }
+        let mut exponent_byte_from_bits = AB::Expr::zero();
+        let mut bit = AB::Expr::zero();
+        for (i, (&flag, &exponent_bit)) in
+            local.bit_flags.iter().zip(local.exponent_byte_bits.iter()).enumerate()
+        {
+            builder.assert_bool(exponent_bit);
+            exponent_byte_from_bits += exponent_bit * AB::F::from_canonical_u32(1 << i);
+            bit += flag * exponent_bit;
+        }
+        builder.assert_eq(exponent_byte, exponent_byte_from_bits);
+        builder.assert_eq(local.bit, bit);
+
+        // If the modulus is zero, we use 2^256 as the modulus, as in `UINT256_MUL`.
+        let modulus_byte_sum =
+            modulus_limbs.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            modulus_byte_sum,
+            local.modulus_is_zero,
+            local.is_real.into(),
+        );
+        let modulus_is_zero = local.modulus_is_zero.result;
+        let mut coeff_2_256 = Vec::new();
+        coeff_2_256.resize(NUM_LIMBS, AB::Expr::zero());
+        coeff_2_256.push(AB::Expr::one());
+        let modulus_polynomial: Polynomial<AB::Expr> = modulus_limbs.into();
+        let p_modulus: Polynomial<AB::Expr> = modulus_polynomial
+            * (AB::Expr::one() - modulus_is_zero.into())
+            + Polynomial::from_coefficients(&coeff_2_256) * modulus_is_zero.into();
+
+        // Reduce the base, and start from an accumulator of one and the reduced base as the square.
+        let one = Polynomial::from_coefficients(&[AB::Expr::one()]);
+        local.reduced_base.eval_with_modulus(
+            builder,
+            &base_limbs,
+            &one,
+            &p_modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        builder.when(local.is_first_bit).assert_one(local.accumulator[0]);
+        for i in 1..NUM_LIMBS {
+            builder.when(local.is_first_bit).assert_zero(local.accumulator[i]);
+        }
+        builder.when(local.is_first_bit).assert_all_eq(local.square, local.reduced_base.result);
+
+        // The multiplier is the square if the bit is set, and one otherwise.
+        builder.assert_bool(local.bit);
+        builder.when(local.is_real).assert_eq(
+            local.multiplier[0],
+            local.bit * local.square[0] + AB::Expr::one() - local.bit,
+        );
+        for i in 1..NUM_LIMBS {
+            builder.when(local.is_real).assert_eq(local.multiplier[i], local.bit * local.square[i]);
+        }
+
+        // Evaluate the multiplication and the squaring of this bit.
+        local.product.eval_with_modulus(
+            builder,
+            &local.accumulator,
+            &local.multiplier,
+            &p_modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.next_square.eval_with_modulus(
+            builder,
+            &local.square,
+            &local.square,
+            &p_modulus,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // The last product is the result written over the base, and it must be less than the
+        // modulus if the modulus is not zero.
+        builder.when(local.is_last_bit).assert_all_eq(local.product.result, result_limbs);
+        builder.assert_eq(
+            local.check_output_range,
+            local.is_last_bit * (AB::Expr::one() - modulus_is_zero.into()),
+        );
+        local.output_range_check.eval(
+            builder,
+            &local.product.result,
+            &modulus_limbs,
+            local.shard,
+            local.channel,
+            local.check_output_range,
+        );
+
+        // Read the base and write the result on the first row.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.base_ptr,
+            &local.base_memory,
+            local.is_first_bit,
+        );
+
+        // Read the exponent.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.exponent_ptr,
+            &local.exponent_memory,
+            local.is_first_bit,
+        );
+
+        // Read the modulus.
+        let modulus_ptr =
+            local.exponent_ptr + AB::F::from_canonical_u32((WORDS_FIELD_ELEMENT * 4) as u32);
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            modulus_ptr,
+            &local.modulus_memory,
+            local.is_first_bit,
+        );
+
+        // Receive the arguments on the first row.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::UINT256_MOD_EXP.syscall_id()),
+            local.base_ptr,
+            local.exponent_ptr,
+            local.is_first_bit,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
@@ -0,0 +1,382 @@
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+};
+
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    operations::field::range::FieldLtCols,
+    utils::{
+        limbs_from_access, limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows,
+        words_to_bytes_le,
+    },
+};
+
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::U256Field,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Uint256MontMulCols.
+const NUM_COLS: usize = size_of::<Uint256MontMulCols<u8>>();
+
+/// A chip that computes the Montgomery product `x * y * R^-1 % modulus` for 256-bit integers, with
+/// `R = 2^256`.
+///
+/// `x_ptr` points to the 8 words of x, which are overwritten with the result, and `y_ptr` points to
+/// the 8 words of y followed by the 8 words of the modulus, as in `UINT256_MUL`. The modulus must
+/// be odd so that R is invertible.
+///
+/// Instead of tracking the REDC reduction, the result z is witnessed and constrained by
+/// `z * (R - modulus) == x * y` modulo the modulus with `z < modulus`, since `R - modulus` is
+/// congruent to R. The executor only accepts a reduced x, which keeps the quotient of `x * y` by
+/// the modulus within the 256 bits of the carry.
+#[derive(Default)]
+pub struct Uint256MontMulChip;
+
+impl Uint256MontMulChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// A set of columns for the Uint256MontMul operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Uint256MontMulCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the first input.
+    pub x_ptr: T,
+
+    /// The pointer to the second input, which contains the y value and the modulus.
+    pub y_ptr: T,
+
+    // Memory columns.
+    // x_memory is written to with the result, which is why it is of type MemoryWriteCols.
+    pub x_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+    pub y_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub modulus_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+
+    /// Half of the lowest limb of the modulus rounded down, which witnesses that the modulus is
+    /// odd.
+    pub modulus_low_limb_half: T,
+
+    /// The value `R - modulus`, computed as `0 - modulus` modulo 2^256.
+    pub negated_modulus: FieldOpCols<T, U256Field>,
+
+    /// The value `(x * y) % modulus`.
+    pub product: FieldOpCols<T, U256Field>,
+
+    /// The value `(z * (R - modulus)) % modulus`, where z is the result.
+    pub shifted_result: FieldOpCols<T, U256Field>,
+
+    pub output_range_check: FieldLtCols<T, U256Field>,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Uint256MontMulChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Uint256MontMul".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(&input.uint256_mont_mul_events, |event, new_byte_lookup_events| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Uint256MontMulCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Decode uint256 points
+                let x = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.x));
+                let y = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.y));
+                let modulus = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.modulus));
+                let result = BigUint::from_bytes_le(&words_to_bytes_le::<32>(
+                    &event.x_memory_records.iter().map(|record| record.value).collect::<Vec<_>>(),
+                ));
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+                // Populate memory columns.
+                for i in 0..WORDS_FIELD_ELEMENT {
+                    cols.x_memory[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.y_memory[i].populate(
+                        event.channel,
+                        event.y_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.modulus_memory[i].populate(
+                        event.channel,
+                        event.modulus_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+
+                // The result is not the output of a field operation, so its limbs are range checked
+                // separately.
+                new_byte_lookup_events.add_u8_range_checks_field(
+                    event.shard,
+                    event.channel,
+                    &U256Field::to_limbs_field::<F, _>(&result).0,
+                );
+
+                let modulus_low_limb_half = (event.modulus[0] & 0xff) >> 1;
+                cols.modulus_low_limb_half = F::from_canonical_u32(modulus_low_limb_half);
+                new_byte_lookup_events.add_u8_range_checks_field(
+                    event.shard,
+                    event.channel,
+                    &[cols.modulus_low_limb_half],
+                );
+
+                // Populate the field operations.
+                let negated_modulus = cols.negated_modulus.populate(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    &BigUint::zero(),
+                    &modulus,
+                    FieldOperation::Sub,
+                );
+                let product = cols.product.populate_with_modulus(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    &x,
+                    &y,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+                let shifted_result = cols.shifted_result.populate_with_modulus(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    &result,
+                    &negated_modulus,
+                    &modulus,
+                    FieldOperation::Mul,
+                );
+                debug_assert_eq!(product, shifted_result);
+
+                cols.output_range_check.populate(
+                    new_byte_lookup_events,
+                    event.shard,
+                    event.channel,
+                    &result,
+                    &modulus,
+                );
+
+                row
+            });
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+            let cols: &mut Uint256MontMulCols<F> = row.as_mut_slice().borrow_mut();
+
+            let zero = BigUint::zero();
+            cols.negated_modulus.populate(&mut vec![], 0, 0, &zero, &zero, FieldOperation::Sub);
+            cols.product.populate(&mut vec![], 0, 0, &zero, &zero, FieldOperation::Mul);
+            cols.shifted_result.populate(&mut vec![], 0, 0, &zero, &zero, FieldOperation::Mul);
+
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Uint256MontMulCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.uint256_mont_mul_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F> BaseAir<F> for Uint256MontMulChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Uint256MontMulChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Uint256MontMulCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Uint256MontMulCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The value of x is stored in the "prev_value" of the x_memory, since we write to it later.
+        let x_limbs = limbs_from_prev_access(&local.x_memory);
+        let y_limbs = limbs_from_access(&local.y_memory);
+        let modulus_limbs = limbs_from_access(&local.modulus_memory);
+        let result_limbs: Limbs<AB::Var, <U256Field as NumLimbs>::Limbs> =
+            limbs_from_access(&local.x_memory);
+
+        // Range check the result, which is an input to the field operations.
+        builder.slice_range_check_u8(&result_limbs.0, local.shard, local.channel, local.is_real);
+
+        // Assert that the modulus is odd. The half is a byte, so the sum cannot wrap around.
+        builder.when(local.is_real).assert_eq(
+            modulus_limbs[0],
+            local.modulus_low_limb_half * AB::F::from_canonical_u32(2) + AB::Expr::one(),
+        );
+        builder.slice_range_check_u8(
+            &[local.modulus_low_limb_half],
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Evaluate R - modulus as a subtraction from zero modulo 2^256.
+        let zero: Polynomial<AB::Expr> = Polynomial::from_coefficients(&[AB::Expr::zero()]);
+        local.negated_modulus.eval(
+            builder,
+            &zero,
+            &modulus_limbs,
+            FieldOperation::Sub,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Evaluate both sides of the congruence modulo the modulus, and assert that they agree.
+        local.product.eval_with_modulus(
+            builder,
+            &x_limbs,
+            &y_limbs,
+            &modulus_limbs,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.shifted_result.eval_with_modulus(
+            builder,
+            &result_limbs,
+            &local.negated_modulus.result,
+            &modulus_limbs,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        builder
+            .when(local.is_real)
+            .assert_all_eq(local.product.result, local.shifted_result.result);
+
+        // Verify that the result is reduced, which makes it unique.
+        local.output_range_check.eval(
+            builder,
+            &result_limbs,
+            &modulus_limbs,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Read and write x.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+
+        // Evaluate the y_ptr memory access. We concatenate y and modulus into a single array since
+        // we read it contiguously from the y_ptr memory location.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.y_ptr,
+            &[local.y_memory.clone(), local.modulus_memory.clone()].concat(),
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::UINT256_MONT_MUL.syscall_id()),
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::{pad_rows, par_generate_rows},
+};
+
+use generic_array::GenericArray;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord},
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_curves::{params::NumWords, uint256::U256Field};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::WORD_SIZE;
+use sp1_stark::air::{MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Uint256RangeCheckCols.
+const NUM_COLS: usize = size_of::<Uint256RangeCheckCols<u8>>();
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// The number of bytes of a 256-bit integer.
+const NUM_BYTES: usize = WORDS_FIELD_ELEMENT * WORD_SIZE;
+
+/// A chip that checks whether a 256-bit integer is less than a prime.
+///
+/// `value_ptr` points to the 8 words of the value, followed by a word to which the result is
+/// written: zero if the value is less than the prime, and one otherwise. `prime_ptr` points to the
+/// 8 words of the prime. A zero prime is not special, so every value is out of range for it.
+///
+/// The comparison is a borrow chain over the bytes, from the least significant one, without a
+/// subtraction: the value is less than the prime on the bytes up to `i` if its byte `i` is less
+/// than the prime's, or if they are equal and it is less on the bytes below `i`. Each byte is
+/// compared with an `LTU` lookup in both directions, so equality is when neither is less.
+#[derive(Default)]
+pub struct Uint256RangeCheckChip;
+
+impl Uint256RangeCheckChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Uint256RangeCheck operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Uint256RangeCheckCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the value.
+    pub value_ptr: T,
+
+    /// The pointer to the prime.
+    pub prime_ptr: T,
+
+    // Memory columns.
+    pub value_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub prime_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub result_memory: MemoryWriteCols<T>,
+
+    /// Whether each byte of the value is less than the byte of the prime.
+    pub byte_is_less: [T; NUM_BYTES],
+
+    /// Whether each byte of the value is greater than the byte of the prime.
+    pub byte_is_greater: [T; NUM_BYTES],
+
+    /// Whether the value is less than the prime on the bytes up to each byte, which is the borrow
+    /// out of that byte in `value - prime`.
+    pub borrow: [T; NUM_BYTES],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Uint256RangeCheckChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Uint256RangeCheck".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let (mut rows, new_byte_lookup_events) = par_generate_rows(
+            &input.uint256_range_check_events,
+            |event, new_byte_lookup_events| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Uint256RangeCheckCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.value_ptr = F::from_canonical_u32(event.value_ptr);
+                cols.prime_ptr = F::from_canonical_u32(event.prime_ptr);
+
+                // Populate memory columns.
+                for i in 0..WORDS_FIELD_ELEMENT {
+                    cols.value_memory[i].populate(
+                        event.channel,
+                        event.value_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                    cols.prime_memory[i].populate(
+                        event.channel,
+                        event.prime_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+                cols.result_memory.populate(
+                    event.channel,
+                    event.result_memory_record,
+                    new_byte_lookup_events,
+                );
+
+                // Compare the bytes and propagate the borrow.
+                let value_bytes = event.value.iter().flat_map(|word| word.to_le_bytes());
+                let prime_bytes = event.prime.iter().flat_map(|word| word.to_le_bytes());
+                let mut borrow = false;
+                for (i, (v, p)) in value_bytes.zip(prime_bytes).enumerate() {
+                    cols.byte_is_less[i] = F::from_bool(v < p);
+                    cols.byte_is_greater[i] = F::from_bool(v > p);
+                    for (b, c) in [(v, p), (p, v)] {
+                        new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                            event.shard,
+                            event.channel,
+                            ByteOpcode::LTU,
+                            (b < c) as u16,
+                            0,
+                            b,
+                            c,
+                        ));
+                    }
+                    borrow = v < p || (v == p && borrow);
+                    cols.borrow[i] = F::from_bool(borrow);
+                }
+
+                row
+            },
+        );
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Uint256RangeCheckCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.uint256_range_check_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for Uint256RangeCheckChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Uint256RangeCheckChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Uint256RangeCheckCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Uint256RangeCheckCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Compare each byte of the value with the byte of the prime, in both directions. The
+        // lookups also check that the flags are correct booleans on real rows.
+        let value_bytes = local.value_memory.iter().flat_map(|mem| mem.value().0);
+        let prime_bytes = local.prime_memory.iter().flat_map(|mem| mem.value().0);
+        for (i, (v, p)) in value_bytes.zip(prime_bytes).enumerate() {
+            builder.send_byte(
+                ByteOpcode::LTU.as_field::<AB::F>(),
+                local.byte_is_less[i],
+                v,
+                p,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            builder.send_byte(
+                ByteOpcode::LTU.as_field::<AB::F>(),
+                local.byte_is_greater[i],
+                p,
+                v,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Propagate the borrow from the least significant byte. The bytes are equal when neither
+        // is less than the other, and then the borrow passes through.
+        builder.assert_eq(local.borrow[0], local.byte_is_less[0]);
+        for i in 1..NUM_BYTES {
+            let is_equal = AB::Expr::one() - local.byte_is_less[i] - local.byte_is_greater[i];
+            builder
+                .assert_eq(local.borrow[i], local.byte_is_less[i] + is_equal * local.borrow[i - 1]);
+        }
+
+        // The result is zero if the value is less than the prime, and one otherwise.
+        let result = local.result_memory.value();
+        builder
+            .when(local.is_real)
+            .assert_eq(result[0], AB::Expr::one() - local.borrow[NUM_BYTES - 1]);
+        for byte in &result.0[1..] {
+            builder.when(local.is_real).assert_zero(*byte);
+        }
+
+        // Read the value and the prime.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.value_ptr,
+            &local.value_memory,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.prime_ptr,
+            &local.prime_memory,
+            local.is_real,
+        );
+
+        // Write the result after the value.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.value_ptr.into() + AB::Expr::from_canonical_usize(NUM_BYTES),
+            &local.result_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::UINT256_RANGE_CHECK.syscall_id()),
+            local.value_ptr,
+            local.prime_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
@@ -1,7 +1,15 @@
 mod weierstrass_add;
 mod weierstrass_decompress;
 mod weierstrass_double;
+mod weierstrass_ecdsa_verify;
+mod weierstrass_linear_combination;
+mod weierstrass_msm;
+mod weierstrass_mul;
 
 pub use weierstrass_add::*;
 pub use weierstrass_decompress::*;
 pub use weierstrass_double::*;
+pub use weierstrass_ecdsa_verify::*;
+pub use weierstrass_linear_combination::*;
+pub use weierstrass_msm::*;
+pub use weierstrass_mul::*;
@@ -21,13 +21,13 @@ use sp1_curves::{
     AffinePoint, CurveType, EllipticCurve,
 };
 use sp1_derive::AlignedBorrow;
-use sp1_stark::air::{MachineAir, SP1AirBuilder};
+use sp1_stark::air::{MachineAir, PaddingStrategy, SP1AirBuilder};
 use typenum::Unsigned;
 
 use crate::{
     memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
     operations::field::field_op::FieldOpCols,
-    utils::{limbs_from_prev_access, pad_rows},
+    utils::{limbs_from_prev_access, pad_rows_with_strategy, par_generate_rows},
 };
 
 pub const fn num_weierstrass_add_cols<P: FieldParameters + NumWords>() -> usize {
@@ -184,6 +184,9 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256k1 => "Secp256k1AddAssign".to_string(),
             CurveType::Bn254 => "Bn254AddAssign".to_string(),
             CurveType::Bls12381 => "Bls12381AddAssign".to_string(),
+            CurveType::P256 => "P256AddAssign".to_string(),
+            CurveType::P384 => "P384AddAssign".to_string(),
+            CurveType::Grumpkin => "GrumpkinAddAssign".to_string(),
             _ => panic!("Unsupported curve"),
         }
     }
@@ -197,67 +200,66 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256k1 => &input.secp256k1_add_events,
             CurveType::Bn254 => &input.bn254_add_events,
             CurveType::Bls12381 => &input.bls12381_add_events,
+            CurveType::P256 => &input.p256_add_events,
+            CurveType::P384 => &input.p384_add_events,
+            CurveType::Grumpkin => &input.grumpkin_add_events,
             _ => panic!("Unsupported curve"),
         };
 
-        let mut rows = Vec::new();
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(events, |event, new_byte_lookup_events| {
+                let mut row = vec![F::zero(); num_weierstrass_add_cols::<E::BaseField>()];
+                let cols: &mut WeierstrassAddAssignCols<F, E::BaseField> =
+                    row.as_mut_slice().borrow_mut();
 
-        let mut new_byte_lookup_events = Vec::new();
+                // Decode affine points.
+                let p = &event.p;
+                let q = &event.q;
+                let p = AffinePoint::<E>::from_words_le(p);
+                let (p_x, p_y) = (p.x, p.y);
+                let q = AffinePoint::<E>::from_words_le(q);
+                let (q_x, q_y) = (q.x, q.y);
 
-        for i in 0..events.len() {
-            let event = &events[i];
-            let mut row = vec![F::zero(); num_weierstrass_add_cols::<E::BaseField>()];
-            let cols: &mut WeierstrassAddAssignCols<F, E::BaseField> =
-                row.as_mut_slice().borrow_mut();
+                // Populate basic columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+                cols.q_ptr = F::from_canonical_u32(event.q_ptr);
 
-            // Decode affine points.
-            let p = &event.p;
-            let q = &event.q;
-            let p = AffinePoint::<E>::from_words_le(p);
-            let (p_x, p_y) = (p.x, p.y);
-            let q = AffinePoint::<E>::from_words_le(q);
-            let (q_x, q_y) = (q.x, q.y);
-
-            // Populate basic columns.
-            cols.is_real = F::one();
-            cols.shard = F::from_canonical_u32(event.shard);
-            cols.channel = F::from_canonical_u8(event.channel);
-            cols.clk = F::from_canonical_u32(event.clk);
-            cols.p_ptr = F::from_canonical_u32(event.p_ptr);
-            cols.q_ptr = F::from_canonical_u32(event.q_ptr);
-
-            Self::populate_field_ops(
-                &mut new_byte_lookup_events,
-                event.shard,
-                event.channel,
-                cols,
-                p_x,
-                p_y,
-                q_x,
-                q_y,
-            );
-
-            // Populate the memory access columns.
-            for i in 0..cols.q_access.len() {
-                cols.q_access[i].populate(
-                    event.channel,
-                    event.q_memory_records[i],
-                    &mut new_byte_lookup_events,
-                );
-            }
-            for i in 0..cols.p_access.len() {
-                cols.p_access[i].populate(
+                Self::populate_field_ops(
+                    new_byte_lookup_events,
+                    event.shard,
                     event.channel,
-                    event.p_memory_records[i],
-                    &mut new_byte_lookup_events,
+                    cols,
+                    p_x,
+                    p_y,
+                    q_x,
+                    q_y,
                 );
-            }
 
-            rows.push(row);
-        }
+                // Populate the memory access columns.
+                for i in 0..cols.q_access.len() {
+                    cols.q_access[i].populate(
+                        event.channel,
+                        event.q_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+                for i in 0..cols.p_access.len() {
+                    cols.p_access[i].populate(
+                        event.channel,
+                        event.p_memory_records[i],
+                        new_byte_lookup_events,
+                    );
+                }
+
+                row
+            });
         output.add_byte_lookup_events(new_byte_lookup_events);
 
-        pad_rows(&mut rows, || {
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
             let mut row = vec![F::zero(); num_weierstrass_add_cols::<E::BaseField>()];
             let cols: &mut WeierstrassAddAssignCols<F, E::BaseField> =
                 row.as_mut_slice().borrow_mut();
@@ -298,9 +300,16 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256k1 => !shard.secp256k1_add_events.is_empty(),
             CurveType::Bn254 => !shard.bn254_add_events.is_empty(),
             CurveType::Bls12381 => !shard.bls12381_add_events.is_empty(),
+            CurveType::P256 => !shard.p256_add_events.is_empty(),
+            CurveType::P384 => !shard.p384_add_events.is_empty(),
+            CurveType::Grumpkin => !shard.grumpkin_add_events.is_empty(),
             _ => panic!("Unsupported curve"),
         }
     }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
 }
 
 impl<F, E: EllipticCurve> BaseAir<F> for WeierstrassAddAssignChip<E> {
@@ -475,6 +484,11 @@ where
             CurveType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_ADD.syscall_id())
             }
+            CurveType::P256 => AB::F::from_canonical_u32(SyscallCode::P256_ADD.syscall_id()),
+            CurveType::P384 => AB::F::from_canonical_u32(SyscallCode::P384_ADD.syscall_id()),
+            CurveType::Grumpkin => {
+                AB::F::from_canonical_u32(SyscallCode::GRUMPKIN_ADD.syscall_id())
+            }
             _ => panic!("Unsupported curve"),
         };
 
@@ -488,23 +502,664 @@ where
             local.q_ptr,
             local.is_real,
         );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
     }
 }
 
 #[cfg(test)]
 mod tests {
 
-    use sp1_core_executor::Program;
-    use sp1_stark::CpuProver;
+    use elliptic_curve::sec1::ToEncodedPoint;
+    use k256::ecdsa::{RecoveryId, Signature as K256Signature, SigningKey, VerifyingKey};
+    use num::{BigUint, Zero};
+    use sha2::{Digest, Sha256};
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Instruction, Opcode, Program};
+    use sp1_curves::{
+        params::FieldParameters,
+        weierstrass::{
+            grumpkin::GrumpkinParameters,
+            p256::P256Parameters,
+            p384::P384Parameters,
+            secp256k1::{Secp256k1BaseField, Secp256k1Parameters, Secp256k1ScalarField},
+            SwCurve,
+        },
+        AffinePoint,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
 
     use crate::utils::{
         run_test, setup_logger,
         tests::{
-            BLS12381_ADD_ELF, BLS12381_DOUBLE_ELF, BLS12381_MUL_ELF, BN254_ADD_ELF, BN254_MUL_ELF,
-            SECP256K1_ADD_ELF, SECP256K1_MUL_ELF,
+            call_syscall, slice_at, store_words, syscall_program, words_at, BLS12381_ADD_ELF,
+            BLS12381_DOUBLE_ELF, BLS12381_MUL_ELF, BN254_ADD_ELF, BN254_MUL_ELF, SECP256K1_ADD_ELF,
+            SECP256K1_MUL_ELF,
         },
     };
 
+    const P_PTR: u32 = 100;
+    const Q_PTR: u32 = 300;
+
+    /// Builds a program that stores `p` and `q` and adds `q` into `p` with the given syscall.
+    fn add_program(syscall: SyscallCode, p: &[u32], q: &[u32]) -> Program {
+        let words = [slice_at(P_PTR, p), slice_at(Q_PTR, q)].concat();
+        syscall_program(syscall, words, P_PTR, Q_PTR)
+    }
+
+    /// Builds a program that computes `G + 2G` on secp256k1.
+    fn secp256k1_add_program() -> Program {
+        let g = SwCurve::<Secp256k1Parameters>::generator();
+        let g2 = g.sw_double();
+        add_program(SyscallCode::SECP256K1_ADD, &g.to_words_le(), &g2.to_words_le())
+    }
+
+    #[test]
+    fn test_secp256k1_add_execute() {
+        setup_logger();
+        let mut runtime = Executor::new(secp256k1_add_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let words = (0..16).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+        let result = AffinePoint::<SwCurve<Secp256k1Parameters>>::from_words_le(&words);
+        let expected_x = BigUint::parse_bytes(
+            b"F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            16,
+        )
+        .unwrap();
+        let expected_y = BigUint::parse_bytes(
+            b"388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672",
+            16,
+        )
+        .unwrap();
+        assert_eq!(result.x, expected_x);
+        assert_eq!(result.y, expected_y);
+    }
+
+    #[test]
+    fn test_secp256k1_add_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(secp256k1_add_program()).unwrap();
+    }
+
+    const R_PTR: u32 = 500;
+    const S_PTR: u32 = 700;
+    const INFINITY_PTR: u32 = 900;
+
+    /// Appends the instructions that add the secp256k1 point at `q_ptr` into the one at `p_ptr`
+    /// the way `AffinePoint::accumulate` of `sp1-lib` does for distinct points: if the x
+    /// coordinates are equal, the points are negations of each other and the sum is the point at
+    /// infinity, so the syscall is skipped and 1 is stored at `infinity_ptr` instead.
+    fn accumulate(instructions: &mut Vec<Instruction>, p_ptr: u32, q_ptr: u32, infinity_ptr: u32) {
+        // Compare the 8 words of x, jumping to the syscall on the first difference.
+        for i in 0..8 {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 30, 0, p_ptr + i * 4, false, true),
+                Instruction::new(Opcode::ADD, 31, 0, q_ptr + i * 4, false, true),
+                Instruction::new(Opcode::LW, 28, 30, 0, false, true),
+                Instruction::new(Opcode::LW, 29, 31, 0, false, true),
+                Instruction::new(Opcode::BNE, 28, 29, (40 - i * 5) * 4, false, true),
+            ]);
+        }
+        store_words(instructions, [(infinity_ptr, 1)]);
+        instructions.push(Instruction::new(Opcode::JAL, 0, 5 * 4, 0, true, true));
+        call_syscall(instructions, SyscallCode::SECP256K1_ADD, p_ptr, q_ptr);
+    }
+
+    /// Builds a program that accumulates `-3G` into `3G`, which gives the point at infinity, and
+    /// then `2G` into `G`.
+    fn secp256k1_add_negation_program() -> Program {
+        let g = SwCurve::<Secp256k1Parameters>::generator();
+        let g3 = g.sw_scalar_mul(&BigUint::from(3u32));
+        let mut instructions = vec![];
+        for (ptr, point) in [(P_PTR, &g3), (Q_PTR, &-&g3), (R_PTR, &g), (S_PTR, &g.sw_double())] {
+            store_words(&mut instructions, slice_at(ptr, &point.to_words_le()));
+        }
+        accumulate(&mut instructions, P_PTR, Q_PTR, INFINITY_PTR);
+        accumulate(&mut instructions, R_PTR, S_PTR, INFINITY_PTR + 4);
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_secp256k1_add_negation_execute() {
+        setup_logger();
+        let mut runtime = Executor::new(secp256k1_add_negation_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let point_at = |runtime: &mut Executor, ptr: u32| {
+            let words = (0..16).map(|i| runtime.word(ptr + i * 4)).collect::<Vec<_>>();
+            AffinePoint::<SwCurve<Secp256k1Parameters>>::from_words_le(&words)
+        };
+        let g3 = SwCurve::<Secp256k1Parameters>::generator().sw_scalar_mul(&BigUint::from(3u32));
+        assert_eq!(runtime.word(INFINITY_PTR), 1);
+        assert_eq!(point_at(&mut runtime, P_PTR), g3);
+        assert_eq!(runtime.word(INFINITY_PTR + 4), 0);
+        assert_eq!(point_at(&mut runtime, R_PTR), g3);
+    }
+
+    #[test]
+    fn test_secp256k1_add_negation_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(secp256k1_add_negation_program()).unwrap();
+    }
+
+    /// Builds a program that computes `a * P` and `b * Q` on secp256k1 with the mul syscall and
+    /// accumulates the second into the first at `P_PTR`, as `schnorr_verify` and `ecrecover` of
+    /// `sp1-lib` do. The scalars must be nonzero.
+    fn secp256k1_mul_accumulate_program(
+        p: &AffinePoint<SwCurve<Secp256k1Parameters>>,
+        a: &BigUint,
+        q: &AffinePoint<SwCurve<Secp256k1Parameters>>,
+        b: &BigUint,
+    ) -> Program {
+        let mut instructions = vec![];
+        let words = [
+            slice_at(P_PTR, &p.to_words_le()),
+            slice_at(Q_PTR, &q.to_words_le()),
+            words_at(R_PTR, 8, &[a, b]),
+        ];
+        store_words(&mut instructions, words.concat());
+        call_syscall(&mut instructions, SyscallCode::SECP256K1_MUL, P_PTR, R_PTR);
+        call_syscall(&mut instructions, SyscallCode::SECP256K1_MUL, Q_PTR, R_PTR + 32);
+        accumulate(&mut instructions, P_PTR, Q_PTR, INFINITY_PTR);
+        Program::new(instructions, 0, 0)
+    }
+
+    /// Returns the point with the given big endian x coordinate and y parity, using `k256` as the
+    /// reference, or `None` if there is no such point.
+    fn secp256k1_decompress(
+        x: &[u8],
+        is_odd: bool,
+    ) -> Option<AffinePoint<SwCurve<Secp256k1Parameters>>> {
+        let public_key =
+            k256::PublicKey::from_sec1_bytes(&[&[0x02 | is_odd as u8], x].concat()).ok()?;
+        let encoded = public_key.to_encoded_point(false);
+        Some(AffinePoint::new(
+            BigUint::from_bytes_be(encoded.x().unwrap()),
+            BigUint::from_bytes_be(encoded.y().unwrap()),
+        ))
+    }
+
+    /// Test vectors 0 to 14 from BIP-340, which are all the vectors with 32-byte messages, as
+    /// `(public key x, message, signature, valid)`.
+    const BIP340_VECTORS: [(&str, &str, &str, bool); 15] = [
+        // 0.
+        (
+            "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+            true,
+        ),
+        // 1.
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+            true,
+        ),
+        // 2.
+        (
+            "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+            "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+            "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1BAB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+            true,
+        ),
+        // 3.
+        (
+            "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3",
+            true,
+        ),
+        // 4.
+        (
+            "D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9",
+            "4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703",
+            "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C6376AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4",
+            true,
+        ),
+        // 5: public key not on the curve.
+        (
+            "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+        // 6: R has an odd y coordinate.
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A14602975563CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2",
+            false,
+        ),
+        // 7: negated message.
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "1FA62E331EDBC21C394792D2AB1100A7B432B013DF3F6FF4F99FCB33E0E1515F28890B3EDB6E7189B630448B515CE4F8622A954CFE545735AAEA5134FCCDB2BD",
+            false,
+        ),
+        // 8: negated s.
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769961764B3AA9B2FFCB6EF947B6887A226E8D7C93E00C5ED0C1834FF0D0C2E6DA6",
+            false,
+        ),
+        // 9: s * G - e * P is the point at infinity.
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "0000000000000000000000000000000000000000000000000000000000000000123DDA8328AF9C23A94C1FEECFD123BA4FB73476F0D594DCB65C6425BD186051",
+            false,
+        ),
+        // 10: s * G - e * P is the point at infinity.
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "00000000000000000000000000000000000000000000000000000000000000017615FBAF5AE28864013C099742DEADB4DBA87F11AC6754F93780D5A1837CF197",
+            false,
+        ),
+        // 11: r is not the x coordinate of a point.
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+        // 12: r is equal to the field modulus.
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+        // 13: s is equal to the group order.
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            false,
+        ),
+        // 14: public key exceeds the field modulus.
+        (
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+    ];
+
+    /// Builds a program that computes `s * G - e * P` for a BIP-340 signature with
+    /// [`secp256k1_mul_accumulate_program`], or returns `None` if `schnorr_verify` rejects the
+    /// signature before any curve arithmetic.
+    fn schnorr_program(pubkey_x: &[u8], msg: &[u8], sig: &[u8]) -> Option<Program> {
+        let public_key = secp256k1_decompress(pubkey_x, false)?;
+        let r = BigUint::from_bytes_be(&sig[..32]);
+        let s = BigUint::from_bytes_be(&sig[32..]);
+        let order = Secp256k1ScalarField::modulus();
+        if r >= Secp256k1BaseField::modulus() || s >= order {
+            return None;
+        }
+
+        let tag = Sha256::digest(b"BIP0340/challenge");
+        let challenge = Sha256::new()
+            .chain_update(tag)
+            .chain_update(tag)
+            .chain_update(&sig[..32])
+            .chain_update(pubkey_x)
+            .chain_update(msg)
+            .finalize();
+        let e = BigUint::from_bytes_be(&challenge) % &order;
+        let g = SwCurve::<Secp256k1Parameters>::generator();
+        Some(secp256k1_mul_accumulate_program(&g, &s, &-&public_key, &e))
+    }
+
+    #[test]
+    fn test_secp256k1_schnorr_verify_execute() {
+        setup_logger();
+        for (i, (pubkey_x, msg, sig, valid)) in BIP340_VECTORS.into_iter().enumerate() {
+            let [pubkey_x, msg, sig] = [pubkey_x, msg, sig].map(|s| hex::decode(s).unwrap());
+            let verified = schnorr_program(&pubkey_x, &msg, &sig).is_some_and(|program| {
+                let mut runtime = Executor::new(program, SP1CoreOpts::default());
+                runtime.run().unwrap();
+                let words = (0..16).map(|j| runtime.word(P_PTR + j * 4)).collect::<Vec<_>>();
+                let point = AffinePoint::<SwCurve<Secp256k1Parameters>>::from_words_le(&words);
+                runtime.word(INFINITY_PTR) == 0
+                    && point.x == BigUint::from_bytes_be(&sig[..32])
+                    && !point.y.bit(0)
+            });
+            assert_eq!(verified, valid, "test vector {i}");
+        }
+    }
+
+    #[test]
+    fn test_secp256k1_schnorr_verify_prove() {
+        setup_logger();
+        // A valid signature, and one whose `s * G - e * P` is the point at infinity.
+        for i in [0, 9] {
+            let (pubkey_x, msg, sig, _) = BIP340_VECTORS[i];
+            let [pubkey_x, msg, sig] = [pubkey_x, msg, sig].map(|s| hex::decode(s).unwrap());
+            run_test::<CpuProver<_, _>>(schnorr_program(&pubkey_x, &msg, &sig).unwrap()).unwrap();
+        }
+    }
+
+    /// The message hash of the `ecrecover` precompile test of go-ethereum.
+    const ECRECOVER_HASH: &str = "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c";
+
+    /// Message hashes and signatures `r || s || v` for `ecrecover`.
+    const ECRECOVER_VECTORS: [(&str, &str); 15] = [
+        // The `ecrecover` precompile test of go-ethereum.
+        (
+            ECRECOVER_HASH,
+            "73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c45491c",
+        ),
+        // The same signature with the other recovery id, which recovers another key.
+        (
+            ECRECOVER_HASH,
+            "73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c45491b",
+        ),
+        // keccak256("hello") signed with the private key 1.
+        (
+            "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8",
+            "f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80fd9d2ee5c82ea8531ef9978d259755e84ff401059c9d25883fe486943ac4c2d8e1c",
+        ),
+        // The same signature with `s` replaced by `n - s` and the recovery id flipped, which the
+        // precompile accepts.
+        (
+            "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8",
+            "f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f262d11a37d157ace1066872da68aa179bb6ecc8ce57647b7c189f54923ea13b31b",
+        ),
+        // A hash that is not reduced modulo the order, signed with the private key 0xdeadbeef.
+        (
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364146",
+            "e1fe434d345bf33083abb6280f4f44ac5fb22934977813c20c015f2b43d3fab8bc80225946af612c27ef25d96a1628463009c42c222a266b36036084ca3e4f331c",
+        ),
+        // Recovery ids other than 27 and 28.
+        (
+            ECRECOVER_HASH,
+            "73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c454900",
+        ),
+        (
+            ECRECOVER_HASH,
+            "73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c454901",
+        ),
+        (
+            ECRECOVER_HASH,
+            "73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c45491d",
+        ),
+        (
+            ECRECOVER_HASH,
+            "73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c45491f",
+        ),
+        // `r` or `s` is zero or equal to the order.
+        (
+            ECRECOVER_HASH,
+            "0000000000000000000000000000000000000000000000000000000000000000eeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c45491c",
+        ),
+        (
+            ECRECOVER_HASH,
+            "73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75f00000000000000000000000000000000000000000000000000000000000000001c",
+        ),
+        (
+            ECRECOVER_HASH,
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141eeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c45491c",
+        ),
+        (
+            ECRECOVER_HASH,
+            "73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75ffffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641411c",
+        ),
+        // 5 is not the x coordinate of a point.
+        (
+            ECRECOVER_HASH,
+            "0000000000000000000000000000000000000000000000000000000000000005eeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c45491c",
+        ),
+        // R is the generator and `s = z`, so `s * R - z * G` is the point at infinity.
+        (
+            "0000000000000000000000000000000000000000000000000000000000001234",
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f8179800000000000000000000000000000000000000000000000000000000000012341b",
+        ),
+    ];
+
+    /// Builds a program that computes the public key `u1 * G + u2 * R` of a signature `r || s || v`
+    /// with [`secp256k1_mul_accumulate_program`], or returns `None` if `ecrecover` rejects the
+    /// signature before any curve arithmetic.
+    fn ecrecover_program(msg_hash: &[u8], sig: &[u8]) -> Option<Program> {
+        let is_odd = match sig[64] {
+            27 => false,
+            28 => true,
+            _ => return None,
+        };
+        let r = BigUint::from_bytes_be(&sig[..32]);
+        let s = BigUint::from_bytes_be(&sig[32..64]);
+        let order = Secp256k1ScalarField::modulus();
+        if r.is_zero() || s.is_zero() || r >= order || s >= order {
+            return None;
+        }
+        let point = secp256k1_decompress(&sig[..32], is_odd)?;
+
+        let r_inv = r.modpow(&(&order - 2u32), &order);
+        let z = BigUint::from_bytes_be(msg_hash) % &order;
+        let u1 = (&order - z * &r_inv % &order) % &order;
+        let u2 = s * r_inv % &order;
+        let g = SwCurve::<Secp256k1Parameters>::generator();
+        Some(secp256k1_mul_accumulate_program(&g, &u1, &point, &u2))
+    }
+
+    /// Recovers the public key of a signature `r || s || v` with `k256`. It only accepts a low
+    /// `s`, so a high `s` is replaced by `n - s` with the other parity of `R`, which recovers the
+    /// same key.
+    fn k256_recover(
+        msg_hash: &[u8],
+        sig: &[u8],
+    ) -> Option<AffinePoint<SwCurve<Secp256k1Parameters>>> {
+        let mut recovery_id = sig[64].checked_sub(27).filter(|id| *id < 2)?;
+        let mut signature = K256Signature::from_slice(&sig[..64]).ok()?;
+        if let Some(low) = signature.normalize_s() {
+            signature = low;
+            recovery_id ^= 1;
+        }
+        let recovery_id = RecoveryId::from_byte(recovery_id).unwrap();
+        let key = VerifyingKey::recover_from_prehash(msg_hash, &signature, recovery_id).ok()?;
+        let encoded = key.to_encoded_point(false);
+        Some(AffinePoint::new(
+            BigUint::from_bytes_be(encoded.x().unwrap()),
+            BigUint::from_bytes_be(encoded.y().unwrap()),
+        ))
+    }
+
+    /// Runs the program of [`ecrecover_program`] and returns the public key, or `None` if the
+    /// signature is rejected or the key is the point at infinity.
+    fn execute_ecrecover(
+        msg_hash: &[u8],
+        sig: &[u8],
+    ) -> Option<AffinePoint<SwCurve<Secp256k1Parameters>>> {
+        let mut runtime = Executor::new(ecrecover_program(msg_hash, sig)?, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let words = (0..16).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+        (runtime.word(INFINITY_PTR) == 0).then(|| AffinePoint::from_words_le(&words))
+    }
+
+    #[test]
+    fn test_secp256k1_ecrecover_execute() {
+        setup_logger();
+        for (i, (msg_hash, sig)) in ECRECOVER_VECTORS.into_iter().enumerate() {
+            let [msg_hash, sig] = [msg_hash, sig].map(|s| hex::decode(s).unwrap());
+            assert_eq!(
+                execute_ecrecover(&msg_hash, &sig),
+                k256_recover(&msg_hash, &sig),
+                "test vector {i}"
+            );
+        }
+
+        // A signature made with `k256`.
+        let signing_key = SigningKey::from_slice(&[0x2a; 32]).unwrap();
+        let msg_hash = [0x5c; 32];
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&msg_hash).unwrap();
+        let sig = [&signature.to_bytes()[..], &[27 + recovery_id.to_byte()]].concat();
+        let public_key = signing_key.verifying_key().to_encoded_point(false);
+        let expected = AffinePoint::new(
+            BigUint::from_bytes_be(public_key.x().unwrap()),
+            BigUint::from_bytes_be(public_key.y().unwrap()),
+        );
+        assert_eq!(execute_ecrecover(&msg_hash, &sig), Some(expected));
+    }
+
+    #[test]
+    fn test_secp256k1_ecrecover_prove() {
+        setup_logger();
+        // The go-ethereum vector, and a key at infinity.
+        for i in [0, 14] {
+            let (msg_hash, sig) = ECRECOVER_VECTORS[i];
+            let [msg_hash, sig] = [msg_hash, sig].map(|s| hex::decode(s).unwrap());
+            run_test::<CpuProver<_, _>>(ecrecover_program(&msg_hash, &sig).unwrap()).unwrap();
+        }
+    }
+
+    /// Builds a program that computes `G + 2G` on P-256.
+    fn p256_add_program() -> Program {
+        let g = SwCurve::<P256Parameters>::generator();
+        let g2 = g.sw_double();
+        add_program(SyscallCode::P256_ADD, &g.to_words_le(), &g2.to_words_le())
+    }
+
+    #[test]
+    fn test_p256_add_execute() {
+        setup_logger();
+        let mut runtime = Executor::new(p256_add_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let words = (0..16).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+        let result = AffinePoint::<SwCurve<P256Parameters>>::from_words_le(&words);
+        let expected_x = BigUint::parse_bytes(
+            b"5ECBE4D1A6330A44C8F7EF951D4BF165E6C6B721EFADA985FB41661BC6E7FD6C",
+            16,
+        )
+        .unwrap();
+        let expected_y = BigUint::parse_bytes(
+            b"8734640C4998FF7E374B06CE1A64A2ECD82AB036384FB83D9A79B127A27D5032",
+            16,
+        )
+        .unwrap();
+        assert_eq!(result.x, expected_x);
+        assert_eq!(result.y, expected_y);
+    }
+
+    #[test]
+    fn test_p256_add_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(p256_add_program()).unwrap();
+    }
+
+    /// Builds a program that computes `G + 2G` on P-384.
+    fn p384_add_program() -> Program {
+        let g = SwCurve::<P384Parameters>::generator();
+        let g2 = g.sw_double();
+        add_program(SyscallCode::P384_ADD, &g.to_words_le(), &g2.to_words_le())
+    }
+
+    #[test]
+    fn test_p384_add_execute() {
+        setup_logger();
+        let mut runtime = Executor::new(p384_add_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let words = (0..24).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+        let result = AffinePoint::<SwCurve<P384Parameters>>::from_words_le(&words);
+        let expected_x = BigUint::parse_bytes(
+            b"077A41D4606FFA1464793C7E5FDC7D98CB9D3910202DCD06BEA4F240D3566DA6B408BBAE5026580D02D7E5C70500C831",
+            16,
+        )
+        .unwrap();
+        let expected_y = BigUint::parse_bytes(
+            b"C995F7CA0B0C42837D0BBE9602A9FC998520B41C85115AA5F7684C0EDC111EACC24ABD6BE4B5D298B65F28600A2F1DF1",
+            16,
+        )
+        .unwrap();
+        assert_eq!(result.x, expected_x);
+        assert_eq!(result.y, expected_y);
+    }
+
+    #[test]
+    fn test_p384_add_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(p384_add_program()).unwrap();
+    }
+
+    /// Builds a program that computes `G + 2G` on Grumpkin.
+    fn grumpkin_add_program() -> Program {
+        let g = SwCurve::<GrumpkinParameters>::generator();
+        let g2 = g.sw_double();
+        add_program(SyscallCode::GRUMPKIN_ADD, &g.to_words_le(), &g2.to_words_le())
+    }
+
+    #[test]
+    fn test_grumpkin_add_execute() {
+        setup_logger();
+        let mut runtime = Executor::new(grumpkin_add_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let words = (0..16).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+        let result = AffinePoint::<SwCurve<GrumpkinParameters>>::from_words_le(&words);
+        let expected_x = BigUint::parse_bytes(
+            b"2941B0928DF1B9480273773B36397DA3E495430A2A7A3857661BC7A446C94F4D",
+            16,
+        )
+        .unwrap();
+        let expected_y = BigUint::parse_bytes(
+            b"13AE7E938C892308BEF0F45EE7386DAA2D3B447349A7D0A11B5AA4CFBE69072C",
+            16,
+        )
+        .unwrap();
+        assert_eq!(result.x, expected_x);
+        assert_eq!(result.y, expected_y);
+    }
+
+    #[test]
+    fn test_grumpkin_add_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(grumpkin_add_program()).unwrap();
+    }
+
+    /// Builds a program that computes the multi-scalar multiplication `3 * G + 2 * H` on Grumpkin,
+    /// where `H = 5 * G`, by doubling and adding into the point at `P_PTR`.
+    fn grumpkin_msm_program() -> Program {
+        let g = SwCurve::<GrumpkinParameters>::generator();
+        let h = g.sw_scalar_mul(&BigUint::from(5u32));
+        let mut instructions = vec![];
+        for (ptr, point) in [(P_PTR, &g), (Q_PTR, &h), (R_PTR, &g)] {
+            store_words(&mut instructions, slice_at(ptr, &point.to_words_le()));
+        }
+        for (syscall, arg1, arg2) in [
+            (SyscallCode::GRUMPKIN_DOUBLE, P_PTR, 0),
+            (SyscallCode::GRUMPKIN_ADD, P_PTR, R_PTR),
+            (SyscallCode::GRUMPKIN_DOUBLE, Q_PTR, 0),
+            (SyscallCode::GRUMPKIN_ADD, P_PTR, Q_PTR),
+        ] {
+            call_syscall(&mut instructions, syscall, arg1, arg2);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_grumpkin_msm_execute() {
+        setup_logger();
+        let mut runtime = Executor::new(grumpkin_msm_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let words = (0..16).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+        let result = AffinePoint::<SwCurve<GrumpkinParameters>>::from_words_le(&words);
+        let expected =
+            SwCurve::<GrumpkinParameters>::generator().sw_scalar_mul(&BigUint::from(13u32));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_grumpkin_msm_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(grumpkin_msm_program()).unwrap();
+    }
+
     #[test]
     fn test_secp256k1_add_simple() {
         setup_logger();
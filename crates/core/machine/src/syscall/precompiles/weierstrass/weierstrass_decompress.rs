@@ -17,18 +17,31 @@ use sp1_core_executor::{
 };
 use sp1_curves::{
     params::{limbs_from_vec, FieldParameters, Limbs, NumLimbs, NumWords},
-    weierstrass::{bls12_381::bls12381_sqrt, secp256k1::secp256k1_sqrt, WeierstrassParameters},
+    weierstrass::{
+        bls12_381::bls12381_sqrt,
+        p256::{p256_sqrt, p256_try_sqrt},
+        p384::{p384_sqrt, p384_try_sqrt},
+        secp256k1::secp256k1_sqrt,
+        WeierstrassParameters,
+    },
     CurveType, EllipticCurve,
 };
 use sp1_derive::AlignedBorrow;
-use sp1_stark::air::{BaseAirBuilder, MachineAir, SP1AirBuilder};
+use sp1_stark::air::{BaseAirBuilder, MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder};
 use std::marker::PhantomData;
 use typenum::Unsigned;
 
 use crate::{
     memory::{MemoryReadCols, MemoryReadWriteCols},
-    operations::field::{field_op::FieldOpCols, field_sqrt::FieldSqrtCols, range::FieldLtCols},
-    utils::{bytes_to_words_le_vec, limbs_from_access, limbs_from_prev_access, pad_rows},
+    operations::field::{
+        field_op::FieldOpCols,
+        field_sqrt::FieldSqrtCols,
+        range::{FieldLtCols, FieldRangeCols},
+    },
+    utils::{
+        bytes_to_words_le_vec, limbs_from_access, limbs_from_prev_access, pad_rows_with_strategy,
+        par_generate_rows,
+    },
 };
 
 pub const fn num_weierstrass_decompress_cols<P: FieldParameters + NumWords>() -> usize {
@@ -49,7 +62,9 @@ pub struct WeierstrassDecompressCols<T, P: FieldParameters + NumWords> {
     pub sign_bit: T,
     pub x_access: GenericArray<MemoryReadCols<T>, P::WordsFieldElement>,
     pub y_access: GenericArray<MemoryReadWriteCols<T>, P::WordsFieldElement>,
-    pub(crate) range_x: FieldLtCols<T, P>,
+    pub(crate) range_x: FieldRangeCols<T, P>,
+    /// `x` if it is reduced, and the x coordinate of the generator otherwise.
+    pub reduced_x: Limbs<T, P::Limbs>,
     pub(crate) x_2: FieldOpCols<T, P>,
     pub(crate) x_3: FieldOpCols<T, P>,
     pub(crate) x_3_plus_b: FieldOpCols<T, P>,
@@ -69,6 +84,26 @@ pub struct LexicographicChoiceCols<T, P: FieldParameters + NumWords> {
     pub when_neg_y_res_is_lt: T,
 }
 
+/// A set of columns for curves with a nonzero `a` coefficient, such as P-256 and P-384.
+///
+/// These add the `a * x` term to the right-hand side of the curve equation. They also let an `x`
+/// that is not on the curve be reported to the guest, by writing a `y` with every bit set, instead
+/// of making the proof fail. Since the modulus is 3 mod 4, `-1` is not a square, so the right-hand
+/// side is not a square exactly when its negation is one (it is never zero, as the curve has prime
+/// order and thus no point with `y = 0`). The square root is taken of the right-hand side when
+/// `is_on_curve` is set, and of its negation otherwise. An `x` that is not reduced is reported in
+/// the same way, the right-hand side being that of the generator.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct GeneralCurveCols<T, P: FieldParameters + NumWords> {
+    pub is_on_curve: T,
+    /// If `x` is reduced and on the curve, so that the point is decompressed.
+    pub is_decompressed: T,
+    pub(crate) ax: FieldOpCols<T, P>,
+    pub(crate) rhs: FieldOpCols<T, P>,
+    pub(crate) signed_rhs: FieldOpCols<T, P>,
+}
+
 /// The convention for choosing the decompressed `y` value given a sign bit.
 pub enum SignChoiceRule {
     /// Lease significant bit convention.
@@ -102,15 +137,32 @@ impl<E: EllipticCurve + WeierstrassParameters> WeierstrassDecompressChip<E> {
         Self { sign_rule: SignChoiceRule::Lexicographic, _marker: PhantomData::<E> }
     }
 
+    /// Whether the chip has the [`GeneralCurveCols`] for the `a * x` term.
+    fn has_general_cols() -> bool {
+        matches!(E::CURVE_TYPE, CurveType::P256 | CurveType::P384)
+    }
+
+    fn num_general_cols() -> usize {
+        if Self::has_general_cols() {
+            size_of::<GeneralCurveCols<u8, E::BaseField>>()
+        } else {
+            0
+        }
+    }
+
     fn populate_field_ops<F: PrimeField32>(
         record: &mut impl ByteRecord,
         shard: u32,
         channel: u8,
         cols: &mut WeierstrassDecompressCols<F, E::BaseField>,
+        general_cols: Option<&mut GeneralCurveCols<F, E::BaseField>>,
         x: BigUint,
     ) {
-        // Y = sqrt(x^3 + b)
-        cols.range_x.populate(record, shard, channel, &x, &E::BaseField::modulus());
+        // Y = sqrt(x^3 + ax + b)
+        let is_reduced =
+            cols.range_x.populate(record, shard, channel, &x, &E::BaseField::modulus());
+        let x = if is_reduced { x } else { E::generator().0 };
+        cols.reduced_x = E::BaseField::to_limbs_field::<F, _>(&x);
         let x_2 =
             cols.x_2.populate(record, shard, channel, &x.clone(), &x.clone(), FieldOperation::Mul);
         let x_3 = cols.x_3.populate(record, shard, channel, &x_2, &x, FieldOperation::Mul);
@@ -118,12 +170,41 @@ impl<E: EllipticCurve + WeierstrassParameters> WeierstrassDecompressChip<E> {
         let x_3_plus_b =
             cols.x_3_plus_b.populate(record, shard, channel, &x_3, &b, FieldOperation::Add);
 
+        let sqrt_input = match general_cols {
+            Some(general_cols) => {
+                let a = E::a_int();
+                let ax =
+                    general_cols.ax.populate(record, shard, channel, &a, &x, FieldOperation::Mul);
+                let rhs = general_cols.rhs.populate(
+                    record,
+                    shard,
+                    channel,
+                    &x_3_plus_b,
+                    &ax,
+                    FieldOperation::Add,
+                );
+                let try_sqrt_fn = match E::CURVE_TYPE {
+                    CurveType::P256 => p256_try_sqrt,
+                    CurveType::P384 => p384_try_sqrt,
+                    _ => panic!("Unsupported curve"),
+                };
+                let is_on_curve = try_sqrt_fn(&rhs).is_some();
+                general_cols.is_on_curve = F::from_bool(is_on_curve);
+                general_cols.is_decompressed = F::from_bool(is_on_curve && is_reduced);
+                let op = if is_on_curve { FieldOperation::Add } else { FieldOperation::Sub };
+                general_cols.signed_rhs.populate(record, shard, channel, &BigUint::zero(), &rhs, op)
+            }
+            None => x_3_plus_b,
+        };
+
         let sqrt_fn = match E::CURVE_TYPE {
             CurveType::Secp256k1 => secp256k1_sqrt,
             CurveType::Bls12381 => bls12381_sqrt,
+            CurveType::P256 => p256_sqrt,
+            CurveType::P384 => p384_sqrt,
             _ => panic!("Unsupported curve"),
         };
-        let y = cols.y.populate(record, shard, channel, &x_3_plus_b, sqrt_fn);
+        let y = cols.y.populate(record, shard, channel, &sqrt_input, sqrt_fn);
 
         let zero = BigUint::zero();
         cols.neg_y.populate(record, shard, channel, &zero, &y, FieldOperation::Sub);
@@ -140,6 +221,8 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => "Secp256k1Decompress".to_string(),
             CurveType::Bls12381 => "Bls12381Decompress".to_string(),
+            CurveType::P256 => "P256Decompress".to_string(),
+            CurveType::P384 => "P384Decompress".to_string(),
             _ => panic!("Unsupported curve"),
         }
     }
@@ -152,117 +235,124 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
         let events = match E::CURVE_TYPE {
             CurveType::Secp256k1 => &input.k256_decompress_events,
             CurveType::Bls12381 => &input.bls12381_decompress_events,
+            CurveType::P256 => &input.p256_decompress_events,
+            CurveType::P384 => &input.p384_decompress_events,
             _ => panic!("Unsupported curve"),
         };
 
-        let mut rows = Vec::new();
         let weierstrass_width = num_weierstrass_decompress_cols::<E::BaseField>();
+        let general_width = Self::num_general_cols();
         let width = BaseAir::<F>::width(self);
 
-        let mut new_byte_lookup_events = Vec::new();
-
         let modulus = E::BaseField::modulus();
 
-        for i in 0..events.len() {
-            let event = events[i].clone();
-            let mut row = vec![F::zero(); width];
-            let cols: &mut WeierstrassDecompressCols<F, E::BaseField> =
-                row[0..weierstrass_width].borrow_mut();
-
-            cols.is_real = F::from_bool(true);
-            cols.shard = F::from_canonical_u32(event.shard);
-            cols.channel = F::from_canonical_u8(event.channel);
-            cols.channel = F::from_canonical_u8(event.channel);
-            cols.clk = F::from_canonical_u32(event.clk);
-            cols.ptr = F::from_canonical_u32(event.ptr);
-            cols.sign_bit = F::from_bool(event.sign_bit);
-
-            let x = BigUint::from_bytes_le(&event.x_bytes);
-            Self::populate_field_ops(
-                &mut new_byte_lookup_events,
-                event.shard,
-                event.channel,
-                cols,
-                x,
-            );
-
-            for i in 0..cols.x_access.len() {
-                cols.x_access[i].populate(
+        let (mut rows, new_byte_lookup_events) =
+            par_generate_rows(events, |event, new_byte_lookup_events| {
+                let mut row = vec![F::zero(); width];
+                let (base, extension) = row.split_at_mut(weierstrass_width);
+                let cols: &mut WeierstrassDecompressCols<F, E::BaseField> = base.borrow_mut();
+                let general_cols: Option<&mut GeneralCurveCols<F, E::BaseField>> =
+                    if Self::has_general_cols() {
+                        Some(extension[0..general_width].borrow_mut())
+                    } else {
+                        None
+                    };
+
+                cols.is_real = F::from_bool(true);
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.ptr = F::from_canonical_u32(event.ptr);
+                cols.sign_bit = F::from_bool(event.sign_bit);
+
+                let x = BigUint::from_bytes_le(&event.x_bytes);
+                Self::populate_field_ops(
+                    new_byte_lookup_events,
+                    event.shard,
                     event.channel,
-                    event.x_memory_records[i],
-                    &mut new_byte_lookup_events,
+                    cols,
+                    general_cols,
+                    x,
                 );
-            }
-            for i in 0..cols.y_access.len() {
-                cols.y_access[i].populate_write(
-                    event.channel,
-                    event.y_memory_records[i],
-                    &mut new_byte_lookup_events,
-                );
-            }
 
-            if matches!(self.sign_rule, SignChoiceRule::Lexicographic) {
-                let lsb = cols.y.lsb;
-                let choice_cols: &mut LexicographicChoiceCols<F, E::BaseField> =
-                    row[weierstrass_width..width].borrow_mut();
-
-                let decompressed_y = BigUint::from_bytes_le(&event.decompressed_y_bytes);
-                let neg_y = &modulus - &decompressed_y;
-
-                let is_y_eq_sqrt_y_result =
-                    F::from_canonical_u8(event.decompressed_y_bytes[0] % 2) == lsb;
-                choice_cols.is_y_eq_sqrt_y_result = F::from_bool(is_y_eq_sqrt_y_result);
-
-                if is_y_eq_sqrt_y_result {
-                    choice_cols.neg_y_range_check.populate(
-                        &mut new_byte_lookup_events,
-                        event.shard,
+                for i in 0..cols.x_access.len() {
+                    cols.x_access[i].populate(
                         event.channel,
-                        &neg_y,
-                        &modulus,
-                    );
-                } else {
-                    choice_cols.neg_y_range_check.populate(
-                        &mut new_byte_lookup_events,
-                        event.shard,
-                        event.channel,
-                        &decompressed_y,
-                        &modulus,
+                        event.x_memory_records[i],
+                        new_byte_lookup_events,
                     );
                 }
-                if event.sign_bit {
-                    assert!(neg_y < decompressed_y);
-                    choice_cols.when_sqrt_y_res_is_lt = F::from_bool(!is_y_eq_sqrt_y_result);
-                    choice_cols.when_neg_y_res_is_lt = F::from_bool(is_y_eq_sqrt_y_result);
-                    choice_cols.comparison_lt_cols.populate(
-                        &mut new_byte_lookup_events,
-                        event.shard,
+                for i in 0..cols.y_access.len() {
+                    cols.y_access[i].populate_write(
                         event.channel,
-                        &neg_y,
-                        &decompressed_y,
-                    );
-                } else {
-                    assert!(neg_y > decompressed_y);
-                    choice_cols.when_sqrt_y_res_is_lt = F::from_bool(is_y_eq_sqrt_y_result);
-                    choice_cols.when_neg_y_res_is_lt = F::from_bool(!is_y_eq_sqrt_y_result);
-                    choice_cols.comparison_lt_cols.populate(
-                        &mut new_byte_lookup_events,
-                        event.shard,
-                        event.channel,
-                        &decompressed_y,
-                        &neg_y,
+                        event.y_memory_records[i],
+                        new_byte_lookup_events,
                     );
                 }
-            }
 
-            rows.push(row);
-        }
+                if matches!(self.sign_rule, SignChoiceRule::Lexicographic) {
+                    let lsb = cols.y.lsb;
+                    let choice_cols: &mut LexicographicChoiceCols<F, E::BaseField> =
+                        row[weierstrass_width + general_width..width].borrow_mut();
+
+                    let decompressed_y = BigUint::from_bytes_le(&event.decompressed_y_bytes);
+                    let neg_y = &modulus - &decompressed_y;
+
+                    let is_y_eq_sqrt_y_result =
+                        F::from_canonical_u8(event.decompressed_y_bytes[0] % 2) == lsb;
+                    choice_cols.is_y_eq_sqrt_y_result = F::from_bool(is_y_eq_sqrt_y_result);
+
+                    if is_y_eq_sqrt_y_result {
+                        choice_cols.neg_y_range_check.populate(
+                            new_byte_lookup_events,
+                            event.shard,
+                            event.channel,
+                            &neg_y,
+                            &modulus,
+                        );
+                    } else {
+                        choice_cols.neg_y_range_check.populate(
+                            new_byte_lookup_events,
+                            event.shard,
+                            event.channel,
+                            &decompressed_y,
+                            &modulus,
+                        );
+                    }
+                    if event.sign_bit {
+                        assert!(neg_y < decompressed_y);
+                        choice_cols.when_sqrt_y_res_is_lt = F::from_bool(!is_y_eq_sqrt_y_result);
+                        choice_cols.when_neg_y_res_is_lt = F::from_bool(is_y_eq_sqrt_y_result);
+                        choice_cols.comparison_lt_cols.populate(
+                            new_byte_lookup_events,
+                            event.shard,
+                            event.channel,
+                            &neg_y,
+                            &decompressed_y,
+                        );
+                    } else {
+                        assert!(neg_y > decompressed_y);
+                        choice_cols.when_sqrt_y_res_is_lt = F::from_bool(is_y_eq_sqrt_y_result);
+                        choice_cols.when_neg_y_res_is_lt = F::from_bool(!is_y_eq_sqrt_y_result);
+                        choice_cols.comparison_lt_cols.populate(
+                            new_byte_lookup_events,
+                            event.shard,
+                            event.channel,
+                            &decompressed_y,
+                            &neg_y,
+                        );
+                    }
+                }
+
+                row
+            });
         output.add_byte_lookup_events(new_byte_lookup_events);
 
-        pad_rows(&mut rows, || {
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
             let mut row = vec![F::zero(); width];
-            let cols: &mut WeierstrassDecompressCols<F, E::BaseField> =
-                row.as_mut_slice()[0..weierstrass_width].borrow_mut();
+            let (base, extension) = row.split_at_mut(weierstrass_width);
+            let cols: &mut WeierstrassDecompressCols<F, E::BaseField> = base.borrow_mut();
 
             // take X of the generator as a dummy value to make sure Y^2 = X^3 + b holds
             let dummy_value = E::generator().0;
@@ -272,7 +362,23 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                 cols.x_access[i].access.value = words[i].into();
             }
 
-            Self::populate_field_ops(&mut vec![], 0, 0, cols, dummy_value);
+            if Self::has_general_cols() {
+                let general_cols: &mut GeneralCurveCols<F, E::BaseField> =
+                    extension[0..general_width].borrow_mut();
+                Self::populate_field_ops(
+                    &mut vec![],
+                    0,
+                    0,
+                    cols,
+                    Some(&mut *general_cols),
+                    dummy_value,
+                );
+                // The flags must stay off on padding rows, which then leaves `signed_rhs` unused.
+                general_cols.is_on_curve = F::zero();
+                general_cols.is_decompressed = F::zero();
+            } else {
+                Self::populate_field_ops(&mut vec![], 0, 0, cols, None, dummy_value);
+            }
             row
         });
 
@@ -292,14 +398,21 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => !shard.k256_decompress_events.is_empty(),
             CurveType::Bls12381 => !shard.bls12381_decompress_events.is_empty(),
+            CurveType::P256 => !shard.p256_decompress_events.is_empty(),
+            CurveType::P384 => !shard.p384_decompress_events.is_empty(),
             _ => panic!("Unsupported curve"),
         }
     }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
 }
 
-impl<F, E: EllipticCurve> BaseAir<F> for WeierstrassDecompressChip<E> {
+impl<F, E: EllipticCurve + WeierstrassParameters> BaseAir<F> for WeierstrassDecompressChip<E> {
     fn width(&self) -> usize {
         num_weierstrass_decompress_cols::<E::BaseField>()
+            + Self::num_general_cols()
             + match self.sign_rule {
                 SignChoiceRule::LeastSignificantBit => 0,
                 SignChoiceRule::Lexicographic => {
@@ -336,15 +449,26 @@ where
 
         let x: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
             limbs_from_prev_access(&local.x_access);
-        let max_num_limbs = E::BaseField::to_limbs_field_vec(&E::BaseField::modulus());
         local.range_x.eval(
             builder,
             &x,
-            &limbs_from_vec::<AB::Expr, <E::BaseField as NumLimbs>::Limbs, AB::F>(max_num_limbs),
+            &E::BaseField::modulus(),
             local.shard,
             local.channel,
             local.is_real,
         );
+
+        // An `x` that is not reduced is replaced with the x coordinate of the generator, so that
+        // the field operations have reduced operands.
+        let generator_x = E::BaseField::to_limbs_field::<AB::Expr, _>(&E::generator().0);
+        let is_not_reduced = AB::Expr::one() - local.range_x.is_less;
+        for i in 0..num_limbs {
+            builder.assert_eq(
+                local.reduced_x[i],
+                local.range_x.is_less * x[i] + is_not_reduced.clone() * generator_x[i].clone(),
+            );
+        }
+        let x = local.reduced_x;
         local.x_2.eval(
             builder,
             &x,
@@ -375,6 +499,78 @@ where
             local.is_real,
         );
 
+        // For curves with a nonzero `a`, the square root is taken of either the right-hand side
+        // or its negation, and only a real row with `is_decompressed` set decompresses a point.
+        let general_width = Self::num_general_cols();
+        let (sqrt_input, is_decompressed): (
+            Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>,
+            AB::Expr,
+        ) = if Self::has_general_cols() {
+            let general_cols: &GeneralCurveCols<AB::Var, E::BaseField> =
+                (*local_slice)[weierstrass_cols..weierstrass_cols + general_width].borrow();
+
+            builder.assert_bool(general_cols.is_on_curve);
+            builder.when_not(local.is_real).assert_zero(general_cols.is_on_curve);
+            builder.assert_eq(
+                general_cols.is_decompressed,
+                general_cols.is_on_curve * local.range_x.is_less,
+            );
+
+            let a_const = E::BaseField::to_limbs_field::<AB::F, _>(&E::a_int());
+            general_cols.ax.eval(
+                builder,
+                &a_const,
+                &x,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            general_cols.rhs.eval(
+                builder,
+                &local.x_3_plus_b.result,
+                &general_cols.ax.result,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            let is_off_curve: AB::Expr = local.is_real - general_cols.is_on_curve;
+            let modulus_coeffs = E::BaseField::MODULUS
+                .iter()
+                .map(|&limb| AB::Expr::from_canonical_u8(limb))
+                .collect::<Vec<_>>();
+            general_cols.signed_rhs.eval_variable(
+                builder,
+                &[AB::Expr::zero()].iter(),
+                &general_cols.rhs.result,
+                &Polynomial::from_coefficients(&modulus_coeffs),
+                general_cols.is_on_curve,
+                is_off_curve.clone(),
+                AB::F::zero(),
+                AB::F::zero(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            // An x that is not reduced or not on the curve is reported by writing a y with every
+            // bit set.
+            let y_limbs: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+                limbs_from_access(&local.y_access);
+            builder.when(local.is_real - general_cols.is_decompressed).assert_all_eq(
+                y_limbs,
+                (0..num_limbs).map(|_| AB::Expr::from_canonical_u8(u8::MAX)),
+            );
+
+            (general_cols.signed_rhs.result, general_cols.is_decompressed.into())
+        } else {
+            // The other curves require `x` to be reduced.
+            builder.when(local.is_real).assert_one(local.range_x.is_less);
+            (local.x_3_plus_b.result, local.is_real.into())
+        };
+
         local.neg_y.eval(
             builder,
             &[AB::Expr::zero()].iter(),
@@ -385,14 +581,7 @@ where
             local.is_real,
         );
 
-        local.y.eval(
-            builder,
-            &local.x_3_plus_b.result,
-            local.y.lsb,
-            local.shard,
-            local.channel,
-            local.is_real,
-        );
+        local.y.eval(builder, &sqrt_input, local.y.lsb, local.shard, local.channel, local.is_real);
 
         let y_limbs: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
             limbs_from_access(&local.y_access);
@@ -406,11 +595,11 @@ where
                 // should be the square root of the y value. Otherwise, the result should be the
                 // negative square root of the y value.
                 builder
-                    .when(local.is_real)
+                    .when(is_decompressed.clone())
                     .when_ne(local.y.lsb, AB::Expr::one() - local.sign_bit)
                     .assert_all_eq(local.y.multiplication.result, y_limbs);
                 builder
-                    .when(local.is_real)
+                    .when(is_decompressed)
                     .when_ne(local.y.lsb, local.sign_bit)
                     .assert_all_eq(local.neg_y.result, y_limbs);
             }
@@ -423,8 +612,9 @@ where
 
                 // Get the choice columns from the row slice
                 let choice_cols: &LexicographicChoiceCols<AB::Var, E::BaseField> = (*local_slice)
-                    [weierstrass_cols
+                    [weierstrass_cols + general_width
                         ..weierstrass_cols
+                            + general_width
                             + size_of::<LexicographicChoiceCols<u8, E::BaseField>>()]
                     .borrow();
 
@@ -538,6 +728,12 @@ where
             CurveType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_DECOMPRESS.syscall_id())
             }
+            CurveType::P256 => {
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_DECOMPRESS.syscall_id())
+            }
+            CurveType::P384 => {
+                AB::F::from_canonical_u32(SyscallCode::SECP384R1_DECOMPRESS.syscall_id())
+            }
             _ => panic!("Unsupported curve"),
         };
 
@@ -551,6 +747,9 @@ where
             local.sign_bit,
             local.is_real,
         );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
     }
 }
 
@@ -558,18 +757,31 @@ where
 mod tests {
     use crate::{
         io::SP1Stdin,
-        utils::{self, tests::BLS12381_DECOMPRESS_ELF},
+        utils::{
+            self, run_test, run_test_io,
+            tests::{
+                syscall_program, to_words, words_at, BLS12381_DECOMPRESS_ELF,
+                SECP256K1_DECOMPRESS_ELF,
+            },
+        },
     };
     use amcl::{
         bls381::bls381::{basic::key_pair_generate_g2, utils::deserialize_g1},
         rand::RAND,
     };
     use elliptic_curve::sec1::ToEncodedPoint;
+    use num::BigUint;
     use rand::{thread_rng, Rng};
-    use sp1_core_executor::Program;
-    use sp1_stark::CpuProver;
-
-    use crate::utils::{run_test_io, tests::SECP256K1_DECOMPRESS_ELF};
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        params::FieldParameters,
+        weierstrass::{
+            p256::{P256BaseField, P256Parameters},
+            p384::{P384BaseField, P384Parameters},
+            WeierstrassParameters,
+        },
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
 
     #[test]
     fn test_weierstrass_bls_decompress() {
@@ -630,4 +842,75 @@ mod tests {
             assert_eq!(result, decompressed);
         }
     }
+
+    const POINT_PTR: u32 = 100;
+
+    /// A program that decompresses `x`, a field element of `num_words` words, with `syscall`.
+    fn decompress_program(
+        syscall: SyscallCode,
+        num_words: usize,
+        x: &BigUint,
+        sign_bit: u32,
+    ) -> Program {
+        let x_ptr = POINT_PTR + num_words as u32 * 4;
+        syscall_program(syscall, words_at(x_ptr, num_words, &[x]), POINT_PTR, sign_bit)
+    }
+
+    #[test]
+    fn test_weierstrass_p256_decompress() {
+        utils::setup_logger();
+        let (x, y) = P256Parameters::generator();
+        let modulus = P256BaseField::modulus();
+        let y_odd = (&y % 2u32) == BigUint::from(1u32);
+
+        // The generator with both parities, an x that is not on the curve, and x that are not
+        // reduced.
+        let cases = [
+            (x.clone(), y_odd as u32, Some(y.clone())),
+            (x.clone(), !y_odd as u32, Some(&modulus - &y)),
+            (BigUint::from(1u32), 0, None),
+            (modulus.clone(), 0, None),
+            ((BigUint::from(1u32) << 256) - 1u32, 1, None),
+        ];
+        for (x, sign_bit, expected) in cases {
+            let program = decompress_program(SyscallCode::SECP256R1_DECOMPRESS, 8, &x, sign_bit);
+            let mut runtime = Executor::new(program.clone(), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> = (0..8).map(|i| runtime.word(POINT_PTR + i * 4)).collect();
+            match expected {
+                Some(y) => assert_eq!(result, to_words(8, &y)),
+                None => assert_eq!(result, vec![u32::MAX; 8]),
+            }
+
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_weierstrass_p384_decompress() {
+        utils::setup_logger();
+        let (x, y) = P384Parameters::generator();
+        let modulus = P384BaseField::modulus();
+        let y_odd = (&y % 2u32) == BigUint::from(1u32);
+
+        let cases = [
+            (x.clone(), y_odd as u32, Some(y.clone())),
+            (x.clone(), !y_odd as u32, Some(&modulus - &y)),
+            (BigUint::from(1u32), 0, None),
+            (modulus.clone(), y_odd as u32, None),
+            ((BigUint::from(1u32) << 384) - 1u32, 1, None),
+        ];
+        for (x, sign_bit, expected) in cases {
+            let program = decompress_program(SyscallCode::SECP384R1_DECOMPRESS, 12, &x, sign_bit);
+            let mut runtime = Executor::new(program.clone(), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            let result: Vec<u32> = (0..12).map(|i| runtime.word(POINT_PTR + i * 4)).collect();
+            match expected {
+                Some(y) => assert_eq!(result, to_words(12, &y)),
+                None => assert_eq!(result, vec![u32::MAX; 12]),
+            }
+
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
 }
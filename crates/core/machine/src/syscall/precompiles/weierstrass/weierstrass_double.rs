@@ -6,7 +6,7 @@ use std::{fmt::Debug, marker::PhantomData};
 
 use crate::air::MemoryAirBuilder;
 use generic_array::GenericArray;
-use num::{BigUint, Zero};
+use num::{BigUint, One, Zero};
 use p3_air::{Air, AirBuilder, BaseAir};
 use p3_field::{AbstractField, PrimeField32};
 use p3_matrix::{dense::RowMajorMatrix, Matrix};
@@ -23,14 +23,14 @@ use sp1_curves::{
 };
 use sp1_derive::AlignedBorrow;
 use sp1_stark::{
-    air::{MachineAir, SP1AirBuilder},
-    MachineRecord,
+    air::{MachineAir, PaddingStrategy, SP1AirBuilder},
+    MachineRecord, Word,
 };
 
 use crate::{
     memory::{MemoryCols, MemoryWriteCols},
     operations::field::field_op::FieldOpCols,
-    utils::{limbs_from_prev_access, pad_rows},
+    utils::{limbs_from_prev_access, pad_rows_with_strategy},
 };
 
 pub const fn num_weierstrass_double_cols<P: FieldParameters + NumWords>() -> usize {
@@ -205,6 +205,9 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256k1 => "Secp256k1DoubleAssign".to_string(),
             CurveType::Bn254 => "Bn254DoubleAssign".to_string(),
             CurveType::Bls12381 => "Bls12381DoubleAssign".to_string(),
+            CurveType::P256 => "P256DoubleAssign".to_string(),
+            CurveType::P384 => "P384DoubleAssign".to_string(),
+            CurveType::Grumpkin => "GrumpkinDoubleAssign".to_string(),
             _ => panic!("Unsupported curve"),
         }
     }
@@ -219,6 +222,9 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256k1 => &input.secp256k1_double_events,
             CurveType::Bn254 => &input.bn254_double_events,
             CurveType::Bls12381 => &input.bls12381_double_events,
+            CurveType::P256 => &input.p256_double_events,
+            CurveType::P384 => &input.p384_double_events,
+            CurveType::Grumpkin => &input.grumpkin_double_events,
             _ => panic!("Unsupported curve"),
         };
 
@@ -283,12 +289,15 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             output.append(&mut row_and_record.1);
         }
 
-        pad_rows(&mut rows, || {
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
             let mut row = vec![F::zero(); num_weierstrass_double_cols::<E::BaseField>()];
             let cols: &mut WeierstrassDoubleAssignCols<F, E::BaseField> =
                 row.as_mut_slice().borrow_mut();
-            let zero = BigUint::zero();
-            Self::populate_field_ops(&mut vec![], 0, 0, cols, zero.clone(), zero.clone());
+            // Double `(0, 1)`, so that the slope is well defined on curves with a nonzero `a`.
+            let num_words_field_element = E::BaseField::NB_LIMBS / 4;
+            cols.p_access[num_words_field_element].prev_value =
+                Word([F::one(), F::zero(), F::zero(), F::zero()]);
+            Self::populate_field_ops(&mut vec![], 0, 0, cols, BigUint::zero(), BigUint::one());
             row
         });
 
@@ -315,9 +324,16 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256k1 => !shard.secp256k1_double_events.is_empty(),
             CurveType::Bn254 => !shard.bn254_double_events.is_empty(),
             CurveType::Bls12381 => !shard.bls12381_double_events.is_empty(),
+            CurveType::P256 => !shard.p256_double_events.is_empty(),
+            CurveType::P384 => !shard.p384_double_events.is_empty(),
+            CurveType::Grumpkin => !shard.grumpkin_double_events.is_empty(),
             _ => panic!("Unsupported curve"),
         }
     }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
 }
 
 impl<F, E: EllipticCurve + WeierstrassParameters> BaseAir<F> for WeierstrassDoubleAssignChip<E> {
@@ -501,6 +517,11 @@ where
             CurveType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_DOUBLE.syscall_id())
             }
+            CurveType::P256 => AB::F::from_canonical_u32(SyscallCode::P256_DOUBLE.syscall_id()),
+            CurveType::P384 => AB::F::from_canonical_u32(SyscallCode::P384_DOUBLE.syscall_id()),
+            CurveType::Grumpkin => {
+                AB::F::from_canonical_u32(SyscallCode::GRUMPKIN_DOUBLE.syscall_id())
+            }
             _ => panic!("Unsupported curve"),
         };
 
@@ -514,18 +535,30 @@ where
             AB::Expr::zero(),
             local.is_real,
         );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
     }
 }
 
 #[cfg(test)]
 pub mod tests {
 
-    use sp1_core_executor::Program;
-    use sp1_stark::CpuProver;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        weierstrass::{
+            grumpkin::GrumpkinParameters, p256::P256Parameters, p384::P384Parameters, SwCurve,
+            WeierstrassParameters,
+        },
+        AffinePoint,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
 
     use crate::utils::{
         run_test, setup_logger,
-        tests::{BLS12381_DOUBLE_ELF, BN254_DOUBLE_ELF, SECP256K1_DOUBLE_ELF},
+        tests::{
+            slice_at, syscall_program, BLS12381_DOUBLE_ELF, BN254_DOUBLE_ELF, SECP256K1_DOUBLE_ELF,
+        },
     };
 
     #[test]
@@ -548,4 +581,83 @@ pub mod tests {
         let program = Program::from(BLS12381_DOUBLE_ELF).unwrap();
         run_test::<CpuProver<_, _>>(program).unwrap();
     }
+
+    const P_PTR: u32 = 100;
+
+    /// Builds a program that doubles the generator with the given syscall.
+    fn double_program<P: WeierstrassParameters>(syscall: SyscallCode) -> Program {
+        let g = SwCurve::<P>::generator();
+        syscall_program(syscall, slice_at(P_PTR, &g.to_words_le()), P_PTR, 0)
+    }
+
+    #[test]
+    fn test_p256_double_execute() {
+        setup_logger();
+        let mut runtime = Executor::new(
+            double_program::<P256Parameters>(SyscallCode::P256_DOUBLE),
+            SP1CoreOpts::default(),
+        );
+        runtime.run().unwrap();
+
+        let words = (0..16).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+        let result = AffinePoint::<SwCurve<P256Parameters>>::from_words_le(&words);
+        let expected = SwCurve::<P256Parameters>::generator().sw_double();
+        assert_eq!(result.x, expected.x);
+        assert_eq!(result.y, expected.y);
+    }
+
+    #[test]
+    fn test_p256_double_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(double_program::<P256Parameters>(SyscallCode::P256_DOUBLE))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_p384_double_execute() {
+        setup_logger();
+        let mut runtime = Executor::new(
+            double_program::<P384Parameters>(SyscallCode::P384_DOUBLE),
+            SP1CoreOpts::default(),
+        );
+        runtime.run().unwrap();
+
+        let words = (0..24).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+        let result = AffinePoint::<SwCurve<P384Parameters>>::from_words_le(&words);
+        let expected = SwCurve::<P384Parameters>::generator().sw_double();
+        assert_eq!(result.x, expected.x);
+        assert_eq!(result.y, expected.y);
+    }
+
+    #[test]
+    fn test_p384_double_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(double_program::<P384Parameters>(SyscallCode::P384_DOUBLE))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_grumpkin_double_execute() {
+        setup_logger();
+        let mut runtime = Executor::new(
+            double_program::<GrumpkinParameters>(SyscallCode::GRUMPKIN_DOUBLE),
+            SP1CoreOpts::default(),
+        );
+        runtime.run().unwrap();
+
+        let words = (0..16).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+        let result = AffinePoint::<SwCurve<GrumpkinParameters>>::from_words_le(&words);
+        let expected = SwCurve::<GrumpkinParameters>::generator().sw_double();
+        assert_eq!(result.x, expected.x);
+        assert_eq!(result.y, expected.y);
+    }
+
+    #[test]
+    fn test_grumpkin_double_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(double_program::<GrumpkinParameters>(
+            SyscallCode::GRUMPKIN_DOUBLE,
+        ))
+        .unwrap();
+    }
 }
@@ -0,0 +1,1295 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use crate::air::MemoryAirBuilder;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{
+        ByteLookupEvent, ByteRecord, EcdsaVerifyEvent, FieldOperation, ECDSA_SCALAR_BITS,
+        ECDSA_VERIFY_INPUT_WORDS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs},
+    weierstrass::secp256k1::{Secp256k1, Secp256k1BaseField, Secp256k1ScalarField},
+    AffinePoint,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{MachineAir, PaddingStrategy, Polynomial, SP1AirBuilder},
+    MachineRecord,
+};
+use typenum::U32;
+
+use super::{coordinates, EcAddCols, EcDoubleCols, MsmPointCols};
+use crate::{
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{
+            field_op::FieldOpCols,
+            range::{FieldLtCols, FieldRangeCols},
+        },
+        IsZeroOperation,
+    },
+    utils::{limbs_from_access, pad_rows_with_strategy},
+};
+
+/// The number of bytes of the scalars, each of which takes up eight pairs of rows.
+const NUM_SCALAR_BYTES: usize = ECDSA_SCALAR_BITS / 8;
+
+/// The number of rows of a syscall: a setup row, a doubling and an adding row per bit of the
+/// scalars, and a final row.
+pub const ECDSA_VERIFY_ROWS: usize = 2 * ECDSA_SCALAR_BITS + 2;
+
+pub const NUM_ECDSA_VERIFY_COLS: usize = size_of::<EcdsaVerifyCols<u8>>();
+
+type BaseField = Secp256k1BaseField;
+type ScalarField = Secp256k1ScalarField;
+type Point = AffinePoint<Secp256k1>;
+
+/// A set of columns to verify an ECDSA signature `(r, s)` of a message hash `z` for a public key
+/// `Q` on the secp256k1 curve.
+///
+/// The setup row reads the input, checks that it is in range and that the public key is on the
+/// curve, computes the scalars `u1 = z / s` and `u2 = r / s`, and adds the public key to the
+/// generator. Each bit of the scalars, most significant first, then takes up a doubling row, which
+/// doubles the result, and an adding row, which adds `G`, `Q` or `G + Q` to the result. The final
+/// row compares the x coordinate of the result with `r` modulo the order of the curve, and writes
+/// the validity bit.
+///
+/// Every row performs one addition with the same columns as the multi-scalar multiplication, where
+/// the sum of a point and its negation is the point at infinity.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EcdsaVerifyCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub ptr: T,
+
+    /// The kind of row.
+    pub is_setup: T,
+    pub is_doubling: T,
+    pub is_adding: T,
+    pub is_final: T,
+
+    /// Which byte of the scalars this row processes, most significant first.
+    pub byte_flags: [T; NUM_SCALAR_BYTES],
+    /// Which bit of the byte this row processes, most significant first.
+    pub bit_flags: [T; 8],
+    /// The bits of `u1` and `u2` of an adding row, and their product.
+    pub u1_bit: T,
+    pub u2_bit: T,
+    pub both_bits: T,
+    /// The value of the bits of the byte that come before this row.
+    pub u1_partial: T,
+    pub u2_partial: T,
+    /// The bytes of `u1` and `u2` selected by the byte flags.
+    pub u1_byte: T,
+    pub u2_byte: T,
+    /// If an adding row adds a point, which it does not if both bits are zero, or if both are set
+    /// and `G + Q` is the point at infinity.
+    pub is_selected: T,
+
+    /// The values that stay the same throughout the rows of a syscall.
+    pub r: Limbs<T, U32>,
+    pub u1: Limbs<T, U32>,
+    pub u2: Limbs<T, U32>,
+    pub pubkey_x: Limbs<T, U32>,
+    pub pubkey_y: Limbs<T, U32>,
+    pub is_on_curve: T,
+    pub sum: MsmPointCols<T, BaseField>,
+
+    /// The input of the setup row, its range checks, the curve equation and the scalars.
+    pub input_access: [MemoryReadCols<T>; ECDSA_VERIFY_INPUT_WORDS],
+    pub(crate) r_range: FieldRangeCols<T, ScalarField>,
+    pub(crate) s_range: FieldRangeCols<T, ScalarField>,
+    pub r_is_zero: IsZeroOperation<T>,
+    pub s_is_zero: IsZeroOperation<T>,
+    pub(crate) pubkey_x_range: FieldRangeCols<T, BaseField>,
+    pub(crate) pubkey_y_range: FieldRangeCols<T, BaseField>,
+    /// If `r` and `s` are nonzero and less than the order, and if the public key is reduced.
+    pub is_r_valid: T,
+    pub is_s_valid: T,
+    pub is_pubkey_reduced: T,
+    /// If all of the above hold. Otherwise the public key is taken to be `(0, 0)`, which is not on
+    /// the curve, so that the validity bit is not set.
+    pub is_in_range: T,
+    /// The public key that is checked against the curve equation. It is zero outside the setup
+    /// rows, as the memory values are.
+    pub setup_pubkey_x: Limbs<T, U32>,
+    pub setup_pubkey_y: Limbs<T, U32>,
+    pub(crate) yy: FieldOpCols<T, BaseField>,
+    pub(crate) xx: FieldOpCols<T, BaseField>,
+    pub(crate) xxx: FieldOpCols<T, BaseField>,
+    pub(crate) curve_rhs: FieldOpCols<T, BaseField>,
+    pub(crate) curve_diff: FieldOpCols<T, BaseField>,
+    pub(crate) curve_diff_range: FieldLtCols<T, BaseField>,
+    pub curve_diff_is_zero: IsZeroOperation<T>,
+    pub(crate) w: FieldOpCols<T, ScalarField>,
+    pub(crate) zw: FieldOpCols<T, ScalarField>,
+    pub(crate) rw: FieldOpCols<T, ScalarField>,
+
+    /// The result at the start of the row, which is also the point that `q` is added to.
+    pub result: MsmPointCols<T, BaseField>,
+    pub q_x: Limbs<T, U32>,
+    pub q_y: Limbs<T, U32>,
+    /// If `q` is added to the result. Otherwise the result is kept as it is.
+    pub is_enabled: T,
+    /// If the result and `q` are the same point.
+    pub is_equal: T,
+    /// If the result is the negation of `q`.
+    pub is_opposite: T,
+    /// If the sum is `q`, because the result is the point at infinity.
+    pub is_copy: T,
+    /// If the sum is `q` doubled, because the result is `q`.
+    pub is_double: T,
+    /// If the sum is the point at infinity, because the result is the negation of `q`.
+    pub is_cancel: T,
+    /// If the sum is `result + q` with the addition formulas.
+    pub is_add: T,
+    pub double_input_x: Limbs<T, U32>,
+    pub double_input_y: Limbs<T, U32>,
+    pub(crate) double: EcDoubleCols<T, BaseField>,
+    pub add_p_x: Limbs<T, U32>,
+    pub add_p_y: Limbs<T, U32>,
+    pub add_q_x: Limbs<T, U32>,
+    pub add_q_y: Limbs<T, U32>,
+    pub(crate) add: EcAddCols<T, BaseField>,
+    pub x_diff: FieldOpCols<T, BaseField>,
+    pub x_diff_inverse: FieldOpCols<T, BaseField>,
+    pub(crate) y_sum: FieldOpCols<T, BaseField>,
+    /// The sum at the end of the row.
+    pub out: MsmPointCols<T, BaseField>,
+
+    /// The comparison of the final row, where the x coordinate of the result must be reduced so
+    /// that its residue modulo the order is well defined.
+    pub(crate) result_x_range: FieldLtCols<T, BaseField>,
+    pub(crate) r_diff: FieldOpCols<T, ScalarField>,
+    pub(crate) r_diff_range: FieldLtCols<T, ScalarField>,
+    pub r_diff_is_zero: IsZeroOperation<T>,
+    /// If the result is not the point at infinity and its x coordinate is `r` modulo the order.
+    pub is_match: T,
+    pub result_access: MemoryWriteCols<T>,
+}
+
+/// Verifies ECDSA signatures on the secp256k1 curve.
+///
+/// The validity bit is set if `r` and `s` are between 1 and the order of the curve minus one, the
+/// coordinates of the public key are reduced, the public key is on the curve and the signature
+/// matches.
+#[derive(Default)]
+pub struct EcdsaVerifyChip;
+
+impl EcdsaVerifyChip {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns a row whose field operations hold with zero operands.
+    ///
+    /// The constraints of a field operation do not hold with zero columns, as its witness is
+    /// shifted, so the operations of the setup and final rows are populated in the other rows as
+    /// well, where their operands are zero.
+    fn blank_row<F: PrimeField32>() -> Vec<F> {
+        let mut row = vec![F::zero(); NUM_ECDSA_VERIFY_COLS];
+        let cols: &mut EcdsaVerifyCols<F> = row.as_mut_slice().borrow_mut();
+        let zero = BigUint::zero();
+        for (op, kind) in [
+            (&mut cols.yy, FieldOperation::Mul),
+            (&mut cols.xx, FieldOperation::Mul),
+            (&mut cols.xxx, FieldOperation::Mul),
+            (&mut cols.curve_rhs, FieldOperation::Add),
+            (&mut cols.curve_diff, FieldOperation::Sub),
+            (&mut cols.y_sum, FieldOperation::Add),
+        ] {
+            op.populate(&mut vec![], 0, 0, &zero, &zero, kind);
+        }
+        for (op, kind) in [
+            (&mut cols.w, FieldOperation::Div),
+            (&mut cols.zw, FieldOperation::Mul),
+            (&mut cols.rw, FieldOperation::Mul),
+            (&mut cols.r_diff, FieldOperation::Sub),
+        ] {
+            op.populate(&mut vec![], 0, 0, &zero, &zero, kind);
+        }
+        row
+    }
+
+    /// Populates the addition of `q` to the result `acc` if `is_enabled` is set, and returns the
+    /// sum, or `acc` otherwise.
+    fn populate_accumulate<F: PrimeField32>(
+        cols: &mut EcdsaVerifyCols<F>,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        event: &EcdsaVerifyEvent,
+        acc: &Option<Point>,
+        q: &Option<Point>,
+        is_enabled: bool,
+    ) -> Option<Point> {
+        let (acc_x, acc_y) = coordinates(acc);
+        let (q_x, q_y) = coordinates(q);
+        cols.result.populate(acc);
+        cols.q_x = BaseField::to_limbs_field::<F, _>(&q_x);
+        cols.q_y = BaseField::to_limbs_field::<F, _>(&q_y);
+
+        let y_sum = cols.y_sum.populate(
+            blu_events,
+            event.shard,
+            event.channel,
+            &acc_y,
+            &q_y,
+            FieldOperation::Add,
+        );
+        let is_equal = acc_x == q_x && acc_y == q_y;
+        let is_opposite = acc_x == q_x && !is_equal && y_sum.is_zero();
+        let is_copy = is_enabled && acc.is_none();
+        let is_double = is_enabled && !is_copy && is_equal;
+        let is_cancel = is_enabled && !is_copy && !is_double && is_opposite;
+        let is_add = is_enabled && !is_copy && !is_double && !is_cancel;
+        cols.is_enabled = F::from_bool(is_enabled);
+        cols.is_equal = F::from_bool(is_equal);
+        cols.is_opposite = F::from_bool(is_opposite);
+        cols.is_copy = F::from_bool(is_copy);
+        cols.is_double = F::from_bool(is_double);
+        cols.is_cancel = F::from_bool(is_cancel);
+        cols.is_add = F::from_bool(is_add);
+
+        let (double_input_x, double_input_y) =
+            if is_double { (q_x.clone(), q_y.clone()) } else { (BigUint::zero(), BigUint::one()) };
+        let doubled = Self::populate_double(
+            cols,
+            blu_events,
+            event.shard,
+            event.channel,
+            &double_input_x,
+            &double_input_y,
+        );
+
+        let (add_p_x, add_p_y, add_q_x, add_q_y) = if is_add {
+            (acc_x, acc_y, q_x, q_y)
+        } else {
+            (BigUint::zero(), BigUint::zero(), BigUint::one(), BigUint::zero())
+        };
+        let added = Self::populate_add(
+            cols,
+            blu_events,
+            event.shard,
+            event.channel,
+            &add_p_x,
+            &add_p_y,
+            &add_q_x,
+            &add_q_y,
+        );
+
+        let out = if is_copy {
+            q.clone()
+        } else if is_double {
+            Some(Point::new(doubled.0, doubled.1))
+        } else if is_cancel {
+            None
+        } else if is_add {
+            Some(Point::new(added.0, added.1))
+        } else {
+            acc.clone()
+        };
+        cols.out.populate(&out);
+        out
+    }
+
+    fn populate_double<F: PrimeField32>(
+        cols: &mut EcdsaVerifyCols<F>,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        x: &BigUint,
+        y: &BigUint,
+    ) -> (BigUint, BigUint) {
+        cols.double_input_x = BaseField::to_limbs_field::<F, _>(x);
+        cols.double_input_y = BaseField::to_limbs_field::<F, _>(y);
+        cols.double.populate(blu_events, shard, channel, x, y, &Secp256k1::a_int())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn populate_add<F: PrimeField32>(
+        cols: &mut EcdsaVerifyCols<F>,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        p_x: &BigUint,
+        p_y: &BigUint,
+        q_x: &BigUint,
+        q_y: &BigUint,
+    ) -> (BigUint, BigUint) {
+        cols.add_p_x = BaseField::to_limbs_field::<F, _>(p_x);
+        cols.add_p_y = BaseField::to_limbs_field::<F, _>(p_y);
+        cols.add_q_x = BaseField::to_limbs_field::<F, _>(q_x);
+        cols.add_q_y = BaseField::to_limbs_field::<F, _>(q_y);
+        let x_diff =
+            cols.x_diff.populate(blu_events, shard, channel, q_x, p_x, FieldOperation::Sub);
+        cols.x_diff_inverse.populate(
+            blu_events,
+            shard,
+            channel,
+            &BigUint::one(),
+            &x_diff,
+            FieldOperation::Div,
+        );
+        cols.add.populate(blu_events, shard, channel, p_x, p_y, q_x, q_y)
+    }
+
+    /// Populates the setup row, and returns the public key, which is `(0, 0)` if the input is not
+    /// in range, whether it is on the curve and the scalars `u1` and `u2`.
+    fn populate_setup<F: PrimeField32>(
+        cols: &mut EcdsaVerifyCols<F>,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        event: &EcdsaVerifyEvent,
+    ) -> (Point, bool, BigUint, BigUint) {
+        let (shard, channel) = (event.shard, event.channel);
+        let [r, s, z, pubkey_x, pubkey_y] =
+            core::array::from_fn(|i| BigUint::from_slice(&event.input[8 * i..8 * (i + 1)]));
+        for (access, record) in cols.input_access.iter_mut().zip(&event.input_memory_records) {
+            access.populate(channel, *record, blu_events);
+        }
+
+        // The scalars must be nonzero and less than the order, and the public key reduced.
+        let order = ScalarField::modulus();
+        let modulus = BaseField::modulus();
+        let limb_sum = |x: &BigUint| x.to_bytes_le().iter().map(|&byte| byte as u32).sum::<u32>();
+        let r_is_less = cols.r_range.populate(blu_events, shard, channel, &r, &order);
+        let s_is_less = cols.s_range.populate(blu_events, shard, channel, &s, &order);
+        let r_is_zero = cols.r_is_zero.populate(limb_sum(&r)) == 1;
+        let s_is_zero = cols.s_is_zero.populate(limb_sum(&s)) == 1;
+        let x_is_less =
+            cols.pubkey_x_range.populate(blu_events, shard, channel, &pubkey_x, &modulus);
+        let y_is_less =
+            cols.pubkey_y_range.populate(blu_events, shard, channel, &pubkey_y, &modulus);
+        let is_r_valid = r_is_less && !r_is_zero;
+        let is_s_valid = s_is_less && !s_is_zero;
+        let is_pubkey_reduced = x_is_less && y_is_less;
+        let is_in_range = is_r_valid && is_s_valid && is_pubkey_reduced;
+        cols.is_r_valid = F::from_bool(is_r_valid);
+        cols.is_s_valid = F::from_bool(is_s_valid);
+        cols.is_pubkey_reduced = F::from_bool(is_pubkey_reduced);
+        cols.is_in_range = F::from_bool(is_in_range);
+        let (pubkey_x, pubkey_y) =
+            if is_in_range { (pubkey_x, pubkey_y) } else { (BigUint::zero(), BigUint::zero()) };
+        cols.setup_pubkey_x = BaseField::to_limbs_field::<F, _>(&pubkey_x);
+        cols.setup_pubkey_y = BaseField::to_limbs_field::<F, _>(&pubkey_y);
+
+        // The public key is on the curve if `y^2 - (x^3 + 7)` is zero.
+        let yy =
+            cols.yy.populate(blu_events, shard, channel, &pubkey_y, &pubkey_y, FieldOperation::Mul);
+        let xx =
+            cols.xx.populate(blu_events, shard, channel, &pubkey_x, &pubkey_x, FieldOperation::Mul);
+        let xxx =
+            cols.xxx.populate(blu_events, shard, channel, &xx, &pubkey_x, FieldOperation::Mul);
+        let curve_rhs = cols.curve_rhs.populate(
+            blu_events,
+            shard,
+            channel,
+            &xxx,
+            &Secp256k1::b_int(),
+            FieldOperation::Add,
+        );
+        let curve_diff = cols.curve_diff.populate(
+            blu_events,
+            shard,
+            channel,
+            &yy,
+            &curve_rhs,
+            FieldOperation::Sub,
+        );
+        cols.curve_diff_range.populate(blu_events, shard, channel, &curve_diff, &modulus);
+        let is_on_curve = cols.curve_diff_is_zero.populate(limb_sum(&curve_diff)) == 1;
+
+        // The scalars are zero if the input is not in range, as `s` may not be invertible.
+        let dividend = BigUint::from(is_in_range as u32);
+        let w = cols.w.populate(blu_events, shard, channel, &dividend, &s, FieldOperation::Div);
+        let u1 = cols.zw.populate(blu_events, shard, channel, &z, &w, FieldOperation::Mul);
+        let u2 = cols.rw.populate(blu_events, shard, channel, &r, &w, FieldOperation::Mul);
+        (Point::new(pubkey_x, pubkey_y), is_on_curve, u1, u2)
+    }
+
+    fn event_rows<F: PrimeField32>(
+        event: &EcdsaVerifyEvent,
+        blu_events: &mut Vec<ByteLookupEvent>,
+    ) -> Vec<Vec<F>> {
+        let r = BigUint::from_slice(&event.input[0..8]);
+        let generator = Some(Secp256k1::generator());
+
+        let mut rows = Vec::with_capacity(ECDSA_VERIFY_ROWS);
+        let mut setup_row = Self::blank_row::<F>();
+        let cols: &mut EcdsaVerifyCols<F> = setup_row.as_mut_slice().borrow_mut();
+        let (pubkey, is_on_curve, u1, u2) = Self::populate_setup(cols, blu_events, event);
+        let (pubkey_x, pubkey_y) = (pubkey.x.clone(), pubkey.y.clone());
+        let pubkey = Some(pubkey);
+        let sum =
+            Self::populate_accumulate(cols, blu_events, event, &generator, &pubkey, is_on_curve);
+        let mut u1_bytes = u1.to_bytes_le();
+        u1_bytes.resize(NUM_SCALAR_BYTES, 0);
+        let mut u2_bytes = u2.to_bytes_le();
+        u2_bytes.resize(NUM_SCALAR_BYTES, 0);
+
+        // Fills in the values that stay the same throughout the rows of the syscall.
+        let fill_row = |row: &mut [F]| {
+            let cols: &mut EcdsaVerifyCols<F> = row.borrow_mut();
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u8(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.ptr = F::from_canonical_u32(event.ptr);
+            cols.r = ScalarField::to_limbs_field::<F, _>(&r);
+            cols.u1 = ScalarField::to_limbs_field::<F, _>(&u1);
+            cols.u2 = ScalarField::to_limbs_field::<F, _>(&u2);
+            cols.pubkey_x = BaseField::to_limbs_field::<F, _>(&pubkey_x);
+            cols.pubkey_y = BaseField::to_limbs_field::<F, _>(&pubkey_y);
+            cols.is_on_curve = F::from_bool(is_on_curve);
+            cols.sum.populate(&sum);
+        };
+        fill_row(&mut setup_row);
+        let cols: &mut EcdsaVerifyCols<F> = setup_row.as_mut_slice().borrow_mut();
+        cols.is_setup = F::one();
+        rows.push(setup_row);
+
+        // Double the result and add the points selected by the bits, most significant first.
+        let mut result: Option<Point> = None;
+        for j in 0..NUM_SCALAR_BYTES {
+            let (u1_byte, u2_byte) =
+                (u1_bytes[NUM_SCALAR_BYTES - 1 - j], u2_bytes[NUM_SCALAR_BYTES - 1 - j]);
+            for k in 0..8 {
+                let u1_bit = (u1_byte >> (7 - k)) & 1 == 1;
+                let u2_bit = (u2_byte >> (7 - k)) & 1 == 1;
+                let new_row = || {
+                    let mut row = Self::blank_row::<F>();
+                    fill_row(&mut row);
+                    let cols: &mut EcdsaVerifyCols<F> = row.as_mut_slice().borrow_mut();
+                    cols.byte_flags[j] = F::one();
+                    cols.bit_flags[k] = F::one();
+                    cols.u1_partial = F::from_canonical_u8(((u1_byte as u16) >> (8 - k)) as u8);
+                    cols.u2_partial = F::from_canonical_u8(((u2_byte as u16) >> (8 - k)) as u8);
+                    cols.u1_byte = F::from_canonical_u8(u1_byte);
+                    cols.u2_byte = F::from_canonical_u8(u2_byte);
+                    row
+                };
+
+                let mut row = new_row();
+                let cols: &mut EcdsaVerifyCols<F> = row.as_mut_slice().borrow_mut();
+                cols.is_doubling = F::one();
+                result = Self::populate_accumulate(
+                    cols,
+                    blu_events,
+                    event,
+                    &result,
+                    &result,
+                    result.is_some(),
+                );
+                rows.push(row);
+
+                let mut row = new_row();
+                let cols: &mut EcdsaVerifyCols<F> = row.as_mut_slice().borrow_mut();
+                cols.is_adding = F::one();
+                cols.u1_bit = F::from_bool(u1_bit);
+                cols.u2_bit = F::from_bool(u2_bit);
+                cols.both_bits = F::from_bool(u1_bit && u2_bit);
+                let q = match (u1_bit, u2_bit) {
+                    (true, false) => generator.clone(),
+                    (false, true) => pubkey.clone(),
+                    (true, true) => sum.clone(),
+                    (false, false) => None,
+                };
+                let is_selected = q.is_some() && (u1_bit || u2_bit);
+                cols.is_selected = F::from_bool(is_selected);
+                result = Self::populate_accumulate(
+                    cols,
+                    blu_events,
+                    event,
+                    &result,
+                    &q,
+                    is_on_curve && is_selected,
+                );
+                rows.push(row);
+            }
+        }
+
+        // Compare the x coordinate of the result with `r`, and write the validity bit.
+        let mut row = Self::blank_row::<F>();
+        fill_row(&mut row);
+        let cols: &mut EcdsaVerifyCols<F> = row.as_mut_slice().borrow_mut();
+        cols.is_final = F::one();
+        Self::populate_accumulate(cols, blu_events, event, &result, &None, false);
+        let (result_x, _) = coordinates(&result);
+        let (shard, channel) = (event.shard, event.channel);
+        cols.result_x_range.populate(blu_events, shard, channel, &result_x, &BaseField::modulus());
+        let r_diff =
+            cols.r_diff.populate(blu_events, shard, channel, &result_x, &r, FieldOperation::Sub);
+        cols.r_diff_range.populate(blu_events, shard, channel, &r_diff, &ScalarField::modulus());
+        let r_diff_sum = r_diff.to_bytes_le().iter().map(|&byte| byte as u32).sum::<u32>();
+        let is_match = result.is_some() && cols.r_diff_is_zero.populate(r_diff_sum) == 1;
+        cols.is_match = F::from_bool(is_match);
+        debug_assert_eq!(is_on_curve && is_match, event.is_valid);
+        cols.result_access.populate(channel, event.result_memory_record, blu_events);
+        rows.push(row);
+
+        rows
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for EcdsaVerifyChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Secp256k1EcdsaVerify".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = &input.secp256k1_ecdsa_verify_events;
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        // Generate the trace rows & corresponding records for each chunk of events in parallel.
+        let rows_and_records = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut record = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+                let rows = events
+                    .iter()
+                    .flat_map(|event| Self::event_rows::<F>(event, &mut new_byte_lookup_events))
+                    .collect::<Vec<_>>();
+                record.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, record)
+            })
+            .collect::<Vec<_>>();
+
+        let mut rows = Vec::new();
+        for mut row_and_record in rows_and_records {
+            rows.extend(row_and_record.0);
+            output.append(&mut row_and_record.1);
+        }
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = Self::blank_row::<F>();
+            let cols: &mut EcdsaVerifyCols<F> = row.as_mut_slice().borrow_mut();
+            // Padding rows double `(0, 1)` and add `(0, 0)` and `(1, 0)`, so that the slopes and
+            // the inverse of the difference of the x coordinates are well defined.
+            let zero = BigUint::zero();
+            let one = BigUint::one();
+            Self::populate_double(cols, &mut vec![], 0, 0, &zero, &one);
+            Self::populate_add(cols, &mut vec![], 0, 0, &zero, &zero, &one, &zero);
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_ECDSA_VERIFY_COLS,
+        );
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut EcdsaVerifyCols<F> = trace.values
+                [i * NUM_ECDSA_VERIFY_COLS..(i + 1) * NUM_ECDSA_VERIFY_COLS]
+                .borrow_mut();
+            if i > 0 && cols.is_setup == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.secp256k1_ecdsa_verify_events.is_empty()
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F> BaseAir<F> for EcdsaVerifyChip {
+    fn width(&self) -> usize {
+        NUM_ECDSA_VERIFY_COLS
+    }
+}
+
+impl<AB> Air<AB> for EcdsaVerifyChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &EcdsaVerifyCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &EcdsaVerifyCols<AB::Var> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans several rows, so the nonce counts the syscalls
+        // rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_setup, next.nonce);
+
+        self.eval_flags(builder, local, next);
+
+        // Constrain that the inputs stay the same throughout the rows of each syscall. The sum of
+        // the generator and the public key is the sum of the setup row.
+        let not_final = local.is_real - local.is_final;
+        let mut transition_builder = builder.when_transition();
+        let mut carry_builder = transition_builder.when(not_final.clone());
+        carry_builder.assert_eq(local.shard, next.shard);
+        carry_builder.assert_eq(local.channel, next.channel);
+        carry_builder.assert_eq(local.clk, next.clk);
+        carry_builder.assert_eq(local.ptr, next.ptr);
+        carry_builder.assert_eq(local.is_on_curve, next.is_on_curve);
+        carry_builder.assert_all_eq(local.r, next.r);
+        carry_builder.assert_all_eq(local.u1, next.u1);
+        carry_builder.assert_all_eq(local.u2, next.u2);
+        carry_builder.assert_all_eq(local.pubkey_x, next.pubkey_x);
+        carry_builder.assert_all_eq(local.pubkey_y, next.pubkey_y);
+        let mut sum_builder = transition_builder.when(not_final - local.is_setup);
+        sum_builder.assert_eq(local.sum.is_infinity, next.sum.is_infinity);
+        sum_builder.assert_all_eq(local.sum.x, next.sum.x);
+        sum_builder.assert_all_eq(local.sum.y, next.sum.y);
+        let mut setup_builder = transition_builder.when(local.is_setup);
+        setup_builder.assert_eq(local.out.is_infinity, next.sum.is_infinity);
+        setup_builder.assert_all_eq(local.out.x, next.sum.x);
+        setup_builder.assert_all_eq(local.out.y, next.sum.y);
+
+        self.eval_bits(builder, local, next);
+
+        self.eval_setup(builder, local);
+
+        self.eval_result(builder, local, next);
+
+        self.eval_accumulate(builder, local);
+
+        self.eval_final(builder, local);
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::SECP256K1_ECDSA_VERIFY.syscall_id()),
+            local.ptr,
+            AB::Expr::zero(),
+            local.is_setup,
+        );
+    }
+}
+
+impl EcdsaVerifyChip {
+    /// Constrains the order of the rows: a setup row, a doubling and an adding row per bit, most
+    /// significant first, and a final row.
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &EcdsaVerifyCols<AB::Var>,
+        next: &EcdsaVerifyCols<AB::Var>,
+    ) {
+        let sum_flags =
+            |flags: &[AB::Var]| flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_setup);
+        builder.assert_bool(local.is_doubling);
+        builder.assert_bool(local.is_adding);
+        builder.assert_bool(local.is_final);
+        for &flag in local.byte_flags.iter().chain(local.bit_flags.iter()) {
+            builder.assert_bool(flag);
+        }
+        builder.assert_eq(
+            local.is_setup + local.is_doubling + local.is_adding + local.is_final,
+            local.is_real,
+        );
+        builder.assert_eq(sum_flags(&local.byte_flags), local.is_doubling + local.is_adding);
+        builder.assert_eq(sum_flags(&local.bit_flags), local.is_doubling + local.is_adding);
+
+        // A syscall starts in the first row, or after the final row of the previous syscall, and
+        // the padding rows come last.
+        builder.when_first_row().assert_eq(local.is_real, local.is_setup);
+        builder.when_transition().when(local.is_final).assert_eq(next.is_real, next.is_setup);
+        builder.when_transition().when(AB::Expr::one() - local.is_real).assert_zero(next.is_real);
+        builder.when_last_row().assert_eq(local.is_real, local.is_final);
+
+        // The setup row is followed by the doubling row of the first bit, and each doubling row by
+        // the adding row of the same bit.
+        let mut setup_builder = builder.when_transition();
+        let mut setup_builder = setup_builder.when(local.is_setup);
+        setup_builder.assert_one(next.is_doubling);
+        setup_builder.assert_one(next.byte_flags[0]);
+        setup_builder.assert_one(next.bit_flags[0]);
+        let mut doubling_builder = builder.when_transition();
+        let mut doubling_builder = doubling_builder.when(local.is_doubling);
+        doubling_builder.assert_one(next.is_adding);
+        doubling_builder.assert_all_eq(local.byte_flags, next.byte_flags);
+        doubling_builder.assert_all_eq(local.bit_flags, next.bit_flags);
+
+        // The adding row of the last bit is followed by the final row, and the others by the
+        // doubling row of the next bit.
+        let is_last_bit = local.byte_flags[NUM_SCALAR_BYTES - 1] * local.bit_flags[7];
+        let mut adding_builder = builder.when_transition();
+        let mut adding_builder = adding_builder.when(local.is_adding);
+        adding_builder.assert_eq(next.is_final, is_last_bit.clone());
+        adding_builder.assert_eq(next.is_doubling, AB::Expr::one() - is_last_bit.clone());
+        for i in 0..8 {
+            builder
+                .when_transition()
+                .when(local.is_adding - is_last_bit.clone())
+                .assert_eq(local.bit_flags[i], next.bit_flags[(i + 1) % 8]);
+        }
+        for j in 0..NUM_SCALAR_BYTES {
+            builder
+                .when_transition()
+                .when(local.is_adding)
+                .when_not(local.bit_flags[7])
+                .assert_eq(local.byte_flags[j], next.byte_flags[j]);
+        }
+        for j in 0..NUM_SCALAR_BYTES - 1 {
+            builder
+                .when_transition()
+                .when(local.is_adding)
+                .when(local.bit_flags[7])
+                .assert_eq(local.byte_flags[j], next.byte_flags[j + 1]);
+        }
+    }
+
+    /// Constrains the bits of the adding rows to be the bits of `u1` and `u2`, most significant
+    /// first. The bits of each byte are accumulated, and compared with the byte after its last bit.
+    fn eval_bits<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &EcdsaVerifyCols<AB::Var>,
+        next: &EcdsaVerifyCols<AB::Var>,
+    ) {
+        builder.assert_bool(local.u1_bit);
+        builder.assert_bool(local.u2_bit);
+        builder.assert_eq(local.both_bits, local.u1_bit * local.u2_bit);
+        builder.when_not(local.is_adding).assert_zero(local.u1_bit);
+        builder.when_not(local.is_adding).assert_zero(local.u2_bit);
+
+        for (partial, next_partial, bit, byte, scalar) in [
+            (local.u1_partial, next.u1_partial, local.u1_bit, local.u1_byte, &local.u1),
+            (local.u2_partial, next.u2_partial, local.u2_bit, local.u2_byte, &local.u2),
+        ] {
+            let selected_byte =
+                local.byte_flags.iter().enumerate().fold(AB::Expr::zero(), |acc, (j, &flag)| {
+                    acc + flag * scalar[NUM_SCALAR_BYTES - 1 - j]
+                });
+            builder.assert_eq(byte, selected_byte);
+
+            let partial_after = partial * AB::F::two() + bit;
+            builder.when_transition().when(local.is_setup).assert_zero(next_partial);
+            builder.when_transition().when(local.is_doubling).assert_eq(partial, next_partial);
+            builder
+                .when_transition()
+                .when(local.is_adding)
+                .when_not(local.bit_flags[7])
+                .assert_eq(partial_after.clone(), next_partial);
+            builder.when(local.is_adding).when(local.bit_flags[7]).assert_eq(partial_after, byte);
+            builder
+                .when_transition()
+                .when(local.is_adding)
+                .when(local.bit_flags[7])
+                .assert_zero(next_partial);
+        }
+    }
+
+    /// Constrains the setup row: the reads of the input, the range checks of the scalars and of
+    /// the public key, the curve equation and the scalars `u1 = z / s` and `u2 = r / s`.
+    ///
+    /// The operands of the field operations are zero outside the setup rows, as the memory values
+    /// are, so that the operations hold with zero operands in the other rows.
+    fn eval_setup<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &EcdsaVerifyCols<AB::Var>) {
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr,
+            &local.input_access,
+            local.is_setup,
+        );
+        let r: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[0..8]);
+        let s: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[8..16]);
+        let z: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[16..24]);
+        let pubkey_x: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[24..32]);
+        let pubkey_y: Limbs<AB::Var, U32> = limbs_from_access(&local.input_access[32..40]);
+        builder.when(local.is_setup).assert_all_eq(local.r, r);
+
+        // The scalars must be nonzero and less than the order, and the public key reduced. The
+        // flags only matter in the setup row, so their constraints are not gated.
+        let order = ScalarField::modulus();
+        let modulus = BaseField::modulus();
+        let (shard, channel, is_setup) = (local.shard, local.channel, local.is_setup);
+        local.r_range.eval(builder, &r, &order, shard, channel, is_setup);
+        local.s_range.eval(builder, &s, &order, shard, channel, is_setup);
+        let limb_sum = |limbs: &Limbs<AB::Var, U32>| {
+            limbs.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb)
+        };
+        IsZeroOperation::<AB::F>::eval(builder, limb_sum(&r), local.r_is_zero, is_setup.into());
+        IsZeroOperation::<AB::F>::eval(builder, limb_sum(&s), local.s_is_zero, is_setup.into());
+        local.pubkey_x_range.eval(builder, &pubkey_x, &modulus, shard, channel, is_setup);
+        local.pubkey_y_range.eval(builder, &pubkey_y, &modulus, shard, channel, is_setup);
+        builder.assert_eq(
+            local.is_r_valid,
+            local.r_range.is_less * (AB::Expr::one() - local.r_is_zero.result),
+        );
+        builder.assert_eq(
+            local.is_s_valid,
+            local.s_range.is_less * (AB::Expr::one() - local.s_is_zero.result),
+        );
+        builder.assert_eq(
+            local.is_pubkey_reduced,
+            local.pubkey_x_range.is_less * local.pubkey_y_range.is_less,
+        );
+        builder.assert_eq(
+            local.is_in_range,
+            local.is_r_valid * local.is_s_valid * local.is_pubkey_reduced,
+        );
+
+        // The public key is `(0, 0)` if the input is not in range.
+        for i in 0..BaseField::NB_LIMBS {
+            builder.assert_eq(local.setup_pubkey_x[i], local.is_in_range * pubkey_x[i]);
+            builder.assert_eq(local.setup_pubkey_y[i], local.is_in_range * pubkey_y[i]);
+        }
+        builder.when(local.is_setup).assert_all_eq(local.pubkey_x, local.setup_pubkey_x);
+        builder.when(local.is_setup).assert_all_eq(local.pubkey_y, local.setup_pubkey_y);
+        let (pubkey_x, pubkey_y) = (local.setup_pubkey_x, local.setup_pubkey_y);
+
+        // The public key is on the curve if `y^2 - (x^3 + 7)` is zero. The constant is only added
+        // in the setup row.
+        local.yy.eval(builder, &pubkey_y, &pubkey_y, FieldOperation::Mul, shard, channel, is_setup);
+        local.xx.eval(builder, &pubkey_x, &pubkey_x, FieldOperation::Mul, shard, channel, is_setup);
+        local.xxx.eval(
+            builder,
+            &local.xx.result,
+            &pubkey_x,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_setup,
+        );
+        let b = Polynomial::<AB::Expr>::from(BaseField::to_limbs_field::<AB::Expr, _>(
+            &Secp256k1::b_int(),
+        )) * AB::Expr::from(local.is_setup);
+        local.curve_rhs.eval(
+            builder,
+            &local.xxx.result,
+            &b,
+            FieldOperation::Add,
+            shard,
+            channel,
+            is_setup,
+        );
+        local.curve_diff.eval(
+            builder,
+            &local.yy.result,
+            &local.curve_rhs.result,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_setup,
+        );
+        local.curve_diff_range.eval(
+            builder,
+            &local.curve_diff.result,
+            &BaseField::to_limbs_field::<AB::Expr, _>(&modulus),
+            shard,
+            channel,
+            is_setup,
+        );
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            limb_sum(&local.curve_diff.result),
+            local.curve_diff_is_zero,
+            is_setup.into(),
+        );
+        builder.when(is_setup).assert_eq(local.is_on_curve, local.curve_diff_is_zero.result);
+
+        // `w = 1 / s`, where the dividend is only one in the setup row of an input in range, so
+        // that the scalars are zero if `s` is not invertible.
+        let one = Polynomial::<AB::Expr>::from(ScalarField::to_limbs_field::<AB::Expr, _>(
+            &BigUint::one(),
+        )) * (local.is_setup * local.is_in_range);
+        local.w.eval(builder, &one, &s, FieldOperation::Div, shard, channel, is_setup);
+        local.zw.eval(builder, &z, &local.w.result, FieldOperation::Mul, shard, channel, is_setup);
+        local.rw.eval(builder, &r, &local.w.result, FieldOperation::Mul, shard, channel, is_setup);
+        builder.when(is_setup).assert_all_eq(local.u1, local.zw.result);
+        builder.when(is_setup).assert_all_eq(local.u2, local.rw.result);
+    }
+
+    /// Constrains the result from one row to the next, and the point `q` added to it.
+    ///
+    /// The result is the generator in the setup row, so that the setup row adds the public key to
+    /// it, and it starts at the point at infinity in the first doubling row.
+    fn eval_result<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &EcdsaVerifyCols<AB::Var>,
+        next: &EcdsaVerifyCols<AB::Var>,
+    ) {
+        let generator = Secp256k1::generator();
+        let g_x = BaseField::to_limbs_field::<AB::Expr, _>(&generator.x);
+        let g_y = BaseField::to_limbs_field::<AB::Expr, _>(&generator.y);
+
+        builder.assert_bool(local.result.is_infinity);
+        builder.when(local.is_setup).assert_zero(local.result.is_infinity);
+        builder.when(local.is_setup).assert_all_eq(local.result.x, g_x.clone());
+        builder.when(local.is_setup).assert_all_eq(local.result.y, g_y.clone());
+        builder.when_transition().when(local.is_setup).assert_one(next.result.is_infinity);
+
+        let mut update_builder = builder.when_transition();
+        let mut update_builder = update_builder.when(local.is_doubling + local.is_adding);
+        update_builder.assert_eq(local.out.is_infinity, next.result.is_infinity);
+        update_builder.assert_all_eq(local.out.x, next.result.x);
+        update_builder.assert_all_eq(local.out.y, next.result.y);
+
+        // The setup row adds the public key, a doubling row doubles the result, and an adding row
+        // adds `G`, `Q` or `G + Q` for the bits `(1, 0)`, `(0, 1)` and `(1, 1)`.
+        let only_u1 = local.u1_bit - local.both_bits;
+        let only_u2 = local.u2_bit - local.both_bits;
+        for i in 0..BaseField::NB_LIMBS {
+            builder.assert_eq(
+                local.q_x[i],
+                local.is_setup * local.pubkey_x[i]
+                    + local.is_doubling * local.result.x[i]
+                    + local.is_adding
+                        * (only_u1.clone() * g_x[i].clone()
+                            + only_u2.clone() * local.pubkey_x[i]
+                            + local.both_bits * local.sum.x[i]),
+            );
+            builder.assert_eq(
+                local.q_y[i],
+                local.is_setup * local.pubkey_y[i]
+                    + local.is_doubling * local.result.y[i]
+                    + local.is_adding
+                        * (only_u1.clone() * g_y[i].clone()
+                            + only_u2.clone() * local.pubkey_y[i]
+                            + local.both_bits * local.sum.y[i]),
+            );
+        }
+
+        // Nothing is added if the public key is not on the curve, so that the addition formulas
+        // only ever see points of the curve.
+        builder.when(local.is_adding).assert_eq(
+            local.is_selected,
+            local.u1_bit + local.u2_bit - local.both_bits - local.both_bits * local.sum.is_infinity,
+        );
+        builder.assert_eq(
+            local.is_enabled,
+            local.is_setup * local.is_on_curve
+                + local.is_doubling * (AB::Expr::one() - local.result.is_infinity)
+                + local.is_adding * local.is_on_curve * local.is_selected,
+        );
+    }
+
+    /// Constrains the addition of the row, `result + q`.
+    fn eval_accumulate<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &EcdsaVerifyCols<AB::Var>,
+    ) {
+        // Pick the formulas. Two points with the same x coordinate are either equal, or the
+        // negation of each other, in which case their y coordinates add up to zero.
+        builder.assert_bool(local.is_equal);
+        builder.assert_bool(local.is_opposite);
+        builder.assert_eq(local.is_copy, local.is_enabled * local.result.is_infinity);
+        builder.assert_eq(local.is_double, (local.is_enabled - local.is_copy) * local.is_equal);
+        builder.assert_eq(
+            local.is_cancel,
+            (local.is_enabled - local.is_copy - local.is_double) * local.is_opposite,
+        );
+        builder.assert_eq(
+            local.is_add,
+            local.is_enabled - local.is_copy - local.is_double - local.is_cancel,
+        );
+        builder.when(local.is_equal).assert_all_eq(local.result.x, local.q_x);
+        builder.when(local.is_equal).assert_all_eq(local.result.y, local.q_y);
+        builder.when(local.is_opposite).assert_all_eq(local.result.x, local.q_x);
+        builder.when(local.is_opposite).assert_all_zero(local.y_sum.result);
+        builder.when(local.is_double).assert_all_eq(local.double_input_x, local.q_x);
+        builder.when(local.is_double).assert_all_eq(local.double_input_y, local.q_y);
+        builder.when(local.is_add).assert_all_eq(local.add_p_x, local.result.x);
+        builder.when(local.is_add).assert_all_eq(local.add_p_y, local.result.y);
+        builder.when(local.is_add).assert_all_eq(local.add_q_x, local.q_x);
+        builder.when(local.is_add).assert_all_eq(local.add_q_y, local.q_y);
+
+        local.y_sum.eval(
+            builder,
+            &local.result.y,
+            &local.q_y,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        let a = BaseField::to_limbs_field::<AB::Expr, _>(&Secp256k1::a_int());
+        local.double.eval(
+            builder,
+            &local.double_input_x,
+            &local.double_input_y,
+            &a,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.add.eval(
+            builder,
+            &local.add_p_x,
+            &local.add_p_y,
+            &local.add_q_x,
+            &local.add_q_y,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x_diff.eval(
+            builder,
+            &local.add_q_x,
+            &local.add_p_x,
+            FieldOperation::Sub,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x_diff_inverse.eval(
+            builder,
+            &BaseField::to_limbs_field::<AB::Expr, _>(&BigUint::one()),
+            &local.x_diff.result,
+            FieldOperation::Div,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // The sum is `q`, `q` doubled, the point at infinity, `result + q`, or the result if `q` is
+        // not added.
+        let (doubled_x, doubled_y) = local.double.result();
+        let (added_x, added_y) = local.add.result();
+        let is_kept = AB::Expr::one() - local.is_enabled;
+        builder.assert_eq(
+            local.out.is_infinity,
+            is_kept.clone() * local.result.is_infinity + local.is_cancel,
+        );
+        for i in 0..BaseField::NB_LIMBS {
+            builder.assert_eq(
+                local.out.x[i],
+                local.is_copy * local.q_x[i]
+                    + local.is_double * doubled_x[i]
+                    + local.is_add * added_x[i]
+                    + is_kept.clone() * local.result.x[i],
+            );
+            builder.assert_eq(
+                local.out.y[i],
+                local.is_copy * local.q_y[i]
+                    + local.is_double * doubled_y[i]
+                    + local.is_add * added_y[i]
+                    + is_kept.clone() * local.result.y[i],
+            );
+        }
+    }
+
+    /// Constrains the final row: the x coordinate of the result is compared with `r` modulo the
+    /// order, and the validity bit is written after the input.
+    fn eval_final<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &EcdsaVerifyCols<AB::Var>) {
+        let (shard, channel, is_final) = (local.shard, local.channel, local.is_final);
+        local.result_x_range.eval(
+            builder,
+            &local.result.x,
+            &BaseField::to_limbs_field::<AB::Expr, _>(&BaseField::modulus()),
+            shard,
+            channel,
+            is_final,
+        );
+
+        // The operands of the difference are only nonzero in the final row.
+        let gated = |limbs: Limbs<AB::Var, U32>| {
+            Polynomial::<AB::Expr>::from(limbs) * AB::Expr::from(local.is_final)
+        };
+        local.r_diff.eval(
+            builder,
+            &gated(local.result.x),
+            &gated(local.r),
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_final,
+        );
+        local.r_diff_range.eval(
+            builder,
+            &local.r_diff.result,
+            &ScalarField::to_limbs_field::<AB::Expr, _>(&ScalarField::modulus()),
+            shard,
+            channel,
+            is_final,
+        );
+        let r_diff_sum =
+            local.r_diff.result.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
+        IsZeroOperation::<AB::F>::eval(builder, r_diff_sum, local.r_diff_is_zero, is_final.into());
+        builder.when(is_final).assert_eq(
+            local.is_match,
+            (AB::Expr::one() - local.result.is_infinity) * local.r_diff_is_zero.result,
+        );
+
+        builder.eval_memory_access(
+            shard,
+            channel,
+            local.clk + AB::F::one(),
+            local.ptr + AB::F::from_canonical_usize(ECDSA_VERIFY_INPUT_WORDS * 4),
+            &local.result_access,
+            is_final,
+        );
+        let value = local.result_access.value();
+        builder.when(is_final).assert_eq(value[0], local.is_on_curve * local.is_match);
+        for i in 1..4 {
+            builder.when(is_final).assert_zero(value[i]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature as K256Signature, SigningKey};
+    use num::BigUint;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        params::FieldParameters,
+        weierstrass::secp256k1::{Secp256k1, Secp256k1BaseField, Secp256k1ScalarField},
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, store_words, words_at},
+    };
+
+    const PTR: u32 = 100;
+
+    /// The size in bytes of the input and the result of a syscall.
+    const SYSCALL_SIZE: u32 = 164;
+
+    /// A signature `(r, s)` of the message hash `z` for the public key `(q_x, q_y)`.
+    #[derive(Clone)]
+    struct Signature {
+        r: BigUint,
+        s: BigUint,
+        z: BigUint,
+        q_x: BigUint,
+        q_y: BigUint,
+    }
+
+    /// Signs `z` with the secret key and the nonce `k`.
+    fn sign(secret: &BigUint, k: u32, z: &BigUint) -> Signature {
+        let order = Secp256k1ScalarField::modulus();
+        let point = Secp256k1::generator().sw_scalar_mul(&k.into());
+        let r = point.x % &order;
+        let k_inverse = BigUint::from(k).modpow(&(&order - 2u32), &order);
+        let s = k_inverse * (z + &r * secret) % &order;
+        let pubkey = Secp256k1::generator().sw_scalar_mul(secret);
+        Signature { r, s, z: z.clone(), q_x: pubkey.x, q_y: pubkey.y }
+    }
+
+    /// A signature made with `k256`.
+    fn k256_signature() -> Signature {
+        let signing_key = SigningKey::from_slice(&[0x2a; 32]).unwrap();
+        let prehash = [0x5c; 32];
+        let signature: K256Signature = signing_key.sign_prehash(&prehash).unwrap();
+        let (r, s) = signature.split_bytes();
+        let pubkey = signing_key.verifying_key().to_encoded_point(false);
+        Signature {
+            r: BigUint::from_bytes_be(&r),
+            s: BigUint::from_bytes_be(&s),
+            z: BigUint::from_bytes_be(&prehash),
+            q_x: BigUint::from_bytes_be(pubkey.x().unwrap()),
+            q_y: BigUint::from_bytes_be(pubkey.y().unwrap()),
+        }
+    }
+
+    /// Builds a program that verifies each signature, the inputs being laid out one after the
+    /// other from [`PTR`]. Returns the program and the pointer of each input.
+    fn ecdsa_verify_program(signatures: &[Signature]) -> (Program, Vec<u32>) {
+        let mut instructions = vec![];
+        let mut ptrs = vec![];
+        for (i, signature) in signatures.iter().enumerate() {
+            let ptr = PTR + i as u32 * SYSCALL_SIZE;
+            ptrs.push(ptr);
+            let values = [&signature.r, &signature.s, &signature.z, &signature.q_x, &signature.q_y];
+            store_words(&mut instructions, words_at(ptr, 8, &values));
+        }
+        for &ptr in ptrs.iter() {
+            call_syscall(&mut instructions, SyscallCode::SECP256K1_ECDSA_VERIFY, ptr, 0);
+        }
+        (Program::new(instructions, 0, 0), ptrs)
+    }
+
+    /// The signatures of the tests, and whether they are valid.
+    fn signatures() -> Vec<(Signature, bool)> {
+        let modulus = Secp256k1BaseField::modulus();
+        let order = Secp256k1ScalarField::modulus();
+        let z = BigUint::parse_bytes(
+            b"4b688df40bcedbe641ddb16ff0a1842d9c67ea1c3bf63f3e0471baa664531d1a",
+            16,
+        )
+        .unwrap();
+        let valid = sign(&BigUint::from(0x1234_5678u32), 0x9abc_def0, &z);
+
+        vec![
+            (valid.clone(), true),
+            (k256_signature(), true),
+            // The other `s` of the same signature is also valid.
+            (Signature { s: &order - &valid.s, ..valid.clone() }, true),
+            (Signature { z: &z + 1u32, ..valid.clone() }, false),
+            (Signature { r: &valid.r + 1u32, ..valid.clone() }, false),
+            // A public key that is not on the curve.
+            (Signature { q_y: (&valid.q_y + 1u32) % &modulus, ..valid.clone() }, false),
+            // The public key is the generator, so the setup row doubles it.
+            (sign(&BigUint::from(1u32), 0x9abc_def0, &z), true),
+            // The public key is the negation of the generator, so `G + Q` is the point at
+            // infinity.
+            (sign(&(&order - 1u32), 0x9abc_def0, &z), true),
+            // Inputs out of range are reported as invalid.
+            (Signature { r: BigUint::from(0u32), ..valid.clone() }, false),
+            (Signature { s: BigUint::from(0u32), ..valid.clone() }, false),
+            (Signature { s: order.clone(), ..valid.clone() }, false),
+            (Signature { q_x: (BigUint::from(1u32) << 256) - 1u32, ..valid.clone() }, false),
+            (Signature { q_y: modulus.clone(), ..valid.clone() }, false),
+        ]
+    }
+
+    #[test]
+    fn test_secp256k1_ecdsa_verify_execute() {
+        setup_logger();
+        let (signatures, expected): (Vec<_>, Vec<_>) = signatures().into_iter().unzip();
+        let (program, ptrs) = ecdsa_verify_program(&signatures);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        for (ptr, is_valid) in ptrs.into_iter().zip(expected) {
+            assert_eq!(runtime.word(ptr + 160), is_valid as u32);
+        }
+    }
+
+    #[test]
+    fn test_secp256k1_ecdsa_verify_prove() {
+        setup_logger();
+        let signatures =
+            signatures().into_iter().map(|(signature, _)| signature).collect::<Vec<_>>();
+        run_test::<CpuProver<_, _>>(ecdsa_verify_program(&signatures[4..]).0).unwrap();
+    }
+}
@@ -0,0 +1,847 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::marker::PhantomData;
+
+use crate::air::{MemoryAirBuilder, WordAirBuilder};
+use generic_array::GenericArray;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{
+        ByteLookupEvent, ByteRecord, EllipticCurveLinearCombinationEvent,
+        LINEAR_COMBINATION_ENTRY_WORDS, LINEAR_COMBINATION_MAX_PAIRS,
+    },
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::WeierstrassParameters,
+    AffinePoint, CurveType, EllipticCurve,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{MachineAir, PaddingStrategy, SP1AirBuilder},
+    MachineRecord, Word,
+};
+
+use super::{eval_accumulate, EcAccumulateCols, NUM_SCALAR_BITS};
+use crate::{
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::{limbs_from_access, pad_rows_with_strategy},
+};
+
+const NUM_SCALAR_BYTES: usize = NUM_SCALAR_BITS / 8;
+
+const NUM_SCALAR_WORDS: usize = NUM_SCALAR_BYTES / 4;
+
+const MAX_PAIRS: usize = LINEAR_COMBINATION_MAX_PAIRS as usize;
+
+/// The largest number of rows of a syscall. Each bit of the scalars takes up one row to double the
+/// sum and one row per pair.
+pub const LINEAR_COMBINATION_MAX_ROWS: usize = NUM_SCALAR_BITS * (MAX_PAIRS + 1);
+
+/// The size in bytes of an entry of the syscall, a pointer to a scalar followed by a pointer to a
+/// point.
+const ENTRY_SIZE: u32 = LINEAR_COMBINATION_ENTRY_WORDS as u32 * 4;
+
+pub const fn num_weierstrass_linear_combination_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<WeierstrassLinearCombinationCols<u8, P>>()
+}
+
+/// A set of columns to compute the sum of points on a Weierstrass curve multiplied by scalars with
+/// Shamir's trick.
+///
+/// Each syscall takes up one block of rows per bit of the scalars, most significant first. A block
+/// doubles the sum in its first row, then has one row per pair which adds the point of the pair to
+/// the sum if the bit of its scalar is set. Every row performs one addition, so that the rows share
+/// a single set of addition and doubling columns.
+///
+/// The entries, the scalars and the points are read from memory in the block of the most
+/// significant bit, the `k`-th pair at `k` cycles after the syscall, and carried through the rows
+/// of the syscall. The result is written to memory in the last row.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WeierstrassLinearCombinationCols<T, P: FieldParameters + NumWords> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub ptr: T,
+    pub num_pairs: T,
+    /// The number of pairs, one-hot: the `k`-th flag is set for `k + 1` pairs.
+    pub num_pairs_flags: [T; MAX_PAIRS],
+
+    /// Which bit of the current scalar byte this block processes, most significant first.
+    pub bit_flags: [T; 8],
+    /// Which byte of the scalars this block processes, most significant first.
+    pub byte_flags: [T; NUM_SCALAR_BYTES],
+    /// If the block processes the most significant bit of the scalars.
+    pub is_first_bit: T,
+    /// If the block processes the least significant bit of the scalars.
+    pub is_last_bit: T,
+
+    /// If the row doubles the sum, at the start of a block.
+    pub is_double_row: T,
+    /// If the row adds the point of a pair to the sum.
+    pub is_point: T,
+    /// Which pair a point row processes.
+    pub pair_flags: [T; MAX_PAIRS],
+    /// If the row processes the last pair, at the end of a block.
+    pub is_bit_end: T,
+    /// If the row is the first row of a syscall.
+    pub is_first: T,
+    /// If the row is the last row of a syscall.
+    pub is_last: T,
+
+    /// If the row reads its pair, which the point rows of the first block do.
+    pub is_read: T,
+    pub pair_access: [MemoryReadCols<T>; LINEAR_COMBINATION_ENTRY_WORDS],
+    pub scalar_access: [MemoryReadCols<T>; NUM_SCALAR_WORDS],
+    pub point_access: GenericArray<MemoryReadCols<T>, P::WordsCurvePoint>,
+
+    /// The pointer to the first point, which the result is written over.
+    pub result_ptr: T,
+    pub result_access: GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>,
+
+    /// The scalars and the points of all the pairs, carried through the rows of the syscall.
+    pub scalars: [[Word<T>; NUM_SCALAR_WORDS]; MAX_PAIRS],
+    pub points_x: [Limbs<T, P::Limbs>; MAX_PAIRS],
+    pub points_y: [Limbs<T, P::Limbs>; MAX_PAIRS],
+
+    /// The scalar of the pair of a point row.
+    pub scalar: [Word<T>; NUM_SCALAR_WORDS],
+    /// The byte of the scalar which holds the bit of the block.
+    pub scalar_byte: T,
+    /// The bit of the scalar, and the byte with only that bit, which is the bit times its mask.
+    pub bit: T,
+    pub masked_byte: T,
+
+    /// The addition of this row, `acc + q`, where `acc` is the sum and `q` is the sum in a
+    /// doubling row and the point of the pair in a point row.
+    pub accumulate: EcAccumulateCols<T, P>,
+}
+
+/// Computes the sum of points on a Weierstrass curve multiplied by scalars, as in the linear
+/// combinations of a Groth16 or Pedersen commitment, with Shamir's trick.
+///
+/// The double-and-add iterations of all the scalars run in the same pass over the bits: the sum is
+/// doubled once per bit, and each point whose scalar has the bit set is added to it, so the
+/// doublings are shared by all the pairs.
+#[derive(Default)]
+pub struct LinearCombinationChip<E> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: EllipticCurve + WeierstrassParameters> LinearCombinationChip<E> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn event_rows<F: PrimeField32>(
+        event: &EllipticCurveLinearCombinationEvent,
+        blu_events: &mut Vec<ByteLookupEvent>,
+    ) -> Vec<Vec<F>> {
+        let num_pairs = event.num_pairs as usize;
+        let points = event
+            .points
+            .iter()
+            .map(|point| Some(AffinePoint::<E>::from_words_le(point)))
+            .collect::<Vec<_>>();
+        let scalar_bytes = event
+            .scalars
+            .iter()
+            .map(|scalar| scalar.iter().flat_map(|word| word.to_le_bytes()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // The columns which stay the same throughout the rows of the syscall.
+        let mut template =
+            vec![F::zero(); num_weierstrass_linear_combination_cols::<E::BaseField>()];
+        let cols: &mut WeierstrassLinearCombinationCols<F, E::BaseField> =
+            template.as_mut_slice().borrow_mut();
+        cols.is_real = F::one();
+        cols.shard = F::from_canonical_u32(event.shard);
+        cols.channel = F::from_canonical_u8(event.channel);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.ptr = F::from_canonical_u32(event.ptr);
+        cols.num_pairs = F::from_canonical_u32(event.num_pairs);
+        cols.num_pairs_flags[num_pairs - 1] = F::one();
+        cols.result_ptr = F::from_canonical_u32(event.point_ptrs[0]);
+        for (k, (scalar, point)) in event.scalars.iter().zip(&points).enumerate() {
+            let point = point.as_ref().unwrap();
+            cols.scalars[k] = core::array::from_fn(|j| Word::from(scalar[j]));
+            cols.points_x[k] = E::BaseField::to_limbs_field::<F, _>(&point.x);
+            cols.points_y[k] = E::BaseField::to_limbs_field::<F, _>(&point.y);
+        }
+
+        let mut rows = Vec::with_capacity(NUM_SCALAR_BITS * (num_pairs + 1));
+        let mut acc: Option<AffinePoint<E>> = None;
+        for i in 0..NUM_SCALAR_BITS {
+            // Creates a row of the block of the i-th bit, most significant first.
+            let new_row = || {
+                let mut row = template.clone();
+                let cols: &mut WeierstrassLinearCombinationCols<F, E::BaseField> =
+                    row.as_mut_slice().borrow_mut();
+                cols.bit_flags[i % 8] = F::one();
+                cols.byte_flags[i / 8] = F::one();
+                cols.is_first_bit = F::from_bool(i == 0);
+                cols.is_last_bit = F::from_bool(i == NUM_SCALAR_BITS - 1);
+                row
+            };
+
+            // Double the sum.
+            let mut row = new_row();
+            let cols: &mut WeierstrassLinearCombinationCols<F, E::BaseField> =
+                row.as_mut_slice().borrow_mut();
+            cols.is_double_row = F::one();
+            cols.is_first = F::from_bool(i == 0);
+            acc = cols.accumulate.populate(
+                blu_events,
+                event.shard,
+                event.channel,
+                &acc,
+                &acc,
+                acc.is_some(),
+            );
+            rows.push(row);
+
+            // Add the points whose scalar has the bit set.
+            let byte = NUM_SCALAR_BYTES - 1 - i / 8;
+            let mask = 1u8 << (7 - i % 8);
+            for k in 0..num_pairs {
+                let mut row = new_row();
+                let cols: &mut WeierstrassLinearCombinationCols<F, E::BaseField> =
+                    row.as_mut_slice().borrow_mut();
+                let is_last = i == NUM_SCALAR_BITS - 1 && k == num_pairs - 1;
+                cols.is_point = F::one();
+                cols.pair_flags[k] = F::one();
+                cols.is_bit_end = F::from_bool(k == num_pairs - 1);
+                cols.is_last = F::from_bool(is_last);
+
+                if i == 0 {
+                    let records = &event.pair_memory_records[k];
+                    let (entry_records, records) = records.split_at(LINEAR_COMBINATION_ENTRY_WORDS);
+                    let (scalar_records, point_records) = records.split_at(NUM_SCALAR_WORDS);
+                    cols.is_read = F::one();
+                    for (access, record) in cols.pair_access.iter_mut().zip(entry_records) {
+                        access.populate(event.channel, *record, blu_events);
+                        blu_events.add_u8_range_checks(
+                            event.shard,
+                            event.channel,
+                            &record.value.to_le_bytes(),
+                        );
+                    }
+                    for (access, record) in cols.scalar_access.iter_mut().zip(scalar_records) {
+                        access.populate(event.channel, *record, blu_events);
+                    }
+                    for (access, record) in cols.point_access.iter_mut().zip(point_records) {
+                        access.populate(event.channel, *record, blu_events);
+                    }
+                }
+                if is_last {
+                    for (access, record) in
+                        cols.result_access.iter_mut().zip(&event.result_memory_records)
+                    {
+                        access.populate(event.channel, *record, blu_events);
+                    }
+                }
+
+                let scalar_byte = scalar_bytes[k][byte];
+                let masked_byte = scalar_byte & mask;
+                cols.scalar = cols.scalars[k];
+                cols.scalar_byte = F::from_canonical_u8(scalar_byte);
+                cols.bit = F::from_bool(masked_byte != 0);
+                cols.masked_byte = F::from_canonical_u8(masked_byte);
+                blu_events.add_byte_lookup_event(ByteLookupEvent {
+                    shard: event.shard,
+                    channel: event.channel,
+                    opcode: ByteOpcode::AND,
+                    a1: masked_byte as u16,
+                    a2: 0,
+                    b: scalar_byte,
+                    c: mask,
+                });
+
+                acc = cols.accumulate.populate(
+                    blu_events,
+                    event.shard,
+                    event.channel,
+                    &acc,
+                    &points[k],
+                    masked_byte != 0,
+                );
+                rows.push(row);
+            }
+        }
+        rows
+    }
+}
+
+impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
+    for LinearCombinationChip<E>
+{
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => "Secp256k1LinearCombination".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => &input.secp256k1_linear_combination_events,
+            _ => panic!("Unsupported curve"),
+        };
+
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        // Generate the trace rows & corresponding records for each chunk of events in parallel.
+        let rows_and_records = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut record = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+                let rows = events
+                    .iter()
+                    .flat_map(|event| Self::event_rows::<F>(event, &mut new_byte_lookup_events))
+                    .collect::<Vec<_>>();
+                record.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, record)
+            })
+            .collect::<Vec<_>>();
+
+        let mut rows = Vec::new();
+        for mut row_and_record in rows_and_records {
+            rows.extend(row_and_record.0);
+            output.append(&mut row_and_record.1);
+        }
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row =
+                vec![F::zero(); num_weierstrass_linear_combination_cols::<E::BaseField>()];
+            let cols: &mut WeierstrassLinearCombinationCols<F, E::BaseField> =
+                row.as_mut_slice().borrow_mut();
+            cols.accumulate.populate_padding::<E>();
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_weierstrass_linear_combination_cols::<E::BaseField>(),
+        );
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut WeierstrassLinearCombinationCols<F, E::BaseField> = trace.values[i
+                * num_weierstrass_linear_combination_cols::<E::BaseField>()
+                ..(i + 1) * num_weierstrass_linear_combination_cols::<E::BaseField>()]
+                .borrow_mut();
+            if i > 0 && cols.is_first == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => !shard.secp256k1_linear_combination_events.is_empty(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F, E: EllipticCurve + WeierstrassParameters> BaseAir<F> for LinearCombinationChip<E> {
+    fn width(&self) -> usize {
+        num_weierstrass_linear_combination_cols::<E::BaseField>()
+    }
+}
+
+impl<AB, E: EllipticCurve + WeierstrassParameters> Air<AB> for LinearCombinationChip<E>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &WeierstrassLinearCombinationCols<AB::Var, E::BaseField> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &WeierstrassLinearCombinationCols<AB::Var, E::BaseField> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans a variable number of rows, so the nonce counts the
+        // syscalls rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_first, next.nonce);
+
+        self.eval_flags(builder, local, next);
+
+        // Constrain that the inputs stay the same throughout the rows of each syscall.
+        let not_last = local.is_real - local.is_last;
+        let mut transition_builder = builder.when_transition();
+        let mut carry_builder = transition_builder.when(not_last);
+        carry_builder.assert_eq(local.shard, next.shard);
+        carry_builder.assert_eq(local.channel, next.channel);
+        carry_builder.assert_eq(local.clk, next.clk);
+        carry_builder.assert_eq(local.ptr, next.ptr);
+        carry_builder.assert_eq(local.num_pairs, next.num_pairs);
+        carry_builder.assert_eq(local.result_ptr, next.result_ptr);
+        for k in 0..MAX_PAIRS {
+            carry_builder.assert_eq(local.num_pairs_flags[k], next.num_pairs_flags[k]);
+            for j in 0..NUM_SCALAR_WORDS {
+                carry_builder.assert_word_eq(local.scalars[k][j], next.scalars[k][j]);
+            }
+            for i in 0..E::BaseField::NB_LIMBS {
+                carry_builder.assert_eq(local.points_x[k][i], next.points_x[k][i]);
+                carry_builder.assert_eq(local.points_y[k][i], next.points_y[k][i]);
+            }
+        }
+
+        self.eval_pairs(builder, local);
+
+        self.eval_bits(builder, local);
+
+        self.eval_accumulate(builder, local, next);
+
+        // In the last row, the result can't be the point at infinity and is written over the first
+        // point.
+        let out = &local.accumulate.out;
+        let num_words_field_element = E::BaseField::NB_LIMBS / 4;
+        builder.when(local.is_last).assert_zero(out.is_infinity);
+        for i in 0..E::BaseField::NB_LIMBS {
+            builder
+                .when(local.is_last)
+                .assert_eq(out.x[i], local.result_access[i / 4].value()[i % 4]);
+            builder.when(local.is_last).assert_eq(
+                out.y[i],
+                local.result_access[num_words_field_element + i / 4].value()[i % 4],
+            );
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            // The result is written after the reads of the largest number of pairs.
+            local.clk + AB::F::from_canonical_usize(MAX_PAIRS),
+            local.result_ptr,
+            &local.result_access,
+            local.is_last,
+        );
+
+        // Fetch the syscall id for the curve type.
+        let syscall_id_felt = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => {
+                AB::F::from_canonical_u32(SyscallCode::EC_LINEAR_COMBINATION.syscall_id())
+            }
+            _ => panic!("Unsupported curve"),
+        };
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.ptr,
+            local.num_pairs,
+            local.is_first,
+        );
+    }
+}
+
+impl<E: EllipticCurve + WeierstrassParameters> LinearCombinationChip<E> {
+    /// Constrains the order of the rows: each block starts with its doubling row, followed by one
+    /// point row per pair, and the blocks go through the bits of the scalars from the most
+    /// significant one.
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &WeierstrassLinearCombinationCols<AB::Var, E::BaseField>,
+        next: &WeierstrassLinearCombinationCols<AB::Var, E::BaseField>,
+    ) {
+        let sum = |flags: &[AB::Var]| flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_double_row);
+        builder.assert_bool(local.is_point);
+        for &flag in local
+            .num_pairs_flags
+            .iter()
+            .chain(local.bit_flags.iter())
+            .chain(local.byte_flags.iter())
+            .chain(local.pair_flags.iter())
+        {
+            builder.assert_bool(flag);
+        }
+        builder.assert_eq(local.is_double_row + local.is_point, local.is_real);
+        builder.assert_eq(sum(&local.bit_flags[..]), local.is_real);
+        builder.assert_eq(sum(&local.byte_flags[..]), local.is_real);
+        builder.assert_eq(sum(&local.pair_flags[..]), local.is_point);
+
+        // The number of pairs is between one and the largest number of pairs.
+        builder.assert_eq(sum(&local.num_pairs_flags[..]), local.is_real);
+        builder.assert_eq(
+            local.num_pairs,
+            local.num_pairs_flags.iter().enumerate().fold(AB::Expr::zero(), |acc, (k, &flag)| {
+                acc + flag * AB::F::from_canonical_usize(k + 1)
+            }),
+        );
+
+        builder.assert_eq(local.is_first_bit, local.byte_flags[0] * local.bit_flags[0]);
+        builder.assert_eq(
+            local.is_last_bit,
+            local.byte_flags[NUM_SCALAR_BYTES - 1] * local.bit_flags[7],
+        );
+        builder.assert_eq(
+            local.is_bit_end,
+            (0..MAX_PAIRS).fold(AB::Expr::zero(), |acc, k| {
+                acc + local.pair_flags[k] * local.num_pairs_flags[k]
+            }),
+        );
+        builder.assert_eq(local.is_first, local.is_double_row * local.is_first_bit);
+        builder.assert_eq(local.is_last, local.is_bit_end * local.is_last_bit);
+
+        // A syscall starts in the first row, or after the last row of the previous syscall, and
+        // the padding rows come last. The table can't end in the middle of a syscall.
+        builder.when_first_row().assert_eq(local.is_real, local.is_first);
+        builder.when_transition().when(local.is_last).assert_eq(next.is_real, next.is_first);
+        builder.when_transition().when(AB::Expr::one() - local.is_real).assert_zero(next.is_real);
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+
+        // The doubling row is followed by the row of the first pair, and the row of each pair but
+        // the last one by the row of the next pair, within the same block.
+        let mut transition_builder = builder.when_transition();
+        let mut double_builder = transition_builder.when(local.is_double_row);
+        double_builder.assert_one(next.is_point);
+        double_builder.assert_one(next.pair_flags[0]);
+        let mut transition_builder = builder.when_transition();
+        let mut pair_builder = transition_builder.when(local.is_point - local.is_bit_end);
+        pair_builder.assert_one(next.is_point);
+        for k in 0..MAX_PAIRS - 1 {
+            pair_builder.assert_eq(local.pair_flags[k], next.pair_flags[k + 1]);
+        }
+        let mut transition_builder = builder.when_transition();
+        let mut block_builder = transition_builder.when(local.is_real - local.is_bit_end);
+        for i in 0..8 {
+            block_builder.assert_eq(local.bit_flags[i], next.bit_flags[i]);
+        }
+        for i in 0..NUM_SCALAR_BYTES {
+            block_builder.assert_eq(local.byte_flags[i], next.byte_flags[i]);
+        }
+
+        // The row of the last pair is followed by the doubling row of the next bit. The bit flags
+        // advance after each block, and the byte flags after the last bit of each byte.
+        let mut transition_builder = builder.when_transition();
+        let mut next_block_builder = transition_builder.when(local.is_bit_end - local.is_last);
+        next_block_builder.assert_one(next.is_double_row);
+        for i in 0..7 {
+            next_block_builder.assert_eq(local.bit_flags[i], next.bit_flags[i + 1]);
+        }
+        next_block_builder.assert_eq(local.bit_flags[7], next.bit_flags[0]);
+        for i in 0..NUM_SCALAR_BYTES - 1 {
+            next_block_builder
+                .when(local.bit_flags[7])
+                .assert_eq(local.byte_flags[i], next.byte_flags[i + 1]);
+            next_block_builder
+                .when(AB::Expr::one() - local.bit_flags[7])
+                .assert_eq(local.byte_flags[i], next.byte_flags[i]);
+        }
+    }
+
+    /// Constrains the reads of the pairs in the point rows of the first block.
+    ///
+    /// The scalars and the points of the pairs are carried through the rows of the syscall, so
+    /// each value read is the value that the pair holds in every row.
+    fn eval_pairs<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &WeierstrassLinearCombinationCols<AB::Var, E::BaseField>,
+    ) where
+        Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+    {
+        builder.assert_eq(local.is_read, local.is_point * local.is_first_bit);
+
+        // The k-th pair is read k cycles after the syscall. The entry holds the pointers to the
+        // scalar and to the point, whose bytes are range checked.
+        let pair_index =
+            local.pair_flags.iter().enumerate().fold(AB::Expr::zero(), |acc, (k, &flag)| {
+                acc + flag * AB::F::from_canonical_usize(k)
+            });
+        let clk = local.clk + pair_index.clone();
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            clk.clone(),
+            local.ptr + pair_index * AB::F::from_canonical_u32(ENTRY_SIZE),
+            &local.pair_access,
+            local.is_read,
+        );
+        for access in local.pair_access.iter() {
+            builder.slice_range_check_u8(
+                &access.value().0,
+                local.shard,
+                local.channel,
+                local.is_read,
+            );
+        }
+        let base = AB::F::from_canonical_u32(256);
+        let compose = |word: &Word<AB::Var>| {
+            word.0.iter().rev().fold(AB::Expr::zero(), |acc, &byte| acc * base + byte)
+        };
+        let scalar_ptr = compose(local.pair_access[0].value());
+        let point_ptr = compose(local.pair_access[1].value());
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            clk.clone(),
+            scalar_ptr,
+            &local.scalar_access,
+            local.is_read,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            clk,
+            point_ptr.clone(),
+            &local.point_access,
+            local.is_read,
+        );
+
+        // The result is written over the point of the first pair.
+        builder.when(local.is_read * local.pair_flags[0]).assert_eq(local.result_ptr, point_ptr);
+
+        // The values read are the scalar and the point of the pair.
+        let num_words_field_element = E::BaseField::NB_LIMBS / 4;
+        let point_x: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.point_access[0..num_words_field_element]);
+        let point_y: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.point_access[num_words_field_element..]);
+        for k in 0..MAX_PAIRS {
+            let mut read_builder = builder.when(local.is_read * local.pair_flags[k]);
+            for j in 0..NUM_SCALAR_WORDS {
+                read_builder.assert_word_eq(local.scalars[k][j], *local.scalar_access[j].value());
+            }
+            for i in 0..E::BaseField::NB_LIMBS {
+                read_builder.assert_eq(local.points_x[k][i], point_x[i]);
+                read_builder.assert_eq(local.points_y[k][i], point_y[i]);
+            }
+        }
+    }
+
+    /// Constrains the bit of the scalar of the pair in a point row, which is the bit of the block.
+    fn eval_bits<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &WeierstrassLinearCombinationCols<AB::Var, E::BaseField>,
+    ) {
+        // Select the scalar of the pair, and the byte of the block, most significant first.
+        for j in 0..NUM_SCALAR_WORDS {
+            for b in 0..4 {
+                builder.assert_eq(
+                    local.scalar[j][b],
+                    (0..MAX_PAIRS).fold(AB::Expr::zero(), |acc, k| {
+                        acc + local.pair_flags[k] * local.scalars[k][j][b]
+                    }),
+                );
+            }
+        }
+        let scalar_byte = (0..NUM_SCALAR_BYTES).fold(AB::Expr::zero(), |acc, i| {
+            let byte = NUM_SCALAR_BYTES - 1 - i;
+            acc + local.byte_flags[i] * local.scalar[byte / 4][byte % 4]
+        });
+        builder.assert_eq(local.scalar_byte, scalar_byte);
+
+        // The bit is set if the byte has the bit of the block set, which is checked by masking the
+        // byte with the bit.
+        let mask = (0..8).fold(AB::Expr::zero(), |acc, i| {
+            acc + local.bit_flags[i] * AB::F::from_canonical_u32(1 << (7 - i))
+        });
+        builder.assert_bool(local.bit);
+        builder.assert_eq(local.masked_byte, local.bit * mask.clone());
+        builder.send_byte(
+            ByteOpcode::AND.as_field::<AB::F>(),
+            local.masked_byte,
+            local.scalar_byte,
+            mask,
+            local.shard,
+            local.channel,
+            local.is_point,
+        );
+    }
+
+    /// Constrains the addition of the row, `acc + q`, and the sum from one row to the next.
+    ///
+    /// The doubling rows double the sum unless it is the point at infinity, and the point rows add
+    /// the point of the pair to the sum if the bit of its scalar is set.
+    fn eval_accumulate<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &WeierstrassLinearCombinationCols<AB::Var, E::BaseField>,
+        next: &WeierstrassLinearCombinationCols<AB::Var, E::BaseField>,
+    ) where
+        Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+    {
+        let accumulate = &local.accumulate;
+
+        // The sum starts at the point at infinity, and each row starts from the sum of the
+        // previous row.
+        builder.when(local.is_first).assert_one(accumulate.acc.is_infinity);
+        let mut transition_builder = builder.when_transition();
+        let mut carry_builder = transition_builder.when(local.is_real - local.is_last);
+        carry_builder.assert_eq(next.accumulate.acc.is_infinity, accumulate.out.is_infinity);
+        for i in 0..E::BaseField::NB_LIMBS {
+            carry_builder.assert_eq(next.accumulate.acc.x[i], accumulate.out.x[i]);
+            carry_builder.assert_eq(next.accumulate.acc.y[i], accumulate.out.y[i]);
+        }
+
+        // Select `q` for the kind of row.
+        for i in 0..E::BaseField::NB_LIMBS {
+            builder.assert_eq(
+                accumulate.q_x[i],
+                (0..MAX_PAIRS).fold(local.is_double_row * accumulate.acc.x[i], |acc, k| {
+                    acc + local.pair_flags[k] * local.points_x[k][i]
+                }),
+            );
+            builder.assert_eq(
+                accumulate.q_y[i],
+                (0..MAX_PAIRS).fold(local.is_double_row * accumulate.acc.y[i], |acc, k| {
+                    acc + local.pair_flags[k] * local.points_y[k][i]
+                }),
+            );
+        }
+        builder.assert_eq(
+            accumulate.is_enabled,
+            local.is_double_row * (AB::Expr::one() - accumulate.acc.is_infinity)
+                + local.is_point * local.bit,
+        );
+
+        eval_accumulate::<AB, E>(builder, accumulate, local.shard, local.channel, local.is_real);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        weierstrass::{secp256k1::Secp256k1Parameters, SwCurve},
+        AffinePoint,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words, words_at},
+    };
+
+    const PTR: u32 = 100;
+
+    /// The pairs of a syscall, as a multiple of the generator for each point.
+    type Pairs = Vec<(BigUint, u32)>;
+
+    /// Builds a program that makes one linear combination syscall per list of pairs. The scalars
+    /// and the points of each list are laid out from [`PTR`] in reverse order, followed by the
+    /// entries that point to them. Returns the program and the pointer of the first point of each
+    /// list.
+    fn linear_combination_program(syscalls: &[Pairs]) -> (Program, Vec<u32>) {
+        let mut instructions = vec![];
+        let mut calls = vec![];
+        let mut result_ptrs = vec![];
+        let mut ptr = PTR;
+        for pairs in syscalls {
+            let mut entries = vec![0; 2 * pairs.len()];
+            for (k, (scalar, multiple)) in pairs.iter().enumerate().rev() {
+                entries[2 * k] = ptr;
+                store_words(&mut instructions, words_at(ptr, 8, &[scalar]));
+                ptr += 32;
+                let point =
+                    SwCurve::<Secp256k1Parameters>::generator().sw_scalar_mul(&(*multiple).into());
+                let words = point.to_words_le();
+                entries[2 * k + 1] = ptr;
+                store_words(&mut instructions, slice_at(ptr, &words));
+                ptr += words.len() as u32 * 4;
+            }
+            result_ptrs.push(entries[1]);
+            calls.push((ptr, pairs.len() as u32));
+            store_words(&mut instructions, slice_at(ptr, &entries));
+            ptr += entries.len() as u32 * 4;
+        }
+        for (ptr, num_pairs) in calls {
+            call_syscall(&mut instructions, SyscallCode::EC_LINEAR_COMBINATION, ptr, num_pairs);
+        }
+        (Program::new(instructions, 0, 0), result_ptrs)
+    }
+
+    fn large_scalar(shift: u32) -> BigUint {
+        // Shifted so that the sums in the tests stay below the order of the curve, and no partial
+        // sum is the point at infinity.
+        let scalar = BigUint::parse_bytes(
+            b"C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+            16,
+        )
+        .unwrap();
+        scalar >> (4 + shift)
+    }
+
+    fn syscalls() -> Vec<Pairs> {
+        vec![
+            vec![(BigUint::from(3u32), 1)],
+            // The point of the second pair is added to a sum equal to it, so that the sum doubles.
+            vec![(BigUint::from(1u32), 1), (BigUint::from(1u32), 1)],
+            vec![
+                (large_scalar(0), 1),
+                (large_scalar(3), 2),
+                (large_scalar(9), 5),
+                // The same point and scalar as the first pair.
+                (large_scalar(0), 1),
+            ],
+            vec![(large_scalar(1), 7), (BigUint::from(0x0100u32), 3)],
+        ]
+    }
+
+    #[test]
+    fn test_secp256k1_linear_combination_execute() {
+        setup_logger();
+        let syscalls = syscalls();
+        let (program, result_ptrs) = linear_combination_program(&syscalls);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        for (pairs, ptr) in syscalls.iter().zip(result_ptrs) {
+            let words = (0..16).map(|i| runtime.word(ptr + i * 4)).collect::<Vec<_>>();
+            let result = AffinePoint::<SwCurve<Secp256k1Parameters>>::from_words_le(&words);
+            let total = pairs
+                .iter()
+                .fold(BigUint::from(0u32), |acc, (scalar, multiple)| acc + scalar * *multiple);
+            let expected = SwCurve::<Secp256k1Parameters>::generator().sw_scalar_mul(&total);
+            assert_eq!(result.x, expected.x);
+            assert_eq!(result.y, expected.y);
+        }
+    }
+
+    #[test]
+    fn test_secp256k1_linear_combination_prove() {
+        setup_logger();
+        let syscalls = syscalls();
+        run_test::<CpuProver<_, _>>(linear_combination_program(&syscalls[..3]).0).unwrap();
+    }
+}
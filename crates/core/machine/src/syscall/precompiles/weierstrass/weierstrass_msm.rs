@@ -0,0 +1,1145 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::marker::PhantomData;
+
+use crate::air::MemoryAirBuilder;
+use generic_array::GenericArray;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{
+        ByteLookupEvent, ByteRecord, EllipticCurveMsmEvent, FieldOperation, MSM_MAX_PAIRS,
+        MSM_NUM_WINDOWS, MSM_WINDOW_BITS,
+    },
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::WeierstrassParameters,
+    AffinePoint, CurveType, EllipticCurve,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{MachineAir, PaddingStrategy, SP1AirBuilder},
+    MachineRecord,
+};
+
+use super::{EcAddCols, EcDoubleCols};
+use crate::{
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::{field::field_op::FieldOpCols, IsZeroOperation},
+    utils::{limbs_from_access, pad_rows_with_strategy},
+};
+
+/// The number of nonzero window values, each of which takes up one row per window.
+const NUM_DIGITS: usize = (1 << MSM_WINDOW_BITS) - 1;
+
+/// The largest number of rows of a syscall. Each window takes up one row per doubling of the
+/// result, one row per pair, one row per nonzero window value and one row to add the window sum to
+/// the result.
+pub const MSM_MAX_ROWS: usize =
+    MSM_NUM_WINDOWS * (MSM_WINDOW_BITS + MSM_MAX_PAIRS as usize + NUM_DIGITS + 1);
+
+/// The offset in bytes of the point in a (scalar, point) pair.
+const POINT_OFFSET: u32 = 32;
+
+/// The size in bytes of a (scalar, point) pair.
+const PAIR_SIZE: u32 = 96;
+
+pub const fn num_weierstrass_msm_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<WeierstrassMsmCols<u8, P>>()
+}
+
+/// A point in the columns of the multi-scalar multiplication, which may be the point at infinity.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct MsmPointCols<T, P: FieldParameters> {
+    pub x: Limbs<T, P::Limbs>,
+    pub y: Limbs<T, P::Limbs>,
+    /// If the point is the point at infinity, in which case the coordinates are zero.
+    pub is_infinity: T,
+}
+
+/// A set of columns to compute the sum of points on a Weierstrass curve multiplied by scalars.
+///
+/// Each syscall takes up one block of rows per window of the scalars, most significant first. A
+/// block doubles the result once per bit of the window, then adds the points to the running sum in
+/// decreasing order of their window value, adding the running sum to the window sum after each
+/// window value, and finally adds the window sum to the result. Every row performs one addition,
+/// so that the rows share a single set of addition and doubling columns.
+///
+/// The pairs are read from memory once per window, at the cycle of the window, and the result is
+/// written to memory in the last row.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WeierstrassMsmCols<T, P: FieldParameters + NumWords> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub ptr: T,
+    pub num_pairs: T,
+
+    /// Which window this row processes, most significant first.
+    pub window_flags: [T; MSM_NUM_WINDOWS],
+    /// Which doubling of the result this row performs, if it is one of the doubling rows at the
+    /// start of a window.
+    pub double_flags: [T; MSM_WINDOW_BITS],
+    /// If the row adds a point to the running sum.
+    pub is_point: T,
+    /// If the row adds the running sum to the window sum.
+    pub is_digit: T,
+    /// If the row adds the window sum to the result.
+    pub is_combine: T,
+    /// If the row is the first row of a syscall.
+    pub is_first: T,
+    /// If the row is the last row of a syscall.
+    pub is_last: T,
+
+    /// The window value of the points added in this part of the window. It starts at the largest
+    /// window value, and each digit row moves it down by one.
+    pub digit: T,
+    pub digit_is_zero: IsZeroOperation<T>,
+    /// The number of points left to be read in this window.
+    pub points_left: T,
+
+    /// The index of the pair read in a point row, and the memory accesses of the scalar word of
+    /// the window and of the point.
+    pub pair_index: T,
+    pub scalar_access: MemoryReadCols<T>,
+    pub point_access: GenericArray<MemoryReadCols<T>, P::WordsCurvePoint>,
+    pub result_access: GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>,
+
+    /// The result, the running sum and the window sum at the start of the row.
+    pub result: MsmPointCols<T, P>,
+    pub running: MsmPointCols<T, P>,
+    pub window_sum: MsmPointCols<T, P>,
+
+    /// The addition of this row, `acc + q`, where `acc` is one of the sums above and `q` is a
+    /// point or one of the sums above.
+    pub accumulate: EcAccumulateCols<T, P>,
+}
+
+/// A set of columns to add a point `q` to a sum `acc`, either of which may be the point at
+/// infinity, or to keep `acc` as it is.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct EcAccumulateCols<T, P: FieldParameters> {
+    pub acc: MsmPointCols<T, P>,
+    pub q_x: Limbs<T, P::Limbs>,
+    pub q_y: Limbs<T, P::Limbs>,
+    /// If `q` is added to `acc`. Otherwise `acc` is kept as it is.
+    pub is_enabled: T,
+    /// If `acc` and `q` are the same point.
+    pub is_equal: T,
+    /// If the sum is `q`, because `acc` is the point at infinity.
+    pub is_copy: T,
+    /// If the sum is `q` doubled, because `acc` is `q`.
+    pub is_double: T,
+    /// If the sum is `acc + q` with the addition formulas.
+    pub is_add: T,
+
+    /// The input of the doubling if `is_double` is set, and `(0, 1)` otherwise.
+    pub double_input_x: Limbs<T, P::Limbs>,
+    pub double_input_y: Limbs<T, P::Limbs>,
+    pub(crate) double: EcDoubleCols<T, P>,
+
+    /// The inputs of the addition if `is_add` is set, and `(0, 0)` and `(1, 0)` otherwise. The x
+    /// coordinates must differ, which is checked by inverting their difference.
+    pub add_p_x: Limbs<T, P::Limbs>,
+    pub add_p_y: Limbs<T, P::Limbs>,
+    pub add_q_x: Limbs<T, P::Limbs>,
+    pub add_q_y: Limbs<T, P::Limbs>,
+    pub(crate) add: EcAddCols<T, P>,
+    pub x_diff: FieldOpCols<T, P>,
+    pub x_diff_inverse: FieldOpCols<T, P>,
+
+    /// The sum.
+    pub out: MsmPointCols<T, P>,
+}
+
+impl<F: PrimeField32, P: FieldParameters> MsmPointCols<F, P> {
+    pub(crate) fn populate<E: EllipticCurve<BaseField = P>>(
+        &mut self,
+        point: &Option<AffinePoint<E>>,
+    ) {
+        let (x, y) = coordinates(point);
+        self.x = P::to_limbs_field::<F, _>(&x);
+        self.y = P::to_limbs_field::<F, _>(&y);
+        self.is_infinity = F::from_bool(point.is_none());
+    }
+}
+
+impl<F: PrimeField32, P: FieldParameters> EcAccumulateCols<F, P> {
+    /// Populates the addition of `q` to `acc` if `is_enabled` is set, and returns the sum, or
+    /// `acc` otherwise.
+    pub(crate) fn populate<E: EllipticCurve<BaseField = P> + WeierstrassParameters>(
+        &mut self,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        acc: &Option<AffinePoint<E>>,
+        q: &Option<AffinePoint<E>>,
+        is_enabled: bool,
+    ) -> Option<AffinePoint<E>> {
+        let (acc_x, acc_y) = coordinates(acc);
+        let (q_x, q_y) = coordinates(q);
+        self.acc.populate(acc);
+        self.q_x = P::to_limbs_field::<F, _>(&q_x);
+        self.q_y = P::to_limbs_field::<F, _>(&q_y);
+
+        let is_equal = acc_x == q_x && acc_y == q_y;
+        let is_copy = is_enabled && acc.is_none();
+        let is_double = is_enabled && !is_copy && is_equal;
+        let is_add = is_enabled && !is_copy && !is_equal;
+        self.is_enabled = F::from_bool(is_enabled);
+        self.is_equal = F::from_bool(is_equal);
+        self.is_copy = F::from_bool(is_copy);
+        self.is_double = F::from_bool(is_double);
+        self.is_add = F::from_bool(is_add);
+
+        let (double_input_x, double_input_y) =
+            if is_double { (q_x.clone(), q_y.clone()) } else { (BigUint::zero(), BigUint::one()) };
+        let doubled =
+            self.populate_double::<E>(blu_events, shard, channel, &double_input_x, &double_input_y);
+
+        let (add_p_x, add_p_y, add_q_x, add_q_y) = if is_add {
+            (acc_x.clone(), acc_y.clone(), q_x.clone(), q_y.clone())
+        } else {
+            (BigUint::zero(), BigUint::zero(), BigUint::one(), BigUint::zero())
+        };
+        let added =
+            self.populate_add(blu_events, shard, channel, &add_p_x, &add_p_y, &add_q_x, &add_q_y);
+
+        let out = if is_copy {
+            q.clone()
+        } else if is_double {
+            Some(AffinePoint::new(doubled.0, doubled.1))
+        } else if is_add {
+            Some(AffinePoint::new(added.0, added.1))
+        } else {
+            acc.clone()
+        };
+        self.out.populate(&out);
+        out
+    }
+
+    /// Populates the columns of a padding row, which doubles `(0, 1)` and adds `(0, 0)` and
+    /// `(1, 0)`, so that the slopes and the inverse of the difference of the x coordinates are
+    /// well defined.
+    pub(crate) fn populate_padding<E: EllipticCurve<BaseField = P> + WeierstrassParameters>(
+        &mut self,
+    ) {
+        let zero = BigUint::zero();
+        let one = BigUint::one();
+        self.populate_double::<E>(&mut vec![], 0, 0, &zero, &one);
+        self.populate_add(&mut vec![], 0, 0, &zero, &zero, &one, &zero);
+    }
+
+    fn populate_double<E: EllipticCurve<BaseField = P> + WeierstrassParameters>(
+        &mut self,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        x: &BigUint,
+        y: &BigUint,
+    ) -> (BigUint, BigUint) {
+        self.double_input_x = P::to_limbs_field::<F, _>(x);
+        self.double_input_y = P::to_limbs_field::<F, _>(y);
+        self.double.populate(blu_events, shard, channel, x, y, &E::a_int())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn populate_add(
+        &mut self,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        p_x: &BigUint,
+        p_y: &BigUint,
+        q_x: &BigUint,
+        q_y: &BigUint,
+    ) -> (BigUint, BigUint) {
+        self.add_p_x = P::to_limbs_field::<F, _>(p_x);
+        self.add_p_y = P::to_limbs_field::<F, _>(p_y);
+        self.add_q_x = P::to_limbs_field::<F, _>(q_x);
+        self.add_q_y = P::to_limbs_field::<F, _>(q_y);
+        let x_diff =
+            self.x_diff.populate(blu_events, shard, channel, q_x, p_x, FieldOperation::Sub);
+        self.x_diff_inverse.populate(
+            blu_events,
+            shard,
+            channel,
+            &BigUint::one(),
+            &x_diff,
+            FieldOperation::Div,
+        );
+        self.add.populate(blu_events, shard, channel, p_x, p_y, q_x, q_y)
+    }
+}
+
+/// Constrains the sum `out` of `acc` and `q` in the accumulate columns, given `acc`, `q` and
+/// `is_enabled`, which are selected by the caller.
+pub(crate) fn eval_accumulate<AB, E>(
+    builder: &mut AB,
+    cols: &EcAccumulateCols<AB::Var, E::BaseField>,
+    shard: AB::Var,
+    channel: AB::Var,
+    is_real: AB::Var,
+) where
+    AB: SP1AirBuilder,
+    E: EllipticCurve + WeierstrassParameters,
+    Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+{
+    // Pick the formulas. A sum of a point and its negation would be the point at infinity,
+    // which is not supported, so `acc` and `q` must have different x coordinates unless they
+    // are equal.
+    builder.assert_bool(cols.acc.is_infinity);
+    builder.assert_bool(cols.is_equal);
+    builder.assert_eq(cols.is_copy, cols.is_enabled * cols.acc.is_infinity);
+    builder.assert_eq(cols.is_double, (cols.is_enabled - cols.is_copy) * cols.is_equal);
+    builder.assert_eq(cols.is_add, cols.is_enabled - cols.is_copy - cols.is_double);
+    for i in 0..E::BaseField::NB_LIMBS {
+        builder.when(cols.is_equal).assert_eq(cols.acc.x[i], cols.q_x[i]);
+        builder.when(cols.is_equal).assert_eq(cols.acc.y[i], cols.q_y[i]);
+        builder.when(cols.is_double).assert_eq(cols.double_input_x[i], cols.q_x[i]);
+        builder.when(cols.is_double).assert_eq(cols.double_input_y[i], cols.q_y[i]);
+        builder.when(cols.is_add).assert_eq(cols.add_p_x[i], cols.acc.x[i]);
+        builder.when(cols.is_add).assert_eq(cols.add_p_y[i], cols.acc.y[i]);
+        builder.when(cols.is_add).assert_eq(cols.add_q_x[i], cols.q_x[i]);
+        builder.when(cols.is_add).assert_eq(cols.add_q_y[i], cols.q_y[i]);
+    }
+
+    let a = E::BaseField::to_limbs_field::<AB::Expr, _>(&E::a_int());
+    cols.double.eval(
+        builder,
+        &cols.double_input_x,
+        &cols.double_input_y,
+        &a,
+        shard,
+        channel,
+        is_real,
+    );
+    cols.add.eval(
+        builder,
+        &cols.add_p_x,
+        &cols.add_p_y,
+        &cols.add_q_x,
+        &cols.add_q_y,
+        shard,
+        channel,
+        is_real,
+    );
+    cols.x_diff.eval(
+        builder,
+        &cols.add_q_x,
+        &cols.add_p_x,
+        FieldOperation::Sub,
+        shard,
+        channel,
+        is_real,
+    );
+    cols.x_diff_inverse.eval(
+        builder,
+        &E::BaseField::to_limbs_field::<AB::Expr, _>(&BigUint::one()),
+        &cols.x_diff.result,
+        FieldOperation::Div,
+        shard,
+        channel,
+        is_real,
+    );
+
+    // The sum is `q`, `q` doubled, `acc + q`, or `acc` if `q` is not added.
+    let (doubled_x, doubled_y) = cols.double.result();
+    let (added_x, added_y) = cols.add.result();
+    let is_kept = AB::Expr::one() - cols.is_enabled;
+    builder.assert_eq(cols.out.is_infinity, is_kept.clone() * cols.acc.is_infinity);
+    for i in 0..E::BaseField::NB_LIMBS {
+        builder.assert_eq(
+            cols.out.x[i],
+            cols.is_copy * cols.q_x[i]
+                + cols.is_double * doubled_x[i]
+                + cols.is_add * added_x[i]
+                + is_kept.clone() * cols.acc.x[i],
+        );
+        builder.assert_eq(
+            cols.out.y[i],
+            cols.is_copy * cols.q_y[i]
+                + cols.is_double * doubled_y[i]
+                + cols.is_add * added_y[i]
+                + is_kept.clone() * cols.acc.y[i],
+        );
+    }
+}
+
+/// The coordinates of a point, or zero for the point at infinity.
+pub(crate) fn coordinates<E: EllipticCurve>(point: &Option<AffinePoint<E>>) -> (BigUint, BigUint) {
+    match point {
+        Some(point) => (point.x.clone(), point.y.clone()),
+        None => (BigUint::zero(), BigUint::zero()),
+    }
+}
+
+/// Computes the sum of points on a Weierstrass curve multiplied by scalars with the bucket method
+/// of Pippenger.
+///
+/// The buckets of a window are never stored. The points are read sorted by their window value, so
+/// the running sum holds the sum of the buckets from the top down, and adding it to the window sum
+/// after each window value gives the sum of the buckets weighted by their window value.
+#[derive(Default)]
+pub struct WeierstrassMsmChip<E> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: EllipticCurve + WeierstrassParameters> WeierstrassMsmChip<E> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn event_rows<F: PrimeField32>(
+        event: &EllipticCurveMsmEvent,
+        blu_events: &mut Vec<ByteLookupEvent>,
+    ) -> Vec<Vec<F>> {
+        let num_pairs = event.num_pairs as usize;
+        let points = event
+            .points
+            .iter()
+            .map(|point| Some(AffinePoint::<E>::from_words_le(point)))
+            .collect::<Vec<_>>();
+
+        let mut rows =
+            Vec::with_capacity(MSM_NUM_WINDOWS * (MSM_WINDOW_BITS + num_pairs + NUM_DIGITS + 1));
+        let mut result: Option<AffinePoint<E>> = None;
+        for k in 0..MSM_NUM_WINDOWS {
+            let window = MSM_NUM_WINDOWS - 1 - k;
+            let digits = event
+                .scalars
+                .iter()
+                .map(|scalar| (scalar[window / 4] >> (8 * (window % 4))) & 0xff)
+                .collect::<Vec<_>>();
+            let mut running: Option<AffinePoint<E>> = None;
+            let mut window_sum: Option<AffinePoint<E>> = None;
+
+            // Creates a row of the window with the sums at the start of the row.
+            let new_row = |result: &Option<AffinePoint<E>>,
+                           running: &Option<AffinePoint<E>>,
+                           window_sum: &Option<AffinePoint<E>>| {
+                let mut row = vec![F::zero(); num_weierstrass_msm_cols::<E::BaseField>()];
+                let cols: &mut WeierstrassMsmCols<F, E::BaseField> =
+                    row.as_mut_slice().borrow_mut();
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.ptr = F::from_canonical_u32(event.ptr);
+                cols.num_pairs = F::from_canonical_u32(event.num_pairs);
+                cols.window_flags[k] = F::one();
+                cols.result.populate(result);
+                cols.running.populate(running);
+                cols.window_sum.populate(window_sum);
+                row
+            };
+
+            // Double the result once per bit of the window.
+            for i in 0..MSM_WINDOW_BITS {
+                let mut row = new_row(&result, &running, &window_sum);
+                let cols: &mut WeierstrassMsmCols<F, E::BaseField> =
+                    row.as_mut_slice().borrow_mut();
+                cols.double_flags[i] = F::one();
+                cols.is_first = F::from_bool(k == 0 && i == 0);
+                cols.digit_is_zero.populate(0);
+                result = cols.accumulate.populate(
+                    blu_events,
+                    event.shard,
+                    event.channel,
+                    &result,
+                    &result,
+                    result.is_some(),
+                );
+                rows.push(row);
+            }
+
+            // Add the points to the running sum from the largest window value down, and the
+            // running sum to the window sum after each window value. The points of window value
+            // zero are read last, and not added.
+            let mut order = (0..num_pairs).collect::<Vec<_>>();
+            order.sort_by_key(|&i| core::cmp::Reverse(digits[i]));
+            let mut order = order.into_iter().peekable();
+            let mut points_left = num_pairs;
+            for digit in (0..=NUM_DIGITS as u32).rev() {
+                while let Some(i) = order.next_if(|&i| digits[i] == digit) {
+                    let mut row = new_row(&result, &running, &window_sum);
+                    let cols: &mut WeierstrassMsmCols<F, E::BaseField> =
+                        row.as_mut_slice().borrow_mut();
+                    cols.is_point = F::one();
+                    cols.digit = F::from_canonical_u32(digit);
+                    cols.digit_is_zero.populate(digit);
+                    cols.points_left = F::from_canonical_usize(points_left);
+                    cols.pair_index = F::from_canonical_usize(i);
+                    let records = &event.pair_memory_records[k * num_pairs + i];
+                    cols.scalar_access.populate(event.channel, records[0], blu_events);
+                    for (access, record) in cols.point_access.iter_mut().zip(&records[1..]) {
+                        access.populate(event.channel, *record, blu_events);
+                    }
+                    blu_events.add_byte_lookup_event(ByteLookupEvent {
+                        shard: event.shard,
+                        channel: event.channel,
+                        opcode: ByteOpcode::LTU,
+                        a1: 1,
+                        a2: 0,
+                        b: i as u8,
+                        c: event.num_pairs as u8,
+                    });
+                    running = cols.accumulate.populate(
+                        blu_events,
+                        event.shard,
+                        event.channel,
+                        &running,
+                        &points[i],
+                        digit != 0,
+                    );
+                    points_left -= 1;
+                    rows.push(row);
+                }
+
+                if digit != 0 {
+                    let mut row = new_row(&result, &running, &window_sum);
+                    let cols: &mut WeierstrassMsmCols<F, E::BaseField> =
+                        row.as_mut_slice().borrow_mut();
+                    cols.is_digit = F::one();
+                    cols.digit = F::from_canonical_u32(digit);
+                    cols.digit_is_zero.populate(digit);
+                    cols.points_left = F::from_canonical_usize(points_left);
+                    window_sum = cols.accumulate.populate(
+                        blu_events,
+                        event.shard,
+                        event.channel,
+                        &window_sum,
+                        &running,
+                        running.is_some(),
+                    );
+                    rows.push(row);
+                }
+            }
+
+            // Add the window sum to the result.
+            let mut row = new_row(&result, &running, &window_sum);
+            let cols: &mut WeierstrassMsmCols<F, E::BaseField> = row.as_mut_slice().borrow_mut();
+            cols.is_combine = F::one();
+            cols.is_last = F::from_bool(k == MSM_NUM_WINDOWS - 1);
+            cols.digit_is_zero.populate(0);
+            result = cols.accumulate.populate(
+                blu_events,
+                event.shard,
+                event.channel,
+                &result,
+                &window_sum,
+                window_sum.is_some(),
+            );
+            if k == MSM_NUM_WINDOWS - 1 {
+                for (access, record) in
+                    cols.result_access.iter_mut().zip(&event.result_memory_records)
+                {
+                    access.populate(event.channel, *record, blu_events);
+                }
+            }
+            rows.push(row);
+        }
+        rows
+    }
+}
+
+impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
+    for WeierstrassMsmChip<E>
+{
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => "Secp256k1Msm".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => &input.secp256k1_msm_events,
+            _ => panic!("Unsupported curve"),
+        };
+
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        // Generate the trace rows & corresponding records for each chunk of events in parallel.
+        let rows_and_records = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut record = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+                let rows = events
+                    .iter()
+                    .flat_map(|event| Self::event_rows::<F>(event, &mut new_byte_lookup_events))
+                    .collect::<Vec<_>>();
+                record.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, record)
+            })
+            .collect::<Vec<_>>();
+
+        let mut rows = Vec::new();
+        for mut row_and_record in rows_and_records {
+            rows.extend(row_and_record.0);
+            output.append(&mut row_and_record.1);
+        }
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = vec![F::zero(); num_weierstrass_msm_cols::<E::BaseField>()];
+            let cols: &mut WeierstrassMsmCols<F, E::BaseField> = row.as_mut_slice().borrow_mut();
+            cols.digit_is_zero.populate(0);
+            cols.accumulate.populate_padding::<E>();
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_weierstrass_msm_cols::<E::BaseField>(),
+        );
+
+        // Write the nonces to the trace. The nonce is the index of the syscall the row belongs to.
+        let mut nonce = 0;
+        for i in 0..trace.height() {
+            let cols: &mut WeierstrassMsmCols<F, E::BaseField> = trace.values[i
+                * num_weierstrass_msm_cols::<E::BaseField>()
+                ..(i + 1) * num_weierstrass_msm_cols::<E::BaseField>()]
+                .borrow_mut();
+            if i > 0 && cols.is_first == F::one() {
+                nonce += 1;
+            }
+            cols.nonce = F::from_canonical_usize(nonce);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => !shard.secp256k1_msm_events.is_empty(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F, E: EllipticCurve + WeierstrassParameters> BaseAir<F> for WeierstrassMsmChip<E> {
+    fn width(&self) -> usize {
+        num_weierstrass_msm_cols::<E::BaseField>()
+    }
+}
+
+impl<AB, E: EllipticCurve + WeierstrassParameters> Air<AB> for WeierstrassMsmChip<E>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &WeierstrassMsmCols<AB::Var, E::BaseField> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &WeierstrassMsmCols<AB::Var, E::BaseField> = (*next).borrow();
+
+        // Constrain the nonce. A syscall spans a variable number of rows, so the nonce counts the
+        // syscalls rather than the rows.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + next.is_first, next.nonce);
+
+        self.eval_flags(builder, local, next);
+
+        // Constrain that the inputs stay the same throughout the rows of each syscall.
+        let not_last = local.is_real - local.is_last;
+        let mut transition_builder = builder.when_transition();
+        let mut carry_builder = transition_builder.when(not_last);
+        carry_builder.assert_eq(local.shard, next.shard);
+        carry_builder.assert_eq(local.channel, next.channel);
+        carry_builder.assert_eq(local.clk, next.clk);
+        carry_builder.assert_eq(local.ptr, next.ptr);
+        carry_builder.assert_eq(local.num_pairs, next.num_pairs);
+
+        self.eval_counters(builder, local, next);
+
+        self.eval_pairs(builder, local);
+
+        self.eval_sums(builder, local, next);
+
+        self.eval_accumulate(builder, local);
+
+        // In the last row, the result can't be the point at infinity and is written over the first
+        // point.
+        let num_words_field_element = E::BaseField::NB_LIMBS / 4;
+        builder.when(local.is_last).assert_zero(local.accumulate.out.is_infinity);
+        for i in 0..E::BaseField::NB_LIMBS {
+            builder
+                .when(local.is_last)
+                .assert_eq(local.accumulate.out.x[i], local.result_access[i / 4].value()[i % 4]);
+            builder.when(local.is_last).assert_eq(
+                local.accumulate.out.y[i],
+                local.result_access[num_words_field_element + i / 4].value()[i % 4],
+            );
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            // The result is written after the reads of the last window.
+            local.clk + AB::F::from_canonical_usize(MSM_NUM_WINDOWS),
+            local.ptr + AB::F::from_canonical_u32(POINT_OFFSET),
+            &local.result_access,
+            local.is_last,
+        );
+
+        // Fetch the syscall id for the curve type.
+        let syscall_id_felt = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => {
+                AB::F::from_canonical_u32(SyscallCode::SECP256K1_MSM.syscall_id())
+            }
+            _ => panic!("Unsupported curve"),
+        };
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.ptr,
+            local.num_pairs,
+            local.is_first,
+        );
+    }
+}
+
+impl<E: EllipticCurve + WeierstrassParameters> WeierstrassMsmChip<E> {
+    /// Constrains the order of the rows: each window starts with its doubling rows, then has point
+    /// and digit rows, and ends with a combine row.
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &WeierstrassMsmCols<AB::Var, E::BaseField>,
+        next: &WeierstrassMsmCols<AB::Var, E::BaseField>,
+    ) {
+        let is_doubling = local.double_flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag);
+
+        builder.assert_bool(local.is_real);
+        for &flag in local.window_flags.iter().chain(local.double_flags.iter()) {
+            builder.assert_bool(flag);
+        }
+        builder.assert_bool(local.is_point);
+        builder.assert_bool(local.is_digit);
+        builder.assert_bool(local.is_combine);
+        builder.assert_eq(
+            local.window_flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag),
+            local.is_real,
+        );
+        builder.assert_eq(
+            is_doubling + local.is_point + local.is_digit + local.is_combine,
+            local.is_real,
+        );
+        builder.assert_eq(local.is_first, local.double_flags[0] * local.window_flags[0]);
+        builder
+            .assert_eq(local.is_last, local.is_combine * local.window_flags[MSM_NUM_WINDOWS - 1]);
+
+        // A syscall starts in the first row, or after the last row of the previous syscall, and
+        // the padding rows come last.
+        builder.when_first_row().assert_eq(local.is_real, local.is_first);
+        builder.when_transition().when(local.is_last).assert_eq(next.is_real, next.is_first);
+        builder.when_transition().when(AB::Expr::one() - local.is_real).assert_zero(next.is_real);
+
+        // The doubling rows follow each other, and are followed by the point and digit rows, which
+        // are followed by the combine row. The next window starts after the combine row.
+        for i in 0..MSM_WINDOW_BITS - 1 {
+            builder.when_transition().assert_eq(local.double_flags[i], next.double_flags[i + 1]);
+        }
+        builder
+            .when_transition()
+            .when(local.double_flags[MSM_WINDOW_BITS - 1])
+            .assert_one(next.is_point + next.is_digit);
+        builder
+            .when_transition()
+            .when(local.is_point + local.is_digit)
+            .assert_one(next.is_point + next.is_digit + next.is_combine);
+        builder
+            .when_transition()
+            .when(local.is_combine - local.is_last)
+            .assert_one(next.double_flags[0]);
+
+        // The window flags advance after each combine row.
+        for i in 0..MSM_NUM_WINDOWS {
+            builder
+                .when_transition()
+                .when(local.is_real - local.is_combine)
+                .assert_eq(local.window_flags[i], next.window_flags[i]);
+        }
+        for i in 0..MSM_NUM_WINDOWS - 1 {
+            builder
+                .when_transition()
+                .when(local.is_combine - local.is_last)
+                .assert_eq(local.window_flags[i], next.window_flags[i + 1]);
+        }
+    }
+
+    /// Constrains the window value and the number of points left, so that each window has one
+    /// digit row per nonzero window value and one point row per pair.
+    fn eval_counters<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &WeierstrassMsmCols<AB::Var, E::BaseField>,
+        next: &WeierstrassMsmCols<AB::Var, E::BaseField>,
+    ) {
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            local.digit.into(),
+            local.digit_is_zero,
+            local.is_real.into(),
+        );
+
+        // The counters start after the doubling rows. Digit rows move the window value down, and
+        // point rows move the number of points left down.
+        builder
+            .when_transition()
+            .when(local.double_flags[MSM_WINDOW_BITS - 1])
+            .assert_eq(next.digit, AB::F::from_canonical_usize(NUM_DIGITS));
+        builder
+            .when_transition()
+            .when(local.double_flags[MSM_WINDOW_BITS - 1])
+            .assert_eq(next.points_left, local.num_pairs);
+        builder
+            .when_transition()
+            .when(local.is_point + local.is_digit)
+            .assert_eq(next.digit, local.digit - local.is_digit);
+        builder
+            .when_transition()
+            .when(local.is_point + local.is_digit)
+            .assert_eq(next.points_left, local.points_left - local.is_point);
+
+        // A digit row can't move the window value below zero, and the window ends when both
+        // counters are zero.
+        builder.when(local.is_digit).assert_zero(local.digit_is_zero.result);
+        builder.when(local.is_combine).assert_zero(local.digit);
+        builder.when(local.is_combine).assert_zero(local.points_left);
+    }
+
+    /// Constrains the reads of the pairs in the point rows.
+    ///
+    /// Two reads of the same address at the same cycle are rejected by the memory argument, so
+    /// the point rows of a window read distinct pairs, and there is one per pair.
+    fn eval_pairs<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &WeierstrassMsmCols<AB::Var, E::BaseField>,
+    ) {
+        // The index of the pair is less than the number of pairs.
+        builder.send_byte(
+            ByteOpcode::LTU.as_field::<AB::F>(),
+            AB::F::one(),
+            local.pair_index,
+            local.num_pairs,
+            local.shard,
+            local.channel,
+            local.is_point,
+        );
+
+        // The k-th window is read k cycles after the syscall, from the scalar word that holds it.
+        let clk = local
+            .window_flags
+            .iter()
+            .enumerate()
+            .fold(local.clk.into(), |acc: AB::Expr, (k, &flag)| {
+                acc + flag * AB::F::from_canonical_usize(k)
+            });
+        let pair_ptr = local.ptr + local.pair_index * AB::F::from_canonical_u32(PAIR_SIZE);
+        let scalar_offset =
+            local.window_flags.iter().enumerate().fold(AB::Expr::zero(), |acc, (k, &flag)| {
+                let window = MSM_NUM_WINDOWS - 1 - k;
+                acc + flag * AB::F::from_canonical_usize(window / 4 * 4)
+            });
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            clk.clone(),
+            pair_ptr.clone() + scalar_offset,
+            &local.scalar_access,
+            local.is_point,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            clk,
+            pair_ptr + AB::F::from_canonical_u32(POINT_OFFSET),
+            &local.point_access,
+            local.is_point,
+        );
+
+        // The window value of the point is the byte of the scalar word for the window.
+        let scalar_byte =
+            local.window_flags.iter().enumerate().fold(AB::Expr::zero(), |acc, (k, &flag)| {
+                let window = MSM_NUM_WINDOWS - 1 - k;
+                acc + flag * local.scalar_access.value()[window % 4]
+            });
+        builder.when(local.is_point).assert_eq(scalar_byte, local.digit);
+    }
+
+    /// Constrains the result, the running sum and the window sum from one row to the next. The
+    /// sum of each row replaces the sum that it adds to.
+    fn eval_sums<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &WeierstrassMsmCols<AB::Var, E::BaseField>,
+        next: &WeierstrassMsmCols<AB::Var, E::BaseField>,
+    ) where
+        Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+    {
+        let is_doubling = local.double_flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag);
+
+        // The result starts at the point at infinity, and the running sum and the window sum start
+        // at the point at infinity in each window.
+        builder.assert_bool(local.result.is_infinity);
+        builder.assert_bool(local.running.is_infinity);
+        builder.assert_bool(local.window_sum.is_infinity);
+        builder.when(local.is_first).assert_one(local.result.is_infinity);
+        builder.when(local.double_flags[0]).assert_one(local.running.is_infinity);
+        builder.when(local.double_flags[0]).assert_one(local.window_sum.is_infinity);
+
+        let updates_result = is_doubling.clone() + local.is_combine;
+        let keeps_result = local.is_point + local.is_digit;
+        let keeps_running = is_doubling.clone() + local.is_digit;
+        let keeps_window_sum = is_doubling + local.is_point;
+        let carry = |builder: &mut AB,
+                     gate: AB::Expr,
+                     update: AB::Expr,
+                     keep: AB::Expr,
+                     from: &MsmPointCols<AB::Var, E::BaseField>,
+                     to: &MsmPointCols<AB::Var, E::BaseField>| {
+            let mut transition_builder = builder.when_transition();
+            let mut carry_builder = transition_builder.when(gate);
+            carry_builder.assert_eq(
+                to.is_infinity,
+                update.clone() * local.accumulate.out.is_infinity + keep.clone() * from.is_infinity,
+            );
+            for i in 0..E::BaseField::NB_LIMBS {
+                carry_builder.assert_eq(
+                    to.x[i],
+                    update.clone() * local.accumulate.out.x[i] + keep.clone() * from.x[i],
+                );
+                carry_builder.assert_eq(
+                    to.y[i],
+                    update.clone() * local.accumulate.out.y[i] + keep.clone() * from.y[i],
+                );
+            }
+        };
+        carry(
+            builder,
+            local.is_real - local.is_last,
+            updates_result,
+            keeps_result,
+            &local.result,
+            &next.result,
+        );
+        carry(
+            builder,
+            local.is_real - local.is_combine,
+            local.is_point.into(),
+            keeps_running,
+            &local.running,
+            &next.running,
+        );
+        carry(
+            builder,
+            local.is_real - local.is_combine,
+            local.is_digit.into(),
+            keeps_window_sum,
+            &local.window_sum,
+            &next.window_sum,
+        );
+    }
+
+    /// Constrains the addition of the row, `acc + q`.
+    ///
+    /// The doubling rows double the result, the point rows add the point to the running sum, the
+    /// digit rows add the running sum to the window sum, and the combine row adds the window sum
+    /// to the result. A point of window value zero or a sum at infinity is not added.
+    fn eval_accumulate<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &WeierstrassMsmCols<AB::Var, E::BaseField>,
+    ) where
+        Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+    {
+        let is_doubling = local.double_flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag);
+        let num_words_field_element = E::BaseField::NB_LIMBS / 4;
+        let point_x: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.point_access[0..num_words_field_element]);
+        let point_y: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.point_access[num_words_field_element..]);
+
+        // Select `acc` and `q` for the kind of row.
+        let acc_is_result = is_doubling.clone() + local.is_combine;
+        builder.assert_eq(
+            local.accumulate.acc.is_infinity,
+            acc_is_result.clone() * local.result.is_infinity
+                + local.is_point * local.running.is_infinity
+                + local.is_digit * local.window_sum.is_infinity,
+        );
+        for i in 0..E::BaseField::NB_LIMBS {
+            builder.assert_eq(
+                local.accumulate.acc.x[i],
+                acc_is_result.clone() * local.result.x[i]
+                    + local.is_point * local.running.x[i]
+                    + local.is_digit * local.window_sum.x[i],
+            );
+            builder.assert_eq(
+                local.accumulate.acc.y[i],
+                acc_is_result.clone() * local.result.y[i]
+                    + local.is_point * local.running.y[i]
+                    + local.is_digit * local.window_sum.y[i],
+            );
+            builder.assert_eq(
+                local.accumulate.q_x[i],
+                is_doubling.clone() * local.result.x[i]
+                    + local.is_point * point_x[i]
+                    + local.is_digit * local.running.x[i]
+                    + local.is_combine * local.window_sum.x[i],
+            );
+            builder.assert_eq(
+                local.accumulate.q_y[i],
+                is_doubling.clone() * local.result.y[i]
+                    + local.is_point * point_y[i]
+                    + local.is_digit * local.running.y[i]
+                    + local.is_combine * local.window_sum.y[i],
+            );
+        }
+        builder.assert_eq(
+            local.accumulate.is_enabled,
+            is_doubling * (AB::Expr::one() - local.result.is_infinity)
+                + local.is_point * (AB::Expr::one() - local.digit_is_zero.result)
+                + local.is_digit * (AB::Expr::one() - local.running.is_infinity)
+                + local.is_combine * (AB::Expr::one() - local.window_sum.is_infinity),
+        );
+
+        eval_accumulate::<AB, E>(
+            builder,
+            &local.accumulate,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        weierstrass::{secp256k1::Secp256k1Parameters, SwCurve},
+        AffinePoint,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{call_syscall, slice_at, store_words, to_words},
+    };
+
+    const PTR: u32 = 100;
+
+    /// The pairs of a syscall, as a multiple of the generator for each point.
+    type Pairs = Vec<(BigUint, u32)>;
+
+    /// Builds a program that makes one msm syscall per list of pairs, the lists being laid out one
+    /// after the other from [`PTR`]. Returns the program and the pointer of each list.
+    fn msm_program(syscalls: &[Pairs]) -> (Program, Vec<u32>) {
+        let mut instructions = vec![];
+        let mut ptrs = vec![];
+        let mut ptr = PTR;
+        for pairs in syscalls {
+            ptrs.push(ptr);
+            for (scalar, multiple) in pairs {
+                let mut words = to_words(8, scalar);
+                let point =
+                    SwCurve::<Secp256k1Parameters>::generator().sw_scalar_mul(&(*multiple).into());
+                words.extend(point.to_words_le());
+                store_words(&mut instructions, slice_at(ptr, &words));
+                ptr += words.len() as u32 * 4;
+            }
+        }
+        for (pairs, &ptr) in syscalls.iter().zip(&ptrs) {
+            call_syscall(&mut instructions, SyscallCode::SECP256K1_MSM, ptr, pairs.len() as u32);
+        }
+        (Program::new(instructions, 0, 0), ptrs)
+    }
+
+    fn large_scalar(shift: u32) -> BigUint {
+        // Shifted so that the sums in the tests stay below the order of the curve, and no partial
+        // sum is the point at infinity.
+        let scalar = BigUint::parse_bytes(
+            b"C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+            16,
+        )
+        .unwrap();
+        scalar >> (4 + shift)
+    }
+
+    fn syscalls() -> Vec<Pairs> {
+        vec![
+            vec![(BigUint::from(3u32), 1)],
+            vec![
+                (large_scalar(0), 1),
+                (large_scalar(3), 2),
+                (large_scalar(9), 5),
+                // The same point and scalar as the first pair, so that the running sums double.
+                (large_scalar(0), 1),
+            ],
+            vec![(large_scalar(1), 7), (BigUint::from(0x0100u32), 3)],
+        ]
+    }
+
+    #[test]
+    fn test_secp256k1_msm_execute() {
+        setup_logger();
+        let syscalls = syscalls();
+        let (program, ptrs) = msm_program(&syscalls);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        for (pairs, ptr) in syscalls.iter().zip(ptrs) {
+            let words = (0..16).map(|i| runtime.word(ptr + 32 + i * 4)).collect::<Vec<_>>();
+            let result = AffinePoint::<SwCurve<Secp256k1Parameters>>::from_words_le(&words);
+            let total = pairs
+                .iter()
+                .fold(BigUint::from(0u32), |acc, (scalar, multiple)| acc + scalar * *multiple);
+            let expected = SwCurve::<Secp256k1Parameters>::generator().sw_scalar_mul(&total);
+            assert_eq!(result.x, expected.x);
+            assert_eq!(result.y, expected.y);
+        }
+    }
+
+    #[test]
+    fn test_secp256k1_msm_prove() {
+        setup_logger();
+        let syscalls = syscalls();
+        run_test::<CpuProver<_, _>>(msm_program(&syscalls[..2]).0).unwrap();
+    }
+}
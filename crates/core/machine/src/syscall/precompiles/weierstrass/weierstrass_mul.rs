@@ -0,0 +1,1051 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::marker::PhantomData;
+
+use crate::air::{MemoryAirBuilder, WordAirBuilder};
+use generic_array::GenericArray;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, EllipticCurveMulEvent, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::WeierstrassParameters,
+    AffinePoint, CurveType, EllipticCurve,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{MachineAir, PaddingStrategy, SP1AirBuilder},
+    MachineRecord, Word,
+};
+
+use crate::{
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+    utils::{limbs_from_access, pad_rows_with_strategy},
+};
+
+/// The number of bits in a scalar. Each bit takes up one row.
+pub const NUM_SCALAR_BITS: usize = 256;
+
+const NUM_SCALAR_BYTES: usize = NUM_SCALAR_BITS / 8;
+
+const NUM_SCALAR_WORDS: usize = NUM_SCALAR_BYTES / 4;
+
+pub const fn num_weierstrass_mul_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<WeierstrassMulAssignCols<u8, P>>()
+}
+
+/// A set of columns to multiply a point on a Weierstrass curve by a scalar.
+///
+/// Each syscall takes up one row per scalar bit, most significant first. Each row doubles the
+/// accumulator and conditionally adds the point to it. The point and the scalar are read from
+/// memory in the first row and the result is written to memory in the last row.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WeierstrassMulAssignCols<T, P: FieldParameters + NumWords> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub scalar_ptr: T,
+
+    /// Which bit of the current scalar byte this row processes, most significant first.
+    pub bit_flags: [T; 8],
+    /// Which byte of the scalar this row processes, most significant first.
+    pub byte_flags: [T; NUM_SCALAR_BYTES],
+    /// If the row is real and processes the most significant bit of the scalar.
+    pub is_first: T,
+    /// If the row is real and processes the least significant bit of the scalar.
+    pub is_last: T,
+
+    pub p_access: GenericArray<MemoryReadCols<T>, P::WordsCurvePoint>,
+    pub scalar_access: [MemoryReadCols<T>; NUM_SCALAR_WORDS],
+    pub result_access: GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>,
+
+    /// The point and the scalar, carried through the rows of the syscall.
+    pub p_x: Limbs<T, P::Limbs>,
+    pub p_y: Limbs<T, P::Limbs>,
+    pub scalar: [Word<T>; NUM_SCALAR_WORDS],
+
+    /// The scalar bit processed in this row.
+    pub bit: T,
+    /// The bits of the current scalar byte up to and including this row.
+    pub bit_acc: T,
+
+    /// The accumulator at the start of the row. When it is the point at infinity, the
+    /// coordinates hold the point so that doubling stays well defined.
+    pub acc_is_infinity: T,
+    pub acc_x: Limbs<T, P::Limbs>,
+    pub acc_y: Limbs<T, P::Limbs>,
+    pub(crate) double: EcDoubleCols<T, P>,
+
+    /// If the point is added to the doubled accumulator.
+    pub is_add: T,
+    /// The doubled accumulator if `is_add` is set. Otherwise it's any value that keeps the
+    /// addition well defined, since the sum is discarded.
+    pub add_input_x: Limbs<T, P::Limbs>,
+    pub add_input_y: Limbs<T, P::Limbs>,
+    pub(crate) add: EcAddCols<T, P>,
+
+    /// The accumulator at the end of the row.
+    pub result_x: Limbs<T, P::Limbs>,
+    pub result_y: Limbs<T, P::Limbs>,
+}
+
+/// The columns to double a point, with the same formulas as [`super::WeierstrassDoubleAssignCols`].
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub(crate) struct EcDoubleCols<T, P: FieldParameters> {
+    slope_denominator: FieldOpCols<T, P>,
+    slope_numerator: FieldOpCols<T, P>,
+    slope: FieldOpCols<T, P>,
+    x_squared: FieldOpCols<T, P>,
+    x_squared_times_3: FieldOpCols<T, P>,
+    slope_squared: FieldOpCols<T, P>,
+    x_plus_x: FieldOpCols<T, P>,
+    x3_ins: FieldOpCols<T, P>,
+    x_minus_x3: FieldOpCols<T, P>,
+    y3_ins: FieldOpCols<T, P>,
+    slope_times_x_minus_x3: FieldOpCols<T, P>,
+}
+
+/// The columns to add two points, with the same formulas as [`super::WeierstrassAddAssignCols`].
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub(crate) struct EcAddCols<T, P: FieldParameters> {
+    slope_denominator: FieldOpCols<T, P>,
+    slope_numerator: FieldOpCols<T, P>,
+    slope: FieldOpCols<T, P>,
+    slope_squared: FieldOpCols<T, P>,
+    p_x_plus_q_x: FieldOpCols<T, P>,
+    x3_ins: FieldOpCols<T, P>,
+    p_x_minus_x3: FieldOpCols<T, P>,
+    y3_ins: FieldOpCols<T, P>,
+    slope_times_p_x_minus_x3: FieldOpCols<T, P>,
+}
+
+impl<F: PrimeField32, P: FieldParameters> EcDoubleCols<F, P> {
+    /// Populates the columns to double `(x, y)` on the curve `y^2 = x^3 + a * x + b`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn populate(
+        &mut self,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        x: &BigUint,
+        y: &BigUint,
+        a: &BigUint,
+    ) -> (BigUint, BigUint) {
+        // slope = (a + (x * x) * 3) / (2 * y).
+        let x_squared =
+            self.x_squared.populate(blu_events, shard, channel, x, x, FieldOperation::Mul);
+        let x_squared_times_3 = self.x_squared_times_3.populate(
+            blu_events,
+            shard,
+            channel,
+            &x_squared,
+            &BigUint::from(3u32),
+            FieldOperation::Mul,
+        );
+        let slope_numerator = self.slope_numerator.populate(
+            blu_events,
+            shard,
+            channel,
+            a,
+            &x_squared_times_3,
+            FieldOperation::Add,
+        );
+        let slope_denominator = self.slope_denominator.populate(
+            blu_events,
+            shard,
+            channel,
+            &BigUint::from(2u32),
+            y,
+            FieldOperation::Mul,
+        );
+        let slope = self.slope.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope_numerator,
+            &slope_denominator,
+            FieldOperation::Div,
+        );
+
+        // x3 = slope * slope - (x + x).
+        let slope_squared = self.slope_squared.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope,
+            &slope,
+            FieldOperation::Mul,
+        );
+        let x_plus_x =
+            self.x_plus_x.populate(blu_events, shard, channel, x, x, FieldOperation::Add);
+        let x3 = self.x3_ins.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope_squared,
+            &x_plus_x,
+            FieldOperation::Sub,
+        );
+
+        // y3 = slope * (x - x3) - y.
+        let x_minus_x3 =
+            self.x_minus_x3.populate(blu_events, shard, channel, x, &x3, FieldOperation::Sub);
+        let slope_times_x_minus_x3 = self.slope_times_x_minus_x3.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope,
+            &x_minus_x3,
+            FieldOperation::Mul,
+        );
+        let y3 = self.y3_ins.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope_times_x_minus_x3,
+            y,
+            FieldOperation::Sub,
+        );
+
+        (x3, y3)
+    }
+}
+
+impl<F: PrimeField32, P: FieldParameters> EcAddCols<F, P> {
+    /// Populates the columns to add `(p_x, p_y)` and `(q_x, q_y)`, which must have different x
+    /// coordinates.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn populate(
+        &mut self,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        p_x: &BigUint,
+        p_y: &BigUint,
+        q_x: &BigUint,
+        q_y: &BigUint,
+    ) -> (BigUint, BigUint) {
+        // slope = (q.y - p.y) / (q.x - p.x).
+        let slope_numerator = self.slope_numerator.populate(
+            blu_events,
+            shard,
+            channel,
+            q_y,
+            p_y,
+            FieldOperation::Sub,
+        );
+        let slope_denominator = self.slope_denominator.populate(
+            blu_events,
+            shard,
+            channel,
+            q_x,
+            p_x,
+            FieldOperation::Sub,
+        );
+        let slope = self.slope.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope_numerator,
+            &slope_denominator,
+            FieldOperation::Div,
+        );
+
+        // x3 = slope * slope - (p.x + q.x).
+        let slope_squared = self.slope_squared.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope,
+            &slope,
+            FieldOperation::Mul,
+        );
+        let p_x_plus_q_x =
+            self.p_x_plus_q_x.populate(blu_events, shard, channel, p_x, q_x, FieldOperation::Add);
+        let x3 = self.x3_ins.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope_squared,
+            &p_x_plus_q_x,
+            FieldOperation::Sub,
+        );
+
+        // y3 = slope * (p.x - x3) - p.y.
+        let p_x_minus_x3 =
+            self.p_x_minus_x3.populate(blu_events, shard, channel, p_x, &x3, FieldOperation::Sub);
+        let slope_times_p_x_minus_x3 = self.slope_times_p_x_minus_x3.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope,
+            &p_x_minus_x3,
+            FieldOperation::Mul,
+        );
+        let y3 = self.y3_ins.populate(
+            blu_events,
+            shard,
+            channel,
+            &slope_times_p_x_minus_x3,
+            p_y,
+            FieldOperation::Sub,
+        );
+
+        (x3, y3)
+    }
+}
+
+impl<V: Copy, P: FieldParameters> EcDoubleCols<V, P> {
+    /// The coordinates of the doubled point.
+    pub(crate) fn result(&self) -> (&Limbs<V, P::Limbs>, &Limbs<V, P::Limbs>) {
+        (&self.x3_ins.result, &self.y3_ins.result)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        x: &Limbs<V, P::Limbs>,
+        y: &Limbs<V, P::Limbs>,
+        a: &Limbs<AB::Expr, P::Limbs>,
+        shard: V,
+        channel: V,
+        is_real: V,
+    ) where
+        V: Into<AB::Expr>,
+        Limbs<V, P::Limbs>: Copy,
+    {
+        // slope = (a + (x * x) * 3) / (2 * y).
+        self.x_squared.eval(builder, x, x, FieldOperation::Mul, shard, channel, is_real);
+        self.x_squared_times_3.eval(
+            builder,
+            &self.x_squared.result,
+            &P::to_limbs_field::<AB::Expr, _>(&BigUint::from(3u32)),
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        self.slope_numerator.eval(
+            builder,
+            a,
+            &self.x_squared_times_3.result,
+            FieldOperation::Add,
+            shard,
+            channel,
+            is_real,
+        );
+        self.slope_denominator.eval(
+            builder,
+            &P::to_limbs_field::<AB::Expr, _>(&BigUint::from(2u32)),
+            y,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        self.slope.eval(
+            builder,
+            &self.slope_numerator.result,
+            &self.slope_denominator.result,
+            FieldOperation::Div,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // x3 = slope * slope - (x + x).
+        self.slope_squared.eval(
+            builder,
+            &self.slope.result,
+            &self.slope.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        self.x_plus_x.eval(builder, x, x, FieldOperation::Add, shard, channel, is_real);
+        self.x3_ins.eval(
+            builder,
+            &self.slope_squared.result,
+            &self.x_plus_x.result,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // y3 = slope * (x - x3) - y.
+        self.x_minus_x3.eval(
+            builder,
+            x,
+            &self.x3_ins.result,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_real,
+        );
+        self.slope_times_x_minus_x3.eval(
+            builder,
+            &self.slope.result,
+            &self.x_minus_x3.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        self.y3_ins.eval(
+            builder,
+            &self.slope_times_x_minus_x3.result,
+            y,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
+
+impl<V: Copy, P: FieldParameters> EcAddCols<V, P> {
+    /// The coordinates of the sum.
+    pub(crate) fn result(&self) -> (&Limbs<V, P::Limbs>, &Limbs<V, P::Limbs>) {
+        (&self.x3_ins.result, &self.y3_ins.result)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        p_x: &Limbs<V, P::Limbs>,
+        p_y: &Limbs<V, P::Limbs>,
+        q_x: &Limbs<V, P::Limbs>,
+        q_y: &Limbs<V, P::Limbs>,
+        shard: V,
+        channel: V,
+        is_real: V,
+    ) where
+        V: Into<AB::Expr>,
+        Limbs<V, P::Limbs>: Copy,
+    {
+        // slope = (q.y - p.y) / (q.x - p.x).
+        self.slope_numerator.eval(builder, q_y, p_y, FieldOperation::Sub, shard, channel, is_real);
+        self.slope_denominator.eval(
+            builder,
+            q_x,
+            p_x,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_real,
+        );
+        self.slope.eval(
+            builder,
+            &self.slope_numerator.result,
+            &self.slope_denominator.result,
+            FieldOperation::Div,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // x3 = slope * slope - (p.x + q.x).
+        self.slope_squared.eval(
+            builder,
+            &self.slope.result,
+            &self.slope.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        self.p_x_plus_q_x.eval(builder, p_x, q_x, FieldOperation::Add, shard, channel, is_real);
+        self.x3_ins.eval(
+            builder,
+            &self.slope_squared.result,
+            &self.p_x_plus_q_x.result,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // y3 = slope * (p.x - x3) - p.y.
+        self.p_x_minus_x3.eval(
+            builder,
+            p_x,
+            &self.x3_ins.result,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_real,
+        );
+        self.slope_times_p_x_minus_x3.eval(
+            builder,
+            &self.slope.result,
+            &self.p_x_minus_x3.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        self.y3_ins.eval(
+            builder,
+            &self.slope_times_p_x_minus_x3.result,
+            p_y,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
+
+/// Multiplies a point on a Weierstrass curve by a scalar with double-and-add.
+///
+/// A windowed NAF decomposition would need fewer additions, but every row pays for the addition
+/// columns whether or not they are used, so it would not make the trace any smaller.
+#[derive(Default)]
+pub struct WeierstrassMulAssignChip<E> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: EllipticCurve + WeierstrassParameters> WeierstrassMulAssignChip<E> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn event_rows<F: PrimeField32>(
+        event: &EllipticCurveMulEvent,
+        blu_events: &mut Vec<ByteLookupEvent>,
+    ) -> Vec<Vec<F>> {
+        let p = AffinePoint::<E>::from_words_le(&event.p);
+        let scalar = BigUint::from_slice(&event.scalar);
+        let a = E::a_int();
+
+        let mut rows = Vec::with_capacity(NUM_SCALAR_BITS);
+        let mut acc: Option<AffinePoint<E>> = None;
+        let mut bit_acc = 0u32;
+        for i in 0..NUM_SCALAR_BITS {
+            let mut row = vec![F::zero(); num_weierstrass_mul_cols::<E::BaseField>()];
+            let cols: &mut WeierstrassMulAssignCols<F, E::BaseField> =
+                row.as_mut_slice().borrow_mut();
+
+            // Populate basic columns.
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u8(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+            cols.scalar_ptr = F::from_canonical_u32(event.scalar_ptr);
+            cols.is_first = F::from_bool(i == 0);
+            cols.is_last = F::from_bool(i == NUM_SCALAR_BITS - 1);
+
+            // Populate the memory access columns.
+            if i == 0 {
+                for (access, record) in cols.p_access.iter_mut().zip(&event.p_memory_records) {
+                    access.populate(event.channel, *record, blu_events);
+                }
+                for (access, record) in
+                    cols.scalar_access.iter_mut().zip(&event.scalar_memory_records)
+                {
+                    access.populate(event.channel, *record, blu_events);
+                }
+            }
+            if i == NUM_SCALAR_BITS - 1 {
+                for (access, record) in
+                    cols.result_access.iter_mut().zip(&event.result_memory_records)
+                {
+                    access.populate(event.channel, *record, blu_events);
+                }
+            }
+
+            cols.p_x = E::BaseField::to_limbs_field::<F, _>(&p.x);
+            cols.p_y = E::BaseField::to_limbs_field::<F, _>(&p.y);
+            cols.scalar = core::array::from_fn(|j| Word::from(event.scalar[j]));
+
+            let bit = scalar.bit((NUM_SCALAR_BITS - 1 - i) as u64);
+            bit_acc = if i % 8 == 0 { bit as u32 } else { 2 * bit_acc + bit as u32 };
+            cols.bit = F::from_bool(bit);
+            cols.bit_acc = F::from_canonical_u32(bit_acc);
+
+            let (acc_x, acc_y) = match &acc {
+                Some(acc) => (acc.x.clone(), acc.y.clone()),
+                None => (p.x.clone(), p.y.clone()),
+            };
+            cols.acc_is_infinity = F::from_bool(acc.is_none());
+            cols.acc_x = E::BaseField::to_limbs_field::<F, _>(&acc_x);
+            cols.acc_y = E::BaseField::to_limbs_field::<F, _>(&acc_y);
+            let (doubled_x, doubled_y) =
+                cols.double.populate(blu_events, event.shard, event.channel, &acc_x, &acc_y, &a);
+
+            // When the sum is discarded, add the point to one with a different x coordinate.
+            let is_add = acc.is_some() && bit;
+            let (add_input_x, add_input_y) = if is_add {
+                (doubled_x.clone(), doubled_y.clone())
+            } else {
+                ((&p.x + 1u32) % E::BaseField::modulus(), BigUint::zero())
+            };
+            cols.is_add = F::from_bool(is_add);
+            cols.add_input_x = E::BaseField::to_limbs_field::<F, _>(&add_input_x);
+            cols.add_input_y = E::BaseField::to_limbs_field::<F, _>(&add_input_y);
+            let (added_x, added_y) = cols.add.populate(
+                blu_events,
+                event.shard,
+                event.channel,
+                &add_input_x,
+                &add_input_y,
+                &p.x,
+                &p.y,
+            );
+
+            let (result_x, result_y) = match (&acc, bit) {
+                (None, _) => (p.x.clone(), p.y.clone()),
+                (Some(_), true) => (added_x, added_y),
+                (Some(_), false) => (doubled_x, doubled_y),
+            };
+            cols.result_x = E::BaseField::to_limbs_field::<F, _>(&result_x);
+            cols.result_y = E::BaseField::to_limbs_field::<F, _>(&result_y);
+            if acc.is_some() || bit {
+                acc = Some(AffinePoint::new(result_x, result_y));
+            }
+
+            rows.push(row);
+        }
+        rows
+    }
+}
+
+impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
+    for WeierstrassMulAssignChip<E>
+{
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => "Secp256k1MulAssign".to_string(),
+            CurveType::P256 => "P256MulAssign".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => &input.secp256k1_mul_events,
+            CurveType::P256 => &input.p256_mul_events,
+            _ => panic!("Unsupported curve"),
+        };
+
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        // Generate the trace rows & corresponding records for each chunk of events in parallel.
+        let rows_and_records = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut record = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+                let rows = events
+                    .iter()
+                    .flat_map(|event| Self::event_rows::<F>(event, &mut new_byte_lookup_events))
+                    .collect::<Vec<_>>();
+                record.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, record)
+            })
+            .collect::<Vec<_>>();
+
+        let mut rows = Vec::new();
+        for mut row_and_record in rows_and_records {
+            rows.extend(row_and_record.0);
+            output.append(&mut row_and_record.1);
+        }
+
+        pad_rows_with_strategy(&mut rows, MachineAir::<F>::padding_strategy(self), |_| {
+            let mut row = vec![F::zero(); num_weierstrass_mul_cols::<E::BaseField>()];
+            let cols: &mut WeierstrassMulAssignCols<F, E::BaseField> =
+                row.as_mut_slice().borrow_mut();
+            // Padding rows double `(0, 1)`, so that the slope is well defined on curves with a
+            // nonzero `a`.
+            let zero = BigUint::zero();
+            let one = BigUint::one();
+            cols.acc_y = E::BaseField::to_limbs_field::<F, _>(&one);
+            cols.double.populate(&mut vec![], 0, 0, &zero, &one, &E::a_int());
+            cols.add.populate(&mut vec![], 0, 0, &zero, &zero, &zero, &zero);
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_weierstrass_mul_cols::<E::BaseField>(),
+        );
+
+        // Write the nonces and the bit and byte flags to the trace. The number of rows is a power
+        // of two, so the flags cycle through the padding rows as well.
+        for i in 0..trace.height() {
+            let cols: &mut WeierstrassMulAssignCols<F, E::BaseField> = trace.values[i
+                * num_weierstrass_mul_cols::<E::BaseField>()
+                ..(i + 1) * num_weierstrass_mul_cols::<E::BaseField>()]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+            cols.bit_flags[i % 8] = F::one();
+            cols.byte_flags[(i / 8) % NUM_SCALAR_BYTES] = F::one();
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => !shard.secp256k1_mul_events.is_empty(),
+            CurveType::P256 => !shard.p256_mul_events.is_empty(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::DummyEvent
+    }
+}
+
+impl<F, E: EllipticCurve + WeierstrassParameters> BaseAir<F> for WeierstrassMulAssignChip<E> {
+    fn width(&self) -> usize {
+        num_weierstrass_mul_cols::<E::BaseField>()
+    }
+}
+
+impl<AB, E: EllipticCurve + WeierstrassParameters> Air<AB> for WeierstrassMulAssignChip<E>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &WeierstrassMulAssignCols<AB::Var, E::BaseField> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &WeierstrassMulAssignCols<AB::Var, E::BaseField> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The bit flags are one-hot and advance every row. The byte flags are one-hot and advance
+        // after the last bit of each byte.
+        for &flag in local.bit_flags.iter().chain(local.byte_flags.iter()) {
+            builder.assert_bool(flag);
+        }
+        builder.assert_one(local.bit_flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag));
+        builder.assert_one(local.byte_flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag));
+        builder.when_first_row().assert_one(local.bit_flags[0]);
+        builder.when_first_row().assert_one(local.byte_flags[0]);
+        let last_bit = local.bit_flags[7];
+        for i in 0..8 {
+            builder.when_transition().assert_eq(local.bit_flags[i], next.bit_flags[(i + 1) % 8]);
+        }
+        for i in 0..NUM_SCALAR_BYTES {
+            builder
+                .when_transition()
+                .when(last_bit)
+                .assert_eq(local.byte_flags[i], next.byte_flags[(i + 1) % NUM_SCALAR_BYTES]);
+            builder
+                .when_transition()
+                .when(AB::Expr::one() - last_bit)
+                .assert_eq(local.byte_flags[i], next.byte_flags[i]);
+        }
+
+        // Each syscall takes up a block of rows that starts at the first flags and ends at the
+        // last flags.
+        let block_start = local.byte_flags[0] * local.bit_flags[0];
+        let block_end = local.byte_flags[NUM_SCALAR_BYTES - 1] * last_bit;
+        builder.assert_bool(local.is_real);
+        builder.assert_eq(local.is_first, block_start * local.is_real);
+        builder.assert_eq(local.is_last, block_end.clone() * local.is_real);
+        builder
+            .when_transition()
+            .when(AB::Expr::one() - block_end)
+            .assert_eq(local.is_real, next.is_real);
+
+        // Constrain that the inputs stay the same throughout the rows of each syscall.
+        let not_last = local.is_real - local.is_last;
+        let mut transition_builder = builder.when_transition();
+        let mut carry_builder = transition_builder.when(not_last.clone());
+        carry_builder.assert_eq(local.shard, next.shard);
+        carry_builder.assert_eq(local.channel, next.channel);
+        carry_builder.assert_eq(local.clk, next.clk);
+        carry_builder.assert_eq(local.p_ptr, next.p_ptr);
+        carry_builder.assert_eq(local.scalar_ptr, next.scalar_ptr);
+        for i in 0..E::BaseField::NB_LIMBS {
+            carry_builder.assert_eq(local.p_x[i], next.p_x[i]);
+            carry_builder.assert_eq(local.p_y[i], next.p_y[i]);
+        }
+        for i in 0..NUM_SCALAR_WORDS {
+            carry_builder.assert_word_eq(local.scalar[i], next.scalar[i]);
+        }
+
+        // In the first row, the point and the scalar come from memory.
+        let num_words_field_element = E::BaseField::NB_LIMBS / 4;
+        let p_x: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.p_access[0..num_words_field_element]);
+        let p_y: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.p_access[num_words_field_element..]);
+        for i in 0..E::BaseField::NB_LIMBS {
+            builder.when(local.is_first).assert_eq(local.p_x[i], p_x[i]);
+            builder.when(local.is_first).assert_eq(local.p_y[i], p_y[i]);
+        }
+        for i in 0..NUM_SCALAR_WORDS {
+            builder
+                .when(local.is_first)
+                .assert_word_eq(local.scalar[i], *local.scalar_access[i].value());
+        }
+
+        // The bits of each scalar byte, most significant first, must add up to the byte.
+        builder.assert_bool(local.bit);
+        builder.when(local.bit_flags[0]).assert_eq(local.bit_acc, local.bit);
+        builder
+            .when_transition()
+            .when(AB::Expr::one() - last_bit)
+            .assert_eq(next.bit_acc, local.bit_acc * AB::F::from_canonical_u32(2) + next.bit);
+        let scalar_byte = (0..NUM_SCALAR_BYTES).fold(AB::Expr::zero(), |acc, i| {
+            let byte = NUM_SCALAR_BYTES - 1 - i;
+            acc + local.byte_flags[i] * local.scalar[byte / 4][byte % 4]
+        });
+        builder.when(last_bit).assert_eq(local.bit_acc, scalar_byte);
+
+        // The accumulator starts at the point at infinity, and stays there until the first set
+        // bit.
+        builder.assert_bool(local.acc_is_infinity);
+        builder.when(local.is_first).assert_one(local.acc_is_infinity);
+        builder.assert_eq(local.is_add, (AB::Expr::one() - local.acc_is_infinity) * local.bit);
+
+        // Double the accumulator and add the point to it.
+        let a = E::BaseField::to_limbs_field::<AB::Expr, _>(&E::a_int());
+        local.double.eval(
+            builder,
+            &local.acc_x,
+            &local.acc_y,
+            &a,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        let (doubled_x, doubled_y) = local.double.result();
+        for i in 0..E::BaseField::NB_LIMBS {
+            builder.when(local.is_add).assert_eq(local.add_input_x[i], doubled_x[i]);
+            builder.when(local.is_add).assert_eq(local.add_input_y[i], doubled_y[i]);
+        }
+        local.add.eval(
+            builder,
+            &local.add_input_x,
+            &local.add_input_y,
+            &local.p_x,
+            &local.p_y,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        let (added_x, added_y) = local.add.result();
+
+        // The result is the point if the accumulator is the point at infinity, the sum if the
+        // point was added, and the doubled accumulator otherwise.
+        let is_double = AB::Expr::one() - local.acc_is_infinity - local.is_add;
+        for i in 0..E::BaseField::NB_LIMBS {
+            builder.when(local.is_real).assert_eq(
+                local.result_x[i],
+                local.acc_is_infinity * local.p_x[i]
+                    + local.is_add * added_x[i]
+                    + is_double.clone() * doubled_x[i],
+            );
+            builder.when(local.is_real).assert_eq(
+                local.result_y[i],
+                local.acc_is_infinity * local.p_y[i]
+                    + local.is_add * added_y[i]
+                    + is_double.clone() * doubled_y[i],
+            );
+        }
+
+        // Carry the result to the next row.
+        let mut transition_builder = builder.when_transition();
+        let mut carry_builder = transition_builder.when(not_last);
+        carry_builder
+            .assert_eq(next.acc_is_infinity, local.acc_is_infinity * (AB::Expr::one() - local.bit));
+        for i in 0..E::BaseField::NB_LIMBS {
+            carry_builder.assert_eq(next.acc_x[i], local.result_x[i]);
+            carry_builder.assert_eq(next.acc_y[i], local.result_y[i]);
+        }
+
+        // In the last row, the result can't be the point at infinity and is written to memory.
+        builder
+            .when(local.is_last)
+            .assert_zero(local.acc_is_infinity * (AB::Expr::one() - local.bit));
+        for i in 0..E::BaseField::NB_LIMBS {
+            builder
+                .when(local.is_last)
+                .assert_eq(local.result_x[i], local.result_access[i / 4].value()[i % 4]);
+            builder.when(local.is_last).assert_eq(
+                local.result_y[i],
+                local.result_access[num_words_field_element + i / 4].value()[i % 4],
+            );
+        }
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.p_ptr,
+            &local.p_access,
+            local.is_first,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.scalar_ptr,
+            &local.scalar_access,
+            local.is_first,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_u32(1), // The result is written after the reads.
+            local.p_ptr,
+            &local.result_access,
+            local.is_last,
+        );
+
+        // Fetch the syscall id for the curve type.
+        let syscall_id_felt = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => {
+                AB::F::from_canonical_u32(SyscallCode::SECP256K1_MUL.syscall_id())
+            }
+            CurveType::P256 => AB::F::from_canonical_u32(SyscallCode::P256_MUL.syscall_id()),
+            _ => panic!("Unsupported curve"),
+        };
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.p_ptr,
+            local.scalar_ptr,
+            local.is_first,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigUint, One};
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Program};
+    use sp1_curves::{
+        weierstrass::{
+            p256::P256Parameters, secp256k1::Secp256k1Parameters, SwCurve, WeierstrassParameters,
+        },
+        AffinePoint,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{
+        run_test, setup_logger,
+        tests::{slice_at, syscall_program, words_at},
+    };
+
+    const P_PTR: u32 = 100;
+    const SCALAR_PTR: u32 = 300;
+
+    /// Builds a program that multiplies the generator of the curve by `scalar`.
+    fn mul_program<E: WeierstrassParameters>(syscall: SyscallCode, scalar: &BigUint) -> Program {
+        let p = SwCurve::<E>::generator().to_words_le();
+        let words = [slice_at(P_PTR, &p), words_at(SCALAR_PTR, 8, &[scalar])].concat();
+        syscall_program(syscall, words, P_PTR, SCALAR_PTR)
+    }
+
+    fn scalars<E: WeierstrassParameters>() -> Vec<BigUint> {
+        let order = E::prime_group_order();
+        vec![
+            BigUint::one(),
+            BigUint::from(3u32),
+            BigUint::parse_bytes(
+                b"C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+                16,
+            )
+            .unwrap(),
+            // In the last row, the doubled accumulator is the negated point, so the skipped
+            // addition would be undefined.
+            &order - BigUint::one(),
+        ]
+    }
+
+    /// Runs the scalar multiplication syscall and checks the result against the reference
+    /// implementation.
+    fn execute_mul<E: WeierstrassParameters>(syscall: SyscallCode) {
+        for scalar in scalars::<E>() {
+            let mut runtime =
+                Executor::new(mul_program::<E>(syscall, &scalar), SP1CoreOpts::default());
+            runtime.run().unwrap();
+
+            let words = (0..16).map(|i| runtime.word(P_PTR + i * 4)).collect::<Vec<_>>();
+            let result = AffinePoint::<SwCurve<E>>::from_words_le(&words);
+            let expected = SwCurve::<E>::generator().sw_scalar_mul(&scalar);
+            assert_eq!(result.x, expected.x);
+            assert_eq!(result.y, expected.y);
+        }
+    }
+
+    #[test]
+    fn test_secp256k1_mul_execute() {
+        setup_logger();
+        execute_mul::<Secp256k1Parameters>(SyscallCode::SECP256K1_MUL);
+    }
+
+    #[test]
+    fn test_secp256k1_mul_prove() {
+        setup_logger();
+        for scalar in scalars::<Secp256k1Parameters>() {
+            run_test::<CpuProver<_, _>>(mul_program::<Secp256k1Parameters>(
+                SyscallCode::SECP256K1_MUL,
+                &scalar,
+            ))
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_p256_mul_execute() {
+        setup_logger();
+        execute_mul::<P256Parameters>(SyscallCode::P256_MUL);
+    }
+
+    #[test]
+    fn test_p256_mul_prove() {
+        setup_logger();
+        for scalar in scalars::<P256Parameters>() {
+            run_test::<CpuProver<_, _>>(mul_program::<P256Parameters>(
+                SyscallCode::P256_MUL,
+                &scalar,
+            ))
+            .unwrap();
+        }
+    }
+}
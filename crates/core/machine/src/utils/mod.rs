@@ -17,7 +17,9 @@ pub use programs::*;
 
 use crate::memory::MemoryCols;
 use generic_array::ArrayLength;
-use p3_maybe_rayon::prelude::{ParallelBridge, ParallelIterator};
+use p3_maybe_rayon::prelude::{ParallelBridge, ParallelIterator, ParallelSlice};
+use sp1_core_executor::events::ByteLookupEvent;
+use sp1_stark::air::PaddingStrategy;
 
 pub const fn indices_arr<const N: usize>() -> [usize; N] {
     let mut indices_arr = [0; N];
@@ -54,6 +56,12 @@ pub fn limbs_from_access<T: Copy, N: ArrayLength, M: MemoryCols<T>>(cols: &[M])
     Limbs(sized)
 }
 
+/// Pads `rows` to the next power of two, and to at least 16 rows, with copies of `row_fn()`.
+///
+/// Most chips pass a row of zeros, which is [`PaddingStrategy::ZeroPad`]: the padding rows then
+/// only satisfy the chip's constraints if each one is gated on `is_real` or holds on zeros. Chips
+/// whose constraints don't allow that should use [`pad_rows_with_strategy`] with the strategy
+/// they declare in [`MachineAir::padding_strategy`](sp1_stark::air::MachineAir::padding_strategy).
 pub fn pad_rows<T: Clone>(rows: &mut Vec<T>, row_fn: impl Fn() -> T) {
     let nb_rows = rows.len();
     let mut padded_nb_rows = nb_rows.next_power_of_two();
@@ -67,6 +75,29 @@ pub fn pad_rows<T: Clone>(rows: &mut Vec<T>, row_fn: impl Fn() -> T) {
     rows.resize(padded_nb_rows, dummy_row);
 }
 
+/// Pads `rows` to the next power of two, and to at least 16 rows, following `strategy`.
+///
+/// For [`PaddingStrategy::ZeroPad`] and [`PaddingStrategy::DummyEvent`], `row_fn` is called with
+/// `None` and returns a row of zeros or the row of the dummy event. For
+/// [`PaddingStrategy::RepeatLast`], it is called with the last row, or `None` if there are no
+/// rows, and returns that row with `is_real` cleared.
+pub fn pad_rows_with_strategy<T: Clone>(
+    rows: &mut Vec<T>,
+    strategy: PaddingStrategy,
+    row_fn: impl Fn(Option<&T>) -> T,
+) {
+    let nb_rows = rows.len();
+    let padded_nb_rows = next_power_of_two(nb_rows, None);
+    if padded_nb_rows == nb_rows {
+        return;
+    }
+    let dummy_row = match strategy {
+        PaddingStrategy::ZeroPad | PaddingStrategy::DummyEvent => row_fn(None),
+        PaddingStrategy::RepeatLast => row_fn(rows.last()),
+    };
+    rows.resize(padded_nb_rows, dummy_row);
+}
+
 pub fn pad_rows_fixed<R: Clone>(
     rows: &mut Vec<R>,
     row_fn: impl Fn() -> R,
@@ -195,6 +226,48 @@ where
     );
 }
 
+/// Returns the number of events per chunk when generating a trace in parallel.
+///
+/// This is the `TRACE_CHUNK_SIZE` environment variable if it is set, and otherwise one chunk per
+/// CPU. The trace doesn't depend on it.
+pub fn trace_chunk_size(num_events: usize) -> usize {
+    std::env::var("TRACE_CHUNK_SIZE")
+        .ok()
+        .and_then(|size| size.parse::<usize>().ok())
+        .unwrap_or_else(|| num_events / num_cpus::get())
+        .max(1)
+}
+
+/// Generates the rows of each event in parallel, in chunks of [`trace_chunk_size`] events.
+///
+/// Every chunk collects its byte lookups into its own vector, so `generate` never shares mutable
+/// state across threads. The rows and the byte lookups of the chunks are then concatenated in
+/// event order, so the result is the same as a sequential loop over the events, whatever the
+/// chunk size or the scheduling of the threads.
+pub fn par_generate_rows<E, R, G>(events: &[E], generate: G) -> (Vec<R>, Vec<ByteLookupEvent>)
+where
+    E: Sync,
+    R: Send,
+    G: Fn(&E, &mut Vec<ByteLookupEvent>) -> R + Send + Sync,
+{
+    let chunks = events
+        .par_chunks(trace_chunk_size(events.len()))
+        .map(|events| {
+            let mut blu = Vec::new();
+            let rows = events.iter().map(|event| generate(event, &mut blu)).collect::<Vec<_>>();
+            (rows, blu)
+        })
+        .collect::<Vec<_>>();
+
+    let mut rows = Vec::with_capacity(events.len());
+    let mut blu = Vec::new();
+    for (chunk_rows, chunk_blu) in chunks {
+        rows.extend(chunk_rows);
+        blu.extend(chunk_blu);
+    }
+    (rows, blu)
+}
+
 /// Returns whether the `SP1_DEBUG` environment variable is enabled or disabled.
 ///
 /// This variable controls whether backtraces are attached to compiled circuit programs, as well
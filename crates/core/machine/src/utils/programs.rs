@@ -1,4 +1,7 @@
 pub mod tests {
+    use num::BigUint;
+    use sp1_core_executor::{syscalls::SyscallCode, Instruction, Opcode, Program};
+
     /// Demos.
 
     pub const CHESS_ELF: &[u8] =
@@ -100,4 +103,68 @@ pub mod tests {
 
     pub const PANIC_ELF: &[u8] =
         include_bytes!("../../../../../tests/panic/elf/riscv32im-succinct-zkvm-elf");
+
+    /// Syscall programs.
+
+    /// Appends the instructions that store each of the `(address, word)` pairs to memory.
+    pub fn store_words(
+        instructions: &mut Vec<Instruction>,
+        words: impl IntoIterator<Item = (u32, u32)>,
+    ) {
+        for (addr, word) in words {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 29, 0, word, false, true),
+                Instruction::new(Opcode::ADD, 30, 0, addr, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            ]);
+        }
+    }
+
+    /// Appends the instructions that invoke the given syscall with `arg1` and `arg2`.
+    pub fn call_syscall(
+        instructions: &mut Vec<Instruction>,
+        syscall: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) {
+        instructions.extend(vec![
+            Instruction::new(Opcode::ADD, 5, 0, syscall as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, arg1, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, arg2, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+    }
+
+    /// Builds a program that stores the given `(address, word)` pairs and invokes the given
+    /// syscall with `arg1` and `arg2`.
+    pub fn syscall_program(
+        syscall: SyscallCode,
+        words: impl IntoIterator<Item = (u32, u32)>,
+        arg1: u32,
+        arg2: u32,
+    ) -> Program {
+        let mut instructions = vec![];
+        store_words(&mut instructions, words);
+        call_syscall(&mut instructions, syscall, arg1, arg2);
+        Program::new(instructions, 0, 0)
+    }
+
+    /// Returns the `(address, word)` pairs that store the given words contiguously at `ptr`.
+    pub fn slice_at(ptr: u32, words: &[u32]) -> Vec<(u32, u32)> {
+        words.iter().enumerate().map(|(i, &w)| (ptr + i as u32 * 4, w)).collect()
+    }
+
+    /// Returns the little endian words of `value`, padded to `num_words` words.
+    pub fn to_words(num_words: usize, value: &BigUint) -> Vec<u32> {
+        let mut words = value.to_u32_digits();
+        words.resize(num_words, 0);
+        words
+    }
+
+    /// Returns the `(address, word)` pairs that store the given values contiguously at `ptr`, each
+    /// padded to `num_words` words.
+    pub fn words_at(ptr: u32, num_words: usize, values: &[&BigUint]) -> Vec<(u32, u32)> {
+        let words = values.iter().flat_map(|value| to_words(num_words, value)).collect::<Vec<_>>();
+        slice_at(ptr, &words)
+    }
 }
@@ -29,8 +29,8 @@ use sp1_core_executor::events::sorted_table_lines;
 use sp1_primitives::io::SP1PublicValues;
 
 use sp1_core_executor::{
-    subproof::NoOpSubproofVerifier, ExecutionError, ExecutionRecord, ExecutionReport, Executor,
-    Program, SP1Context,
+    subproof::NoOpSubproofVerifier, CycleReport, ExecutionError, ExecutionRecord, ExecutionReport,
+    Executor, Program, SP1Context,
 };
 use sp1_stark::{
     air::{MachineAir, PublicValues},
@@ -97,7 +97,7 @@ pub fn prove<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<SC::Val>>>(
     stdin: &SP1Stdin,
     config: SC,
     opts: SP1CoreOpts,
-) -> Result<(MachineProof<SC>, Vec<u8>, u64), SP1CoreProverError>
+) -> Result<(MachineProof<SC>, Vec<u8>, u64, CycleReport), SP1CoreProverError>
 where
     SC::Challenger: 'static + Clone + Send,
     <SC as StarkGenericConfig>::Val: PrimeField32,
@@ -118,7 +118,7 @@ pub fn prove_with_context<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<
     stdin: &SP1Stdin,
     opts: SP1CoreOpts,
     context: SP1Context,
-) -> Result<(MachineProof<SC>, Vec<u8>, u64), SP1CoreProverError>
+) -> Result<(MachineProof<SC>, Vec<u8>, u64, CycleReport), SP1CoreProverError>
 where
     SC::Val: PrimeField32,
     SC::Challenger: 'static + Clone + Send,
@@ -370,8 +370,9 @@ where
             challenger
         });
 
-        // Wait until the checkpoint generator handle has fully finished.
-        let public_values_stream = checkpoint_generator_handle.join().unwrap().unwrap();
+        // Wait until the checkpoint generator handle has fully finished. If the execution failed,
+        // the other threads stop once they have handled the checkpoints that were already sent.
+        let public_values_stream = checkpoint_generator_handle.join().unwrap()?;
 
         // Wait until the records and traces have been fully generated.
         p1_record_and_trace_gen_handles.into_iter().for_each(|handle| handle.join().unwrap());
@@ -389,6 +390,7 @@ where
         let p2_records_and_traces_tx = Arc::new(Mutex::new(p2_records_and_traces_tx));
 
         let report_aggregate = Arc::new(Mutex::new(ExecutionReport::default()));
+        let cycle_tracker_events = Arc::new(Mutex::new(Vec::new()));
         let state = Arc::new(Mutex::new(PublicValues::<u32, u32>::default().reset()));
         let deferred = Arc::new(Mutex::new(ExecutionRecord::new(program.clone().into())));
         let mut p2_record_and_trace_gen_handles = Vec::new();
@@ -398,6 +400,7 @@ where
             let records_and_traces_tx = Arc::clone(&p2_records_and_traces_tx);
 
             let report_aggregate = Arc::clone(&report_aggregate);
+            let cycle_tracker_events = Arc::clone(&cycle_tracker_events);
             let checkpoints = Arc::clone(&checkpoints);
             let state = Arc::clone(&state);
             let deferred = Arc::clone(&deferred);
@@ -415,6 +418,9 @@ where
                             let (mut records, report) = tracing::debug_span!("trace checkpoint")
                                 .in_scope(|| trace_checkpoint(program.clone(), &checkpoint, opts));
                             *report_aggregate.lock().unwrap() += report;
+                            cycle_tracker_events.lock().unwrap().extend(
+                                records.iter().flat_map(|r| r.cycle_tracker_events.iter().copied()),
+                            );
                             reset_seek(&mut checkpoint);
 
                             // Generate the dependencies.
@@ -554,6 +560,19 @@ where
             tracing::info!("  {line}");
         }
 
+        let cycle_report = CycleReport::from_events(cycle_tracker_events.lock().unwrap().iter());
+        if !cycle_report.entries.is_empty() {
+            tracing::info!("execution report (cycle tracker):");
+            for entry in &cycle_report.entries {
+                tracing::info!(
+                    "  {:#010x}: {} cycles over {} calls",
+                    entry.label_hash,
+                    entry.total_cycles,
+                    entry.count
+                );
+            }
+        }
+
         let proof = MachineProof::<SC> { shard_proofs };
         let cycles = report_aggregate.total_instruction_count();
 
@@ -575,7 +594,7 @@ where
             prover.machine().debug_constraints(pk, all_records, &mut challenger);
         }
 
-        Ok((proof, public_values_stream, cycles))
+        Ok((proof, public_values_stream, cycles, cycle_report))
     })
 }
 
@@ -615,7 +634,7 @@ pub fn run_test_core<P: MachineProver<BabyBearPoseidon2, RiscvAir<BabyBear>>>(
     let machine = RiscvAir::machine(config);
     let prover = P::new(machine);
     let (pk, _) = prover.setup(runtime.program.as_ref());
-    let (proof, output, _) = prove_with_context(
+    let (proof, output, _, _) = prove_with_context(
         &prover,
         &pk,
         Program::clone(&runtime.program),
@@ -0,0 +1,137 @@
+//! BabyJubJub, the twisted Edwards curve `168700 * x^2 + y^2 = 1 + 168696 * x^2 * y^2` over the
+//! scalar field of BN254, as specified in EIP-2494 and used by circomlib.
+//!
+//! The addition law of [`EdwardsCurve`] is the one of the curves with `a = -1`, so the points are
+//! represented on the isomorphic curve `-u^2 + y^2 = 1 + d * u^2 * y^2`, with `u = s * x` where
+//! `s^2 = -168700` and `d = -168696 / 168700`. [`babyjubjub_from_circom`] and
+//! [`babyjubjub_to_circom`] map the points from and to the coordinates of circomlib.
+
+use generic_array::GenericArray;
+use num::{BigUint, Num};
+use serde::{Deserialize, Serialize};
+use typenum::U32;
+
+use crate::{
+    edwards::{EdwardsCurve, EdwardsParameters},
+    params::FieldParameters,
+    weierstrass::bn254::Bn254ScalarField,
+    AffinePoint, CurveType, EllipticCurveParameters,
+};
+
+pub type BabyJubJub = EdwardsCurve<BabyJubJubParameters>;
+
+/// The coefficient `a` of the curve in the coordinates of circomlib.
+pub const BABYJUBJUB_A: u32 = 168700;
+
+/// The coefficient `d` of the curve in the coordinates of circomlib.
+pub const BABYJUBJUB_D: u32 = 168696;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BabyJubJubParameters;
+
+impl EllipticCurveParameters for BabyJubJubParameters {
+    type BaseField = Bn254ScalarField;
+    const CURVE_TYPE: CurveType = CurveType::BabyJubJub;
+}
+
+impl EdwardsParameters for BabyJubJubParameters {
+    // d = -168696 / 168700.
+    const D: GenericArray<u8, U32> = GenericArray::from_array([
+        142, 235, 215, 244, 140, 202, 117, 208, 103, 200, 183, 235, 89, 41, 155, 3, 252, 17, 253,
+        153, 215, 114, 240, 61, 105, 137, 33, 95, 241, 144, 238, 26,
+    ]);
+
+    fn prime_group_order() -> BigUint {
+        BigUint::from_str_radix(
+            "2736030358979909402780800718157159386076813972158567259200215660948447373041",
+            10,
+        )
+        .unwrap()
+    }
+
+    /// The point `Base8` of circomlib, which generates the prime order subgroup.
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from_str_radix(
+            "12216525397769193039033285140139874868932027386087289415053270333399021305954",
+            10,
+        )
+        .unwrap();
+        let y = BigUint::from_str_radix(
+            "16950150798460657717958625567821834550301663161624707787222815936182638968203",
+            10,
+        )
+        .unwrap();
+        (x, y)
+    }
+}
+
+/// The square root `s` of `-168700` which maps the `x` coordinate of circomlib to `u = s * x`.
+pub fn babyjubjub_sqrt_neg_a() -> BigUint {
+    BigUint::from_str_radix(
+        "6360561867910373094066688120553762416144456282423235903351243436111059670888",
+        10,
+    )
+    .unwrap()
+}
+
+/// Maps a point in the coordinates of circomlib to the curve with `a = -1`.
+pub fn babyjubjub_from_circom(x: &BigUint, y: &BigUint) -> AffinePoint<BabyJubJub> {
+    let p = Bn254ScalarField::modulus();
+    AffinePoint::new((x * babyjubjub_sqrt_neg_a()) % &p, y % &p)
+}
+
+/// Maps a point of the curve with `a = -1` back to the coordinates of circomlib.
+pub fn babyjubjub_to_circom(point: &AffinePoint<BabyJubJub>) -> (BigUint, BigUint) {
+    let p = Bn254ScalarField::modulus();
+    let s_inv = babyjubjub_sqrt_neg_a().modpow(&(&p - 2u32), &p);
+    ((&point.x * s_inv) % &p, point.y.clone())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use num::Zero;
+
+    use super::*;
+    use crate::EllipticCurve;
+
+    #[test]
+    fn test_babyjubjub_parameters() {
+        let p = Bn254ScalarField::modulus();
+        let s = babyjubjub_sqrt_neg_a();
+        assert_eq!((&s * &s + BABYJUBJUB_A) % &p, BigUint::zero());
+
+        // d = -168696 / 168700.
+        let d = BabyJubJubParameters::d_biguint();
+        assert_eq!((d * BABYJUBJUB_A + BABYJUBJUB_D) % &p, BigUint::zero());
+    }
+
+    #[test]
+    fn test_babyjubjub_generator() {
+        // Base8 in the coordinates of circomlib.
+        let x = BigUint::from_str_radix(
+            "5299619240641551281634865583518297030282874472190772894086521144482721001553",
+            10,
+        )
+        .unwrap();
+        let y = BigUint::from_str_radix(
+            "16950150798460657717958625567821834550301663161624707787222815936182638968203",
+            10,
+        )
+        .unwrap();
+        let base = BabyJubJub::ec_generator();
+        assert_eq!(babyjubjub_from_circom(&x, &y), base);
+        assert_eq!(babyjubjub_to_circom(&base), (x.clone(), y.clone()));
+
+        // The point is on the curve of circomlib.
+        let p = Bn254ScalarField::modulus();
+        let (x_2, y_2) = ((&x * &x) % &p, (&y * &y) % &p);
+        assert_eq!(
+            (&x_2 * BABYJUBJUB_A + &y_2) % &p,
+            (BigUint::from(1u32) + &x_2 * &y_2 * BABYJUBJUB_D) % &p
+        );
+
+        let order = BabyJubJubParameters::prime_group_order();
+        assert_eq!(&base * &order, BabyJubJub::neutral());
+    }
+}
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use curve25519_dalek::edwards::CompressedEdwardsY;
 use generic_array::GenericArray;
-use num::{BigUint, Num, One};
+use num::{BigUint, Num, One, Zero};
 use serde::{Deserialize, Serialize};
 use typenum::{U32, U62};
 
@@ -73,6 +73,11 @@ impl EdwardsParameters for Ed25519Parameters {
 /// This function always returns the nonnegative square root, in the sense that the least
 /// significant bit of the result is always 0.
 pub fn ed25519_sqrt(a: &BigUint) -> BigUint {
+    ed25519_try_sqrt(a).expect("a is not a square")
+}
+
+/// Computes the nonnegative square root of a number in the base field of Ed25519, if one exists.
+pub fn ed25519_try_sqrt(a: &BigUint) -> Option<BigUint> {
     // Here is a description of how to calculate sqrt in the Curve25519 base field:
     // ssh://git@github.com/succinctlabs/curve25519-dalek/blob/
     // e2d1bd10d6d772af07cac5c8161cd7655016af6d/curve25519-dalek/src/field.rs#L256
@@ -108,7 +113,7 @@ pub fn ed25519_sqrt(a: &BigUint) -> BigUint {
     let flipped_sign_sqrt = beta_squared == neg_a;
 
     if !correct_sign_sqrt && !flipped_sign_sqrt {
-        panic!("a is not a square");
+        return None;
     }
 
     let beta_bytes = beta.to_bytes_le();
@@ -116,7 +121,7 @@ pub fn ed25519_sqrt(a: &BigUint) -> BigUint {
         beta = (&modulus - &beta) % &modulus;
     }
 
-    beta
+    Some(beta)
 }
 
 pub fn decompress(compressed_point: &CompressedEdwardsY) -> AffinePoint<Ed25519> {
@@ -145,6 +150,33 @@ pub fn decompress(compressed_point: &CompressedEdwardsY) -> AffinePoint<Ed25519>
     AffinePoint::new(x, y.clone())
 }
 
+/// Decompresses a point with the checks of the strict decoding in RFC 8032, returning `None` if
+/// the `y` coordinate is not reduced, if there is no `x` for it, or if `x` is zero and its sign bit
+/// is set.
+pub fn decompress_strict(compressed_point: &CompressedEdwardsY) -> Option<AffinePoint<Ed25519>> {
+    let mut point_bytes = *compressed_point.as_bytes();
+    let sign = point_bytes[31] >> 7 == 1;
+    point_bytes[31] &= 0b0111_1111;
+    let modulus = &Ed25519BaseField::modulus();
+
+    let y = BigUint::from_bytes_le(&point_bytes);
+    if &y >= modulus {
+        return None;
+    }
+    let yy = &((&y * &y) % modulus);
+    let u = (yy + modulus - BigUint::one()) % modulus;
+    let v = &((yy * &Ed25519Parameters::d_biguint()) + &BigUint::one()) % modulus;
+    let v_inv = v.modpow(&(modulus - BigUint::from(2u64)), modulus);
+    let u_div_v = (u * &v_inv) % modulus;
+
+    let x = ed25519_try_sqrt(&u_div_v)?;
+    if x.is_zero() && sign {
+        return None;
+    }
+    let x = if sign { modulus - &x } else { x };
+    Some(AffinePoint::new(x, y))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -179,9 +211,29 @@ mod tests {
                 CompressedEdwardsY(compressed)
             };
             assert_eq!(point, decompress(&compressed_point));
+            assert_eq!(Some(point.clone()), decompress_strict(&compressed_point));
 
             // Double the point to create a "random" point for the next iteration.
             point = point.clone() + point.clone();
         }
     }
+
+    #[test]
+    fn test_ed25519_decompress_strict_rejects_invalid_encodings() {
+        // The identity with the sign bit set, y = p, y = p + 1 and a y with no corresponding x.
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        identity[31] = 0x80;
+        let mut modulus = Ed25519BaseField::modulus().to_bytes_le();
+        modulus.resize(32, 0);
+        let mut modulus_plus_one = modulus.clone();
+        modulus_plus_one[0] += 1;
+        let mut two = [0u8; 32];
+        two[0] = 2;
+
+        for encoding in [identity.to_vec(), modulus, modulus_plus_one, two.to_vec()] {
+            let compressed_point = CompressedEdwardsY(encoding.try_into().unwrap());
+            assert_eq!(decompress_strict(&compressed_point), None);
+        }
+    }
 }
@@ -0,0 +1,183 @@
+//! Jubjub, the twisted Edwards curve `-x^2 + y^2 = 1 + d * x^2 * y^2` with `d = -(10240/10241)`
+//! over the scalar field of BLS12-381. The constants are taken from the Zcash protocol
+//! specification.
+
+use generic_array::GenericArray;
+use num::{BigUint, Num, One, Zero};
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use crate::{
+    edwards::{EdwardsCurve, EdwardsParameters},
+    params::{FieldParameters, NumLimbs},
+    AffinePoint, CurveType, EllipticCurve, EllipticCurveParameters,
+};
+
+pub type Jubjub = EdwardsCurve<JubjubParameters>;
+
+/// The cofactor of the curve.
+pub const JUBJUB_COFACTOR: u32 = 8;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JubjubParameters;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JubjubBaseField;
+
+impl FieldParameters for JubjubBaseField {
+    const MODULUS: &'static [u8] = &[
+        1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8, 216,
+        57, 51, 72, 125, 157, 41, 83, 167, 237, 115,
+    ];
+
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(Self::MODULUS)
+    }
+}
+
+impl NumLimbs for JubjubBaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for JubjubParameters {
+    type BaseField = JubjubBaseField;
+    const CURVE_TYPE: CurveType = CurveType::Jubjub;
+}
+
+impl EdwardsParameters for JubjubParameters {
+    const D: GenericArray<u8, U32> = GenericArray::from_array([
+        177, 62, 52, 214, 214, 95, 6, 1, 38, 157, 87, 55, 109, 127, 45, 41, 212, 127, 189, 230, 7,
+        146, 253, 245, 72, 43, 250, 75, 231, 24, 147, 42,
+    ]);
+
+    fn prime_group_order() -> BigUint {
+        BigUint::from_str_radix(
+            "0e7db4ea6533afa906673b0101343b00a6682093ccc81082d0970e5ed6f72cb7",
+            16,
+        )
+        .unwrap()
+    }
+
+    /// The point with `y = 11` and an even `x`, multiplied by the cofactor.
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from_str_radix(
+            "28336281903124990867587793011069573392383982287722241916350956173377953689573",
+            10,
+        )
+        .unwrap();
+        let y = BigUint::from_str_radix(
+            "39385640392217313770878525135509063452020585410343666726093009378539878503883",
+            10,
+        )
+        .unwrap();
+        (x, y)
+    }
+}
+
+/// Computes a square root of `a` in the base field of Jubjub, if one exists.
+///
+/// The modulus is `2^32 * t + 1` with `t` odd, so this uses Tonelli-Shanks.
+pub fn jubjub_sqrt(a: &BigUint) -> Option<BigUint> {
+    let p = JubjubBaseField::modulus();
+    let a = a % &p;
+    if a.is_zero() {
+        return Some(a);
+    }
+    let p_minus_one = &p - BigUint::one();
+    if a.modpow(&(&p_minus_one >> 1), &p) != BigUint::one() {
+        return None;
+    }
+
+    let two_adicity = p_minus_one.trailing_zeros().unwrap();
+    let t = &p_minus_one >> two_adicity;
+
+    // Any non-residue works here, and 7 is the smallest one.
+    let mut c = BigUint::from(7u32).modpow(&t, &p);
+    let mut x = a.modpow(&((&t + BigUint::one()) >> 1), &p);
+    let mut b = a.modpow(&t, &p);
+    let mut m = two_adicity;
+    while !b.is_one() {
+        // Find the least i such that b^(2^i) = 1.
+        let mut i = 0;
+        let mut b_pow = b.clone();
+        while !b_pow.is_one() {
+            b_pow = (&b_pow * &b_pow) % &p;
+            i += 1;
+        }
+        let d = c.modpow(&(BigUint::one() << (m - i - 1)), &p);
+        x = (x * &d) % &p;
+        c = (&d * &d) % &p;
+        b = (b * &c) % &p;
+        m = i;
+    }
+    Some(x)
+}
+
+/// Returns the point with the given `y` coordinate and an even `x` coordinate, if one exists.
+pub fn jubjub_point_from_y(y: &BigUint) -> Option<AffinePoint<Jubjub>> {
+    let p = JubjubBaseField::modulus();
+    let d = JubjubParameters::d_biguint();
+
+    // From -x^2 + y^2 = 1 + d * x^2 * y^2, we get x^2 = (y^2 - 1) / (d * y^2 + 1).
+    let y_2 = (y * y) % &p;
+    let numerator = (&y_2 + &p - BigUint::one()) % &p;
+    let denominator = (&d * &y_2 + BigUint::one()) % &p;
+    if denominator.is_zero() {
+        return None;
+    }
+    let x_2 = (numerator * denominator.modpow(&(&p - 2u32), &p)) % &p;
+    let x = jubjub_sqrt(&x_2)?;
+    let x = if x.bit(0) { &p - x } else { x };
+    Some(AffinePoint::new(x % &p, y % &p))
+}
+
+/// Multiplies a point by the cofactor, which maps it into the prime order subgroup.
+pub fn jubjub_clear_cofactor(point: &AffinePoint<Jubjub>) -> AffinePoint<Jubjub> {
+    let mut result = point.clone();
+    for _ in 0..JUBJUB_COFACTOR.trailing_zeros() {
+        result = Jubjub::ec_double(&result);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::biguint_from_limbs;
+
+    #[test]
+    fn test_jubjub_parameters() {
+        assert_eq!(biguint_from_limbs(JubjubBaseField::MODULUS), JubjubBaseField::modulus());
+
+        // d = -(10240/10241).
+        let p = JubjubBaseField::modulus();
+        let d = JubjubParameters::d_biguint();
+        assert_eq!((d * 10241u32 + 10240u32) % &p, BigUint::zero());
+    }
+
+    #[test]
+    fn test_jubjub_generator() {
+        let base = Jubjub::ec_generator();
+        let point = jubjub_point_from_y(&BigUint::from(11u32)).unwrap();
+        assert_eq!(jubjub_clear_cofactor(&point), base);
+
+        let order = JubjubParameters::prime_group_order();
+        assert_eq!(&base * &order, Jubjub::neutral());
+    }
+
+    #[test]
+    fn test_jubjub_sqrt() {
+        let p = JubjubBaseField::modulus();
+        for a in 1u32..50 {
+            let a = BigUint::from(a);
+            if let Some(root) = jubjub_sqrt(&a) {
+                assert_eq!((&root * &root) % &p, a);
+            }
+        }
+        assert!(jubjub_sqrt(&BigUint::from(7u32)).is_none());
+    }
+}
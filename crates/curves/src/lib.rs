@@ -1,8 +1,14 @@
 pub mod edwards;
 pub mod params;
+pub mod pedersen;
+pub mod rescue;
 // pub mod polynomial;
 pub mod scalar_mul;
+pub mod uint;
 pub mod uint256;
+pub mod uint32;
+pub mod uint384;
+pub mod uint512;
 pub mod utils;
 pub mod weierstrass;
 
@@ -42,6 +48,11 @@ pub enum CurveType {
     Bn254,
     Ed25519,
     Bls12381,
+    P256,
+    P384,
+    Jubjub,
+    Grumpkin,
+    BabyJubJub,
 }
 
 impl Display for CurveType {
@@ -51,6 +62,11 @@ impl Display for CurveType {
             CurveType::Bn254 => write!(f, "Bn254"),
             CurveType::Ed25519 => write!(f, "Ed25519"),
             CurveType::Bls12381 => write!(f, "Bls12381"),
+            CurveType::P256 => write!(f, "P256"),
+            CurveType::P384 => write!(f, "P384"),
+            CurveType::Jubjub => write!(f, "Jubjub"),
+            CurveType::Grumpkin => write!(f, "Grumpkin"),
+            CurveType::BabyJubJub => write!(f, "BabyJubJub"),
         }
     }
 }
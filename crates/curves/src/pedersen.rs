@@ -0,0 +1,137 @@
+//! A Pedersen hash over the Jubjub curve.
+//!
+//! The input bits, least significant bit of each byte first, are split into windows of three bits
+//! `(s0, s1, s2)`, with the last window padded with zeros. As in Zcash Sapling, each window is
+//! encoded as the nonzero multiplier `(1 - 2 * s2) * (1 + s0 + 2 * s1)`, and window `i` adds its
+//! multiplier times the base point `G_i` to an accumulator that starts at the identity. The hash is
+//! the `x` coordinate of the result. Inputs of a different length can hash to the same value, so
+//! the input length should be fixed by the application.
+//!
+//! Each base point `G_i` is the first point with an even `x` and `y = 2^16 * (i + 1) + c` for
+//! `c = 0, 1, ...`, multiplied by the cofactor, so that no discrete logarithm relation between the
+//! base points is known.
+
+use std::sync::OnceLock;
+
+use num::BigUint;
+
+use crate::{
+    edwards::jubjub::{jubjub_clear_cofactor, jubjub_point_from_y, Jubjub},
+    AffinePoint, EllipticCurve,
+};
+
+/// The number of input bits in a window.
+pub const PEDERSEN_WINDOW_BITS: usize = 3;
+
+/// The number of values a window can take.
+pub const PEDERSEN_WINDOW_SIZE: usize = 1 << PEDERSEN_WINDOW_BITS;
+
+/// The maximum number of input bytes.
+pub const PEDERSEN_MAX_INPUT_BYTES: usize = 64;
+
+/// The number of windows, and so of base points, needed for the longest input.
+pub const PEDERSEN_NUM_WINDOWS: usize =
+    (8 * PEDERSEN_MAX_INPUT_BYTES).div_ceil(PEDERSEN_WINDOW_BITS);
+
+/// Returns the base point of each window.
+pub fn pedersen_base_points() -> &'static [AffinePoint<Jubjub>] {
+    static BASE_POINTS: OnceLock<Vec<AffinePoint<Jubjub>>> = OnceLock::new();
+    BASE_POINTS.get_or_init(|| {
+        (0..PEDERSEN_NUM_WINDOWS)
+            .map(|i| {
+                let start = BigUint::from(i as u64 + 1) << 16;
+                (0u32..)
+                    .filter_map(|c| jubjub_point_from_y(&(&start + c)))
+                    .map(|point| jubjub_clear_cofactor(&point))
+                    .find(|point| point != &Jubjub::neutral())
+                    .unwrap()
+            })
+            .collect()
+    })
+}
+
+/// Returns the points added for each window and each of its values, so that `table[i][chunk]` is
+/// the encoding of `chunk` times the base point of window `i`.
+pub fn pedersen_table() -> &'static [[AffinePoint<Jubjub>; PEDERSEN_WINDOW_SIZE]] {
+    static TABLE: OnceLock<Vec<[AffinePoint<Jubjub>; PEDERSEN_WINDOW_SIZE]>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        pedersen_base_points()
+            .iter()
+            .map(|base| {
+                let multiples = [
+                    base.clone(),
+                    Jubjub::ec_double(base),
+                    Jubjub::ec_add(&Jubjub::ec_double(base), base),
+                    Jubjub::ec_double(&Jubjub::ec_double(base)),
+                ];
+                core::array::from_fn(|chunk| {
+                    let multiple = &multiples[chunk & 0b11];
+                    if chunk & 0b100 == 0 {
+                        multiple.clone()
+                    } else {
+                        Jubjub::ec_neg(multiple)
+                    }
+                })
+            })
+            .collect()
+    })
+}
+
+/// Splits the input into windows.
+pub fn pedersen_windows(input: &[u8]) -> Vec<u32> {
+    assert!(input.len() <= PEDERSEN_MAX_INPUT_BYTES, "input is too long");
+    let bits = input.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) as u32 & 1));
+    bits.collect::<Vec<_>>()
+        .chunks(PEDERSEN_WINDOW_BITS)
+        .map(|window| window.iter().enumerate().map(|(i, bit)| bit << i).sum())
+        .collect()
+}
+
+/// Adds the point of the given window and value to the accumulator.
+pub fn pedersen_add_window(
+    acc: &AffinePoint<Jubjub>,
+    window: usize,
+    chunk: u32,
+) -> AffinePoint<Jubjub> {
+    Jubjub::ec_add(acc, &pedersen_table()[window][chunk as usize])
+}
+
+/// Computes the Pedersen hash of the input, returning the `x` coordinate of the result.
+pub fn pedersen_hash(input: &[u8]) -> BigUint {
+    let acc = pedersen_windows(input)
+        .into_iter()
+        .enumerate()
+        .fold(Jubjub::neutral(), |acc, (i, chunk)| pedersen_add_window(&acc, i, chunk));
+    acc.x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edwards::{jubjub::JubjubParameters, EdwardsParameters};
+
+    #[test]
+    fn test_pedersen_table() {
+        let order = JubjubParameters::prime_group_order();
+        for (base, row) in pedersen_base_points().iter().zip(pedersen_table()) {
+            assert_eq!(base * &order, Jubjub::neutral());
+            for (chunk, point) in row.iter().enumerate() {
+                let chunk = chunk as i64;
+                let magnitude = 1 + (chunk & 1) + 2 * ((chunk >> 1) & 1);
+                let expected = base * BigUint::from(magnitude as u64);
+                let expected = if chunk & 4 == 0 { expected } else { Jubjub::ec_neg(&expected) };
+                assert_eq!(point, &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pedersen_hash() {
+        assert_eq!(
+            pedersen_windows(&[0b1100_0101, 0xff]),
+            vec![0b101, 0b000, 0b111, 0b111, 0b111, 0b1]
+        );
+        assert_ne!(pedersen_hash(&[1, 2, 3]), pedersen_hash(&[1, 2, 4]));
+        assert_ne!(pedersen_hash(&[0; 32]), pedersen_hash(&[0; 33]));
+    }
+}
@@ -0,0 +1,170 @@
+//! The Rescue-Prime permutation over the BN254 scalar field, with a state of three elements.
+//!
+//! A round applies the forward S-box `x^5`, the linear layer and the first round constants, then
+//! the inverse S-box `x^(1/5)`, the linear layer and the second round constants. The linear layer
+//! is the MDS matrix `J + I`, where `J` is the all-ones matrix, so that each output element is the
+//! sum of the state plus the corresponding input element.
+//!
+//! The round constants are the SHA-256 digests of `Rescue-Prime-BN254-{k}` for `k = 0, 1, ...`,
+//! reduced modulo the field order.
+
+use num::{BigUint, Num, One};
+
+use crate::{params::FieldParameters, weierstrass::bn254::Bn254ScalarField};
+
+/// The number of field elements in the state.
+pub const RESCUE_WIDTH: usize = 3;
+
+/// The number of rounds of the permutation.
+pub const RESCUE_NUM_ROUNDS: usize = 8;
+
+/// The exponent of the forward S-box.
+pub const RESCUE_ALPHA: u32 = 5;
+
+/// The round constants, two sets per round, as big-endian hex strings.
+const RESCUE_ROUND_CONSTANTS: [[&str; RESCUE_WIDTH]; 2 * RESCUE_NUM_ROUNDS] = [
+    [
+        "0dece99f7ce635c0c49dd96ba261dab610bbcdca89a0803dab39b8b704a0dcce",
+        "15fff271a3cd307b873fdf1735bec280b5983e9e72558117d76369aecd8cf7c9",
+        "2a88b611120706735b5ad78d64754b27aca715459fce525acc3855eaf71cdbf0",
+    ],
+    [
+        "06c1a39422466d9608fd346f0a1c58ca8e12fc468b363940f07a246b2f346075",
+        "16797c107298c73320a0914e356be4973d46401f57442ebcbdf3d177c4e4fcf3",
+        "26c35cb62894ea6ec9ea6335524387dcce41107e46ce44c8deaf5655452062a8",
+    ],
+    [
+        "09c1fb769cf584e45f7acd186b7581da669d756cb66002d5ef189d99c54cecc3",
+        "283f2728c03169d41bb74448980c9ac647e8cec65e16925b914e7d6123bf5a2b",
+        "12c081d64481f3db5ef6bfd0c7726c845e9e67b077f5c55ac099852e258c7cd9",
+    ],
+    [
+        "21918d4a81d3ce0972e1115371f58597729823d88f632a80b8a7157b97508798",
+        "04e297a56743dd801eb509d49efe20c55bdc4ceb933739f72fc438aacaf3806d",
+        "26347baa8ec6259c6dc5bcf6419ff273115c14dfab15d503b5c197d9e232710d",
+    ],
+    [
+        "029c1a570a73115d088f2633ec4720f5ba93179e4d6b22ea6e16a7ff802fc8ae",
+        "257ed9810dc81f9de0bee97f8043511fb9d0a79928aa134844b4b5b9d2c348ea",
+        "1d3ea577f569e45c38ba428a46a352215c98fcb02191049b3b1463001efd99e3",
+    ],
+    [
+        "0cf8fe982a2db7b1ee8c60d99e1ed619722d52e0d46a55284c43c485544d7a36",
+        "2b84e589d9cbf541cef56deda785c36c3f539a479b84733cbc075cd7ebba0108",
+        "06044fb8a27cf4d2aef11d63e51456fea9ca056761283879cbef4dd7d6a2de3f",
+    ],
+    [
+        "13f0616212062e90bd7e6d2bac1e2c4671bda0684f30d894ec91fe93f5608f9b",
+        "1657de38747167f4b3b48fc0639dae20a8d94b8947fa5985284724c890c3d40a",
+        "06b564800927897715a7d8559284969eed48bf2f510629ef7fa9e979826f9f29",
+    ],
+    [
+        "098e78f41efa34a3cdd1e2e9e8d9c7db5910660256991a39124dd7240d3d6e88",
+        "04d654779c5c972d42dbfd10c24ae76e44d56e0076a5b28664ce53589ea6c592",
+        "13b932fd36a8bfc28fb6b568ac2da67447bb78fa484f07da8d8479b518afff21",
+    ],
+    [
+        "039476e16c1a13ebc76d6cc505efded3460fae66859959dc0ce1b2790e327a27",
+        "214cbe59468e256c70ae0b4751b5325c884f367536247f70441c9ce869f8f7b1",
+        "18fc5c8f1340bcb199db061593084aa369074680fd38a40b0d1b260dc3d9aa3e",
+    ],
+    [
+        "1922d51901ebfb5d5c951a6a940ff466eed2ee649cd42c5902c5ace94e480719",
+        "26f2447751a7419d205d6d6466d329b3410636eb5d32e0ef2201b89276793876",
+        "20fa4250329157bad83b3e07f8ddc9b9ad6422a85e8e2c5af3400e48f5f9ddcc",
+    ],
+    [
+        "06bd948e7d01aedacfaa06f4fbe1f0d402a01b1e9138d2e92873627c4385cce6",
+        "28dff66fc605dd5849069f3cac63fef69f0ac1d73592088d069a064c5cdeb87c",
+        "17ed380cf42749a241e52fa764605ef513a68826ee198a1324f76d923d2a5239",
+    ],
+    [
+        "2cd4378cd471c26a2bbeac42870f034e3c61d46e878c289711c43b3dd4d00f54",
+        "08fd2220de1024e5df2c16e0ce86b264eb48ed0ad7c363034c21178eb0d9482b",
+        "2ef23e71b543ffe0040a63f282fb7bdfe28e41f2d5208082e4d3b0f2f198f122",
+    ],
+    [
+        "04b262c7f4ca6d45448f583a7a41b92cb16285feac75da7cd3c6e72306395b81",
+        "2e4a74ffbbec4411e15dc47b99f2ba84249b28533c3068835d4f632ac40eb9c3",
+        "1964f49b0f4442ca738e37d8371821a3b217e2659730797ad783cd03dfb76848",
+    ],
+    [
+        "2a84aaca61724222ae53552fcd8355e4f0d9f8a8231845f1e9871784bf261726",
+        "00915f39d4b1348014450bfedfd02bfeff7a17cff4bad02894848c0fdc135461",
+        "19e08caa963236d63c3c15f04099038741e8ade658606f6f25c2abc8d331dd60",
+    ],
+    [
+        "18306aff41aecf549ee1bb7b631e68aa35bf55aeccb1c957206c2a83d800ae9c",
+        "1516f7105a1f2809f1814b15c87cc531639fabdef10fbaccce0da3dea37510e6",
+        "1529a425b531d00245c7f4e3731a27d71102962c3801beaefeba0e9b5936f353",
+    ],
+    [
+        "24f50f14f94adc3f247f1e266928722b7d0b985febf6c592db96750bdeeff342",
+        "132d1d881efded2b4439aa5bf22df9f752b4c78fbd95c19569fb7deadab89d15",
+        "1e297ccf4aea55c19581757153266c64d063c14075349745d50923de33997827",
+    ],
+];
+
+/// Returns the round constants added after the forward (`step = 2 * round`) or the inverse
+/// (`step = 2 * round + 1`) half of a round.
+pub fn rescue_round_constants(step: usize) -> [BigUint; RESCUE_WIDTH] {
+    RESCUE_ROUND_CONSTANTS[step].map(|c| BigUint::from_str_radix(c, 16).unwrap())
+}
+
+/// Returns the exponent of the inverse S-box, `1/5 mod (p - 1)`.
+pub fn rescue_alpha_inverse() -> BigUint {
+    let order = Bn254ScalarField::modulus() - BigUint::one();
+    // 5 divides 4 * (p - 1) + 1, since p - 1 is 1 modulo 5.
+    (BigUint::from(4u32) * &order + BigUint::one()) / RESCUE_ALPHA
+}
+
+/// Applies the linear layer and adds the given round constants.
+pub fn rescue_linear_layer(
+    state: &[BigUint; RESCUE_WIDTH],
+    constants: &[BigUint; RESCUE_WIDTH],
+) -> [BigUint; RESCUE_WIDTH] {
+    let modulus = Bn254ScalarField::modulus();
+    let sum: BigUint = state.iter().sum();
+    core::array::from_fn(|i| (&sum + &state[i] + &constants[i]) % &modulus)
+}
+
+/// Applies a single round of the permutation. The state elements need not be reduced.
+pub fn rescue_round(state: &[BigUint; RESCUE_WIDTH], round: usize) -> [BigUint; RESCUE_WIDTH] {
+    assert!(round < RESCUE_NUM_ROUNDS, "round {round} is out of range");
+    let modulus = Bn254ScalarField::modulus();
+    let alpha = BigUint::from(RESCUE_ALPHA);
+    let alpha_inverse = rescue_alpha_inverse();
+
+    let forward = state.clone().map(|x| x.modpow(&alpha, &modulus));
+    let state = rescue_linear_layer(&forward, &rescue_round_constants(2 * round));
+    let inverse = state.map(|x| x.modpow(&alpha_inverse, &modulus));
+    rescue_linear_layer(&inverse, &rescue_round_constants(2 * round + 1))
+}
+
+/// Applies the full permutation.
+pub fn rescue_permute(state: &[BigUint; RESCUE_WIDTH]) -> [BigUint; RESCUE_WIDTH] {
+    (0..RESCUE_NUM_ROUNDS).fold(state.clone(), |state, round| rescue_round(&state, round))
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+
+    use super::*;
+
+    #[test]
+    fn test_rescue_alpha_inverse() {
+        let modulus = Bn254ScalarField::modulus();
+        let x = BigUint::from(0x1234_5678u32);
+        let root = x.modpow(&rescue_alpha_inverse(), &modulus);
+        assert_eq!(root.modpow(&BigUint::from(RESCUE_ALPHA), &modulus), x);
+    }
+
+    #[test]
+    fn test_rescue_round_constants_are_reduced() {
+        let modulus = Bn254ScalarField::modulus();
+        for step in 0..2 * RESCUE_NUM_ROUNDS {
+            assert!(rescue_round_constants(step).iter().all(|c| c < &modulus));
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    params::{FieldParameters, NumWords},
+    uint256::U256Field,
+    uint384::U384Field,
+    uint512::U512Field,
+};
+
+/// The width of an unsigned integer handled by the uint precompiles.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UintType {
+    Uint256,
+    Uint384,
+    Uint512,
+}
+
+/// The parameters of an unsigned integer handled by the uint precompiles.
+pub trait UintField: FieldParameters + NumWords {
+    const UINT_TYPE: UintType;
+}
+
+impl UintField for U256Field {
+    const UINT_TYPE: UintType = UintType::Uint256;
+}
+
+impl UintField for U384Field {
+    const UINT_TYPE: UintType = UintType::Uint384;
+}
+
+impl UintField for U512Field {
+    const UINT_TYPE: UintType = UintType::Uint512;
+}
@@ -0,0 +1,31 @@
+use typenum::{U4, U7};
+
+use num::{BigUint, One};
+use serde::{Deserialize, Serialize};
+
+use crate::params::{FieldParameters, NumLimbs};
+
+/// Like [`crate::uint256::U256Field`], `U32` is not a field, but we utilize `FieldParameters` so
+/// that the `FieldOps` operations can be used with a variable modulus that fits in a word, such as
+/// the prime of a number theoretic transform.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct U32Field;
+
+impl FieldParameters for U32Field {
+    /// The modulus of the field. It is represented as a little-endian array of 5 bytes.
+    const MODULUS: &'static [u8] = &[0, 0, 0, 0, 1];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    /// The modulus of Uint32 is 2^32.
+    fn modulus() -> BigUint {
+        BigUint::one() << 32
+    }
+}
+
+impl NumLimbs for U32Field {
+    type Limbs = U4;
+    // Unlike `U256Field`, there is no extra limb, since the moduli used with it are below 2^32.
+    type Witness = U7;
+}
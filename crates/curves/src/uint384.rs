@@ -0,0 +1,35 @@
+use typenum::{U48, U95};
+
+use num::{BigUint, One};
+use serde::{Deserialize, Serialize};
+
+use crate::params::{FieldParameters, NumLimbs};
+
+/// Like [`crate::uint256::U256Field`], `U384` is not a field, but we utilize `FieldParameters` so
+/// that the `FieldOps` multiplication operation can use 2^384 as its default modulus. 384 bits is
+/// enough for the BLS12-381 base field.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct U384Field;
+
+impl FieldParameters for U384Field {
+    /// The modulus of the field. It is represented as a little-endian array of 49 bytes.
+    const MODULUS: &'static [u8] = &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field. The
+    /// column sums of the product of two 48-limb values are bounded by those of the 64-limb case.
+    const WITNESS_OFFSET: usize = 1usize << 15;
+
+    /// The modulus of Uint384 is 2^384.
+    fn modulus() -> BigUint {
+        BigUint::one() << 384
+    }
+}
+
+impl NumLimbs for U384Field {
+    type Limbs = U48;
+    // As with `U256Field`, we use one more limb than usual so that mulmod with mod 1<<384 fits.
+    type Witness = U95;
+}
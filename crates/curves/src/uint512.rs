@@ -0,0 +1,35 @@
+use typenum::{U127, U64};
+
+use num::{BigUint, One};
+use serde::{Deserialize, Serialize};
+
+use crate::params::{FieldParameters, NumLimbs};
+
+/// Like [`crate::uint256::U256Field`], `U512` is not a field, but we utilize `FieldParameters` so
+/// that the `FieldOps` multiplication operation can use 2^512 as its default modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct U512Field;
+
+impl FieldParameters for U512Field {
+    /// The modulus of the field. It is represented as a little-endian array of 65 bytes.
+    const MODULUS: &'static [u8] = &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 1,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field. The
+    /// product of two 64-limb values has twice the column sums of the 32-limb case.
+    const WITNESS_OFFSET: usize = 1usize << 15;
+
+    /// The modulus of Uint512 is 2^512.
+    fn modulus() -> BigUint {
+        BigUint::one() << 512
+    }
+}
+
+impl NumLimbs for U512Field {
+    type Limbs = U64;
+    // As with `U256Field`, we use one more limb than usual so that mulmod with mod 1<<512 fits.
+    type Witness = U127;
+}
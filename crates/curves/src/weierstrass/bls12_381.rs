@@ -155,6 +155,34 @@ mod tests {
         assert_eq!(biguint_from_limbs(Bls12381BaseField::MODULUS), Bls12381BaseField::modulus());
     }
 
+    #[test]
+    fn test_bls12381_add_and_double() {
+        // The multiples of the generator from the IETF pairing-friendly curves draft.
+        let point = |x: &str, y: &str| {
+            AffinePoint::<Bls12381>::new(
+                BigUint::from_str_radix(x, 16).unwrap(),
+                BigUint::from_str_radix(y, 16).unwrap(),
+            )
+        };
+        let generator = point(
+            "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+            "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+        );
+        let double = point(
+            "0572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e",
+            "166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28",
+        );
+        let triple = point(
+            "09ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e5224",
+            "032b80d3a6f5b09f8a84623389c5f80ca69a0cddabc3097f9d9c27310fd43be6e745256c634af45ca3473b0590ae30d1",
+        );
+
+        let (x, y) = Bls12381Parameters::generator();
+        assert_eq!(AffinePoint::new(x, y), generator);
+        assert_eq!(generator.sw_double(), double);
+        assert_eq!(generator.sw_add(&double), triple);
+    }
+
     #[test]
     fn test_bls12381_decompress() {
         // This test checks that decompression of generator, 2x generator, 4x generator, etc. works.
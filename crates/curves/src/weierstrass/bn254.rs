@@ -48,6 +48,34 @@ impl NumLimbs for Bn254BaseField {
     type Witness = U62;
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Bn254 scalar field parameter
+pub struct Bn254ScalarField;
+
+impl FieldParameters for Bn254ScalarField {
+    const MODULUS: &'static [u8] = &[
+        1, 0, 0, 240, 147, 245, 225, 67, 145, 112, 185, 121, 72, 232, 51, 40, 93, 88, 129, 129,
+        182, 69, 80, 184, 41, 160, 49, 225, 114, 78, 100, 48,
+    ];
+
+    // A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    // The modulus is the order of the group of points of the curve.
+    fn modulus() -> BigUint {
+        Bn254Parameters::prime_group_order()
+    }
+}
+
+impl FpOpField for Bn254ScalarField {
+    const FIELD_TYPE: FieldType = FieldType::Bn254Scalar;
+}
+
+impl NumLimbs for Bn254ScalarField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
 impl EllipticCurveParameters for Bn254Parameters {
     type BaseField = Bn254BaseField;
 
@@ -98,5 +126,6 @@ mod tests {
     #[test]
     fn test_weierstrass_biguint_scalar_mul() {
         assert_eq!(biguint_from_limbs(Bn254BaseField::MODULUS), Bn254BaseField::modulus());
+        assert_eq!(biguint_from_limbs(Bn254ScalarField::MODULUS), Bn254ScalarField::modulus());
     }
 }
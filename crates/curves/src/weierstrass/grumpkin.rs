@@ -0,0 +1,107 @@
+//! Modulo defining the Grumpkin curve `y^2 = x^3 - 17`, whose base field is the scalar field of
+//! Bn254 and whose group order is the base field modulus of Bn254. The constants are the ones used
+//! by barretenberg.
+
+use generic_array::GenericArray;
+use num::{BigUint, Num, Zero};
+use serde::{Deserialize, Serialize};
+use typenum::U32;
+
+use super::{
+    bn254::{Bn254BaseField, Bn254ScalarField},
+    SwCurve, WeierstrassParameters,
+};
+use crate::{params::FieldParameters, CurveType, EllipticCurveParameters};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Grumpkin curve parameter
+pub struct GrumpkinParameters;
+
+pub type Grumpkin = SwCurve<GrumpkinParameters>;
+
+impl EllipticCurveParameters for GrumpkinParameters {
+    type BaseField = Bn254ScalarField;
+
+    const CURVE_TYPE: CurveType = CurveType::Grumpkin;
+}
+
+impl WeierstrassParameters for GrumpkinParameters {
+    const A: GenericArray<u8, U32> = GenericArray::from_array([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ]);
+
+    // b = r - 17, where r is the modulus of the base field.
+    const B: GenericArray<u8, U32> = GenericArray::from_array([
+        240, 255, 255, 239, 147, 245, 225, 67, 145, 112, 185, 121, 72, 232, 51, 40, 93, 88, 129,
+        129, 182, 69, 80, 184, 41, 160, 49, 225, 114, 78, 100, 48,
+    ]);
+
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from(1u32);
+        let y = BigUint::from_str_radix(
+            "0000000000000002cf135e7506a45d632d270d45f1181294833fc48d823f272c",
+            16,
+        )
+        .unwrap();
+        (x, y)
+    }
+
+    fn prime_group_order() -> BigUint {
+        Bn254BaseField::modulus()
+    }
+
+    fn a_int() -> BigUint {
+        BigUint::zero()
+    }
+
+    fn b_int() -> BigUint {
+        Bn254ScalarField::modulus() - 17u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AffinePoint;
+
+    fn point(x: &str, y: &str) -> AffinePoint<Grumpkin> {
+        AffinePoint::new(
+            BigUint::from_str_radix(x, 16).unwrap(),
+            BigUint::from_str_radix(y, 16).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_grumpkin_parameters() {
+        let (x, y) = GrumpkinParameters::generator();
+        let modulus = Bn254ScalarField::modulus();
+        assert_eq!((&y * &y) % &modulus, (&x * &x * &x + GrumpkinParameters::b_int()) % &modulus);
+
+        let mut b = BigUint::zero();
+        for (i, limb) in GrumpkinParameters::B.iter().enumerate() {
+            b += BigUint::from(*limb) << (8 * i);
+        }
+        assert_eq!(b, GrumpkinParameters::b_int());
+    }
+
+    #[test]
+    fn test_grumpkin_add_double() {
+        let g = Grumpkin::generator();
+        let doubled = point(
+            "6ce1b0827aafa85ddeb49cdaa36306d19a74caa311e13d46d8bc688cdbffffe",
+            "1c122f81a3a14964909ede0ba2a6855fc93faf6fa1a788bf467be7e7a43f80ac",
+        );
+        let tripled = point(
+            "2941b0928df1b9480273773b36397da3e495430a2a7a3857661bc7a446c94f4d",
+            "13ae7e938c892308bef0f45ee7386daa2d3b447349a7d0a11b5aa4cfbe69072c",
+        );
+        assert_eq!(g.sw_double(), doubled);
+        assert_eq!(g.sw_add(&doubled), tripled);
+
+        // The group order is the Bn254 base field modulus, so multiplying by it minus one gives -G.
+        let order = GrumpkinParameters::prime_group_order();
+        let neg_g = g.sw_scalar_mul(&(order - 1u32));
+        assert_eq!(neg_g, AffinePoint::new(g.x.clone(), Bn254ScalarField::modulus() - &g.y));
+    }
+}
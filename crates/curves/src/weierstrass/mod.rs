@@ -11,6 +11,9 @@ use crate::{
 
 pub mod bls12_381;
 pub mod bn254;
+pub mod grumpkin;
+pub mod p256;
+pub mod p384;
 pub mod secp256k1;
 
 /// Parameters that specify a short Weierstrass curve : y^2 = x^3 + ax + b.
@@ -218,6 +221,7 @@ impl<E: WeierstrassParameters> AffinePoint<SwCurve<E>> {
 pub enum FieldType {
     Bls12381,
     Bn254,
+    Bn254Scalar,
 }
 
 pub trait FpOpField: FieldParameters + NumWords {
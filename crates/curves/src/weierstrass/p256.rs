@@ -0,0 +1,158 @@
+//! Modulo defining the P-256 (secp256r1) curve and its base field. The constants are all taken
+//! from https://neuromancer.sk/std/nist/P-256.
+
+use std::str::FromStr;
+
+use generic_array::GenericArray;
+use num::{BigUint, One};
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use super::{SwCurve, WeierstrassParameters};
+use crate::{
+    params::{FieldParameters, NumLimbs},
+    AffinePoint, CurveType, EllipticCurve, EllipticCurveParameters,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// P-256 curve parameter
+pub struct P256Parameters;
+
+pub type P256 = SwCurve<P256Parameters>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// P-256 base field parameter
+pub struct P256BaseField;
+
+impl FieldParameters for P256BaseField {
+    const MODULUS: &'static [u8] = &[
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(Self::MODULUS)
+    }
+}
+
+impl NumLimbs for P256BaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for P256Parameters {
+    type BaseField = P256BaseField;
+    const CURVE_TYPE: CurveType = CurveType::P256;
+}
+
+impl WeierstrassParameters for P256Parameters {
+    // a = p - 3.
+    const A: GenericArray<u8, U32> = GenericArray::from_array([
+        0xfc, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff,
+    ]);
+
+    const B: GenericArray<u8, U32> = GenericArray::from_array([
+        0x4b, 0x60, 0xd2, 0x27, 0x3e, 0x3c, 0xce, 0x3b, 0xf6, 0xb0, 0x53, 0xcc, 0xb0, 0x06, 0x1d,
+        0x65, 0xbc, 0x86, 0x98, 0x76, 0x55, 0xbd, 0xeb, 0xb3, 0xe7, 0x93, 0x3a, 0xaa, 0xd8, 0x35,
+        0xc6, 0x5a,
+    ]);
+
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from_str(
+            "48439561293906451759052585252797914202762949526041747995844080717082404635286",
+        )
+        .unwrap();
+        let y = BigUint::from_str(
+            "36134250956749795798585127919587881956611106672985015071877198253568414405109",
+        )
+        .unwrap();
+        (x, y)
+    }
+
+    fn prime_group_order() -> num::BigUint {
+        BigUint::from_slice(&[
+            0xFC632551, 0xF3B9CAC2, 0xA7179E84, 0xBCE6FAAD, 0xFFFFFFFF, 0xFFFFFFFF, 0x00000000,
+            0xFFFFFFFF,
+        ])
+    }
+}
+
+/// Decompresses a P-256 point from its big-endian x coordinate, choosing the y coordinate whose
+/// parity matches `sign`. Returns `None` if x is not reduced, or not the x coordinate of a point on
+/// the curve.
+pub fn p256_decompress<E: EllipticCurve>(bytes_be: &[u8], sign: u32) -> Option<AffinePoint<E>> {
+    let modulus = P256BaseField::modulus();
+    let x = BigUint::from_bytes_be(bytes_be);
+    if x >= modulus {
+        return None;
+    }
+
+    let rhs = (&x * &x * &x + P256Parameters::a_int() * &x + P256Parameters::b_int()) % &modulus;
+    let y = p256_try_sqrt(&rhs)?;
+    let y = if (&y % 2u32 == BigUint::one()) == (sign == 1) { y } else { &modulus - y };
+    Some(AffinePoint::<E>::new(x, y))
+}
+
+/// Computes a square root of `n` modulo the P-256 base field prime, if one exists. Since the prime
+/// is 3 mod 4, Tonelli-Shanks reduces to a single exponentiation by `(p + 1) / 4`.
+pub fn p256_try_sqrt(n: &BigUint) -> Option<BigUint> {
+    let modulus = P256BaseField::modulus();
+    let n = n % &modulus;
+    let exponent = (&modulus + BigUint::one()) >> 2;
+    let root = n.modpow(&exponent, &modulus);
+    ((&root * &root) % &modulus == n).then_some(root)
+}
+
+pub fn p256_sqrt(n: &BigUint) -> BigUint {
+    p256_try_sqrt(n).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::biguint_from_limbs;
+
+    #[test]
+    fn test_weierstrass_biguint_scalar_mul() {
+        assert_eq!(biguint_from_limbs(P256BaseField::MODULUS), P256BaseField::modulus());
+    }
+
+    #[test]
+    fn test_p256_generator_on_curve() {
+        let modulus = P256BaseField::modulus();
+        let (x, y) = P256Parameters::generator();
+        let lhs = (&y * &y) % &modulus;
+        let rhs =
+            (&x * &x * &x + P256Parameters::a_int() * &x + P256Parameters::b_int()) % &modulus;
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn test_p256_decompress() {
+        let (x, y) = P256Parameters::generator();
+        let sign = if &y % 2u32 == BigUint::one() { 1 } else { 0 };
+        let point = p256_decompress::<P256>(&x.to_bytes_be(), sign).unwrap();
+        assert_eq!(point.y, y);
+        let point = p256_decompress::<P256>(&x.to_bytes_be(), 1 - sign).unwrap();
+        assert_eq!(point.y, P256BaseField::modulus() - y);
+    }
+
+    #[test]
+    fn test_p256_decompress_off_curve() {
+        // x = 1 gives a right-hand side of the curve equation that is not a square.
+        assert!(p256_decompress::<P256>(&[1], 0).is_none());
+    }
+
+    #[test]
+    fn test_p256_decompress_unreduced() {
+        let x = P256BaseField::modulus();
+        assert!(p256_decompress::<P256>(&x.to_bytes_be(), 0).is_none());
+    }
+}
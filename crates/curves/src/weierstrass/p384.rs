@@ -0,0 +1,213 @@
+//! Modulo defining the P-384 (secp384r1) curve and its base field. The constants are all taken
+//! from https://neuromancer.sk/std/nist/P-384.
+
+use generic_array::GenericArray;
+use num::{BigUint, Num, One};
+use serde::{Deserialize, Serialize};
+use typenum::{U48, U94};
+
+use super::{SwCurve, WeierstrassParameters};
+use crate::{
+    params::{FieldParameters, NumLimbs},
+    AffinePoint, CurveType, EllipticCurve, EllipticCurveParameters,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// P-384 curve parameter
+pub struct P384Parameters;
+
+pub type P384 = SwCurve<P384Parameters>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// P-384 base field parameter
+pub struct P384BaseField;
+
+impl FieldParameters for P384BaseField {
+    const NB_LIMBS: usize = 48;
+
+    const MODULUS: &'static [u8] = &[
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff,
+        0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 15;
+
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(Self::MODULUS)
+    }
+}
+
+impl NumLimbs for P384BaseField {
+    type Limbs = U48;
+    type Witness = U94;
+}
+
+impl EllipticCurveParameters for P384Parameters {
+    type BaseField = P384BaseField;
+    const CURVE_TYPE: CurveType = CurveType::P384;
+}
+
+impl WeierstrassParameters for P384Parameters {
+    // a = p - 3.
+    const A: GenericArray<u8, U48> = GenericArray::from_array([
+        0xfc, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff,
+        0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff,
+    ]);
+
+    const B: GenericArray<u8, U48> = GenericArray::from_array([
+        0xef, 0x2a, 0xec, 0xd3, 0xed, 0xc8, 0x85, 0x2a, 0x9d, 0xd1, 0x2e, 0x8a, 0x8d, 0x39, 0x56,
+        0xc6, 0x5a, 0x87, 0x13, 0x50, 0x8f, 0x08, 0x14, 0x03, 0x12, 0x41, 0x81, 0xfe, 0x6e, 0x9c,
+        0x1d, 0x18, 0x19, 0x2d, 0xf8, 0xe3, 0x6b, 0x05, 0x8e, 0x98, 0xe4, 0xe7, 0x3e, 0xe2, 0xa7,
+        0x2f, 0x31, 0xb3,
+    ]);
+
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from_str_radix(
+            "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+            16,
+        )
+        .unwrap();
+        let y = BigUint::from_str_radix(
+            "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+            16,
+        )
+        .unwrap();
+        (x, y)
+    }
+
+    fn prime_group_order() -> num::BigUint {
+        BigUint::from_slice(&[
+            0xCCC52973, 0xECEC196A, 0x48B0A77A, 0x581A0DB2, 0xF4372DDF, 0xC7634D81, 0xFFFFFFFF,
+            0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF,
+        ])
+    }
+}
+
+/// Decompresses a P-384 point from its big-endian x coordinate, choosing the y coordinate whose
+/// parity matches `sign`. Returns `None` if x is not reduced, or not the x coordinate of a point on
+/// the curve.
+pub fn p384_decompress<E: EllipticCurve>(bytes_be: &[u8], sign: u32) -> Option<AffinePoint<E>> {
+    let modulus = P384BaseField::modulus();
+    let x = BigUint::from_bytes_be(bytes_be);
+    if x >= modulus {
+        return None;
+    }
+
+    let rhs = (&x * &x * &x + P384Parameters::a_int() * &x + P384Parameters::b_int()) % &modulus;
+    let y = p384_try_sqrt(&rhs)?;
+    let y = if (&y % 2u32 == BigUint::one()) == (sign == 1) { y } else { &modulus - y };
+    Some(AffinePoint::<E>::new(x, y))
+}
+
+/// Computes a square root of `n` modulo the P-384 base field prime, if one exists. As for P-256,
+/// the prime is 3 mod 4, so this is a single exponentiation by `(p + 1) / 4`.
+pub fn p384_try_sqrt(n: &BigUint) -> Option<BigUint> {
+    let modulus = P384BaseField::modulus();
+    let n = n % &modulus;
+    let exponent = (&modulus + BigUint::one()) >> 2;
+    let root = n.modpow(&exponent, &modulus);
+    ((&root * &root) % &modulus == n).then_some(root)
+}
+
+pub fn p384_sqrt(n: &BigUint) -> BigUint {
+    p384_try_sqrt(n).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::biguint_from_limbs;
+
+    /// The multiples `k * G` for `k = 1, 2, 3, 7`, computed with the p384 crate.
+    const MULTIPLES: [(&str, &str); 4] = [
+        (
+            "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+            "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+        ),
+        (
+            "08d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df61",
+            "8e80f1fa5b1b3cedb7bfe8dffd6dba74b275d875bc6cc43e904e505f256ab4255ffd43e94d39e22d61501e700a940e80",
+        ),
+        (
+            "077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06bea4f240d3566da6b408bbae5026580d02d7e5c70500c831",
+            "c995f7ca0b0c42837d0bbe9602a9fc998520b41c85115aa5f7684c0edc111eacc24abd6be4b5d298b65f28600a2f1df1",
+        ),
+        (
+            "283c1d7365ce4788f29f8ebf234edffead6fe997fbea5ffa2d58cc9dfa7b1c508b05526f55b9ebb2040f05b48fb6d0e1",
+            "9475c99061e41b88ba52efdb8c1690471a61d867ed799729d9c92cd01dbd225630d84ede32a78f9e64664cdac512ef8c",
+        ),
+    ];
+
+    fn multiple(index: usize) -> AffinePoint<P384> {
+        let (x, y) = MULTIPLES[index];
+        AffinePoint::new(
+            BigUint::from_str_radix(x, 16).unwrap(),
+            BigUint::from_str_radix(y, 16).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_p384_modulus() {
+        assert_eq!(biguint_from_limbs(P384BaseField::MODULUS), P384BaseField::modulus());
+        let expected = (BigUint::one() << 384) - (BigUint::one() << 128) - (BigUint::one() << 96)
+            + (BigUint::one() << 32)
+            - BigUint::one();
+        assert_eq!(P384BaseField::modulus(), expected);
+        assert_eq!(P384Parameters::a_int(), &expected - 3u32);
+    }
+
+    #[test]
+    fn test_p384_generator() {
+        let generator = multiple(0);
+        assert_eq!((generator.x, generator.y), P384Parameters::generator());
+
+        let modulus = P384BaseField::modulus();
+        let (x, y) = P384Parameters::generator();
+        let lhs = (&y * &y) % &modulus;
+        let rhs =
+            (&x * &x * &x + P384Parameters::a_int() * &x + P384Parameters::b_int()) % &modulus;
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn test_p384_add_double() {
+        let g = P384::generator();
+
+        let doubled = g.sw_double();
+        assert_eq!(doubled, multiple(1));
+        let tripled = g.sw_add(&doubled);
+        assert_eq!(tripled, multiple(2));
+    }
+
+    #[test]
+    fn test_p384_decompress() {
+        let (x, y) = P384Parameters::generator();
+        let sign = if &y % 2u32 == BigUint::one() { 1 } else { 0 };
+        let point = p384_decompress::<P384>(&x.to_bytes_be(), sign).unwrap();
+        assert_eq!(point.y, y);
+        let point = p384_decompress::<P384>(&x.to_bytes_be(), 1 - sign).unwrap();
+        assert_eq!(point.y, P384BaseField::modulus() - y);
+
+        // 7G has an even y, so its compressed SEC1 encoding has the tag 0x02.
+        let expected = multiple(3);
+        let point = p384_decompress::<P384>(&expected.x.to_bytes_be(), 0).unwrap();
+        assert_eq!(point, expected);
+    }
+
+    #[test]
+    fn test_p384_decompress_off_curve() {
+        // x = 1 gives a right-hand side of the curve equation that is not a square.
+        assert!(p384_decompress::<P384>(&[1], 0).is_none());
+    }
+
+    #[test]
+    fn test_p384_decompress_unreduced() {
+        let x = P384BaseField::modulus();
+        assert!(p384_decompress::<P384>(&x.to_bytes_be(), 0).is_none());
+    }
+}
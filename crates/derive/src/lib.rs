@@ -184,6 +184,13 @@ pub fn machine_air_derive(input: TokenStream) -> TokenStream {
                 }
             });
 
+            let padding_strategy_arms = variants.iter().map(|(variant_name, field)| {
+                let field_ty = &field.ty;
+                quote! {
+                    #name::#variant_name(x) => <#field_ty as sp1_stark::air::MachineAir<F>>::padding_strategy(x)
+                }
+            });
+
             let machine_air = quote! {
                 impl #impl_generics sp1_stark::air::MachineAir<F> for #name #ty_generics #where_clause {
                     type Record = #execution_record_path;
@@ -236,6 +243,12 @@ pub fn machine_air_derive(input: TokenStream) -> TokenStream {
                             #(#included_arms,)*
                         }
                     }
+
+                    fn padding_strategy(&self) -> sp1_stark::air::PaddingStrategy {
+                        match self {
+                            #(#padding_strategy_arms,)*
+                        }
+                    }
                 }
             };
 
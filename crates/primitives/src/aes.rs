@@ -0,0 +1,148 @@
+//! The AES-128 block cipher from FIPS 197.
+//!
+//! A block is sixteen bytes, and byte `r + 4 * c` of a block is row `r` and column `c` of the
+//! state, so each column is four consecutive bytes.
+
+/// The number of bytes in a block and in a key.
+pub const AES_BLOCK_SIZE: usize = 16;
+
+/// The number of rounds of AES-128.
+pub const AES128_NUM_ROUNDS: usize = 10;
+
+/// The substitution box used by `SubBytes` and the key expansion.
+pub const AES_SBOX: [u8; 256] = {
+    let mut sbox = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        // The inverse in GF(2^8) is `b^254`, which maps zero to zero.
+        let b = i as u8;
+        let mut inverse = 1u8;
+        let mut power = b;
+        let mut exp = 254u32;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                inverse = gf_mul(inverse, power);
+            }
+            power = gf_mul(power, power);
+            exp >>= 1;
+        }
+        sbox[i] = inverse
+            ^ inverse.rotate_left(1)
+            ^ inverse.rotate_left(2)
+            ^ inverse.rotate_left(3)
+            ^ inverse.rotate_left(4)
+            ^ 0x63;
+        i += 1;
+    }
+    sbox
+};
+
+/// The round constants of the key expansion.
+pub const AES_RCON: [u8; AES128_NUM_ROUNDS] =
+    [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// Multiplies by `x` in GF(2^8), reducing by `x^8 + x^4 + x^3 + x + 1`.
+pub const fn aes_xtime(a: u8) -> u8 {
+    (a << 1) ^ ((a >> 7) * 0x1b)
+}
+
+/// Multiplies two elements of GF(2^8).
+const fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        a = aes_xtime(a);
+        b >>= 1;
+    }
+    product
+}
+
+/// Returns the index of the byte that `ShiftRows` moves to index `i`.
+pub const fn aes_shift_rows_index(i: usize) -> usize {
+    let (row, col) = (i % 4, i / 4);
+    row + 4 * ((col + row) % 4)
+}
+
+/// Applies `ShiftRows`, which rotates row `r` left by `r` columns.
+pub fn aes_shift_rows(state: &[u8; AES_BLOCK_SIZE]) -> [u8; AES_BLOCK_SIZE] {
+    core::array::from_fn(|i| state[aes_shift_rows_index(i)])
+}
+
+/// Applies `MixColumns` to a single column.
+///
+/// Each output byte is `2 * a_i + 3 * a_(i+1) + a_(i+2) + a_(i+3)`, computed here as
+/// `a_i ^ t ^ xtime(a_i ^ a_(i+1))` with `t` the xor of the whole column.
+pub fn aes_mix_column(column: &[u8; 4]) -> [u8; 4] {
+    let t = column[0] ^ column[1] ^ column[2] ^ column[3];
+    core::array::from_fn(|i| column[i] ^ t ^ aes_xtime(column[i] ^ column[(i + 1) % 4]))
+}
+
+/// Derives the next round key from the previous one.
+pub fn aes128_next_round_key(key: &[u8; AES_BLOCK_SIZE], round: usize) -> [u8; AES_BLOCK_SIZE] {
+    let mut next = [0u8; AES_BLOCK_SIZE];
+    for i in 0..4 {
+        // `SubWord(RotWord(w3))` with the round constant added to the first byte.
+        let temp =
+            AES_SBOX[key[12 + (i + 1) % 4] as usize] ^ if i == 0 { AES_RCON[round] } else { 0 };
+        next[i] = key[i] ^ temp;
+    }
+    for i in 4..AES_BLOCK_SIZE {
+        next[i] = key[i] ^ next[i - 4];
+    }
+    next
+}
+
+/// Encrypts a block with AES-128.
+pub fn aes128_encrypt(
+    key: &[u8; AES_BLOCK_SIZE],
+    block: &[u8; AES_BLOCK_SIZE],
+) -> [u8; AES_BLOCK_SIZE] {
+    let mut round_key = *key;
+    let mut state: [u8; AES_BLOCK_SIZE] = core::array::from_fn(|i| block[i] ^ key[i]);
+    for round in 0..AES128_NUM_ROUNDS {
+        let sub_bytes = state.map(|b| AES_SBOX[b as usize]);
+        let mut shifted = aes_shift_rows(&sub_bytes);
+        if round != AES128_NUM_ROUNDS - 1 {
+            for column in shifted.chunks_exact_mut(4) {
+                let mixed = aes_mix_column(&column.try_into().unwrap());
+                column.copy_from_slice(&mixed);
+            }
+        }
+        round_key = aes128_next_round_key(&round_key, round);
+        state = core::array::from_fn(|i| shifted[i] ^ round_key[i]);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aes128_encrypt() {
+        assert_eq!(AES_SBOX[0x00], 0x63);
+        assert_eq!(AES_SBOX[0x53], 0xed);
+
+        // The examples from appendices B and C.1 of FIPS 197.
+        let vectors = [
+            (
+                "2b7e151628aed2a6abf7158809cf4f3c",
+                "3243f6a8885a308d313198a2e0370734",
+                "3925841d02dc09fbdc118597196a0b32",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "00112233445566778899aabbccddeeff",
+                "69c4e0d86a7b0430d8cdb78070b4c55a",
+            ),
+        ];
+        for (key, plaintext, ciphertext) in vectors {
+            let key = hex::decode(key).unwrap().try_into().unwrap();
+            let plaintext = hex::decode(plaintext).unwrap().try_into().unwrap();
+            let ciphertext = hex::decode(ciphertext).unwrap();
+            assert_eq!(aes128_encrypt(&key, &plaintext).to_vec(), ciphertext);
+        }
+    }
+}
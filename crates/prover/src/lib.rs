@@ -234,7 +234,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
     ) -> Result<SP1CoreProof, SP1CoreProverError> {
         context.subproof_verifier.replace(Arc::new(self));
         let program = Program::from(&pk.elf).unwrap();
-        let (proof, public_values_stream, cycles) =
+        let (proof, public_values_stream, cycles, cycle_report) =
             sp1_core_machine::utils::prove_with_context::<_, C::CoreProver>(
                 &self.core_prover,
                 &pk.pk,
@@ -250,6 +250,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
             stdin: stdin.clone(),
             public_values,
             cycles,
+            cycle_report,
         })
     }
 
@@ -6,6 +6,7 @@ use p3_bn254_fr::Bn254Fr;
 use p3_commit::{Pcs, TwoAdicMultiplicativeCoset};
 use p3_field::{AbstractField, PrimeField, PrimeField32, TwoAdicField};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp1_core_executor::CycleReport;
 use sp1_core_machine::{io::SP1Stdin, riscv::RiscvAir};
 use sp1_primitives::{io::SP1PublicValues, poseidon2_hash};
 use sp1_recursion_core::{air::RecursionPublicValues, stark::config::BabyBearPoseidon2Outer};
@@ -111,6 +112,10 @@ pub struct SP1ProofWithMetadata<P: Clone> {
     pub stdin: SP1Stdin,
     pub public_values: SP1PublicValues,
     pub cycles: u64,
+    /// The cycles spent in each region labelled by the guest's cycle tracker. Only populated when
+    /// the proof is generated locally.
+    #[serde(skip)]
+    pub cycle_report: CycleReport,
 }
 
 impl<P: Serialize + DeserializeOwned + Clone> SP1ProofWithMetadata<P> {
@@ -304,7 +304,7 @@ pub mod tests {
 
         let machine = RiscvAir::<C::F>::machine(SC::default());
         let (_, vk) = machine.setup(&Program::from(elf).unwrap());
-        let (proof, _, _) =
+        let (proof, _, _, _) =
             prove::<_, CoreP>(Program::from(elf).unwrap(), &SP1Stdin::new(), SC::default(), opts)
                 .unwrap();
         let mut challenger = machine.config().challenger();
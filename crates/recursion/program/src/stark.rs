@@ -389,7 +389,7 @@ pub(crate) mod tests {
         let machine = A::machine(SC::default());
         let (_, vk) = machine.setup(&Program::from(elf).unwrap());
         let mut challenger_val = machine.config().challenger();
-        let (proof, _, _) = sp1_core_machine::utils::prove::<_, CpuProver<_, _>>(
+        let (proof, _, _, _) = sp1_core_machine::utils::prove::<_, CpuProver<_, _>>(
             Program::from(elf).unwrap(),
             &SP1Stdin::new(),
             SC::default(),
@@ -1,4 +1,4 @@
-use sp1_core_executor::{ExecutionReport, HookEnv, SP1ContextBuilder};
+use sp1_core_executor::{ExecutionReport, GasMeter, HookEnv, SP1ContextBuilder};
 use sp1_core_machine::io::SP1Stdin;
 use sp1_primitives::io::SP1PublicValues;
 use sp1_prover::{components::DefaultProverComponents, SP1ProvingKey};
@@ -69,6 +69,15 @@ impl<'a> Execute<'a> {
         self.context_builder.max_cycles(max_cycles);
         self
     }
+
+    /// Meter the gas of the execution, which is then included in the [ExecutionReport].
+    ///
+    /// If the gas limit of the meter is exceeded, execution will return
+    /// [sp1_core_executor::ExecutionError::OutOfGas].
+    pub fn gas_meter(mut self, gas_meter: GasMeter) -> Self {
+        self.context_builder.gas_meter(gas_meter);
+        self
+    }
 }
 
 /// Builder to prepare and configure proving execution of a program on an input.
@@ -199,6 +208,15 @@ impl<'a> Prove<'a> {
         self
     }
 
+    /// Meter the gas of the execution.
+    ///
+    /// If the gas limit of the meter is exceeded, execution will return
+    /// [sp1_core_executor::ExecutionError::OutOfGas].
+    pub fn gas_meter(mut self, gas_meter: GasMeter) -> Self {
+        self.context_builder.gas_meter(gas_meter);
+        self
+    }
+
     /// Set the timeout for the proof's generation.
     ///
     /// This parameter is only used when the prover is run in network mode.
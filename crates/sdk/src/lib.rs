@@ -38,7 +38,10 @@ use {std::future::Future, tokio::task::block_in_place};
 
 pub use provers::{CpuProver, MockProver, Prover};
 
-pub use sp1_core_executor::{ExecutionReport, HookEnv, SP1Context, SP1ContextBuilder};
+pub use sp1_core_executor::{
+    CostTable, CycleReport, CycleReportEntry, ExecutionReport, GasMeter, HookEnv, SP1Context,
+    SP1ContextBuilder,
+};
 pub use sp1_core_machine::{io::SP1Stdin, riscv::cost::CostEstimator, SP1_CIRCUIT_VERSION};
 pub use sp1_primitives::io::SP1PublicValues;
 pub use sp1_prover::{
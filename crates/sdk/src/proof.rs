@@ -2,6 +2,7 @@ use std::{fmt::Debug, fs::File, path::Path};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use sp1_core_executor::CycleReport;
 use sp1_core_machine::io::SP1Stdin;
 use sp1_primitives::io::SP1PublicValues;
 use strum_macros::{EnumDiscriminants, EnumTryAs};
@@ -28,6 +29,10 @@ pub struct SP1ProofWithPublicValues {
     pub stdin: SP1Stdin,
     pub public_values: SP1PublicValues,
     pub sp1_version: String,
+    /// The cycles spent in each region labelled by the guest's cycle tracker, sorted by total
+    /// cost. Only populated for proofs generated locally; it is not saved with the proof.
+    #[serde(skip)]
+    pub cycle_report: CycleReport,
 }
 
 impl SP1ProofWithPublicValues {
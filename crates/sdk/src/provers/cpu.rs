@@ -56,11 +56,13 @@ impl Prover<DefaultProverComponents> for CpuProver {
                 stdin: proof.stdin,
                 public_values: proof.public_values,
                 sp1_version: self.version().to_string(),
+                cycle_report: proof.cycle_report,
             });
         }
 
         let deferred_proofs = stdin.proofs.iter().map(|p| p.0.clone()).collect();
         let public_values = proof.public_values.clone();
+        let cycle_report = proof.cycle_report.clone();
 
         // Generate the compressed proof.
         let reduce_proof =
@@ -71,6 +73,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                cycle_report,
             });
         }
 
@@ -96,6 +99,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                cycle_report,
             });
         } else if kind == SP1ProofKind::Groth16 {
             let groth16_bn254_artifacts = if sp1_prover::build::sp1_dev_mode() {
@@ -113,6 +117,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                cycle_report,
             });
         }
 
@@ -54,11 +54,13 @@ impl Prover<DefaultProverComponents> for CudaProver {
                 stdin: proof.stdin,
                 public_values: proof.public_values,
                 sp1_version: self.version().to_string(),
+                cycle_report: proof.cycle_report,
             });
         }
 
         let deferred_proofs = stdin.proofs.iter().map(|p| p.0.clone()).collect();
         let public_values = proof.public_values.clone();
+        let cycle_report = proof.cycle_report.clone();
 
         // Generate the compressed proof.
         let reduce_proof = self.cuda_prover.compress(&pk.vk, proof, deferred_proofs)?;
@@ -68,6 +70,7 @@ impl Prover<DefaultProverComponents> for CudaProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                cycle_report,
             });
         }
 
@@ -92,6 +95,7 @@ impl Prover<DefaultProverComponents> for CudaProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                cycle_report,
             });
         }
 
@@ -1,6 +1,6 @@
 #![allow(unused_variables)]
 use hashbrown::HashMap;
-use sp1_core_executor::SP1Context;
+use sp1_core_executor::{CycleReport, SP1Context};
 use sp1_core_machine::io::SP1Stdin;
 use sp1_stark::{ShardCommitment, ShardOpenedValues, ShardProof};
 
@@ -62,6 +62,7 @@ impl Prover<DefaultProverComponents> for MockProver {
                     stdin,
                     public_values,
                     sp1_version: self.version().to_string(),
+                    cycle_report: CycleReport::default(),
                 })
             }
             SP1ProofKind::Compressed => {
@@ -89,6 +90,7 @@ impl Prover<DefaultProverComponents> for MockProver {
                     stdin,
                     public_values,
                     sp1_version: self.version().to_string(),
+                    cycle_report: CycleReport::default(),
                 })
             }
             SP1ProofKind::Plonk => {
@@ -106,6 +108,7 @@ impl Prover<DefaultProverComponents> for MockProver {
                     stdin,
                     public_values,
                     sp1_version: self.version().to_string(),
+                    cycle_report: CycleReport::default(),
                 })
             }
             SP1ProofKind::Groth16 => {
@@ -123,6 +126,7 @@ impl Prover<DefaultProverComponents> for MockProver {
                     stdin,
                     public_values,
                     sp1_version: self.version().to_string(),
+                    cycle_report: CycleReport::default(),
                 })
             }
         }
@@ -0,0 +1,369 @@
+use std::{cell::Cell, fmt};
+
+use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues, PairBuilder};
+use p3_field::{AbstractField, Field};
+use p3_matrix::{
+    dense::{RowMajorMatrix, RowMajorMatrixView},
+    stack::VerticalPair,
+    Matrix,
+};
+
+use super::EmptyMessageBuilder;
+
+/// The maximum number of violated constraints listed when [`debug_air`] panics.
+const MAX_REPORTED_FAILURES: usize = 16;
+
+thread_local! {
+    static CONSTRAINT_NAME: Cell<Option<ConstraintName>> = const { Cell::new(None) };
+}
+
+/// The name of a constraint: where it is asserted in the AIR source, with an optional label.
+///
+/// Names are attached with the [`constraint!`](crate::constraint) macro.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConstraintName {
+    pub file: &'static str,
+    pub line: u32,
+    pub label: Option<&'static str>,
+}
+
+impl fmt::Display for ConstraintName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.label {
+            Some(label) => write!(f, "{label} ({}:{})", self.file, self.line),
+            None => write!(f, "{}:{}", self.file, self.line),
+        }
+    }
+}
+
+/// Runs `f` with `name` as the name of the constraints asserted inside it.
+///
+/// This is the implementation of [`constraint!`](crate::constraint), which should be used instead.
+#[doc(hidden)]
+pub fn with_constraint_name<R>(name: ConstraintName, f: impl FnOnce() -> R) -> R {
+    /// Restores the enclosing name, also when a failing constraint panics.
+    struct Restore(Option<ConstraintName>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CONSTRAINT_NAME.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(CONSTRAINT_NAME.with(|current| current.replace(Some(name))));
+    f()
+}
+
+/// The name of the constraint being asserted, if it is wrapped in
+/// [`constraint!`](crate::constraint).
+pub fn current_constraint_name() -> Option<ConstraintName> {
+    CONSTRAINT_NAME.with(Cell::get)
+}
+
+/// Names the constraints asserted by an expression after its location in the source, so that the
+/// debug builders can say which constraint failed. An optional string literal label can be given
+/// first.
+///
+/// Outside of the debug builders this has no effect on the constraints.
+///
+/// ### Examples
+/// ```ignore
+/// sp1_stark::constraint!(builder.when_first_row().assert_zero(local.nonce));
+/// sp1_stark::constraint!("nonce", builder.when_transition().assert_eq(local.nonce + one, next.nonce));
+/// ```
+#[macro_export]
+macro_rules! constraint {
+    ($label:literal, $assertion:expr) => {
+        $crate::air::with_constraint_name(
+            $crate::air::ConstraintName { file: file!(), line: line!(), label: Some($label) },
+            || $assertion,
+        )
+    };
+    ($assertion:expr) => {
+        $crate::air::with_constraint_name(
+            $crate::air::ConstraintName { file: file!(), line: line!(), label: None },
+            || $assertion,
+        )
+    };
+}
+
+/// The way a constraint was violated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstraintViolation<F> {
+    /// The two sides of an equality differ. Constraints asserted through `when` reach the builder
+    /// already multiplied out, so they show up as a nonzero left side and a zero right side.
+    NotEqual { left: F, right: F },
+    /// The value is neither zero nor one.
+    NotBool(F),
+}
+
+/// A constraint that does not hold on some row of a trace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintFailure<F> {
+    /// The row the constraint was evaluated on.
+    pub row: usize,
+    /// The position of the constraint among those asserted on the row.
+    pub index: usize,
+    /// The name of the constraint, if it was asserted inside [`constraint!`](crate::constraint).
+    pub name: Option<ConstraintName>,
+    pub violation: ConstraintViolation<F>,
+}
+
+impl<F: Field> fmt::Display for ConstraintFailure<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {}: constraint #{}", self.row, self.index)?;
+        if let Some(name) = self.name {
+            write!(f, " at {name}")?;
+        }
+        match self.violation {
+            ConstraintViolation::NotEqual { left, right } => write!(f, ": {left:?} != {right:?}"),
+            ConstraintViolation::NotBool(value) => write!(f, ": {value:?} is not a bool"),
+        }
+    }
+}
+
+/// A builder that evaluates the constraints of an AIR on a concrete trace and records every
+/// constraint that does not hold, instead of stopping at the first one.
+///
+/// Interactions are ignored, so this only checks the constraints local to the AIR.
+pub struct DebugAirBuilder<'a, F: Field> {
+    preprocessed: VerticalPair<RowMajorMatrixView<'a, F>, RowMajorMatrixView<'a, F>>,
+    main: VerticalPair<RowMajorMatrixView<'a, F>, RowMajorMatrixView<'a, F>>,
+    public_values: &'a [F],
+    row: usize,
+    is_first_row: F,
+    is_last_row: F,
+    is_transition: F,
+    num_constraints: usize,
+    failures: Vec<ConstraintFailure<F>>,
+}
+
+impl<'a, F: Field> DebugAirBuilder<'a, F> {
+    fn record(&mut self, violation: Option<ConstraintViolation<F>>) {
+        if let Some(violation) = violation {
+            self.failures.push(ConstraintFailure {
+                row: self.row,
+                index: self.num_constraints,
+                name: current_constraint_name(),
+                violation,
+            });
+        }
+        self.num_constraints += 1;
+    }
+}
+
+impl<'a, F: Field> AirBuilder for DebugAirBuilder<'a, F> {
+    type F = F;
+    type Expr = F;
+    type Var = F;
+    type M = VerticalPair<RowMajorMatrixView<'a, F>, RowMajorMatrixView<'a, F>>;
+
+    fn main(&self) -> Self::M {
+        self.main
+    }
+
+    fn is_first_row(&self) -> Self::Expr {
+        self.is_first_row
+    }
+
+    fn is_last_row(&self) -> Self::Expr {
+        self.is_last_row
+    }
+
+    fn is_transition_window(&self, size: usize) -> Self::Expr {
+        if size == 2 {
+            self.is_transition
+        } else {
+            panic!("only supports a window size of 2")
+        }
+    }
+
+    fn assert_zero<I: Into<Self::Expr>>(&mut self, x: I) {
+        self.assert_eq(x, F::zero());
+    }
+
+    fn assert_one<I: Into<Self::Expr>>(&mut self, x: I) {
+        self.assert_eq(x, F::one());
+    }
+
+    fn assert_eq<I1: Into<Self::Expr>, I2: Into<Self::Expr>>(&mut self, x: I1, y: I2) {
+        let (left, right) = (x.into(), y.into());
+        self.record((left != right).then_some(ConstraintViolation::NotEqual { left, right }));
+    }
+
+    fn assert_bool<I: Into<Self::Expr>>(&mut self, x: I) {
+        let x = x.into();
+        self.record((x != F::zero() && x != F::one()).then_some(ConstraintViolation::NotBool(x)));
+    }
+}
+
+impl<'a, F: Field> PairBuilder for DebugAirBuilder<'a, F> {
+    fn preprocessed(&self) -> Self::M {
+        self.preprocessed
+    }
+}
+
+impl<'a, F: Field> AirBuilderWithPublicValues for DebugAirBuilder<'a, F> {
+    type PublicVar = F;
+
+    fn public_values(&self) -> &[Self::PublicVar] {
+        self.public_values
+    }
+}
+
+impl<'a, F: Field> EmptyMessageBuilder for DebugAirBuilder<'a, F> {}
+
+/// Evaluates the constraints of `air` on every row of the trace and returns the ones that do not
+/// hold. As in the prover, the row after the last one is the first one.
+pub fn check_air_constraints<F, A>(
+    air: &A,
+    preprocessed: Option<&RowMajorMatrix<F>>,
+    main: &RowMajorMatrix<F>,
+    public_values: &[F],
+) -> Vec<ConstraintFailure<F>>
+where
+    F: Field,
+    A: for<'a> Air<DebugAirBuilder<'a, F>>,
+{
+    let height = main.height();
+    let mut failures = Vec::new();
+    for row in 0..height {
+        let row_next = (row + 1) % height;
+        let (preprocessed_local, preprocessed_next) = preprocessed.map_or_else(
+            || (Vec::new(), Vec::new()),
+            |preprocessed| (preprocessed.row(row).collect(), preprocessed.row(row_next).collect()),
+        );
+        let main_local = main.row_slice(row);
+        let main_next = main.row_slice(row_next);
+
+        let mut builder = DebugAirBuilder {
+            preprocessed: VerticalPair::new(
+                RowMajorMatrixView::new_row(&preprocessed_local),
+                RowMajorMatrixView::new_row(&preprocessed_next),
+            ),
+            main: VerticalPair::new(
+                RowMajorMatrixView::new_row(&main_local),
+                RowMajorMatrixView::new_row(&main_next),
+            ),
+            public_values,
+            row,
+            is_first_row: F::from_bool(row == 0),
+            is_last_row: F::from_bool(row == height - 1),
+            is_transition: F::from_bool(row != height - 1),
+            num_constraints: 0,
+            failures: Vec::new(),
+        };
+        air.eval(&mut builder);
+        failures.append(&mut builder.failures);
+    }
+    failures
+}
+
+/// Checks the constraints of `air` on every row of the trace, and panics with a list of the
+/// violated constraints, their names and the values they were evaluated to if any do not hold.
+///
+/// Unlike the prover's constraint debugging, this does not need a machine or interactions, so it
+/// can be run directly on the trace of a single chip.
+pub fn debug_air<F, A>(
+    air: &A,
+    preprocessed: Option<&RowMajorMatrix<F>>,
+    main: &RowMajorMatrix<F>,
+    public_values: &[F],
+) where
+    F: Field,
+    A: for<'a> Air<DebugAirBuilder<'a, F>>,
+{
+    let failures = check_air_constraints(air, preprocessed, main, public_values);
+    if failures.is_empty() {
+        return;
+    }
+
+    let mut report = format!("{} constraint(s) violated:\n", failures.len());
+    for failure in failures.iter().take(MAX_REPORTED_FAILURES) {
+        report.push_str(&format!("  {failure}\n"));
+    }
+    if failures.len() > MAX_REPORTED_FAILURES {
+        report.push_str(&format!("  ... and {} more\n", failures.len() - MAX_REPORTED_FAILURES));
+    }
+    panic!("{report}");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Borrow;
+
+    use p3_air::BaseAir;
+    use p3_baby_bear::BabyBear;
+
+    use super::*;
+    use crate::air::SP1AirBuilder;
+
+    /// Each row holds two consecutive Fibonacci numbers and a boolean flag.
+    struct FibonacciAir;
+
+    impl<F: Field> BaseAir<F> for FibonacciAir {
+        fn width(&self) -> usize {
+            3
+        }
+    }
+
+    impl<AB: SP1AirBuilder> Air<AB> for FibonacciAir {
+        fn eval(&self, builder: &mut AB) {
+            let main = builder.main();
+            let (local, next) = (main.row_slice(0), main.row_slice(1));
+            let local: &[AB::Var] = (*local).borrow();
+            let next: &[AB::Var] = (*next).borrow();
+
+            crate::constraint!("starts at one", builder.when_first_row().assert_one(local[0]));
+            crate::constraint!(builder.when_transition().assert_eq(next[0], local[1]));
+            crate::constraint!(
+                "fibonacci",
+                builder.when_transition().assert_eq(next[1], local[0] + local[1])
+            );
+            builder.assert_bool(local[2]);
+        }
+    }
+
+    fn trace(values: &[[u32; 3]]) -> RowMajorMatrix<BabyBear> {
+        let values = values.iter().flatten().copied().map(BabyBear::from_canonical_u32).collect();
+        RowMajorMatrix::new(values, 3)
+    }
+
+    #[test]
+    fn test_debug_air_valid_trace() {
+        let main = trace(&[[1, 1, 0], [1, 2, 1], [2, 3, 0], [3, 5, 1]]);
+        assert!(check_air_constraints(&FibonacciAir, None, &main, &[]).is_empty());
+        debug_air(&FibonacciAir, None, &main, &[]);
+    }
+
+    #[test]
+    fn test_debug_air_records_named_failures() {
+        let main = trace(&[[1, 1, 0], [1, 2, 2], [2, 4, 0], [4, 6, 1]]);
+        let failures = check_air_constraints(&FibonacciAir, None, &main, &[]);
+        assert_eq!(failures.len(), 2);
+
+        // The constraints asserted through `when_transition` are multiplied out, so the violation
+        // is a nonzero difference.
+        assert_eq!(failures[0].row, 1);
+        assert_eq!(failures[0].index, 2);
+        assert_eq!(failures[0].name.unwrap().label, Some("fibonacci"));
+        assert_eq!(failures[0].name.unwrap().file, file!());
+        assert_eq!(
+            failures[0].violation,
+            ConstraintViolation::NotEqual { left: BabyBear::one(), right: BabyBear::zero() }
+        );
+
+        assert_eq!(failures[1].row, 1);
+        assert_eq!(failures[1].name, None);
+        assert_eq!(failures[1].violation, ConstraintViolation::NotBool(BabyBear::two()));
+
+        assert!(current_constraint_name().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "fibonacci (")]
+    fn test_debug_air_panics_with_report() {
+        let main = trace(&[[1, 1, 0], [1, 3, 0]]);
+        debug_air(&FibonacciAir, None, &main, &[]);
+    }
+}
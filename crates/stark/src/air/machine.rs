@@ -41,6 +41,31 @@ pub trait MachineAir<F: Field>: BaseAir<F> + 'static + Send + Sync {
     fn generate_preprocessed_trace(&self, _program: &Self::Program) -> Option<RowMajorMatrix<F>> {
         None
     }
+
+    /// How the rows after the last event are filled when the trace is padded to a power of two.
+    fn padding_strategy(&self) -> PaddingStrategy {
+        PaddingStrategy::ZeroPad
+    }
+}
+
+/// The rows a chip appends after its last event to pad its trace to a power of two height.
+///
+/// Padding rows have `is_real` set to zero, which turns off the interactions and the constraints
+/// that a chip gates on it. Every other constraint still applies to them, so the strategy is part
+/// of the soundness argument of the chip: it has to produce rows that satisfy the ungated
+/// constraints without those constraints becoming vacuous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaddingStrategy {
+    /// Rows of zeros. This is sound when every constraint either holds trivially on zeros or is
+    /// gated on `is_real`. It is not sound for a field operation whose modulus is a column, since
+    /// a zero modulus makes the operation hold for any result.
+    ZeroPad,
+    /// Copies of the last row with `is_real` cleared, for chips with transition constraints that a
+    /// row of zeros would break.
+    RepeatLast,
+    /// Rows populated from a dummy event, such as an operation on zeros. Every ungated constraint
+    /// holds with a valid witness, including the ones with a constant modulus.
+    DummyEvent,
 }
 
 /// A program that defines the control flow of a machine through a program counter.
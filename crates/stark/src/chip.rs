@@ -7,7 +7,7 @@ use p3_uni_stark::{get_max_constraint_degree, SymbolicAirBuilder};
 use p3_util::log2_ceil_usize;
 
 use crate::{
-    air::{MachineAir, MultiTableAirBuilder, SP1AirBuilder},
+    air::{MachineAir, MultiTableAirBuilder, PaddingStrategy, SP1AirBuilder},
     lookup::{Interaction, InteractionBuilder, InteractionKind},
 };
 
@@ -195,6 +195,10 @@ where
     fn included(&self, shard: &Self::Record) -> bool {
         self.air.included(shard)
     }
+
+    fn padding_strategy(&self) -> PaddingStrategy {
+        <A as MachineAir<F>>::padding_strategy(&self.air)
+    }
 }
 
 // Implement AIR directly on Chip, evaluating both execution and permutation constraints.
@@ -16,7 +16,7 @@ use p3_matrix::{
 };
 
 use super::{MachineChip, StarkGenericConfig, Val};
-use crate::air::{EmptyMessageBuilder, MachineAir, MultiTableAirBuilder};
+use crate::air::{current_constraint_name, EmptyMessageBuilder, MachineAir, MultiTableAirBuilder};
 
 /// Checks that the constraints of the given AIR are satisfied, including the permutation trace.
 ///
@@ -109,6 +109,12 @@ pub fn debug_constraints<SC, A>(
     });
 }
 
+/// The name of the failing constraint for error messages, if it was asserted inside
+/// [`constraint!`](crate::constraint).
+fn constraint_name_suffix() -> String {
+    current_constraint_name().map_or_else(String::new, |name| format!(" at {name}"))
+}
+
 fn catch_unwind_silent<F: FnOnce() -> R + panic::UnwindSafe, R>(f: F) -> std::thread::Result<R> {
     let prev_hook = panic::take_hook();
     panic::set_hook(Box::new(|_| {}));
@@ -193,7 +199,7 @@ where
     fn debug_constraint(&self, x: F, y: F) {
         if x != y {
             let backtrace = std::backtrace::Backtrace::force_capture();
-            eprintln!("constraint failed: {x:?} != {y:?}\n{backtrace}");
+            eprintln!("constraint failed{}: {x:?} != {y:?}\n{backtrace}", constraint_name_suffix());
             panic!();
         }
     }
@@ -246,7 +252,10 @@ where
         let x = x.into();
         if x != F::zero() && x != F::one() {
             let backtrace = std::backtrace::Backtrace::force_capture();
-            eprintln!("constraint failed: {x:?} is not a bool\n{backtrace}");
+            eprintln!(
+                "constraint failed{}: {x:?} is not a bool\n{backtrace}",
+                constraint_name_suffix()
+            );
             panic!();
         }
     }
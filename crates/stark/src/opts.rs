@@ -137,6 +137,40 @@ pub struct SplitOpts {
     pub sha_extend: usize,
     /// The threshold for sha compress events.
     pub sha_compress: usize,
+    /// The threshold for uint256 modular exponentiation events.
+    pub mod_exp: usize,
+    /// The threshold for blake3 compress events.
+    pub blake3: usize,
+    /// The threshold for blake2b compress events.
+    pub blake2b: usize,
+    /// The threshold for ripemd160 compress events.
+    pub ripemd160: usize,
+    /// The threshold for weierstrass scalar multiplication events.
+    pub ec_mul: usize,
+    /// The threshold for chacha20 block events.
+    pub chacha20: usize,
+    /// The threshold for merkle path verification events.
+    pub merkle: usize,
+    /// The threshold for weierstrass multi-scalar multiplication events.
+    pub ec_msm: usize,
+    /// The threshold for weierstrass linear combination events.
+    pub ec_linear_combination: usize,
+    /// The threshold for FRI decommitment events.
+    pub fri: usize,
+    /// The threshold for ECDSA verification events.
+    pub ecdsa: usize,
+    /// The threshold for Ed25519 verification events.
+    pub eddsa: usize,
+    /// The threshold for batch field inversion events.
+    pub batch_inverse: usize,
+    /// The threshold for NTT events.
+    pub ntt: usize,
+    /// The threshold for SHA-256 hash events, counted in messages rather than blocks.
+    pub sha256_hash: usize,
+    /// The threshold for polynomial evaluation and multi-evaluation events.
+    pub poly_eval: usize,
+    /// The threshold for Lagrange interpolation events.
+    pub lagrange_interp: usize,
     /// The threshold for memory events.
     pub memory: usize,
 }
@@ -150,6 +184,23 @@ impl SplitOpts {
             keccak: deferred_shift_threshold / 24,
             sha_extend: deferred_shift_threshold / 48,
             sha_compress: deferred_shift_threshold / 80,
+            mod_exp: deferred_shift_threshold / 256,
+            blake3: deferred_shift_threshold / 7,
+            blake2b: deferred_shift_threshold / 12,
+            ripemd160: deferred_shift_threshold / 80,
+            ec_mul: deferred_shift_threshold / 256,
+            chacha20: deferred_shift_threshold / 10,
+            merkle: deferred_shift_threshold / 4096,
+            ec_msm: deferred_shift_threshold / 16608,
+            ec_linear_combination: deferred_shift_threshold / 2304,
+            fri: deferred_shift_threshold / 64,
+            ecdsa: deferred_shift_threshold / 514,
+            eddsa: deferred_shift_threshold / 258,
+            batch_inverse: deferred_shift_threshold / 64,
+            ntt: deferred_shift_threshold / 4096,
+            sha256_hash: deferred_shift_threshold / 1024,
+            poly_eval: deferred_shift_threshold / 4096,
+            lagrange_interp: deferred_shift_threshold / 1024,
             memory: deferred_shift_threshold * 4,
         }
     }
@@ -0,0 +1,244 @@
+use cipher::{
+    consts::{U1, U12, U32, U64},
+    Block, BlockSizeUser, Iv, IvSizeUser, Key, KeyIvInit, KeySizeUser, ParBlocksSizeUser,
+    StreamBackend, StreamCipherCore, StreamCipherCoreWrapper, StreamCipherSeekCore, StreamClosure,
+};
+
+/// The constant words at the start of the state, `"expand 32-byte k"`.
+const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+/// The index of the block counter in the state.
+const COUNTER_INDEX: usize = 12;
+
+/// The ChaCha20 stream cipher of RFC 8439, with a 32-bit block counter and a 96-bit nonce.
+///
+/// The keystream blocks are computed with the `CHACHA20_BLOCK` precompile inside the zkVM. The
+/// [`cipher::StreamCipher`] and [`cipher::StreamCipherSeek`] implementations of the wrapper keep
+/// the unused bytes of the last block, so the data may be processed in pieces of any length.
+///
+/// ### Examples
+/// ```ignore
+/// use sp1_zkvm::ciphers::ChaCha20;
+/// use cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+///
+/// let mut cipher = ChaCha20::new(&key.into(), &nonce.into());
+/// // Start from the block counter 1, as in the AEAD construction of RFC 8439.
+/// cipher.seek(64u32);
+/// cipher.apply_keystream(&mut ciphertext);
+/// ```
+pub type ChaCha20 = StreamCipherCoreWrapper<ChaCha20Core>;
+
+/// The block level core of [`ChaCha20`].
+#[derive(Clone)]
+pub struct ChaCha20Core {
+    /// The input state of the next keystream block.
+    state: [u32; 16],
+}
+
+impl KeySizeUser for ChaCha20Core {
+    type KeySize = U32;
+}
+
+impl IvSizeUser for ChaCha20Core {
+    type IvSize = U12;
+}
+
+impl BlockSizeUser for ChaCha20Core {
+    type BlockSize = U64;
+}
+
+impl KeyIvInit for ChaCha20Core {
+    fn new(key: &Key<Self>, iv: &Iv<Self>) -> Self {
+        let mut state = [0u32; 16];
+        state[..4].copy_from_slice(&CONSTANTS);
+        for (word, chunk) in state[4..COUNTER_INDEX].iter_mut().zip(key.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        for (word, chunk) in state[COUNTER_INDEX + 1..].iter_mut().zip(iv.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self { state }
+    }
+}
+
+impl StreamCipherCore for ChaCha20Core {
+    fn remaining_blocks(&self) -> Option<usize> {
+        (u32::MAX - self.state[COUNTER_INDEX]).try_into().ok()
+    }
+
+    fn process_with_backend(&mut self, f: impl StreamClosure<BlockSize = Self::BlockSize>) {
+        f.call(&mut Backend(self));
+    }
+}
+
+impl StreamCipherSeekCore for ChaCha20Core {
+    type Counter = u32;
+
+    fn get_block_pos(&self) -> u32 {
+        self.state[COUNTER_INDEX]
+    }
+
+    fn set_block_pos(&mut self, pos: u32) {
+        self.state[COUNTER_INDEX] = pos;
+    }
+}
+
+/// Generates the keystream blocks one at a time, since the precompile computes a single block.
+struct Backend<'a>(&'a mut ChaCha20Core);
+
+impl BlockSizeUser for Backend<'_> {
+    type BlockSize = U64;
+}
+
+impl ParBlocksSizeUser for Backend<'_> {
+    type ParBlocksSize = U1;
+}
+
+impl StreamBackend for Backend<'_> {
+    fn gen_ks_block(&mut self, block: &mut Block<Self>) {
+        let state = &mut self.0.state;
+        let keystream = keystream_block(state);
+        for (chunk, word) in block.chunks_exact_mut(4).zip(keystream) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        state[COUNTER_INDEX] = state[COUNTER_INDEX].wrapping_add(1);
+    }
+}
+
+/// The ChaCha20 block function: the state after the 20 rounds plus the input state. It is
+/// computed with the `CHACHA20_BLOCK` precompile inside the zkVM.
+fn keystream_block(state: &[u32; 16]) -> [u32; 16] {
+    let mut block = *state;
+
+    #[cfg(target_os = "zkvm")]
+    crate::syscalls::syscall_chacha20_block(&mut block);
+
+    #[cfg(not(target_os = "zkvm"))]
+    {
+        rounds(&mut block);
+        for (word, input) in block.iter_mut().zip(state) {
+            *word = word.wrapping_add(*input);
+        }
+    }
+
+    block
+}
+
+/// The 20 rounds of the block function, done in software outside the zkVM.
+#[cfg(not(target_os = "zkvm"))]
+fn rounds(state: &mut [u32; 16]) {
+    fn quarter_round(state: &mut [u32; 16], [a, b, c, d]: [usize; 4]) {
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] = (state[d] ^ state[a]).rotate_left(16);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_left(12);
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] = (state[d] ^ state[a]).rotate_left(8);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_left(7);
+    }
+
+    for _ in 0..10 {
+        quarter_round(state, [0, 4, 8, 12]);
+        quarter_round(state, [1, 5, 9, 13]);
+        quarter_round(state, [2, 6, 10, 14]);
+        quarter_round(state, [3, 7, 11, 15]);
+        quarter_round(state, [0, 5, 10, 15]);
+        quarter_round(state, [1, 6, 11, 12]);
+        quarter_round(state, [2, 7, 8, 13]);
+        quarter_round(state, [3, 4, 9, 14]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cipher::{StreamCipher, StreamCipherSeek};
+
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// The key `0x00..0x1f` of the RFC 8439 examples.
+    fn key() -> [u8; 32] {
+        core::array::from_fn(|i| i as u8)
+    }
+
+    /// The plaintext of the example of section 2.4.2 of RFC 8439.
+    const SUNSCREEN: &[u8] = b"Ladies and Gentlemen of the class of '99: If I could offer you \
+        only one tip for the future, sunscreen would be it.";
+
+    /// The ciphertext of the example of section 2.4.2 of RFC 8439.
+    const SUNSCREEN_CIPHERTEXT: &str =
+        "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b\
+        f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8\
+        07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736\
+        5af90bbf74a35be6b40b8eedf2785e42874d";
+
+    #[test]
+    fn test_chacha20_block_rfc8439() {
+        // The example of section 2.3.2 of RFC 8439.
+        let nonce = [0, 0, 0, 0x09, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+        let mut cipher = ChaCha20::new(&key().into(), &nonce.into());
+        cipher.seek(64u32);
+        let mut block = [0u8; 64];
+        cipher.apply_keystream(&mut block);
+        assert_eq!(
+            hex(&block),
+            "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e\
+             d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e"
+        );
+    }
+
+    #[test]
+    fn test_chacha20_encrypt_rfc8439() {
+        let nonce = [0, 0, 0, 0, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+        let mut cipher = ChaCha20::new(&key().into(), &nonce.into());
+        cipher.seek(64u32);
+        let mut data = SUNSCREEN.to_vec();
+        cipher.apply_keystream(&mut data);
+        assert_eq!(hex(&data), SUNSCREEN_CIPHERTEXT);
+
+        // Decryption is the same operation.
+        cipher.seek(64u32);
+        cipher.apply_keystream(&mut data);
+        assert_eq!(data, SUNSCREEN);
+    }
+
+    #[test]
+    fn test_chacha20_partial_blocks() {
+        // Processing the data in pieces that cross the block boundaries keeps the unused
+        // keystream bytes of the last block.
+        let nonce = [0, 0, 0, 0, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+        let mut cipher = ChaCha20::new(&key().into(), &nonce.into());
+        cipher.seek(64u32);
+        let mut data = SUNSCREEN.to_vec();
+        let mut rest = data.as_mut_slice();
+        for len in [1, 7, 56, 3, 61, 1] {
+            let (piece, tail) = rest.split_at_mut(len.min(rest.len()));
+            cipher.apply_keystream(piece);
+            rest = tail;
+        }
+        cipher.apply_keystream(rest);
+        assert_eq!(hex(&data), SUNSCREEN_CIPHERTEXT);
+        assert_eq!(cipher.current_pos::<u64>(), 64 + SUNSCREEN.len() as u64);
+
+        // Seeking to the middle of a block resumes from the same keystream byte.
+        let mut data = SUNSCREEN[100..].to_vec();
+        cipher.seek(164u32);
+        cipher.apply_keystream(&mut data);
+        assert_eq!(hex(&data), SUNSCREEN_CIPHERTEXT[200..]);
+    }
+
+    #[test]
+    fn test_chacha20_counter_overflow() {
+        // The block counter is 32 bits and never wraps around, so the last block of the
+        // keystream is the block 2^32 - 2.
+        let mut cipher = ChaCha20::new(&key().into(), &[0u8; 12].into());
+        cipher.seek((u32::MAX as u64 - 1) * 64);
+        let mut block = [0u8; 64];
+        assert!(cipher.try_apply_keystream(&mut block).is_ok());
+        assert!(cipher.try_apply_keystream(&mut [0u8; 1]).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! Ciphers implementing the `cipher` traits on top of the zkVM precompiles. Outside the zkVM they
+//! compute the same keystream in software, so code shared between the guest and the host compiles
+//! both ways.
+
+mod chacha20;
+
+pub use chacha20::{ChaCha20, ChaCha20Core};
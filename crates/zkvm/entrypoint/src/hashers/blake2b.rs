@@ -0,0 +1,286 @@
+use digest::{
+    consts::{U32, U64},
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+/// The size of a Blake2b block in bytes.
+const BLOCK_LEN: usize = 128;
+
+/// The maximum length of a Blake2b key in bytes.
+pub const BLAKE2B_MAX_KEY_LEN: usize = 64;
+
+/// The Blake2b initialization vector.
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// The Blake2b message schedule.
+#[cfg(not(target_os = "zkvm"))]
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// The Blake2b compression function `F`, with the block laid out as for the `BLAKE2B_COMPRESS`
+/// precompile.
+#[cfg(not(target_os = "zkvm"))]
+fn compress(h: &mut [u64; 8], block: &[u32; 37]) {
+    fn g(v: &mut [u64; 16], [a, b, c, d]: [usize; 4], x: u64, y: u64) {
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+        v[d] = (v[d] ^ v[a]).rotate_right(32);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(24);
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(63);
+    }
+
+    let words = |i: usize| block[2 * i] as u64 | (block[2 * i + 1] as u64) << 32;
+    let m: [u64; 16] = core::array::from_fn(words);
+
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&IV);
+    v[12] ^= words(16);
+    v[13] ^= words(17);
+    if block[36] == 1 {
+        v[14] = !v[14];
+    }
+    for round in 0..12 {
+        let s = &SIGMA[round % 10];
+        g(&mut v, [0, 4, 8, 12], m[s[0]], m[s[1]]);
+        g(&mut v, [1, 5, 9, 13], m[s[2]], m[s[3]]);
+        g(&mut v, [2, 6, 10, 14], m[s[4]], m[s[5]]);
+        g(&mut v, [3, 7, 11, 15], m[s[6]], m[s[7]]);
+        g(&mut v, [0, 5, 10, 15], m[s[8]], m[s[9]]);
+        g(&mut v, [1, 6, 11, 12], m[s[10]], m[s[11]]);
+        g(&mut v, [2, 7, 8, 13], m[s[12]], m[s[13]]);
+        g(&mut v, [3, 4, 9, 14], m[s[14]], m[s[15]]);
+    }
+    for (i, h) in h.iter_mut().enumerate() {
+        *h ^= v[i] ^ v[i + 8];
+    }
+}
+
+/// The Blake2b hashing state. Every block is compressed with the `BLAKE2B_COMPRESS` precompile
+/// inside the zkVM and in software outside of it.
+#[derive(Clone)]
+struct State {
+    h: [u64; 8],
+    /// The number of bytes compressed so far, not counting the buffered block.
+    t: u128,
+    buf: [u8; BLOCK_LEN],
+    buf_len: usize,
+}
+
+impl State {
+    /// Initializes the state for a digest of `out_len` bytes, with the key, if any, padded into
+    /// the first block.
+    fn new(out_len: usize, key: &[u8]) -> Self {
+        assert!(key.len() <= BLAKE2B_MAX_KEY_LEN, "blake2b keys are at most 64 bytes");
+        let mut h = IV;
+        h[0] ^= 0x01010000 ^ ((key.len() as u64) << 8) ^ out_len as u64;
+        let mut state = Self { h, t: 0, buf: [0; BLOCK_LEN], buf_len: 0 };
+        if !key.is_empty() {
+            state.buf[..key.len()].copy_from_slice(key);
+            state.buf_len = BLOCK_LEN;
+        }
+        state
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // The last block is compressed with the final block flag set, so a full buffer is
+            // only compressed once more input arrives.
+            if self.buf_len == BLOCK_LEN {
+                self.t += BLOCK_LEN as u128;
+                self.compress(false);
+                self.buf_len = 0;
+            }
+            let len = (BLOCK_LEN - self.buf_len).min(data.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&data[..len]);
+            self.buf_len += len;
+            data = &data[len..];
+        }
+    }
+
+    /// Compresses the zero padded last block and returns the full 64-byte state.
+    fn finalize(mut self) -> [u8; 64] {
+        self.t += self.buf_len as u128;
+        self.buf[self.buf_len..].fill(0);
+        self.compress(true);
+
+        let mut output = [0u8; 64];
+        for (chunk, word) in output.chunks_exact_mut(8).zip(self.h.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        output
+    }
+
+    fn compress(&mut self, last: bool) {
+        let mut block = [0u32; 37];
+        for (word, chunk) in block.iter_mut().zip(self.buf.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        for (i, word) in block[32..36].iter_mut().enumerate() {
+            *word = (self.t >> (32 * i)) as u32;
+        }
+        block[36] = last as u32;
+
+        #[cfg(target_os = "zkvm")]
+        crate::syscalls::syscall_blake2b_compress(&mut self.h, &block);
+
+        #[cfg(not(target_os = "zkvm"))]
+        compress(&mut self.h, &block);
+    }
+}
+
+macro_rules! impl_blake2b {
+    ($name:ident, $output_size:ty, $output_len:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Inside the zkVM it uses the `BLAKE2B_COMPRESS` precompile.
+        #[derive(Clone)]
+        pub struct $name {
+            state: State,
+            /// The state right after keying, restored on reset.
+            initial: State,
+        }
+
+        impl $name {
+            /// Creates a keyed hasher, which computes a MAC as described in RFC 7693.
+            ///
+            /// Panics if the key is longer than [`BLAKE2B_MAX_KEY_LEN`] bytes.
+            pub fn new_with_key(key: &[u8]) -> Self {
+                let state = State::new($output_len, key);
+                Self { state: state.clone(), initial: state }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new_with_key(&[])
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $output_size;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                self.state.update(data);
+            }
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.state.finalize()[..$output_len]);
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.state = self.initial.clone();
+            }
+        }
+
+        impl FixedOutputReset for $name {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                let state = core::mem::replace(&mut self.state, self.initial.clone());
+                out.copy_from_slice(&state.finalize()[..$output_len]);
+            }
+        }
+    };
+}
+
+impl_blake2b!(Blake2b512, U64, 64, "A streaming BLAKE2b-512 hasher.");
+impl_blake2b!(Blake2b256, U32, 32, "A streaming BLAKE2b-256 hasher.");
+
+#[cfg(test)]
+mod tests {
+    use digest::Digest;
+
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_blake2b_rfc7693() {
+        // The example from appendix A of RFC 7693.
+        assert_eq!(
+            hex(&Blake2b512::digest(b"abc")),
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+        assert_eq!(
+            hex(&Blake2b512::digest(b"")),
+            "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419\
+             d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce"
+        );
+        assert_eq!(
+            hex(&Blake2b256::digest(b"abc")),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+    }
+
+    #[test]
+    fn test_blake2b_keyed() {
+        // The keyed known answers from the reference implementation, with the key 0x00..0x3f and
+        // the input 0x00..(len - 1).
+        let key = (0..64u8).collect::<Vec<_>>();
+        let input = (0..=255u8).collect::<Vec<_>>();
+        let expected = [
+            (0, "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568"),
+            (1, "961f6dd1e4dd30f63901690c512e78e4b45e4742ed197c3c5e45c549fd25f2e4187b0bc9fe30492b16b0d0bc4ef9b0f34c7003fac09a5ef1532e69430234cebd"),
+            (127, "76d2d819c92bce55fa8e092ab1bf9b9eab237a25267986cacf2b8ee14d214d730dc9a5aa2d7b596e86a1fd8fa0804c77402d2fcd45083688b218b1cdfa0dcbcb"),
+            (128, "72065ee4dd91c2d8509fa1fc28a37c7fc9fa7d5b3f8ad3d0d7a25626b57b1b44788d4caf806290425f9890a3a2a35a905ab4b37acfd0da6e4517b2525c9651e4"),
+            (129, "64475dfe7600d7171bea0b394e27c9b00d8e74dd1e416a79473682ad3dfdbb706631558055cfc8a40e07bd015a4540dcdea15883cbbf31412df1de1cd4152b91"),
+            (255, "142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e92484be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461"),
+        ];
+        for (len, digest) in expected {
+            let hasher = Blake2b512::new_with_key(&key).chain_update(&input[..len]);
+            assert_eq!(hex(&hasher.finalize()), digest, "length {len}");
+        }
+
+        assert_eq!(
+            hex(&Blake2b256::new_with_key(b"key").chain_update(b"abc").finalize()),
+            "0330531d097355a3f72e80d55c1245ccf79f1704431c6e3887938320442c23c0"
+        );
+    }
+
+    #[test]
+    fn test_blake2b_streaming() {
+        let key = [7u8; 32];
+        let input = [3u8; 300];
+        let mut hasher = Blake2b512::new_with_key(&key);
+        for chunk in input.chunks(47) {
+            Digest::update(&mut hasher, chunk);
+        }
+        let streamed = hasher.finalize_reset();
+        assert_eq!(streamed, Blake2b512::new_with_key(&key).chain_update(input).finalize());
+
+        // Resetting a keyed hasher keeps the key.
+        assert_eq!(hasher.finalize(), Blake2b512::new_with_key(&key).finalize());
+    }
+}
@@ -0,0 +1,364 @@
+use digest::{
+    consts::U32, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+/// The Blake3 initialization vector.
+const IV: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+
+/// The number of bytes in a block.
+const BLOCK_LEN: usize = 64;
+
+/// The number of bytes in a chunk.
+const CHUNK_LEN: usize = 1024;
+
+/// The maximum depth of the tree, enough for inputs of up to 2^64 bytes.
+const MAX_DEPTH: usize = 54;
+
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+
+/// The seven rounds of the Blake3 compression function, done in software outside the zkVM.
+#[cfg(not(target_os = "zkvm"))]
+fn rounds(state: &mut [u32; 16], block: &[u32; 16]) {
+    const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+    fn g(state: &mut [u32; 16], [a, b, c, d]: [usize; 4], x: u32, y: u32) {
+        state[a] = state[a].wrapping_add(state[b]).wrapping_add(x);
+        state[d] = (state[d] ^ state[a]).rotate_right(16);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_right(12);
+        state[a] = state[a].wrapping_add(state[b]).wrapping_add(y);
+        state[d] = (state[d] ^ state[a]).rotate_right(8);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_right(7);
+    }
+
+    let mut m = *block;
+    for _ in 0..7 {
+        g(state, [0, 4, 8, 12], m[0], m[1]);
+        g(state, [1, 5, 9, 13], m[2], m[3]);
+        g(state, [2, 6, 10, 14], m[4], m[5]);
+        g(state, [3, 7, 11, 15], m[6], m[7]);
+        g(state, [0, 5, 10, 15], m[8], m[9]);
+        g(state, [1, 6, 11, 12], m[10], m[11]);
+        g(state, [2, 7, 8, 13], m[12], m[13]);
+        g(state, [3, 4, 9, 14], m[14], m[15]);
+        m = MSG_PERMUTATION.map(|i| m[i]);
+    }
+}
+
+/// The Blake3 compression function. The rounds are computed with the `BLAKE3_COMPRESS`
+/// precompile inside the zkVM.
+///
+/// Returns the full 16 word output. The first 8 words are the new chaining value.
+fn compress(
+    chaining_value: &[u32; 8],
+    block: &[u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+) -> [u32; 16] {
+    let mut state = [0u32; 16];
+    state[..8].copy_from_slice(chaining_value);
+    state[8..12].copy_from_slice(&IV[..4]);
+    state[12] = counter as u32;
+    state[13] = (counter >> 32) as u32;
+    state[14] = block_len;
+    state[15] = flags;
+
+    #[cfg(target_os = "zkvm")]
+    crate::syscalls::syscall_blake3_compress(&mut state, block);
+
+    #[cfg(not(target_os = "zkvm"))]
+    rounds(&mut state, block);
+
+    for i in 0..8 {
+        state[i] ^= state[i + 8];
+        state[i + 8] ^= chaining_value[i];
+    }
+    state
+}
+
+fn first_8_words(words: [u32; 16]) -> [u32; 8] {
+    words[..8].try_into().unwrap()
+}
+
+/// The inputs to a compression that has not been performed yet, so that the root flag can still
+/// be added to it.
+struct NodeOutput {
+    chaining_value: [u32; 8],
+    block: [u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+}
+
+impl NodeOutput {
+    fn parent(left: &[u32; 8], right: &[u32; 8]) -> Self {
+        let mut block = [0u32; 16];
+        block[..8].copy_from_slice(left);
+        block[8..].copy_from_slice(right);
+        Self { chaining_value: IV, block, counter: 0, block_len: BLOCK_LEN as u32, flags: PARENT }
+    }
+
+    fn chaining_value(&self) -> [u32; 8] {
+        first_8_words(compress(
+            &self.chaining_value,
+            &self.block,
+            self.counter,
+            self.block_len,
+            self.flags,
+        ))
+    }
+
+    /// Fills `out` with the root output, compressing once per 64 bytes with an incrementing
+    /// output block counter.
+    fn root_output_bytes(&self, out: &mut [u8]) {
+        for (counter, out_block) in out.chunks_mut(BLOCK_LEN).enumerate() {
+            let words = compress(
+                &self.chaining_value,
+                &self.block,
+                counter as u64,
+                self.block_len,
+                self.flags | ROOT,
+            );
+            for (bytes, word) in out_block.chunks_mut(4).zip(words) {
+                bytes.copy_from_slice(&word.to_le_bytes()[..bytes.len()]);
+            }
+        }
+    }
+}
+
+/// The state of the chunk being hashed.
+#[derive(Clone)]
+struct ChunkState {
+    chaining_value: [u32; 8],
+    chunk_counter: u64,
+    block: [u8; BLOCK_LEN],
+    block_len: usize,
+    blocks_compressed: usize,
+}
+
+impl ChunkState {
+    const fn new(chunk_counter: u64) -> Self {
+        Self {
+            chaining_value: IV,
+            chunk_counter,
+            block: [0; BLOCK_LEN],
+            block_len: 0,
+            blocks_compressed: 0,
+        }
+    }
+
+    const fn len(&self) -> usize {
+        BLOCK_LEN * self.blocks_compressed + self.block_len
+    }
+
+    const fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            // The last block of the chunk gets the end flag, so a full block is only compressed
+            // once more input arrives.
+            if self.block_len == BLOCK_LEN {
+                let words = compress(
+                    &self.chaining_value,
+                    &block_words(&self.block),
+                    self.chunk_counter,
+                    BLOCK_LEN as u32,
+                    self.start_flag(),
+                );
+                self.chaining_value = first_8_words(words);
+                self.blocks_compressed += 1;
+                self.block = [0; BLOCK_LEN];
+                self.block_len = 0;
+            }
+
+            let len = (BLOCK_LEN - self.block_len).min(input.len());
+            self.block[self.block_len..self.block_len + len].copy_from_slice(&input[..len]);
+            self.block_len += len;
+            input = &input[len..];
+        }
+    }
+
+    fn output(&self) -> NodeOutput {
+        NodeOutput {
+            chaining_value: self.chaining_value,
+            block: block_words(&self.block),
+            counter: self.chunk_counter,
+            block_len: self.block_len as u32,
+            flags: self.start_flag() | CHUNK_END,
+        }
+    }
+}
+
+fn block_words(block: &[u8; BLOCK_LEN]) -> [u32; 16] {
+    core::array::from_fn(|i| u32::from_le_bytes(block[i * 4..i * 4 + 4].try_into().unwrap()))
+}
+
+/// A streaming Blake3 hasher, computing the full chunk and tree construction.
+///
+/// Inside the zkVM every compression uses the `BLAKE3_COMPRESS` precompile. Besides the 32-byte
+/// digest of the `digest` traits, [`Blake3::finalize_xof_into`] reads an output of any length.
+///
+/// ### Examples
+/// ```ignore
+/// use digest::Digest;
+///
+/// let digest = sp1_zkvm::hashers::Blake3::new().chain_update(b"hello").finalize();
+/// ```
+#[derive(Clone)]
+pub struct Blake3 {
+    chunk_state: ChunkState,
+    /// The chaining values of the completed subtrees, from the left.
+    cv_stack: [[u32; 8]; MAX_DEPTH],
+    cv_stack_len: usize,
+}
+
+impl Blake3 {
+    /// Adds the chaining value of a completed chunk, first merging it with every completed
+    /// subtree of the same size. There are as many of those as trailing zeros in the total
+    /// number of chunks.
+    fn add_chunk_chaining_value(&mut self, mut chaining_value: [u32; 8], mut total_chunks: u64) {
+        while total_chunks & 1 == 0 {
+            self.cv_stack_len -= 1;
+            chaining_value = NodeOutput::parent(&self.cv_stack[self.cv_stack_len], &chaining_value)
+                .chaining_value();
+            total_chunks >>= 1;
+        }
+        self.cv_stack[self.cv_stack_len] = chaining_value;
+        self.cv_stack_len += 1;
+    }
+
+    /// Fills `out` with the extendable output of the hash of the input so far. The first 32 bytes
+    /// are the digest.
+    pub fn finalize_xof_into(&self, out: &mut [u8]) {
+        let mut output = self.chunk_state.output();
+        for left in self.cv_stack[..self.cv_stack_len].iter().rev() {
+            output = NodeOutput::parent(left, &output.chaining_value());
+        }
+        output.root_output_bytes(out);
+    }
+}
+
+impl Default for Blake3 {
+    fn default() -> Self {
+        Self { chunk_state: ChunkState::new(0), cv_stack: [[0; 8]; MAX_DEPTH], cv_stack_len: 0 }
+    }
+}
+
+impl HashMarker for Blake3 {}
+
+impl OutputSizeUser for Blake3 {
+    type OutputSize = U32;
+}
+
+impl Update for Blake3 {
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // As with blocks, a full chunk is only finished once more input arrives, since the
+            // last chunk may be the root.
+            if self.chunk_state.len() == CHUNK_LEN {
+                let chaining_value = self.chunk_state.output().chaining_value();
+                let total_chunks = self.chunk_state.chunk_counter + 1;
+                self.add_chunk_chaining_value(chaining_value, total_chunks);
+                self.chunk_state = ChunkState::new(total_chunks);
+            }
+
+            let len = (CHUNK_LEN - self.chunk_state.len()).min(data.len());
+            self.chunk_state.update(&data[..len]);
+            data = &data[len..];
+        }
+    }
+}
+
+impl FixedOutput for Blake3 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.finalize_xof_into(out);
+    }
+}
+
+impl Reset for Blake3 {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl FixedOutputReset for Blake3 {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.finalize_xof_into(out);
+        self.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use digest::Digest;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_blake3_empty() {
+        assert_eq!(Blake3::digest(b"")[..], ::blake3::hash(b"").as_bytes()[..]);
+    }
+
+    #[test]
+    fn test_blake3_differential() {
+        let mut rng = StdRng::seed_from_u64(0xb1a4e3);
+        let input = (0..10 * 1024).map(|_| rng.gen()).collect::<Vec<u8>>();
+
+        // The lengths around the block, chunk and subtree boundaries, then random ones.
+        let mut lengths = vec![0, 1, 63, 64, 65, 1023, 1024, 1025, 2048, 2049, 3072, 4097, 10240];
+        lengths.extend((0..64).map(|_| rng.gen_range(0..=10 * 1024)));
+        for len in lengths {
+            let input = &input[..len];
+            let expected = ::blake3::hash(input);
+            assert_eq!(Blake3::digest(input)[..], expected.as_bytes()[..], "length {len}");
+
+            // Feed the input in random pieces.
+            let mut hasher = Blake3::new();
+            let mut rest = input;
+            while !rest.is_empty() {
+                let (piece, tail) = rest.split_at(rng.gen_range(1..=rest.len().min(1500)));
+                Digest::update(&mut hasher, piece);
+                rest = tail;
+            }
+            assert_eq!(hasher.finalize()[..], expected.as_bytes()[..], "length {len}");
+        }
+    }
+
+    #[test]
+    fn test_blake3_xof() {
+        let input = [0x5a; 2500];
+        let mut hasher = Blake3::new();
+        Digest::update(&mut hasher, input);
+
+        for len in [1, 16, 32, 64, 65, 200] {
+            let mut output = vec![0u8; len];
+            hasher.finalize_xof_into(&mut output);
+
+            let mut expected = vec![0u8; len];
+            ::blake3::Hasher::new().update(&input).finalize_xof().fill(&mut expected);
+            assert_eq!(output, expected, "length {len}");
+        }
+    }
+
+    #[test]
+    fn test_blake3_reset() {
+        let mut hasher = Blake3::new();
+        Digest::update(&mut hasher, [1u8; 5000]);
+        assert_eq!(hasher.finalize_reset()[..], ::blake3::hash(&[1u8; 5000]).as_bytes()[..]);
+        assert_eq!(hasher.finalize()[..], ::blake3::hash(b"").as_bytes()[..]);
+    }
+}
@@ -0,0 +1,141 @@
+use digest::{
+    consts::U32, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+/// The rate of Keccak-256 in bytes.
+#[cfg(any(target_os = "zkvm", test))]
+const RATE: usize = 136;
+
+/// The Keccak-256 sponge, with the original Keccak domain byte.
+#[cfg(any(target_os = "zkvm", test))]
+type Sponge = super::sponge::KeccakSponge<RATE, 0x01>;
+
+#[cfg(any(target_os = "zkvm", test))]
+impl Sponge {
+    /// Pads the input and squeezes the digest.
+    fn finalize(mut self) -> [u8; 32] {
+        let mut output = [0u8; 32];
+        self.squeeze(&mut output);
+        output
+    }
+}
+
+/// A streaming Keccak-256 hasher.
+///
+/// Inside the zkVM it uses the `KECCAK_PERMUTE` precompile; outside it delegates to `tiny-keccak`.
+///
+/// ### Examples
+/// ```ignore
+/// use digest::Digest;
+///
+/// let digest = sp1_zkvm::hashers::Keccak256::new().chain_update(b"hello").finalize();
+/// ```
+#[derive(Clone)]
+pub struct Keccak256 {
+    #[cfg(target_os = "zkvm")]
+    inner: Sponge,
+    #[cfg(not(target_os = "zkvm"))]
+    inner: tiny_keccak::Keccak,
+}
+
+impl Default for Keccak256 {
+    fn default() -> Self {
+        Self {
+            #[cfg(target_os = "zkvm")]
+            inner: Sponge::new(),
+            #[cfg(not(target_os = "zkvm"))]
+            inner: tiny_keccak::Keccak::v256(),
+        }
+    }
+}
+
+impl HashMarker for Keccak256 {}
+
+impl OutputSizeUser for Keccak256 {
+    type OutputSize = U32;
+}
+
+impl Update for Keccak256 {
+    fn update(&mut self, data: &[u8]) {
+        #[cfg(target_os = "zkvm")]
+        self.inner.absorb(data);
+
+        #[cfg(not(target_os = "zkvm"))]
+        tiny_keccak::Hasher::update(&mut self.inner, data);
+    }
+}
+
+impl FixedOutput for Keccak256 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        #[cfg(target_os = "zkvm")]
+        out.copy_from_slice(&self.inner.finalize());
+
+        #[cfg(not(target_os = "zkvm"))]
+        tiny_keccak::Hasher::finalize(self.inner, out);
+    }
+}
+
+impl Reset for Keccak256 {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl FixedOutputReset for Keccak256 {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        core::mem::take(self).finalize_into(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use digest::Digest;
+
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn sponge_digest(input: &[u8]) -> [u8; 32] {
+        let mut sponge = Sponge::new();
+        sponge.absorb(input);
+        sponge.finalize()
+    }
+
+    #[test]
+    fn test_keccak256_empty() {
+        let expected = "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+        assert_eq!(hex(&Keccak256::digest(b"")), expected);
+        assert_eq!(hex(&sponge_digest(&[])), expected);
+    }
+
+    #[test]
+    fn test_keccak256_block_boundaries() {
+        // Around the rate, the padding either shares the last block, fills it exactly, or needs a
+        // block of its own.
+        let input = (0..=255u8).cycle().take(3 * RATE + 1).collect::<Vec<_>>();
+        for len in [1, RATE - 2, RATE - 1, RATE, RATE + 1, 2 * RATE, 3 * RATE + 1] {
+            let expected = Keccak256::digest(&input[..len]);
+            assert_eq!(sponge_digest(&input[..len]), expected[..], "length {len}");
+        }
+    }
+
+    #[test]
+    fn test_keccak256_streaming() {
+        let input = [7u8; 300];
+        let mut hasher = Keccak256::new();
+        for chunk in input.chunks(47) {
+            Digest::update(&mut hasher, chunk);
+        }
+        let streamed = hasher.finalize_reset();
+        assert_eq!(streamed, Keccak256::digest(input));
+        assert_eq!(hasher.finalize(), Keccak256::digest(b""));
+
+        let mut sponge = Sponge::new();
+        for chunk in input.chunks(47) {
+            sponge.absorb(chunk);
+        }
+        assert_eq!(sponge.finalize(), streamed[..]);
+    }
+}
@@ -0,0 +1,17 @@
+//! Hashers implementing the `digest` traits on top of the zkVM precompiles. Outside the zkVM they
+//! delegate to the usual software implementations, so code shared between the guest and the host
+//! compiles both ways.
+
+mod blake2b;
+mod blake3;
+mod keccak256;
+mod ripemd160;
+mod shake;
+#[cfg(any(target_os = "zkvm", test))]
+mod sponge;
+
+pub use blake2b::{Blake2b256, Blake2b512, BLAKE2B_MAX_KEY_LEN};
+pub use blake3::Blake3;
+pub use keccak256::Keccak256;
+pub use ripemd160::{hash160, Ripemd160};
+pub use shake::{Shake128, Shake256};
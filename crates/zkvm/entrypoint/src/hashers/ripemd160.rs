@@ -0,0 +1,291 @@
+use digest::{
+    consts::U20, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+/// The size of a RIPEMD-160 block in bytes.
+const BLOCK_LEN: usize = 64;
+
+/// The RIPEMD-160 initial chaining value.
+const IV: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+/// The RIPEMD-160 compression function, done in software outside the zkVM.
+#[cfg(not(target_os = "zkvm"))]
+fn compress(h: &mut [u32; 5], block: &[u32; 16]) {
+    const R_LEFT: [usize; 80] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, //
+        7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8, //
+        3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, //
+        1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2, //
+        4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+    ];
+    const R_RIGHT: [usize; 80] = [
+        5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, //
+        6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2, //
+        15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, //
+        8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14, //
+        12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+    ];
+    const S_LEFT: [u32; 80] = [
+        11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, //
+        7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12, //
+        11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, //
+        11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, //
+        9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+    ];
+    const S_RIGHT: [u32; 80] = [
+        8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, //
+        9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11, //
+        9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, //
+        15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8, //
+        8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+    ];
+    const K_LEFT: [u32; 5] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+    const K_RIGHT: [u32; 5] = [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+    fn f(j: usize, x: u32, y: u32, z: u32) -> u32 {
+        match j {
+            0 => x ^ y ^ z,
+            1 => (x & y) | (!x & z),
+            2 => (x | !y) ^ z,
+            3 => (x & z) | (y & !z),
+            _ => x ^ (y | !z),
+        }
+    }
+
+    fn step(lane: &mut [u32; 5], f_index: usize, x: u32, k: u32, s: u32) {
+        let [a, b, c, d, e] = *lane;
+        let t = a.wrapping_add(f(f_index, b, c, d)).wrapping_add(x).wrapping_add(k);
+        *lane = [e, t.rotate_left(s).wrapping_add(e), b, c.rotate_left(10), d];
+    }
+
+    let mut left = *h;
+    let mut right = *h;
+    for j in 0..80 {
+        let round = j / 16;
+        step(&mut left, round, block[R_LEFT[j]], K_LEFT[round], S_LEFT[j]);
+        step(&mut right, 4 - round, block[R_RIGHT[j]], K_RIGHT[round], S_RIGHT[j]);
+    }
+    *h = core::array::from_fn(|i| {
+        h[(i + 1) % 5].wrapping_add(left[(i + 2) % 5]).wrapping_add(right[(i + 3) % 5])
+    });
+}
+
+/// A streaming RIPEMD-160 hasher.
+///
+/// Inside the zkVM it uses the `RIPEMD160_COMPRESS` precompile.
+#[derive(Clone)]
+pub struct Ripemd160 {
+    h: [u32; 5],
+    /// The number of bytes compressed so far, not counting the buffered block.
+    len: u64,
+    buf: [u8; BLOCK_LEN],
+    buf_len: usize,
+}
+
+impl Ripemd160 {
+    /// Appends the padding and the bit length, and returns the digest.
+    fn finalize_bytes(mut self) -> [u8; 20] {
+        let bit_len = (self.len + self.buf_len as u64) * 8;
+        self.buf[self.buf_len] = 0x80;
+        self.buf[self.buf_len + 1..].fill(0);
+        if self.buf_len + 1 > BLOCK_LEN - 8 {
+            self.compress();
+            self.buf.fill(0);
+        }
+        self.buf[BLOCK_LEN - 8..].copy_from_slice(&bit_len.to_le_bytes());
+        self.compress();
+
+        let mut output = [0u8; 20];
+        for (chunk, word) in output.chunks_exact_mut(4).zip(self.h.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        output
+    }
+
+    fn compress(&mut self) {
+        let mut block = [0u32; 16];
+        for (word, chunk) in block.iter_mut().zip(self.buf.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        #[cfg(target_os = "zkvm")]
+        crate::syscalls::syscall_ripemd160_compress(&mut self.h, &block);
+
+        #[cfg(not(target_os = "zkvm"))]
+        compress(&mut self.h, &block);
+    }
+}
+
+impl Default for Ripemd160 {
+    fn default() -> Self {
+        Self { h: IV, len: 0, buf: [0; BLOCK_LEN], buf_len: 0 }
+    }
+}
+
+impl HashMarker for Ripemd160 {}
+
+impl OutputSizeUser for Ripemd160 {
+    type OutputSize = U20;
+}
+
+impl Update for Ripemd160 {
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let len = (BLOCK_LEN - self.buf_len).min(data.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&data[..len]);
+            self.buf_len += len;
+            data = &data[len..];
+            if self.buf_len == BLOCK_LEN {
+                self.compress();
+                self.len += BLOCK_LEN as u64;
+                self.buf_len = 0;
+            }
+        }
+    }
+}
+
+impl FixedOutput for Ripemd160 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.finalize_bytes());
+    }
+}
+
+impl Reset for Ripemd160 {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl FixedOutputReset for Ripemd160 {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&core::mem::take(self).finalize_bytes());
+    }
+}
+
+/// Computes SHA-256 with the `SHA_EXTEND` and `SHA_COMPRESS` precompiles.
+#[cfg(target_os = "zkvm")]
+fn sha256(bytes: &[u8]) -> [u8; 32] {
+    const IV: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    fn compress(state: &mut [u32; 8], block: &[u8]) {
+        let mut w = [0u32; 64];
+        for (word, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+        crate::syscalls::syscall_sha256_extend(&mut w);
+        crate::syscalls::syscall_sha256_compress(&mut w, state);
+    }
+
+    let mut state = IV;
+    let mut blocks = bytes.chunks_exact(BLOCK_LEN);
+    for block in &mut blocks {
+        compress(&mut state, block);
+    }
+
+    // The padding and the big endian bit length take up one or two more blocks.
+    let remainder = blocks.remainder();
+    let mut last = [0u8; 2 * BLOCK_LEN];
+    last[..remainder.len()].copy_from_slice(remainder);
+    last[remainder.len()] = 0x80;
+    let last_len = if remainder.len() + 1 > BLOCK_LEN - 8 { 2 * BLOCK_LEN } else { BLOCK_LEN };
+    last[last_len - 8..last_len].copy_from_slice(&(bytes.len() as u64 * 8).to_be_bytes());
+    for block in last[..last_len].chunks_exact(BLOCK_LEN) {
+        compress(&mut state, block);
+    }
+
+    let mut output = [0u8; 32];
+    for (chunk, word) in output.chunks_exact_mut(4).zip(state.iter()) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    output
+}
+
+/// Computes `RIPEMD160(SHA256(bytes))`, the HASH160 used by Bitcoin for P2PKH and P2SH.
+///
+/// Inside the zkVM both hashes use precompiles.
+pub fn hash160(bytes: &[u8]) -> [u8; 20] {
+    #[cfg(target_os = "zkvm")]
+    let sha256 = sha256(bytes);
+
+    #[cfg(not(target_os = "zkvm"))]
+    let sha256: [u8; 32] = <sha2::Sha256 as sha2::Digest>::digest(bytes).into();
+
+    let mut hasher = Ripemd160::default();
+    hasher.update(&sha256);
+    hasher.finalize_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use digest::Digest;
+
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_ripemd160_vectors() {
+        // The test vectors from the RIPEMD-160 specification.
+        let vectors: [(&[u8], &str); 8] = [
+            (b"", "9c1185a5c5e9fc54612808977ee8f548b2258d31"),
+            (b"a", "0bdc9d2d256b3ee9daae347be6f4dc835a467ffe"),
+            (b"abc", "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+            (b"message digest", "5d0689ef49d2fae572b881b123a85ffa21595f36"),
+            (b"abcdefghijklmnopqrstuvwxyz", "f71c27109c692c1b56bbdceb5b9d2865b3708dbc"),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "12a053384a9c0c88e405a06c27dcf49ada62eb2b",
+            ),
+            (
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+                "b0e20b6e3116640286ed3a87a5713079b21f5189",
+            ),
+            (
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                "9b752e45573d4b39f4dbd3323cab82bf63326bfb",
+            ),
+        ];
+        for (input, digest) in vectors {
+            assert_eq!(hex(&Ripemd160::digest(input)), digest);
+        }
+
+        let mut hasher = Ripemd160::new();
+        for _ in 0..1000 {
+            Digest::update(&mut hasher, [b'a'; 1000]);
+        }
+        assert_eq!(hex(&hasher.finalize()), "52783243c1697bdbe16d37f97f68f08325dc1528");
+    }
+
+    #[test]
+    fn test_ripemd160_multi_block() {
+        // Inputs around the block boundaries, hashed at once and streamed in uneven chunks.
+        let input = (0..300u32).map(|i| (i * 7 + 3) as u8).collect::<Vec<_>>();
+        let expected = [
+            (55, "ced4a416d2eddc4c54a59c57fa299bc86af70de9"),
+            (56, "581330764dcfaa5bbe4de58601aa56a838cc58d7"),
+            (64, "6049fc18acb2ba0205d12fbf2ebc57628031d28c"),
+            (300, "9c0d07f545cdcccbe74e846e5a2aa7d538bd5e6f"),
+        ];
+        for (len, digest) in expected {
+            assert_eq!(hex(&Ripemd160::digest(&input[..len])), digest, "length {len}");
+
+            let mut hasher = Ripemd160::new();
+            for chunk in input[..len].chunks(13) {
+                Digest::update(&mut hasher, chunk);
+            }
+            assert_eq!(hex(&hasher.finalize_reset()), digest, "length {len}");
+            assert_eq!(hasher.finalize(), Ripemd160::digest(b""));
+        }
+    }
+
+    #[test]
+    fn test_hash160() {
+        assert_eq!(hex(&hash160(b"")), "b472a266d0bd89c13706a4132ccfb16f7c3b9fcb");
+        assert_eq!(hex(&hash160(b"abc")), "bb1be98c142444d7a56aa3981c3942a978e4dc33");
+    }
+}
@@ -0,0 +1,201 @@
+/// The rate of SHAKE128 in bytes.
+#[cfg(any(target_os = "zkvm", test))]
+const SHAKE128_RATE: usize = 168;
+
+/// The rate of SHAKE256 in bytes.
+#[cfg(any(target_os = "zkvm", test))]
+const SHAKE256_RATE: usize = 136;
+
+/// The domain byte of SHAKE, which appends the bits `1111` to the input before the padding.
+#[cfg(any(target_os = "zkvm", test))]
+const SHAKE_DOMAIN: u8 = 0x1f;
+
+macro_rules! impl_shake {
+    ($name:ident, $rate:ident, $host:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// The input is absorbed with [`Self::update`], and the output is read with
+        /// [`Self::squeeze`], which can be called any number of times: each call continues the
+        /// output stream where the previous one stopped. The input cannot be updated after the
+        /// first squeeze.
+        ///
+        /// Inside the zkVM it uses the `KECCAK_PERMUTE` precompile; outside it delegates to
+        /// `tiny-keccak`.
+        #[derive(Clone)]
+        pub struct $name {
+            #[cfg(target_os = "zkvm")]
+            inner: super::sponge::KeccakSponge<$rate, SHAKE_DOMAIN>,
+            #[cfg(not(target_os = "zkvm"))]
+            inner: tiny_keccak::Shake,
+        }
+
+        impl $name {
+            /// Creates a hasher with no input.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Absorbs more input.
+            ///
+            /// Panics inside the zkVM if the hasher has already been squeezed.
+            pub fn update(&mut self, data: &[u8]) {
+                #[cfg(target_os = "zkvm")]
+                self.inner.absorb(data);
+
+                #[cfg(not(target_os = "zkvm"))]
+                tiny_keccak::Hasher::update(&mut self.inner, data);
+            }
+
+            /// Fills `out` with the next bytes of the output.
+            pub fn squeeze(&mut self, out: &mut [u8]) {
+                #[cfg(target_os = "zkvm")]
+                self.inner.squeeze(out);
+
+                #[cfg(not(target_os = "zkvm"))]
+                tiny_keccak::Xof::squeeze(&mut self.inner, out);
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    #[cfg(target_os = "zkvm")]
+                    inner: super::sponge::KeccakSponge::new(),
+                    #[cfg(not(target_os = "zkvm"))]
+                    inner: tiny_keccak::Shake::$host(),
+                }
+            }
+        }
+    };
+}
+
+impl_shake!(Shake128, SHAKE128_RATE, v128, "A SHAKE128 extendable-output function.");
+impl_shake!(Shake256, SHAKE256_RATE, v256, "A SHAKE256 extendable-output function.");
+
+#[cfg(test)]
+mod tests {
+    use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+    use super::*;
+    use crate::hashers::sponge::KeccakSponge;
+
+    type Shake128Sponge = KeccakSponge<SHAKE128_RATE, SHAKE_DOMAIN>;
+    type Shake256Sponge = KeccakSponge<SHAKE256_RATE, SHAKE_DOMAIN>;
+
+    fn expected_shake128(input: &[u8], len: usize) -> Vec<u8> {
+        let mut output = vec![0; len];
+        sha3::Shake128::default().chain(input).finalize_xof().read(&mut output);
+        output
+    }
+
+    fn expected_shake256(input: &[u8], len: usize) -> Vec<u8> {
+        let mut output = vec![0; len];
+        sha3::Shake256::default().chain(input).finalize_xof().read(&mut output);
+        output
+    }
+
+    /// Squeezes `len` bytes from the sponge in chunks of the given size.
+    fn squeeze_in_chunks<const RATE: usize>(
+        mut sponge: KeccakSponge<RATE, SHAKE_DOMAIN>,
+        len: usize,
+        chunk: usize,
+    ) -> Vec<u8> {
+        let mut output = vec![0; len];
+        for out in output.chunks_mut(chunk) {
+            sponge.squeeze(out);
+        }
+        output
+    }
+
+    #[test]
+    fn test_shake_vectors() {
+        // The outputs for the empty input from the SHA-3 standard.
+        let mut output = [0u8; 32];
+        Shake128::new().squeeze(&mut output);
+        assert_eq!(output[..], expected_shake128(b"", 32));
+        assert_eq!(output[..4], [0x7f, 0x9c, 0x2b, 0xa4]);
+        let mut output = [0u8; 64];
+        Shake256::new().squeeze(&mut output);
+        assert_eq!(output[..], expected_shake256(b"", 64));
+        assert_eq!(output[..4], [0x46, 0xb9, 0xdd, 0x2b]);
+    }
+
+    #[test]
+    fn test_shake_absorb_boundaries() {
+        // Around the rate, the padding either shares the last block, fills it exactly, or needs a
+        // block of its own.
+        let input = (0..=255u8).cycle().take(3 * SHAKE128_RATE + 1).collect::<Vec<_>>();
+        for rate in [SHAKE128_RATE, SHAKE256_RATE] {
+            for len in [0, 1, rate - 2, rate - 1, rate, rate + 1, 2 * rate, 3 * rate + 1] {
+                let mut sponge = Shake128Sponge::new();
+                sponge.absorb(&input[..len]);
+                let output = squeeze_in_chunks(sponge, 200, 200);
+                assert_eq!(output, expected_shake128(&input[..len], 200), "length {len}");
+
+                let mut sponge = Shake256Sponge::new();
+                sponge.absorb(&input[..len]);
+                let output = squeeze_in_chunks(sponge, 200, 200);
+                assert_eq!(output, expected_shake256(&input[..len], 200), "length {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_shake_multi_squeeze() {
+        // Squeezing in chunks which end before, at and across the rate gives the same stream as
+        // squeezing it at once, including chunks of nothing.
+        let input = b"the quick brown fox jumps over the lazy dog";
+        let len = 4 * SHAKE128_RATE + 3;
+        for chunk in [1, 7, SHAKE256_RATE - 1, SHAKE256_RATE, SHAKE128_RATE, SHAKE128_RATE + 1] {
+            let mut sponge = Shake128Sponge::new();
+            sponge.absorb(input);
+            let output = squeeze_in_chunks(sponge, len, chunk);
+            assert_eq!(output, expected_shake128(input, len), "chunk {chunk}");
+
+            let mut sponge = Shake256Sponge::new();
+            sponge.absorb(input);
+            let output = squeeze_in_chunks(sponge, len, chunk);
+            assert_eq!(output, expected_shake256(input, len), "chunk {chunk}");
+        }
+
+        let mut sponge = Shake256Sponge::new();
+        sponge.absorb(input);
+        let mut output = [0u8; SHAKE256_RATE];
+        sponge.squeeze(&mut []);
+        sponge.squeeze(&mut output[..SHAKE256_RATE - 1]);
+        sponge.squeeze(&mut []);
+        sponge.squeeze(&mut output[SHAKE256_RATE - 1..]);
+        assert_eq!(output[..], expected_shake256(input, SHAKE256_RATE));
+    }
+
+    #[test]
+    fn test_shake_streaming() {
+        // The hashers split the input and the output across calls the same way.
+        let input = [7u8; 500];
+        let mut shake128 = Shake128::new();
+        let mut shake256 = Shake256::new();
+        let mut sponge = Shake128Sponge::new();
+        for chunk in input.chunks(61) {
+            shake128.update(chunk);
+            shake256.update(chunk);
+            sponge.absorb(chunk);
+        }
+        let mut output128 = vec![0; 400];
+        let mut output256 = vec![0; 400];
+        for (out128, out256) in output128.chunks_mut(50).zip(output256.chunks_mut(50)) {
+            shake128.squeeze(out128);
+            shake256.squeeze(out256);
+        }
+        assert_eq!(output128, expected_shake128(&input, 400));
+        assert_eq!(output256, expected_shake256(&input, 400));
+        assert_eq!(squeeze_in_chunks(sponge, 400, 400), output128);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot absorb into a sponge after squeezing it")]
+    fn test_shake_absorb_after_squeeze() {
+        let mut sponge = Shake128Sponge::new();
+        sponge.squeeze(&mut [0u8; 1]);
+        sponge.absorb(b"more");
+    }
+}
@@ -0,0 +1,65 @@
+/// A Keccak sponge with a rate of `RATE` bytes and the domain separation byte `DOMAIN`. The state
+/// lives in guest memory and every block is permuted with the `KECCAK_PERMUTE` precompile.
+///
+/// The sponge absorbs until the first squeeze, which pads the input with the domain byte and the
+/// pad10*1 rule. After that it only squeezes, and each squeeze continues from where the previous
+/// one stopped.
+#[derive(Clone)]
+pub(super) struct KeccakSponge<const RATE: usize, const DOMAIN: u8> {
+    state: [u64; 25],
+    /// The number of bytes absorbed into, or squeezed from, the current block.
+    offset: usize,
+    squeezing: bool,
+}
+
+impl<const RATE: usize, const DOMAIN: u8> KeccakSponge<RATE, DOMAIN> {
+    pub(super) const fn new() -> Self {
+        Self { state: [0; 25], offset: 0, squeezing: false }
+    }
+
+    /// Panics if the sponge has already been squeezed.
+    pub(super) fn absorb(&mut self, input: &[u8]) {
+        assert!(!self.squeezing, "cannot absorb into a sponge after squeezing it");
+        for &byte in input {
+            self.xor_byte(self.offset, byte);
+            self.offset += 1;
+            if self.offset == RATE {
+                self.permute();
+                self.offset = 0;
+            }
+        }
+    }
+
+    /// Fills `output` with the next bytes of the output. An input that ends exactly at a block
+    /// boundary gets a whole block of padding, and a block is only permuted once more output is
+    /// needed from it.
+    pub(super) fn squeeze(&mut self, output: &mut [u8]) {
+        if !self.squeezing {
+            self.xor_byte(self.offset, DOMAIN);
+            self.xor_byte(RATE - 1, 0x80);
+            self.permute();
+            self.offset = 0;
+            self.squeezing = true;
+        }
+        for byte in output {
+            if self.offset == RATE {
+                self.permute();
+                self.offset = 0;
+            }
+            *byte = (self.state[self.offset / 8] >> (8 * (self.offset % 8))) as u8;
+            self.offset += 1;
+        }
+    }
+
+    fn xor_byte(&mut self, offset: usize, byte: u8) {
+        self.state[offset / 8] ^= (byte as u64) << (8 * (offset % 8));
+    }
+
+    fn permute(&mut self) {
+        #[cfg(target_os = "zkvm")]
+        crate::syscalls::syscall_keccak_permute(&mut self.state);
+
+        #[cfg(not(target_os = "zkvm"))]
+        tiny_keccak::keccakf(&mut self.state);
+    }
+}
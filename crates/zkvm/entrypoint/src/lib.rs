@@ -1,6 +1,9 @@
 extern crate alloc;
 
+pub mod ciphers;
+pub mod hashers;
 pub mod heap;
+pub mod merkle;
 pub mod syscalls;
 
 #[cfg(feature = "lib")]
@@ -13,6 +16,11 @@ pub mod lib {
     pub use sp1_lib::*;
 }
 
+#[cfg(feature = "lib")]
+pub mod precompiles {
+    pub use sp1_lib::{secp256k1, uint256 as u256};
+}
+
 #[cfg(all(target_os = "zkvm", feature = "libm"))]
 mod libm;
 
@@ -0,0 +1,297 @@
+//! Merkle proof verification on top of any of the `digest` hashers, so that the hashing runs in the
+//! zkVM precompiles when the hasher is backed by one, such as [`crate::hashers::Keccak256`] or
+//! `sha2::Sha256`.
+//!
+//! Two kinds of binary trees are supported:
+//! - Indexed trees, where the node of a pair is `H(left || right)` and the index of the leaf gives
+//!   the side of each node. The last node of a level with an odd number of nodes is paired with
+//!   itself, so its proof holds the node itself at that level.
+//! - Sorted-pair trees, as in OpenZeppelin's `MerkleProof`, where the node of a pair is the hash of
+//!   the two nodes in increasing order, so that proofs need no index. The last node of a level with
+//!   an odd number of nodes is moved up to the next level unchanged, so its proof skips that level.
+//!
+//! The leaves are the nodes of the lowest level, so hashing the leaf data, with any domain
+//! separation the application needs, is up to the caller. The proof of a tree with a single leaf is
+//! empty, and its root is the leaf.
+//!
+//! Since the last node of an odd level of an indexed tree is paired with itself, the tree with the
+//! leaves `[a, b, c]` has the same root as the tree with the leaves `[a, b, c, c]`. Applications
+//! where this matters should commit to the number of leaves as well.
+//!
+//! [`MerkleTree`] builds both kinds of trees and their proofs, for tests and for the host side of
+//! an application.
+
+use digest::{Digest, Output};
+
+/// Verifies that `leaf` is the leaf at `index` of the indexed tree with the given root.
+///
+/// The proof holds the sibling of the node on the path from the leaf to the root at each level,
+/// from the lowest one up. The index must be below `2^proof.len()`, so that it selects a leaf of a
+/// tree of the depth of the proof.
+///
+/// ### Examples
+/// ```ignore
+/// use sp1_zkvm::{hashers::Keccak256, merkle::verify_proof};
+///
+/// assert!(verify_proof::<Keccak256>(&root, &leaf, &proof, index));
+/// ```
+pub fn verify_proof<H: Digest>(root: &[u8], leaf: &[u8], proof: &[Vec<u8>], index: u64) -> bool {
+    if proof.len() < u64::BITS as usize && index >> proof.len() != 0 {
+        return false;
+    }
+    let Some((sibling, proof)) = proof.split_first() else {
+        return root == leaf;
+    };
+
+    // Keep the node on the stack, so that each level only copies the digest of the hasher.
+    let mut node = hash_indexed::<H>(leaf, sibling, index & 1 == 1);
+    for (level, sibling) in proof.iter().enumerate() {
+        node = hash_indexed::<H>(&node, sibling, (index >> (level + 1)) & 1 == 1);
+    }
+    root == node.as_slice()
+}
+
+/// Verifies that `leaf` is a leaf of the sorted-pair tree with the given root.
+///
+/// The proof holds the sibling of the node on the path from the leaf to the root at each level
+/// where it has one, from the lowest one up.
+///
+/// ### Examples
+/// ```ignore
+/// use sp1_zkvm::{hashers::Keccak256, merkle::verify_sorted_proof};
+///
+/// assert!(verify_sorted_proof::<Keccak256>(&root, &leaf, &proof));
+/// ```
+pub fn verify_sorted_proof<H: Digest>(root: &[u8], leaf: &[u8], proof: &[Vec<u8>]) -> bool {
+    let Some((sibling, proof)) = proof.split_first() else {
+        return root == leaf;
+    };
+
+    let mut node = hash_sorted::<H>(leaf, sibling);
+    for sibling in proof {
+        node = hash_sorted::<H>(&node, sibling);
+    }
+    root == node.as_slice()
+}
+
+/// Hashes a node with its sibling, which is on the left if `is_right` is set.
+fn hash_indexed<H: Digest>(node: &[u8], sibling: &[u8], is_right: bool) -> Output<H> {
+    if is_right {
+        H::new().chain_update(sibling).chain_update(node).finalize()
+    } else {
+        H::new().chain_update(node).chain_update(sibling).finalize()
+    }
+}
+
+/// Hashes two nodes in increasing order.
+fn hash_sorted<H: Digest>(a: &[u8], b: &[u8]) -> Output<H> {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    H::new().chain_update(left).chain_update(right).finalize()
+}
+
+/// A Merkle tree, with all of its levels, which gives the root and the proofs of its leaves for
+/// [`verify_proof`] or [`verify_sorted_proof`].
+///
+/// ### Examples
+/// ```ignore
+/// use sp1_zkvm::{hashers::Keccak256, merkle::{verify_proof, MerkleTree}};
+///
+/// let tree = MerkleTree::new::<Keccak256>(&leaves);
+/// assert!(verify_proof::<Keccak256>(tree.root(), &leaves[2], &tree.proof(2), 2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    /// The levels of the tree, from the leaves up to the root.
+    levels: Vec<Vec<Vec<u8>>>,
+    /// Whether the tree is a sorted-pair tree.
+    sorted: bool,
+}
+
+impl MerkleTree {
+    /// Builds the indexed tree of the given leaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no leaves.
+    pub fn new<H: Digest>(leaves: &[impl AsRef<[u8]>]) -> Self {
+        Self::build(leaves, false, |level| {
+            level
+                .chunks(2)
+                .map(|pair| {
+                    let right = pair.last().unwrap();
+                    hash_indexed::<H>(&pair[0], right, false).to_vec()
+                })
+                .collect()
+        })
+    }
+
+    /// Builds the sorted-pair tree of the given leaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no leaves.
+    pub fn new_sorted<H: Digest>(leaves: &[impl AsRef<[u8]>]) -> Self {
+        Self::build(leaves, true, |level| {
+            level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_sorted::<H>(left, right).to_vec(),
+                    _ => pair[0].clone(),
+                })
+                .collect()
+        })
+    }
+
+    fn build(
+        leaves: &[impl AsRef<[u8]>],
+        sorted: bool,
+        next_level: impl Fn(&[Vec<u8>]) -> Vec<Vec<u8>>,
+    ) -> Self {
+        assert!(!leaves.is_empty(), "a Merkle tree needs at least one leaf");
+        let mut levels = vec![leaves.iter().map(|leaf| leaf.as_ref().to_vec()).collect::<Vec<_>>()];
+        while levels.last().unwrap().len() > 1 {
+            let level = next_level(levels.last().unwrap());
+            levels.push(level);
+        }
+        Self { levels, sorted }
+    }
+
+    /// The number of leaves of the tree.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Whether the tree has no leaves, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// The root of the tree.
+    pub fn root(&self) -> &[u8] {
+        &self.levels.last().unwrap()[0]
+    }
+
+    /// The proof of the leaf at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn proof(&self, index: u64) -> Vec<Vec<u8>> {
+        assert!(index < self.len() as u64, "the index of the leaf is out of bounds");
+        let mut index = index as usize;
+        let mut proof = Vec::with_capacity(self.levels.len() - 1);
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = index ^ 1;
+            if sibling < level.len() {
+                proof.push(level[sibling].clone());
+            } else if !self.sorted {
+                // The last node of an odd level is paired with itself.
+                proof.push(level[index].clone());
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::Sha256;
+
+    use super::*;
+    use crate::hashers::Keccak256;
+
+    fn leaves(len: u8) -> Vec<Vec<u8>> {
+        (0..len).map(|i| Sha256::digest([i]).to_vec()).collect()
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        for len in 1..=17 {
+            let leaves = leaves(len);
+            let tree = MerkleTree::new::<Sha256>(&leaves);
+            for (index, leaf) in leaves.iter().enumerate() {
+                let index = index as u64;
+                let proof = tree.proof(index);
+                assert!(verify_proof::<Sha256>(tree.root(), leaf, &proof, index));
+
+                // The proof is bound to the leaf, its index, the root and the hasher.
+                assert!(!verify_proof::<Sha256>(tree.root(), b"leaf", &proof, index));
+                if (index ^ 1) < u64::from(len) {
+                    assert!(!verify_proof::<Sha256>(tree.root(), leaf, &proof, index ^ 1));
+                }
+                if len > 1 {
+                    assert!(!verify_proof::<Sha256>(&leaves[0], leaf, &proof, index));
+                    assert!(!verify_proof::<Keccak256>(tree.root(), leaf, &proof, index));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_proof_odd_levels() {
+        // The last leaf of a tree of three leaves is paired with itself.
+        let leaves = leaves(3);
+        let tree = MerkleTree::new::<Sha256>(&leaves);
+        let left = Sha256::new().chain_update(&leaves[0]).chain_update(&leaves[1]).finalize();
+        let right = Sha256::new().chain_update(&leaves[2]).chain_update(&leaves[2]).finalize();
+        let root = Sha256::new().chain_update(left).chain_update(right).finalize();
+        assert_eq!(tree.root(), root.as_slice());
+        assert_eq!(tree.proof(2), vec![leaves[2].clone(), left.to_vec()]);
+    }
+
+    #[test]
+    fn test_verify_proof_single_leaf() {
+        let leaf = b"leaf";
+        let tree = MerkleTree::new::<Sha256>(&[leaf]);
+        assert_eq!(tree.root(), leaf);
+        assert!(tree.proof(0).is_empty());
+        assert!(verify_proof::<Sha256>(leaf, leaf, &[], 0));
+        assert!(!verify_proof::<Sha256>(leaf, leaf, &[], 1));
+        assert!(!verify_proof::<Sha256>(b"root", leaf, &[], 0));
+    }
+
+    #[test]
+    fn test_verify_proof_index_out_of_bounds() {
+        let leaves = leaves(4);
+        let tree = MerkleTree::new::<Sha256>(&leaves);
+        let proof = tree.proof(1);
+        assert!(verify_proof::<Sha256>(tree.root(), &leaves[1], &proof, 1));
+        // The index selects the same path modulo the depth, but is not a leaf of the tree.
+        assert!(!verify_proof::<Sha256>(tree.root(), &leaves[1], &proof, 5));
+        assert!(!verify_proof::<Sha256>(tree.root(), &leaves[1], &proof, u64::MAX));
+        // A truncated proof does not verify.
+        assert!(!verify_proof::<Sha256>(tree.root(), &leaves[1], &proof[..1], 1));
+    }
+
+    #[test]
+    fn test_verify_sorted_proof() {
+        for len in 1..=17 {
+            let leaves = leaves(len);
+            let tree = MerkleTree::new_sorted::<Keccak256>(&leaves);
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = tree.proof(index as u64);
+                assert!(verify_sorted_proof::<Keccak256>(tree.root(), leaf, &proof));
+                assert!(!verify_sorted_proof::<Keccak256>(tree.root(), b"leaf", &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_sorted_proof_odd_levels() {
+        // The last leaf of a tree of three leaves is moved up unchanged, and the pairs are hashed
+        // in increasing order.
+        let leaves = [[3u8; 32], [1u8; 32], [2u8; 32]];
+        let tree = MerkleTree::new_sorted::<Keccak256>(&leaves);
+        let left = Keccak256::new().chain_update(leaves[1]).chain_update(leaves[0]).finalize();
+        let (low, high) = if leaves[2].as_slice() <= left.as_slice() {
+            (leaves[2].as_slice(), left.as_slice())
+        } else {
+            (left.as_slice(), leaves[2].as_slice())
+        };
+        let root = Keccak256::new().chain_update(low).chain_update(high).finalize();
+        assert_eq!(tree.root(), root.as_slice());
+        assert_eq!(tree.proof(2), vec![left.to_vec()]);
+        assert!(verify_sorted_proof::<Keccak256>(&root, &leaves[2], &tree.proof(2)));
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Encrypts the block at `input` with AES-128 under the key at `key`, and writes the ciphertext
+/// to `output`.
+///
+/// The precompile encrypts a block in place, so the plaintext is first copied to `output`. The
+/// bytes of the key and of each block are in order, four to a little endian word.
+///
+/// ### Safety
+///
+/// The caller must ensure that `key`, `input` and `output` are valid pointers to data that is
+/// aligned along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_aes128_encrypt(
+    key: *const [u32; 4],
+    input: *const [u32; 4],
+    output: *mut [u32; 4],
+) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        *output = *input;
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::AES128_ENCRYPT,
+            in("a0") output,
+            in("a1") key,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -1,18 +1,27 @@
-use super::syscall_uint256_mulmod;
+use super::{syscall_uint256_addmod, syscall_uint256_mulmod, syscall_uint256_submod};
 
 /// The number of limbs in a "uint256".
 const N: usize = 8;
 
+/// The `sys_bigint` operation for modular multiplication.
+pub const BIGINT_OP_MUL: u32 = 0;
+
+/// The `sys_bigint` operation for modular addition. The modulus must not be one.
+pub const BIGINT_OP_ADD: u32 = 1;
+
+/// The `sys_bigint` operation for modular subtraction. The x operand must be less than the modulus.
+pub const BIGINT_OP_SUB: u32 = 2;
+
 /// Sets `result` to be `(x op y) % modulus`.
 ///
-/// Currently only multiplication is supported and `op` is not used. If the modulus is zero, then
-/// the modulus applied is 2^256.
+/// The operation is selected by `op`: [`BIGINT_OP_ADD`] performs an addition, [`BIGINT_OP_SUB`]
+/// performs a subtraction, and any other value performs a multiplication. If the modulus is zero,
+/// then the modulus applied is 2^256.
 ///
 /// ### Safety
 ///
 /// The caller must ensure that `result`, `x`, `y`, and `modulus` are valid pointers to data that is
 /// aligned along a four byte boundary.
-#[allow(unused_variables)]
 #[no_mangle]
 pub extern "C" fn sys_bigint(
     result: *mut [u32; N],
@@ -37,12 +46,17 @@ pub extern "C" fn sys_bigint(
         core::ptr::copy(modulus as *const u32, concat_ptr.add(N), N);
 
         // Copy x into the result array, as our syscall will write the result into the first input.
-        core::ptr::copy(x as *const u32, result_ptr, N);
+        core::ptr::copy(x_ptr, result_ptr, N);
 
-        // Call the uint256_mul syscall to multiply the x value with the concatenated y and modulus.
-        // This syscall writes the result in-place, so it will mutate the result ptr appropriately.
+        // Call the uint256 syscall for the requested operation on the x value with the
+        // concatenated y and modulus. Both syscalls write the result in-place, so they will
+        // mutate the result ptr appropriately.
         let result_ptr = result_ptr as *mut [u32; N];
         let concat_ptr = concat_ptr as *mut [u32; N];
-        syscall_uint256_mulmod(result_ptr, concat_ptr);
+        match op {
+            BIGINT_OP_ADD => syscall_uint256_addmod(result_ptr, concat_ptr),
+            BIGINT_OP_SUB => syscall_uint256_submod(result_ptr, concat_ptr),
+            _ => syscall_uint256_mulmod(result_ptr, concat_ptr),
+        }
     }
 }
@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Executes the Blake2b compression function `F` on the given state.
+///
+/// The block holds the 16 little endian u64 message words as 32 u32 words, followed by the
+/// 128-bit byte counter as four words and the final block flag, which must be 0 or 1. The state
+/// is overwritten with the result.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `block` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_blake2b_compress(state: *mut [u64; 8], block: *const [u32; 37]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLAKE2B_COMPRESS,
+            in("a0") state,
+            in("a1") block,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Executes the seven rounds of the Blake3 compression function on the given state.
+///
+/// The state is overwritten with the result. Initializing the state from the chaining value and the
+/// final feed-forward are left to the caller.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `message` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_blake3_compress(state: *mut [u32; 16], message: *const [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLAKE3_COMPRESS,
+            in("a0") state,
+            in("a1") message,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
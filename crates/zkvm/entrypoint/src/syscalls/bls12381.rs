@@ -79,3 +79,55 @@ pub extern "C" fn syscall_bls12381_decompress(point: &mut [u8; 96], sign_bit: bo
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Adds two BLS12-381 G2 points.
+///
+/// Each point is given as its coordinates `[x.c0, x.c1, y.c0, y.c1]` over `Fp2`, and the result is
+/// stored in the first point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `q` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_g2_add(p: *mut [u32; 48], q: *const [u32; 48]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_G2_ADD,
+            in("a0") p,
+            in("a1") q,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Doubles a BLS12-381 G2 point.
+///
+/// The point is given as its coordinates `[x.c0, x.c1, y.c0, y.c1]` over `Fp2`, and the result is
+/// stored in place.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` is valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_g2_double(p: *mut [u32; 48]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_G2_DOUBLE,
+            in("a0") p,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
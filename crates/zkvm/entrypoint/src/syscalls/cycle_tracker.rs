@@ -0,0 +1,38 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Opens a cycle tracker region with the given label hash.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_cycle_tracker_start(label_hash: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::CYCLE_TRACKER_START,
+            in("a0") label_hash,
+            in("a1") 0
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Closes the innermost open cycle tracker region with the given label hash.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_cycle_tracker_end(label_hash: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::CYCLE_TRACKER_END,
+            in("a0") label_hash,
+            in("a1") 0
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
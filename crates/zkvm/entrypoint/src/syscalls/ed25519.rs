@@ -30,7 +30,8 @@ pub extern "C" fn syscall_ed_add(p: *mut [u32; 16], q: *const [u32; 16]) {
 ///
 /// The second half of the input array should contain the compressed Y point with the final bit as
 /// the sign bit. The first half of the input array will be overwritten with the decompressed point,
-/// and the sign bit will be removed.
+/// and the sign bit will be removed. If the Y coordinate is not reduced or is not the Y coordinate
+/// of a point with the given sign, every byte of the X coordinate is set to `0xff` instead.
 ///
 /// ### Safety
 ///
@@ -57,3 +58,57 @@ pub extern "C" fn syscall_ed_decompress(point: &mut [u8; 64]) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Executes a step of the X25519 Montgomery ladder.
+///
+/// The state `[x2, z2, x3, z3]` is overwritten with the projective u-coordinates of the double of
+/// `(x2 : z2)` and of the sum of both points, whose difference has u-coordinate `u`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `u` are valid pointers to data that is aligned along a
+/// four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_x25519_ladder_step(state: *mut [u32; 32], u: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::X25519_LADDER_STEP,
+            in("a0") state,
+            in("a1") u
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Verifies an Ed25519 signature equation.
+///
+/// `input` points to `S`, the challenge `k` and the two coordinates of the public key `A` and of
+/// `R`, each of them 8 little endian words, followed by a word that is set to 1 if `S * B - k * A`
+/// is `R` and 0 otherwise. The result is also 0 if a coordinate of the public key is not reduced,
+/// or if the public key is not on the curve.
+///
+/// ### Safety
+///
+/// The caller must ensure that `input` is a valid pointer to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_ed25519_verify(input: *mut [u32; 49]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::ED25519_VERIFY,
+            in("a0") input,
+            in("a1") 0
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,31 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Decommits a FRI query against a commitment whose leaves are hashed with the Poseidon2 sponge
+/// and whose nodes are compressed with the Poseidon2 permutation.
+///
+/// The input is the query index, followed by the number of leaf values, a word for the result, the
+/// 8 word root, the leaf values and `depth` 8 word siblings from the leaf level up. Bit `i` of the
+/// index is set if the node at level `i` is the right child. The result word is set to one if the
+/// computed root is equal to the root, and zero otherwise.
+///
+/// ### Safety
+///
+/// The caller must ensure that `input` is valid pointer to data that is aligned along a four byte
+/// boundary, that there are between 1 and 256 values, and that `depth` is between 1 and 32.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_fri_decommit(input: *mut u32, depth: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::FRI_DECOMMIT,
+            in("a0") input,
+            in("a1") depth
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,30 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Evaluates at a target the polynomial of least degree through a set of points, and writes the
+/// result over the target in the parameters.
+///
+/// The points are `x_i, y_i` pairs, and the parameters are the number of points, between 1 and 32,
+/// the target and a modulus below 2^32. The points and the target must be reduced, and the `x_i`
+/// must be distinct.
+///
+/// ### Safety
+///
+/// The caller must ensure that `points` and `params` are valid pointers to data that is aligned
+/// along a four byte boundary, and that the points are twice the number of points words.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_lagrange_interp(points: *const u32, params: *mut [u32; 3]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::LAGRANGE_INTERP,
+            in("a0") points,
+            in("a1") params,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,30 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Verifies a Merkle path whose nodes are hashed with SHA-256.
+///
+/// The input is the 8 word leaf, followed by the 8 word root, the word of direction bits, a word
+/// for the result and `depth` 8 word siblings from the leaf level up. Bit `i` of the directions is
+/// set if the node at level `i` is the right child. The result word is set to one if the computed
+/// root is equal to the root, and zero otherwise.
+///
+/// ### Safety
+///
+/// The caller must ensure that `input` is valid pointer to data that is aligned along a four byte
+/// boundary, and that `depth` is between 1 and 32.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_merkle_verify_path(input: *mut u32, depth: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::MERKLE_VERIFY_PATH,
+            in("a0") input,
+            in("a1") depth
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
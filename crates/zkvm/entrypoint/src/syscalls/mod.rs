@@ -1,35 +1,105 @@
+mod aes128_encrypt;
+mod babyjubjub;
 mod bigint;
+mod blake2b_compress;
+mod blake3_compress;
 mod bls12381;
 mod bn254;
+mod chacha20_block;
+mod clz32;
+mod cycle_tracker;
 mod ed25519;
 mod fptower;
+mod fri_decommit;
+mod grumpkin;
 mod halt;
 mod io;
 mod keccak_permute;
+mod lagrange_interp;
 mod memory;
+mod merkle_verify_path;
+mod ntt;
+mod p256;
+mod p384;
+mod pedersen_hash;
+mod poly_eval;
+mod poly_multi_eval;
+mod popcount;
+mod poseidon2_permute;
+mod rescue_permute;
+mod ripemd160_compress;
 mod secp256k1;
+mod sha256_hash;
 mod sha_compress;
 mod sha_extend;
 mod sys;
+mod uint256_add_mod;
+mod uint256_bitwise;
+mod uint256_cmp;
+mod uint256_divrem;
+mod uint256_inv_mod;
+mod uint256_mod_exp;
+mod uint256_mont_mul;
 mod uint256_mul;
+mod uint256_mul_wide;
+mod uint256_range_check;
+mod uint256_square_mod;
+mod uint256_sub_mod;
+mod uint384_mul;
+mod uint512_mul;
 mod unconstrained;
 #[cfg(feature = "verify")]
 mod verify;
 
+pub use aes128_encrypt::*;
+pub use babyjubjub::*;
 pub use bigint::*;
+pub use blake2b_compress::*;
+pub use blake3_compress::*;
 pub use bls12381::*;
 pub use bn254::*;
+pub use chacha20_block::*;
+pub use clz32::*;
+pub use cycle_tracker::*;
 pub use ed25519::*;
 pub use fptower::*;
+pub use fri_decommit::*;
+pub use grumpkin::*;
 pub use halt::*;
 pub use io::*;
 pub use keccak_permute::*;
+pub use lagrange_interp::*;
 pub use memory::*;
+pub use merkle_verify_path::*;
+pub use ntt::*;
+pub use p256::*;
+pub use p384::*;
+pub use pedersen_hash::*;
+pub use poly_eval::*;
+pub use poly_multi_eval::*;
+pub use popcount::*;
+pub use poseidon2_permute::*;
+pub use rescue_permute::*;
+pub use ripemd160_compress::*;
 pub use secp256k1::*;
+pub use sha256_hash::*;
 pub use sha_compress::*;
 pub use sha_extend::*;
 pub use sys::*;
+pub use uint256_add_mod::*;
+pub use uint256_bitwise::*;
+pub use uint256_cmp::*;
+pub use uint256_divrem::*;
+pub use uint256_inv_mod::*;
+pub use uint256_mod_exp::*;
+pub use uint256_mont_mul::*;
 pub use uint256_mul::*;
+pub use uint256_mul_wide::*;
+pub use uint256_range_check::*;
+pub use uint256_square_mod::*;
+pub use uint256_sub_mod::*;
+pub use uint384_mul::*;
+pub use uint512_mul::*;
 pub use unconstrained::*;
 #[cfg(feature = "verify")]
 pub use verify::*;
@@ -94,6 +164,12 @@ pub const HINT_LEN: u32 = 0x00_00_00_F0;
 /// Executes `HINT_READ`.
 pub const HINT_READ: u32 = 0x00_00_00_F1;
 
+/// Executes `CYCLE_TRACKER_START`.
+pub const CYCLE_TRACKER_START: u32 = 0x00_00_00_F2;
+
+/// Executes `CYCLE_TRACKER_END`.
+pub const CYCLE_TRACKER_END: u32 = 0x00_00_00_F3;
+
 /// Executes `BLS12381_DECOMPRESS`.
 pub const BLS12381_DECOMPRESS: u32 = 0x00_00_01_1C;
 
@@ -141,3 +217,183 @@ pub const BN254_FP2_SUB: u32 = 0x00_01_01_2A;
 
 /// Executes the `BN254_FP2_MUL` precompile.
 pub const BN254_FP2_MUL: u32 = 0x00_01_01_2B;
+
+/// Executes the `UINT256_ADD_MOD` precompile.
+pub const UINT256_ADD_MOD: u32 = 0x00_01_01_2C;
+
+/// Executes the `UINT256_SUB_MOD` precompile.
+pub const UINT256_SUB_MOD: u32 = 0x00_01_01_2D;
+
+/// Executes the `UINT512_MUL` precompile.
+pub const UINT512_MUL: u32 = 0x00_01_01_2E;
+
+/// Executes the `UINT256_INV_MOD` precompile.
+pub const UINT256_INV_MOD: u32 = 0x00_01_01_2F;
+
+/// Executes the `BLAKE3_COMPRESS` precompile.
+pub const BLAKE3_COMPRESS: u32 = 0x00_01_01_30;
+
+/// Executes the `UINT256_SQUARE_MOD` precompile.
+pub const UINT256_SQUARE_MOD: u32 = 0x00_01_01_31;
+
+/// Executes the `SECP256K1_MUL` precompile.
+pub const SECP256K1_MUL: u32 = 0x0001_0132;
+
+/// Executes the `UINT384_MUL` precompile.
+pub const UINT384_MUL: u32 = 0x00_01_01_33;
+
+/// Executes the `P256_ADD` precompile.
+pub const P256_ADD: u32 = 0x00_01_01_34;
+
+/// Executes the `P256_DOUBLE` precompile.
+pub const P256_DOUBLE: u32 = 0x00_00_01_35;
+
+/// Executes the `P256_MUL` precompile.
+pub const P256_MUL: u32 = 0x00_01_01_36;
+
+/// Executes the `UINT256_MUL_WIDE` precompile.
+pub const UINT256_MUL_WIDE: u32 = 0x00_01_01_37;
+
+/// Executes the `BN254_G2_ADD` precompile.
+pub const BN254_G2_ADD: u32 = 0x00_01_01_38;
+
+/// Executes the `UINT256_DIVREM` precompile.
+pub const UINT256_DIVREM: u32 = 0x00_01_01_39;
+
+/// Executes the `UINT256_MONT_MUL` precompile.
+pub const UINT256_MONT_MUL: u32 = 0x00_01_01_3A;
+
+/// Executes the `UINT256_MOD_EXP` precompile.
+pub const UINT256_MOD_EXP: u32 = 0x00_01_01_3B;
+
+/// Executes the `POSEIDON2_PERMUTE` precompile.
+pub const POSEIDON2_PERMUTE: u32 = 0x00_01_01_3C;
+
+/// Executes the `RESCUE_PERMUTE` precompile.
+pub const RESCUE_PERMUTE: u32 = 0x00_01_01_3D;
+
+/// Executes the `SECP256R1_DECOMPRESS` precompile.
+pub const SECP256R1_DECOMPRESS: u32 = 0x00_00_01_3E;
+
+/// Executes the `PEDERSEN_HASH` precompile.
+pub const PEDERSEN_HASH: u32 = 0x00_01_01_3F;
+
+/// Executes the `AES128_ENCRYPT` precompile.
+pub const AES128_ENCRYPT: u32 = 0x00_01_01_40;
+
+/// Executes the `CHACHA20_BLOCK` precompile.
+pub const CHACHA20_BLOCK: u32 = 0x00_01_01_41;
+
+/// Executes the `BLS12381_G2_ADD` precompile.
+pub const BLS12381_G2_ADD: u32 = 0x00_01_01_42;
+
+/// Executes the `BLS12381_G2_DOUBLE` precompile.
+pub const BLS12381_G2_DOUBLE: u32 = 0x00_00_01_43;
+
+/// Executes the `BN254_G2_DOUBLE` precompile.
+pub const BN254_G2_DOUBLE: u32 = 0x00_00_01_44;
+
+/// Executes the `POPCOUNT` precompile.
+pub const POPCOUNT: u32 = 0x00_00_01_45;
+
+/// Executes the `CLZ32` precompile.
+pub const CLZ32: u32 = 0x00_00_01_46;
+
+/// Executes the `UINT256_RANGE_CHECK` precompile.
+pub const UINT256_RANGE_CHECK: u32 = 0x00_01_01_47;
+
+/// Executes the `MERKLE_VERIFY_PATH` precompile.
+pub const MERKLE_VERIFY_PATH: u32 = 0x00_01_01_48;
+
+/// Executes the `SECP256K1_MSM` precompile.
+pub const SECP256K1_MSM: u32 = 0x00_21_01_49;
+
+/// Executes the `X25519_LADDER_STEP` precompile.
+pub const X25519_LADDER_STEP: u32 = 0x00_01_01_4A;
+
+/// Executes the `FRI_DECOMMIT` precompile.
+pub const FRI_DECOMMIT: u32 = 0x00_01_01_4B;
+
+/// Executes the `SECP256K1_ECDSA_VERIFY` precompile.
+pub const SECP256K1_ECDSA_VERIFY: u32 = 0x00_01_01_4C;
+
+/// Executes the `ED25519_VERIFY` precompile.
+pub const ED25519_VERIFY: u32 = 0x00_01_01_4D;
+
+/// Executes the `P384_ADD` precompile.
+pub const P384_ADD: u32 = 0x00_01_01_4E;
+
+/// Executes the `P384_DOUBLE` precompile.
+pub const P384_DOUBLE: u32 = 0x00_00_01_4F;
+
+/// Executes the `SECP384R1_DECOMPRESS` precompile.
+pub const SECP384R1_DECOMPRESS: u32 = 0x00_00_01_50;
+
+/// Executes the `BN254_FR_ADD` precompile.
+pub const BN254_FR_ADD: u32 = 0x00_01_01_51;
+
+/// Executes the `BN254_FR_SUB` precompile.
+pub const BN254_FR_SUB: u32 = 0x00_01_01_52;
+
+/// Executes the `BN254_FR_MUL` precompile.
+pub const BN254_FR_MUL: u32 = 0x00_01_01_53;
+
+/// Executes the `GRUMPKIN_ADD` precompile.
+pub const GRUMPKIN_ADD: u32 = 0x00_01_01_54;
+
+/// Executes the `GRUMPKIN_DOUBLE` precompile.
+pub const GRUMPKIN_DOUBLE: u32 = 0x00_00_01_55;
+
+/// Executes the `BLAKE2B_COMPRESS` precompile.
+pub const BLAKE2B_COMPRESS: u32 = 0x00_01_01_56;
+
+/// Executes the `RIPEMD160_COMPRESS` precompile.
+pub const RIPEMD160_COMPRESS: u32 = 0x00_01_01_57;
+
+/// Executes the `BLS12381_FP_BATCH_INV` precompile.
+pub const BLS12381_FP_BATCH_INV: u32 = 0x00_01_01_58;
+
+/// Executes the `BN254_FP_BATCH_INV` precompile.
+pub const BN254_FP_BATCH_INV: u32 = 0x00_01_01_59;
+
+/// Executes the `BN254_FR_BATCH_INV` precompile.
+pub const BN254_FR_BATCH_INV: u32 = 0x00_01_01_5A;
+
+/// Executes the `NTT` precompile.
+pub const NTT: u32 = 0x00_14_01_5B;
+
+/// Executes the `INTT` precompile.
+pub const INTT: u32 = 0x00_14_01_5C;
+
+/// Executes the `SHA256_HASH` precompile.
+pub const SHA256_HASH: u32 = 0x00_02_01_5D;
+
+/// Executes the `POLY_EVAL` precompile.
+pub const POLY_EVAL: u32 = 0x00_02_01_5E;
+
+/// Executes the `POLY_MULTI_EVAL` precompile.
+pub const POLY_MULTI_EVAL: u32 = 0x00_02_01_5F;
+
+/// Executes the `BABYJUBJUB_ADD` precompile.
+pub const BABYJUBJUB_ADD: u32 = 0x00_01_01_60;
+
+/// Executes the `BABYJUBJUB_DOUBLE` precompile.
+pub const BABYJUBJUB_DOUBLE: u32 = 0x00_00_01_61;
+
+/// Executes the `LAGRANGE_INTERP` precompile.
+pub const LAGRANGE_INTERP: u32 = 0x00_21_01_62;
+
+/// Executes the `EC_LINEAR_COMBINATION` precompile.
+pub const EC_LINEAR_COMBINATION: u32 = 0x00_09_01_63;
+
+/// Executes the `UINT256_CMP` precompile.
+pub const UINT256_CMP: u32 = 0x0001_0164;
+
+/// Executes the `UINT256_AND` precompile.
+pub const UINT256_AND: u32 = 0x00_01_01_65;
+
+/// Executes the `UINT256_OR` precompile.
+pub const UINT256_OR: u32 = 0x00_01_01_66;
+
+/// Executes the `UINT256_XOR` precompile.
+pub const UINT256_XOR: u32 = 0x00_01_01_67;
@@ -0,0 +1,56 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Applies a number theoretic transform in place to the given coefficients.
+///
+/// The parameters are the number of coefficients, a power of two between 2 and 2^20, a root of
+/// unity of that order, and a modulus below 2^32. The coefficients and the root must be reduced.
+/// The evaluations are left in bit-reversed order.
+///
+/// ### Safety
+///
+/// The caller must ensure that `coeffs` and `params` are valid pointers to data that is aligned
+/// along a four byte boundary, and that `coeffs` points to the given number of coefficients.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_ntt(coeffs: *mut u32, params: *const [u32; 3]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::NTT,
+            in("a0") coeffs,
+            in("a1") params,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Applies an inverse number theoretic transform in place to the given evaluations, which are in
+/// bit-reversed order.
+///
+/// The parameters are as for [`syscall_ntt`], with the inverse of the root of unity of the forward
+/// transform. The result is not divided by the number of coefficients.
+///
+/// ### Safety
+///
+/// The caller must ensure that `coeffs` and `params` are valid pointers to data that is aligned
+/// along a four byte boundary, and that `coeffs` points to the given number of coefficients.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_intt(coeffs: *mut u32, params: *const [u32; 3]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::INTT,
+            in("a0") coeffs,
+            in("a1") params,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Adds a window of a Pedersen hash over the Jubjub curve to the given accumulator, which holds
+/// the little endian `x` and `y` coordinates of a point.
+///
+/// `input` is the index of the window shifted left by three, plus the three bits of the window.
+/// The index must be below the number of windows of the hash.
+///
+/// ### Safety
+///
+/// The caller must ensure that `acc` is a valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_pedersen_hash(acc: *mut [u32; 16], input: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::PEDERSEN_HASH,
+            in("a0") acc,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Evaluates a polynomial at a point with Horner's method, and writes the result over the point.
+///
+/// The parameters are the degree, below 2^20, the point, and a modulus below 2^32. The coefficients
+/// go from the constant one up, and they and the point must be reduced.
+///
+/// ### Safety
+///
+/// The caller must ensure that `coeffs` and `params` are valid pointers to data that is aligned
+/// along a four byte boundary, and that `coeffs` points to one more coefficient than the degree.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_poly_eval(coeffs: *const u32, params: *mut [u32; 3]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::POLY_EVAL,
+            in("a0") coeffs,
+            in("a1") params,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,30 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Evaluates a polynomial at each of `count` points, and writes the results over the points.
+///
+/// The parameters are a pointer to the coefficients, a pointer to the points, the degree, below
+/// 2^20, and a modulus below 2^32. The coefficients go from the constant one up, and there are
+/// between 1 and 8 points. The coefficients and the points must be reduced.
+///
+/// ### Safety
+///
+/// The caller must ensure that `params` and the pointers in it are valid pointers to data that is
+/// aligned along a four byte boundary, that the coefficients are one more than the degree, and that
+/// the points are `count` words.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_poly_multi_eval(params: *const [u32; 4], count: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::POLY_MULTI_EVAL,
+            in("a0") params,
+            in("a1") count,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
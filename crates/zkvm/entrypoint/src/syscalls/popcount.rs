@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Counts the set bits of a 64-bit value.
+///
+/// The value is given as two little endian words. The count is written over the low word, and
+/// the high word is cleared.
+///
+/// ### Safety
+///
+/// The caller must ensure that `value` is valid pointer to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_popcount(value: *mut [u32; 2]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::POPCOUNT,
+            in("a0") value,
+            in("a1") 0
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Executes the Poseidon2 permutation over BabyBear on the given state.
+///
+/// The state words are reduced modulo the BabyBear prime and the canonical result is written over
+/// them. `field_bits` must be 31, the bit size of the BabyBear modulus.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` is a valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_poseidon2_permute(state: *mut [u32; 16], field_bits: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::POSEIDON2_PERMUTE,
+            in("a0") state,
+            in("a1") field_bits,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
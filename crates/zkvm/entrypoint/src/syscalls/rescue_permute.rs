@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Executes a round of the Rescue-Prime permutation over the BN254 scalar field on the given
+/// state, which holds three little endian field elements of eight words each.
+///
+/// The state elements are reduced and the result of the round is written over them. `round` must
+/// be below the number of rounds of the permutation.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` is a valid pointer to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_rescue_permute(state: *mut [u32; 24], round: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::RESCUE_PERMUTE,
+            in("a0") state,
+            in("a1") round,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
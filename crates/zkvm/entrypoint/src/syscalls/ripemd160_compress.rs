@@ -0,0 +1,27 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Executes the RIPEMD-160 compression function on the given state and message block.
+///
+/// The state and the block are little endian words. The state is overwritten with the result.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `block` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_ripemd160_compress(state: *mut [u32; 5], block: *const [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::RIPEMD160_COMPRESS,
+            in("a0") state,
+            in("a1") block,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -51,6 +51,117 @@ pub extern "C" fn syscall_secp256k1_double(p: *mut [u32; 16]) {
     unreachable!()
 }
 
+/// Multiplies a Secp256k1 point by a scalar.
+///
+/// The scalar is little endian, and must be nonzero and less than the order of the curve. The
+/// result is stored in-place in the supplied point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `scalar` are valid pointers to data that is aligned along a
+/// four byte boundary, and that they don't overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_mul(p: *mut [u32; 16], scalar: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_MUL,
+            in("a0") p,
+            in("a1") scalar
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Computes the sum of Secp256k1 points multiplied by scalars.
+///
+/// `pairs` points to `num_pairs` (scalar, point) pairs of 24 words each: a little endian scalar
+/// followed by a point. There must be between 1 and 255 pairs. The result is stored in-place in the
+/// point of the first pair. Neither the result nor any partial sum of the bucket method may be the
+/// point at infinity.
+///
+/// ### Safety
+///
+/// The caller must ensure that `pairs` is a valid pointer to `num_pairs` pairs that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_msm(pairs: *mut [u32; 24], num_pairs: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_MSM,
+            in("a0") pairs,
+            in("a1") num_pairs
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Computes the sum of Secp256k1 points multiplied by scalars with Shamir's trick.
+///
+/// `pairs` points to `num_pairs` entries of two words each: a pointer to a little endian scalar of
+/// 8 words followed by a pointer to a point of 16 words. There must be between 1 and 8 entries.
+/// The result is stored in-place in the point of the first entry. Neither the result nor any
+/// partial sum may be the point at infinity.
+///
+/// ### Safety
+///
+/// The caller must ensure that `pairs` is a valid pointer to `num_pairs` entries, and that the
+/// entries hold valid pointers to scalars and points, all aligned along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_ec_linear_combination(pairs: *const [u32; 2], num_pairs: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::EC_LINEAR_COMBINATION,
+            in("a0") pairs,
+            in("a1") num_pairs
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Verifies a Secp256k1 ECDSA signature.
+///
+/// `input` points to `r`, `s`, the message hash and the two coordinates of the public key, each of
+/// them 8 little endian words, followed by a word that is set to 1 if the signature is valid and 0
+/// otherwise. The signature is invalid if `r` or `s` is not between 1 and the order of the curve
+/// minus one, if a coordinate of the public key is not reduced, or if the public key is not on the
+/// curve.
+///
+/// ### Safety
+///
+/// The caller must ensure that `input` is a valid pointer to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_ecdsa_verify(input: *mut [u32; 41]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_ECDSA_VERIFY,
+            in("a0") input,
+            in("a1") 0
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
 /// Decompresses a compressed Secp256k1 point.
 ///
 /// The input array should be 64 bytes long, with the first 32 bytes containing the X coordinate in
@@ -0,0 +1,27 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Writes the SHA-256 digest of a message to `result`, with the bytes of the digest in order.
+///
+/// The parameters are a pointer to the message and its length in bytes, which must be below 2^24.
+///
+/// ### Safety
+///
+/// The caller must ensure that `result`, `params` and the pointer to the message are valid pointers
+/// to data that is aligned along a four byte boundary, and that the message has the given length.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_sha256_hash(result: *mut [u32; 8], params: *const [u32; 2]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SHA256_HASH,
+            in("a0") result,
+            in("a1") params,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint256 comparison operation.
+///
+/// Compares the value in the first 8 words of `x_and_result` with `y`, and writes the result to
+/// the last word: -1, 0 or 1 as `x` is less than, equal to or greater than `y`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x_and_result` and `y` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_cmp(x_and_result: *mut [u32; 9], y: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT256_CMP,
+            in("a0") x_and_result,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
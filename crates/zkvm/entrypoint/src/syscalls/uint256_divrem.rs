@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint256 division with remainder.
+///
+/// Divides x by the divisor held in the first 8 words of `divisor_and_remainder`. The quotient is
+/// written over `x`, and the remainder is written to the last 8 words of `divisor_and_remainder`.
+/// Dividing by zero traps.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `divisor_and_remainder` are valid pointers to data that is
+/// aligned along a four byte boundary, and that `x` does not overlap the remainder.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_divrem(x: *mut [u32; 8], divisor_and_remainder: *mut [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT256_DIVREM,
+            in("a0") x,
+            in("a1") divisor_and_remainder,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
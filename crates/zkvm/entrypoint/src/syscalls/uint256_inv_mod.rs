@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint256 modular inverse operation.
+///
+/// Computes `x^{-1} % modulus`. A modulus of zero is interpreted as 2^256. The result is written
+/// over the first input. If x is not invertible, the result is zero, which is never a valid
+/// inverse for a modulus greater than one.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `modulus` are valid pointers to data that is aligned along
+/// a four byte boundary, and that the modulus is not one.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_invmod(x: *mut [u32; 8], modulus: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT256_INV_MOD,
+            in("a0") x,
+            in("a1") modulus,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
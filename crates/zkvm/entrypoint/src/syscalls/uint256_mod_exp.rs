@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint256 modular exponentiation operation.
+///
+/// Computes `base^exponent % modulus`, where the modulus is stored in the 8 words after `exponent`.
+/// The result is written over the base. A zero modulus is interpreted as 2^256.
+///
+/// ### Safety
+///
+/// The caller must ensure that `base` and `exponent` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_mod_exp(base: *mut [u32; 8], exponent: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT256_MOD_EXP,
+            in("a0") base,
+            in("a1") exponent,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
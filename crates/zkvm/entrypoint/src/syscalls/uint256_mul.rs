@@ -3,7 +3,8 @@ use core::arch::asm;
 
 /// Uint256 multiplication operation.
 ///
-/// The result is written over the first input.
+/// The result is written over the first input. The pointers may be equal, in which case x is
+/// squared in place.
 ///
 /// ### Safety
 ///
@@ -0,0 +1,31 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint256 range check operation.
+///
+/// Checks whether the value in the first 8 words of `value_and_result` is less than `prime`, and
+/// writes the result to the last word: zero if it is less, and one otherwise.
+///
+/// ### Safety
+///
+/// The caller must ensure that `value_and_result` and `prime` are valid pointers to data that is
+/// aligned along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_range_check(
+    value_and_result: *mut [u32; 9],
+    prime: *const [u32; 8],
+) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT256_RANGE_CHECK,
+            in("a0") value_and_result,
+            in("a1") prime,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
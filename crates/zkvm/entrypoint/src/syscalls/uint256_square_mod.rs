@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint256 modular squaring operation.
+///
+/// Computes `(x * x) % modulus`. A modulus of zero is interpreted as 2^256. The result is written
+/// over the first input, which must be reduced modulo the modulus.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `modulus` are valid pointers to data that is aligned along
+/// a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_square(x: *mut [u32; 8], modulus: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT256_SQUARE_MOD,
+            in("a0") x,
+            in("a1") modulus,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
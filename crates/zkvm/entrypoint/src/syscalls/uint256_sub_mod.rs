@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint256 modular subtraction operation.
+///
+/// Computes `(x - y) % modulus`, where `y` points to 16 words holding y followed by the modulus.
+/// A modulus of zero is interpreted as 2^256. The result is written over the first input. When
+/// x < y the result wraps around to `modulus - (y - x)`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary, and that x is less than the modulus.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_submod(x: *mut [u32; 8], y: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT256_SUB_MOD,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,83 @@
+//! Generates the tables of the multiples `2^i * G` of the curve generators that
+//! `AffinePoint::mul_generator` adds up, so that the guest gets them as constants instead of
+//! doubling the generator with syscalls.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+use num_bigint::BigUint;
+
+/// The number of bits of the scalars, which is also the number of entries of each table.
+const SCALAR_BITS: usize = 256;
+
+/// The number of words of a coordinate.
+const WORDS: usize = 8;
+
+/// A short Weierstrass curve `y^2 = x^3 + a * x + b`, with big endian hexadecimal parameters.
+struct Curve {
+    name: &'static str,
+    modulus: &'static str,
+    a: &'static str,
+    generator_x: &'static str,
+    generator_y: &'static str,
+}
+
+const CURVES: [Curve; 2] = [
+    Curve {
+        name: "secp256k1",
+        modulus: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        a: "0",
+        generator_x: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        generator_y: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    },
+    Curve {
+        name: "p256",
+        modulus: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+        generator_x: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        generator_y: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+    },
+];
+
+fn hex(value: &str) -> BigUint {
+    BigUint::parse_bytes(value.as_bytes(), 16).unwrap()
+}
+
+/// Doubles the affine point `(x, y)`, whose y coordinate must be nonzero.
+fn double(x: &BigUint, y: &BigUint, a: &BigUint, p: &BigUint) -> (BigUint, BigUint) {
+    let numerator = (BigUint::from(3u32) * x * x + a) % p;
+    let denominator = (BigUint::from(2u32) * y) % p;
+    let slope = numerator * denominator.modpow(&(p - 2u32), p) % p;
+    let x_doubled = (&slope * &slope + BigUint::from(2u32) * (p - x)) % p;
+    let y_doubled = (slope * (x + p - &x_doubled) + p - y) % p;
+    (x_doubled, y_doubled)
+}
+
+/// Returns the little endian words of `value`, padded to a coordinate.
+fn words(value: &BigUint) -> Vec<u32> {
+    let mut words = value.to_u32_digits();
+    words.resize(WORDS, 0);
+    words
+}
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    for curve in CURVES {
+        let p = hex(curve.modulus);
+        let a = hex(curve.a);
+        let (mut x, mut y) = (hex(curve.generator_x), hex(curve.generator_y));
+
+        // The table is an array expression of `2^i * G` for every bit of a scalar.
+        let mut table = String::from("[\n");
+        for _ in 0..SCALAR_BITS {
+            let limbs = words(&x).into_iter().chain(words(&y)).collect::<Vec<_>>();
+            let limbs = limbs.iter().map(|limb| format!("{limb:#010x}")).collect::<Vec<_>>();
+            writeln!(table, "    [{}],", limbs.join(", ")).unwrap();
+            (x, y) = double(&x, &y, &a, &p);
+        }
+        table.push(']');
+
+        let path = Path::new(&out_dir).join(format!("{}_generator_table.rs", curve.name));
+        fs::write(path, table).unwrap();
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
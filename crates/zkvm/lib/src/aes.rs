@@ -0,0 +1,22 @@
+use crate::syscall_aes128_encrypt;
+
+/// Encrypts a 16-byte block with AES-128 using the `AES128_ENCRYPT` precompile.
+pub fn aes128_encrypt(key: &[u8; 16], block: &[u8; 16]) -> [u8; 16] {
+    let key = bytes_to_words(key);
+    let input = bytes_to_words(block);
+    let mut output = [0u32; 4];
+    unsafe {
+        syscall_aes128_encrypt(&key, &input, &mut output);
+    }
+
+    let mut ciphertext = [0u8; 16];
+    for (chunk, word) in ciphertext.chunks_exact_mut(4).zip(output) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    ciphertext
+}
+
+/// Packs the bytes of a block into little endian words.
+fn bytes_to_words(bytes: &[u8; 16]) -> [u32; 4] {
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
@@ -0,0 +1,394 @@
+//! The BabyJubJub curve of EIP-2494, and the Pedersen hash of circomlib on top of it.
+//!
+//! BabyJubJub is the twisted Edwards curve `168700 * x^2 + y^2 = 1 + 168696 * x^2 * y^2` over the
+//! BN254 scalar field. The precompiles implement the addition law of the curves with `a = -1`, so
+//! [`BabyJubJubPoint`] holds points of the isomorphic curve `-u^2 + y^2 = 1 + d * u^2 * y^2`, where
+//! `u = s * x`, `s^2 = -168700` and `d = -168696 / 168700`. [`BabyJubJubPoint::from_circom`] and
+//! [`BabyJubJubPoint::to_circom`] convert from and to the coordinates used by circomlib.
+
+use crate::{
+    uint256::U256,
+    utils::{AffinePoint, MulAssignError, DEFAULT_MUL_WINDOW},
+};
+
+/// The number of limbs in [BabyJubJubPoint].
+pub const N: usize = 16;
+
+/// The modulus of the base field, the BN254 scalar field.
+const MODULUS: [u32; 8] = [
+    0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// `(p - 1) / 2`, the largest x coordinate that circomlib packs as positive.
+const HALF_MODULUS: [u32; 8] = [
+    0xf8000000, 0xa1f0fac9, 0x3cdcb848, 0x9419f424, 0x40c0ac2e, 0xdc2822db, 0x7098d014, 0x18322739,
+];
+
+/// The smaller square root `s` of `-168700`.
+const SQRT_NEG_A: [u32; 8] = [
+    0xd8b2d768, 0x6c7be2c1, 0x321ea117, 0x74a5f6ca, 0x84e61177, 0x2c7ac0ce, 0x05d6e54b, 0x0e0ff350,
+];
+
+/// The inverse of `s`.
+const SQRT_NEG_A_INV: [u32; 8] = [
+    0x0ff68abf, 0x21f598c0, 0x0da751f7, 0x660441e6, 0xbc475e19, 0x58fa569c, 0x3f684b42, 0x2c2a2999,
+];
+
+/// `d = -168696 / 168700`, the coefficient of the curve with `a = -1`.
+#[cfg(not(target_os = "zkvm"))]
+const D: [u32; 8] = [
+    0xf4d7eb8e, 0xd075ca8c, 0xebb7c867, 0x039b2959, 0x99fd11fc, 0x3df072d7, 0x5f218969, 0x1aee90f1,
+];
+
+/// The number of input bits of a segment of the Pedersen hash, each with its own base point.
+pub const PEDERSEN_SEGMENT_BITS: usize = 200;
+
+/// The maximum number of input bits of the Pedersen hash, one segment per base point.
+pub const PEDERSEN_MAX_BITS: usize = PEDERSEN_SEGMENT_BITS * PEDERSEN_BASES.len();
+
+/// The number of input bits of a window: three bits of magnitude and a sign.
+const PEDERSEN_WINDOW_BITS: usize = 4;
+
+/// The base points `BASE` of `pedersen.circom`, mapped to the curve with `a = -1`.
+const PEDERSEN_BASES: [[u32; N]; 10] = [
+    [
+        0xb61720d7, 0x6a641e62, 0x8af4e7c4, 0x5212f287, 0xa196c0fd, 0x5e6ab916, 0xb25e69a2,
+        0x04d14cd6, 0x172f1a1d, 0x7162e259, 0x4eb4d3d2, 0x9de8c156, 0xd15252e6, 0xf88adfd2,
+        0x97fbbca9, 0x2bd407d8,
+    ],
+    [
+        0x40d33e43, 0x7a42b55f, 0x338f3f9f, 0x3ed2bf38, 0x2b687c76, 0xda09e650, 0xe8344d6f,
+        0x2b6d6791, 0xb02f3766, 0xa9cf56bb, 0x751b1b46, 0x4125d94e, 0x08c64889, 0xa6667d76,
+        0x9c07449e, 0x05e35226,
+    ],
+    [
+        0xf54ea41b, 0xbbbb8c8d, 0x25775f52, 0xed236712, 0x10c839b2, 0x77e6de8b, 0xad033d15,
+        0x03830365, 0x13401278, 0x6c2a82e8, 0x683dd35f, 0x3f89f04a, 0x6d0f584d, 0xeec2a6e3,
+        0x472cd7ee, 0x0d38cda2,
+    ],
+    [
+        0xbc423f93, 0xa0612547, 0xe9c6b99e, 0xdb2ec2a8, 0x35b1e8d4, 0x6d2bb119, 0x4d769da6,
+        0x12abb95c, 0xfee29736, 0x87d23be4, 0x4e6bd294, 0x71b7fe5f, 0x87db196e, 0xcd9f4f4e,
+        0x6e4921ed, 0x06517f39,
+    ],
+    [
+        0x71ba24da, 0xbffb19f8, 0x94e517b7, 0x72d76ac5, 0x7f3d0baa, 0x70e33db8, 0x879069bb,
+        0x2eb0e7fa, 0xdf609a22, 0xaf5d03d1, 0xff02def9, 0x78ec2c64, 0x45a52f48, 0xd4946fc9,
+        0xcb35c231, 0x0290cc48,
+    ],
+    [
+        0xacaae580, 0xd12b6ef9, 0x26a03f6e, 0x73c400e9, 0xb4f2bfcf, 0x9a17518d, 0x797bb30e,
+        0x0e7587bb, 0x0d58eb63, 0x854e60c0, 0x989625db, 0xe5345500, 0x0cb6a00f, 0xc52f6a0e,
+        0xb712a427, 0x1efd7c79,
+    ],
+    [
+        0xfb2ee48d, 0x3c9d6e90, 0xc0795d80, 0x84228b89, 0x77fd7e16, 0x4ab116ab, 0x317fe33e,
+        0x14260133, 0x4c9e0d9c, 0x112890d7, 0x58608984, 0x5b3eb255, 0x9f45574b, 0x1b4e048b,
+        0x6b5baa1b, 0x1cfef6f2,
+    ],
+    [
+        0xd5c832f2, 0x604f1965, 0x58755339, 0xe3c452b8, 0x5d89d0d0, 0xa3242916, 0x427f38f9,
+        0x0a66c393, 0x2daf433f, 0x6a699139, 0x63096e8c, 0x6bd1e7d5, 0xdc46965b, 0xda45dbc5,
+        0x706c1826, 0x1e337532,
+    ],
+    [
+        0xb7f3b0b8, 0x0930f279, 0xc2bee17e, 0x15ddb6a7, 0xac6b9e00, 0x7b122685, 0xb138ee56,
+        0x10e56b76, 0x039cb2aa, 0x6517cbf1, 0xa7fd4046, 0x22faee3d, 0xeaa3d0ba, 0x8da405f2,
+        0xee31cdc6, 0x1a7d3453,
+    ],
+    [
+        0x3c70b3ed, 0xf40e9971, 0x2e8b79f4, 0xb407fb4a, 0xdc0dc3d4, 0x2dd9c387, 0x8b0fa926,
+        0x175a9e90, 0x44289789, 0x2f9af538, 0xef75e486, 0xc5b6fd50, 0x0a030ac5, 0xc3070c01,
+        0xe1208499, 0x0eee3eb6,
+    ],
+];
+
+/// An affine point on BabyJubJub, as the little endian `u` and `y` coordinates of the curve with
+/// `a = -1`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(align(4))]
+pub struct BabyJubJubPoint(pub [u32; N]);
+
+impl AffinePoint<N> for BabyJubJubPoint {
+    /// The point `Base8` of circomlib, which generates the subgroup of prime order.
+    const GENERATOR: [u32; N] = [
+        0xf1aac062, 0x51e8dc38, 0x92c6ce19, 0xf0b773ad, 0xbfe76f10, 0x2e018e11, 0x745002f4,
+        0x1b024eef, 0x872d7d8b, 0x4b3c257a, 0xb9e13377, 0xfce0051f, 0xd16bf9ed, 0x25572e1c,
+        0xf7a0b249, 0x25797203,
+    ];
+
+    /// The neutral element `(0, 1)`, which the complete addition law handles like any other point.
+    const IDENTITY: [u32; N] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(limbs)
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        &self.0
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        &mut self.0
+    }
+
+    /// Inside the zkVM, the sum is computed by the `BABYJUBJUB_ADD` precompile.
+    fn add_assign(&mut self, other: &Self) {
+        #[cfg(target_os = "zkvm")]
+        unsafe {
+            crate::syscall_babyjubjub_add(&mut self.0, &other.0);
+        }
+
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            self.0 = add_host(&self.0, &other.0);
+        }
+    }
+
+    /// Inside the zkVM, the double is computed by the `BABYJUBJUB_DOUBLE` precompile.
+    fn double(&mut self) {
+        #[cfg(target_os = "zkvm")]
+        unsafe {
+            crate::syscall_babyjubjub_double(&mut self.0);
+        }
+
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            self.0 = add_host(&self.0, &self.0);
+        }
+    }
+
+    fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        self.mul_assign_windowed(scalar, DEFAULT_MUL_WINDOW)
+    }
+
+    /// The addition law is complete, and the negation of a point shares its y coordinate rather
+    /// than its x coordinate, so every sum is a plain addition.
+    fn accumulate(acc: &mut Option<Self>, other: &Self) {
+        match acc.as_mut() {
+            Some(acc) => acc.add_assign(other),
+            None => *acc = Some(*other),
+        }
+    }
+}
+
+impl BabyJubJubPoint {
+    pub fn identity() -> Self {
+        Self(Self::IDENTITY)
+    }
+
+    /// Maps a point in the coordinates of circomlib, as little endian words, to the curve with
+    /// `a = -1`. The coordinates do not need to be reduced.
+    pub fn from_circom(x: &[u32; 8], y: &[u32; 8]) -> Self {
+        let modulus = U256(MODULUS);
+        let u = U256(*x).mulmod(&U256(SQRT_NEG_A), &modulus);
+        let y = U256(*y).mulmod(&U256::ONE, &modulus);
+        let mut limbs = [0u32; N];
+        limbs[..8].copy_from_slice(&u.0);
+        limbs[8..].copy_from_slice(&y.0);
+        Self(limbs)
+    }
+
+    /// Returns the coordinates of the point in circomlib, as little endian words.
+    pub fn to_circom(&self) -> ([u32; 8], [u32; 8]) {
+        let u = U256(self.0[..8].try_into().unwrap());
+        let x = u.mulmod(&U256(SQRT_NEG_A_INV), &U256(MODULUS));
+        (x.0, self.0[8..].try_into().unwrap())
+    }
+
+    /// Negates the point, which negates its u coordinate.
+    pub fn negate(&mut self) {
+        if self.0[..8].iter().any(|&word| word != 0) {
+            let mut x = MODULUS;
+            sub_assign(&mut x, &self.0[..8]);
+            self.0[..8].copy_from_slice(&x);
+        }
+    }
+
+    /// Packs the point as circomlib's `packPoint` does: the little endian y coordinate, with the
+    /// top bit set if the x coordinate of circomlib is greater than `(p - 1) / 2`.
+    pub fn pack(&self) -> [u8; 32] {
+        let (x, y) = self.to_circom();
+        let mut bytes = U256(y).to_le_bytes();
+        if x.iter().rev().gt(HALF_MODULUS.iter().rev()) {
+            bytes[31] |= 0x80;
+        }
+        bytes
+    }
+}
+
+/// Computes the Pedersen hash of the bits as `pedersen.circom` does, returning the packed point.
+///
+/// The bits are split into segments of [`PEDERSEN_SEGMENT_BITS`] bits, and each segment into
+/// windows of four bits `b0, b1, b2, b3`, where missing bits at the end are zero. The window `j` of
+/// a segment contributes `(1 + b0 + 2 * b1 + 4 * b2) * 32^j` to the scalar of the segment,
+/// negated if `b3` is set, and the hash is the sum of the base point of each segment multiplied by
+/// its scalar. The scalars are below `2^250` and never zero.
+pub fn pedersen_hash(bits: &[bool]) -> [u8; 32] {
+    assert!(!bits.is_empty(), "the input is empty");
+    assert!(bits.len() <= PEDERSEN_MAX_BITS, "the input is too long");
+
+    let bit = |i: usize| i < bits.len() && bits[i];
+    let mut acc = BabyJubJubPoint::identity();
+    for (start, base) in (0..bits.len()).step_by(PEDERSEN_SEGMENT_BITS).zip(PEDERSEN_BASES.iter()) {
+        let end = bits.len().min(start + PEDERSEN_SEGMENT_BITS);
+
+        // Each window takes up five bits of the scalar, so the positive and the negative windows
+        // are accumulated apart without carries, and subtracted at the end.
+        let mut positive = [0u32; 8];
+        let mut negative = [0u32; 8];
+        for (j, i) in (start..end).step_by(PEDERSEN_WINDOW_BITS).enumerate() {
+            let value = 1 + bit(i) as u32 + 2 * bit(i + 1) as u32 + 4 * bit(i + 2) as u32;
+            let scalar = if bit(i + 3) { &mut negative } else { &mut positive };
+            let shift = 5 * j;
+            scalar[shift / 32] |= value << (shift % 32);
+            if shift % 32 > 28 {
+                scalar[shift / 32 + 1] |= value >> (32 - shift % 32);
+            }
+        }
+
+        // A negative scalar multiplies the base point by its magnitude, and negates the result.
+        let is_negative = negative.iter().rev().gt(positive.iter().rev());
+        let (mut scalar, other) =
+            if is_negative { (negative, positive) } else { (positive, negative) };
+        sub_assign(&mut scalar, &other);
+        let mut point = BabyJubJubPoint(*base);
+        point.mul_assign(&scalar).expect("the scalar of a segment is never zero");
+        if is_negative {
+            point.negate();
+        }
+        acc.add_assign(&point);
+    }
+    acc.pack()
+}
+
+/// Subtracts `b` from `a` in place, for `a >= b`.
+fn sub_assign(a: &mut [u32], b: &[u32]) {
+    let mut borrow = false;
+    for (a, &b) in a.iter_mut().zip(b) {
+        let (diff, borrow_1) = a.overflowing_sub(b);
+        let (diff, borrow_2) = diff.overflowing_sub(borrow as u32);
+        *a = diff;
+        borrow = borrow_1 || borrow_2;
+    }
+}
+
+/// Adds two points with the complete addition law of the curve with `a = -1`, outside the zkVM.
+#[cfg(not(target_os = "zkvm"))]
+fn add_host(p: &[u32; N], q: &[u32; N]) -> [u32; N] {
+    use num_bigint::BigUint;
+
+    let modulus = BigUint::from_slice(&MODULUS);
+    let invert = |x: BigUint| x.modpow(&(&modulus - 2u32), &modulus);
+    let (x1, y1) = (BigUint::from_slice(&p[..8]), BigUint::from_slice(&p[8..]));
+    let (x2, y2) = (BigUint::from_slice(&q[..8]), BigUint::from_slice(&q[8..]));
+
+    let f = BigUint::from_slice(&D) * &x1 * &x2 * &y1 * &y2 % &modulus;
+    let x3 = (&x1 * &y2 + &y1 * &x2) * invert(&f + 1u32) % &modulus;
+    let y3 = (&y1 * &y2 + &x1 * &x2) * invert(&modulus + 1u32 - &f) % &modulus;
+
+    let mut limbs = [0u32; N];
+    for (limbs, coordinate) in limbs.chunks_exact_mut(8).zip([x3, y3]) {
+        let words = coordinate.to_u32_digits();
+        limbs[..words.len()].copy_from_slice(&words);
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::*;
+
+    fn words(decimal: &str) -> [u32; 8] {
+        let mut words = BigUint::parse_bytes(decimal.as_bytes(), 10).unwrap().to_u32_digits();
+        words.resize(8, 0);
+        words.try_into().unwrap()
+    }
+
+    /// The bits of the bytes, least significant bit first, as circomlib's tests lay them out.
+    fn bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    fn hex(bytes: &[u8; 32]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_from_circom() {
+        // Base8 and the first base point of `pedersen.circom`.
+        let base8 = BabyJubJubPoint::from_circom(
+            &words("5299619240641551281634865583518297030282874472190772894086521144482721001553"),
+            &words("16950150798460657717958625567821834550301663161624707787222815936182638968203"),
+        );
+        assert_eq!(base8.0, BabyJubJubPoint::GENERATOR);
+        let x =
+            words("10457101036533406547632367118273992217979173478358440826365724437999023779287");
+        let y =
+            words("19824078218392094440610104313265183977899662750282163392862422243483260492317");
+        let base = BabyJubJubPoint::from_circom(&x, &y);
+        assert_eq!(base.0, PEDERSEN_BASES[0]);
+        assert_eq!(base.to_circom(), (x, y));
+    }
+
+    #[test]
+    fn test_add_double() {
+        let generator = BabyJubJubPoint(BabyJubJubPoint::GENERATOR);
+        let mut sum = generator;
+        sum.add_assign(&generator);
+        let mut double = generator;
+        double.double();
+        assert_eq!(sum, double);
+
+        let mut negation = generator;
+        negation.negate();
+        negation.add_assign(&generator);
+        assert_eq!(negation, BabyJubJubPoint::identity());
+    }
+
+    #[test]
+    fn test_pedersen_hash_one_bit() {
+        assert_eq!(
+            hex(&pedersen_hash(&[true])),
+            "9131e63e80adff35eed1bc6b0287856234b8b9e97ac7ffb7c04f7c670adde1af"
+        );
+        assert_eq!(
+            hex(&pedersen_hash(&[false])),
+            "1d1a2f1759e26271d2d3b44e56c1e89de65252d1d2df8af8a9bcfb97d807d42b"
+        );
+    }
+
+    #[test]
+    fn test_pedersen_hash_one_segment() {
+        let bits = bits_le(b"The quick brown fox jumps");
+        assert_eq!(bits.len(), PEDERSEN_SEGMENT_BITS);
+        assert_eq!(
+            hex(&pedersen_hash(&bits)),
+            "f5e25b5d8c7a9caca99f977143ce545be481354a4077cda508d96444a315e404"
+        );
+    }
+
+    #[test]
+    fn test_pedersen_hash_multiple_segments() {
+        // Three segments, the last of which is not full.
+        let bits = bits_le(&(0..62).collect::<Vec<u8>>());
+        assert_eq!(
+            hex(&pedersen_hash(&bits)),
+            "a4e59877416ed78ad99691660b749c47c9e9d062b291d1475b45833fc3c62c11"
+        );
+
+        let bits = (0..1000).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        assert_eq!(
+            hex(&pedersen_hash(&bits)),
+            "e1ab4856c9993734133b0473bf6d4a4f2bf579d977460bdc12060cc2668a8f8e"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the input is too long")]
+    fn test_pedersen_hash_too_long() {
+        pedersen_hash(&[false; PEDERSEN_MAX_BITS + 1]);
+    }
+}
@@ -0,0 +1,19 @@
+//! Arithmetic on 256-bit unsigned integers that spans many precompile rows.
+
+use crate::syscall_uint256_mod_exp;
+
+/// Computes `base^exponent % modulus` for little endian 256-bit operands.
+///
+/// The whole exponentiation is a single call to the `UINT256_MOD_EXP` precompile, which is cheaper
+/// than the square-and-multiply loop of [`crate::modexp::modexp_u256`] for large exponents. A zero
+/// modulus is interpreted as 2^256, and the base does not need to be reduced.
+pub fn mod_exp(base: &[u32; 8], exponent: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    let mut result = *base;
+    let mut exponent_and_modulus = [0u32; 16];
+    exponent_and_modulus[..8].copy_from_slice(exponent);
+    exponent_and_modulus[8..].copy_from_slice(modulus);
+    unsafe {
+        syscall_uint256_mod_exp(&mut result, exponent_and_modulus.as_ptr() as *const [u32; 8]);
+    }
+    result
+}
@@ -0,0 +1,24 @@
+use crate::{syscall_clz32, syscall_popcount};
+
+/// Returns the number of set bits of `x` using the `POPCOUNT` precompile.
+pub fn popcount_u32(x: u32) -> u32 {
+    popcount_u64(x as u64)
+}
+
+/// Returns the number of set bits of `x` using the `POPCOUNT` precompile.
+pub fn popcount_u64(x: u64) -> u32 {
+    let mut value = [x as u32, (x >> 32) as u32];
+    unsafe {
+        syscall_popcount(&mut value);
+    }
+    value[0]
+}
+
+/// Returns the number of leading zeros of `x` using the `CLZ32` precompile.
+pub fn leading_zeros_u32(x: u32) -> u32 {
+    let mut value = x;
+    unsafe {
+        syscall_clz32(&mut value);
+    }
+    value
+}
@@ -1,7 +1,12 @@
+pub mod pairing;
+
 use std::io::ErrorKind;
 
 use crate::{
-    syscall_bls12381_add, syscall_bls12381_decompress, syscall_bls12381_double, utils::AffinePoint,
+    syscall_bls12381_add, syscall_bls12381_decompress, syscall_bls12381_double,
+    syscall_bls12381_fp_addmod, syscall_bls12381_fp_mulmod, syscall_bls12381_g2_add,
+    syscall_bls12381_g2_double,
+    utils::{AffinePoint, MulAssignError, WeierstrassAffinePoint, DEFAULT_MUL_WINDOW},
 };
 
 /// The number of limbs in [Bls12381AffinePoint].
@@ -49,6 +54,97 @@ impl AffinePoint<N> for Bls12381AffinePoint {
             syscall_bls12381_double(a);
         }
     }
+
+    fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        self.mul_assign_windowed(scalar, DEFAULT_MUL_WINDOW)
+    }
+}
+
+impl WeierstrassAffinePoint<N> for Bls12381AffinePoint {
+    const MODULUS: &'static [u32] = &[
+        0xffffaaab, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf,
+        0x64774b84, 0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+    ];
+
+    const A: &'static [u32] = &[0; 12];
+
+    const B: &'static [u32] = &[4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+}
+
+/// The number of limbs in an affine [Bls12381G2Point].
+pub const G2_N: usize = 48;
+
+/// A point on the BLS12-381 G2 twist, which is the curve `y^2 = x^3 + 4(1 + u)` over
+/// `Fp2 = Fp[u]/(u^2 + 1)`.
+///
+/// Affine points are stored as little endian words `[x.c0, x.c1, y.c0, y.c1]`, and each element
+/// must be reduced. The point at infinity has no affine coordinates, so it is a separate variant
+/// rather than a sentinel encoding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Bls12381G2Point {
+    /// The point at infinity, which is the identity.
+    Infinity,
+    /// An affine point.
+    Affine([u32; G2_N]),
+}
+
+impl Bls12381G2Point {
+    /// The generator from the IETF pairing-friendly curves draft.
+    pub const GENERATOR: [u32; G2_N] = [
+        3240213944, 3565180616, 2818948079, 195822374, 2061750647, 3025210212, 4198513410,
+        3336862420, 767889489, 638059815, 4035906193, 38445746, 1560554366, 3853286661, 328490327,
+        860680466, 3699331145, 3050987963, 2569057818, 1500238032, 2284277605, 2108478368,
+        1383178080, 333458272, 146286593, 3784529030, 1001169545, 2453326284, 1365299500,
+        1833081449, 2361250727, 2919078826, 3660461338, 2362035654, 1920822801, 216388903,
+        4032788926, 2863204191, 1558977953, 1060572455, 1462671787, 645173931, 2242339759,
+        3409848446, 734170009, 850186928, 782709964, 101106848,
+    ];
+
+    /// Returns the generator.
+    pub const fn generator() -> Self {
+        Self::Affine(Self::GENERATOR)
+    }
+
+    /// Returns whether this is the point at infinity.
+    pub const fn is_identity(&self) -> bool {
+        matches!(self, Self::Infinity)
+    }
+
+    /// Adds the given point to `self`.
+    ///
+    /// The syscall requires distinct x coordinates, so the point at infinity, a point added to
+    /// itself and a point added to its negation are handled here.
+    pub fn add_assign(&mut self, other: &Self) {
+        match (&mut *self, other) {
+            (_, Self::Infinity) => {}
+            (Self::Infinity, _) => *self = *other,
+            (Self::Affine(p), Self::Affine(q)) => {
+                if p[..G2_N / 2] != q[..G2_N / 2] {
+                    unsafe {
+                        syscall_bls12381_g2_add(p, q);
+                    }
+                } else if p[G2_N / 2..] == q[G2_N / 2..] {
+                    self.double();
+                } else {
+                    *self = Self::Infinity;
+                }
+            }
+        }
+    }
+
+    /// Doubles `self`.
+    pub fn double(&mut self) {
+        if let Self::Affine(p) = self {
+            // A point with `y = 0` has order two.
+            if p[G2_N / 2..].iter().all(|&limb| limb == 0) {
+                *self = Self::Infinity;
+            } else {
+                unsafe {
+                    syscall_bls12381_g2_double(p);
+                }
+            }
+        }
+    }
 }
 
 /// Decompresses a compressed public key using bls12381_decompress precompile.
@@ -64,3 +160,111 @@ pub fn decompress_pubkey(compressed_key: &[u8; 48]) -> Result<[u8; 96], ErrorKin
 
     Ok(decompressed_key)
 }
+
+/// The base field modulus of BLS12-381 as big endian bytes.
+const MODULUS_BE: [u8; 48] = [
+    0x1a, 0x01, 0x11, 0xea, 0x39, 0x7f, 0xe6, 0x9a, 0x4b, 0x1b, 0xa7, 0xb6, 0x43, 0x4b, 0xac, 0xd7,
+    0x64, 0x77, 0x4b, 0x84, 0xf3, 0x85, 0x12, 0xbf, 0x67, 0x30, 0xd2, 0xa0, 0xf6, 0xb0, 0xf6, 0x24,
+    0x1e, 0xab, 0xff, 0xfe, 0xb1, 0x53, 0xff, 0xff, 0xb9, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xaa, 0xab,
+];
+
+/// Adds two G1 points with the semantics of the `BLS12_G1ADD` precompile (EIP-2537).
+///
+/// The input must be exactly 256 bytes: two points `(x1, y1, x2, y2)`, each coordinate a 48-byte
+/// big endian integer left padded with 16 zero bytes. The all-zero encoding is the point at
+/// infinity. The output uses the same encoding for the sum. As specified, no subgroup check is
+/// performed.
+///
+/// Returns `None` if the input has the wrong length, a coordinate is not reduced or has nonzero
+/// padding, or a point is not on the curve.
+pub fn g1_add(input: &[u8]) -> Option<[u8; 128]> {
+    if input.len() != 256 {
+        return None;
+    }
+    let p = decode_g1(input[..128].try_into().unwrap())?;
+    let q = decode_g1(input[128..].try_into().unwrap())?;
+
+    // The syscalls don't handle the point at infinity or a point added to itself or its negation,
+    // so those cases are handled here.
+    let sum = match (p, q) {
+        (None, q) => q,
+        (p, None) => p,
+        (Some(mut p), Some(q)) => {
+            let (p_x, p_y) = p.0.split_at(12);
+            let (q_x, q_y) = q.0.split_at(12);
+            if p_x != q_x {
+                p.add_assign(&q);
+                Some(p)
+            } else if p_y == q_y {
+                p.double();
+                Some(p)
+            } else {
+                None
+            }
+        }
+    };
+    Some(encode_g1(sum.as_ref()))
+}
+
+/// Decodes a padded big endian `(x, y)` pair, where the all-zero encoding is the point at infinity.
+pub(crate) fn decode_g1(bytes: &[u8; 128]) -> Option<Option<Bls12381AffinePoint>> {
+    if bytes.iter().all(|&b| b == 0) {
+        return Some(None);
+    }
+    let (x, y) = bytes.split_at(64);
+    let (x_padding, x) = x.split_at(16);
+    let (y_padding, y) = y.split_at(16);
+    if x_padding.iter().chain(y_padding).any(|&b| b != 0) {
+        return None;
+    }
+    if x >= &MODULUS_BE[..] || y >= &MODULUS_BE[..] {
+        return None;
+    }
+
+    let mut limbs = [0u32; N];
+    limbs[..12].copy_from_slice(&be_bytes_to_words_le(x));
+    limbs[12..].copy_from_slice(&be_bytes_to_words_le(y));
+    let point = Bls12381AffinePoint(limbs);
+    is_on_curve(&point).then_some(Some(point))
+}
+
+/// Encodes a point as a padded big endian `(x, y)` pair, where the all-zero encoding is the point
+/// at infinity.
+fn encode_g1(point: Option<&Bls12381AffinePoint>) -> [u8; 128] {
+    let mut bytes = [0u8; 128];
+    if let Some(point) = point {
+        let (x, y) = point.0.split_at(12);
+        for (coordinate, words) in bytes.chunks_exact_mut(64).zip([x, y]) {
+            for (chunk, word) in coordinate[16..].chunks_exact_mut(4).zip(words.iter().rev()) {
+                chunk.copy_from_slice(&word.to_be_bytes());
+            }
+        }
+    }
+    bytes
+}
+
+/// Checks that `y^2 = x^3 + 4` using the base field precompiles.
+fn is_on_curve(point: &Bls12381AffinePoint) -> bool {
+    let x: [u32; 12] = point.0[..12].try_into().unwrap();
+    let y: [u32; 12] = point.0[12..].try_into().unwrap();
+    let mut four = [0u32; 12];
+    four[0] = 4;
+
+    let mut y_squared = y;
+    let mut rhs = x;
+    unsafe {
+        syscall_bls12381_fp_mulmod(y_squared.as_mut_ptr(), y.as_ptr());
+        syscall_bls12381_fp_mulmod(rhs.as_mut_ptr(), x.as_ptr());
+        syscall_bls12381_fp_mulmod(rhs.as_mut_ptr(), x.as_ptr());
+        syscall_bls12381_fp_addmod(rhs.as_mut_ptr(), four.as_ptr());
+    }
+    y_squared == rhs
+}
+
+/// Converts a 48-byte big endian integer to little endian words.
+fn be_bytes_to_words_le(bytes: &[u8]) -> [u32; 12] {
+    core::array::from_fn(|i| {
+        let start = 48 - 4 * (i + 1);
+        u32::from_be_bytes(bytes[start..start + 4].try_into().unwrap())
+    })
+}
@@ -0,0 +1,381 @@
+//! A BLS12-381 pairing check built from the base field and `Fp2` precompiles.
+//!
+//! The extension tower is `Fp2 = Fp[u] / (u^2 + 1)`, `Fp6 = Fp2[v] / (v^3 - (1 + u))` and
+//! `Fp12 = Fp6[w] / (w^2 - v)`, and G2 is the subgroup of order `r` of the sextic twist
+//! `y^2 = x^3 + 4 * (1 + u)`. Only `Fp2` arithmetic is done by precompiles, so every product in
+//! `Fp6` and `Fp12` is a short sequence of `Fp2` syscalls.
+
+use super::{be_bytes_to_words_le, Bls12381G2Point, G2_N, MODULUS_BE};
+use crate::{
+    syscall_bls12381_fp2_addmod, syscall_bls12381_fp2_mulmod, syscall_bls12381_fp2_submod,
+    syscall_bls12381_fp_addmod, syscall_bls12381_fp_mulmod, syscall_bls12381_fp_submod,
+};
+
+/// An element of the base field as little endian words.
+pub type Fp = [u32; 12];
+
+/// The exponent `p - 2`, which inverts a nonzero element of the base field.
+const P_MINUS_TWO: [u32; 12] = [
+    0xffffaaa9, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84,
+    0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+];
+
+/// The scalar `r - 1`, where `r` is the order of G1 and G2.
+const R_MINUS_ONE: [u32; 8] = [
+    0x00000000, 0xffffffff, 0xfffe5bfe, 0x53bda402, 0x09a1d805, 0x3339d808, 0x299d7d48, 0x73eda753,
+];
+
+/// The absolute value of the BLS parameter `x = -0xd201000000010000`, which is the loop count of
+/// the ate pairing.
+const ATE_LOOP_COUNT: u64 = 0xd201000000010000;
+
+/// The exponent `(p^6 + 1) / r` of the final exponentiation.
+const FINAL_EXPONENT: [u32; 64] = [
+    0xc0705d6a, 0x8739e1cd, 0xe0381a16, 0x09a5256d, 0x61c791e2, 0x9cf0f70a, 0x7903f76e, 0x3a09c449,
+    0x3890f133, 0x2d727156, 0x6fec7760, 0x224741b3, 0x2a12bd40, 0x338259c2, 0x778e0de7, 0x38ee1cd4,
+    0x188a20b0, 0xc3b5ef4b, 0xe2764d7b, 0x1d615d49, 0xd076117d, 0x816101dd, 0x7ebe3afc, 0xf007c01e,
+    0x935021c3, 0x27d7bd90, 0x57c0b15f, 0xc3b5e2f5, 0xc4f82384, 0x5e886c94, 0x11e63f56, 0xee6a95db,
+    0x4a9c4f6f, 0x2b822f51, 0xd21b73da, 0x12d6a874, 0xf499dffb, 0x1304275e, 0xbcb95d1f, 0x967878fe,
+    0x8b2f2922, 0x4744497f, 0xf0841855, 0x85a2e707, 0x6c802eec, 0x9f0c5012, 0xbd2fa489, 0xfb46e197,
+    0x9bc5f61a, 0x548ce080, 0x73beaa8c, 0xcf56fb15, 0x763bdf7c, 0xad7375a3, 0x179bdecc, 0xe0ec9031,
+    0x3c48c1da, 0x6579aea8, 0x64cf5bb3, 0xdbf85ae6, 0x55ca7566, 0x7b6f235c, 0x14877503, 0x000028b3,
+];
+
+/// Iterates over the bits of a little endian exponent, most significant first.
+fn bits_be(words: &[u32]) -> impl Iterator<Item = bool> + '_ {
+    words.iter().rev().flat_map(|word| (0..32).rev().map(move |i| (word >> i) & 1 == 1))
+}
+
+fn fp_add(a: &Fp, b: &Fp) -> Fp {
+    let mut result = *a;
+    unsafe {
+        syscall_bls12381_fp_addmod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+fn fp_sub(a: &Fp, b: &Fp) -> Fp {
+    let mut result = *a;
+    unsafe {
+        syscall_bls12381_fp_submod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+fn fp_mul(a: &Fp, b: &Fp) -> Fp {
+    let mut result = *a;
+    unsafe {
+        syscall_bls12381_fp_mulmod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+/// Inverts a nonzero element of the base field as `a^(p - 2)`.
+fn fp_inv(a: &Fp) -> Fp {
+    let mut result = [0; 12];
+    result[0] = 1;
+    for bit in bits_be(&P_MINUS_TWO) {
+        result = fp_mul(&result, &result);
+        if bit {
+            result = fp_mul(&result, a);
+        }
+    }
+    result
+}
+
+/// An element `c0 + c1 * u` of `Fp2`, with the words of `c0` followed by those of `c1`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fp2(pub [u32; 24]);
+
+impl Fp2 {
+    pub const ZERO: Self = Self([0; 24]);
+
+    pub const ONE: Self = {
+        let mut limbs = [0; 24];
+        limbs[0] = 1;
+        Self(limbs)
+    };
+
+    /// The non-residue `1 + u` that defines `Fp6`.
+    const XI: Self = {
+        let mut limbs = [0; 24];
+        limbs[0] = 1;
+        limbs[12] = 1;
+        Self(limbs)
+    };
+
+    pub fn new(c0: &Fp, c1: &Fp) -> Self {
+        let mut limbs = [0; 24];
+        limbs[..12].copy_from_slice(c0);
+        limbs[12..].copy_from_slice(c1);
+        Self(limbs)
+    }
+
+    pub fn c0(&self) -> Fp {
+        self.0[..12].try_into().unwrap()
+    }
+
+    pub fn c1(&self) -> Fp {
+        self.0[12..].try_into().unwrap()
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut result = *self;
+        unsafe {
+            syscall_bls12381_fp2_addmod(result.0.as_mut_ptr(), other.0.as_ptr());
+        }
+        result
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        let mut result = *self;
+        unsafe {
+            syscall_bls12381_fp2_submod(result.0.as_mut_ptr(), other.0.as_ptr());
+        }
+        result
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut result = *self;
+        unsafe {
+            syscall_bls12381_fp2_mulmod(result.0.as_mut_ptr(), other.0.as_ptr());
+        }
+        result
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    /// Inverts a nonzero element as `(c0 - c1 * u) / (c0^2 + c1^2)`.
+    pub fn inv(&self) -> Self {
+        let (c0, c1) = (self.c0(), self.c1());
+        let norm_inv = fp_inv(&fp_add(&fp_mul(&c0, &c0), &fp_mul(&c1, &c1)));
+        Self::new(&fp_mul(&c0, &norm_inv), &fp_sub(&[0; 12], &fp_mul(&c1, &norm_inv)))
+    }
+}
+
+/// An element `c0 + c1 * v + c2 * v^2` of `Fp6`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fp6 {
+    pub c0: Fp2,
+    pub c1: Fp2,
+    pub c2: Fp2,
+}
+
+impl Fp6 {
+    pub const ZERO: Self = Self { c0: Fp2::ZERO, c1: Fp2::ZERO, c2: Fp2::ZERO };
+
+    pub const ONE: Self = Self { c0: Fp2::ONE, c1: Fp2::ZERO, c2: Fp2::ZERO };
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self { c0: self.c0.add(&other.c0), c1: self.c1.add(&other.c1), c2: self.c2.add(&other.c2) }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        Self { c0: self.c0.sub(&other.c0), c1: self.c1.sub(&other.c1), c2: self.c2.sub(&other.c2) }
+    }
+
+    /// Multiplies with Karatsuba, using six `Fp2` multiplications.
+    pub fn mul(&self, other: &Self) -> Self {
+        let t0 = self.c0.mul(&other.c0);
+        let t1 = self.c1.mul(&other.c1);
+        let t2 = self.c2.mul(&other.c2);
+
+        let c0 = self.c1.add(&self.c2).mul(&other.c1.add(&other.c2)).sub(&t1.add(&t2));
+        let c1 = self.c0.add(&self.c1).mul(&other.c0.add(&other.c1)).sub(&t0.add(&t1));
+        let c2 = self.c0.add(&self.c2).mul(&other.c0.add(&other.c2)).sub(&t0.add(&t2));
+        Self { c0: t0.add(&c0.mul(&Fp2::XI)), c1: c1.add(&t2.mul(&Fp2::XI)), c2: c2.add(&t1) }
+    }
+
+    /// Multiplies by `v`, using `v^3 = 1 + u`.
+    pub fn mul_by_v(&self) -> Self {
+        Self { c0: self.c2.mul(&Fp2::XI), c1: self.c0, c2: self.c1 }
+    }
+}
+
+/// An element `c0 + c1 * w` of `Fp12`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fp12 {
+    pub c0: Fp6,
+    pub c1: Fp6,
+}
+
+impl Fp12 {
+    pub const ONE: Self = Self { c0: Fp6::ONE, c1: Fp6::ZERO };
+
+    /// Multiplies with Karatsuba, using three `Fp6` multiplications.
+    pub fn mul(&self, other: &Self) -> Self {
+        let t0 = self.c0.mul(&other.c0);
+        let t1 = self.c1.mul(&other.c1);
+        let c1 = self.c0.add(&self.c1).mul(&other.c0.add(&other.c1)).sub(&t0.add(&t1));
+        Self { c0: t0.add(&t1.mul_by_v()), c1 }
+    }
+
+    /// Raises to the power of a little endian exponent.
+    pub fn pow(&self, exp: &[u32]) -> Self {
+        let mut result = Self::ONE;
+        for bit in bits_be(exp) {
+            result = result.mul(&result);
+            if bit {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+}
+
+/// An affine point on the twist, which is the G2 point `(x / w^2, y / w^3)` on the curve.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct G2Affine {
+    pub x: Fp2,
+    pub y: Fp2,
+}
+
+impl G2Affine {
+    /// The coefficient `4 * (1 + u)` of the twist.
+    const B: Fp2 = {
+        let mut limbs = [0; 24];
+        limbs[0] = 4;
+        limbs[12] = 4;
+        Fp2(limbs)
+    };
+
+    /// Returns the generator of G2.
+    pub fn generator() -> Self {
+        Self::from_limbs(&Bls12381G2Point::GENERATOR)
+    }
+
+    /// Converts the limbs `[x.c0, x.c1, y.c0, y.c1]` of an affine [`Bls12381G2Point`].
+    pub fn from_limbs(limbs: &[u32; G2_N]) -> Self {
+        Self { x: Fp2(limbs[..24].try_into().unwrap()), y: Fp2(limbs[24..].try_into().unwrap()) }
+    }
+
+    /// Returns the limbs `[x.c0, x.c1, y.c0, y.c1]` of the affine [`Bls12381G2Point`].
+    pub fn to_limbs(&self) -> [u32; G2_N] {
+        let mut limbs = [0; G2_N];
+        limbs[..24].copy_from_slice(&self.x.0);
+        limbs[24..].copy_from_slice(&self.y.0);
+        limbs
+    }
+
+    pub fn neg(&self) -> Self {
+        Self { x: self.x, y: self.y.neg() }
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        let rhs = self.x.mul(&self.x).mul(&self.x).add(&Self::B);
+        self.y.mul(&self.y) == rhs
+    }
+
+    /// Checks that the point has order `r` by checking that `(r - 1) * Q = -Q`.
+    ///
+    /// The multiplication uses the G2 addition and doubling precompiles of [`Bls12381G2Point`],
+    /// which take no inversion in the guest.
+    pub fn is_in_subgroup(&self) -> bool {
+        let point = Bls12381G2Point::Affine(self.to_limbs());
+        let mut acc = Bls12381G2Point::Infinity;
+        for bit in bits_be(&R_MINUS_ONE) {
+            acc.double();
+            if bit {
+                acc.add_assign(&point);
+            }
+        }
+        acc == Bls12381G2Point::Affine(self.neg().to_limbs())
+    }
+
+    /// The slope of the tangent at the point, which must not have `y = 0`.
+    fn tangent_slope(&self) -> Fp2 {
+        let x_squared = self.x.mul(&self.x);
+        let numerator = x_squared.add(&x_squared).add(&x_squared);
+        numerator.mul(&self.y.add(&self.y).inv())
+    }
+
+    /// The slope of the line through the point and another point with a different `x`.
+    fn chord_slope(&self, other: &Self) -> Fp2 {
+        other.y.sub(&self.y).mul(&other.x.sub(&self.x).inv())
+    }
+
+    /// Adds the point to `other`, given the slope of the line through them.
+    fn add_with_slope(&self, other: &Self, slope: &Fp2) -> Self {
+        let x = slope.mul(slope).sub(&self.x).sub(&other.x);
+        let y = slope.mul(&self.x.sub(&x)).sub(&self.y);
+        Self { x, y }
+    }
+}
+
+/// Evaluates the line with the given slope through `t` at the G1 point `(p_x, p_y)`, scaled by
+/// `w^3`.
+///
+/// The slope of the untwisted line is `slope / w`, so the scaled line is
+/// `slope * t.x - t.y - slope * p_x * w^2 + p_y * w^3`, where `w^2 = v` and `w^3 = v * w`. The
+/// factor `w^3` lies in a proper subfield of `Fp12` and vanishes in the final exponentiation, and
+/// so do the omitted vertical lines.
+fn line(t: &G2Affine, slope: &Fp2, p_x: &Fp, p_y: &Fp) -> Fp12 {
+    Fp12 {
+        c0: Fp6 {
+            c0: slope.mul(&t.x).sub(&t.y),
+            c1: slope.mul(&Fp2::new(p_x, &[0; 12])).neg(),
+            c2: Fp2::ZERO,
+        },
+        c1: Fp6 { c0: Fp2::ZERO, c1: Fp2::new(p_y, &[0; 12]), c2: Fp2::ZERO },
+    }
+}
+
+/// Computes the product of the Miller loops of the ate pairing for each pair.
+///
+/// All the pairs share the squarings of the accumulator, so a product of `n` pairings costs far
+/// less than `n` pairings. The loop runs over `|x|` and skips the conjugation that the negative
+/// `x` calls for, which does not change whether the final product is one. The multiples of each
+/// G2 point stay below `r`, so they never reach the point at infinity and the doubling and
+/// addition steps always have a well defined slope.
+fn miller_loop(pairs: &[(Fp, Fp, G2Affine)]) -> Fp12 {
+    let mut f = Fp12::ONE;
+    let mut acc = pairs.iter().map(|(_, _, q)| *q).collect::<Vec<_>>();
+    for i in (0..ATE_LOOP_COUNT.ilog2()).rev() {
+        f = f.mul(&f);
+        for ((p_x, p_y, _), t) in pairs.iter().zip(acc.iter_mut()) {
+            let slope = t.tangent_slope();
+            f = f.mul(&line(t, &slope, p_x, p_y));
+            *t = t.add_with_slope(t, &slope);
+        }
+        if (ATE_LOOP_COUNT >> i) & 1 == 1 {
+            for ((p_x, p_y, q), t) in pairs.iter().zip(acc.iter_mut()) {
+                let slope = t.chord_slope(q);
+                f = f.mul(&line(t, &slope, p_x, p_y));
+                *t = t.add_with_slope(q, &slope);
+            }
+        }
+    }
+    f
+}
+
+/// Returns whether the product of the pairings `e(P, Q)` over the `(P.x, P.y, Q)` pairs is one.
+///
+/// The G1 points must be on the curve and the G2 points in G2, and neither may be the point at
+/// infinity. The final exponentiation `f^((p^12 - 1) / r)` equals `g^(p^6 - 1)` for
+/// `g = f^((p^6 + 1) / r)`, and `g^(p^6)` is the conjugate `g.c0 - g.c1 * w`, so the product is
+/// one exactly when `g.c1` is zero. This avoids an inversion in `Fp12`.
+pub fn pairing_check(pairs: &[(Fp, Fp, G2Affine)]) -> bool {
+    miller_loop(pairs).pow(&FINAL_EXPONENT).c1 == Fp6::ZERO
+}
+
+/// Decodes the `(x.c0, x.c1, y.c0, y.c1)` encoding of EIP-2537, where each coordinate is a 48-byte
+/// big endian integer left padded with 16 zero bytes and all zeros is the point at infinity.
+pub(crate) fn decode_g2(bytes: &[u8; 256]) -> Option<Option<G2Affine>> {
+    if bytes.iter().all(|&b| b == 0) {
+        return Some(None);
+    }
+    let mut coords = [[0u32; 12]; 4];
+    for (coord, chunk) in coords.iter_mut().zip(bytes.chunks_exact(64)) {
+        let (padding, value) = chunk.split_at(16);
+        if padding.iter().any(|&b| b != 0) || value >= &MODULUS_BE[..] {
+            return None;
+        }
+        *coord = be_bytes_to_words_le(value);
+    }
+
+    let [x_c0, x_c1, y_c0, y_c1] = coords;
+    let point = G2Affine { x: Fp2::new(&x_c0, &x_c1), y: Fp2::new(&y_c0, &y_c1) };
+    (point.is_on_curve() && point.is_in_subgroup()).then_some(Some(point))
+}
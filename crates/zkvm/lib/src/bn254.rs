@@ -1,4 +1,11 @@
-use crate::{syscall_bn254_add, syscall_bn254_double, utils::AffinePoint};
+pub mod fr;
+pub mod pairing;
+
+use crate::{
+    syscall_bn254_add, syscall_bn254_double, syscall_bn254_fp_addmod, syscall_bn254_fp_mulmod,
+    uint256::U256,
+    utils::{AffinePoint, MulAssignError, WeierstrassAffinePoint, DEFAULT_MUL_WINDOW},
+};
 
 /// The number of limbs in [Bn254AffinePoint].
 pub const N: usize = 16;
@@ -40,4 +47,155 @@ impl AffinePoint<N> for Bn254AffinePoint {
             syscall_bn254_double(a);
         }
     }
+
+    fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        self.mul_assign_windowed(scalar, DEFAULT_MUL_WINDOW)
+    }
+}
+
+impl WeierstrassAffinePoint<N> for Bn254AffinePoint {
+    const MODULUS: &'static [u32] = &[
+        0xd87cfd47, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029,
+        0x30644e72,
+    ];
+
+    const A: &'static [u32] = &[0; 8];
+
+    const B: &'static [u32] = &[3, 0, 0, 0, 0, 0, 0, 0];
+
+    fn field_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let modulus = U256(Self::MODULUS.try_into().unwrap());
+        U256(a.try_into().unwrap()).mulmod(&U256(b.try_into().unwrap()), &modulus).0.to_vec()
+    }
+}
+
+/// The base field modulus of BN254 as big endian bytes.
+const MODULUS_BE: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// Adds two G1 points with the semantics of the EVM `ECADD` precompile at address 0x06 (EIP-196).
+///
+/// The input is two points `(x1, y1, x2, y2)`, each coordinate a 32-byte big endian integer.
+/// Shorter inputs are padded with zeros and longer inputs are truncated to 128 bytes. The point
+/// `(0, 0)` encodes the point at infinity. The output uses the same encoding for the sum.
+///
+/// Returns `None` if a coordinate is not reduced or a point is not on the curve.
+pub fn ecadd(input: &[u8]) -> Option<[u8; 64]> {
+    let mut padded = [0u8; 128];
+    let len = core::cmp::min(input.len(), 128);
+    padded[..len].copy_from_slice(&input[..len]);
+
+    let p = decode_g1(padded[..64].try_into().unwrap())?;
+    let q = decode_g1(padded[64..].try_into().unwrap())?;
+
+    // The syscalls don't handle the point at infinity or a point added to itself or its negation,
+    // so those cases are handled here.
+    let sum = match (p, q) {
+        (None, q) => q,
+        (p, None) => p,
+        (Some(mut p), Some(q)) => {
+            let (p_x, p_y) = p.0.split_at(8);
+            let (q_x, q_y) = q.0.split_at(8);
+            if p_x != q_x {
+                p.add_assign(&q);
+                Some(p)
+            } else if p_y == q_y {
+                p.double();
+                Some(p)
+            } else {
+                None
+            }
+        }
+    };
+    Some(encode_g1(sum.as_ref()))
+}
+
+/// Checks a product of pairings with the semantics of the EVM `ECPAIRING` precompile at address
+/// 0x08 (EIP-197).
+///
+/// The input is a sequence of 192-byte `(P, Q)` pairs, where `P` is a G1 point encoded as for
+/// [ecadd] and `Q` is a G2 point encoded as `(x_im, x_re, y_im, y_re)`, each a 32-byte big endian
+/// integer, with all zeros encoding the point at infinity. The output is one as a 32-byte big
+/// endian integer if the product of the pairings `e(P, Q)` is one, and zero otherwise.
+///
+/// Returns `None` if the input length is not a multiple of 192, a coordinate is not reduced, a
+/// point is not on its curve, or a G2 point is not in the subgroup of order `r`.
+pub fn ecpairing(input: &[u8]) -> Option<[u8; 32]> {
+    if input.len() % 192 != 0 {
+        return None;
+    }
+
+    let mut pairs: Vec<(pairing::Fp, pairing::Fp, pairing::G2Affine)> =
+        Vec::with_capacity(input.len() / 192);
+    for chunk in input.chunks_exact(192) {
+        let p = decode_g1(chunk[..64].try_into().unwrap())?;
+        let q = pairing::decode_g2(chunk[64..].try_into().unwrap())?;
+        // A pair with the point at infinity contributes a factor of one.
+        if let (Some(p), Some(q)) = (p, q) {
+            let (x, y) = p.0.split_at(8);
+            pairs.push((x.try_into().unwrap(), y.try_into().unwrap(), q));
+        }
+    }
+
+    let mut output = [0u8; 32];
+    output[31] = pairing::pairing_check(&pairs) as u8;
+    Some(output)
+}
+
+/// Decodes a big endian `(x, y)` pair, where `(0, 0)` is the point at infinity.
+pub(crate) fn decode_g1(bytes: &[u8; 64]) -> Option<Option<Bn254AffinePoint>> {
+    if bytes.iter().all(|&b| b == 0) {
+        return Some(None);
+    }
+    let (x, y) = bytes.split_at(32);
+    if x >= &MODULUS_BE[..] || y >= &MODULUS_BE[..] {
+        return None;
+    }
+
+    let mut limbs = [0u32; N];
+    limbs[..8].copy_from_slice(&be_bytes_to_words_le(x));
+    limbs[8..].copy_from_slice(&be_bytes_to_words_le(y));
+    let point = Bn254AffinePoint(limbs);
+    is_on_curve(&point).then_some(Some(point))
+}
+
+/// Encodes a point as a big endian `(x, y)` pair, where `(0, 0)` is the point at infinity.
+fn encode_g1(point: Option<&Bn254AffinePoint>) -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    if let Some(point) = point {
+        for (chunk, word) in bytes
+            .chunks_exact_mut(4)
+            .zip(point.0[..8].iter().rev().chain(point.0[8..].iter().rev()))
+        {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+    }
+    bytes
+}
+
+/// Checks that `y^2 = x^3 + 3` using the base field precompiles.
+fn is_on_curve(point: &Bn254AffinePoint) -> bool {
+    let x: [u32; 8] = point.0[..8].try_into().unwrap();
+    let y: [u32; 8] = point.0[8..].try_into().unwrap();
+    let three = [3, 0, 0, 0, 0, 0, 0, 0];
+
+    let mut y_squared = y;
+    let mut rhs = x;
+    unsafe {
+        syscall_bn254_fp_mulmod(y_squared.as_mut_ptr(), y.as_ptr());
+        syscall_bn254_fp_mulmod(rhs.as_mut_ptr(), x.as_ptr());
+        syscall_bn254_fp_mulmod(rhs.as_mut_ptr(), x.as_ptr());
+        syscall_bn254_fp_addmod(rhs.as_mut_ptr(), three.as_ptr());
+    }
+    y_squared == rhs
+}
+
+/// Converts a 32-byte big endian integer to little endian words.
+fn be_bytes_to_words_le(bytes: &[u8]) -> [u32; 8] {
+    core::array::from_fn(|i| {
+        let start = 32 - 4 * (i + 1);
+        u32::from_be_bytes(bytes[start..start + 4].try_into().unwrap())
+    })
 }
@@ -0,0 +1,117 @@
+//! The BN254 scalar field, whose modulus `r` is the order of G1 and G2.
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use super::be_bytes_to_words_le;
+use crate::{syscall_bn254_fr_addmod, syscall_bn254_fr_mulmod, syscall_bn254_fr_submod};
+
+/// The scalar field modulus `r` as a big endian integer.
+const MODULUS_BE: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// An element of the BN254 scalar field, stored as little endian words of its canonical
+/// representative.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fr([u32; 8]);
+
+impl Fr {
+    pub const ZERO: Self = Self([0; 8]);
+
+    pub const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Decodes a 32-byte big endian integer, returning `None` if it is not less than `r`.
+    pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        (bytes[..] < MODULUS_BE[..]).then(|| Self(be_bytes_to_words_le(bytes)))
+    }
+
+    /// Encodes the element as a 32-byte big endian integer.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(self.0.iter().rev()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        bytes
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+}
+
+impl AddAssign for Fr {
+    fn add_assign(&mut self, rhs: Self) {
+        unsafe {
+            syscall_bn254_fr_addmod(self.0.as_mut_ptr(), rhs.0.as_ptr());
+        }
+    }
+}
+
+impl SubAssign for Fr {
+    fn sub_assign(&mut self, rhs: Self) {
+        unsafe {
+            syscall_bn254_fr_submod(self.0.as_mut_ptr(), rhs.0.as_ptr());
+        }
+    }
+}
+
+impl MulAssign for Fr {
+    fn mul_assign(&mut self, rhs: Self) {
+        unsafe {
+            syscall_bn254_fr_mulmod(self.0.as_mut_ptr(), rhs.0.as_ptr());
+        }
+    }
+}
+
+impl Add for Fr {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl Sub for Fr {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl Mul for Fr {
+    type Output = Self;
+
+    fn mul(mut self, rhs: Self) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fr_bytes_roundtrip() {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        assert_eq!(Fr::from_canonical_bytes(&bytes), Some(Fr::ONE));
+
+        let mut r_minus_one = MODULUS_BE;
+        r_minus_one[31] -= 1;
+        let element = Fr::from_canonical_bytes(&r_minus_one).unwrap();
+        assert_eq!(element.0[0], 0xf0000000);
+        assert_eq!(element.0[7], 0x30644e72);
+        assert_eq!(element.to_bytes(), r_minus_one);
+    }
+
+    #[test]
+    fn test_fr_rejects_non_canonical() {
+        assert!(Fr::from_canonical_bytes(&MODULUS_BE).is_none());
+        assert!(Fr::from_canonical_bytes(&[0xff; 32]).is_none());
+    }
+}
@@ -0,0 +1,357 @@
+//! A BN254 pairing check built from the base field and `Fp2` precompiles.
+//!
+//! The extension tower is `Fp2 = Fp[u] / (u^2 + 1)`, `Fp6 = Fp2[v] / (v^3 - (9 + u))` and
+//! `Fp12 = Fp6[w] / (w^2 - v)`, and G2 is the subgroup of order `r` of the sextic twist
+//! `y^2 = x^3 + 3 / (9 + u)`. Only `Fp2` arithmetic is done by precompiles, so every product in
+//! `Fp6` and `Fp12` is a short sequence of `Fp2` syscalls.
+
+use super::{be_bytes_to_words_le, MODULUS_BE};
+use crate::{
+    syscall_bn254_fp2_addmod, syscall_bn254_fp2_mulmod, syscall_bn254_fp2_submod,
+    syscall_bn254_fp_addmod, syscall_bn254_fp_mulmod, syscall_bn254_fp_submod,
+};
+
+/// An element of the base field as little endian words.
+pub type Fp = [u32; 8];
+
+/// The exponent `p - 2`, which inverts a nonzero element of the base field.
+const P_MINUS_TWO: [u32; 8] = [
+    0xd87cfd45, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// The scalar `r - 1`, where `r` is the order of G1 and G2.
+const R_MINUS_ONE: [u32; 8] = [
+    0xf0000000, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// The loop count `6 * x^2` of the ate pairing, where `x` is the BN parameter of the curve.
+const ATE_LOOP_COUNT: u128 = 0x6f4d8248eeb859fbf83e9682e87cfd46;
+
+/// The exponent `(p^6 + 1) / r` of the final exponentiation.
+const FINAL_EXPONENT: [u32; 40] = [
+    0x36e3f812, 0x5250a540, 0x96789051, 0xa5635f15, 0x4d5bd1d4, 0xd1138bf5, 0xbe36c7a2, 0xa8ce2533,
+    0x84e09bf6, 0x94f69f6b, 0x50ef3644, 0x42ad1f5e, 0x48c3454c, 0x0fcc420e, 0xecc9952c, 0x758e4408,
+    0x87c6042c, 0xc901bf18, 0xb14bb3b5, 0xa733cd65, 0xcf51b0d8, 0xdf6d76bd, 0x82eb59e1, 0xca64c0fd,
+    0xe39276a1, 0x1d2e5726, 0xa391cae9, 0xc2d1ea74, 0xc82d647e, 0x07409206, 0xa5afdd17, 0x051c6d1a,
+    0x19667af5, 0xb37f6019, 0x5084015b, 0x150e578c, 0xc23998e4, 0xfbdea556, 0xc52f5b83, 0x000fd14c,
+];
+
+/// Iterates over the bits of a little endian exponent, most significant first.
+fn bits_be(words: &[u32]) -> impl Iterator<Item = bool> + '_ {
+    words.iter().rev().flat_map(|word| (0..32).rev().map(move |i| (word >> i) & 1 == 1))
+}
+
+fn fp_add(a: &Fp, b: &Fp) -> Fp {
+    let mut result = *a;
+    unsafe {
+        syscall_bn254_fp_addmod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+fn fp_sub(a: &Fp, b: &Fp) -> Fp {
+    let mut result = *a;
+    unsafe {
+        syscall_bn254_fp_submod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+fn fp_mul(a: &Fp, b: &Fp) -> Fp {
+    let mut result = *a;
+    unsafe {
+        syscall_bn254_fp_mulmod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+/// Inverts a nonzero element of the base field as `a^(p - 2)`.
+fn fp_inv(a: &Fp) -> Fp {
+    let mut result = [1, 0, 0, 0, 0, 0, 0, 0];
+    for bit in bits_be(&P_MINUS_TWO) {
+        result = fp_mul(&result, &result);
+        if bit {
+            result = fp_mul(&result, a);
+        }
+    }
+    result
+}
+
+/// An element `c0 + c1 * u` of `Fp2`, with the words of `c0` followed by those of `c1`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fp2(pub [u32; 16]);
+
+impl Fp2 {
+    pub const ZERO: Self = Self([0; 16]);
+
+    pub const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// The non-residue `9 + u` that defines `Fp6`.
+    const XI: Self = Self([9, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0]);
+
+    pub fn new(c0: &Fp, c1: &Fp) -> Self {
+        let mut limbs = [0; 16];
+        limbs[..8].copy_from_slice(c0);
+        limbs[8..].copy_from_slice(c1);
+        Self(limbs)
+    }
+
+    pub fn c0(&self) -> Fp {
+        self.0[..8].try_into().unwrap()
+    }
+
+    pub fn c1(&self) -> Fp {
+        self.0[8..].try_into().unwrap()
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut result = *self;
+        unsafe {
+            syscall_bn254_fp2_addmod(result.0.as_mut_ptr(), other.0.as_ptr());
+        }
+        result
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        let mut result = *self;
+        unsafe {
+            syscall_bn254_fp2_submod(result.0.as_mut_ptr(), other.0.as_ptr());
+        }
+        result
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut result = *self;
+        unsafe {
+            syscall_bn254_fp2_mulmod(result.0.as_mut_ptr(), other.0.as_ptr());
+        }
+        result
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    /// Inverts a nonzero element as `(c0 - c1 * u) / (c0^2 + c1^2)`.
+    pub fn inv(&self) -> Self {
+        let (c0, c1) = (self.c0(), self.c1());
+        let norm_inv = fp_inv(&fp_add(&fp_mul(&c0, &c0), &fp_mul(&c1, &c1)));
+        Self::new(&fp_mul(&c0, &norm_inv), &fp_sub(&[0; 8], &fp_mul(&c1, &norm_inv)))
+    }
+}
+
+/// An element `c0 + c1 * v + c2 * v^2` of `Fp6`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fp6 {
+    pub c0: Fp2,
+    pub c1: Fp2,
+    pub c2: Fp2,
+}
+
+impl Fp6 {
+    pub const ZERO: Self = Self { c0: Fp2::ZERO, c1: Fp2::ZERO, c2: Fp2::ZERO };
+
+    pub const ONE: Self = Self { c0: Fp2::ONE, c1: Fp2::ZERO, c2: Fp2::ZERO };
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self { c0: self.c0.add(&other.c0), c1: self.c1.add(&other.c1), c2: self.c2.add(&other.c2) }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        Self { c0: self.c0.sub(&other.c0), c1: self.c1.sub(&other.c1), c2: self.c2.sub(&other.c2) }
+    }
+
+    /// Multiplies with Karatsuba, using six `Fp2` multiplications.
+    pub fn mul(&self, other: &Self) -> Self {
+        let t0 = self.c0.mul(&other.c0);
+        let t1 = self.c1.mul(&other.c1);
+        let t2 = self.c2.mul(&other.c2);
+
+        let c0 = self.c1.add(&self.c2).mul(&other.c1.add(&other.c2)).sub(&t1.add(&t2));
+        let c1 = self.c0.add(&self.c1).mul(&other.c0.add(&other.c1)).sub(&t0.add(&t1));
+        let c2 = self.c0.add(&self.c2).mul(&other.c0.add(&other.c2)).sub(&t0.add(&t2));
+        Self { c0: t0.add(&c0.mul(&Fp2::XI)), c1: c1.add(&t2.mul(&Fp2::XI)), c2: c2.add(&t1) }
+    }
+
+    /// Multiplies by `v`, using `v^3 = 9 + u`.
+    pub fn mul_by_v(&self) -> Self {
+        Self { c0: self.c2.mul(&Fp2::XI), c1: self.c0, c2: self.c1 }
+    }
+}
+
+/// An element `c0 + c1 * w` of `Fp12`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fp12 {
+    pub c0: Fp6,
+    pub c1: Fp6,
+}
+
+impl Fp12 {
+    pub const ONE: Self = Self { c0: Fp6::ONE, c1: Fp6::ZERO };
+
+    /// Multiplies with Karatsuba, using three `Fp6` multiplications.
+    pub fn mul(&self, other: &Self) -> Self {
+        let t0 = self.c0.mul(&other.c0);
+        let t1 = self.c1.mul(&other.c1);
+        let c1 = self.c0.add(&self.c1).mul(&other.c0.add(&other.c1)).sub(&t0.add(&t1));
+        Self { c0: t0.add(&t1.mul_by_v()), c1 }
+    }
+
+    /// Raises to the power of a little endian exponent.
+    pub fn pow(&self, exp: &[u32]) -> Self {
+        let mut result = Self::ONE;
+        for bit in bits_be(exp) {
+            result = result.mul(&result);
+            if bit {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+}
+
+/// An affine point on the twist, which is the G2 point `(x * w^2, y * w^3)` on the curve.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct G2Affine {
+    pub x: Fp2,
+    pub y: Fp2,
+}
+
+impl G2Affine {
+    /// The coefficient `3 / (9 + u)` of the twist.
+    const B: Fp2 = Fp2([
+        0x24a138e5, 0x3267e6dc, 0x59dbefa3, 0xb5b4c5e5, 0x1be06ac3, 0x81be1899, 0xceb8aaae,
+        0x2b149d40, 0x85c315d2, 0xe4a2bd06, 0xe52d1852, 0xa74fa084, 0xeed8fdf4, 0xcd2cafad,
+        0x3af0fed4, 0x009713b0,
+    ]);
+
+    pub fn neg(&self) -> Self {
+        Self { x: self.x, y: self.y.neg() }
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        let rhs = self.x.mul(&self.x).mul(&self.x).add(&Self::B);
+        self.y.mul(&self.y) == rhs
+    }
+
+    /// Checks that the point has order `r` by checking that `(r - 1) * Q = -Q`.
+    pub fn is_in_subgroup(&self) -> bool {
+        // `None` is the point at infinity.
+        let mut acc: Option<Self> = None;
+        for bit in bits_be(&R_MINUS_ONE) {
+            acc = acc.and_then(|acc| acc.double());
+            if bit {
+                acc = match acc {
+                    Some(acc) => acc.add(self),
+                    None => Some(*self),
+                };
+            }
+        }
+        acc == Some(self.neg())
+    }
+
+    /// Returns `self + self`, or `None` for the point at infinity.
+    pub fn double(&self) -> Option<Self> {
+        (self.y != Fp2::ZERO).then(|| self.add_with_slope(self, &self.tangent_slope()))
+    }
+
+    /// Returns `self + other`, or `None` for the point at infinity.
+    pub fn add(&self, other: &Self) -> Option<Self> {
+        if self.x != other.x {
+            Some(self.add_with_slope(other, &self.chord_slope(other)))
+        } else if self.y == other.y {
+            self.double()
+        } else {
+            None
+        }
+    }
+
+    /// The slope of the tangent at the point, which must not have `y = 0`.
+    fn tangent_slope(&self) -> Fp2 {
+        let x_squared = self.x.mul(&self.x);
+        let numerator = x_squared.add(&x_squared).add(&x_squared);
+        numerator.mul(&self.y.add(&self.y).inv())
+    }
+
+    /// The slope of the line through the point and another point with a different `x`.
+    fn chord_slope(&self, other: &Self) -> Fp2 {
+        other.y.sub(&self.y).mul(&other.x.sub(&self.x).inv())
+    }
+
+    /// Adds the point to `other`, given the slope of the line through them.
+    fn add_with_slope(&self, other: &Self, slope: &Fp2) -> Self {
+        let x = slope.mul(slope).sub(&self.x).sub(&other.x);
+        let y = slope.mul(&self.x.sub(&x)).sub(&self.y);
+        Self { x, y }
+    }
+}
+
+/// Evaluates the line with the given slope through `t` at the G1 point `(p_x, p_y)`.
+///
+/// The slope of the untwisted line is `slope * w`, so the line is
+/// `p_y - slope * p_x * w + (slope * t.x - t.y) * w^3`, and `w^3 = v * w`. Vertical lines are
+/// omitted, since their values lie in `Fp6` and vanish in the final exponentiation.
+fn line(t: &G2Affine, slope: &Fp2, p_x: &Fp, p_y: &Fp) -> Fp12 {
+    Fp12 {
+        c0: Fp6 { c0: Fp2::new(p_y, &[0; 8]), c1: Fp2::ZERO, c2: Fp2::ZERO },
+        c1: Fp6 {
+            c0: slope.mul(&Fp2::new(p_x, &[0; 8])).neg(),
+            c1: slope.mul(&t.x).sub(&t.y),
+            c2: Fp2::ZERO,
+        },
+    }
+}
+
+/// Computes the product of the Miller loops of the ate pairing for each pair.
+///
+/// The multiples of each G2 point stay below `r`, so they never reach the point at infinity and
+/// the doubling and addition steps always have a well defined slope.
+fn miller_loop(pairs: &[(Fp, Fp, G2Affine)]) -> Fp12 {
+    let mut f = Fp12::ONE;
+    let mut acc = pairs.iter().map(|(_, _, q)| *q).collect::<Vec<_>>();
+    for i in (0..ATE_LOOP_COUNT.ilog2()).rev() {
+        f = f.mul(&f);
+        for ((p_x, p_y, _), t) in pairs.iter().zip(acc.iter_mut()) {
+            let slope = t.tangent_slope();
+            f = f.mul(&line(t, &slope, p_x, p_y));
+            *t = t.add_with_slope(t, &slope);
+        }
+        if (ATE_LOOP_COUNT >> i) & 1 == 1 {
+            for ((p_x, p_y, q), t) in pairs.iter().zip(acc.iter_mut()) {
+                let slope = t.chord_slope(q);
+                f = f.mul(&line(t, &slope, p_x, p_y));
+                *t = t.add_with_slope(q, &slope);
+            }
+        }
+    }
+    f
+}
+
+/// Returns whether the product of the pairings `e(P, Q)` over the `(P.x, P.y, Q)` pairs is one.
+///
+/// The G1 points must be on the curve and the G2 points in G2, and neither may be the point at
+/// infinity. The final exponentiation `f^((p^12 - 1) / r)` equals `g^(p^6 - 1)` for
+/// `g = f^((p^6 + 1) / r)`, and `g^(p^6)` is the conjugate `g.c0 - g.c1 * w`, so the product is
+/// one exactly when `g.c1` is zero. This avoids an inversion in `Fp12`.
+pub fn pairing_check(pairs: &[(Fp, Fp, G2Affine)]) -> bool {
+    miller_loop(pairs).pow(&FINAL_EXPONENT).c1 == Fp6::ZERO
+}
+
+/// Decodes a big endian `(x_im, x_re, y_im, y_re)` tuple, where all zeros is the point at
+/// infinity.
+pub(crate) fn decode_g2(bytes: &[u8; 128]) -> Option<Option<G2Affine>> {
+    if bytes.iter().all(|&b| b == 0) {
+        return Some(None);
+    }
+    let mut coords = [[0u32; 8]; 4];
+    for (coord, chunk) in coords.iter_mut().zip(bytes.chunks_exact(32)) {
+        if chunk >= &MODULUS_BE[..] {
+            return None;
+        }
+        *coord = be_bytes_to_words_le(chunk);
+    }
+
+    let [x_im, x_re, y_im, y_re] = coords;
+    let point = G2Affine { x: Fp2::new(&x_re, &x_im), y: Fp2::new(&y_re, &y_im) };
+    (point.is_on_curve() && point.is_in_subgroup()).then_some(Some(point))
+}
@@ -0,0 +1,68 @@
+//! Guest-side profiling. Regions opened with [`CycleTracker::start`] are reported per label in the
+//! `CycleReport` of the proof, with the cycles of every call summed up.
+//!
+//! ### Examples
+//! ```ignore
+//! let tracker = sp1_lib::cycle_tracker::CycleTracker::start("verify");
+//! verify_signature(&msg, &sig);
+//! tracker.end();
+//! ```
+
+/// Hashes a region label into the identifier passed to the syscalls (32-bit FNV-1a). This must
+/// match `CycleReport::label_hash` in the executor.
+pub const fn label_hash(label: &str) -> u32 {
+    let bytes = label.as_bytes();
+    let mut hash = 0x811c_9dc5u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+    hash
+}
+
+/// An open cycle tracker region, which is closed when it is ended or dropped. Outside the zkVM
+/// there is nothing to measure, so the tracker does nothing.
+#[must_use = "the region is closed as soon as the tracker is dropped"]
+pub struct CycleTracker {
+    #[cfg_attr(not(target_os = "zkvm"), allow(dead_code))]
+    label_hash: u32,
+}
+
+impl CycleTracker {
+    /// Opens a region with the given label.
+    pub fn start(label: &str) -> Self {
+        let label_hash = label_hash(label);
+        #[cfg(target_os = "zkvm")]
+        unsafe {
+            crate::syscall_cycle_tracker_start(label_hash);
+        }
+        Self { label_hash }
+    }
+
+    /// Closes the region.
+    pub fn end(self) {}
+}
+
+impl Drop for CycleTracker {
+    fn drop(&mut self) {
+        #[cfg(target_os = "zkvm")]
+        unsafe {
+            crate::syscall_cycle_tracker_end(self.label_hash);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_hash() {
+        // Reference values of 32-bit FNV-1a.
+        assert_eq!(label_hash(""), 0x811c9dc5);
+        assert_eq!(label_hash("a"), 0xe40c292c);
+        assert_eq!(label_hash("foobar"), 0xbf9cf968);
+    }
+}
@@ -1,8 +1,25 @@
-use crate::{syscall_ed_add, utils::AffinePoint};
+use std::io::ErrorKind;
+
+use crate::{
+    hash::sha512,
+    syscall_ed25519_verify, syscall_ed_add, syscall_ed_decompress,
+    uint256::U256,
+    utils::{AffinePoint, MulAssignError, DEFAULT_MUL_WINDOW},
+};
 
 /// The number of limbs in [Ed25519AffinePoint].
 pub const N: usize = 16;
 
+/// The order `L` of the prime subgroup of Ed25519, as little endian words.
+const GROUP_ORDER: [u32; 8] = [
+    0x5cf5d3ed, 0x5812631a, 0xa2f79cd6, 0x14def9de, 0x00000000, 0x00000000, 0x00000000, 0x10000000,
+];
+
+/// `2^256 % L`, as little endian words.
+const TWO_POW_256_MOD_ORDER: [u32; 8] = [
+    0x8d98951d, 0xd6ec3174, 0x737dcf70, 0xc6ef5bf4, 0xfffffffe, 0xffffffff, 0xffffffff, 0x0fffffff,
+];
+
 /// An affine point on the Ed25519 curve.
 #[derive(Copy, Clone)]
 #[repr(align(4))]
@@ -16,6 +33,9 @@ impl AffinePoint<N> for Ed25519AffinePoint {
         3665724614, 2969860233,
     ];
 
+    /// The neutral element `(0, 1)`, which the complete addition law handles like any other point.
+    const IDENTITY: [u32; N] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+
     fn new(limbs: [u32; N]) -> Self {
         Self(limbs)
     }
@@ -43,12 +63,104 @@ impl AffinePoint<N> for Ed25519AffinePoint {
             syscall_ed_add(a, a);
         }
     }
+
+    fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        self.mul_assign_windowed(scalar, DEFAULT_MUL_WINDOW)
+    }
+
+    /// The addition law is complete, and points with the same x coordinate are not negations of
+    /// each other, so every sum is a plain addition.
+    fn accumulate(acc: &mut Option<Self>, other: &Self) {
+        match acc.as_mut() {
+            Some(acc) => acc.add_assign(other),
+            None => *acc = Some(*other),
+        }
+    }
 }
 
 impl Ed25519AffinePoint {
-    const IDENTITY: [u32; N] = [0; N];
-
     pub fn identity() -> Self {
         Self(Self::IDENTITY)
     }
+
+    /// Decompresses an RFC 8032 point encoding using the ed_decompress precompile.
+    ///
+    /// Fails if the y coordinate is not reduced, or if it is not the y coordinate of a point with
+    /// the encoded sign of x.
+    pub fn decompress(compressed: &[u8; 32]) -> Result<Self, ErrorKind> {
+        let mut point = [0u8; 64];
+        point[32..].copy_from_slice(compressed);
+        unsafe {
+            syscall_ed_decompress(&mut point);
+        }
+
+        // The precompile sets every bit of x when the encoding is not a valid point.
+        if point[..32].iter().all(|&byte| byte == 0xff) {
+            return Err(ErrorKind::InvalidInput);
+        }
+        Ok(Self(core::array::from_fn(|i| {
+            u32::from_le_bytes(point[4 * i..4 * i + 4].try_into().unwrap())
+        })))
+    }
+}
+
+/// Verifies an RFC 8032 Ed25519 signature of `msg`.
+///
+/// The signature is `R || S`, where `R` is a compressed point and `S` a little endian scalar. The
+/// signature is rejected if `S` is not reduced, if the public key or `R` is not a valid point
+/// encoding, or if `S * B` is not `R + k * A` for the challenge `k = SHA-512(R || A || msg) % L`.
+/// The challenge is hashed in the guest, and the group equation is checked by the
+/// `ED25519_VERIFY` precompile.
+pub fn verify(pubkey: &[u8; 32], msg: &[u8], sig: &[u8; 64]) -> bool {
+    let s = le_scalar(&sig[32..]);
+    let order = U256(GROUP_ORDER);
+    if !s.is_reduced(&order) {
+        return false;
+    }
+    let Ok(a) = Ed25519AffinePoint::decompress(pubkey) else {
+        return false;
+    };
+    let Ok(r) = Ed25519AffinePoint::decompress(sig[..32].try_into().unwrap()) else {
+        return false;
+    };
+
+    let mut preimage = Vec::with_capacity(64 + msg.len());
+    preimage.extend_from_slice(&sig[..32]);
+    preimage.extend_from_slice(pubkey);
+    preimage.extend_from_slice(msg);
+    let k = challenge(&sha512(&preimage));
+
+    let mut input = [0u32; 49];
+    for (words, value) in input.chunks_exact_mut(8).zip([&s.0, &k.0]) {
+        words.copy_from_slice(value);
+    }
+    input[16..32].copy_from_slice(&a.0);
+    input[32..48].copy_from_slice(&r.0);
+    unsafe {
+        syscall_ed25519_verify(&mut input);
+    }
+    input[48] == 1
+}
+
+/// Reads a 256-bit integer from 32 little endian bytes.
+fn le_scalar(bytes: &[u8]) -> U256 {
+    U256(core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap())))
+}
+
+/// Reduces a 512-bit little endian digest modulo `L`, as `hi * 2^256 + lo`.
+fn challenge(digest: &[u8; 64]) -> U256 {
+    let order = U256(GROUP_ORDER);
+    let lo = le_scalar(&digest[..32]).mulmod(&U256::ONE, &order);
+    let hi = le_scalar(&digest[32..]).mulmod(&U256(TWO_POW_256_MOD_ORDER), &order);
+
+    // Both terms are less than `L < 2^253`, so their sum does not overflow.
+    let mut sum = [0u32; 8];
+    let mut carry = false;
+    for ((sum, lo), hi) in sum.iter_mut().zip(lo.0.iter()).zip(hi.0.iter()) {
+        let (t, c1) = lo.overflowing_add(*hi);
+        let (t, c2) = t.overflowing_add(carry as u32);
+        *sum = t;
+        carry = c1 || c2;
+    }
+    U256(sum).mulmod(&U256::ONE, &order)
 }
@@ -0,0 +1,119 @@
+//! Verification of Groth16 proofs over BN254.
+//!
+//! The points use the encodings of the EVM precompiles (EIP-196 and EIP-197): a G1 point is
+//! `(x, y)` and a G2 point is `(x_im, x_re, y_im, y_re)`, each coordinate a 32-byte big endian
+//! integer. The public inputs are 32-byte big endian elements of the scalar field.
+//!
+//! The sum over the public inputs is a multi-scalar multiplication with the G1 precompiles, and
+//! the pairing check is the one of [`crate::bn254::pairing`], whose Miller loop and final
+//! exponentiation run on the `Fp2` precompiles.
+
+use crate::{
+    bn254::{
+        decode_g1,
+        pairing::{decode_g2, pairing_check, Fp, G2Affine},
+        Bn254AffinePoint,
+    },
+    uint256::U256,
+    utils::{AffinePoint, WeierstrassAffinePoint},
+};
+
+/// The order `r` of the scalar field, as little endian words.
+const SCALAR_MODULUS: [u32; 8] = [
+    0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// A Groth16 verifying key.
+#[derive(Clone)]
+pub struct Groth16VerifyingKey {
+    pub alpha_g1: Bn254AffinePoint,
+    pub beta_g2: G2Affine,
+    pub gamma_g2: G2Affine,
+    pub delta_g2: G2Affine,
+    /// The points `IC_0, ..., IC_n`, where `n` is the number of public inputs.
+    pub ic: Vec<Bn254AffinePoint>,
+}
+
+impl Groth16VerifyingKey {
+    /// Decodes `alpha_g1 || beta_g2 || gamma_g2 || delta_g2 || IC_0 || ... || IC_n`.
+    ///
+    /// Returns `None` if the length is not 448 bytes plus a positive multiple of 64, if a
+    /// coordinate is not reduced, or if a point is the point at infinity, is not on its curve or,
+    /// for G2, is not in the subgroup of order `r`.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 512 || (bytes.len() - 448) % 64 != 0 {
+            return None;
+        }
+        Some(Self {
+            alpha_g1: g1(&bytes[..64])?,
+            beta_g2: g2(&bytes[64..192])?,
+            gamma_g2: g2(&bytes[192..320])?,
+            delta_g2: g2(&bytes[320..448])?,
+            ic: bytes[448..].chunks_exact(64).map(g1).collect::<Option<Vec<_>>>()?,
+        })
+    }
+}
+
+/// A Groth16 proof `(A, B, C)`.
+#[derive(Clone)]
+pub struct Groth16Proof {
+    pub a: Bn254AffinePoint,
+    pub b: G2Affine,
+    pub c: Bn254AffinePoint,
+}
+
+impl Groth16Proof {
+    /// Decodes `A || B || C`, with the same rules as [`Groth16VerifyingKey::from_bytes`].
+    pub fn from_bytes(bytes: &[u8; 256]) -> Option<Self> {
+        Some(Self { a: g1(&bytes[..64])?, b: g2(&bytes[64..192])?, c: g1(&bytes[192..])? })
+    }
+}
+
+/// Verifies a Groth16 proof for the given public inputs.
+///
+/// The proof is valid if `e(A, B) = e(alpha, beta) * e(L, gamma) * e(C, delta)`, where
+/// `L = IC_0 + sum(x_i * IC_i)` over the public inputs `x_i`. This is checked as the single
+/// product `e(-A, B) * e(alpha, beta) * e(L, gamma) * e(C, delta) = 1`. The proof is rejected if
+/// the number of public inputs does not match the key, or if an input is not reduced.
+pub fn verify(vk: &Groth16VerifyingKey, proof: &Groth16Proof, public_inputs: &[[u8; 32]]) -> bool {
+    if public_inputs.len() + 1 != vk.ic.len() {
+        return false;
+    }
+
+    let mut scalars = Vec::with_capacity(public_inputs.len());
+    for input in public_inputs {
+        let scalar = U256::from(*input);
+        if !scalar.is_reduced(&U256(SCALAR_MODULUS)) {
+            return false;
+        }
+        scalars.push((0..256).map(|i| (scalar.0[i / 32] >> (i % 32)) & 1 == 1).collect::<Vec<_>>());
+    }
+    let scalars = scalars.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let mut l = Bn254AffinePoint::msm(&scalars, &vk.ic[1..]);
+    Bn254AffinePoint::accumulate(&mut l, &vk.ic[0]);
+
+    let mut neg_a = proof.a;
+    neg_a.negate();
+    let mut pairs =
+        vec![pair(&neg_a, &proof.b), pair(&vk.alpha_g1, &vk.beta_g2), pair(&proof.c, &vk.delta_g2)];
+    // If the inputs cancel out `IC_0`, then `L` is the point at infinity and its pairing is one.
+    if let Some(l) = l {
+        pairs.push(pair(&l, &vk.gamma_g2));
+    }
+    pairing_check(&pairs)
+}
+
+/// Decodes a G1 point, rejecting the point at infinity.
+fn g1(bytes: &[u8]) -> Option<Bn254AffinePoint> {
+    decode_g1(bytes.try_into().unwrap()).flatten()
+}
+
+/// Decodes a G2 point, rejecting the point at infinity.
+fn g2(bytes: &[u8]) -> Option<G2Affine> {
+    decode_g2(bytes.try_into().unwrap()).flatten()
+}
+
+fn pair(p: &Bn254AffinePoint, q: &G2Affine) -> (Fp, Fp, G2Affine) {
+    let (x, y) = p.0.split_at(8);
+    (x.try_into().unwrap(), y.try_into().unwrap(), *q)
+}
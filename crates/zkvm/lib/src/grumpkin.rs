@@ -0,0 +1,83 @@
+use crate::{
+    syscall_grumpkin_add, syscall_grumpkin_double,
+    utils::{AffinePoint, MulAssignError, WeierstrassAffinePoint, DEFAULT_MUL_WINDOW},
+};
+
+/// The number of limbs in [GrumpkinPoint].
+pub const N: usize = 16;
+
+/// An affine point on the Grumpkin curve `y^2 = x^3 - 17`, whose base field is the BN254 scalar
+/// field.
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct GrumpkinPoint(pub [u32; N]);
+
+impl AffinePoint<N> for GrumpkinPoint {
+    /// The generator `(1, sqrt(-16))` is the one used by barretenberg.
+    const GENERATOR: [u32; N] = [
+        1, 0, 0, 0, 0, 0, 0, 0, 0x823f272c, 0x833fc48d, 0xf1181294, 0x2d270d45, 0x06a45d63,
+        0xcf135e75, 0x00000002, 0x00000000,
+    ];
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(limbs)
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        &self.0
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        &mut self.0
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_grumpkin_add(a, b);
+        }
+    }
+
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_grumpkin_double(a);
+        }
+    }
+
+    fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        self.mul_assign_windowed(scalar, DEFAULT_MUL_WINDOW)
+    }
+}
+
+impl WeierstrassAffinePoint<N> for GrumpkinPoint {
+    const MODULUS: &'static [u32] = &[
+        0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029,
+        0x30644e72,
+    ];
+
+    const A: &'static [u32] = &[0; 8];
+
+    /// `b = -17`.
+    const B: &'static [u32] = &[
+        0xeffffff0, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029,
+        0x30644e72,
+    ];
+
+    /// Inside the zkVM, the product is computed by the BN254 scalar field precompile.
+    fn field_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+        #[cfg(target_os = "zkvm")]
+        {
+            let mut result: [u32; 8] = a.try_into().unwrap();
+            let b: [u32; 8] = b.try_into().unwrap();
+            unsafe {
+                crate::syscall_bn254_fr_mulmod(result.as_mut_ptr(), b.as_ptr());
+            }
+            result.to_vec()
+        }
+
+        #[cfg(not(target_os = "zkvm"))]
+        crate::utils::mul_mod(a, b, Self::MODULUS)
+    }
+}
@@ -0,0 +1,339 @@
+pub mod blake3;
+pub mod pedersen;
+pub mod poseidon2;
+pub mod rescue;
+
+use crate::{
+    syscall_fri_decommit, syscall_keccak_permute, syscall_merkle_verify_path,
+    syscall_sha256_compress, syscall_sha256_extend,
+};
+
+/// Applies the SHA-256 compression function to `state` with the given 64-byte message block.
+///
+/// The block is interpreted as sixteen big endian words, as in FIPS 180-4. The message schedule
+/// is expanded with the `SHA_EXTEND` precompile and the 64 rounds are computed with the
+/// `SHA_COMPRESS` precompile, which also adds the result into `state`.
+pub fn sha256_compress(state: &mut [u32; 8], block: &[u8; 64]) {
+    let mut w = [0u32; 64];
+    for (word, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    unsafe {
+        syscall_sha256_extend(&mut w);
+        syscall_sha256_compress(&mut w, state);
+    }
+}
+
+/// The initial hash value of SHA-512, from FIPS 180-4.
+const SHA512_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// The round constants of SHA-512, from FIPS 180-4.
+const SHA512_K: [u64; 80] = [
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+];
+
+/// Computes the SHA-512 digest of the given input.
+///
+/// There is no SHA-512 precompile, so the compression function runs as plain RISC-V code. It is
+/// mostly used to hash the challenge of an Ed25519 signature.
+pub fn sha512(input: &[u8]) -> [u8; 64] {
+    // The input is followed by a one bit, zeros, and its length in bits as a 128-bit big endian
+    // integer, up to a multiple of the 128-byte block size.
+    let mut message = input.to_vec();
+    message.push(0x80);
+    while message.len() % 128 != 112 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((input.len() as u128) * 8).to_be_bytes());
+
+    let mut state = SHA512_IV;
+    for block in message.chunks_exact(128) {
+        let mut w = [0u64; 80];
+        for (word, chunk) in w.iter_mut().zip(block.chunks_exact(8)) {
+            *word = u64::from_be_bytes(chunk.try_into().unwrap());
+        }
+        for i in 16..80 {
+            let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+            let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for i in 0..80 {
+            let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+            let ch = (e & f) ^ (!e & g);
+            let temp1 =
+                h.wrapping_add(s1).wrapping_add(ch).wrapping_add(SHA512_K[i]).wrapping_add(w[i]);
+            let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = word.wrapping_add(value);
+        }
+    }
+
+    let mut digest = [0u8; 64];
+    for (chunk, word) in digest.chunks_exact_mut(8).zip(state) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+/// Verifies a Merkle path with the `MERKLE_VERIFY_PATH` precompile, where each parent is the
+/// SHA-256 digest of its left child followed by its right child.
+///
+/// The siblings are given from the leaf level up, and bit `i` of `directions` is set if the node at
+/// level `i` is the right child. Panics if there are no siblings or more than 32.
+pub fn merkle_verify_path(
+    leaf: &[u8; 32],
+    root: &[u8; 32],
+    directions: u32,
+    siblings: &[[u8; 32]],
+) -> bool {
+    assert!((1..=32).contains(&siblings.len()), "the path must have between 1 and 32 levels");
+
+    let mut input = Vec::with_capacity(18 + 8 * siblings.len());
+    let push_node = |input: &mut Vec<u32>, node: &[u8; 32]| {
+        input.extend(
+            node.chunks_exact(4).map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap())),
+        );
+    };
+    push_node(&mut input, leaf);
+    push_node(&mut input, root);
+    input.push(directions);
+    input.push(0);
+    for sibling in siblings {
+        push_node(&mut input, sibling);
+    }
+    unsafe {
+        syscall_merkle_verify_path(input.as_mut_ptr(), siblings.len() as u32);
+    }
+    input[17] == 1
+}
+
+/// Decommits a FRI query with the `FRI_DECOMMIT` precompile, for a commitment of the recursion
+/// prover over BabyBear.
+///
+/// The leaf is the Poseidon2 sponge hash of `values`, and each parent is the start of the Poseidon2
+/// permutation of its left child followed by its right child. The siblings are given from the leaf
+/// level up, and bit `i` of `index` is set if the node at level `i` is the right child. Panics if
+/// there are no values or more than 256, or if there are no siblings or more than 32.
+pub fn fri_decommit(index: u32, values: &[u32], root: &[u32; 8], siblings: &[[u32; 8]]) -> bool {
+    assert!((1..=256).contains(&values.len()), "the leaf must have between 1 and 256 values");
+    assert!((1..=32).contains(&siblings.len()), "the path must have between 1 and 32 levels");
+
+    let mut input = Vec::with_capacity(11 + values.len() + 8 * siblings.len());
+    input.extend([index, values.len() as u32, 0]);
+    input.extend(root);
+    input.extend(values);
+    for sibling in siblings {
+        input.extend(sibling);
+    }
+    unsafe {
+        syscall_fri_decommit(input.as_mut_ptr(), siblings.len() as u32);
+    }
+    input[2] == 1
+}
+
+/// The rate of Keccak-256 in bytes.
+const KECCAK256_RATE: usize = 136;
+
+/// A Keccak-256 hasher backed by the `KECCAK_PERMUTE` precompile.
+///
+/// The API mirrors `tiny_keccak::Keccak`. With the `tiny-keccak` feature enabled, it also
+/// implements `tiny_keccak::Hasher`, so code that is generic over that trait can use it directly.
+#[derive(Clone)]
+pub struct Keccak {
+    state: [u64; 25],
+    offset: usize,
+}
+
+impl Keccak {
+    /// Creates a new Keccak-256 hasher.
+    pub const fn v256() -> Self {
+        Self { state: [0; 25], offset: 0 }
+    }
+
+    /// Absorbs the given bytes into the state.
+    pub fn update(&mut self, input: &[u8]) {
+        for &byte in input {
+            self.xor_byte(self.offset, byte);
+            self.offset += 1;
+            if self.offset == KECCAK256_RATE {
+                self.permute();
+                self.offset = 0;
+            }
+        }
+    }
+
+    /// Pads the input and squeezes the digest into `output`.
+    pub fn finalize(mut self, output: &mut [u8]) {
+        self.xor_byte(self.offset, 0x01);
+        self.xor_byte(KECCAK256_RATE - 1, 0x80);
+        self.permute();
+
+        for (i, byte) in output.iter_mut().enumerate() {
+            let offset = i % KECCAK256_RATE;
+            if i > 0 && offset == 0 {
+                self.permute();
+            }
+            *byte = (self.state[offset / 8] >> (8 * (offset % 8))) as u8;
+        }
+    }
+
+    fn xor_byte(&mut self, offset: usize, byte: u8) {
+        self.state[offset / 8] ^= (byte as u64) << (8 * (offset % 8));
+    }
+
+    fn permute(&mut self) {
+        unsafe {
+            syscall_keccak_permute(&mut self.state);
+        }
+    }
+}
+
+#[cfg(feature = "tiny-keccak")]
+impl tiny_keccak::Hasher for Keccak {
+    fn update(&mut self, input: &[u8]) {
+        Keccak::update(self, input);
+    }
+
+    fn finalize(self, output: &mut [u8]) {
+        Keccak::finalize(self, output);
+    }
+}
+
+/// Computes the Keccak-256 digest of the given input.
+pub fn keccak256(input: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(input);
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_sha512() {
+        assert_eq!(
+            to_hex(&sha512(b"abc")),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        // The padding of a 112-byte input does not fit in its block.
+        assert_eq!(
+            to_hex(&sha512(&[b'a'; 112])),
+            "c01d080efd492776a1c43bd23dd99d0a2e626d481e16782e75d54c2503b5dc32\
+             bd05f0f1ba33e568b88fd2d970929b719ecbb152f58f130a407c8830604b70ca"
+        );
+        assert_eq!(
+            to_hex(&sha512(&[b'a'; 200])),
+            "4b11459c33f52a22ee8236782714c150a3b2c60994e9acee17fe68947a3e6789\
+             f31e7668394592da7bef827cddca88c4e6f86e4df7ed1ae6cba71f3e98faee9f"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Blake3 hashing backed by the `BLAKE3_COMPRESS` precompile.
+
+use crate::syscall_blake3_compress;
+
+/// The Blake3 initialization vector.
+pub const IV: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+
+/// The number of bytes in a block.
+pub const BLOCK_LEN: usize = 64;
+
+/// The number of bytes in a chunk.
+pub const CHUNK_LEN: usize = 1024;
+
+/// Domain separation flag for the first block of a chunk.
+pub const CHUNK_START: u32 = 1 << 0;
+/// Domain separation flag for the last block of a chunk.
+pub const CHUNK_END: u32 = 1 << 1;
+/// Domain separation flag for parent nodes.
+pub const PARENT: u32 = 1 << 2;
+/// Domain separation flag for the root node.
+pub const ROOT: u32 = 1 << 3;
+
+/// The Blake3 compression function.
+///
+/// Returns the full 16 word output. The first 8 words are the new chaining value.
+pub fn compress(
+    chaining_value: &[u32; 8],
+    block: &[u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+) -> [u32; 16] {
+    let mut state = [0u32; 16];
+    state[..8].copy_from_slice(chaining_value);
+    state[8..12].copy_from_slice(&IV[..4]);
+    state[12] = counter as u32;
+    state[13] = (counter >> 32) as u32;
+    state[14] = block_len;
+    state[15] = flags;
+
+    unsafe {
+        syscall_blake3_compress(&mut state, block);
+    }
+
+    for i in 0..8 {
+        state[i] ^= state[i + 8];
+        state[i + 8] ^= chaining_value[i];
+    }
+    state
+}
+
+/// Computes the Blake3 digest of the given input.
+pub fn hash(input: &[u8]) -> [u8; 32] {
+    let num_chunks = core::cmp::max(input.len().div_ceil(CHUNK_LEN), 1);
+
+    // Hash each chunk, merging completed subtrees as soon as they are full.
+    let mut cv_stack: Vec<[u32; 8]> = Vec::new();
+    let mut output = chunk_output(&input[..core::cmp::min(input.len(), CHUNK_LEN)], 0);
+    for chunk_counter in 1..num_chunks {
+        let mut cv = output.chaining_value();
+        let mut total_chunks = chunk_counter;
+        while total_chunks & 1 == 0 {
+            cv = parent_output(&cv_stack.pop().unwrap(), &cv).chaining_value();
+            total_chunks >>= 1;
+        }
+        cv_stack.push(cv);
+
+        let start = chunk_counter * CHUNK_LEN;
+        let end = core::cmp::min(input.len(), start + CHUNK_LEN);
+        output = chunk_output(&input[start..end], chunk_counter as u64);
+    }
+
+    // Merge the remaining subtrees into the root.
+    while let Some(left) = cv_stack.pop() {
+        output = parent_output(&left, &output.chaining_value());
+    }
+
+    let words =
+        compress(&output.chaining_value, &output.block, 0, output.block_len, output.flags | ROOT);
+    let mut digest = [0u8; 32];
+    for (bytes, word) in digest.chunks_exact_mut(4).zip(words) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+/// The inputs to a compression that has not been performed yet, so that the root flag can be
+/// added to it.
+struct Output {
+    chaining_value: [u32; 8],
+    block: [u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+}
+
+impl Output {
+    fn chaining_value(&self) -> [u32; 8] {
+        let words =
+            compress(&self.chaining_value, &self.block, self.counter, self.block_len, self.flags);
+        words[..8].try_into().unwrap()
+    }
+}
+
+fn block_words(block: &[u8]) -> [u32; 16] {
+    let mut bytes = [0u8; BLOCK_LEN];
+    bytes[..block.len()].copy_from_slice(block);
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap()))
+}
+
+fn chunk_output(chunk: &[u8], counter: u64) -> Output {
+    let num_blocks = core::cmp::max(chunk.len().div_ceil(BLOCK_LEN), 1);
+    let mut chaining_value = IV;
+    let mut blocks = chunk.chunks(BLOCK_LEN);
+    for i in 0..num_blocks - 1 {
+        let flags = if i == 0 { CHUNK_START } else { 0 };
+        let block = block_words(blocks.next().unwrap());
+        let words = compress(&chaining_value, &block, counter, BLOCK_LEN as u32, flags);
+        chaining_value = words[..8].try_into().unwrap();
+    }
+
+    let last_block = blocks.next().unwrap_or(&[]);
+    let start_flag = if num_blocks == 1 { CHUNK_START } else { 0 };
+    Output {
+        chaining_value,
+        block: block_words(last_block),
+        counter,
+        block_len: last_block.len() as u32,
+        flags: start_flag | CHUNK_END,
+    }
+}
+
+fn parent_output(left: &[u32; 8], right: &[u32; 8]) -> Output {
+    let mut block = [0u32; 16];
+    block[..8].copy_from_slice(left);
+    block[8..].copy_from_slice(right);
+    Output { chaining_value: IV, block, counter: 0, block_len: BLOCK_LEN as u32, flags: PARENT }
+}
@@ -0,0 +1,39 @@
+//! A Pedersen hash over the Jubjub curve, backed by the `PEDERSEN_HASH` precompile.
+//!
+//! The input bits, least significant bit of each byte first, are split into windows of three
+//! bits, with the last window padded with zeros, and each window adds a multiple of its own base
+//! point to an accumulator that starts at the identity. Inputs of a different length can hash to
+//! the same value, so the input length should be fixed by the application.
+
+use crate::syscall_pedersen_hash;
+
+/// The maximum number of input bytes.
+pub const PEDERSEN_MAX_INPUT_BYTES: usize = 64;
+
+/// The number of input bits in a window.
+const WINDOW_BITS: usize = 3;
+
+/// Computes the Pedersen hash of the input, returning the little endian `x` coordinate of the
+/// result, with one precompile call per window.
+pub fn pedersen_hash(input: &[u8]) -> [u8; 32] {
+    assert!(input.len() <= PEDERSEN_MAX_INPUT_BYTES, "input is too long");
+
+    // The accumulator starts at the identity (0, 1).
+    let mut acc = [0u32; 16];
+    acc[8] = 1;
+
+    let num_bits = 8 * input.len();
+    let bit = |i: usize| if i < num_bits { ((input[i / 8] >> (i % 8)) & 1) as u32 } else { 0 };
+    for (window, start) in (0..num_bits).step_by(WINDOW_BITS).enumerate() {
+        let chunk = bit(start) | (bit(start + 1) << 1) | (bit(start + 2) << 2);
+        unsafe {
+            syscall_pedersen_hash(&mut acc, ((window as u32) << WINDOW_BITS) | chunk);
+        }
+    }
+
+    let mut output = [0u8; 32];
+    for (bytes, word) in output.chunks_exact_mut(4).zip(acc[..8].iter()) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    output
+}
@@ -0,0 +1,74 @@
+//! The Poseidon2 permutation over BabyBear used by the recursion prover, backed by the
+//! `POSEIDON2_PERMUTE` precompile, and the sponge hash of its Merkle trees.
+
+use crate::syscall_poseidon2_permute;
+
+/// The number of BabyBear elements in the state of the permutation.
+pub const POSEIDON2_WIDTH: usize = 16;
+
+/// The number of elements absorbed per permutation, which is also the size of a digest.
+pub const POSEIDON2_RATE: usize = 8;
+
+/// The number of bits of the BabyBear modulus, which the precompile takes as its second argument.
+const BABYBEAR_FIELD_BITS: u32 = 31;
+
+/// Applies the permutation to `state`, which holds BabyBear elements as words. Words that are not
+/// canonical are reduced, and the result is canonical.
+pub fn poseidon2_permute(state: &mut [u32; POSEIDON2_WIDTH]) {
+    unsafe {
+        syscall_poseidon2_permute(state, BABYBEAR_FIELD_BITS);
+    }
+}
+
+/// Hashes the given BabyBear elements with the sponge of the recursion prover, so the digest
+/// matches the hash of a leaf of its Merkle trees.
+///
+/// The sponge starts from the zero state, overwrites the start of the state with each chunk of 8
+/// elements and permutes it, and the digest is the first 8 elements. It has no padding, so an
+/// empty input hashes to zero and inputs that differ by trailing zeros of their last chunk collide,
+/// as in the prover. The elements must be canonical for the digest to be canonical.
+pub fn poseidon2_hash(input: &[u32]) -> [u32; POSEIDON2_RATE] {
+    sponge(input, poseidon2_permute)
+}
+
+fn sponge(
+    input: &[u32],
+    mut permute: impl FnMut(&mut [u32; POSEIDON2_WIDTH]),
+) -> [u32; POSEIDON2_RATE] {
+    let mut state = [0u32; POSEIDON2_WIDTH];
+    for chunk in input.chunks(POSEIDON2_RATE) {
+        state[..chunk.len()].copy_from_slice(chunk);
+        permute(&mut state);
+    }
+    state[..POSEIDON2_RATE].try_into().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::{AbstractField, PrimeField32};
+    use p3_symmetric::Permutation;
+    use sp1_primitives::{poseidon2_hash as host_poseidon2_hash, poseidon2_init};
+
+    use super::*;
+
+    #[test]
+    fn test_poseidon2_sponge() {
+        let perm = poseidon2_init();
+        let permute = |state: &mut [u32; POSEIDON2_WIDTH]| {
+            let output = perm.permute(state.map(BabyBear::from_canonical_u32));
+            *state = output.map(|x| x.as_canonical_u32());
+        };
+
+        // Cover the empty input, partial chunks and inputs that end on a chunk boundary.
+        for len in 0..=3 * POSEIDON2_RATE + 1 {
+            // Shifting keeps the elements below the BabyBear modulus.
+            let input =
+                (0..len as u32).map(|i| i.wrapping_mul(0x9e3779b9) >> 5).collect::<Vec<_>>();
+            let expected = host_poseidon2_hash(
+                input.iter().map(|&x| BabyBear::from_canonical_u32(x)).collect(),
+            );
+            assert_eq!(sponge(&input, permute), expected.map(|x| x.as_canonical_u32()));
+        }
+    }
+}
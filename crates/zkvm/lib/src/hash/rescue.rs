@@ -0,0 +1,23 @@
+//! The Rescue-Prime permutation over the BN254 scalar field, backed by the `RESCUE_PERMUTE`
+//! precompile.
+
+use crate::syscall_rescue_permute;
+
+/// The number of rounds of the permutation.
+pub const RESCUE_NUM_ROUNDS: u32 = 8;
+
+/// Applies the given round of the permutation to `state`, which holds three little endian field
+/// elements. The elements do not need to be reduced, and the result is.
+pub fn rescue_round(state: &mut [[u32; 8]; 3], round: u32) {
+    assert!(round < RESCUE_NUM_ROUNDS, "round {round} is out of range");
+    unsafe {
+        syscall_rescue_permute(state.as_mut_ptr() as *mut [u32; 24], round);
+    }
+}
+
+/// Applies the full permutation to `state`, with one precompile call per round.
+pub fn rescue_permute(state: &mut [[u32; 8]; 3]) {
+    for round in 0..RESCUE_NUM_ROUNDS {
+        rescue_round(state, round);
+    }
+}
@@ -0,0 +1,450 @@
+//! Jacobian coordinates for the short Weierstrass curves with 256-bit coordinates.
+//!
+//! Each addition or doubling of a [`WeierstrassAffinePoint`] is one syscall of the curve's
+//! precompile, which divides by the slope. A [`JacobianPoint`] `(X, Y, Z)` stands for the affine
+//! point `(X / Z^2, Y / Z^3)` and never divides: its operations are a handful of `UINT256_MUL`
+//! syscalls, whose rows are much narrower than those of the curve precompiles, and a sum of many
+//! points is converted back to affine coordinates with a single inversion.
+//!
+//! Which representation is cheaper depends on the curve and on the number of operations. The
+//! `msm` example reports the cycles and precompile rows of both for BN254.
+
+use core::marker::PhantomData;
+
+use crate::{
+    uint256::U256,
+    utils::{WeierstrassAffinePoint, MSM_PIPPENGER_THRESHOLD},
+};
+
+/// A point in Jacobian coordinates on the curve of `C`, where `Z = 0` is the point at infinity.
+///
+/// The coordinates are always reduced modulo the base field.
+pub struct JacobianPoint<C> {
+    x: U256,
+    y: U256,
+    z: U256,
+    _curve: PhantomData<C>,
+}
+
+impl<C> Clone for JacobianPoint<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for JacobianPoint<C> {}
+
+impl<C: WeierstrassAffinePoint<16>> JacobianPoint<C> {
+    /// The point at infinity.
+    pub const IDENTITY: Self =
+        Self { x: U256::ONE, y: U256::ONE, z: U256::ZERO, _curve: PhantomData };
+
+    /// Returns whether `self` is the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.z == U256::ZERO
+    }
+
+    /// Converts an affine point, which takes no syscall.
+    pub fn from_affine(point: &C) -> Self {
+        let limbs = point.limbs_ref();
+        Self {
+            x: U256(limbs[..8].try_into().unwrap()),
+            y: U256(limbs[8..].try_into().unwrap()),
+            z: U256::ONE,
+            _curve: PhantomData,
+        }
+    }
+
+    /// Converts `self` to affine coordinates, or returns `None` for the point at infinity.
+    ///
+    /// This costs one inversion and four multiplications, so a sum should stay in Jacobian
+    /// coordinates until it is complete.
+    pub fn to_affine(&self) -> Option<C> {
+        if self.is_identity() {
+            return None;
+        }
+        let z_inv = invert(&self.z, &modulus::<C>());
+        let z_inv_2 = mul::<C>(&z_inv, &z_inv);
+        let z_inv_3 = mul::<C>(&z_inv_2, &z_inv);
+
+        let mut limbs = [0u32; 16];
+        limbs[..8].copy_from_slice(&mul::<C>(&self.x, &z_inv_2).0);
+        limbs[8..].copy_from_slice(&mul::<C>(&self.y, &z_inv_3).0);
+        Some(C::new(limbs))
+    }
+
+    /// Doubles `self`, with the `dbl-2007-bl` formulas of the Explicit-Formulas Database.
+    ///
+    /// This takes seven multiplications, and two more if the coefficient `a` is nonzero.
+    pub fn double(&mut self) {
+        if self.is_identity() {
+            return;
+        }
+        let xx = mul::<C>(&self.x, &self.x);
+        let yy = mul::<C>(&self.y, &self.y);
+        let yyyy = mul::<C>(&yy, &yy);
+        let zz = mul::<C>(&self.z, &self.z);
+
+        // S = 2 * ((X + YY)^2 - XX - YYYY)
+        let x_plus_yy = add::<C>(&self.x, &yy);
+        let s = sub::<C>(&sub::<C>(&mul::<C>(&x_plus_yy, &x_plus_yy), &xx), &yyyy);
+        let s = add::<C>(&s, &s);
+
+        // M = 3 * XX + a * ZZ^2
+        let mut m = add::<C>(&add::<C>(&xx, &xx), &xx);
+        if C::A.iter().any(|word| *word != 0) {
+            let a = U256(C::A.try_into().unwrap());
+            m = add::<C>(&m, &mul::<C>(&a, &mul::<C>(&zz, &zz)));
+        }
+
+        // X3 = M^2 - 2 * S
+        let x3 = sub::<C>(&sub::<C>(&mul::<C>(&m, &m), &s), &s);
+
+        // Y3 = M * (S - X3) - 8 * YYYY
+        let yyyy_2 = add::<C>(&yyyy, &yyyy);
+        let yyyy_4 = add::<C>(&yyyy_2, &yyyy_2);
+        let yyyy_8 = add::<C>(&yyyy_4, &yyyy_4);
+        let y3 = sub::<C>(&mul::<C>(&m, &sub::<C>(&s, &x3)), &yyyy_8);
+
+        // Z3 = (Y + Z)^2 - YY - ZZ
+        let y_plus_z = add::<C>(&self.y, &self.z);
+        let z3 = sub::<C>(&sub::<C>(&mul::<C>(&y_plus_z, &y_plus_z), &yy), &zz);
+
+        self.x = x3;
+        self.y = y3;
+        self.z = z3;
+    }
+
+    /// Adds `other` to `self`, with the `add-2007-bl` formulas of the Explicit-Formulas Database.
+    ///
+    /// This takes sixteen multiplications. Every input is supported: the sum of a point and itself
+    /// is a doubling, and the sum of a point and its negation is the point at infinity.
+    pub fn add(&mut self, other: &Self) {
+        if other.is_identity() {
+            return;
+        }
+        if self.is_identity() {
+            *self = *other;
+            return;
+        }
+        let z1z1 = mul::<C>(&self.z, &self.z);
+        let z2z2 = mul::<C>(&other.z, &other.z);
+        let u1 = mul::<C>(&self.x, &z2z2);
+        let u2 = mul::<C>(&other.x, &z1z1);
+        let s1 = mul::<C>(&mul::<C>(&self.y, &other.z), &z2z2);
+        let s2 = mul::<C>(&mul::<C>(&other.y, &self.z), &z1z1);
+
+        let h = sub::<C>(&u2, &u1);
+        let r = sub::<C>(&s2, &s1);
+        if h == U256::ZERO {
+            if r == U256::ZERO {
+                self.double();
+            } else {
+                *self = Self::IDENTITY;
+            }
+            return;
+        }
+        let r = add::<C>(&r, &r);
+
+        // I = (2 * H)^2, J = H * I, V = U1 * I
+        let h_2 = add::<C>(&h, &h);
+        let i = mul::<C>(&h_2, &h_2);
+        let j = mul::<C>(&h, &i);
+        let v = mul::<C>(&u1, &i);
+
+        // X3 = r^2 - J - 2 * V
+        let x3 = sub::<C>(&sub::<C>(&sub::<C>(&mul::<C>(&r, &r), &j), &v), &v);
+
+        // Y3 = r * (V - X3) - 2 * S1 * J
+        let s1_j = mul::<C>(&s1, &j);
+        let y3 = sub::<C>(&sub::<C>(&mul::<C>(&r, &sub::<C>(&v, &x3)), &s1_j), &s1_j);
+
+        // Z3 = ((Z1 + Z2)^2 - Z1Z1 - Z2Z2) * H
+        let z1_plus_z2 = add::<C>(&self.z, &other.z);
+        let z3 = sub::<C>(&sub::<C>(&mul::<C>(&z1_plus_z2, &z1_plus_z2), &z1z1), &z2z2);
+        let z3 = mul::<C>(&z3, &h);
+
+        self.x = x3;
+        self.y = y3;
+        self.z = z3;
+    }
+
+    /// Adds the affine point `other` to `self`, with the `madd-2007-bl` formulas of the
+    /// Explicit-Formulas Database.
+    ///
+    /// This takes eleven multiplications, and supports every input like [`JacobianPoint::add`].
+    pub fn add_affine(&mut self, other: &C) {
+        let other = Self::from_affine(other);
+        if self.is_identity() {
+            *self = other;
+            return;
+        }
+        let z1z1 = mul::<C>(&self.z, &self.z);
+        let u2 = mul::<C>(&other.x, &z1z1);
+        let s2 = mul::<C>(&mul::<C>(&other.y, &self.z), &z1z1);
+
+        let h = sub::<C>(&u2, &self.x);
+        let r = sub::<C>(&s2, &self.y);
+        if h == U256::ZERO {
+            if r == U256::ZERO {
+                self.double();
+            } else {
+                *self = Self::IDENTITY;
+            }
+            return;
+        }
+        let r = add::<C>(&r, &r);
+
+        // HH = H^2, I = 4 * HH, J = H * I, V = X1 * I
+        let hh = mul::<C>(&h, &h);
+        let hh_2 = add::<C>(&hh, &hh);
+        let i = add::<C>(&hh_2, &hh_2);
+        let j = mul::<C>(&h, &i);
+        let v = mul::<C>(&self.x, &i);
+
+        // X3 = r^2 - J - 2 * V
+        let x3 = sub::<C>(&sub::<C>(&sub::<C>(&mul::<C>(&r, &r), &j), &v), &v);
+
+        // Y3 = r * (V - X3) - 2 * Y1 * J
+        let y1_j = mul::<C>(&self.y, &j);
+        let y3 = sub::<C>(&sub::<C>(&mul::<C>(&r, &sub::<C>(&v, &x3)), &y1_j), &y1_j);
+
+        // Z3 = (Z1 + H)^2 - Z1Z1 - HH
+        let z1_plus_h = add::<C>(&self.z, &h);
+        let z3 = sub::<C>(&sub::<C>(&mul::<C>(&z1_plus_h, &z1_plus_h), &z1z1), &hh);
+
+        self.x = x3;
+        self.y = y3;
+        self.z = z3;
+    }
+}
+
+/// Computes `sum(scalars[i] * points[i])` in Jacobian coordinates, or `None` if the sum is the
+/// point at infinity.
+///
+/// This is [`crate::utils::AffinePoint::msm`] with the additions and doublings of
+/// [`JacobianPoint`], and a single inversion at the end. The scalars are little endian bit
+/// vectors, possibly of different lengths, and every input is supported.
+pub fn msm_jacobian<C: WeierstrassAffinePoint<16>>(scalars: &[&[bool]], points: &[C]) -> Option<C> {
+    assert_eq!(scalars.len(), points.len());
+    let num_bits = scalars.iter().map(|scalar| scalar.len()).max().unwrap_or(0);
+    let bit = |scalar: &[bool], i: usize| scalar.get(i).copied().unwrap_or(false);
+
+    let mut res = JacobianPoint::<C>::IDENTITY;
+    if points.len() < MSM_PIPPENGER_THRESHOLD {
+        for i in (0..num_bits).rev() {
+            res.double();
+            for (scalar, point) in scalars.iter().zip(points) {
+                if bit(scalar, i) {
+                    res.add_affine(point);
+                }
+            }
+        }
+        return res.to_affine();
+    }
+
+    let window = (points.len().ilog2() as usize - 2).max(1);
+    for start in (0..num_bits).step_by(window).rev() {
+        for _ in 0..window {
+            res.double();
+        }
+
+        // buckets[j] gathers the points whose scalar has the value j + 1 in this window.
+        let mut buckets = vec![JacobianPoint::<C>::IDENTITY; (1 << window) - 1];
+        for (scalar, point) in scalars.iter().zip(points) {
+            let value = (start..start + window)
+                .rev()
+                .fold(0, |value, i| (value << 1) | bit(scalar, i) as usize);
+            if value != 0 {
+                buckets[value - 1].add_affine(point);
+            }
+        }
+
+        // sum((j + 1) * buckets[j]) is the sum of the running sums from the top bucket down.
+        let mut running = JacobianPoint::<C>::IDENTITY;
+        for bucket in buckets.iter().rev() {
+            running.add(bucket);
+            res.add(&running);
+        }
+    }
+    res.to_affine()
+}
+
+fn modulus<C: WeierstrassAffinePoint<16>>() -> U256 {
+    U256(C::MODULUS.try_into().unwrap())
+}
+
+fn mul<C: WeierstrassAffinePoint<16>>(a: &U256, b: &U256) -> U256 {
+    a.mulmod(b, &modulus::<C>())
+}
+
+/// Computes `(a + b) % p` for reduced inputs, without a syscall.
+fn add<C: WeierstrassAffinePoint<16>>(a: &U256, b: &U256) -> U256 {
+    let p = modulus::<C>();
+    let (sum, carry) = add_words(a, b);
+    if carry || !sum.is_reduced(&p) {
+        sub_words(&sum, &p).0
+    } else {
+        sum
+    }
+}
+
+/// Computes `(a - b) % p` for reduced inputs, without a syscall.
+fn sub<C: WeierstrassAffinePoint<16>>(a: &U256, b: &U256) -> U256 {
+    let (difference, borrow) = sub_words(a, b);
+    if borrow {
+        add_words(&difference, &modulus::<C>()).0
+    } else {
+        difference
+    }
+}
+
+/// Computes `a + b` modulo 2^256, and whether it overflowed.
+fn add_words(a: &U256, b: &U256) -> (U256, bool) {
+    let mut sum = [0u32; 8];
+    let mut carry = false;
+    for ((sum, a), b) in sum.iter_mut().zip(a.0).zip(b.0) {
+        let (word, c1) = a.overflowing_add(b);
+        let (word, c2) = word.overflowing_add(carry as u32);
+        *sum = word;
+        carry = c1 || c2;
+    }
+    (U256(sum), carry)
+}
+
+/// Computes `a - b` modulo 2^256, and whether it underflowed.
+fn sub_words(a: &U256, b: &U256) -> (U256, bool) {
+    let mut difference = [0u32; 8];
+    let mut borrow = false;
+    for ((difference, a), b) in difference.iter_mut().zip(a.0).zip(b.0) {
+        let (word, b1) = a.overflowing_sub(b);
+        let (word, b2) = word.overflowing_sub(borrow as u32);
+        *difference = word;
+        borrow = b1 || b2;
+    }
+    (U256(difference), borrow)
+}
+
+/// Computes the inverse of the nonzero `x` modulo the prime `modulus`.
+fn invert(x: &U256, modulus: &U256) -> U256 {
+    #[cfg(target_os = "zkvm")]
+    {
+        U256(crate::uint256::invmod(&x.0, &modulus.0).expect("the modulus is prime"))
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    {
+        use num_bigint::BigUint;
+
+        let modulus = BigUint::from_slice(&modulus.0);
+        let exponent = &modulus - 2u32;
+        let mut words = BigUint::from_slice(&x.0).modpow(&exponent, &modulus).to_u32_digits();
+        words.resize(8, 0);
+        U256(words.try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k256::{elliptic_curve::sec1::ToEncodedPoint, ProjectivePoint, Scalar};
+    use rand::Rng;
+
+    use super::*;
+    use crate::secp256k1::Secp256k1AffinePoint;
+
+    type Point = JacobianPoint<Secp256k1AffinePoint>;
+
+    fn to_affine(point: &ProjectivePoint) -> Secp256k1AffinePoint {
+        let bytes = point.to_affine().to_encoded_point(false);
+        Secp256k1AffinePoint::from_sec1_bytes(bytes.as_bytes()).unwrap()
+    }
+
+    fn assert_eq_point(point: &Point, expected: &ProjectivePoint) {
+        let expected = expected.to_affine().to_encoded_point(false);
+        match point.to_affine() {
+            Some(point) => assert_eq!(point.to_sec1_bytes(false), expected.as_bytes()),
+            None => assert!(expected.is_identity()),
+        }
+    }
+
+    #[test]
+    fn test_add_double() {
+        let mut rng = rand::thread_rng();
+        let p = ProjectivePoint::GENERATOR * Scalar::from(rng.gen::<u64>());
+        let q = ProjectivePoint::GENERATOR * Scalar::from(rng.gen::<u64>());
+
+        let mut sum = Point::from_affine(&to_affine(&p));
+        sum.double();
+        assert_eq_point(&sum, &p.double());
+        sum.add_affine(&to_affine(&q));
+        assert_eq_point(&sum, &(p.double() + q));
+
+        // Both operands have a Z coordinate other than one.
+        let mut other = Point::from_affine(&to_affine(&q));
+        other.double();
+        sum.add(&other);
+        assert_eq_point(&sum, &(p.double() + q.double() + q));
+
+        // The sum of a point and itself, and of a point and its negation.
+        let mut point = Point::from_affine(&to_affine(&p));
+        point.double();
+        let mut doubled = point;
+        doubled.add(&point);
+        assert_eq_point(&doubled, &p.double().double());
+        let mut point = Point::from_affine(&to_affine(&p));
+        point.add_affine(&to_affine(&p));
+        assert_eq_point(&point, &p.double());
+        point.add_affine(&to_affine(&-p.double()));
+        assert!(point.is_identity());
+
+        let mut identity = Point::IDENTITY;
+        identity.double();
+        identity.add(&Point::IDENTITY);
+        assert!(identity.to_affine().is_none());
+        identity.add_affine(&to_affine(&p));
+        assert_eq_point(&identity, &p);
+    }
+
+    #[test]
+    fn test_msm_jacobian() {
+        let mut rng = rand::thread_rng();
+        for num_points in [0, 1, 5, 8, 40] {
+            let mut points: Vec<ProjectivePoint> = (0..num_points)
+                .map(|_| ProjectivePoint::GENERATOR * Scalar::from(rng.gen::<u64>()))
+                .collect();
+            if num_points >= 5 {
+                // A repeated point, and a point next to its negation.
+                points[1] = points[0];
+                points[3] = -points[2];
+            }
+            let scalars: Vec<u64> = (0..num_points)
+                .map(|i| match i % 4 {
+                    0 => 0,
+                    1 => rng.gen_range(0..1 << 8),
+                    _ => rng.gen(),
+                })
+                .collect();
+
+            let bits: Vec<Vec<bool>> = scalars
+                .iter()
+                .map(|scalar| {
+                    (0..64 - scalar.leading_zeros()).map(|i| (scalar >> i) & 1 == 1).collect()
+                })
+                .collect();
+            let bits: Vec<&[bool]> = bits.iter().map(Vec::as_slice).collect();
+            let affine: Vec<Secp256k1AffinePoint> = points.iter().map(to_affine).collect();
+
+            let expected = points
+                .iter()
+                .zip(&scalars)
+                .fold(ProjectivePoint::IDENTITY, |sum, (point, scalar)| {
+                    sum + point * &Scalar::from(*scalar)
+                });
+            let mut result = Point::IDENTITY;
+            if let Some(point) = msm_jacobian(&bits, &affine) {
+                result = Point::from_affine(&point);
+            }
+            assert_eq_point(&result, &expected);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Verification of KZG opening proofs over BLS12-381.
+//!
+//! The points use the encodings of EIP-2537: a G1 point is `(x, y)` and a G2 point is
+//! `(x.c0, x.c1, y.c0, y.c1)`, each coordinate a 48-byte big endian integer left padded with 16
+//! zero bytes, and all zeros encode the point at infinity. The evaluation point and the value are
+//! 32-byte big endian elements of the scalar field, as in EIP-4844.
+//!
+//! The group operations in G1 use the BLS12-381 precompiles, and the pairing check is the one of
+//! [`crate::bls12381::pairing`], whose Miller loop is shared by both pairings.
+
+use crate::{
+    bls12381::{
+        decode_g1,
+        pairing::{decode_g2, pairing_check, Fp, G2Affine},
+        Bls12381AffinePoint,
+    },
+    utils::{AffinePoint, WeierstrassAffinePoint},
+};
+
+/// The order `r` of the scalar field, as little endian words.
+const SCALAR_MODULUS: [u32; 8] = [
+    0x00000001, 0xffffffff, 0xfffe5bfe, 0x53bda402, 0x09a1d805, 0x3339d808, 0x299d7d48, 0x73eda753,
+];
+
+/// Verifies that the polynomial committed to by `commitment` takes the value `y` at `z`.
+///
+/// With the quotient `q(X) = (f(X) - y) / (X - z)` committed to by `proof`, this checks that
+/// `e(C - [y] G1 + [z] proof, G2) * e(-proof, [tau] G2) = 1`, which only needs scalar
+/// multiplications in G1. `tau_g2` is the point `[tau] G2` of the trusted setup.
+///
+/// Returns `false` if a point is not reduced, not on its curve or not in its subgroup, or if `z`
+/// or `y` is not reduced.
+pub fn verify_proof(
+    commitment: &[u8; 128],
+    z: &[u8; 32],
+    y: &[u8; 32],
+    proof: &[u8; 128],
+    tau_g2: &[u8; 256],
+) -> bool {
+    let (Some(commitment), Some(proof), Some(z), Some(y), Some(tau_g2)) =
+        (g1(commitment), g1(proof), scalar(z), scalar(y), decode_g2(tau_g2))
+    else {
+        return false;
+    };
+
+    // C - [y] G1 + [z] proof.
+    let mut generator = Bls12381AffinePoint::new(Bls12381AffinePoint::GENERATOR);
+    generator.negate();
+    let mut scalars = vec![y.as_slice()];
+    let mut points = vec![generator];
+    if let Some(proof) = proof {
+        scalars.push(z.as_slice());
+        points.push(proof);
+    }
+    let mut lhs = Bls12381AffinePoint::msm(&scalars, &points);
+    if let Some(commitment) = commitment {
+        Bls12381AffinePoint::accumulate(&mut lhs, &commitment);
+    }
+
+    // Pairings with the point at infinity are one, so they are left out.
+    let mut pairs = Vec::with_capacity(2);
+    if let Some(lhs) = lhs {
+        pairs.push(pair(&lhs, &G2Affine::generator()));
+    }
+    if let (Some(mut proof), Some(tau_g2)) = (proof, tau_g2) {
+        proof.negate();
+        pairs.push(pair(&proof, &tau_g2));
+    }
+    pairing_check(&pairs)
+}
+
+/// Decodes a G1 point and checks that it is in the subgroup of order `r`, which is the case
+/// exactly when `r * P` is the point at infinity.
+fn g1(bytes: &[u8; 128]) -> Option<Option<Bls12381AffinePoint>> {
+    let point = decode_g1(bytes)?;
+    if let Some(point) = point {
+        let mut multiple = point;
+        multiple.mul_assign_complete(&SCALAR_MODULUS);
+        if multiple.0 != Bls12381AffinePoint::IDENTITY {
+            return None;
+        }
+    }
+    Some(point)
+}
+
+/// Decodes a big endian scalar as little endian bits, or returns `None` if it is not reduced.
+fn scalar(bytes: &[u8; 32]) -> Option<Vec<bool>> {
+    let mut words = [0u32; 8];
+    for (word, chunk) in words.iter_mut().zip(bytes.rchunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    if !words.iter().rev().lt(SCALAR_MODULUS.iter().rev()) {
+        return None;
+    }
+    Some((0..256).map(|i| (words[i / 32] >> (i % 32)) & 1 == 1).collect())
+}
+
+fn pair(p: &Bls12381AffinePoint, q: &G2Affine) -> (Fp, Fp, G2Affine) {
+    let (x, y) = p.0.split_at(12);
+    (x.try_into().unwrap(), y.try_into().unwrap(), *q)
+}
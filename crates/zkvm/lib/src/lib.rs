@@ -3,15 +3,32 @@
 //! Documentation for these syscalls can be found in the zkVM entrypoint
 //! `sp1_zkvm::syscalls` module.
 
+pub mod aes;
+pub mod babyjubjub;
+pub mod bignum;
+pub mod bits;
 pub mod bls12381;
 pub mod bn254;
+pub mod chacha20;
+pub mod cycle_tracker;
 pub mod ed25519;
+pub mod groth16;
+pub mod grumpkin;
+pub mod hash;
 pub mod io;
+pub mod jacobian;
+pub mod kzg;
+pub mod modexp;
+pub mod p256;
+pub mod p384;
 pub mod secp256k1;
+pub mod transcript;
+pub mod uint256;
 pub mod unconstrained;
 pub mod utils;
 #[cfg(feature = "verify")]
 pub mod verify;
+pub mod x25519;
 
 extern "C" {
     /// Halts the program with the given exit code.
@@ -32,36 +49,192 @@ extern "C" {
     /// Executes an Ed25519 curve addition on the given points.
     pub fn syscall_ed_add(p: *mut [u32; 16], q: *const [u32; 16]);
 
-    /// Executes an Ed25519 curve decompression on the given point.
+    /// Executes an Ed25519 curve decompression on the given point, setting every bit of x if the
+    /// encoding is not a valid point.
     pub fn syscall_ed_decompress(point: &mut [u8; 64]);
 
+    /// Executes a step of the X25519 Montgomery ladder on the given state.
+    pub fn syscall_x25519_ladder_step(state: *mut [u32; 32], u: *const [u32; 8]);
+
+    /// Executes an Ed25519 signature verification on the given input.
+    pub fn syscall_ed25519_verify(input: *mut [u32; 49]);
+
     /// Executes an Sepc256k1 curve addition on the given points.
     pub fn syscall_secp256k1_add(p: *mut [u32; 16], q: *const [u32; 16]);
 
     /// Executes an Secp256k1 curve doubling on the given point.
     pub fn syscall_secp256k1_double(p: *mut [u32; 16]);
 
+    /// Executes an Secp256k1 scalar multiplication on the given point.
+    pub fn syscall_secp256k1_mul(p: *mut [u32; 16], scalar: *const [u32; 8]);
+
+    /// Executes an Secp256k1 multi-scalar multiplication on the given (scalar, point) pairs.
+    pub fn syscall_secp256k1_msm(pairs: *mut [u32; 24], num_pairs: u32);
+
+    /// Executes an Secp256k1 linear combination on the given (scalar pointer, point pointer)
+    /// entries.
+    pub fn syscall_ec_linear_combination(pairs: *const [u32; 2], num_pairs: u32);
+
+    /// Executes an Secp256k1 ECDSA signature verification on the given input.
+    pub fn syscall_secp256k1_ecdsa_verify(input: *mut [u32; 41]);
+
     /// Executes an Secp256k1 curve decompression on the given point.
     pub fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: bool);
 
+    /// Executes a P-256 curve addition on the given points.
+    pub fn syscall_p256_add(p: *mut [u32; 16], q: *const [u32; 16]);
+
+    /// Executes a P-256 curve doubling on the given point.
+    pub fn syscall_p256_double(p: *mut [u32; 16]);
+
+    /// Executes a P-256 scalar multiplication on the given point.
+    pub fn syscall_p256_mul(p: *mut [u32; 16], scalar: *const [u32; 8]);
+
+    /// Executes a P-256 curve decompression on the given point.
+    pub fn syscall_secp256r1_decompress(point: &mut [u8; 64], is_odd: bool);
+
+    /// Executes a P-384 curve addition on the given points.
+    pub fn syscall_p384_add(p: *mut [u32; 24], q: *const [u32; 24]);
+
+    /// Executes a P-384 curve doubling on the given point.
+    pub fn syscall_p384_double(p: *mut [u32; 24]);
+
+    /// Executes a P-384 curve decompression on the given point.
+    pub fn syscall_secp384r1_decompress(point: &mut [u8; 96], is_odd: bool);
+
+    /// Executes a Grumpkin curve addition on the given points.
+    pub fn syscall_grumpkin_add(p: *mut [u32; 16], q: *const [u32; 16]);
+
+    /// Executes a Grumpkin curve doubling on the given point.
+    pub fn syscall_grumpkin_double(p: *mut [u32; 16]);
+
+    /// Executes a BabyJubJub curve addition on the given points.
+    pub fn syscall_babyjubjub_add(p: *mut [u32; 16], q: *const [u32; 16]);
+
+    /// Executes a BabyJubJub curve doubling on the given point.
+    pub fn syscall_babyjubjub_double(p: *mut [u32; 16]);
+
     /// Executes a Bn254 curve addition on the given points.
     pub fn syscall_bn254_add(p: *mut [u32; 16], q: *const [u32; 16]);
 
     /// Executes a Bn254 curve doubling on the given point.
     pub fn syscall_bn254_double(p: *mut [u32; 16]);
 
+    /// Executes a Bn254 G2 addition on the given points.
+    pub fn syscall_bn254_g2_add(p: *mut [u32; 32], q: *const [u32; 32]);
+
+    /// Executes a Bn254 G2 doubling on the given point.
+    pub fn syscall_bn254_g2_double(p: *mut [u32; 32]);
+
     /// Executes a BLS12-381 curve addition on the given points.
     pub fn syscall_bls12381_add(p: *mut [u32; 24], q: *const [u32; 24]);
 
     /// Executes a BLS12-381 curve doubling on the given point.
     pub fn syscall_bls12381_double(p: *mut [u32; 24]);
 
+    /// Executes a BLS12-381 G2 addition on the given points.
+    pub fn syscall_bls12381_g2_add(p: *mut [u32; 48], q: *const [u32; 48]);
+
+    /// Executes a BLS12-381 G2 doubling on the given point.
+    pub fn syscall_bls12381_g2_double(p: *mut [u32; 48]);
+
     /// Executes the Keccak-256 permutation on the given state.
     pub fn syscall_keccak_permute(state: *mut [u64; 25]);
 
+    /// Executes the rounds of the Blake3 compression function on the given state.
+    pub fn syscall_blake3_compress(state: *mut [u32; 16], message: *const [u32; 16]);
+
+    /// Executes the Blake2b compression function on the given state and block.
+    pub fn syscall_blake2b_compress(state: *mut [u64; 8], block: *const [u32; 37]);
+
+    /// Executes the RIPEMD-160 compression function on the given state and block.
+    pub fn syscall_ripemd160_compress(state: *mut [u32; 5], block: *const [u32; 16]);
+
     /// Executes an uint256 multiplication on the given inputs.
     pub fn syscall_uint256_mulmod(x: *mut [u32; 8], y: *const [u32; 8]);
 
+    /// Executes an uint256 modular addition on the given inputs.
+    pub fn syscall_uint256_addmod(x: *mut [u32; 8], y: *const [u32; 8]);
+
+    /// Executes an uint256 modular subtraction on the given inputs.
+    pub fn syscall_uint256_submod(x: *mut [u32; 8], y: *const [u32; 8]);
+
+    /// Executes an uint384 multiplication on the given inputs.
+    pub fn syscall_uint384_mulmod(x: *mut [u32; 12], y: *const [u32; 12]);
+
+    /// Executes an uint512 multiplication on the given inputs.
+    pub fn syscall_uint512_mulmod(x: *mut [u32; 16], y: *const [u32; 16]);
+
+    /// Executes an uint256 modular inverse on the given input. The result is zero if the input is
+    /// not invertible.
+    pub fn syscall_uint256_invmod(x: *mut [u32; 8], modulus: *const [u32; 8]);
+
+    /// Executes an uint256 modular squaring on the given input.
+    pub fn syscall_uint256_square(x: *mut [u32; 8], modulus: *const [u32; 8]);
+
+    /// Executes an uint256 widening multiplication, writing the 512-bit product over `x`.
+    pub fn syscall_uint256_mul_wide(x: *mut [u32; 16], y: *const [u32; 8]);
+
+    /// Executes an uint256 division, writing the quotient over `x` and the remainder after the
+    /// divisor.
+    pub fn syscall_uint256_divrem(x: *mut [u32; 8], divisor_and_remainder: *mut [u32; 16]);
+
+    /// Executes an uint256 Montgomery multiplication, with the modulus stored after `y`.
+    pub fn syscall_uint256_mont_mul(x: *mut [u32; 8], y: *const [u32; 8]);
+
+    /// Executes an uint256 modular exponentiation, with the modulus stored after `exponent`.
+    pub fn syscall_uint256_mod_exp(base: *mut [u32; 8], exponent: *const [u32; 8]);
+
+    /// Checks whether an uint256 is less than `prime`, writing zero after the value if it is and
+    /// one otherwise.
+    pub fn syscall_uint256_range_check(value_and_result: *mut [u32; 9], prime: *const [u32; 8]);
+
+    /// Compares two uint256, writing -1, 0 or 1 after `x` as it is less than, equal to or greater
+    /// than `y`.
+    pub fn syscall_uint256_cmp(x_and_result: *mut [u32; 9], y: *const [u32; 8]);
+
+    /// Computes the bitwise and of two uint256, writing the result over `x`.
+    pub fn syscall_uint256_and(x: *mut [u32; 8], y: *const [u32; 8]);
+
+    /// Computes the bitwise or of two uint256, writing the result over `x`.
+    pub fn syscall_uint256_or(x: *mut [u32; 8], y: *const [u32; 8]);
+
+    /// Computes the bitwise xor of two uint256, writing the result over `x`.
+    pub fn syscall_uint256_xor(x: *mut [u32; 8], y: *const [u32; 8]);
+
+    /// Executes the Poseidon2 permutation over BabyBear on the given state.
+    pub fn syscall_poseidon2_permute(state: *mut [u32; 16], field_bits: u32);
+
+    /// Executes a round of the Rescue-Prime permutation over the BN254 scalar field.
+    pub fn syscall_rescue_permute(state: *mut [u32; 24], round: u32);
+
+    /// Executes a window of a Pedersen hash over the Jubjub curve on the given accumulator.
+    pub fn syscall_pedersen_hash(acc: *mut [u32; 16], input: u32);
+
+    /// Executes AES-128 encryption of `input` under `key`, writing the ciphertext to `output`.
+    pub fn syscall_aes128_encrypt(
+        key: *const [u32; 4],
+        input: *const [u32; 4],
+        output: *mut [u32; 4],
+    );
+
+    /// Computes the ChaCha20 keystream block for the given state, overwriting the state.
+    pub fn syscall_chacha20_block(state: *mut [u32; 16]);
+
+    /// Counts the set bits of the given 64-bit value, writing the count over its low word.
+    pub fn syscall_popcount(value: *mut [u32; 2]);
+
+    /// Counts the leading zeros of the given word, writing the count over it.
+    pub fn syscall_clz32(value: *mut u32);
+
+    /// Verifies a SHA-256 Merkle path of the given depth, writing one to the result word if it
+    /// leads to the root and zero otherwise.
+    pub fn syscall_merkle_verify_path(input: *mut u32, depth: u32);
+
+    /// Decommits a FRI query against a Poseidon2 commitment of the given depth, writing one to the
+    /// result word if it leads to the root and zero otherwise.
+    pub fn syscall_fri_decommit(input: *mut u32, depth: u32);
+
     /// Enters unconstrained mode.
     pub fn syscall_enter_unconstrained() -> bool;
 
@@ -77,6 +250,12 @@ extern "C" {
     /// Reads the next element in the hint stream into the given buffer.
     pub fn syscall_hint_read(ptr: *mut u8, len: usize);
 
+    /// Opens a cycle tracker region with the given label hash.
+    pub fn syscall_cycle_tracker_start(label_hash: u32);
+
+    /// Closes the innermost open cycle tracker region with the given label hash.
+    pub fn syscall_cycle_tracker_end(label_hash: u32);
+
     /// Allocates a buffer aligned to the given alignment.
     pub fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u8;
 
@@ -84,6 +263,9 @@ extern "C" {
     pub fn syscall_bls12381_decompress(point: &mut [u8; 96], is_odd: bool);
 
     /// Computes a big integer operation with a modulus.
+    ///
+    /// An `op` of 1 computes `(x + y) % modulus`, an `op` of 2 computes `(x - y) % modulus`, and
+    /// any other value computes `(x * y) % modulus`.
     pub fn sys_bigint(
         result: *mut [u32; 8],
         op: u32,
@@ -119,6 +301,43 @@ extern "C" {
     /// Executes a BN254 field multiplication on the given inputs.
     pub fn syscall_bn254_fp_mulmod(p: *mut u32, q: *const u32);
 
+    /// Executes a BN254 scalar field addition on the given inputs.
+    pub fn syscall_bn254_fr_addmod(p: *mut u32, q: *const u32);
+
+    /// Executes a BN254 scalar field subtraction on the given inputs.
+    pub fn syscall_bn254_fr_submod(p: *mut u32, q: *const u32);
+
+    /// Executes a BN254 scalar field multiplication on the given inputs.
+    pub fn syscall_bn254_fr_mulmod(p: *mut u32, q: *const u32);
+
+    /// Inverts the given BLS12-381 field elements in place, leaving the zero elements as zero.
+    pub fn syscall_bls12381_fp_batch_inverse(p: *mut u32, len: u32);
+
+    /// Inverts the given BN254 field elements in place, leaving the zero elements as zero.
+    pub fn syscall_bn254_fp_batch_inverse(p: *mut u32, len: u32);
+
+    /// Inverts the given BN254 scalar field elements in place, leaving the zero elements as zero.
+    pub fn syscall_bn254_fr_batch_inverse(p: *mut u32, len: u32);
+
+    /// Applies a number theoretic transform in place to the given coefficients.
+    pub fn syscall_ntt(coeffs: *mut u32, params: *const [u32; 3]);
+
+    /// Applies an inverse number theoretic transform in place to the given evaluations.
+    pub fn syscall_intt(coeffs: *mut u32, params: *const [u32; 3]);
+
+    /// Writes the SHA-256 digest of the message given by a pointer and a length to the result.
+    pub fn syscall_sha256_hash(result: *mut [u32; 8], params: *const [u32; 2]);
+
+    /// Evaluates a polynomial at a point, and writes the result over the point in the parameters.
+    pub fn syscall_poly_eval(coeffs: *const u32, params: *mut [u32; 3]);
+
+    /// Evaluates a polynomial at each of `count` points, and writes the results over the points.
+    pub fn syscall_poly_multi_eval(params: *const [u32; 4], count: u32);
+
+    /// Evaluates the polynomial through a set of points at a target, and writes the result over
+    /// the target in the parameters.
+    pub fn syscall_lagrange_interp(points: *const u32, params: *mut [u32; 3]);
+
     /// Executes a BN254 Fp2 addition on the given inputs.
     pub fn syscall_bn254_fp2_addmod(p: *mut u32, q: *const u32);
 
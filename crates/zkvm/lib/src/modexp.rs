@@ -0,0 +1,211 @@
+//! Modular exponentiation, as used by the EVM `MODEXP` precompile at address 0x05.
+
+use crate::{syscall_uint256_addmod, syscall_uint256_mulmod, syscall_uint256_square};
+
+/// The number of words in a 256-bit operand.
+const N: usize = 8;
+
+/// Computes `base^exponent % modulus` for little endian 256-bit operands.
+///
+/// Squarings and multiplications go through the `UINT256_SQUARE_MOD` and `UINT256_MUL`
+/// precompiles. As with those precompiles, a zero modulus is interpreted as 2^256. The base does
+/// not need to be reduced, and an exponent of zero gives `1 % modulus`.
+pub fn modexp_u256(base: &[u32; N], exponent: &[u32; N], modulus: &[u32; N]) -> [u32; N] {
+    let mut base = *base;
+    mulmod_u256(&mut base, &one_u256(), modulus);
+    let exponent_bits =
+        exponent.iter().rev().flat_map(|word| (0..32).rev().map(move |i| (word >> i) & 1 == 1));
+    pow_u256(&base, exponent_bits, modulus)
+}
+
+/// Computes `base^exponent % modulus` on big endian byte strings of arbitrary length, with the
+/// semantics of the EVM `MODEXP` precompile.
+///
+/// The result has the same length as the modulus, and a zero modulus gives zero. Moduli that fit in
+/// 256 bits use the uint256 precompiles; larger moduli fall back to a software implementation.
+pub fn modexp(base: &[u8], exponent: &[u8], modulus: &[u8]) -> Vec<u8> {
+    let significant_modulus = strip_leading_zeros(modulus);
+    let exponent_bits = bits_be(exponent);
+
+    let result = if significant_modulus.is_empty() {
+        vec![]
+    } else if significant_modulus.len() <= N * 4 {
+        let modulus_words: [u32; N] =
+            be_bytes_to_words_le(significant_modulus, N).try_into().unwrap();
+        let base = reduce_u256(base, &modulus_words);
+        pow_u256(&base, exponent_bits, &modulus_words).to_vec()
+    } else {
+        software_modexp(base, exponent_bits, significant_modulus)
+    };
+
+    let mut output = vec![0u8; modulus.len()];
+    let result_bytes = words_le_to_be_bytes(&result);
+    let result_bytes = &result_bytes[result_bytes.len().saturating_sub(modulus.len())..];
+    output[modulus.len() - result_bytes.len()..].copy_from_slice(result_bytes);
+    output
+}
+
+/// Square-and-multiply over the exponent bits, most significant first. The base must be reduced.
+fn pow_u256(
+    base: &[u32; N],
+    exponent_bits: impl Iterator<Item = bool>,
+    modulus: &[u32; N],
+) -> [u32; N] {
+    // Reducing the initial accumulator handles a modulus of one.
+    let mut result = one_u256();
+    mulmod_u256(&mut result, &one_u256(), modulus);
+
+    for bit in exponent_bits.skip_while(|bit| !bit) {
+        unsafe {
+            syscall_uint256_square(&mut result, modulus);
+        }
+        if bit {
+            mulmod_u256(&mut result, base, modulus);
+        }
+    }
+    result
+}
+
+/// Reduces a big endian byte string of arbitrary length modulo a nonzero 256-bit modulus.
+fn reduce_u256(value: &[u8], modulus: &[u32; N]) -> [u32; N] {
+    // Everything is zero modulo one, which `UINT256_ADD_MOD` does not support.
+    if *modulus == one_u256() {
+        return [0; N];
+    }
+
+    // 2^256 % modulus, computed as 2^128 * 2^128.
+    let mut shift = [0u32; N];
+    shift[N / 2] = 1;
+    let two_pow_128 = shift;
+    mulmod_u256(&mut shift, &two_pow_128, modulus);
+
+    // Horner's method over 32-byte chunks, most significant first.
+    let value = strip_leading_zeros(value);
+    let first_chunk_len = match value.len() % (N * 4) {
+        0 => N * 4,
+        len => len,
+    };
+    let (first_chunk, rest) = value.split_at(core::cmp::min(first_chunk_len, value.len()));
+
+    let mut result: [u32; N] = be_bytes_to_words_le(first_chunk, N).try_into().unwrap();
+    mulmod_u256(&mut result, &one_u256(), modulus);
+    for chunk in rest.chunks(N * 4) {
+        let chunk: [u32; N] = be_bytes_to_words_le(chunk, N).try_into().unwrap();
+        mulmod_u256(&mut result, &shift, modulus);
+        let mut chunk_and_modulus = [0u32; 2 * N];
+        chunk_and_modulus[..N].copy_from_slice(&chunk);
+        chunk_and_modulus[N..].copy_from_slice(modulus);
+        unsafe {
+            syscall_uint256_addmod(&mut result, chunk_and_modulus.as_ptr() as *const [u32; N]);
+        }
+    }
+    result
+}
+
+fn mulmod_u256(x: &mut [u32; N], y: &[u32; N], modulus: &[u32; N]) {
+    let mut y_and_modulus = [0u32; 2 * N];
+    y_and_modulus[..N].copy_from_slice(y);
+    y_and_modulus[N..].copy_from_slice(modulus);
+    unsafe {
+        syscall_uint256_mulmod(x, y_and_modulus.as_ptr() as *const [u32; N]);
+    }
+}
+
+fn one_u256() -> [u32; N] {
+    let mut one = [0u32; N];
+    one[0] = 1;
+    one
+}
+
+/// A bit-serial implementation for moduli that don't fit the precompiles. The modulus must be
+/// nonzero and have no leading zero bytes.
+fn software_modexp(
+    base: &[u8],
+    exponent_bits: impl Iterator<Item = bool>,
+    modulus: &[u8],
+) -> Vec<u32> {
+    let num_words = modulus.len().div_ceil(4);
+    let modulus = be_bytes_to_words_le(modulus, num_words);
+    let zero = vec![0u32; num_words];
+    let mut one = zero.clone();
+    one[0] = 1;
+    if modulus == one {
+        return zero;
+    }
+
+    // Reduce the base one bit at a time.
+    let mut reduced_base = zero.clone();
+    for bit in bits_be(base) {
+        reduced_base = add_mod(&reduced_base, &reduced_base, &modulus);
+        if bit {
+            reduced_base = add_mod(&reduced_base, &one, &modulus);
+        }
+    }
+
+    let mut result = one;
+    for bit in exponent_bits.skip_while(|bit| !bit) {
+        result = mul_mod(&result, &result, &modulus);
+        if bit {
+            result = mul_mod(&result, &reduced_base, &modulus);
+        }
+    }
+    result
+}
+
+/// Computes `(a * b) % modulus` by double-and-add. Both operands must be reduced.
+fn mul_mod(a: &[u32], b: &[u32], modulus: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; modulus.len()];
+    for bit in b.iter().rev().flat_map(|word| (0..32).rev().map(move |i| (word >> i) & 1 == 1)) {
+        result = add_mod(&result, &result, modulus);
+        if bit {
+            result = add_mod(&result, a, modulus);
+        }
+    }
+    result
+}
+
+/// Computes `(a + b) % modulus`. Both operands must be reduced.
+fn add_mod(a: &[u32], b: &[u32], modulus: &[u32]) -> Vec<u32> {
+    let mut sum = vec![0u32; modulus.len()];
+    let mut carry = false;
+    for i in 0..modulus.len() {
+        let (s1, c1) = a[i].overflowing_add(b[i]);
+        let (s2, c2) = s1.overflowing_add(carry as u32);
+        sum[i] = s2;
+        carry = c1 || c2;
+    }
+
+    let at_least_modulus = sum.iter().rev().cmp(modulus.iter().rev()) != core::cmp::Ordering::Less;
+    if carry || at_least_modulus {
+        let mut borrow = false;
+        for i in 0..modulus.len() {
+            let (d1, b1) = sum[i].overflowing_sub(modulus[i]);
+            let (d2, b2) = d1.overflowing_sub(borrow as u32);
+            sum[i] = d2;
+            borrow = b1 || b2;
+        }
+    }
+    sum
+}
+
+fn bits_be(bytes: &[u8]) -> impl Iterator<Item = bool> + '_ {
+    bytes.iter().flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+}
+
+fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|&byte| byte != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+/// Converts big endian bytes to `num_words` little endian words. The bytes must fit.
+fn be_bytes_to_words_le(bytes: &[u8], num_words: usize) -> Vec<u32> {
+    let mut words = vec![0u32; num_words];
+    for (i, &byte) in bytes.iter().rev().enumerate() {
+        words[i / 4] |= (byte as u32) << (8 * (i % 4));
+    }
+    words
+}
+
+fn words_le_to_be_bytes(words: &[u32]) -> Vec<u8> {
+    words.iter().rev().flat_map(|word| word.to_be_bytes()).collect()
+}
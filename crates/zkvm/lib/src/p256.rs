@@ -0,0 +1,171 @@
+use std::io::ErrorKind;
+
+use crate::{
+    syscall_p256_add, syscall_p256_double, syscall_p256_mul, syscall_secp256r1_decompress,
+    uint256::U256,
+    utils::{
+        AffinePoint, MulAssignError, PointError, WeierstrassAffinePoint, DEFAULT_MUL_WINDOW,
+        MAX_GENERATOR_TABLE_BYTES,
+    },
+};
+
+/// The number of limbs in [P256AffinePoint].
+pub const N: usize = 16;
+
+/// An affine point on the P-256 curve.
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct P256AffinePoint(pub [u32; N]);
+
+impl AffinePoint<N> for P256AffinePoint {
+    /// The values are taken from https://neuromancer.sk/std/nist/P-256.
+    const GENERATOR: [u32; N] = [
+        3633889942, 4104206661, 770388896, 1996717441, 1671708914, 4173129445, 3777774151,
+        1796723186, 935285237, 3417718888, 1798397646, 734933847, 2081398294, 2397563722,
+        4263149467, 1340293858,
+    ];
+
+    const GENERATOR_TABLE: &'static [[u32; N]] = &GENERATOR_MULTIPLES;
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(limbs)
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        &self.0
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        &mut self.0
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_p256_add(a, b);
+        }
+    }
+
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_p256_double(a);
+        }
+    }
+
+    fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        self.mul_assign_windowed(scalar, DEFAULT_MUL_WINDOW)
+    }
+}
+
+impl WeierstrassAffinePoint<N> for P256AffinePoint {
+    const MODULUS: &'static [u32] = &[
+        0xffffffff, 0xffffffff, 0xffffffff, 0x00000000, 0x00000000, 0x00000000, 0x00000001,
+        0xffffffff,
+    ];
+
+    const A: &'static [u32] = &[
+        0xfffffffc, 0xffffffff, 0xffffffff, 0x00000000, 0x00000000, 0x00000000, 0x00000001,
+        0xffffffff,
+    ];
+
+    const B: &'static [u32] = &[
+        0x27d2604b, 0x3bce3c3e, 0xcc53b0f6, 0x651d06b0, 0x769886bc, 0xb3ebbd55, 0xaa3a93e7,
+        0x5ac635d8,
+    ];
+
+    fn field_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let modulus = U256(Self::MODULUS.try_into().unwrap());
+        U256(a.try_into().unwrap()).mulmod(&U256(b.try_into().unwrap()), &modulus).0.to_vec()
+    }
+
+    /// Inside the zkVM, the square root is computed by the secp256r1 decompress precompile.
+    fn decompress(x: &[u32], is_odd: bool) -> Result<Self, PointError> {
+        #[cfg(target_os = "zkvm")]
+        {
+            let x = U256(x.try_into().map_err(|_| PointError::InvalidLength)?);
+            if !x.is_reduced(&U256(Self::MODULUS.try_into().unwrap())) {
+                return Err(PointError::NonCanonical);
+            }
+            let mut compressed_key = [0u8; 33];
+            compressed_key[0] = 0x02 | is_odd as u8;
+            compressed_key[1..].copy_from_slice(&x.to_be_bytes());
+            Secp256r1Point::decompress_pubkey(&compressed_key)
+                .map(|point| point.to_affine())
+                .map_err(|_| PointError::NotOnCurve)
+        }
+
+        #[cfg(not(target_os = "zkvm"))]
+        crate::utils::decompress_with_sqrt(x, is_odd)
+    }
+}
+
+/// Multiplies `point` by `scalar` in a single syscall.
+///
+/// The scalar is little endian, and must be nonzero and less than the order of the curve.
+pub fn mul(point: &mut P256AffinePoint, scalar: &[u8; 32]) {
+    let scalar: [u32; 8] =
+        core::array::from_fn(|i| u32::from_le_bytes(scalar[4 * i..4 * i + 4].try_into().unwrap()));
+    unsafe {
+        syscall_p256_mul(&mut point.0, &scalar);
+    }
+}
+
+/// The multiples `2^i * G` of the generator for every bit of a 256-bit scalar, generated by the
+/// build script.
+const GENERATOR_MULTIPLES: [[u32; N]; 256] =
+    include!(concat!(env!("OUT_DIR"), "/p256_generator_table.rs"));
+
+const _: () = assert!(core::mem::size_of::<[[u32; N]; 256]>() <= MAX_GENERATOR_TABLE_BYTES);
+
+/// The base field modulus of P-256 as big endian bytes.
+const MODULUS_BE: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+];
+
+/// A P-256 (secp256r1) point, stored as the big endian x coordinate followed by the big endian y
+/// coordinate.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(align(4))]
+pub struct Secp256r1Point(pub [u8; 64]);
+
+impl Secp256r1Point {
+    /// Decompresses a SEC1 compressed public key using the secp256r1_decompress precompile.
+    ///
+    /// Fails if the prefix byte is not `0x02` or `0x03`, or if the x coordinate is not reduced or
+    /// not on the curve.
+    pub fn decompress_pubkey(compressed_key: &[u8; 33]) -> Result<Self, ErrorKind> {
+        let is_odd = match compressed_key[0] {
+            0x02 => false,
+            0x03 => true,
+            _ => return Err(ErrorKind::InvalidInput),
+        };
+        if compressed_key[1..] >= MODULUS_BE[..] {
+            return Err(ErrorKind::InvalidInput);
+        }
+
+        let mut point = Self([0u8; 64]);
+        point.0[..32].copy_from_slice(&compressed_key[1..]);
+        unsafe {
+            syscall_secp256r1_decompress(&mut point.0, is_odd);
+        }
+
+        // The precompile sets every bit of y when x is not on the curve.
+        if point.0[32..].iter().all(|&byte| byte == 0xff) {
+            return Err(ErrorKind::InvalidInput);
+        }
+        Ok(point)
+    }
+
+    /// Converts the point to the little endian limbs used by the P-256 curve syscalls.
+    pub fn to_affine(&self) -> P256AffinePoint {
+        let mut bytes = self.0;
+        bytes[..32].reverse();
+        bytes[32..].reverse();
+        P256AffinePoint(core::array::from_fn(|i| {
+            u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap())
+        }))
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{
+    syscall_p384_add, syscall_p384_double,
+    utils::{AffinePoint, MulAssignError, PointError, WeierstrassAffinePoint, DEFAULT_MUL_WINDOW},
+};
+
+/// The number of limbs in [Secp384r1Point].
+pub const N: usize = 24;
+
+/// An affine point on the P-384 (secp384r1) curve.
+///
+/// There is no scalar multiplication syscall or generator table for this curve, so
+/// [`AffinePoint::mul_assign`] goes through the add and double syscalls.
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct Secp384r1Point(pub [u32; N]);
+
+impl AffinePoint<N> for Secp384r1Point {
+    /// The values are taken from https://neuromancer.sk/std/nist/P-384.
+    const GENERATOR: [u32; N] = [
+        1920338615, 978607672, 3210029420, 1426256477, 2186553912, 1509376480, 2343017368,
+        1847409506, 4079005044, 2394015518, 3196781879, 2861025826, 2431258207, 2051218812,
+        494829981, 174109134, 3052452032, 3923390739, 681186428, 4176747965, 2459098153,
+        1570674879, 2519084143, 907533898,
+    ];
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(limbs)
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        &self.0
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        &mut self.0
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_p384_add(a, b);
+        }
+    }
+
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_p384_double(a);
+        }
+    }
+
+    fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        self.mul_assign_windowed(scalar, DEFAULT_MUL_WINDOW)
+    }
+}
+
+impl WeierstrassAffinePoint<N> for Secp384r1Point {
+    const MODULUS: &'static [u32] = &[
+        0xffffffff, 0x00000000, 0x00000000, 0xffffffff, 0xfffffffe, 0xffffffff, 0xffffffff,
+        0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+    ];
+
+    const A: &'static [u32] = &[
+        0xfffffffc, 0x00000000, 0x00000000, 0xffffffff, 0xfffffffe, 0xffffffff, 0xffffffff,
+        0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+    ];
+
+    const B: &'static [u32] = &[
+        0xd3ec2aef, 0x2a85c8ed, 0x8a2ed19d, 0xc656398d, 0x5013875a, 0x0314088f, 0xfe814112,
+        0x181d9c6e, 0xe3f82d19, 0x988e056b, 0xe23ee7e4, 0xb3312fa7,
+    ];
+
+    /// Inside the zkVM, the product is computed by the uint384 mulmod precompile.
+    fn field_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+        #[cfg(target_os = "zkvm")]
+        {
+            let mut result: [u32; 12] = a.try_into().unwrap();
+            let mut y_and_modulus = [0u32; 24];
+            y_and_modulus[..12].copy_from_slice(b);
+            y_and_modulus[12..].copy_from_slice(Self::MODULUS);
+            unsafe {
+                crate::syscall_uint384_mulmod(
+                    &mut result,
+                    y_and_modulus.as_ptr() as *const [u32; 12],
+                );
+            }
+            result.to_vec()
+        }
+
+        #[cfg(not(target_os = "zkvm"))]
+        crate::utils::mul_mod(a, b, Self::MODULUS)
+    }
+
+    /// Inside the zkVM, the square root is computed by the secp384r1 decompress precompile.
+    fn decompress(x: &[u32], is_odd: bool) -> Result<Self, PointError> {
+        #[cfg(target_os = "zkvm")]
+        {
+            let x: [u32; 12] = x.try_into().map_err(|_| PointError::InvalidLength)?;
+            if x.iter().rev().ge(Self::MODULUS.iter().rev()) {
+                return Err(PointError::NonCanonical);
+            }
+
+            // The syscall takes the big endian x coordinate followed by space for y.
+            let mut point = [0u8; 96];
+            for (chunk, word) in point[..48].chunks_exact_mut(4).zip(x.iter().rev()) {
+                chunk.copy_from_slice(&word.to_be_bytes());
+            }
+            unsafe {
+                crate::syscall_secp384r1_decompress(&mut point, is_odd);
+            }
+
+            // The precompile sets every bit of y when x is not on the curve.
+            if point[48..].iter().all(|&byte| byte == 0xff) {
+                return Err(PointError::NotOnCurve);
+            }
+            let mut limbs = [0u32; N];
+            limbs[..12].copy_from_slice(&x);
+            for (limb, chunk) in limbs[12..].iter_mut().zip(point[48..].rchunks_exact(4)) {
+                *limb = u32::from_be_bytes(chunk.try_into().unwrap());
+            }
+            Ok(Self(limbs))
+        }
+
+        #[cfg(not(target_os = "zkvm"))]
+        crate::utils::decompress_with_sqrt(x, is_odd)
+    }
+}
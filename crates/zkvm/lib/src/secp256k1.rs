@@ -1,4 +1,12 @@
-use crate::{syscall_secp256k1_add, syscall_secp256k1_double, utils::AffinePoint};
+use crate::{
+    hash::{keccak256, sha256_compress},
+    syscall_ec_linear_combination, syscall_secp256k1_add, syscall_secp256k1_double,
+    syscall_secp256k1_ecdsa_verify, syscall_secp256k1_msm, syscall_secp256k1_mul,
+    uint256::{invmod, U256},
+    utils::{
+        AffinePoint, MulAssignError, PointError, WeierstrassAffinePoint, MAX_GENERATOR_TABLE_BYTES,
+    },
+};
 
 /// The number of limbs in [Secp256k1AffinePoint].
 pub const N: usize = 16;
@@ -16,6 +24,8 @@ impl AffinePoint<N> for Secp256k1AffinePoint {
         1211816567,
     ];
 
+    const GENERATOR_TABLE: &'static [[u32; N]] = &GENERATOR_MULTIPLES;
+
     fn new(limbs: [u32; N]) -> Self {
         Self(limbs)
     }
@@ -42,4 +52,628 @@ impl AffinePoint<N> for Secp256k1AffinePoint {
             syscall_secp256k1_double(a);
         }
     }
+
+    /// Multiplies `self` by the given scalar, using the GLV endomorphism to halve the number of
+    /// doublings compared to the default double-and-add.
+    ///
+    /// Scalars that are a multiple of the group order are rejected like zero, since the result
+    /// would be the point at infinity.
+    fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        debug_assert!(scalar.len() == N / 2);
+        glv_mul_assign(self, scalar.try_into().unwrap())
+    }
+}
+
+impl WeierstrassAffinePoint<N> for Secp256k1AffinePoint {
+    const MODULUS: &'static [u32] = &FIELD_MODULUS;
+
+    const A: &'static [u32] = &[0; 8];
+
+    const B: &'static [u32] = &B;
+
+    fn field_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let modulus = U256(Self::MODULUS.try_into().unwrap());
+        U256(a.try_into().unwrap()).mulmod(&U256(b.try_into().unwrap()), &modulus).0.to_vec()
+    }
+
+    /// Inside the zkVM, the square root is computed by the secp256k1 decompress precompile.
+    fn decompress(x: &[u32], is_odd: bool) -> Result<Self, PointError> {
+        #[cfg(target_os = "zkvm")]
+        {
+            use crate::{bignum::mod_exp, syscall_secp256k1_decompress};
+
+            let x = U256(x.try_into().map_err(|_| PointError::InvalidLength)?);
+            let modulus = U256(FIELD_MODULUS);
+            if !x.is_reduced(&modulus) {
+                return Err(PointError::NonCanonical);
+            }
+
+            // The decompress precompile cannot fail, so Euler's criterion is checked first.
+            let c = add_mod(&x.mulmod(&x, &modulus).mulmod(&x, &modulus), &U256(B), &FIELD_MODULUS);
+            if mod_exp(&c.0, &HALF_FIELD_MODULUS, &FIELD_MODULUS) != U256::ONE.0 {
+                return Err(PointError::NotOnCurve);
+            }
+
+            let mut bytes = [0u8; 64];
+            bytes[..32].copy_from_slice(&x.to_be_bytes());
+            unsafe {
+                syscall_secp256k1_decompress(&mut bytes, is_odd);
+            }
+            bytes[..32].reverse();
+            bytes[32..].reverse();
+            Ok(<Self as AffinePoint<N>>::from(&bytes[..32], &bytes[32..]))
+        }
+
+        #[cfg(not(target_os = "zkvm"))]
+        crate::utils::decompress_with_sqrt(x, is_odd)
+    }
+}
+
+impl GlvPoint for Secp256k1AffinePoint {
+    fn endomorphism(&self) -> Self {
+        let mut limbs = self.0;
+        let x: [u32; 8] = limbs[..8].try_into().unwrap();
+        limbs[..8].copy_from_slice(&U256(x).mulmod(&U256(BETA), &U256(FIELD_MODULUS)).0);
+        Self(limbs)
+    }
+
+    fn negate(&mut self) {
+        WeierstrassAffinePoint::negate(self);
+    }
+}
+
+/// Multiplies `point` by `scalar` in a single syscall.
+///
+/// The scalar is little endian, and must be nonzero and less than the order of the curve.
+pub fn mul(point: &mut Secp256k1AffinePoint, scalar: &[u8; 32]) {
+    let scalar: [u32; 8] =
+        core::array::from_fn(|i| u32::from_le_bytes(scalar[4 * i..4 * i + 4].try_into().unwrap()));
+    unsafe {
+        syscall_secp256k1_mul(&mut point.0, &scalar);
+    }
+}
+
+/// Computes `sum(scalars[i] * points[i])` in a single syscall.
+///
+/// The scalars are little endian. There must be between 1 and 255 pairs, and neither the result
+/// nor any partial sum of the bucket method may be the point at infinity, which rules out a point
+/// and its negation with equal scalars. [`Secp256k1AffinePoint::msm`] supports every input.
+pub fn msm(scalars: &[[u8; 32]], points: &[Secp256k1AffinePoint]) -> Secp256k1AffinePoint {
+    assert_eq!(scalars.len(), points.len());
+    let mut pairs = scalars
+        .iter()
+        .zip(points)
+        .map(|(scalar, point)| {
+            let mut pair = [0u32; 24];
+            for (word, bytes) in pair[..8].iter_mut().zip(scalar.chunks_exact(4)) {
+                *word = u32::from_le_bytes(bytes.try_into().unwrap());
+            }
+            pair[8..].copy_from_slice(&point.0);
+            pair
+        })
+        .collect::<Vec<_>>();
+    unsafe {
+        syscall_secp256k1_msm(pairs.as_mut_ptr(), pairs.len() as u32);
+    }
+    Secp256k1AffinePoint(pairs[0][8..].try_into().unwrap())
+}
+
+/// Computes `sum(scalars[i] * points[i])` in a single syscall with Shamir's trick, which shares
+/// the doublings of all the scalars.
+///
+/// The scalars are little endian. There must be between 1 and 8 pairs, and neither the result nor
+/// any partial sum of the double-and-add may be the point at infinity. The points are read in
+/// place, so unlike [`msm`] only the first point is copied.
+pub fn linear_combination(
+    scalars: &[[u8; 32]],
+    points: &[Secp256k1AffinePoint],
+) -> Secp256k1AffinePoint {
+    assert_eq!(scalars.len(), points.len());
+    assert!(!points.is_empty(), "a linear combination needs at least one pair");
+    let scalars = scalars
+        .iter()
+        .map(|scalar| {
+            core::array::from_fn(|i| {
+                u32::from_le_bytes(scalar[4 * i..4 * i + 4].try_into().unwrap())
+            })
+        })
+        .collect::<Vec<[u32; 8]>>();
+    let mut result = points[0].0;
+    let pairs = scalars
+        .iter()
+        .zip(points)
+        .enumerate()
+        .map(|(i, (scalar, point))| {
+            // The result is written over the first point, so it points to a copy.
+            let point_ptr =
+                if i == 0 { result.as_mut_ptr() as u32 } else { point.0.as_ptr() as u32 };
+            [scalar.as_ptr() as u32, point_ptr]
+        })
+        .collect::<Vec<_>>();
+    unsafe {
+        syscall_ec_linear_combination(pairs.as_ptr(), pairs.len() as u32);
+    }
+    Secp256k1AffinePoint(result)
+}
+
+/// Verifies an ECDSA signature of a 32-byte message hash in a single syscall.
+///
+/// The public key is `x || y` and the signature is `r || s`, all big endian. The signature is
+/// rejected if `r` or `s` is not a nonzero reduced scalar, if the public key is not a point of the
+/// curve, or if the x coordinate of `u1 * G + u2 * Q` is not `r` modulo the order.
+pub fn ecdsa_verify(pubkey: &[u8; 64], msg_hash: &[u8; 32], sig: &[u8; 64]) -> bool {
+    let r = U256::from(<[u8; 32]>::try_from(&sig[..32]).unwrap());
+    let s = U256::from(<[u8; 32]>::try_from(&sig[32..]).unwrap());
+    let x = U256::from(<[u8; 32]>::try_from(&pubkey[..32]).unwrap());
+    let y = U256::from(<[u8; 32]>::try_from(&pubkey[32..]).unwrap());
+    let order = U256(GROUP_ORDER);
+    let modulus = U256(FIELD_MODULUS);
+    if r == U256::ZERO || s == U256::ZERO || !r.is_reduced(&order) || !s.is_reduced(&order) {
+        return false;
+    }
+    if !x.is_reduced(&modulus) || !y.is_reduced(&modulus) {
+        return false;
+    }
+
+    let mut input = [0u32; 41];
+    for (words, value) in input.chunks_exact_mut(8).zip([r, s, U256::from(*msg_hash), x, y]) {
+        words.copy_from_slice(&value.0);
+    }
+    unsafe {
+        syscall_secp256k1_ecdsa_verify(&mut input);
+    }
+    input[40] == 1
+}
+
+/// Verifies a BIP-340 Schnorr signature of a 32-byte message.
+///
+/// The public key is an x-only key and the signature is `r || s`, all big endian. The signature is
+/// rejected if the public key is not the x coordinate of a point, if `r` is not a reduced field
+/// element or `s` is not a reduced scalar, or if `s * G - e * P` is the point at infinity or does
+/// not have an even y coordinate and x coordinate `r`.
+pub fn schnorr_verify(pubkey_x: &[u8; 32], msg: &[u8; 32], sig: &[u8; 64]) -> bool {
+    let Some(mut public_key) = lift_x(pubkey_x) else {
+        return false;
+    };
+    let r_bytes: [u8; 32] = sig[..32].try_into().unwrap();
+    let r = U256::from(r_bytes);
+    let s = U256::from(<[u8; 32]>::try_from(&sig[32..]).unwrap());
+    if !r.is_reduced(&U256(FIELD_MODULUS)) || !s.is_reduced(&U256(GROUP_ORDER)) {
+        return false;
+    }
+
+    let e = schnorr_challenge(&r_bytes, pubkey_x, msg);
+    WeierstrassAffinePoint::negate(&mut public_key);
+
+    // `s * G` only adds up entries of the generator table, and a zero `s` or `e` drops its term.
+    let mut sum = Secp256k1AffinePoint::mul_generator(&s.0).ok();
+    if public_key.mul_assign(&e.0).is_ok() {
+        Secp256k1AffinePoint::accumulate(&mut sum, &public_key);
+    }
+    let Some(point) = sum else {
+        return false;
+    };
+    point.0[8] & 1 == 0 && point.0[..8] == r.0
+}
+
+/// Errors of [`ecrecover`], for the inputs that the `ecrecover` precompile of the EVM rejects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EcrecoverError {
+    /// `v` is not 27 or 28.
+    InvalidRecoveryId,
+    /// `r` or `s` is zero or not less than the order of the curve.
+    InvalidSignature,
+    /// `r` is not the x coordinate of a point of the curve.
+    InvalidR,
+    /// The recovered public key is the point at infinity.
+    PointAtInfinity,
+}
+
+/// Recovers the Ethereum address that signed a 32-byte message hash.
+///
+/// The signature is `r || s || v`, with big endian `r` and `s` and a recovery id `v` of 27 or 28,
+/// and the address is the last 20 bytes of the Keccak-256 hash of the public key `x || y`. The
+/// accepted inputs are those of the EVM precompile: `s` may be above half the order, since only
+/// transactions require low `s`, and recovery ids 2 and 3, for which the x coordinate of `R` is
+/// `r + n`, are rejected like any other `v`.
+///
+/// The public key is `r^-1 * (s * R - z * G)`, where `R` is decompressed from `r` with the parity
+/// given by `v` and `z` is the hash reduced modulo the order.
+pub fn ecrecover(sig: &[u8; 65], msg_hash: &[u8; 32]) -> Result<[u8; 20], EcrecoverError> {
+    let is_odd = match sig[64] {
+        27 => false,
+        28 => true,
+        _ => return Err(EcrecoverError::InvalidRecoveryId),
+    };
+    let r = U256::from(<[u8; 32]>::try_from(&sig[..32]).unwrap());
+    let s = U256::from(<[u8; 32]>::try_from(&sig[32..64]).unwrap());
+    let order = U256(GROUP_ORDER);
+    if r == U256::ZERO || s == U256::ZERO || !r.is_reduced(&order) || !s.is_reduced(&order) {
+        return Err(EcrecoverError::InvalidSignature);
+    }
+
+    // `r` is less than the order, which is less than the field modulus.
+    let mut point =
+        Secp256k1AffinePoint::decompress(&r.0, is_odd).map_err(|_| EcrecoverError::InvalidR)?;
+
+    // A nonzero `r` below the prime order is invertible.
+    let r_inv = U256(invmod(&r.0, &GROUP_ORDER).unwrap());
+    let mut z = U256::from(*msg_hash);
+    if !z.is_reduced(&order) {
+        z = sub(&z.0, &GROUP_ORDER);
+    }
+    let u1 = sub_mod_order(&U256::ZERO, &z.mulmod(&r_inv, &order));
+    let u2 = s.mulmod(&r_inv, &order);
+
+    // `u1 * G` only adds up entries of the generator table, and is dropped if `z` is zero. `u2`
+    // is nonzero since `s` is.
+    let mut sum = Secp256k1AffinePoint::mul_generator(&u1.0).ok();
+    point.mul_assign(&u2.0).unwrap();
+    Secp256k1AffinePoint::accumulate(&mut sum, &point);
+    let public_key = sum.ok_or(EcrecoverError::PointAtInfinity)?;
+
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&U256(public_key.0[..8].try_into().unwrap()).to_be_bytes());
+    bytes[32..].copy_from_slice(&U256(public_key.0[8..].try_into().unwrap()).to_be_bytes());
+    Ok(keccak256(&bytes)[12..].try_into().unwrap())
+}
+
+/// Returns the point with the given big endian x coordinate and an even y coordinate, or `None`
+/// if there is no such point.
+fn lift_x(x: &[u8; 32]) -> Option<Secp256k1AffinePoint> {
+    Secp256k1AffinePoint::decompress(&U256::from(*x).0, false).ok()
+}
+
+/// Computes the challenge `e = int(hash_BIP0340/challenge(r || pubkey_x || msg)) % GROUP_ORDER`.
+fn schnorr_challenge(r: &[u8; 32], pubkey_x: &[u8; 32], msg: &[u8; 32]) -> U256 {
+    let mut state = CHALLENGE_MIDSTATE;
+    let mut block = [0u8; 64];
+    block[..32].copy_from_slice(r);
+    block[32..].copy_from_slice(pubkey_x);
+    sha256_compress(&mut state, &block);
+
+    // The tagged hash is over 160 bytes: the two tag hashes, `r`, `pubkey_x` and `msg`.
+    let mut block = [0u8; 64];
+    block[..32].copy_from_slice(msg);
+    block[32] = 0x80;
+    block[56..].copy_from_slice(&(160u64 * 8).to_be_bytes());
+    sha256_compress(&mut state, &block);
+
+    let mut digest = [0u8; 32];
+    for (chunk, word) in digest.chunks_exact_mut(4).zip(state.iter()) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    // Every 256-bit value is less than twice the group order.
+    let e = U256::from(digest);
+    if e.is_reduced(&U256(GROUP_ORDER)) {
+        e
+    } else {
+        sub(&e.0, &GROUP_ORDER)
+    }
+}
+
+/// The modulus of the base field.
+const FIELD_MODULUS: [u32; 8] = [
+    0xfffffc2f, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+
+/// The coefficient `b` of the curve equation `y^2 = x^3 + b`.
+const B: [u32; 8] = [7, 0, 0, 0, 0, 0, 0, 0];
+
+/// `(FIELD_MODULUS - 1) / 2`, the exponent of Euler's criterion.
+#[cfg(target_os = "zkvm")]
+const HALF_FIELD_MODULUS: [u32; 8] = [
+    0x7ffffe17, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x7fffffff,
+];
+
+/// The SHA-256 state after compressing `SHA256("BIP0340/challenge")` twice, which every challenge
+/// hash starts with.
+const CHALLENGE_MIDSTATE: [u32; 8] = [
+    0x9cecba11, 0x23925381, 0x11679112, 0xd1627e0f, 0x97c87550, 0x003cc765, 0x90f61164, 0x33e9b66a,
+];
+
+/// The multiples `2^i * G` of the generator, generated by the build script.
+const GENERATOR_MULTIPLES: [[u32; N]; SCALAR_BITS] =
+    include!(concat!(env!("OUT_DIR"), "/secp256k1_generator_table.rs"));
+
+const _: () = assert!(core::mem::size_of::<[[u32; N]; SCALAR_BITS]>() <= MAX_GENERATOR_TABLE_BYTES);
+
+/// The order of the curve.
+const GROUP_ORDER: [u32; 8] = [
+    0xd0364141, 0xbfd25e8c, 0xaf48a03b, 0xbaaedce6, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+
+/// `(GROUP_ORDER - 1) / 2`. Reduced scalars above it are decomposed as negative values.
+const HALF_GROUP_ORDER: [u32; 8] = [
+    0x681b20a0, 0xdfe92f46, 0x57a4501d, 0x5d576e73, 0xffffffff, 0xffffffff, 0xffffffff, 0x7fffffff,
+];
+
+/// A cube root of unity in the base field, so that `(x, y) -> (BETA * x, y)` is an endomorphism.
+const BETA: [u32; 8] = [
+    0x719501ee, 0xc1396c28, 0x12f58995, 0x9cf04975, 0xac3434e9, 0x6e64479e, 0x657c0710, 0x7ae96a2b,
+];
+
+/// The cube root of unity modulo the group order by which the endomorphism multiplies points.
+const LAMBDA: [u32; 8] = [
+    0x1b23bd72, 0xdf02967c, 0x20816678, 0x122e22ea, 0x8812645a, 0xa5261c02, 0xc05c30e0, 0x5363ad4c,
+];
+
+/// `-b1` and `-b2 % GROUP_ORDER` for the short lattice basis `(a1, b1), (a2, b2)` of the scalars
+/// `k1 + k2 * LAMBDA = 0`, with `b2 = a1`.
+const MINUS_B1: [u32; 8] = [0x0abfe4c3, 0x6f547fa9, 0x010e8828, 0xe4437ed6, 0, 0, 0, 0];
+const MINUS_B2: [u32; 8] = [
+    0x3db1562c, 0xd765cda8, 0x0774346d, 0x8a280ac5, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+
+/// `round(2^384 * b2 / GROUP_ORDER)` and `round(2^384 * -b1 / GROUP_ORDER)`, which approximate the
+/// coordinates of a scalar in the lattice basis with a multiplication and a shift.
+const G1: [u32; 8] = [
+    0x45dbb031, 0xe893209a, 0x71e8ca7f, 0x3daa8a14, 0x9284eb15, 0xe86c90e4, 0xa7d46bcd, 0x3086d221,
+];
+const G2: [u32; 8] = [
+    0x8ac47f71, 0x1571b4ae, 0x9df506c6, 0x221208ac, 0x0abfe4c4, 0x6f547fa9, 0x010e8828, 0xe4437ed6,
+];
+
+/// The number of bits of a scalar.
+const SCALAR_BITS: usize = 256;
+
+/// The number of bits of the magnitude of both halves of a decomposed scalar.
+const HALF_SCALAR_BITS: usize = 128;
+
+/// A point with an efficient endomorphism `phi` acting as multiplication by [LAMBDA].
+trait GlvPoint: AffinePoint<N> {
+    /// Returns `phi(self) = LAMBDA * self`.
+    fn endomorphism(&self) -> Self;
+
+    /// Negates `self`.
+    fn negate(&mut self);
+}
+
+/// Multiplies `point` by `scalar` as `k1 * point + k2 * phi(point)`, where both halves of the
+/// decomposition are at most 128 bits.
+fn glv_mul_assign<P: GlvPoint>(point: &mut P, scalar: &[u32; 8]) -> Result<(), MulAssignError> {
+    let ((k1_is_negative, k1), (k2_is_negative, k2)) = glv_decompose(scalar);
+    if k1 == U256::ZERO && k2 == U256::ZERO {
+        return Err(MulAssignError::ScalarIsZero);
+    }
+
+    let mut p1 = point.clone();
+    if k1_is_negative {
+        p1.negate();
+    }
+    let mut p2 = point.endomorphism();
+    if k2_is_negative {
+        p2.negate();
+    }
+
+    *point = P::multi_scalar_multiplication(
+        &bits_le(&k1, HALF_SCALAR_BITS),
+        p1,
+        &bits_le(&k2, HALF_SCALAR_BITS),
+        p2,
+    )
+    .unwrap();
+    Ok(())
+}
+
+/// Splits `scalar` into `(k1, k2)` with `k1 + k2 * LAMBDA = scalar` modulo the group order. Each
+/// half is returned as a sign and a magnitude less than `2^128`.
+///
+/// This is the decomposition used by libsecp256k1: the scalar is written in the lattice basis
+/// with rounded coefficients `c1, c2`, and `k2 = -(c1 * b1 + c2 * b2)`.
+fn glv_decompose(scalar: &[u32; 8]) -> ((bool, U256), (bool, U256)) {
+    // Every 256-bit value is less than twice the group order.
+    let mut k = U256(*scalar);
+    if !k.is_reduced(&U256(GROUP_ORDER)) {
+        k = sub(&k.0, &GROUP_ORDER);
+    }
+
+    let c1 = mul_shift_384(&k.0, &G1);
+    let c2 = mul_shift_384(&k.0, &G2);
+    let k2 = add_mod(
+        &c1.mulmod(&U256(MINUS_B1), &U256(GROUP_ORDER)),
+        &c2.mulmod(&U256(MINUS_B2), &U256(GROUP_ORDER)),
+        &GROUP_ORDER,
+    );
+    let k1 = sub_mod_order(&k, &k2.mulmod(&U256(LAMBDA), &U256(GROUP_ORDER)));
+    (to_signed(&k1), to_signed(&k2))
+}
+
+/// Interprets a reduced scalar as a sign and a magnitude.
+fn to_signed(k: &U256) -> (bool, U256) {
+    if k.0.iter().rev().gt(HALF_GROUP_ORDER.iter().rev()) {
+        (true, sub(&GROUP_ORDER, &k.0))
+    } else {
+        (false, *k)
+    }
+}
+
+/// Computes `round(x * y / 2^384)`.
+fn mul_shift_384(x: &[u32; 8], y: &[u32; 8]) -> U256 {
+    let mut product = [0u32; 16];
+    for (i, x) in x.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, y) in y.iter().enumerate() {
+            let t = product[i + j] as u64 + (*x as u64) * (*y as u64) + carry;
+            product[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        product[i + 8] = carry as u32;
+    }
+
+    let mut result = [0u32; 8];
+    let mut carry = product[11] >> 31 == 1;
+    for (result, word) in result.iter_mut().zip(product[12..].iter()) {
+        (*result, carry) = word.overflowing_add(carry as u32);
+    }
+    U256(result)
+}
+
+/// Computes `(x + y) % modulus` for reduced `x` and `y`.
+fn add_mod(x: &U256, y: &U256, modulus: &[u32; 8]) -> U256 {
+    let mut sum = [0u32; 8];
+    let mut carry = false;
+    for ((sum, x), y) in sum.iter_mut().zip(x.0.iter()).zip(y.0.iter()) {
+        let (t, c1) = x.overflowing_add(*y);
+        let (t, c2) = t.overflowing_add(carry as u32);
+        *sum = t;
+        carry = c1 || c2;
+    }
+    let sum = U256(sum);
+    if carry || !sum.is_reduced(&U256(*modulus)) {
+        sub(&sum.0, modulus)
+    } else {
+        sum
+    }
+}
+
+/// Computes `(x - y) % GROUP_ORDER` for reduced `x` and `y`.
+fn sub_mod_order(x: &U256, y: &U256) -> U256 {
+    if x.0.iter().rev().lt(y.0.iter().rev()) {
+        sub(&GROUP_ORDER, &sub(&y.0, &x.0).0)
+    } else {
+        sub(&x.0, &y.0)
+    }
+}
+
+/// Computes `x - y`, wrapping around `2^256`.
+fn sub(x: &[u32; 8], y: &[u32; 8]) -> U256 {
+    let mut difference = [0u32; 8];
+    let mut borrow = false;
+    for ((difference, x), y) in difference.iter_mut().zip(x.iter()).zip(y.iter()) {
+        let (t, b1) = x.overflowing_sub(*y);
+        let (t, b2) = t.overflowing_sub(borrow as u32);
+        *difference = t;
+        borrow = b1 || b2;
+    }
+    U256(difference)
+}
+
+/// Returns the low `num_bits` bits of `x`, least significant first.
+fn bits_le(x: &U256, num_bits: usize) -> Vec<bool> {
+    (0..num_bits).map(|i| (x.0[i / 32] >> (i % 32)) & 1 == 1).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use rand::Rng;
+
+    use super::*;
+
+    /// A model of the curve's group, where a point is represented by its discrete logarithm with
+    /// respect to the generator, stored in the low words.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Multiple([u32; N]);
+
+    impl Multiple {
+        fn value(&self) -> BigUint {
+            BigUint::from_slice(&self.0[..8])
+        }
+
+        fn from_value(value: BigUint) -> Self {
+            let mut words = (value % BigUint::from_slice(&GROUP_ORDER)).to_u32_digits();
+            words.resize(N, 0);
+            Self(words.try_into().unwrap())
+        }
+    }
+
+    impl AffinePoint<N> for Multiple {
+        const GENERATOR: [u32; N] = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        fn new(limbs: [u32; N]) -> Self {
+            Self(limbs)
+        }
+
+        fn limbs_ref(&self) -> &[u32; N] {
+            &self.0
+        }
+
+        fn limbs_mut(&mut self) -> &mut [u32; N] {
+            &mut self.0
+        }
+
+        fn add_assign(&mut self, other: &Self) {
+            // The precompile does not support adding a point to itself or to its negation.
+            let sum = Self::from_value(self.value() + other.value());
+            assert_ne!(self, other);
+            assert_ne!(sum.value(), BigUint::default());
+            *self = sum;
+        }
+
+        fn double(&mut self) {
+            *self = Self::from_value(self.value() * 2u32);
+        }
+    }
+
+    impl GlvPoint for Multiple {
+        fn endomorphism(&self) -> Self {
+            Self::from_value(self.value() * BigUint::from_slice(&LAMBDA))
+        }
+
+        fn negate(&mut self) {
+            *self = Self::from_value(BigUint::from_slice(&GROUP_ORDER) - self.value());
+        }
+    }
+
+    fn to_words(value: &BigUint) -> [u32; 8] {
+        let mut words = value.to_u32_digits();
+        words.resize(8, 0);
+        words.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_glv_mul_assign() {
+        let order = BigUint::from_slice(&GROUP_ORDER);
+        let lambda = BigUint::from_slice(&LAMBDA);
+        let one = BigUint::from(1u32);
+        let mut scalars = vec![
+            one.clone(),
+            BigUint::from(2u32),
+            &order - 1u32,
+            &order - 2u32,
+            &order + 1u32,
+            &order >> 1,
+            (&order >> 1) + 1u32,
+            lambda.clone(),
+            &order - &lambda,
+            one.clone() << 255,
+            (one << 256) - 1u32,
+        ];
+        let mut rng = rand::thread_rng();
+        scalars.extend((0..100).map(|_| BigUint::from_slice(&rng.gen::<[u32; 8]>())));
+
+        let point = Multiple::from_value(BigUint::from_slice(&rng.gen::<[u32; 8]>()));
+        for scalar in scalars {
+            let words = to_words(&scalar);
+
+            let ((k1_is_negative, k1), (k2_is_negative, k2)) = glv_decompose(&words);
+            assert!(k1.0[4..].iter().all(|word| *word == 0));
+            assert!(k2.0[4..].iter().all(|word| *word == 0));
+            let signed = |is_negative: bool, k: U256| {
+                let k = BigUint::from_slice(&k.0);
+                if is_negative {
+                    &order - k
+                } else {
+                    k
+                }
+            };
+            let recomposed = signed(k1_is_negative, k1) + signed(k2_is_negative, k2) * &lambda;
+            assert_eq!(recomposed % &order, &scalar % &order);
+
+            let mut expected = point.clone();
+            expected.mul_assign(&words).unwrap();
+            let mut result = point.clone();
+            glv_mul_assign(&mut result, &words).unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_glv_mul_assign_zero() {
+        let point = Multiple::new(Multiple::GENERATOR);
+        for scalar in [[0; 8], GROUP_ORDER] {
+            assert!(glv_mul_assign(&mut point.clone(), &scalar).is_err());
+        }
+    }
 }
@@ -0,0 +1,335 @@
+//! Merlin transcripts for Fiat-Shamir challenges, compatible with the `merlin` crate.
+//!
+//! Merlin runs STROBE-128 over Keccak-f[1600], which is computed with the `KECCAK_PERMUTE`
+//! precompile inside the zkVM. The STROBE state lives in the guest memory between the operations,
+//! so the memory argument already ties the state of each permutation to the previous one, and the
+//! byte operations around it are cheap next to the permutation.
+
+#[cfg(target_os = "zkvm")]
+use crate::syscall_keccak_permute;
+
+/// The protocol label of the STROBE object of every Merlin transcript.
+const MERLIN_PROTOCOL_LABEL: &[u8] = b"Merlin v1.0";
+
+/// The rate of STROBE-128 in bytes, leaving room for the padding of each block.
+const STROBE_R: usize = 166;
+
+const FLAG_I: u8 = 1;
+const FLAG_A: u8 = 1 << 1;
+const FLAG_C: u8 = 1 << 2;
+const FLAG_T: u8 = 1 << 3;
+const FLAG_M: u8 = 1 << 4;
+const FLAG_K: u8 = 1 << 5;
+
+/// A Merlin transcript, which absorbs the messages of a protocol and squeezes challenges that
+/// depend on all of them.
+///
+/// The labels are static, as in `merlin::Transcript`, since they are part of the protocol rather
+/// than of its data. The challenges match the ones of `merlin::Transcript` for the same operations.
+///
+/// ### Examples
+/// ```ignore
+/// use sp1_lib::transcript::MerlinTranscript;
+///
+/// let mut transcript = MerlinTranscript::new(b"my protocol");
+/// transcript.append_message(b"commitment", &commitment);
+/// let mut challenge = [0u8; 32];
+/// transcript.challenge_bytes(b"challenge", &mut challenge);
+/// ```
+#[derive(Clone)]
+pub struct MerlinTranscript {
+    strobe: Strobe128,
+}
+
+impl MerlinTranscript {
+    /// Creates a transcript with the given domain separation label.
+    pub fn new(label: &'static [u8]) -> Self {
+        let mut transcript = Self { strobe: Strobe128::new(MERLIN_PROTOCOL_LABEL) };
+        transcript.append_message(b"dom-sep", label);
+        transcript
+    }
+
+    /// Appends a labeled message to the transcript.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message is longer than `u32::MAX` bytes.
+    pub fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&encode_len(message.len()), true);
+        self.strobe.ad(message);
+    }
+
+    /// Appends a labeled `u64` to the transcript, as its 8 little endian bytes.
+    pub fn append_u64(&mut self, label: &'static [u8], x: u64) {
+        self.append_message(label, &x.to_le_bytes());
+    }
+
+    /// Fills `dest` with a labeled challenge, which also becomes part of the transcript.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dest` is longer than `u32::MAX` bytes.
+    pub fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&encode_len(dest.len()), true);
+        self.strobe.prf(dest);
+    }
+}
+
+/// Encodes the length of a message or a challenge as Merlin does.
+fn encode_len(len: usize) -> [u8; 4] {
+    u32::try_from(len).expect("Merlin lengths must fit in a u32").to_le_bytes()
+}
+
+/// A STROBE-128 object with the operations that Merlin uses.
+#[derive(Clone)]
+struct Strobe128 {
+    /// The Keccak state, whose bytes are the little endian bytes of the lanes.
+    state: [u64; 25],
+    /// The position of the next byte in the block.
+    pos: usize,
+    /// The position after the start of the current operation in the block, or zero if it started
+    /// in an earlier block.
+    pos_begin: u8,
+    /// The flags of the current operation.
+    cur_flags: u8,
+}
+
+impl Strobe128 {
+    fn new(protocol_label: &[u8]) -> Self {
+        let mut strobe = Self { state: [0; 25], pos: 0, pos_begin: 0, cur_flags: 0 };
+        for (i, &byte) in
+            [1, STROBE_R as u8 + 2, 1, 0, 1, 96].iter().chain(b"STROBEv1.0.2").enumerate()
+        {
+            strobe.xor_byte(i, byte);
+        }
+        permute(&mut strobe.state);
+        strobe.meta_ad(protocol_label, false);
+        strobe
+    }
+
+    fn meta_ad(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_M | FLAG_A, more);
+        self.absorb(data);
+    }
+
+    fn ad(&mut self, data: &[u8]) {
+        self.begin_op(FLAG_A, false);
+        self.absorb(data);
+    }
+
+    fn prf(&mut self, data: &mut [u8]) {
+        self.begin_op(FLAG_I | FLAG_A | FLAG_C, false);
+        self.squeeze(data);
+    }
+
+    fn begin_op(&mut self, flags: u8, more: bool) {
+        if more {
+            assert_eq!(self.cur_flags, flags, "a STROBE operation can only continue itself");
+            return;
+        }
+        debug_assert_eq!(flags & FLAG_T, 0, "Merlin does not use transport operations");
+
+        let old_begin = self.pos_begin;
+        self.pos_begin = self.pos as u8 + 1;
+        self.cur_flags = flags;
+        self.absorb(&[old_begin, flags]);
+
+        // Operations with a cipher or key flag start on a fresh block.
+        if flags & (FLAG_C | FLAG_K) != 0 && self.pos != 0 {
+            self.run_f();
+        }
+    }
+
+    fn absorb(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.xor_byte(self.pos, byte);
+            self.advance();
+        }
+    }
+
+    fn squeeze(&mut self, data: &mut [u8]) {
+        for byte in data {
+            *byte = self.byte(self.pos);
+            self.xor_byte(self.pos, *byte);
+            self.advance();
+        }
+    }
+
+    fn advance(&mut self) {
+        self.pos += 1;
+        if self.pos == STROBE_R {
+            self.run_f();
+        }
+    }
+
+    /// Pads the block and permutes the state.
+    fn run_f(&mut self) {
+        self.xor_byte(self.pos, self.pos_begin);
+        self.xor_byte(self.pos + 1, 0x04);
+        self.xor_byte(STROBE_R + 1, 0x80);
+        permute(&mut self.state);
+        self.pos = 0;
+        self.pos_begin = 0;
+    }
+
+    fn byte(&self, offset: usize) -> u8 {
+        (self.state[offset / 8] >> (8 * (offset % 8))) as u8
+    }
+
+    fn xor_byte(&mut self, offset: usize, byte: u8) {
+        self.state[offset / 8] ^= (byte as u64) << (8 * (offset % 8));
+    }
+}
+
+/// Keccak-f[1600], computed with the `KECCAK_PERMUTE` precompile inside the zkVM.
+fn permute(state: &mut [u64; 25]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        syscall_keccak_permute(state);
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    keccak_f(state);
+}
+
+/// Keccak-f[1600] in software, outside the zkVM.
+#[cfg(not(target_os = "zkvm"))]
+fn keccak_f(state: &mut [u64; 25]) {
+    const ROUND_CONSTANTS: [u64; 24] = [
+        0x0000000000000001,
+        0x0000000000008082,
+        0x800000000000808a,
+        0x8000000080008000,
+        0x000000000000808b,
+        0x0000000080000001,
+        0x8000000080008081,
+        0x8000000000008009,
+        0x000000000000008a,
+        0x0000000000000088,
+        0x0000000080008009,
+        0x000000008000000a,
+        0x000000008000808b,
+        0x800000000000008b,
+        0x8000000000008089,
+        0x8000000000008003,
+        0x8000000000008002,
+        0x8000000000000080,
+        0x000000000000800a,
+        0x800000008000000a,
+        0x8000000080008081,
+        0x8000000000008080,
+        0x0000000080000001,
+        0x8000000080008008,
+    ];
+    const RHO: [u32; 24] =
+        [1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44];
+    const PI: [usize; 24] =
+        [10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1];
+
+    for round_constant in ROUND_CONSTANTS {
+        // Theta.
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[5 * y + x] ^= d;
+            }
+        }
+
+        // Rho and pi.
+        let mut last = state[1];
+        for (&rho, &pi) in RHO.iter().zip(&PI) {
+            let lane = state[pi];
+            state[pi] = last.rotate_left(rho);
+            last = lane;
+        }
+
+        // Chi.
+        for row in state.chunks_exact_mut(5) {
+            let lanes: [u64; 5] = row.try_into().unwrap();
+            for (x, lane) in row.iter_mut().enumerate() {
+                *lane = lanes[x] ^ (!lanes[(x + 1) % 5] & lanes[(x + 2) % 5]);
+            }
+        }
+
+        // Iota.
+        state[0] ^= round_constant;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_keccak_f() {
+        // The first permutation of the zero state, from the Keccak reference test vectors.
+        let mut state = [0u64; 25];
+        keccak_f(&mut state);
+        assert_eq!(state[0], 0xF1258F7940E1DDE7);
+        assert_eq!(state[1], 0x84D5CCF933C0478A);
+        assert_eq!(state[24], 0xEAF1FF7B5CECA249);
+    }
+
+    #[test]
+    fn test_simple_transcript() {
+        // The simple transcript of the Merlin test vectors.
+        let mut transcript = MerlinTranscript::new(b"test protocol");
+        transcript.append_message(b"some label", b"some data");
+        let mut challenge = [0u8; 32];
+        transcript.challenge_bytes(b"challenge", &mut challenge);
+        assert_eq!(
+            hex(&challenge),
+            "d5a21972d0d5fe320c0d263fac7fffb8145aa640af6e9bca177c03c7efcf0615"
+        );
+    }
+
+    #[test]
+    fn test_complex_transcript() {
+        // The complex transcript of the Merlin test vectors, whose messages span several blocks
+        // and alternate with the challenges.
+        let mut transcript = MerlinTranscript::new(b"test protocol");
+        transcript.append_message(b"step1", b"some data");
+        let data = [99u8; 1024];
+        let mut challenge = [0u8; 32];
+        for _ in 0..32 {
+            transcript.challenge_bytes(b"challenge", &mut challenge);
+            transcript.append_message(b"bigdata", &data);
+            transcript.append_message(b"challengedata", &challenge);
+        }
+        assert_eq!(
+            hex(&challenge),
+            "a8c933f54fae76e3f9bea93648c1308e7dfa2152dd51674ff3ca438351cf003c"
+        );
+    }
+
+    #[test]
+    fn test_transcript_binds_messages() {
+        let challenge = |label: &'static [u8], message: &[u8]| {
+            let mut transcript = MerlinTranscript::new(b"test protocol");
+            transcript.append_message(label, message);
+            let mut challenge = [0u8; 32];
+            transcript.challenge_bytes(b"challenge", &mut challenge);
+            challenge
+        };
+        let expected = challenge(b"some label", b"some data");
+        assert_ne!(challenge(b"some label", b"some datb"), expected);
+        assert_ne!(challenge(b"other label", b"some data"), expected);
+
+        // A longer challenge is not an extension of a shorter one, since the length is absorbed.
+        let mut transcript = MerlinTranscript::new(b"test protocol");
+        transcript.append_message(b"some label", b"some data");
+        let mut long = [0u8; 64];
+        transcript.challenge_bytes(b"challenge", &mut long);
+        assert_ne!(long[..32], expected);
+    }
+}
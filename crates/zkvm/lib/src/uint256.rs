@@ -0,0 +1,301 @@
+//! Helpers for 256-bit unsigned integers, represented as 8 little endian words.
+
+use core::{
+    cmp::Ordering,
+    ops::{BitAnd, BitOr, BitXor},
+};
+
+use crate::{
+    syscall_uint256_and, syscall_uint256_cmp, syscall_uint256_divrem, syscall_uint256_invmod,
+    syscall_uint256_mont_mul, syscall_uint256_mul_wide, syscall_uint256_mulmod, syscall_uint256_or,
+    syscall_uint256_range_check, syscall_uint256_xor,
+};
+
+/// A 256-bit unsigned integer, stored as 8 little endian words.
+///
+/// Inside the zkVM, the arithmetic goes through the uint256 precompiles, laying out the operands
+/// the way the syscalls expect. Elsewhere it falls back to `num-bigint`, so that host tests of
+/// guest code see the same results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct U256(pub [u32; 8]);
+
+impl U256 {
+    pub const ZERO: Self = Self([0; 8]);
+    pub const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Computes `(self * other) % modulus`. A zero modulus is interpreted as 2^256.
+    ///
+    /// The inputs do not need to be reduced, and the result always is. The `UINT256_MUL`
+    /// precompile requires the quotient of the product by the modulus to fit in 256 bits, so an
+    /// unreduced `self` is first reduced with a multiplication by one, at the cost of an extra
+    /// syscall.
+    pub fn mulmod(&self, other: &Self, modulus: &Self) -> Self {
+        #[cfg(target_os = "zkvm")]
+        {
+            let mut result = self.0;
+            if !self.is_reduced(modulus) {
+                mulmod(&mut result, &Self::ONE.0, &modulus.0);
+            }
+            mulmod(&mut result, &other.0, &modulus.0);
+            Self(result)
+        }
+
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            use num_bigint::BigUint;
+
+            let modulus = if *modulus == Self::ZERO {
+                BigUint::from(1u32) << 256
+            } else {
+                BigUint::from_slice(&modulus.0)
+            };
+            let result = BigUint::from_slice(&self.0) * BigUint::from_slice(&other.0) % modulus;
+            let mut words = result.to_u32_digits();
+            words.resize(8, 0);
+            Self(words.try_into().unwrap())
+        }
+    }
+
+    /// Returns whether `self` is less than the modulus. Every value is reduced modulo zero, which
+    /// is interpreted as 2^256.
+    pub fn is_reduced(&self, modulus: &Self) -> bool {
+        *modulus == Self::ZERO || self.0.iter().rev().lt(modulus.0.iter().rev())
+    }
+
+    /// Returns the little endian bytes of `self`.
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(self.0.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the big endian bytes of `self`.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+}
+
+impl From<[u32; 8]> for U256 {
+    fn from(words: [u32; 8]) -> Self {
+        Self(words)
+    }
+}
+
+/// Compares with the `UINT256_CMP` precompile inside the zkVM.
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        #[cfg(target_os = "zkvm")]
+        {
+            cmp(&self.0, &other.0)
+        }
+
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            self.0.iter().rev().cmp(other.0.iter().rev())
+        }
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Computes the bitwise operations with the `UINT256_AND`, `UINT256_OR` and `UINT256_XOR`
+/// precompiles inside the zkVM, and word by word elsewhere.
+macro_rules! impl_bitwise {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl $trait for U256 {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                #[cfg(target_os = "zkvm")]
+                {
+                    Self($method(&self.0, &other.0))
+                }
+
+                #[cfg(not(target_os = "zkvm"))]
+                {
+                    Self(core::array::from_fn(|i| self.0[i] $op other.0[i]))
+                }
+            }
+        }
+    };
+}
+
+impl_bitwise!(BitAnd, bitand, &);
+impl_bitwise!(BitOr, bitor, |);
+impl_bitwise!(BitXor, bitxor, ^);
+
+/// Converts big endian bytes, matching the EVM encoding of 256-bit words.
+impl From<[u8; 32]> for U256 {
+    fn from(mut bytes: [u8; 32]) -> Self {
+        bytes.reverse();
+        let mut words = [0u32; 8];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self(words)
+    }
+}
+
+/// Computes the full 512-bit product `x * y`, returned as its low and high 256-bit halves.
+pub fn mul_wide(x: &[u32; 8], y: &[u32; 8]) -> ([u32; 8], [u32; 8]) {
+    let mut product = [0u32; 16];
+    product[..8].copy_from_slice(x);
+    unsafe {
+        syscall_uint256_mul_wide(&mut product, y);
+    }
+    (product[..8].try_into().unwrap(), product[8..].try_into().unwrap())
+}
+
+/// Returns zero if `value` is less than `prime`, and one otherwise.
+///
+/// Validating a deserialized field element this way costs one precompile row instead of a loop
+/// over the words.
+pub fn range_check(value: &[u32; 8], prime: &[u32; 8]) -> u32 {
+    let mut value_and_result = [0u32; 9];
+    value_and_result[..8].copy_from_slice(value);
+    unsafe {
+        syscall_uint256_range_check(&mut value_and_result, prime);
+    }
+    value_and_result[8]
+}
+
+/// Compares `x` and `y` in one precompile row instead of a loop over the words.
+pub fn cmp(x: &[u32; 8], y: &[u32; 8]) -> Ordering {
+    let mut x_and_result = [0u32; 9];
+    x_and_result[..8].copy_from_slice(x);
+    unsafe {
+        syscall_uint256_cmp(&mut x_and_result, y);
+    }
+    (x_and_result[8] as i32).cmp(&0)
+}
+
+/// Computes the bitwise and of `x` and `y` in one precompile row.
+pub fn bitand(x: &[u32; 8], y: &[u32; 8]) -> [u32; 8] {
+    let mut result = *x;
+    unsafe {
+        syscall_uint256_and(&mut result, y);
+    }
+    result
+}
+
+/// Computes the bitwise or of `x` and `y` in one precompile row.
+pub fn bitor(x: &[u32; 8], y: &[u32; 8]) -> [u32; 8] {
+    let mut result = *x;
+    unsafe {
+        syscall_uint256_or(&mut result, y);
+    }
+    result
+}
+
+/// Computes the bitwise xor of `x` and `y` in one precompile row.
+pub fn bitxor(x: &[u32; 8], y: &[u32; 8]) -> [u32; 8] {
+    let mut result = *x;
+    unsafe {
+        syscall_uint256_xor(&mut result, y);
+    }
+    result
+}
+
+/// Computes the quotient and remainder of `x / divisor`. Traps if the divisor is zero.
+pub fn divrem(x: &[u32; 8], divisor: &[u32; 8]) -> ([u32; 8], [u32; 8]) {
+    let mut quotient = *x;
+    let mut divisor_and_remainder = [0u32; 16];
+    divisor_and_remainder[..8].copy_from_slice(divisor);
+    unsafe {
+        syscall_uint256_divrem(&mut quotient, &mut divisor_and_remainder);
+    }
+    (quotient, divisor_and_remainder[8..].try_into().unwrap())
+}
+
+/// Computes the inverse of `x` modulo `modulus`, or `None` if it does not exist. A zero modulus is
+/// interpreted as 2^256.
+///
+/// For a prime modulus this is `x^(modulus - 2) % modulus`, but the precompile only checks that
+/// the result times x is one, which is much cheaper than an exponentiation.
+pub fn invmod(x: &[u32; 8], modulus: &[u32; 8]) -> Option<[u32; 8]> {
+    let mut result = *x;
+    unsafe {
+        syscall_uint256_invmod(&mut result, modulus);
+    }
+    // The precompile writes zero when x is not invertible. Zero is only a valid inverse modulo one.
+    let is_zero = result.iter().all(|word| *word == 0);
+    let modulus_is_one = modulus[0] == 1 && modulus[1..].iter().all(|word| *word == 0);
+    (!is_zero || modulus_is_one).then_some(result)
+}
+
+/// Montgomery arithmetic modulo an odd 256-bit modulus, with `R = 2^256`.
+///
+/// Values are converted into Montgomery form `x * R % modulus` once, multiplied with the
+/// `UINT256_MONT_MUL` precompile, and converted back at the end. All values must be reduced.
+pub struct MontU256 {
+    modulus: [u32; 8],
+    /// `R^2 % modulus`, which converts a value into Montgomery form.
+    r_squared: [u32; 8],
+}
+
+impl MontU256 {
+    /// Creates the Montgomery context for the given odd modulus.
+    pub fn new(modulus: &[u32; 8]) -> Self {
+        assert!(modulus[0] & 1 == 1, "the modulus must be odd");
+
+        // R % modulus is the reduction of R - modulus, which is the two's complement of modulus.
+        let mut r = [0u32; 8];
+        let mut carry = true;
+        for (r, word) in r.iter_mut().zip(modulus.iter()) {
+            (*r, carry) = (!word).overflowing_add(carry as u32);
+        }
+        mulmod(&mut r, &one(), modulus);
+
+        let mut r_squared = r;
+        mulmod(&mut r_squared, &r, modulus);
+        Self { modulus: *modulus, r_squared }
+    }
+
+    /// Converts `x` into Montgomery form.
+    pub fn to_mont(&self, x: &[u32; 8]) -> [u32; 8] {
+        self.mul(x, &self.r_squared)
+    }
+
+    /// Converts `x` out of Montgomery form.
+    pub fn from_mont(&self, x: &[u32; 8]) -> [u32; 8] {
+        self.mul(x, &one())
+    }
+
+    /// Multiplies two values in Montgomery form.
+    pub fn mul(&self, x: &[u32; 8], y: &[u32; 8]) -> [u32; 8] {
+        let mut result = *x;
+        let y_and_modulus = concat(y, &self.modulus);
+        unsafe {
+            syscall_uint256_mont_mul(&mut result, y_and_modulus.as_ptr() as *const [u32; 8]);
+        }
+        result
+    }
+}
+
+fn mulmod(x: &mut [u32; 8], y: &[u32; 8], modulus: &[u32; 8]) {
+    let y_and_modulus = concat(y, modulus);
+    unsafe {
+        syscall_uint256_mulmod(x, y_and_modulus.as_ptr() as *const [u32; 8]);
+    }
+}
+
+fn concat(a: &[u32; 8], b: &[u32; 8]) -> [u32; 16] {
+    let mut result = [0u32; 16];
+    result[..8].copy_from_slice(a);
+    result[8..].copy_from_slice(b);
+    result
+}
+
+fn one() -> [u32; 8] {
+    let mut one = [0u32; 8];
+    one[0] = 1;
+    one
+}
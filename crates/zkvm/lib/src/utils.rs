@@ -1,7 +1,26 @@
+/// A point of an elliptic curve whose group operations are syscalls.
+///
+/// The syscalls a guest makes and its cycle count are visible in the execution trace, so the
+/// multiplications that branch on the bits of the scalar leak it. This holds for
+/// [`AffinePoint::mul_assign`], [`AffinePoint::mul_assign_windowed`],
+/// [`AffinePoint::multi_scalar_multiplication`] and [`AffinePoint::msm`], which are meant for
+/// public scalars. [`WeierstrassAffinePoint::mul_assign_ct`] is the only multiplication whose
+/// sequence of syscalls is independent of the scalar. The additions and doublings themselves are
+/// single syscalls and do not depend on the values of the points.
 pub trait AffinePoint<const N: usize>: Clone + Sized {
     /// The generator.
     const GENERATOR: [u32; N];
 
+    /// The multiples `2^i * GENERATOR` for every bit of a scalar, which
+    /// [`AffinePoint::mul_generator`] adds up without doubling. Curves without a table leave it
+    /// empty.
+    const GENERATOR_TABLE: &'static [[u32; N]] = &[];
+
+    /// The limbs that [`AffinePoint::mul_assign_complete`] returns for the identity. Short
+    /// Weierstrass curves have no affine identity, so the default is the all-zero encoding that
+    /// stands in for the point at infinity.
+    const IDENTITY: [u32; N] = [0; N];
+
     /// Creates a new [`AffinePoint`] from the given limbs.
     fn new(limbs: [u32; N]) -> Self;
 
@@ -52,6 +71,11 @@ pub trait AffinePoint<const N: usize>: Clone + Sized {
 
     /// Multiplies `self` by the given scalar.
     fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        self.mul_assign_bit_serial(scalar)
+    }
+
+    /// Multiplies `self` by the given scalar with a double-and-add over every bit of the scalar.
+    fn mul_assign_bit_serial(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
         debug_assert!(scalar.len() == N / 2);
 
         let mut res: Option<Self> = None;
@@ -79,6 +103,119 @@ pub trait AffinePoint<const N: usize>: Clone + Sized {
         Ok(())
     }
 
+    /// Multiplies `self` by the given scalar with a sliding window of `window` bits.
+    ///
+    /// The odd multiples `P, 3P, ..., (2^window - 1)P` are precomputed with one doubling and
+    /// `2^(window - 1) - 1` additions. Each window then costs a single addition, so a 256-bit
+    /// scalar needs about `256 / (window + 1)` additions instead of one per set bit. The table does
+    /// not pay for itself on small scalars, which use
+    /// [`AffinePoint::mul_assign_bit_serial`].
+    ///
+    /// As in the bit-serial path, the point at infinity is only ever the empty
+    /// accumulator, so the order of `self` must exceed `2^window`.
+    fn mul_assign_windowed(&mut self, scalar: &[u32], window: usize) -> Result<(), MulAssignError> {
+        debug_assert!(scalar.len() == N / 2);
+        debug_assert!((1..=8).contains(&window));
+
+        let bit = |i: usize| (scalar[i / 32] >> (i % 32)) & 1 == 1;
+        let num_bits = match (0..32 * scalar.len()).rev().find(|&i| bit(i)) {
+            Some(msb) => msb + 1,
+            None => return Err(MulAssignError::ScalarIsZero),
+        };
+        if num_bits <= 1 << (window + 1) {
+            return self.mul_assign_bit_serial(scalar);
+        }
+
+        // table[i] = (2i + 1) * self.
+        let mut self_doubled = self.clone();
+        self_doubled.double();
+        let mut table = Vec::with_capacity(1 << (window - 1));
+        table.push(self.clone());
+        for i in 1..1 << (window - 1) {
+            let mut next: Self = table[i - 1].clone();
+            next.add_assign(&self_doubled);
+            table.push(next);
+        }
+
+        let mut res: Option<Self> = None;
+        let mut i = num_bits;
+        while i > 0 {
+            if !bit(i - 1) {
+                if let Some(res) = res.as_mut() {
+                    res.double();
+                }
+                i -= 1;
+                continue;
+            }
+
+            // The longest window ending in a set bit, so that its value is odd.
+            let mut low = i.saturating_sub(window);
+            while !bit(low) {
+                low += 1;
+            }
+            let value = (low..i).rev().fold(0, |value, j| (value << 1) | bit(j) as usize);
+            match res.as_mut() {
+                Some(res) => {
+                    for _ in low..i {
+                        res.double();
+                    }
+                    res.add_assign(&table[value >> 1]);
+                }
+                None => res = Some(table[value >> 1].clone()),
+            }
+            i = low;
+        }
+
+        *self = res.unwrap();
+        Ok(())
+    }
+
+    /// Multiplies `self` by the given scalar, which may be zero or a multiple of the order.
+    ///
+    /// Unlike [`AffinePoint::mul_assign`], a product at the point at infinity is not an error:
+    /// `self` is set to [`AffinePoint::IDENTITY`] instead. This is a double-and-add from the top
+    /// bit where every addition goes through [`AffinePoint::accumulate`], so the running sum may
+    /// cancel out to infinity and start over.
+    fn mul_assign_complete(&mut self, scalar: &[u32]) {
+        debug_assert!(scalar.len() == N / 2);
+
+        let mut res: Option<Self> = None;
+        for i in (0..32 * scalar.len()).rev() {
+            if let Some(res) = res.as_mut() {
+                res.double();
+            }
+            if (scalar[i / 32] >> (i % 32)) & 1 == 1 {
+                Self::accumulate(&mut res, self);
+            }
+        }
+        *self = res.unwrap_or_else(|| Self::new(Self::IDENTITY));
+    }
+
+    /// Multiplies the generator by the given scalar.
+    ///
+    /// With a [`AffinePoint::GENERATOR_TABLE`], this adds up the table entries of the set bits of
+    /// the scalar, so a 256-bit scalar costs about 128 additions and no doubling. Without one, it
+    /// falls back to [`AffinePoint::mul_assign`]. As there, a zero scalar is an error, and so is a
+    /// multiple of the order, whose product is the point at infinity.
+    fn mul_generator(scalar: &[u32]) -> Result<Self, MulAssignError> {
+        debug_assert!(scalar.len() == N / 2);
+
+        if Self::GENERATOR_TABLE.is_empty() {
+            let mut res = Self::new(Self::GENERATOR);
+            res.mul_assign(scalar)?;
+            return Ok(res);
+        }
+
+        debug_assert!(Self::GENERATOR_TABLE.len() == 32 * scalar.len());
+        let mut res: Option<Self> = None;
+        for (i, limbs) in Self::GENERATOR_TABLE.iter().enumerate() {
+            if (scalar[i / 32] >> (i % 32)) & 1 == 1 {
+                Self::accumulate(&mut res, &Self::new(*limbs));
+            }
+        }
+        res.ok_or(MulAssignError::ScalarIsZero)
+    }
+
     /// Performs multi-scalar multiplication (MSM) on slices of bit vectors and points. Note:
     /// a_bits_le and b_bits_le should be in little endian order.
     fn multi_scalar_multiplication(
@@ -110,14 +247,316 @@ pub trait AffinePoint<const N: usize>: Clone + Sized {
         }
         res
     }
+
+    /// Adds `other` to `acc`, where `None` is the point at infinity.
+    ///
+    /// Unlike [`AffinePoint::add_assign`], every input is supported: a point added to itself is
+    /// doubled, and a point added to its negation, the only other point with the same x
+    /// coordinate, gives infinity. Curves with a complete addition law can override this with a
+    /// plain addition.
+    fn accumulate(acc: &mut Option<Self>, other: &Self) {
+        let Some(point) = acc else {
+            *acc = Some(other.clone());
+            return;
+        };
+        if point.limbs_ref() == other.limbs_ref() {
+            point.double();
+        } else if point.limbs_ref()[..N / 2] == other.limbs_ref()[..N / 2] {
+            *acc = None;
+        } else {
+            point.add_assign(other);
+        }
+    }
+
+    /// Computes `sum(scalars[i] * points[i])`, or `None` if the sum is the point at infinity.
+    ///
+    /// The scalars are little endian bit vectors, possibly of different lengths. Below
+    /// [`MSM_PIPPENGER_THRESHOLD`] points, this is a double-and-add where all the points share one
+    /// accumulator and its doublings. From there on, it is the bucket method of Pippenger with
+    /// windows of `log2(n) - 2` bits. Every addition goes through [`AffinePoint::accumulate`], so
+    /// zero scalars, repeated points and partial sums at infinity are all supported.
+    fn msm(scalars: &[&[bool]], points: &[Self]) -> Option<Self> {
+        assert_eq!(scalars.len(), points.len());
+        let num_bits = scalars.iter().map(|scalar| scalar.len()).max().unwrap_or(0);
+        let bit = |scalar: &[bool], i: usize| scalar.get(i).copied().unwrap_or(false);
+
+        let mut res: Option<Self> = None;
+        if points.len() < MSM_PIPPENGER_THRESHOLD {
+            for i in (0..num_bits).rev() {
+                if let Some(res) = res.as_mut() {
+                    res.double();
+                }
+                for (scalar, point) in scalars.iter().zip(points) {
+                    if bit(scalar, i) {
+                        Self::accumulate(&mut res, point);
+                    }
+                }
+            }
+            return res;
+        }
+
+        let window = (points.len().ilog2() as usize - 2).max(1);
+        for start in (0..num_bits).step_by(window).rev() {
+            for _ in 0..window {
+                if let Some(res) = res.as_mut() {
+                    res.double();
+                }
+            }
+
+            // buckets[j] gathers the points whose scalar has the value j + 1 in this window.
+            let mut buckets: Vec<Option<Self>> = vec![None; (1 << window) - 1];
+            for (scalar, point) in scalars.iter().zip(points) {
+                let value = (start..start + window)
+                    .rev()
+                    .fold(0, |value, i| (value << 1) | bit(scalar, i) as usize);
+                if value != 0 {
+                    Self::accumulate(&mut buckets[value - 1], point);
+                }
+            }
+
+            // sum((j + 1) * buckets[j]) is the sum of the running sums from the top bucket down.
+            let mut running: Option<Self> = None;
+            for bucket in buckets.iter().rev() {
+                if let Some(bucket) = bucket {
+                    Self::accumulate(&mut running, bucket);
+                }
+                if let Some(running) = running.as_ref() {
+                    Self::accumulate(&mut res, running);
+                }
+            }
+        }
+        res
+    }
+}
+
+/// An [`AffinePoint`] on a short Weierstrass curve `y^2 = x^3 + a * x + b`.
+///
+/// The field modulus and the coefficients are little endian words of `N / 2` limbs each. The
+/// affine representation has no point at infinity, and the all-zero encoding that stands in for it
+/// is never on the curve, since `b` is nonzero for every supported curve.
+pub trait WeierstrassAffinePoint<const N: usize>: AffinePoint<N> {
+    /// The modulus of the base field.
+    const MODULUS: &'static [u32];
+
+    /// The coefficient `a` of the curve.
+    const A: &'static [u32];
+
+    /// The coefficient `b` of the curve.
+    const B: &'static [u32];
+
+    /// Computes `(a * b) % MODULUS` for reduced `a` and `b`.
+    ///
+    /// The default is a double-and-add over the bits of `b`, which needs no precompile. Curves
+    /// with a modular multiplication precompile override it.
+    fn field_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+        mul_mod(a, b, Self::MODULUS)
+    }
+
+    /// Returns whether the coordinates of `self` satisfy the curve equation.
+    ///
+    /// The coordinates are reduced first, so a non-canonical encoding of a point on the curve is
+    /// accepted. Use [`WeierstrassAffinePoint::validate`] to also reject those.
+    fn is_on_curve(&self) -> bool {
+        let p = Self::MODULUS;
+        let (x, y) = self.limbs_ref().split_at(N / 2);
+        let (x, y) = (reduce(x, p), reduce(y, p));
+
+        let x_squared = Self::field_mul(&x, &x);
+        let x_cubed = Self::field_mul(&x_squared, &x);
+        let a_x = Self::field_mul(&x, Self::A);
+        let rhs = add_mod(&add_mod(&x_cubed, &a_x, p), Self::B, p);
+        Self::field_mul(&y, &y) == rhs
+    }
+
+    /// Checks that `self` is a point on the curve that the precompiles can take as input.
+    ///
+    /// Besides the curve equation, this rejects the all-zero encoding and coordinates that are not
+    /// less than the modulus.
+    fn validate(&self) -> Result<(), PointError> {
+        let limbs = self.limbs_ref();
+        if limbs.iter().all(|&limb| limb == 0) {
+            return Err(PointError::Infinity);
+        }
+        if limbs.chunks_exact(N / 2).any(|coordinate| !is_less(coordinate, Self::MODULUS)) {
+            return Err(PointError::NonCanonical);
+        }
+        if !self.is_on_curve() {
+            return Err(PointError::NotOnCurve);
+        }
+        Ok(())
+    }
+
+    /// Negates `self`.
+    ///
+    /// The `y` coordinate must be reduced and nonzero, which holds for every point of a curve of
+    /// odd order.
+    fn negate(&mut self) {
+        let mut y = Self::MODULUS.to_vec();
+        sub_assign(&mut y, &self.limbs_ref()[N / 2..]);
+        self.limbs_mut()[N / 2..].copy_from_slice(&y);
+    }
+
+    /// Multiplies `self` by the given scalar with a Montgomery ladder, for secret scalars.
+    ///
+    /// Every bit of the scalar costs one addition and one doubling, and the two points of the
+    /// ladder are exchanged with a masked swap of their limbs rather than a branch. The syscalls
+    /// made and the cycle count therefore only depend on the length of the scalar. The only branch
+    /// on the scalar rejects zero.
+    ///
+    /// The ladder starts from `self` and `2 * self` to avoid the point at infinity, so it computes
+    /// `(2^n + k) * self` for an `n`-bit scalar `k`, and `2^n * self` is subtracted at the end. As
+    /// with the other multiplications, the incomplete addition of the precompiles gives a wrong
+    /// result if an intermediate sum is the point at infinity, which for a large prime order only
+    /// happens for a negligible fraction of scalars.
+    fn mul_assign_ct(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        debug_assert!(scalar.len() == N / 2);
+
+        if scalar.iter().fold(0, |acc, &word| acc | word) == 0 {
+            return Err(MulAssignError::ScalarIsZero);
+        }
+
+        let num_bits = 32 * scalar.len();
+        let mut offset = self.clone();
+        for _ in 0..num_bits {
+            offset.double();
+        }
+        offset.negate();
+
+        // r1 - r0 = self throughout, and r0 = (2^i + k_i) * self for the top `i` bits `k_i` of
+        // the scalar.
+        let mut r0 = self.clone();
+        let mut r1 = self.clone();
+        r1.double();
+        for i in (0..num_bits).rev() {
+            let bit = (scalar[i / 32] >> (i % 32)) & 1;
+            conditional_swap(r0.limbs_mut(), r1.limbs_mut(), bit);
+            r1.add_assign(&r0);
+            r0.double();
+            conditional_swap(r0.limbs_mut(), r1.limbs_mut(), bit);
+        }
+
+        r0.add_assign(&offset);
+        *self = r0;
+        Ok(())
+    }
+
+    /// Creates a new [`AffinePoint`] from the given x and y coordinates, checking it with
+    /// [`WeierstrassAffinePoint::validate`].
+    ///
+    /// The bytes are the concatenated little endian representations of the coordinates.
+    fn try_from_coordinates(x: &[u8], y: &[u8]) -> Result<Self, PointError> {
+        if x.len() != N * 2 || y.len() != N * 2 {
+            return Err(PointError::InvalidLength);
+        }
+        let point = <Self as AffinePoint<N>>::from(x, y);
+        point.validate()?;
+        Ok(point)
+    }
+
+    /// Creates a new [`AffinePoint`] from the given bytes in little endian, checking it with
+    /// [`WeierstrassAffinePoint::validate`].
+    fn try_from_le_bytes(bytes: &[u8]) -> Result<Self, PointError> {
+        if bytes.len() != N * 4 {
+            return Err(PointError::InvalidLength);
+        }
+        let point = Self::from_le_bytes(bytes);
+        point.validate()?;
+        Ok(point)
+    }
+
+    /// Returns the point with the given x coordinate, in little endian words, whose y coordinate
+    /// has the given parity.
+    ///
+    /// The default computes the square root with [`field_mul`], as `rhs^((p + 1) / 4)` when the
+    /// modulus is `3` modulo `4` and with Tonelli-Shanks otherwise. Curves with a decompress
+    /// precompile override it to use the syscall inside the zkVM.
+    ///
+    /// [`field_mul`]: WeierstrassAffinePoint::field_mul
+    fn decompress(x: &[u32], is_odd: bool) -> Result<Self, PointError> {
+        decompress_with_sqrt(x, is_odd)
+    }
+
+    /// Encodes `self` in the SEC1 format: `0x02` or `0x03` followed by the big endian x
+    /// coordinate when `compressed`, and `0x04` followed by both big endian coordinates
+    /// otherwise. The all-zero encoding of the point at infinity is the single byte `0x00`.
+    fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+        let limbs = self.limbs_ref();
+        if limbs.iter().all(|&limb| limb == 0) {
+            return vec![0x00];
+        }
+
+        let le_bytes = self.to_le_bytes();
+        let (x, y) = le_bytes.split_at(N * 2);
+        let mut bytes = Vec::with_capacity(1 + N * 4);
+        if compressed {
+            bytes.push(0x02 | (limbs[N / 2] & 1) as u8);
+            bytes.extend(x.iter().rev());
+        } else {
+            bytes.push(0x04);
+            bytes.extend(x.iter().rev());
+            bytes.extend(y.iter().rev());
+        }
+        bytes
+    }
+
+    /// Decodes a point in the SEC1 format written by [`WeierstrassAffinePoint::to_sec1_bytes`].
+    ///
+    /// Uncompressed points are checked with [`WeierstrassAffinePoint::validate`], and compressed
+    /// points go through [`WeierstrassAffinePoint::decompress`]. The single byte `0x00` decodes
+    /// to the all-zero encoding of the point at infinity.
+    fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, PointError> {
+        let Some((&tag, coordinates)) = bytes.split_first() else {
+            return Err(PointError::InvalidLength);
+        };
+        match tag {
+            0x00 if coordinates.is_empty() => Ok(Self::new([0; N])),
+            0x02 | 0x03 if coordinates.len() == N * 2 => {
+                let x = coordinates.iter().rev().copied().collect::<Vec<_>>();
+                Self::decompress(&bytes_to_words_le(&x), tag == 0x03)
+            }
+            0x04 if coordinates.len() == N * 4 => {
+                let (x, y) = coordinates.split_at(N * 2);
+                let x = x.iter().rev().copied().collect::<Vec<_>>();
+                let y = y.iter().rev().copied().collect::<Vec<_>>();
+                Self::try_from_coordinates(&x, &y)
+            }
+            0x00 | 0x02 | 0x03 | 0x04 => Err(PointError::InvalidLength),
+            _ => Err(PointError::InvalidEncoding),
+        }
+    }
 }
 
+/// The window used by the curve wrappers that multiply with [`AffinePoint::mul_assign_windowed`].
+pub const DEFAULT_MUL_WINDOW: usize = 4;
+
+/// The number of points from which [`AffinePoint::msm`] uses the bucket method.
+pub const MSM_PIPPENGER_THRESHOLD: usize = 32;
+
+/// The largest size of an [`AffinePoint::GENERATOR_TABLE`], which is part of every guest that
+/// links the curve. It fits the 256 entries of a curve with 256-bit coordinates.
+pub const MAX_GENERATOR_TABLE_BYTES: usize = 16 * 1024;
+
 /// Errors that can occur during scalar multiplication of an [`AffinePoint`].
 #[derive(Debug)]
 pub enum MulAssignError {
     ScalarIsZero,
 }
 
+/// Errors that can occur when validating a [`WeierstrassAffinePoint`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum PointError {
+    /// The input does not have the length of an encoded point.
+    InvalidLength,
+    /// The point is the all-zero encoding, which stands in for the point at infinity.
+    Infinity,
+    /// A coordinate is not less than the field modulus.
+    NonCanonical,
+    /// The point does not satisfy the curve equation.
+    NotOnCurve,
+    /// The first byte of a SEC1 encoding is not a known tag.
+    InvalidEncoding,
+}
+
 /// Converts a slice of words to a byte array in little endian.
 pub fn words_to_bytes_le(words: &[u32]) -> Vec<u8> {
     words.iter().flat_map(|word| word.to_le_bytes().to_vec()).collect::<Vec<_>>()
@@ -130,3 +569,725 @@ pub fn bytes_to_words_le(bytes: &[u8]) -> Vec<u32> {
         .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
         .collect::<Vec<_>>()
 }
+
+/// Swaps `a` and `b` if `choice` is one and leaves them if it is zero, without branching on
+/// `choice`.
+pub(crate) fn conditional_swap(a: &mut [u32], b: &mut [u32], choice: u32) {
+    let mask = 0u32.wrapping_sub(choice);
+    for (a, b) in a.iter_mut().zip(b) {
+        let t = mask & (*a ^ *b);
+        *a ^= t;
+        *b ^= t;
+    }
+}
+
+/// Returns the point with the given x coordinate and y parity, computing the square root of
+/// `x^3 + a * x + b` with [`sqrt_mod`].
+pub(crate) fn decompress_with_sqrt<const N: usize, P: WeierstrassAffinePoint<N>>(
+    x: &[u32],
+    is_odd: bool,
+) -> Result<P, PointError> {
+    let p = P::MODULUS;
+    if x.len() != N / 2 {
+        return Err(PointError::InvalidLength);
+    }
+    if !is_less(x, p) {
+        return Err(PointError::NonCanonical);
+    }
+
+    let x_cubed = P::field_mul(&P::field_mul(x, x), x);
+    let rhs = add_mod(&add_mod(&x_cubed, &P::field_mul(x, P::A), p), P::B, p);
+    let y = sqrt_mod::<N, P>(&rhs).ok_or(PointError::NotOnCurve)?;
+
+    let mut limbs = [0; N];
+    limbs[..N / 2].copy_from_slice(x);
+    limbs[N / 2..].copy_from_slice(&y);
+    let mut point = P::new(limbs);
+    if (y[0] & 1 == 1) != is_odd {
+        point.negate();
+    }
+    Ok(point)
+}
+
+/// Returns a square root of the reduced `n` modulo `P::MODULUS`, if there is one.
+///
+/// A modulus that is `3` modulo `4` takes the `(p + 1) / 4`-th power of `n`. Any other modulus,
+/// such as the one of Grumpkin, goes through Tonelli-Shanks with `p - 1 = 2^s * q` for an odd `q`.
+fn sqrt_mod<const N: usize, P: WeierstrassAffinePoint<N>>(n: &[u32]) -> Option<Vec<u32>> {
+    let p = P::MODULUS;
+    let mut one = vec![0; p.len()];
+    one[0] = 1;
+
+    let root = if p[0] & 3 == 3 {
+        // `(p + 1) / 4`, where `p + 1` does not overflow since no supported modulus is all ones.
+        let mut exponent = p.to_vec();
+        increment(&mut exponent);
+        pow_mod::<N, P>(n, &shr(&exponent, 2))
+    } else {
+        let mut p_minus_one = p.to_vec();
+        p_minus_one[0] -= 1;
+        let s = p_minus_one.iter().position(|&word| word != 0).unwrap() * 32
+            + p_minus_one.iter().find(|&&word| word != 0).unwrap().trailing_zeros() as usize;
+        let q = shr(&p_minus_one, s);
+
+        // The smallest quadratic non-residue, found with Euler's criterion.
+        let half = shr(&p_minus_one, 1);
+        let z = (2..)
+            .map(|z| {
+                let mut z_words = vec![0; p.len()];
+                z_words[0] = z;
+                z_words
+            })
+            .find(|z| pow_mod::<N, P>(z, &half) == p_minus_one)
+            .unwrap();
+
+        let mut q_plus_one = q.clone();
+        increment(&mut q_plus_one);
+        let mut m = s;
+        let mut c = pow_mod::<N, P>(&z, &q);
+        let mut t = pow_mod::<N, P>(n, &q);
+        let mut root = pow_mod::<N, P>(n, &shr(&q_plus_one, 1));
+        // Each step keeps `root^2 = n * t`, and lowers the order of `t`, a power of two, until
+        // `t` is one. A zero `n` gives a zero `t` and root straight away.
+        while t != one && t.iter().any(|&word| word != 0) {
+            let mut i = 0;
+            let mut t_power = t.clone();
+            while t_power != one {
+                t_power = P::field_mul(&t_power, &t_power);
+                i += 1;
+                if i == m {
+                    return None;
+                }
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = P::field_mul(&b, &b);
+            }
+            m = i;
+            c = P::field_mul(&b, &b);
+            t = P::field_mul(&t, &c);
+            root = P::field_mul(&root, &b);
+        }
+        root
+    };
+    (P::field_mul(&root, &root) == n).then_some(root)
+}
+
+/// Computes `base^exponent` modulo `P::MODULUS` by square-and-multiply with [`field_mul`].
+///
+/// [`field_mul`]: WeierstrassAffinePoint::field_mul
+fn pow_mod<const N: usize, P: WeierstrassAffinePoint<N>>(
+    base: &[u32],
+    exponent: &[u32],
+) -> Vec<u32> {
+    let mut result = vec![0; P::MODULUS.len()];
+    result[0] = 1;
+    for i in (0..32 * exponent.len()).rev() {
+        result = P::field_mul(&result, &result);
+        if (exponent[i / 32] >> (i % 32)) & 1 == 1 {
+            result = P::field_mul(&result, base);
+        }
+    }
+    result
+}
+
+/// Adds one to `a` in place, wrapping on overflow.
+fn increment(a: &mut [u32]) {
+    for word in a.iter_mut() {
+        let (sum, carry) = word.overflowing_add(1);
+        *word = sum;
+        if !carry {
+            break;
+        }
+    }
+}
+
+/// Shifts `a` right by `shift` bits.
+fn shr(a: &[u32], shift: usize) -> Vec<u32> {
+    let (words, bits) = (shift / 32, shift % 32);
+    (0..a.len())
+        .map(|i| {
+            let low = a.get(i + words).map_or(0, |&word| word >> bits);
+            let high = match a.get(i + words + 1) {
+                Some(&word) if bits > 0 => word << (32 - bits),
+                _ => 0,
+            };
+            low | high
+        })
+        .collect()
+}
+
+/// Returns whether `a < b`, for little endian words of the same length.
+fn is_less(a: &[u32], b: &[u32]) -> bool {
+    a.iter().rev().lt(b.iter().rev())
+}
+
+/// Subtracts `b` from `a` in place, wrapping on underflow.
+fn sub_assign(a: &mut [u32], b: &[u32]) {
+    let mut borrow = false;
+    for (a, &b) in a.iter_mut().zip(b) {
+        let (diff, borrow_1) = a.overflowing_sub(b);
+        let (diff, borrow_2) = diff.overflowing_sub(borrow as u32);
+        *a = diff;
+        borrow = borrow_1 || borrow_2;
+    }
+}
+
+/// Reduces `a` modulo `p` by repeated subtraction, which is quick when `p` is close to the
+/// largest value of its width.
+fn reduce(a: &[u32], p: &[u32]) -> Vec<u32> {
+    let mut a = a.to_vec();
+    while !is_less(&a, p) {
+        sub_assign(&mut a, p);
+    }
+    a
+}
+
+/// Computes `(a + b) % p` for reduced `a` and `b`.
+fn add_mod(a: &[u32], b: &[u32], p: &[u32]) -> Vec<u32> {
+    let mut carry = false;
+    let mut sum = a
+        .iter()
+        .zip(b)
+        .map(|(&a, &b)| {
+            let (sum, carry_1) = a.overflowing_add(b);
+            let (sum, carry_2) = sum.overflowing_add(carry as u32);
+            carry = carry_1 || carry_2;
+            sum
+        })
+        .collect::<Vec<_>>();
+    // The sum is less than `2p`, so one subtraction reduces it, and the wrapping subtraction
+    // accounts for the carry out of the top word.
+    if carry || !is_less(&sum, p) {
+        sub_assign(&mut sum, p);
+    }
+    sum
+}
+
+/// Computes `(a * b) % p` for reduced `a` and `b` with a double-and-add over the bits of `b`.
+pub(crate) fn mul_mod(a: &[u32], b: &[u32], p: &[u32]) -> Vec<u32> {
+    let mut res = vec![0; p.len()];
+    for i in (0..32 * b.len()).rev() {
+        res = add_mod(&res, &res, p);
+        if (b[i / 32] >> (i % 32)) & 1 == 1 {
+            res = add_mod(&res, a, p);
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use rand::Rng;
+
+    use super::*;
+
+    /// The prime `2^61 - 1`, the order of the model group below.
+    const ORDER: u64 = (1 << 61) - 1;
+
+    thread_local! {
+        /// The number of additions and doublings of the model group on this thread.
+        static NUM_OPS: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+    }
+
+    /// A model of a cyclic group of prime order, where a point is represented by its discrete
+    /// logarithm `v` with respect to the generator. The "x coordinate" is `min(v, -v)`, so that
+    /// only a point and its negation share it, and the "y coordinate" is `v`.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Multiple([u32; 4]);
+
+    impl Multiple {
+        fn value(&self) -> u64 {
+            self.0[2] as u64 | (self.0[3] as u64) << 32
+        }
+
+        fn from_value(value: u64) -> Self {
+            let value = value % ORDER;
+            let x = value.min((ORDER - value) % ORDER);
+            Self([x as u32, (x >> 32) as u32, value as u32, (value >> 32) as u32])
+        }
+    }
+
+    impl AffinePoint<4> for Multiple {
+        const GENERATOR: [u32; 4] = [1, 0, 1, 0];
+
+        const GENERATOR_TABLE: &'static [[u32; 4]] = &{
+            let mut table = [[0; 4]; 64];
+            let mut i = 0;
+            while i < 64 {
+                let value = ((1u128 << i) % ORDER as u128) as u64;
+                let x = if value < ORDER - value { value } else { ORDER - value };
+                table[i] = [x as u32, (x >> 32) as u32, value as u32, (value >> 32) as u32];
+                i += 1;
+            }
+            table
+        };
+
+        fn new(limbs: [u32; 4]) -> Self {
+            Self(limbs)
+        }
+
+        fn limbs_ref(&self) -> &[u32; 4] {
+            &self.0
+        }
+
+        fn limbs_mut(&mut self) -> &mut [u32; 4] {
+            &mut self.0
+        }
+
+        fn add_assign(&mut self, other: &Self) {
+            // The Weierstrass precompiles do not support adding a point to itself or its negation.
+            assert_ne!(self.0[..2], other.0[..2]);
+            NUM_OPS.with(|ops| ops.set((ops.get().0 + 1, ops.get().1)));
+            *self = Self::from_value(self.value() + other.value());
+        }
+
+        fn double(&mut self) {
+            NUM_OPS.with(|ops| ops.set((ops.get().0, ops.get().1 + 1)));
+            *self = Self::from_value(2 * self.value());
+        }
+    }
+
+    /// Negating the "y coordinate" modulo the order negates the point, which is all that
+    /// [`WeierstrassAffinePoint::mul_assign_ct`] needs. The curve equation does not apply.
+    impl WeierstrassAffinePoint<4> for Multiple {
+        const MODULUS: &'static [u32] = &[ORDER as u32, (ORDER >> 32) as u32];
+
+        const A: &'static [u32] = &[0, 0];
+
+        const B: &'static [u32] = &[0, 0];
+    }
+
+    #[test]
+    fn test_mul_assign_windowed() {
+        let mut rng = rand::thread_rng();
+        let mut scalars = vec![1, 2, 3, 15, 16, 17, (1 << 32) - 1, 1 << 32, ORDER - 1];
+        scalars.extend((0..50).map(|_| rng.gen_range(1..ORDER)));
+
+        for scalar in scalars {
+            let words = [scalar as u32, (scalar >> 32) as u32];
+            let point = Multiple::from_value(rng.gen_range(1..ORDER));
+
+            let mut expected = point.clone();
+            expected.mul_assign_bit_serial(&words).unwrap();
+            assert_eq!(
+                expected.value() as u128,
+                point.value() as u128 * scalar as u128 % ORDER as u128
+            );
+
+            for window in 1..=5 {
+                let mut result = point.clone();
+                result.mul_assign_windowed(&words, window).unwrap();
+                assert_eq!(result, expected);
+            }
+        }
+
+        let mut point = Multiple::new(Multiple::GENERATOR);
+        assert!(point.mul_assign_windowed(&[0, 0], DEFAULT_MUL_WINDOW).is_err());
+    }
+
+    #[test]
+    fn test_mul_assign_complete() {
+        let mut rng = rand::thread_rng();
+        let point = Multiple::from_value(rng.gen_range(1..ORDER));
+
+        // A zero scalar and the order both give the identity.
+        for scalar in [0, ORDER] {
+            let mut result = point.clone();
+            result.mul_assign_complete(&[scalar as u32, (scalar >> 32) as u32]);
+            assert_eq!(result, Multiple::new(Multiple::IDENTITY));
+        }
+
+        let mut result = point.clone();
+        result.mul_assign_complete(&[1, 0]);
+        assert_eq!(result, point);
+
+        for _ in 0..20 {
+            let scalar = rng.gen::<u64>();
+            let mut result = point.clone();
+            result.mul_assign_complete(&[scalar as u32, (scalar >> 32) as u32]);
+            let expected = point.value() as u128 * scalar as u128 % ORDER as u128;
+            assert_eq!(result, Multiple::from_value(expected as u64));
+        }
+    }
+
+    #[test]
+    fn test_mul_generator() {
+        let mut rng = rand::thread_rng();
+        for scalar in [[1, 0], [6, 0], [u32::MAX, 0x0fff_ffff], [rng.gen(), rng.gen()]] {
+            let value = scalar[0] as u128 | (scalar[1] as u128) << 32;
+            NUM_OPS.with(|ops| ops.set((0, 0)));
+            let res = Multiple::mul_generator(&scalar).unwrap();
+            assert_eq!(res, Multiple::from_value((value % ORDER as u128) as u64));
+
+            // Only the table entries of the set bits are added, and nothing is doubled.
+            let num_additions = value.count_ones() as usize - 1;
+            assert_eq!(NUM_OPS.with(Cell::get), (num_additions, 0));
+        }
+
+        // The sum of the table entries of the order is the point at infinity.
+        assert!(Multiple::mul_generator(&[0, 0]).is_err());
+        assert!(Multiple::mul_generator(&[ORDER as u32, (ORDER >> 32) as u32]).is_err());
+    }
+
+    #[test]
+    fn test_generator_tables() {
+        use k256::{elliptic_curve::sec1::ToEncodedPoint, ProjectivePoint};
+
+        use crate::{p256::P256AffinePoint, secp256k1::Secp256k1AffinePoint};
+
+        // Both tables come from the same build script, and the secp256k1 one is checked against
+        // k256 entry by entry.
+        let table = Secp256k1AffinePoint::GENERATOR_TABLE;
+        assert_eq!(table.len(), 256);
+        let mut expected = ProjectivePoint::GENERATOR;
+        for limbs in table {
+            let point = Secp256k1AffinePoint::new(*limbs);
+            assert_eq!(
+                point.to_sec1_bytes(false),
+                expected.to_affine().to_encoded_point(false).as_bytes()
+            );
+            expected = expected.double();
+        }
+
+        let table = P256AffinePoint::GENERATOR_TABLE;
+        assert_eq!(table.len(), 256);
+        assert_eq!(table[0], P256AffinePoint::GENERATOR);
+        assert!(table.iter().all(|limbs| P256AffinePoint::new(*limbs).validate().is_ok()));
+    }
+
+    #[test]
+    fn test_msm() {
+        let mut rng = rand::thread_rng();
+        for num_points in [0, 1, 2, 5, 31, 32, 40, 64] {
+            let mut values: Vec<u64> = (0..num_points).map(|_| rng.gen_range(1..ORDER)).collect();
+            if num_points >= 5 {
+                // A repeated point, and a point next to its negation.
+                values[1] = values[0];
+                values[3] = ORDER - values[2];
+            }
+            let scalars: Vec<u64> = (0..num_points)
+                .map(|i| match i % 4 {
+                    0 => 0,
+                    1 => rng.gen_range(0..1 << 8),
+                    _ => rng.gen(),
+                })
+                .collect();
+
+            let bits: Vec<Vec<bool>> = scalars
+                .iter()
+                .map(|scalar| {
+                    (0..64 - scalar.leading_zeros()).map(|i| (scalar >> i) & 1 == 1).collect()
+                })
+                .collect();
+            let bits: Vec<&[bool]> = bits.iter().map(Vec::as_slice).collect();
+            let points: Vec<Multiple> =
+                values.iter().map(|value| Multiple::from_value(*value)).collect();
+
+            let expected = values
+                .iter()
+                .zip(&scalars)
+                .map(|(value, scalar)| *value as u128 * *scalar as u128 % ORDER as u128)
+                .sum::<u128>()
+                % ORDER as u128;
+            let result = Multiple::msm(&bits, &points).map_or(0, |point| point.value());
+            assert_eq!(result as u128, expected);
+        }
+
+        // Every partial sum is at infinity.
+        let point = Multiple::from_value(rng.gen_range(1..ORDER));
+        let negation = Multiple::from_value(ORDER - point.value());
+        let scalar = [true, false, true];
+        assert!(Multiple::msm(&[&scalar[..], &scalar[..]], &[point.clone(), negation.clone()])
+            .is_none());
+        let points = [point, negation]
+            .iter()
+            .cycle()
+            .take(2 * MSM_PIPPENGER_THRESHOLD)
+            .cloned()
+            .collect::<Vec<_>>();
+        let scalars = vec![&scalar[..]; 2 * MSM_PIPPENGER_THRESHOLD];
+        assert!(Multiple::msm(&scalars, &points).is_none());
+    }
+
+    #[test]
+    fn test_weierstrass_validate() {
+        use crate::{
+            bls12381::Bls12381AffinePoint, bn254::Bn254AffinePoint, grumpkin::GrumpkinPoint,
+            p256::P256AffinePoint, p384::Secp384r1Point, secp256k1::Secp256k1AffinePoint,
+        };
+
+        fn check<const N: usize, P: WeierstrassAffinePoint<N>>() {
+            let generator = P::new(P::GENERATOR);
+            assert!(generator.is_on_curve());
+            assert_eq!(generator.validate(), Ok(()));
+
+            let bytes = generator.to_le_bytes();
+            assert!(P::try_from_le_bytes(&bytes).is_ok());
+            assert!(P::try_from_coordinates(&bytes[..N * 2], &bytes[N * 2..]).is_ok());
+            assert_eq!(P::try_from_le_bytes(&bytes[4..]).err(), Some(PointError::InvalidLength));
+
+            let infinity = P::new([0; N]);
+            assert!(!infinity.is_on_curve());
+            assert_eq!(infinity.validate(), Err(PointError::Infinity));
+
+            let mut limbs = P::GENERATOR;
+            limbs[N / 2] ^= 1;
+            assert!(!P::new(limbs).is_on_curve());
+            assert_eq!(
+                P::try_from_le_bytes(&words_to_bytes_le(&limbs)).err(),
+                Some(PointError::NotOnCurve)
+            );
+
+            // Coordinates equal to the modulus are rejected.
+            for coordinate in [0, N / 2] {
+                let mut limbs = P::GENERATOR;
+                limbs[coordinate..coordinate + N / 2].copy_from_slice(P::MODULUS);
+                assert_eq!(P::new(limbs).validate(), Err(PointError::NonCanonical));
+            }
+
+            for compressed in [false, true] {
+                let bytes = generator.to_sec1_bytes(compressed);
+                let decoded = P::from_sec1_bytes(&bytes).map(|point| point.to_le_bytes());
+                assert_eq!(decoded, Ok(generator.to_le_bytes()));
+            }
+        }
+
+        check::<16, Secp256k1AffinePoint>();
+        check::<16, Bn254AffinePoint>();
+        check::<16, P256AffinePoint>();
+        check::<24, Bls12381AffinePoint>();
+        check::<24, Secp384r1Point>();
+        // The Grumpkin modulus is 1 modulo 4, so its decompression goes through Tonelli-Shanks.
+        check::<16, GrumpkinPoint>();
+
+        // The modulus of BN254 is below 2^255, so `x + p` is a non-canonical encoding of the
+        // generator that is still on the curve.
+        let mut limbs = Bn254AffinePoint::GENERATOR;
+        limbs[..8].copy_from_slice(Bn254AffinePoint::MODULUS);
+        limbs[0] += 1;
+        let point = Bn254AffinePoint::new(limbs);
+        assert!(point.is_on_curve());
+        assert_eq!(point.validate(), Err(PointError::NonCanonical));
+    }
+
+    #[test]
+    fn test_sec1_secp256k1() {
+        use k256::{elliptic_curve::sec1::ToEncodedPoint, ProjectivePoint, PublicKey, Scalar};
+
+        use crate::secp256k1::Secp256k1AffinePoint;
+
+        let mut rng = rand::thread_rng();
+        for k in [1u64, 2, 3, rng.gen()] {
+            let expected = (ProjectivePoint::GENERATOR * Scalar::from(k)).to_affine();
+            let compressed = expected.to_encoded_point(true);
+            let uncompressed = expected.to_encoded_point(false);
+
+            let point = Secp256k1AffinePoint::from_sec1_bytes(uncompressed.as_bytes()).unwrap();
+            assert_eq!(point.to_sec1_bytes(false), uncompressed.as_bytes());
+            assert_eq!(point.to_sec1_bytes(true), compressed.as_bytes());
+            let decompressed =
+                Secp256k1AffinePoint::from_sec1_bytes(compressed.as_bytes()).unwrap();
+            assert_eq!(decompressed.0, point.0);
+        }
+
+        // Small x coordinates cover both points on the curve and points off it.
+        for x in 0..16u8 {
+            for tag in [0x02, 0x03] {
+                let mut bytes = [0u8; 33];
+                bytes[0] = tag;
+                bytes[32] = x;
+                match PublicKey::from_sec1_bytes(&bytes) {
+                    Ok(key) => assert_eq!(
+                        Secp256k1AffinePoint::from_sec1_bytes(&bytes).unwrap().to_sec1_bytes(false),
+                        key.to_encoded_point(false).as_bytes()
+                    ),
+                    Err(_) => assert_eq!(
+                        Secp256k1AffinePoint::from_sec1_bytes(&bytes).err(),
+                        Some(PointError::NotOnCurve)
+                    ),
+                }
+            }
+        }
+
+        let infinity = k256::AffinePoint::IDENTITY.to_encoded_point(true);
+        let point = Secp256k1AffinePoint::from_sec1_bytes(infinity.as_bytes()).unwrap();
+        assert_eq!(point.0, [0; 16]);
+        assert_eq!(point.to_sec1_bytes(true), infinity.as_bytes());
+        assert_eq!(point.to_sec1_bytes(false), infinity.as_bytes());
+
+        let generator = Secp256k1AffinePoint::new(Secp256k1AffinePoint::GENERATOR);
+        let mut bytes = generator.to_sec1_bytes(true);
+        assert_eq!(
+            Secp256k1AffinePoint::from_sec1_bytes(&bytes[..32]).err(),
+            Some(PointError::InvalidLength)
+        );
+        assert_eq!(
+            Secp256k1AffinePoint::from_sec1_bytes(&[]).err(),
+            Some(PointError::InvalidLength)
+        );
+        bytes[0] = 0x05;
+        assert_eq!(
+            Secp256k1AffinePoint::from_sec1_bytes(&bytes).err(),
+            Some(PointError::InvalidEncoding)
+        );
+        bytes[0] = 0x02;
+        bytes[1..].copy_from_slice(&words_to_bytes_le(Secp256k1AffinePoint::MODULUS));
+        bytes[1..].reverse();
+        assert_eq!(
+            Secp256k1AffinePoint::from_sec1_bytes(&bytes).err(),
+            Some(PointError::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn test_sec1_p384() {
+        use crate::p384::Secp384r1Point;
+
+        // Uncompressed encodings of `k * G` for k = 1, 2, 3 and 0x9e3779b97f4a7c15, computed with
+        // the p384 crate.
+        let multiples = [
+            concat!(
+                "04",
+                "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+                "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+            ),
+            concat!(
+                "04",
+                "08d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df61",
+                "8e80f1fa5b1b3cedb7bfe8dffd6dba74b275d875bc6cc43e904e505f256ab4255ffd43e94d39e22d61501e700a940e80",
+            ),
+            concat!(
+                "04",
+                "077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06bea4f240d3566da6b408bbae5026580d02d7e5c70500c831",
+                "c995f7ca0b0c42837d0bbe9602a9fc998520b41c85115aa5f7684c0edc111eacc24abd6be4b5d298b65f28600a2f1df1",
+            ),
+            concat!(
+                "04",
+                "1c4387e829775689439b1693b5b764a1df0fa1be60d6f1b20e4831ec27712a68e416b064d83ecae6eacaba922dee36ab",
+                "69aa4c762b1c6673c96f6e0708457774345be7e355ebcd3c9c0f7b1fb469c8aa487cc864c4111901176d167f2b6cc9f3",
+            ),
+        ];
+        for uncompressed in multiples {
+            let uncompressed = hex::decode(uncompressed).unwrap();
+            let compressed = [&[0x02 | (uncompressed[96] & 1)][..], &uncompressed[1..49]].concat();
+
+            let point = Secp384r1Point::from_sec1_bytes(&uncompressed).unwrap();
+            assert_eq!(point.to_sec1_bytes(false), uncompressed);
+            assert_eq!(point.to_sec1_bytes(true), compressed);
+            let decompressed = Secp384r1Point::from_sec1_bytes(&compressed).unwrap();
+            assert_eq!(decompressed.0, point.0);
+        }
+
+        // The nonzero `a` enters the curve equation for both the points on it and those off it.
+        // The even y coordinate for each small x on the curve, computed with the p384 crate.
+        let even_ys = [
+            Some("3cf99ef04f51a5ea630ba3f9f960dd593a14c9be39fd2bd215d3b4b08aaaf86bbf927f2c46e52ab06fb742b8850e521e"),
+            None,
+            Some("8cdeadbbd04911a3c1931e26df3fa6439dca9c7eb286fbd46fc319f0e2bb780232baf57825fc0c1912ada2fefe84024c"),
+            Some("6660041b1c7984620e8d7fd7ccdb50cc3ba816da14d41a4d8affaba8488867f0ca5a24f8d42dd7e44b530a27dc5b58da"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some("f31bf533343f55307425042705d25d8b133349a2ee348f1e416cfbac3e6692c204739482495ca9bdc3f118006f4bc682"),
+            Some("0146bc8df1638f14c565974d16373ec819564ed2393bdfb0504353d11b0f5dcafcff3a838fcd2d011ce8654f76355af8"),
+            Some("62518f2490fc29395f8d07b9f892bf39c9573f639adfc3cf88b598bbd691495249a538430cf1fd2e69636d75c978a79e"),
+            Some("a3dcc13370aad34976b04943544fbad73ba56ec404e0618aa472e1fcd3f6af9d3a44dc2050b526657249e80399af8536"),
+            None,
+            Some("a4ae8e7917e4474a1a882aea3238f61f471ed69341b0f4230a1ed38427501b1b71004a51570686e82b904ccdf1a759c2"),
+        ];
+        for (x, even_y) in even_ys.into_iter().enumerate() {
+            let mut bytes = [0u8; 49];
+            bytes[0] = 0x02;
+            bytes[48] = x as u8;
+            let Some(even_y) = even_y else {
+                for tag in [0x02, 0x03] {
+                    bytes[0] = tag;
+                    assert_eq!(
+                        Secp384r1Point::from_sec1_bytes(&bytes).err(),
+                        Some(PointError::NotOnCurve)
+                    );
+                }
+                continue;
+            };
+
+            let mut point = Secp384r1Point::from_sec1_bytes(&bytes).unwrap();
+            assert_eq!(point.to_sec1_bytes(false)[49..], hex::decode(even_y).unwrap());
+            bytes[0] = 0x03;
+            let odd = Secp384r1Point::from_sec1_bytes(&bytes).unwrap();
+            point.negate();
+            assert_eq!(odd.0, point.0);
+        }
+    }
+
+    #[test]
+    fn test_sec1_bn254() {
+        use crate::bn254::Bn254AffinePoint;
+
+        // Multiples of the generator (1, 2) for k = 1, 2, 3 and 0x9e3779b97f4a7c15, computed with
+        // ark-bn254.
+        let multiples = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+            ),
+            (
+                "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3",
+                "15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+            ),
+            (
+                "0769bf9ac56bea3ff40232bcb1b6bd159315d84715b8e679f2d355961915abf0",
+                "2ab799bee0489429554fdb7c8d086475319e63b40b9c5b57cdf1ff3dd9fe2261",
+            ),
+            (
+                "15e9f0f2cc4d6d245f6359dbe25076bdf6293d67f9b267eaa32956b7f0b95617",
+                "1550e7d71f383728bb3c142247f874cdbb501b6abfaeaace7bef452fac09d81f",
+            ),
+        ];
+        for (x, y) in multiples {
+            let (x, y) = (hex::decode(x).unwrap(), hex::decode(y).unwrap());
+            let compressed = [vec![0x02 | (y[31] & 1)], x.clone()].concat();
+            let uncompressed = [vec![0x04], x, y].concat();
+
+            let point = Bn254AffinePoint::from_sec1_bytes(&uncompressed).unwrap();
+            assert_eq!(point.to_sec1_bytes(false), uncompressed);
+            assert_eq!(point.to_sec1_bytes(true), compressed);
+            let decompressed = Bn254AffinePoint::from_sec1_bytes(&compressed).unwrap();
+            assert_eq!(decompressed.0, point.0);
+        }
+
+        let point = Bn254AffinePoint::from_sec1_bytes(&[0x00]).unwrap();
+        assert_eq!(point.0, [0; 16]);
+        assert_eq!(point.to_sec1_bytes(true), [0x00]);
+    }
+
+    #[test]
+    fn test_mul_assign_ct() {
+        let mut rng = rand::thread_rng();
+        let point = Multiple::from_value(rng.gen_range(1..ORDER));
+
+        let mut num_ops = Vec::new();
+        for scalar in [[1, 0], [5, 0], [u32::MAX, 0xffff_0000], [rng.gen(), rng.gen()]] {
+            let expected = (point.value() as u128 * (scalar[0] as u128 | (scalar[1] as u128) << 32)
+                % ORDER as u128) as u64;
+
+            NUM_OPS.with(|ops| ops.set((0, 0)));
+            let mut res = point.clone();
+            res.mul_assign_ct(&scalar).unwrap();
+            assert_eq!(res, Multiple::from_value(expected));
+            num_ops.push(NUM_OPS.with(Cell::get));
+        }
+
+        // One addition and two doublings per bit of the scalar, counting the doublings of the
+        // offset, plus the doubling of the setup and the final addition.
+        assert_eq!(num_ops[0], (65, 129));
+        assert!(num_ops.iter().all(|ops| *ops == num_ops[0]));
+
+        assert!(point.clone().mul_assign_ct(&[0, 0]).is_err());
+    }
+}
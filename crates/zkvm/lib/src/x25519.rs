@@ -0,0 +1,88 @@
+//! X25519 Diffie-Hellman as specified in RFC 7748, with the same interface as `x25519-dalek`.
+
+use crate::{bignum::mod_exp, syscall_x25519_ladder_step, uint256::U256, utils::conditional_swap};
+
+/// The u-coordinate of the base point of Curve25519.
+pub const X25519_BASEPOINT_BYTES: [u8; 32] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// The modulus `2^255 - 19` of the base field, as little endian words.
+const P: [u32; 8] = [
+    0xFFFF_FFED,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0x7FFF_FFFF,
+];
+
+/// The exponent `p - 2`, which inverts a field element by Fermat's little theorem.
+const P_MINUS_2: [u32; 8] = [
+    0xFFFF_FFEB,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0xFFFF_FFFF,
+    0x7FFF_FFFF,
+];
+
+/// Clamps a scalar as in RFC 7748: the low three bits are cleared, the top bit is cleared and the
+/// second highest bit is set.
+pub fn clamp_integer(mut bytes: [u8; 32]) -> [u8; 32] {
+    bytes[0] &= 0b1111_1000;
+    bytes[31] &= 0b0111_1111;
+    bytes[31] |= 0b0100_0000;
+    bytes
+}
+
+/// Computes the X25519 function of RFC 7748 on the scalar `k` and the u-coordinate `u`.
+///
+/// The scalar is clamped and the top bit of `u` is masked. Each of the 255 steps of the Montgomery
+/// ladder is a call to the `X25519_LADDER_STEP` precompile, and the final inversion is a single
+/// `UINT256_MOD_EXP` call. The result is all zero when `u` is a point of small order; see
+/// [`x25519_checked`].
+pub fn x25519(k: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let k = clamp_integer(k);
+    let mut u_words = [0u32; 8];
+    for (word, chunk) in u_words.iter_mut().zip(u.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    u_words[7] &= 0x7FFF_FFFF;
+
+    // The state is `[x2, z2, x3, z3]`, starting from the point at infinity and the point `u`.
+    let mut state = [0u32; 32];
+    state[0] = 1;
+    state[16..24].copy_from_slice(&u_words);
+    state[24] = 1;
+
+    let mut swap = 0;
+    for t in (0..255).rev() {
+        let bit = ((k[t / 8] >> (t % 8)) & 1) as u32;
+        swap ^= bit;
+        let (x2_z2, x3_z3) = state.split_at_mut(16);
+        conditional_swap(x2_z2, x3_z3, swap);
+        swap = bit;
+        unsafe {
+            syscall_x25519_ladder_step(&mut state, &u_words);
+        }
+    }
+    let (x2_z2, x3_z3) = state.split_at_mut(16);
+    conditional_swap(x2_z2, x3_z3, swap);
+
+    // A zero z2 has no inverse, and `0^(p - 2) = 0` gives the all zero result of RFC 7748.
+    let z2_inverse = mod_exp(&state[8..16].try_into().unwrap(), &P_MINUS_2, &P);
+    let x2 = U256(state[..8].try_into().unwrap());
+    x2.mulmod(&U256(z2_inverse), &U256(P)).to_le_bytes()
+}
+
+/// Computes [`x25519`], returning `None` for an all zero result as RFC 7748 recommends for
+/// Diffie-Hellman, which happens when the peer's u-coordinate is a point of small order.
+pub fn x25519_checked(k: [u8; 32], u: [u8; 32]) -> Option<[u8; 32]> {
+    let shared = x25519(k, u);
+    shared.iter().any(|byte| *byte != 0).then_some(shared)
+}
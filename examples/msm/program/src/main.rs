@@ -0,0 +1,66 @@
+//! Computes a multi-scalar multiplication on BN254 with the method read from the input.
+//!
+//! The methods are the sum of the individual products, [`AffinePoint::msm`] with the BN254
+//! precompiles, and [`msm_jacobian`] with the uint256 precompile. The `setup` method only builds
+//! the inputs, so that its costs can be subtracted from the others.
+
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::lib::{bn254::Bn254AffinePoint, jacobian::msm_jacobian, utils::AffinePoint};
+
+/// Returns `n` pseudorandom scalars below `2^252`, less than the order of the BN254 group.
+fn scalars(n: usize) -> Vec<[u32; 8]> {
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as u32
+    };
+    (0..n)
+        .map(|_| {
+            let mut scalar: [u32; 8] = core::array::from_fn(|_| next());
+            scalar[7] &= 0x0fff_ffff;
+            scalar
+        })
+        .collect()
+}
+
+pub fn main() {
+    let n = sp1_zkvm::io::read::<usize>();
+    let method = sp1_zkvm::io::read::<String>();
+
+    // The points G, 2G, ..., nG.
+    let generator = Bn254AffinePoint::new(Bn254AffinePoint::GENERATOR);
+    let mut points = vec![generator];
+    let mut point = Some(generator);
+    for _ in 1..n {
+        Bn254AffinePoint::accumulate(&mut point, &generator);
+        points.push(point.unwrap());
+    }
+    let scalars = scalars(n);
+    let bits: Vec<Vec<bool>> = scalars
+        .iter()
+        .map(|scalar| (0..256).map(|i| (scalar[i / 32] >> (i % 32)) & 1 == 1).collect())
+        .collect();
+    let bits: Vec<&[bool]> = bits.iter().map(Vec::as_slice).collect();
+
+    let result = match method.as_str() {
+        "setup" => None,
+        "naive" => {
+            let mut sum = None;
+            for (point, scalar) in points.iter().zip(&scalars) {
+                let mut product = *point;
+                product.mul_assign(scalar).unwrap();
+                Bn254AffinePoint::accumulate(&mut sum, &product);
+            }
+            sum
+        }
+        "affine" => Bn254AffinePoint::msm(&bits, &points),
+        "jacobian" => msm_jacobian(&bits, &points),
+        _ => panic!("unknown method {}", method),
+    };
+
+    sp1_zkvm::io::commit(&result.map(|point| point.0));
+}
@@ -0,0 +1,53 @@
+use sp1_core_executor::{syscalls::SyscallCode, ExecutionReport};
+use sp1_sdk::{utils, ProverClient, SP1Stdin};
+
+/// The ELF we want to execute inside the zkVM.
+const ELF: &[u8] = include_bytes!("../../program/elf/riscv32im-succinct-zkvm-elf");
+
+/// Returns the cycles, the rows of the BN254 precompiles and the rows of the uint256 precompiles.
+fn costs(report: &ExecutionReport) -> [u64; 3] {
+    let counts = &report.syscall_counts;
+    [
+        report.total_instruction_count(),
+        counts[SyscallCode::BN254_ADD] + counts[SyscallCode::BN254_DOUBLE],
+        counts[SyscallCode::UINT256_MUL] + counts[SyscallCode::UINT256_INV_MOD],
+    ]
+}
+
+fn main() {
+    // Setup a tracer for logging.
+    utils::setup_logger();
+
+    let client = ProverClient::new();
+    for n in [8, 32, 64] {
+        let mut setup = [0; 3];
+        let mut expected = None;
+        for method in ["setup", "naive", "affine", "jacobian"] {
+            let mut stdin = SP1Stdin::new();
+            stdin.write(&n);
+            stdin.write(&method.to_string());
+            let (mut public_values, report) =
+                client.execute(ELF, stdin).run().expect("execution failed");
+            let result = public_values.read::<Option<[u32; 16]>>();
+
+            // The costs of building the inputs are subtracted from those of each method.
+            let costs = costs(&report);
+            if method == "setup" {
+                setup = costs;
+                continue;
+            }
+            let [cycles, bn254_rows, uint256_rows] =
+                [0, 1, 2].map(|i| costs[i].saturating_sub(setup[i]));
+            println!(
+                "{} points, {:>8}: {} cycles, {} BN254 rows, {} uint256 rows",
+                n, method, cycles, bn254_rows, uint256_rows
+            );
+
+            // Every method computes the same sum.
+            match expected {
+                Some(expected) => assert_eq!(result, expected),
+                None => expected = Some(result),
+            }
+        }
+    }
+}